@@ -218,6 +218,8 @@
     impl_vec_clone!(f32,  AzF32Vec,  AzF32VecDestructor);
     impl_vec!(AzXmlNode,  AzXmlNodeVec,  AzXmlNodeVecDestructor, az_xml_node_vec_destructor, AzXmlNodeVec_delete);
     impl_vec_clone!(AzXmlNode,  AzXmlNodeVec,  AzXmlNodeVecDestructor);
+    impl_vec!(AzXmlSpan,  AzXmlSpanVec,  AzXmlSpanVecDestructor, az_xml_span_vec_destructor, AzXmlSpanVec_delete);
+    impl_vec_clone!(AzXmlSpan,  AzXmlSpanVec,  AzXmlSpanVecDestructor);
     impl_vec!(AzInlineWord,  AzInlineWordVec,  AzInlineWordVecDestructor, az_inline_word_vec_destructor, AzInlineWordVec_delete);
     impl_vec_clone!(AzInlineWord,  AzInlineWordVec,  AzInlineWordVecDestructor);
     impl_vec!(AzInlineGlyph,  AzInlineGlyphVec,  AzInlineGlyphVecDestructor, az_inline_glyph_vec_destructor, AzInlineGlyphVec_delete);
@@ -309,6 +311,33 @@
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
 
+    impl_vec!(AzTextInputValidator,  AzTextInputValidatorVec,  AzTextInputValidatorVecDestructor, az_text_input_validator_vec_destructor, AzTextInputValidatorVec_delete);
+    impl_vec_clone!(AzTextInputValidator,  AzTextInputValidatorVec,  AzTextInputValidatorVecDestructor);
+
+    impl_vec!(AzDropDownChoice,  AzDropDownChoiceVec,  AzDropDownChoiceVecDestructor, az_drop_down_choice_vec_destructor, AzDropDownChoiceVec_delete);
+    impl_vec_clone!(AzDropDownChoice,  AzDropDownChoiceVec,  AzDropDownChoiceVecDestructor);
+
+    impl_vec!(AzTabContainerTab,  AzTabContainerTabVec,  AzTabContainerTabVecDestructor, az_tab_container_tab_vec_destructor, AzTabContainerTabVec_delete);
+    impl_vec_clone!(AzTabContainerTab,  AzTabContainerTabVec,  AzTabContainerTabVecDestructor);
+
+    impl_vec!(AzTextEditUndoEntry,  AzTextEditUndoEntryVec,  AzTextEditUndoEntryVecDestructor, az_text_edit_undo_entry_vec_destructor, AzTextEditUndoEntryVec_delete);
+    impl_vec_clone!(AzTextEditUndoEntry,  AzTextEditUndoEntryVec,  AzTextEditUndoEntryVecDestructor);
+
+    impl_vec!(AzTreeViewNodeData,  AzTreeViewNodeDataVec,  AzTreeViewNodeDataVecDestructor, az_tree_view_node_data_vec_destructor, AzTreeViewNodeDataVec_delete);
+    impl_vec_clone!(AzTreeViewNodeData,  AzTreeViewNodeDataVec,  AzTreeViewNodeDataVecDestructor);
+
+    impl_vec!(AzTreeViewNode,  AzTreeViewNodeVec,  AzTreeViewNodeVecDestructor, az_tree_view_node_vec_destructor, AzTreeViewNodeVec_delete);
+    impl_vec_clone!(AzTreeViewNode,  AzTreeViewNodeVec,  AzTreeViewNodeVecDestructor);
+
+    impl_vec!(AzTreeViewRow,  AzTreeViewRowVec,  AzTreeViewRowVecDestructor, az_tree_view_row_vec_destructor, AzTreeViewRowVec_delete);
+    impl_vec_clone!(AzTreeViewRow,  AzTreeViewRowVec,  AzTreeViewRowVecDestructor);
+
+    impl_vec!(AzTreeViewRenderedRow,  AzTreeViewRenderedRowVec,  AzTreeViewRenderedRowVecDestructor, az_tree_view_rendered_row_vec_destructor, AzTreeViewRenderedRowVec_delete);
+    impl_vec_clone!(AzTreeViewRenderedRow,  AzTreeViewRenderedRowVec,  AzTreeViewRenderedRowVecDestructor);
+
+    impl_vec!(AzVirtualListMeasuredRow,  AzVirtualListMeasuredRowVec,  AzVirtualListMeasuredRowVecDestructor, az_virtual_list_measured_row_vec_destructor, AzVirtualListMeasuredRowVec_delete);
+    impl_vec_clone!(AzVirtualListMeasuredRow,  AzVirtualListMeasuredRowVec,  AzVirtualListMeasuredRowVecDestructor);
+
     impl From<vec::Vec<string::String>> for crate::vec::StringVec {
         fn from(v: vec::Vec<string::String>) -> crate::vec::StringVec {
             let vec: Vec<AzString> = v.into_iter().map(Into::into).collect();
@@ -48,6 +48,7 @@
     .enable_visual_panic_hook = true, \
     .enable_logging_on_panic = true, \
     .enable_tab_navigation = true, \
+    .record_events = false, \
     .system_callbacks = AzSystemCallbacks_libraryInternal(), \
 }
 
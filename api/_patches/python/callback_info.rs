@@ -0,0 +1,19 @@
+    // impl CallbackInfo {
+
+    fn start_drag(&mut self, py: Python, payload: PyObject, ghost: AzOptionImageRefEnumWrapper) -> Result<bool, PyErr> { // RefAny<DatasetTy>
+        use pyo3::type_object::PyTypeInfo;
+
+        if payload.as_ref(py.clone()).is_callable() {
+            return Err(PyException::new_err(format!("ERROR in CallbackInfo.start_drag: - argument \"payload\" is a function callback, expected class")));
+        }
+
+        let payload_refany = azul_impl::callbacks::RefAny::new(DatasetTy {
+            _py_data: Some(payload),
+        });
+
+        Ok(unsafe { mem::transmute(crate::AzCallbackInfo_startDrag(
+            mem::transmute(self),
+            payload_refany,
+            mem::transmute(ghost),
+        )) })
+    }
@@ -79,4 +79,40 @@
         self.add_callback(py, event, data, callback)?;
         let d: &mut azul_impl::dom::NodeData = unsafe { mem::transmute(self) };
         Ok(unsafe { mem::transmute(d.swap_with_default()) })
+    }
+
+    fn add_shortcut(&mut self, py: Python, combo: AzVirtualKeyCodeCombo, data: PyObject, callback: PyObject) -> Result<(), PyErr> { // RefAny<CallbackTy>
+        use pyo3::type_object::PyTypeInfo;
+
+        if data.as_ref(py.clone()).is_callable() {
+            return Err(PyException::new_err(format!("ERROR in NodeData.add_shortcut: - argument \"data\" is a function callback, expected class")));
+        }
+
+        let cb_any = callback.as_ref(py);
+        if !cb_any.is_callable() {
+            let type_name = cb_any.get_type().name().unwrap_or("<unknown>");
+            return Err(PyException::new_err(format!("ERROR in NodeData.add_shortcut: - argument \"callback\" is of type \"{}\", expected function", type_name)));
+        }
+
+        let callback_refany = azul_impl::callbacks::RefAny::new(CallbackTy {
+            _py_callback: Some(callback),
+            _py_data: Some(data),
+        });
+
+        unsafe {
+            crate::AzNodeData_addShortcut(
+                mem::transmute(self),
+                mem::transmute(combo),
+                callback_refany,
+                invoke_python_callback
+            );
+        }
+
+        Ok(())
+    }
+
+    fn with_shortcut(&mut self, py: Python, combo: AzVirtualKeyCodeCombo, data: PyObject, callback: PyObject) -> Result<Self, PyErr> { // RefAny<CallbackTy>
+        self.add_shortcut(py, combo, data, callback)?;
+        let d: &mut azul_impl::dom::NodeData = unsafe { mem::transmute(self) };
+        Ok(unsafe { mem::transmute(d.swap_with_default()) })
     }
\ No newline at end of file
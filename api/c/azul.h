@@ -84,6 +84,10 @@ struct AzCheckBoxState;
 typedef struct AzCheckBoxState AzCheckBoxState;
 typedef AzUpdate (*AzCheckBoxOnToggleCallbackType)(AzRefAny* restrict A, AzCheckBoxState* const B, AzCallbackInfo* restrict C);
 
+struct AzDropDownState;
+typedef struct AzDropDownState AzDropDownState;
+typedef AzUpdate (*AzDropDownOnChoiceChangeCallbackType)(AzRefAny* restrict A, AzDropDownState* const B, AzCallbackInfo* restrict C);
+
 struct AzColorInputState;
 typedef struct AzColorInputState AzColorInputState;
 typedef AzUpdate (*AzColorInputOnValueChangeCallbackType)(AzRefAny* restrict A, AzColorInputState* const B, AzCallbackInfo* restrict C);
@@ -98,10 +102,54 @@ typedef AzOnTextInputReturn (*AzTextInputOnVirtualKeyDownCallbackType)(AzRefAny*
 
 typedef AzUpdate (*AzTextInputOnFocusLostCallbackType)(AzRefAny* restrict A, AzTextInputState* const B, AzCallbackInfo* restrict C);
 
+union AzTextInputValidationResult;
+typedef union AzTextInputValidationResult AzTextInputValidationResult;
+typedef AzTextInputValidationResult (*AzTextInputValidatorCallbackType)(AzRefAny* restrict A, AzTextInputState* const B);
+
+typedef AzUpdate (*AzTextInputOnValidationChangeCallbackType)(AzRefAny* restrict A, AzTextInputState* const B, AzCallbackInfo* restrict C);
+
 struct AzNumberInputState;
 typedef struct AzNumberInputState AzNumberInputState;
 typedef AzUpdate (*AzNumberInputOnValueChangeCallbackType)(AzRefAny* restrict A, AzNumberInputState* const B, AzCallbackInfo* restrict C);
 
+struct AzSplitPaneState;
+typedef struct AzSplitPaneState AzSplitPaneState;
+typedef AzUpdate (*AzSplitPaneOnResizeCallbackType)(AzRefAny* restrict A, AzSplitPaneState* const B, AzCallbackInfo* restrict C);
+
+struct AzTabContainerState;
+typedef struct AzTabContainerState AzTabContainerState;
+typedef AzUpdate (*AzTabContainerOnTabChangeCallbackType)(AzRefAny* restrict A, AzTabContainerState* const B, AzCallbackInfo* restrict C);
+
+struct AzTabContainerTab;
+typedef struct AzTabContainerTab AzTabContainerTab;
+typedef AzUpdate (*AzTabContainerOnTabCloseCallbackType)(AzRefAny* restrict A, AzTabContainerState* const B, AzTabContainerTab C, AzCallbackInfo* restrict D);
+
+typedef AzUpdate (*AzTabContainerOnTabMoveCallbackType)(AzRefAny* restrict A, AzTabContainerState* const B, size_t C, size_t D, AzCallbackInfo* restrict E);
+
+struct AzSliderState;
+typedef struct AzSliderState AzSliderState;
+typedef AzUpdate (*AzSliderOnValueChangeCallbackType)(AzRefAny* restrict A, AzSliderState* const B, AzCallbackInfo* restrict C);
+
+struct AzTextEditState;
+typedef struct AzTextEditState AzTextEditState;
+typedef AzUpdate (*AzTextEditOnTextChangeCallbackType)(AzRefAny* restrict A, AzTextEditState* const B, AzCallbackInfo* restrict C);
+
+struct AzTreeViewState;
+typedef struct AzTreeViewState AzTreeViewState;
+struct AzTreeViewLoadRequest;
+typedef struct AzTreeViewLoadRequest AzTreeViewLoadRequest;
+typedef AzUpdate (*AzTreeViewOnLoadChildrenCallbackType)(AzRefAny* restrict A, AzTreeViewState* restrict B, AzTreeViewLoadRequest C, AzCallbackInfo* restrict D);
+
+typedef AzUpdate (*AzTreeViewOnSelectionChangeCallbackType)(AzRefAny* restrict A, AzTreeViewState* const B, AzCallbackInfo* restrict C);
+
+typedef AzUpdate (*AzTreeViewOnNodeMovedCallbackType)(AzRefAny* restrict A, AzTreeViewState* const B, size_t C, AzCallbackInfo* restrict D);
+
+typedef AzStyledDom (*AzVirtualListRenderRowCallbackType)(AzRefAny* restrict A, size_t B);
+
+struct AzVirtualListState;
+typedef struct AzVirtualListState AzVirtualListState;
+typedef AzUpdate (*AzVirtualListOnScrollCallbackType)(AzRefAny* restrict A, AzVirtualListState* const B, AzCallbackInfo* restrict C);
+
 typedef void (*AzParsedFontDestructorFnType)(void* restrict A);
 
 struct AzInstantPtr;
@@ -164,6 +212,10 @@ struct AzXmlNodeVec;
 typedef struct AzXmlNodeVec AzXmlNodeVec;
 typedef void (*AzXmlNodeVecDestructorType)(AzXmlNodeVec* restrict A);
 
+struct AzXmlSpanVec;
+typedef struct AzXmlSpanVec AzXmlSpanVec;
+typedef void (*AzXmlSpanVecDestructorType)(AzXmlSpanVec* restrict A);
+
 struct AzFmtArgVec;
 typedef struct AzFmtArgVec AzFmtArgVec;
 typedef void (*AzFmtArgVecDestructorType)(AzFmtArgVec* restrict A);
@@ -268,6 +320,42 @@ struct AzScanCodeVec;
 typedef struct AzScanCodeVec AzScanCodeVec;
 typedef void (*AzScanCodeVecDestructorType)(AzScanCodeVec* restrict A);
 
+struct AzTextInputValidatorVec;
+typedef struct AzTextInputValidatorVec AzTextInputValidatorVec;
+typedef void (*AzTextInputValidatorVecDestructorType)(AzTextInputValidatorVec* restrict A);
+
+struct AzDropDownChoiceVec;
+typedef struct AzDropDownChoiceVec AzDropDownChoiceVec;
+typedef void (*AzDropDownChoiceVecDestructorType)(AzDropDownChoiceVec* restrict A);
+
+struct AzTabContainerTabVec;
+typedef struct AzTabContainerTabVec AzTabContainerTabVec;
+typedef void (*AzTabContainerTabVecDestructorType)(AzTabContainerTabVec* restrict A);
+
+struct AzTextEditUndoEntryVec;
+typedef struct AzTextEditUndoEntryVec AzTextEditUndoEntryVec;
+typedef void (*AzTextEditUndoEntryVecDestructorType)(AzTextEditUndoEntryVec* restrict A);
+
+struct AzTreeViewNodeDataVec;
+typedef struct AzTreeViewNodeDataVec AzTreeViewNodeDataVec;
+typedef void (*AzTreeViewNodeDataVecDestructorType)(AzTreeViewNodeDataVec* restrict A);
+
+struct AzTreeViewNodeVec;
+typedef struct AzTreeViewNodeVec AzTreeViewNodeVec;
+typedef void (*AzTreeViewNodeVecDestructorType)(AzTreeViewNodeVec* restrict A);
+
+struct AzTreeViewRowVec;
+typedef struct AzTreeViewRowVec AzTreeViewRowVec;
+typedef void (*AzTreeViewRowVecDestructorType)(AzTreeViewRowVec* restrict A);
+
+struct AzTreeViewRenderedRowVec;
+typedef struct AzTreeViewRenderedRowVec AzTreeViewRenderedRowVec;
+typedef void (*AzTreeViewRenderedRowVecDestructorType)(AzTreeViewRenderedRowVec* restrict A);
+
+struct AzVirtualListMeasuredRowVec;
+typedef struct AzVirtualListMeasuredRowVec AzVirtualListMeasuredRowVec;
+typedef void (*AzVirtualListMeasuredRowVecDestructorType)(AzVirtualListMeasuredRowVec* restrict A);
+
 struct AzCssDeclarationVec;
 typedef struct AzCssDeclarationVec AzCssDeclarationVec;
 typedef void (*AzCssDeclarationVecDestructorType)(AzCssDeclarationVec* restrict A);
@@ -928,6 +1016,13 @@ enum AzHoverEventFilter {
    AzHoverEventFilter_TouchMove,
    AzHoverEventFilter_TouchEnd,
    AzHoverEventFilter_TouchCancel,
+   AzHoverEventFilter_DragStart,
+   AzHoverEventFilter_DragEnter,
+   AzHoverEventFilter_DragOver,
+   AzHoverEventFilter_DragLeave,
+   AzHoverEventFilter_Drop,
+   AzHoverEventFilter_DragEnd,
+   AzHoverEventFilter_ImeComposition,
 };
 typedef enum AzHoverEventFilter AzHoverEventFilter;
 
@@ -951,6 +1046,7 @@ enum AzFocusEventFilter {
    AzFocusEventFilter_VirtualKeyUp,
    AzFocusEventFilter_FocusReceived,
    AzFocusEventFilter_FocusLost,
+   AzFocusEventFilter_ImeComposition,
 };
 typedef enum AzFocusEventFilter AzFocusEventFilter;
 
@@ -985,6 +1081,11 @@ enum AzWindowEventFilter {
    AzWindowEventFilter_FocusLost,
    AzWindowEventFilter_CloseRequested,
    AzWindowEventFilter_ThemeChanged,
+   AzWindowEventFilter_DragStart,
+   AzWindowEventFilter_DragOver,
+   AzWindowEventFilter_Drop,
+   AzWindowEventFilter_DragEnd,
+   AzWindowEventFilter_ImeComposition,
 };
 typedef enum AzWindowEventFilter AzWindowEventFilter;
 
@@ -1111,6 +1212,13 @@ union AzTabIndex {
 };
 typedef union AzTabIndex AzTabIndex;
 
+enum AzRovingFocusOrientation {
+   AzRovingFocusOrientation_Horizontal,
+   AzRovingFocusOrientation_Vertical,
+   AzRovingFocusOrientation_Both,
+};
+typedef enum AzRovingFocusOrientation AzRovingFocusOrientation;
+
 enum AzContextMenuMouseButton {
    AzContextMenuMouseButton_Right,
    AzContextMenuMouseButton_Middle,
@@ -1454,6 +1562,11 @@ struct AzCheckBoxState {
 };
 typedef struct AzCheckBoxState AzCheckBoxState;
 
+struct AzDropDownOnChoiceChangeCallback {
+    AzDropDownOnChoiceChangeCallbackType cb;
+};
+typedef struct AzDropDownOnChoiceChangeCallback AzDropDownOnChoiceChangeCallback;
+
 struct AzColorInputOnValueChangeCallback {
     AzColorInputOnValueChangeCallbackType cb;
 };
@@ -1486,6 +1599,16 @@ enum AzTextInputValid {
 };
 typedef enum AzTextInputValid AzTextInputValid;
 
+struct AzTextInputValidatorCallback {
+    AzTextInputValidatorCallbackType cb;
+};
+typedef struct AzTextInputValidatorCallback AzTextInputValidatorCallback;
+
+struct AzTextInputOnValidationChangeCallback {
+    AzTextInputOnValidationChangeCallbackType cb;
+};
+typedef struct AzTextInputOnValidationChangeCallback AzTextInputOnValidationChangeCallback;
+
 struct AzNumberInputState {
     float previous;
     float number;
@@ -1505,6 +1628,162 @@ struct AzProgressBarState {
 };
 typedef struct AzProgressBarState AzProgressBarState;
 
+enum AzSplitPaneOrientation {
+   AzSplitPaneOrientation_Horizontal,
+   AzSplitPaneOrientation_Vertical,
+};
+typedef enum AzSplitPaneOrientation AzSplitPaneOrientation;
+
+enum AzSplitPaneCollapsed {
+   AzSplitPaneCollapsed_None,
+   AzSplitPaneCollapsed_First,
+   AzSplitPaneCollapsed_Second,
+};
+typedef enum AzSplitPaneCollapsed AzSplitPaneCollapsed;
+
+struct AzSplitPaneOnResizeCallback {
+    AzSplitPaneOnResizeCallbackType cb;
+};
+typedef struct AzSplitPaneOnResizeCallback AzSplitPaneOnResizeCallback;
+
+enum AzTabContainerOverflow {
+   AzTabContainerOverflow_Scroll,
+   AzTabContainerOverflow_Menu,
+};
+typedef enum AzTabContainerOverflow AzTabContainerOverflow;
+
+struct AzTabContainerOnTabChangeCallback {
+    AzTabContainerOnTabChangeCallbackType cb;
+};
+typedef struct AzTabContainerOnTabChangeCallback AzTabContainerOnTabChangeCallback;
+
+struct AzTabContainerOnTabCloseCallback {
+    AzTabContainerOnTabCloseCallbackType cb;
+};
+typedef struct AzTabContainerOnTabCloseCallback AzTabContainerOnTabCloseCallback;
+
+struct AzTabContainerOnTabMoveCallback {
+    AzTabContainerOnTabMoveCallbackType cb;
+};
+typedef struct AzTabContainerOnTabMoveCallback AzTabContainerOnTabMoveCallback;
+
+enum AzSliderOrientation {
+   AzSliderOrientation_Horizontal,
+   AzSliderOrientation_Vertical,
+};
+typedef enum AzSliderOrientation AzSliderOrientation;
+
+enum AzSliderThumb {
+   AzSliderThumb_Lower,
+   AzSliderThumb_Upper,
+};
+typedef enum AzSliderThumb AzSliderThumb;
+
+struct AzSliderOnValueChangeCallback {
+    AzSliderOnValueChangeCallbackType cb;
+};
+typedef struct AzSliderOnValueChangeCallback AzSliderOnValueChangeCallback;
+
+struct AzTextEditCursor {
+    size_t line;
+    size_t column;
+};
+typedef struct AzTextEditCursor AzTextEditCursor;
+
+enum AzTextEditEditKind {
+   AzTextEditEditKind_Insert,
+   AzTextEditEditKind_DeleteBackward,
+   AzTextEditEditKind_DeleteForward,
+   AzTextEditEditKind_Other,
+};
+typedef enum AzTextEditEditKind AzTextEditEditKind;
+
+struct AzTextEditOnTextChangeCallback {
+    AzTextEditOnTextChangeCallbackType cb;
+};
+typedef struct AzTextEditOnTextChangeCallback AzTextEditOnTextChangeCallback;
+
+enum AzTreeViewChildren {
+   AzTreeViewChildren_None,
+   AzTreeViewChildren_NotLoaded,
+   AzTreeViewChildren_Loading,
+   AzTreeViewChildren_Loaded,
+};
+typedef enum AzTreeViewChildren AzTreeViewChildren;
+
+enum AzTreeViewSelectionMode {
+   AzTreeViewSelectionMode_None,
+   AzTreeViewSelectionMode_Single,
+   AzTreeViewSelectionMode_Multiple,
+};
+typedef enum AzTreeViewSelectionMode AzTreeViewSelectionMode;
+
+enum AzTreeViewDropPosition {
+   AzTreeViewDropPosition_Before,
+   AzTreeViewDropPosition_After,
+   AzTreeViewDropPosition_Into,
+};
+typedef enum AzTreeViewDropPosition AzTreeViewDropPosition;
+
+struct AzTreeViewRow {
+    size_t node;
+    size_t depth;
+};
+typedef struct AzTreeViewRow AzTreeViewRow;
+
+struct AzTreeViewDragPayload {
+    size_t node;
+};
+typedef struct AzTreeViewDragPayload AzTreeViewDragPayload;
+
+struct AzTreeViewOnLoadChildrenCallback {
+    AzTreeViewOnLoadChildrenCallbackType cb;
+};
+typedef struct AzTreeViewOnLoadChildrenCallback AzTreeViewOnLoadChildrenCallback;
+
+struct AzTreeViewOnSelectionChangeCallback {
+    AzTreeViewOnSelectionChangeCallbackType cb;
+};
+typedef struct AzTreeViewOnSelectionChangeCallback AzTreeViewOnSelectionChangeCallback;
+
+struct AzTreeViewOnNodeMovedCallback {
+    AzTreeViewOnNodeMovedCallbackType cb;
+};
+typedef struct AzTreeViewOnNodeMovedCallback AzTreeViewOnNodeMovedCallback;
+
+enum AzVirtualListRowHeightTag {
+   AzVirtualListRowHeightTag_Fixed,
+   AzVirtualListRowHeightTag_Estimated,
+};
+typedef enum AzVirtualListRowHeightTag AzVirtualListRowHeightTag;
+
+struct AzVirtualListRowHeightVariant_Fixed { AzVirtualListRowHeightTag tag; float payload; };
+typedef struct AzVirtualListRowHeightVariant_Fixed AzVirtualListRowHeightVariant_Fixed;
+struct AzVirtualListRowHeightVariant_Estimated { AzVirtualListRowHeightTag tag; float payload; };
+typedef struct AzVirtualListRowHeightVariant_Estimated AzVirtualListRowHeightVariant_Estimated;
+union AzVirtualListRowHeight {
+    AzVirtualListRowHeightVariant_Fixed Fixed;
+    AzVirtualListRowHeightVariant_Estimated Estimated;
+};
+typedef union AzVirtualListRowHeight AzVirtualListRowHeight;
+
+struct AzVirtualListMeasuredRow {
+    size_t row;
+    float height;
+    double delta_before;
+};
+typedef struct AzVirtualListMeasuredRow AzVirtualListMeasuredRow;
+
+struct AzVirtualListRenderRowCallback {
+    AzVirtualListRenderRowCallbackType cb;
+};
+typedef struct AzVirtualListRenderRowCallback AzVirtualListRenderRowCallback;
+
+struct AzVirtualListOnScrollCallback {
+    AzVirtualListOnScrollCallbackType cb;
+};
+typedef struct AzVirtualListOnScrollCallback AzVirtualListOnScrollCallback;
+
 struct AzNode {
     size_t parent;
     size_t previous_sibling;
@@ -1524,6 +1803,9 @@ struct AzStyledNodeState {
     bool  hover;
     bool  active;
     bool  focused;
+    bool  focus_visible;
+    bool  focus_within;
+    bool  drag_over;
 };
 typedef struct AzStyledNodeState AzStyledNodeState;
 
@@ -2097,6 +2379,26 @@ union AzXmlNodeVecDestructor {
 };
 typedef union AzXmlNodeVecDestructor AzXmlNodeVecDestructor;
 
+enum AzXmlSpanVecDestructorTag {
+   AzXmlSpanVecDestructorTag_DefaultRust,
+   AzXmlSpanVecDestructorTag_NoDestructor,
+   AzXmlSpanVecDestructorTag_External,
+};
+typedef enum AzXmlSpanVecDestructorTag AzXmlSpanVecDestructorTag;
+
+struct AzXmlSpanVecDestructorVariant_DefaultRust { AzXmlSpanVecDestructorTag tag; };
+typedef struct AzXmlSpanVecDestructorVariant_DefaultRust AzXmlSpanVecDestructorVariant_DefaultRust;
+struct AzXmlSpanVecDestructorVariant_NoDestructor { AzXmlSpanVecDestructorTag tag; };
+typedef struct AzXmlSpanVecDestructorVariant_NoDestructor AzXmlSpanVecDestructorVariant_NoDestructor;
+struct AzXmlSpanVecDestructorVariant_External { AzXmlSpanVecDestructorTag tag; AzXmlSpanVecDestructorType payload; };
+typedef struct AzXmlSpanVecDestructorVariant_External AzXmlSpanVecDestructorVariant_External;
+union AzXmlSpanVecDestructor {
+    AzXmlSpanVecDestructorVariant_DefaultRust DefaultRust;
+    AzXmlSpanVecDestructorVariant_NoDestructor NoDestructor;
+    AzXmlSpanVecDestructorVariant_External External;
+};
+typedef union AzXmlSpanVecDestructor AzXmlSpanVecDestructor;
+
 enum AzFmtArgVecDestructorTag {
    AzFmtArgVecDestructorTag_DefaultRust,
    AzFmtArgVecDestructorTag_NoDestructor,
@@ -2617,6 +2919,186 @@ union AzScanCodeVecDestructor {
 };
 typedef union AzScanCodeVecDestructor AzScanCodeVecDestructor;
 
+enum AzTextInputValidatorVecDestructorTag {
+   AzTextInputValidatorVecDestructorTag_DefaultRust,
+   AzTextInputValidatorVecDestructorTag_NoDestructor,
+   AzTextInputValidatorVecDestructorTag_External,
+};
+typedef enum AzTextInputValidatorVecDestructorTag AzTextInputValidatorVecDestructorTag;
+
+struct AzTextInputValidatorVecDestructorVariant_DefaultRust { AzTextInputValidatorVecDestructorTag tag; };
+typedef struct AzTextInputValidatorVecDestructorVariant_DefaultRust AzTextInputValidatorVecDestructorVariant_DefaultRust;
+struct AzTextInputValidatorVecDestructorVariant_NoDestructor { AzTextInputValidatorVecDestructorTag tag; };
+typedef struct AzTextInputValidatorVecDestructorVariant_NoDestructor AzTextInputValidatorVecDestructorVariant_NoDestructor;
+struct AzTextInputValidatorVecDestructorVariant_External { AzTextInputValidatorVecDestructorTag tag; AzTextInputValidatorVecDestructorType payload; };
+typedef struct AzTextInputValidatorVecDestructorVariant_External AzTextInputValidatorVecDestructorVariant_External;
+union AzTextInputValidatorVecDestructor {
+    AzTextInputValidatorVecDestructorVariant_DefaultRust DefaultRust;
+    AzTextInputValidatorVecDestructorVariant_NoDestructor NoDestructor;
+    AzTextInputValidatorVecDestructorVariant_External External;
+};
+typedef union AzTextInputValidatorVecDestructor AzTextInputValidatorVecDestructor;
+
+enum AzDropDownChoiceVecDestructorTag {
+   AzDropDownChoiceVecDestructorTag_DefaultRust,
+   AzDropDownChoiceVecDestructorTag_NoDestructor,
+   AzDropDownChoiceVecDestructorTag_External,
+};
+typedef enum AzDropDownChoiceVecDestructorTag AzDropDownChoiceVecDestructorTag;
+
+struct AzDropDownChoiceVecDestructorVariant_DefaultRust { AzDropDownChoiceVecDestructorTag tag; };
+typedef struct AzDropDownChoiceVecDestructorVariant_DefaultRust AzDropDownChoiceVecDestructorVariant_DefaultRust;
+struct AzDropDownChoiceVecDestructorVariant_NoDestructor { AzDropDownChoiceVecDestructorTag tag; };
+typedef struct AzDropDownChoiceVecDestructorVariant_NoDestructor AzDropDownChoiceVecDestructorVariant_NoDestructor;
+struct AzDropDownChoiceVecDestructorVariant_External { AzDropDownChoiceVecDestructorTag tag; AzDropDownChoiceVecDestructorType payload; };
+typedef struct AzDropDownChoiceVecDestructorVariant_External AzDropDownChoiceVecDestructorVariant_External;
+union AzDropDownChoiceVecDestructor {
+    AzDropDownChoiceVecDestructorVariant_DefaultRust DefaultRust;
+    AzDropDownChoiceVecDestructorVariant_NoDestructor NoDestructor;
+    AzDropDownChoiceVecDestructorVariant_External External;
+};
+typedef union AzDropDownChoiceVecDestructor AzDropDownChoiceVecDestructor;
+
+enum AzTabContainerTabVecDestructorTag {
+   AzTabContainerTabVecDestructorTag_DefaultRust,
+   AzTabContainerTabVecDestructorTag_NoDestructor,
+   AzTabContainerTabVecDestructorTag_External,
+};
+typedef enum AzTabContainerTabVecDestructorTag AzTabContainerTabVecDestructorTag;
+
+struct AzTabContainerTabVecDestructorVariant_DefaultRust { AzTabContainerTabVecDestructorTag tag; };
+typedef struct AzTabContainerTabVecDestructorVariant_DefaultRust AzTabContainerTabVecDestructorVariant_DefaultRust;
+struct AzTabContainerTabVecDestructorVariant_NoDestructor { AzTabContainerTabVecDestructorTag tag; };
+typedef struct AzTabContainerTabVecDestructorVariant_NoDestructor AzTabContainerTabVecDestructorVariant_NoDestructor;
+struct AzTabContainerTabVecDestructorVariant_External { AzTabContainerTabVecDestructorTag tag; AzTabContainerTabVecDestructorType payload; };
+typedef struct AzTabContainerTabVecDestructorVariant_External AzTabContainerTabVecDestructorVariant_External;
+union AzTabContainerTabVecDestructor {
+    AzTabContainerTabVecDestructorVariant_DefaultRust DefaultRust;
+    AzTabContainerTabVecDestructorVariant_NoDestructor NoDestructor;
+    AzTabContainerTabVecDestructorVariant_External External;
+};
+typedef union AzTabContainerTabVecDestructor AzTabContainerTabVecDestructor;
+
+enum AzTextEditUndoEntryVecDestructorTag {
+   AzTextEditUndoEntryVecDestructorTag_DefaultRust,
+   AzTextEditUndoEntryVecDestructorTag_NoDestructor,
+   AzTextEditUndoEntryVecDestructorTag_External,
+};
+typedef enum AzTextEditUndoEntryVecDestructorTag AzTextEditUndoEntryVecDestructorTag;
+
+struct AzTextEditUndoEntryVecDestructorVariant_DefaultRust { AzTextEditUndoEntryVecDestructorTag tag; };
+typedef struct AzTextEditUndoEntryVecDestructorVariant_DefaultRust AzTextEditUndoEntryVecDestructorVariant_DefaultRust;
+struct AzTextEditUndoEntryVecDestructorVariant_NoDestructor { AzTextEditUndoEntryVecDestructorTag tag; };
+typedef struct AzTextEditUndoEntryVecDestructorVariant_NoDestructor AzTextEditUndoEntryVecDestructorVariant_NoDestructor;
+struct AzTextEditUndoEntryVecDestructorVariant_External { AzTextEditUndoEntryVecDestructorTag tag; AzTextEditUndoEntryVecDestructorType payload; };
+typedef struct AzTextEditUndoEntryVecDestructorVariant_External AzTextEditUndoEntryVecDestructorVariant_External;
+union AzTextEditUndoEntryVecDestructor {
+    AzTextEditUndoEntryVecDestructorVariant_DefaultRust DefaultRust;
+    AzTextEditUndoEntryVecDestructorVariant_NoDestructor NoDestructor;
+    AzTextEditUndoEntryVecDestructorVariant_External External;
+};
+typedef union AzTextEditUndoEntryVecDestructor AzTextEditUndoEntryVecDestructor;
+
+enum AzTreeViewNodeDataVecDestructorTag {
+   AzTreeViewNodeDataVecDestructorTag_DefaultRust,
+   AzTreeViewNodeDataVecDestructorTag_NoDestructor,
+   AzTreeViewNodeDataVecDestructorTag_External,
+};
+typedef enum AzTreeViewNodeDataVecDestructorTag AzTreeViewNodeDataVecDestructorTag;
+
+struct AzTreeViewNodeDataVecDestructorVariant_DefaultRust { AzTreeViewNodeDataVecDestructorTag tag; };
+typedef struct AzTreeViewNodeDataVecDestructorVariant_DefaultRust AzTreeViewNodeDataVecDestructorVariant_DefaultRust;
+struct AzTreeViewNodeDataVecDestructorVariant_NoDestructor { AzTreeViewNodeDataVecDestructorTag tag; };
+typedef struct AzTreeViewNodeDataVecDestructorVariant_NoDestructor AzTreeViewNodeDataVecDestructorVariant_NoDestructor;
+struct AzTreeViewNodeDataVecDestructorVariant_External { AzTreeViewNodeDataVecDestructorTag tag; AzTreeViewNodeDataVecDestructorType payload; };
+typedef struct AzTreeViewNodeDataVecDestructorVariant_External AzTreeViewNodeDataVecDestructorVariant_External;
+union AzTreeViewNodeDataVecDestructor {
+    AzTreeViewNodeDataVecDestructorVariant_DefaultRust DefaultRust;
+    AzTreeViewNodeDataVecDestructorVariant_NoDestructor NoDestructor;
+    AzTreeViewNodeDataVecDestructorVariant_External External;
+};
+typedef union AzTreeViewNodeDataVecDestructor AzTreeViewNodeDataVecDestructor;
+
+enum AzTreeViewNodeVecDestructorTag {
+   AzTreeViewNodeVecDestructorTag_DefaultRust,
+   AzTreeViewNodeVecDestructorTag_NoDestructor,
+   AzTreeViewNodeVecDestructorTag_External,
+};
+typedef enum AzTreeViewNodeVecDestructorTag AzTreeViewNodeVecDestructorTag;
+
+struct AzTreeViewNodeVecDestructorVariant_DefaultRust { AzTreeViewNodeVecDestructorTag tag; };
+typedef struct AzTreeViewNodeVecDestructorVariant_DefaultRust AzTreeViewNodeVecDestructorVariant_DefaultRust;
+struct AzTreeViewNodeVecDestructorVariant_NoDestructor { AzTreeViewNodeVecDestructorTag tag; };
+typedef struct AzTreeViewNodeVecDestructorVariant_NoDestructor AzTreeViewNodeVecDestructorVariant_NoDestructor;
+struct AzTreeViewNodeVecDestructorVariant_External { AzTreeViewNodeVecDestructorTag tag; AzTreeViewNodeVecDestructorType payload; };
+typedef struct AzTreeViewNodeVecDestructorVariant_External AzTreeViewNodeVecDestructorVariant_External;
+union AzTreeViewNodeVecDestructor {
+    AzTreeViewNodeVecDestructorVariant_DefaultRust DefaultRust;
+    AzTreeViewNodeVecDestructorVariant_NoDestructor NoDestructor;
+    AzTreeViewNodeVecDestructorVariant_External External;
+};
+typedef union AzTreeViewNodeVecDestructor AzTreeViewNodeVecDestructor;
+
+enum AzTreeViewRowVecDestructorTag {
+   AzTreeViewRowVecDestructorTag_DefaultRust,
+   AzTreeViewRowVecDestructorTag_NoDestructor,
+   AzTreeViewRowVecDestructorTag_External,
+};
+typedef enum AzTreeViewRowVecDestructorTag AzTreeViewRowVecDestructorTag;
+
+struct AzTreeViewRowVecDestructorVariant_DefaultRust { AzTreeViewRowVecDestructorTag tag; };
+typedef struct AzTreeViewRowVecDestructorVariant_DefaultRust AzTreeViewRowVecDestructorVariant_DefaultRust;
+struct AzTreeViewRowVecDestructorVariant_NoDestructor { AzTreeViewRowVecDestructorTag tag; };
+typedef struct AzTreeViewRowVecDestructorVariant_NoDestructor AzTreeViewRowVecDestructorVariant_NoDestructor;
+struct AzTreeViewRowVecDestructorVariant_External { AzTreeViewRowVecDestructorTag tag; AzTreeViewRowVecDestructorType payload; };
+typedef struct AzTreeViewRowVecDestructorVariant_External AzTreeViewRowVecDestructorVariant_External;
+union AzTreeViewRowVecDestructor {
+    AzTreeViewRowVecDestructorVariant_DefaultRust DefaultRust;
+    AzTreeViewRowVecDestructorVariant_NoDestructor NoDestructor;
+    AzTreeViewRowVecDestructorVariant_External External;
+};
+typedef union AzTreeViewRowVecDestructor AzTreeViewRowVecDestructor;
+
+enum AzTreeViewRenderedRowVecDestructorTag {
+   AzTreeViewRenderedRowVecDestructorTag_DefaultRust,
+   AzTreeViewRenderedRowVecDestructorTag_NoDestructor,
+   AzTreeViewRenderedRowVecDestructorTag_External,
+};
+typedef enum AzTreeViewRenderedRowVecDestructorTag AzTreeViewRenderedRowVecDestructorTag;
+
+struct AzTreeViewRenderedRowVecDestructorVariant_DefaultRust { AzTreeViewRenderedRowVecDestructorTag tag; };
+typedef struct AzTreeViewRenderedRowVecDestructorVariant_DefaultRust AzTreeViewRenderedRowVecDestructorVariant_DefaultRust;
+struct AzTreeViewRenderedRowVecDestructorVariant_NoDestructor { AzTreeViewRenderedRowVecDestructorTag tag; };
+typedef struct AzTreeViewRenderedRowVecDestructorVariant_NoDestructor AzTreeViewRenderedRowVecDestructorVariant_NoDestructor;
+struct AzTreeViewRenderedRowVecDestructorVariant_External { AzTreeViewRenderedRowVecDestructorTag tag; AzTreeViewRenderedRowVecDestructorType payload; };
+typedef struct AzTreeViewRenderedRowVecDestructorVariant_External AzTreeViewRenderedRowVecDestructorVariant_External;
+union AzTreeViewRenderedRowVecDestructor {
+    AzTreeViewRenderedRowVecDestructorVariant_DefaultRust DefaultRust;
+    AzTreeViewRenderedRowVecDestructorVariant_NoDestructor NoDestructor;
+    AzTreeViewRenderedRowVecDestructorVariant_External External;
+};
+typedef union AzTreeViewRenderedRowVecDestructor AzTreeViewRenderedRowVecDestructor;
+
+enum AzVirtualListMeasuredRowVecDestructorTag {
+   AzVirtualListMeasuredRowVecDestructorTag_DefaultRust,
+   AzVirtualListMeasuredRowVecDestructorTag_NoDestructor,
+   AzVirtualListMeasuredRowVecDestructorTag_External,
+};
+typedef enum AzVirtualListMeasuredRowVecDestructorTag AzVirtualListMeasuredRowVecDestructorTag;
+
+struct AzVirtualListMeasuredRowVecDestructorVariant_DefaultRust { AzVirtualListMeasuredRowVecDestructorTag tag; };
+typedef struct AzVirtualListMeasuredRowVecDestructorVariant_DefaultRust AzVirtualListMeasuredRowVecDestructorVariant_DefaultRust;
+struct AzVirtualListMeasuredRowVecDestructorVariant_NoDestructor { AzVirtualListMeasuredRowVecDestructorTag tag; };
+typedef struct AzVirtualListMeasuredRowVecDestructorVariant_NoDestructor AzVirtualListMeasuredRowVecDestructorVariant_NoDestructor;
+struct AzVirtualListMeasuredRowVecDestructorVariant_External { AzVirtualListMeasuredRowVecDestructorTag tag; AzVirtualListMeasuredRowVecDestructorType payload; };
+typedef struct AzVirtualListMeasuredRowVecDestructorVariant_External AzVirtualListMeasuredRowVecDestructorVariant_External;
+union AzVirtualListMeasuredRowVecDestructor {
+    AzVirtualListMeasuredRowVecDestructorVariant_DefaultRust DefaultRust;
+    AzVirtualListMeasuredRowVecDestructorVariant_NoDestructor NoDestructor;
+    AzVirtualListMeasuredRowVecDestructorVariant_External External;
+};
+typedef union AzVirtualListMeasuredRowVecDestructor AzVirtualListMeasuredRowVecDestructor;
+
 enum AzCssDeclarationVecDestructorTag {
    AzCssDeclarationVecDestructorTag_DefaultRust,
    AzCssDeclarationVecDestructorTag_NoDestructor,
@@ -3466,6 +3948,12 @@ union AzNotEventFilter {
 };
 typedef union AzNotEventFilter AzNotEventFilter;
 
+struct AzRovingFocus {
+    AzRovingFocusOrientation orientation;
+    bool  wrap;
+};
+typedef struct AzRovingFocus AzRovingFocus;
+
 struct AzMenuCallback {
     AzCallback callback;
     AzRefAny data;
@@ -5612,6 +6100,12 @@ struct AzCheckBoxOnToggle {
 };
 typedef struct AzCheckBoxOnToggle AzCheckBoxOnToggle;
 
+struct AzDropDownOnChoiceChange {
+    AzRefAny data;
+    AzDropDownOnChoiceChangeCallback callback;
+};
+typedef struct AzDropDownOnChoiceChange AzDropDownOnChoiceChange;
+
 struct AzColorInputState {
     AzColorU color;
 };
@@ -5663,12 +6157,124 @@ struct AzOnTextInputReturn {
 };
 typedef struct AzOnTextInputReturn AzOnTextInputReturn;
 
+struct AzTextInputCustomValidator {
+    AzRefAny data;
+    AzTextInputValidatorCallback callback;
+};
+typedef struct AzTextInputCustomValidator AzTextInputCustomValidator;
+
+struct AzTextInputOnValidationChange {
+    AzRefAny data;
+    AzTextInputOnValidationChangeCallback callback;
+};
+typedef struct AzTextInputOnValidationChange AzTextInputOnValidationChange;
+
 struct AzNumberInputOnValueChange {
     AzRefAny data;
     AzNumberInputOnValueChangeCallback callback;
 };
 typedef struct AzNumberInputOnValueChange AzNumberInputOnValueChange;
 
+struct AzSplitPaneState {
+    AzSplitPaneOrientation orientation;
+    float ratio;
+    float min_first_size;
+    float min_second_size;
+    bool  collapsible;
+    AzSplitPaneCollapsed collapsed;
+};
+typedef struct AzSplitPaneState AzSplitPaneState;
+
+struct AzSplitPaneOnResize {
+    AzRefAny data;
+    AzSplitPaneOnResizeCallback callback;
+};
+typedef struct AzSplitPaneOnResize AzSplitPaneOnResize;
+
+struct AzTabContainerOnTabChange {
+    AzRefAny data;
+    AzTabContainerOnTabChangeCallback callback;
+};
+typedef struct AzTabContainerOnTabChange AzTabContainerOnTabChange;
+
+struct AzTabContainerOnTabClose {
+    AzRefAny data;
+    AzTabContainerOnTabCloseCallback callback;
+};
+typedef struct AzTabContainerOnTabClose AzTabContainerOnTabClose;
+
+struct AzTabContainerOnTabMove {
+    AzRefAny data;
+    AzTabContainerOnTabMoveCallback callback;
+};
+typedef struct AzTabContainerOnTabMove AzTabContainerOnTabMove;
+
+struct AzSliderState {
+    float value;
+    AzOptionF32 upper_value;
+    float min;
+    float max;
+    float step;
+    AzSliderOrientation orientation;
+    AzOptionF32 tick_interval;
+    AzSliderThumb active_thumb;
+};
+typedef struct AzSliderState AzSliderState;
+
+struct AzSliderOnValueChange {
+    AzRefAny data;
+    AzSliderOnValueChangeCallback callback;
+};
+typedef struct AzSliderOnValueChange AzSliderOnValueChange;
+
+struct AzTextEditOnTextChange {
+    AzRefAny data;
+    AzTextEditOnTextChangeCallback callback;
+};
+typedef struct AzTextEditOnTextChange AzTextEditOnTextChange;
+
+struct AzTreeViewDropTarget {
+    size_t node;
+    AzTreeViewDropPosition position;
+};
+typedef struct AzTreeViewDropTarget AzTreeViewDropTarget;
+
+struct AzTreeViewLoadRequest {
+    size_t node;
+    AzRefAny tree;
+};
+typedef struct AzTreeViewLoadRequest AzTreeViewLoadRequest;
+
+struct AzTreeViewOnLoadChildren {
+    AzRefAny data;
+    AzTreeViewOnLoadChildrenCallback callback;
+};
+typedef struct AzTreeViewOnLoadChildren AzTreeViewOnLoadChildren;
+
+struct AzTreeViewOnSelectionChange {
+    AzRefAny data;
+    AzTreeViewOnSelectionChangeCallback callback;
+};
+typedef struct AzTreeViewOnSelectionChange AzTreeViewOnSelectionChange;
+
+struct AzTreeViewOnNodeMoved {
+    AzRefAny data;
+    AzTreeViewOnNodeMovedCallback callback;
+};
+typedef struct AzTreeViewOnNodeMoved AzTreeViewOnNodeMoved;
+
+struct AzVirtualListRenderRow {
+    AzRefAny data;
+    AzVirtualListRenderRowCallback callback;
+};
+typedef struct AzVirtualListRenderRow AzVirtualListRenderRow;
+
+struct AzVirtualListOnScroll {
+    AzRefAny data;
+    AzVirtualListOnScrollCallback callback;
+};
+typedef struct AzVirtualListOnScroll AzVirtualListOnScroll;
+
 struct AzParentWithNodeDepth {
     size_t depth;
     AzNodeId node_id;
@@ -5792,6 +6398,12 @@ struct AzSvgFillStyle {
 };
 typedef struct AzSvgFillStyle AzSvgFillStyle;
 
+struct AzXmlSpan {
+    AzSvgParseErrorPosition start;
+    AzSvgParseErrorPosition end;
+};
+typedef struct AzXmlSpan AzXmlSpan;
+
 struct AzInstantPtr {
     void* ptr;
     AzInstantPtrCloneFn clone_fn;
@@ -5869,6 +6481,14 @@ struct AzXmlNodeVec {
 };
 typedef struct AzXmlNodeVec AzXmlNodeVec;
 
+struct AzXmlSpanVec {
+    AzXmlSpan* ptr;
+    size_t len;
+    size_t cap;
+    AzXmlSpanVecDestructor destructor;
+};
+typedef struct AzXmlSpanVec AzXmlSpanVec;
+
 struct AzInlineGlyphVec {
     AzInlineGlyph* ptr;
     size_t len;
@@ -5975,6 +6595,22 @@ struct AzScanCodeVec {
 };
 typedef struct AzScanCodeVec AzScanCodeVec;
 
+struct AzTreeViewRowVec {
+    AzTreeViewRow* ptr;
+    size_t len;
+    size_t cap;
+    AzTreeViewRowVecDestructor destructor;
+};
+typedef struct AzTreeViewRowVec AzTreeViewRowVec;
+
+struct AzVirtualListMeasuredRowVec {
+    AzVirtualListMeasuredRow* ptr;
+    size_t len;
+    size_t cap;
+    AzVirtualListMeasuredRowVecDestructor destructor;
+};
+typedef struct AzVirtualListMeasuredRowVec AzVirtualListMeasuredRowVec;
+
 struct AzU16Vec {
     uint16_t* ptr;
     size_t len;
@@ -6167,6 +6803,230 @@ union AzOptionTextInputSelection {
 };
 typedef union AzOptionTextInputSelection AzOptionTextInputSelection;
 
+enum AzOptionTextInputOnValidationChangeTag {
+   AzOptionTextInputOnValidationChangeTag_None,
+   AzOptionTextInputOnValidationChangeTag_Some,
+};
+typedef enum AzOptionTextInputOnValidationChangeTag AzOptionTextInputOnValidationChangeTag;
+
+struct AzOptionTextInputOnValidationChangeVariant_None { AzOptionTextInputOnValidationChangeTag tag; };
+typedef struct AzOptionTextInputOnValidationChangeVariant_None AzOptionTextInputOnValidationChangeVariant_None;
+struct AzOptionTextInputOnValidationChangeVariant_Some { AzOptionTextInputOnValidationChangeTag tag; AzTextInputOnValidationChange payload; };
+typedef struct AzOptionTextInputOnValidationChangeVariant_Some AzOptionTextInputOnValidationChangeVariant_Some;
+union AzOptionTextInputOnValidationChange {
+    AzOptionTextInputOnValidationChangeVariant_None None;
+    AzOptionTextInputOnValidationChangeVariant_Some Some;
+};
+typedef union AzOptionTextInputOnValidationChange AzOptionTextInputOnValidationChange;
+
+enum AzOptionDropDownOnChoiceChangeTag {
+   AzOptionDropDownOnChoiceChangeTag_None,
+   AzOptionDropDownOnChoiceChangeTag_Some,
+};
+typedef enum AzOptionDropDownOnChoiceChangeTag AzOptionDropDownOnChoiceChangeTag;
+
+struct AzOptionDropDownOnChoiceChangeVariant_None { AzOptionDropDownOnChoiceChangeTag tag; };
+typedef struct AzOptionDropDownOnChoiceChangeVariant_None AzOptionDropDownOnChoiceChangeVariant_None;
+struct AzOptionDropDownOnChoiceChangeVariant_Some { AzOptionDropDownOnChoiceChangeTag tag; AzDropDownOnChoiceChange payload; };
+typedef struct AzOptionDropDownOnChoiceChangeVariant_Some AzOptionDropDownOnChoiceChangeVariant_Some;
+union AzOptionDropDownOnChoiceChange {
+    AzOptionDropDownOnChoiceChangeVariant_None None;
+    AzOptionDropDownOnChoiceChangeVariant_Some Some;
+};
+typedef union AzOptionDropDownOnChoiceChange AzOptionDropDownOnChoiceChange;
+
+enum AzOptionTabContainerOnTabChangeTag {
+   AzOptionTabContainerOnTabChangeTag_None,
+   AzOptionTabContainerOnTabChangeTag_Some,
+};
+typedef enum AzOptionTabContainerOnTabChangeTag AzOptionTabContainerOnTabChangeTag;
+
+struct AzOptionTabContainerOnTabChangeVariant_None { AzOptionTabContainerOnTabChangeTag tag; };
+typedef struct AzOptionTabContainerOnTabChangeVariant_None AzOptionTabContainerOnTabChangeVariant_None;
+struct AzOptionTabContainerOnTabChangeVariant_Some { AzOptionTabContainerOnTabChangeTag tag; AzTabContainerOnTabChange payload; };
+typedef struct AzOptionTabContainerOnTabChangeVariant_Some AzOptionTabContainerOnTabChangeVariant_Some;
+union AzOptionTabContainerOnTabChange {
+    AzOptionTabContainerOnTabChangeVariant_None None;
+    AzOptionTabContainerOnTabChangeVariant_Some Some;
+};
+typedef union AzOptionTabContainerOnTabChange AzOptionTabContainerOnTabChange;
+
+enum AzOptionTabContainerOnTabCloseTag {
+   AzOptionTabContainerOnTabCloseTag_None,
+   AzOptionTabContainerOnTabCloseTag_Some,
+};
+typedef enum AzOptionTabContainerOnTabCloseTag AzOptionTabContainerOnTabCloseTag;
+
+struct AzOptionTabContainerOnTabCloseVariant_None { AzOptionTabContainerOnTabCloseTag tag; };
+typedef struct AzOptionTabContainerOnTabCloseVariant_None AzOptionTabContainerOnTabCloseVariant_None;
+struct AzOptionTabContainerOnTabCloseVariant_Some { AzOptionTabContainerOnTabCloseTag tag; AzTabContainerOnTabClose payload; };
+typedef struct AzOptionTabContainerOnTabCloseVariant_Some AzOptionTabContainerOnTabCloseVariant_Some;
+union AzOptionTabContainerOnTabClose {
+    AzOptionTabContainerOnTabCloseVariant_None None;
+    AzOptionTabContainerOnTabCloseVariant_Some Some;
+};
+typedef union AzOptionTabContainerOnTabClose AzOptionTabContainerOnTabClose;
+
+enum AzOptionTabContainerOnTabMoveTag {
+   AzOptionTabContainerOnTabMoveTag_None,
+   AzOptionTabContainerOnTabMoveTag_Some,
+};
+typedef enum AzOptionTabContainerOnTabMoveTag AzOptionTabContainerOnTabMoveTag;
+
+struct AzOptionTabContainerOnTabMoveVariant_None { AzOptionTabContainerOnTabMoveTag tag; };
+typedef struct AzOptionTabContainerOnTabMoveVariant_None AzOptionTabContainerOnTabMoveVariant_None;
+struct AzOptionTabContainerOnTabMoveVariant_Some { AzOptionTabContainerOnTabMoveTag tag; AzTabContainerOnTabMove payload; };
+typedef struct AzOptionTabContainerOnTabMoveVariant_Some AzOptionTabContainerOnTabMoveVariant_Some;
+union AzOptionTabContainerOnTabMove {
+    AzOptionTabContainerOnTabMoveVariant_None None;
+    AzOptionTabContainerOnTabMoveVariant_Some Some;
+};
+typedef union AzOptionTabContainerOnTabMove AzOptionTabContainerOnTabMove;
+
+enum AzOptionSplitPaneOnResizeTag {
+   AzOptionSplitPaneOnResizeTag_None,
+   AzOptionSplitPaneOnResizeTag_Some,
+};
+typedef enum AzOptionSplitPaneOnResizeTag AzOptionSplitPaneOnResizeTag;
+
+struct AzOptionSplitPaneOnResizeVariant_None { AzOptionSplitPaneOnResizeTag tag; };
+typedef struct AzOptionSplitPaneOnResizeVariant_None AzOptionSplitPaneOnResizeVariant_None;
+struct AzOptionSplitPaneOnResizeVariant_Some { AzOptionSplitPaneOnResizeTag tag; AzSplitPaneOnResize payload; };
+typedef struct AzOptionSplitPaneOnResizeVariant_Some AzOptionSplitPaneOnResizeVariant_Some;
+union AzOptionSplitPaneOnResize {
+    AzOptionSplitPaneOnResizeVariant_None None;
+    AzOptionSplitPaneOnResizeVariant_Some Some;
+};
+typedef union AzOptionSplitPaneOnResize AzOptionSplitPaneOnResize;
+
+enum AzOptionSliderOnValueChangeTag {
+   AzOptionSliderOnValueChangeTag_None,
+   AzOptionSliderOnValueChangeTag_Some,
+};
+typedef enum AzOptionSliderOnValueChangeTag AzOptionSliderOnValueChangeTag;
+
+struct AzOptionSliderOnValueChangeVariant_None { AzOptionSliderOnValueChangeTag tag; };
+typedef struct AzOptionSliderOnValueChangeVariant_None AzOptionSliderOnValueChangeVariant_None;
+struct AzOptionSliderOnValueChangeVariant_Some { AzOptionSliderOnValueChangeTag tag; AzSliderOnValueChange payload; };
+typedef struct AzOptionSliderOnValueChangeVariant_Some AzOptionSliderOnValueChangeVariant_Some;
+union AzOptionSliderOnValueChange {
+    AzOptionSliderOnValueChangeVariant_None None;
+    AzOptionSliderOnValueChangeVariant_Some Some;
+};
+typedef union AzOptionSliderOnValueChange AzOptionSliderOnValueChange;
+
+enum AzOptionTextEditOnTextChangeTag {
+   AzOptionTextEditOnTextChangeTag_None,
+   AzOptionTextEditOnTextChangeTag_Some,
+};
+typedef enum AzOptionTextEditOnTextChangeTag AzOptionTextEditOnTextChangeTag;
+
+struct AzOptionTextEditOnTextChangeVariant_None { AzOptionTextEditOnTextChangeTag tag; };
+typedef struct AzOptionTextEditOnTextChangeVariant_None AzOptionTextEditOnTextChangeVariant_None;
+struct AzOptionTextEditOnTextChangeVariant_Some { AzOptionTextEditOnTextChangeTag tag; AzTextEditOnTextChange payload; };
+typedef struct AzOptionTextEditOnTextChangeVariant_Some AzOptionTextEditOnTextChangeVariant_Some;
+union AzOptionTextEditOnTextChange {
+    AzOptionTextEditOnTextChangeVariant_None None;
+    AzOptionTextEditOnTextChangeVariant_Some Some;
+};
+typedef union AzOptionTextEditOnTextChange AzOptionTextEditOnTextChange;
+
+enum AzOptionTextEditCursorTag {
+   AzOptionTextEditCursorTag_None,
+   AzOptionTextEditCursorTag_Some,
+};
+typedef enum AzOptionTextEditCursorTag AzOptionTextEditCursorTag;
+
+struct AzOptionTextEditCursorVariant_None { AzOptionTextEditCursorTag tag; };
+typedef struct AzOptionTextEditCursorVariant_None AzOptionTextEditCursorVariant_None;
+struct AzOptionTextEditCursorVariant_Some { AzOptionTextEditCursorTag tag; AzTextEditCursor payload; };
+typedef struct AzOptionTextEditCursorVariant_Some AzOptionTextEditCursorVariant_Some;
+union AzOptionTextEditCursor {
+    AzOptionTextEditCursorVariant_None None;
+    AzOptionTextEditCursorVariant_Some Some;
+};
+typedef union AzOptionTextEditCursor AzOptionTextEditCursor;
+
+enum AzOptionTreeViewOnLoadChildrenTag {
+   AzOptionTreeViewOnLoadChildrenTag_None,
+   AzOptionTreeViewOnLoadChildrenTag_Some,
+};
+typedef enum AzOptionTreeViewOnLoadChildrenTag AzOptionTreeViewOnLoadChildrenTag;
+
+struct AzOptionTreeViewOnLoadChildrenVariant_None { AzOptionTreeViewOnLoadChildrenTag tag; };
+typedef struct AzOptionTreeViewOnLoadChildrenVariant_None AzOptionTreeViewOnLoadChildrenVariant_None;
+struct AzOptionTreeViewOnLoadChildrenVariant_Some { AzOptionTreeViewOnLoadChildrenTag tag; AzTreeViewOnLoadChildren payload; };
+typedef struct AzOptionTreeViewOnLoadChildrenVariant_Some AzOptionTreeViewOnLoadChildrenVariant_Some;
+union AzOptionTreeViewOnLoadChildren {
+    AzOptionTreeViewOnLoadChildrenVariant_None None;
+    AzOptionTreeViewOnLoadChildrenVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnLoadChildren AzOptionTreeViewOnLoadChildren;
+
+enum AzOptionTreeViewOnSelectionChangeTag {
+   AzOptionTreeViewOnSelectionChangeTag_None,
+   AzOptionTreeViewOnSelectionChangeTag_Some,
+};
+typedef enum AzOptionTreeViewOnSelectionChangeTag AzOptionTreeViewOnSelectionChangeTag;
+
+struct AzOptionTreeViewOnSelectionChangeVariant_None { AzOptionTreeViewOnSelectionChangeTag tag; };
+typedef struct AzOptionTreeViewOnSelectionChangeVariant_None AzOptionTreeViewOnSelectionChangeVariant_None;
+struct AzOptionTreeViewOnSelectionChangeVariant_Some { AzOptionTreeViewOnSelectionChangeTag tag; AzTreeViewOnSelectionChange payload; };
+typedef struct AzOptionTreeViewOnSelectionChangeVariant_Some AzOptionTreeViewOnSelectionChangeVariant_Some;
+union AzOptionTreeViewOnSelectionChange {
+    AzOptionTreeViewOnSelectionChangeVariant_None None;
+    AzOptionTreeViewOnSelectionChangeVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnSelectionChange AzOptionTreeViewOnSelectionChange;
+
+enum AzOptionTreeViewOnNodeMovedTag {
+   AzOptionTreeViewOnNodeMovedTag_None,
+   AzOptionTreeViewOnNodeMovedTag_Some,
+};
+typedef enum AzOptionTreeViewOnNodeMovedTag AzOptionTreeViewOnNodeMovedTag;
+
+struct AzOptionTreeViewOnNodeMovedVariant_None { AzOptionTreeViewOnNodeMovedTag tag; };
+typedef struct AzOptionTreeViewOnNodeMovedVariant_None AzOptionTreeViewOnNodeMovedVariant_None;
+struct AzOptionTreeViewOnNodeMovedVariant_Some { AzOptionTreeViewOnNodeMovedTag tag; AzTreeViewOnNodeMoved payload; };
+typedef struct AzOptionTreeViewOnNodeMovedVariant_Some AzOptionTreeViewOnNodeMovedVariant_Some;
+union AzOptionTreeViewOnNodeMoved {
+    AzOptionTreeViewOnNodeMovedVariant_None None;
+    AzOptionTreeViewOnNodeMovedVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnNodeMoved AzOptionTreeViewOnNodeMoved;
+
+enum AzOptionTreeViewDropTargetTag {
+   AzOptionTreeViewDropTargetTag_None,
+   AzOptionTreeViewDropTargetTag_Some,
+};
+typedef enum AzOptionTreeViewDropTargetTag AzOptionTreeViewDropTargetTag;
+
+struct AzOptionTreeViewDropTargetVariant_None { AzOptionTreeViewDropTargetTag tag; };
+typedef struct AzOptionTreeViewDropTargetVariant_None AzOptionTreeViewDropTargetVariant_None;
+struct AzOptionTreeViewDropTargetVariant_Some { AzOptionTreeViewDropTargetTag tag; AzTreeViewDropTarget payload; };
+typedef struct AzOptionTreeViewDropTargetVariant_Some AzOptionTreeViewDropTargetVariant_Some;
+union AzOptionTreeViewDropTarget {
+    AzOptionTreeViewDropTargetVariant_None None;
+    AzOptionTreeViewDropTargetVariant_Some Some;
+};
+typedef union AzOptionTreeViewDropTarget AzOptionTreeViewDropTarget;
+
+enum AzOptionVirtualListOnScrollTag {
+   AzOptionVirtualListOnScrollTag_None,
+   AzOptionVirtualListOnScrollTag_Some,
+};
+typedef enum AzOptionVirtualListOnScrollTag AzOptionVirtualListOnScrollTag;
+
+struct AzOptionVirtualListOnScrollVariant_None { AzOptionVirtualListOnScrollTag tag; };
+typedef struct AzOptionVirtualListOnScrollVariant_None AzOptionVirtualListOnScrollVariant_None;
+struct AzOptionVirtualListOnScrollVariant_Some { AzOptionVirtualListOnScrollTag tag; AzVirtualListOnScroll payload; };
+typedef struct AzOptionVirtualListOnScrollVariant_Some AzOptionVirtualListOnScrollVariant_Some;
+union AzOptionVirtualListOnScroll {
+    AzOptionVirtualListOnScrollVariant_None None;
+    AzOptionVirtualListOnScrollVariant_Some Some;
+};
+typedef union AzOptionVirtualListOnScroll AzOptionVirtualListOnScroll;
+
 enum AzOptionNumberInputOnValueChangeTag {
    AzOptionNumberInputOnValueChangeTag_None,
    AzOptionNumberInputOnValueChangeTag_Some,
@@ -6781,6 +7641,7 @@ struct AzAppConfig {
     bool  enable_visual_panic_hook;
     bool  enable_logging_on_panic;
     bool  enable_tab_navigation;
+    bool  record_events;
     AzSystemCallbacks system_callbacks;
 };
 typedef struct AzAppConfig AzAppConfig;
@@ -6829,18 +7690,6 @@ struct AzWindowSize {
 };
 typedef struct AzWindowSize AzWindowSize;
 
-struct AzKeyboardState {
-    bool  shift_down;
-    bool  ctrl_down;
-    bool  alt_down;
-    bool  super_down;
-    AzOptionChar current_char;
-    AzOptionVirtualKeyCode current_virtual_keycode;
-    AzVirtualKeyCodeVec pressed_virtual_keycodes;
-    AzScanCodeVec pressed_scancodes;
-};
-typedef struct AzKeyboardState AzKeyboardState;
-
 struct AzMouseState {
     AzOptionMouseCursorType mouse_cursor_type;
     AzCursorPosition cursor_position;
@@ -6865,6 +7714,13 @@ struct AzInlineTextContents {
 };
 typedef struct AzInlineTextContents AzInlineTextContents;
 
+struct AzDragData {
+    AzDomNodeId source;
+    AzRefAny payload;
+    AzOptionImageRef ghost;
+};
+typedef struct AzDragData AzDragData;
+
 enum AzAnimationEasingTag {
    AzAnimationEasingTag_Ease,
    AzAnimationEasingTag_Linear,
@@ -6956,6 +7812,22 @@ union AzEventFilter {
 };
 typedef union AzEventFilter AzEventFilter;
 
+enum AzFocusScopeTag {
+   AzFocusScopeTag_Trap,
+   AzFocusScopeTag_Roving,
+};
+typedef enum AzFocusScopeTag AzFocusScopeTag;
+
+struct AzFocusScopeVariant_Trap { AzFocusScopeTag tag; };
+typedef struct AzFocusScopeVariant_Trap AzFocusScopeVariant_Trap;
+struct AzFocusScopeVariant_Roving { AzFocusScopeTag tag; AzRovingFocus payload; };
+typedef struct AzFocusScopeVariant_Roving AzFocusScopeVariant_Roving;
+union AzFocusScope {
+    AzFocusScopeVariant_Trap Trap;
+    AzFocusScopeVariant_Roving Roving;
+};
+typedef union AzFocusScope AzFocusScope;
+
 struct AzMenu {
     AzMenuItemVec items;
     AzMenuPopupPosition position;
@@ -6975,6 +7847,9 @@ enum AzCssPathPseudoSelectorTag {
    AzCssPathPseudoSelectorTag_Hover,
    AzCssPathPseudoSelectorTag_Active,
    AzCssPathPseudoSelectorTag_Focus,
+   AzCssPathPseudoSelectorTag_FocusVisible,
+   AzCssPathPseudoSelectorTag_FocusWithin,
+   AzCssPathPseudoSelectorTag_DragOver,
 };
 typedef enum AzCssPathPseudoSelectorTag AzCssPathPseudoSelectorTag;
 
@@ -6990,6 +7865,12 @@ struct AzCssPathPseudoSelectorVariant_Active { AzCssPathPseudoSelectorTag tag; }
 typedef struct AzCssPathPseudoSelectorVariant_Active AzCssPathPseudoSelectorVariant_Active;
 struct AzCssPathPseudoSelectorVariant_Focus { AzCssPathPseudoSelectorTag tag; };
 typedef struct AzCssPathPseudoSelectorVariant_Focus AzCssPathPseudoSelectorVariant_Focus;
+struct AzCssPathPseudoSelectorVariant_FocusVisible { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_FocusVisible AzCssPathPseudoSelectorVariant_FocusVisible;
+struct AzCssPathPseudoSelectorVariant_FocusWithin { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_FocusWithin AzCssPathPseudoSelectorVariant_FocusWithin;
+struct AzCssPathPseudoSelectorVariant_DragOver { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_DragOver AzCssPathPseudoSelectorVariant_DragOver;
 union AzCssPathPseudoSelector {
     AzCssPathPseudoSelectorVariant_First First;
     AzCssPathPseudoSelectorVariant_Last Last;
@@ -6997,6 +7878,9 @@ union AzCssPathPseudoSelector {
     AzCssPathPseudoSelectorVariant_Hover Hover;
     AzCssPathPseudoSelectorVariant_Active Active;
     AzCssPathPseudoSelectorVariant_Focus Focus;
+    AzCssPathPseudoSelectorVariant_FocusVisible FocusVisible;
+    AzCssPathPseudoSelectorVariant_FocusWithin FocusWithin;
+    AzCssPathPseudoSelectorVariant_DragOver DragOver;
 };
 typedef union AzCssPathPseudoSelector AzCssPathPseudoSelector;
 
@@ -7247,12 +8131,81 @@ struct AzCheckBoxStateWrapper {
 };
 typedef struct AzCheckBoxStateWrapper AzCheckBoxStateWrapper;
 
+enum AzTextInputValidatorTag {
+   AzTextInputValidatorTag_NotEmpty,
+   AzTextInputValidatorTag_Integer,
+   AzTextInputValidatorTag_Number,
+   AzTextInputValidatorTag_Email,
+   AzTextInputValidatorTag_Ipv4,
+   AzTextInputValidatorTag_MinLength,
+   AzTextInputValidatorTag_Custom,
+};
+typedef enum AzTextInputValidatorTag AzTextInputValidatorTag;
+
+struct AzTextInputValidatorVariant_NotEmpty { AzTextInputValidatorTag tag; };
+typedef struct AzTextInputValidatorVariant_NotEmpty AzTextInputValidatorVariant_NotEmpty;
+struct AzTextInputValidatorVariant_Integer { AzTextInputValidatorTag tag; };
+typedef struct AzTextInputValidatorVariant_Integer AzTextInputValidatorVariant_Integer;
+struct AzTextInputValidatorVariant_Number { AzTextInputValidatorTag tag; };
+typedef struct AzTextInputValidatorVariant_Number AzTextInputValidatorVariant_Number;
+struct AzTextInputValidatorVariant_Email { AzTextInputValidatorTag tag; };
+typedef struct AzTextInputValidatorVariant_Email AzTextInputValidatorVariant_Email;
+struct AzTextInputValidatorVariant_Ipv4 { AzTextInputValidatorTag tag; };
+typedef struct AzTextInputValidatorVariant_Ipv4 AzTextInputValidatorVariant_Ipv4;
+struct AzTextInputValidatorVariant_MinLength { AzTextInputValidatorTag tag; size_t payload; };
+typedef struct AzTextInputValidatorVariant_MinLength AzTextInputValidatorVariant_MinLength;
+struct AzTextInputValidatorVariant_Custom { AzTextInputValidatorTag tag; AzTextInputCustomValidator payload; };
+typedef struct AzTextInputValidatorVariant_Custom AzTextInputValidatorVariant_Custom;
+union AzTextInputValidator {
+    AzTextInputValidatorVariant_NotEmpty NotEmpty;
+    AzTextInputValidatorVariant_Integer Integer;
+    AzTextInputValidatorVariant_Number Number;
+    AzTextInputValidatorVariant_Email Email;
+    AzTextInputValidatorVariant_Ipv4 Ipv4;
+    AzTextInputValidatorVariant_MinLength MinLength;
+    AzTextInputValidatorVariant_Custom Custom;
+};
+typedef union AzTextInputValidator AzTextInputValidator;
+
 struct AzNumberInputStateWrapper {
     AzNumberInputState inner;
     AzOptionNumberInputOnValueChange on_value_change;
 };
 typedef struct AzNumberInputStateWrapper AzNumberInputStateWrapper;
 
+struct AzSplitPaneStateWrapper {
+    AzSplitPaneState inner;
+    AzOptionSplitPaneOnResize on_resize;
+    bool  is_dragging;
+    float drag_position;
+    AzSplitPaneState state_before_drag;
+};
+typedef struct AzSplitPaneStateWrapper AzSplitPaneStateWrapper;
+
+struct AzSliderStateWrapper {
+    AzSliderState inner;
+    AzOptionSliderOnValueChange on_value_change;
+    bool  is_dragging;
+    float drag_value;
+};
+typedef struct AzSliderStateWrapper AzSliderStateWrapper;
+
+struct AzVirtualListState {
+    size_t row_count;
+    AzVirtualListRowHeight row_height;
+    size_t overscan;
+    float scroll_offset;
+    float viewport_height;
+    AzVirtualListMeasuredRowVec measured_rows;
+};
+typedef struct AzVirtualListState AzVirtualListState;
+
+struct AzVirtualListIFrameNodes {
+    AzDomNodeId rows;
+    AzOptionDomNodeId scrollbar_thumb;
+};
+typedef struct AzVirtualListIFrameNodes AzVirtualListIFrameNodes;
+
 struct AzStyledNode {
     AzStyledNodeState state;
     AzOptionTagId tag_id;
@@ -7429,6 +8382,14 @@ struct AzSvgPathElementVec {
 };
 typedef struct AzSvgPathElementVec AzSvgPathElementVec;
 
+struct AzTextInputValidatorVec {
+    AzTextInputValidator* ptr;
+    size_t len;
+    size_t cap;
+    AzTextInputValidatorVecDestructor destructor;
+};
+typedef struct AzTextInputValidatorVec AzTextInputValidatorVec;
+
 struct AzStringVec {
     AzString* ptr;
     size_t len;
@@ -7453,6 +8414,22 @@ struct AzTagIdToNodeIdMappingVec {
 };
 typedef struct AzTagIdToNodeIdMappingVec AzTagIdToNodeIdMappingVec;
 
+enum AzOptionVirtualListIFrameNodesTag {
+   AzOptionVirtualListIFrameNodesTag_None,
+   AzOptionVirtualListIFrameNodesTag_Some,
+};
+typedef enum AzOptionVirtualListIFrameNodesTag AzOptionVirtualListIFrameNodesTag;
+
+struct AzOptionVirtualListIFrameNodesVariant_None { AzOptionVirtualListIFrameNodesTag tag; };
+typedef struct AzOptionVirtualListIFrameNodesVariant_None AzOptionVirtualListIFrameNodesVariant_None;
+struct AzOptionVirtualListIFrameNodesVariant_Some { AzOptionVirtualListIFrameNodesTag tag; AzVirtualListIFrameNodes payload; };
+typedef struct AzOptionVirtualListIFrameNodesVariant_Some AzOptionVirtualListIFrameNodesVariant_Some;
+union AzOptionVirtualListIFrameNodes {
+    AzOptionVirtualListIFrameNodesVariant_None None;
+    AzOptionVirtualListIFrameNodesVariant_Some Some;
+};
+typedef union AzOptionVirtualListIFrameNodes AzOptionVirtualListIFrameNodes;
+
 enum AzOptionVirtualKeyCodeComboTag {
    AzOptionVirtualKeyCodeComboTag_None,
    AzOptionVirtualKeyCodeComboTag_Some,
@@ -7469,6 +8446,22 @@ union AzOptionVirtualKeyCodeCombo {
 };
 typedef union AzOptionVirtualKeyCodeCombo AzOptionVirtualKeyCodeCombo;
 
+enum AzOptionDragDataTag {
+   AzOptionDragDataTag_None,
+   AzOptionDragDataTag_Some,
+};
+typedef enum AzOptionDragDataTag AzOptionDragDataTag;
+
+struct AzOptionDragDataVariant_None { AzOptionDragDataTag tag; };
+typedef struct AzOptionDragDataVariant_None AzOptionDragDataVariant_None;
+struct AzOptionDragDataVariant_Some { AzOptionDragDataTag tag; AzDragData payload; };
+typedef struct AzOptionDragDataVariant_Some AzOptionDragDataVariant_Some;
+union AzOptionDragData {
+    AzOptionDragDataVariant_None None;
+    AzOptionDragDataVariant_Some Some;
+};
+typedef union AzOptionDragData AzOptionDragData;
+
 enum AzOptionMouseStateTag {
    AzOptionMouseStateTag_None,
    AzOptionMouseStateTag_Some,
@@ -7485,22 +8478,6 @@ union AzOptionMouseState {
 };
 typedef union AzOptionMouseState AzOptionMouseState;
 
-enum AzOptionKeyboardStateTag {
-   AzOptionKeyboardStateTag_None,
-   AzOptionKeyboardStateTag_Some,
-};
-typedef enum AzOptionKeyboardStateTag AzOptionKeyboardStateTag;
-
-struct AzOptionKeyboardStateVariant_None { AzOptionKeyboardStateTag tag; };
-typedef struct AzOptionKeyboardStateVariant_None AzOptionKeyboardStateVariant_None;
-struct AzOptionKeyboardStateVariant_Some { AzOptionKeyboardStateTag tag; AzKeyboardState payload; };
-typedef struct AzOptionKeyboardStateVariant_Some AzOptionKeyboardStateVariant_Some;
-union AzOptionKeyboardState {
-    AzOptionKeyboardStateVariant_None None;
-    AzOptionKeyboardStateVariant_Some Some;
-};
-typedef union AzOptionKeyboardState AzOptionKeyboardState;
-
 enum AzOptionStringVecTag {
    AzOptionStringVecTag_None,
    AzOptionStringVecTag_Some,
@@ -7713,6 +8690,13 @@ struct AzStringPair {
 };
 typedef struct AzStringPair AzStringPair;
 
+struct AzImeComposition {
+    AzString text;
+    size_t cursor_start;
+    size_t cursor_end;
+};
+typedef struct AzImeComposition AzImeComposition;
+
 struct AzMonitor {
     size_t id;
     AzOptionString name;
@@ -7997,6 +8981,13 @@ union AzStyleTransformVecValue {
 };
 typedef union AzStyleTransformVecValue AzStyleTransformVecValue;
 
+struct AzDropDownChoice {
+    AzString label;
+    bool  disabled;
+    AzOptionString group;
+};
+typedef struct AzDropDownChoice AzDropDownChoice;
+
 struct AzColorInputStateWrapper {
     AzColorInputState inner;
     AzString title;
@@ -8004,14 +8995,71 @@ struct AzColorInputStateWrapper {
 };
 typedef struct AzColorInputStateWrapper AzColorInputStateWrapper;
 
-struct AzTextInputState {
-    AzU32Vec text;
-    AzOptionString placeholder;
-    size_t max_len;
-    AzOptionTextInputSelection selection;
-    size_t cursor_pos;
+enum AzTextInputValidationResultTag {
+   AzTextInputValidationResultTag_Valid,
+   AzTextInputValidationResultTag_Invalid,
 };
-typedef struct AzTextInputState AzTextInputState;
+typedef enum AzTextInputValidationResultTag AzTextInputValidationResultTag;
+
+struct AzTextInputValidationResultVariant_Valid { AzTextInputValidationResultTag tag; };
+typedef struct AzTextInputValidationResultVariant_Valid AzTextInputValidationResultVariant_Valid;
+struct AzTextInputValidationResultVariant_Invalid { AzTextInputValidationResultTag tag; AzString payload; };
+typedef struct AzTextInputValidationResultVariant_Invalid AzTextInputValidationResultVariant_Invalid;
+union AzTextInputValidationResult {
+    AzTextInputValidationResultVariant_Valid Valid;
+    AzTextInputValidationResultVariant_Invalid Invalid;
+};
+typedef union AzTextInputValidationResult AzTextInputValidationResult;
+
+struct AzTextInputMask {
+    AzString pattern;
+};
+typedef struct AzTextInputMask AzTextInputMask;
+
+struct AzTabContainerTab {
+    AzString title;
+    bool  closable;
+    size_t page;
+};
+typedef struct AzTabContainerTab AzTabContainerTab;
+
+struct AzTextEditState {
+    AzStringVec lines;
+    AzTextEditCursor cursor;
+    AzOptionTextEditCursor selection_anchor;
+    AzOptionUsize preferred_column;
+    size_t first_visible_row;
+    size_t visible_rows;
+    size_t wrap_column;
+};
+typedef struct AzTextEditState AzTextEditState;
+
+struct AzTextEditUndoEntry {
+    AzTextEditCursor start;
+    AzString removed;
+    AzString inserted;
+    AzTextEditCursor cursor_before;
+    AzTextEditEditKind kind;
+};
+typedef struct AzTextEditUndoEntry AzTextEditUndoEntry;
+
+struct AzTreeViewNodeData {
+    AzString label;
+    AzOptionImageRef icon;
+    AzTreeViewChildren children;
+};
+typedef struct AzTreeViewNodeData AzTreeViewNodeData;
+
+struct AzTreeViewRenderedRow {
+    AzOptionUsize node;
+    size_t depth;
+    AzString toggle;
+    AzOptionImageRef icon;
+    AzString label;
+    bool  is_selected;
+    bool  is_cursor;
+};
+typedef struct AzTreeViewRenderedRow AzTreeViewRenderedRow;
 
 struct AzVertexAttribute {
     AzString name;
@@ -8251,6 +9299,46 @@ struct AzVertexAttributeVec {
 };
 typedef struct AzVertexAttributeVec AzVertexAttributeVec;
 
+struct AzDropDownChoiceVec {
+    AzDropDownChoice* ptr;
+    size_t len;
+    size_t cap;
+    AzDropDownChoiceVecDestructor destructor;
+};
+typedef struct AzDropDownChoiceVec AzDropDownChoiceVec;
+
+struct AzTabContainerTabVec {
+    AzTabContainerTab* ptr;
+    size_t len;
+    size_t cap;
+    AzTabContainerTabVecDestructor destructor;
+};
+typedef struct AzTabContainerTabVec AzTabContainerTabVec;
+
+struct AzTextEditUndoEntryVec {
+    AzTextEditUndoEntry* ptr;
+    size_t len;
+    size_t cap;
+    AzTextEditUndoEntryVecDestructor destructor;
+};
+typedef struct AzTextEditUndoEntryVec AzTextEditUndoEntryVec;
+
+struct AzTreeViewNodeDataVec {
+    AzTreeViewNodeData* ptr;
+    size_t len;
+    size_t cap;
+    AzTreeViewNodeDataVecDestructor destructor;
+};
+typedef struct AzTreeViewNodeDataVec AzTreeViewNodeDataVec;
+
+struct AzTreeViewRenderedRowVec {
+    AzTreeViewRenderedRow* ptr;
+    size_t len;
+    size_t cap;
+    AzTreeViewRenderedRowVecDestructor destructor;
+};
+typedef struct AzTreeViewRenderedRowVec AzTreeViewRenderedRowVec;
+
 struct AzCssPathSelectorVec {
     AzCssPathSelector* ptr;
     size_t len;
@@ -8283,6 +9371,38 @@ struct AzStringPairVec {
 };
 typedef struct AzStringPairVec AzStringPairVec;
 
+enum AzOptionTextInputMaskTag {
+   AzOptionTextInputMaskTag_None,
+   AzOptionTextInputMaskTag_Some,
+};
+typedef enum AzOptionTextInputMaskTag AzOptionTextInputMaskTag;
+
+struct AzOptionTextInputMaskVariant_None { AzOptionTextInputMaskTag tag; };
+typedef struct AzOptionTextInputMaskVariant_None AzOptionTextInputMaskVariant_None;
+struct AzOptionTextInputMaskVariant_Some { AzOptionTextInputMaskTag tag; AzTextInputMask payload; };
+typedef struct AzOptionTextInputMaskVariant_Some AzOptionTextInputMaskVariant_Some;
+union AzOptionTextInputMask {
+    AzOptionTextInputMaskVariant_None None;
+    AzOptionTextInputMaskVariant_Some Some;
+};
+typedef union AzOptionTextInputMask AzOptionTextInputMask;
+
+enum AzOptionImeCompositionTag {
+   AzOptionImeCompositionTag_None,
+   AzOptionImeCompositionTag_Some,
+};
+typedef enum AzOptionImeCompositionTag AzOptionImeCompositionTag;
+
+struct AzOptionImeCompositionVariant_None { AzOptionImeCompositionTag tag; };
+typedef struct AzOptionImeCompositionVariant_None AzOptionImeCompositionVariant_None;
+struct AzOptionImeCompositionVariant_Some { AzOptionImeCompositionTag tag; AzImeComposition payload; };
+typedef struct AzOptionImeCompositionVariant_Some AzOptionImeCompositionVariant_Some;
+union AzOptionImeComposition {
+    AzOptionImeCompositionVariant_None None;
+    AzOptionImeCompositionVariant_Some Some;
+};
+typedef union AzOptionImeComposition AzOptionImeComposition;
+
 enum AzOptionFileTypeListTag {
    AzOptionFileTypeListTag_None,
    AzOptionFileTypeListTag_Some,
@@ -8423,6 +9543,19 @@ union AzXmlStreamError {
 };
 typedef union AzXmlStreamError AzXmlStreamError;
 
+struct AzKeyboardState {
+    bool  shift_down;
+    bool  ctrl_down;
+    bool  alt_down;
+    bool  super_down;
+    AzOptionChar current_char;
+    AzOptionVirtualKeyCode current_virtual_keycode;
+    AzVirtualKeyCodeVec pressed_virtual_keycodes;
+    AzScanCodeVec pressed_scancodes;
+    AzOptionImeComposition ime_composition;
+};
+typedef struct AzKeyboardState AzKeyboardState;
+
 struct AzLinuxWindowOptions {
     AzOptionX11Visual x11_visual;
     AzOptionI32 x11_screen;
@@ -8814,15 +9947,67 @@ union AzCssProperty {
 };
 typedef union AzCssProperty AzCssProperty;
 
-struct AzTextInputStateWrapper {
-    AzTextInputState inner;
-    AzOptionTextInputOnTextInput on_text_input;
-    AzOptionTextInputOnVirtualKeyDown on_virtual_key_down;
-    AzOptionTextInputOnFocusLost on_focus_lost;
-    bool  update_text_input_before_calling_focus_lost_fn;
-    bool  update_text_input_before_calling_vk_down_fn;
+struct AzDropDownState {
+    AzDropDownChoiceVec choices;
+    AzOptionUsize selected;
+    AzOptionUsize highlighted;
+    bool  is_open;
+    AzString search;
 };
-typedef struct AzTextInputStateWrapper AzTextInputStateWrapper;
+typedef struct AzDropDownState AzDropDownState;
+
+struct AzTextInputState {
+    AzU32Vec text;
+    AzOptionString placeholder;
+    size_t max_len;
+    AzOptionTextInputSelection selection;
+    size_t cursor_pos;
+    AzTextInputValidatorVec validators;
+    AzOptionTextInputMask mask;
+    AzOptionChar obscure_char;
+    AzTextInputValidationResult validation;
+    AzOptionImeComposition ime_composition;
+};
+typedef struct AzTextInputState AzTextInputState;
+
+struct AzTabContainerState {
+    AzTabContainerTabVec tabs;
+    AzOptionUsize active_tab;
+    float scroll_offset;
+    AzTabContainerOverflow overflow;
+    bool  menu_open;
+};
+typedef struct AzTabContainerState AzTabContainerState;
+
+struct AzTextEditStateWrapper {
+    AzTextEditState inner;
+    AzOptionTextEditOnTextChange on_text_change;
+    AzTextEditUndoEntryVec undo_stack;
+    AzTextEditUndoEntryVec redo_stack;
+    bool  wrap_lines;
+    float char_width;
+    AzStringVec rendered_rows;
+    bool  is_selecting;
+};
+typedef struct AzTextEditStateWrapper AzTextEditStateWrapper;
+
+struct AzTreeViewNode {
+    AzTreeViewNodeData data;
+    AzOptionUsize parent;
+    AzOptionUsize first_child;
+    AzOptionUsize last_child;
+    AzOptionUsize previous_sibling;
+    AzOptionUsize next_sibling;
+    bool  is_expanded;
+    bool  is_selected;
+};
+typedef struct AzTreeViewNode AzTreeViewNode;
+
+struct AzTreeViewLoadedChildren {
+    size_t node;
+    AzTreeViewNodeDataVec children;
+};
+typedef struct AzTreeViewLoadedChildren AzTreeViewLoadedChildren;
 
 enum AzCssPropertySourceTag {
    AzCssPropertySourceTag_Css,
@@ -8872,6 +10057,9 @@ struct AzXmlNode {
     AzStringPairVec attributes;
     AzXmlNodeVec children;
     AzOptionString text;
+    AzXmlSpan span;
+    AzXmlSpanVec attribute_spans;
+    AzXmlSpan text_span;
 };
 typedef struct AzXmlNode AzXmlNode;
 
@@ -8899,6 +10087,14 @@ struct AzSvgMultiPolygonVec {
 };
 typedef struct AzSvgMultiPolygonVec AzSvgMultiPolygonVec;
 
+struct AzTreeViewNodeVec {
+    AzTreeViewNode* ptr;
+    size_t len;
+    size_t cap;
+    AzTreeViewNodeVecDestructor destructor;
+};
+typedef struct AzTreeViewNodeVec AzTreeViewNodeVec;
+
 enum AzOptionCssPropertyTag {
    AzOptionCssPropertyTag_None,
    AzOptionCssPropertyTag_Some,
@@ -8915,6 +10111,22 @@ union AzOptionCssProperty {
 };
 typedef union AzOptionCssProperty AzOptionCssProperty;
 
+enum AzOptionKeyboardStateTag {
+   AzOptionKeyboardStateTag_None,
+   AzOptionKeyboardStateTag_Some,
+};
+typedef enum AzOptionKeyboardStateTag AzOptionKeyboardStateTag;
+
+struct AzOptionKeyboardStateVariant_None { AzOptionKeyboardStateTag tag; };
+typedef struct AzOptionKeyboardStateVariant_None AzOptionKeyboardStateVariant_None;
+struct AzOptionKeyboardStateVariant_Some { AzOptionKeyboardStateTag tag; AzKeyboardState payload; };
+typedef struct AzOptionKeyboardStateVariant_Some AzOptionKeyboardStateVariant_Some;
+union AzOptionKeyboardState {
+    AzOptionKeyboardStateVariant_None None;
+    AzOptionKeyboardStateVariant_Some Some;
+};
+typedef union AzOptionKeyboardState AzOptionKeyboardState;
+
 struct AzXmlTextError {
     AzXmlStreamError stream_error;
     AzSvgParseErrorPosition pos;
@@ -8970,6 +10182,7 @@ struct AzCallbackInfo {
     void* restrict datasets;
     bool * restrict stop_propagation;
     void* restrict focus_target;
+    void* restrict drag_started;
     void* words_cache;
     void* shaped_words_cache;
     void* positioned_words_cache;
@@ -9055,6 +10268,51 @@ struct AzDynamicCssProperty {
 };
 typedef struct AzDynamicCssProperty AzDynamicCssProperty;
 
+struct AzDropDownStateWrapper {
+    AzDropDownState inner;
+    AzOptionDropDownOnChoiceChange on_choice_change;
+};
+typedef struct AzDropDownStateWrapper AzDropDownStateWrapper;
+
+struct AzTextInputStateWrapper {
+    AzTextInputState inner;
+    AzOptionTextInputOnTextInput on_text_input;
+    AzOptionTextInputOnVirtualKeyDown on_virtual_key_down;
+    AzOptionTextInputOnFocusLost on_focus_lost;
+    bool  update_text_input_before_calling_focus_lost_fn;
+    bool  update_text_input_before_calling_vk_down_fn;
+    AzOptionTextInputOnValidationChange on_validation_change;
+    AzCssPropertyVec error_style;
+    AzCssPropertyVec style_before_error;
+};
+typedef struct AzTextInputStateWrapper AzTextInputStateWrapper;
+
+struct AzTabContainerStateWrapper {
+    AzTabContainerState inner;
+    AzOptionTabContainerOnTabChange on_tab_change;
+    AzOptionTabContainerOnTabClose on_tab_close;
+    AzOptionTabContainerOnTabMove on_tab_move;
+    bool  allow_reorder;
+    size_t tab_count;
+    AzOptionUsize pressed_tab;
+    AzOptionUsize dragged_tab;
+    AzOptionUsize drop_index;
+};
+typedef struct AzTabContainerStateWrapper AzTabContainerStateWrapper;
+
+struct AzTreeViewState {
+    AzTreeViewNodeVec nodes;
+    AzOptionUsize first_root;
+    AzOptionUsize last_root;
+    AzTreeViewRowVec rows;
+    AzOptionUsize cursor;
+    AzOptionUsize selection_anchor;
+    AzTreeViewSelectionMode selection_mode;
+    size_t first_visible_row;
+    size_t visible_rows;
+};
+typedef struct AzTreeViewState AzTreeViewState;
+
 enum AzSvgNodeTag {
    AzSvgNodeTag_MultiPolygonCollection,
    AzSvgNodeTag_MultiPolygon,
@@ -9267,6 +10525,16 @@ struct AzCheckBox {
 };
 typedef struct AzCheckBox AzCheckBox;
 
+struct AzDropDown {
+    AzDropDownStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec header_style;
+    AzNodeDataInlineCssPropertyVec popup_style;
+    AzNodeDataInlineCssPropertyVec choice_style;
+    AzNodeDataInlineCssPropertyVec group_style;
+};
+typedef struct AzDropDown AzDropDown;
+
 struct AzLabel {
     AzString text;
     AzNodeDataInlineCssPropertyVec style;
@@ -9284,6 +10552,7 @@ struct AzTextInput {
     AzNodeDataInlineCssPropertyVec placeholder_style;
     AzNodeDataInlineCssPropertyVec container_style;
     AzNodeDataInlineCssPropertyVec label_style;
+    AzNodeDataInlineCssPropertyVec preedit_style;
 };
 typedef struct AzTextInput AzTextInput;
 
@@ -9301,6 +10570,74 @@ struct AzProgressBar {
 };
 typedef struct AzProgressBar AzProgressBar;
 
+struct AzTabContainer {
+    AzTabContainerStateWrapper state;
+    AzDomVec pages;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec header_style;
+    AzNodeDataInlineCssPropertyVec tab_style;
+    AzNodeDataInlineCssPropertyVec close_button_style;
+    AzNodeDataInlineCssPropertyVec button_style;
+    AzNodeDataInlineCssPropertyVec menu_style;
+    AzNodeDataInlineCssPropertyVec page_style;
+};
+typedef struct AzTabContainer AzTabContainer;
+
+struct AzSlider {
+    AzSliderStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec track_style;
+    AzNodeDataInlineCssPropertyVec fill_style;
+    AzNodeDataInlineCssPropertyVec thumb_style;
+    AzNodeDataInlineCssPropertyVec tick_style;
+};
+typedef struct AzSlider AzSlider;
+
+struct AzTextEdit {
+    AzTextEditStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec row_style;
+    AzNodeDataInlineCssPropertyVec label_style;
+    AzNodeDataInlineCssPropertyVec selection_style;
+    AzNodeDataInlineCssPropertyVec caret_style;
+};
+typedef struct AzTextEdit AzTextEdit;
+
+struct AzTreeViewStateWrapper {
+    AzTreeViewState inner;
+    AzOptionTreeViewOnLoadChildren on_load_children;
+    AzOptionTreeViewOnSelectionChange on_selection_change;
+    AzOptionTreeViewOnNodeMoved on_node_moved;
+    bool  allow_reorder;
+    AzTreeViewRenderedRowVec rendered_rows;
+    AzOptionUsize pressed_node;
+    AzOptionUsize dragged_node;
+    AzOptionTreeViewDropTarget drop_target;
+};
+typedef struct AzTreeViewStateWrapper AzTreeViewStateWrapper;
+
+struct AzVirtualList {
+    AzRefAny state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec row_style;
+    AzNodeDataInlineCssPropertyVec scrollbar_track_style;
+    AzNodeDataInlineCssPropertyVec scrollbar_thumb_style;
+};
+typedef struct AzVirtualList AzVirtualList;
+
+struct AzVirtualListStateWrapper {
+    AzVirtualListState inner;
+    AzVirtualListRenderRow render_row;
+    AzOptionVirtualListOnScroll on_scroll;
+    AzNodeDataInlineCssPropertyVec row_style;
+    AzNodeDataInlineCssPropertyVec scrollbar_track_style;
+    AzNodeDataInlineCssPropertyVec scrollbar_thumb_style;
+    size_t rendered_rows_start;
+    size_t rendered_rows_end;
+    AzOptionVirtualListIFrameNodes iframe_nodes;
+};
+typedef struct AzVirtualListStateWrapper AzVirtualListStateWrapper;
+
 struct AzCssDeclarationVec {
     AzCssDeclaration* ptr;
     size_t len;
@@ -9406,6 +10743,29 @@ struct AzCssRuleBlock {
 };
 typedef struct AzCssRuleBlock AzCssRuleBlock;
 
+struct AzSplitPane {
+    AzSplitPaneStateWrapper state;
+    AzDom first_pane;
+    AzDom second_pane;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec pane_style;
+    AzNodeDataInlineCssPropertyVec divider_style;
+};
+typedef struct AzSplitPane AzSplitPane;
+
+struct AzTreeView {
+    AzTreeViewStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec row_style;
+    AzNodeDataInlineCssPropertyVec guides_style;
+    AzNodeDataInlineCssPropertyVec toggle_style;
+    AzNodeDataInlineCssPropertyVec icon_style;
+    AzNodeDataInlineCssPropertyVec label_style;
+    AzNodeDataInlineCssPropertyVec scrollbar_thumb_style;
+    AzNodeDataInlineCssPropertyVec drop_indicator_style;
+};
+typedef struct AzTreeView AzTreeView;
+
 struct AzStyledDom {
     AzNodeId root;
     AzNodeVec node_hierarchy;
@@ -9561,6 +10921,8 @@ typedef struct AzCss AzCss;
 #define AzTabIndex_Auto { .Auto = { .tag = AzTabIndexTag_Auto } }
 #define AzTabIndex_OverrideInParent(v) { .OverrideInParent = { .tag = AzTabIndexTag_OverrideInParent, .payload = v } }
 #define AzTabIndex_NoKeyboardFocus { .NoKeyboardFocus = { .tag = AzTabIndexTag_NoKeyboardFocus } }
+#define AzVirtualListRowHeight_Fixed(v) { .Fixed = { .tag = AzVirtualListRowHeightTag_Fixed, .payload = v } }
+#define AzVirtualListRowHeight_Estimated(v) { .Estimated = { .tag = AzVirtualListRowHeightTag_Estimated, .payload = v } }
 #define AzIndent_None { .None = { .tag = AzIndentTag_None } }
 #define AzIndent_Spaces(v) { .Spaces = { .tag = AzIndentTag_Spaces, .payload = v } }
 #define AzIndent_Tabs { .Tabs = { .tag = AzIndentTag_Tabs } }
@@ -9583,6 +10945,9 @@ typedef struct AzCss AzCss;
 #define AzXmlNodeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzXmlNodeVecDestructorTag_DefaultRust } }
 #define AzXmlNodeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzXmlNodeVecDestructorTag_NoDestructor } }
 #define AzXmlNodeVecDestructor_External(v) { .External = { .tag = AzXmlNodeVecDestructorTag_External, .payload = v } }
+#define AzXmlSpanVecDestructor_DefaultRust { .DefaultRust = { .tag = AzXmlSpanVecDestructorTag_DefaultRust } }
+#define AzXmlSpanVecDestructor_NoDestructor { .NoDestructor = { .tag = AzXmlSpanVecDestructorTag_NoDestructor } }
+#define AzXmlSpanVecDestructor_External(v) { .External = { .tag = AzXmlSpanVecDestructorTag_External, .payload = v } }
 #define AzFmtArgVecDestructor_DefaultRust { .DefaultRust = { .tag = AzFmtArgVecDestructorTag_DefaultRust } }
 #define AzFmtArgVecDestructor_NoDestructor { .NoDestructor = { .tag = AzFmtArgVecDestructorTag_NoDestructor } }
 #define AzFmtArgVecDestructor_External(v) { .External = { .tag = AzFmtArgVecDestructorTag_External, .payload = v } }
@@ -9661,6 +11026,33 @@ typedef struct AzCss AzCss;
 #define AzScanCodeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzScanCodeVecDestructorTag_DefaultRust } }
 #define AzScanCodeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzScanCodeVecDestructorTag_NoDestructor } }
 #define AzScanCodeVecDestructor_External(v) { .External = { .tag = AzScanCodeVecDestructorTag_External, .payload = v } }
+#define AzTextInputValidatorVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTextInputValidatorVecDestructorTag_DefaultRust } }
+#define AzTextInputValidatorVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTextInputValidatorVecDestructorTag_NoDestructor } }
+#define AzTextInputValidatorVecDestructor_External(v) { .External = { .tag = AzTextInputValidatorVecDestructorTag_External, .payload = v } }
+#define AzDropDownChoiceVecDestructor_DefaultRust { .DefaultRust = { .tag = AzDropDownChoiceVecDestructorTag_DefaultRust } }
+#define AzDropDownChoiceVecDestructor_NoDestructor { .NoDestructor = { .tag = AzDropDownChoiceVecDestructorTag_NoDestructor } }
+#define AzDropDownChoiceVecDestructor_External(v) { .External = { .tag = AzDropDownChoiceVecDestructorTag_External, .payload = v } }
+#define AzTabContainerTabVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTabContainerTabVecDestructorTag_DefaultRust } }
+#define AzTabContainerTabVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTabContainerTabVecDestructorTag_NoDestructor } }
+#define AzTabContainerTabVecDestructor_External(v) { .External = { .tag = AzTabContainerTabVecDestructorTag_External, .payload = v } }
+#define AzTextEditUndoEntryVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTextEditUndoEntryVecDestructorTag_DefaultRust } }
+#define AzTextEditUndoEntryVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTextEditUndoEntryVecDestructorTag_NoDestructor } }
+#define AzTextEditUndoEntryVecDestructor_External(v) { .External = { .tag = AzTextEditUndoEntryVecDestructorTag_External, .payload = v } }
+#define AzTreeViewNodeDataVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTreeViewNodeDataVecDestructorTag_DefaultRust } }
+#define AzTreeViewNodeDataVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTreeViewNodeDataVecDestructorTag_NoDestructor } }
+#define AzTreeViewNodeDataVecDestructor_External(v) { .External = { .tag = AzTreeViewNodeDataVecDestructorTag_External, .payload = v } }
+#define AzTreeViewNodeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTreeViewNodeVecDestructorTag_DefaultRust } }
+#define AzTreeViewNodeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTreeViewNodeVecDestructorTag_NoDestructor } }
+#define AzTreeViewNodeVecDestructor_External(v) { .External = { .tag = AzTreeViewNodeVecDestructorTag_External, .payload = v } }
+#define AzTreeViewRowVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTreeViewRowVecDestructorTag_DefaultRust } }
+#define AzTreeViewRowVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTreeViewRowVecDestructorTag_NoDestructor } }
+#define AzTreeViewRowVecDestructor_External(v) { .External = { .tag = AzTreeViewRowVecDestructorTag_External, .payload = v } }
+#define AzTreeViewRenderedRowVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTreeViewRenderedRowVecDestructorTag_DefaultRust } }
+#define AzTreeViewRenderedRowVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTreeViewRenderedRowVecDestructorTag_NoDestructor } }
+#define AzTreeViewRenderedRowVecDestructor_External(v) { .External = { .tag = AzTreeViewRenderedRowVecDestructorTag_External, .payload = v } }
+#define AzVirtualListMeasuredRowVecDestructor_DefaultRust { .DefaultRust = { .tag = AzVirtualListMeasuredRowVecDestructorTag_DefaultRust } }
+#define AzVirtualListMeasuredRowVecDestructor_NoDestructor { .NoDestructor = { .tag = AzVirtualListMeasuredRowVecDestructorTag_NoDestructor } }
+#define AzVirtualListMeasuredRowVecDestructor_External(v) { .External = { .tag = AzVirtualListMeasuredRowVecDestructorTag_External, .payload = v } }
 #define AzCssDeclarationVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssDeclarationVecDestructorTag_DefaultRust } }
 #define AzCssDeclarationVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssDeclarationVecDestructorTag_NoDestructor } }
 #define AzCssDeclarationVecDestructor_External(v) { .External = { .tag = AzCssDeclarationVecDestructorTag_External, .payload = v } }
@@ -10103,6 +11495,34 @@ typedef struct AzCss AzCss;
 #define AzOptionTextInputOnFocusLost_Some(v) { .Some = { .tag = AzOptionTextInputOnFocusLostTag_Some, .payload = v } }
 #define AzOptionTextInputSelection_None { .None = { .tag = AzOptionTextInputSelectionTag_None } }
 #define AzOptionTextInputSelection_Some(v) { .Some = { .tag = AzOptionTextInputSelectionTag_Some, .payload = v } }
+#define AzOptionTextInputOnValidationChange_None { .None = { .tag = AzOptionTextInputOnValidationChangeTag_None } }
+#define AzOptionTextInputOnValidationChange_Some(v) { .Some = { .tag = AzOptionTextInputOnValidationChangeTag_Some, .payload = v } }
+#define AzOptionDropDownOnChoiceChange_None { .None = { .tag = AzOptionDropDownOnChoiceChangeTag_None } }
+#define AzOptionDropDownOnChoiceChange_Some(v) { .Some = { .tag = AzOptionDropDownOnChoiceChangeTag_Some, .payload = v } }
+#define AzOptionTabContainerOnTabChange_None { .None = { .tag = AzOptionTabContainerOnTabChangeTag_None } }
+#define AzOptionTabContainerOnTabChange_Some(v) { .Some = { .tag = AzOptionTabContainerOnTabChangeTag_Some, .payload = v } }
+#define AzOptionTabContainerOnTabClose_None { .None = { .tag = AzOptionTabContainerOnTabCloseTag_None } }
+#define AzOptionTabContainerOnTabClose_Some(v) { .Some = { .tag = AzOptionTabContainerOnTabCloseTag_Some, .payload = v } }
+#define AzOptionTabContainerOnTabMove_None { .None = { .tag = AzOptionTabContainerOnTabMoveTag_None } }
+#define AzOptionTabContainerOnTabMove_Some(v) { .Some = { .tag = AzOptionTabContainerOnTabMoveTag_Some, .payload = v } }
+#define AzOptionSplitPaneOnResize_None { .None = { .tag = AzOptionSplitPaneOnResizeTag_None } }
+#define AzOptionSplitPaneOnResize_Some(v) { .Some = { .tag = AzOptionSplitPaneOnResizeTag_Some, .payload = v } }
+#define AzOptionSliderOnValueChange_None { .None = { .tag = AzOptionSliderOnValueChangeTag_None } }
+#define AzOptionSliderOnValueChange_Some(v) { .Some = { .tag = AzOptionSliderOnValueChangeTag_Some, .payload = v } }
+#define AzOptionTextEditOnTextChange_None { .None = { .tag = AzOptionTextEditOnTextChangeTag_None } }
+#define AzOptionTextEditOnTextChange_Some(v) { .Some = { .tag = AzOptionTextEditOnTextChangeTag_Some, .payload = v } }
+#define AzOptionTextEditCursor_None { .None = { .tag = AzOptionTextEditCursorTag_None } }
+#define AzOptionTextEditCursor_Some(v) { .Some = { .tag = AzOptionTextEditCursorTag_Some, .payload = v } }
+#define AzOptionTreeViewOnLoadChildren_None { .None = { .tag = AzOptionTreeViewOnLoadChildrenTag_None } }
+#define AzOptionTreeViewOnLoadChildren_Some(v) { .Some = { .tag = AzOptionTreeViewOnLoadChildrenTag_Some, .payload = v } }
+#define AzOptionTreeViewOnSelectionChange_None { .None = { .tag = AzOptionTreeViewOnSelectionChangeTag_None } }
+#define AzOptionTreeViewOnSelectionChange_Some(v) { .Some = { .tag = AzOptionTreeViewOnSelectionChangeTag_Some, .payload = v } }
+#define AzOptionTreeViewOnNodeMoved_None { .None = { .tag = AzOptionTreeViewOnNodeMovedTag_None } }
+#define AzOptionTreeViewOnNodeMoved_Some(v) { .Some = { .tag = AzOptionTreeViewOnNodeMovedTag_Some, .payload = v } }
+#define AzOptionTreeViewDropTarget_None { .None = { .tag = AzOptionTreeViewDropTargetTag_None } }
+#define AzOptionTreeViewDropTarget_Some(v) { .Some = { .tag = AzOptionTreeViewDropTargetTag_Some, .payload = v } }
+#define AzOptionVirtualListOnScroll_None { .None = { .tag = AzOptionVirtualListOnScrollTag_None } }
+#define AzOptionVirtualListOnScroll_Some(v) { .Some = { .tag = AzOptionVirtualListOnScrollTag_Some, .payload = v } }
 #define AzOptionNumberInputOnValueChange_None { .None = { .tag = AzOptionNumberInputOnValueChangeTag_None } }
 #define AzOptionNumberInputOnValueChange_Some(v) { .Some = { .tag = AzOptionNumberInputOnValueChangeTag_Some, .payload = v } }
 #define AzOptionMenuItemIcon_None { .None = { .tag = AzOptionMenuItemIconTag_None } }
@@ -10189,12 +11609,17 @@ typedef struct AzCss AzCss;
 #define AzEventFilter_Window(v) { .Window = { .tag = AzEventFilterTag_Window, .payload = v } }
 #define AzEventFilter_Component(v) { .Component = { .tag = AzEventFilterTag_Component, .payload = v } }
 #define AzEventFilter_Application(v) { .Application = { .tag = AzEventFilterTag_Application, .payload = v } }
+#define AzFocusScope_Trap { .Trap = { .tag = AzFocusScopeTag_Trap } }
+#define AzFocusScope_Roving(v) { .Roving = { .tag = AzFocusScopeTag_Roving, .payload = v } }
 #define AzCssPathPseudoSelector_First { .First = { .tag = AzCssPathPseudoSelectorTag_First } }
 #define AzCssPathPseudoSelector_Last { .Last = { .tag = AzCssPathPseudoSelectorTag_Last } }
 #define AzCssPathPseudoSelector_NthChild(v) { .NthChild = { .tag = AzCssPathPseudoSelectorTag_NthChild, .payload = v } }
 #define AzCssPathPseudoSelector_Hover { .Hover = { .tag = AzCssPathPseudoSelectorTag_Hover } }
 #define AzCssPathPseudoSelector_Active { .Active = { .tag = AzCssPathPseudoSelectorTag_Active } }
 #define AzCssPathPseudoSelector_Focus { .Focus = { .tag = AzCssPathPseudoSelectorTag_Focus } }
+#define AzCssPathPseudoSelector_FocusVisible { .FocusVisible = { .tag = AzCssPathPseudoSelectorTag_FocusVisible } }
+#define AzCssPathPseudoSelector_FocusWithin { .FocusWithin = { .tag = AzCssPathPseudoSelectorTag_FocusWithin } }
+#define AzCssPathPseudoSelector_DragOver { .DragOver = { .tag = AzCssPathPseudoSelectorTag_DragOver } }
 #define AzAnimationInterpolationFunction_Ease { .Ease = { .tag = AzAnimationInterpolationFunctionTag_Ease } }
 #define AzAnimationInterpolationFunction_Linear { .Linear = { .tag = AzAnimationInterpolationFunctionTag_Linear } }
 #define AzAnimationInterpolationFunction_EaseIn { .EaseIn = { .tag = AzAnimationInterpolationFunctionTag_EaseIn } }
@@ -10237,6 +11662,13 @@ typedef struct AzCss AzCss;
 #define AzStyleBackgroundSizeVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundSizeVecValueTag_Inherit } }
 #define AzStyleBackgroundSizeVecValue_Initial { .Initial = { .tag = AzStyleBackgroundSizeVecValueTag_Initial } }
 #define AzStyleBackgroundSizeVecValue_Exact(v) { .Exact = { .tag = AzStyleBackgroundSizeVecValueTag_Exact, .payload = v } }
+#define AzTextInputValidator_NotEmpty { .NotEmpty = { .tag = AzTextInputValidatorTag_NotEmpty } }
+#define AzTextInputValidator_Integer { .Integer = { .tag = AzTextInputValidatorTag_Integer } }
+#define AzTextInputValidator_Number { .Number = { .tag = AzTextInputValidatorTag_Number } }
+#define AzTextInputValidator_Email { .Email = { .tag = AzTextInputValidatorTag_Email } }
+#define AzTextInputValidator_Ipv4 { .Ipv4 = { .tag = AzTextInputValidatorTag_Ipv4 } }
+#define AzTextInputValidator_MinLength(v) { .MinLength = { .tag = AzTextInputValidatorTag_MinLength, .payload = v } }
+#define AzTextInputValidator_Custom(v) { .Custom = { .tag = AzTextInputValidatorTag_Custom, .payload = v } }
 #define AzRawImageData_U8(v) { .U8 = { .tag = AzRawImageDataTag_U8, .payload = v } }
 #define AzRawImageData_U16(v) { .U16 = { .tag = AzRawImageDataTag_U16, .payload = v } }
 #define AzRawImageData_F32(v) { .F32 = { .tag = AzRawImageDataTag_F32, .payload = v } }
@@ -10247,12 +11679,14 @@ typedef struct AzCss AzCss;
 #define AzInstant_Tick(v) { .Tick = { .tag = AzInstantTag_Tick, .payload = v } }
 #define AzThreadReceiveMsg_WriteBack(v) { .WriteBack = { .tag = AzThreadReceiveMsgTag_WriteBack, .payload = v } }
 #define AzThreadReceiveMsg_Update(v) { .Update = { .tag = AzThreadReceiveMsgTag_Update, .payload = v } }
+#define AzOptionVirtualListIFrameNodes_None { .None = { .tag = AzOptionVirtualListIFrameNodesTag_None } }
+#define AzOptionVirtualListIFrameNodes_Some(v) { .Some = { .tag = AzOptionVirtualListIFrameNodesTag_Some, .payload = v } }
 #define AzOptionVirtualKeyCodeCombo_None { .None = { .tag = AzOptionVirtualKeyCodeComboTag_None } }
 #define AzOptionVirtualKeyCodeCombo_Some(v) { .Some = { .tag = AzOptionVirtualKeyCodeComboTag_Some, .payload = v } }
+#define AzOptionDragData_None { .None = { .tag = AzOptionDragDataTag_None } }
+#define AzOptionDragData_Some(v) { .Some = { .tag = AzOptionDragDataTag_Some, .payload = v } }
 #define AzOptionMouseState_None { .None = { .tag = AzOptionMouseStateTag_None } }
 #define AzOptionMouseState_Some(v) { .Some = { .tag = AzOptionMouseStateTag_Some, .payload = v } }
-#define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
-#define AzOptionKeyboardState_Some(v) { .Some = { .tag = AzOptionKeyboardStateTag_Some, .payload = v } }
 #define AzOptionStringVec_None { .None = { .tag = AzOptionStringVecTag_None } }
 #define AzOptionStringVec_Some(v) { .Some = { .tag = AzOptionStringVecTag_Some, .payload = v } }
 #define AzOptionThreadReceiveMsg_None { .None = { .tag = AzOptionThreadReceiveMsgTag_None } }
@@ -10306,6 +11740,8 @@ typedef struct AzCss AzCss;
 #define AzStyleTransformVecValue_Inherit { .Inherit = { .tag = AzStyleTransformVecValueTag_Inherit } }
 #define AzStyleTransformVecValue_Initial { .Initial = { .tag = AzStyleTransformVecValueTag_Initial } }
 #define AzStyleTransformVecValue_Exact(v) { .Exact = { .tag = AzStyleTransformVecValueTag_Exact, .payload = v } }
+#define AzTextInputValidationResult_Valid { .Valid = { .tag = AzTextInputValidationResultTag_Valid } }
+#define AzTextInputValidationResult_Invalid(v) { .Invalid = { .tag = AzTextInputValidationResultTag_Invalid, .payload = v } }
 #define AzSvgStyle_Fill(v) { .Fill = { .tag = AzSvgStyleTag_Fill, .payload = v } }
 #define AzSvgStyle_Stroke(v) { .Stroke = { .tag = AzSvgStyleTag_Stroke, .payload = v } }
 #define AzFmtValue_Bool(v) { .Bool = { .tag = AzFmtValueTag_Bool, .payload = v } }
@@ -10323,6 +11759,10 @@ typedef struct AzCss AzCss;
 #define AzFmtValue_Double(v) { .Double = { .tag = AzFmtValueTag_Double, .payload = v } }
 #define AzFmtValue_Str(v) { .Str = { .tag = AzFmtValueTag_Str, .payload = v } }
 #define AzFmtValue_StrVec(v) { .StrVec = { .tag = AzFmtValueTag_StrVec, .payload = v } }
+#define AzOptionTextInputMask_None { .None = { .tag = AzOptionTextInputMaskTag_None } }
+#define AzOptionTextInputMask_Some(v) { .Some = { .tag = AzOptionTextInputMaskTag_Some, .payload = v } }
+#define AzOptionImeComposition_None { .None = { .tag = AzOptionImeCompositionTag_None } }
+#define AzOptionImeComposition_Some(v) { .Some = { .tag = AzOptionImeCompositionTag_Some, .payload = v } }
 #define AzOptionFileTypeList_None { .None = { .tag = AzOptionFileTypeListTag_None } }
 #define AzOptionFileTypeList_Some(v) { .Some = { .tag = AzOptionFileTypeListTag_Some, .payload = v } }
 #define AzOptionFile_None { .None = { .tag = AzOptionFileTag_None } }
@@ -10433,6 +11873,8 @@ typedef struct AzCss AzCss;
 #define AzCssPropertySource_Inline { .Inline = { .tag = AzCssPropertySourceTag_Inline } }
 #define AzOptionCssProperty_None { .None = { .tag = AzOptionCssPropertyTag_None } }
 #define AzOptionCssProperty_Some(v) { .Some = { .tag = AzOptionCssPropertyTag_Some, .payload = v } }
+#define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
+#define AzOptionKeyboardState_Some(v) { .Some = { .tag = AzOptionKeyboardStateTag_Some, .payload = v } }
 #define AzNodeDataInlineCssProperty_Normal(v) { .Normal = { .tag = AzNodeDataInlineCssPropertyTag_Normal, .payload = v } }
 #define AzNodeDataInlineCssProperty_Active(v) { .Active = { .tag = AzNodeDataInlineCssPropertyTag_Active, .payload = v } }
 #define AzNodeDataInlineCssProperty_Focus(v) { .Focus = { .tag = AzNodeDataInlineCssPropertyTag_Focus, .payload = v } }
@@ -10517,6 +11959,10 @@ AzXmlNode AzXmlNodeVecArray[] = {};
 #define AzXmlNodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzXmlNode), .cap = sizeof(v) / sizeof(AzXmlNode), .destructor = { .NoDestructor = { .tag = AzXmlNodeVecDestructorTag_NoDestructor, }, }, }
 #define AzXmlNodeVec_empty { .ptr = &AzXmlNodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzXmlNodeVecDestructorTag_NoDestructor, }, }, }
 
+AzXmlSpan AzXmlSpanVecArray[] = {};
+#define AzXmlSpanVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzXmlSpan), .cap = sizeof(v) / sizeof(AzXmlSpan), .destructor = { .NoDestructor = { .tag = AzXmlSpanVecDestructorTag_NoDestructor, }, }, }
+#define AzXmlSpanVec_empty { .ptr = &AzXmlSpanVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzXmlSpanVecDestructorTag_NoDestructor, }, }, }
+
 AzFmtArg AzFmtArgVecArray[] = {};
 #define AzFmtArgVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzFmtArg), .cap = sizeof(v) / sizeof(AzFmtArg), .destructor = { .NoDestructor = { .tag = AzFmtArgVecDestructorTag_NoDestructor, }, }, }
 #define AzFmtArgVec_empty { .ptr = &AzFmtArgVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzFmtArgVecDestructorTag_NoDestructor, }, }, }
@@ -10621,6 +12067,42 @@ uint32_t AzScanCodeVecArray[] = {};
 #define AzScanCodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(uint32_t), .cap = sizeof(v) / sizeof(uint32_t), .destructor = { .NoDestructor = { .tag = AzScanCodeVecDestructorTag_NoDestructor, }, }, }
 #define AzScanCodeVec_empty { .ptr = &AzScanCodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzScanCodeVecDestructorTag_NoDestructor, }, }, }
 
+AzTextInputValidator AzTextInputValidatorVecArray[] = {};
+#define AzTextInputValidatorVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTextInputValidator), .cap = sizeof(v) / sizeof(AzTextInputValidator), .destructor = { .NoDestructor = { .tag = AzTextInputValidatorVecDestructorTag_NoDestructor, }, }, }
+#define AzTextInputValidatorVec_empty { .ptr = &AzTextInputValidatorVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTextInputValidatorVecDestructorTag_NoDestructor, }, }, }
+
+AzDropDownChoice AzDropDownChoiceVecArray[] = {};
+#define AzDropDownChoiceVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzDropDownChoice), .cap = sizeof(v) / sizeof(AzDropDownChoice), .destructor = { .NoDestructor = { .tag = AzDropDownChoiceVecDestructorTag_NoDestructor, }, }, }
+#define AzDropDownChoiceVec_empty { .ptr = &AzDropDownChoiceVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzDropDownChoiceVecDestructorTag_NoDestructor, }, }, }
+
+AzTabContainerTab AzTabContainerTabVecArray[] = {};
+#define AzTabContainerTabVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTabContainerTab), .cap = sizeof(v) / sizeof(AzTabContainerTab), .destructor = { .NoDestructor = { .tag = AzTabContainerTabVecDestructorTag_NoDestructor, }, }, }
+#define AzTabContainerTabVec_empty { .ptr = &AzTabContainerTabVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTabContainerTabVecDestructorTag_NoDestructor, }, }, }
+
+AzTextEditUndoEntry AzTextEditUndoEntryVecArray[] = {};
+#define AzTextEditUndoEntryVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTextEditUndoEntry), .cap = sizeof(v) / sizeof(AzTextEditUndoEntry), .destructor = { .NoDestructor = { .tag = AzTextEditUndoEntryVecDestructorTag_NoDestructor, }, }, }
+#define AzTextEditUndoEntryVec_empty { .ptr = &AzTextEditUndoEntryVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTextEditUndoEntryVecDestructorTag_NoDestructor, }, }, }
+
+AzTreeViewNodeData AzTreeViewNodeDataVecArray[] = {};
+#define AzTreeViewNodeDataVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTreeViewNodeData), .cap = sizeof(v) / sizeof(AzTreeViewNodeData), .destructor = { .NoDestructor = { .tag = AzTreeViewNodeDataVecDestructorTag_NoDestructor, }, }, }
+#define AzTreeViewNodeDataVec_empty { .ptr = &AzTreeViewNodeDataVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTreeViewNodeDataVecDestructorTag_NoDestructor, }, }, }
+
+AzTreeViewNode AzTreeViewNodeVecArray[] = {};
+#define AzTreeViewNodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTreeViewNode), .cap = sizeof(v) / sizeof(AzTreeViewNode), .destructor = { .NoDestructor = { .tag = AzTreeViewNodeVecDestructorTag_NoDestructor, }, }, }
+#define AzTreeViewNodeVec_empty { .ptr = &AzTreeViewNodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTreeViewNodeVecDestructorTag_NoDestructor, }, }, }
+
+AzTreeViewRow AzTreeViewRowVecArray[] = {};
+#define AzTreeViewRowVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTreeViewRow), .cap = sizeof(v) / sizeof(AzTreeViewRow), .destructor = { .NoDestructor = { .tag = AzTreeViewRowVecDestructorTag_NoDestructor, }, }, }
+#define AzTreeViewRowVec_empty { .ptr = &AzTreeViewRowVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTreeViewRowVecDestructorTag_NoDestructor, }, }, }
+
+AzTreeViewRenderedRow AzTreeViewRenderedRowVecArray[] = {};
+#define AzTreeViewRenderedRowVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTreeViewRenderedRow), .cap = sizeof(v) / sizeof(AzTreeViewRenderedRow), .destructor = { .NoDestructor = { .tag = AzTreeViewRenderedRowVecDestructorTag_NoDestructor, }, }, }
+#define AzTreeViewRenderedRowVec_empty { .ptr = &AzTreeViewRenderedRowVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTreeViewRenderedRowVecDestructorTag_NoDestructor, }, }, }
+
+AzVirtualListMeasuredRow AzVirtualListMeasuredRowVecArray[] = {};
+#define AzVirtualListMeasuredRowVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzVirtualListMeasuredRow), .cap = sizeof(v) / sizeof(AzVirtualListMeasuredRow), .destructor = { .NoDestructor = { .tag = AzVirtualListMeasuredRowVecDestructorTag_NoDestructor, }, }, }
+#define AzVirtualListMeasuredRowVec_empty { .ptr = &AzVirtualListMeasuredRowVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzVirtualListMeasuredRowVecDestructorTag_NoDestructor, }, }, }
+
 AzCssDeclaration AzCssDeclarationVecArray[] = {};
 #define AzCssDeclarationVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssDeclaration), .cap = sizeof(v) / sizeof(AzCssDeclaration), .destructor = { .NoDestructor = { .tag = AzCssDeclarationVecDestructorTag_NoDestructor, }, }, }
 #define AzCssDeclarationVec_empty { .ptr = &AzCssDeclarationVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssDeclarationVecDestructorTag_NoDestructor, }, }, }
@@ -10728,6 +12210,7 @@ extern DLLIMPORT void AzWindowsWindowOptions_delete(AzWindowsWindowOptions* rest
 extern DLLIMPORT void AzWaylandTheme_delete(AzWaylandTheme* restrict instance);
 extern DLLIMPORT void AzStringPair_delete(AzStringPair* restrict instance);
 extern DLLIMPORT void AzLinuxWindowOptions_delete(AzLinuxWindowOptions* restrict instance);
+extern DLLIMPORT void AzImeComposition_delete(AzImeComposition* restrict instance);
 extern DLLIMPORT void AzMonitor_delete(AzMonitor* restrict instance);
 extern DLLIMPORT AzWindowState AzWindowState_new(AzLayoutCallbackType  layout_callback);
 extern DLLIMPORT AzWindowState AzWindowState_default();
@@ -10735,6 +12218,7 @@ extern DLLIMPORT void AzWindowState_delete(AzWindowState* restrict instance);
 extern DLLIMPORT void AzLayoutCallback_delete(AzLayoutCallback* restrict instance);
 extern DLLIMPORT void AzMarshaledLayoutCallback_delete(AzMarshaledLayoutCallback* restrict instance);
 extern DLLIMPORT AzDomNodeId AzCallbackInfo_getHitNode(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionDomNodeId AzCallbackInfo_getFocusedNode(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzGetSystemTimeFn AzCallbackInfo_getSystemTimeFn(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getCursorRelativeToViewport(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getCursorRelativeToNode(const AzCallbackInfo* callbackinfo);
@@ -10781,6 +12265,8 @@ extern DLLIMPORT bool  AzCallbackInfo_stopTimer(AzCallbackInfo* restrict callbac
 extern DLLIMPORT AzOptionThreadId AzCallbackInfo_startThread(AzCallbackInfo* restrict callbackinfo, AzRefAny  thread_initialize_data, AzRefAny  writeback_data, AzThreadCallback  callback);
 extern DLLIMPORT bool  AzCallbackInfo_sendThreadMsg(AzCallbackInfo* restrict callbackinfo, AzThreadId  thread_id, AzThreadSendMsg  msg);
 extern DLLIMPORT bool  AzCallbackInfo_stopThread(AzCallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
+extern DLLIMPORT bool  AzCallbackInfo_startDrag(AzCallbackInfo* restrict callbackinfo, AzRefAny  payload, AzOptionImageRef  ghost);
+extern DLLIMPORT AzOptionDragData AzCallbackInfo_getDragData(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT void AzCallbackInfo_delete(AzCallbackInfo* restrict instance);
 extern DLLIMPORT AzLogicalSize AzHidpiAdjustedBounds_getLogicalSize(const AzHidpiAdjustedBounds* hidpiadjustedbounds);
 extern DLLIMPORT AzPhysicalSizeU32 AzHidpiAdjustedBounds_getPhysicalSize(const AzHidpiAdjustedBounds* hidpiadjustedbounds);
@@ -10791,6 +12277,7 @@ extern DLLIMPORT void AzInlineLine_delete(AzInlineLine* restrict instance);
 extern DLLIMPORT void AzInlineWord_delete(AzInlineWord* restrict instance);
 extern DLLIMPORT void AzInlineTextContents_delete(AzInlineTextContents* restrict instance);
 extern DLLIMPORT void AzFocusTarget_delete(AzFocusTarget* restrict instance);
+extern DLLIMPORT void AzDragData_delete(AzDragData* restrict instance);
 extern DLLIMPORT void AzFocusTargetPath_delete(AzFocusTargetPath* restrict instance);
 extern DLLIMPORT void AzAnimation_delete(AzAnimation* restrict instance);
 extern DLLIMPORT void AzIFrameCallbackReturn_delete(AzIFrameCallbackReturn* restrict instance);
@@ -10862,6 +12349,12 @@ extern DLLIMPORT void AzDom_setClipMask(AzDom* restrict dom, AzImageMask  clip_m
 extern DLLIMPORT AzDom AzDom_withClipMask(AzDom* restrict dom, AzImageMask  clip_mask);
 extern DLLIMPORT void AzDom_setTabIndex(AzDom* restrict dom, AzTabIndex  tab_index);
 extern DLLIMPORT AzDom AzDom_withTabIndex(AzDom* restrict dom, AzTabIndex  tab_index);
+extern DLLIMPORT void AzDom_setKey(AzDom* restrict dom, uint64_t key);
+extern DLLIMPORT AzDom AzDom_withKey(AzDom* restrict dom, uint64_t key);
+extern DLLIMPORT void AzDom_setFocusScope(AzDom* restrict dom, AzFocusScope  focus_scope);
+extern DLLIMPORT AzDom AzDom_withFocusScope(AzDom* restrict dom, AzFocusScope  focus_scope);
+extern DLLIMPORT void AzDom_addShortcut(AzDom* restrict dom, AzVirtualKeyCodeCombo  combo, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT AzDom AzDom_withShortcut(AzDom* restrict dom, AzVirtualKeyCodeCombo  combo, AzRefAny  data, AzCallbackType  callback);
 extern DLLIMPORT void AzDom_setAccessibilityInfo(AzDom* restrict dom, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT AzDom AzDom_withAccessibilityInfo(AzDom* restrict dom, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT void AzDom_setMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
@@ -10897,6 +12390,9 @@ extern DLLIMPORT void AzNodeData_setInlineCssProps(AzNodeData* restrict nodedata
 extern DLLIMPORT AzNodeData AzNodeData_withInlineCssProps(AzNodeData* restrict nodedata, AzNodeDataInlineCssPropertyVec  css_properties);
 extern DLLIMPORT void AzNodeData_setClipMask(AzNodeData* restrict nodedata, AzImageMask  image_mask);
 extern DLLIMPORT void AzNodeData_setTabIndex(AzNodeData* restrict nodedata, AzTabIndex  tab_index);
+extern DLLIMPORT void AzNodeData_setKey(AzNodeData* restrict nodedata, uint64_t key);
+extern DLLIMPORT void AzNodeData_setFocusScope(AzNodeData* restrict nodedata, AzFocusScope  focus_scope);
+extern DLLIMPORT void AzNodeData_addShortcut(AzNodeData* restrict nodedata, AzVirtualKeyCodeCombo  combo, AzRefAny  data, AzCallbackType  callback);
 extern DLLIMPORT void AzNodeData_setAccessibilityInfo(AzNodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT void AzNodeData_setMenuBar(AzNodeData* restrict nodedata, AzMenu  menu_bar);
 extern DLLIMPORT void AzNodeData_setContextMenu(AzNodeData* restrict nodedata, AzMenu  context_menu);
@@ -10967,6 +12463,26 @@ extern DLLIMPORT AzDom AzCheckBox_dom(AzCheckBox* restrict checkbox);
 extern DLLIMPORT void AzCheckBox_delete(AzCheckBox* restrict instance);
 extern DLLIMPORT void AzCheckBoxStateWrapper_delete(AzCheckBoxStateWrapper* restrict instance);
 extern DLLIMPORT void AzCheckBoxOnToggle_delete(AzCheckBoxOnToggle* restrict instance);
+extern DLLIMPORT AzDropDown AzDropDown_new(AzDropDownChoiceVec  choices);
+extern DLLIMPORT AzDropDown AzDropDown_fromChoicesStr(AzString  choices);
+extern DLLIMPORT void AzDropDown_setSelected(AzDropDown* restrict dropdown, AzOptionUsize  selected);
+extern DLLIMPORT AzDropDown AzDropDown_withSelected(AzDropDown* restrict dropdown, size_t selected);
+extern DLLIMPORT AzDropDown AzDropDown_withSelectedLabel(AzDropDown* restrict dropdown, AzString  label);
+extern DLLIMPORT void AzDropDown_setOnChoiceChange(AzDropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+extern DLLIMPORT AzDropDown AzDropDown_withOnChoiceChange(AzDropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+extern DLLIMPORT void AzDropDown_setContainerStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzDropDown_setHeaderStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzDropDown_setPopupStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzDropDown_setChoiceStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzDropDown_setGroupStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzDropDown_dom(AzDropDown* restrict dropdown);
+extern DLLIMPORT void AzDropDown_delete(AzDropDown* restrict instance);
+extern DLLIMPORT void AzDropDownStateWrapper_delete(AzDropDownStateWrapper* restrict instance);
+extern DLLIMPORT bool  AzDropDownState_isSelectable(const AzDropDownState* dropdownstate, size_t index);
+extern DLLIMPORT void AzDropDownState_delete(AzDropDownState* restrict instance);
+extern DLLIMPORT AzDropDownChoice AzDropDownChoice_new(AzString  label);
+extern DLLIMPORT void AzDropDownChoice_delete(AzDropDownChoice* restrict instance);
+extern DLLIMPORT void AzDropDownOnChoiceChange_delete(AzDropDownOnChoiceChange* restrict instance);
 extern DLLIMPORT AzLabel AzLabel_new(AzString  text);
 extern DLLIMPORT AzDom AzLabel_dom(AzLabel* restrict label);
 extern DLLIMPORT void AzLabel_delete(AzLabel* restrict instance);
@@ -10990,14 +12506,35 @@ extern DLLIMPORT void AzTextInput_setContainerStyle(AzTextInput* restrict textin
 extern DLLIMPORT AzTextInput AzTextInput_withContainerStyle(AzTextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  container_style);
 extern DLLIMPORT void AzTextInput_setLabelStyle(AzTextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  label_style);
 extern DLLIMPORT AzTextInput AzTextInput_withLabelStyle(AzTextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  label_style);
+extern DLLIMPORT void AzTextInput_setOnValidationChange(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnValidationChangeCallbackType  callback);
+extern DLLIMPORT void AzTextInput_addValidator(AzTextInput* restrict textinput, AzTextInputValidator  validator);
+extern DLLIMPORT AzTextInput AzTextInput_withValidator(AzTextInput* restrict textinput, AzTextInputValidator  validator);
+extern DLLIMPORT void AzTextInput_setMask(AzTextInput* restrict textinput, AzTextInputMask  mask);
+extern DLLIMPORT AzTextInput AzTextInput_withMask(AzTextInput* restrict textinput, AzTextInputMask  mask);
+extern DLLIMPORT void AzTextInput_setObscureChar(AzTextInput* restrict textinput, AzOptionChar  obscure_char);
+extern DLLIMPORT AzTextInput AzTextInput_withPasswordMode(AzTextInput* restrict textinput);
+extern DLLIMPORT void AzTextInput_setErrorStyle(AzTextInput* restrict textinput, AzCssPropertyVec  error_style);
+extern DLLIMPORT void AzTextInput_setPreeditStyle(AzTextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  preedit_style);
 extern DLLIMPORT AzDom AzTextInput_dom(AzTextInput* restrict textinput);
 extern DLLIMPORT void AzTextInput_delete(AzTextInput* restrict instance);
 extern DLLIMPORT void AzTextInputStateWrapper_delete(AzTextInputStateWrapper* restrict instance);
 extern DLLIMPORT AzString AzTextInputState_getText(const AzTextInputState* textinputstate);
+extern DLLIMPORT AzString AzTextInputState_getDisplayText(const AzTextInputState* textinputstate);
+extern DLLIMPORT AzTextInputValidationResult AzTextInputState_validate(const AzTextInputState* textinputstate);
 extern DLLIMPORT void AzTextInputState_delete(AzTextInputState* restrict instance);
 extern DLLIMPORT void AzTextInputOnTextInput_delete(AzTextInputOnTextInput* restrict instance);
 extern DLLIMPORT void AzTextInputOnVirtualKeyDown_delete(AzTextInputOnVirtualKeyDown* restrict instance);
 extern DLLIMPORT void AzTextInputOnFocusLost_delete(AzTextInputOnFocusLost* restrict instance);
+extern DLLIMPORT void AzTextInputValidationResult_delete(AzTextInputValidationResult* restrict instance);
+extern DLLIMPORT void AzTextInputValidator_delete(AzTextInputValidator* restrict instance);
+extern DLLIMPORT void AzTextInputCustomValidator_delete(AzTextInputCustomValidator* restrict instance);
+extern DLLIMPORT AzTextInputMask AzTextInputMask_new(AzString  pattern);
+extern DLLIMPORT AzTextInputMask AzTextInputMask_date();
+extern DLLIMPORT AzTextInputMask AzTextInputMask_time();
+extern DLLIMPORT AzTextInputMask AzTextInputMask_phone();
+extern DLLIMPORT AzTextInputMask AzTextInputMask_ipv4();
+extern DLLIMPORT void AzTextInputMask_delete(AzTextInputMask* restrict instance);
+extern DLLIMPORT void AzTextInputOnValidationChange_delete(AzTextInputOnValidationChange* restrict instance);
 extern DLLIMPORT AzNumberInput AzNumberInput_new(float number);
 extern DLLIMPORT void AzNumberInput_setOnTextInput(AzNumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
 extern DLLIMPORT AzNumberInput AzNumberInput_withOnTextInput(AzNumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
@@ -11026,6 +12563,178 @@ extern DLLIMPORT void AzProgressBar_setLabelStyle(AzProgressBar* restrict progre
 extern DLLIMPORT AzProgressBar AzProgressBar_withLabelStyle(AzProgressBar* restrict progressbar, AzNodeDataInlineCssPropertyVec  style);
 extern DLLIMPORT AzDom AzProgressBar_dom(AzProgressBar* restrict progressbar);
 extern DLLIMPORT void AzProgressBar_delete(AzProgressBar* restrict instance);
+extern DLLIMPORT AzSplitPane AzSplitPane_new(AzSplitPaneOrientation  orientation, AzDom  first_pane, AzDom  second_pane);
+extern DLLIMPORT void AzSplitPane_setRatio(AzSplitPane* restrict splitpane, float ratio);
+extern DLLIMPORT AzSplitPane AzSplitPane_withRatio(AzSplitPane* restrict splitpane, float ratio);
+extern DLLIMPORT void AzSplitPane_setMinSizes(AzSplitPane* restrict splitpane, float min_first_size, float min_second_size);
+extern DLLIMPORT AzSplitPane AzSplitPane_withMinSizes(AzSplitPane* restrict splitpane, float min_first_size, float min_second_size);
+extern DLLIMPORT void AzSplitPane_setCollapsible(AzSplitPane* restrict splitpane, bool  collapsible);
+extern DLLIMPORT AzSplitPane AzSplitPane_withCollapsible(AzSplitPane* restrict splitpane, bool  collapsible);
+extern DLLIMPORT void AzSplitPane_setCollapsed(AzSplitPane* restrict splitpane, AzSplitPaneCollapsed  collapsed);
+extern DLLIMPORT AzSplitPane AzSplitPane_withCollapsed(AzSplitPane* restrict splitpane, AzSplitPaneCollapsed  collapsed);
+extern DLLIMPORT void AzSplitPane_setOnResize(AzSplitPane* restrict splitpane, AzRefAny  data, AzSplitPaneOnResizeCallbackType  callback);
+extern DLLIMPORT AzSplitPane AzSplitPane_withOnResize(AzSplitPane* restrict splitpane, AzRefAny  data, AzSplitPaneOnResizeCallbackType  callback);
+extern DLLIMPORT void AzSplitPane_setContainerStyle(AzSplitPane* restrict splitpane, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzSplitPane_setPaneStyle(AzSplitPane* restrict splitpane, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzSplitPane_setDividerStyle(AzSplitPane* restrict splitpane, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzSplitPane_dom(AzSplitPane* restrict splitpane);
+extern DLLIMPORT void AzSplitPane_delete(AzSplitPane* restrict instance);
+extern DLLIMPORT void AzSplitPaneStateWrapper_delete(AzSplitPaneStateWrapper* restrict instance);
+extern DLLIMPORT void AzSplitPaneOnResize_delete(AzSplitPaneOnResize* restrict instance);
+extern DLLIMPORT AzTabContainer AzTabContainer_new();
+extern DLLIMPORT AzTabContainer AzTabContainer_fromTitlesStr(AzString  titles, AzDomVec  pages);
+extern DLLIMPORT void AzTabContainer_addTab(AzTabContainer* restrict tabcontainer, AzString  title, AzDom  content);
+extern DLLIMPORT AzTabContainer AzTabContainer_withTab(AzTabContainer* restrict tabcontainer, AzString  title, AzDom  content);
+extern DLLIMPORT void AzTabContainer_setActiveTab(AzTabContainer* restrict tabcontainer, size_t tab);
+extern DLLIMPORT AzTabContainer AzTabContainer_withActiveTab(AzTabContainer* restrict tabcontainer, size_t tab);
+extern DLLIMPORT void AzTabContainer_setClosable(AzTabContainer* restrict tabcontainer, bool  closable);
+extern DLLIMPORT AzTabContainer AzTabContainer_withClosable(AzTabContainer* restrict tabcontainer, bool  closable);
+extern DLLIMPORT void AzTabContainer_setOverflow(AzTabContainer* restrict tabcontainer, AzTabContainerOverflow  overflow);
+extern DLLIMPORT AzTabContainer AzTabContainer_withOverflow(AzTabContainer* restrict tabcontainer, AzTabContainerOverflow  overflow);
+extern DLLIMPORT void AzTabContainer_setAllowReorder(AzTabContainer* restrict tabcontainer, bool  allow_reorder);
+extern DLLIMPORT AzTabContainer AzTabContainer_withAllowReorder(AzTabContainer* restrict tabcontainer, bool  allow_reorder);
+extern DLLIMPORT void AzTabContainer_setOnTabChange(AzTabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabChangeCallbackType  callback);
+extern DLLIMPORT AzTabContainer AzTabContainer_withOnTabChange(AzTabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabChangeCallbackType  callback);
+extern DLLIMPORT void AzTabContainer_setOnTabClose(AzTabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabCloseCallbackType  callback);
+extern DLLIMPORT AzTabContainer AzTabContainer_withOnTabClose(AzTabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabCloseCallbackType  callback);
+extern DLLIMPORT void AzTabContainer_setOnTabMove(AzTabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabMoveCallbackType  callback);
+extern DLLIMPORT AzTabContainer AzTabContainer_withOnTabMove(AzTabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabMoveCallbackType  callback);
+extern DLLIMPORT void AzTabContainer_setContainerStyle(AzTabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTabContainer_setHeaderStyle(AzTabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTabContainer_setTabStyle(AzTabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTabContainer_setCloseButtonStyle(AzTabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTabContainer_setButtonStyle(AzTabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTabContainer_setMenuStyle(AzTabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTabContainer_setPageStyle(AzTabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzTabContainer_dom(AzTabContainer* restrict tabcontainer);
+extern DLLIMPORT void AzTabContainer_delete(AzTabContainer* restrict instance);
+extern DLLIMPORT void AzTabContainerStateWrapper_delete(AzTabContainerStateWrapper* restrict instance);
+extern DLLIMPORT void AzTabContainerState_delete(AzTabContainerState* restrict instance);
+extern DLLIMPORT void AzTabContainerTab_delete(AzTabContainerTab* restrict instance);
+extern DLLIMPORT void AzTabContainerOnTabChange_delete(AzTabContainerOnTabChange* restrict instance);
+extern DLLIMPORT void AzTabContainerOnTabClose_delete(AzTabContainerOnTabClose* restrict instance);
+extern DLLIMPORT void AzTabContainerOnTabMove_delete(AzTabContainerOnTabMove* restrict instance);
+extern DLLIMPORT AzSlider AzSlider_new(float value, float min, float max);
+extern DLLIMPORT AzSlider AzSlider_newRange(float lower, float upper, float min, float max);
+extern DLLIMPORT void AzSlider_setOrientation(AzSlider* restrict slider, AzSliderOrientation  orientation);
+extern DLLIMPORT AzSlider AzSlider_withOrientation(AzSlider* restrict slider, AzSliderOrientation  orientation);
+extern DLLIMPORT void AzSlider_setStep(AzSlider* restrict slider, float step);
+extern DLLIMPORT AzSlider AzSlider_withStep(AzSlider* restrict slider, float step);
+extern DLLIMPORT void AzSlider_setTickInterval(AzSlider* restrict slider, AzOptionF32  tick_interval);
+extern DLLIMPORT AzSlider AzSlider_withTickInterval(AzSlider* restrict slider, float tick_interval);
+extern DLLIMPORT void AzSlider_setOnValueChange(AzSlider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT AzSlider AzSlider_withOnValueChange(AzSlider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT void AzSlider_setContainerStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzSlider_setTrackStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzSlider_setFillStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzSlider_setThumbStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzSlider_setTickStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzSlider_dom(AzSlider* restrict slider);
+extern DLLIMPORT void AzSlider_delete(AzSlider* restrict instance);
+extern DLLIMPORT void AzSliderStateWrapper_delete(AzSliderStateWrapper* restrict instance);
+extern DLLIMPORT void AzSliderOnValueChange_delete(AzSliderOnValueChange* restrict instance);
+extern DLLIMPORT AzTextEdit AzTextEdit_new(AzString  text);
+extern DLLIMPORT AzTextEdit AzTextEdit_fromState(AzTextEditState  state);
+extern DLLIMPORT void AzTextEdit_setVisibleRows(AzTextEdit* restrict textedit, size_t rows);
+extern DLLIMPORT AzTextEdit AzTextEdit_withVisibleRows(AzTextEdit* restrict textedit, size_t rows);
+extern DLLIMPORT void AzTextEdit_setWrapLines(AzTextEdit* restrict textedit, bool  wrap_lines);
+extern DLLIMPORT AzTextEdit AzTextEdit_withWrapLines(AzTextEdit* restrict textedit, bool  wrap_lines);
+extern DLLIMPORT void AzTextEdit_setOnTextChange(AzTextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+extern DLLIMPORT AzTextEdit AzTextEdit_withOnTextChange(AzTextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+extern DLLIMPORT void AzTextEdit_setContainerStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTextEdit_setRowStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTextEdit_setLabelStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTextEdit_setSelectionStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTextEdit_setCaretStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzTextEdit_dom(AzTextEdit* restrict textedit);
+extern DLLIMPORT void AzTextEdit_delete(AzTextEdit* restrict instance);
+extern DLLIMPORT void AzTextEditStateWrapper_delete(AzTextEditStateWrapper* restrict instance);
+extern DLLIMPORT AzString AzTextEditState_getText(const AzTextEditState* texteditstate);
+extern DLLIMPORT void AzTextEditState_delete(AzTextEditState* restrict instance);
+extern DLLIMPORT void AzTextEditUndoEntry_delete(AzTextEditUndoEntry* restrict instance);
+extern DLLIMPORT void AzTextEditOnTextChange_delete(AzTextEditOnTextChange* restrict instance);
+extern DLLIMPORT AzTreeView AzTreeView_new(AzTreeViewState  state);
+extern DLLIMPORT AzTreeView AzTreeView_fromPathsStr(AzString  paths);
+extern DLLIMPORT void AzTreeView_setVisibleRows(AzTreeView* restrict treeview, size_t rows);
+extern DLLIMPORT AzTreeView AzTreeView_withVisibleRows(AzTreeView* restrict treeview, size_t rows);
+extern DLLIMPORT void AzTreeView_setSelectionMode(AzTreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+extern DLLIMPORT AzTreeView AzTreeView_withSelectionMode(AzTreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+extern DLLIMPORT void AzTreeView_setAllowReorder(AzTreeView* restrict treeview, bool  allow_reorder);
+extern DLLIMPORT AzTreeView AzTreeView_withAllowReorder(AzTreeView* restrict treeview, bool  allow_reorder);
+extern DLLIMPORT void AzTreeView_setOnLoadChildren(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLoadChildrenCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnLoadChildren(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLoadChildrenCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnSelectionChange(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectionChangeCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnSelectionChange(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectionChangeCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnNodeMoved(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnNodeMovedCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnNodeMoved(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnNodeMovedCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setContainerStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTreeView_setRowStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTreeView_setGuidesStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTreeView_setToggleStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTreeView_setIconStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTreeView_setLabelStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTreeView_setScrollbarThumbStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzTreeView_setDropIndicatorStyle(AzTreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzTreeView_dom(AzTreeView* restrict treeview);
+extern DLLIMPORT void AzTreeView_delete(AzTreeView* restrict instance);
+extern DLLIMPORT void AzTreeViewStateWrapper_delete(AzTreeViewStateWrapper* restrict instance);
+extern DLLIMPORT AzTreeViewState AzTreeViewState_new();
+extern DLLIMPORT size_t AzTreeViewState_addNode(AzTreeViewState* restrict treeviewstate, AzOptionUsize  parent, AzTreeViewNodeData  data);
+extern DLLIMPORT void AzTreeViewState_setChildren(AzTreeViewState* restrict treeviewstate, size_t node_id, AzTreeViewNodeDataVec  children);
+extern DLLIMPORT void AzTreeViewState_updateRows(AzTreeViewState* restrict treeviewstate);
+extern DLLIMPORT bool  AzTreeViewState_expand(AzTreeViewState* restrict treeviewstate, size_t node_id);
+extern DLLIMPORT void AzTreeViewState_collapse(AzTreeViewState* restrict treeviewstate, size_t node_id);
+extern DLLIMPORT void AzTreeViewState_reveal(AzTreeViewState* restrict treeviewstate, size_t node_id);
+extern DLLIMPORT bool  AzTreeViewState_isExpanded(const AzTreeViewState* treeviewstate, size_t node_id);
+extern DLLIMPORT void AzTreeViewState_clearSelection(AzTreeViewState* restrict treeviewstate);
+extern DLLIMPORT void AzTreeViewState_setSelected(AzTreeViewState* restrict treeviewstate, size_t node_id, bool  selected);
+extern DLLIMPORT void AzTreeViewState_select(AzTreeViewState* restrict treeviewstate, size_t node_id, bool  extend, bool  toggle);
+extern DLLIMPORT bool  AzTreeViewState_moveNode(AzTreeViewState* restrict treeviewstate, size_t node_id, AzTreeViewDropTarget  target);
+extern DLLIMPORT void AzTreeViewState_delete(AzTreeViewState* restrict instance);
+extern DLLIMPORT void AzTreeViewNode_delete(AzTreeViewNode* restrict instance);
+extern DLLIMPORT AzTreeViewNodeData AzTreeViewNodeData_new(AzString  label);
+extern DLLIMPORT AzTreeViewNodeData AzTreeViewNodeData_withIcon(AzTreeViewNodeData* restrict treeviewnodedata, AzImageRef  icon);
+extern DLLIMPORT AzTreeViewNodeData AzTreeViewNodeData_withLazyChildren(AzTreeViewNodeData* restrict treeviewnodedata);
+extern DLLIMPORT void AzTreeViewNodeData_delete(AzTreeViewNodeData* restrict instance);
+extern DLLIMPORT void AzTreeViewRenderedRow_delete(AzTreeViewRenderedRow* restrict instance);
+extern DLLIMPORT void AzTreeViewLoadRequest_delete(AzTreeViewLoadRequest* restrict instance);
+extern DLLIMPORT AzTreeViewLoadedChildren AzTreeViewLoadedChildren_new(size_t node, AzTreeViewNodeDataVec  children);
+extern DLLIMPORT AzThreadWriteBackMsg AzTreeViewLoadedChildren_intoWritebackMsg(AzTreeViewLoadedChildren* restrict treeviewloadedchildren);
+extern DLLIMPORT void AzTreeViewLoadedChildren_delete(AzTreeViewLoadedChildren* restrict instance);
+extern DLLIMPORT void AzTreeViewOnLoadChildren_delete(AzTreeViewOnLoadChildren* restrict instance);
+extern DLLIMPORT void AzTreeViewOnSelectionChange_delete(AzTreeViewOnSelectionChange* restrict instance);
+extern DLLIMPORT void AzTreeViewOnNodeMoved_delete(AzTreeViewOnNodeMoved* restrict instance);
+extern DLLIMPORT AzVirtualList AzVirtualList_new(size_t row_count, float row_height, AzRefAny  data, AzVirtualListRenderRowCallbackType  render_row);
+extern DLLIMPORT AzVirtualList AzVirtualList_fromState(AzRefAny  state);
+extern DLLIMPORT AzRefAny AzVirtualList_getState(const AzVirtualList* virtuallist);
+extern DLLIMPORT void AzVirtualList_setRowCount(AzVirtualList* restrict virtuallist, size_t row_count);
+extern DLLIMPORT AzVirtualList AzVirtualList_withRowCount(AzVirtualList* restrict virtuallist, size_t row_count);
+extern DLLIMPORT void AzVirtualList_setRowHeight(AzVirtualList* restrict virtuallist, AzVirtualListRowHeight  row_height);
+extern DLLIMPORT AzVirtualList AzVirtualList_withRowHeight(AzVirtualList* restrict virtuallist, AzVirtualListRowHeight  row_height);
+extern DLLIMPORT void AzVirtualList_setScrollOffset(AzVirtualList* restrict virtuallist, float scroll_offset);
+extern DLLIMPORT AzVirtualList AzVirtualList_withScrollOffset(AzVirtualList* restrict virtuallist, float scroll_offset);
+extern DLLIMPORT void AzVirtualList_setOverscan(AzVirtualList* restrict virtuallist, size_t overscan);
+extern DLLIMPORT AzVirtualList AzVirtualList_withOverscan(AzVirtualList* restrict virtuallist, size_t overscan);
+extern DLLIMPORT void AzVirtualList_setOnScroll(AzVirtualList* restrict virtuallist, AzRefAny  data, AzVirtualListOnScrollCallbackType  callback);
+extern DLLIMPORT AzVirtualList AzVirtualList_withOnScroll(AzVirtualList* restrict virtuallist, AzRefAny  data, AzVirtualListOnScrollCallbackType  callback);
+extern DLLIMPORT void AzVirtualList_setContainerStyle(AzVirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzVirtualList_setRowStyle(AzVirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzVirtualList_setScrollbarTrackStyle(AzVirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzVirtualList_setScrollbarThumbStyle(AzVirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzVirtualList_dom(AzVirtualList* restrict virtuallist);
+extern DLLIMPORT void AzVirtualList_delete(AzVirtualList* restrict instance);
+extern DLLIMPORT void AzVirtualListStateWrapper_delete(AzVirtualListStateWrapper* restrict instance);
+extern DLLIMPORT AzVirtualListState AzVirtualListState_new(size_t row_count, AzVirtualListRowHeight  row_height);
+extern DLLIMPORT float AzVirtualListState_getDefaultRowHeight(const AzVirtualListState* virtualliststate);
+extern DLLIMPORT float AzVirtualListState_getRowHeight(const AzVirtualListState* virtualliststate, size_t row);
+extern DLLIMPORT float AzVirtualListState_getRowTop(const AzVirtualListState* virtualliststate, size_t row);
+extern DLLIMPORT size_t AzVirtualListState_getRowAtOffset(const AzVirtualListState* virtualliststate, float offset);
+extern DLLIMPORT float AzVirtualListState_getTotalHeight(const AzVirtualListState* virtualliststate);
+extern DLLIMPORT float AzVirtualListState_getMaxScrollOffset(const AzVirtualListState* virtualliststate);
+extern DLLIMPORT float AzVirtualListState_clampScrollOffset(const AzVirtualListState* virtualliststate, float scroll_offset);
+extern DLLIMPORT void AzVirtualListState_delete(AzVirtualListState* restrict instance);
+extern DLLIMPORT void AzVirtualListRenderRow_delete(AzVirtualListRenderRow* restrict instance);
+extern DLLIMPORT void AzVirtualListOnScroll_delete(AzVirtualListOnScroll* restrict instance);
 extern DLLIMPORT void AzCssPropertySource_delete(AzCssPropertySource* restrict instance);
 extern DLLIMPORT void AzTagIdToNodeIdMapping_delete(AzTagIdToNodeIdMapping* restrict instance);
 extern DLLIMPORT void AzCssPropertyCache_delete(AzCssPropertyCache* restrict instance);
@@ -11414,6 +13123,7 @@ extern DLLIMPORT AzTessellatedSvgNodeVecRef AzTessellatedSvgNodeVec_asRefVec(con
 extern DLLIMPORT void AzTessellatedSvgNodeVec_delete(AzTessellatedSvgNodeVec* restrict instance);
 extern DLLIMPORT void AzStyleFontFamilyVec_delete(AzStyleFontFamilyVec* restrict instance);
 extern DLLIMPORT void AzXmlNodeVec_delete(AzXmlNodeVec* restrict instance);
+extern DLLIMPORT void AzXmlSpanVec_delete(AzXmlSpanVec* restrict instance);
 extern DLLIMPORT void AzFmtArgVec_delete(AzFmtArgVec* restrict instance);
 extern DLLIMPORT void AzInlineLineVec_delete(AzInlineLineVec* restrict instance);
 extern DLLIMPORT void AzInlineWordVec_delete(AzInlineWordVec* restrict instance);
@@ -11440,6 +13150,15 @@ extern DLLIMPORT void AzXWindowTypeVec_delete(AzXWindowTypeVec* restrict instanc
 extern DLLIMPORT void AzVirtualKeyCodeVec_delete(AzVirtualKeyCodeVec* restrict instance);
 extern DLLIMPORT void AzCascadeInfoVec_delete(AzCascadeInfoVec* restrict instance);
 extern DLLIMPORT void AzScanCodeVec_delete(AzScanCodeVec* restrict instance);
+extern DLLIMPORT void AzTextInputValidatorVec_delete(AzTextInputValidatorVec* restrict instance);
+extern DLLIMPORT void AzDropDownChoiceVec_delete(AzDropDownChoiceVec* restrict instance);
+extern DLLIMPORT void AzTabContainerTabVec_delete(AzTabContainerTabVec* restrict instance);
+extern DLLIMPORT void AzTextEditUndoEntryVec_delete(AzTextEditUndoEntryVec* restrict instance);
+extern DLLIMPORT void AzTreeViewNodeDataVec_delete(AzTreeViewNodeDataVec* restrict instance);
+extern DLLIMPORT void AzTreeViewNodeVec_delete(AzTreeViewNodeVec* restrict instance);
+extern DLLIMPORT void AzTreeViewRowVec_delete(AzTreeViewRowVec* restrict instance);
+extern DLLIMPORT void AzTreeViewRenderedRowVec_delete(AzTreeViewRenderedRowVec* restrict instance);
+extern DLLIMPORT void AzVirtualListMeasuredRowVec_delete(AzVirtualListMeasuredRowVec* restrict instance);
 extern DLLIMPORT void AzCssDeclarationVec_delete(AzCssDeclarationVec* restrict instance);
 extern DLLIMPORT void AzCssPathSelectorVec_delete(AzCssPathSelectorVec* restrict instance);
 extern DLLIMPORT void AzStylesheetVec_delete(AzStylesheetVec* restrict instance);
@@ -11469,12 +13188,27 @@ extern DLLIMPORT void AzOptionCheckBoxOnToggle_delete(AzOptionCheckBoxOnToggle*
 extern DLLIMPORT void AzOptionTextInputOnTextInput_delete(AzOptionTextInputOnTextInput* restrict instance);
 extern DLLIMPORT void AzOptionTextInputOnVirtualKeyDown_delete(AzOptionTextInputOnVirtualKeyDown* restrict instance);
 extern DLLIMPORT void AzOptionTextInputOnFocusLost_delete(AzOptionTextInputOnFocusLost* restrict instance);
+extern DLLIMPORT void AzOptionTextInputMask_delete(AzOptionTextInputMask* restrict instance);
+extern DLLIMPORT void AzOptionTextInputOnValidationChange_delete(AzOptionTextInputOnValidationChange* restrict instance);
+extern DLLIMPORT void AzOptionDropDownOnChoiceChange_delete(AzOptionDropDownOnChoiceChange* restrict instance);
+extern DLLIMPORT void AzOptionTabContainerOnTabChange_delete(AzOptionTabContainerOnTabChange* restrict instance);
+extern DLLIMPORT void AzOptionTabContainerOnTabClose_delete(AzOptionTabContainerOnTabClose* restrict instance);
+extern DLLIMPORT void AzOptionTabContainerOnTabMove_delete(AzOptionTabContainerOnTabMove* restrict instance);
+extern DLLIMPORT void AzOptionSplitPaneOnResize_delete(AzOptionSplitPaneOnResize* restrict instance);
+extern DLLIMPORT void AzOptionSliderOnValueChange_delete(AzOptionSliderOnValueChange* restrict instance);
+extern DLLIMPORT void AzOptionTextEditOnTextChange_delete(AzOptionTextEditOnTextChange* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnLoadChildren_delete(AzOptionTreeViewOnLoadChildren* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnSelectionChange_delete(AzOptionTreeViewOnSelectionChange* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnNodeMoved_delete(AzOptionTreeViewOnNodeMoved* restrict instance);
+extern DLLIMPORT void AzOptionVirtualListOnScroll_delete(AzOptionVirtualListOnScroll* restrict instance);
 extern DLLIMPORT void AzOptionNumberInputOnValueChange_delete(AzOptionNumberInputOnValueChange* restrict instance);
 extern DLLIMPORT void AzOptionMenuItemIcon_delete(AzOptionMenuItemIcon* restrict instance);
 extern DLLIMPORT void AzOptionMenuCallback_delete(AzOptionMenuCallback* restrict instance);
 extern DLLIMPORT void AzOptionVirtualKeyCodeCombo_delete(AzOptionVirtualKeyCodeCombo* restrict instance);
 extern DLLIMPORT void AzOptionCssProperty_delete(AzOptionCssProperty* restrict instance);
 extern DLLIMPORT void AzOptionImageRef_delete(AzOptionImageRef* restrict instance);
+extern DLLIMPORT void AzOptionDragData_delete(AzOptionDragData* restrict instance);
+extern DLLIMPORT void AzOptionImeComposition_delete(AzOptionImeComposition* restrict instance);
 extern DLLIMPORT void AzOptionFontRef_delete(AzOptionFontRef* restrict instance);
 extern DLLIMPORT void AzOptionSystemClipboard_delete(AzOptionSystemClipboard* restrict instance);
 extern DLLIMPORT void AzOptionFileTypeList_delete(AzOptionFileTypeList* restrict instance);
@@ -13472,6 +15206,20 @@ bool AzTabIndex_matchMutOverrideInParent(AzTabIndex* restrict value, Azu32* rest
     return valid;
 }
 
+bool AzFocusScope_matchRefRoving(const AzFocusScope* value, const AzRovingFocus** restrict out) {
+    const AzFocusScopeVariant_Roving* casted = (const AzFocusScopeVariant_Roving*)value;
+    bool valid = casted->tag == AzFocusScopeTag_Roving;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFocusScope_matchMutRoving(AzFocusScope* restrict value, AzRovingFocus* restrict * restrict out) {
+    AzFocusScopeVariant_Roving* restrict casted = (AzFocusScopeVariant_Roving* restrict)value;
+    bool valid = casted->tag == AzFocusScopeTag_Roving;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzIdOrClass_matchRefId(const AzIdOrClass* value, const AzString** restrict out) {
     const AzIdOrClassVariant_Id* casted = (const AzIdOrClassVariant_Id*)value;
     bool valid = casted->tag == AzIdOrClassTag_Id;
@@ -16132,6 +17880,76 @@ bool AzTextInputSelection_matchMutFromTo(AzTextInputSelection* restrict value, A
     return valid;
 }
 
+bool AzTextInputValidationResult_matchRefInvalid(const AzTextInputValidationResult* value, const AzString** restrict out) {
+    const AzTextInputValidationResultVariant_Invalid* casted = (const AzTextInputValidationResultVariant_Invalid*)value;
+    bool valid = casted->tag == AzTextInputValidationResultTag_Invalid;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextInputValidationResult_matchMutInvalid(AzTextInputValidationResult* restrict value, AzString* restrict * restrict out) {
+    AzTextInputValidationResultVariant_Invalid* restrict casted = (AzTextInputValidationResultVariant_Invalid* restrict)value;
+    bool valid = casted->tag == AzTextInputValidationResultTag_Invalid;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextInputValidator_matchRefMinLength(const AzTextInputValidator* value, const Azusize** restrict out) {
+    const AzTextInputValidatorVariant_MinLength* casted = (const AzTextInputValidatorVariant_MinLength*)value;
+    bool valid = casted->tag == AzTextInputValidatorTag_MinLength;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextInputValidator_matchMutMinLength(AzTextInputValidator* restrict value, Azusize* restrict * restrict out) {
+    AzTextInputValidatorVariant_MinLength* restrict casted = (AzTextInputValidatorVariant_MinLength* restrict)value;
+    bool valid = casted->tag == AzTextInputValidatorTag_MinLength;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextInputValidator_matchRefCustom(const AzTextInputValidator* value, const AzTextInputCustomValidator** restrict out) {
+    const AzTextInputValidatorVariant_Custom* casted = (const AzTextInputValidatorVariant_Custom*)value;
+    bool valid = casted->tag == AzTextInputValidatorTag_Custom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextInputValidator_matchMutCustom(AzTextInputValidator* restrict value, AzTextInputCustomValidator* restrict * restrict out) {
+    AzTextInputValidatorVariant_Custom* restrict casted = (AzTextInputValidatorVariant_Custom* restrict)value;
+    bool valid = casted->tag == AzTextInputValidatorTag_Custom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVirtualListRowHeight_matchRefFixed(const AzVirtualListRowHeight* value, const Azf32** restrict out) {
+    const AzVirtualListRowHeightVariant_Fixed* casted = (const AzVirtualListRowHeightVariant_Fixed*)value;
+    bool valid = casted->tag == AzVirtualListRowHeightTag_Fixed;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVirtualListRowHeight_matchMutFixed(AzVirtualListRowHeight* restrict value, Azf32* restrict * restrict out) {
+    AzVirtualListRowHeightVariant_Fixed* restrict casted = (AzVirtualListRowHeightVariant_Fixed* restrict)value;
+    bool valid = casted->tag == AzVirtualListRowHeightTag_Fixed;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVirtualListRowHeight_matchRefEstimated(const AzVirtualListRowHeight* value, const Azf32** restrict out) {
+    const AzVirtualListRowHeightVariant_Estimated* casted = (const AzVirtualListRowHeightVariant_Estimated*)value;
+    bool valid = casted->tag == AzVirtualListRowHeightTag_Estimated;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVirtualListRowHeight_matchMutEstimated(AzVirtualListRowHeight* restrict value, Azf32* restrict * restrict out) {
+    AzVirtualListRowHeightVariant_Estimated* restrict casted = (AzVirtualListRowHeightVariant_Estimated* restrict)value;
+    bool valid = casted->tag == AzVirtualListRowHeightTag_Estimated;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssPropertySource_matchRefCss(const AzCssPropertySource* value, const AzCssPath** restrict out) {
     const AzCssPropertySourceVariant_Css* casted = (const AzCssPropertySourceVariant_Css*)value;
     bool valid = casted->tag == AzCssPropertySourceTag_Css;
@@ -16762,6 +18580,20 @@ bool AzXmlNodeVecDestructor_matchMutExternal(AzXmlNodeVecDestructor* restrict va
     return valid;
 }
 
+bool AzXmlSpanVecDestructor_matchRefExternal(const AzXmlSpanVecDestructor* value, const AzXmlSpanVecDestructorType** restrict out) {
+    const AzXmlSpanVecDestructorVariant_External* casted = (const AzXmlSpanVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzXmlSpanVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzXmlSpanVecDestructor_matchMutExternal(AzXmlSpanVecDestructor* restrict value, AzXmlSpanVecDestructorType* restrict * restrict out) {
+    AzXmlSpanVecDestructorVariant_External* restrict casted = (AzXmlSpanVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzXmlSpanVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzFmtArgVecDestructor_matchRefExternal(const AzFmtArgVecDestructor* value, const AzFmtArgVecDestructorType** restrict out) {
     const AzFmtArgVecDestructorVariant_External* casted = (const AzFmtArgVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzFmtArgVecDestructorTag_External;
@@ -17126,6 +18958,132 @@ bool AzScanCodeVecDestructor_matchMutExternal(AzScanCodeVecDestructor* restrict
     return valid;
 }
 
+bool AzTextInputValidatorVecDestructor_matchRefExternal(const AzTextInputValidatorVecDestructor* value, const AzTextInputValidatorVecDestructorType** restrict out) {
+    const AzTextInputValidatorVecDestructorVariant_External* casted = (const AzTextInputValidatorVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTextInputValidatorVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextInputValidatorVecDestructor_matchMutExternal(AzTextInputValidatorVecDestructor* restrict value, AzTextInputValidatorVecDestructorType* restrict * restrict out) {
+    AzTextInputValidatorVecDestructorVariant_External* restrict casted = (AzTextInputValidatorVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTextInputValidatorVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDropDownChoiceVecDestructor_matchRefExternal(const AzDropDownChoiceVecDestructor* value, const AzDropDownChoiceVecDestructorType** restrict out) {
+    const AzDropDownChoiceVecDestructorVariant_External* casted = (const AzDropDownChoiceVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzDropDownChoiceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDropDownChoiceVecDestructor_matchMutExternal(AzDropDownChoiceVecDestructor* restrict value, AzDropDownChoiceVecDestructorType* restrict * restrict out) {
+    AzDropDownChoiceVecDestructorVariant_External* restrict casted = (AzDropDownChoiceVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzDropDownChoiceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTabContainerTabVecDestructor_matchRefExternal(const AzTabContainerTabVecDestructor* value, const AzTabContainerTabVecDestructorType** restrict out) {
+    const AzTabContainerTabVecDestructorVariant_External* casted = (const AzTabContainerTabVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTabContainerTabVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTabContainerTabVecDestructor_matchMutExternal(AzTabContainerTabVecDestructor* restrict value, AzTabContainerTabVecDestructorType* restrict * restrict out) {
+    AzTabContainerTabVecDestructorVariant_External* restrict casted = (AzTabContainerTabVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTabContainerTabVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextEditUndoEntryVecDestructor_matchRefExternal(const AzTextEditUndoEntryVecDestructor* value, const AzTextEditUndoEntryVecDestructorType** restrict out) {
+    const AzTextEditUndoEntryVecDestructorVariant_External* casted = (const AzTextEditUndoEntryVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTextEditUndoEntryVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextEditUndoEntryVecDestructor_matchMutExternal(AzTextEditUndoEntryVecDestructor* restrict value, AzTextEditUndoEntryVecDestructorType* restrict * restrict out) {
+    AzTextEditUndoEntryVecDestructorVariant_External* restrict casted = (AzTextEditUndoEntryVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTextEditUndoEntryVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewNodeDataVecDestructor_matchRefExternal(const AzTreeViewNodeDataVecDestructor* value, const AzTreeViewNodeDataVecDestructorType** restrict out) {
+    const AzTreeViewNodeDataVecDestructorVariant_External* casted = (const AzTreeViewNodeDataVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTreeViewNodeDataVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewNodeDataVecDestructor_matchMutExternal(AzTreeViewNodeDataVecDestructor* restrict value, AzTreeViewNodeDataVecDestructorType* restrict * restrict out) {
+    AzTreeViewNodeDataVecDestructorVariant_External* restrict casted = (AzTreeViewNodeDataVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTreeViewNodeDataVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewNodeVecDestructor_matchRefExternal(const AzTreeViewNodeVecDestructor* value, const AzTreeViewNodeVecDestructorType** restrict out) {
+    const AzTreeViewNodeVecDestructorVariant_External* casted = (const AzTreeViewNodeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTreeViewNodeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewNodeVecDestructor_matchMutExternal(AzTreeViewNodeVecDestructor* restrict value, AzTreeViewNodeVecDestructorType* restrict * restrict out) {
+    AzTreeViewNodeVecDestructorVariant_External* restrict casted = (AzTreeViewNodeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTreeViewNodeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewRowVecDestructor_matchRefExternal(const AzTreeViewRowVecDestructor* value, const AzTreeViewRowVecDestructorType** restrict out) {
+    const AzTreeViewRowVecDestructorVariant_External* casted = (const AzTreeViewRowVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTreeViewRowVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewRowVecDestructor_matchMutExternal(AzTreeViewRowVecDestructor* restrict value, AzTreeViewRowVecDestructorType* restrict * restrict out) {
+    AzTreeViewRowVecDestructorVariant_External* restrict casted = (AzTreeViewRowVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTreeViewRowVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewRenderedRowVecDestructor_matchRefExternal(const AzTreeViewRenderedRowVecDestructor* value, const AzTreeViewRenderedRowVecDestructorType** restrict out) {
+    const AzTreeViewRenderedRowVecDestructorVariant_External* casted = (const AzTreeViewRenderedRowVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTreeViewRenderedRowVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewRenderedRowVecDestructor_matchMutExternal(AzTreeViewRenderedRowVecDestructor* restrict value, AzTreeViewRenderedRowVecDestructorType* restrict * restrict out) {
+    AzTreeViewRenderedRowVecDestructorVariant_External* restrict casted = (AzTreeViewRenderedRowVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTreeViewRenderedRowVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVirtualListMeasuredRowVecDestructor_matchRefExternal(const AzVirtualListMeasuredRowVecDestructor* value, const AzVirtualListMeasuredRowVecDestructorType** restrict out) {
+    const AzVirtualListMeasuredRowVecDestructorVariant_External* casted = (const AzVirtualListMeasuredRowVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzVirtualListMeasuredRowVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVirtualListMeasuredRowVecDestructor_matchMutExternal(AzVirtualListMeasuredRowVecDestructor* restrict value, AzVirtualListMeasuredRowVecDestructorType* restrict * restrict out) {
+    AzVirtualListMeasuredRowVecDestructorVariant_External* restrict casted = (AzVirtualListMeasuredRowVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzVirtualListMeasuredRowVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssDeclarationVecDestructor_matchRefExternal(const AzCssDeclarationVecDestructor* value, const AzCssDeclarationVecDestructorType** restrict out) {
     const AzCssDeclarationVecDestructorVariant_External* casted = (const AzCssDeclarationVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzCssDeclarationVecDestructorTag_External;
@@ -17518,6 +19476,230 @@ bool AzOptionTextInputSelection_matchMutSome(AzOptionTextInputSelection* restric
     return valid;
 }
 
+bool AzOptionTextInputMask_matchRefSome(const AzOptionTextInputMask* value, const AzTextInputMask** restrict out) {
+    const AzOptionTextInputMaskVariant_Some* casted = (const AzOptionTextInputMaskVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextInputMaskTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextInputMask_matchMutSome(AzOptionTextInputMask* restrict value, AzTextInputMask* restrict * restrict out) {
+    AzOptionTextInputMaskVariant_Some* restrict casted = (AzOptionTextInputMaskVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextInputMaskTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextInputOnValidationChange_matchRefSome(const AzOptionTextInputOnValidationChange* value, const AzTextInputOnValidationChange** restrict out) {
+    const AzOptionTextInputOnValidationChangeVariant_Some* casted = (const AzOptionTextInputOnValidationChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextInputOnValidationChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextInputOnValidationChange_matchMutSome(AzOptionTextInputOnValidationChange* restrict value, AzTextInputOnValidationChange* restrict * restrict out) {
+    AzOptionTextInputOnValidationChangeVariant_Some* restrict casted = (AzOptionTextInputOnValidationChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextInputOnValidationChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDropDownOnChoiceChange_matchRefSome(const AzOptionDropDownOnChoiceChange* value, const AzDropDownOnChoiceChange** restrict out) {
+    const AzOptionDropDownOnChoiceChangeVariant_Some* casted = (const AzOptionDropDownOnChoiceChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDropDownOnChoiceChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDropDownOnChoiceChange_matchMutSome(AzOptionDropDownOnChoiceChange* restrict value, AzDropDownOnChoiceChange* restrict * restrict out) {
+    AzOptionDropDownOnChoiceChangeVariant_Some* restrict casted = (AzOptionDropDownOnChoiceChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDropDownOnChoiceChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabContainerOnTabChange_matchRefSome(const AzOptionTabContainerOnTabChange* value, const AzTabContainerOnTabChange** restrict out) {
+    const AzOptionTabContainerOnTabChangeVariant_Some* casted = (const AzOptionTabContainerOnTabChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTabContainerOnTabChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabContainerOnTabChange_matchMutSome(AzOptionTabContainerOnTabChange* restrict value, AzTabContainerOnTabChange* restrict * restrict out) {
+    AzOptionTabContainerOnTabChangeVariant_Some* restrict casted = (AzOptionTabContainerOnTabChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTabContainerOnTabChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabContainerOnTabClose_matchRefSome(const AzOptionTabContainerOnTabClose* value, const AzTabContainerOnTabClose** restrict out) {
+    const AzOptionTabContainerOnTabCloseVariant_Some* casted = (const AzOptionTabContainerOnTabCloseVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTabContainerOnTabCloseTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabContainerOnTabClose_matchMutSome(AzOptionTabContainerOnTabClose* restrict value, AzTabContainerOnTabClose* restrict * restrict out) {
+    AzOptionTabContainerOnTabCloseVariant_Some* restrict casted = (AzOptionTabContainerOnTabCloseVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTabContainerOnTabCloseTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabContainerOnTabMove_matchRefSome(const AzOptionTabContainerOnTabMove* value, const AzTabContainerOnTabMove** restrict out) {
+    const AzOptionTabContainerOnTabMoveVariant_Some* casted = (const AzOptionTabContainerOnTabMoveVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTabContainerOnTabMoveTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabContainerOnTabMove_matchMutSome(AzOptionTabContainerOnTabMove* restrict value, AzTabContainerOnTabMove* restrict * restrict out) {
+    AzOptionTabContainerOnTabMoveVariant_Some* restrict casted = (AzOptionTabContainerOnTabMoveVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTabContainerOnTabMoveTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSplitPaneOnResize_matchRefSome(const AzOptionSplitPaneOnResize* value, const AzSplitPaneOnResize** restrict out) {
+    const AzOptionSplitPaneOnResizeVariant_Some* casted = (const AzOptionSplitPaneOnResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSplitPaneOnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSplitPaneOnResize_matchMutSome(AzOptionSplitPaneOnResize* restrict value, AzSplitPaneOnResize* restrict * restrict out) {
+    AzOptionSplitPaneOnResizeVariant_Some* restrict casted = (AzOptionSplitPaneOnResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSplitPaneOnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSliderOnValueChange_matchRefSome(const AzOptionSliderOnValueChange* value, const AzSliderOnValueChange** restrict out) {
+    const AzOptionSliderOnValueChangeVariant_Some* casted = (const AzOptionSliderOnValueChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSliderOnValueChange_matchMutSome(AzOptionSliderOnValueChange* restrict value, AzSliderOnValueChange* restrict * restrict out) {
+    AzOptionSliderOnValueChangeVariant_Some* restrict casted = (AzOptionSliderOnValueChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditOnTextChange_matchRefSome(const AzOptionTextEditOnTextChange* value, const AzTextEditOnTextChange** restrict out) {
+    const AzOptionTextEditOnTextChangeVariant_Some* casted = (const AzOptionTextEditOnTextChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextEditOnTextChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditOnTextChange_matchMutSome(AzOptionTextEditOnTextChange* restrict value, AzTextEditOnTextChange* restrict * restrict out) {
+    AzOptionTextEditOnTextChangeVariant_Some* restrict casted = (AzOptionTextEditOnTextChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextEditOnTextChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditCursor_matchRefSome(const AzOptionTextEditCursor* value, const AzTextEditCursor** restrict out) {
+    const AzOptionTextEditCursorVariant_Some* casted = (const AzOptionTextEditCursorVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextEditCursorTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditCursor_matchMutSome(AzOptionTextEditCursor* restrict value, AzTextEditCursor* restrict * restrict out) {
+    AzOptionTextEditCursorVariant_Some* restrict casted = (AzOptionTextEditCursorVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextEditCursorTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnLoadChildren_matchRefSome(const AzOptionTreeViewOnLoadChildren* value, const AzTreeViewOnLoadChildren** restrict out) {
+    const AzOptionTreeViewOnLoadChildrenVariant_Some* casted = (const AzOptionTreeViewOnLoadChildrenVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnLoadChildrenTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnLoadChildren_matchMutSome(AzOptionTreeViewOnLoadChildren* restrict value, AzTreeViewOnLoadChildren* restrict * restrict out) {
+    AzOptionTreeViewOnLoadChildrenVariant_Some* restrict casted = (AzOptionTreeViewOnLoadChildrenVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnLoadChildrenTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnSelectionChange_matchRefSome(const AzOptionTreeViewOnSelectionChange* value, const AzTreeViewOnSelectionChange** restrict out) {
+    const AzOptionTreeViewOnSelectionChangeVariant_Some* casted = (const AzOptionTreeViewOnSelectionChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnSelectionChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnSelectionChange_matchMutSome(AzOptionTreeViewOnSelectionChange* restrict value, AzTreeViewOnSelectionChange* restrict * restrict out) {
+    AzOptionTreeViewOnSelectionChangeVariant_Some* restrict casted = (AzOptionTreeViewOnSelectionChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnSelectionChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnNodeMoved_matchRefSome(const AzOptionTreeViewOnNodeMoved* value, const AzTreeViewOnNodeMoved** restrict out) {
+    const AzOptionTreeViewOnNodeMovedVariant_Some* casted = (const AzOptionTreeViewOnNodeMovedVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnNodeMovedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnNodeMoved_matchMutSome(AzOptionTreeViewOnNodeMoved* restrict value, AzTreeViewOnNodeMoved* restrict * restrict out) {
+    AzOptionTreeViewOnNodeMovedVariant_Some* restrict casted = (AzOptionTreeViewOnNodeMovedVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnNodeMovedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewDropTarget_matchRefSome(const AzOptionTreeViewDropTarget* value, const AzTreeViewDropTarget** restrict out) {
+    const AzOptionTreeViewDropTargetVariant_Some* casted = (const AzOptionTreeViewDropTargetVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewDropTargetTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewDropTarget_matchMutSome(AzOptionTreeViewDropTarget* restrict value, AzTreeViewDropTarget* restrict * restrict out) {
+    AzOptionTreeViewDropTargetVariant_Some* restrict casted = (AzOptionTreeViewDropTargetVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewDropTargetTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionVirtualListOnScroll_matchRefSome(const AzOptionVirtualListOnScroll* value, const AzVirtualListOnScroll** restrict out) {
+    const AzOptionVirtualListOnScrollVariant_Some* casted = (const AzOptionVirtualListOnScrollVariant_Some*)value;
+    bool valid = casted->tag == AzOptionVirtualListOnScrollTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionVirtualListOnScroll_matchMutSome(AzOptionVirtualListOnScroll* restrict value, AzVirtualListOnScroll* restrict * restrict out) {
+    AzOptionVirtualListOnScrollVariant_Some* restrict casted = (AzOptionVirtualListOnScrollVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionVirtualListOnScrollTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionVirtualListIFrameNodes_matchRefSome(const AzOptionVirtualListIFrameNodes* value, const AzVirtualListIFrameNodes** restrict out) {
+    const AzOptionVirtualListIFrameNodesVariant_Some* casted = (const AzOptionVirtualListIFrameNodesVariant_Some*)value;
+    bool valid = casted->tag == AzOptionVirtualListIFrameNodesTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionVirtualListIFrameNodes_matchMutSome(AzOptionVirtualListIFrameNodes* restrict value, AzVirtualListIFrameNodes* restrict * restrict out) {
+    AzOptionVirtualListIFrameNodesVariant_Some* restrict casted = (AzOptionVirtualListIFrameNodesVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionVirtualListIFrameNodesTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionNumberInputOnValueChange_matchRefSome(const AzOptionNumberInputOnValueChange* value, const AzNumberInputOnValueChange** restrict out) {
     const AzOptionNumberInputOnValueChangeVariant_Some* casted = (const AzOptionNumberInputOnValueChangeVariant_Some*)value;
     bool valid = casted->tag == AzOptionNumberInputOnValueChangeTag_Some;
@@ -17686,6 +19868,34 @@ bool AzOptionImageRef_matchMutSome(AzOptionImageRef* restrict value, AzImageRef*
     return valid;
 }
 
+bool AzOptionDragData_matchRefSome(const AzOptionDragData* value, const AzDragData** restrict out) {
+    const AzOptionDragDataVariant_Some* casted = (const AzOptionDragDataVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDragDataTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDragData_matchMutSome(AzOptionDragData* restrict value, AzDragData* restrict * restrict out) {
+    AzOptionDragDataVariant_Some* restrict casted = (AzOptionDragDataVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDragDataTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionImeComposition_matchRefSome(const AzOptionImeComposition* value, const AzImeComposition** restrict out) {
+    const AzOptionImeCompositionVariant_Some* casted = (const AzOptionImeCompositionVariant_Some*)value;
+    bool valid = casted->tag == AzOptionImeCompositionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionImeComposition_matchMutSome(AzOptionImeComposition* restrict value, AzImeComposition* restrict * restrict out) {
+    AzOptionImeCompositionVariant_Some* restrict casted = (AzOptionImeCompositionVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionImeCompositionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionFontRef_matchRefSome(const AzOptionFontRef* value, const AzFontRef** restrict out) {
     const AzOptionFontRefVariant_Some* casted = (const AzOptionFontRefVariant_Some*)value;
     bool valid = casted->tag == AzOptionFontRefTag_Some;
@@ -19011,6 +21221,7 @@ bool AzXmlStreamError_matchMutInvalidString(AzXmlStreamError* restrict value, Az
     .enable_visual_panic_hook = true, \
     .enable_logging_on_panic = true, \
     .enable_tab_navigation = true, \
+    .record_events = false, \
     .system_callbacks = AzSystemCallbacks_libraryInternal(), \
 }
 
@@ -40,6 +40,9 @@ namespace dll {
     struct CheckBoxState;
     using CheckBoxOnToggleCallbackType = Update(*)(RefAny* restrict, CheckBoxState* const, CallbackInfo* restrict);
     
+    struct DropDownState;
+    using DropDownOnChoiceChangeCallbackType = Update(*)(RefAny* restrict, DropDownState* const, CallbackInfo* restrict);
+    
     struct ColorInputState;
     using ColorInputOnValueChangeCallbackType = Update(*)(RefAny* restrict, ColorInputState* const, CallbackInfo* restrict);
     
@@ -51,9 +54,44 @@ namespace dll {
     
     using TextInputOnFocusLostCallbackType = Update(*)(RefAny* restrict, TextInputState* const, CallbackInfo* restrict);
     
+    union TextInputValidationResult;
+    using TextInputValidatorCallbackType = TextInputValidationResult(*)(RefAny* restrict, TextInputState* const);
+    
+    using TextInputOnValidationChangeCallbackType = Update(*)(RefAny* restrict, TextInputState* const, CallbackInfo* restrict);
+    
     struct NumberInputState;
     using NumberInputOnValueChangeCallbackType = Update(*)(RefAny* restrict, NumberInputState* const, CallbackInfo* restrict);
     
+    struct SplitPaneState;
+    using SplitPaneOnResizeCallbackType = Update(*)(RefAny* restrict, SplitPaneState* const, CallbackInfo* restrict);
+    
+    struct TabContainerState;
+    using TabContainerOnTabChangeCallbackType = Update(*)(RefAny* restrict, TabContainerState* const, CallbackInfo* restrict);
+    
+    struct TabContainerTab;
+    using TabContainerOnTabCloseCallbackType = Update(*)(RefAny* restrict, TabContainerState* const, TabContainerTab, CallbackInfo* restrict);
+    
+    using TabContainerOnTabMoveCallbackType = Update(*)(RefAny* restrict, TabContainerState* const, size_t, size_t, CallbackInfo* restrict);
+    
+    struct SliderState;
+    using SliderOnValueChangeCallbackType = Update(*)(RefAny* restrict, SliderState* const, CallbackInfo* restrict);
+    
+    struct TextEditState;
+    using TextEditOnTextChangeCallbackType = Update(*)(RefAny* restrict, TextEditState* const, CallbackInfo* restrict);
+    
+    struct TreeViewState;
+    struct TreeViewLoadRequest;
+    using TreeViewOnLoadChildrenCallbackType = Update(*)(RefAny* restrict, TreeViewState* restrict, TreeViewLoadRequest, CallbackInfo* restrict);
+    
+    using TreeViewOnSelectionChangeCallbackType = Update(*)(RefAny* restrict, TreeViewState* const, CallbackInfo* restrict);
+    
+    using TreeViewOnNodeMovedCallbackType = Update(*)(RefAny* restrict, TreeViewState* const, size_t, CallbackInfo* restrict);
+    
+    using VirtualListRenderRowCallbackType = StyledDom(*)(RefAny* restrict, size_t);
+    
+    struct VirtualListState;
+    using VirtualListOnScrollCallbackType = Update(*)(RefAny* restrict, VirtualListState* const, CallbackInfo* restrict);
+    
     using ParsedFontDestructorFnType = void(*)(void* restrict);
     
     struct InstantPtr;
@@ -103,6 +141,9 @@ namespace dll {
     struct XmlNodeVec;
     using XmlNodeVecDestructorType = void(*)(XmlNodeVec* restrict);
     
+    struct XmlSpanVec;
+    using XmlSpanVecDestructorType = void(*)(XmlSpanVec* restrict);
+    
     struct FmtArgVec;
     using FmtArgVecDestructorType = void(*)(FmtArgVec* restrict);
     
@@ -181,6 +222,33 @@ namespace dll {
     struct ScanCodeVec;
     using ScanCodeVecDestructorType = void(*)(ScanCodeVec* restrict);
     
+    struct TextInputValidatorVec;
+    using TextInputValidatorVecDestructorType = void(*)(TextInputValidatorVec* restrict);
+    
+    struct DropDownChoiceVec;
+    using DropDownChoiceVecDestructorType = void(*)(DropDownChoiceVec* restrict);
+    
+    struct TabContainerTabVec;
+    using TabContainerTabVecDestructorType = void(*)(TabContainerTabVec* restrict);
+    
+    struct TextEditUndoEntryVec;
+    using TextEditUndoEntryVecDestructorType = void(*)(TextEditUndoEntryVec* restrict);
+    
+    struct TreeViewNodeDataVec;
+    using TreeViewNodeDataVecDestructorType = void(*)(TreeViewNodeDataVec* restrict);
+    
+    struct TreeViewNodeVec;
+    using TreeViewNodeVecDestructorType = void(*)(TreeViewNodeVec* restrict);
+    
+    struct TreeViewRowVec;
+    using TreeViewRowVecDestructorType = void(*)(TreeViewRowVec* restrict);
+    
+    struct TreeViewRenderedRowVec;
+    using TreeViewRenderedRowVecDestructorType = void(*)(TreeViewRenderedRowVec* restrict);
+    
+    struct VirtualListMeasuredRowVec;
+    using VirtualListMeasuredRowVecDestructorType = void(*)(VirtualListMeasuredRowVec* restrict);
+    
     struct CssDeclarationVec;
     using CssDeclarationVecDestructorType = void(*)(CssDeclarationVec* restrict);
     
@@ -839,6 +907,13 @@ namespace dll {
        TouchMove,
        TouchEnd,
        TouchCancel,
+       DragStart,
+       DragEnter,
+       DragOver,
+       DragLeave,
+       Drop,
+       DragEnd,
+       ImeComposition,
     };
     
     enum class FocusEventFilter {
@@ -861,6 +936,7 @@ namespace dll {
        VirtualKeyUp,
        FocusReceived,
        FocusLost,
+       ImeComposition,
     };
     
     enum class WindowEventFilter {
@@ -894,6 +970,11 @@ namespace dll {
        FocusLost,
        CloseRequested,
        ThemeChanged,
+       DragStart,
+       DragOver,
+       Drop,
+       DragEnd,
+       ImeComposition,
     };
     
     enum class ComponentEventFilter {
@@ -1011,6 +1092,12 @@ namespace dll {
     };
     
     
+    enum class RovingFocusOrientation {
+       Horizontal,
+       Vertical,
+       Both,
+    };
+    
     enum class ContextMenuMouseButton {
        Right,
        Middle,
@@ -1334,6 +1421,13 @@ namespace dll {
         CheckBoxState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct DropDownOnChoiceChangeCallback {
+        DropDownOnChoiceChangeCallbackType cb;
+        DropDownOnChoiceChangeCallback& operator=(const DropDownOnChoiceChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownOnChoiceChangeCallback(const DropDownOnChoiceChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownOnChoiceChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ColorInputOnValueChangeCallback {
         ColorInputOnValueChangeCallbackType cb;
         ColorInputOnValueChangeCallback& operator=(const ColorInputOnValueChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -1375,6 +1469,20 @@ namespace dll {
        No,
     };
     
+    struct TextInputValidatorCallback {
+        TextInputValidatorCallbackType cb;
+        TextInputValidatorCallback& operator=(const TextInputValidatorCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputValidatorCallback(const TextInputValidatorCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputValidatorCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextInputOnValidationChangeCallback {
+        TextInputOnValidationChangeCallbackType cb;
+        TextInputOnValidationChangeCallback& operator=(const TextInputOnValidationChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputOnValidationChangeCallback(const TextInputOnValidationChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputOnValidationChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NumberInputState {
         float previous;
         float number;
@@ -1400,6 +1508,179 @@ namespace dll {
         ProgressBarState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class SplitPaneOrientation {
+       Horizontal,
+       Vertical,
+    };
+    
+    enum class SplitPaneCollapsed {
+       None,
+       First,
+       Second,
+    };
+    
+    struct SplitPaneOnResizeCallback {
+        SplitPaneOnResizeCallbackType cb;
+        SplitPaneOnResizeCallback& operator=(const SplitPaneOnResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitPaneOnResizeCallback(const SplitPaneOnResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitPaneOnResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class TabContainerOverflow {
+       Scroll,
+       Menu,
+    };
+    
+    struct TabContainerOnTabChangeCallback {
+        TabContainerOnTabChangeCallbackType cb;
+        TabContainerOnTabChangeCallback& operator=(const TabContainerOnTabChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerOnTabChangeCallback(const TabContainerOnTabChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerOnTabChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerOnTabCloseCallback {
+        TabContainerOnTabCloseCallbackType cb;
+        TabContainerOnTabCloseCallback& operator=(const TabContainerOnTabCloseCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerOnTabCloseCallback(const TabContainerOnTabCloseCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerOnTabCloseCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerOnTabMoveCallback {
+        TabContainerOnTabMoveCallbackType cb;
+        TabContainerOnTabMoveCallback& operator=(const TabContainerOnTabMoveCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerOnTabMoveCallback(const TabContainerOnTabMoveCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerOnTabMoveCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class SliderOrientation {
+       Horizontal,
+       Vertical,
+    };
+    
+    enum class SliderThumb {
+       Lower,
+       Upper,
+    };
+    
+    struct SliderOnValueChangeCallback {
+        SliderOnValueChangeCallbackType cb;
+        SliderOnValueChangeCallback& operator=(const SliderOnValueChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderOnValueChangeCallback(const SliderOnValueChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderOnValueChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditCursor {
+        size_t line;
+        size_t column;
+        TextEditCursor& operator=(const TextEditCursor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditCursor(const TextEditCursor&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditCursor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class TextEditEditKind {
+       Insert,
+       DeleteBackward,
+       DeleteForward,
+       Other,
+    };
+    
+    struct TextEditOnTextChangeCallback {
+        TextEditOnTextChangeCallbackType cb;
+        TextEditOnTextChangeCallback& operator=(const TextEditOnTextChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditOnTextChangeCallback(const TextEditOnTextChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditOnTextChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class TreeViewChildren {
+       None,
+       NotLoaded,
+       Loading,
+       Loaded,
+    };
+    
+    enum class TreeViewSelectionMode {
+       None,
+       Single,
+       Multiple,
+    };
+    
+    enum class TreeViewDropPosition {
+       Before,
+       After,
+       Into,
+    };
+    
+    struct TreeViewRow {
+        size_t node;
+        size_t depth;
+        TreeViewRow& operator=(const TreeViewRow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewRow(const TreeViewRow&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewRow() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewDragPayload {
+        size_t node;
+        TreeViewDragPayload& operator=(const TreeViewDragPayload&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewDragPayload(const TreeViewDragPayload&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewDragPayload() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnLoadChildrenCallback {
+        TreeViewOnLoadChildrenCallbackType cb;
+        TreeViewOnLoadChildrenCallback& operator=(const TreeViewOnLoadChildrenCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnLoadChildrenCallback(const TreeViewOnLoadChildrenCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnLoadChildrenCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnSelectionChangeCallback {
+        TreeViewOnSelectionChangeCallbackType cb;
+        TreeViewOnSelectionChangeCallback& operator=(const TreeViewOnSelectionChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnSelectionChangeCallback(const TreeViewOnSelectionChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnSelectionChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnNodeMovedCallback {
+        TreeViewOnNodeMovedCallbackType cb;
+        TreeViewOnNodeMovedCallback& operator=(const TreeViewOnNodeMovedCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnNodeMovedCallback(const TreeViewOnNodeMovedCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnNodeMovedCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class VirtualListRowHeightTag {
+       Fixed,
+       Estimated,
+    };
+    
+    struct VirtualListRowHeightVariant_Fixed { VirtualListRowHeightTag tag; float payload; };
+    struct VirtualListRowHeightVariant_Estimated { VirtualListRowHeightTag tag; float payload; };
+    union VirtualListRowHeight {
+        VirtualListRowHeightVariant_Fixed Fixed;
+        VirtualListRowHeightVariant_Estimated Estimated;
+    };
+    
+    
+    struct VirtualListMeasuredRow {
+        size_t row;
+        float height;
+        double delta_before;
+        VirtualListMeasuredRow& operator=(const VirtualListMeasuredRow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListMeasuredRow() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListRenderRowCallback {
+        VirtualListRenderRowCallbackType cb;
+        VirtualListRenderRowCallback& operator=(const VirtualListRenderRowCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListRenderRowCallback(const VirtualListRenderRowCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualListRenderRowCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListOnScrollCallback {
+        VirtualListOnScrollCallbackType cb;
+        VirtualListOnScrollCallback& operator=(const VirtualListOnScrollCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListOnScrollCallback(const VirtualListOnScrollCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualListOnScrollCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Node {
         size_t parent;
         size_t previous_sibling;
@@ -1421,6 +1702,9 @@ namespace dll {
         bool  hover;
         bool  active;
         bool  focused;
+        bool  focus_visible;
+        bool  focus_within;
+        bool  drag_over;
         StyledNodeState& operator=(const StyledNodeState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         StyledNodeState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -2037,6 +2321,22 @@ namespace dll {
     };
     
     
+    enum class XmlSpanVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct XmlSpanVecDestructorVariant_DefaultRust { XmlSpanVecDestructorTag tag; };
+    struct XmlSpanVecDestructorVariant_NoDestructor { XmlSpanVecDestructorTag tag; };
+    struct XmlSpanVecDestructorVariant_External { XmlSpanVecDestructorTag tag; XmlSpanVecDestructorType payload; };
+    union XmlSpanVecDestructor {
+        XmlSpanVecDestructorVariant_DefaultRust DefaultRust;
+        XmlSpanVecDestructorVariant_NoDestructor NoDestructor;
+        XmlSpanVecDestructorVariant_External External;
+    };
+    
+    
     enum class FmtArgVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2453,6 +2753,150 @@ namespace dll {
     };
     
     
+    enum class TextInputValidatorVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TextInputValidatorVecDestructorVariant_DefaultRust { TextInputValidatorVecDestructorTag tag; };
+    struct TextInputValidatorVecDestructorVariant_NoDestructor { TextInputValidatorVecDestructorTag tag; };
+    struct TextInputValidatorVecDestructorVariant_External { TextInputValidatorVecDestructorTag tag; TextInputValidatorVecDestructorType payload; };
+    union TextInputValidatorVecDestructor {
+        TextInputValidatorVecDestructorVariant_DefaultRust DefaultRust;
+        TextInputValidatorVecDestructorVariant_NoDestructor NoDestructor;
+        TextInputValidatorVecDestructorVariant_External External;
+    };
+    
+    
+    enum class DropDownChoiceVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct DropDownChoiceVecDestructorVariant_DefaultRust { DropDownChoiceVecDestructorTag tag; };
+    struct DropDownChoiceVecDestructorVariant_NoDestructor { DropDownChoiceVecDestructorTag tag; };
+    struct DropDownChoiceVecDestructorVariant_External { DropDownChoiceVecDestructorTag tag; DropDownChoiceVecDestructorType payload; };
+    union DropDownChoiceVecDestructor {
+        DropDownChoiceVecDestructorVariant_DefaultRust DefaultRust;
+        DropDownChoiceVecDestructorVariant_NoDestructor NoDestructor;
+        DropDownChoiceVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TabContainerTabVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TabContainerTabVecDestructorVariant_DefaultRust { TabContainerTabVecDestructorTag tag; };
+    struct TabContainerTabVecDestructorVariant_NoDestructor { TabContainerTabVecDestructorTag tag; };
+    struct TabContainerTabVecDestructorVariant_External { TabContainerTabVecDestructorTag tag; TabContainerTabVecDestructorType payload; };
+    union TabContainerTabVecDestructor {
+        TabContainerTabVecDestructorVariant_DefaultRust DefaultRust;
+        TabContainerTabVecDestructorVariant_NoDestructor NoDestructor;
+        TabContainerTabVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TextEditUndoEntryVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TextEditUndoEntryVecDestructorVariant_DefaultRust { TextEditUndoEntryVecDestructorTag tag; };
+    struct TextEditUndoEntryVecDestructorVariant_NoDestructor { TextEditUndoEntryVecDestructorTag tag; };
+    struct TextEditUndoEntryVecDestructorVariant_External { TextEditUndoEntryVecDestructorTag tag; TextEditUndoEntryVecDestructorType payload; };
+    union TextEditUndoEntryVecDestructor {
+        TextEditUndoEntryVecDestructorVariant_DefaultRust DefaultRust;
+        TextEditUndoEntryVecDestructorVariant_NoDestructor NoDestructor;
+        TextEditUndoEntryVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TreeViewNodeDataVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TreeViewNodeDataVecDestructorVariant_DefaultRust { TreeViewNodeDataVecDestructorTag tag; };
+    struct TreeViewNodeDataVecDestructorVariant_NoDestructor { TreeViewNodeDataVecDestructorTag tag; };
+    struct TreeViewNodeDataVecDestructorVariant_External { TreeViewNodeDataVecDestructorTag tag; TreeViewNodeDataVecDestructorType payload; };
+    union TreeViewNodeDataVecDestructor {
+        TreeViewNodeDataVecDestructorVariant_DefaultRust DefaultRust;
+        TreeViewNodeDataVecDestructorVariant_NoDestructor NoDestructor;
+        TreeViewNodeDataVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TreeViewNodeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TreeViewNodeVecDestructorVariant_DefaultRust { TreeViewNodeVecDestructorTag tag; };
+    struct TreeViewNodeVecDestructorVariant_NoDestructor { TreeViewNodeVecDestructorTag tag; };
+    struct TreeViewNodeVecDestructorVariant_External { TreeViewNodeVecDestructorTag tag; TreeViewNodeVecDestructorType payload; };
+    union TreeViewNodeVecDestructor {
+        TreeViewNodeVecDestructorVariant_DefaultRust DefaultRust;
+        TreeViewNodeVecDestructorVariant_NoDestructor NoDestructor;
+        TreeViewNodeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TreeViewRowVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TreeViewRowVecDestructorVariant_DefaultRust { TreeViewRowVecDestructorTag tag; };
+    struct TreeViewRowVecDestructorVariant_NoDestructor { TreeViewRowVecDestructorTag tag; };
+    struct TreeViewRowVecDestructorVariant_External { TreeViewRowVecDestructorTag tag; TreeViewRowVecDestructorType payload; };
+    union TreeViewRowVecDestructor {
+        TreeViewRowVecDestructorVariant_DefaultRust DefaultRust;
+        TreeViewRowVecDestructorVariant_NoDestructor NoDestructor;
+        TreeViewRowVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TreeViewRenderedRowVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TreeViewRenderedRowVecDestructorVariant_DefaultRust { TreeViewRenderedRowVecDestructorTag tag; };
+    struct TreeViewRenderedRowVecDestructorVariant_NoDestructor { TreeViewRenderedRowVecDestructorTag tag; };
+    struct TreeViewRenderedRowVecDestructorVariant_External { TreeViewRenderedRowVecDestructorTag tag; TreeViewRenderedRowVecDestructorType payload; };
+    union TreeViewRenderedRowVecDestructor {
+        TreeViewRenderedRowVecDestructorVariant_DefaultRust DefaultRust;
+        TreeViewRenderedRowVecDestructorVariant_NoDestructor NoDestructor;
+        TreeViewRenderedRowVecDestructorVariant_External External;
+    };
+    
+    
+    enum class VirtualListMeasuredRowVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct VirtualListMeasuredRowVecDestructorVariant_DefaultRust { VirtualListMeasuredRowVecDestructorTag tag; };
+    struct VirtualListMeasuredRowVecDestructorVariant_NoDestructor { VirtualListMeasuredRowVecDestructorTag tag; };
+    struct VirtualListMeasuredRowVecDestructorVariant_External { VirtualListMeasuredRowVecDestructorTag tag; VirtualListMeasuredRowVecDestructorType payload; };
+    union VirtualListMeasuredRowVecDestructor {
+        VirtualListMeasuredRowVecDestructorVariant_DefaultRust DefaultRust;
+        VirtualListMeasuredRowVecDestructorVariant_NoDestructor NoDestructor;
+        VirtualListMeasuredRowVecDestructorVariant_External External;
+    };
+    
+    
     enum class CssDeclarationVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3181,6 +3625,13 @@ namespace dll {
     };
     
     
+    struct RovingFocus {
+        RovingFocusOrientation orientation;
+        bool  wrap;
+        RovingFocus& operator=(const RovingFocus&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RovingFocus() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct MenuCallback {
         Callback callback;
         RefAny data;
@@ -5016,6 +5467,14 @@ namespace dll {
         CheckBoxOnToggle() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct DropDownOnChoiceChange {
+        RefAny data;
+        DropDownOnChoiceChangeCallback callback;
+        DropDownOnChoiceChange& operator=(const DropDownOnChoiceChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownOnChoiceChange(const DropDownOnChoiceChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownOnChoiceChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ColorInputState {
         ColorU color;
         ColorInputState& operator=(const ColorInputState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -5076,6 +5535,22 @@ namespace dll {
         OnTextInputReturn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextInputCustomValidator {
+        RefAny data;
+        TextInputValidatorCallback callback;
+        TextInputCustomValidator& operator=(const TextInputCustomValidator&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputCustomValidator(const TextInputCustomValidator&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputCustomValidator() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextInputOnValidationChange {
+        RefAny data;
+        TextInputOnValidationChangeCallback callback;
+        TextInputOnValidationChange& operator=(const TextInputOnValidationChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputOnValidationChange(const TextInputOnValidationChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputOnValidationChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NumberInputOnValueChange {
         RefAny data;
         NumberInputOnValueChangeCallback callback;
@@ -5084,6 +5559,136 @@ namespace dll {
         NumberInputOnValueChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SplitPaneState {
+        SplitPaneOrientation orientation;
+        float ratio;
+        float min_first_size;
+        float min_second_size;
+        bool  collapsible;
+        SplitPaneCollapsed collapsed;
+        SplitPaneState& operator=(const SplitPaneState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitPaneState(const SplitPaneState&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitPaneState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitPaneOnResize {
+        RefAny data;
+        SplitPaneOnResizeCallback callback;
+        SplitPaneOnResize& operator=(const SplitPaneOnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitPaneOnResize(const SplitPaneOnResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitPaneOnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerOnTabChange {
+        RefAny data;
+        TabContainerOnTabChangeCallback callback;
+        TabContainerOnTabChange& operator=(const TabContainerOnTabChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerOnTabChange(const TabContainerOnTabChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerOnTabChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerOnTabClose {
+        RefAny data;
+        TabContainerOnTabCloseCallback callback;
+        TabContainerOnTabClose& operator=(const TabContainerOnTabClose&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerOnTabClose(const TabContainerOnTabClose&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerOnTabClose() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerOnTabMove {
+        RefAny data;
+        TabContainerOnTabMoveCallback callback;
+        TabContainerOnTabMove& operator=(const TabContainerOnTabMove&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerOnTabMove(const TabContainerOnTabMove&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerOnTabMove() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderState {
+        float value;
+        OptionF32 upper_value;
+        float min;
+        float max;
+        float step;
+        SliderOrientation orientation;
+        OptionF32 tick_interval;
+        SliderThumb active_thumb;
+        SliderState& operator=(const SliderState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderState(const SliderState&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderOnValueChange {
+        RefAny data;
+        SliderOnValueChangeCallback callback;
+        SliderOnValueChange& operator=(const SliderOnValueChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderOnValueChange(const SliderOnValueChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderOnValueChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditOnTextChange {
+        RefAny data;
+        TextEditOnTextChangeCallback callback;
+        TextEditOnTextChange& operator=(const TextEditOnTextChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditOnTextChange(const TextEditOnTextChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditOnTextChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewDropTarget {
+        size_t node;
+        TreeViewDropPosition position;
+        TreeViewDropTarget& operator=(const TreeViewDropTarget&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewDropTarget(const TreeViewDropTarget&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewDropTarget() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewLoadRequest {
+        size_t node;
+        RefAny tree;
+        TreeViewLoadRequest& operator=(const TreeViewLoadRequest&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewLoadRequest(const TreeViewLoadRequest&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewLoadRequest() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnLoadChildren {
+        RefAny data;
+        TreeViewOnLoadChildrenCallback callback;
+        TreeViewOnLoadChildren& operator=(const TreeViewOnLoadChildren&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnLoadChildren(const TreeViewOnLoadChildren&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnLoadChildren() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnSelectionChange {
+        RefAny data;
+        TreeViewOnSelectionChangeCallback callback;
+        TreeViewOnSelectionChange& operator=(const TreeViewOnSelectionChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnSelectionChange(const TreeViewOnSelectionChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnSelectionChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnNodeMoved {
+        RefAny data;
+        TreeViewOnNodeMovedCallback callback;
+        TreeViewOnNodeMoved& operator=(const TreeViewOnNodeMoved&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnNodeMoved(const TreeViewOnNodeMoved&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnNodeMoved() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListRenderRow {
+        RefAny data;
+        VirtualListRenderRowCallback callback;
+        VirtualListRenderRow& operator=(const VirtualListRenderRow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListRenderRow(const VirtualListRenderRow&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualListRenderRow() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListOnScroll {
+        RefAny data;
+        VirtualListOnScrollCallback callback;
+        VirtualListOnScroll& operator=(const VirtualListOnScroll&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListOnScroll(const VirtualListOnScroll&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualListOnScroll() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ParentWithNodeDepth {
         size_t depth;
         NodeId node_id;
@@ -5222,6 +5827,13 @@ namespace dll {
         SvgFillStyle() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct XmlSpan {
+        SvgParseErrorPosition start;
+        SvgParseErrorPosition end;
+        XmlSpan& operator=(const XmlSpan&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        XmlSpan() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InstantPtr {
         void* ptr;
         InstantPtrCloneFn clone_fn;
@@ -5300,6 +5912,16 @@ namespace dll {
         XmlNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct XmlSpanVec {
+        XmlSpan* ptr;
+        size_t len;
+        size_t cap;
+        XmlSpanVecDestructor destructor;
+        XmlSpanVec& operator=(const XmlSpanVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        XmlSpanVec(const XmlSpanVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        XmlSpanVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InlineGlyphVec {
         InlineGlyph* ptr;
         size_t len;
@@ -5431,6 +6053,26 @@ namespace dll {
         ScanCodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TreeViewRowVec {
+        TreeViewRow* ptr;
+        size_t len;
+        size_t cap;
+        TreeViewRowVecDestructor destructor;
+        TreeViewRowVec& operator=(const TreeViewRowVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewRowVec(const TreeViewRowVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewRowVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListMeasuredRowVec {
+        VirtualListMeasuredRow* ptr;
+        size_t len;
+        size_t cap;
+        VirtualListMeasuredRowVecDestructor destructor;
+        VirtualListMeasuredRowVec& operator=(const VirtualListMeasuredRowVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListMeasuredRowVec(const VirtualListMeasuredRowVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualListMeasuredRowVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct U16Vec {
         uint16_t* ptr;
         size_t len;
@@ -5622,33 +6264,215 @@ namespace dll {
     };
     
     
-    enum class OptionNumberInputOnValueChangeTag {
+    enum class OptionTextInputOnValidationChangeTag {
        None,
        Some,
     };
     
-    struct OptionNumberInputOnValueChangeVariant_None { OptionNumberInputOnValueChangeTag tag; };
-    struct OptionNumberInputOnValueChangeVariant_Some { OptionNumberInputOnValueChangeTag tag; NumberInputOnValueChange payload; };
-    union OptionNumberInputOnValueChange {
-        OptionNumberInputOnValueChangeVariant_None None;
-        OptionNumberInputOnValueChangeVariant_Some Some;
+    struct OptionTextInputOnValidationChangeVariant_None { OptionTextInputOnValidationChangeTag tag; };
+    struct OptionTextInputOnValidationChangeVariant_Some { OptionTextInputOnValidationChangeTag tag; TextInputOnValidationChange payload; };
+    union OptionTextInputOnValidationChange {
+        OptionTextInputOnValidationChangeVariant_None None;
+        OptionTextInputOnValidationChangeVariant_Some Some;
     };
     
     
-    enum class OptionMenuItemIconTag {
+    enum class OptionDropDownOnChoiceChangeTag {
        None,
        Some,
     };
     
-    struct OptionMenuItemIconVariant_None { OptionMenuItemIconTag tag; };
-    struct OptionMenuItemIconVariant_Some { OptionMenuItemIconTag tag; MenuItemIcon payload; };
-    union OptionMenuItemIcon {
-        OptionMenuItemIconVariant_None None;
-        OptionMenuItemIconVariant_Some Some;
+    struct OptionDropDownOnChoiceChangeVariant_None { OptionDropDownOnChoiceChangeTag tag; };
+    struct OptionDropDownOnChoiceChangeVariant_Some { OptionDropDownOnChoiceChangeTag tag; DropDownOnChoiceChange payload; };
+    union OptionDropDownOnChoiceChange {
+        OptionDropDownOnChoiceChangeVariant_None None;
+        OptionDropDownOnChoiceChangeVariant_Some Some;
     };
     
     
-    enum class OptionMenuCallbackTag {
+    enum class OptionTabContainerOnTabChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTabContainerOnTabChangeVariant_None { OptionTabContainerOnTabChangeTag tag; };
+    struct OptionTabContainerOnTabChangeVariant_Some { OptionTabContainerOnTabChangeTag tag; TabContainerOnTabChange payload; };
+    union OptionTabContainerOnTabChange {
+        OptionTabContainerOnTabChangeVariant_None None;
+        OptionTabContainerOnTabChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionTabContainerOnTabCloseTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTabContainerOnTabCloseVariant_None { OptionTabContainerOnTabCloseTag tag; };
+    struct OptionTabContainerOnTabCloseVariant_Some { OptionTabContainerOnTabCloseTag tag; TabContainerOnTabClose payload; };
+    union OptionTabContainerOnTabClose {
+        OptionTabContainerOnTabCloseVariant_None None;
+        OptionTabContainerOnTabCloseVariant_Some Some;
+    };
+    
+    
+    enum class OptionTabContainerOnTabMoveTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTabContainerOnTabMoveVariant_None { OptionTabContainerOnTabMoveTag tag; };
+    struct OptionTabContainerOnTabMoveVariant_Some { OptionTabContainerOnTabMoveTag tag; TabContainerOnTabMove payload; };
+    union OptionTabContainerOnTabMove {
+        OptionTabContainerOnTabMoveVariant_None None;
+        OptionTabContainerOnTabMoveVariant_Some Some;
+    };
+    
+    
+    enum class OptionSplitPaneOnResizeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSplitPaneOnResizeVariant_None { OptionSplitPaneOnResizeTag tag; };
+    struct OptionSplitPaneOnResizeVariant_Some { OptionSplitPaneOnResizeTag tag; SplitPaneOnResize payload; };
+    union OptionSplitPaneOnResize {
+        OptionSplitPaneOnResizeVariant_None None;
+        OptionSplitPaneOnResizeVariant_Some Some;
+    };
+    
+    
+    enum class OptionSliderOnValueChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSliderOnValueChangeVariant_None { OptionSliderOnValueChangeTag tag; };
+    struct OptionSliderOnValueChangeVariant_Some { OptionSliderOnValueChangeTag tag; SliderOnValueChange payload; };
+    union OptionSliderOnValueChange {
+        OptionSliderOnValueChangeVariant_None None;
+        OptionSliderOnValueChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionTextEditOnTextChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTextEditOnTextChangeVariant_None { OptionTextEditOnTextChangeTag tag; };
+    struct OptionTextEditOnTextChangeVariant_Some { OptionTextEditOnTextChangeTag tag; TextEditOnTextChange payload; };
+    union OptionTextEditOnTextChange {
+        OptionTextEditOnTextChangeVariant_None None;
+        OptionTextEditOnTextChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionTextEditCursorTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTextEditCursorVariant_None { OptionTextEditCursorTag tag; };
+    struct OptionTextEditCursorVariant_Some { OptionTextEditCursorTag tag; TextEditCursor payload; };
+    union OptionTextEditCursor {
+        OptionTextEditCursorVariant_None None;
+        OptionTextEditCursorVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnLoadChildrenTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnLoadChildrenVariant_None { OptionTreeViewOnLoadChildrenTag tag; };
+    struct OptionTreeViewOnLoadChildrenVariant_Some { OptionTreeViewOnLoadChildrenTag tag; TreeViewOnLoadChildren payload; };
+    union OptionTreeViewOnLoadChildren {
+        OptionTreeViewOnLoadChildrenVariant_None None;
+        OptionTreeViewOnLoadChildrenVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnSelectionChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnSelectionChangeVariant_None { OptionTreeViewOnSelectionChangeTag tag; };
+    struct OptionTreeViewOnSelectionChangeVariant_Some { OptionTreeViewOnSelectionChangeTag tag; TreeViewOnSelectionChange payload; };
+    union OptionTreeViewOnSelectionChange {
+        OptionTreeViewOnSelectionChangeVariant_None None;
+        OptionTreeViewOnSelectionChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnNodeMovedTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnNodeMovedVariant_None { OptionTreeViewOnNodeMovedTag tag; };
+    struct OptionTreeViewOnNodeMovedVariant_Some { OptionTreeViewOnNodeMovedTag tag; TreeViewOnNodeMoved payload; };
+    union OptionTreeViewOnNodeMoved {
+        OptionTreeViewOnNodeMovedVariant_None None;
+        OptionTreeViewOnNodeMovedVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewDropTargetTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewDropTargetVariant_None { OptionTreeViewDropTargetTag tag; };
+    struct OptionTreeViewDropTargetVariant_Some { OptionTreeViewDropTargetTag tag; TreeViewDropTarget payload; };
+    union OptionTreeViewDropTarget {
+        OptionTreeViewDropTargetVariant_None None;
+        OptionTreeViewDropTargetVariant_Some Some;
+    };
+    
+    
+    enum class OptionVirtualListOnScrollTag {
+       None,
+       Some,
+    };
+    
+    struct OptionVirtualListOnScrollVariant_None { OptionVirtualListOnScrollTag tag; };
+    struct OptionVirtualListOnScrollVariant_Some { OptionVirtualListOnScrollTag tag; VirtualListOnScroll payload; };
+    union OptionVirtualListOnScroll {
+        OptionVirtualListOnScrollVariant_None None;
+        OptionVirtualListOnScrollVariant_Some Some;
+    };
+    
+    
+    enum class OptionNumberInputOnValueChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionNumberInputOnValueChangeVariant_None { OptionNumberInputOnValueChangeTag tag; };
+    struct OptionNumberInputOnValueChangeVariant_Some { OptionNumberInputOnValueChangeTag tag; NumberInputOnValueChange payload; };
+    union OptionNumberInputOnValueChange {
+        OptionNumberInputOnValueChangeVariant_None None;
+        OptionNumberInputOnValueChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionMenuItemIconTag {
+       None,
+       Some,
+    };
+    
+    struct OptionMenuItemIconVariant_None { OptionMenuItemIconTag tag; };
+    struct OptionMenuItemIconVariant_Some { OptionMenuItemIconTag tag; MenuItemIcon payload; };
+    union OptionMenuItemIcon {
+        OptionMenuItemIconVariant_None None;
+        OptionMenuItemIconVariant_Some Some;
+    };
+    
+    
+    enum class OptionMenuCallbackTag {
        None,
        Some,
     };
@@ -6134,6 +6958,7 @@ namespace dll {
         bool  enable_visual_panic_hook;
         bool  enable_logging_on_panic;
         bool  enable_tab_navigation;
+        bool  record_events;
         SystemCallbacks system_callbacks;
         AppConfig& operator=(const AppConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         AppConfig(const AppConfig&) = delete; /* disable copy constructor, use explicit .clone() */
@@ -6188,20 +7013,6 @@ namespace dll {
         WindowSize() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct KeyboardState {
-        bool  shift_down;
-        bool  ctrl_down;
-        bool  alt_down;
-        bool  super_down;
-        OptionChar current_char;
-        OptionVirtualKeyCode current_virtual_keycode;
-        VirtualKeyCodeVec pressed_virtual_keycodes;
-        ScanCodeVec pressed_scancodes;
-        KeyboardState& operator=(const KeyboardState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        KeyboardState(const KeyboardState&) = delete; /* disable copy constructor, use explicit .clone() */
-        KeyboardState() = delete; /* disable default constructor, use C++20 designated initializer instead */
-    };
-    
     struct MouseState {
         OptionMouseCursorType mouse_cursor_type;
         CursorPosition cursor_position;
@@ -6232,6 +7043,15 @@ namespace dll {
         InlineTextContents() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct DragData {
+        DomNodeId source;
+        RefAny payload;
+        OptionImageRef ghost;
+        DragData& operator=(const DragData&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DragData(const DragData&) = delete; /* disable copy constructor, use explicit .clone() */
+        DragData() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class AnimationEasingTag {
        Ease,
        Linear,
@@ -6313,6 +7133,19 @@ namespace dll {
     };
     
     
+    enum class FocusScopeTag {
+       Trap,
+       Roving,
+    };
+    
+    struct FocusScopeVariant_Trap { FocusScopeTag tag; };
+    struct FocusScopeVariant_Roving { FocusScopeTag tag; RovingFocus payload; };
+    union FocusScope {
+        FocusScopeVariant_Trap Trap;
+        FocusScopeVariant_Roving Roving;
+    };
+    
+    
     struct Menu {
         MenuItemVec items;
         MenuPopupPosition position;
@@ -6336,6 +7169,9 @@ namespace dll {
        Hover,
        Active,
        Focus,
+       FocusVisible,
+       FocusWithin,
+       DragOver,
     };
     
     struct CssPathPseudoSelectorVariant_First { CssPathPseudoSelectorTag tag; };
@@ -6344,6 +7180,9 @@ namespace dll {
     struct CssPathPseudoSelectorVariant_Hover { CssPathPseudoSelectorTag tag; };
     struct CssPathPseudoSelectorVariant_Active { CssPathPseudoSelectorTag tag; };
     struct CssPathPseudoSelectorVariant_Focus { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_FocusVisible { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_FocusWithin { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_DragOver { CssPathPseudoSelectorTag tag; };
     union CssPathPseudoSelector {
         CssPathPseudoSelectorVariant_First First;
         CssPathPseudoSelectorVariant_Last Last;
@@ -6351,6 +7190,9 @@ namespace dll {
         CssPathPseudoSelectorVariant_Hover Hover;
         CssPathPseudoSelectorVariant_Active Active;
         CssPathPseudoSelectorVariant_Focus Focus;
+        CssPathPseudoSelectorVariant_FocusVisible FocusVisible;
+        CssPathPseudoSelectorVariant_FocusWithin FocusWithin;
+        CssPathPseudoSelectorVariant_DragOver DragOver;
     };
     
     
@@ -6563,6 +7405,34 @@ namespace dll {
         CheckBoxStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class TextInputValidatorTag {
+       NotEmpty,
+       Integer,
+       Number,
+       Email,
+       Ipv4,
+       MinLength,
+       Custom,
+    };
+    
+    struct TextInputValidatorVariant_NotEmpty { TextInputValidatorTag tag; };
+    struct TextInputValidatorVariant_Integer { TextInputValidatorTag tag; };
+    struct TextInputValidatorVariant_Number { TextInputValidatorTag tag; };
+    struct TextInputValidatorVariant_Email { TextInputValidatorTag tag; };
+    struct TextInputValidatorVariant_Ipv4 { TextInputValidatorTag tag; };
+    struct TextInputValidatorVariant_MinLength { TextInputValidatorTag tag; size_t payload; };
+    struct TextInputValidatorVariant_Custom { TextInputValidatorTag tag; TextInputCustomValidator payload; };
+    union TextInputValidator {
+        TextInputValidatorVariant_NotEmpty NotEmpty;
+        TextInputValidatorVariant_Integer Integer;
+        TextInputValidatorVariant_Number Number;
+        TextInputValidatorVariant_Email Email;
+        TextInputValidatorVariant_Ipv4 Ipv4;
+        TextInputValidatorVariant_MinLength MinLength;
+        TextInputValidatorVariant_Custom Custom;
+    };
+    
+    
     struct NumberInputStateWrapper {
         NumberInputState inner;
         OptionNumberInputOnValueChange on_value_change;
@@ -6571,6 +7441,46 @@ namespace dll {
         NumberInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SplitPaneStateWrapper {
+        SplitPaneState inner;
+        OptionSplitPaneOnResize on_resize;
+        bool  is_dragging;
+        float drag_position;
+        SplitPaneState state_before_drag;
+        SplitPaneStateWrapper& operator=(const SplitPaneStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitPaneStateWrapper(const SplitPaneStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitPaneStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderStateWrapper {
+        SliderState inner;
+        OptionSliderOnValueChange on_value_change;
+        bool  is_dragging;
+        float drag_value;
+        SliderStateWrapper& operator=(const SliderStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderStateWrapper(const SliderStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListState {
+        size_t row_count;
+        VirtualListRowHeight row_height;
+        size_t overscan;
+        float scroll_offset;
+        float viewport_height;
+        VirtualListMeasuredRowVec measured_rows;
+        VirtualListState& operator=(const VirtualListState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListState(const VirtualListState&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualListState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListIFrameNodes {
+        DomNodeId rows;
+        OptionDomNodeId scrollbar_thumb;
+        VirtualListIFrameNodes& operator=(const VirtualListIFrameNodes&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListIFrameNodes() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyledNode {
         StyledNodeState state;
         OptionTagId tag_id;
@@ -6760,6 +7670,16 @@ namespace dll {
         SvgPathElementVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextInputValidatorVec {
+        TextInputValidator* ptr;
+        size_t len;
+        size_t cap;
+        TextInputValidatorVecDestructor destructor;
+        TextInputValidatorVec& operator=(const TextInputValidatorVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputValidatorVec(const TextInputValidatorVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputValidatorVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StringVec {
         String* ptr;
         size_t len;
@@ -6790,6 +7710,19 @@ namespace dll {
         TagIdToNodeIdMappingVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class OptionVirtualListIFrameNodesTag {
+       None,
+       Some,
+    };
+    
+    struct OptionVirtualListIFrameNodesVariant_None { OptionVirtualListIFrameNodesTag tag; };
+    struct OptionVirtualListIFrameNodesVariant_Some { OptionVirtualListIFrameNodesTag tag; VirtualListIFrameNodes payload; };
+    union OptionVirtualListIFrameNodes {
+        OptionVirtualListIFrameNodesVariant_None None;
+        OptionVirtualListIFrameNodesVariant_Some Some;
+    };
+    
+    
     enum class OptionVirtualKeyCodeComboTag {
        None,
        Some,
@@ -6803,29 +7736,29 @@ namespace dll {
     };
     
     
-    enum class OptionMouseStateTag {
+    enum class OptionDragDataTag {
        None,
        Some,
     };
     
-    struct OptionMouseStateVariant_None { OptionMouseStateTag tag; };
-    struct OptionMouseStateVariant_Some { OptionMouseStateTag tag; MouseState payload; };
-    union OptionMouseState {
-        OptionMouseStateVariant_None None;
-        OptionMouseStateVariant_Some Some;
+    struct OptionDragDataVariant_None { OptionDragDataTag tag; };
+    struct OptionDragDataVariant_Some { OptionDragDataTag tag; DragData payload; };
+    union OptionDragData {
+        OptionDragDataVariant_None None;
+        OptionDragDataVariant_Some Some;
     };
     
     
-    enum class OptionKeyboardStateTag {
+    enum class OptionMouseStateTag {
        None,
        Some,
     };
     
-    struct OptionKeyboardStateVariant_None { OptionKeyboardStateTag tag; };
-    struct OptionKeyboardStateVariant_Some { OptionKeyboardStateTag tag; KeyboardState payload; };
-    union OptionKeyboardState {
-        OptionKeyboardStateVariant_None None;
-        OptionKeyboardStateVariant_Some Some;
+    struct OptionMouseStateVariant_None { OptionMouseStateTag tag; };
+    struct OptionMouseStateVariant_Some { OptionMouseStateTag tag; MouseState payload; };
+    union OptionMouseState {
+        OptionMouseStateVariant_None None;
+        OptionMouseStateVariant_Some Some;
     };
     
     
@@ -7038,6 +7971,15 @@ namespace dll {
         StringPair() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ImeComposition {
+        String text;
+        size_t cursor_start;
+        size_t cursor_end;
+        ImeComposition& operator=(const ImeComposition&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ImeComposition(const ImeComposition&) = delete; /* disable copy constructor, use explicit .clone() */
+        ImeComposition() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Monitor {
         size_t id;
         OptionString name;
@@ -7286,6 +8228,15 @@ namespace dll {
     };
     
     
+    struct DropDownChoice {
+        String label;
+        bool  disabled;
+        OptionString group;
+        DropDownChoice& operator=(const DropDownChoice&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownChoice(const DropDownChoice&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownChoice() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ColorInputStateWrapper {
         ColorInputState inner;
         String title;
@@ -7295,15 +8246,79 @@ namespace dll {
         ColorInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct TextInputState {
-        U32Vec text;
-        OptionString placeholder;
-        size_t max_len;
-        OptionTextInputSelection selection;
-        size_t cursor_pos;
-        TextInputState& operator=(const TextInputState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        TextInputState(const TextInputState&) = delete; /* disable copy constructor, use explicit .clone() */
-        TextInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    enum class TextInputValidationResultTag {
+       Valid,
+       Invalid,
+    };
+    
+    struct TextInputValidationResultVariant_Valid { TextInputValidationResultTag tag; };
+    struct TextInputValidationResultVariant_Invalid { TextInputValidationResultTag tag; String payload; };
+    union TextInputValidationResult {
+        TextInputValidationResultVariant_Valid Valid;
+        TextInputValidationResultVariant_Invalid Invalid;
+    };
+    
+    
+    struct TextInputMask {
+        String pattern;
+        TextInputMask& operator=(const TextInputMask&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputMask(const TextInputMask&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputMask() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerTab {
+        String title;
+        bool  closable;
+        size_t page;
+        TabContainerTab& operator=(const TabContainerTab&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerTab(const TabContainerTab&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerTab() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditState {
+        StringVec lines;
+        TextEditCursor cursor;
+        OptionTextEditCursor selection_anchor;
+        OptionUsize preferred_column;
+        size_t first_visible_row;
+        size_t visible_rows;
+        size_t wrap_column;
+        TextEditState& operator=(const TextEditState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditState(const TextEditState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditUndoEntry {
+        TextEditCursor start;
+        String removed;
+        String inserted;
+        TextEditCursor cursor_before;
+        TextEditEditKind kind;
+        TextEditUndoEntry& operator=(const TextEditUndoEntry&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditUndoEntry(const TextEditUndoEntry&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditUndoEntry() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewNodeData {
+        String label;
+        OptionImageRef icon;
+        TreeViewChildren children;
+        TreeViewNodeData& operator=(const TreeViewNodeData&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNodeData(const TreeViewNodeData&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewNodeData() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewRenderedRow {
+        OptionUsize node;
+        size_t depth;
+        String toggle;
+        OptionImageRef icon;
+        String label;
+        bool  is_selected;
+        bool  is_cursor;
+        TreeViewRenderedRow& operator=(const TreeViewRenderedRow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewRenderedRow(const TreeViewRenderedRow&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewRenderedRow() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct VertexAttribute {
@@ -7563,6 +8578,56 @@ namespace dll {
         VertexAttributeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct DropDownChoiceVec {
+        DropDownChoice* ptr;
+        size_t len;
+        size_t cap;
+        DropDownChoiceVecDestructor destructor;
+        DropDownChoiceVec& operator=(const DropDownChoiceVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownChoiceVec(const DropDownChoiceVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownChoiceVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerTabVec {
+        TabContainerTab* ptr;
+        size_t len;
+        size_t cap;
+        TabContainerTabVecDestructor destructor;
+        TabContainerTabVec& operator=(const TabContainerTabVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerTabVec(const TabContainerTabVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerTabVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditUndoEntryVec {
+        TextEditUndoEntry* ptr;
+        size_t len;
+        size_t cap;
+        TextEditUndoEntryVecDestructor destructor;
+        TextEditUndoEntryVec& operator=(const TextEditUndoEntryVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditUndoEntryVec(const TextEditUndoEntryVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditUndoEntryVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewNodeDataVec {
+        TreeViewNodeData* ptr;
+        size_t len;
+        size_t cap;
+        TreeViewNodeDataVecDestructor destructor;
+        TreeViewNodeDataVec& operator=(const TreeViewNodeDataVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNodeDataVec(const TreeViewNodeDataVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewNodeDataVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewRenderedRowVec {
+        TreeViewRenderedRow* ptr;
+        size_t len;
+        size_t cap;
+        TreeViewRenderedRowVecDestructor destructor;
+        TreeViewRenderedRowVec& operator=(const TreeViewRenderedRowVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewRenderedRowVec(const TreeViewRenderedRowVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewRenderedRowVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CssPathSelectorVec {
         CssPathSelector* ptr;
         size_t len;
@@ -7603,6 +8668,32 @@ namespace dll {
         StringPairVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class OptionTextInputMaskTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTextInputMaskVariant_None { OptionTextInputMaskTag tag; };
+    struct OptionTextInputMaskVariant_Some { OptionTextInputMaskTag tag; TextInputMask payload; };
+    union OptionTextInputMask {
+        OptionTextInputMaskVariant_None None;
+        OptionTextInputMaskVariant_Some Some;
+    };
+    
+    
+    enum class OptionImeCompositionTag {
+       None,
+       Some,
+    };
+    
+    struct OptionImeCompositionVariant_None { OptionImeCompositionTag tag; };
+    struct OptionImeCompositionVariant_Some { OptionImeCompositionTag tag; ImeComposition payload; };
+    union OptionImeComposition {
+        OptionImeCompositionVariant_None None;
+        OptionImeCompositionVariant_Some Some;
+    };
+    
+    
     enum class OptionFileTypeListTag {
        None,
        Some,
@@ -7714,6 +8805,21 @@ namespace dll {
     };
     
     
+    struct KeyboardState {
+        bool  shift_down;
+        bool  ctrl_down;
+        bool  alt_down;
+        bool  super_down;
+        OptionChar current_char;
+        OptionVirtualKeyCode current_virtual_keycode;
+        VirtualKeyCodeVec pressed_virtual_keycodes;
+        ScanCodeVec pressed_scancodes;
+        OptionImeComposition ime_composition;
+        KeyboardState& operator=(const KeyboardState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardState(const KeyboardState&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeyboardState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LinuxWindowOptions {
         OptionX11Visual x11_visual;
         OptionI32 x11_screen;
@@ -8024,16 +9130,78 @@ namespace dll {
     };
     
     
-    struct TextInputStateWrapper {
-        TextInputState inner;
-        OptionTextInputOnTextInput on_text_input;
-        OptionTextInputOnVirtualKeyDown on_virtual_key_down;
-        OptionTextInputOnFocusLost on_focus_lost;
-        bool  update_text_input_before_calling_focus_lost_fn;
-        bool  update_text_input_before_calling_vk_down_fn;
-        TextInputStateWrapper& operator=(const TextInputStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        TextInputStateWrapper(const TextInputStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
-        TextInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    struct DropDownState {
+        DropDownChoiceVec choices;
+        OptionUsize selected;
+        OptionUsize highlighted;
+        bool  is_open;
+        String search;
+        DropDownState& operator=(const DropDownState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownState(const DropDownState&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextInputState {
+        U32Vec text;
+        OptionString placeholder;
+        size_t max_len;
+        OptionTextInputSelection selection;
+        size_t cursor_pos;
+        TextInputValidatorVec validators;
+        OptionTextInputMask mask;
+        OptionChar obscure_char;
+        TextInputValidationResult validation;
+        OptionImeComposition ime_composition;
+        TextInputState& operator=(const TextInputState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputState(const TextInputState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerState {
+        TabContainerTabVec tabs;
+        OptionUsize active_tab;
+        float scroll_offset;
+        TabContainerOverflow overflow;
+        bool  menu_open;
+        TabContainerState& operator=(const TabContainerState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerState(const TabContainerState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditStateWrapper {
+        TextEditState inner;
+        OptionTextEditOnTextChange on_text_change;
+        TextEditUndoEntryVec undo_stack;
+        TextEditUndoEntryVec redo_stack;
+        bool  wrap_lines;
+        float char_width;
+        StringVec rendered_rows;
+        bool  is_selecting;
+        TextEditStateWrapper& operator=(const TextEditStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditStateWrapper(const TextEditStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewNode {
+        TreeViewNodeData data;
+        OptionUsize parent;
+        OptionUsize first_child;
+        OptionUsize last_child;
+        OptionUsize previous_sibling;
+        OptionUsize next_sibling;
+        bool  is_expanded;
+        bool  is_selected;
+        TreeViewNode& operator=(const TreeViewNode&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNode(const TreeViewNode&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewLoadedChildren {
+        size_t node;
+        TreeViewNodeDataVec children;
+        TreeViewLoadedChildren& operator=(const TreeViewLoadedChildren&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewLoadedChildren(const TreeViewLoadedChildren&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewLoadedChildren() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     enum class CssPropertySourceTag {
@@ -8089,6 +9257,9 @@ namespace dll {
         StringPairVec attributes;
         XmlNodeVec children;
         OptionString text;
+        XmlSpan span;
+        XmlSpanVec attribute_spans;
+        XmlSpan text_span;
         XmlNode& operator=(const XmlNode&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         XmlNode(const XmlNode&) = delete; /* disable copy constructor, use explicit .clone() */
         XmlNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8124,6 +9295,16 @@ namespace dll {
         SvgMultiPolygonVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TreeViewNodeVec {
+        TreeViewNode* ptr;
+        size_t len;
+        size_t cap;
+        TreeViewNodeVecDestructor destructor;
+        TreeViewNodeVec& operator=(const TreeViewNodeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNodeVec(const TreeViewNodeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionCssPropertyTag {
        None,
        Some,
@@ -8137,6 +9318,19 @@ namespace dll {
     };
     
     
+    enum class OptionKeyboardStateTag {
+       None,
+       Some,
+    };
+    
+    struct OptionKeyboardStateVariant_None { OptionKeyboardStateTag tag; };
+    struct OptionKeyboardStateVariant_Some { OptionKeyboardStateTag tag; KeyboardState payload; };
+    union OptionKeyboardState {
+        OptionKeyboardStateVariant_None None;
+        OptionKeyboardStateVariant_Some Some;
+    };
+    
+    
     struct XmlTextError {
         XmlStreamError stream_error;
         SvgParseErrorPosition pos;
@@ -8198,6 +9392,7 @@ namespace dll {
         void* restrict datasets;
         bool * restrict stop_propagation;
         void* restrict focus_target;
+        void* restrict drag_started;
         void* words_cache;
         void* shaped_words_cache;
         void* positioned_words_cache;
@@ -8290,6 +9485,59 @@ namespace dll {
         DynamicCssProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct DropDownStateWrapper {
+        DropDownState inner;
+        OptionDropDownOnChoiceChange on_choice_change;
+        DropDownStateWrapper& operator=(const DropDownStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownStateWrapper(const DropDownStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextInputStateWrapper {
+        TextInputState inner;
+        OptionTextInputOnTextInput on_text_input;
+        OptionTextInputOnVirtualKeyDown on_virtual_key_down;
+        OptionTextInputOnFocusLost on_focus_lost;
+        bool  update_text_input_before_calling_focus_lost_fn;
+        bool  update_text_input_before_calling_vk_down_fn;
+        OptionTextInputOnValidationChange on_validation_change;
+        CssPropertyVec error_style;
+        CssPropertyVec style_before_error;
+        TextInputStateWrapper& operator=(const TextInputStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputStateWrapper(const TextInputStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabContainerStateWrapper {
+        TabContainerState inner;
+        OptionTabContainerOnTabChange on_tab_change;
+        OptionTabContainerOnTabClose on_tab_close;
+        OptionTabContainerOnTabMove on_tab_move;
+        bool  allow_reorder;
+        size_t tab_count;
+        OptionUsize pressed_tab;
+        OptionUsize dragged_tab;
+        OptionUsize drop_index;
+        TabContainerStateWrapper& operator=(const TabContainerStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainerStateWrapper(const TabContainerStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainerStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewState {
+        TreeViewNodeVec nodes;
+        OptionUsize first_root;
+        OptionUsize last_root;
+        TreeViewRowVec rows;
+        OptionUsize cursor;
+        OptionUsize selection_anchor;
+        TreeViewSelectionMode selection_mode;
+        size_t first_visible_row;
+        size_t visible_rows;
+        TreeViewState& operator=(const TreeViewState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewState(const TreeViewState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class SvgNodeTag {
        MultiPolygonCollection,
        MultiPolygon,
@@ -8480,6 +9728,18 @@ namespace dll {
         CheckBox() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct DropDown {
+        DropDownStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec header_style;
+        NodeDataInlineCssPropertyVec popup_style;
+        NodeDataInlineCssPropertyVec choice_style;
+        NodeDataInlineCssPropertyVec group_style;
+        DropDown& operator=(const DropDown&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDown(const DropDown&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDown() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Label {
         String text;
         NodeDataInlineCssPropertyVec style;
@@ -8501,6 +9761,7 @@ namespace dll {
         NodeDataInlineCssPropertyVec placeholder_style;
         NodeDataInlineCssPropertyVec container_style;
         NodeDataInlineCssPropertyVec label_style;
+        NodeDataInlineCssPropertyVec preedit_style;
         TextInput& operator=(const TextInput&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         TextInput(const TextInput&) = delete; /* disable copy constructor, use explicit .clone() */
         TextInput() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8524,6 +9785,86 @@ namespace dll {
         ProgressBar() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TabContainer {
+        TabContainerStateWrapper state;
+        DomVec pages;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec header_style;
+        NodeDataInlineCssPropertyVec tab_style;
+        NodeDataInlineCssPropertyVec close_button_style;
+        NodeDataInlineCssPropertyVec button_style;
+        NodeDataInlineCssPropertyVec menu_style;
+        NodeDataInlineCssPropertyVec page_style;
+        TabContainer& operator=(const TabContainer&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabContainer(const TabContainer&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabContainer() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Slider {
+        SliderStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec track_style;
+        NodeDataInlineCssPropertyVec fill_style;
+        NodeDataInlineCssPropertyVec thumb_style;
+        NodeDataInlineCssPropertyVec tick_style;
+        Slider& operator=(const Slider&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Slider(const Slider&) = delete; /* disable copy constructor, use explicit .clone() */
+        Slider() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEdit {
+        TextEditStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec row_style;
+        NodeDataInlineCssPropertyVec label_style;
+        NodeDataInlineCssPropertyVec selection_style;
+        NodeDataInlineCssPropertyVec caret_style;
+        TextEdit& operator=(const TextEdit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEdit(const TextEdit&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEdit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewStateWrapper {
+        TreeViewState inner;
+        OptionTreeViewOnLoadChildren on_load_children;
+        OptionTreeViewOnSelectionChange on_selection_change;
+        OptionTreeViewOnNodeMoved on_node_moved;
+        bool  allow_reorder;
+        TreeViewRenderedRowVec rendered_rows;
+        OptionUsize pressed_node;
+        OptionUsize dragged_node;
+        OptionTreeViewDropTarget drop_target;
+        TreeViewStateWrapper& operator=(const TreeViewStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewStateWrapper(const TreeViewStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualList {
+        RefAny state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec row_style;
+        NodeDataInlineCssPropertyVec scrollbar_track_style;
+        NodeDataInlineCssPropertyVec scrollbar_thumb_style;
+        VirtualList& operator=(const VirtualList&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualList(const VirtualList&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualList() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct VirtualListStateWrapper {
+        VirtualListState inner;
+        VirtualListRenderRow render_row;
+        OptionVirtualListOnScroll on_scroll;
+        NodeDataInlineCssPropertyVec row_style;
+        NodeDataInlineCssPropertyVec scrollbar_track_style;
+        NodeDataInlineCssPropertyVec scrollbar_thumb_style;
+        size_t rendered_rows_start;
+        size_t rendered_rows_end;
+        OptionVirtualListIFrameNodes iframe_nodes;
+        VirtualListStateWrapper& operator=(const VirtualListStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualListStateWrapper(const VirtualListStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualListStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CssDeclarationVec {
         CssDeclaration* ptr;
         size_t len;
@@ -8619,6 +9960,33 @@ namespace dll {
         CssRuleBlock() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SplitPane {
+        SplitPaneStateWrapper state;
+        Dom first_pane;
+        Dom second_pane;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec pane_style;
+        NodeDataInlineCssPropertyVec divider_style;
+        SplitPane& operator=(const SplitPane&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitPane(const SplitPane&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitPane() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeView {
+        TreeViewStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec row_style;
+        NodeDataInlineCssPropertyVec guides_style;
+        NodeDataInlineCssPropertyVec toggle_style;
+        NodeDataInlineCssPropertyVec icon_style;
+        NodeDataInlineCssPropertyVec label_style;
+        NodeDataInlineCssPropertyVec scrollbar_thumb_style;
+        NodeDataInlineCssPropertyVec drop_indicator_style;
+        TreeView& operator=(const TreeView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeView(const TreeView&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeView() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyledDom {
         NodeId root;
         NodeVec node_hierarchy;
@@ -8785,6 +10153,7 @@ namespace dll {
         void WaylandTheme_delete(WaylandTheme* restrict instance);
         void StringPair_delete(StringPair* restrict instance);
         void LinuxWindowOptions_delete(LinuxWindowOptions* restrict instance);
+        void ImeComposition_delete(ImeComposition* restrict instance);
         void Monitor_delete(Monitor* restrict instance);
         WindowState WindowState_new(AzLayoutCallbackType  layout_callback);
         WindowState WindowState_default();
@@ -8792,6 +10161,7 @@ namespace dll {
         void LayoutCallback_delete(LayoutCallback* restrict instance);
         void MarshaledLayoutCallback_delete(MarshaledLayoutCallback* restrict instance);
         DomNodeId CallbackInfo_getHitNode(const CallbackInfo* callbackinfo);
+        OptionDomNodeId CallbackInfo_getFocusedNode(const CallbackInfo* callbackinfo);
         GetSystemTimeFn CallbackInfo_getSystemTimeFn(const CallbackInfo* callbackinfo);
         OptionLogicalPosition CallbackInfo_getCursorRelativeToViewport(const CallbackInfo* callbackinfo);
         OptionLogicalPosition CallbackInfo_getCursorRelativeToNode(const CallbackInfo* callbackinfo);
@@ -8838,6 +10208,8 @@ namespace dll {
         OptionThreadId CallbackInfo_startThread(CallbackInfo* restrict callbackinfo, AzRefAny  thread_initialize_data, AzRefAny  writeback_data, AzThreadCallback  callback);
         bool  CallbackInfo_sendThreadMsg(CallbackInfo* restrict callbackinfo, AzThreadId  thread_id, AzThreadSendMsg  msg);
         bool  CallbackInfo_stopThread(CallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
+        bool  CallbackInfo_startDrag(CallbackInfo* restrict callbackinfo, AzRefAny  payload, AzOptionImageRef  ghost);
+        OptionDragData CallbackInfo_getDragData(const CallbackInfo* callbackinfo);
         void CallbackInfo_delete(CallbackInfo* restrict instance);
         LogicalSize HidpiAdjustedBounds_getLogicalSize(const HidpiAdjustedBounds* hidpiadjustedbounds);
         PhysicalSizeU32 HidpiAdjustedBounds_getPhysicalSize(const HidpiAdjustedBounds* hidpiadjustedbounds);
@@ -8848,6 +10220,7 @@ namespace dll {
         void InlineWord_delete(InlineWord* restrict instance);
         void InlineTextContents_delete(InlineTextContents* restrict instance);
         void FocusTarget_delete(FocusTarget* restrict instance);
+        void DragData_delete(DragData* restrict instance);
         void FocusTargetPath_delete(FocusTargetPath* restrict instance);
         void Animation_delete(Animation* restrict instance);
         void IFrameCallbackReturn_delete(IFrameCallbackReturn* restrict instance);
@@ -8919,6 +10292,12 @@ namespace dll {
         Dom Dom_withClipMask(Dom* restrict dom, AzImageMask  clip_mask);
         void Dom_setTabIndex(Dom* restrict dom, AzTabIndex  tab_index);
         Dom Dom_withTabIndex(Dom* restrict dom, AzTabIndex  tab_index);
+        void Dom_setKey(Dom* restrict dom, uint64_t key);
+        Dom Dom_withKey(Dom* restrict dom, uint64_t key);
+        void Dom_setFocusScope(Dom* restrict dom, AzFocusScope  focus_scope);
+        Dom Dom_withFocusScope(Dom* restrict dom, AzFocusScope  focus_scope);
+        void Dom_addShortcut(Dom* restrict dom, AzVirtualKeyCodeCombo  combo, AzRefAny  data, AzCallbackType  callback);
+        Dom Dom_withShortcut(Dom* restrict dom, AzVirtualKeyCodeCombo  combo, AzRefAny  data, AzCallbackType  callback);
         void Dom_setAccessibilityInfo(Dom* restrict dom, AzAccessibilityInfo  accessibility_info);
         Dom Dom_withAccessibilityInfo(Dom* restrict dom, AzAccessibilityInfo  accessibility_info);
         void Dom_setMenuBar(Dom* restrict dom, AzMenu  menu_bar);
@@ -8954,6 +10333,9 @@ namespace dll {
         NodeData NodeData_withInlineCssProps(NodeData* restrict nodedata, AzNodeDataInlineCssPropertyVec  css_properties);
         void NodeData_setClipMask(NodeData* restrict nodedata, AzImageMask  image_mask);
         void NodeData_setTabIndex(NodeData* restrict nodedata, AzTabIndex  tab_index);
+        void NodeData_setKey(NodeData* restrict nodedata, uint64_t key);
+        void NodeData_setFocusScope(NodeData* restrict nodedata, AzFocusScope  focus_scope);
+        void NodeData_addShortcut(NodeData* restrict nodedata, AzVirtualKeyCodeCombo  combo, AzRefAny  data, AzCallbackType  callback);
         void NodeData_setAccessibilityInfo(NodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
         void NodeData_setMenuBar(NodeData* restrict nodedata, AzMenu  menu_bar);
         void NodeData_setContextMenu(NodeData* restrict nodedata, AzMenu  context_menu);
@@ -9024,6 +10406,26 @@ namespace dll {
         void CheckBox_delete(CheckBox* restrict instance);
         void CheckBoxStateWrapper_delete(CheckBoxStateWrapper* restrict instance);
         void CheckBoxOnToggle_delete(CheckBoxOnToggle* restrict instance);
+        DropDown DropDown_new(AzDropDownChoiceVec  choices);
+        DropDown DropDown_fromChoicesStr(AzString  choices);
+        void DropDown_setSelected(DropDown* restrict dropdown, AzOptionUsize  selected);
+        DropDown DropDown_withSelected(DropDown* restrict dropdown, size_t selected);
+        DropDown DropDown_withSelectedLabel(DropDown* restrict dropdown, AzString  label);
+        void DropDown_setOnChoiceChange(DropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+        DropDown DropDown_withOnChoiceChange(DropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+        void DropDown_setContainerStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+        void DropDown_setHeaderStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+        void DropDown_setPopupStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+        void DropDown_setChoiceStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+        void DropDown_setGroupStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  style);
+        Dom DropDown_dom(DropDown* restrict dropdown);
+        void DropDown_delete(DropDown* restrict instance);
+        void DropDownStateWrapper_delete(DropDownStateWrapper* restrict instance);
+        bool  DropDownState_isSelectable(const DropDownState* dropdownstate, size_t index);
+        void DropDownState_delete(DropDownState* restrict instance);
+        DropDownChoice DropDownChoice_new(AzString  label);
+        void DropDownChoice_delete(DropDownChoice* restrict instance);
+        void DropDownOnChoiceChange_delete(DropDownOnChoiceChange* restrict instance);
         Label Label_new(AzString  text);
         Dom Label_dom(Label* restrict label);
         void Label_delete(Label* restrict instance);
@@ -9047,14 +10449,35 @@ namespace dll {
         TextInput TextInput_withContainerStyle(TextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  container_style);
         void TextInput_setLabelStyle(TextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  label_style);
         TextInput TextInput_withLabelStyle(TextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  label_style);
+        void TextInput_setOnValidationChange(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnValidationChangeCallbackType  callback);
+        void TextInput_addValidator(TextInput* restrict textinput, AzTextInputValidator  validator);
+        TextInput TextInput_withValidator(TextInput* restrict textinput, AzTextInputValidator  validator);
+        void TextInput_setMask(TextInput* restrict textinput, AzTextInputMask  mask);
+        TextInput TextInput_withMask(TextInput* restrict textinput, AzTextInputMask  mask);
+        void TextInput_setObscureChar(TextInput* restrict textinput, AzOptionChar  obscure_char);
+        TextInput TextInput_withPasswordMode(TextInput* restrict textinput);
+        void TextInput_setErrorStyle(TextInput* restrict textinput, AzCssPropertyVec  error_style);
+        void TextInput_setPreeditStyle(TextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  preedit_style);
         Dom TextInput_dom(TextInput* restrict textinput);
         void TextInput_delete(TextInput* restrict instance);
         void TextInputStateWrapper_delete(TextInputStateWrapper* restrict instance);
         String TextInputState_getText(const TextInputState* textinputstate);
+        String TextInputState_getDisplayText(const TextInputState* textinputstate);
+        TextInputValidationResult TextInputState_validate(const TextInputState* textinputstate);
         void TextInputState_delete(TextInputState* restrict instance);
         void TextInputOnTextInput_delete(TextInputOnTextInput* restrict instance);
         void TextInputOnVirtualKeyDown_delete(TextInputOnVirtualKeyDown* restrict instance);
         void TextInputOnFocusLost_delete(TextInputOnFocusLost* restrict instance);
+        void TextInputValidationResult_delete(TextInputValidationResult* restrict instance);
+        void TextInputValidator_delete(TextInputValidator* restrict instance);
+        void TextInputCustomValidator_delete(TextInputCustomValidator* restrict instance);
+        TextInputMask TextInputMask_new(AzString  pattern);
+        TextInputMask TextInputMask_date();
+        TextInputMask TextInputMask_time();
+        TextInputMask TextInputMask_phone();
+        TextInputMask TextInputMask_ipv4();
+        void TextInputMask_delete(TextInputMask* restrict instance);
+        void TextInputOnValidationChange_delete(TextInputOnValidationChange* restrict instance);
         NumberInput NumberInput_new(float number);
         void NumberInput_setOnTextInput(NumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
         NumberInput NumberInput_withOnTextInput(NumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
@@ -9083,6 +10506,178 @@ namespace dll {
         ProgressBar ProgressBar_withLabelStyle(ProgressBar* restrict progressbar, AzNodeDataInlineCssPropertyVec  style);
         Dom ProgressBar_dom(ProgressBar* restrict progressbar);
         void ProgressBar_delete(ProgressBar* restrict instance);
+        SplitPane SplitPane_new(AzSplitPaneOrientation  orientation, AzDom  first_pane, AzDom  second_pane);
+        void SplitPane_setRatio(SplitPane* restrict splitpane, float ratio);
+        SplitPane SplitPane_withRatio(SplitPane* restrict splitpane, float ratio);
+        void SplitPane_setMinSizes(SplitPane* restrict splitpane, float min_first_size, float min_second_size);
+        SplitPane SplitPane_withMinSizes(SplitPane* restrict splitpane, float min_first_size, float min_second_size);
+        void SplitPane_setCollapsible(SplitPane* restrict splitpane, bool  collapsible);
+        SplitPane SplitPane_withCollapsible(SplitPane* restrict splitpane, bool  collapsible);
+        void SplitPane_setCollapsed(SplitPane* restrict splitpane, AzSplitPaneCollapsed  collapsed);
+        SplitPane SplitPane_withCollapsed(SplitPane* restrict splitpane, AzSplitPaneCollapsed  collapsed);
+        void SplitPane_setOnResize(SplitPane* restrict splitpane, AzRefAny  data, AzSplitPaneOnResizeCallbackType  callback);
+        SplitPane SplitPane_withOnResize(SplitPane* restrict splitpane, AzRefAny  data, AzSplitPaneOnResizeCallbackType  callback);
+        void SplitPane_setContainerStyle(SplitPane* restrict splitpane, AzNodeDataInlineCssPropertyVec  style);
+        void SplitPane_setPaneStyle(SplitPane* restrict splitpane, AzNodeDataInlineCssPropertyVec  style);
+        void SplitPane_setDividerStyle(SplitPane* restrict splitpane, AzNodeDataInlineCssPropertyVec  style);
+        Dom SplitPane_dom(SplitPane* restrict splitpane);
+        void SplitPane_delete(SplitPane* restrict instance);
+        void SplitPaneStateWrapper_delete(SplitPaneStateWrapper* restrict instance);
+        void SplitPaneOnResize_delete(SplitPaneOnResize* restrict instance);
+        TabContainer TabContainer_new();
+        TabContainer TabContainer_fromTitlesStr(AzString  titles, AzDomVec  pages);
+        void TabContainer_addTab(TabContainer* restrict tabcontainer, AzString  title, AzDom  content);
+        TabContainer TabContainer_withTab(TabContainer* restrict tabcontainer, AzString  title, AzDom  content);
+        void TabContainer_setActiveTab(TabContainer* restrict tabcontainer, size_t tab);
+        TabContainer TabContainer_withActiveTab(TabContainer* restrict tabcontainer, size_t tab);
+        void TabContainer_setClosable(TabContainer* restrict tabcontainer, bool  closable);
+        TabContainer TabContainer_withClosable(TabContainer* restrict tabcontainer, bool  closable);
+        void TabContainer_setOverflow(TabContainer* restrict tabcontainer, AzTabContainerOverflow  overflow);
+        TabContainer TabContainer_withOverflow(TabContainer* restrict tabcontainer, AzTabContainerOverflow  overflow);
+        void TabContainer_setAllowReorder(TabContainer* restrict tabcontainer, bool  allow_reorder);
+        TabContainer TabContainer_withAllowReorder(TabContainer* restrict tabcontainer, bool  allow_reorder);
+        void TabContainer_setOnTabChange(TabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabChangeCallbackType  callback);
+        TabContainer TabContainer_withOnTabChange(TabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabChangeCallbackType  callback);
+        void TabContainer_setOnTabClose(TabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabCloseCallbackType  callback);
+        TabContainer TabContainer_withOnTabClose(TabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabCloseCallbackType  callback);
+        void TabContainer_setOnTabMove(TabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabMoveCallbackType  callback);
+        TabContainer TabContainer_withOnTabMove(TabContainer* restrict tabcontainer, AzRefAny  data, AzTabContainerOnTabMoveCallbackType  callback);
+        void TabContainer_setContainerStyle(TabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+        void TabContainer_setHeaderStyle(TabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+        void TabContainer_setTabStyle(TabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+        void TabContainer_setCloseButtonStyle(TabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+        void TabContainer_setButtonStyle(TabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+        void TabContainer_setMenuStyle(TabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+        void TabContainer_setPageStyle(TabContainer* restrict tabcontainer, AzNodeDataInlineCssPropertyVec  style);
+        Dom TabContainer_dom(TabContainer* restrict tabcontainer);
+        void TabContainer_delete(TabContainer* restrict instance);
+        void TabContainerStateWrapper_delete(TabContainerStateWrapper* restrict instance);
+        void TabContainerState_delete(TabContainerState* restrict instance);
+        void TabContainerTab_delete(TabContainerTab* restrict instance);
+        void TabContainerOnTabChange_delete(TabContainerOnTabChange* restrict instance);
+        void TabContainerOnTabClose_delete(TabContainerOnTabClose* restrict instance);
+        void TabContainerOnTabMove_delete(TabContainerOnTabMove* restrict instance);
+        Slider Slider_new(float value, float min, float max);
+        Slider Slider_newRange(float lower, float upper, float min, float max);
+        void Slider_setOrientation(Slider* restrict slider, AzSliderOrientation  orientation);
+        Slider Slider_withOrientation(Slider* restrict slider, AzSliderOrientation  orientation);
+        void Slider_setStep(Slider* restrict slider, float step);
+        Slider Slider_withStep(Slider* restrict slider, float step);
+        void Slider_setTickInterval(Slider* restrict slider, AzOptionF32  tick_interval);
+        Slider Slider_withTickInterval(Slider* restrict slider, float tick_interval);
+        void Slider_setOnValueChange(Slider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+        Slider Slider_withOnValueChange(Slider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+        void Slider_setContainerStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+        void Slider_setTrackStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+        void Slider_setFillStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+        void Slider_setThumbStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+        void Slider_setTickStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  style);
+        Dom Slider_dom(Slider* restrict slider);
+        void Slider_delete(Slider* restrict instance);
+        void SliderStateWrapper_delete(SliderStateWrapper* restrict instance);
+        void SliderOnValueChange_delete(SliderOnValueChange* restrict instance);
+        TextEdit TextEdit_new(AzString  text);
+        TextEdit TextEdit_fromState(AzTextEditState  state);
+        void TextEdit_setVisibleRows(TextEdit* restrict textedit, size_t rows);
+        TextEdit TextEdit_withVisibleRows(TextEdit* restrict textedit, size_t rows);
+        void TextEdit_setWrapLines(TextEdit* restrict textedit, bool  wrap_lines);
+        TextEdit TextEdit_withWrapLines(TextEdit* restrict textedit, bool  wrap_lines);
+        void TextEdit_setOnTextChange(TextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+        TextEdit TextEdit_withOnTextChange(TextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+        void TextEdit_setContainerStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+        void TextEdit_setRowStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+        void TextEdit_setLabelStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+        void TextEdit_setSelectionStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+        void TextEdit_setCaretStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  style);
+        Dom TextEdit_dom(TextEdit* restrict textedit);
+        void TextEdit_delete(TextEdit* restrict instance);
+        void TextEditStateWrapper_delete(TextEditStateWrapper* restrict instance);
+        String TextEditState_getText(const TextEditState* texteditstate);
+        void TextEditState_delete(TextEditState* restrict instance);
+        void TextEditUndoEntry_delete(TextEditUndoEntry* restrict instance);
+        void TextEditOnTextChange_delete(TextEditOnTextChange* restrict instance);
+        TreeView TreeView_new(AzTreeViewState  state);
+        TreeView TreeView_fromPathsStr(AzString  paths);
+        void TreeView_setVisibleRows(TreeView* restrict treeview, size_t rows);
+        TreeView TreeView_withVisibleRows(TreeView* restrict treeview, size_t rows);
+        void TreeView_setSelectionMode(TreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+        TreeView TreeView_withSelectionMode(TreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+        void TreeView_setAllowReorder(TreeView* restrict treeview, bool  allow_reorder);
+        TreeView TreeView_withAllowReorder(TreeView* restrict treeview, bool  allow_reorder);
+        void TreeView_setOnLoadChildren(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLoadChildrenCallbackType  callback);
+        TreeView TreeView_withOnLoadChildren(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLoadChildrenCallbackType  callback);
+        void TreeView_setOnSelectionChange(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectionChangeCallbackType  callback);
+        TreeView TreeView_withOnSelectionChange(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectionChangeCallbackType  callback);
+        void TreeView_setOnNodeMoved(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnNodeMovedCallbackType  callback);
+        TreeView TreeView_withOnNodeMoved(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnNodeMovedCallbackType  callback);
+        void TreeView_setContainerStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        void TreeView_setRowStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        void TreeView_setGuidesStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        void TreeView_setToggleStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        void TreeView_setIconStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        void TreeView_setLabelStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        void TreeView_setScrollbarThumbStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        void TreeView_setDropIndicatorStyle(TreeView* restrict treeview, AzNodeDataInlineCssPropertyVec  style);
+        Dom TreeView_dom(TreeView* restrict treeview);
+        void TreeView_delete(TreeView* restrict instance);
+        void TreeViewStateWrapper_delete(TreeViewStateWrapper* restrict instance);
+        TreeViewState TreeViewState_new();
+        size_t TreeViewState_addNode(TreeViewState* restrict treeviewstate, AzOptionUsize  parent, AzTreeViewNodeData  data);
+        void TreeViewState_setChildren(TreeViewState* restrict treeviewstate, size_t node_id, AzTreeViewNodeDataVec  children);
+        void TreeViewState_updateRows(TreeViewState* restrict treeviewstate);
+        bool  TreeViewState_expand(TreeViewState* restrict treeviewstate, size_t node_id);
+        void TreeViewState_collapse(TreeViewState* restrict treeviewstate, size_t node_id);
+        void TreeViewState_reveal(TreeViewState* restrict treeviewstate, size_t node_id);
+        bool  TreeViewState_isExpanded(const TreeViewState* treeviewstate, size_t node_id);
+        void TreeViewState_clearSelection(TreeViewState* restrict treeviewstate);
+        void TreeViewState_setSelected(TreeViewState* restrict treeviewstate, size_t node_id, bool  selected);
+        void TreeViewState_select(TreeViewState* restrict treeviewstate, size_t node_id, bool  extend, bool  toggle);
+        bool  TreeViewState_moveNode(TreeViewState* restrict treeviewstate, size_t node_id, AzTreeViewDropTarget  target);
+        void TreeViewState_delete(TreeViewState* restrict instance);
+        void TreeViewNode_delete(TreeViewNode* restrict instance);
+        TreeViewNodeData TreeViewNodeData_new(AzString  label);
+        TreeViewNodeData TreeViewNodeData_withIcon(TreeViewNodeData* restrict treeviewnodedata, AzImageRef  icon);
+        TreeViewNodeData TreeViewNodeData_withLazyChildren(TreeViewNodeData* restrict treeviewnodedata);
+        void TreeViewNodeData_delete(TreeViewNodeData* restrict instance);
+        void TreeViewRenderedRow_delete(TreeViewRenderedRow* restrict instance);
+        void TreeViewLoadRequest_delete(TreeViewLoadRequest* restrict instance);
+        TreeViewLoadedChildren TreeViewLoadedChildren_new(size_t node, AzTreeViewNodeDataVec  children);
+        ThreadWriteBackMsg TreeViewLoadedChildren_intoWritebackMsg(TreeViewLoadedChildren* restrict treeviewloadedchildren);
+        void TreeViewLoadedChildren_delete(TreeViewLoadedChildren* restrict instance);
+        void TreeViewOnLoadChildren_delete(TreeViewOnLoadChildren* restrict instance);
+        void TreeViewOnSelectionChange_delete(TreeViewOnSelectionChange* restrict instance);
+        void TreeViewOnNodeMoved_delete(TreeViewOnNodeMoved* restrict instance);
+        VirtualList VirtualList_new(size_t row_count, float row_height, AzRefAny  data, AzVirtualListRenderRowCallbackType  render_row);
+        VirtualList VirtualList_fromState(AzRefAny  state);
+        RefAny VirtualList_getState(const VirtualList* virtuallist);
+        void VirtualList_setRowCount(VirtualList* restrict virtuallist, size_t row_count);
+        VirtualList VirtualList_withRowCount(VirtualList* restrict virtuallist, size_t row_count);
+        void VirtualList_setRowHeight(VirtualList* restrict virtuallist, AzVirtualListRowHeight  row_height);
+        VirtualList VirtualList_withRowHeight(VirtualList* restrict virtuallist, AzVirtualListRowHeight  row_height);
+        void VirtualList_setScrollOffset(VirtualList* restrict virtuallist, float scroll_offset);
+        VirtualList VirtualList_withScrollOffset(VirtualList* restrict virtuallist, float scroll_offset);
+        void VirtualList_setOverscan(VirtualList* restrict virtuallist, size_t overscan);
+        VirtualList VirtualList_withOverscan(VirtualList* restrict virtuallist, size_t overscan);
+        void VirtualList_setOnScroll(VirtualList* restrict virtuallist, AzRefAny  data, AzVirtualListOnScrollCallbackType  callback);
+        VirtualList VirtualList_withOnScroll(VirtualList* restrict virtuallist, AzRefAny  data, AzVirtualListOnScrollCallbackType  callback);
+        void VirtualList_setContainerStyle(VirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+        void VirtualList_setRowStyle(VirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+        void VirtualList_setScrollbarTrackStyle(VirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+        void VirtualList_setScrollbarThumbStyle(VirtualList* restrict virtuallist, AzNodeDataInlineCssPropertyVec  style);
+        Dom VirtualList_dom(VirtualList* restrict virtuallist);
+        void VirtualList_delete(VirtualList* restrict instance);
+        void VirtualListStateWrapper_delete(VirtualListStateWrapper* restrict instance);
+        VirtualListState VirtualListState_new(size_t row_count, AzVirtualListRowHeight  row_height);
+        float VirtualListState_getDefaultRowHeight(const VirtualListState* virtualliststate);
+        float VirtualListState_getRowHeight(const VirtualListState* virtualliststate, size_t row);
+        float VirtualListState_getRowTop(const VirtualListState* virtualliststate, size_t row);
+        size_t VirtualListState_getRowAtOffset(const VirtualListState* virtualliststate, float offset);
+        float VirtualListState_getTotalHeight(const VirtualListState* virtualliststate);
+        float VirtualListState_getMaxScrollOffset(const VirtualListState* virtualliststate);
+        float VirtualListState_clampScrollOffset(const VirtualListState* virtualliststate, float scroll_offset);
+        void VirtualListState_delete(VirtualListState* restrict instance);
+        void VirtualListRenderRow_delete(VirtualListRenderRow* restrict instance);
+        void VirtualListOnScroll_delete(VirtualListOnScroll* restrict instance);
         void CssPropertySource_delete(CssPropertySource* restrict instance);
         void TagIdToNodeIdMapping_delete(TagIdToNodeIdMapping* restrict instance);
         void CssPropertyCache_delete(CssPropertyCache* restrict instance);
@@ -9471,6 +11066,7 @@ namespace dll {
         void TessellatedSvgNodeVec_delete(TessellatedSvgNodeVec* restrict instance);
         void StyleFontFamilyVec_delete(StyleFontFamilyVec* restrict instance);
         void XmlNodeVec_delete(XmlNodeVec* restrict instance);
+        void XmlSpanVec_delete(XmlSpanVec* restrict instance);
         void FmtArgVec_delete(FmtArgVec* restrict instance);
         void InlineLineVec_delete(InlineLineVec* restrict instance);
         void InlineWordVec_delete(InlineWordVec* restrict instance);
@@ -9497,6 +11093,15 @@ namespace dll {
         void VirtualKeyCodeVec_delete(VirtualKeyCodeVec* restrict instance);
         void CascadeInfoVec_delete(CascadeInfoVec* restrict instance);
         void ScanCodeVec_delete(ScanCodeVec* restrict instance);
+        void TextInputValidatorVec_delete(TextInputValidatorVec* restrict instance);
+        void DropDownChoiceVec_delete(DropDownChoiceVec* restrict instance);
+        void TabContainerTabVec_delete(TabContainerTabVec* restrict instance);
+        void TextEditUndoEntryVec_delete(TextEditUndoEntryVec* restrict instance);
+        void TreeViewNodeDataVec_delete(TreeViewNodeDataVec* restrict instance);
+        void TreeViewNodeVec_delete(TreeViewNodeVec* restrict instance);
+        void TreeViewRowVec_delete(TreeViewRowVec* restrict instance);
+        void TreeViewRenderedRowVec_delete(TreeViewRenderedRowVec* restrict instance);
+        void VirtualListMeasuredRowVec_delete(VirtualListMeasuredRowVec* restrict instance);
         void CssDeclarationVec_delete(CssDeclarationVec* restrict instance);
         void CssPathSelectorVec_delete(CssPathSelectorVec* restrict instance);
         void StylesheetVec_delete(StylesheetVec* restrict instance);
@@ -9526,12 +11131,27 @@ namespace dll {
         void OptionTextInputOnTextInput_delete(OptionTextInputOnTextInput* restrict instance);
         void OptionTextInputOnVirtualKeyDown_delete(OptionTextInputOnVirtualKeyDown* restrict instance);
         void OptionTextInputOnFocusLost_delete(OptionTextInputOnFocusLost* restrict instance);
+        void OptionTextInputMask_delete(OptionTextInputMask* restrict instance);
+        void OptionTextInputOnValidationChange_delete(OptionTextInputOnValidationChange* restrict instance);
+        void OptionDropDownOnChoiceChange_delete(OptionDropDownOnChoiceChange* restrict instance);
+        void OptionTabContainerOnTabChange_delete(OptionTabContainerOnTabChange* restrict instance);
+        void OptionTabContainerOnTabClose_delete(OptionTabContainerOnTabClose* restrict instance);
+        void OptionTabContainerOnTabMove_delete(OptionTabContainerOnTabMove* restrict instance);
+        void OptionSplitPaneOnResize_delete(OptionSplitPaneOnResize* restrict instance);
+        void OptionSliderOnValueChange_delete(OptionSliderOnValueChange* restrict instance);
+        void OptionTextEditOnTextChange_delete(OptionTextEditOnTextChange* restrict instance);
+        void OptionTreeViewOnLoadChildren_delete(OptionTreeViewOnLoadChildren* restrict instance);
+        void OptionTreeViewOnSelectionChange_delete(OptionTreeViewOnSelectionChange* restrict instance);
+        void OptionTreeViewOnNodeMoved_delete(OptionTreeViewOnNodeMoved* restrict instance);
+        void OptionVirtualListOnScroll_delete(OptionVirtualListOnScroll* restrict instance);
         void OptionNumberInputOnValueChange_delete(OptionNumberInputOnValueChange* restrict instance);
         void OptionMenuItemIcon_delete(OptionMenuItemIcon* restrict instance);
         void OptionMenuCallback_delete(OptionMenuCallback* restrict instance);
         void OptionVirtualKeyCodeCombo_delete(OptionVirtualKeyCodeCombo* restrict instance);
         void OptionCssProperty_delete(OptionCssProperty* restrict instance);
         void OptionImageRef_delete(OptionImageRef* restrict instance);
+        void OptionDragData_delete(OptionDragData* restrict instance);
+        void OptionImeComposition_delete(OptionImeComposition* restrict instance);
         void OptionFontRef_delete(OptionFontRef* restrict instance);
         void OptionSystemClipboard_delete(OptionSystemClipboard* restrict instance);
         void OptionFileTypeList_delete(OptionFileTypeList* restrict instance);
@@ -1151,6 +1151,13 @@ mod dll {
         TouchMove,
         TouchEnd,
         TouchCancel,
+        DragStart,
+        DragEnter,
+        DragOver,
+        DragLeave,
+        Drop,
+        DragEnd,
+        ImeComposition,
     }
 
     /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -1179,6 +1186,7 @@ mod dll {
         VirtualKeyUp,
         FocusReceived,
         FocusLost,
+        ImeComposition,
     }
 
     /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -1218,6 +1226,11 @@ mod dll {
         FocusLost,
         CloseRequested,
         ThemeChanged,
+        DragStart,
+        DragOver,
+        Drop,
+        DragEnd,
+        ImeComposition,
     }
 
     /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1353,6 +1366,18 @@ mod dll {
         NoKeyboardFocus,
     }
 
+    /// Re-export of rust-allocated (stack based) `RovingFocusOrientation` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzRovingFocusOrientation {
+        Horizontal,
+        Vertical,
+        Both,
+    }
+
     /// Determines whether this context menu should pop up on a left, right or middle click
     #[repr(C)]
     #[derive(Debug)]
@@ -1853,6 +1878,16 @@ mod dll {
         pub checked: bool,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzDropDownOnChoiceChangeCallback {
+        pub cb: AzDropDownOnChoiceChangeCallbackType,
+    }
+
+    /// `AzDropDownOnChoiceChangeCallbackType` struct
+    pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `ColorInputOnValueChangeCallback` struct
     #[repr(C)]
     #[derive(Clone)]
@@ -1913,6 +1948,26 @@ mod dll {
         No,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextInputValidatorCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTextInputValidatorCallback {
+        pub cb: AzTextInputValidatorCallbackType,
+    }
+
+    /// `AzTextInputValidatorCallbackType` struct
+    pub type AzTextInputValidatorCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState) -> AzTextInputValidationResult;
+
+    /// Re-export of rust-allocated (stack based) `TextInputOnValidationChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTextInputOnValidationChangeCallback {
+        pub cb: AzTextInputOnValidationChangeCallbackType,
+    }
+
+    /// `AzTextInputOnValidationChangeCallbackType` struct
+    pub type AzTextInputOnValidationChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `NumberInputState` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -1945,6 +2000,265 @@ mod dll {
         pub display_percentage: bool,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPaneOrientation` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzSplitPaneOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitPaneCollapsed` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzSplitPaneCollapsed {
+        None,
+        First,
+        Second,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitPaneOnResizeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSplitPaneOnResizeCallback {
+        pub cb: AzSplitPaneOnResizeCallbackType,
+    }
+
+    /// `AzSplitPaneOnResizeCallbackType` struct
+    pub type AzSplitPaneOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSplitPaneState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// What happens if the tabs don't fit into the header
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTabContainerOverflow {
+        Scroll,
+        Menu,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTabContainerOnTabChangeCallback {
+        pub cb: AzTabContainerOnTabChangeCallbackType,
+    }
+
+    /// `AzTabContainerOnTabChangeCallbackType` struct
+    pub type AzTabContainerOnTabChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabCloseCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTabContainerOnTabCloseCallback {
+        pub cb: AzTabContainerOnTabCloseCallbackType,
+    }
+
+    /// `AzTabContainerOnTabCloseCallbackType` struct
+    pub type AzTabContainerOnTabCloseCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, AzTabContainerTab, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabMoveCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTabContainerOnTabMoveCallback {
+        pub cb: AzTabContainerOnTabMoveCallbackType,
+    }
+
+    /// `AzTabContainerOnTabMoveCallbackType` struct
+    pub type AzTabContainerOnTabMoveCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, usize, usize, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzSliderOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Identifies one of the thumbs, a non-range slider only has the `Lower` thumb
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzSliderThumb {
+        Lower,
+        Upper,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSliderOnValueChangeCallback {
+        pub cb: AzSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzSliderOnValueChangeCallbackType` struct
+    pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Position in the text: line index and character (not byte) index in the line
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditCursor {
+        pub line: usize,
+        pub column: usize,
+    }
+
+    /// Kind of an edit, consecutive edits of the same kind are undone together
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTextEditEditKind {
+        Insert,
+        DeleteBackward,
+        DeleteForward,
+        Other,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTextEditOnTextChangeCallback {
+        pub cb: AzTextEditOnTextChangeCallbackType,
+    }
+
+    /// `AzTextEditOnTextChangeCallbackType` struct
+    pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Whether a node has children and whether they have been loaded yet
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTreeViewChildren {
+        None,
+        NotLoaded,
+        Loading,
+        Loaded,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTreeViewSelectionMode {
+        None,
+        Single,
+        Multiple,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewDropPosition` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTreeViewDropPosition {
+        Before,
+        After,
+        Into,
+    }
+
+    /// Visible node: all ancestors of the node are expanded
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewRow {
+        pub node: usize,
+        pub depth: usize,
+    }
+
+    /// Payload of a drag that was started on a node, can be used by other widgets to accept nodes that are dragged out of the tree
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewDragPayload {
+        pub node: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildrenCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTreeViewOnLoadChildrenCallback {
+        pub cb: AzTreeViewOnLoadChildrenCallbackType,
+    }
+
+    /// `AzTreeViewOnLoadChildrenCallbackType` struct
+    pub type AzTreeViewOnLoadChildrenCallbackType = extern "C" fn(&mut AzRefAny, &mut AzTreeViewState, AzTreeViewLoadRequest, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTreeViewOnSelectionChangeCallback {
+        pub cb: AzTreeViewOnSelectionChangeCallbackType,
+    }
+
+    /// `AzTreeViewOnSelectionChangeCallbackType` struct
+    pub type AzTreeViewOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnNodeMovedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTreeViewOnNodeMovedCallback {
+        pub cb: AzTreeViewOnNodeMovedCallbackType,
+    }
+
+    /// `AzTreeViewOnNodeMovedCallbackType` struct
+    pub type AzTreeViewOnNodeMovedCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, usize, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Height of the rows of a `VirtualList`
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzVirtualListRowHeight {
+        Fixed(f32),
+        Estimated(f32),
+    }
+
+    /// Measured height of a row (only used with `VirtualListRowHeight::Estimated`)
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzVirtualListMeasuredRow {
+        pub row: usize,
+        pub height: f32,
+        pub delta_before: f64,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListRenderRowCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzVirtualListRenderRowCallback {
+        pub cb: AzVirtualListRenderRowCallbackType,
+    }
+
+    /// `AzVirtualListRenderRowCallbackType` struct
+    pub type AzVirtualListRenderRowCallbackType = extern "C" fn(&mut AzRefAny, usize) -> AzStyledDom;
+
+    /// Re-export of rust-allocated (stack based) `VirtualListOnScrollCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzVirtualListOnScrollCallback {
+        pub cb: AzVirtualListOnScrollCallbackType,
+    }
+
+    /// `AzVirtualListOnScrollCallbackType` struct
+    pub type AzVirtualListOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &AzVirtualListState, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -1980,6 +2294,9 @@ mod dll {
         pub hover: bool,
         pub active: bool,
         pub focused: bool,
+        pub focus_visible: bool,
+        pub focus_within: bool,
+        pub drag_over: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `TagId` struct
@@ -2804,6 +3121,19 @@ mod dll {
     /// `AzXmlNodeVecDestructorType` struct
     pub type AzXmlNodeVecDestructorType = extern "C" fn(&mut AzXmlNodeVec);
 
+    /// Re-export of rust-allocated (stack based) `XmlSpanVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzXmlSpanVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzXmlSpanVecDestructorType),
+    }
+
+    /// `AzXmlSpanVecDestructorType` struct
+    pub type AzXmlSpanVecDestructorType = extern "C" fn(&mut AzXmlSpanVec);
+
     /// Re-export of rust-allocated (stack based) `FmtArgVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
@@ -3142,6 +3472,123 @@ mod dll {
     /// `AzScanCodeVecDestructorType` struct
     pub type AzScanCodeVecDestructorType = extern "C" fn(&mut AzScanCodeVec);
 
+    /// Re-export of rust-allocated (stack based) `TextInputValidatorVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTextInputValidatorVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextInputValidatorVecDestructorType),
+    }
+
+    /// `AzTextInputValidatorVecDestructorType` struct
+    pub type AzTextInputValidatorVecDestructorType = extern "C" fn(&mut AzTextInputValidatorVec);
+
+    /// Re-export of rust-allocated (stack based) `DropDownChoiceVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzDropDownChoiceVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzDropDownChoiceVecDestructorType),
+    }
+
+    /// `AzDropDownChoiceVecDestructorType` struct
+    pub type AzDropDownChoiceVecDestructorType = extern "C" fn(&mut AzDropDownChoiceVec);
+
+    /// Re-export of rust-allocated (stack based) `TabContainerTabVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTabContainerTabVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTabContainerTabVecDestructorType),
+    }
+
+    /// `AzTabContainerTabVecDestructorType` struct
+    pub type AzTabContainerTabVecDestructorType = extern "C" fn(&mut AzTabContainerTabVec);
+
+    /// Re-export of rust-allocated (stack based) `TextEditUndoEntryVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTextEditUndoEntryVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextEditUndoEntryVecDestructorType),
+    }
+
+    /// `AzTextEditUndoEntryVecDestructorType` struct
+    pub type AzTextEditUndoEntryVecDestructorType = extern "C" fn(&mut AzTextEditUndoEntryVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeDataVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTreeViewNodeDataVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewNodeDataVecDestructorType),
+    }
+
+    /// `AzTreeViewNodeDataVecDestructorType` struct
+    pub type AzTreeViewNodeDataVecDestructorType = extern "C" fn(&mut AzTreeViewNodeDataVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTreeViewNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewNodeVecDestructorType),
+    }
+
+    /// `AzTreeViewNodeVecDestructorType` struct
+    pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewRowVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTreeViewRowVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewRowVecDestructorType),
+    }
+
+    /// `AzTreeViewRowVecDestructorType` struct
+    pub type AzTreeViewRowVecDestructorType = extern "C" fn(&mut AzTreeViewRowVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewRenderedRowVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTreeViewRenderedRowVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewRenderedRowVecDestructorType),
+    }
+
+    /// `AzTreeViewRenderedRowVecDestructorType` struct
+    pub type AzTreeViewRenderedRowVecDestructorType = extern "C" fn(&mut AzTreeViewRenderedRowVec);
+
+    /// Re-export of rust-allocated (stack based) `VirtualListMeasuredRowVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzVirtualListMeasuredRowVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzVirtualListMeasuredRowVecDestructorType),
+    }
+
+    /// `AzVirtualListMeasuredRowVecDestructorType` struct
+    pub type AzVirtualListMeasuredRowVecDestructorType = extern "C" fn(&mut AzVirtualListMeasuredRowVec);
+
     /// Re-export of rust-allocated (stack based) `CssDeclarationVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
@@ -3790,14 +4237,25 @@ mod dll {
         Focus(AzFocusEventFilter),
     }
 
-    /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
+    /// Configures the arrow key navigation of a `FocusScope::Roving`
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzMenuCallback {
-        pub callback: AzCallback,
-        pub data: AzRefAny,
+    #[derive(Copy)]
+    pub struct AzRovingFocus {
+        pub orientation: AzRovingFocusOrientation,
+        pub wrap: bool,
+    }
+
+    /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzMenuCallback {
+        pub callback: AzCallback,
+        pub data: AzRefAny,
     }
 
     /// Icon of a menu entry
@@ -5380,6 +5838,16 @@ mod dll {
         pub callback: AzCheckBoxOnToggleCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDownOnChoiceChange {
+        pub data: AzRefAny,
+        pub callback: AzDropDownOnChoiceChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ColorInputState` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -5449,6 +5917,26 @@ mod dll {
         pub valid: AzTextInputValid,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextInputCustomValidator` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextInputCustomValidator {
+        pub data: AzRefAny,
+        pub callback: AzTextInputValidatorCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextInputOnValidationChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextInputOnValidationChange {
+        pub data: AzRefAny,
+        pub callback: AzTextInputOnValidationChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -5459,6 +5947,166 @@ mod dll {
         pub callback: AzNumberInputOnValueChangeCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPaneState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSplitPaneState {
+        pub orientation: AzSplitPaneOrientation,
+        pub ratio: f32,
+        pub min_first_size: f32,
+        pub min_second_size: f32,
+        pub collapsible: bool,
+        pub collapsed: AzSplitPaneCollapsed,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitPaneOnResize` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSplitPaneOnResize {
+        pub data: AzRefAny,
+        pub callback: AzSplitPaneOnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabContainerOnTabChange {
+        pub data: AzRefAny,
+        pub callback: AzTabContainerOnTabChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabClose` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabContainerOnTabClose {
+        pub data: AzRefAny,
+        pub callback: AzTabContainerOnTabCloseCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabMove` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabContainerOnTabMove {
+        pub data: AzRefAny,
+        pub callback: AzTabContainerOnTabMoveCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSliderState {
+        pub value: f32,
+        pub upper_value: AzOptionF32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
+        pub orientation: AzSliderOrientation,
+        pub tick_interval: AzOptionF32,
+        pub active_thumb: AzSliderThumb,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzSliderOnValueChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditOnTextChange {
+        pub data: AzRefAny,
+        pub callback: AzTextEditOnTextChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewDropTarget` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewDropTarget {
+        pub node: usize,
+        pub position: AzTreeViewDropPosition,
+    }
+
+    /// Passed to the `on_load_children` callback
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewLoadRequest {
+        pub node: usize,
+        pub tree: AzRefAny,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildren` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewOnLoadChildren {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnLoadChildrenCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewOnSelectionChange {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnSelectionChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnNodeMoved` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewOnNodeMoved {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnNodeMovedCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListRenderRow` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzVirtualListRenderRow {
+        pub data: AzRefAny,
+        pub callback: AzVirtualListRenderRowCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListOnScroll` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzVirtualListOnScroll {
+        pub data: AzRefAny,
+        pub callback: AzVirtualListOnScrollCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -5626,6 +6274,17 @@ mod dll {
         pub high_quality_aa: bool,
     }
 
+    /// Start and end position of a node, attribute or text in the XML source
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzXmlSpan {
+        pub start: AzSvgParseErrorPosition,
+        pub end: AzSvgParseErrorPosition,
+    }
+
     /// Re-export of rust-allocated (stack based) `InstantPtr` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -5695,6 +6354,15 @@ mod dll {
         pub destructor: AzXmlNodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<XmlSpan>`
+    #[repr(C)]
+    pub struct AzXmlSpanVec {
+        pub(crate) ptr: *const AzXmlSpan,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzXmlSpanVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<InlineGlyph>`
     #[repr(C)]
     pub struct AzInlineGlyphVec {
@@ -5812,6 +6480,24 @@ mod dll {
         pub destructor: AzScanCodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `TreeViewRow`
+    #[repr(C)]
+    pub struct AzTreeViewRowVec {
+        pub(crate) ptr: *const AzTreeViewRow,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewRowVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `VirtualListMeasuredRow`
+    #[repr(C)]
+    pub struct AzVirtualListMeasuredRowVec {
+        pub(crate) ptr: *const AzVirtualListMeasuredRow,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzVirtualListMeasuredRowVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<u16>`
     #[repr(C)]
     pub struct AzU16Vec {
@@ -5972,6 +6658,146 @@ mod dll {
         Some(AzTextInputSelection),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextInputOnValidationChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTextInputOnValidationChange {
+        None,
+        Some(AzTextInputOnValidationChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionDropDownOnChoiceChange {
+        None,
+        Some(AzDropDownOnChoiceChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTabContainerOnTabChange {
+        None,
+        Some(AzTabContainerOnTabChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabClose` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTabContainerOnTabClose {
+        None,
+        Some(AzTabContainerOnTabClose),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabMove` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTabContainerOnTabMove {
+        None,
+        Some(AzTabContainerOnTabMove),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSplitPaneOnResize` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSplitPaneOnResize {
+        None,
+        Some(AzSplitPaneOnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSliderOnValueChange {
+        None,
+        Some(AzSliderOnValueChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTextEditOnTextChange {
+        None,
+        Some(AzTextEditOnTextChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditCursor` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTextEditCursor {
+        None,
+        Some(AzTextEditCursor),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnLoadChildren` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTreeViewOnLoadChildren {
+        None,
+        Some(AzTreeViewOnLoadChildren),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelectionChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTreeViewOnSelectionChange {
+        None,
+        Some(AzTreeViewOnSelectionChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnNodeMoved` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTreeViewOnNodeMoved {
+        None,
+        Some(AzTreeViewOnNodeMoved),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewDropTarget` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTreeViewDropTarget {
+        None,
+        Some(AzTreeViewDropTarget),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionVirtualListOnScroll` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionVirtualListOnScroll {
+        None,
+        Some(AzVirtualListOnScroll),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionNumberInputOnValueChange` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -6419,6 +7245,7 @@ mod dll {
         pub enable_visual_panic_hook: bool,
         pub enable_logging_on_panic: bool,
         pub enable_tab_navigation: bool,
+        pub record_events: bool,
         pub system_callbacks: AzSystemCallbacks,
     }
 
@@ -6477,22 +7304,6 @@ mod dll {
         pub max_dimensions: AzOptionLogicalSize,
     }
 
-    /// Current keyboard state, stores what keys / characters have been pressed
-    #[repr(C)]
-    #[derive(Debug)]
-    #[derive(Clone)]
-    #[derive(PartialEq, PartialOrd)]
-    pub struct AzKeyboardState {
-        pub shift_down: bool,
-        pub ctrl_down: bool,
-        pub alt_down: bool,
-        pub super_down: bool,
-        pub current_char: AzOptionChar,
-        pub current_virtual_keycode: AzOptionVirtualKeyCode,
-        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-        pub pressed_scancodes: AzScanCodeVec,
-    }
-
     /// Current mouse / cursor state
     #[repr(C)]
     #[derive(Debug)]
@@ -6529,6 +7340,17 @@ mod dll {
         pub bounds: AzLogicalRect,
     }
 
+    /// Data of an in-app drag operation, created by `CallbackInfo::start_drag`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDragData {
+        pub source: AzDomNodeId,
+        pub payload: AzRefAny,
+        pub ghost: AzOptionImageRef,
+    }
+
     /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -6593,6 +7415,17 @@ mod dll {
         Application(AzApplicationEventFilter),
     }
 
+    /// Restricts the keyboard focus navigation to the subtree of a node
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzFocusScope {
+        Trap,
+        Roving(AzRovingFocus),
+    }
+
     /// Menu struct (application / window menu, dropdown menu, context menu). Modeled after the Windows API
     #[repr(C)]
     #[derive(Debug)]
@@ -6626,6 +7459,9 @@ mod dll {
         Hover,
         Active,
         Focus,
+        FocusVisible,
+        FocusWithin,
+        DragOver,
     }
 
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -6772,6 +7608,21 @@ mod dll {
         pub on_toggle: AzOptionCheckBoxOnToggle,
     }
 
+    /// Validator that is run on every change of the text, an empty text is only rejected by `NotEmpty`
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTextInputValidator {
+        NotEmpty,
+        Integer,
+        Number,
+        Email,
+        Ipv4,
+        MinLength(usize),
+        Custom(AzTextInputCustomValidator),
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6782,6 +7633,56 @@ mod dll {
         pub on_value_change: AzOptionNumberInputOnValueChange,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPaneStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSplitPaneStateWrapper {
+        pub inner: AzSplitPaneState,
+        pub on_resize: AzOptionSplitPaneOnResize,
+        pub is_dragging: bool,
+        pub drag_position: f32,
+        pub state_before_drag: AzSplitPaneState,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSliderStateWrapper {
+        pub inner: AzSliderState,
+        pub on_value_change: AzOptionSliderOnValueChange,
+        pub is_dragging: bool,
+        pub drag_value: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzVirtualListState {
+        pub row_count: usize,
+        pub row_height: AzVirtualListRowHeight,
+        pub overscan: usize,
+        pub scroll_offset: f32,
+        pub viewport_height: f32,
+        pub measured_rows: AzVirtualListMeasuredRowVec,
+    }
+
+    /// Nodes inside the IFrame that are updated in place when the list is scrolled
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzVirtualListIFrameNodes {
+        pub rows: AzDomNodeId,
+        pub scrollbar_thumb: AzOptionDomNodeId,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyledNode` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6976,6 +7877,15 @@ mod dll {
         pub destructor: AzSvgPathElementVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `TextInputValidator`
+    #[repr(C)]
+    pub struct AzTextInputValidatorVec {
+        pub(crate) ptr: *const AzTextInputValidator,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextInputValidatorVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `StringVec`
     #[repr(C)]
     pub struct AzStringVec {
@@ -7003,6 +7913,17 @@ mod dll {
         pub destructor: AzTagIdToNodeIdMappingVecDestructor,
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionVirtualListIFrameNodes` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzOptionVirtualListIFrameNodes {
+        None,
+        Some(AzVirtualListIFrameNodes),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCodeCombo` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7013,24 +7934,24 @@ mod dll {
         Some(AzVirtualKeyCodeCombo),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
+    /// Re-export of rust-allocated (stack based) `OptionDragData` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub enum AzOptionMouseState {
+    pub enum AzOptionDragData {
         None,
-        Some(AzMouseState),
+        Some(AzDragData),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+    /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub enum AzOptionKeyboardState {
+    pub enum AzOptionMouseState {
         None,
-        Some(AzKeyboardState),
+        Some(AzMouseState),
     }
 
     /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
@@ -7239,6 +8160,17 @@ mod dll {
         pub value: AzString,
     }
 
+    /// Pre-edit text of an input method, the IME shows its candidate window at the `WindowState.ime_position`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzImeComposition {
+        pub text: AzString,
+        pub cursor_start: usize,
+        pub cursor_end: usize,
+    }
+
     /// Information about a single (or many) monitors, useful for dock widgets
     #[repr(C)]
     #[derive(Debug)]
@@ -7430,6 +8362,17 @@ mod dll {
         Exact(AzStyleTransformVec),
     }
 
+    /// Single selectable entry of a `DropDown`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDownChoice {
+        pub label: AzString,
+        pub disabled: bool,
+        pub group: AzOptionString,
+    }
+
     /// Re-export of rust-allocated (stack based) `ColorInputStateWrapper` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -7441,17 +8384,88 @@ mod dll {
         pub on_value_change: AzOptionColorInputOnValueChange,
     }
 
-    /// Re-export of rust-allocated (stack based) `TextInputState` struct
+    /// Re-export of rust-allocated (stack based) `TextInputValidationResult` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTextInputValidationResult {
+        Valid,
+        Invalid(AzString),
+    }
+
+    /// Input mask: `9` = digit, `#` = optional digit, `a` = letter, `*` = letter or digit, other characters are literals that are inserted automatically (`99/99/9999`)
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzTextInputState {
-        pub text: AzU32Vec,
-        pub placeholder: AzOptionString,
-        pub max_len: usize,
-        pub selection: AzOptionTextInputSelection,
-        pub cursor_pos: usize,
+    pub struct AzTextInputMask {
+        pub pattern: AzString,
+    }
+
+    /// Header of a single tab
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabContainerTab {
+        pub title: AzString,
+        pub closable: bool,
+        pub page: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditState {
+        pub lines: AzStringVec,
+        pub cursor: AzTextEditCursor,
+        pub selection_anchor: AzOptionTextEditCursor,
+        pub preferred_column: AzOptionUsize,
+        pub first_visible_row: usize,
+        pub visible_rows: usize,
+        pub wrap_column: usize,
+    }
+
+    /// Reversible edit: `removed` was replaced by `inserted` at `start`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditUndoEntry {
+        pub start: AzTextEditCursor,
+        pub removed: AzString,
+        pub inserted: AzString,
+        pub cursor_before: AzTextEditCursor,
+        pub kind: AzTextEditEditKind,
+    }
+
+    /// User-facing content of a node
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewNodeData {
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub children: AzTreeViewChildren,
+    }
+
+    /// Content of one row of the row pool, `node` is `None` for the empty rows below the last node
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewRenderedRow {
+        pub node: AzOptionUsize,
+        pub depth: usize,
+        pub toggle: AzString,
+        pub icon: AzOptionImageRef,
+        pub label: AzString,
+        pub is_selected: bool,
+        pub is_cursor: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
@@ -7693,6 +8707,51 @@ mod dll {
         pub destructor: AzVertexAttributeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `DropDownChoice`
+    #[repr(C)]
+    pub struct AzDropDownChoiceVec {
+        pub(crate) ptr: *const AzDropDownChoice,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzDropDownChoiceVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TabContainerTab`
+    #[repr(C)]
+    pub struct AzTabContainerTabVec {
+        pub(crate) ptr: *const AzTabContainerTab,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTabContainerTabVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TextEditUndoEntry`
+    #[repr(C)]
+    pub struct AzTextEditUndoEntryVec {
+        pub(crate) ptr: *const AzTextEditUndoEntry,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextEditUndoEntryVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TreeViewNodeData`
+    #[repr(C)]
+    pub struct AzTreeViewNodeDataVec {
+        pub(crate) ptr: *const AzTreeViewNodeData,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeDataVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TreeViewRenderedRow`
+    #[repr(C)]
+    pub struct AzTreeViewRenderedRowVec {
+        pub(crate) ptr: *const AzTreeViewRenderedRow,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewRenderedRowVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `CssPathSelector`
     #[repr(C)]
     pub struct AzCssPathSelectorVec {
@@ -7729,6 +8788,26 @@ mod dll {
         pub destructor: AzStringPairVecDestructor,
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextInputMask` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTextInputMask {
+        None,
+        Some(AzTextInputMask),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionImeComposition {
+        None,
+        Some(AzImeComposition),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionFileTypeList` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7800,6 +8879,23 @@ mod dll {
         InvalidCharacterData,
     }
 
+    /// Current keyboard state, stores what keys / characters have been pressed
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzKeyboardState {
+        pub shift_down: bool,
+        pub ctrl_down: bool,
+        pub alt_down: bool,
+        pub super_down: bool,
+        pub current_char: AzOptionChar,
+        pub current_virtual_keycode: AzOptionVirtualKeyCode,
+        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+        pub pressed_scancodes: AzScanCodeVec,
+        pub ime_composition: AzOptionImeComposition,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -7954,18 +9050,90 @@ mod dll {
         BackfaceVisibility(AzStyleBackfaceVisibilityValue),
     }
 
-    /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+    /// Re-export of rust-allocated (stack based) `DropDownState` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzTextInputStateWrapper {
-        pub inner: AzTextInputState,
-        pub on_text_input: AzOptionTextInputOnTextInput,
-        pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDown,
-        pub on_focus_lost: AzOptionTextInputOnFocusLost,
-        pub update_text_input_before_calling_focus_lost_fn: bool,
-        pub update_text_input_before_calling_vk_down_fn: bool,
+    pub struct AzDropDownState {
+        pub choices: AzDropDownChoiceVec,
+        pub selected: AzOptionUsize,
+        pub highlighted: AzOptionUsize,
+        pub is_open: bool,
+        pub search: AzString,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextInputState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextInputState {
+        pub text: AzU32Vec,
+        pub placeholder: AzOptionString,
+        pub max_len: usize,
+        pub selection: AzOptionTextInputSelection,
+        pub cursor_pos: usize,
+        pub validators: AzTextInputValidatorVec,
+        pub mask: AzOptionTextInputMask,
+        pub obscure_char: AzOptionChar,
+        pub validation: AzTextInputValidationResult,
+        pub ime_composition: AzOptionImeComposition,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabContainerState {
+        pub tabs: AzTabContainerTabVec,
+        pub active_tab: AzOptionUsize,
+        pub scroll_offset: f32,
+        pub overflow: AzTabContainerOverflow,
+        pub menu_open: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditStateWrapper {
+        pub inner: AzTextEditState,
+        pub on_text_change: AzOptionTextEditOnTextChange,
+        pub undo_stack: AzTextEditUndoEntryVec,
+        pub redo_stack: AzTextEditUndoEntryVec,
+        pub wrap_lines: bool,
+        pub char_width: f32,
+        pub rendered_rows: AzStringVec,
+        pub is_selecting: bool,
+    }
+
+    /// Node in the arena of the `TreeViewState`, the links are indices into `TreeViewState::nodes`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewNode {
+        pub data: AzTreeViewNodeData,
+        pub parent: AzOptionUsize,
+        pub first_child: AzOptionUsize,
+        pub last_child: AzOptionUsize,
+        pub previous_sibling: AzOptionUsize,
+        pub next_sibling: AzOptionUsize,
+        pub is_expanded: bool,
+        pub is_selected: bool,
+    }
+
+    /// Children that were loaded on a background thread
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewLoadedChildren {
+        pub node: usize,
+        pub children: AzTreeViewNodeDataVec,
     }
 
     /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
@@ -8031,6 +9199,9 @@ mod dll {
         pub attributes: AzStringPairVec,
         pub children: AzXmlNodeVec,
         pub text: AzOptionString,
+        pub span: AzXmlSpan,
+        pub attribute_spans: AzXmlSpanVec,
+        pub text_span: AzXmlSpan,
     }
 
     /// Wrapper over a Rust-allocated `Vec<InlineLine>`
@@ -8060,6 +9231,15 @@ mod dll {
         pub destructor: AzSvgMultiPolygonVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `TreeViewNode`
+    #[repr(C)]
+    pub struct AzTreeViewNodeVec {
+        pub(crate) ptr: *const AzTreeViewNode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8070,6 +9250,16 @@ mod dll {
         Some(AzCssProperty),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionKeyboardState {
+        None,
+        Some(AzKeyboardState),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlTextError` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -8142,6 +9332,7 @@ mod dll {
         pub datasets: *mut c_void,
         pub stop_propagation: *mut bool,
         pub focus_target: *mut c_void,
+        pub drag_started: *mut c_void,
         pub words_cache: *const c_void,
         pub shaped_words_cache: *const c_void,
         pub positioned_words_cache: *const c_void,
@@ -8234,6 +9425,67 @@ mod dll {
         pub default_value: AzCssProperty,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDownStateWrapper {
+        pub inner: AzDropDownState,
+        pub on_choice_change: AzOptionDropDownOnChoiceChange,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextInputStateWrapper {
+        pub inner: AzTextInputState,
+        pub on_text_input: AzOptionTextInputOnTextInput,
+        pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDown,
+        pub on_focus_lost: AzOptionTextInputOnFocusLost,
+        pub update_text_input_before_calling_focus_lost_fn: bool,
+        pub update_text_input_before_calling_vk_down_fn: bool,
+        pub on_validation_change: AzOptionTextInputOnValidationChange,
+        pub error_style: AzCssPropertyVec,
+        pub style_before_error: AzCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabContainerStateWrapper {
+        pub inner: AzTabContainerState,
+        pub on_tab_change: AzOptionTabContainerOnTabChange,
+        pub on_tab_close: AzOptionTabContainerOnTabClose,
+        pub on_tab_move: AzOptionTabContainerOnTabMove,
+        pub allow_reorder: bool,
+        pub tab_count: usize,
+        pub pressed_tab: AzOptionUsize,
+        pub dragged_tab: AzOptionUsize,
+        pub drop_index: AzOptionUsize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewState {
+        pub nodes: AzTreeViewNodeVec,
+        pub first_root: AzOptionUsize,
+        pub last_root: AzOptionUsize,
+        pub rows: AzTreeViewRowVec,
+        pub cursor: AzOptionUsize,
+        pub selection_anchor: AzOptionUsize,
+        pub selection_mode: AzTreeViewSelectionMode,
+        pub first_visible_row: usize,
+        pub visible_rows: usize,
+    }
+
     /// Re-export of rust-allocated (stack based) `SvgNode` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8383,6 +9635,20 @@ mod dll {
         pub content_style: AzNodeDataInlineCssPropertyVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDown` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDown {
+        pub state: AzDropDownStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub header_style: AzNodeDataInlineCssPropertyVec,
+        pub popup_style: AzNodeDataInlineCssPropertyVec,
+        pub choice_style: AzNodeDataInlineCssPropertyVec,
+        pub group_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `Label` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -8413,6 +9679,7 @@ mod dll {
         pub placeholder_style: AzNodeDataInlineCssPropertyVec,
         pub container_style: AzNodeDataInlineCssPropertyVec,
         pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub preedit_style: AzNodeDataInlineCssPropertyVec,
     }
 
     /// Re-export of rust-allocated (stack based) `NumberInput` struct
@@ -8437,6 +9704,98 @@ mod dll {
         pub label_style: AzNodeDataInlineCssPropertyVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `TabContainer` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabContainer {
+        pub state: AzTabContainerStateWrapper,
+        pub pages: AzDomVec,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub header_style: AzNodeDataInlineCssPropertyVec,
+        pub tab_style: AzNodeDataInlineCssPropertyVec,
+        pub close_button_style: AzNodeDataInlineCssPropertyVec,
+        pub button_style: AzNodeDataInlineCssPropertyVec,
+        pub menu_style: AzNodeDataInlineCssPropertyVec,
+        pub page_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Slider` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSlider {
+        pub state: AzSliderStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub fill_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEdit` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEdit {
+        pub state: AzTextEditStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub selection_style: AzNodeDataInlineCssPropertyVec,
+        pub caret_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewStateWrapper {
+        pub inner: AzTreeViewState,
+        pub on_load_children: AzOptionTreeViewOnLoadChildren,
+        pub on_selection_change: AzOptionTreeViewOnSelectionChange,
+        pub on_node_moved: AzOptionTreeViewOnNodeMoved,
+        pub allow_reorder: bool,
+        pub rendered_rows: AzTreeViewRenderedRowVec,
+        pub pressed_node: AzOptionUsize,
+        pub dragged_node: AzOptionUsize,
+        pub drop_target: AzOptionTreeViewDropTarget,
+    }
+
+    /// Virtualized list: renders only the rows that are visible, so that lists with millions of rows stay cheap to lay out
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzVirtualList {
+        pub state: AzRefAny,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_track_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzVirtualListStateWrapper {
+        pub inner: AzVirtualListState,
+        pub render_row: AzVirtualListRenderRow,
+        pub on_scroll: AzOptionVirtualListOnScroll,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_track_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub rendered_rows_start: usize,
+        pub rendered_rows_end: usize,
+        pub iframe_nodes: AzOptionVirtualListIFrameNodes,
+    }
+
     /// Wrapper over a Rust-allocated `CssDeclaration`
     #[repr(C)]
     pub struct AzCssDeclarationVec {
@@ -8501,6 +9860,37 @@ mod dll {
         pub declarations: AzCssDeclarationVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPane` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSplitPane {
+        pub state: AzSplitPaneStateWrapper,
+        pub first_pane: AzDom,
+        pub second_pane: AzDom,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub pane_style: AzNodeDataInlineCssPropertyVec,
+        pub divider_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeView` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeView {
+        pub state: AzTreeViewStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub guides_style: AzNodeDataInlineCssPropertyVec,
+        pub toggle_style: AzNodeDataInlineCssPropertyVec,
+        pub icon_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub drop_indicator_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyledDom` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -8640,6 +10030,7 @@ mod dll {
         pub(crate) fn AzWindowState_new(_:  AzLayoutCallbackType) -> AzWindowState;
         pub(crate) fn AzWindowState_default() -> AzWindowState;
         pub(crate) fn AzCallbackInfo_getHitNode(_:  &AzCallbackInfo) -> AzDomNodeId;
+        pub(crate) fn AzCallbackInfo_getFocusedNode(_:  &AzCallbackInfo) -> AzOptionDomNodeId;
         pub(crate) fn AzCallbackInfo_getSystemTimeFn(_:  &AzCallbackInfo) -> AzGetSystemTimeFn;
         pub(crate) fn AzCallbackInfo_getCursorRelativeToViewport(_:  &AzCallbackInfo) -> AzOptionLogicalPosition;
         pub(crate) fn AzCallbackInfo_getCursorRelativeToNode(_:  &AzCallbackInfo) -> AzOptionLogicalPosition;
@@ -8686,6 +10077,8 @@ mod dll {
         pub(crate) fn AzCallbackInfo_startThread(_:  &mut AzCallbackInfo, _:  AzRefAny, _:  AzRefAny, _:  AzThreadCallback) -> AzOptionThreadId;
         pub(crate) fn AzCallbackInfo_sendThreadMsg(_:  &mut AzCallbackInfo, _:  AzThreadId, _:  AzThreadSendMsg) -> bool;
         pub(crate) fn AzCallbackInfo_stopThread(_:  &mut AzCallbackInfo, _:  AzThreadId) -> bool;
+        pub(crate) fn AzCallbackInfo_startDrag(_:  &mut AzCallbackInfo, _:  AzRefAny, _:  AzOptionImageRef) -> bool;
+        pub(crate) fn AzCallbackInfo_getDragData(_:  &AzCallbackInfo) -> AzOptionDragData;
         pub(crate) fn AzHidpiAdjustedBounds_getLogicalSize(_:  &AzHidpiAdjustedBounds) -> AzLogicalSize;
         pub(crate) fn AzHidpiAdjustedBounds_getPhysicalSize(_:  &AzHidpiAdjustedBounds) -> AzPhysicalSizeU32;
         pub(crate) fn AzHidpiAdjustedBounds_getHidpiFactor(_:  &AzHidpiAdjustedBounds) -> f32;
@@ -8755,6 +10148,12 @@ mod dll {
         pub(crate) fn AzDom_withClipMask(_:  &mut AzDom, _:  AzImageMask) -> AzDom;
         pub(crate) fn AzDom_setTabIndex(_:  &mut AzDom, _:  AzTabIndex);
         pub(crate) fn AzDom_withTabIndex(_:  &mut AzDom, _:  AzTabIndex) -> AzDom;
+        pub(crate) fn AzDom_setKey(_:  &mut AzDom, _:  u64);
+        pub(crate) fn AzDom_withKey(_:  &mut AzDom, _:  u64) -> AzDom;
+        pub(crate) fn AzDom_setFocusScope(_:  &mut AzDom, _:  AzFocusScope);
+        pub(crate) fn AzDom_withFocusScope(_:  &mut AzDom, _:  AzFocusScope) -> AzDom;
+        pub(crate) fn AzDom_addShortcut(_:  &mut AzDom, _:  AzVirtualKeyCodeCombo, _:  AzRefAny, _:  AzCallbackType);
+        pub(crate) fn AzDom_withShortcut(_:  &mut AzDom, _:  AzVirtualKeyCodeCombo, _:  AzRefAny, _:  AzCallbackType) -> AzDom;
         pub(crate) fn AzDom_setAccessibilityInfo(_:  &mut AzDom, _:  AzAccessibilityInfo);
         pub(crate) fn AzDom_withAccessibilityInfo(_:  &mut AzDom, _:  AzAccessibilityInfo) -> AzDom;
         pub(crate) fn AzDom_setMenuBar(_:  &mut AzDom, _:  AzMenu);
@@ -8787,6 +10186,9 @@ mod dll {
         pub(crate) fn AzNodeData_withInlineCssProps(_:  &mut AzNodeData, _:  AzNodeDataInlineCssPropertyVec) -> AzNodeData;
         pub(crate) fn AzNodeData_setClipMask(_:  &mut AzNodeData, _:  AzImageMask);
         pub(crate) fn AzNodeData_setTabIndex(_:  &mut AzNodeData, _:  AzTabIndex);
+        pub(crate) fn AzNodeData_setKey(_:  &mut AzNodeData, _:  u64);
+        pub(crate) fn AzNodeData_setFocusScope(_:  &mut AzNodeData, _:  AzFocusScope);
+        pub(crate) fn AzNodeData_addShortcut(_:  &mut AzNodeData, _:  AzVirtualKeyCodeCombo, _:  AzRefAny, _:  AzCallbackType);
         pub(crate) fn AzNodeData_setAccessibilityInfo(_:  &mut AzNodeData, _:  AzAccessibilityInfo);
         pub(crate) fn AzNodeData_setMenuBar(_:  &mut AzNodeData, _:  AzMenu);
         pub(crate) fn AzNodeData_setContextMenu(_:  &mut AzNodeData, _:  AzMenu);
@@ -8819,6 +10221,21 @@ mod dll {
         pub(crate) fn AzCheckBox_setOnToggle(_:  &mut AzCheckBox, _:  AzRefAny, _:  AzCheckBoxOnToggleCallbackType);
         pub(crate) fn AzCheckBox_withOnToggle(_:  &mut AzCheckBox, _:  AzRefAny, _:  AzCheckBoxOnToggleCallbackType) -> AzCheckBox;
         pub(crate) fn AzCheckBox_dom(_:  &mut AzCheckBox) -> AzDom;
+        pub(crate) fn AzDropDown_new(_:  AzDropDownChoiceVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_fromChoicesStr(_:  AzString) -> AzDropDown;
+        pub(crate) fn AzDropDown_setSelected(_:  &mut AzDropDown, _:  AzOptionUsize);
+        pub(crate) fn AzDropDown_withSelected(_:  &mut AzDropDown, _:  usize) -> AzDropDown;
+        pub(crate) fn AzDropDown_withSelectedLabel(_:  &mut AzDropDown, _:  AzString) -> AzDropDown;
+        pub(crate) fn AzDropDown_setOnChoiceChange(_:  &mut AzDropDown, _:  AzRefAny, _:  AzDropDownOnChoiceChangeCallbackType);
+        pub(crate) fn AzDropDown_withOnChoiceChange(_:  &mut AzDropDown, _:  AzRefAny, _:  AzDropDownOnChoiceChangeCallbackType) -> AzDropDown;
+        pub(crate) fn AzDropDown_setContainerStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_setHeaderStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_setPopupStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_setChoiceStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_setGroupStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_dom(_:  &mut AzDropDown) -> AzDom;
+        pub(crate) fn AzDropDownState_isSelectable(_:  &AzDropDownState, _:  usize) -> bool;
+        pub(crate) fn AzDropDownChoice_new(_:  AzString) -> AzDropDownChoice;
         pub(crate) fn AzLabel_new(_:  AzString) -> AzLabel;
         pub(crate) fn AzLabel_dom(_:  &mut AzLabel) -> AzDom;
         pub(crate) fn AzColorInput_new(_:  AzColorU) -> AzColorInput;
@@ -8838,8 +10255,24 @@ mod dll {
         pub(crate) fn AzTextInput_withContainerStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec) -> AzTextInput;
         pub(crate) fn AzTextInput_setLabelStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec);
         pub(crate) fn AzTextInput_withLabelStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec) -> AzTextInput;
+        pub(crate) fn AzTextInput_setOnValidationChange(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnValidationChangeCallbackType);
+        pub(crate) fn AzTextInput_addValidator(_:  &mut AzTextInput, _:  AzTextInputValidator);
+        pub(crate) fn AzTextInput_withValidator(_:  &mut AzTextInput, _:  AzTextInputValidator) -> AzTextInput;
+        pub(crate) fn AzTextInput_setMask(_:  &mut AzTextInput, _:  AzTextInputMask);
+        pub(crate) fn AzTextInput_withMask(_:  &mut AzTextInput, _:  AzTextInputMask) -> AzTextInput;
+        pub(crate) fn AzTextInput_setObscureChar(_:  &mut AzTextInput, _:  AzOptionChar);
+        pub(crate) fn AzTextInput_withPasswordMode(_:  &mut AzTextInput) -> AzTextInput;
+        pub(crate) fn AzTextInput_setErrorStyle(_:  &mut AzTextInput, _:  AzCssPropertyVec);
+        pub(crate) fn AzTextInput_setPreeditStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec);
         pub(crate) fn AzTextInput_dom(_:  &mut AzTextInput) -> AzDom;
         pub(crate) fn AzTextInputState_getText(_:  &AzTextInputState) -> AzString;
+        pub(crate) fn AzTextInputState_getDisplayText(_:  &AzTextInputState) -> AzString;
+        pub(crate) fn AzTextInputState_validate(_:  &AzTextInputState) -> AzTextInputValidationResult;
+        pub(crate) fn AzTextInputMask_new(_:  AzString) -> AzTextInputMask;
+        pub(crate) fn AzTextInputMask_date() -> AzTextInputMask;
+        pub(crate) fn AzTextInputMask_time() -> AzTextInputMask;
+        pub(crate) fn AzTextInputMask_phone() -> AzTextInputMask;
+        pub(crate) fn AzTextInputMask_ipv4() -> AzTextInputMask;
         pub(crate) fn AzNumberInput_new(_:  f32) -> AzNumberInput;
         pub(crate) fn AzNumberInput_setOnTextInput(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType);
         pub(crate) fn AzNumberInput_withOnTextInput(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType) -> AzNumberInput;
@@ -8864,6 +10297,144 @@ mod dll {
         pub(crate) fn AzProgressBar_setLabelStyle(_:  &mut AzProgressBar, _:  AzNodeDataInlineCssPropertyVec);
         pub(crate) fn AzProgressBar_withLabelStyle(_:  &mut AzProgressBar, _:  AzNodeDataInlineCssPropertyVec) -> AzProgressBar;
         pub(crate) fn AzProgressBar_dom(_:  &mut AzProgressBar) -> AzDom;
+        pub(crate) fn AzSplitPane_new(_:  AzSplitPaneOrientation, _:  AzDom, _:  AzDom) -> AzSplitPane;
+        pub(crate) fn AzSplitPane_setRatio(_:  &mut AzSplitPane, _:  f32);
+        pub(crate) fn AzSplitPane_withRatio(_:  &mut AzSplitPane, _:  f32) -> AzSplitPane;
+        pub(crate) fn AzSplitPane_setMinSizes(_:  &mut AzSplitPane, _:  f32, _:  f32);
+        pub(crate) fn AzSplitPane_withMinSizes(_:  &mut AzSplitPane, _:  f32, _:  f32) -> AzSplitPane;
+        pub(crate) fn AzSplitPane_setCollapsible(_:  &mut AzSplitPane, _:  bool);
+        pub(crate) fn AzSplitPane_withCollapsible(_:  &mut AzSplitPane, _:  bool) -> AzSplitPane;
+        pub(crate) fn AzSplitPane_setCollapsed(_:  &mut AzSplitPane, _:  AzSplitPaneCollapsed);
+        pub(crate) fn AzSplitPane_withCollapsed(_:  &mut AzSplitPane, _:  AzSplitPaneCollapsed) -> AzSplitPane;
+        pub(crate) fn AzSplitPane_setOnResize(_:  &mut AzSplitPane, _:  AzRefAny, _:  AzSplitPaneOnResizeCallbackType);
+        pub(crate) fn AzSplitPane_withOnResize(_:  &mut AzSplitPane, _:  AzRefAny, _:  AzSplitPaneOnResizeCallbackType) -> AzSplitPane;
+        pub(crate) fn AzSplitPane_setContainerStyle(_:  &mut AzSplitPane, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSplitPane_setPaneStyle(_:  &mut AzSplitPane, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSplitPane_setDividerStyle(_:  &mut AzSplitPane, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSplitPane_dom(_:  &mut AzSplitPane) -> AzDom;
+        pub(crate) fn AzTabContainer_new() -> AzTabContainer;
+        pub(crate) fn AzTabContainer_fromTitlesStr(_:  AzString, _:  AzDomVec) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_addTab(_:  &mut AzTabContainer, _:  AzString, _:  AzDom);
+        pub(crate) fn AzTabContainer_withTab(_:  &mut AzTabContainer, _:  AzString, _:  AzDom) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setActiveTab(_:  &mut AzTabContainer, _:  usize);
+        pub(crate) fn AzTabContainer_withActiveTab(_:  &mut AzTabContainer, _:  usize) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setClosable(_:  &mut AzTabContainer, _:  bool);
+        pub(crate) fn AzTabContainer_withClosable(_:  &mut AzTabContainer, _:  bool) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setOverflow(_:  &mut AzTabContainer, _:  AzTabContainerOverflow);
+        pub(crate) fn AzTabContainer_withOverflow(_:  &mut AzTabContainer, _:  AzTabContainerOverflow) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setAllowReorder(_:  &mut AzTabContainer, _:  bool);
+        pub(crate) fn AzTabContainer_withAllowReorder(_:  &mut AzTabContainer, _:  bool) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setOnTabChange(_:  &mut AzTabContainer, _:  AzRefAny, _:  AzTabContainerOnTabChangeCallbackType);
+        pub(crate) fn AzTabContainer_withOnTabChange(_:  &mut AzTabContainer, _:  AzRefAny, _:  AzTabContainerOnTabChangeCallbackType) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setOnTabClose(_:  &mut AzTabContainer, _:  AzRefAny, _:  AzTabContainerOnTabCloseCallbackType);
+        pub(crate) fn AzTabContainer_withOnTabClose(_:  &mut AzTabContainer, _:  AzRefAny, _:  AzTabContainerOnTabCloseCallbackType) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setOnTabMove(_:  &mut AzTabContainer, _:  AzRefAny, _:  AzTabContainerOnTabMoveCallbackType);
+        pub(crate) fn AzTabContainer_withOnTabMove(_:  &mut AzTabContainer, _:  AzRefAny, _:  AzTabContainerOnTabMoveCallbackType) -> AzTabContainer;
+        pub(crate) fn AzTabContainer_setContainerStyle(_:  &mut AzTabContainer, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTabContainer_setHeaderStyle(_:  &mut AzTabContainer, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTabContainer_setTabStyle(_:  &mut AzTabContainer, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTabContainer_setCloseButtonStyle(_:  &mut AzTabContainer, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTabContainer_setButtonStyle(_:  &mut AzTabContainer, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTabContainer_setMenuStyle(_:  &mut AzTabContainer, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTabContainer_setPageStyle(_:  &mut AzTabContainer, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTabContainer_dom(_:  &mut AzTabContainer) -> AzDom;
+        pub(crate) fn AzSlider_new(_:  f32, _:  f32, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_newRange(_:  f32, _:  f32, _:  f32, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setOrientation(_:  &mut AzSlider, _:  AzSliderOrientation);
+        pub(crate) fn AzSlider_withOrientation(_:  &mut AzSlider, _:  AzSliderOrientation) -> AzSlider;
+        pub(crate) fn AzSlider_setStep(_:  &mut AzSlider, _:  f32);
+        pub(crate) fn AzSlider_withStep(_:  &mut AzSlider, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setTickInterval(_:  &mut AzSlider, _:  AzOptionF32);
+        pub(crate) fn AzSlider_withTickInterval(_:  &mut AzSlider, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setOnValueChange(_:  &mut AzSlider, _:  AzRefAny, _:  AzSliderOnValueChangeCallbackType);
+        pub(crate) fn AzSlider_withOnValueChange(_:  &mut AzSlider, _:  AzRefAny, _:  AzSliderOnValueChangeCallbackType) -> AzSlider;
+        pub(crate) fn AzSlider_setContainerStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_setTrackStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_setFillStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_setThumbStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_setTickStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_dom(_:  &mut AzSlider) -> AzDom;
+        pub(crate) fn AzTextEdit_new(_:  AzString) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_fromState(_:  AzTextEditState) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setVisibleRows(_:  &mut AzTextEdit, _:  usize);
+        pub(crate) fn AzTextEdit_withVisibleRows(_:  &mut AzTextEdit, _:  usize) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setWrapLines(_:  &mut AzTextEdit, _:  bool);
+        pub(crate) fn AzTextEdit_withWrapLines(_:  &mut AzTextEdit, _:  bool) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setOnTextChange(_:  &mut AzTextEdit, _:  AzRefAny, _:  AzTextEditOnTextChangeCallbackType);
+        pub(crate) fn AzTextEdit_withOnTextChange(_:  &mut AzTextEdit, _:  AzRefAny, _:  AzTextEditOnTextChangeCallbackType) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setContainerStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_setRowStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_setLabelStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_setSelectionStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_setCaretStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_dom(_:  &mut AzTextEdit) -> AzDom;
+        pub(crate) fn AzTextEditState_getText(_:  &AzTextEditState) -> AzString;
+        pub(crate) fn AzTreeView_new(_:  AzTreeViewState) -> AzTreeView;
+        pub(crate) fn AzTreeView_fromPathsStr(_:  AzString) -> AzTreeView;
+        pub(crate) fn AzTreeView_setVisibleRows(_:  &mut AzTreeView, _:  usize);
+        pub(crate) fn AzTreeView_withVisibleRows(_:  &mut AzTreeView, _:  usize) -> AzTreeView;
+        pub(crate) fn AzTreeView_setSelectionMode(_:  &mut AzTreeView, _:  AzTreeViewSelectionMode);
+        pub(crate) fn AzTreeView_withSelectionMode(_:  &mut AzTreeView, _:  AzTreeViewSelectionMode) -> AzTreeView;
+        pub(crate) fn AzTreeView_setAllowReorder(_:  &mut AzTreeView, _:  bool);
+        pub(crate) fn AzTreeView_withAllowReorder(_:  &mut AzTreeView, _:  bool) -> AzTreeView;
+        pub(crate) fn AzTreeView_setOnLoadChildren(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnLoadChildrenCallbackType);
+        pub(crate) fn AzTreeView_withOnLoadChildren(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnLoadChildrenCallbackType) -> AzTreeView;
+        pub(crate) fn AzTreeView_setOnSelectionChange(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnSelectionChangeCallbackType);
+        pub(crate) fn AzTreeView_withOnSelectionChange(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnSelectionChangeCallbackType) -> AzTreeView;
+        pub(crate) fn AzTreeView_setOnNodeMoved(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnNodeMovedCallbackType);
+        pub(crate) fn AzTreeView_withOnNodeMoved(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnNodeMovedCallbackType) -> AzTreeView;
+        pub(crate) fn AzTreeView_setContainerStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_setRowStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_setGuidesStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_setToggleStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_setIconStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_setLabelStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_setScrollbarThumbStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_setDropIndicatorStyle(_:  &mut AzTreeView, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTreeView_dom(_:  &mut AzTreeView) -> AzDom;
+        pub(crate) fn AzTreeViewState_new() -> AzTreeViewState;
+        pub(crate) fn AzTreeViewState_addNode(_:  &mut AzTreeViewState, _:  AzOptionUsize, _:  AzTreeViewNodeData) -> usize;
+        pub(crate) fn AzTreeViewState_setChildren(_:  &mut AzTreeViewState, _:  usize, _:  AzTreeViewNodeDataVec);
+        pub(crate) fn AzTreeViewState_updateRows(_:  &mut AzTreeViewState);
+        pub(crate) fn AzTreeViewState_expand(_:  &mut AzTreeViewState, _:  usize) -> bool;
+        pub(crate) fn AzTreeViewState_collapse(_:  &mut AzTreeViewState, _:  usize);
+        pub(crate) fn AzTreeViewState_reveal(_:  &mut AzTreeViewState, _:  usize);
+        pub(crate) fn AzTreeViewState_isExpanded(_:  &AzTreeViewState, _:  usize) -> bool;
+        pub(crate) fn AzTreeViewState_clearSelection(_:  &mut AzTreeViewState);
+        pub(crate) fn AzTreeViewState_setSelected(_:  &mut AzTreeViewState, _:  usize, _:  bool);
+        pub(crate) fn AzTreeViewState_select(_:  &mut AzTreeViewState, _:  usize, _:  bool, _:  bool);
+        pub(crate) fn AzTreeViewState_moveNode(_:  &mut AzTreeViewState, _:  usize, _:  AzTreeViewDropTarget) -> bool;
+        pub(crate) fn AzTreeViewNodeData_new(_:  AzString) -> AzTreeViewNodeData;
+        pub(crate) fn AzTreeViewNodeData_withIcon(_:  &mut AzTreeViewNodeData, _:  AzImageRef) -> AzTreeViewNodeData;
+        pub(crate) fn AzTreeViewNodeData_withLazyChildren(_:  &mut AzTreeViewNodeData) -> AzTreeViewNodeData;
+        pub(crate) fn AzTreeViewLoadedChildren_new(_:  usize, _:  AzTreeViewNodeDataVec) -> AzTreeViewLoadedChildren;
+        pub(crate) fn AzTreeViewLoadedChildren_intoWritebackMsg(_:  &mut AzTreeViewLoadedChildren) -> AzThreadWriteBackMsg;
+        pub(crate) fn AzVirtualList_new(_:  usize, _:  f32, _:  AzRefAny, _:  AzVirtualListRenderRowCallbackType) -> AzVirtualList;
+        pub(crate) fn AzVirtualList_fromState(_:  AzRefAny) -> AzVirtualList;
+        pub(crate) fn AzVirtualList_getState(_:  &AzVirtualList) -> AzRefAny;
+        pub(crate) fn AzVirtualList_setRowCount(_:  &mut AzVirtualList, _:  usize);
+        pub(crate) fn AzVirtualList_withRowCount(_:  &mut AzVirtualList, _:  usize) -> AzVirtualList;
+        pub(crate) fn AzVirtualList_setRowHeight(_:  &mut AzVirtualList, _:  AzVirtualListRowHeight);
+        pub(crate) fn AzVirtualList_withRowHeight(_:  &mut AzVirtualList, _:  AzVirtualListRowHeight) -> AzVirtualList;
+        pub(crate) fn AzVirtualList_setScrollOffset(_:  &mut AzVirtualList, _:  f32);
+        pub(crate) fn AzVirtualList_withScrollOffset(_:  &mut AzVirtualList, _:  f32) -> AzVirtualList;
+        pub(crate) fn AzVirtualList_setOverscan(_:  &mut AzVirtualList, _:  usize);
+        pub(crate) fn AzVirtualList_withOverscan(_:  &mut AzVirtualList, _:  usize) -> AzVirtualList;
+        pub(crate) fn AzVirtualList_setOnScroll(_:  &mut AzVirtualList, _:  AzRefAny, _:  AzVirtualListOnScrollCallbackType);
+        pub(crate) fn AzVirtualList_withOnScroll(_:  &mut AzVirtualList, _:  AzRefAny, _:  AzVirtualListOnScrollCallbackType) -> AzVirtualList;
+        pub(crate) fn AzVirtualList_setContainerStyle(_:  &mut AzVirtualList, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzVirtualList_setRowStyle(_:  &mut AzVirtualList, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzVirtualList_setScrollbarTrackStyle(_:  &mut AzVirtualList, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzVirtualList_setScrollbarThumbStyle(_:  &mut AzVirtualList, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzVirtualList_dom(_:  &mut AzVirtualList) -> AzDom;
+        pub(crate) fn AzVirtualListState_new(_:  usize, _:  AzVirtualListRowHeight) -> AzVirtualListState;
+        pub(crate) fn AzVirtualListState_getDefaultRowHeight(_:  &AzVirtualListState) -> f32;
+        pub(crate) fn AzVirtualListState_getRowHeight(_:  &AzVirtualListState, _:  usize) -> f32;
+        pub(crate) fn AzVirtualListState_getRowTop(_:  &AzVirtualListState, _:  usize) -> f32;
+        pub(crate) fn AzVirtualListState_getRowAtOffset(_:  &AzVirtualListState, _:  f32) -> usize;
+        pub(crate) fn AzVirtualListState_getTotalHeight(_:  &AzVirtualListState) -> f32;
+        pub(crate) fn AzVirtualListState_getMaxScrollOffset(_:  &AzVirtualListState) -> f32;
+        pub(crate) fn AzVirtualListState_clampScrollOffset(_:  &AzVirtualListState, _:  f32) -> f32;
         pub(crate) fn AzCssPropertyCache_delete(_:  &mut AzCssPropertyCache);
         pub(crate) fn AzCssPropertyCache_deepCopy(_:  &AzCssPropertyCache) -> AzCssPropertyCache;
         pub(crate) fn AzStyledDom_new(_:  AzDom, _:  AzCss) -> AzStyledDom;
@@ -9219,6 +10790,7 @@ mod dll {
         pub(crate) fn AzTessellatedSvgNodeVec_delete(_:  &mut AzTessellatedSvgNodeVec);
         pub(crate) fn AzStyleFontFamilyVec_delete(_:  &mut AzStyleFontFamilyVec);
         pub(crate) fn AzXmlNodeVec_delete(_:  &mut AzXmlNodeVec);
+        pub(crate) fn AzXmlSpanVec_delete(_:  &mut AzXmlSpanVec);
         pub(crate) fn AzFmtArgVec_delete(_:  &mut AzFmtArgVec);
         pub(crate) fn AzInlineLineVec_delete(_:  &mut AzInlineLineVec);
         pub(crate) fn AzInlineWordVec_delete(_:  &mut AzInlineWordVec);
@@ -9245,6 +10817,15 @@ mod dll {
         pub(crate) fn AzVirtualKeyCodeVec_delete(_:  &mut AzVirtualKeyCodeVec);
         pub(crate) fn AzCascadeInfoVec_delete(_:  &mut AzCascadeInfoVec);
         pub(crate) fn AzScanCodeVec_delete(_:  &mut AzScanCodeVec);
+        pub(crate) fn AzTextInputValidatorVec_delete(_:  &mut AzTextInputValidatorVec);
+        pub(crate) fn AzDropDownChoiceVec_delete(_:  &mut AzDropDownChoiceVec);
+        pub(crate) fn AzTabContainerTabVec_delete(_:  &mut AzTabContainerTabVec);
+        pub(crate) fn AzTextEditUndoEntryVec_delete(_:  &mut AzTextEditUndoEntryVec);
+        pub(crate) fn AzTreeViewNodeDataVec_delete(_:  &mut AzTreeViewNodeDataVec);
+        pub(crate) fn AzTreeViewNodeVec_delete(_:  &mut AzTreeViewNodeVec);
+        pub(crate) fn AzTreeViewRowVec_delete(_:  &mut AzTreeViewRowVec);
+        pub(crate) fn AzTreeViewRenderedRowVec_delete(_:  &mut AzTreeViewRenderedRowVec);
+        pub(crate) fn AzVirtualListMeasuredRowVec_delete(_:  &mut AzVirtualListMeasuredRowVec);
         pub(crate) fn AzCssDeclarationVec_delete(_:  &mut AzCssDeclarationVec);
         pub(crate) fn AzCssPathSelectorVec_delete(_:  &mut AzCssPathSelectorVec);
         pub(crate) fn AzStylesheetVec_delete(_:  &mut AzStylesheetVec);
@@ -9580,6 +11161,9 @@ pub mod window {
     /// Position of the virtual keyboard necessary to insert CJK characters
     
 #[doc(inline)] pub use crate::dll::AzImePosition as ImePosition;
+    /// Pre-edit text of an input method, the IME shows its candidate window at the `WindowState.ime_position`
+    
+#[doc(inline)] pub use crate::dll::AzImeComposition as ImeComposition;
     /// Current state of touch devices / touch inputs
     
 #[doc(inline)] pub use crate::dll::AzTouchState as TouchState;
@@ -9738,6 +11322,7 @@ pub mod callbacks {
     use crate::window::{LogicalPosition, WindowCreateOptions, WindowState};
     use crate::image::{ImageMask, ImageRef};
     use crate::task::{ThreadId, ThreadSendMsg, Timer, TimerId};
+    use crate::option::OptionImageRef;
     /// `LayoutCallback` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutCallback as LayoutCallback;
@@ -9768,6 +11353,8 @@ pub mod callbacks {
     impl CallbackInfo {
         /// Returns the `DomNodeId` of the element that the callback was attached to.
         pub fn get_hit_node(&self)  -> crate::callbacks::DomNodeId { unsafe { crate::dll::AzCallbackInfo_getHitNode(self) } }
+        /// Returns the `DomNodeId` of the node that currently has the keyboard focus, if any.
+        pub fn get_focused_node(&self)  -> crate::option::OptionDomNodeId { unsafe { crate::dll::AzCallbackInfo_getFocusedNode(self) } }
         /// Returns the function pointer necessary to query the current time.
         pub fn get_system_time_fn(&self)  -> crate::task::GetSystemTimeFn { unsafe { crate::dll::AzCallbackInfo_getSystemTimeFn(self) } }
         /// Returns the `LayoutPoint` of the cursor in the viewport (relative to the origin of the `Dom`). Set to `None` if the cursor is not in the current window.
@@ -9860,6 +11447,10 @@ pub mod callbacks {
         pub fn send_thread_msg(&mut self, thread_id: ThreadId, msg: ThreadSendMsg)  -> bool { unsafe { crate::dll::AzCallbackInfo_sendThreadMsg(self, thread_id, msg) } }
         /// Stops a thread at the nearest possible opportunity. Sends a `ThreadSendMsg::TerminateThread` message to the thread and joins the thread.
         pub fn stop_thread(&mut self, thread_id: ThreadId)  -> bool { unsafe { crate::dll::AzCallbackInfo_stopThread(self, thread_id) } }
+        /// Accepts a pending drag operation on the hit node, only has an effect when called from a `DragStart` callback. The `payload` is handed to the `Drop` callback of the target node, the `ghost` image (if any) follows the cursor while dragging.
+        pub fn start_drag(&mut self, payload: RefAny, ghost: OptionImageRef)  -> bool { unsafe { crate::dll::AzCallbackInfo_startDrag(self, payload, ghost) } }
+        /// Returns the data of the drag operation that is currently in progress (or that was just dropped)
+        pub fn get_drag_data(&self)  -> crate::option::OptionDragData { unsafe { crate::dll::AzCallbackInfo_getDragData(self) } }
     }
 
     /// Which type of image should be updated: background image (the CSS background) or content image (the <img src=""> content)
@@ -9921,6 +11512,9 @@ pub mod callbacks {
     /// Defines the keyboard input focus target
     
 #[doc(inline)] pub use crate::dll::AzFocusTarget as FocusTarget;
+    /// Data of an in-app drag operation, created by `CallbackInfo::start_drag`
+    
+#[doc(inline)] pub use crate::dll::AzDragData as DragData;
     /// CSS path to set the keyboard input focus
     
 #[doc(inline)] pub use crate::dll::AzFocusTargetPath as FocusTargetPath;
@@ -10182,7 +11776,7 @@ pub mod dom {
     use crate::callbacks::{CallbackType, IFrameCallbackType, RefAny};
     use crate::vec::{CallbackDataVec, DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec};
     use crate::css::{Css, CssProperty};
-    use crate::menu::Menu;
+    use crate::menu::{Menu, VirtualKeyCodeCombo};
     /// `Dom` struct
     
 #[doc(inline)] pub use crate::dll::AzDom as Dom;
@@ -10265,6 +11859,18 @@ pub mod dom {
         pub fn set_tab_index(&mut self, tab_index: TabIndex)  { unsafe { crate::dll::AzDom_setTabIndex(self, tab_index) } }
         /// Same as set_tab_index, but as a builder method
         pub fn with_tab_index(&mut self, tab_index: TabIndex)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withTabIndex(self, tab_index) } }
+        /// Sets a key that identifies the DOM root node among its siblings, so that it keeps its focus and scroll position when the DOM is re-generated
+        pub fn set_key(&mut self, key: u64)  { unsafe { crate::dll::AzDom_setKey(self, key) } }
+        /// Same as set_key, but as a builder method
+        pub fn with_key(&mut self, key: u64)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withKey(self, key) } }
+        /// Makes the DOM root node a focus trap or a roving focus group
+        pub fn set_focus_scope(&mut self, focus_scope: FocusScope)  { unsafe { crate::dll::AzDom_setFocusScope(self, focus_scope) } }
+        /// Same as set_focus_scope, but as a builder method
+        pub fn with_focus_scope(&mut self, focus_scope: FocusScope)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withFocusScope(self, focus_scope) } }
+        /// Adds a keyboard shortcut to the DOM root node that is active while the focus is inside of the node. See `NodeData::add_shortcut` for more information.
+        pub fn add_shortcut(&mut self, combo: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType)  { unsafe { crate::dll::AzDom_addShortcut(self, combo, data, callback) } }
+        /// Same as add_shortcut, but as a builder method
+        pub fn with_shortcut(&mut self, combo: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withShortcut(self, combo, data, callback) } }
         /// Sets accessibility attributes for the DOM root node.
         pub fn set_accessibility_info(&mut self, accessibility_info: AccessibilityInfo)  { unsafe { crate::dll::AzDom_setAccessibilityInfo(self, accessibility_info) } }
         /// Same as set_accessibility_info, but as a builder method
@@ -10341,6 +11947,12 @@ pub mod dom {
         pub fn set_clip_mask(&mut self, image_mask: ImageMask)  { unsafe { crate::dll::AzNodeData_setClipMask(self, image_mask) } }
         /// Sets the tab index for this node
         pub fn set_tab_index(&mut self, tab_index: TabIndex)  { unsafe { crate::dll::AzNodeData_setTabIndex(self, tab_index) } }
+        /// Sets a key that identifies this node among its siblings, so that it keeps its focus and scroll position when the DOM is re-generated
+        pub fn set_key(&mut self, key: u64)  { unsafe { crate::dll::AzNodeData_setKey(self, key) } }
+        /// Makes this node a focus trap or a roving focus group
+        pub fn set_focus_scope(&mut self, focus_scope: FocusScope)  { unsafe { crate::dll::AzNodeData_setFocusScope(self, focus_scope) } }
+        /// Adds a keyboard shortcut that invokes the callback if the key combination is pressed while the focus is on this node or one of its children. Shortcuts of nodes closer to the focused node take precedence over the menu bar accelerators.
+        pub fn add_shortcut(&mut self, combo: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType)  { unsafe { crate::dll::AzNodeData_addShortcut(self, combo, data, callback) } }
         /// Sets accessibility attributes for this node
         pub fn set_accessibility_info(&mut self, accessibility_info: AccessibilityInfo)  { unsafe { crate::dll::AzNodeData_setAccessibilityInfo(self, accessibility_info) } }
         /// Adds a (native) menu bar: If this node is the root node the menu bar will be added to the window, else it will be displayed using the width and position of the bounding rectangle
@@ -10395,6 +12007,15 @@ pub mod dom {
     /// `TabIndex` struct
     
 #[doc(inline)] pub use crate::dll::AzTabIndex as TabIndex;
+    /// Restricts the keyboard focus navigation to the subtree of a node
+    
+#[doc(inline)] pub use crate::dll::AzFocusScope as FocusScope;
+    /// Configures the arrow key navigation of a `FocusScope::Roving`
+    
+#[doc(inline)] pub use crate::dll::AzRovingFocus as RovingFocus;
+    /// `RovingFocusOrientation` struct
+    
+#[doc(inline)] pub use crate::dll::AzRovingFocusOrientation as RovingFocusOrientation;
     /// `IdOrClass` struct
     
 #[doc(inline)] pub use crate::dll::AzIdOrClass as IdOrClass;
@@ -11685,8 +13306,11 @@ pub mod widgets {
     use core::ffi::c_void;
     use crate::str::String;
     use crate::callbacks::{CallbackType, RefAny};
+    use crate::vec::{CssPropertyVec, DomVec, DropDownChoiceVec, NodeDataInlineCssPropertyVec, TreeViewNodeDataVec};
+    use crate::option::{OptionChar, OptionF32, OptionUsize};
     use crate::css::ColorU;
-    use crate::vec::NodeDataInlineCssPropertyVec;
+    use crate::dom::Dom;
+    use crate::image::ImageRef;
     /// `Button` struct
     
 #[doc(inline)] pub use crate::dll::AzButton as Button;
@@ -11733,6 +13357,66 @@ pub mod widgets {
     /// `CheckBoxState` struct
     
 #[doc(inline)] pub use crate::dll::AzCheckBoxState as CheckBoxState;
+    /// `DropDown` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDown as DropDown;
+    impl DropDown {
+        /// Creates a new `DropDown` instance.
+        pub fn new(choices: DropDownChoiceVec) -> Self { unsafe { crate::dll::AzDropDown_new(choices) } }
+        /// Parses a list of choices in the form `"Apple, Fruits/Banana, !Cherry"`: `Group/Label` puts the choice into a group, a leading `!` disables the choice
+        pub fn from_choices_str(choices: String) -> Self { unsafe { crate::dll::AzDropDown_fromChoicesStr(choices) } }
+        /// Calls the `DropDown::set_selected` function.
+        pub fn set_selected(&mut self, selected: OptionUsize)  { unsafe { crate::dll::AzDropDown_setSelected(self, selected) } }
+        /// Calls the `DropDown::with_selected` function.
+        pub fn with_selected(&mut self, selected: usize)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withSelected(self, selected) } }
+        /// Selects the first choice with the given label, does nothing if no choice matches
+        pub fn with_selected_label(&mut self, label: String)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withSelectedLabel(self, label) } }
+        /// Calls the `DropDown::set_on_choice_change` function.
+        pub fn set_on_choice_change(&mut self, data: RefAny, callback: DropDownOnChoiceChangeCallbackType)  { unsafe { crate::dll::AzDropDown_setOnChoiceChange(self, data, callback) } }
+        /// Calls the `DropDown::with_on_choice_change` function.
+        pub fn with_on_choice_change(&mut self, data: RefAny, callback: DropDownOnChoiceChangeCallbackType)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withOnChoiceChange(self, data, callback) } }
+        /// Calls the `DropDown::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setContainerStyle(self, style) } }
+        /// Calls the `DropDown::set_header_style` function.
+        pub fn set_header_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setHeaderStyle(self, style) } }
+        /// Calls the `DropDown::set_popup_style` function.
+        pub fn set_popup_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setPopupStyle(self, style) } }
+        /// Calls the `DropDown::set_choice_style` function.
+        pub fn set_choice_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setChoiceStyle(self, style) } }
+        /// Calls the `DropDown::set_group_style` function.
+        pub fn set_group_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setGroupStyle(self, style) } }
+        /// Calls the `DropDown::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzDropDown_dom(self) } }
+    }
+
+    /// `DropDownStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownStateWrapper as DropDownStateWrapper;
+    /// `DropDownState` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownState as DropDownState;
+    impl DropDownState {
+        /// Calls the `DropDownState::is_selectable` function.
+        pub fn is_selectable(&self, index: usize)  -> bool { unsafe { crate::dll::AzDropDownState_isSelectable(self, index) } }
+    }
+
+    /// Single selectable entry of a `DropDown`
+    
+#[doc(inline)] pub use crate::dll::AzDropDownChoice as DropDownChoice;
+    impl DropDownChoice {
+        /// Creates a new `DropDownChoice` instance.
+        pub fn new(label: String) -> Self { unsafe { crate::dll::AzDropDownChoice_new(label) } }
+    }
+
+    /// `DropDownOnChoiceChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownOnChoiceChange as DropDownOnChoiceChange;
+    /// `DropDownOnChoiceChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownOnChoiceChangeCallback as DropDownOnChoiceChangeCallback;
+    /// `DropDownOnChoiceChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownOnChoiceChangeCallbackType as DropDownOnChoiceChangeCallbackType;
     /// `Label` struct
     
 #[doc(inline)] pub use crate::dll::AzLabel as Label;
@@ -11802,6 +13486,24 @@ pub mod widgets {
         pub fn set_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextInput_setLabelStyle(self, label_style) } }
         /// Calls the `TextInput::with_label_style` function.
         pub fn with_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withLabelStyle(self, label_style) } }
+        /// Calls the `TextInput::set_on_validation_change` function.
+        pub fn set_on_validation_change(&mut self, data: RefAny, callback: TextInputOnValidationChangeCallbackType)  { unsafe { crate::dll::AzTextInput_setOnValidationChange(self, data, callback) } }
+        /// Calls the `TextInput::add_validator` function.
+        pub fn add_validator(&mut self, validator: TextInputValidator)  { unsafe { crate::dll::AzTextInput_addValidator(self, validator) } }
+        /// Calls the `TextInput::with_validator` function.
+        pub fn with_validator(&mut self, validator: TextInputValidator)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withValidator(self, validator) } }
+        /// Calls the `TextInput::set_mask` function.
+        pub fn set_mask(&mut self, mask: TextInputMask)  { unsafe { crate::dll::AzTextInput_setMask(self, mask) } }
+        /// Calls the `TextInput::with_mask` function.
+        pub fn with_mask(&mut self, mask: TextInputMask)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withMask(self, mask) } }
+        /// Password mode: displays every character as `obscure_char` (`None` = display the text)
+        pub fn set_obscure_char(&mut self, obscure_char: OptionChar)  { unsafe { crate::dll::AzTextInput_setObscureChar(self, obscure_char) } }
+        /// Calls the `TextInput::with_password_mode` function.
+        pub fn with_password_mode(&mut self)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withPasswordMode(self) } }
+        /// Sets the properties that are applied to the container while the text is invalid
+        pub fn set_error_style(&mut self, error_style: CssPropertyVec)  { unsafe { crate::dll::AzTextInput_setErrorStyle(self, error_style) } }
+        /// Calls the `TextInput::set_preedit_style` function.
+        pub fn set_preedit_style(&mut self, preedit_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextInput_setPreeditStyle(self, preedit_style) } }
         /// Calls the `TextInput::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTextInput_dom(self) } }
     }
@@ -11815,6 +13517,10 @@ pub mod widgets {
     impl TextInputState {
         /// Calls the `TextInputState::get_text` function.
         pub fn get_text(&self)  -> crate::str::String { unsafe { crate::dll::AzTextInputState_getText(self) } }
+        /// Calls the `TextInputState::get_display_text` function.
+        pub fn get_display_text(&self)  -> crate::str::String { unsafe { crate::dll::AzTextInputState_getDisplayText(self) } }
+        /// Calls the `TextInputState::validate` function.
+        pub fn validate(&self)  -> crate::widgets::TextInputValidationResult { unsafe { crate::dll::AzTextInputState_validate(self) } }
     }
 
     /// `TextInputSelection` struct
@@ -11856,6 +13562,46 @@ pub mod widgets {
     /// `TextInputValid` struct
     
 #[doc(inline)] pub use crate::dll::AzTextInputValid as TextInputValid;
+    /// `TextInputValidationResult` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputValidationResult as TextInputValidationResult;
+    /// Validator that is run on every change of the text, an empty text is only rejected by `NotEmpty`
+    
+#[doc(inline)] pub use crate::dll::AzTextInputValidator as TextInputValidator;
+    /// `TextInputCustomValidator` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputCustomValidator as TextInputCustomValidator;
+    /// `TextInputValidatorCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputValidatorCallback as TextInputValidatorCallback;
+    /// `TextInputValidatorCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputValidatorCallbackType as TextInputValidatorCallbackType;
+    /// Input mask: `9` = digit, `#` = optional digit, `a` = letter, `*` = letter or digit, other characters are literals that are inserted automatically (`99/99/9999`)
+    
+#[doc(inline)] pub use crate::dll::AzTextInputMask as TextInputMask;
+    impl TextInputMask {
+        /// Creates a new `TextInputMask` instance.
+        pub fn new(pattern: String) -> Self { unsafe { crate::dll::AzTextInputMask_new(pattern) } }
+        /// Creates a new `TextInputMask` instance.
+        pub fn date() -> Self { unsafe { crate::dll::AzTextInputMask_date() } }
+        /// Creates a new `TextInputMask` instance.
+        pub fn time() -> Self { unsafe { crate::dll::AzTextInputMask_time() } }
+        /// Creates a new `TextInputMask` instance.
+        pub fn phone() -> Self { unsafe { crate::dll::AzTextInputMask_phone() } }
+        /// Creates a new `TextInputMask` instance.
+        pub fn ipv4() -> Self { unsafe { crate::dll::AzTextInputMask_ipv4() } }
+    }
+
+    /// `TextInputOnValidationChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputOnValidationChange as TextInputOnValidationChange;
+    /// `TextInputOnValidationChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputOnValidationChangeCallback as TextInputOnValidationChangeCallback;
+    /// `TextInputOnValidationChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputOnValidationChangeCallbackType as TextInputOnValidationChangeCallbackType;
     /// `NumberInput` struct
     
 #[doc(inline)] pub use crate::dll::AzNumberInput as NumberInput;
@@ -11934,6 +13680,537 @@ pub mod widgets {
     /// `ProgressBarState` struct
     
 #[doc(inline)] pub use crate::dll::AzProgressBarState as ProgressBarState;
+    /// `SplitPane` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPane as SplitPane;
+    impl SplitPane {
+        /// Creates a new `SplitPane` instance.
+        pub fn new(orientation: SplitPaneOrientation, first_pane: Dom, second_pane: Dom) -> Self { unsafe { crate::dll::AzSplitPane_new(orientation, first_pane, second_pane) } }
+        /// Sets the size of the first pane relative to the container (0.0 - 1.0)
+        pub fn set_ratio(&mut self, ratio: f32)  { unsafe { crate::dll::AzSplitPane_setRatio(self, ratio) } }
+        /// Calls the `SplitPane::with_ratio` function.
+        pub fn with_ratio(&mut self, ratio: f32)  -> crate::widgets::SplitPane { unsafe { crate::dll::AzSplitPane_withRatio(self, ratio) } }
+        /// Sets the minimum sizes (in pixels) of the first and second pane
+        pub fn set_min_sizes(&mut self, min_first_size: f32, min_second_size: f32)  { unsafe { crate::dll::AzSplitPane_setMinSizes(self, min_first_size, min_second_size) } }
+        /// Calls the `SplitPane::with_min_sizes` function.
+        pub fn with_min_sizes(&mut self, min_first_size: f32, min_second_size: f32)  -> crate::widgets::SplitPane { unsafe { crate::dll::AzSplitPane_withMinSizes(self, min_first_size, min_second_size) } }
+        /// Calls the `SplitPane::set_collapsible` function.
+        pub fn set_collapsible(&mut self, collapsible: bool)  { unsafe { crate::dll::AzSplitPane_setCollapsible(self, collapsible) } }
+        /// Calls the `SplitPane::with_collapsible` function.
+        pub fn with_collapsible(&mut self, collapsible: bool)  -> crate::widgets::SplitPane { unsafe { crate::dll::AzSplitPane_withCollapsible(self, collapsible) } }
+        /// Calls the `SplitPane::set_collapsed` function.
+        pub fn set_collapsed(&mut self, collapsed: SplitPaneCollapsed)  { unsafe { crate::dll::AzSplitPane_setCollapsed(self, collapsed) } }
+        /// Calls the `SplitPane::with_collapsed` function.
+        pub fn with_collapsed(&mut self, collapsed: SplitPaneCollapsed)  -> crate::widgets::SplitPane { unsafe { crate::dll::AzSplitPane_withCollapsed(self, collapsed) } }
+        /// Calls the `SplitPane::set_on_resize` function.
+        pub fn set_on_resize(&mut self, data: RefAny, callback: SplitPaneOnResizeCallbackType)  { unsafe { crate::dll::AzSplitPane_setOnResize(self, data, callback) } }
+        /// Calls the `SplitPane::with_on_resize` function.
+        pub fn with_on_resize(&mut self, data: RefAny, callback: SplitPaneOnResizeCallbackType)  -> crate::widgets::SplitPane { unsafe { crate::dll::AzSplitPane_withOnResize(self, data, callback) } }
+        /// Calls the `SplitPane::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSplitPane_setContainerStyle(self, style) } }
+        /// Calls the `SplitPane::set_pane_style` function.
+        pub fn set_pane_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSplitPane_setPaneStyle(self, style) } }
+        /// Calls the `SplitPane::set_divider_style` function.
+        pub fn set_divider_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSplitPane_setDividerStyle(self, style) } }
+        /// Calls the `SplitPane::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSplitPane_dom(self) } }
+    }
+
+    /// `SplitPaneStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPaneStateWrapper as SplitPaneStateWrapper;
+    /// `SplitPaneState` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPaneState as SplitPaneState;
+    /// `SplitPaneOrientation` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPaneOrientation as SplitPaneOrientation;
+    /// `SplitPaneCollapsed` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPaneCollapsed as SplitPaneCollapsed;
+    /// `SplitPaneOnResize` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPaneOnResize as SplitPaneOnResize;
+    /// `SplitPaneOnResizeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPaneOnResizeCallback as SplitPaneOnResizeCallback;
+    /// `SplitPaneOnResizeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSplitPaneOnResizeCallbackType as SplitPaneOnResizeCallbackType;
+    /// `TabContainer` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainer as TabContainer;
+    impl TabContainer {
+        /// Creates a new `TabContainer` instance.
+        pub fn new() -> Self { unsafe { crate::dll::AzTabContainer_new() } }
+        /// Creates a tab container from a comma-separated list of tab titles (`"Editor, Console, Output"`) and the content of the tabs, in the same order
+        pub fn from_titles_str(titles: String, pages: DomVec) -> Self { unsafe { crate::dll::AzTabContainer_fromTitlesStr(titles, pages) } }
+        /// Appends a tab, the first tab is activated automatically
+        pub fn add_tab(&mut self, title: String, content: Dom)  { unsafe { crate::dll::AzTabContainer_addTab(self, title, content) } }
+        /// Calls the `TabContainer::with_tab` function.
+        pub fn with_tab(&mut self, title: String, content: Dom)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withTab(self, title, content) } }
+        /// Calls the `TabContainer::set_active_tab` function.
+        pub fn set_active_tab(&mut self, tab: usize)  { unsafe { crate::dll::AzTabContainer_setActiveTab(self, tab) } }
+        /// Calls the `TabContainer::with_active_tab` function.
+        pub fn with_active_tab(&mut self, tab: usize)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withActiveTab(self, tab) } }
+        /// Adds / removes the close button of all tabs that were added so far
+        pub fn set_closable(&mut self, closable: bool)  { unsafe { crate::dll::AzTabContainer_setClosable(self, closable) } }
+        /// Calls the `TabContainer::with_closable` function.
+        pub fn with_closable(&mut self, closable: bool)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withClosable(self, closable) } }
+        /// Calls the `TabContainer::set_overflow` function.
+        pub fn set_overflow(&mut self, overflow: TabContainerOverflow)  { unsafe { crate::dll::AzTabContainer_setOverflow(self, overflow) } }
+        /// Calls the `TabContainer::with_overflow` function.
+        pub fn with_overflow(&mut self, overflow: TabContainerOverflow)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withOverflow(self, overflow) } }
+        /// Allows reordering the tabs by dragging them along the tab strip
+        pub fn set_allow_reorder(&mut self, allow_reorder: bool)  { unsafe { crate::dll::AzTabContainer_setAllowReorder(self, allow_reorder) } }
+        /// Calls the `TabContainer::with_allow_reorder` function.
+        pub fn with_allow_reorder(&mut self, allow_reorder: bool)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withAllowReorder(self, allow_reorder) } }
+        /// Calls the `TabContainer::set_on_tab_change` function.
+        pub fn set_on_tab_change(&mut self, data: RefAny, callback: TabContainerOnTabChangeCallbackType)  { unsafe { crate::dll::AzTabContainer_setOnTabChange(self, data, callback) } }
+        /// Calls the `TabContainer::with_on_tab_change` function.
+        pub fn with_on_tab_change(&mut self, data: RefAny, callback: TabContainerOnTabChangeCallbackType)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withOnTabChange(self, data, callback) } }
+        /// Calls the `TabContainer::set_on_tab_close` function.
+        pub fn set_on_tab_close(&mut self, data: RefAny, callback: TabContainerOnTabCloseCallbackType)  { unsafe { crate::dll::AzTabContainer_setOnTabClose(self, data, callback) } }
+        /// Calls the `TabContainer::with_on_tab_close` function.
+        pub fn with_on_tab_close(&mut self, data: RefAny, callback: TabContainerOnTabCloseCallbackType)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withOnTabClose(self, data, callback) } }
+        /// Calls the `TabContainer::set_on_tab_move` function.
+        pub fn set_on_tab_move(&mut self, data: RefAny, callback: TabContainerOnTabMoveCallbackType)  { unsafe { crate::dll::AzTabContainer_setOnTabMove(self, data, callback) } }
+        /// Calls the `TabContainer::with_on_tab_move` function.
+        pub fn with_on_tab_move(&mut self, data: RefAny, callback: TabContainerOnTabMoveCallbackType)  -> crate::widgets::TabContainer { unsafe { crate::dll::AzTabContainer_withOnTabMove(self, data, callback) } }
+        /// Calls the `TabContainer::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTabContainer_setContainerStyle(self, style) } }
+        /// Calls the `TabContainer::set_header_style` function.
+        pub fn set_header_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTabContainer_setHeaderStyle(self, style) } }
+        /// Calls the `TabContainer::set_tab_style` function.
+        pub fn set_tab_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTabContainer_setTabStyle(self, style) } }
+        /// Calls the `TabContainer::set_close_button_style` function.
+        pub fn set_close_button_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTabContainer_setCloseButtonStyle(self, style) } }
+        /// Calls the `TabContainer::set_button_style` function.
+        pub fn set_button_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTabContainer_setButtonStyle(self, style) } }
+        /// Calls the `TabContainer::set_menu_style` function.
+        pub fn set_menu_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTabContainer_setMenuStyle(self, style) } }
+        /// Calls the `TabContainer::set_page_style` function.
+        pub fn set_page_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTabContainer_setPageStyle(self, style) } }
+        /// Calls the `TabContainer::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTabContainer_dom(self) } }
+    }
+
+    /// `TabContainerStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerStateWrapper as TabContainerStateWrapper;
+    /// `TabContainerState` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerState as TabContainerState;
+    /// Header of a single tab
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerTab as TabContainerTab;
+    /// What happens if the tabs don't fit into the header
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOverflow as TabContainerOverflow;
+    /// `TabContainerOnTabChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabChange as TabContainerOnTabChange;
+    /// `TabContainerOnTabChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabChangeCallback as TabContainerOnTabChangeCallback;
+    /// `TabContainerOnTabChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabChangeCallbackType as TabContainerOnTabChangeCallbackType;
+    /// `TabContainerOnTabClose` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabClose as TabContainerOnTabClose;
+    /// `TabContainerOnTabCloseCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabCloseCallback as TabContainerOnTabCloseCallback;
+    /// `TabContainerOnTabCloseCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabCloseCallbackType as TabContainerOnTabCloseCallbackType;
+    /// `TabContainerOnTabMove` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabMove as TabContainerOnTabMove;
+    /// `TabContainerOnTabMoveCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabMoveCallback as TabContainerOnTabMoveCallback;
+    /// `TabContainerOnTabMoveCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerOnTabMoveCallbackType as TabContainerOnTabMoveCallbackType;
+    /// `Slider` struct
+    
+#[doc(inline)] pub use crate::dll::AzSlider as Slider;
+    impl Slider {
+        /// Creates a new `Slider` instance.
+        pub fn new(value: f32, min: f32, max: f32) -> Self { unsafe { crate::dll::AzSlider_new(value, min, max) } }
+        /// Creates a range slider with two thumbs at `lower` and `upper`
+        pub fn new_range(lower: f32, upper: f32, min: f32, max: f32) -> Self { unsafe { crate::dll::AzSlider_newRange(lower, upper, min, max) } }
+        /// Sets the orientation, resets the container and track style to the default style of the orientation
+        pub fn set_orientation(&mut self, orientation: SliderOrientation)  { unsafe { crate::dll::AzSlider_setOrientation(self, orientation) } }
+        /// Calls the `Slider::with_orientation` function.
+        pub fn with_orientation(&mut self, orientation: SliderOrientation)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withOrientation(self, orientation) } }
+        /// Calls the `Slider::set_step` function.
+        pub fn set_step(&mut self, step: f32)  { unsafe { crate::dll::AzSlider_setStep(self, step) } }
+        /// Calls the `Slider::with_step` function.
+        pub fn with_step(&mut self, step: f32)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withStep(self, step) } }
+        /// Calls the `Slider::set_tick_interval` function.
+        pub fn set_tick_interval(&mut self, tick_interval: OptionF32)  { unsafe { crate::dll::AzSlider_setTickInterval(self, tick_interval) } }
+        /// Calls the `Slider::with_tick_interval` function.
+        pub fn with_tick_interval(&mut self, tick_interval: f32)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withTickInterval(self, tick_interval) } }
+        /// Calls the `Slider::set_on_value_change` function.
+        pub fn set_on_value_change(&mut self, data: RefAny, callback: SliderOnValueChangeCallbackType)  { unsafe { crate::dll::AzSlider_setOnValueChange(self, data, callback) } }
+        /// Calls the `Slider::with_on_value_change` function.
+        pub fn with_on_value_change(&mut self, data: RefAny, callback: SliderOnValueChangeCallbackType)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withOnValueChange(self, data, callback) } }
+        /// Calls the `Slider::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setContainerStyle(self, style) } }
+        /// Calls the `Slider::set_track_style` function.
+        pub fn set_track_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setTrackStyle(self, style) } }
+        /// Calls the `Slider::set_fill_style` function.
+        pub fn set_fill_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setFillStyle(self, style) } }
+        /// Calls the `Slider::set_thumb_style` function.
+        pub fn set_thumb_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setThumbStyle(self, style) } }
+        /// Calls the `Slider::set_tick_style` function.
+        pub fn set_tick_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setTickStyle(self, style) } }
+        /// Calls the `Slider::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSlider_dom(self) } }
+    }
+
+    /// `SliderStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderStateWrapper as SliderStateWrapper;
+    /// `SliderState` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderState as SliderState;
+    /// `SliderOrientation` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOrientation as SliderOrientation;
+    /// Identifies one of the thumbs, a non-range slider only has the `Lower` thumb
+    
+#[doc(inline)] pub use crate::dll::AzSliderThumb as SliderThumb;
+    /// `SliderOnValueChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOnValueChange as SliderOnValueChange;
+    /// `SliderOnValueChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOnValueChangeCallback as SliderOnValueChangeCallback;
+    /// `SliderOnValueChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOnValueChangeCallbackType as SliderOnValueChangeCallbackType;
+    /// `TextEdit` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEdit as TextEdit;
+    impl TextEdit {
+        /// Creates a new `TextEdit` instance.
+        pub fn new(text: String) -> Self { unsafe { crate::dll::AzTextEdit_new(text) } }
+        /// Creates the editor from a previous state, e.g. the state that was passed to the `on_text_change` callback (keeps the cursor and scroll position)
+        pub fn from_state(state: TextEditState) -> Self { unsafe { crate::dll::AzTextEdit_fromState(state) } }
+        /// Sets the number of visible rows, the height of the editor is `rows * 18px`
+        pub fn set_visible_rows(&mut self, rows: usize)  { unsafe { crate::dll::AzTextEdit_setVisibleRows(self, rows) } }
+        /// Calls the `TextEdit::with_visible_rows` function.
+        pub fn with_visible_rows(&mut self, rows: usize)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withVisibleRows(self, rows) } }
+        /// Calls the `TextEdit::set_wrap_lines` function.
+        pub fn set_wrap_lines(&mut self, wrap_lines: bool)  { unsafe { crate::dll::AzTextEdit_setWrapLines(self, wrap_lines) } }
+        /// Calls the `TextEdit::with_wrap_lines` function.
+        pub fn with_wrap_lines(&mut self, wrap_lines: bool)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withWrapLines(self, wrap_lines) } }
+        /// Calls the `TextEdit::set_on_text_change` function.
+        pub fn set_on_text_change(&mut self, data: RefAny, callback: TextEditOnTextChangeCallbackType)  { unsafe { crate::dll::AzTextEdit_setOnTextChange(self, data, callback) } }
+        /// Calls the `TextEdit::with_on_text_change` function.
+        pub fn with_on_text_change(&mut self, data: RefAny, callback: TextEditOnTextChangeCallbackType)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withOnTextChange(self, data, callback) } }
+        /// Calls the `TextEdit::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setContainerStyle(self, style) } }
+        /// Calls the `TextEdit::set_row_style` function.
+        pub fn set_row_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setRowStyle(self, style) } }
+        /// Calls the `TextEdit::set_label_style` function.
+        pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setLabelStyle(self, style) } }
+        /// Calls the `TextEdit::set_selection_style` function.
+        pub fn set_selection_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setSelectionStyle(self, style) } }
+        /// Calls the `TextEdit::set_caret_style` function.
+        pub fn set_caret_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setCaretStyle(self, style) } }
+        /// Calls the `TextEdit::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTextEdit_dom(self) } }
+    }
+
+    /// `TextEditStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditStateWrapper as TextEditStateWrapper;
+    /// `TextEditState` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditState as TextEditState;
+    impl TextEditState {
+        /// Returns the text, lines separated by `\n`
+        pub fn get_text(&self)  -> crate::str::String { unsafe { crate::dll::AzTextEditState_getText(self) } }
+    }
+
+    /// Position in the text: line index and character (not byte) index in the line
+    
+#[doc(inline)] pub use crate::dll::AzTextEditCursor as TextEditCursor;
+    /// Kind of an edit, consecutive edits of the same kind are undone together
+    
+#[doc(inline)] pub use crate::dll::AzTextEditEditKind as TextEditEditKind;
+    /// Reversible edit: `removed` was replaced by `inserted` at `start`
+    
+#[doc(inline)] pub use crate::dll::AzTextEditUndoEntry as TextEditUndoEntry;
+    /// `TextEditOnTextChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnTextChange as TextEditOnTextChange;
+    /// `TextEditOnTextChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnTextChangeCallback as TextEditOnTextChangeCallback;
+    /// `TextEditOnTextChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnTextChangeCallbackType as TextEditOnTextChangeCallbackType;
+    /// `TreeView` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeView as TreeView;
+    impl TreeView {
+        /// Creates a new `TreeView` instance.
+        pub fn new(state: TreeViewState) -> Self { unsafe { crate::dll::AzTreeView_new(state) } }
+        /// Creates a tree from a list of paths in the form `"Documents/notes.txt, Documents/Photos, Music"`: every path segment is a node, nodes with the same path are only created once
+        pub fn from_paths_str(paths: String) -> Self { unsafe { crate::dll::AzTreeView_fromPathsStr(paths) } }
+        /// Sets the number of visible rows, the height of the tree is `rows * 20px`
+        pub fn set_visible_rows(&mut self, rows: usize)  { unsafe { crate::dll::AzTreeView_setVisibleRows(self, rows) } }
+        /// Calls the `TreeView::with_visible_rows` function.
+        pub fn with_visible_rows(&mut self, rows: usize)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withVisibleRows(self, rows) } }
+        /// Calls the `TreeView::set_selection_mode` function.
+        pub fn set_selection_mode(&mut self, selection_mode: TreeViewSelectionMode)  { unsafe { crate::dll::AzTreeView_setSelectionMode(self, selection_mode) } }
+        /// Calls the `TreeView::with_selection_mode` function.
+        pub fn with_selection_mode(&mut self, selection_mode: TreeViewSelectionMode)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withSelectionMode(self, selection_mode) } }
+        /// Allows reordering nodes by dragging them onto other nodes
+        pub fn set_allow_reorder(&mut self, allow_reorder: bool)  { unsafe { crate::dll::AzTreeView_setAllowReorder(self, allow_reorder) } }
+        /// Calls the `TreeView::with_allow_reorder` function.
+        pub fn with_allow_reorder(&mut self, allow_reorder: bool)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withAllowReorder(self, allow_reorder) } }
+        /// Calls the `TreeView::set_on_load_children` function.
+        pub fn set_on_load_children(&mut self, data: RefAny, callback: TreeViewOnLoadChildrenCallbackType)  { unsafe { crate::dll::AzTreeView_setOnLoadChildren(self, data, callback) } }
+        /// Calls the `TreeView::with_on_load_children` function.
+        pub fn with_on_load_children(&mut self, data: RefAny, callback: TreeViewOnLoadChildrenCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnLoadChildren(self, data, callback) } }
+        /// Calls the `TreeView::set_on_selection_change` function.
+        pub fn set_on_selection_change(&mut self, data: RefAny, callback: TreeViewOnSelectionChangeCallbackType)  { unsafe { crate::dll::AzTreeView_setOnSelectionChange(self, data, callback) } }
+        /// Calls the `TreeView::with_on_selection_change` function.
+        pub fn with_on_selection_change(&mut self, data: RefAny, callback: TreeViewOnSelectionChangeCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnSelectionChange(self, data, callback) } }
+        /// Calls the `TreeView::set_on_node_moved` function.
+        pub fn set_on_node_moved(&mut self, data: RefAny, callback: TreeViewOnNodeMovedCallbackType)  { unsafe { crate::dll::AzTreeView_setOnNodeMoved(self, data, callback) } }
+        /// Calls the `TreeView::with_on_node_moved` function.
+        pub fn with_on_node_moved(&mut self, data: RefAny, callback: TreeViewOnNodeMovedCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnNodeMoved(self, data, callback) } }
+        /// Calls the `TreeView::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setContainerStyle(self, style) } }
+        /// Calls the `TreeView::set_row_style` function.
+        pub fn set_row_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setRowStyle(self, style) } }
+        /// Calls the `TreeView::set_guides_style` function.
+        pub fn set_guides_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setGuidesStyle(self, style) } }
+        /// Calls the `TreeView::set_toggle_style` function.
+        pub fn set_toggle_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setToggleStyle(self, style) } }
+        /// Calls the `TreeView::set_icon_style` function.
+        pub fn set_icon_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setIconStyle(self, style) } }
+        /// Calls the `TreeView::set_label_style` function.
+        pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setLabelStyle(self, style) } }
+        /// Calls the `TreeView::set_scrollbar_thumb_style` function.
+        pub fn set_scrollbar_thumb_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setScrollbarThumbStyle(self, style) } }
+        /// Calls the `TreeView::set_drop_indicator_style` function.
+        pub fn set_drop_indicator_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTreeView_setDropIndicatorStyle(self, style) } }
+        /// Calls the `TreeView::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTreeView_dom(self) } }
+    }
+
+    /// `TreeViewStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewStateWrapper as TreeViewStateWrapper;
+    /// `TreeViewState` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewState as TreeViewState;
+    impl TreeViewState {
+        /// Creates a new `TreeViewState` instance.
+        pub fn new() -> Self { unsafe { crate::dll::AzTreeViewState_new() } }
+        /// Appends a node to the children of `parent` (or to the root nodes), returns the id of the new node. Call `update_rows` once the tree is built.
+        pub fn add_node(&mut self, parent: OptionUsize, data: TreeViewNodeData)  -> usize { unsafe { crate::dll::AzTreeViewState_addNode(self, parent, data) } }
+        /// Replaces the children of `node_id` and marks them as loaded
+        pub fn set_children(&mut self, node_id: usize, children: TreeViewNodeDataVec)  { unsafe { crate::dll::AzTreeViewState_setChildren(self, node_id, children) } }
+        /// Recomputes the visible rows, has to be called after the tree structure or the expanded state of a node was modified directly
+        pub fn update_rows(&mut self)  { unsafe { crate::dll::AzTreeViewState_updateRows(self) } }
+        /// Expands the node, returns true if the children of the node have to be loaded (the node is set to `TreeViewChildren::Loading`)
+        pub fn expand(&mut self, node_id: usize)  -> bool { unsafe { crate::dll::AzTreeViewState_expand(self, node_id) } }
+        /// Collapses the node, moves the cursor to the node if it was inside the collapsed subtree
+        pub fn collapse(&mut self, node_id: usize)  { unsafe { crate::dll::AzTreeViewState_collapse(self, node_id) } }
+        /// Expands all ancestors of the node, so that the node becomes visible
+        pub fn reveal(&mut self, node_id: usize)  { unsafe { crate::dll::AzTreeViewState_reveal(self, node_id) } }
+        /// Calls the `TreeViewState::is_expanded` function.
+        pub fn is_expanded(&self, node_id: usize)  -> bool { unsafe { crate::dll::AzTreeViewState_isExpanded(self, node_id) } }
+        /// Calls the `TreeViewState::clear_selection` function.
+        pub fn clear_selection(&mut self)  { unsafe { crate::dll::AzTreeViewState_clearSelection(self) } }
+        /// Calls the `TreeViewState::set_selected` function.
+        pub fn set_selected(&mut self, node_id: usize, selected: bool)  { unsafe { crate::dll::AzTreeViewState_setSelected(self, node_id, selected) } }
+        /// Moves the cursor to the node and selects it according to the selection mode: `extend` selects the rows from the anchor to the node, `toggle` adds / removes the node to / from the selection (only for `Multiple`)
+        pub fn select(&mut self, node_id: usize, extend: bool, toggle: bool)  { unsafe { crate::dll::AzTreeViewState_select(self, node_id, extend, toggle) } }
+        /// Moves the node (with its subtree) to the target, returns false if the move isn't allowed
+        pub fn move_node(&mut self, node_id: usize, target: TreeViewDropTarget)  -> bool { unsafe { crate::dll::AzTreeViewState_moveNode(self, node_id, target) } }
+    }
+
+    /// Node in the arena of the `TreeViewState`, the links are indices into `TreeViewState::nodes`
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNode as TreeViewNode;
+    /// User-facing content of a node
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNodeData as TreeViewNodeData;
+    impl TreeViewNodeData {
+        /// Creates a new `TreeViewNodeData` instance.
+        pub fn new(label: String) -> Self { unsafe { crate::dll::AzTreeViewNodeData_new(label) } }
+        /// Calls the `TreeViewNodeData::with_icon` function.
+        pub fn with_icon(&mut self, icon: ImageRef)  -> crate::widgets::TreeViewNodeData { unsafe { crate::dll::AzTreeViewNodeData_withIcon(self, icon) } }
+        /// Marks the node as having children that are loaded on the first expand
+        pub fn with_lazy_children(&mut self)  -> crate::widgets::TreeViewNodeData { unsafe { crate::dll::AzTreeViewNodeData_withLazyChildren(self) } }
+    }
+
+    /// Whether a node has children and whether they have been loaded yet
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewChildren as TreeViewChildren;
+    /// `TreeViewSelectionMode` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewSelectionMode as TreeViewSelectionMode;
+    /// `TreeViewDropPosition` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewDropPosition as TreeViewDropPosition;
+    /// `TreeViewDropTarget` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewDropTarget as TreeViewDropTarget;
+    /// Visible node: all ancestors of the node are expanded
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRow as TreeViewRow;
+    /// Content of one row of the row pool, `node` is `None` for the empty rows below the last node
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRenderedRow as TreeViewRenderedRow;
+    /// Passed to the `on_load_children` callback
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewLoadRequest as TreeViewLoadRequest;
+    /// Children that were loaded on a background thread
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewLoadedChildren as TreeViewLoadedChildren;
+    impl TreeViewLoadedChildren {
+        /// Creates a new `TreeViewLoadedChildren` instance.
+        pub fn new(node: usize, children: TreeViewNodeDataVec) -> Self { unsafe { crate::dll::AzTreeViewLoadedChildren_new(node, children) } }
+        /// Message that inserts the children into the tree when it is sent from the thread
+        pub fn into_writeback_msg(&mut self)  -> crate::task::ThreadWriteBackMsg { unsafe { crate::dll::AzTreeViewLoadedChildren_intoWritebackMsg(self) } }
+    }
+
+    /// Payload of a drag that was started on a node, can be used by other widgets to accept nodes that are dragged out of the tree
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewDragPayload as TreeViewDragPayload;
+    /// `TreeViewOnLoadChildren` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnLoadChildren as TreeViewOnLoadChildren;
+    /// `TreeViewOnLoadChildrenCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnLoadChildrenCallback as TreeViewOnLoadChildrenCallback;
+    /// `TreeViewOnLoadChildrenCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnLoadChildrenCallbackType as TreeViewOnLoadChildrenCallbackType;
+    /// `TreeViewOnSelectionChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnSelectionChange as TreeViewOnSelectionChange;
+    /// `TreeViewOnSelectionChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnSelectionChangeCallback as TreeViewOnSelectionChangeCallback;
+    /// `TreeViewOnSelectionChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnSelectionChangeCallbackType as TreeViewOnSelectionChangeCallbackType;
+    /// `TreeViewOnNodeMoved` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnNodeMoved as TreeViewOnNodeMoved;
+    /// `TreeViewOnNodeMovedCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnNodeMovedCallback as TreeViewOnNodeMovedCallback;
+    /// `TreeViewOnNodeMovedCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewOnNodeMovedCallbackType as TreeViewOnNodeMovedCallbackType;
+    /// Virtualized list: renders only the rows that are visible, so that lists with millions of rows stay cheap to lay out
+    
+#[doc(inline)] pub use crate::dll::AzVirtualList as VirtualList;
+    impl VirtualList {
+        /// Creates a list with rows of a fixed height
+        pub fn new(row_count: usize, row_height: f32, data: RefAny, render_row: VirtualListRenderRowCallbackType) -> Self { unsafe { crate::dll::AzVirtualList_new(row_count, row_height, data, render_row) } }
+        /// Re-creates a list from the state of a previous list (see `get_state`), keeping the scroll offset and the measured row heights
+        pub fn from_state(state: RefAny) -> Self { unsafe { crate::dll::AzVirtualList_fromState(state) } }
+        /// Returns the shared state of the list, store it and pass it to `from_state` so that the scroll offset survives when the DOM is regenerated
+        pub fn get_state(&self)  -> crate::callbacks::RefAny { unsafe { crate::dll::AzVirtualList_getState(self) } }
+        /// Sets the number of rows (i.e. if rows were added to a list created with `from_state`)
+        pub fn set_row_count(&mut self, row_count: usize)  { unsafe { crate::dll::AzVirtualList_setRowCount(self, row_count) } }
+        /// Calls the `VirtualList::with_row_count` function.
+        pub fn with_row_count(&mut self, row_count: usize)  -> crate::widgets::VirtualList { unsafe { crate::dll::AzVirtualList_withRowCount(self, row_count) } }
+        /// Sets whether the rows have a fixed or an estimated height, discards the measured heights if the height changes
+        pub fn set_row_height(&mut self, row_height: VirtualListRowHeight)  { unsafe { crate::dll::AzVirtualList_setRowHeight(self, row_height) } }
+        /// Calls the `VirtualList::with_row_height` function.
+        pub fn with_row_height(&mut self, row_height: VirtualListRowHeight)  -> crate::widgets::VirtualList { unsafe { crate::dll::AzVirtualList_withRowHeight(self, row_height) } }
+        /// Sets the scroll offset (in pixels) that the list is rendered with
+        pub fn set_scroll_offset(&mut self, scroll_offset: f32)  { unsafe { crate::dll::AzVirtualList_setScrollOffset(self, scroll_offset) } }
+        /// Calls the `VirtualList::with_scroll_offset` function.
+        pub fn with_scroll_offset(&mut self, scroll_offset: f32)  -> crate::widgets::VirtualList { unsafe { crate::dll::AzVirtualList_withScrollOffset(self, scroll_offset) } }
+        /// Calls the `VirtualList::set_overscan` function.
+        pub fn set_overscan(&mut self, overscan: usize)  { unsafe { crate::dll::AzVirtualList_setOverscan(self, overscan) } }
+        /// Calls the `VirtualList::with_overscan` function.
+        pub fn with_overscan(&mut self, overscan: usize)  -> crate::widgets::VirtualList { unsafe { crate::dll::AzVirtualList_withOverscan(self, overscan) } }
+        /// Calls the `VirtualList::set_on_scroll` function.
+        pub fn set_on_scroll(&mut self, data: RefAny, callback: VirtualListOnScrollCallbackType)  { unsafe { crate::dll::AzVirtualList_setOnScroll(self, data, callback) } }
+        /// Calls the `VirtualList::with_on_scroll` function.
+        pub fn with_on_scroll(&mut self, data: RefAny, callback: VirtualListOnScrollCallbackType)  -> crate::widgets::VirtualList { unsafe { crate::dll::AzVirtualList_withOnScroll(self, data, callback) } }
+        /// Calls the `VirtualList::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzVirtualList_setContainerStyle(self, style) } }
+        /// Calls the `VirtualList::set_row_style` function.
+        pub fn set_row_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzVirtualList_setRowStyle(self, style) } }
+        /// Calls the `VirtualList::set_scrollbar_track_style` function.
+        pub fn set_scrollbar_track_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzVirtualList_setScrollbarTrackStyle(self, style) } }
+        /// Calls the `VirtualList::set_scrollbar_thumb_style` function.
+        pub fn set_scrollbar_thumb_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzVirtualList_setScrollbarThumbStyle(self, style) } }
+        /// Calls the `VirtualList::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzVirtualList_dom(self) } }
+    }
+
+    /// `VirtualListStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListStateWrapper as VirtualListStateWrapper;
+    /// `VirtualListState` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListState as VirtualListState;
+    impl VirtualListState {
+        /// Creates a new `VirtualListState` instance.
+        pub fn new(row_count: usize, row_height: VirtualListRowHeight) -> Self { unsafe { crate::dll::AzVirtualListState_new(row_count, row_height) } }
+        /// Returns the fixed row height or the estimate for rows that were not measured yet
+        pub fn get_default_row_height(&self)  -> f32 { unsafe { crate::dll::AzVirtualListState_getDefaultRowHeight(self) } }
+        /// Returns the (measured or estimated) height of the given row
+        pub fn get_row_height(&self, row: usize)  -> f32 { unsafe { crate::dll::AzVirtualListState_getRowHeight(self, row) } }
+        /// Returns the distance of the top of the row to the top of the first row
+        pub fn get_row_top(&self, row: usize)  -> f32 { unsafe { crate::dll::AzVirtualListState_getRowTop(self, row) } }
+        /// Returns the row at the given distance from the top of the first row, `row_count` if the offset is below the last row
+        pub fn get_row_at_offset(&self, offset: f32)  -> usize { unsafe { crate::dll::AzVirtualListState_getRowAtOffset(self, offset) } }
+        /// Height of all rows combined, i.e. the scrollable height of the list
+        pub fn get_total_height(&self)  -> f32 { unsafe { crate::dll::AzVirtualListState_getTotalHeight(self) } }
+        /// Calls the `VirtualListState::get_max_scroll_offset` function.
+        pub fn get_max_scroll_offset(&self)  -> f32 { unsafe { crate::dll::AzVirtualListState_getMaxScrollOffset(self) } }
+        /// Calls the `VirtualListState::clamp_scroll_offset` function.
+        pub fn clamp_scroll_offset(&self, scroll_offset: f32)  -> f32 { unsafe { crate::dll::AzVirtualListState_clampScrollOffset(self, scroll_offset) } }
+    }
+
+    /// Height of the rows of a `VirtualList`
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListRowHeight as VirtualListRowHeight;
+    /// Measured height of a row (only used with `VirtualListRowHeight::Estimated`)
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListMeasuredRow as VirtualListMeasuredRow;
+    /// Nodes inside the IFrame that are updated in place when the list is scrolled
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListIFrameNodes as VirtualListIFrameNodes;
+    /// `VirtualListRenderRow` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListRenderRow as VirtualListRenderRow;
+    /// `VirtualListRenderRowCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListRenderRowCallback as VirtualListRenderRowCallback;
+    /// `VirtualListRenderRowCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListRenderRowCallbackType as VirtualListRenderRowCallbackType;
+    /// `VirtualListOnScroll` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListOnScroll as VirtualListOnScroll;
+    /// `VirtualListOnScrollCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListOnScrollCallback as VirtualListOnScrollCallback;
+    /// `VirtualListOnScrollCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListOnScrollCallbackType as VirtualListOnScrollCallbackType;
 }
 
 pub mod style {
@@ -11984,7 +14261,7 @@ pub mod style {
         pub fn default() -> Self { unsafe { crate::dll::AzStyledDom_default() } }
         /// Returns a DOM loaded from an XML file
         pub fn from_xml(xml_string: String) -> Self { unsafe { crate::dll::AzStyledDom_fromXml(xml_string) } }
-        /// Same as `from_xml`, but loads the file relative to the current directory
+        /// Same as `from_xml`, but loads the file relative to the current directory. Stylesheets linked via `<link rel="stylesheet" href="..."/>` and `@import` are loaded as well. The file is only re-parsed if it changed since the last call - if it can't be parsed, the error is shown on top of the last working version of the UI
         pub fn from_file(xml_file_path: String) -> Self { unsafe { crate::dll::AzStyledDom_fromFile(xml_file_path) } }
         /// Appends an already styled list of DOM nodes to the current `dom.root` - complexity `O(count(dom.dom_nodes))`
         pub fn append_child(&mut self, dom: StyledDom)  { unsafe { crate::dll::AzStyledDom_appendChild(self, dom) } }
@@ -14567,6 +16844,9 @@ pub mod xml {
     /// `XmlNode` struct
     
 #[doc(inline)] pub use crate::dll::AzXmlNode as XmlNode;
+    /// Start and end position of a node, attribute or text in the XML source
+    
+#[doc(inline)] pub use crate::dll::AzXmlSpan as XmlSpan;
 }
 
 pub mod fs {
@@ -15155,6 +17435,8 @@ pub mod vec {
     impl_vec_clone!(f32,  AzF32Vec,  AzF32VecDestructor);
     impl_vec!(AzXmlNode,  AzXmlNodeVec,  AzXmlNodeVecDestructor, az_xml_node_vec_destructor, AzXmlNodeVec_delete);
     impl_vec_clone!(AzXmlNode,  AzXmlNodeVec,  AzXmlNodeVecDestructor);
+    impl_vec!(AzXmlSpan,  AzXmlSpanVec,  AzXmlSpanVecDestructor, az_xml_span_vec_destructor, AzXmlSpanVec_delete);
+    impl_vec_clone!(AzXmlSpan,  AzXmlSpanVec,  AzXmlSpanVecDestructor);
     impl_vec!(AzInlineWord,  AzInlineWordVec,  AzInlineWordVecDestructor, az_inline_word_vec_destructor, AzInlineWordVec_delete);
     impl_vec_clone!(AzInlineWord,  AzInlineWordVec,  AzInlineWordVecDestructor);
     impl_vec!(AzInlineGlyph,  AzInlineGlyphVec,  AzInlineGlyphVecDestructor, az_inline_glyph_vec_destructor, AzInlineGlyphVec_delete);
@@ -15246,6 +17528,33 @@ pub mod vec {
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
 
+    impl_vec!(AzTextInputValidator,  AzTextInputValidatorVec,  AzTextInputValidatorVecDestructor, az_text_input_validator_vec_destructor, AzTextInputValidatorVec_delete);
+    impl_vec_clone!(AzTextInputValidator,  AzTextInputValidatorVec,  AzTextInputValidatorVecDestructor);
+
+    impl_vec!(AzDropDownChoice,  AzDropDownChoiceVec,  AzDropDownChoiceVecDestructor, az_drop_down_choice_vec_destructor, AzDropDownChoiceVec_delete);
+    impl_vec_clone!(AzDropDownChoice,  AzDropDownChoiceVec,  AzDropDownChoiceVecDestructor);
+
+    impl_vec!(AzTabContainerTab,  AzTabContainerTabVec,  AzTabContainerTabVecDestructor, az_tab_container_tab_vec_destructor, AzTabContainerTabVec_delete);
+    impl_vec_clone!(AzTabContainerTab,  AzTabContainerTabVec,  AzTabContainerTabVecDestructor);
+
+    impl_vec!(AzTextEditUndoEntry,  AzTextEditUndoEntryVec,  AzTextEditUndoEntryVecDestructor, az_text_edit_undo_entry_vec_destructor, AzTextEditUndoEntryVec_delete);
+    impl_vec_clone!(AzTextEditUndoEntry,  AzTextEditUndoEntryVec,  AzTextEditUndoEntryVecDestructor);
+
+    impl_vec!(AzTreeViewNodeData,  AzTreeViewNodeDataVec,  AzTreeViewNodeDataVecDestructor, az_tree_view_node_data_vec_destructor, AzTreeViewNodeDataVec_delete);
+    impl_vec_clone!(AzTreeViewNodeData,  AzTreeViewNodeDataVec,  AzTreeViewNodeDataVecDestructor);
+
+    impl_vec!(AzTreeViewNode,  AzTreeViewNodeVec,  AzTreeViewNodeVecDestructor, az_tree_view_node_vec_destructor, AzTreeViewNodeVec_delete);
+    impl_vec_clone!(AzTreeViewNode,  AzTreeViewNodeVec,  AzTreeViewNodeVecDestructor);
+
+    impl_vec!(AzTreeViewRow,  AzTreeViewRowVec,  AzTreeViewRowVecDestructor, az_tree_view_row_vec_destructor, AzTreeViewRowVec_delete);
+    impl_vec_clone!(AzTreeViewRow,  AzTreeViewRowVec,  AzTreeViewRowVecDestructor);
+
+    impl_vec!(AzTreeViewRenderedRow,  AzTreeViewRenderedRowVec,  AzTreeViewRenderedRowVecDestructor, az_tree_view_rendered_row_vec_destructor, AzTreeViewRenderedRowVec_delete);
+    impl_vec_clone!(AzTreeViewRenderedRow,  AzTreeViewRenderedRowVec,  AzTreeViewRenderedRowVecDestructor);
+
+    impl_vec!(AzVirtualListMeasuredRow,  AzVirtualListMeasuredRowVec,  AzVirtualListMeasuredRowVecDestructor, az_virtual_list_measured_row_vec_destructor, AzVirtualListMeasuredRowVec_delete);
+    impl_vec_clone!(AzVirtualListMeasuredRow,  AzVirtualListMeasuredRowVec,  AzVirtualListMeasuredRowVecDestructor);
+
     impl From<vec::Vec<string::String>> for crate::vec::StringVec {
         fn from(v: vec::Vec<string::String>) -> crate::vec::StringVec {
             let vec: Vec<AzString> = v.into_iter().map(Into::into).collect();
@@ -15272,6 +17581,9 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<XmlNode>`
     
 #[doc(inline)] pub use crate::dll::AzXmlNodeVec as XmlNodeVec;
+    /// Wrapper over a Rust-allocated `Vec<XmlSpan>`
+    
+#[doc(inline)] pub use crate::dll::AzXmlSpanVec as XmlSpanVec;
     /// Wrapper over a Rust-allocated `Vec<FmtArg>`
     
 #[doc(inline)] pub use crate::dll::AzFmtArgVec as FmtArgVec;
@@ -15350,6 +17662,33 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `ScanCode`
     
 #[doc(inline)] pub use crate::dll::AzScanCodeVec as ScanCodeVec;
+    /// Wrapper over a Rust-allocated `TextInputValidator`
+    
+#[doc(inline)] pub use crate::dll::AzTextInputValidatorVec as TextInputValidatorVec;
+    /// Wrapper over a Rust-allocated `DropDownChoice`
+    
+#[doc(inline)] pub use crate::dll::AzDropDownChoiceVec as DropDownChoiceVec;
+    /// Wrapper over a Rust-allocated `TabContainerTab`
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerTabVec as TabContainerTabVec;
+    /// Wrapper over a Rust-allocated `TextEditUndoEntry`
+    
+#[doc(inline)] pub use crate::dll::AzTextEditUndoEntryVec as TextEditUndoEntryVec;
+    /// Wrapper over a Rust-allocated `TreeViewNodeData`
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNodeDataVec as TreeViewNodeDataVec;
+    /// Wrapper over a Rust-allocated `TreeViewNode`
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNodeVec as TreeViewNodeVec;
+    /// Wrapper over a Rust-allocated `TreeViewRow`
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRowVec as TreeViewRowVec;
+    /// Wrapper over a Rust-allocated `TreeViewRenderedRow`
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRenderedRowVec as TreeViewRenderedRowVec;
+    /// Wrapper over a Rust-allocated `VirtualListMeasuredRow`
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListMeasuredRowVec as VirtualListMeasuredRowVec;
     /// Wrapper over a Rust-allocated `CssDeclaration`
     
 #[doc(inline)] pub use crate::dll::AzCssDeclarationVec as CssDeclarationVec;
@@ -15450,6 +17789,12 @@ pub mod vec {
     /// `XmlNodeVecDestructorType` struct
     
 #[doc(inline)] pub use crate::dll::AzXmlNodeVecDestructorType as XmlNodeVecDestructorType;
+    /// `XmlSpanVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzXmlSpanVecDestructor as XmlSpanVecDestructor;
+    /// `XmlSpanVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzXmlSpanVecDestructorType as XmlSpanVecDestructorType;
     /// `FmtArgVecDestructor` struct
     
 #[doc(inline)] pub use crate::dll::AzFmtArgVecDestructor as FmtArgVecDestructor;
@@ -15606,6 +17951,60 @@ pub mod vec {
     /// `ScanCodeVecDestructorType` struct
     
 #[doc(inline)] pub use crate::dll::AzScanCodeVecDestructorType as ScanCodeVecDestructorType;
+    /// `TextInputValidatorVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputValidatorVecDestructor as TextInputValidatorVecDestructor;
+    /// `TextInputValidatorVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextInputValidatorVecDestructorType as TextInputValidatorVecDestructorType;
+    /// `DropDownChoiceVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownChoiceVecDestructor as DropDownChoiceVecDestructor;
+    /// `DropDownChoiceVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownChoiceVecDestructorType as DropDownChoiceVecDestructorType;
+    /// `TabContainerTabVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerTabVecDestructor as TabContainerTabVecDestructor;
+    /// `TabContainerTabVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTabContainerTabVecDestructorType as TabContainerTabVecDestructorType;
+    /// `TextEditUndoEntryVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditUndoEntryVecDestructor as TextEditUndoEntryVecDestructor;
+    /// `TextEditUndoEntryVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditUndoEntryVecDestructorType as TextEditUndoEntryVecDestructorType;
+    /// `TreeViewNodeDataVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNodeDataVecDestructor as TreeViewNodeDataVecDestructor;
+    /// `TreeViewNodeDataVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNodeDataVecDestructorType as TreeViewNodeDataVecDestructorType;
+    /// `TreeViewNodeVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNodeVecDestructor as TreeViewNodeVecDestructor;
+    /// `TreeViewNodeVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewNodeVecDestructorType as TreeViewNodeVecDestructorType;
+    /// `TreeViewRowVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRowVecDestructor as TreeViewRowVecDestructor;
+    /// `TreeViewRowVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRowVecDestructorType as TreeViewRowVecDestructorType;
+    /// `TreeViewRenderedRowVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRenderedRowVecDestructor as TreeViewRenderedRowVecDestructor;
+    /// `TreeViewRenderedRowVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTreeViewRenderedRowVecDestructorType as TreeViewRenderedRowVecDestructorType;
+    /// `VirtualListMeasuredRowVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListMeasuredRowVecDestructor as VirtualListMeasuredRowVecDestructor;
+    /// `VirtualListMeasuredRowVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzVirtualListMeasuredRowVecDestructorType as VirtualListMeasuredRowVecDestructorType;
     /// `CssDeclarationVecDestructor` struct
     
 #[doc(inline)] pub use crate::dll::AzCssDeclarationVecDestructor as CssDeclarationVecDestructor;
@@ -15925,6 +18324,54 @@ pub mod option {
     /// `OptionTextInputSelection` struct
     
 #[doc(inline)] pub use crate::dll::AzOptionTextInputSelection as OptionTextInputSelection;
+    /// `OptionTextInputMask` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTextInputMask as OptionTextInputMask;
+    /// `OptionTextInputOnValidationChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTextInputOnValidationChange as OptionTextInputOnValidationChange;
+    /// `OptionDropDownOnChoiceChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionDropDownOnChoiceChange as OptionDropDownOnChoiceChange;
+    /// `OptionTabContainerOnTabChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTabContainerOnTabChange as OptionTabContainerOnTabChange;
+    /// `OptionTabContainerOnTabClose` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTabContainerOnTabClose as OptionTabContainerOnTabClose;
+    /// `OptionTabContainerOnTabMove` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTabContainerOnTabMove as OptionTabContainerOnTabMove;
+    /// `OptionSplitPaneOnResize` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSplitPaneOnResize as OptionSplitPaneOnResize;
+    /// `OptionSliderOnValueChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSliderOnValueChange as OptionSliderOnValueChange;
+    /// `OptionTextEditOnTextChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTextEditOnTextChange as OptionTextEditOnTextChange;
+    /// `OptionTextEditCursor` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTextEditCursor as OptionTextEditCursor;
+    /// `OptionTreeViewOnLoadChildren` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTreeViewOnLoadChildren as OptionTreeViewOnLoadChildren;
+    /// `OptionTreeViewOnSelectionChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTreeViewOnSelectionChange as OptionTreeViewOnSelectionChange;
+    /// `OptionTreeViewOnNodeMoved` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTreeViewOnNodeMoved as OptionTreeViewOnNodeMoved;
+    /// `OptionTreeViewDropTarget` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTreeViewDropTarget as OptionTreeViewDropTarget;
+    /// `OptionVirtualListOnScroll` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionVirtualListOnScroll as OptionVirtualListOnScroll;
+    /// `OptionVirtualListIFrameNodes` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionVirtualListIFrameNodes as OptionVirtualListIFrameNodes;
     /// `OptionNumberInputOnValueChange` struct
     
 #[doc(inline)] pub use crate::dll::AzOptionNumberInputOnValueChange as OptionNumberInputOnValueChange;
@@ -15961,6 +18408,12 @@ pub mod option {
     /// `OptionImageRef` struct
     
 #[doc(inline)] pub use crate::dll::AzOptionImageRef as OptionImageRef;
+    /// `OptionDragData` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionDragData as OptionDragData;
+    /// `OptionImeComposition` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionImeComposition as OptionImeComposition;
     /// `OptionFontRef` struct
     
 #[doc(inline)] pub use crate::dll::AzOptionFontRef as OptionFontRef;
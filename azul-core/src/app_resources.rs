@@ -46,6 +46,12 @@ pub struct AppConfig {
     /// (STUB) Whether keyboard navigation should be enabled (default: true).
     /// Currently not implemented.
     pub enable_tab_navigation: bool,
+    /// If set, all user input (mouse, keyboard, window size) is written to an event
+    /// recording file, which can be replayed headlessly to reproduce bugs.
+    /// The file name can be set with the `AZUL_RECORD_EVENTS` environment variable
+    /// (which also enables the recording). Only the Windows shell records events
+    /// at the moment. Default: false
+    pub record_events: bool,
     /// External callbacks to create a thread or get the curent time
     pub system_callbacks: ExternalSystemCallbacks,
 }
@@ -58,6 +64,7 @@ impl AppConfig {
             enable_visual_panic_hook: true,
             enable_logging_on_panic: true,
             enable_tab_navigation: true,
+            record_events: false,
             system_callbacks: ExternalSystemCallbacks::rust_internal(),
         }
     }
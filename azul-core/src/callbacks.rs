@@ -20,7 +20,7 @@ use rust_fontconfig::FcFontCache;
 use crate::{
     FastHashMap, FastBTreeSet,
     app_resources::{
-        ImageCache, ImageRef, IdNamespace, Words, ShapedWords,
+        ImageCache, ImageRef, OptionImageRef, IdNamespace, Words, ShapedWords,
         WordPositions, FontInstanceKey, LayoutedGlyphs, ImageMask
     },
     window::{AzStringPair, OptionLogicalPosition},
@@ -35,6 +35,7 @@ use crate::{
         WindowSize, WindowState, FullWindowState, LogicalPosition, OptionChar,
         LogicalSize, PhysicalSize, UpdateFocusWarning, WindowCreateOptions,
         RawWindowHandle, KeyboardState, MouseState, LogicalRect, WindowTheme,
+        DragData, DragState, OptionDragData,
     },
     task::{
         ThreadSendMsg, Duration as AzDuration, Instant as AzInstant,
@@ -998,6 +999,8 @@ pub struct CallbackInfo {
     /// The callback can change the focus_target - note that the focus_target is set before the
     /// next frames' layout() function is invoked, but the current frames callbacks are not affected.
     focus_target: *mut Option<FocusTarget>,
+    /// Set by `start_drag()` if the callback accepts a `DragStart` event
+    drag_started: *mut Option<DragData>,
     /// Cache of UI strings broken into words
     words_cache: *const BTreeMap<NodeId, Words>,
     /// Cache of words shaped into glyphs
@@ -1063,6 +1066,7 @@ impl CallbackInfo {
        dataset_map: &'a mut BTreeMap<NodeId, &'b mut RefAny>,
        stop_propagation: &'a mut bool,
        focus_target: &'a mut Option<FocusTarget>,
+       drag_started: &'a mut Option<DragData>,
        words_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
        images_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
        image_masks_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
@@ -1098,6 +1102,7 @@ impl CallbackInfo {
             dataset_map: dataset_map as *mut BTreeMap<NodeId, &'b mut RefAny> as *mut BTreeMap<NodeId, *mut RefAny>,
             stop_propagation: stop_propagation as *mut bool,
             focus_target: focus_target as *mut Option<FocusTarget>,
+            drag_started: drag_started as *mut Option<DragData>,
             words_changed_in_callbacks: words_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
             images_changed_in_callbacks: images_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
             image_masks_changed_in_callbacks: image_masks_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
@@ -1133,6 +1138,7 @@ impl CallbackInfo {
     fn internal_get_dataset_map<'a>(&'a mut self) -> &'a mut BTreeMap<NodeId, *mut RefAny> { unsafe { &mut *self.dataset_map } }
     fn internal_get_stop_propagation<'a>(&'a mut self) -> &'a mut bool { unsafe { &mut *self.stop_propagation } }
     fn internal_get_focus_target<'a>(&'a mut self) -> &'a mut Option<FocusTarget> { unsafe { &mut *self.focus_target } }
+    fn internal_get_drag_started<'a>(&'a mut self) -> &'a mut Option<DragData> { unsafe { &mut *self.drag_started } }
     fn internal_get_current_scroll_states<'a>(&'a self) -> &'a BTreeMap<DomId, BTreeMap<AzNodeId, ScrollPosition>> { unsafe { &*self.current_scroll_states } }
     fn internal_get_css_properties_changed_in_callbacks<'a>(&'a mut self) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>> { unsafe { &mut *self.css_properties_changed_in_callbacks } }
     fn internal_get_nodes_scrolled_in_callback<'a>(&'a mut self) -> &'a mut BTreeMap<DomId, BTreeMap<AzNodeId, LogicalPosition>> { unsafe { &mut *self.nodes_scrolled_in_callback } }
@@ -1154,6 +1160,7 @@ impl CallbackInfo {
     pub fn get_cursor_relative_to_node(&self) -> OptionLogicalPosition { self.internal_get_cursor_relative_to_item() }
     pub fn get_cursor_relative_to_viewport(&self) -> OptionLogicalPosition { self.internal_get_cursor_in_viewport() }
     pub fn get_current_window_state(&self) -> WindowState { self.internal_get_current_window_state().clone().into() }
+    pub fn get_focused_node(&self) -> Option<DomNodeId> { self.internal_get_current_window_state().focused_node }
     pub fn get_current_keyboard_state(&self) -> KeyboardState { self.internal_get_current_window_state().keyboard_state.clone() }
     pub fn get_current_mouse_state(&self) -> MouseState { self.internal_get_current_window_state().mouse_state.clone() }
     pub fn get_previous_window_state(&self) -> Option<WindowState> { Some(self.internal_get_previous_window_state().as_ref()?.clone().into()) }
@@ -1352,6 +1359,25 @@ impl CallbackInfo {
         *self.internal_get_stop_propagation() = true;
     }
 
+    /// Accepts the drag in a `DragStart` callback: the `payload` is carried over to the
+    /// `DragOver` / `Drop` callbacks, the `ghost` image is drawn under the cursor while dragging.
+    ///
+    /// Returns false (and does nothing) if the callback wasn't invoked by a `DragStart` event.
+    pub fn start_drag(&mut self, payload: RefAny, ghost: OptionImageRef) -> bool {
+        match self.internal_get_current_window_state().drag_state {
+            Some(DragState::Started { .. }) => { },
+            _ => return false,
+        }
+        let source = self.get_hit_node();
+        *self.internal_get_drag_started() = Some(DragData { source, payload, ghost });
+        true
+    }
+
+    /// Returns the data of the current drag (while dragging and in the `Drop` / `DragEnd` callbacks)
+    pub fn get_drag_data(&self) -> OptionDragData {
+        self.internal_get_current_window_state().get_drag_data().cloned().into()
+    }
+
     pub fn create_window(&mut self, window: WindowCreateOptions) {
         self.internal_get_new_windows().push(window);
     }
@@ -2195,76 +2221,10 @@ impl FocusTarget {
 
         use crate::callbacks::FocusTarget::*;
         use crate::style::matches_html_element;
+        use crate::focus::{get_next_tab_stop, TabDirection};
 
         if layout_results.is_empty() { return Ok(None); }
 
-        macro_rules! search_for_focusable_node_id {($layout_results:expr, $start_dom_id:expr, $start_node_id:expr, $get_next_node_fn:ident) => {{
-
-            let mut start_dom_id = $start_dom_id;
-            let mut start_node_id = $start_node_id;
-
-            let min_dom_id = DomId::ROOT_ID;
-            let max_dom_id = DomId { inner: layout_results.len() - 1 };
-
-            // iterate through all DOMs
-            loop { // 'outer_dom_iter
-
-                let layout_result = $layout_results.get(start_dom_id.inner).ok_or(UpdateFocusWarning::FocusInvalidDomId(start_dom_id.clone()))?;
-
-                let node_id_valid = layout_result.styled_dom.node_data.as_container().get(start_node_id).is_some();
-
-                if !node_id_valid {
-                    return Err(UpdateFocusWarning::FocusInvalidNodeId(AzNodeId::from_crate_internal(Some(start_node_id.clone()))));
-                }
-
-                if layout_result.styled_dom.node_data.is_empty() {
-                    return Err(UpdateFocusWarning::FocusInvalidDomId(start_dom_id.clone())); // ???
-                }
-
-                let max_node_id = NodeId::new(layout_result.styled_dom.node_data.len() - 1);
-                let min_node_id = NodeId::ZERO;
-
-                // iterate through nodes in DOM
-                loop {
-
-                    let current_node_id = NodeId::new(start_node_id.index().$get_next_node_fn(1))
-                        .max(min_node_id)
-                        .min(max_node_id);
-
-                    if layout_result.styled_dom.node_data.as_container()[current_node_id].is_focusable() {
-                        return Ok(Some(DomNodeId {
-                            dom: start_dom_id,
-                            node: AzNodeId::from_crate_internal(Some(current_node_id)),
-                        }));
-                    }
-
-                    if current_node_id == min_node_id && current_node_id < start_node_id {
-                        // going in decreasing (previous) direction
-                        if start_dom_id == min_dom_id {
-                            // root node / root dom encountered
-                            return Ok(None);
-                        } else {
-                            start_dom_id.inner -= 1;
-                            start_node_id = NodeId::new($layout_results[start_dom_id.inner].styled_dom.node_data.len() - 1);
-                            break; // continue 'outer_dom_iter
-                        }
-                    } else if current_node_id == max_node_id && current_node_id > start_node_id {
-                        // going in increasing (next) direction
-                        if start_dom_id == max_dom_id {
-                            // last dom / last node encountered
-                            return Ok(None);
-                        } else {
-                            start_dom_id.inner += 1;
-                            start_node_id = NodeId::ZERO;
-                            break; // continue 'outer_dom_iter
-                        }
-                    } else {
-                        start_node_id = current_node_id;
-                    }
-                }
-            }
-        }};}
-
         match self {
             Path(FocusTargetPath { dom, css_path }) => {
                 let layout_result = layout_results.get(dom.inner).ok_or(UpdateFocusWarning::FocusInvalidDomId(dom.clone()))?;
@@ -2293,56 +2253,10 @@ impl FocusTarget {
                     Ok(Some(dom_node_id.clone()))
                 }
             },
-            Previous => {
-
-                let last_layout_dom_id = DomId { inner: layout_results.len() - 1 };
-
-                // select the previous focusable element or `None`
-                // if this was the first focusable element in the DOM
-                let (current_focus_dom, current_focus_node_id) = match current_focus {
-                    Some(s) => match s.node.into_crate_internal() {
-                        Some(n) => (s.dom, n),
-                        None => {
-                            if let Some(layout_result) = layout_results.get(s.dom.inner) {
-                                (s.dom, NodeId::new(layout_result.styled_dom.node_data.len() - 1))
-                            } else {
-                                (last_layout_dom_id, NodeId::new(layout_results[last_layout_dom_id.inner].styled_dom.node_data.len() - 1))
-                            }
-                        }
-                    },
-                    None => (last_layout_dom_id, NodeId::new(layout_results[last_layout_dom_id.inner].styled_dom.node_data.len() - 1)),
-                };
-
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_sub);
-            },
-            Next => {
-                // select the previous focusable element or `None`
-                // if this was the first focusable element in the DOM, select the first focusable element
-                let (current_focus_dom, current_focus_node_id) = match current_focus {
-                    Some(s) => match s.node.into_crate_internal() {
-                        Some(n) => (s.dom, n),
-                        None => {
-                            if layout_results.get(s.dom.inner).is_some() {
-                                (s.dom, NodeId::ZERO)
-                            } else {
-                                (DomId::ROOT_ID, NodeId::ZERO)
-                            }
-                        }
-                    },
-                    None => (DomId::ROOT_ID, NodeId::ZERO),
-                };
-
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_add);
-            },
-            First => {
-                let (current_focus_dom, current_focus_node_id) = (DomId::ROOT_ID, NodeId::ZERO);
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_add);
-            },
-            Last => {
-                let last_layout_dom_id = DomId { inner: layout_results.len() - 1 };
-                let (current_focus_dom, current_focus_node_id) = (last_layout_dom_id, NodeId::new(layout_results[last_layout_dom_id.inner].styled_dom.node_data.len() - 1));
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_add);
-            },
+            Previous => Ok(get_next_tab_stop(layout_results, current_focus, TabDirection::Previous)),
+            Next => Ok(get_next_tab_stop(layout_results, current_focus, TabDirection::Next)),
+            First => Ok(get_next_tab_stop(layout_results, current_focus, TabDirection::First)),
+            Last => Ok(get_next_tab_stop(layout_results, current_focus, TabDirection::Last)),
             NoFocus => Ok(None),
         }
     }
@@ -220,6 +220,36 @@ pub fn css_to_rust_code(css: &Css) -> String {
     output
 }
 
+/// Formats the (parsed) CSS back into a stylesheet, used for embedding the styles
+/// into the C, C++ and Python code, since these languages can't declare the
+/// `NodeDataInlineCssProperty` items as constants
+pub fn css_to_stylesheet_string(css: &Css) -> String {
+
+    let mut output = String::new();
+
+    for stylesheet in css.stylesheets.iter() {
+        for block in stylesheet.rules.iter() {
+
+            output.push_str(&format!("{} {{\r\n", block.path));
+
+            for declaration in block.declarations.iter() {
+                let formatted = match declaration {
+                    CssDeclaration::Static(s) => s.format_css(),
+                    CssDeclaration::Dynamic(d) => format!(
+                        "{}: var(--{}, {});",
+                        d.default_value.key(), d.dynamic_id.as_str(), d.default_value.value()
+                    ),
+                };
+                output.push_str(&format!("    {}\r\n", formatted));
+            }
+
+            output.push_str("}\r\n");
+        }
+    }
+
+    output
+}
+
 fn print_block_path(path: &CssPath, tabs: usize) -> String {
     let t = String::from("    ").repeat(tabs);
     let t1 = String::from("    ").repeat(tabs + 1);
@@ -270,6 +300,9 @@ fn format_pseudo_selector_type(p: &CssPathPseudoSelector) -> String {
         CssPathPseudoSelector::Hover => format!("CssPathPseudoSelector::Hover"),
         CssPathPseudoSelector::Active => format!("CssPathPseudoSelector::Active"),
         CssPathPseudoSelector::Focus => format!("CssPathPseudoSelector::Focus"),
+        CssPathPseudoSelector::FocusVisible => format!("CssPathPseudoSelector::FocusVisible"),
+        CssPathPseudoSelector::FocusWithin => format!("CssPathPseudoSelector::FocusWithin"),
+        CssPathPseudoSelector::DragOver => format!("CssPathPseudoSelector::DragOver"),
     }
 }
 
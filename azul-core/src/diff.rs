@@ -1,14 +1,28 @@
-use core::{
-    fmt,
-};
+//! Keyed diff between two `StyledDom`s
+//!
+//! Nodes are matched top-down, starting at the root: the children of two matched
+//! parents are matched by their key (see `NodeData::set_key`) if they have one,
+//! otherwise by their position among the unkeyed siblings. A node that is matched
+//! keeps its identity in the new DOM (focus, scroll position, hover / active state
+//! and animations attached to the node), while unmatched nodes are added / removed.
+//!
+//! The `StyledDom` is already styled by the layout callback, so the diff only
+//! records the computed CSS properties that actually changed - if the tree structure
+//! stayed the same, only these nodes (and the changed text nodes) have to be re-layouted.
+
+use core::fmt;
 use alloc::vec::Vec;
-use alloc::string::String;
+use alloc::collections::btree_map::BTreeMap;
 use alloc::collections::btree_set::BTreeSet;
+use azul_css::{AzString, CssProperty, CssPropertyType};
 use crate::{
-    id_tree::{NodeId, NodeDataContainer},
-    dom::{CompactDom, NodeData},
+    FastHashMap,
+    id_tree::NodeId,
+    dom::{NodeData, NodeType, NodeDataInlineCssProperty},
+    styled_dom::{StyledDom, StyledNodeState, ChangedCssProperty, CssPropertyCache},
 };
 
+/// Range of nodes (inclusive) in a DOM
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DomRange {
     pub start: NodeId,
@@ -27,6 +41,14 @@ impl DomRange {
             end: node_id,
         }
     }
+
+    /// Is `other` a subtree of `self`? - Assumes that the DOM was
+    /// constructed in a linear order, i.e. the child being within
+    /// the parents start / end bounds
+    pub fn contains(&self, other: &Self) -> bool {
+        other.start.index() >= self.start.index() &&
+        other.end.index() <= self.end.index()
+    }
 }
 
 impl fmt::Debug for DomRange {
@@ -41,222 +63,320 @@ impl fmt::Display for DomRange {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum DomChange {
-    /// Node is present on the new DOM, but not on the old one = add node
-    Added(DomRange),
-    /// Node is present on the old DOM, but not on the new one = remove node
-    Removed(DomRange),
+/// Difference between the `StyledDom` of the last frame and a re-generated `StyledDom`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StyledDomDiff {
+    /// For each node of the new DOM: the matching node of the old DOM (`None` = node was added)
+    pub old_node_ids: Vec<Option<NodeId>>,
+    /// For each node of the old DOM: the matching node of the new DOM (`None` = node was removed)
+    pub new_node_ids: Vec<Option<NodeId>>,
+    /// Computed CSS properties that changed on matched nodes, indexed by the new node ID
+    ///
+    /// NOTE: The properties are compared using the state (hover, active, focus) of the old node
+    pub changed_css_properties: BTreeMap<NodeId, Vec<ChangedCssProperty>>,
+    /// Text nodes whose text changed, indexed by the new node ID
+    pub changed_texts: BTreeMap<NodeId, AzString>,
 }
 
-impl fmt::Display for DomChange {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use self::DomChange::*;
-        match self {
-            Added(c) => write!(f, "+ {}", c),
-            Removed(c) => write!(f, "- {}", c),
+impl StyledDomDiff {
+
+    pub fn new(old: &StyledDom, new: &StyledDom) -> Self {
+
+        let old_node_data = old.node_data.as_container();
+        let new_node_data = new.node_data.as_container();
+
+        let mut diff = Self {
+            old_node_ids: vec![None; new_node_data.len()],
+            new_node_ids: vec![None; old_node_data.len()],
+            changed_css_properties: BTreeMap::new(),
+            changed_texts: BTreeMap::new(),
+        };
+
+        let (old_root, new_root) = match (old.root.into_crate_internal(), new.root.into_crate_internal()) {
+            (Some(o), Some(n)) => (o, n),
+            _ => return diff,
+        };
+
+        if !nodes_can_match(&old_node_data[old_root], &new_node_data[new_root]) {
+            return diff;
         }
+
+        diff.match_node(old, new, old_root, new_root);
+
+        diff
     }
-}
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DomDiff {
-    /// What the actual changes nodes (not trees / subtrees) were in this diff, in order of appearance
-    pub changed_nodes: Vec<DomChange>,
-}
+    /// Returns whether the new DOM is the same as the old DOM (callbacks and datasets may differ)
+    pub fn is_empty(&self) -> bool {
+        self.is_same_structure() &&
+        self.changed_css_properties.is_empty() &&
+        self.changed_texts.is_empty()
+    }
 
-impl DomDiff {
-    pub fn new(old: &CompactDom, new: &CompactDom) -> Self {
+    /// Returns whether every node of the new DOM has the same node ID as in the old DOM,
+    /// i.e. no nodes were added, removed or re-ordered
+    ///
+    /// In that case the layout of the old DOM can be re-used, only the nodes in
+    /// `changed_css_properties` and `changed_texts` have to be re-layouted.
+    pub fn is_same_structure(&self) -> bool {
+        self.old_node_ids.len() == self.new_node_ids.len() &&
+        self.old_node_ids.iter().enumerate().all(|(new_id, old_id)| *old_id == Some(NodeId::new(new_id)))
+    }
 
-        // TODO: Check if old root = new root, if not, change entire tree
+    /// Returns the node ID in the new DOM of a node in the old DOM
+    pub fn get_new_node_id(&self, old_node_id: NodeId) -> Option<NodeId> {
+        self.new_node_ids.get(old_node_id.index()).copied().flatten()
+    }
 
-        let mut changes = BTreeSet::new();
-        let mut visited_nodes = NodeDataContainer::new(vec![false; new.len()]);
+    /// Returns the node ID in the old DOM of a node in the new DOM
+    pub fn get_old_node_id(&self, new_node_id: NodeId) -> Option<NodeId> {
+        self.old_node_ids.get(new_node_id.index()).copied().flatten()
+    }
 
-        visited_nodes[NodeId::ZERO] = true;
+    /// Returns the nodes of the old DOM that have no matching node in the new DOM
+    pub fn get_removed_nodes(&self) -> Vec<NodeId> {
+        self.new_node_ids.iter().enumerate()
+        .filter_map(|(old_id, new_id)| if new_id.is_none() { Some(NodeId::new(old_id)) } else { None })
+        .collect()
+    }
 
-        let has_root_changed = node_has_changed(
-            &old.arena.node_data[NodeId::ZERO],
-            &new.arena.node_data[NodeId::ZERO]
-        );
+    /// Returns the ranges of the new DOM that were added or changed (and need
+    /// to be re-layouted / re-drawn), merged into contiguous ranges
+    pub fn get_changed_ranges(&self) -> Vec<DomRange> {
+
+        let changed_nodes = self.old_node_ids.iter().enumerate()
+        .filter_map(|(new_id, old_id)| {
+            let new_id = NodeId::new(new_id);
+            let changed = old_id.is_none() ||
+                self.changed_css_properties.contains_key(&new_id) ||
+                self.changed_texts.contains_key(&new_id);
+            if changed { Some(new_id) } else { None }
+        });
 
-        if has_root_changed == NODE_CHANGED_NOTHING {
+        let mut ranges: Vec<DomRange> = Vec::new();
 
-            diff_tree_inner(NodeId::ZERO, old, new, &mut changes, &mut visited_nodes);
-            add_visited_nodes(visited_nodes, &mut changes);
+        for node_id in changed_nodes {
+            match ranges.last_mut() {
+                Some(last) if last.end.index() + 1 == node_id.index() => { last.end = node_id; },
+                _ => { ranges.push(DomRange::single_node(node_id)); },
+            }
+        }
 
-            Self {
-                changed_nodes: optimize_changeset(changes)
+        ranges
+    }
+
+    /// Copies the state (hover, active, focus) of the old nodes into the matching nodes of the new DOM
+    pub fn transfer_node_states(&self, old: &StyledDom, new: &mut StyledDom) {
+        let old_styled_nodes = old.styled_nodes.as_ref();
+        let new_styled_nodes = new.styled_nodes.as_mut();
+        for (new_id, old_id) in self.old_node_ids.iter().enumerate() {
+            if let Some(old_id) = old_id {
+                new_styled_nodes[new_id].state = old_styled_nodes[old_id.index()].state.clone();
             }
+        }
+    }
 
-        } else {
+    fn match_node(&mut self, old: &StyledDom, new: &StyledDom, old_id: NodeId, new_id: NodeId) {
 
-            // Root changed = everything changed
-            changes.insert(DomChange::Removed(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(old.len() - 1)
-            }));
+        self.old_node_ids[new_id.index()] = Some(old_id);
+        self.new_node_ids[old_id.index()] = Some(new_id);
 
-            changes.insert(DomChange::Added(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(new.len() - 1)
-            }));
+        let old_node_data = &old.node_data.as_ref()[old_id.index()];
+        let new_node_data = &new.node_data.as_ref()[new_id.index()];
 
-            Self {
-                changed_nodes: optimize_changeset(changes)
+        if let (NodeType::Text(old_text), NodeType::Text(new_text)) = (old_node_data.get_node_type(), new_node_data.get_node_type()) {
+            if old_text != new_text {
+                self.changed_texts.insert(new_id, new_text.clone());
             }
         }
+
+        let changed_css_properties = get_changed_css_properties(
+            old.get_css_property_cache(),
+            new.get_css_property_cache(),
+            old_node_data,
+            new_node_data,
+            old_id,
+            new_id,
+            &old.styled_nodes.as_ref()[old_id.index()].state,
+        );
+
+        if !changed_css_properties.is_empty() {
+            self.changed_css_properties.insert(new_id, changed_css_properties);
+        }
+
+        self.match_children(old, new, old_id, new_id);
     }
 
-    /// Formats the diff into a git-like `+ Node1 / - Node3` form
-    pub fn format_nicely(&self, old: &CompactDom, new: &CompactDom) -> String {
-        use self::DomChange::*;
-        self.changed_nodes.iter().map(|change| {
-            match change {
-                Added(c) => format!("+\t{}", new.arena.node_data[c.start]),
-                Removed(c) => format!("-\t{}", old.arena.node_data[c.start]),
+    fn match_children(&mut self, old: &StyledDom, new: &StyledDom, old_parent: NodeId, new_parent: NodeId) {
+
+        let old_hierarchy = old.node_hierarchy.as_container();
+        let new_hierarchy = new.node_hierarchy.as_container();
+        let old_node_data = old.node_data.as_container();
+        let new_node_data = new.node_data.as_container();
+
+        let mut old_keyed_children = FastHashMap::new();
+        let mut old_unkeyed_children = Vec::new();
+
+        for old_child in old_parent.az_children(&old_hierarchy) {
+            match old_node_data[old_child].get_key() {
+                Some(key) => { old_keyed_children.entry(key).or_insert(old_child); },
+                None => { old_unkeyed_children.push(old_child); },
+            }
+        }
+
+        let mut old_unkeyed_children = old_unkeyed_children.into_iter();
+        let new_children = new_parent.az_children_collect(&new_hierarchy);
+
+        for new_child in new_children {
+
+            let old_child = match new_node_data[new_child].get_key() {
+                Some(key) => old_keyed_children.remove(&key),
+                None => old_unkeyed_children.next(),
+            };
+
+            let old_child = match old_child {
+                Some(s) => s,
+                None => continue,
+            };
+
+            if nodes_can_match(&old_node_data[old_child], &new_node_data[new_child]) {
+                self.match_node(old, new, old_child, new_child);
             }
-        }).collect::<Vec<String>>().join("\r\n")
+        }
     }
 }
 
-impl fmt::Display for DomDiff {
+impl fmt::Display for StyledDomDiff {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for c in self.changed_nodes.iter() {
-            write!(f, "{}", c)?;
+        for removed in self.get_removed_nodes() {
+            writeln!(f, "- {}", removed)?;
+        }
+        for range in self.get_changed_ranges() {
+            writeln!(f, "~ {}", range)?;
         }
         Ok(())
     }
 }
 
-impl DomRange {
+/// Two nodes can be matched if they have the same key and the same node type
+///
+/// Text nodes match other text nodes (the changed text is re-layouted), images
+/// and IFrames only match if they display the same image / IFrame callback.
+fn nodes_can_match(old: &NodeData, new: &NodeData) -> bool {
 
-    /// Is `other` a subtree of `self`? - Assumes that the DOM was
-    /// constructed in a linear order, i.e. the child being within
-    /// the parents start / end bounds
-    pub fn contains(&self, other: &Self) -> bool {
-        other.start.index() >= self.start.index() &&
-        other.end.index() <= self.end.index()
+    if old.get_key() != new.get_key() {
+        return false;
     }
 
-    /// Compares two DOM ranges *without* looking at the DOM hashes (not equivalent to `==`)
-    pub fn equals_range(&self, other: &Self) -> bool {
-        other.start == self.start &&
-        other.end == self.end
+    match (old.get_node_type(), new.get_node_type()) {
+        (NodeType::Text(_), NodeType::Text(_)) => true,
+        (a, b) => a == b,
     }
 }
 
-const NODE_CHANGED_NOTHING: u8  = 0x01;
-const NODE_CHANGED_TYPE: u8     = 0x02;
-const NODE_CHANGED_CLASSES: u8  = 0x04;
-const NODE_CHANGED_IDS: u8      = 0x08;
-
-// In order to test two DOM nodes for "equality", you'd need to
-// test if the node type, the classes and the ids are the same.
-// The rest of the attributes can be ignored, since they are not
-// used by the CSS engine.
-//
-// NOTE: The callbacks / etc. need to be changed!
-#[inline]
-fn node_has_changed(old: &NodeData, new: &NodeData) -> u8 {
-    let mut result = NODE_CHANGED_NOTHING;
-
-    if old.get_node_type() != new.get_node_type() {
-        result &= NODE_CHANGED_TYPE;
-    }
-
-    if old.get_classes() != new.get_classes() {
-        result &= NODE_CHANGED_CLASSES;
-    }
-
-    if old.get_ids() != new.get_ids() {
-        result &= NODE_CHANGED_IDS;
-    }
-
-    result
+/// Compares the computed CSS properties of two matched nodes, using the state of the old node
+fn get_changed_css_properties(
+    old_cache: &CssPropertyCache,
+    new_cache: &CssPropertyCache,
+    old_node_data: &NodeData,
+    new_node_data: &NodeData,
+    old_id: NodeId,
+    new_id: NodeId,
+    node_state: &StyledNodeState,
+) -> Vec<ChangedCssProperty> {
+
+    let mut property_types = BTreeSet::new();
+    collect_property_types(old_cache, old_node_data, old_id, &mut property_types);
+    collect_property_types(new_cache, new_node_data, new_id, &mut property_types);
+
+    property_types.into_iter().filter_map(|prop| {
+        let old_prop = old_cache.get_property(old_node_data, &old_id, node_state, &prop);
+        let new_prop = new_cache.get_property(new_node_data, &new_id, node_state, &prop);
+        if old_prop == new_prop {
+            None
+        } else {
+            Some(ChangedCssProperty {
+                previous_state: node_state.clone(),
+                previous_prop: match old_prop { None => CssProperty::auto(prop), Some(s) => s.clone() },
+                current_state: node_state.clone(),
+                current_prop: match new_prop { None => CssProperty::auto(prop), Some(s) => s.clone() },
+            })
+        }
+    }).collect()
 }
 
-fn diff_tree_inner(
-    old_root_id: NodeId,
-    old: &CompactDom,
-    new: &CompactDom,
-    changes: &mut BTreeSet<DomChange>,
-    visited_nodes: &mut NodeDataContainer<bool>,
+/// Collects all CSS property types that are set on the node (in any state) - all
+/// other properties have their default value
+fn collect_property_types(
+    cache: &CssPropertyCache,
+    node_data: &NodeData,
+    node_id: NodeId,
+    property_types: &mut BTreeSet<CssPropertyType>,
 ) {
-    let mut node_shift = 0_isize;
-
-    for old_node_id in old_root_id.children(&old.arena.node_hierarchy) {
-
-        // Node ID that corresponds to the same node in the new node tree
-        let new_node_id = NodeId::new((old_node_id.index() as isize + node_shift) as usize);
-
-        let old_node_last_child = NodeId::new(match old.arena.node_hierarchy[old_node_id].next_sibling {
-            Some(s) => s.index(),
-            None => old.arena.node_hierarchy.len() - 1,
-        });
-
-        match new.arena.node_data.get(new_node_id) {
-            None => {
-                // Couldn't find the new node in the new tree, old tree has more children than new
-                changes.insert(DomChange::Removed(DomRange {
-                    start: old_node_id,
-                    end: old_node_last_child,
-                }));
-            },
-            Some(new_node_data) => {
-
-                visited_nodes[new_node_id] = true;
-
-                let old_node_data = &old.arena.node_data[old_node_id];
-                let compare_nodes = node_has_changed(old_node_data, new_node_data);
-
-                if compare_nodes == NODE_CHANGED_NOTHING {
-                    diff_tree_inner(old_node_id, old, new, changes, visited_nodes);
-                } else {
-
-                    let new_node_subtree_len = new.arena.node_hierarchy.subtree_len(new_node_id);
-
-                    let next_node_id = match new.arena.node_hierarchy[new_node_id].next_sibling {
-                        Some(s) => s.index(),
-                        None => new.arena.node_hierarchy.len() - 1,
-                    };
-
-                    // remove entire old subtree, including the node itself
-                    changes.insert(DomChange::Removed(DomRange {
-                        start: old_node_id,
-                        end: old_node_last_child,
-                    }));
-
-                    // add entire new subtree, including the node itself
-                    changes.insert(DomChange::Added(DomRange {
-                        start: new_node_id,
-                        end: NodeId::new(next_node_id),
-                    }));
-
-                    node_shift += new_node_subtree_len as isize;
-
-                    for n in new_node_id.index()..next_node_id {
-                        visited_nodes[NodeId::new(n)] = true;
-                    }
-                }
-            }
+    let maps = [
+        &cache.user_overridden_properties,
+        &cache.cascaded_normal_props,
+        &cache.cascaded_hover_props,
+        &cache.cascaded_active_props,
+        &cache.cascaded_focus_props,
+        &cache.css_normal_props,
+        &cache.css_hover_props,
+        &cache.css_active_props,
+        &cache.css_focus_props,
+    ];
+
+    for map in maps.iter() {
+        if let Some(props) = map.get(&node_id) {
+            property_types.extend(props.keys().copied());
         }
     }
+
+    property_types.extend(node_data.get_inline_css_props().iter().map(|prop| match prop {
+        NodeDataInlineCssProperty::Normal(p) |
+        NodeDataInlineCssProperty::Active(p) |
+        NodeDataInlineCssProperty::Focus(p) |
+        NodeDataInlineCssProperty::Hover(p) => p.get_type(),
+    }));
 }
 
-fn add_visited_nodes(
-    visited_nodes: NodeDataContainer<bool>,
-    changes: &mut BTreeSet<DomChange>,
-) {
-    changes.extend(
-        visited_nodes
-        .linear_iter()
-        .filter_map(|node_id| if visited_nodes[node_id] { None } else { Some(node_id) })
-        .map(|node_id| DomChange::Added(DomRange::single_node(node_id)))
-    );
+#[test]
+fn test_keyed_diff_reorder() {
+
+    use azul_css::Css;
+    use crate::dom::Dom;
+
+    let list = |keys: &[u64]| -> StyledDom {
+        Dom::body()
+        .with_children(keys.iter().map(|k| Dom::text(format!("{}", k)).with_key(*k)).collect::<Vec<_>>().into())
+        .style(&mut Css::empty())
+    };
+
+    let old = list(&[1, 2, 3]);
+    let new = list(&[3, 1, 2, 4]);
+    let diff = old.diff(&new);
+
+    assert!(!diff.is_same_structure());
+    assert_eq!(diff.get_new_node_id(NodeId::new(0)), Some(NodeId::new(0)));
+    assert_eq!(diff.get_new_node_id(NodeId::new(1)), Some(NodeId::new(2)));
+    assert_eq!(diff.get_new_node_id(NodeId::new(2)), Some(NodeId::new(3)));
+    assert_eq!(diff.get_new_node_id(NodeId::new(3)), Some(NodeId::new(1)));
+    assert_eq!(diff.get_old_node_id(NodeId::new(4)), None);
+    assert!(diff.get_removed_nodes().is_empty());
+    assert_eq!(diff.get_changed_ranges(), vec![DomRange::single_node(NodeId::new(4))]);
 }
 
-fn optimize_changeset(changes: BTreeSet<DomChange>) -> Vec<DomChange> {
-    // TODO: optimize changeset into larger chunks!
-    changes.into_iter().collect()
+#[test]
+fn test_unkeyed_diff_text_change() {
+
+    use azul_css::Css;
+    use crate::dom::Dom;
+
+    let old = Dom::body().with_children(vec![Dom::text("a"), Dom::div()].into()).style(&mut Css::empty());
+    let new = Dom::body().with_children(vec![Dom::text("b"), Dom::div()].into()).style(&mut Css::empty());
+    let diff = old.diff(&new);
+
+    assert!(diff.is_same_structure());
+    assert!(!diff.is_empty());
+    assert_eq!(diff.changed_texts.get(&NodeId::new(1)).map(|s| s.as_str()), Some("b"));
+    assert!(old.diff(&old.clone()).is_empty());
 }
@@ -26,7 +26,7 @@ use crate::{
         NodeDataContainer, NodeDataContainerRef,
         NodeDataContainerRefMut
     },
-    window::{Menu, OptionVirtualKeyCodeCombo},
+    window::{Menu, MenuCallback, KeyboardShortcut, OptionVirtualKeyCodeCombo, VirtualKeyCodeCombo},
     styled_dom::{StyledDom, AzNodeId},
 };
 use azul_css::{
@@ -368,6 +368,22 @@ pub enum HoverEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    /// The left mouse button was pressed on the node and the cursor moved
+    /// further than `DRAG_THRESHOLD`: call `CallbackInfo::start_drag` to
+    /// accept the drag, otherwise it is ignored until the button is released
+    DragStart,
+    /// A drag entered the node
+    DragEnter,
+    /// A drag moved over the node
+    DragOver,
+    /// A drag left the node
+    DragLeave,
+    /// A drag was released over the node, use `CallbackInfo::get_drag_data` to get the payload
+    Drop,
+    /// The drag that was started by this node ended (dropped or cancelled)
+    DragEnd,
+    /// The composition text of the input method changed, see `keyboard_state.ime_composition`
+    ImeComposition,
 }
 
 impl HoverEventFilter {
@@ -397,6 +413,13 @@ impl HoverEventFilter {
             HoverEventFilter::TouchMove => None,
             HoverEventFilter::TouchEnd => None,
             HoverEventFilter::TouchCancel => None,
+            HoverEventFilter::DragStart => None,
+            HoverEventFilter::DragEnter => None,
+            HoverEventFilter::DragOver => None,
+            HoverEventFilter::DragLeave => None,
+            HoverEventFilter::Drop => None,
+            HoverEventFilter::DragEnd => None,
+            HoverEventFilter::ImeComposition => Some(FocusEventFilter::ImeComposition),
         }
     }
 }
@@ -446,6 +469,8 @@ pub enum FocusEventFilter {
     VirtualKeyUp,
     FocusReceived,
     FocusLost,
+    /// The composition text of the input method changed, see `keyboard_state.ime_composition`
+    ImeComposition,
 }
 
 /// Event filter that fires when any action fires on the entire window
@@ -483,6 +508,11 @@ pub enum WindowEventFilter {
     FocusLost,
     CloseRequested,
     ThemeChanged,
+    DragStart,
+    DragOver,
+    Drop,
+    DragEnd,
+    ImeComposition,
 }
 
 impl WindowEventFilter {
@@ -520,6 +550,12 @@ impl WindowEventFilter {
             WindowEventFilter::FocusLost => None,
             WindowEventFilter::CloseRequested => None,
             WindowEventFilter::ThemeChanged => None,
+            WindowEventFilter::DragStart => Some(HoverEventFilter::DragStart),
+            WindowEventFilter::DragOver => Some(HoverEventFilter::DragOver),
+            WindowEventFilter::Drop => Some(HoverEventFilter::Drop),
+            // DragEnd only fires on the node that started the drag
+            WindowEventFilter::DragEnd => None,
+            WindowEventFilter::ImeComposition => Some(HoverEventFilter::ImeComposition),
         }
     }
 }
@@ -675,6 +711,12 @@ impl Hash for NodeData {
             if let Some(c) = ext.accessibility.as_ref() { c.hash(state); }
             if let Some(c) = ext.menu_bar.as_ref() { c.hash(state); }
             if let Some(c) = ext.context_menu.as_ref() { c.hash(state); }
+            if let Some(c) = ext.key.as_ref() { c.hash(state); }
+            if let Some(c) = ext.focus_scope.as_ref() { c.hash(state); }
+            for shortcut in ext.shortcuts.iter() {
+                shortcut.combo.hash(state);
+                shortcut.callback.callback.hash(state);
+            }
         }
     }
 }
@@ -693,6 +735,13 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// Optional key to identify this node among its siblings when diffing
+    /// two DOMs, see `StyledDomDiff`
+    pub(crate) key: Option<u64>,
+    /// Restricts the keyboard navigation to the children of this node
+    pub(crate) focus_scope: Option<FocusScope>,
+    /// Keyboard shortcuts that are active while the focus is inside of this node
+    pub(crate) shortcuts: Vec<KeyboardShortcut>,
 
     // ... insert further API extensions here...
 }
@@ -974,6 +1023,54 @@ impl Default for TabIndex {
     }
 }
 
+/// Restricts the keyboard focus navigation to the subtree of a node,
+/// see `NodeData::set_focus_scope`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum FocusScope {
+    /// Focus can't leave the subtree (modal dialogs, popups): Tab / Shift+Tab wrap
+    /// around inside of the subtree and clicking outside of it doesn't move the focus.
+    /// Once the node is removed from the DOM, the focus is restored to the node
+    /// that was focused before the scope appeared.
+    ///
+    /// If multiple traps are in the DOM, the last one (in DOM order) is active.
+    Trap,
+    /// The subtree is a single tab stop (toolbars, lists, radio groups): Tab / Shift+Tab
+    /// skip over the focusable children, the arrow keys move the focus between them.
+    ///
+    /// The tab stop is the first child with a `TabIndex::Auto` or `TabIndex::OverrideInParent`,
+    /// so widgets can implement a "roving tabindex" by giving the active child `TabIndex::Auto`
+    /// and all other children `TabIndex::NoKeyboardFocus`.
+    Roving(RovingFocus),
+}
+
+/// Configures the arrow key navigation of a `FocusScope::Roving`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct RovingFocus {
+    /// Which arrow keys move the focus to the previous / next child
+    pub orientation: RovingFocusOrientation,
+    /// Whether the focus wraps around at the first / last child
+    pub wrap: bool,
+}
+
+impl RovingFocus {
+    pub const fn horizontal() -> Self { Self { orientation: RovingFocusOrientation::Horizontal, wrap: false } }
+    pub const fn vertical() -> Self { Self { orientation: RovingFocusOrientation::Vertical, wrap: false } }
+    pub const fn with_wrap(self, wrap: bool) -> Self { Self { wrap, .. self } }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum RovingFocusOrientation {
+    /// Left / Right arrow keys
+    Horizontal,
+    /// Up / Down arrow keys
+    Vertical,
+    /// All arrow keys (grids, radio groups)
+    Both,
+}
+
 impl Default for NodeData {
     fn default() -> Self {
         NodeData::new(NodeType::Div)
@@ -1131,6 +1228,12 @@ impl NodeData {
     pub fn get_menu_bar(&self) -> Option<&Box<Menu>> { self.extra.as_ref().and_then(|e| e.menu_bar.as_ref()) }
     #[inline]
     pub fn get_context_menu(&self) -> Option<&Box<Menu>> { self.extra.as_ref().and_then(|e| e.context_menu.as_ref()) }
+    #[inline]
+    pub fn get_key(&self) -> Option<u64> { self.extra.as_ref().and_then(|e| e.key) }
+    #[inline]
+    pub fn get_focus_scope(&self) -> Option<FocusScope> { self.extra.as_ref().and_then(|e| e.focus_scope) }
+    #[inline]
+    pub fn get_shortcuts(&self) -> &[KeyboardShortcut] { self.extra.as_ref().map(|e| e.shortcuts.as_slice()).unwrap_or(&[]) }
 
     #[inline(always)]
     pub fn set_node_type(&mut self, node_type: NodeType) { self.node_type = node_type; }
@@ -1164,6 +1267,20 @@ impl NodeData {
         self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
         .context_menu = Some(Box::new(context_menu));
     }
+    /// Sets a key that identifies the node among its siblings, so that
+    /// the node keeps its focus / scroll position when the DOM is re-generated
+    /// and the siblings are inserted, removed or re-ordered
+    #[inline]
+    pub fn set_key(&mut self, key: u64) {
+        self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
+        .key = Some(key);
+    }
+    /// Makes this node a focus trap or a roving focus group, see `FocusScope`
+    #[inline]
+    pub fn set_focus_scope(&mut self, focus_scope: FocusScope) {
+        self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
+        .focus_scope = Some(focus_scope);
+    }
     #[inline]
     pub fn add_callback(&mut self, event: EventFilter, data: RefAny, callback: CallbackType) {
         let mut v: CallbackDataVec = Vec::new().into();
@@ -1172,6 +1289,15 @@ impl NodeData {
         v.push(CallbackData { event, data, callback: Callback { cb: callback } });
         self.callbacks = v.into();
     }
+    /// Adds a keyboard shortcut that invokes the `callback` if the `combo` is pressed
+    /// while the focus is on this node or one of its children. Shortcuts of nodes closer
+    /// to the focused node take precedence over the shortcuts of the parents and over the
+    /// accelerators of the menu bar.
+    #[inline]
+    pub fn add_shortcut(&mut self, combo: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType) {
+        self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
+        .shortcuts.push(KeyboardShortcut { combo, callback: MenuCallback { callback: Callback { cb: callback }, data } });
+    }
     #[inline]
     pub fn add_id(&mut self, s: AzString) {
         let mut v: IdOrClassVec = Vec::new().into();
@@ -1233,6 +1359,12 @@ impl NodeData {
     #[inline(always)]
     pub fn with_tab_index(mut self, tab_index: TabIndex) -> Self { self.set_tab_index(tab_index); self }
     #[inline(always)]
+    pub fn with_key(mut self, key: u64) -> Self { self.set_key(key); self }
+    #[inline(always)]
+    pub fn with_focus_scope(mut self, focus_scope: FocusScope) -> Self { self.set_focus_scope(focus_scope); self }
+    #[inline(always)]
+    pub fn with_shortcut(mut self, combo: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType) -> Self { self.add_shortcut(combo, data, callback); self }
+    #[inline(always)]
     pub fn with_dataset(mut self, data: OptionRefAny) -> Self { self.dataset = data; self }
     #[inline(always)]
     pub fn with_ids_and_classes(mut self, ids_and_classes: IdOrClassVec) -> Self { self.ids_and_classes = ids_and_classes; self }
@@ -1397,6 +1529,12 @@ impl Dom {
     #[inline(always)]
     pub fn with_tab_index(mut self, tab_index: TabIndex) -> Self { self.root.set_tab_index(tab_index); self }
     #[inline(always)]
+    pub fn with_key(mut self, key: u64) -> Self { self.root.set_key(key); self }
+    #[inline(always)]
+    pub fn with_focus_scope(mut self, focus_scope: FocusScope) -> Self { self.root.set_focus_scope(focus_scope); self }
+    #[inline(always)]
+    pub fn with_shortcut(mut self, combo: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType) -> Self { self.root.add_shortcut(combo, data, callback); self }
+    #[inline(always)]
     pub fn with_dataset(mut self, data: OptionRefAny) -> Self { self.root.dataset = data; self }
     #[inline(always)]
     pub fn with_ids_and_classes(mut self, ids_and_classes: IdOrClassVec) -> Self { self.root.ids_and_classes = ids_and_classes; self }
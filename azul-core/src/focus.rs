@@ -0,0 +1,471 @@
+//! Keyboard focus navigation: tab order, focus traps and roving focus groups
+//!
+//! The tab order is the DOM order (DOM by DOM, node by node), skipping nodes that
+//! aren't focusable or that have `TabIndex::NoKeyboardFocus`. A `FocusScope` on a
+//! node changes the navigation inside of its subtree:
+//!
+//! - `FocusScope::Trap`: while a trap is in the DOM, Tab / Shift+Tab cycle only
+//!   through the nodes inside of the trap and focus can't be moved outside of it.
+//!   If there are multiple traps, the last trap in DOM order is the active one.
+//!   When the trap is removed, the focus is restored to the node that was focused
+//!   before the trap appeared (see `FocusTrapRestore`).
+//! - `FocusScope::Roving`: the focusable children of the group are only a single
+//!   tab stop, the arrow keys move the focus between the children. Tabbing back
+//!   into the group focuses the child that was focused last.
+
+use alloc::vec::Vec;
+use alloc::collections::btree_map::BTreeMap;
+use crate::{
+    callbacks::DomNodeId,
+    dom::{FocusScope, NodeData, RovingFocus, RovingFocusOrientation, TabIndex},
+    id_tree::NodeId,
+    styled_dom::{AzNodeId, DomId, StyledDom},
+    ui_solver::LayoutResult,
+    window::VirtualKeyCode,
+};
+
+/// Direction of a Tab / Shift+Tab key press
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TabDirection {
+    /// Tab
+    Next,
+    /// Shift + Tab
+    Previous,
+    /// Focus the first tab stop
+    First,
+    /// Focus the last tab stop
+    Last,
+}
+
+/// Focus trap that was active at some point + the node that was focused
+/// before the trap became active, stored in the `WindowInternal`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FocusTrapRestore {
+    /// Node that has the `FocusScope::Trap`
+    pub trap: DomNodeId,
+    /// Node to focus when the trap is removed from the DOM
+    pub restore_focus: Option<DomNodeId>,
+}
+
+/// Children of a `FocusScope::Roving` node, in DOM order
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RovingFocusGroup {
+    pub focus: RovingFocus,
+    pub items: Vec<NodeId>,
+}
+
+impl RovingFocusGroup {
+
+    /// Returns the item that should be focused when pressing the `key`
+    /// while `current` is focused, or `None` if the key isn't handled
+    pub fn get_focus_target(&self, current: NodeId, key: VirtualKeyCode) -> Option<NodeId> {
+
+        use self::RovingFocusOrientation::*;
+
+        let current_pos = self.items.iter().position(|i| *i == current)?;
+        let last_pos = self.items.len().saturating_sub(1);
+
+        let new_pos = match (self.focus.orientation, key) {
+            (Horizontal, VirtualKeyCode::Left) |
+            (Vertical, VirtualKeyCode::Up) |
+            (Both, VirtualKeyCode::Left) |
+            (Both, VirtualKeyCode::Up) => {
+                if current_pos > 0 {
+                    current_pos - 1
+                } else if self.focus.wrap {
+                    last_pos
+                } else {
+                    current_pos
+                }
+            },
+            (Horizontal, VirtualKeyCode::Right) |
+            (Vertical, VirtualKeyCode::Down) |
+            (Both, VirtualKeyCode::Right) |
+            (Both, VirtualKeyCode::Down) => {
+                if current_pos < last_pos {
+                    current_pos + 1
+                } else if self.focus.wrap {
+                    0
+                } else {
+                    current_pos
+                }
+            },
+            (_, VirtualKeyCode::Home) => 0,
+            (_, VirtualKeyCode::End) => last_pos,
+            _ => return None,
+        };
+
+        self.items.get(new_pos).copied()
+    }
+
+    /// Returns the item that is the tab stop of the group: the item that was focused
+    /// last (see `LayoutResult::roving_focus`), otherwise the first item with a
+    /// `TabIndex::Auto` or `TabIndex::OverrideInParent`, otherwise the first item
+    pub fn get_tab_stop(&self, styled_dom: &StyledDom, last_focused: Option<NodeId>) -> Option<NodeId> {
+        if let Some(last_focused) = last_focused.filter(|l| self.items.contains(l)) {
+            return Some(last_focused);
+        }
+        let node_data = styled_dom.node_data.as_container();
+        self.items.iter()
+        .find(|i| is_keyboard_focusable(&node_data[**i]))
+        .or(self.items.first())
+        .copied()
+    }
+}
+
+fn is_keyboard_focusable(node_data: &NodeData) -> bool {
+    node_data.is_focusable() && node_data.get_tab_index() != Some(&TabIndex::NoKeyboardFocus)
+}
+
+fn dom_node_id(dom: DomId, node_id: NodeId) -> DomNodeId {
+    DomNodeId { dom, node: AzNodeId::from_crate_internal(Some(node_id)) }
+}
+
+/// Returns the innermost node (starting at `node_id` itself) that has a `FocusScope`
+pub fn get_enclosing_focus_scope(styled_dom: &StyledDom, node_id: NodeId) -> Option<(NodeId, FocusScope)> {
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data = styled_dom.node_data.as_container();
+    let mut current = Some(node_id);
+    while let Some(c) = current {
+        if let Some(scope) = node_data.get(c)?.get_focus_scope() {
+            return Some((c, scope));
+        }
+        current = node_hierarchy[c].parent_id();
+    }
+    None
+}
+
+/// Returns the roving focus group that the node is an item of
+/// (the `FocusScope::Roving` node is not an item of its own group)
+fn get_roving_group_id(styled_dom: &StyledDom, node_id: NodeId) -> Option<(NodeId, RovingFocus)> {
+    let parent = styled_dom.node_hierarchy.as_container().get(node_id)?.parent_id()?;
+    match get_enclosing_focus_scope(styled_dom, parent)? {
+        (group_id, FocusScope::Roving(focus)) => Some((group_id, focus)),
+        _ => None,
+    }
+}
+
+/// Returns all roving focus groups in the DOM, indexed by the node
+/// that has the `FocusScope::Roving`
+pub fn get_roving_focus_groups(styled_dom: &StyledDom) -> BTreeMap<NodeId, RovingFocusGroup> {
+    let node_data = styled_dom.node_data.as_container();
+    let mut groups = BTreeMap::new();
+    for node_id in node_data.linear_iter() {
+        if !node_data[node_id].is_focusable() {
+            continue;
+        }
+        if let Some((group_id, focus)) = get_roving_group_id(styled_dom, node_id) {
+            groups
+            .entry(group_id)
+            .or_insert_with(|| RovingFocusGroup { focus, items: Vec::new() })
+            .items.push(node_id);
+        }
+    }
+    groups
+}
+
+/// Returns whether `node` is `ancestor` or inside of the subtree of `ancestor`,
+/// following iframes into their parent DOMs
+pub fn is_inside_of(layout_results: &[LayoutResult], node: DomNodeId, ancestor: DomNodeId) -> bool {
+
+    let ancestor_id = match ancestor.node.into_crate_internal() {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let mut node = node;
+
+    loop {
+
+        let node_id = match node.node.into_crate_internal() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let layout_result = match layout_results.get(node.dom.inner) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        if node.dom == ancestor.dom {
+            // node IDs are in depth-first order, parents always have a lower ID
+            let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+            let mut current = Some(node_id);
+            while let Some(c) = current {
+                if c == ancestor_id {
+                    return true;
+                }
+                if c < ancestor_id {
+                    return false;
+                }
+                current = node_hierarchy.get(c).and_then(|n| n.parent_id());
+            }
+            return false;
+        }
+
+        let parent_dom = match layout_result.parent_dom_id {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let iframe_node_id = match layout_results.get(parent_dom.inner).and_then(|p| {
+            p.iframe_mapping.iter().find(|(_, dom)| **dom == node.dom).map(|(node_id, _)| *node_id)
+        }) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        node = dom_node_id(parent_dom, iframe_node_id);
+    }
+}
+
+/// Returns the focus trap that is currently active, i.e. the last node
+/// with a `FocusScope::Trap` in DOM order
+pub fn get_active_focus_trap(layout_results: &[LayoutResult]) -> Option<DomNodeId> {
+    layout_results.iter().enumerate().rev().find_map(|(dom_id, layout_result)| {
+        let node_data = layout_result.styled_dom.node_data.as_slice();
+        node_data.iter().enumerate().rev()
+        .find(|(_, n)| n.get_focus_scope() == Some(FocusScope::Trap))
+        .map(|(node_id, _)| dom_node_id(DomId { inner: dom_id }, NodeId::new(node_id)))
+    })
+}
+
+/// Remembers the focused node as the tab stop of its roving focus group (if it is
+/// an item of one), so that tabbing back into the group focuses the same item
+pub fn remember_roving_focus(layout_results: &mut [LayoutResult], focused_node: DomNodeId) {
+    let node_id = match focused_node.node.into_crate_internal() {
+        Some(s) => s,
+        None => return,
+    };
+    let layout_result = match layout_results.get_mut(focused_node.dom.inner) {
+        Some(s) => s,
+        None => return,
+    };
+    if let Some((group_id, _)) = get_roving_group_id(&layout_result.styled_dom, node_id) {
+        layout_result.roving_focus.insert(group_id, node_id);
+    }
+}
+
+/// Returns all nodes that can be reached with Tab / Shift+Tab, in tab order.
+///
+/// Only one item of each roving focus group is a tab stop. If `trap` is set,
+/// only the nodes inside of the trap are returned.
+pub fn get_tab_stops(layout_results: &[LayoutResult], trap: Option<DomNodeId>) -> Vec<DomNodeId> {
+
+    let mut tab_stops = Vec::new();
+
+    for (dom_id, layout_result) in layout_results.iter().enumerate() {
+
+        let dom_id = DomId { inner: dom_id };
+        let styled_dom = &layout_result.styled_dom;
+        let node_data = styled_dom.node_data.as_container();
+        let roving_tab_stops = get_roving_focus_groups(styled_dom)
+            .iter()
+            .filter_map(|(group_id, group)| group.get_tab_stop(styled_dom, layout_result.roving_focus.get(group_id).copied()))
+            .collect::<Vec<_>>();
+
+        for node_id in node_data.linear_iter() {
+
+            if !node_data[node_id].is_focusable() {
+                continue;
+            }
+
+            let is_tab_stop = if get_roving_group_id(styled_dom, node_id).is_some() {
+                roving_tab_stops.contains(&node_id)
+            } else {
+                is_keyboard_focusable(&node_data[node_id])
+            };
+
+            if !is_tab_stop {
+                continue;
+            }
+
+            let node = dom_node_id(dom_id, node_id);
+
+            if let Some(trap) = trap {
+                if !is_inside_of(layout_results, node, trap) {
+                    continue;
+                }
+            }
+
+            tab_stops.push(node);
+        }
+    }
+
+    tab_stops
+}
+
+/// Returns the node to focus when navigating with the keyboard.
+///
+/// Inside of the active focus trap, the focus wraps around at the first / last tab
+/// stop, otherwise `None` is returned at the end of the tab order. Moving out of a
+/// roving focus group skips the remaining items of the group.
+pub fn get_next_tab_stop(
+    layout_results: &[LayoutResult],
+    current_focus: Option<DomNodeId>,
+    direction: TabDirection,
+) -> Option<DomNodeId> {
+
+    let trap = get_active_focus_trap(layout_results);
+    let tab_stops = get_tab_stops(layout_results, trap);
+
+    let current_focus = current_focus.filter(|c| match trap {
+        Some(t) => is_inside_of(layout_results, *c, t),
+        None => true,
+    });
+
+    let current = match (direction, current_focus) {
+        (TabDirection::First, _) | (TabDirection::Next, None) => return tab_stops.first().copied(),
+        (TabDirection::Last, _) | (TabDirection::Previous, None) => return tab_stops.last().copied(),
+        (_, Some(s)) => s,
+    };
+
+    // the roving group that the current node is in, if any
+    let current_group = current.node.into_crate_internal().and_then(|node_id| {
+        let styled_dom = &layout_results.get(current.dom.inner)?.styled_dom;
+        let (group_id, _) = get_roving_group_id(styled_dom, node_id)?;
+        Some(dom_node_id(current.dom, group_id))
+    });
+
+    let is_outside_group = |n: &&DomNodeId| match current_group {
+        Some(g) => !is_inside_of(layout_results, **n, g),
+        None => true,
+    };
+
+    let next = match direction {
+        TabDirection::Next => tab_stops.iter().filter(|n| **n > current).find(is_outside_group),
+        _ => tab_stops.iter().rev().filter(|n| **n < current).find(is_outside_group),
+    };
+
+    match (next, trap) {
+        (Some(s), _) => Some(*s),
+        (None, Some(_)) => match direction {
+            TabDirection::Next => tab_stops.first().copied(),
+            _ => tab_stops.last().copied(),
+        },
+        (None, None) => None,
+    }
+}
+
+/// Constrains a focus change to the active focus trap: if the new focus is
+/// outside of the trap, the focus stays on the old node (or is cleared if the
+/// old node is outside of the trap, too)
+pub fn constrain_focus(
+    layout_results: &[LayoutResult],
+    old_focus: Option<DomNodeId>,
+    new_focus: Option<DomNodeId>,
+) -> Option<DomNodeId> {
+
+    let trap = match get_active_focus_trap(layout_results) {
+        Some(s) => s,
+        None => return new_focus,
+    };
+
+    let is_inside_trap = |n: &DomNodeId| is_inside_of(layout_results, *n, trap);
+
+    match (new_focus, old_focus) {
+        (Some(new), _) if is_inside_trap(&new) => Some(new),
+        (_, Some(old)) if is_inside_trap(&old) => Some(old),
+        _ => None,
+    }
+}
+
+/// Returns the nodes that match `:focus-within` if `node_id` is focused,
+/// i.e. the node itself and all of its parents
+pub fn get_focus_within_nodes(styled_dom: &StyledDom, node_id: NodeId) -> Vec<NodeId> {
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let mut nodes = Vec::new();
+    let mut current = Some(node_id);
+    while let Some(c) = current {
+        if node_hierarchy.get(c).is_none() {
+            break;
+        }
+        nodes.push(c);
+        current = node_hierarchy[c].parent_id();
+    }
+    nodes
+}
+
+/// Updates the stack of focus traps after the DOM has been re-generated.
+///
+/// `focus_traps` and `current_focus` have to be already mapped to the node IDs
+/// of the new DOM, with `None` for nodes that were removed. Returns the node
+/// to focus, if the focus has to change:
+///
+/// - if a trap was removed, the focus is restored to the node that
+///   was focused before the trap appeared
+/// - if a new trap appeared and the focus is outside of it, the first
+///   tab stop inside of the trap is focused
+pub fn update_focus_traps(
+    layout_results: &[LayoutResult],
+    focus_traps: &mut Vec<FocusTrapRestore>,
+    current_focus: Option<DomNodeId>,
+) -> Option<Option<DomNodeId>> {
+
+    let is_trap = |n: &DomNodeId| {
+        n.node.into_crate_internal()
+        .and_then(|node_id| layout_results.get(n.dom.inner)?.styled_dom.node_data.as_container().get(node_id)?.get_focus_scope())
+        == Some(FocusScope::Trap)
+    };
+
+    let mut new_focus = None;
+
+    // restore the focus of the outermost trap that was removed
+    if let Some(first_removed) = focus_traps.iter().position(|t| !is_trap(&t.trap)) {
+        let restore_focus = focus_traps[first_removed].restore_focus;
+        focus_traps.retain(|t| is_trap(&t.trap));
+        if restore_focus != current_focus {
+            new_focus = Some(restore_focus);
+        }
+    }
+
+    let focus = new_focus.unwrap_or(current_focus);
+
+    if let Some(active_trap) = get_active_focus_trap(layout_results) {
+        if focus_traps.last().map(|t| t.trap) != Some(active_trap) {
+            focus_traps.push(FocusTrapRestore { trap: active_trap, restore_focus: focus });
+            let focus_is_inside_trap = focus.map(|f| is_inside_of(layout_results, f, active_trap)).unwrap_or(false);
+            if !focus_is_inside_trap {
+                new_focus = Some(get_next_tab_stop(layout_results, None, TabDirection::First));
+            }
+        }
+    }
+
+    new_focus
+}
+
+#[test]
+fn test_roving_focus_group() {
+
+    use azul_css::Css;
+    use crate::dom::Dom;
+
+    let item = |tab_index: TabIndex| Dom::div().with_tab_index(tab_index);
+
+    // body > toolbar > [button, button (active), button]
+    let styled_dom = Dom::body()
+    .with_children(vec![
+        Dom::div()
+        .with_focus_scope(FocusScope::Roving(RovingFocus::horizontal().with_wrap(true)))
+        .with_children(vec![
+            item(TabIndex::NoKeyboardFocus),
+            item(TabIndex::Auto),
+            item(TabIndex::NoKeyboardFocus),
+        ].into())
+    ].into())
+    .style(&mut Css::empty());
+
+    let groups = get_roving_focus_groups(&styled_dom);
+    let group = groups.get(&NodeId::new(1)).unwrap();
+
+    assert_eq!(group.items, vec![NodeId::new(2), NodeId::new(3), NodeId::new(4)]);
+    assert_eq!(group.get_tab_stop(&styled_dom, None), Some(NodeId::new(3)));
+    assert_eq!(group.get_tab_stop(&styled_dom, Some(NodeId::new(4))), Some(NodeId::new(4)));
+    // nodes that aren't items of the group are ignored
+    assert_eq!(group.get_tab_stop(&styled_dom, Some(NodeId::new(1))), Some(NodeId::new(3)));
+    assert_eq!(group.get_focus_target(NodeId::new(3), VirtualKeyCode::Right), Some(NodeId::new(4)));
+    assert_eq!(group.get_focus_target(NodeId::new(4), VirtualKeyCode::Right), Some(NodeId::new(2)));
+    assert_eq!(group.get_focus_target(NodeId::new(2), VirtualKeyCode::Left), Some(NodeId::new(4)));
+    assert_eq!(group.get_focus_target(NodeId::new(2), VirtualKeyCode::Down), None);
+    assert_eq!(get_focus_within_nodes(&styled_dom, NodeId::new(3)), vec![NodeId::new(3), NodeId::new(1), NodeId::new(0)]);
+}
@@ -31,8 +31,8 @@ pub mod app_resources;
 pub mod display_list;
 /// `Dom` construction, `NodeData` and `NodeType` management functions
 pub mod dom;
-// Algorithms to create git-like diffs between two doms in linear time
-// pub mod diff;
+/// Keyed diff between two `StyledDom`s, to preserve focus / scroll state across DOM re-generation
+pub mod diff;
 /// Contains OpenGL helper functions (to compile / link shaders), `VirtualGlDriver` for unit testing
 pub mod gl;
 /// Internal, arena-based storage for Dom nodes
@@ -43,6 +43,10 @@ pub mod style;
 pub mod svg;
 /// XML structures
 pub mod xml;
+/// Expression language of the `<if>` / `<for>` XML templates
+pub mod xml_expr;
+/// Type system of the XML component arguments
+pub mod xml_types;
 /// Main `Layout` and `GetTextLayout` trait definition
 pub mod traits;
 /// Async (task, thread, timer) helper functions
@@ -55,6 +59,12 @@ pub mod ui_solver;
 pub mod window;
 /// Window state handling / synchronization
 pub mod window_state;
+/// Keyboard focus navigation: tab order, focus traps and roving focus groups
+pub mod focus;
+/// Cross-platform keyboard shortcut routing (menu accelerators + shortcuts scoped to the focused node)
+pub mod shortcuts;
+/// Recording of user input into a file, for replaying bug reports
+pub mod recording;
 
 // Typedef for possible faster implementation of hashing
 pub type FastHashMap<T, U> = alloc::collections::BTreeMap<T, U>;
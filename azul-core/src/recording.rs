@@ -0,0 +1,447 @@
+//! Recording of user input for deterministic replay
+//!
+//! The recorder stores the changes of the input-related fields of a `FullWindowState`
+//! (mouse, keyboard, size, theme) as a line-based text file:
+//!
+//! ```text
+//! azul-event-recording 1
+//! 0 0 size 800 600
+//! 0 0 cursor out
+//! 16000 0 cursor 10 20.5
+//! 250000 0 mouse 1 0 0
+//! 251000 0 keys LShift,A
+//! ```
+//!
+//! Each line is `<microseconds since start> <window index> <change>`, the
+//! timestamps are taken from the `GetSystemTimeCallback` of the application.
+//! The file is written line by line, so that it survives crashes.
+//!
+//! This module only contains the file format, the file itself is written
+//! by the `EventRecorder` of the desktop shell (`azul_desktop::shell::recording`).
+
+use core::fmt;
+use alloc::vec::Vec;
+use alloc::string::String;
+use crate::window::{
+    FullWindowState, CursorPosition, LogicalPosition, LogicalSize,
+    WindowTheme, VirtualKeyCode,
+};
+use crate::task::Duration;
+
+/// First line of every event recording file
+pub const RECORDING_HEADER: &str = "azul-event-recording 1";
+/// Environment variable that enables the recording and sets the file name
+pub const RECORD_EVENTS_ENV_VAR: &str = "AZUL_RECORD_EVENTS";
+/// File name of the recording if `AppConfig.record_events` is set, but `AZUL_RECORD_EVENTS` is not
+pub const DEFAULT_RECORDING_FILE: &str = "azul-events.txt";
+
+/// Input-related part of a `FullWindowState`, used to compute the changes between two frames
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedWindowState {
+    pub theme: WindowTheme,
+    pub size: LogicalSize,
+    pub hidpi_factor: f32,
+    pub cursor_position: CursorPosition,
+    pub left_down: bool,
+    pub right_down: bool,
+    pub middle_down: bool,
+    pub scroll: Option<(f32, f32)>,
+    pub current_char: Option<char>,
+    pub current_virtual_keycode: Option<VirtualKeyCode>,
+    pub pressed_virtual_keycodes: Vec<VirtualKeyCode>,
+    pub shift_down: bool,
+    pub ctrl_down: bool,
+    pub alt_down: bool,
+    pub super_down: bool,
+}
+
+impl RecordedWindowState {
+
+    pub fn new(window_state: &FullWindowState) -> Self {
+        let mouse_state = &window_state.mouse_state;
+        let keyboard_state = &window_state.keyboard_state;
+        Self {
+            theme: window_state.theme,
+            size: window_state.size.dimensions,
+            hidpi_factor: window_state.size.hidpi_factor,
+            cursor_position: mouse_state.cursor_position,
+            left_down: mouse_state.left_down,
+            right_down: mouse_state.right_down,
+            middle_down: mouse_state.middle_down,
+            scroll: mouse_state.get_scroll_amount(),
+            current_char: keyboard_state.current_char.into_option().and_then(core::char::from_u32),
+            current_virtual_keycode: keyboard_state.current_virtual_keycode.into_option(),
+            pressed_virtual_keycodes: keyboard_state.pressed_virtual_keycodes.as_ref().to_vec(),
+            shift_down: keyboard_state.shift_down,
+            ctrl_down: keyboard_state.ctrl_down,
+            alt_down: keyboard_state.alt_down,
+            super_down: keyboard_state.super_down,
+        }
+    }
+
+    /// Returns the changes necessary to get from `previous` to `new`
+    ///
+    /// If `previous` is `None`, all fields are returned
+    pub fn diff(previous: Option<&Self>, new: &Self) -> Vec<WindowStateDelta> {
+
+        use self::WindowStateDelta::*;
+
+        let mut deltas = Vec::new();
+
+        macro_rules! push_if_changed {($field:ident, $delta:expr) => {
+            if previous.map(|p| p.$field != new.$field).unwrap_or(true) {
+                deltas.push($delta);
+            }
+        }}
+
+        push_if_changed!(theme, Theme(new.theme));
+        push_if_changed!(size, Size(new.size));
+        push_if_changed!(hidpi_factor, HidpiFactor(new.hidpi_factor));
+        push_if_changed!(cursor_position, Cursor(new.cursor_position));
+
+        if previous.map(|p| {
+            (p.left_down, p.right_down, p.middle_down) != (new.left_down, new.right_down, new.middle_down)
+        }).unwrap_or(true) {
+            deltas.push(MouseButtons { left: new.left_down, right: new.right_down, middle: new.middle_down });
+        }
+
+        // scroll amounts are reset after every frame, so they are always recorded
+        if let Some((x, y)) = new.scroll {
+            deltas.push(Scroll { x, y });
+        }
+
+        push_if_changed!(current_char, CurrentChar(new.current_char));
+        push_if_changed!(current_virtual_keycode, CurrentVirtualKeyCode(new.current_virtual_keycode));
+        push_if_changed!(pressed_virtual_keycodes, PressedVirtualKeyCodes(new.pressed_virtual_keycodes.clone()));
+
+        if previous.map(|p| {
+            (p.shift_down, p.ctrl_down, p.alt_down, p.super_down) != (new.shift_down, new.ctrl_down, new.alt_down, new.super_down)
+        }).unwrap_or(true) {
+            deltas.push(Modifiers { shift: new.shift_down, ctrl: new.ctrl_down, alt: new.alt_down, super_key: new.super_down });
+        }
+
+        deltas
+    }
+}
+
+/// Change of a single field (or a group of fields) of the `FullWindowState`
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowStateDelta {
+    Theme(WindowTheme),
+    Size(LogicalSize),
+    HidpiFactor(f32),
+    Cursor(CursorPosition),
+    MouseButtons { left: bool, right: bool, middle: bool },
+    Scroll { x: f32, y: f32 },
+    CurrentChar(Option<char>),
+    CurrentVirtualKeyCode(Option<VirtualKeyCode>),
+    PressedVirtualKeyCodes(Vec<VirtualKeyCode>),
+    Modifiers { shift: bool, ctrl: bool, alt: bool, super_key: bool },
+}
+
+impl WindowStateDelta {
+
+    /// Writes the change into the window state
+    pub fn apply(&self, window_state: &mut FullWindowState) {
+        use self::WindowStateDelta::*;
+        match self {
+            Theme(t) => { window_state.theme = *t; },
+            Size(s) => { window_state.size.dimensions = *s; },
+            HidpiFactor(f) => { window_state.size.hidpi_factor = *f; },
+            Cursor(c) => { window_state.mouse_state.cursor_position = *c; },
+            MouseButtons { left, right, middle } => {
+                window_state.mouse_state.left_down = *left;
+                window_state.mouse_state.right_down = *right;
+                window_state.mouse_state.middle_down = *middle;
+            },
+            Scroll { x, y } => {
+                window_state.mouse_state.scroll_x = Some(*x).into();
+                window_state.mouse_state.scroll_y = Some(*y).into();
+            },
+            CurrentChar(c) => { window_state.keyboard_state.current_char = c.map(|c| c as u32).into(); },
+            CurrentVirtualKeyCode(k) => { window_state.keyboard_state.current_virtual_keycode = (*k).into(); },
+            PressedVirtualKeyCodes(k) => { window_state.keyboard_state.pressed_virtual_keycodes = k.clone().into(); },
+            Modifiers { shift, ctrl, alt, super_key } => {
+                window_state.keyboard_state.shift_down = *shift;
+                window_state.keyboard_state.ctrl_down = *ctrl;
+                window_state.keyboard_state.alt_down = *alt;
+                window_state.keyboard_state.super_down = *super_key;
+            },
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+
+        use self::WindowStateDelta::*;
+
+        fn parse_bool(s: &str) -> Option<bool> {
+            match s {
+                "0" => Some(false),
+                "1" => Some(true),
+                _ => None,
+            }
+        }
+
+        let mut parts = s.split_whitespace();
+        let key = parts.next()?;
+        let args = parts.collect::<Vec<_>>();
+
+        let delta = match (key, args.as_slice()) {
+            ("theme", ["light"]) => Theme(WindowTheme::LightMode),
+            ("theme", ["dark"]) => Theme(WindowTheme::DarkMode),
+            ("size", [w, h]) => Size(LogicalSize::new(w.parse().ok()?, h.parse().ok()?)),
+            ("hidpi", [f]) => HidpiFactor(f.parse().ok()?),
+            ("cursor", ["out"]) => Cursor(CursorPosition::OutOfWindow),
+            ("cursor", ["uninitialized"]) => Cursor(CursorPosition::Uninitialized),
+            ("cursor", [x, y]) => Cursor(CursorPosition::InWindow(LogicalPosition::new(x.parse().ok()?, y.parse().ok()?))),
+            ("mouse", [l, r, m]) => MouseButtons { left: parse_bool(l)?, right: parse_bool(r)?, middle: parse_bool(m)? },
+            ("scroll", [x, y]) => Scroll { x: x.parse().ok()?, y: y.parse().ok()? },
+            ("char", ["-"]) => CurrentChar(None),
+            ("char", [c]) => CurrentChar(Some(core::char::from_u32(c.parse().ok()?)?)),
+            ("vk", ["-"]) => CurrentVirtualKeyCode(None),
+            ("vk", [k]) => CurrentVirtualKeyCode(Some(VirtualKeyCode::from_name(k)?)),
+            ("keys", ["-"]) => PressedVirtualKeyCodes(Vec::new()),
+            ("keys", [k]) => PressedVirtualKeyCodes(k.split(',').map(VirtualKeyCode::from_name).collect::<Option<Vec<_>>>()?),
+            ("mods", [s, c, a, w]) => Modifiers { shift: parse_bool(s)?, ctrl: parse_bool(c)?, alt: parse_bool(a)?, super_key: parse_bool(w)? },
+            _ => return None,
+        };
+
+        Some(delta)
+    }
+}
+
+impl fmt::Display for WindowStateDelta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::WindowStateDelta::*;
+        let b = |b: &bool| if *b { 1 } else { 0 };
+        match self {
+            Theme(WindowTheme::LightMode) => write!(f, "theme light"),
+            Theme(WindowTheme::DarkMode) => write!(f, "theme dark"),
+            Size(s) => write!(f, "size {} {}", s.width, s.height),
+            HidpiFactor(h) => write!(f, "hidpi {}", h),
+            Cursor(CursorPosition::OutOfWindow) => write!(f, "cursor out"),
+            Cursor(CursorPosition::Uninitialized) => write!(f, "cursor uninitialized"),
+            Cursor(CursorPosition::InWindow(p)) => write!(f, "cursor {} {}", p.x, p.y),
+            MouseButtons { left, right, middle } => write!(f, "mouse {} {} {}", b(left), b(right), b(middle)),
+            Scroll { x, y } => write!(f, "scroll {} {}", x, y),
+            CurrentChar(None) => write!(f, "char -"),
+            CurrentChar(Some(c)) => write!(f, "char {}", *c as u32),
+            CurrentVirtualKeyCode(None) => write!(f, "vk -"),
+            CurrentVirtualKeyCode(Some(k)) => write!(f, "vk {}", k.get_name()),
+            PressedVirtualKeyCodes(k) if k.is_empty() => write!(f, "keys -"),
+            PressedVirtualKeyCodes(k) => {
+                let names = k.iter().map(|k| k.get_name()).collect::<Vec<_>>();
+                write!(f, "keys {}", names.join(","))
+            },
+            Modifiers { shift, ctrl, alt, super_key } => write!(f, "mods {} {} {} {}", b(shift), b(ctrl), b(alt), b(super_key)),
+        }
+    }
+}
+
+/// Single line of an event recording
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    /// Microseconds since the recording was started
+    pub time_micros: u64,
+    /// Index of the window, in the order the windows were first seen by the recorder
+    pub window: usize,
+    pub delta: WindowStateDelta,
+}
+
+impl fmt::Display for RecordedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.time_micros, self.window, self.delta)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordingParseError {
+    /// The first line is not `azul-event-recording 1`
+    InvalidHeader,
+    /// Line number (1-based) + content of the line that could not be parsed
+    InvalidLine(usize, String),
+}
+
+impl_display!(RecordingParseError, {
+    InvalidHeader => format!("event recording: missing header \"{}\"", RECORDING_HEADER),
+    InvalidLine(line, content) => format!("event recording: invalid event on line {}: \"{}\"", line, content),
+});
+
+/// Parsed event recording file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EventRecording {
+    /// Events, sorted by time
+    pub events: Vec<RecordedEvent>,
+}
+
+impl EventRecording {
+
+    pub fn parse(s: &str) -> Result<Self, RecordingParseError> {
+
+        let mut lines = s.lines().enumerate();
+
+        match lines.next() {
+            Some((_, header)) if header.trim() == RECORDING_HEADER => { },
+            _ => return Err(RecordingParseError::InvalidHeader),
+        }
+
+        let mut events = Vec::new();
+
+        for (line_idx, line) in lines {
+
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let invalid_line = || RecordingParseError::InvalidLine(line_idx + 1, line.into());
+
+            let mut parts = line.splitn(3, ' ');
+            let time_micros = parts.next().and_then(|s| s.parse().ok()).ok_or_else(invalid_line)?;
+            let window = parts.next().and_then(|s| s.parse().ok()).ok_or_else(invalid_line)?;
+            let delta = parts.next().and_then(WindowStateDelta::parse).ok_or_else(invalid_line)?;
+
+            events.push(RecordedEvent { time_micros, window, delta });
+        }
+
+        events.sort_by_key(|e| e.time_micros);
+
+        Ok(Self { events })
+    }
+
+    /// Returns only the events of a single window
+    pub fn get_window_events(&self, window: usize) -> impl Iterator<Item = &RecordedEvent> {
+        self.events.iter().filter(move |e| e.window == window)
+    }
+}
+
+impl fmt::Display for EventRecording {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", RECORDING_HEADER)?;
+        for event in self.events.iter() {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a duration into microseconds (ticks are taken as microseconds)
+pub fn duration_to_micros(duration: &Duration) -> u64 {
+    match duration {
+        Duration::System(s) => s.secs.saturating_mul(1_000_000).saturating_add((s.nanos / 1_000) as u64),
+        Duration::Tick(t) => t.tick_diff,
+    }
+}
+
+#[test]
+fn test_recorded_window_state_diff() {
+
+    use self::WindowStateDelta::*;
+
+    let mut window_state = FullWindowState::default();
+    let first = RecordedWindowState::new(&window_state);
+
+    // without a previous state, every field except for the (empty) scroll amount is recorded
+    let all = RecordedWindowState::diff(None, &first);
+    assert_eq!(all.len(), 9);
+    assert!(!all.iter().any(|d| matches!(d, WindowStateDelta::Scroll { .. })));
+    assert!(RecordedWindowState::diff(Some(&first), &first).is_empty());
+
+    window_state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(10.0, 20.5));
+    window_state.mouse_state.left_down = true;
+    window_state.keyboard_state.shift_down = true;
+    window_state.keyboard_state.pressed_virtual_keycodes = vec![VirtualKeyCode::LShift, VirtualKeyCode::A].into();
+    let second = RecordedWindowState::new(&window_state);
+
+    assert_eq!(RecordedWindowState::diff(Some(&first), &second), vec![
+        Cursor(CursorPosition::InWindow(LogicalPosition::new(10.0, 20.5))),
+        MouseButtons { left: true, right: false, middle: false },
+        PressedVirtualKeyCodes(vec![VirtualKeyCode::LShift, VirtualKeyCode::A]),
+        Modifiers { shift: true, ctrl: false, alt: false, super_key: false },
+    ]);
+
+    // scroll amounts are recorded even if they didn't change
+    window_state.mouse_state.scroll_x = Some(0.0).into();
+    window_state.mouse_state.scroll_y = Some(-3.0).into();
+    let third = RecordedWindowState::new(&window_state);
+    assert_eq!(RecordedWindowState::diff(Some(&third), &third), vec![Scroll { x: 0.0, y: -3.0 }]);
+
+    // applying the deltas restores the window state
+    let mut replayed = FullWindowState::default();
+    for delta in RecordedWindowState::diff(None, &third) {
+        delta.apply(&mut replayed);
+    }
+    assert_eq!(RecordedWindowState::new(&replayed), third);
+}
+
+#[test]
+fn test_window_state_delta_display_parse_roundtrip() {
+
+    use self::WindowStateDelta::*;
+
+    let deltas = vec![
+        Theme(WindowTheme::LightMode),
+        Theme(WindowTheme::DarkMode),
+        Size(LogicalSize::new(800.0, 600.5)),
+        HidpiFactor(1.25),
+        Cursor(CursorPosition::OutOfWindow),
+        Cursor(CursorPosition::Uninitialized),
+        Cursor(CursorPosition::InWindow(LogicalPosition::new(10.0, -20.5))),
+        MouseButtons { left: true, right: false, middle: true },
+        Scroll { x: 0.0, y: -3.5 },
+        CurrentChar(None),
+        CurrentChar(Some('ä')),
+        CurrentVirtualKeyCode(None),
+        CurrentVirtualKeyCode(Some(VirtualKeyCode::Return)),
+        PressedVirtualKeyCodes(Vec::new()),
+        PressedVirtualKeyCodes(vec![VirtualKeyCode::LControl, VirtualKeyCode::Key1, VirtualKeyCode::NumpadAdd]),
+        Modifiers { shift: false, ctrl: true, alt: false, super_key: true },
+    ];
+
+    for delta in deltas {
+        let s = format!("{}", delta);
+        assert_eq!(WindowStateDelta::parse(&s), Some(delta), "{}", s);
+    }
+
+    // every key code survives the round-trip via its name
+    for key in VirtualKeyCode::ALL.iter() {
+        assert_eq!(VirtualKeyCode::from_name(key.get_name()), Some(*key));
+    }
+}
+
+#[test]
+fn test_event_recording_parse() {
+
+    let recording = format!("{}\n250000 0 mouse 1 0 0\n\n0 1 size 800 600\n", RECORDING_HEADER);
+    let parsed = EventRecording::parse(&recording).unwrap();
+
+    // events are sorted by time
+    assert_eq!(parsed.events, vec![
+        RecordedEvent { time_micros: 0, window: 1, delta: WindowStateDelta::Size(LogicalSize::new(800.0, 600.0)) },
+        RecordedEvent { time_micros: 250000, window: 0, delta: WindowStateDelta::MouseButtons { left: true, right: false, middle: false } },
+    ]);
+    assert_eq!(parsed.get_window_events(1).count(), 1);
+    assert_eq!(EventRecording::parse(&format!("{}", parsed)), Ok(parsed));
+
+    assert_eq!(EventRecording::parse(""), Err(RecordingParseError::InvalidHeader));
+    assert_eq!(EventRecording::parse("0 0 cursor out"), Err(RecordingParseError::InvalidHeader));
+
+    let invalid_lines = [
+        "x 0 cursor out",
+        "0 x cursor out",
+        "0 0",
+        "0 0 cursor 10",
+        "0 0 mouse 1 0 2",
+        "0 0 keys LShift,NotAKey",
+        "0 0 unknown 1",
+    ];
+
+    for line in invalid_lines.iter() {
+        // the line number is 1-based and includes the header
+        let recording = format!("{}\n0 0 cursor out\n  {}  \n", RECORDING_HEADER, line);
+        assert_eq!(
+            EventRecording::parse(&recording),
+            Err(RecordingParseError::InvalidLine(3, String::from(*line))),
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! Cross-platform keyboard shortcut routing
+//!
+//! Native menus only handle the accelerators of their items on some platforms,
+//! so the shortcuts are matched against the `KeyboardState` by azul itself.
+//! The sources of shortcuts are (in order of precedence):
+//!
+//! 1. `NodeData::add_shortcut` and the accelerators of the context menus of the
+//!    focused node and its parents, the node closest to the focused node wins
+//! 2. the accelerators of the menu bar of the window
+//! 3. the accelerators of the `AccessibilityInfo` of any node, which invoke the
+//!    `ComponentEventFilter::DefaultAction` callback of the node
+//!
+//! If the same key combination is registered twice with the same precedence,
+//! the first one (in DOM order) wins and `get_shortcut_conflicts` reports it.
+//!
+//! While a node that accepts text input (i.e. has a `FocusEventFilter::TextInput`
+//! callback, such as a `TextInput` or `TextEdit`) is focused, shortcuts without
+//! Ctrl / Alt / Super (ex. `Delete` or `A`) are not matched, so that they don't
+//! swallow the key presses of the user typing. Function keys are still matched.
+
+use core::fmt;
+use alloc::vec::Vec;
+use azul_css::AzString;
+use crate::{
+    callbacks::{Callback, DomNodeId},
+    dom::{ComponentEventFilter, EventFilter, FocusEventFilter, NodeData},
+    id_tree::NodeId,
+    styled_dom::{AzNodeId, DomId},
+    ui_solver::LayoutResult,
+    window::{
+        KeyboardModifiers, KeyboardState, Menu, MenuCallback,
+        MenuItem, MenuItemState, VirtualKeyCode, VirtualKeyCodeCombo,
+    },
+};
+
+/// Where a keyboard shortcut was registered
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ShortcutSource {
+    /// Accelerator of a menu bar item (label of the item)
+    MenuBar(AzString),
+    /// Accelerator of a context menu item on the given node
+    ContextMenu(DomNodeId, AzString),
+    /// Shortcut registered with `NodeData::add_shortcut` on the given node
+    Node(DomNodeId),
+    /// Accelerator of the `AccessibilityInfo` of the given node
+    Accessibility(DomNodeId),
+}
+
+impl fmt::Display for ShortcutSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ShortcutSource::*;
+        match self {
+            MenuBar(label) => write!(f, "menu bar item \"{}\"", label.as_str()),
+            ContextMenu(node, label) => write!(f, "context menu item \"{}\" of node {:?}", label.as_str(), node),
+            Node(node) => write!(f, "shortcut on node {:?}", node),
+            Accessibility(node) => write!(f, "accessibility accelerator of node {:?}", node),
+        }
+    }
+}
+
+/// Shortcut that matched the current keyboard state
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutMatch {
+    pub source: ShortcutSource,
+    /// Node to use as the `CallbackInfo::get_hit_node()` when invoking the callback
+    pub hit_dom_node: DomNodeId,
+    pub callback: MenuCallback,
+}
+
+/// Two shortcuts with the same key combination and the same precedence, or a
+/// node shortcut that shadows a menu bar accelerator while the node is focused
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortcutConflict {
+    pub combo: VirtualKeyCodeCombo,
+    /// Shortcut that is invoked when the combo is pressed
+    pub active: ShortcutSource,
+    /// Shortcut that is never invoked (menu bar accelerators: never invoked
+    /// while the focus is on the node of the `active` shortcut or its children)
+    pub shadowed: ShortcutSource,
+}
+
+impl fmt::Display for ShortcutConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keys = self.combo.keys.iter().map(|k| format!("{:?}", k)).collect::<Vec<_>>();
+        write!(f, "shortcut {} is registered twice: {} shadows {}", keys.join(" + "), self.active, self.shadowed)
+    }
+}
+
+struct ShortcutCandidate {
+    combo: VirtualKeyCodeCombo,
+    source: ShortcutSource,
+    hit_dom_node: DomNodeId,
+    callback: MenuCallback,
+}
+
+fn dom_node_id(dom: DomId, node_id: NodeId) -> DomNodeId {
+    DomNodeId { dom, node: AzNodeId::from_crate_internal(Some(node_id)) }
+}
+
+/// Recursively collects all accelerators of a menu, skipping disabled or greyed out items
+fn collect_menu_shortcuts<F: Fn(AzString) -> ShortcutSource>(
+    menu: &Menu,
+    hit_dom_node: DomNodeId,
+    make_source: &F,
+    candidates: &mut Vec<ShortcutCandidate>,
+) {
+    fn collect_items<F: Fn(AzString) -> ShortcutSource>(
+        items: &[MenuItem],
+        hit_dom_node: DomNodeId,
+        make_source: &F,
+        candidates: &mut Vec<ShortcutCandidate>,
+    ) {
+        for item in items.iter() {
+            let item = match item {
+                MenuItem::String(s) => s,
+                _ => continue,
+            };
+            if item.state != MenuItemState::Normal {
+                continue;
+            }
+            if let (Some(combo), Some(callback)) = (item.accelerator.as_ref(), item.callback.as_ref()) {
+                candidates.push(ShortcutCandidate {
+                    combo: combo.clone(),
+                    source: make_source(item.label.clone()),
+                    hit_dom_node,
+                    callback: callback.clone(),
+                });
+            }
+            collect_items(item.children.as_ref(), hit_dom_node, make_source, candidates);
+        }
+    }
+
+    collect_items(menu.items.as_ref(), hit_dom_node, make_source, candidates);
+}
+
+/// Returns the shortcuts registered on a single node (node shortcuts + context menu)
+fn collect_node_shortcuts(node_data: &NodeData, node: DomNodeId, candidates: &mut Vec<ShortcutCandidate>) {
+
+    for shortcut in node_data.get_shortcuts() {
+        candidates.push(ShortcutCandidate {
+            combo: shortcut.combo.clone(),
+            source: ShortcutSource::Node(node),
+            hit_dom_node: node,
+            callback: shortcut.callback.clone(),
+        });
+    }
+
+    if let Some(context_menu) = node_data.get_context_menu() {
+        collect_menu_shortcuts(context_menu, node, &|label| ShortcutSource::ContextMenu(node, label), candidates);
+    }
+}
+
+/// Returns the `DefaultAction` callback of a node with an accessibility accelerator
+fn get_accessibility_shortcut(node_data: &NodeData, node: DomNodeId) -> Option<ShortcutCandidate> {
+    let combo = node_data.get_accessibility_info()?.accelerator.as_ref()?.clone();
+    let callback = node_data.get_callbacks().iter().find(|cb| {
+        cb.event == EventFilter::Component(ComponentEventFilter::DefaultAction)
+    })?;
+    Some(ShortcutCandidate {
+        combo,
+        source: ShortcutSource::Accessibility(node),
+        hit_dom_node: node,
+        callback: MenuCallback { callback: Callback { cb: callback.callback.cb }, data: callback.data.clone() },
+    })
+}
+
+/// Returns the focused node and all its parents (following iframes into their parent DOM),
+/// starting with the focused node - if nothing is focused, returns only the root node
+fn get_focus_path(layout_results: &[LayoutResult], focused_node: Option<DomNodeId>) -> Vec<DomNodeId> {
+
+    let mut path = Vec::new();
+
+    let mut current = match focused_node {
+        Some(s) => s,
+        None => {
+            if !layout_results.is_empty() {
+                path.push(dom_node_id(DomId::ROOT_ID, NodeId::ZERO));
+            }
+            return path;
+        },
+    };
+
+    loop {
+        let layout_result = match layout_results.get(current.dom.inner) {
+            Some(s) => s,
+            None => return path,
+        };
+
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        let mut node_id = current.node.into_crate_internal();
+        while let Some(n) = node_id {
+            if node_hierarchy.get(n).is_none() {
+                break;
+            }
+            path.push(dom_node_id(current.dom, n));
+            node_id = node_hierarchy[n].parent_id();
+        }
+
+        let parent_dom = match layout_result.parent_dom_id {
+            Some(s) => s,
+            None => return path,
+        };
+
+        current = match layout_results.get(parent_dom.inner).and_then(|p| {
+            p.iframe_mapping.iter().find(|(_, dom)| **dom == current.dom).map(|(node_id, _)| *node_id)
+        }) {
+            Some(iframe_node_id) => dom_node_id(parent_dom, iframe_node_id),
+            None => return path,
+        };
+    }
+}
+
+/// Returns the shortcut groups that are active for the focused node, ordered by
+/// precedence: one group per node on the focus path, then the menu bar, then all
+/// accessibility accelerators. Within a group, the first shortcut wins.
+fn get_active_shortcut_groups(layout_results: &[LayoutResult], focused_node: Option<DomNodeId>) -> Vec<Vec<ShortcutCandidate>> {
+
+    let mut groups = Vec::new();
+
+    for node in get_focus_path(layout_results, focused_node) {
+        let node_data = match node.node.into_crate_internal().and_then(|n| {
+            layout_results.get(node.dom.inner)?.styled_dom.node_data.as_container().get_extended_lifetime(n)
+        }) {
+            Some(s) => s,
+            None => continue,
+        };
+        let mut group = Vec::new();
+        collect_node_shortcuts(node_data, node, &mut group);
+        groups.push(group);
+    }
+
+    groups.push(get_menu_bar_shortcuts(layout_results));
+    groups.push(get_accessibility_shortcuts(layout_results));
+
+    groups
+}
+
+fn get_menu_bar_shortcuts(layout_results: &[LayoutResult]) -> Vec<ShortcutCandidate> {
+    let mut group = Vec::new();
+    let root = dom_node_id(DomId::ROOT_ID, NodeId::ZERO);
+    let menu_bar = layout_results.get(0).and_then(|lr| {
+        lr.styled_dom.node_data.as_container().get_extended_lifetime(NodeId::ZERO)?.get_menu_bar()
+    });
+    if let Some(menu_bar) = menu_bar {
+        collect_menu_shortcuts(menu_bar, root, &|label| ShortcutSource::MenuBar(label), &mut group);
+    }
+    group
+}
+
+fn get_accessibility_shortcuts(layout_results: &[LayoutResult]) -> Vec<ShortcutCandidate> {
+    layout_results.iter().enumerate().flat_map(|(dom_id, layout_result)| {
+        let dom_id = DomId { inner: dom_id };
+        layout_result.styled_dom.node_data.as_slice().iter().enumerate().filter_map(move |(node_id, node_data)| {
+            get_accessibility_shortcut(node_data, dom_node_id(dom_id, NodeId::new(node_id)))
+        })
+    }).collect()
+}
+
+/// Returns whether the node has a `FocusEventFilter::TextInput` callback
+fn accepts_text_input(layout_results: &[LayoutResult], node: DomNodeId) -> bool {
+    node.node.into_crate_internal()
+    .and_then(|n| layout_results.get(node.dom.inner)?.styled_dom.node_data.as_container().get_extended_lifetime(n))
+    .map(|node_data| node_data.get_callbacks().iter().any(|cb| cb.event == EventFilter::Focus(FocusEventFilter::TextInput)))
+    .unwrap_or(false)
+}
+
+/// Returns whether the combo could be a key press of the user typing into a text input,
+/// i.e. it has no Ctrl / Alt / Super modifier and isn't made up of function keys
+fn is_typing_combo(combo: &VirtualKeyCodeCombo) -> bool {
+    use crate::window::VirtualKeyCode::*;
+    let (modifiers, keys) = combo.normalize();
+    let is_function_key = |k: &VirtualKeyCode| match k {
+        F1 | F2 | F3 | F4 | F5 | F6 | F7 | F8 | F9 | F10 | F11 | F12 |
+        F13 | F14 | F15 | F16 | F17 | F18 | F19 | F20 | F21 | F22 | F23 | F24 => true,
+        _ => false,
+    };
+    !modifiers.ctrl && !modifiers.alt && !modifiers.super_key && !keys.iter().all(is_function_key)
+}
+
+/// Returns the shortcut that should be invoked for the current keyboard state,
+/// or `None` if no shortcut matches. Should only be called on a `VirtualKeyDown` event.
+pub fn get_matching_shortcut(
+    layout_results: &[LayoutResult],
+    focused_node: Option<DomNodeId>,
+    keyboard_state: &KeyboardState,
+) -> Option<ShortcutMatch> {
+
+    // don't collect all shortcuts on every modifier key press
+    match keyboard_state.current_virtual_keycode.into_option()? {
+        VirtualKeyCode::LControl | VirtualKeyCode::RControl |
+        VirtualKeyCode::LAlt | VirtualKeyCode::RAlt |
+        VirtualKeyCode::LShift | VirtualKeyCode::RShift |
+        VirtualKeyCode::LWin | VirtualKeyCode::RWin => return None,
+        _ => { },
+    }
+
+    let is_typing = focused_node.map(|n| accepts_text_input(layout_results, n)).unwrap_or(false);
+
+    get_active_shortcut_groups(layout_results, focused_node)
+    .into_iter()
+    .flat_map(|group| group.into_iter())
+    .filter(|candidate| !(is_typing && is_typing_combo(&candidate.combo)))
+    .find(|candidate| candidate.combo.matches(keyboard_state))
+    .map(|candidate| ShortcutMatch {
+        source: candidate.source,
+        hit_dom_node: candidate.hit_dom_node,
+        callback: candidate.callback,
+    })
+}
+
+/// Returns all shortcuts that can never be invoked because another shortcut
+/// with the same key combination and the same precedence exists, as well as
+/// the menu bar accelerators that are shadowed by a node-scoped shortcut
+pub fn get_shortcut_conflicts(layout_results: &[LayoutResult]) -> Vec<ShortcutConflict> {
+
+    fn find_conflicts(group: &[ShortcutCandidate], conflicts: &mut Vec<ShortcutConflict>) {
+        let normalized = group.iter().map(|c| c.combo.normalize()).collect::<Vec<(KeyboardModifiers, Vec<VirtualKeyCode>)>>();
+        for (i, candidate) in group.iter().enumerate() {
+            if let Some(first) = normalized[..i].iter().position(|n| *n == normalized[i]) {
+                conflicts.push(ShortcutConflict {
+                    combo: candidate.combo.clone(),
+                    active: group[first].source.clone(),
+                    shadowed: candidate.source.clone(),
+                });
+            }
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    let menu_bar = get_menu_bar_shortcuts(layout_results);
+    let menu_bar_normalized = menu_bar.iter().map(|c| c.combo.normalize()).collect::<Vec<_>>();
+
+    for (dom_id, layout_result) in layout_results.iter().enumerate() {
+        let dom_id = DomId { inner: dom_id };
+        for (node_id, node_data) in layout_result.styled_dom.node_data.as_slice().iter().enumerate() {
+            let mut group = Vec::new();
+            collect_node_shortcuts(node_data, dom_node_id(dom_id, NodeId::new(node_id)), &mut group);
+            find_conflicts(&group, &mut conflicts);
+
+            // node shortcuts take precedence over the menu bar
+            for (menu_item, normalized) in menu_bar.iter().zip(menu_bar_normalized.iter()) {
+                if let Some(active) = group.iter().find(|c| c.combo.normalize() == *normalized) {
+                    conflicts.push(ShortcutConflict {
+                        combo: menu_item.combo.clone(),
+                        active: active.source.clone(),
+                        shadowed: menu_item.source.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    find_conflicts(&menu_bar, &mut conflicts);
+    find_conflicts(&get_accessibility_shortcuts(layout_results), &mut conflicts);
+
+    conflicts
+}
+
+#[test]
+fn test_virtual_key_code_combo_matches() {
+
+    use crate::window::VirtualKeyCodeVec;
+
+    let ctrl_s = VirtualKeyCodeCombo {
+        keys: VirtualKeyCodeVec::from_vec(vec![VirtualKeyCode::LControl, VirtualKeyCode::S]),
+    };
+
+    let mut keyboard_state = KeyboardState::default();
+    keyboard_state.ctrl_down = true;
+    keyboard_state.current_virtual_keycode = Some(VirtualKeyCode::S).into();
+    keyboard_state.pressed_virtual_keycodes = VirtualKeyCodeVec::from_vec(vec![VirtualKeyCode::RControl, VirtualKeyCode::S]);
+    assert!(ctrl_s.matches(&keyboard_state));
+
+    // modifiers have to match exactly
+    keyboard_state.shift_down = true;
+    assert!(!ctrl_s.matches(&keyboard_state));
+
+    let ctrl_s_reordered = VirtualKeyCodeCombo {
+        keys: VirtualKeyCodeVec::from_vec(vec![VirtualKeyCode::S, VirtualKeyCode::RControl]),
+    };
+    assert_eq!(ctrl_s.normalize(), ctrl_s_reordered.normalize());
+}
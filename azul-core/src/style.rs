@@ -315,6 +315,18 @@ pub(crate) fn selector_group_matches(
                     CssPathPseudoSelector::Focus => {
                         if !is_last_content_group { return false; }
                         if expected_path_ending != Some(CssPathPseudoSelector::Focus) { return false; }
+                    },
+                    CssPathPseudoSelector::FocusVisible => {
+                        if !is_last_content_group { return false; }
+                        if expected_path_ending != Some(CssPathPseudoSelector::FocusVisible) { return false; }
+                    },
+                    CssPathPseudoSelector::FocusWithin => {
+                        if !is_last_content_group { return false; }
+                        if expected_path_ending != Some(CssPathPseudoSelector::FocusWithin) { return false; }
+                    },
+                    CssPathPseudoSelector::DragOver => {
+                        if !is_last_content_group { return false; }
+                        if expected_path_ending != Some(CssPathPseudoSelector::DragOver) { return false; }
                     }
                 }
             }
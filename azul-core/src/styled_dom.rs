@@ -42,7 +42,8 @@ use crate::{
         CompactDom, TagId, OptionTabIndex,
         NodeDataInlineCssProperty
     },
-    callbacks::{RefAny, Update, CallbackInfo},
+    callbacks::{RefAny, Update, CallbackInfo, FocusTarget, DomNodeId},
+    focus::RovingFocusGroup,
     style::{
         CascadeInfo, CascadeInfoVec, construct_html_cascade_tree,
         matches_html_element, rule_ends_with,
@@ -52,6 +53,7 @@ use crate::{
         ImageCache, Au, ImmediateFontId
     },
     window::Menu,
+    diff::StyledDomDiff,
 };
 
 #[repr(C)]
@@ -86,6 +88,12 @@ pub struct StyledNodeState {
     pub hover: bool,
     pub active: bool,
     pub focused: bool,
+    /// Node is focused and the focus was not set with the mouse (`:focus-visible`)
+    pub focus_visible: bool,
+    /// Node or one of its children is focused (`:focus-within`)
+    pub focus_within: bool,
+    /// Node is hovered while a drag is in progress (`:drag-over`)
+    pub drag_over: bool,
 }
 
 impl core::fmt::Debug for StyledNodeState {
@@ -95,6 +103,9 @@ impl core::fmt::Debug for StyledNodeState {
         if self.hover { v.push("hover"); }
         if self.active { v.push("active"); }
         if self.focused { v.push("focused"); }
+        if self.focus_visible { v.push("focus_visible"); }
+        if self.focus_within { v.push("focus_within"); }
+        if self.drag_over { v.push("drag_over"); }
         write!(f, "{:?}", v)
     }
 }
@@ -112,6 +123,9 @@ impl StyledNodeState {
             hover: false,
             active: false,
             focused: false,
+            focus_visible: false,
+            focus_within: false,
+            drag_over: false,
         }
     }
 }
@@ -191,6 +205,11 @@ pub struct CssPropertyCache {
     pub css_hover_props:         BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_active_props:        BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_focus_props:         BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+
+    // NOTE: :focus-visible, :focus-within and :drag-over properties are not inherited
+    pub css_focus_visible_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub css_focus_within_props:  BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub css_drag_over_props:     BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
 }
 
 impl CssPropertyCache {
@@ -277,6 +296,24 @@ impl CssPropertyCache {
                 if r.is_empty() { None } else { Some((node_id, r)) }
             });
 
+            let css_focus_visible_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)>  =
+            node_data.transform_nodeid_multithreaded_optional(|node_id| {
+                let r = filter_rules!(Some(FocusVisible), node_id);
+                if r.is_empty() { None } else { Some((node_id, r)) }
+            });
+
+            let css_focus_within_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)>  =
+            node_data.transform_nodeid_multithreaded_optional(|node_id| {
+                let r = filter_rules!(Some(FocusWithin), node_id);
+                if r.is_empty() { None } else { Some((node_id, r)) }
+            });
+
+            let css_drag_over_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)>  =
+            node_data.transform_nodeid_multithreaded_optional(|node_id| {
+                let r = filter_rules!(Some(DragOver), node_id);
+                if r.is_empty() { None } else { Some((node_id, r)) }
+            });
+
             self.css_normal_props = css_normal_rules.internal.into_iter()
             .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
             .collect();
@@ -292,6 +329,18 @@ impl CssPropertyCache {
             self.css_focus_props = css_focus_rules.internal.into_iter()
             .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
             .collect();
+
+            self.css_focus_visible_props = css_focus_visible_rules.internal.into_iter()
+            .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
+            .collect();
+
+            self.css_focus_within_props = css_focus_within_rules.internal.into_iter()
+            .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
+            .collect();
+
+            self.css_drag_over_props = css_drag_over_rules.internal.into_iter()
+            .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
+            .collect();
         }
 
         // Inheritance: Inherit all values of the parent to the children, but
@@ -663,6 +712,9 @@ impl CssPropertyCache {
             css_hover_props: BTreeMap::new(),
             css_active_props: BTreeMap::new(),
             css_focus_props: BTreeMap::new(),
+            css_focus_visible_props: BTreeMap::new(),
+            css_focus_within_props: BTreeMap::new(),
+            css_drag_over_props: BTreeMap::new(),
         }
     }
 
@@ -683,6 +735,9 @@ impl CssPropertyCache {
         append_css_property_vec!(css_hover_props);
         append_css_property_vec!(css_active_props);
         append_css_property_vec!(css_focus_props);
+        append_css_property_vec!(css_focus_visible_props);
+        append_css_property_vec!(css_focus_within_props);
+        append_css_property_vec!(css_drag_over_props);
 
         self.node_count += other.node_count;
     }
@@ -750,13 +805,28 @@ impl CssPropertyCache {
         }
 
         if !(node_state.normal || node_state.active ||
-           node_state.hover || node_state.focused)
+           node_state.hover || node_state.focused ||
+           node_state.focus_within)
         {
             return None;
         }
 
         // If that fails, see if there is an inline CSS property that matches
-        // :focus > :active > :hover > :normal
+        // :drag-over > :focus-visible > :focus > :focus-within > :active > :hover > :normal
+        if node_state.drag_over {
+            if let Some(p) = self.css_drag_over_props.get(node_id)
+            .and_then(|map| map.get(css_property_type)) {
+                return Some(p);
+            }
+        }
+
+        if node_state.focus_visible {
+            if let Some(p) = self.css_focus_visible_props.get(node_id)
+            .and_then(|map| map.get(css_property_type)) {
+                return Some(p);
+            }
+        }
+
         if node_state.focused {
             if let Some(p) = self.css_focus_props.get(node_id)
             .and_then(|map| map.get(css_property_type)) {
@@ -780,6 +850,13 @@ impl CssPropertyCache {
             }
         }
 
+        if node_state.focus_within {
+            if let Some(p) = self.css_focus_within_props.get(node_id)
+            .and_then(|map| map.get(css_property_type)) {
+                return Some(p);
+            }
+        }
+
         if node_state.active {
             if let Some(p) = self.css_active_props.get(node_id)
             .and_then(|map| map.get(css_property_type)) {
@@ -1540,7 +1617,7 @@ impl StyledDom {
 
         use crate::dom::{
             CallbackData, EventFilter,
-            HoverEventFilter, FocusEventFilter
+            HoverEventFilter, FocusEventFilter, WindowEventFilter,
         };
         use crate::callbacks::Callback;
 
@@ -1563,26 +1640,53 @@ impl StyledDom {
             return;
         }
 
-        let tab_data = RefAny::new(DefaultTabIndexCallbackData { });
+        // items of a roving focus group share the group, so that
+        // the arrow keys can move the focus between the items
+        let tab_data = RefAny::new(DefaultTabIndexCallbackData { roving_group: None });
+        let mut roving_item_data = BTreeMap::new();
+        for (_, group) in crate::focus::get_roving_focus_groups(self) {
+            let items = group.items.clone();
+            let group_data = RefAny::new(DefaultTabIndexCallbackData { roving_group: Some(group) });
+            for item in items {
+                roving_item_data.insert(item, group_data.clone());
+            }
+        }
+
         for focusable_node in self.tag_ids_to_node_ids.iter() {
-            if focusable_node.tab_index.is_some() {
 
-                let focusable_node_id = match focusable_node.node_id.into_crate_internal() {
-                    Some(s) => s,
-                    None => continue,
-                };
+            let focusable_node_id = match focusable_node.node_id.into_crate_internal() {
+                Some(s) => s,
+                None => continue,
+            };
 
-                let mut node_data = &mut self.node_data.as_container_mut()[focusable_node_id];
-                if !node_data.callbacks.iter().any(|cb| cb.event == EventFilter::Focus(FocusEventFilter::VirtualKeyDown)) {
-                    node_data.callbacks.push(CallbackData {
-                        event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
-                        data: tab_data.clone(),
-                        callback: Callback { cb: default_on_tabindex },
-                    });
-                }
+            let mut node_data = &mut self.node_data.as_container_mut()[focusable_node_id];
+            if !node_data.is_focusable() {
+                continue;
+            }
+
+            if !node_data.callbacks.iter().any(|cb| cb.event == EventFilter::Focus(FocusEventFilter::VirtualKeyDown)) {
+                node_data.callbacks.push(CallbackData {
+                    event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                    data: roving_item_data.get(&focusable_node_id).cloned().unwrap_or_else(|| tab_data.clone()),
+                    callback: Callback { cb: default_on_tabindex },
+                });
             }
         }
 
+        // pressing Tab while no node is focused focuses the first tab stop
+        if let Some(root) = self.node_data.as_mut().get_mut(0) {
+            if !root.callbacks.iter().any(|cb| cb.event == EventFilter::Window(WindowEventFilter::VirtualKeyDown)) {
+                root.callbacks.push(CallbackData {
+                    event: EventFilter::Window(WindowEventFilter::VirtualKeyDown),
+                    data: tab_data.clone(),
+                    callback: Callback { cb: default_on_window_tab },
+                });
+                let root_id = AzNodeId::from_crate_internal(Some(NodeId::ZERO));
+                if !self.nodes_with_window_callbacks.iter().any(|n| *n == root_id) {
+                    self.nodes_with_window_callbacks.insert(0, root_id);
+                }
+            }
+        }
     }
 
     #[inline]
@@ -1978,6 +2082,99 @@ impl StyledDom {
         v.into_iter().collect()
     }
 
+    /// Sets the `:focus-visible` state of the nodes (see `restyle_nodes_focus`)
+    #[cfg(feature = "multithreading")]
+    #[must_use]
+    pub fn restyle_nodes_focus_visible(&mut self, nodes: &[NodeId], new_focus_visible_state: bool)
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+        self.restyle_nodes_state(
+            nodes,
+            |state| state.focus_visible = new_focus_visible_state,
+            |cache| &cache.css_focus_visible_props,
+        )
+    }
+
+    /// Sets the `:focus-within` state of the nodes, usually the focused node + all of its parents
+    #[cfg(feature = "multithreading")]
+    #[must_use]
+    pub fn restyle_nodes_focus_within(&mut self, nodes: &[NodeId], new_focus_within_state: bool)
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+        self.restyle_nodes_state(
+            nodes,
+            |state| state.focus_within = new_focus_within_state,
+            |cache| &cache.css_focus_within_props,
+        )
+    }
+
+    /// Sets the `:drag-over` state of the nodes, the hovered nodes while a drag is in progress
+    #[cfg(feature = "multithreading")]
+    #[must_use]
+    pub fn restyle_nodes_drag_over(&mut self, nodes: &[NodeId], new_drag_over_state: bool)
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+        self.restyle_nodes_state(
+            nodes,
+            |state| state.drag_over = new_drag_over_state,
+            |cache| &cache.css_drag_over_props,
+        )
+    }
+
+    /// Updates the state of the nodes and returns the properties of
+    /// the `get_props` map that changed because of the new state
+    #[cfg(feature = "multithreading")]
+    fn restyle_nodes_state<F, G>(&mut self, nodes: &[NodeId], set_state: F, get_props: G)
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>>
+    where F: Fn(&mut StyledNodeState),
+          G: Fn(&CssPropertyCache) -> &BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>
+    {
+        // save the old node state
+        let old_node_states = nodes.iter().map(|nid| {
+            self.styled_nodes.as_container()[*nid].state.clone()
+        }).collect::<Vec<_>>();
+
+        for nid in nodes.iter() {
+            set_state(&mut self.styled_nodes.as_container_mut()[*nid].state);
+        }
+
+        let css_property_cache = self.get_css_property_cache();
+        let styled_nodes = self.styled_nodes.as_container();
+        let node_data = self.node_data.as_container();
+        let props = get_props(css_property_cache);
+
+        nodes
+        .iter()
+        .zip(old_node_states.iter())
+        .filter_map(|(node_id, old_node_state)| {
+
+            let node_properties_that_could_have_changed = props.get(node_id)?;
+            let new_node_state = &styled_nodes[*node_id].state;
+            let node_data = &node_data[*node_id];
+
+            let changes = node_properties_that_could_have_changed
+            .keys()
+            .filter_map(|prop| {
+                let old = css_property_cache.get_property(node_data, node_id, old_node_state, prop);
+                let new = css_property_cache.get_property(node_data, node_id, new_node_state, prop);
+                if old == new {
+                    None
+                } else {
+                    Some(ChangedCssProperty {
+                        previous_state: old_node_state.clone(),
+                        previous_prop: match old { None => CssProperty::auto(*prop), Some(s) => s.clone() },
+                        current_state: new_node_state.clone(),
+                        current_prop: match new { None => CssProperty::auto(*prop), Some(s) => s.clone() },
+                    })
+                }
+            }).collect::<Vec<_>>();
+
+            if changes.is_empty() {
+                None
+            } else {
+                Some((*node_id, changes))
+            }
+        })
+        .collect()
+    }
+
     // Inserts a property into the self.user_overridden_properties
     #[cfg(feature = "multithreading")]
     #[must_use]
@@ -2102,6 +2299,31 @@ impl StyledDom {
     }
 
 
+    /// Returns whether the CSS path matches at least one node of this DOM, regardless of
+    /// the state of the node: `#button:hover` matches if there is a `#button` node.
+    /// Useful to find CSS rules that never apply.
+    pub fn matches_any_node(&self, css_path: &CssPath) -> bool {
+
+        use azul_css::{CssPathSelector, CssPathPseudoSelector::*};
+
+        // :hover, :focus, etc. only match if the node is in the expected state
+        let expected_path_ending = match css_path.selectors.as_ref().last() {
+            Some(CssPathSelector::PseudoSelector(p)) => match p {
+                Hover | Active | Focus | FocusVisible | FocusWithin | DragOver => Some(*p),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let node_hierarchy = self.node_hierarchy.as_container();
+        let node_data = self.node_data.as_container();
+        let html_node_tree = self.cascade_info.as_container();
+
+        html_node_tree.linear_iter().any(|node_id| {
+            matches_html_element(css_path, node_id, &node_hierarchy, &node_data, &html_node_tree, expected_path_ending)
+        })
+    }
+
     /// Returns a HTML-formatted version of the DOM for easier debugging, i.e.
     ///
     /// ```rust,no_run,ignore
@@ -2306,8 +2528,10 @@ impl StyledDom {
         }
     }
 
-    // Computes the diff between the two DOMs
-    // pub fn diff(&self, other: &Self) -> StyledDomDiff { /**/ }
+    /// Computes the diff between this (old) DOM and a re-generated (new) DOM
+    pub fn diff(&self, new: &Self) -> StyledDomDiff {
+        StyledDomDiff::new(self, new)
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -2321,16 +2545,63 @@ pub struct DefaultScrollCallbackData {
     pub smooth_scroll: bool,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub struct DefaultTabIndexCallbackData { }
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefaultTabIndexCallbackData {
+    /// Set if the node is an item of a roving focus group
+    pub roving_group: Option<RovingFocusGroup>,
+}
 
 /// Default On::TabIndex event handler
-extern "C" fn default_on_tabindex(data: &mut RefAny, info: CallbackInfo) -> Update {
-    let mut data = match data.downcast_mut::<DefaultTabIndexCallbackData>() {
+extern "C" fn default_on_tabindex(data: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+    use crate::window::VirtualKeyCode;
+
+    let data = match data.downcast_ref::<DefaultTabIndexCallbackData>() {
         Some(s) => s,
         None => return Update::DoNothing,
     };
 
+    let keyboard_state = info.get_current_keyboard_state();
+    let key = match keyboard_state.current_virtual_keycode.into_option() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    if key == VirtualKeyCode::Tab {
+        info.set_focus(if keyboard_state.shift_down { FocusTarget::Previous } else { FocusTarget::Next });
+        return Update::DoNothing;
+    }
+
+    let hit_node = info.get_hit_node();
+    let new_focus = data.roving_group.as_ref().and_then(|group| {
+        group.get_focus_target(hit_node.node.into_crate_internal()?, key)
+    });
+
+    if let Some(new_focus) = new_focus {
+        info.set_focus(FocusTarget::Id(DomNodeId {
+            dom: hit_node.dom,
+            node: AzNodeId::from_crate_internal(Some(new_focus)),
+        }));
+    }
+
+    Update::DoNothing
+}
+
+/// Default Window::VirtualKeyDown handler on the root node: focuses
+/// the first / last tab stop if Tab is pressed while nothing is focused
+extern "C" fn default_on_window_tab(_data: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+    use crate::window::VirtualKeyCode;
+
+    if info.get_focused_node().is_some() {
+        return Update::DoNothing;
+    }
+
+    let keyboard_state = info.get_current_keyboard_state();
+    if keyboard_state.current_virtual_keycode.into_option() == Some(VirtualKeyCode::Tab) {
+        info.set_focus(if keyboard_state.shift_down { FocusTarget::Last } else { FocusTarget::First });
+    }
+
     Update::DoNothing
 }
 
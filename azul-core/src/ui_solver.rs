@@ -556,6 +556,8 @@ pub struct LayoutResult {
     pub scrollable_nodes: ScrolledNodes,
     pub iframe_mapping: BTreeMap<NodeId, DomId>,
     pub gpu_value_cache: GpuValueCache,
+    /// `FocusScope::Roving` node -> item of the group that was focused last
+    pub roving_focus: BTreeMap<NodeId, NodeId>,
 }
 
 pub struct QuickResizeResult {
@@ -643,6 +645,36 @@ impl LayoutResult {
             });
         }
 
+        // draw the ghost image of the current drag on top of the root DOM,
+        // the image has to be registered in the renderer_resources already
+        if dom_id == DomId::ROOT_ID {
+            let cursor_position = full_window_state.mouse_state.cursor_position.get_position();
+            let ghost = full_window_state.get_drag_ghost().and_then(|ghost| {
+                renderer_resources.currently_registered_images.get(&ghost.get_hash())
+            });
+            if let (Some(cursor_position), Some((image_key, descriptor))) = (cursor_position, ghost) {
+                use crate::display_list::{ImageRendering, AlphaType};
+                let ghost_size = LogicalSize::new(descriptor.width as f32, descriptor.height as f32);
+                let mut ghost_frame = DisplayListFrame::root(LayoutSize::zero(), LayoutPoint::zero());
+                ghost_frame.size = ghost_size;
+                ghost_frame.position = PositionInfo::Static(PositionInfoInner {
+                    x_offset: cursor_position.x,
+                    y_offset: cursor_position.y,
+                    static_x_offset: cursor_position.x,
+                    static_y_offset: cursor_position.y,
+                });
+                ghost_frame.content.push(LayoutRectContent::Image {
+                    size: ghost_size,
+                    offset: LogicalPosition::zero(),
+                    image_rendering: ImageRendering::Auto,
+                    alpha_type: AlphaType::PremultipliedAlpha,
+                    image_key: *image_key,
+                    background_color: StyleColorU::TRANSPARENT,
+                });
+                dl.root.append_child(DisplayListMsg::Frame(ghost_frame));
+            }
+        }
+
         dl
     }
 
@@ -1415,6 +1447,16 @@ impl PositionInfo {
             PositionInfo::Relative(p) => (p.x_offset, p.y_offset)
         }
     }
+    /// Returns the absolute position of the rectangle within its DOM
+    #[inline]
+    pub fn get_static_offset(&self) -> (f32, f32) {
+        match self {
+            PositionInfo::Static(p) |
+            PositionInfo::Fixed(p) |
+            PositionInfo::Absolute(p) |
+            PositionInfo::Relative(p) => (p.static_x_offset, p.static_y_offset)
+        }
+    }
 }
 
 #[derive(Default, Debug, Copy, Clone, PartialEq, PartialOrd)]
@@ -18,7 +18,7 @@ use crate::{
     FastHashMap, FastBTreeSet,
     callbacks::{Callback, UpdateImageType, HitTestItem},
     window_state::RelayoutFn,
-    app_resources::{ImageRef, ImageCache, RendererResources, IdNamespace, ResourceUpdate, Epoch, ImageMask},
+    app_resources::{ImageRef, OptionImageRef, ImageCache, RendererResources, IdNamespace, ResourceUpdate, Epoch, ImageMask},
     styled_dom::{DomId, AzNodeId},
     id_tree::NodeId,
     callbacks::{OptionCallback, PipelineId, RefAny, DocumentId, DomNodeId, ScrollPosition, Update},
@@ -29,6 +29,7 @@ use crate::{
     display_list::{GlTextureCache, RenderCallbacks},
     callbacks::{LayoutCallback, LayoutCallbackType},
     task::{TimerId, ExternalSystemCallbacks, ThreadId, Timer, Thread, Instant},
+    focus::FocusTrapRestore,
 };
 use rust_fontconfig::FcFontCache;
 use crate::gl::OptionGlContextPtr;
@@ -270,10 +271,29 @@ pub struct KeyboardState {
     /// Use when the physical location of the key is more important than the key's host GUI semantics,
     /// such as for movement controls in a first-person game (German keyboard: Z key, UK keyboard: Y key, etc.)
     pub pressed_scancodes: ScanCodeVec,
+    /// Text that is currently being composed by an input method (IME), for example
+    /// the romaji of a japanese word before it is converted. Not part of the text yet,
+    /// the final text is sent via `current_char` / `On::TextInput` - (READONLY)
+    pub ime_composition: OptionImeComposition,
 }
 
 impl_option!(KeyboardState, OptionKeyboardState, copy = false, [Debug, Clone, PartialEq]);
 
+/// Pre-edit text of an input method, the IME shows its candidate window
+/// at the `WindowState.ime_position`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct ImeComposition {
+    /// Text that is being composed (not committed yet)
+    pub text: AzString,
+    /// Start of the cursor / the highlighted clause in the `text` (in characters)
+    pub cursor_start: usize,
+    /// End of the cursor / the highlighted clause in the `text`, equal to `cursor_start` if nothing is highlighted
+    pub cursor_end: usize,
+}
+
+impl_option!(ImeComposition, OptionImeComposition, copy = false, [Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord]);
+
 // char is not ABI-stable, use u32 instead
 impl_option!(u32, OptionChar, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 impl_option!(VirtualKeyCode, OptionVirtualKeyCode, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
@@ -586,6 +606,8 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// Focus traps that were activated (in order) + the node to re-focus when they are removed
+    pub focus_traps: Vec<FocusTrapRestore>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -601,6 +623,144 @@ impl FullHitTest {
             focused_node: focused_node.and_then(|f| Some((f.dom, f.node.into_crate_internal()?))),
         }
     }
+
+    /// Hit-tests the solved layout on the CPU, without asking the renderer
+    ///
+    /// Only nodes with a hit-testing tag can be hit. Scroll offsets are
+    /// subtracted from the children of overflowing nodes and hits outside of
+    /// the scroll frame are discarded. Transforms are not taken into account.
+    pub fn new(
+        layout_results: &[LayoutResult],
+        cursor_position: &CursorPosition,
+        scroll_states: &ScrollStates,
+        old_focus_node: Option<DomNodeId>,
+    ) -> Self {
+
+        use crate::callbacks::ScrollHitTestItem;
+
+        // NOTE: the cursor position is already in logical units
+        let cursor_location = match cursor_position {
+            CursorPosition::OutOfWindow | CursorPosition::Uninitialized => return FullHitTest::empty(old_focus_node),
+            CursorPosition::InWindow(pos) => LogicalPosition::new(pos.x, pos.y),
+        };
+
+        // same as the webrender hit-tester: if no focusable node
+        // was hit, the focus is set to None
+        let mut ret = FullHitTest::empty(None);
+
+        let mut dom_ids = vec![(DomId { inner: 0 }, cursor_location)];
+
+        loop {
+
+            let mut new_dom_ids = Vec::new();
+
+            for (dom_id, cursor_relative_to_dom) in dom_ids.iter() {
+
+                let layout_result = match layout_results.get(dom_id.inner) {
+                    Some(s) => s,
+                    None => break,
+                };
+
+                let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+                let node_data = layout_result.styled_dom.node_data.as_container();
+                let rects = layout_result.rects.as_ref();
+                let overflowing_nodes = &layout_result.scrollable_nodes.overflowing_nodes;
+
+                for tag_mapping in layout_result.styled_dom.tag_ids_to_node_ids.iter() {
+
+                    let node_id = match tag_mapping.node_id.into_crate_internal() {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    let rect = match rects.get(node_id) {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    // offset the node by the scroll position of all scrolling parents,
+                    // discard the hit if the cursor is outside of the scroll frame
+                    let mut scroll_offset = LogicalPosition::zero();
+                    let mut clipped = false;
+                    let mut cur_parent = node_hierarchy[node_id].parent_id();
+                    while let Some(parent_id) = cur_parent {
+                        if let Some(scroll_node) = overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(parent_id))) {
+                            if scroll_node.parent_rect.hit_test(cursor_relative_to_dom).is_none() {
+                                clipped = true;
+                                break;
+                            }
+                            if let Some(p) = scroll_states.get_scroll_position(&scroll_node.parent_external_scroll_id) {
+                                scroll_offset.x += p.x;
+                                scroll_offset.y += p.y;
+                            }
+                        }
+                        cur_parent = node_hierarchy[parent_id].parent_id();
+                    }
+
+                    if clipped {
+                        continue;
+                    }
+
+                    let (static_x, static_y) = rect.position.get_static_offset();
+                    let bounds = LogicalRect::new(
+                        LogicalPosition::new(static_x - scroll_offset.x, static_y - scroll_offset.y),
+                        rect.size,
+                    );
+
+                    let relative_to_item = match bounds.hit_test(cursor_relative_to_dom) {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    let item = HitTestItem {
+                        point_in_viewport: *cursor_relative_to_dom,
+                        point_relative_to_item: relative_to_item,
+                        is_iframe_hit: layout_result.iframe_mapping.get(&node_id).map(|iframe_dom_id| {
+                            (*iframe_dom_id, relative_to_item)
+                        }),
+                        is_focusable: node_data.get(node_id).map(|n| n.get_tab_index().is_some()).unwrap_or(false),
+                    };
+
+                    if let Some(i) = item.is_iframe_hit.as_ref() {
+                        new_dom_ids.push(*i);
+                    }
+
+                    if item.is_focusable {
+                        ret.focused_node = Some((*dom_id, node_id));
+                    }
+
+                    // see fullhittest_new_webrender: a hit node is ALWAYS inserted
+                    // into the regular_hit_test_nodes, and ADDITIONALLY into the
+                    // scroll_hit_test_nodes if it is a scroll node
+                    if let Some(scroll_node) = overflowing_nodes.get(&tag_mapping.node_id) {
+                        ret.hovered_nodes
+                        .entry(*dom_id)
+                        .or_insert_with(|| HitTest::empty())
+                        .scroll_hit_test_nodes
+                        .insert(node_id, ScrollHitTestItem {
+                            point_in_viewport: item.point_in_viewport,
+                            point_relative_to_item: item.point_relative_to_item,
+                            scroll_node: scroll_node.clone(),
+                        });
+                    }
+
+                    ret.hovered_nodes
+                    .entry(*dom_id)
+                    .or_insert_with(|| HitTest::empty())
+                    .regular_hit_test_nodes
+                    .insert(node_id, item);
+                }
+            }
+
+            if new_dom_ids.is_empty() {
+                break;
+            } else {
+                dom_ids = new_dom_ids;
+            }
+        }
+
+        ret
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -671,6 +831,49 @@ impl CursorTypeHitTest {
         }
     }
 }
+/// The layout of the last frame can only be re-used if no nodes were added, removed or
+/// re-ordered and no fonts or images have to be loaded for the changed nodes
+#[cfg(all(feature = "multithreading"))]
+fn can_relayout_incrementally(diff: &crate::diff::StyledDomDiff) -> bool {
+    use azul_css::CssPropertyType;
+    diff.is_same_structure() &&
+    diff.changed_css_properties.values().flat_map(|props| props.iter()).all(|prop| {
+        match prop.current_prop.get_type() {
+            CssPropertyType::FontFamily |
+            CssPropertyType::FontSize |
+            CssPropertyType::BackgroundContent => false,
+            _ => true,
+        }
+    })
+}
+
+/// Replaces the `StyledDom` of a layout result with a `StyledDom` of the same structure,
+/// updating the tag IDs and hashes of the scrollable nodes
+#[cfg(all(feature = "multithreading"))]
+fn replace_styled_dom(layout_result: &mut LayoutResult, styled_dom: crate::styled_dom::StyledDom) {
+
+    use crate::dom::ScrollTagId;
+
+    let scrollable_nodes = &mut layout_result.scrollable_nodes;
+    scrollable_nodes.tags_to_node_ids.clear();
+
+    for (node_id, overflowing_node) in scrollable_nodes.overflowing_nodes.iter_mut() {
+        let nid = match node_id.into_crate_internal() {
+            Some(s) => s,
+            None => continue,
+        };
+        if let Some(tag_id) = styled_dom.styled_nodes.as_ref()[nid.index()].tag_id.into_option() {
+            overflowing_node.scroll_tag_id = ScrollTagId(tag_id.into_crate_internal());
+        }
+        let dom_hash = styled_dom.node_data.as_ref()[nid.index()].calculate_node_data_hash();
+        overflowing_node.parent_dom_hash = dom_hash;
+        overflowing_node.parent_external_scroll_id = ExternalScrollId(dom_hash.0, overflowing_node.parent_external_scroll_id.1);
+        scrollable_nodes.tags_to_node_ids.insert(overflowing_node.scroll_tag_id, *node_id);
+    }
+
+    layout_result.styled_dom = styled_dom;
+}
+
 pub struct WindowInternalInit {
     pub window_create_options: WindowCreateOptions,
     pub document_id: DocumentId,
@@ -727,6 +930,7 @@ impl WindowInternal {
             /*window_state: */ &init.window_create_options.state,
             /*dropped_file: */ None,
             /*hovered_file: */ None,
+            /*drag_state: */ None,
             /*focused_node: */ None,
             /*last_hit_test: */ FullHitTest::empty(/*current_focus*/ None),
         );
@@ -757,7 +961,11 @@ impl WindowInternal {
             current_window_state.mouse_state.mouse_down()
         );
 
-        let _ = StyleAndLayoutChanges::new(
+        // if the first frame already contains a focus trap, focus the first node in the trap
+        let mut focus_traps = Vec::new();
+        let new_focus = crate::focus::update_focus_traps(&layout_results, &mut focus_traps, None);
+
+        let sl = StyleAndLayoutChanges::new(
             &nodes_to_check,
             &mut layout_results,
             &image_cache,
@@ -766,10 +974,14 @@ impl WindowInternal {
             &init.document_id,
             Some(&BTreeMap::new()),
             Some(&BTreeMap::new()),
-            &None,
+            &new_focus,
             relayout_fn,
         );
 
+        if let Some(focus_change) = sl.focus_change {
+            current_window_state.focused_node = focus_change.new;
+        }
+
         let gl_texture_cache = GlTextureCache::new(
             &mut layout_results,
             gl_context,
@@ -797,6 +1009,7 @@ impl WindowInternal {
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
             scroll_states,
+            focus_traps,
         }
     }
 
@@ -846,32 +1059,93 @@ impl WindowInternal {
             enable_autotab: self.current_window_state.flags.autotab_enabled,
         });
 
-        let SolvedLayout {
-            mut layout_results,
-        } = SolvedLayout::new(
-            styled_dom,
-            self.epoch,
-            &self.document_id,
-            &self.current_window_state,
-            all_resource_updates,
-            id_namespace,
-            image_cache,
-            &fc_cache_real,
-            callbacks,
-            &mut self.renderer_resources,
-        );
+        // match the new nodes against the nodes of the last frame, so that
+        // the hover / focus state and the scroll positions are preserved
+        let diff = self.layout_results.get(0).map(|old_layout_result| {
+            let diff = old_layout_result.styled_dom.diff(&styled_dom);
+            diff.transfer_node_states(&old_layout_result.styled_dom, &mut styled_dom);
+            diff
+        });
+
+        let old_scroll_ids = self.layout_results.get(0).map(|old_layout_result| {
+            old_layout_result.scrollable_nodes.overflowing_nodes.iter()
+            .filter_map(|(node_id, overflowing_node)| Some((node_id.into_crate_internal()?, overflowing_node.parent_external_scroll_id)))
+            .collect::<BTreeMap<_, _>>()
+        }).unwrap_or_default();
+
+        let old_roving_focus = self.layout_results.get(0).map(|old_layout_result| {
+            old_layout_result.roving_focus.clone()
+        }).unwrap_or_default();
+
+        let mut layout_results = match diff.as_ref() {
+            Some(diff) if self.layout_results.len() == 1 && can_relayout_incrementally(diff) => {
+
+                // same tree structure: re-use the layout of the last frame
+                // and only re-layout the nodes that changed
+                let mut layout_results = core::mem::replace(&mut self.layout_results, Vec::new());
+                replace_styled_dom(&mut layout_results[0], styled_dom);
+
+                let _ = (relayout_fn)(
+                    DomId::ROOT_ID,
+                    LayoutRect::new(LayoutPoint::zero(), self.current_window_state.size.get_layout_size()),
+                    &mut layout_results[0],
+                    image_cache,
+                    &mut self.renderer_resources,
+                    &self.document_id,
+                    if diff.changed_css_properties.is_empty() { None } else { Some(&diff.changed_css_properties) },
+                    if diff.changed_texts.is_empty() { None } else { Some(&diff.changed_texts) },
+                );
+
+                layout_results
+            },
+            _ => {
+                let SolvedLayout { layout_results } = SolvedLayout::new(
+                    styled_dom,
+                    self.epoch,
+                    &self.document_id,
+                    &self.current_window_state,
+                    all_resource_updates,
+                    id_namespace,
+                    image_cache,
+                    &fc_cache_real,
+                    callbacks,
+                    &mut self.renderer_resources,
+                );
+                layout_results
+            }
+        };
+
+        if let Some(diff) = diff.as_ref() {
+            self.apply_styled_dom_diff(diff, &old_scroll_ids, &layout_results);
+            if let Some(root) = layout_results.get_mut(0) {
+                root.roving_focus = old_roving_focus.iter().filter_map(|(group_id, item)| {
+                    Some((diff.get_new_node_id(*group_id)?, diff.get_new_node_id(*item)?))
+                }).collect();
+            }
+        }
 
         // apply the changes for the first frame
         let ht = hit_test_func(&self.current_window_state, &self.scroll_states, &layout_results);
         self.current_window_state.last_hit_test = ht.clone();
 
         // hit_test
-        let nodes_to_check = NodesToCheck::simulated_mouse_move(
+        let mut nodes_to_check = NodesToCheck::simulated_mouse_move(
             &ht,
             self.current_window_state.focused_node,
             self.current_window_state.mouse_state.mouse_down()
         );
 
+        // the new DOM has to be restyled if a drag is in progress (:drag-over)
+        nodes_to_check.current_window_state_is_dragging = self.current_window_state.is_dragging();
+
+        // move the focus into a focus trap that appeared or restore
+        // the focus of a focus trap that was removed from the DOM
+        let new_focus = crate::focus::update_focus_traps(
+            &layout_results,
+            &mut self.focus_traps,
+            self.current_window_state.focused_node,
+        );
+
         let sl = StyleAndLayoutChanges::new(
             &nodes_to_check,
             &mut layout_results,
@@ -881,10 +1155,14 @@ impl WindowInternal {
             &self.document_id,
             Some(&BTreeMap::new()),
             Some(&BTreeMap::new()),
-            &None,
+            &new_focus,
             relayout_fn,
         );
 
+        if let Some(focus_change) = sl.focus_change.as_ref() {
+            self.current_window_state.focused_node = focus_change.new;
+        }
+
         let gl_texture_cache = GlTextureCache::new(
             &mut layout_results,
             gl_context,
@@ -906,6 +1184,63 @@ impl WindowInternal {
         self.epoch.0 += 1;
     }
 
+    /// Moves the focus, the scroll positions and the timers attached to nodes of
+    /// the root DOM from the old node IDs to the new node IDs (or removes them if
+    /// the node was removed)
+    #[cfg(all(feature = "multithreading"))]
+    fn apply_styled_dom_diff(
+        &mut self,
+        diff: &crate::diff::StyledDomDiff,
+        old_scroll_ids: &BTreeMap<NodeId, ExternalScrollId>,
+        new_layout_results: &[LayoutResult],
+    ) {
+
+        let map_dom_node_id = |dom_node_id: DomNodeId| -> Option<DomNodeId> {
+            if dom_node_id.dom != DomId::ROOT_ID {
+                return None;
+            }
+            let new_node_id = diff.get_new_node_id(dom_node_id.node.into_crate_internal()?)?;
+            Some(DomNodeId { dom: DomId::ROOT_ID, node: AzNodeId::from_crate_internal(Some(new_node_id)) })
+        };
+
+        self.current_window_state.focused_node = self.current_window_state.focused_node.and_then(map_dom_node_id);
+
+        // removed focus traps are kept with an invalid node ID,
+        // so that their focus can be restored in update_focus_traps
+        for focus_trap in self.focus_traps.iter_mut().filter(|t| t.trap.dom == DomId::ROOT_ID) {
+            focus_trap.trap = map_dom_node_id(focus_trap.trap).unwrap_or(DomNodeId { dom: focus_trap.trap.dom, node: AzNodeId::NONE });
+            focus_trap.restore_focus = focus_trap.restore_focus.and_then(map_dom_node_id);
+        }
+
+        let mut timers_to_remove = Vec::new();
+        for (timer_id, timer) in self.timers.iter_mut() {
+            if let Some(node_id) = timer.node_id.into_option() {
+                match map_dom_node_id(node_id) {
+                    Some(new_node_id) => { timer.node_id = Some(new_node_id).into(); },
+                    None => { timers_to_remove.push(*timer_id); },
+                }
+            }
+        }
+        for timer_id in timers_to_remove {
+            self.timers.remove(&timer_id);
+        }
+
+        let new_overflowing_nodes = match new_layout_results.get(0) {
+            Some(s) => &s.scrollable_nodes.overflowing_nodes,
+            None => return,
+        };
+
+        let scroll_states = &mut self.scroll_states;
+        let moved_scroll_states = old_scroll_ids.iter().filter_map(|(old_node_id, old_scroll_id)| {
+            let new_node_id = diff.get_new_node_id(*old_node_id)?;
+            let new_scroll_id = new_overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(new_node_id)))?.parent_external_scroll_id;
+            let scroll_state = scroll_states.0.remove(old_scroll_id)?;
+            Some((new_scroll_id, scroll_state))
+        }).collect::<Vec<_>>();
+
+        scroll_states.0.extend(moved_scroll_states);
+    }
+
     /// Returns a copy of the current scroll states + scroll positions
     pub fn get_current_scroll_states(&self) -> BTreeMap<DomId, BTreeMap<AzNodeId, ScrollPosition>> {
         self.layout_results
@@ -1017,6 +1352,12 @@ impl WindowInternal {
         context_menu
     }
 
+    /// Returns all keyboard shortcuts that are shadowed by another
+    /// shortcut with the same key combination, see `crate::shortcuts`
+    pub fn get_shortcut_conflicts(&self) -> Vec<crate::shortcuts::ShortcutConflict> {
+        crate::shortcuts::get_shortcut_conflicts(&self.layout_results)
+    }
+
     /// Runs a single timer, similar to CallbacksOfHitTest.call()
     ///
     /// NOTE: The timer has to be selected first by the calling code and verified
@@ -1051,6 +1392,7 @@ impl WindowInternal {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            drag_started: None,
             cursor_changed: false,
         };
 
@@ -1068,6 +1410,7 @@ impl WindowInternal {
 
         let mut should_terminate = TerminateTimer::Continue;
         let mut new_focus_target = None;
+        let mut drag_started = None; // start_drag() only has an effect in DragStart callbacks
 
         let current_scroll_states = self.get_current_scroll_states();
 
@@ -1115,6 +1458,7 @@ impl WindowInternal {
                 &mut datasets.1,
                 &mut stop_propagation,
                 &mut new_focus_target,
+                &mut drag_started,
                 &mut ret_words_changed,
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
@@ -1196,6 +1540,7 @@ impl WindowInternal {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            drag_started: None,
             cursor_changed: false,
         };
 
@@ -1211,6 +1556,7 @@ impl WindowInternal {
         let mut ret_css_properties_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
+        let mut drag_started = None; // start_drag() only has an effect in DragStart callbacks
         let mut stop_propagation = false;
         let current_scroll_states = self.get_current_scroll_states();
 
@@ -1276,6 +1622,7 @@ impl WindowInternal {
                 &mut datasets.1,
                 &mut stop_propagation,
                 &mut new_focus_target,
+                &mut drag_started,
                 &mut ret_words_changed,
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
@@ -1317,6 +1664,37 @@ impl WindowInternal {
         return ret;
     }
 
+    /// Invokes the keyboard shortcut that matches the current keyboard state (menu
+    /// accelerators or shortcuts of the focused node, see `crate::shortcuts`).
+    ///
+    /// Should only be called on a `VirtualKeyDown` event. Returns `None` if no shortcut
+    /// matched, in which case the key press is handled by the regular callbacks.
+    pub fn invoke_shortcut(
+        &mut self,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> Option<CallCallbacksResult> {
+
+        let mut shortcut = crate::shortcuts::get_matching_shortcut(
+            &self.layout_results,
+            self.current_window_state.focused_node,
+            &self.current_window_state.keyboard_state,
+        )?;
+
+        Some(self.invoke_menu_callback(
+            &mut shortcut.callback,
+            shortcut.hit_dom_node,
+            current_window_handle,
+            gl_context,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+        ))
+    }
+
     pub fn invoke_menu_callback(
         &mut self,
         menu_callback: &mut MenuCallback,
@@ -1345,6 +1723,7 @@ impl WindowInternal {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            drag_started: None,
             cursor_changed: false,
         };
 
@@ -1360,6 +1739,7 @@ impl WindowInternal {
         let mut ret_css_properties_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
+        let mut drag_started = None; // start_drag() only has an effect in DragStart callbacks
         let mut stop_propagation = false;
         let current_scroll_states = self.get_current_scroll_states();
 
@@ -1399,6 +1779,7 @@ impl WindowInternal {
             &mut datasets.1,
             &mut stop_propagation,
             &mut new_focus_target,
+            &mut drag_started,
             &mut ret_words_changed,
             &mut ret_images_changed,
             &mut ret_image_masks_changed,
@@ -1589,6 +1970,64 @@ impl Default for ImePosition {
     }
 }
 
+/// Distance (in logical pixels) that the cursor has to move while the left mouse
+/// button is held down before a `DragStart` event is emitted
+pub const DRAG_THRESHOLD: f32 = 4.0;
+
+/// Payload of an in-application drag-and-drop operation, see `CallbackInfo::start_drag`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DragData {
+    /// Node whose `DragStart` callback accepted the drag
+    pub source: DomNodeId,
+    /// User-defined data that is carried over to the drop target
+    pub payload: RefAny,
+    /// Image that is drawn under the cursor while dragging
+    pub ghost: OptionImageRef,
+}
+
+impl_option!(DragData, OptionDragData, copy = false, [Debug, Clone, PartialEq]);
+
+/// State of the in-application drag-and-drop, updated by `FullWindowState::update_drag_state`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DragState {
+    /// The left mouse button is held down, but the cursor hasn't
+    /// moved further than `DRAG_THRESHOLD` yet
+    Pending {
+        start_position: LogicalPosition,
+        /// Nodes under the cursor when the button was pressed, receive the `DragStart` event
+        start_hit_node_ids: BTreeMap<DomId, BTreeMap<NodeId, HitTestItem>>,
+    },
+    /// `DragStart` is being emitted, waiting for a callback to call `CallbackInfo::start_drag`
+    Started {
+        start_hit_node_ids: BTreeMap<DomId, BTreeMap<NodeId, HitTestItem>>,
+    },
+    /// No callback accepted the drag (or it was cancelled with Escape),
+    /// ignore the mouse until the button is released
+    Rejected,
+    /// Drag is in progress
+    Dragging(DragData),
+    /// Drag was released over the current hit-test: emits `Drop` and `DragEnd`
+    Dropped(DragData),
+}
+
+impl DragState {
+    /// Returns the payload of the drag while dragging or dropping
+    pub fn get_drag_data(&self) -> Option<&DragData> {
+        match self {
+            DragState::Dragging(d) | DragState::Dropped(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        match self {
+            DragState::Dragging(_) => true,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FullWindowState {
     /// Theme of this window (dark or light) - can be set / overridden by the user
@@ -1644,6 +2083,8 @@ pub struct FullWindowState {
     pub hovered_file: Option<AzString>, // Option<PathBuf>
     /// Whether there was a file currently dropped on the window
     pub dropped_file: Option<AzString>, // Option<PathBuf>
+    /// In-application drag-and-drop between DOM nodes, `None` if the left mouse button is not held down
+    pub drag_state: Option<DragState>,
     /// What node is currently hovered over, default to None. Only necessary internal
     /// to the crate, for emitting `On::FocusReceived` and `On::FocusLost` events,
     /// as well as styling `:focus` elements
@@ -1677,6 +2118,7 @@ impl Default for FullWindowState {
 
             hovered_file: None,
             dropped_file: None,
+            drag_state: None,
             focused_node: None,
             last_hit_test: FullHitTest::empty(None),
         }
@@ -1705,6 +2147,90 @@ impl FullWindowState {
         self.mouse_state.get_scroll_amount()
     }
 
+    pub fn get_drag_data(&self) -> Option<&DragData> {
+        self.drag_state.as_ref().and_then(|d| d.get_drag_data())
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag_state.as_ref().map(|d| d.is_dragging()).unwrap_or(false)
+    }
+
+    /// Whether the display list has to be rebuilt in order to show, move or hide the drag ghost image
+    pub fn drag_ghost_changed(&self, previous_window_state: &Option<FullWindowState>) -> bool {
+        let previous = match previous_window_state.as_ref() {
+            Some(s) => s,
+            None => return self.get_drag_ghost().is_some(),
+        };
+        match (previous.get_drag_ghost(), self.get_drag_ghost()) {
+            (None, None) => false,
+            (Some(_), Some(_)) => previous.mouse_state.cursor_position != self.mouse_state.cursor_position,
+            _ => true,
+        }
+    }
+
+    /// Returns the ghost image of the current drag, if any
+    pub fn get_drag_ghost(&self) -> Option<&ImageRef> {
+        match self.drag_state.as_ref()? {
+            DragState::Dragging(d) => d.ghost.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Advances the drag-and-drop state machine, has to be called after
+    /// the hit-test and before `Events::new()`
+    ///
+    /// The `DragStart` / `DragOver` / `Drop` / `DragEnd` events are then
+    /// generated by diffing the `drag_state` with the previous window state.
+    pub fn update_drag_state(&mut self, previous_window_state: &Option<FullWindowState>) {
+
+        let previous_left_down = previous_window_state.as_ref().map(|p| p.mouse_state.left_down).unwrap_or(false);
+        let current_position = self.mouse_state.cursor_position.get_position();
+        let escape_pressed = self.keyboard_state.current_virtual_keycode.into_option() == Some(VirtualKeyCode::Escape);
+
+        if !self.mouse_state.left_down {
+            self.drag_state = match self.drag_state.take() {
+                Some(DragState::Dragging(d)) => Some(DragState::Dropped(d)),
+                _ => None,
+            };
+            return;
+        }
+
+        self.drag_state = match self.drag_state.take() {
+            None => {
+                match current_position {
+                    Some(start_position) if !previous_left_down => Some(DragState::Pending {
+                        start_position,
+                        start_hit_node_ids: self.last_hit_test.hovered_nodes.iter()
+                            .map(|(dom_id, ht)| (*dom_id, ht.regular_hit_test_nodes.clone()))
+                            .collect(),
+                    }),
+                    _ => None,
+                }
+            },
+            Some(DragState::Pending { start_position, start_hit_node_ids }) => {
+                let moved_past_threshold = current_position.map(|p| {
+                    let (dx, dy) = (p.x - start_position.x, p.y - start_position.y);
+                    dx * dx + dy * dy > DRAG_THRESHOLD * DRAG_THRESHOLD
+                }).unwrap_or(false);
+                if moved_past_threshold {
+                    Some(DragState::Started { start_hit_node_ids })
+                } else {
+                    Some(DragState::Pending { start_position, start_hit_node_ids })
+                }
+            },
+            // no DragStart callback called CallbackInfo::start_drag
+            Some(DragState::Started { .. }) => Some(DragState::Rejected),
+            Some(DragState::Dragging(d)) => {
+                if escape_pressed {
+                    Some(DragState::Rejected)
+                } else {
+                    Some(DragState::Dragging(d))
+                }
+            },
+            Some(DragState::Rejected) | Some(DragState::Dropped(_)) => Some(DragState::Rejected),
+        };
+    }
+
     pub fn layout_callback_changed(&self, other: &Option<Self>) -> bool {
         match other {
             Some(s) => self.layout_callback != s.layout_callback,
@@ -1721,6 +2247,7 @@ impl FullWindowState {
         window_state: &WindowState,
         dropped_file: Option<AzString>,
         hovered_file: Option<AzString>,
+        drag_state: Option<DragState>,
         focused_node: Option<DomNodeId>,
         last_hit_test: FullHitTest,
     ) -> Self {
@@ -1743,6 +2270,7 @@ impl FullWindowState {
             renderer_options: window_state.renderer_options,
             dropped_file,
             hovered_file,
+            drag_state,
             focused_node,
             last_hit_test,
         }
@@ -1802,6 +2330,8 @@ pub struct CallCallbacksResult {
     pub nodes_scrolled_in_callbacks: Option<BTreeMap<DomId, BTreeMap<AzNodeId, LogicalPosition>>>,
     /// Whether the focused node was changed from the callbacks
     pub update_focused_node: Option<Option<DomNodeId>>,
+    /// Set if a `DragStart` callback accepted the drag via `CallbackInfo::start_drag`
+    pub drag_started: Option<DragData>,
     /// Timers that were added in the callbacks
     pub timers: Option<FastHashMap<TimerId, Timer>>,
     /// Tasks that were added in the callbacks
@@ -2273,8 +2803,9 @@ pub struct WindowCreateOptions {
     pub theme: OptionWindowTheme,
     /// Optional callback to run when the window has been created (runs only once on startup)
     pub create_callback: OptionCallback,
-    /// If set to true, will hot-reload the UI every 200ms, useful in combination with `StyledDom::from_file()`
-    /// to hot-reload the UI from a file while developing.
+    /// If set to true, will hot-reload the UI every time one of the XML / CSS files loaded
+    /// via `StyledDom::from_file()` changes (checked every 200ms), useful to iterate on the
+    /// UI while developing. Scroll positions and the window state are preserved.
     pub hot_reload: bool,
 }
 
@@ -2684,173 +3215,205 @@ impl AcceleratorKey {
     }
 }
 
-/// Symbolic name for a keyboard key, does NOT take the keyboard locale into account
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(C)]
-pub enum VirtualKeyCode {
-    Key1,
-    Key2,
-    Key3,
-    Key4,
-    Key5,
-    Key6,
-    Key7,
-    Key8,
-    Key9,
-    Key0,
-    A,
-    B,
-    C,
-    D,
-    E,
-    F,
-    G,
-    H,
-    I,
-    J,
-    K,
-    L,
-    M,
-    N,
-    O,
-    P,
-    Q,
-    R,
-    S,
-    T,
-    U,
-    V,
-    W,
-    X,
-    Y,
-    Z,
-    Escape,
-    F1,
-    F2,
-    F3,
-    F4,
-    F5,
-    F6,
-    F7,
-    F8,
-    F9,
-    F10,
-    F11,
-    F12,
-    F13,
-    F14,
-    F15,
-    F16,
-    F17,
-    F18,
-    F19,
-    F20,
-    F21,
-    F22,
-    F23,
-    F24,
-    Snapshot,
-    Scroll,
-    Pause,
-    Insert,
-    Home,
-    Delete,
-    End,
-    PageDown,
-    PageUp,
-    Left,
-    Up,
-    Right,
-    Down,
-    Back,
-    Return,
-    Space,
-    Compose,
-    Caret,
-    Numlock,
-    Numpad0,
-    Numpad1,
-    Numpad2,
-    Numpad3,
-    Numpad4,
-    Numpad5,
-    Numpad6,
-    Numpad7,
-    Numpad8,
-    Numpad9,
-    NumpadAdd,
-    NumpadDivide,
-    NumpadDecimal,
-    NumpadComma,
-    NumpadEnter,
-    NumpadEquals,
-    NumpadMultiply,
-    NumpadSubtract,
-    AbntC1,
-    AbntC2,
-    Apostrophe,
-    Apps,
-    Asterisk,
-    At,
-    Ax,
-    Backslash,
-    Calculator,
-    Capital,
-    Colon,
-    Comma,
-    Convert,
-    Equals,
-    Grave,
-    Kana,
-    Kanji,
-    LAlt,
-    LBracket,
-    LControl,
-    LShift,
-    LWin,
-    Mail,
-    MediaSelect,
-    MediaStop,
-    Minus,
-    Mute,
-    MyComputer,
-    NavigateForward,
-    NavigateBackward,
-    NextTrack,
-    NoConvert,
-    OEM102,
-    Period,
-    PlayPause,
-    Plus,
-    Power,
-    PrevTrack,
-    RAlt,
-    RBracket,
-    RControl,
-    RShift,
-    RWin,
-    Semicolon,
-    Slash,
-    Sleep,
-    Stop,
-    Sysrq,
-    Tab,
-    Underline,
-    Unlabeled,
-    VolumeDown,
-    VolumeUp,
-    Wake,
-    WebBack,
-    WebFavorites,
-    WebForward,
-    WebHome,
-    WebRefresh,
-    WebSearch,
-    WebStop,
-    Yen,
-    Copy,
-    Paste,
-    Cut,
+/// Declares the `VirtualKeyCode` enum together with `VirtualKeyCode::ALL`
+/// and `VirtualKeyCode::get_name`, so that the variant list only exists once
+macro_rules! impl_virtual_key_codes {(
+    $(#[$attr:meta])*
+    pub enum $enum_name:ident { $($variant:ident,)* }
+) => {
+    $(#[$attr])*
+    pub enum $enum_name {
+        $($variant,)*
+    }
+
+    impl $enum_name {
+
+        /// All virtual key codes, in declaration order
+        pub const ALL: &'static [$enum_name] = &[$($enum_name::$variant,)*];
+
+        /// Parses the name of the key as written in the enum (i.e. `"Key1"`, `"LShift"`)
+        pub fn from_name(name: &str) -> Option<Self> {
+            Self::ALL.iter().find(|k| k.get_name() == name).copied()
+        }
+
+        /// Returns the name of the key as written in the enum, inverse of `from_name`
+        pub fn get_name(&self) -> &'static str {
+            match self {
+                $($enum_name::$variant => stringify!($variant),)*
+            }
+        }
+    }
+}}
+
+impl_virtual_key_codes! {
+    /// Symbolic name for a keyboard key, does NOT take the keyboard locale into account
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[repr(C)]
+    pub enum VirtualKeyCode {
+        Key1,
+        Key2,
+        Key3,
+        Key4,
+        Key5,
+        Key6,
+        Key7,
+        Key8,
+        Key9,
+        Key0,
+        A,
+        B,
+        C,
+        D,
+        E,
+        F,
+        G,
+        H,
+        I,
+        J,
+        K,
+        L,
+        M,
+        N,
+        O,
+        P,
+        Q,
+        R,
+        S,
+        T,
+        U,
+        V,
+        W,
+        X,
+        Y,
+        Z,
+        Escape,
+        F1,
+        F2,
+        F3,
+        F4,
+        F5,
+        F6,
+        F7,
+        F8,
+        F9,
+        F10,
+        F11,
+        F12,
+        F13,
+        F14,
+        F15,
+        F16,
+        F17,
+        F18,
+        F19,
+        F20,
+        F21,
+        F22,
+        F23,
+        F24,
+        Snapshot,
+        Scroll,
+        Pause,
+        Insert,
+        Home,
+        Delete,
+        End,
+        PageDown,
+        PageUp,
+        Left,
+        Up,
+        Right,
+        Down,
+        Back,
+        Return,
+        Space,
+        Compose,
+        Caret,
+        Numlock,
+        Numpad0,
+        Numpad1,
+        Numpad2,
+        Numpad3,
+        Numpad4,
+        Numpad5,
+        Numpad6,
+        Numpad7,
+        Numpad8,
+        Numpad9,
+        NumpadAdd,
+        NumpadDivide,
+        NumpadDecimal,
+        NumpadComma,
+        NumpadEnter,
+        NumpadEquals,
+        NumpadMultiply,
+        NumpadSubtract,
+        AbntC1,
+        AbntC2,
+        Apostrophe,
+        Apps,
+        Asterisk,
+        At,
+        Ax,
+        Backslash,
+        Calculator,
+        Capital,
+        Colon,
+        Comma,
+        Convert,
+        Equals,
+        Grave,
+        Kana,
+        Kanji,
+        LAlt,
+        LBracket,
+        LControl,
+        LShift,
+        LWin,
+        Mail,
+        MediaSelect,
+        MediaStop,
+        Minus,
+        Mute,
+        MyComputer,
+        NavigateForward,
+        NavigateBackward,
+        NextTrack,
+        NoConvert,
+        OEM102,
+        Period,
+        PlayPause,
+        Plus,
+        Power,
+        PrevTrack,
+        RAlt,
+        RBracket,
+        RControl,
+        RShift,
+        RWin,
+        Semicolon,
+        Slash,
+        Sleep,
+        Stop,
+        Sysrq,
+        Tab,
+        Underline,
+        Unlabeled,
+        VolumeDown,
+        VolumeUp,
+        Wake,
+        WebBack,
+        WebFavorites,
+        WebForward,
+        WebHome,
+        WebRefresh,
+        WebSearch,
+        WebStop,
+        Yen,
+        Copy,
+        Paste,
+        Cut,
+    }
 }
 
 /// 16x16x4 bytes icon
@@ -3085,6 +3648,68 @@ pub struct VirtualKeyCodeCombo {
 
 impl_option!(VirtualKeyCodeCombo, OptionVirtualKeyCodeCombo, copy = false, [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]);
 
+/// State of the Ctrl / Alt / Shift / Super modifiers, without differentiating
+/// between the left and right modifier keys
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct KeyboardModifiers {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub super_key: bool,
+}
+
+impl KeyboardModifiers {
+    pub fn from_keyboard_state(keyboard_state: &KeyboardState) -> Self {
+        Self {
+            ctrl: keyboard_state.ctrl_down,
+            alt: keyboard_state.alt_down,
+            shift: keyboard_state.shift_down,
+            super_key: keyboard_state.super_down,
+        }
+    }
+}
+
+impl VirtualKeyCodeCombo {
+
+    /// Splits the combo into the modifiers and the (sorted, deduplicated) regular keys,
+    /// so that `[LControl, S]` and `[S, RControl]` are considered to be the same combo
+    pub fn normalize(&self) -> (KeyboardModifiers, Vec<VirtualKeyCode>) {
+        use self::VirtualKeyCode::*;
+        let mut modifiers = KeyboardModifiers::default();
+        let mut keys = Vec::new();
+        for key in self.keys.iter() {
+            match key {
+                LControl | RControl => { modifiers.ctrl = true; },
+                LAlt | RAlt => { modifiers.alt = true; },
+                LShift | RShift => { modifiers.shift = true; },
+                LWin | RWin => { modifiers.super_key = true; },
+                other => { keys.push(*other); },
+            }
+        }
+        keys.sort();
+        keys.dedup();
+        (modifiers, keys)
+    }
+
+    /// Returns whether the combo was just pressed: the last pressed key has to be one
+    /// of the (non-modifier) keys of the combo, all other keys have to be held down and
+    /// the modifiers have to match exactly, so that `Ctrl + S` doesn't fire on `Ctrl + Shift + S`
+    pub fn matches(&self, keyboard_state: &KeyboardState) -> bool {
+
+        let current_key = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let (modifiers, keys) = self.normalize();
+
+        !keys.is_empty() &&
+        keys.contains(&current_key) &&
+        keys.iter().all(|k| keyboard_state.pressed_virtual_keycodes.iter().any(|p| p == k)) &&
+        modifiers == KeyboardModifiers::from_keyboard_state(keyboard_state)
+    }
+}
+
 /// Menu callback: What data / function pointer should
 /// be called when the menu item is clicked?
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -3096,6 +3721,15 @@ pub struct MenuCallback {
 
 impl_option!(MenuCallback, OptionMenuCallback, copy = false, [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]);
 
+/// Keyboard shortcut that is only active while the focus is inside of
+/// the node it is attached to, see `NodeData::add_shortcut`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct KeyboardShortcut {
+    pub combo: VirtualKeyCodeCombo,
+    pub callback: MenuCallback,
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 #[repr(C, u8)]
 pub enum MenuItemIcon {
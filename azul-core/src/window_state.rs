@@ -76,7 +76,7 @@ use crate::{
     styled_dom::{DomId, ChangedCssProperty, AzNodeId},
     ui_solver::{LayoutResult, RelayoutChanges, GpuEventChanges},
     task::ExternalSystemCallbacks,
-    window::{FullHitTest, RawWindowHandle, FullWindowState, ScrollStates, CallCallbacksResult},
+    window::{FullHitTest, RawWindowHandle, FullWindowState, ScrollStates, CallCallbacksResult, DragState},
 };
 use azul_css::{
     AzString, LayoutSize, CssProperty,
@@ -97,6 +97,12 @@ pub struct Events {
     pub event_was_mouse_down: bool,
     pub event_was_mouse_leave: bool,
     pub event_was_mouse_release: bool,
+    /// Nodes that were hit when the drag was started, receive the `DragStart` event
+    pub drag_start_node_ids: BTreeMap<DomId, BTreeMap<NodeId, HitTestItem>>,
+    /// Source node of the drag that ended in this event, receives the `DragEnd` event
+    pub drag_end_source: Option<DomNodeId>,
+    pub current_window_state_is_dragging: bool,
+    pub previous_window_state_is_dragging: bool,
 }
 
 impl Events {
@@ -114,6 +120,20 @@ impl Events {
         let current_window_state_mouse_is_down = current_window_state.mouse_state.mouse_down();
         let previous_window_state_mouse_is_down = previous_window_state.as_ref().map(|f| f.mouse_state.mouse_down()).unwrap_or(false);
 
+        let current_window_state_is_dragging = current_window_state.is_dragging();
+        let previous_window_state_is_dragging = previous_window_state.as_ref().map(|f| f.is_dragging()).unwrap_or(false);
+
+        let drag_start_node_ids = match current_window_state.drag_state.as_ref() {
+            Some(DragState::Started { start_hit_node_ids }) if current_window_events.contains(&WindowEventFilter::DragStart) => start_hit_node_ids.clone(),
+            _ => BTreeMap::new(),
+        };
+
+        let drag_end_source = if current_window_events.contains(&WindowEventFilter::DragEnd) {
+            previous_window_state.as_ref().and_then(|f| f.get_drag_data()).map(|d| d.source)
+        } else {
+            None
+        };
+
         let old_focus_node = previous_window_state.as_ref().and_then(|f| f.focused_node.clone());
         let old_hit_node_ids = previous_window_state.as_ref().map(|f| {
             if f.last_hit_test.hovered_nodes.is_empty() {
@@ -130,6 +150,10 @@ impl Events {
             if current_window_state.last_hit_test.hovered_nodes != prev_state.last_hit_test.hovered_nodes.clone() {
                 current_hover_events.push(HoverEventFilter::MouseLeave);
                 current_hover_events.push(HoverEventFilter::MouseEnter);
+                if current_window_state_is_dragging && previous_window_state_is_dragging {
+                    current_hover_events.push(HoverEventFilter::DragLeave);
+                    current_hover_events.push(HoverEventFilter::DragEnter);
+                }
             }
         }
 
@@ -150,6 +174,10 @@ impl Events {
             previous_window_state_mouse_is_down,
             old_focus_node,
             old_hit_node_ids,
+            drag_start_node_ids,
+            drag_end_source,
+            current_window_state_is_dragging,
+            previous_window_state_is_dragging,
         }
     }
 
@@ -181,6 +209,9 @@ pub struct NodesToCheck {
     pub old_focus_node: Option<DomNodeId>,
     pub new_focus_node: Option<DomNodeId>,
     pub current_window_state_mouse_is_down: bool,
+    /// Whether a drag is in progress (used for restyling `:drag-over` nodes)
+    pub current_window_state_is_dragging: bool,
+    pub previous_window_state_is_dragging: bool,
 }
 
 impl NodesToCheck {
@@ -201,6 +232,8 @@ impl NodesToCheck {
             old_focus_node: old_focus_node,
             new_focus_node: old_focus_node,
             current_window_state_mouse_is_down: mouse_down,
+            current_window_state_is_dragging: false,
+            previous_window_state_is_dragging: false,
         }
     }
 
@@ -253,6 +286,8 @@ impl NodesToCheck {
             old_focus_node: events.old_focus_node.clone(),
             new_focus_node: new_focus_node,
             current_window_state_mouse_is_down: events.current_window_state_mouse_is_down,
+            current_window_state_is_dragging: events.current_window_state_is_dragging,
+            previous_window_state_is_dragging: events.previous_window_state_is_dragging,
         }
     }
 
@@ -265,6 +300,8 @@ impl NodesToCheck {
             old_focus_node: old_focus_node,
             new_focus_node: old_focus_node,
             current_window_state_mouse_is_down: mouse_down,
+            current_window_state_is_dragging: false,
+            previous_window_state_is_dragging: false,
         }
     }
 
@@ -394,15 +431,53 @@ impl StyleAndLayoutChanges {
             insert_props!(*dom_id,onmouseleave_nodes_active_restyle_props);
         }
 
+        // :drag-over matches all hovered nodes while a drag is in progress
+        let (drag_over_enter_nodes, drag_over_leave_nodes) = match (
+            nodes.previous_window_state_is_dragging,
+            nodes.current_window_state_is_dragging
+        ) {
+            (false, false) => (None, None),
+            (false, true) => (Some(&nodes.new_hit_node_ids), None),
+            (true, false) => (None, Some(&nodes.old_hit_node_ids)),
+            (true, true) => (Some(&nodes.onmouseenter_nodes), Some(&nodes.onmouseleave_nodes)),
+        };
+
+        for (drag_over_nodes, currently_drag_over) in [(drag_over_leave_nodes, false), (drag_over_enter_nodes, true)].iter() {
+            for (dom_id, drag_over_nodes) in drag_over_nodes.iter().flat_map(|m| m.iter()) {
+                let layout_result = &mut layout_results[dom_id.inner];
+                let keys = drag_over_nodes.keys().copied().collect::<Vec<_>>();
+                let drag_over_restyle_props = layout_result.styled_dom.restyle_nodes_drag_over(&keys, *currently_drag_over);
+                insert_props!(*dom_id, drag_over_restyle_props);
+            }
+        }
+
         let new_focus_node = if let Some(new) = callbacks_new_focus.as_ref() { new } else { &nodes.new_focus_node };
 
+        // focus can't leave the active focus trap
+        let new_focus_node = &crate::focus::constrain_focus(layout_results, nodes.old_focus_node, *new_focus_node);
+
+        // :focus-visible only matches if the focus was set with
+        // the keyboard or from a callback, not by clicking on the node
+        let focus_is_visible = callbacks_new_focus.is_some();
+
         let focus_change = if nodes.old_focus_node != *new_focus_node {
+
+            let old_focus_within = nodes.old_focus_node.as_ref().and_then(|DomNodeId { dom, node }| {
+                Some((*dom, crate::focus::get_focus_within_nodes(&layout_results.get(dom.inner)?.styled_dom, node.into_crate_internal()?)))
+            });
+
+            let new_focus_within = new_focus_node.as_ref().and_then(|DomNodeId { dom, node }| {
+                Some((*dom, crate::focus::get_focus_within_nodes(&layout_results.get(dom.inner)?.styled_dom, node.into_crate_internal()?)))
+            });
+
             if let Some(DomNodeId { dom, node }) = nodes.old_focus_node.as_ref() {
                 if let Some(node_id) = node.into_crate_internal() {
                     let layout_result = &mut layout_results[dom.inner];
                     let onfocus_leave_restyle_props = layout_result.styled_dom.restyle_nodes_focus(&[node_id], /* currently_focused = */ false);
                     let dom_id: DomId = *dom;
                     insert_props!(dom_id, onfocus_leave_restyle_props);
+                    let onfocus_visible_leave_restyle_props = layout_result.styled_dom.restyle_nodes_focus_visible(&[node_id], /* currently_focused = */ false);
+                    insert_props!(dom_id, onfocus_visible_leave_restyle_props);
                 }
             }
 
@@ -412,9 +487,34 @@ impl StyleAndLayoutChanges {
                     let onfocus_enter_restyle_props = layout_result.styled_dom.restyle_nodes_focus(&[node_id], /* currently_focused = */ true);
                     let dom_id: DomId = *dom;
                     insert_props!(dom_id, onfocus_enter_restyle_props);
+                    let onfocus_visible_enter_restyle_props = layout_result.styled_dom.restyle_nodes_focus_visible(&[node_id], focus_is_visible);
+                    insert_props!(dom_id, onfocus_visible_enter_restyle_props);
                 }
             }
 
+            // only restyle the parents that are not shared between the old and new focused node
+            if let Some((dom_id, old_focus_within)) = old_focus_within.as_ref() {
+                let nodes_to_restyle = old_focus_within.iter().filter(|n| match new_focus_within.as_ref() {
+                    Some((new_dom_id, new_focus_within)) => new_dom_id != dom_id || !new_focus_within.contains(*n),
+                    None => true,
+                }).copied().collect::<Vec<_>>();
+                let onfocus_within_leave_restyle_props = layout_results[dom_id.inner].styled_dom.restyle_nodes_focus_within(&nodes_to_restyle, false);
+                insert_props!(*dom_id, onfocus_within_leave_restyle_props);
+            }
+
+            if let Some((dom_id, new_focus_within)) = new_focus_within.as_ref() {
+                let nodes_to_restyle = new_focus_within.iter().filter(|n| match old_focus_within.as_ref() {
+                    Some((old_dom_id, old_focus_within)) => old_dom_id != dom_id || !old_focus_within.contains(*n),
+                    None => true,
+                }).copied().collect::<Vec<_>>();
+                let onfocus_within_enter_restyle_props = layout_results[dom_id.inner].styled_dom.restyle_nodes_focus_within(&nodes_to_restyle, true);
+                insert_props!(*dom_id, onfocus_within_enter_restyle_props);
+            }
+
+            if let Some(new_focus_node) = new_focus_node.as_ref() {
+                crate::focus::remember_roving_focus(layout_results, *new_focus_node);
+            }
+
             Some(FocusChange { old: nodes.old_focus_node, new: *new_focus_node })
         } else {
             None
@@ -614,6 +714,10 @@ impl CallbacksOfHitTest {
         let mouseleave_filter = EventFilter::Hover(HoverEventFilter::MouseEnter);
         let focus_received_filter = EventFilter::Focus(FocusEventFilter::FocusReceived);
         let focus_lost_filter = EventFilter::Focus(FocusEventFilter::FocusLost);
+        let dragstart_filter = EventFilter::Hover(HoverEventFilter::DragStart);
+        let dragenter_filter = EventFilter::Hover(HoverEventFilter::DragEnter);
+        let dragleave_filter = EventFilter::Hover(HoverEventFilter::DragLeave);
+        let dragend_filter = EventFilter::Hover(HoverEventFilter::DragEnd);
 
         for (dom_id, layout_result) in layout_results.iter().enumerate() {
 
@@ -681,13 +785,86 @@ impl CallbacksOfHitTest {
                 }
             ));
 
+            // insert Hover::DragEnter and Hover::DragLeave events
+            for (filter, node_map) in [
+                (dragenter_filter, &nodes_to_check.onmouseenter_nodes),
+                (dragleave_filter, &nodes_to_check.onmouseleave_nodes),
+            ].iter() {
+                let hev = match filter.as_hover_event_filter() {
+                    Some(s) => s,
+                    None => continue,
+                };
+                if !events.hover_events.contains(&hev) {
+                    continue;
+                }
+                window_callbacks_this_dom.extend(
+                    node_map
+                    .get(&dom_id)
+                    .unwrap_or(&default_map)
+                    .iter()
+                    .filter_map(|(node_id, ht)| {
+                        if layout_result.styled_dom.node_data.as_container()[*node_id].get_callbacks().iter().any(|e| e.event == *filter) {
+                            Some(CallbackToCall {
+                                event_filter: *filter,
+                                hit_test_item: Some(*ht),
+                                node_id: *node_id,
+                            })
+                        } else {
+                            None
+                        }
+                    }
+                ));
+            }
+
+            // insert Hover::DragStart events: fired on the nodes that were
+            // hit when the mouse button was pressed, not the current nodes
+            window_callbacks_this_dom.extend(
+                events.drag_start_node_ids
+                .get(&dom_id)
+                .unwrap_or(&default_map)
+                .iter()
+                .filter_map(|(node_id, ht)| {
+                    if layout_result.styled_dom.node_data.as_container().get(*node_id)?.get_callbacks().iter().any(|e| e.event == dragstart_filter) {
+                        Some(CallbackToCall {
+                            event_filter: dragstart_filter,
+                            hit_test_item: Some(*ht),
+                            node_id: *node_id,
+                        })
+                    } else {
+                        None
+                    }
+                }
+            ));
+
+            // insert Hover::DragEnd event on the node that started the drag
+            if let Some(DomNodeId { dom, node: az_node_id }) = events.drag_end_source {
+                if dom == dom_id {
+                    if let Some(nid) = az_node_id.into_crate_internal() {
+                        let has_dragend_callback = layout_result.styled_dom.node_data.as_container()
+                            .get(nid)
+                            .map(|n| n.get_callbacks().iter().any(|e| e.event == dragend_filter))
+                            .unwrap_or(false);
+                        if has_dragend_callback {
+                            window_callbacks_this_dom.push(CallbackToCall {
+                                event_filter: dragend_filter,
+                                hit_test_item: events.old_hit_node_ids.get(&dom_id).and_then(|map| map.get(&nid)).cloned(),
+                                node_id: nid,
+                            });
+                        }
+                    }
+                }
+            }
+
             // insert other Hover:: events
             for (nid, ht) in nodes_to_check.new_hit_node_ids.get(&dom_id).unwrap_or(&default_map).iter() {
                 for hev in events.hover_events.iter() {
                     window_callbacks_this_dom.extend(
                         layout_result.styled_dom.node_data.as_container()[*nid].get_callbacks()
                         .iter().filter_map(|e| {
-                            if e.event == EventFilter::Hover(*hev) && e.event != mouseenter_filter && e.event != mouseleave_filter {
+                            if e.event == EventFilter::Hover(*hev) &&
+                               e.event != mouseenter_filter && e.event != mouseleave_filter &&
+                               e.event != dragenter_filter && e.event != dragleave_filter &&
+                               e.event != dragstart_filter {
                                 Some(CallbackToCall {
                                     event_filter: EventFilter::Hover(hev.clone()),
                                     hit_test_item: Some(*ht),
@@ -829,9 +1006,11 @@ impl CallbacksOfHitTest {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            drag_started: None,
             cursor_changed: false,
         };
         let mut new_focus_target = None;
+        let mut ret_drag_started = None;
 
         let current_cursor = full_window_state.mouse_state.mouse_cursor_type.clone();
 
@@ -876,10 +1055,11 @@ impl CallbacksOfHitTest {
 
         for (dom_id, callbacks_filter_list) in self.nodes_with_callbacks.iter() {
 
-            let callbacks = callbacks_filter_list
-            .iter()
-            .map(|cbtc| (cbtc.node_id, (cbtc.hit_test_item, cbtc.event_filter)))
-            .collect::<BTreeMap<_, _>>();
+            // one node can receive multiple events at once (i.e. MouseOver + DragOver)
+            let mut callbacks = BTreeMap::new();
+            for cbtc in callbacks_filter_list.iter() {
+                callbacks.entry(cbtc.node_id).or_insert_with(|| Vec::new()).push((cbtc.hit_test_item, cbtc.event_filter));
+            }
 
             let (
                  root_id,
@@ -903,7 +1083,7 @@ impl CallbacksOfHitTest {
             for ParentWithNodeDepth { depth: _, node_id } in non_leaf_nodes.as_ref().iter().rev() {
                let parent_node_id = node_id;
                for child_id in parent_node_id.into_crate_internal().unwrap().az_children(&node_hierarchy.as_container()) {
-                    if let Some((hit_test_item, event_filter)) = callbacks.get(&child_id) {
+                    for (hit_test_item, event_filter) in callbacks.get(&child_id).into_iter().flatten() {
 
                         if blacklisted_event_types.contains(&*event_filter) {
                             continue;
@@ -937,6 +1117,7 @@ impl CallbacksOfHitTest {
                             /*dataset_map*/ dataset_map,
                             /*stop_propagation:*/ &mut stop_propagation,
                             /*focus_target:*/ &mut new_focus,
+                            /*drag_started:*/ &mut ret_drag_started,
                             /*words_changed_in_callbacks:*/ &mut ret_words_changed,
                             /*images_changed_in_callbacks:*/ &mut ret_images_changed,
                             /*image_masks_changed_in_callbacks:*/ &mut ret_image_masks_changed,
@@ -972,75 +1153,73 @@ impl CallbacksOfHitTest {
             }
 
             // run the callbacks for node ID 0
-            loop {
-                if let Some(((hit_test_item, event_filter), root_id)) = callbacks.get(&root_id).map(|cb| (cb, root_id)) {
+            for (hit_test_item, event_filter) in callbacks.get(&*root_id).into_iter().flatten() {
 
-                    if blacklisted_event_types.contains(&event_filter) {
-                        break; // break out of loop
-                    }
+                if blacklisted_event_types.contains(&event_filter) {
+                    continue;
+                }
 
-                    let mut new_focus = None;
-                    let mut stop_propagation = false;
-
-                    let callback_info = CallbackInfo::new(
-                        /*css_property_cache:*/ &css_property_cache.ptr,
-                        /*styled_node_states:*/ styled_nodes,
-                        /*previous_window_State:*/ &previous_window_state,
-                        /*current_window_state:*/ &full_window_state,
-                        /*modifiable_window_state:*/ &mut ret_modified_window_state,
-                        /*gl_context,*/ gl_context,
-                        /*image_cache,*/ image_cache,
-                        /*system_fonts,*/ system_fonts,
-                        /*timers:*/ &mut ret_timers,
-                        /*threads:*/ &mut ret_threads,
-                        /*timers_removed:*/ &mut ret_timers_removed,
-                        /*threads_removed:*/ &mut ret_threads_removed,
-                        /*new_windows:*/ &mut ret.windows_created,
-                        /*current_window_handle:*/ raw_window_handle,
-                        /*node_hierarchy*/ &node_hierarchy,
-                        /*system_callbacks*/ system_callbacks,
-                        /*words_cache*/ &words_cache,
-                        /*shaped_words_cache*/ &shaped_words_cache,
-                        /*positioned_words_cache*/ &positioned_words_cache,
-                        /*positioned_rects*/ &positioned_rects,
-                        /*font_map*/ &font_map,
-                        /*dataset_map*/ dataset_map,
-                        /*stop_propagation:*/ &mut stop_propagation,
-                        /*focus_target:*/ &mut new_focus,
-                        /*words_changed_in_callbacks:*/ &mut ret_words_changed,
-                        /*images_changed_in_callbacks:*/ &mut ret_images_changed,
-                        /*image_masks_changed_in_callbacks:*/ &mut ret_image_masks_changed,
-                        /*css_properties_changed_in_callbacks:*/ &mut ret_css_properties_changed,
-                        /*current_scroll_states:*/ scroll_states,
-                        /*nodes_scrolled_in_callback:*/ &mut ret_nodes_scrolled_in_callbacks,
-                        /*hit_dom_node:*/ DomNodeId { dom: *dom_id, node: AzNodeId::from_crate_internal(Some(*root_id)) },
-                        /*cursor_relative_to_item:*/ hit_test_item.as_ref().map(|hi|hi.point_relative_to_item).into(),
-                        /*cursor_in_viewport:*/ hit_test_item.as_ref().map(|hi| hi.point_in_viewport).into(),
-                    );
+                let mut new_focus = None;
+                let mut stop_propagation = false;
+
+                let callback_info = CallbackInfo::new(
+                    /*css_property_cache:*/ &css_property_cache.ptr,
+                    /*styled_node_states:*/ styled_nodes,
+                    /*previous_window_State:*/ &previous_window_state,
+                    /*current_window_state:*/ &full_window_state,
+                    /*modifiable_window_state:*/ &mut ret_modified_window_state,
+                    /*gl_context,*/ gl_context,
+                    /*image_cache,*/ image_cache,
+                    /*system_fonts,*/ system_fonts,
+                    /*timers:*/ &mut ret_timers,
+                    /*threads:*/ &mut ret_threads,
+                    /*timers_removed:*/ &mut ret_timers_removed,
+                    /*threads_removed:*/ &mut ret_threads_removed,
+                    /*new_windows:*/ &mut ret.windows_created,
+                    /*current_window_handle:*/ raw_window_handle,
+                    /*node_hierarchy*/ &node_hierarchy,
+                    /*system_callbacks*/ system_callbacks,
+                    /*words_cache*/ &words_cache,
+                    /*shaped_words_cache*/ &shaped_words_cache,
+                    /*positioned_words_cache*/ &positioned_words_cache,
+                    /*positioned_rects*/ &positioned_rects,
+                    /*font_map*/ &font_map,
+                    /*dataset_map*/ dataset_map,
+                    /*stop_propagation:*/ &mut stop_propagation,
+                    /*focus_target:*/ &mut new_focus,
+                    /*drag_started:*/ &mut ret_drag_started,
+                    /*words_changed_in_callbacks:*/ &mut ret_words_changed,
+                    /*images_changed_in_callbacks:*/ &mut ret_images_changed,
+                    /*image_masks_changed_in_callbacks:*/ &mut ret_image_masks_changed,
+                    /*css_properties_changed_in_callbacks:*/ &mut ret_css_properties_changed,
+                    /*current_scroll_states:*/ scroll_states,
+                    /*nodes_scrolled_in_callback:*/ &mut ret_nodes_scrolled_in_callbacks,
+                    /*hit_dom_node:*/ DomNodeId { dom: *dom_id, node: AzNodeId::from_crate_internal(Some(*root_id)) },
+                    /*cursor_relative_to_item:*/ hit_test_item.as_ref().map(|hi|hi.point_relative_to_item).into(),
+                    /*cursor_in_viewport:*/ hit_test_item.as_ref().map(|hi| hi.point_in_viewport).into(),
+                );
 
-                    let callback_return = {
-                        // get a MUTABLE reference to the RefAny inside of the DOM
-                        if let Some(callback_data) = callback_map
-                        .get_mut(&*root_id).unwrap()
-                        .iter_mut().find(|i| i.event == *event_filter) {
-                            // Invoke callback
-                            (callback_data.callback.cb)(&mut callback_data.data, callback_info)
-                        } else {
-                            Update::DoNothing
-                        }
-                    };
+                let callback_return = {
+                    // get a MUTABLE reference to the RefAny inside of the DOM
+                    if let Some(callback_data) = callback_map
+                    .get_mut(&*root_id).unwrap()
+                    .iter_mut().find(|i| i.event == *event_filter) {
+                        // Invoke callback
+                        (callback_data.callback.cb)(&mut callback_data.data, callback_info)
+                    } else {
+                        Update::DoNothing
+                    }
+                };
 
-                    ret.callbacks_update_screen.max_self(callback_return);
+                ret.callbacks_update_screen.max_self(callback_return);
 
-                    if let Some(new_focus) = new_focus.clone() {
-                        new_focus_target = Some(new_focus);
-                    }
+                if let Some(new_focus) = new_focus.clone() {
+                    new_focus_target = Some(new_focus);
+                }
 
-                    if stop_propagation {
-                       blacklisted_event_types.insert(event_filter.clone());
-                    }
+                if stop_propagation {
+                   blacklisted_event_types.insert(event_filter.clone());
                 }
-                break;
             }
         }
 
@@ -1071,6 +1250,8 @@ impl CallbacksOfHitTest {
             ret.cursor_changed = true;
         }
 
+        ret.drag_started = ret_drag_started;
+
         if !ret_timers.is_empty() { ret.timers = Some(ret_timers); }
         if !ret_threads.is_empty() { ret.threads = Some(ret_threads); }
         if ret_modified_window_state != ret_modified_window_state_unmodified {
@@ -1226,6 +1407,10 @@ fn get_window_events(current_window_state: &FullWindowState, previous_window_sta
         events.push(WindowEventFilter::VirtualKeyUp);
     }
 
+    if current_window_state.keyboard_state.ime_composition != previous_window_state.keyboard_state.ime_composition {
+        events.push(WindowEventFilter::ImeComposition);
+    }
+
     // misc events
 
     let hovered_file_equals = previous_window_state.hovered_file == current_window_state.hovered_file;
@@ -1245,6 +1430,28 @@ fn get_window_events(current_window_state: &FullWindowState, previous_window_sta
         events.push(WindowEventFilter::ThemeChanged);
     }
 
+    // drag and drop events (see FullWindowState::update_drag_state)
+
+    match (previous_window_state.drag_state.as_ref(), current_window_state.drag_state.as_ref()) {
+        (Some(DragState::Pending { .. }), Some(DragState::Started { .. })) => {
+            events.push(WindowEventFilter::DragStart);
+        },
+        (Some(DragState::Dragging(_)), Some(DragState::Dragging(_))) => {
+            if events.contains(&WindowEventFilter::MouseOver) {
+                events.push(WindowEventFilter::DragOver);
+            }
+        },
+        (Some(DragState::Dragging(_)), Some(DragState::Dropped(_))) => {
+            events.push(WindowEventFilter::Drop);
+            events.push(WindowEventFilter::DragEnd);
+        },
+        (Some(DragState::Dragging(_)), _) => {
+            // drag was cancelled
+            events.push(WindowEventFilter::DragEnd);
+        },
+        _ => { },
+    }
+
     events
 }
 
@@ -19,6 +19,8 @@ use crate::window::{AzStringPair, StringPairVec};
 use crate::styled_dom::StyledDom;
 use crate::css::VecContents;
 use crate::dom::Dom;
+use crate::xml_expr::{XmlExpr, XmlExprError, XmlForLoop, XmlValue};
+use crate::xml_types::{ComponentArgumentKind, ComponentArgumentValue, ArgumentValueError};
 #[cfg(feature = "css_parser")]
 use azul_css_parser::CssParseError;
 
@@ -158,7 +160,7 @@ impl fmt::Display for XmlStreamError {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Ord, Hash, Eq)]
+#[derive(Debug, Default, Copy, PartialEq, PartialOrd, Clone, Ord, Hash, Eq)]
 #[repr(C)]
 pub struct XmlTextPos {
     pub row: u32,
@@ -171,6 +173,40 @@ impl fmt::Display for XmlTextPos {
     }
 }
 
+/// Start and end of a tag, attribute or text in the XML source, used to
+/// point to the location of an error. Both positions are 1-based, a row
+/// of 0 means that the location is unknown (i.e. the node was not parsed
+/// from a string).
+#[derive(Debug, Default, Copy, PartialEq, PartialOrd, Clone, Ord, Hash, Eq)]
+#[repr(C)]
+pub struct XmlSpan {
+    pub start: XmlTextPos,
+    pub end: XmlTextPos,
+}
+
+impl XmlSpan {
+    /// Returns whether the span points to an actual location in the source
+    pub fn is_known(&self) -> bool {
+        self.start.row != 0
+    }
+}
+
+impl fmt::Display for XmlSpan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.start)
+    }
+}
+
+impl_vec!(XmlSpan, XmlSpanVec, XmlSpanVecDestructor);
+impl_vec_mut!(XmlSpan, XmlSpanVec);
+impl_vec_debug!(XmlSpan, XmlSpanVec);
+impl_vec_partialeq!(XmlSpan, XmlSpanVec);
+impl_vec_eq!(XmlSpan, XmlSpanVec);
+impl_vec_partialord!(XmlSpan, XmlSpanVec);
+impl_vec_ord!(XmlSpan, XmlSpanVec);
+impl_vec_hash!(XmlSpan, XmlSpanVec);
+impl_vec_clone!(XmlSpan, XmlSpanVec, XmlSpanVecDestructor);
+
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 #[repr(C)]
 pub struct XmlTextError {
@@ -193,6 +229,19 @@ pub enum XmlParseError {
     UnknownToken(XmlTextPos),
 }
 
+impl XmlParseError {
+    /// Returns the location of the error in the XML source
+    pub fn get_pos(&self) -> XmlTextPos {
+        use self::XmlParseError::*;
+        match self {
+            InvalidDeclaration(e) | InvalidComment(e) | InvalidPI(e) |
+            InvalidDoctype(e) | InvalidEntity(e) | InvalidElement(e) |
+            InvalidAttribute(e) | InvalidCdata(e) | InvalidCharData(e) => e.pos,
+            UnknownToken(pos) => *pos,
+        }
+    }
+}
+
 impl fmt::Display for XmlParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::XmlParseError::*;
@@ -274,6 +323,27 @@ pub enum XmlError {
     ParserError(XmlParseError),
 }
 
+impl XmlError {
+    /// Returns the location of the error in the XML source, if known
+    pub fn get_pos(&self) -> Option<XmlTextPos> {
+        use self::XmlError::*;
+        match self {
+            InvalidXmlPrefixUri(pos) | UnexpectedXmlUri(pos) | UnexpectedXmlnsUri(pos) |
+            InvalidElementNamePrefix(pos) | UnexpectedEntityCloseTag(pos) |
+            MalformedEntityReference(pos) | EntityReferenceLoop(pos) |
+            InvalidAttributeValue(pos) => Some(*pos),
+            DuplicatedNamespace(e) => Some(e.pos),
+            UnknownNamespace(e) => Some(e.pos),
+            UnexpectedCloseTag(e) => Some(e.pos),
+            UnknownEntityReference(e) => Some(e.pos),
+            DuplicatedAttribute(e) => Some(e.pos),
+            ParserError(e) => Some(e.get_pos()),
+            NoParserAvailable | NoRootNode | SizeLimit |
+            DtdDetected | MalformedHierarchy(..) => None,
+        }
+    }
+}
+
 impl fmt::Display for XmlError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::XmlError::*;
@@ -325,8 +395,8 @@ impl fmt::Display for XmlError {
 /// ```
 ///
 /// For this to work, a component has to note all its arguments and types that it can take.
-/// If a type is not `str` or `String`, it will be formatted using the `{:?}` formatter
-/// in the generated source code, otherwise the compiler will use the `{}` formatter.
+/// The values of the XML attributes are checked against these types when the XML is loaded
+/// and compiled to typed literals in the generated source code (see `ComponentArgumentKind`).
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ComponentArguments {
     /// The arguments of the component, i.e. `date => String`
@@ -334,6 +404,9 @@ pub struct ComponentArguments {
     /// Whether this widget accepts text. Note that this will be passed as the first
     /// argument when rendering the Rust code.
     pub accepts_text: bool,
+    /// Whether this widget has a `<slot/>` for the children of the XML node. Note that the
+    /// children will be passed as the last argument (`slot: DomVec`) when rendering the Rust code.
+    pub accepts_slot: bool,
 }
 
 impl Default for ComponentArguments {
@@ -341,6 +414,7 @@ impl Default for ComponentArguments {
         Self {
             args: ComponentArgumentsMap::default(),
             accepts_text: false,
+            accepts_slot: false,
         }
     }
 }
@@ -352,6 +426,18 @@ impl ComponentArguments {
 }
 
 
+/// Children of a component instance (i.e. the `<p>` in `<Card><p>Hello</p></Card>`), which
+/// get rendered in place of the `<slot/>` node of the component
+#[derive(Debug, Copy, Clone)]
+pub struct XmlSlot<'a, 'b> {
+    /// Children of the XML node that instantiated the component
+    pub children: &'a [XmlNode],
+    /// Variables of the scope the component was instantiated in
+    pub scope: &'b FilteredComponentArguments,
+    /// Slot of the parent component, in case the children contain a `<slot/>` themselves
+    pub parent: Option<&'b XmlSlot<'a, 'b>>,
+}
+
 /// Specifies a component that reacts to a parsed XML node
 pub trait XmlComponent {
 
@@ -385,8 +471,9 @@ pub trait XmlComponent {
     /// ```
     ///
     /// If a user instantiates a component with an invalid argument (i.e. `<Calendar asdf="false">`),
-    /// the user will get an error that the component can't handle this argument. The types are not checked,
-    /// but they are necessary for the XML-to-Rust compiler.
+    /// the user will get an error that the component can't handle this argument. Similarly, if the value
+    /// doesn't match the type of the argument (i.e. `gridVisible="yes"`), the user will get an error
+    /// that the value is invalid.
     ///
     /// When the XML is then compiled to Rust, the generated Rust code will look like this:
     ///
@@ -395,22 +482,33 @@ pub trait XmlComponent {
     ///     selected_date: DateTime::from("01.01.2018")
     ///     minimum_date: DateTime::from("01.01.2018")
     ///     maximum_date: DateTime::from("01.01.2018")
-    ///     first_day_of_week: WeekDay::from("sunday")
+    ///     first_day_of_week: WeekDay::Sunday,
     ///     grid_visible: false,
     ///     .. Default::default()
     /// })
     /// ```
     ///
-    /// Of course, the code generation isn't perfect: For non-builtin types, the compiler will use
-    /// `Type::from` to make the conversion. You can then take that generated Rust code and clean it up,
+    /// Of course, the code generation isn't perfect: Builtin types (numbers, strings, colors, lengths and
+    /// enums declared as `WeekDay(Monday|Sunday)`) are compiled to literals, but for all other types the
+    /// compiler will use `Type::from` to make the conversion. You can then take that generated Rust code and clean it up,
     /// put it somewhere else and create another component out of it - XML should only be seen as a
     /// high-level prototyping tool (to get around the problem of compile times), not as the final
     /// data format.
     fn get_available_arguments(&self) -> ComponentArguments;
     /// Given a root node and a list of possible arguments, returns a DOM or a syntax error
     fn render_dom<'a>(&'a self, components: &'a XmlComponentMap, arguments: &FilteredComponentArguments, content: &XmlTextContent) -> Result<StyledDom, RenderDomError<'a>>;
+    /// Same as `render_dom`, but renders the children of the XML node in place of the `<slot/>`.
+    /// Returns whether the slot was rendered, otherwise the children are appended to the returned DOM.
+    fn render_dom_with_slot<'a>(&'a self, components: &'a XmlComponentMap, arguments: &FilteredComponentArguments, content: &XmlTextContent, _: &XmlSlot<'a, '_>) -> Result<(StyledDom, bool), RenderDomError<'a>> {
+        self.render_dom(components, arguments, content).map(|dom| (dom, false))
+    }
     /// Used to compile the XML component to Rust code - input
     fn compile_to_rust_code(&self, components: &XmlComponentMap, attributes: &FilteredComponentArguments, content: &XmlTextContent) -> Result<String, CompileError>;
+    /// Used to compile the XML component to C, C++ or Python code: returns the expression
+    /// that creates the root `Dom` of the component, defaults to an empty `div`
+    fn compile_to_foreign_code(&self, language: ForeignLanguage, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(language.dom_constructor("div", ""))
+    }
     /// Returns the XML node for this component (necessary to compile the component into a function
     /// during the Rust compilation stage)
     fn get_xml_node<'a>(&'a self) -> &'a XmlNode;
@@ -469,12 +567,28 @@ pub struct XmlNode {
     pub children: XmlNodeVec,
     /// String content of the node, i.e the "Hello" in `<p>Hello</p>`
     pub text: XmlTextContent,
+    /// Location of the node in the source, from the `<p` to the end of the closing `</p>`
+    pub span: XmlSpan,
+    /// Location of the attributes in the source, i.e. the `id="a"` in `<p id="a">`
+    /// (in the same order as the `attributes`)
+    pub attribute_spans: XmlSpanVec,
+    /// Location of the text content in the source
+    pub text_span: XmlSpan,
 }
 
 impl XmlNode {
     pub fn new<I: Into<XmlTagName>>(node_type: I) -> Self {
         XmlNode { node_type: node_type.into(), .. Default::default() }
     }
+
+    /// Returns the location of the (normalized) attribute, falls back
+    /// to the location of the tag name if the attribute isn't present
+    pub fn get_attribute_span(&self, attribute: &str) -> XmlSpan {
+        self.attributes.as_ref().iter()
+        .position(|a| normalize_casing(a.key.as_str()) == attribute)
+        .and_then(|i| self.attribute_spans.as_ref().get(i).copied())
+        .unwrap_or(self.span)
+    }
 }
 
 impl_vec!(XmlNode, XmlNodeVec, XmlNodeVecDestructor);
@@ -506,6 +620,12 @@ impl Default for XmlComponentMap {
 }
 
 impl XmlComponentMap {
+
+    /// Returns the (normalized) names of all registered components
+    pub fn get_component_names(&self) -> Vec<String> {
+        self.components.keys().cloned().collect()
+    }
+
     pub fn register_component(
         &mut self,
         id: &str,
@@ -542,6 +662,22 @@ pub enum DomXmlParseError<'a> {
     Component(ComponentParseError<'a>),
     /// Error parsing global CSS in head node
     Css(CssParseError<'a>),
+    /// Error at the given location in the XML source (for CSS errors:
+    /// the location of the text of the `<style>` node)
+    Spanned(XmlSpan, Box<DomXmlParseError<'a>>),
+}
+
+impl<'a> DomXmlParseError<'a> {
+
+    /// Attaches the source location to the error, if the error doesn't have a location yet
+    pub fn with_span(self, span: XmlSpan) -> Self {
+        match self {
+            DomXmlParseError::Spanned(..) => self,
+            DomXmlParseError::RenderDom(e) => DomXmlParseError::RenderDom(e.with_span(span)),
+            DomXmlParseError::Component(e) => DomXmlParseError::Component(e.with_span(span)),
+            e => if span.is_known() { DomXmlParseError::Spanned(span, Box::new(e)) } else { e },
+        }
+    }
 }
 
 impl<'a> From<XmlError> for DomXmlParseError<'a> {
@@ -577,6 +713,18 @@ pub enum CompileError<'a> {
     Css(CssParseError<'a>),
 }
 
+impl<'a> CompileError<'a> {
+
+    /// Attaches the source location to the error, if the error doesn't have a location yet
+    pub fn with_span(self, span: XmlSpan) -> Self {
+        match self {
+            CompileError::Dom(e) => CompileError::Dom(e.with_span(span)),
+            CompileError::Xml(e) => CompileError::Xml(e.with_span(span)),
+            CompileError::Css(e) => CompileError::Dom(RenderDomError::CssError(e).with_span(span)),
+        }
+    }
+}
+
 impl<'a> From<ComponentError> for CompileError<'a> {
     fn from(e: ComponentError) -> Self {
         CompileError::Dom(RenderDomError::Component(e))
@@ -620,8 +768,73 @@ pub enum ComponentError {
     /// A certain node type can't be rendered, because the
     /// renderer for this node is not available isn't available
     ///
-    /// UnknownComponent(component_name)
-    UnknownComponent(AzString),
+    /// UnknownComponent(component_name, available_components)
+    UnknownComponent(AzString, Vec<String>),
+    /// The `condition` of an `<if>` or the `each` of a `<for>` node could not
+    /// be parsed or evaluated
+    ///
+    /// InvalidExpression(expression, error)
+    InvalidExpression(AzString, XmlExprError),
+    /// `<if>` node without a `condition` or `<for>` node without an `each` attribute
+    ///
+    /// MissingTemplateAttribute(node_type, attribute)
+    MissingTemplateAttribute(AzString, AzString),
+    /// `<else>` node that does not directly follow an `<if>` node
+    ElseWithoutIf,
+    /// `<for>`, `<if>`, `<else>` and `<slot>` can only be compiled to Rust code
+    TemplateNotSupported(AzString),
+    /// The value of an argument doesn't match the type of the argument
+    ///
+    /// InvalidArgumentValue(argument_name, value, argument_type, error)
+    InvalidArgumentValue(AzString, AzString, AzString, ArgumentValueError),
+}
+
+impl ComponentError {
+    /// Returns the closest match to a misspelled component or argument name, if any
+    pub fn get_suggestion(&self) -> Option<&str> {
+        match self {
+            ComponentError::UselessFunctionArgument(k, _, available_args) => {
+                let candidates = available_args.iter().map(|s| s.as_str()).chain(DEFAULT_ARGS.iter().copied());
+                find_similar_name(k.as_str(), candidates)
+            },
+            ComponentError::UnknownComponent(name, available_components) => {
+                find_similar_name(name.as_str(), available_components.iter().map(|s| s.as_str()))
+            },
+            ComponentError::InvalidArgumentValue(_, value, _, ArgumentValueError::UnknownVariant(variants)) => {
+                find_similar_name(value.as_str(), variants.iter().map(|s| s.as_str()))
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Returns the candidate with the smallest edit distance to the `name`,
+/// as long as the distance is small enough to be a typo
+pub fn find_similar_name<'a, I: Iterator<Item=&'a str>>(name: &str, candidates: I) -> Option<&'a str> {
+
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let b = b.chars().collect::<Vec<_>>();
+        let mut row = (0..=b.len()).collect::<Vec<_>>();
+        for (i, ca) in a.chars().enumerate() {
+            let mut last_diagonal = row[0];
+            row[0] = i + 1;
+            for (j, cb) in b.iter().enumerate() {
+                let next = (row[j + 1] + 1).min(row[j] + 1).min(last_diagonal + if ca == *cb { 0 } else { 1 });
+                last_diagonal = row[j + 1];
+                row[j + 1] = next;
+            }
+        }
+        row[b.len()]
+    }
+
+    let name = normalize_casing(name);
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+    .map(|c| (edit_distance(&name, c), c))
+    .filter(|(distance, _)| *distance <= max_distance)
+    .min_by_key(|(distance, _)| *distance)
+    .map(|(_, c)| c)
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -629,6 +842,20 @@ pub enum RenderDomError<'a> {
     Component(ComponentError),
     /// Error parsing the CSS on the component style
     CssError(CssParseError<'a>),
+    /// Error at the given location in the XML source (for CSS errors:
+    /// the location of the text of the `<style>` node)
+    Spanned(XmlSpan, Box<RenderDomError<'a>>),
+}
+
+impl<'a> RenderDomError<'a> {
+
+    /// Attaches the source location to the error, if the error doesn't have a location yet
+    pub fn with_span(self, span: XmlSpan) -> Self {
+        match self {
+            RenderDomError::Spanned(..) => self,
+            e => if span.is_known() { RenderDomError::Spanned(span, Box::new(e)) } else { e },
+        }
+    }
 }
 
 impl<'a> From<ComponentError> for RenderDomError<'a> {
@@ -660,8 +887,24 @@ pub enum ComponentParseError<'a> {
     /// Component type may not contain a whitespace
     /// (probably missing a `,` between the type and the next name)
     WhiteSpaceInComponentType(usize, AzString, AzString),
+    /// Argument at position `usize` with the name `String` has a malformed
+    /// enum type (has to be `Name(VariantA|VariantB)`)
+    InvalidEnumType(usize, AzString, AzString),
     /// Error parsing the <style> tag / CSS
     CssError(CssParseError<'a>),
+    /// Error at the given location in the XML source
+    Spanned(XmlSpan, Box<ComponentParseError<'a>>),
+}
+
+impl<'a> ComponentParseError<'a> {
+
+    /// Attaches the source location to the error, if the error doesn't have a location yet
+    pub fn with_span(self, span: XmlSpan) -> Self {
+        match self {
+            ComponentParseError::Spanned(..) => self,
+            e => if span.is_known() { ComponentParseError::Spanned(span, Box::new(e)) } else { e },
+        }
+    }
 }
 
 impl<'a> fmt::Display for DomXmlParseError<'a> {
@@ -677,6 +920,7 @@ impl<'a> fmt::Display for DomXmlParseError<'a> {
             RenderDom(e) => write!(f, "Error rendering DOM: {}", e),
             Component(c) => write!(f, "Error parsing component in <head> node:\r\n{}", c),
             Css(c) => write!(f, "Error parsing CSS in <head> node:\r\n{}", c),
+            Spanned(span, e) => write!(f, "{} (at {})", e, span),
         }
     }
 }
@@ -698,7 +942,14 @@ impl<'a> fmt::Display for ComponentParseError<'a> {
                        arg_name, arg_pos, arg_type_unparsed
                 )
             },
+            InvalidEnumType(arg_pos, arg_name, arg_type) => {
+                write!(f,
+                       "Invalid enum type \"{}\" of argument \"{}\" at position {}, expected `Name(VariantA|VariantB)`",
+                       arg_type, arg_name, arg_pos
+                )
+            },
             CssError(lsf) => write!(f, "Error parsing <style> tag: {}", lsf),
+            Spanned(span, e) => write!(f, "{} (at {})", e, span),
         }
     }
 }
@@ -710,9 +961,18 @@ impl fmt::Display for ComponentError {
             UselessFunctionArgument(k, v, available_args) => {
                 write!(f, "Useless component argument \"{}\": \"{}\" - available args are: {:#?}",
                     k, v, available_args
-                )
+                )?;
             },
-            UnknownComponent(name) => write!(f, "Unknown component: \"{}\"", name),
+            UnknownComponent(name, _) => write!(f, "Unknown component: \"{}\"", name)?,
+            InvalidExpression(expr, e) => write!(f, "Invalid expression \"{}\": {}", expr, e)?,
+            MissingTemplateAttribute(node, attr) => write!(f, "<{}> node is missing the \"{}\" attribute", node, attr)?,
+            ElseWithoutIf => write!(f, "<else> node has to directly follow an <if> node")?,
+            TemplateNotSupported(node) => write!(f, "<{}> nodes can only be compiled to Rust code", node)?,
+            InvalidArgumentValue(k, v, ty, e) => write!(f, "Invalid value \"{}\" for argument \"{}: {}\": {}", v, k, ty, e)?,
+        }
+        match self.get_suggestion() {
+            Some(suggestion) => write!(f, " - did you mean \"{}\"?", suggestion),
+            None => Ok(()),
         }
     }
 }
@@ -723,6 +983,7 @@ impl<'a> fmt::Display for RenderDomError<'a> {
         match self {
             Component(c) => write!(f, "{}", c),
             CssError(e) => write!(f, "Error parsing CSS in component: {}", e),
+            Spanned(span, e) => write!(f, "{} (at {})", e, span),
         }
     }
 }
@@ -785,6 +1046,10 @@ impl XmlComponent for BodyRenderer {
         Ok("Dom::body()".into())
     }
 
+    fn compile_to_foreign_code(&self, language: ForeignLanguage, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(language.dom_constructor("body", ""))
+    }
+
     fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
 }
 
@@ -806,6 +1071,7 @@ impl XmlComponent for TextRenderer {
         ComponentArguments {
             args: ComponentArgumentsMap::default(),
             accepts_text: true, // important!
+            accepts_slot: false,
         }
     }
 
@@ -818,6 +1084,10 @@ impl XmlComponent for TextRenderer {
         Ok(String::from("Dom::text(text)"))
     }
 
+    fn compile_to_foreign_code(&self, language: ForeignLanguage, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(language.dom_constructor("text", "text"))
+    }
+
     fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
 }
 
@@ -853,6 +1123,10 @@ pub fn parse_component_arguments<'a>(input: &'a str) -> Result<ComponentArgument
             return Err(WhiteSpaceInComponentType(arg_idx, arg_name.into(), arg_type.into()));
         }
 
+        if ComponentArgumentKind::from_type_name(arg_type).is_none() {
+            return Err(InvalidEnumType(arg_idx, arg_name.into(), arg_type.into()));
+        }
+
         let arg_name = normalize_casing(arg_name);
         let arg_type = arg_type.to_string();
 
@@ -862,20 +1136,22 @@ pub fn parse_component_arguments<'a>(input: &'a str) -> Result<ComponentArgument
     Ok(args)
 }
 
-/// Filters the XML attributes of a component given XmlAttributeMap
+/// Filters the XML attributes of a component given XmlAttributeMap,
+/// returns the (unformatted) values of the attributes that the component accepts
 pub fn validate_and_filter_component_args(xml_attributes: &XmlAttributeMap, valid_args: &ComponentArguments)
 -> Result<FilteredComponentArguments, ComponentError> {
 
     let mut map = FilteredComponentArguments {
         args: ComponentArgumentsMap::default(),
         accepts_text: valid_args.accepts_text,
+        accepts_slot: valid_args.accepts_slot,
     };
 
     for AzStringPair { key, value } in xml_attributes.as_ref().iter() {
         let xml_attribute_name = key;
         let xml_attribute_value = value;
-        if let Some((valid_arg_type, valid_arg_index)) = valid_args.args.get(xml_attribute_name.as_str()) {
-            map.args.insert(xml_attribute_name.clone().into_library_owned_string(), (valid_arg_type.clone(), *valid_arg_index));
+        if let Some((_, valid_arg_index)) = valid_args.args.get(xml_attribute_name.as_str()) {
+            map.args.insert(xml_attribute_name.clone().into_library_owned_string(), (xml_attribute_value.clone().into_library_owned_string(), *valid_arg_index));
         } else if DEFAULT_ARGS.contains(&xml_attribute_name.as_str()) {
             // no error, but don't insert the attribute name
         } else {
@@ -890,9 +1166,44 @@ pub fn validate_and_filter_component_args(xml_attributes: &XmlAttributeMap, vali
         }
     }
 
+    check_component_argument_types(&map.args, valid_args)?;
+
     Ok(map)
 }
 
+/// Checks that the values of the (filtered) arguments match the types of the `valid_args`.
+/// Values that still contain `{variables}` are skipped, they are checked once the
+/// variables have been instantiated.
+pub fn check_component_argument_types(values: &ComponentArgumentsMap, valid_args: &ComponentArguments)
+-> Result<(), ComponentError> {
+
+    for (arg_name, (arg_type, _)) in valid_args.args.iter() {
+
+        let value = match values.get(arg_name) {
+            Some((value, _)) => value,
+            None => continue,
+        };
+
+        if split_dynamic_string(value).iter().any(|item| match item { DynamicItem::Var(_) => true, _ => false }) {
+            continue;
+        }
+
+        let kind = match ComponentArgumentKind::from_type_name(arg_type) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        kind.parse_value(value).map_err(|e| ComponentError::InvalidArgumentValue(
+            arg_name.clone().into(),
+            value.clone().into(),
+            arg_type.clone().into(),
+            e,
+        ))?;
+    }
+
+    Ok(())
+}
+
 /// Find the one and only `<body>` node, return error if
 /// there is no app node or there are multiple app nodes
 pub fn get_html_node<'a>(root_nodes: &'a [XmlNode]) -> Result<&'a XmlNode, DomXmlParseError> {
@@ -935,6 +1246,28 @@ pub fn find_node_by_type<'a>(root_nodes: &'a [XmlNode], node_type: &str) -> Opti
     root_nodes.iter().find(|n| normalize_casing(&n.node_type).as_str() == node_type)
 }
 
+/// Parses the CSS of all `<style>` nodes of the `<head>` node and concatenates
+/// the stylesheets in the order of the nodes. Returns `None` if there is no `<style>` node.
+pub fn parse_head_styles<'a>(head_node: &'a XmlNode) -> Result<Option<Css>, RenderDomError<'a>> {
+
+    let mut global_style: Option<Css> = None;
+
+    for style_node in head_node.children.as_ref().iter().filter(|n| normalize_casing(&n.node_type).as_str() == "style") {
+        let text = match style_node.text.as_ref() {
+            Some(s) => s.as_str(),
+            None => continue,
+        };
+        let parsed_css = azul_css_parser::new_from_str(text)
+            .map_err(|e| RenderDomError::from(e).with_span(style_node.text_span))?;
+        match global_style.as_mut() {
+            Some(css) => css.stylesheets.extend(Vec::from(parsed_css.stylesheets)),
+            None => global_style = Some(parsed_css),
+        }
+    }
+
+    Ok(global_style)
+}
+
 pub fn find_attribute<'a>(node: &'a XmlNode, attribute: &str) -> Option<&'a AzString> {
     node.attributes.iter().find(|n| normalize_casing(&n.key.as_str()).as_str() == attribute).map(|s| &s.value)
 }
@@ -1012,12 +1345,7 @@ pub fn str_to_dom<'a>(
         }
 
         // parse the <style></style> tag contents, if present
-        if let Some(style_node) = find_node_by_type(head_node.children.as_ref(), "style") {
-            if let Some(text) = style_node.text.as_ref().map(|s| s.as_str()) {
-                let parsed_css = azul_css_parser::new_from_str(&text)?;
-                global_style = Some(parsed_css);
-            }
-        }
+        global_style = parse_head_styles(head_node)?;
     }
 
     render_dom_from_body_node(
@@ -1051,11 +1379,8 @@ pub fn str_to_rust_code<'a>(
             }
         }
 
-        if let Some(style_node) = find_node_by_type(head_node.children.as_ref(), "style") {
-            if let Some(text) = style_node.text.as_ref().map(|s| s.as_str()) {
-                let parsed_css = azul_css_parser::new_from_str(&text)?;
-                global_style = parsed_css;
-            }
+        if let Some(parsed_css) = parse_head_styles(head_node)? {
+            global_style = parsed_css;
         }
     }
 
@@ -1156,8 +1481,12 @@ pub fn compile_components(
     components: BTreeMap<ComponentName, (CompiledComponent, FilteredComponentArguments, BTreeMap<String, String>)>
 ) -> String {
 
-    let cs = components.iter().map(|(name, (function_body, function_args, css_blocks))| {
-        let f = compile_component(name, function_args, function_body)
+    // components of imported files ("cards:card") are grouped in one module per file
+    let mut modules = BTreeMap::<Option<&str>, Vec<String>>::new();
+
+    for (name, (function_body, function_args, css_blocks)) in components.iter() {
+        let (namespace, local_name) = split_component_namespace(name);
+        let f = compile_component(local_name, function_args, function_body)
         .lines()
         .map(|l| format!("    {}", l))
         .collect::<Vec<String>>()
@@ -1165,7 +1494,21 @@ pub fn compile_components(
 
         // let css_blocks = ...
 
-        format!("#[allow(unused_imports)]\r\npub mod {} {{\r\n    use azul::dom::Dom;\r\n    use azul::str::String as AzString;\r\n{}\r\n}}", name, f)
+        modules.entry(namespace).or_insert_with(Vec::new).push(format!(
+            "#[allow(unused_imports)]\r\npub mod {} {{\r\n    use azul::dom::Dom;\r\n    use azul::str::String as AzString;\r\n    use azul::vec::DomVec;\r\n    use crate::components::*;\r\n{}\r\n}}",
+            local_name, f
+        ));
+    }
+
+    let cs = modules.into_iter().map(|(namespace, components)| {
+        let components = components.join("\r\n\r\n");
+        match namespace {
+            None => components,
+            Some(namespace) => {
+                let components = components.lines().map(|l| format!("    {}", l)).collect::<Vec<String>>().join("\r\n");
+                format!("pub mod {} {{\r\n{}\r\n}}", namespace, components)
+            },
+        }
     }).collect::<Vec<String>>()
     .join("\r\n\r\n");
 
@@ -1178,9 +1521,21 @@ pub fn compile_components(
     if cs.is_empty() { cs } else { format!("pub mod components {{\r\n{}\r\n}}", cs)}
 }
 
+/// Splits the name of a component of an `<import>`ed file (`cards:card`)
+/// into the namespace of the file and the name of the component
+pub fn split_component_namespace(component_name: &str) -> (Option<&str>, &str) {
+    match component_name.find(':') {
+        Some(pos) => (Some(&component_name[..pos]), &component_name[pos + 1..]),
+        None => (None, component_name),
+    }
+}
+
 pub fn format_component_args(component_args: &ComponentArgumentsMap) -> String {
 
     let mut args = component_args.iter().map(|(arg_name, (arg_type, arg_index))| {
+        let arg_type = ComponentArgumentKind::from_type_name(arg_type)
+            .map(|kind| kind.get_rust_type_name())
+            .unwrap_or_else(|| arg_type.clone());
         (*arg_index, format!("{}: {}", arg_name, arg_type))
     }).collect::<Vec<(usize, String)>>();
 
@@ -1195,7 +1550,12 @@ pub fn compile_component(
     component_function_body: &str,
 ) -> String {
 
-    let function_args = format_component_args(&component_args.args);
+    let mut function_args = format_component_args(&component_args.args);
+    if component_args.accepts_slot {
+        // pass the children of the XML node as the last argument
+        if !function_args.is_empty() { function_args.push_str(", "); }
+        function_args.push_str("slot: DomVec");
+    }
     let component_function_body = component_function_body.lines().map(|l| format!("    {}", l)).collect::<Vec<String>>().join("\r\n");
     let should_inline = component_function_body.lines().count() == 1;
     format!(
@@ -1218,9 +1578,13 @@ pub fn render_dom_from_body_node<'a>(
     // Don't actually render the <body></body> node itself
     let mut dom = StyledDom::default();
 
-    for child_node in body_node.children.as_ref() {
-        dom.append_child(render_dom_from_body_node_inner(child_node, component_map, &FilteredComponentArguments::default())?);
-    }
+    render_dom_from_template_nodes(
+        &mut dom,
+        body_node.children.as_ref(),
+        component_map,
+        &FilteredComponentArguments::default(),
+        None,
+    )?;
 
     if let Some(global_css) = global_css.as_mut() {
         dom.restyle(global_css); // apply the CSS again
@@ -1229,21 +1593,161 @@ pub fn render_dom_from_body_node<'a>(
     Ok(dom)
 }
 
+/// Renders the (sibling) `nodes` and appends them to the `parent` DOM, expanding the
+/// `<for>`, `<if>` / `<else>` and `<slot/>` template nodes. `slot` holds the children
+/// of the component that is currently being rendered.
+pub fn render_dom_from_template_nodes<'a>(
+    parent: &mut StyledDom,
+    nodes: &'a [XmlNode],
+    component_map: &'a XmlComponentMap,
+    scope: &FilteredComponentArguments,
+    slot: Option<&XmlSlot<'a, '_>>,
+) -> Result<(), RenderDomError<'a>> {
+
+    // result of the last <if> condition, in case the next node is an <else> node
+    let mut last_condition = None;
+
+    for node in nodes {
+        let node_type = normalize_casing(&node.node_type);
+        let mut condition = None;
+
+        match node_type.as_str() {
+            "if" => {
+                let is_true = get_template_attribute(node, "condition")
+                    .and_then(|expr| evaluate_condition(expr, scope))
+                    .map_err(|e| component_error(node, e))?;
+                if is_true {
+                    render_dom_from_template_nodes(parent, node.children.as_ref(), component_map, scope, slot)?;
+                }
+                condition = Some(is_true);
+            },
+            "else" => {
+                let is_true = last_condition.ok_or_else(|| component_error(node, ComponentError::ElseWithoutIf))?;
+                if !is_true {
+                    render_dom_from_template_nodes(parent, node.children.as_ref(), component_map, scope, slot)?;
+                }
+            },
+            "for" => {
+                let item_scopes = get_template_attribute(node, "each")
+                    .and_then(|expr| get_for_loop_scopes(expr, scope))
+                    .map_err(|e| component_error(node, e))?;
+                for item_scope in item_scopes {
+                    render_dom_from_template_nodes(parent, node.children.as_ref(), component_map, &item_scope, slot)?;
+                }
+            },
+            "slot" => {
+                if let Some(slot) = slot {
+                    render_dom_from_template_nodes(parent, slot.children, component_map, slot.scope, slot.parent)?;
+                }
+            },
+            "style" => { }, // component styles are applied by the component
+            _ => {
+                parent.append_child(render_dom_from_body_node_inner(node, component_map, scope, slot)?);
+            },
+        }
+
+        last_condition = condition;
+    }
+
+    Ok(())
+}
+
+/// Returns the value of the `condition` / `each` attribute of an `<if>` / `<for>` node
+fn get_template_attribute<'a>(node: &'a XmlNode, attribute: &str) -> Result<&'a str, ComponentError> {
+    find_attribute(node, attribute)
+    .map(|s| s.as_str())
+    .ok_or_else(|| ComponentError::MissingTemplateAttribute(node.node_type.clone(), attribute.to_string().into()))
+}
+
+/// Error for a node type that is not registered in the `component_map`
+fn get_unknown_component_error(component_name: &str, component_map: &XmlComponentMap) -> ComponentError {
+    ComponentError::UnknownComponent(component_name.to_string().into(), component_map.get_component_names())
+}
+
+/// Attaches the location of the node (or the attribute of the node) that caused the error
+fn component_error<'a>(node: &XmlNode, e: ComponentError) -> RenderDomError<'a> {
+    let span = match &e {
+        ComponentError::UselessFunctionArgument(k, _, _) |
+        ComponentError::InvalidArgumentValue(k, _, _, _) => node.get_attribute_span(&normalize_casing(k.as_str())),
+        ComponentError::InvalidExpression(..) => match normalize_casing(&node.node_type).as_str() {
+            "for" => node.get_attribute_span("each"),
+            _ => node.get_attribute_span("condition"),
+        },
+        _ => node.span,
+    };
+    RenderDomError::Component(e).with_span(span)
+}
+
+/// Resolves a variable of an expression to the (typed) value of the argument in scope
+fn get_variable_value(scope: &FilteredComponentArguments, variable: &str) -> Option<XmlValue> {
+    scope.args.get(variable)
+    .or_else(|| scope.args.get(&normalize_casing(variable)))
+    .map(|(value, _)| XmlValue::from_argument(value))
+}
+
+/// Evaluates the `condition` of an `<if>` node
+pub fn evaluate_condition(expr: &str, scope: &FilteredComponentArguments) -> Result<bool, ComponentError> {
+    let error = |e| ComponentError::InvalidExpression(expr.to_string().into(), e);
+    let value = XmlExpr::parse(expr)
+        .and_then(|e| e.evaluate(&|v: &str| get_variable_value(scope, v)))
+        .map_err(error)?;
+    match value {
+        XmlValue::Bool(b) => Ok(b),
+        other => Err(error(XmlExprError::ExpectedBool(other.type_name()))),
+    }
+}
+
+/// Evaluates the `each` of a `<for>` node, returns the scope for every item of the list
+/// (the scope of the parent node + the item and index variables)
+pub fn get_for_loop_scopes(expr: &str, scope: &FilteredComponentArguments) -> Result<Vec<FilteredComponentArguments>, ComponentError> {
+
+    let error = |e| ComponentError::InvalidExpression(expr.to_string().into(), e);
+    let for_loop = XmlForLoop::parse(expr).map_err(error)?;
+    let items = match for_loop.items.evaluate(&|v: &str| get_variable_value(scope, v)).map_err(error)? {
+        XmlValue::List(items) => items,
+        other => return Err(error(XmlExprError::ExpectedList(other.type_name()))),
+    };
+
+    Ok(items.into_iter().enumerate().map(|(index, item)| {
+        let mut item_scope = scope.clone();
+        let order = item_scope.args.len();
+        item_scope.args.insert(normalize_casing(&for_loop.item), (item.to_string(), order));
+        if let Some(index_name) = for_loop.index.as_ref() {
+            item_scope.args.insert(normalize_casing(index_name), (index.to_string(), order + 1));
+        }
+        item_scope
+    }).collect())
+}
+
+/// Returns whether the node contains a `<slot/>` node (that is not part of a nested `<component>`)
+pub fn contains_slot(node: &XmlNode) -> bool {
+    node.children.as_ref().iter().any(|child| {
+        match normalize_casing(&child.node_type).as_str() {
+            "slot" => true,
+            "component" => false,
+            _ => contains_slot(child),
+        }
+    })
+}
+
 /// Takes a single (expanded) app node and renders the DOM or returns an error
 pub fn render_dom_from_body_node_inner<'a>(
     xml_node: &'a XmlNode,
     component_map: &'a XmlComponentMap,
     parent_xml_attributes: &FilteredComponentArguments,
+    parent_slot: Option<&XmlSlot<'a, '_>>,
 ) -> Result<StyledDom, RenderDomError<'a>> {
 
     let component_name = normalize_casing(&xml_node.node_type);
 
     let (renderer, inherit_variables) = component_map.components.get(&component_name)
-        .ok_or(ComponentError::UnknownComponent(component_name.clone().into()))?;
+        .ok_or_else(|| get_unknown_component_error(&component_name, component_map))
+        .map_err(|e| component_error(xml_node, e))?;
 
     // Arguments of the current node
     let available_function_args = renderer.get_available_arguments();
-    let mut filtered_xml_attributes = validate_and_filter_component_args(&xml_node.attributes, &available_function_args)?;
+    let mut filtered_xml_attributes = validate_and_filter_component_args(&xml_node.attributes, &available_function_args)
+        .map_err(|e| component_error(xml_node, e))?;
 
     if *inherit_variables {
         // Append all variables that are in scope for the parent node
@@ -1255,14 +1759,27 @@ pub fn render_dom_from_body_node_inner<'a>(
         v.0 = format_args_dynamic(&v.0, &parent_xml_attributes.args).to_string();
     }
 
+    // the values of arguments that referenced {variables} can only be checked now
+    check_component_argument_types(&filtered_xml_attributes.args, &available_function_args)
+        .map_err(|e| component_error(xml_node, e))?;
+
     let text = xml_node.text.as_ref()
     .map(|t| AzString::from(format_args_dynamic(t, &filtered_xml_attributes.args)));
 
-    let mut dom = renderer.render_dom(component_map, &filtered_xml_attributes, &text.into())?;
+    // The children are rendered in the scope the node was instantiated in, since
+    // custom components don't inherit the variables of their parent
+    let children_scope = if *inherit_variables { &filtered_xml_attributes } else { parent_xml_attributes };
+    let slot = XmlSlot {
+        children: xml_node.children.as_ref(),
+        scope: children_scope,
+        parent: parent_slot,
+    };
+
+    let (mut dom, slot_rendered) = renderer.render_dom_with_slot(component_map, &filtered_xml_attributes, &text.into(), &slot)?;
     set_attributes(&mut dom, &xml_node.attributes, &filtered_xml_attributes);
 
-    for child_node in xml_node.children.as_ref() {
-        dom.append_child(render_dom_from_body_node_inner(child_node, component_map, &filtered_xml_attributes)?);
+    if !slot_rendered {
+        render_dom_from_template_nodes(&mut dom, xml_node.children.as_ref(), component_map, children_scope, parent_slot)?;
     }
 
     Ok(dom)
@@ -1583,8 +2100,8 @@ pub fn render_component_inner<'a>(
     let xml_node = renderer.get_xml_node();
 
     let mut css = match find_node_by_type(xml_node.children.as_ref(), "style")
-    .and_then(|style_node| style_node.text.as_ref().map(|s| s.as_str())) {
-        Some(text) => azul_css_parser::new_from_str(&text)?,
+    .and_then(|style_node| style_node.text.as_ref().map(|s| (s.as_str(), style_node.text_span))) {
+        Some((text, text_span)) => azul_css_parser::new_from_str(&text).map_err(|e| CompileError::from(e).with_span(text_span))?,
         None => Css::empty(),
     };
 
@@ -1619,7 +2136,19 @@ pub fn render_component_inner<'a>(
 
     let mut css_blocks = BTreeMap::new();
     let mut extra_blocks = VecContents::default();
-    if !xml_node.children.as_ref().is_empty() {
+    if contains_template_nodes(xml_node.children.as_ref()) {
+        let children = compile_template_children_to_rust_code(
+            xml_node.children.as_ref(),
+            component_map,
+            &filtered_xml_attributes,
+            tabs,
+            &mut extra_blocks,
+            &mut css_blocks,
+            &css,
+            &matcher,
+        )?;
+        dom_string.push_str(&format!("\r\n{}.with_children({})", t, children));
+    } else if !xml_node.children.as_ref().is_empty() {
         dom_string.push_str(&format!("\r\n{}.with_children(DomVec::from_vec(vec![\r\n", t));
         for (child_idx, child_node) in xml_node.children.as_ref().iter().enumerate() {
 
@@ -1795,6 +2324,9 @@ fn group_matches(
             PseudoSelector(CssPathPseudoSelector::Hover) => { },
             PseudoSelector(CssPathPseudoSelector::Active) => { },
             PseudoSelector(CssPathPseudoSelector::Focus) => { },
+            PseudoSelector(CssPathPseudoSelector::FocusVisible) => { },
+            PseudoSelector(CssPathPseudoSelector::FocusWithin) => { },
+            PseudoSelector(CssPathPseudoSelector::DragOver) => { },
 
             Type(tag) => {
                 if !b.iter().any(|t| **t == Type(tag.clone())) { return false; }
@@ -1870,6 +2402,10 @@ pub fn compile_body_node_to_rust_code<'a>(
                 Some(CssPathPseudoSelector::Hover) => "Hover",
                 Some(CssPathPseudoSelector::Active) => "Active",
                 Some(CssPathPseudoSelector::Focus) => "Focus",
+                // there are no inline :focus-visible / :focus-within / :drag-over properties
+                Some(CssPathPseudoSelector::FocusVisible) => "Focus",
+                Some(CssPathPseudoSelector::FocusWithin) => "Focus",
+                Some(CssPathPseudoSelector::DragOver) => "Hover",
                 _ => "Normal",
             };
 
@@ -1895,7 +2431,21 @@ pub fn compile_body_node_to_rust_code<'a>(
         dom_string.push_str(&format!("\r\n{}.with_inline_css_props(CSS_MATCH_{:09})", t2, matcher_hash));
     }
 
-    if !body_node.children.as_ref().is_empty() {
+    if contains_template_nodes(body_node.children.as_ref()) {
+        let mut matcher = matcher.clone();
+        matcher.path.push(CssPathSelector::Children);
+        let children = compile_template_children_to_rust_code(
+            body_node.children.as_ref(),
+            component_map,
+            &FilteredComponentArguments::default(),
+            0,
+            extra_blocks,
+            css_blocks,
+            css,
+            &matcher,
+        )?;
+        dom_string.push_str(&format!("\r\n.with_children({})", children));
+    } else if !body_node.children.as_ref().is_empty() {
         use crate::css::GetHash;
         let children_hash = body_node.children.as_ref().get_hash();
         dom_string.push_str(&format!("\r\n.with_children(DomVec::from_vec(vec![\r\n"));
@@ -1987,6 +2537,23 @@ fn format_args_for_rust_code(input: &str) -> String {
     compile_and_format_dynamic_items(&dynamic_str_items)
 }
 
+/// Compiles the value of a component argument, i.e. `count="5"` to `5` if `count` is an integer:
+/// static values are compiled to typed literals, values with `{variables}` are passed through.
+/// Returns an error naming the argument if a static value doesn't match the type of the argument.
+fn format_typed_arg_for_rust_code(arg_name: &str, input: &str, arg_type: &str) -> Result<String, ComponentError> {
+    let dynamic_str_items = split_dynamic_string(input);
+    let is_static = dynamic_str_items.iter().all(|item| match item { DynamicItem::Str(_) => true, _ => false });
+    match ComponentArgumentKind::from_type_name(arg_type) {
+        Some(kind) if is_static => kind.compile_to_rust_code(input).map_err(|e| ComponentError::InvalidArgumentValue(
+            arg_name.to_string().into(),
+            input.to_string().into(),
+            arg_type.to_string().into(),
+            e,
+        )),
+        _ => Ok(compile_and_format_dynamic_items(&dynamic_str_items)),
+    }
+}
+
 pub fn compile_node_to_rust_code_inner<'a>(
     node: &'a XmlNode,
     component_map: &'a XmlComponentMap,
@@ -2006,11 +2573,13 @@ pub fn compile_node_to_rust_code_inner<'a>(
     let component_name = normalize_casing(&node.node_type);
 
     let (renderer, inherit_variables) = component_map.components.get(&component_name)
-        .ok_or(ComponentError::UnknownComponent(component_name.clone().into()))?;
+        .ok_or_else(|| get_unknown_component_error(&component_name, component_map))
+        .map_err(|e| CompileError::from(component_error(node, e)))?;
 
     // Arguments of the current node
     let available_function_args = renderer.get_available_arguments();
-    let mut filtered_xml_attributes = validate_and_filter_component_args(&node.attributes, &available_function_args)?;
+    let mut filtered_xml_attributes = validate_and_filter_component_args(&node.attributes, &available_function_args)
+        .map_err(|e| CompileError::from(component_error(node, e)))?;
 
     if *inherit_variables {
         // Append all variables that are in scope for the parent node
@@ -2025,9 +2594,12 @@ pub fn compile_node_to_rust_code_inner<'a>(
     let instantiated_function_arguments = {
 
         let mut args = filtered_xml_attributes.args.iter()
-        .filter_map(|(xml_attribute_key, (_xml_attribute_type, xml_attribute_order))| {
+        .filter_map(|(xml_attribute_key, (_xml_attribute_value, xml_attribute_order))| {
             match node.attributes.get_key(xml_attribute_key).cloned() {
-                Some(s) => Some((*xml_attribute_order, format_args_for_rust_code(&s))),
+                Some(s) => {
+                    let arg_type = available_function_args.args.get(xml_attribute_key).map(|(t, _)| t.as_str()).unwrap_or("String");
+                    Some(format_typed_arg_for_rust_code(xml_attribute_key, &s, arg_type).map(|arg| (*xml_attribute_order, arg)))
+                },
                 None => {
                     // __TODO__
                     // let node_text = format_args_for_rust_code(&xml_attribute_key);
@@ -2038,7 +2610,8 @@ pub fn compile_node_to_rust_code_inner<'a>(
                 }
             }
         })
-        .collect::<Vec<(usize, String)>>();
+        .collect::<Result<Vec<(usize, String)>, ComponentError>>()
+        .map_err(|e| CompileError::from(component_error(node, e)))?;
 
         args.sort_by(|(_, a), (_, b)| a.cmp(&b));
 
@@ -2067,18 +2640,61 @@ pub fn compile_node_to_rust_code_inner<'a>(
         "br" => NodeTypeTag::Br,
         "p" => NodeTypeTag::P,
         "img" => NodeTypeTag::Img,
-        other => return Err(CompileError::Dom(RenderDomError::Component(ComponentError::UnknownComponent(other.to_string().into())))),
+        _ => NodeTypeTag::Div, // custom components are wrapped in a div
     });
 
-    // The dom string is the function name
-    let mut dom_string = format!("{}{}::render({}{})", t2, component_name, text_as_first_arg, instantiated_function_arguments);
-
     matcher.path.push(node_type);
     let ids = node.attributes.get_key("id").map(|s| s.split_whitespace().collect::<Vec<_>>()).unwrap_or_default();
     matcher.path.extend(ids.into_iter().map(|id| CssPathSelector::Id(id.to_string().into())));
     let classes = node.attributes.get_key("class").map(|s| s.split_whitespace().collect::<Vec<_>>()).unwrap_or_default();
     matcher.path.extend(classes.into_iter().map(|class| CssPathSelector::Class(class.to_string().into())));
 
+    let mut children_matcher = matcher.clone();
+    children_matcher.path.push(CssPathSelector::Children);
+
+    let children_string = if contains_template_nodes(node.children.as_ref()) {
+        compile_template_children_to_rust_code(
+            node.children.as_ref(), component_map,
+            &filtered_xml_attributes, tabs,
+            extra_blocks, css_blocks, css, &children_matcher,
+        )?
+    } else {
+        let children = node.children.as_ref()
+        .iter()
+        .enumerate()
+        .map(|(child_idx, c)| {
+
+            let mut matcher = children_matcher.clone();
+            matcher.indices_in_parent.push(child_idx);
+            matcher.children_length.push(node.children.len());
+
+            compile_node_to_rust_code_inner(
+                c, component_map,
+                &filtered_xml_attributes, tabs + 1,
+                extra_blocks, css_blocks, css, matcher
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .join(&format!(",\r\n"));
+
+        if children.is_empty() && !filtered_xml_attributes.accepts_slot {
+            String::new()
+        } else {
+            format!("DomVec::from_vec(vec![\r\n{}\r\n{}])", children, t2)
+        }
+    };
+
+    // The children of components with a <slot/> are passed as the last argument
+    let slot_as_last_arg = if filtered_xml_attributes.accepts_slot {
+        let leading_comma = if text_as_first_arg.is_empty() && instantiated_function_arguments.is_empty() { "" } else { ", " };
+        format!("{}{}", leading_comma, children_string)
+    } else {
+        String::new()
+    };
+
+    // The dom string is the function name, components of imported files are in the module of the file
+    let mut dom_string = format!("{}{}::render({}{}{})", t2, component_name.replace(':', "::"), text_as_first_arg, instantiated_function_arguments, slot_as_last_arg);
+
     let matcher_hash = matcher.get_hash();
     let css_blocks_for_this_node = get_css_blocks(css, &matcher);
     if !css_blocks_for_this_node.is_empty() {
@@ -2090,6 +2706,10 @@ pub fn compile_node_to_rust_code_inner<'a>(
                 Some(CssPathPseudoSelector::Hover) => "Hover",
                 Some(CssPathPseudoSelector::Active) => "Active",
                 Some(CssPathPseudoSelector::Focus) => "Focus",
+                // there are no inline :focus-visible / :focus-within / :drag-over properties
+                Some(CssPathPseudoSelector::FocusVisible) => "Focus",
+                Some(CssPathPseudoSelector::FocusWithin) => "Focus",
+                Some(CssPathPseudoSelector::DragOver) => "Hover",
                 _ => "Normal",
             };
 
@@ -2117,76 +2737,841 @@ pub fn compile_node_to_rust_code_inner<'a>(
 
     set_stringified_attributes(&mut dom_string, &node.attributes, &filtered_xml_attributes.args, tabs);
 
-    let mut children_string = node.children.as_ref()
-    .iter()
-    .enumerate()
-    .map(|(child_idx, c)| {
-
-        let mut matcher = matcher.clone();
-        matcher.path.push(CssPathSelector::Children);
-        matcher.indices_in_parent.push(child_idx);
-        matcher.children_length.push(node.children.len());
-
-        compile_node_to_rust_code_inner(
-            c, component_map,
-            &filtered_xml_attributes, tabs + 1,
-            extra_blocks, css_blocks, css, matcher
-        )
-    })
-    .collect::<Result<Vec<_>, _>>()?
-    .join(&format!(",\r\n"));
-
-    if !children_string.is_empty() {
-        dom_string.push_str(&format!("\r\n{}.with_children(DomVec::from_vec(vec![\r\n{}\r\n{}]))", t2, children_string, t2));
+    if !children_string.is_empty() && !filtered_xml_attributes.accepts_slot {
+        dom_string.push_str(&format!("\r\n{}.with_children({})", t2, children_string));
     }
 
     Ok(dom_string)
 }
 
-/// Component that was created from a XML node (instead of being registered from Rust code).
-/// Necessary to
-pub struct DynamicXmlComponent {
-    /// What the name of this component is, i.e. "test" for `<component name="test" />`
-    pub name: String,
-    /// Whether this component has any `args="a: String"` arguments
-    pub arguments: ComponentArguments,
-    /// Root XML node of this component (the `<component />` Node)
-    pub root: XmlNode,
+/// Returns whether the direct children contain `<for>`, `<if>`, `<else>` or `<slot/>` nodes
+fn contains_template_nodes(nodes: &[XmlNode]) -> bool {
+    nodes.iter().any(|n| match normalize_casing(&n.node_type).as_str() {
+        "for" | "if" | "else" | "slot" => true,
+        _ => false,
+    })
 }
 
-impl DynamicXmlComponent {
+/// Compiles children that contain `<for>`, `<if>` / `<else>` or `<slot/>` nodes
+/// into a block that builds the `DomVec` at runtime
+fn compile_template_children_to_rust_code<'a>(
+    nodes: &'a [XmlNode],
+    component_map: &'a XmlComponentMap,
+    parent_xml_attributes: &FilteredComponentArguments,
+    tabs: usize,
+    extra_blocks: &mut VecContents,
+    css_blocks: &mut BTreeMap<String, String>,
+    css: &Css,
+    matcher: &CssMatcher,
+) -> Result<String, CompileError<'a>> {
 
-    /// Parses a `component` from an XML node
-    pub fn new<'a>(root: &'a XmlNode) -> Result<Self, ComponentParseError<'a>> {
+    let t = String::from("    ").repeat(tabs);
+    let mut block = format!("{{\r\n{}    let mut children = Vec::new();\r\n", t);
+    compile_template_nodes_to_rust_code(
+        &mut block, nodes, component_map, parent_xml_attributes,
+        tabs + 1, extra_blocks, css_blocks, css, matcher,
+    )?;
+    block.push_str(&format!("{}    DomVec::from_vec(children)\r\n{}}}", t, t));
+    Ok(block)
+}
 
-        let node_type = normalize_casing(&root.node_type);
+/// Appends the statements that push the rendered nodes to the `children` vec
+fn compile_template_nodes_to_rust_code<'a>(
+    block: &mut String,
+    nodes: &'a [XmlNode],
+    component_map: &'a XmlComponentMap,
+    parent_xml_attributes: &FilteredComponentArguments,
+    tabs: usize,
+    extra_blocks: &mut VecContents,
+    css_blocks: &mut BTreeMap<String, String>,
+    css: &Css,
+    matcher: &CssMatcher,
+) -> Result<(), CompileError<'a>> {
 
-        if node_type.as_str() != "component" {
-            return Err(ComponentParseError::NotAComponent);
-        }
+    let t = String::from("    ").repeat(tabs);
+    let mut last_was_if = false;
 
-        let name = root.attributes.get_key("name").cloned().ok_or(ComponentParseError::NotAComponent)?;
-        let accepts_text = root.attributes.get_key("accepts_text").and_then(|p| parse_bool(p.as_str())).unwrap_or(false);
+    for (child_idx, node) in nodes.iter().enumerate() {
 
-        let args = match root.attributes.get_key("args") {
-            Some(s) => parse_component_arguments(s)?,
-            None => ComponentArgumentsMap::default(),
-        };
+        let node_type = normalize_casing(&node.node_type);
 
-        Ok(Self {
-            name: normalize_casing(&name),
-            arguments: ComponentArguments {
-                args,
-                accepts_text,
+        match node_type.as_str() {
+            "if" => {
+                let condition = get_template_attribute(node, "condition")
+                    .and_then(|expr| XmlExpr::parse(expr).map_err(|e| ComponentError::InvalidExpression(expr.to_string().into(), e)))
+                    .map_err(|e| CompileError::from(component_error(node, e)))?;
+                block.push_str(&format!("{}if {} {{\r\n", t, condition.to_rust_code()));
+                compile_template_nodes_to_rust_code(
+                    block, node.children.as_ref(), component_map, parent_xml_attributes,
+                    tabs + 1, extra_blocks, css_blocks, css, matcher,
+                )?;
+                block.push_str(&format!("{}}}\r\n", t));
             },
-            root: root.clone(),
-        })
+            "else" => {
+                if !last_was_if {
+                    return Err(component_error(node, ComponentError::ElseWithoutIf).into());
+                }
+                // re-open the closing brace of the previous if block
+                let len = block.trim_end().len();
+                block.truncate(len);
+                block.push_str(" else {\r\n");
+                compile_template_nodes_to_rust_code(
+                    block, node.children.as_ref(), component_map, parent_xml_attributes,
+                    tabs + 1, extra_blocks, css_blocks, css, matcher,
+                )?;
+                block.push_str(&format!("{}}}\r\n", t));
+            },
+            "for" => {
+                let for_loop = get_template_attribute(node, "each")
+                    .and_then(|expr| XmlForLoop::parse(expr).map_err(|e| ComponentError::InvalidExpression(expr.to_string().into(), e)))
+                    .map_err(|e| CompileError::from(component_error(node, e)))?;
+                let item = normalize_casing(&for_loop.item);
+                let (pattern, enumerate) = match for_loop.index.as_ref() {
+                    Some(index) => (format!("({}, {})", normalize_casing(index), item), ".enumerate()"),
+                    None => (item, ""),
+                };
+                block.push_str(&format!("{}for {} in {}.iter().cloned(){} {{\r\n", t, pattern, for_loop.items.to_rust_code(), enumerate));
+                compile_template_nodes_to_rust_code(
+                    block, node.children.as_ref(), component_map, parent_xml_attributes,
+                    tabs + 1, extra_blocks, css_blocks, css, matcher,
+                )?;
+                block.push_str(&format!("{}}}\r\n", t));
+            },
+            "slot" => {
+                block.push_str(&format!("{}children.extend(slot.iter().cloned());\r\n", t));
+            },
+            "style" => { },
+            _ => {
+                let mut matcher = matcher.clone();
+                matcher.indices_in_parent.push(child_idx);
+                matcher.children_length.push(nodes.len());
+
+                let child = compile_node_to_rust_code_inner(
+                    node, component_map,
+                    parent_xml_attributes, tabs + 1,
+                    extra_blocks, css_blocks, css, matcher
+                )?;
+                block.push_str(&format!("{}children.push(\r\n{}\r\n{});\r\n", t, child, t));
+            },
+        }
+
+        last_was_if = node_type.as_str() == "if";
     }
+
+    Ok(())
 }
 
-impl XmlComponent for DynamicXmlComponent {
+/// Target language of `str_to_foreign_code` (Rust code is generated by `str_to_rust_code`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ForeignLanguage {
+    C,
+    Cpp,
+    Python,
+}
 
-    fn get_available_arguments(&self) -> ComponentArguments {
+impl ForeignLanguage {
+
+    /// Calls a `Dom` constructor, i.e. `AzDom_div()` / `Dom_div()` / `Dom.div()`
+    pub fn dom_constructor(&self, constructor: &str, args: &str) -> String {
+        match self {
+            ForeignLanguage::C => format!("AzDom_{}({})", constructor, args),
+            ForeignLanguage::Cpp => format!("Dom_{}({})", constructor, args),
+            ForeignLanguage::Python => format!("Dom.{}({})", constructor, args),
+        }
+    }
+
+    /// Calls a `Dom` function that modifies the node in-place, i.e. `AzDom_addChild(&node, child);`
+    fn dom_method_call(&self, node: &str, method: &str, args: &str) -> String {
+        match self {
+            ForeignLanguage::C => format!("AzDom_{}(&{}, {});", snake_case_to_camel_case(method), node, args),
+            ForeignLanguage::Cpp => format!("Dom_{}(&{}, {});", snake_case_to_camel_case(method), node, args),
+            ForeignLanguage::Python => format!("{}.{}({})", node, method, args),
+        }
+    }
+
+    fn declare_dom(&self, node: &str, expr: &str) -> String {
+        match self {
+            ForeignLanguage::C => format!("AzDom {} = {};", node, expr),
+            ForeignLanguage::Cpp => format!("Dom {} = {};", node, expr),
+            ForeignLanguage::Python => format!("{} = {}", node, expr),
+        }
+    }
+
+    fn return_dom(&self, node: &str) -> String {
+        match self {
+            ForeignLanguage::C | ForeignLanguage::Cpp => format!("return {};", node),
+            ForeignLanguage::Python => format!("return {}", node),
+        }
+    }
+
+    fn function_header(&self, function_name: &str, args: &[(String, String)]) -> String {
+        match self {
+            ForeignLanguage::C | ForeignLanguage::Cpp => {
+                let args = args.iter()
+                .map(|(arg_name, arg_type)| format!("{} {}", self.type_name(arg_type), arg_name))
+                .collect::<Vec<_>>();
+                let args = if args.is_empty() && *self == ForeignLanguage::C { String::from("void") } else { args.join(", ") };
+                let return_type = if *self == ForeignLanguage::C { "AzDom" } else { "Dom" };
+                format!("{} {}({})", return_type, function_name, args)
+            },
+            ForeignLanguage::Python => {
+                let args = args.iter().map(|(arg_name, _)| arg_name.clone()).collect::<Vec<_>>();
+                format!("def {}({}):", function_name, args.join(", "))
+            },
+        }
+    }
+
+    fn function(&self, header: &str, statements: &[String]) -> String {
+        let body = statements.iter().map(|s| format!("    {}", s)).collect::<Vec<_>>().join("\r\n");
+        match self {
+            ForeignLanguage::C | ForeignLanguage::Cpp => format!("{} {{\r\n{}\r\n}}", header, body),
+            ForeignLanguage::Python => format!("{}\r\n{}", header, body),
+        }
+    }
+
+    /// Translates the Rust type of a component argument (`args="a: String"`) into the target language
+    fn type_name(&self, rust_type: &str) -> String {
+        let rust_type = rust_type.trim();
+        let native = match rust_type {
+            "bool" => "bool",
+            "i8" => "int8_t",
+            "i16" => "int16_t",
+            "i32" => "int32_t",
+            "i64" => "int64_t",
+            "isize" => "ptrdiff_t",
+            "u8" => "uint8_t",
+            "u16" => "uint16_t",
+            "u32" => "uint32_t",
+            "u64" => "uint64_t",
+            "usize" => "size_t",
+            "f32" => "float",
+            "f64" => "double",
+            _ => "",
+        };
+
+        if !native.is_empty() {
+            return native.to_string();
+        }
+
+        let rust_type = match rust_type {
+            "&str" | "AzString" => "String",
+            other => other,
+        };
+
+        match self {
+            ForeignLanguage::C => format!("Az{}", rust_type),
+            _ => rust_type.to_string(),
+        }
+    }
+
+    /// Value that is passed to a component if the XML node does not set the argument
+    fn default_value(&self, rust_type: &str) -> String {
+        match (self, rust_type.trim()) {
+            (_, "String") | (_, "AzString") | (_, "&str") => self.string_literal(""),
+            (ForeignLanguage::Python, "bool") => String::from("False"),
+            (_, "bool") => String::from("false"),
+            (_, t) if is_numeric_type(t) => String::from("0"),
+            (ForeignLanguage::Python, _) => String::from("None"),
+            (ForeignLanguage::C, t) => format!("({}){{ 0 }}", self.type_name(t)),
+            (ForeignLanguage::Cpp, t) => format!("{}{{}}", self.type_name(t)),
+        }
+    }
+
+    fn string_literal(&self, s: &str) -> String {
+        let escaped = escape_string_literal(s);
+        match self {
+            ForeignLanguage::C | ForeignLanguage::Cpp => format!("az_str(\"{}\")", escaped),
+            ForeignLanguage::Python => format!("\"{}\"", escaped),
+        }
+    }
+
+    fn tab_index(&self, tab_index: crate::dom::TabIndex) -> String {
+        use crate::dom::TabIndex;
+        match (self, tab_index) {
+            (ForeignLanguage::C, TabIndex::Auto) => String::from("(AzTabIndex)AzTabIndex_Auto"),
+            (ForeignLanguage::C, TabIndex::OverrideInParent(i)) => format!("(AzTabIndex)AzTabIndex_OverrideInParent({})", i),
+            (ForeignLanguage::C, TabIndex::NoKeyboardFocus) => String::from("(AzTabIndex)AzTabIndex_NoKeyboardFocus"),
+            (ForeignLanguage::Cpp, TabIndex::Auto) => String::from("TabIndex { .Auto = { TabIndexTag::Auto } }"),
+            (ForeignLanguage::Cpp, TabIndex::OverrideInParent(i)) => format!("TabIndex {{ .OverrideInParent = {{ TabIndexTag::OverrideInParent, {} }} }}", i),
+            (ForeignLanguage::Cpp, TabIndex::NoKeyboardFocus) => String::from("TabIndex { .NoKeyboardFocus = { TabIndexTag::NoKeyboardFocus } }"),
+            (ForeignLanguage::Python, TabIndex::Auto) => String::from("TabIndex.Auto"),
+            (ForeignLanguage::Python, TabIndex::OverrideInParent(i)) => format!("TabIndex.OverrideInParent({})", i),
+            (ForeignLanguage::Python, TabIndex::NoKeyboardFocus) => String::from("TabIndex.NoKeyboardFocus"),
+        }
+    }
+
+    /// Compiles a string with `{variables}` into an expression that evaluates to a `String`
+    fn format_dynamic_items(&self, input: &[DynamicItem], variables: &ComponentArgumentsMap) -> String {
+
+        use self::DynamicItem::*;
+
+        let variable_type = |v: &str| variables.get(v).map(|(ty, _)| ty.trim().to_string()).unwrap_or(String::from("String"));
+
+        match input {
+            [] => return self.string_literal(""),
+            [Str(s)] => return self.string_literal(s),
+            [Var(v)] if variable_type(&normalize_casing(v.trim())) == "String" => return normalize_casing(v.trim()),
+            _ => { },
+        }
+
+        match self {
+            ForeignLanguage::C | ForeignLanguage::Cpp => {
+                // az_format("hello %.*s", AZ_STR_ARG(name))
+                let mut format_str = String::new();
+                let mut format_args = Vec::new();
+                for item in input {
+                    match item {
+                        Var(v) => {
+                            let variable_name = normalize_casing(v.trim());
+                            let (specifier, arg) = match variable_type(&variable_name).as_str() {
+                                "bool" => ("%s", format!("{} ? \"true\" : \"false\"", variable_name)),
+                                "f32" | "f64" => ("%g", format!("(double){}", variable_name)),
+                                t if is_numeric_type(t) => ("%lld", format!("(long long){}", variable_name)),
+                                _ => ("%.*s", format!("AZ_STR_ARG({})", variable_name)),
+                            };
+                            format_str.push_str(specifier);
+                            format_args.push(arg);
+                        },
+                        Str(s) => format_str.push_str(&escape_string_literal(s).replace("%", "%%")),
+                    }
+                }
+                format!("az_format(\"{}\", {})", format_str, format_args.join(", "))
+            },
+            ForeignLanguage::Python => {
+                // f"hello {name}"
+                let mut format_str = String::from("f\"");
+                for item in input {
+                    match item {
+                        Var(v) => format_str.push_str(&format!("{{{}}}", normalize_casing(v.trim()))),
+                        Str(s) => format_str.push_str(&escape_string_literal(s).replace("{", "{{").replace("}", "}}")),
+                    }
+                }
+                format_str.push('\"');
+                format_str
+            },
+        }
+    }
+
+    /// Compiles the value of a component argument, i.e. the `false` in `<checkbox checked="false" />`
+    fn format_argument(&self, value: &str, rust_type: &str, variables: &ComponentArgumentsMap) -> String {
+
+        let items = split_dynamic_string(value.trim());
+
+        if let [DynamicItem::Var(v)] = items.as_slice() {
+            return normalize_casing(v.trim());
+        }
+
+        let kind = ComponentArgumentKind::from_type_name(rust_type);
+        let parsed = match kind.as_ref() {
+            Some(kind) => kind.parse_value(value).ok(),
+            None => None,
+        };
+
+        match (self, parsed) {
+            (ForeignLanguage::Python, Some(ComponentArgumentValue::Bool(true))) => String::from("True"),
+            (ForeignLanguage::Python, Some(ComponentArgumentValue::Bool(false))) => String::from("False"),
+            (_, Some(ComponentArgumentValue::Bool(_))) |
+            (_, Some(ComponentArgumentValue::Integer(_))) |
+            (_, Some(ComponentArgumentValue::Float(_))) => value.trim().to_string(),
+            (ForeignLanguage::C, Some(ComponentArgumentValue::Color(c))) => {
+                format!("(AzColorU){{ .r = {}, .g = {}, .b = {}, .a = {} }}", c.r, c.g, c.b, c.a)
+            },
+            (ForeignLanguage::Cpp, Some(ComponentArgumentValue::Color(c))) => {
+                format!("ColorU {{ .r = {}, .g = {}, .b = {}, .a = {} }}", c.r, c.g, c.b, c.a)
+            },
+            (ForeignLanguage::Python, Some(ComponentArgumentValue::Color(c))) => {
+                format!("ColorU({}, {}, {}, {})", c.r, c.g, c.b, c.a)
+            },
+            (_, Some(ComponentArgumentValue::Enum(variant))) => {
+                let enum_name = kind.map(|k| k.get_rust_type_name()).unwrap_or_default();
+                match self {
+                    ForeignLanguage::C => format!("Az{}_{}", enum_name, variant),
+                    ForeignLanguage::Cpp => format!("{}::{}", enum_name, variant),
+                    ForeignLanguage::Python => format!("{}.{}", enum_name, variant),
+                }
+            },
+            _ => self.format_dynamic_items(&items, variables),
+        }
+    }
+
+    fn css_constant(&self, css: &str) -> String {
+        match self {
+            ForeignLanguage::C | ForeignLanguage::Cpp => {
+                if css.is_empty() {
+                    String::from("static const char* CSS = \"\";")
+                } else {
+                    let lines = css.lines()
+                    .map(|l| format!("    \"{}\\n\"", escape_string_literal(l)))
+                    .collect::<Vec<_>>()
+                    .join("\r\n");
+                    format!("static const char* CSS =\r\n{};", lines)
+                }
+            },
+            ForeignLanguage::Python => format!("CSS = \"\"\"\r\n{}\"\"\"", css.replace("\\", "\\\\")),
+        }
+    }
+
+    fn prelude(&self) -> &'static str {
+        match self {
+            ForeignLanguage::C => "\
+#include <azul.h>
+#include <stdarg.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#define AZ_STR_ARG(s) (int)(s).vec.len, (const char*)(s).vec.ptr
+
+static AzString az_str(const char* s) {
+    return AzString_copyFromBytes((const uint8_t*)s, 0, strlen(s));
+}
+
+static AzString az_format(const char* format, ...) {
+    va_list args;
+    va_start(args, format);
+    int len = vsnprintf(NULL, 0, format, args);
+    va_end(args);
+    if (len <= 0) { return az_str(\"\"); }
+    char* buffer = (char*)malloc((size_t)len + 1);
+    va_start(args, format);
+    vsnprintf(buffer, (size_t)len + 1, format, args);
+    va_end(args);
+    AzString s = AzString_copyFromBytes((const uint8_t*)buffer, 0, (size_t)len);
+    free(buffer);
+    return s;
+}",
+            ForeignLanguage::Cpp => "\
+#include <azul.hpp>
+#include <cstdarg>
+#include <cstdio>
+#include <cstring>
+#include <vector>
+
+using namespace dll;
+
+#define AZ_STR_ARG(s) (int)(s).vec.len, (const char*)(s).vec.ptr
+
+static String az_str(const char* s) {
+    return String_copyFromBytes((const uint8_t*)s, 0, std::strlen(s));
+}
+
+static String az_format(const char* format, ...) {
+    va_list args;
+    va_start(args, format);
+    int len = std::vsnprintf(nullptr, 0, format, args);
+    va_end(args);
+    if (len <= 0) { return az_str(\"\"); }
+    std::vector<char> buffer(static_cast<size_t>(len) + 1);
+    va_start(args, format);
+    std::vsnprintf(buffer.data(), buffer.size(), format, args);
+    va_end(args);
+    return String_copyFromBytes((const uint8_t*)buffer.data(), 0, static_cast<size_t>(len));
+}",
+            ForeignLanguage::Python => "from azul import *",
+        }
+    }
+
+    fn main_function(&self) -> &'static str {
+        match self {
+            ForeignLanguage::C => "\
+typedef struct { int _unused; } Data;
+
+void Data_delete(void* restrict A) { }
+AZ_REFLECT(Data, Data_delete);
+
+AzStyledDom layout(AzRefAny* restrict data, AzLayoutCallbackInfo info) {
+    AzDom dom = ui_render();
+    return AzDom_style(&dom, AzCss_fromString(az_str(CSS)));
+}
+
+int main() {
+    Data data = { 0 };
+    AzApp app = AzApp_new(Data_upcast(data), AzAppConfig_new(AzLayoutSolver_Default));
+    AzWindowCreateOptions window = AzWindowCreateOptions_new(layout);
+    window.state.flags.frame = AzWindowFrame_Maximized;
+    AzApp_run(&app, window);
+    return 0;
+}",
+            ForeignLanguage::Cpp => "\
+struct Data { };
+
+static const uint64_t DATA_TYPE_ID = 0;
+
+static void Data_delete(void* data) { }
+
+StyledDom layout(RefAny* data, LayoutCallbackInfo info) {
+    Dom dom = ui_render();
+    return Dom_style(&dom, Css_fromString(az_str(CSS)));
+}
+
+int main() {
+    Data data;
+    RefAny refany = RefAny_newC(&data, sizeof(Data), (uint64_t)&DATA_TYPE_ID, az_str(\"Data\"), Data_delete);
+    App app = App_new(refany, AppConfig_new(LayoutSolver::Default));
+    WindowCreateOptions window = WindowCreateOptions_new(layout);
+    window.state.flags.frame = WindowFrame::Maximized;
+    App_run(&app, window);
+    return 0;
+}",
+            ForeignLanguage::Python => "\
+class Data:
+    pass
+
+def layout(data, info):
+    return ui_render().style(Css.from_string(CSS))
+
+if __name__ == \"__main__\":
+    app = App(Data(), AppConfig(LayoutSolver.Default))
+    app.run(WindowCreateOptions(layout))",
+        }
+    }
+}
+
+fn is_numeric_type(rust_type: &str) -> bool {
+    match rust_type {
+        "i8" | "i16" | "i32" | "i64" | "isize" |
+        "u8" | "u16" | "u32" | "u64" | "usize" |
+        "f32" | "f64" => true,
+        _ => false,
+    }
+}
+
+fn escape_string_literal(input: &str) -> String {
+    input
+    .replace("\\", "\\\\")
+    .replace("\"", "\\\"")
+    .replace("\r", "")
+    .replace("\n", "\\n")
+}
+
+/// `add_child` -> `addChild`
+fn snake_case_to_camel_case(input: &str) -> String {
+    let mut words = input.split('_');
+    let mut s = words.next().unwrap_or_default().to_string();
+    for word in words {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            s.extend(first.to_uppercase());
+            s.extend(chars);
+        }
+    }
+    s
+}
+
+/// Parses an XML string and returns a `String`, which contains the C, C++ or Python source code.
+///
+/// Works like `str_to_rust_code`, except that the `<style>` is embedded as a CSS string
+/// and applied when the layout callback is invoked, instead of being compiled into
+/// inline CSS properties for every node.
+pub fn str_to_foreign_code<'a>(
+    root_nodes: &'a [XmlNode],
+    language: ForeignLanguage,
+    component_map: &'a mut XmlComponentMap
+) -> Result<String, CompileError<'a>> {
+
+    use crate::css::css_to_stylesheet_string;
+
+    let html_node = get_html_node(&root_nodes)?;
+    let body_node = get_body_node(html_node.children.as_ref())?;
+    let mut global_style = Css::empty();
+
+    if let Some(head_node) = html_node.children.as_ref().iter().find(|n| normalize_casing(&n.node_type).as_str() == "head") {
+        for node in head_node.children.as_ref() {
+            match DynamicXmlComponent::new(node) {
+                Ok(node) => {
+                    let node_name = node.name.clone();
+                    component_map.register_component(node_name.as_str(), Box::new(node), false);
+                },
+                Err(ComponentParseError::NotAComponent) => { }, // not a <component /> node, ignore
+                Err(e) => return Err(CompileError::Xml(e.into())), // Error during parsing the XML component, bail
+            }
+        }
+
+        if let Some(parsed_css) = parse_head_styles(head_node)? {
+            global_style = parsed_css;
+        }
+    }
+
+    global_style.sort_by_specificity();
+
+    let components = compile_components_to_foreign_code(language, component_map)?;
+    let app_source = compile_body_node_to_foreign_code(language, &body_node, component_map)?;
+
+    let (comment, prototypes) = match language {
+        ForeignLanguage::C | ForeignLanguage::Cpp => (
+            "// Auto-generated UI source code",
+            components.iter().map(|(header, _)| format!("{};", header)).collect::<Vec<_>>().join("\r\n"),
+        ),
+        ForeignLanguage::Python => ("# Auto-generated UI source code", String::new()),
+    };
+
+    let components = components.into_iter().map(|(_, f)| f).collect::<Vec<_>>().join("\r\n\r\n");
+
+    let source_code = vec![
+        comment.to_string(),
+        language.prelude().to_string(),
+        language.css_constant(&css_to_stylesheet_string(&global_style)),
+        prototypes,
+        components,
+        app_source,
+        language.main_function().to_string(),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join("\r\n\r\n");
+
+    Ok(source_code)
+}
+
+/// Compiles every component into a `{component}_render` function,
+/// returns the (function header, function source code)
+pub fn compile_components_to_foreign_code<'a>(
+    language: ForeignLanguage,
+    components: &'a XmlComponentMap
+) -> Result<Vec<(String, String)>, CompileError<'a>> {
+
+    let mut functions = Vec::new();
+
+    for (component_name, (renderer, _)) in &components.components {
+
+        let component_args = renderer.get_available_arguments();
+        let function_args = get_foreign_function_args(&component_args);
+        let header = language.function_header(&format!("{}_render", component_name.replace(':', "_")), &function_args);
+
+        let root = renderer.compile_to_foreign_code(language, components, &component_args, &OptionAzString::None)?;
+        let mut statements = vec![language.declare_dom("dom", &root)];
+        let mut node_count = 0;
+
+        for child_node in renderer.get_xml_node().children.as_ref() {
+            if normalize_casing(&child_node.node_type).as_str() == "style" {
+                continue; // component-local styles are not (yet) supported
+            }
+            compile_node_to_foreign_code_inner(
+                language,
+                child_node,
+                components,
+                &component_args.args,
+                "dom",
+                &mut node_count,
+                &mut statements,
+            )?;
+        }
+
+        statements.push(language.return_dom("dom"));
+        let function = language.function(&header, &statements);
+        functions.push((header, function));
+    }
+
+    Ok(functions)
+}
+
+/// Returns the (name, type) of the function arguments, text is passed as the first argument
+fn get_foreign_function_args(component_args: &ComponentArguments) -> Vec<(String, String)> {
+
+    let mut args = component_args.args.iter()
+    .map(|(arg_name, (arg_type, arg_index))| {
+        let arg_type = ComponentArgumentKind::from_type_name(arg_type)
+            .map(|kind| kind.get_rust_type_name())
+            .unwrap_or_else(|| arg_type.clone());
+        (*arg_index, arg_name.clone(), arg_type)
+    })
+    .collect::<Vec<_>>();
+
+    args.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut function_args = Vec::new();
+    if component_args.accepts_text {
+        function_args.push((String::from("text"), String::from("String")));
+    }
+    function_args.extend(args.into_iter().map(|(_, name, ty)| (name, ty)));
+    function_args
+}
+
+/// Compiles the `<body>` node into the `ui_render()` function
+pub fn compile_body_node_to_foreign_code<'a>(
+    language: ForeignLanguage,
+    body_node: &'a XmlNode,
+    component_map: &'a XmlComponentMap,
+) -> Result<String, CompileError<'a>> {
+
+    let variables = ComponentArgumentsMap::default();
+    let mut statements = vec![language.declare_dom("body", &language.dom_constructor("body", ""))];
+    let mut node_count = 0;
+
+    set_foreign_code_attributes(language, &mut statements, "body", &body_node.attributes, &variables);
+
+    for child_node in body_node.children.as_ref() {
+        compile_node_to_foreign_code_inner(
+            language,
+            child_node,
+            component_map,
+            &variables,
+            "body",
+            &mut node_count,
+            &mut statements,
+        )?;
+    }
+
+    statements.push(language.return_dom("body"));
+
+    Ok(language.function(&language.function_header("ui_render", &[]), &statements))
+}
+
+/// Appends the statements that create the node (and its children) and
+/// append it to the `parent_node`. `variables` are the arguments of the function
+/// that is currently being compiled.
+pub fn compile_node_to_foreign_code_inner<'a>(
+    language: ForeignLanguage,
+    node: &'a XmlNode,
+    component_map: &'a XmlComponentMap,
+    variables: &ComponentArgumentsMap,
+    parent_node: &str,
+    node_count: &mut usize,
+    statements: &mut Vec<String>,
+) -> Result<(), CompileError<'a>> {
+
+    let component_name = normalize_casing(&node.node_type);
+
+    match component_name.as_str() {
+        "for" | "if" | "else" | "slot" => {
+            return Err(component_error(node, ComponentError::TemplateNotSupported(component_name.into())).into());
+        },
+        _ => { },
+    }
+
+    let (renderer, _) = component_map.components.get(&component_name)
+        .ok_or_else(|| get_unknown_component_error(&component_name, component_map))
+        .map_err(|e| CompileError::from(component_error(node, e)))?;
+
+    // validate the arguments, but pass all arguments of the function (unset arguments get a default value)
+    let available_function_args = renderer.get_available_arguments();
+    let _ = validate_and_filter_component_args(&node.attributes, &available_function_args)
+        .map_err(|e| CompileError::from(component_error(node, e)))?;
+
+    let function_args = get_foreign_function_args(&available_function_args)
+    .into_iter()
+    .map(|(arg_name, arg_type)| {
+        if available_function_args.accepts_text && arg_name == "text" {
+            let node_text = node.text.as_ref().map(|s| prepare_string(s.as_str())).unwrap_or_default();
+            language.format_dynamic_items(&split_dynamic_string(&node_text), variables)
+        } else {
+            match node.attributes.get_key(&arg_name) {
+                Some(value) => {
+                    // format_argument needs the declared type, which includes the variants of enums
+                    let declared_type = available_function_args.args.get(&arg_name).map(|(t, _)| t.as_str()).unwrap_or(&arg_type);
+                    language.format_argument(value.as_str(), declared_type, variables)
+                },
+                None => language.default_value(&arg_type),
+            }
+        }
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+
+    let node_name = format!("node_{}", *node_count);
+    *node_count += 1;
+
+    statements.push(language.declare_dom(&node_name, &format!("{}_render({})", component_name.replace(':', "_"), function_args)));
+    set_foreign_code_attributes(language, statements, &node_name, &node.attributes, variables);
+
+    for child_node in node.children.as_ref() {
+        compile_node_to_foreign_code_inner(
+            language,
+            child_node,
+            component_map,
+            variables,
+            &node_name,
+            node_count,
+            statements,
+        )?;
+    }
+
+    statements.push(language.dom_method_call(parent_node, "add_child", &node_name));
+
+    Ok(())
+}
+
+/// Same as `set_stringified_attributes`, but for C, C++ and Python
+fn set_foreign_code_attributes(
+    language: ForeignLanguage,
+    statements: &mut Vec<String>,
+    node_name: &str,
+    xml_attributes: &XmlAttributeMap,
+    variables: &ComponentArgumentsMap,
+) {
+    use crate::dom::TabIndex;
+
+    for id in xml_attributes.get_key("id").map(|s| s.split_whitespace().collect::<Vec<_>>()).unwrap_or_default() {
+        let id = language.format_dynamic_items(&split_dynamic_string(id), variables);
+        statements.push(language.dom_method_call(node_name, "add_id", &id));
+    }
+
+    for class in xml_attributes.get_key("class").map(|s| s.split_whitespace().collect::<Vec<_>>()).unwrap_or_default() {
+        let class = language.format_dynamic_items(&split_dynamic_string(class), variables);
+        statements.push(language.dom_method_call(node_name, "add_class", &class));
+    }
+
+    if let Some(focusable) = xml_attributes.get_key("focusable").and_then(|f| parse_bool(f.as_str())) {
+        let tab_index = if focusable { TabIndex::Auto } else { TabIndex::NoKeyboardFocus };
+        statements.push(language.dom_method_call(node_name, "set_tab_index", &language.tab_index(tab_index)));
+    }
+
+    if let Some(tab_index) = xml_attributes.get_key("tabindex").and_then(|val| val.parse::<isize>().ok()) {
+        let tab_index = match tab_index {
+            0 => TabIndex::Auto,
+            i if i > 0 => TabIndex::OverrideInParent(i as u32),
+            _ => TabIndex::NoKeyboardFocus,
+        };
+        statements.push(language.dom_method_call(node_name, "set_tab_index", &language.tab_index(tab_index)));
+    }
+}
+
+/// Component that was created from a XML node (instead of being registered from Rust code).
+/// Necessary to
+pub struct DynamicXmlComponent {
+    /// What the name of this component is, i.e. "test" for `<component name="test" />`
+    pub name: String,
+    /// Whether this component has any `args="a: String"` arguments
+    pub arguments: ComponentArguments,
+    /// Root XML node of this component (the `<component />` Node)
+    pub root: XmlNode,
+}
+
+impl DynamicXmlComponent {
+
+    /// Parses a `component` from an XML node
+    pub fn new<'a>(root: &'a XmlNode) -> Result<Self, ComponentParseError<'a>> {
+
+        let node_type = normalize_casing(&root.node_type);
+
+        if node_type.as_str() != "component" {
+            return Err(ComponentParseError::NotAComponent);
+        }
+
+        let name = root.attributes.get_key("name").cloned().ok_or(ComponentParseError::NotAComponent)?;
+        let accepts_text = root.attributes.get_key("accepts_text").and_then(|p| parse_bool(p.as_str())).unwrap_or(false);
+
+        let args = match root.attributes.get_key("args") {
+            Some(s) => parse_component_arguments(s).map_err(|e| e.with_span(root.get_attribute_span("args")))?,
+            None => ComponentArgumentsMap::default(),
+        };
+
+        Ok(Self {
+            name: normalize_casing(&name),
+            arguments: ComponentArguments {
+                args,
+                accepts_text,
+                accepts_slot: contains_slot(root),
+            },
+            root: root.clone(),
+        })
+    }
+}
+
+impl XmlComponent for DynamicXmlComponent {
+
+    fn get_available_arguments(&self) -> ComponentArguments {
         self.arguments.clone()
     }
 
@@ -2200,11 +3585,23 @@ impl XmlComponent for DynamicXmlComponent {
         arguments: &FilteredComponentArguments,
         content: &XmlTextContent,
     ) -> Result<StyledDom, RenderDomError<'a>> {
+        let empty_slot = XmlSlot { children: &[], scope: arguments, parent: None };
+        self.render_dom_with_slot(components, arguments, content, &empty_slot).map(|(dom, _)| dom)
+    }
+
+    fn render_dom_with_slot<'a>(
+        &'a self,
+        components: &'a XmlComponentMap,
+        arguments: &FilteredComponentArguments,
+        _: &XmlTextContent,
+        slot: &XmlSlot<'a, '_>,
+    ) -> Result<(StyledDom, bool), RenderDomError<'a>> {
 
         let mut component_css = match find_node_by_type(self.root.children.as_ref(), "style") {
             Some(style_node) => {
                 if let Some(text) = style_node.text.as_ref().map(|s| s.as_str()) {
-                    let parsed_css = azul_css_parser::new_from_str(&text)?;
+                    let parsed_css = azul_css_parser::new_from_str(&text)
+                        .map_err(|e| RenderDomError::from(e).with_span(style_node.text_span))?;
                     Some(parsed_css)
                 } else {
                     None
@@ -2214,16 +3611,13 @@ impl XmlComponent for DynamicXmlComponent {
         };
 
         let mut dom = StyledDom::default();
-
-        for child_node in self.root.children.as_ref() {
-            dom.append_child(render_dom_from_body_node_inner(child_node, components, arguments)?);
-        }
+        render_dom_from_template_nodes(&mut dom, self.root.children.as_ref(), components, arguments, Some(slot))?;
 
         if let Some(css) = component_css.as_mut() {
             dom.restyle(css);
         }
 
-        Ok(dom)
+        Ok((dom, self.arguments.accepts_slot))
     }
 
     fn compile_to_rust_code(
@@ -2358,6 +3752,12 @@ impl XmlComponent for DynamicXmlComponent {
             parse_component_arguments("gridVisible: bool, selectedDate DateTime, minimumDate: DateTime"),
             Err(ComponentParseError::WhiteSpaceInComponentName(1, "selectedDate DateTime".to_string()))
         );
+
+        // Missing closing brace in the enum type
+        assert_eq!(
+            parse_component_arguments("gridVisible: bool, firstDayOfWeek: WeekDay(Monday|Sunday"),
+            Err(ComponentParseError::InvalidEnumType(1, "firstDayOfWeek".into(), "WeekDay(Monday|Sunday".into()))
+        );
     }
 
     #[test]
@@ -2438,4 +3838,415 @@ impl XmlComponent for DynamicXmlComponent {
         let output = prepare_string(input1);
         assert_eq!(output, String::from("Hello, 123\nTest Test2\nTest3\nTest4"));
     }
+
+    // -- Code generation for C, C++ and Python
+
+    fn xml_node(node_type: &str, attributes: &[(&str, &str)], text: Option<&str>, children: Vec<XmlNode>) -> XmlNode {
+        XmlNode {
+            node_type: node_type.into(),
+            attributes: attributes.iter().map(|(k, v)| AzStringPair { key: (*k).into(), value: (*v).into() }).collect(),
+            children: children.into(),
+            text: text.map(|t| AzString::from(t)).into(),
+            .. Default::default()
+        }
+    }
+
+    // <html>
+    //     <head>
+    //         <component name="greeting" args="name: String, count: u32">
+    //             <p class="title">Hello {name}!</p>
+    //             <p>{count} items</p>
+    //         </component>
+    //     </head>
+    //     <body>
+    //         <div id="main" class="container">
+    //             <greeting name="World" count="5" />
+    //         </div>
+    //     </body>
+    // </html>
+    fn foreign_code_test_tree() -> Vec<XmlNode> {
+        vec![
+            xml_node("html", &[], None, vec![
+                xml_node("head", &[], None, vec![
+                    xml_node("component", &[("name", "greeting"), ("args", "name: String, count: u32")], None, vec![
+                        xml_node("p", &[("class", "title")], Some("Hello {name}!"), vec![]),
+                        xml_node("p", &[], Some("{count} items"), vec![]),
+                    ]),
+                ]),
+                xml_node("body", &[], None, vec![
+                    xml_node("div", &[("id", "main"), ("class", "container")], None, vec![
+                        xml_node("greeting", &[("name", "World"), ("count", "5")], None, vec![]),
+                    ]),
+                ]),
+            ]),
+        ]
+    }
+
+    fn foreign_code_test_component_map(root_nodes: &[XmlNode]) -> XmlComponentMap {
+        let mut component_map = XmlComponentMap::default();
+        let head_node = &root_nodes[0].children.as_ref()[0];
+        let component = DynamicXmlComponent::new(&head_node.children.as_ref()[0]).unwrap();
+        component_map.register_component("greeting", Box::new(component), false);
+        component_map
+    }
+
+    // expected source code is written with \n, the generated code uses \r\n
+    fn crlf(s: &str) -> String {
+        s.trim_start_matches('\n').replace("\n", "\r\n")
+    }
+
+    /// Compiles the test tree, returns the `greeting_render` function and the `ui_render` function
+    fn compile_foreign_test_tree(language: ForeignLanguage) -> (String, String) {
+        let root_nodes = foreign_code_test_tree();
+        let component_map = foreign_code_test_component_map(&root_nodes);
+        let body_node = &root_nodes[0].children.as_ref()[1];
+
+        let components = compile_components_to_foreign_code(language, &component_map).unwrap();
+        let (_, greeting) = components.iter().find(|(header, _)| header.contains("greeting_render")).unwrap();
+        let ui_render = compile_body_node_to_foreign_code(language, body_node, &component_map).unwrap();
+
+        (greeting.clone(), ui_render)
+    }
+
+    #[test]
+    fn test_compile_foreign_code_c() {
+
+        let (greeting, ui_render) = compile_foreign_test_tree(ForeignLanguage::C);
+
+        assert_eq!(greeting, crlf(r#"
+AzDom greeting_render(AzString name, uint32_t count) {
+    AzDom dom = AzDom_div();
+    AzDom node_0 = p_render(az_format("Hello %.*s!", AZ_STR_ARG(name)));
+    AzDom_addClass(&node_0, az_str("title"));
+    AzDom_addChild(&dom, node_0);
+    AzDom node_1 = p_render(az_format("%lld items", (long long)count));
+    AzDom_addChild(&dom, node_1);
+    return dom;
+}"#));
+
+        assert_eq!(ui_render, crlf(r#"
+AzDom ui_render(void) {
+    AzDom body = AzDom_body();
+    AzDom node_0 = div_render();
+    AzDom_addId(&node_0, az_str("main"));
+    AzDom_addClass(&node_0, az_str("container"));
+    AzDom node_1 = greeting_render(az_str("World"), 5);
+    AzDom_addChild(&node_0, node_1);
+    AzDom_addChild(&body, node_0);
+    return body;
+}"#));
+
+        // every component function is declared before it is used
+        let root_nodes = foreign_code_test_tree();
+        let mut component_map = XmlComponentMap::default();
+        let source = str_to_foreign_code(&root_nodes, ForeignLanguage::C, &mut component_map).unwrap();
+        assert!(source.contains(&crlf(r#"
+AzDom body_render(void);
+AzDom div_render(void);
+AzDom greeting_render(AzString name, uint32_t count);
+AzDom p_render(AzString text);"#)));
+    }
+
+    #[test]
+    fn test_compile_foreign_code_cpp() {
+
+        let (greeting, ui_render) = compile_foreign_test_tree(ForeignLanguage::Cpp);
+
+        assert_eq!(greeting, crlf(r#"
+Dom greeting_render(String name, uint32_t count) {
+    Dom dom = Dom_div();
+    Dom node_0 = p_render(az_format("Hello %.*s!", AZ_STR_ARG(name)));
+    Dom_addClass(&node_0, az_str("title"));
+    Dom_addChild(&dom, node_0);
+    Dom node_1 = p_render(az_format("%lld items", (long long)count));
+    Dom_addChild(&dom, node_1);
+    return dom;
+}"#));
+
+        assert_eq!(ui_render, crlf(r#"
+Dom ui_render() {
+    Dom body = Dom_body();
+    Dom node_0 = div_render();
+    Dom_addId(&node_0, az_str("main"));
+    Dom_addClass(&node_0, az_str("container"));
+    Dom node_1 = greeting_render(az_str("World"), 5);
+    Dom_addChild(&node_0, node_1);
+    Dom_addChild(&body, node_0);
+    return body;
+}"#));
+
+        let root_nodes = foreign_code_test_tree();
+        let mut component_map = XmlComponentMap::default();
+        let source = str_to_foreign_code(&root_nodes, ForeignLanguage::Cpp, &mut component_map).unwrap();
+        assert!(source.contains(&crlf(r#"
+Dom body_render();
+Dom div_render();
+Dom greeting_render(String name, uint32_t count);
+Dom p_render(String text);"#)));
+    }
+
+    #[test]
+    fn test_compile_foreign_code_python() {
+
+        let (greeting, ui_render) = compile_foreign_test_tree(ForeignLanguage::Python);
+
+        assert_eq!(greeting, crlf(r#"
+def greeting_render(name, count):
+    dom = Dom.div()
+    node_0 = p_render(f"Hello {name}!")
+    node_0.add_class("title")
+    dom.add_child(node_0)
+    node_1 = p_render(f"{count} items")
+    dom.add_child(node_1)
+    return dom"#));
+
+        assert_eq!(ui_render, crlf(r#"
+def ui_render():
+    body = Dom.body()
+    node_0 = div_render()
+    node_0.add_id("main")
+    node_0.add_class("container")
+    node_1 = greeting_render("World", 5)
+    node_0.add_child(node_1)
+    body.add_child(node_0)
+    return body"#));
+
+        // the whole file: python has no prototypes and the prelude is a single import
+        let root_nodes = foreign_code_test_tree();
+        let mut component_map = XmlComponentMap::default();
+        let source = str_to_foreign_code(&root_nodes, ForeignLanguage::Python, &mut component_map).unwrap();
+        let expected = crlf(r#"
+# Auto-generated UI source code
+
+from azul import *
+
+CSS = """
+"""
+
+def body_render():
+    dom = Dom.body()
+    return dom
+
+def div_render():
+    dom = Dom.div()
+    return dom
+
+def greeting_render(name, count):
+    dom = Dom.div()
+    node_0 = p_render(f"Hello {name}!")
+    node_0.add_class("title")
+    dom.add_child(node_0)
+    node_1 = p_render(f"{count} items")
+    dom.add_child(node_1)
+    return dom
+
+def p_render(text):
+    dom = Dom.text(text)
+    return dom
+
+def ui_render():
+    body = Dom.body()
+    node_0 = div_render()
+    node_0.add_id("main")
+    node_0.add_class("container")
+    node_1 = greeting_render("World", 5)
+    node_0.add_child(node_1)
+    body.add_child(node_0)
+    return body
+
+"#) + ForeignLanguage::Python.main_function();
+        assert_eq!(source, expected);
+    }
+
+    /// Runs the command, panics with its output if it doesn't exit successfully
+    fn assert_command_succeeds(command: &mut std::process::Command) {
+        let output = command.output().unwrap_or_else(|e| panic!("failed to run {:?}: {}", command, e));
+        assert!(
+            output.status.success(),
+            "{:?} failed:\n{}{}",
+            command,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr),
+        );
+    }
+
+    // Needs cc, c++, python3 and the azul python module, run on CI with --ignored
+    #[test]
+    #[ignore]
+    fn test_foreign_code_compiles_against_api() {
+
+        use std::process::Command;
+
+        let api_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("api");
+        let out_dir = std::env::temp_dir().join(format!("azul-foreign-code-{}", std::process::id()));
+        std::fs::create_dir_all(&out_dir).unwrap();
+
+        let source = |language| {
+            let root_nodes = foreign_code_test_tree();
+            let mut component_map = XmlComponentMap::default();
+            str_to_foreign_code(&root_nodes, language, &mut component_map).unwrap()
+        };
+
+        // -Werror: a destructor or callback with the wrong signature is only a warning in C
+        let c_file = out_dir.join("ui.c");
+        std::fs::write(&c_file, source(ForeignLanguage::C)).unwrap();
+        assert_command_succeeds(Command::new("cc").arg("-fsyntax-only").arg("-Werror").arg("-I").arg(api_dir.join("c")).arg(&c_file));
+
+        let cpp_file = out_dir.join("ui.cpp");
+        std::fs::write(&cpp_file, source(ForeignLanguage::Cpp)).unwrap();
+        assert_command_succeeds(Command::new("c++").arg("-fsyntax-only").arg("-Werror").arg("-I").arg(api_dir.join("cpp")).arg(&cpp_file));
+
+        // importing the module runs `from azul import *`, ui_render() calls every generated function
+        std::fs::write(out_dir.join("ui.py"), source(ForeignLanguage::Python)).unwrap();
+        assert_command_succeeds(Command::new("python3").arg("-c").arg("import ui; ui.ui_render()").current_dir(&out_dir));
+
+        let _ = std::fs::remove_dir_all(&out_dir);
+    }
+
+    // -- <for> and <if> / <else> templates
+
+    fn rendered_texts(dom: &StyledDom) -> Vec<String> {
+        use crate::dom::NodeType;
+        dom.node_data.as_ref().iter().filter_map(|n| match n.get_node_type() {
+            NodeType::Text(t) => Some(t.as_str().to_string()),
+            _ => None,
+        }).collect()
+    }
+
+    fn template_scope(args: &[(&str, &str)]) -> FilteredComponentArguments {
+        let mut scope = FilteredComponentArguments::default();
+        for (order, (k, v)) in args.iter().enumerate() {
+            scope.args.insert(k.to_string(), (v.to_string(), order));
+        }
+        scope
+    }
+
+    #[test]
+    fn test_render_for_loop() {
+
+        // <for each="item, i in items"><p>{i}: {item}</p></for>
+        let nodes = vec![
+            xml_node("for", &[("each", "item, i in items")], None, vec![
+                xml_node("p", &[], Some("{i}: {item}"), vec![]),
+            ]),
+        ];
+
+        let component_map = XmlComponentMap::default();
+
+        let mut dom = StyledDom::default();
+        render_dom_from_template_nodes(&mut dom, &nodes, &component_map, &template_scope(&[("items", "[a, b, c]")]), None).unwrap();
+        assert_eq!(rendered_texts(&dom), vec!["0: a", "1: b", "2: c"]);
+
+        // an empty list renders nothing
+        let mut dom = StyledDom::default();
+        render_dom_from_template_nodes(&mut dom, &nodes, &component_map, &template_scope(&[("items", "[]")]), None).unwrap();
+        assert_eq!(dom.node_count(), 1);
+
+        // iterating over something that isn't a list is an error
+        let mut dom = StyledDom::default();
+        assert!(render_dom_from_template_nodes(&mut dom, &nodes, &component_map, &template_scope(&[("items", "5")]), None).is_err());
+    }
+
+    #[test]
+    fn test_render_if_false_branch() {
+
+        // <if condition="show"><p>Shown</p></if>
+        // <else><p>Hidden</p></else>
+        let nodes = vec![
+            xml_node("if", &[("condition", "show")], None, vec![xml_node("p", &[], Some("Shown"), vec![])]),
+            xml_node("else", &[], None, vec![xml_node("p", &[], Some("Hidden"), vec![])]),
+        ];
+
+        let component_map = XmlComponentMap::default();
+
+        let mut dom = StyledDom::default();
+        render_dom_from_template_nodes(&mut dom, &nodes, &component_map, &template_scope(&[("show", "false")]), None).unwrap();
+        assert_eq!(rendered_texts(&dom), vec!["Hidden"]);
+
+        let mut dom = StyledDom::default();
+        render_dom_from_template_nodes(&mut dom, &nodes, &component_map, &template_scope(&[("show", "true")]), None).unwrap();
+        assert_eq!(rendered_texts(&dom), vec!["Shown"]);
+
+        // a false <if> without an <else> renders nothing
+        let mut dom = StyledDom::default();
+        render_dom_from_template_nodes(&mut dom, &nodes[..1], &component_map, &template_scope(&[("show", "false")]), None).unwrap();
+        assert_eq!(dom.node_count(), 1);
+
+        // <else> has to follow an <if>
+        let mut dom = StyledDom::default();
+        assert!(render_dom_from_template_nodes(&mut dom, &nodes[1..], &component_map, &template_scope(&[("show", "false")]), None).is_err());
+    }
+
+    #[test]
+    fn test_compile_templates_to_rust_code() {
+
+        // <for each="item, i in items"><p>{i}: {item}</p></for>
+        // <if condition="items.is_empty"><p>No items</p></if>
+        // <else><p>Has items</p></else>
+        let nodes = vec![
+            xml_node("for", &[("each", "item, i in items")], None, vec![
+                xml_node("p", &[], Some("{i}: {item}"), vec![]),
+            ]),
+            xml_node("if", &[("condition", "items.is_empty")], None, vec![xml_node("p", &[], Some("No items"), vec![])]),
+            xml_node("else", &[], None, vec![xml_node("p", &[], Some("Has items"), vec![])]),
+        ];
+
+        let component_map = XmlComponentMap::default();
+        let matcher = CssMatcher { path: Vec::new(), indices_in_parent: Vec::new(), children_length: Vec::new() };
+
+        let source = compile_template_children_to_rust_code(
+            &nodes,
+            &component_map,
+            &FilteredComponentArguments::default(),
+            0,
+            &mut VecContents::default(),
+            &mut BTreeMap::new(),
+            &Css::empty(),
+            &matcher,
+        ).unwrap();
+
+        assert_eq!(source, crlf(r#"
+{
+    let mut children = Vec::new();
+    for (i, item) in items.iter().cloned().enumerate() {
+        children.push(
+            p::render(format!("{i}: {item}", i, item).into())
+        );
+    }
+    if items.is_empty() {
+        children.push(
+            p::render(AzString::from_const_str("No items"))
+        );
+    } else {
+        children.push(
+            p::render(AzString::from_const_str("Has items"))
+        );
+    }
+    DomVec::from_vec(children)
+}"#));
+
+        // <else> without <if> is a compile error, too
+        assert!(compile_template_children_to_rust_code(
+            &nodes[2..],
+            &component_map,
+            &FilteredComponentArguments::default(),
+            0,
+            &mut VecContents::default(),
+            &mut BTreeMap::new(),
+            &Css::empty(),
+            &matcher,
+        ).is_err());
+    }
+
+    #[test]
+    fn test_format_typed_arg_for_rust_code() {
+        assert_eq!(format_typed_arg_for_rust_code("count", "5", "u32"), Ok(String::from("5")));
+        assert_eq!(format_typed_arg_for_rust_code("count", "{n}", "u32"), Ok(String::from("n")));
+        assert_eq!(format_typed_arg_for_rust_code("title", "Hello", "String"), Ok(String::from("AzString::from_const_str(\"Hello\")")));
+
+        // invalid static values are an error instead of being compiled to a string literal
+        let error = format_typed_arg_for_rust_code("count", "five", "u32").unwrap_err();
+        assert_eq!(error, ComponentError::InvalidArgumentValue("count".into(), "five".into(), "u32".into(), ArgumentValueError::ExpectedInteger));
+        assert_eq!(format!("{}", error), "Invalid value \"five\" for argument \"count: u32\": expected an integer");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,698 @@
+//! Expression language for the `<if condition="...">` and `<for each="...">` XML templates
+//!
+//! Expressions are evaluated against the arguments of the component that is currently
+//! being rendered. Since the XML arguments are untyped strings, the type of a variable
+//! is inferred from its value (see `XmlValue::from_argument`):
+//!
+//! ```xml,no_run,ignore
+//! <if condition="items.len > 0 and !hidden">
+//!     <for each="item, index in items">
+//!         <p>{index}: {item}</p>
+//!     </for>
+//! </if>
+//! <else>
+//!     <p>No items</p>
+//! </else>
+//! ```
+//!
+//! Supported are `true` / `false`, numbers, `'strings'`, `[lists]`, the operators
+//! `! - * / + == != < <= > >= && ||` (`not`, `and` and `or` can be used instead of
+//! `!`, `&&` and `||`, which have to be escaped in XML) and the `.len` / `.is_empty`
+//! properties of strings and lists.
+
+use core::fmt;
+use core::cmp::Ordering;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+/// Typed value of a component argument or an evaluated expression
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum XmlValue {
+    Bool(bool),
+    Number(f64),
+    String(String),
+    List(Vec<XmlValue>),
+}
+
+impl XmlValue {
+
+    /// Infers the type of a (string) component argument: `"true"` / `"false"` are booleans,
+    /// `"5"` or `"-0.5"` are numbers, `"[a, b, 'c, d']"` is a list of strings, everything
+    /// else is a string
+    pub fn from_argument(input: &str) -> Self {
+        let trimmed = input.trim();
+        match trimmed {
+            "true" => return XmlValue::Bool(true),
+            "false" => return XmlValue::Bool(false),
+            _ => { },
+        }
+
+        if let Some(number) = parse_number(trimmed) {
+            return XmlValue::Number(number);
+        }
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']') && trimmed.len() >= 2 {
+            let inner = &trimmed[1..(trimmed.len() - 1)];
+            if inner.trim().is_empty() {
+                return XmlValue::List(Vec::new());
+            }
+            return XmlValue::List(
+                split_list_items(inner).into_iter()
+                .map(|item| match unquote(item.trim()) {
+                    Some(s) => XmlValue::String(s.to_string()),
+                    None => XmlValue::from_argument(item),
+                })
+                .collect()
+            );
+        }
+
+        XmlValue::String(input.to_string())
+    }
+
+    /// Name of the type, used in error messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            XmlValue::Bool(_) => "bool",
+            XmlValue::Number(_) => "number",
+            XmlValue::String(_) => "string",
+            XmlValue::List(_) => "list",
+        }
+    }
+}
+
+impl fmt::Display for XmlValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            XmlValue::Bool(b) => write!(f, "{}", b),
+            XmlValue::Number(n) => {
+                if libm::trunc(*n) == *n && libm::fabs(*n) < 1e15 {
+                    write!(f, "{}", *n as i64)
+                } else {
+                    write!(f, "{}", n)
+                }
+            },
+            XmlValue::String(s) => write!(f, "{}", s),
+            XmlValue::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i != 0 { write!(f, ", ")?; }
+                    match item {
+                        // quote strings that would otherwise be split or parsed differently
+                        XmlValue::String(s) if s.contains(|c| ",[]'\"".contains(c)) ||
+                            XmlValue::from_argument(s) != *item => {
+                            write!(f, "'{}'", s)?;
+                        },
+                        other => write!(f, "{}", other)?,
+                    }
+                }
+                write!(f, "]")
+            },
+        }
+    }
+}
+
+/// Parsed expression
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum XmlExpr {
+    Literal(XmlValue),
+    Variable(String),
+    List(Vec<XmlExpr>),
+    Not(Box<XmlExpr>),
+    Negate(Box<XmlExpr>),
+    /// `expr.property`, i.e. `items.len`
+    Property(Box<XmlExpr>, String),
+    Binary(Box<XmlExpr>, XmlBinaryOp, Box<XmlExpr>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum XmlBinaryOp {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl XmlBinaryOp {
+    fn as_str(&self) -> &'static str {
+        use self::XmlBinaryOp::*;
+        match self {
+            Or => "||",
+            And => "&&",
+            Equal => "==",
+            NotEqual => "!=",
+            Less => "<",
+            LessEqual => "<=",
+            Greater => ">",
+            GreaterEqual => ">=",
+            Add => "+",
+            Subtract => "-",
+            Multiply => "*",
+            Divide => "/",
+        }
+    }
+}
+
+/// Error while parsing or evaluating an expression
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum XmlExprError {
+    /// Expression ended while expecting more input
+    UnexpectedEnd,
+    /// Character that is not valid in an expression, at the given character position
+    UnexpectedChar(char, usize),
+    /// Valid token, but in the wrong place (i.e. `a ==`, `(a b)`)
+    UnexpectedToken(String),
+    /// A `'` or `"` string literal is not closed
+    UnterminatedString,
+    /// Variable is not an argument of the current component
+    UnknownVariable(String),
+    /// Property is not `len` or `is_empty` or can't be used on the value
+    UnknownProperty(&'static str, String),
+    /// Operator can't be used with the given types: (operator, left type, right type)
+    TypeMismatch(&'static str, &'static str, &'static str),
+    /// `<if condition>` did not evaluate to a bool, holds the type of the result
+    ExpectedBool(&'static str),
+    /// `<for each>` did not evaluate to a list, holds the type of the result
+    ExpectedList(&'static str),
+    /// `each` is not in the form `item in items` or `item, index in items`
+    InvalidLoop(String),
+}
+
+impl fmt::Display for XmlExprError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::XmlExprError::*;
+        match self {
+            UnexpectedEnd => write!(f, "unexpected end of expression"),
+            UnexpectedChar(c, pos) => write!(f, "unexpected character '{}' at position {}", c, pos),
+            UnexpectedToken(t) => write!(f, "unexpected \"{}\"", t),
+            UnterminatedString => write!(f, "unterminated string literal"),
+            UnknownVariable(v) => write!(f, "unknown variable \"{}\"", v),
+            UnknownProperty(ty, p) => write!(f, "{} has no property \"{}\" (available: len, is_empty)", ty, p),
+            TypeMismatch(op, a, b) => write!(f, "operator \"{}\" can't be used with {} and {}", op, a, b),
+            ExpectedBool(ty) => write!(f, "condition must be a bool, found {}", ty),
+            ExpectedList(ty) => write!(f, "can only iterate over lists, found {}", ty),
+            InvalidLoop(s) => write!(f, "invalid loop \"{}\", expected \"item in items\" or \"item, index in items\"", s),
+        }
+    }
+}
+
+/// Parsed `<for each="item, index in items">` attribute
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct XmlForLoop {
+    /// Name of the variable that holds the current item
+    pub item: String,
+    /// Optional name of the variable that holds the index of the current item
+    pub index: Option<String>,
+    /// Expression that evaluates to the list to iterate over
+    pub items: XmlExpr,
+}
+
+impl XmlForLoop {
+    pub fn parse(input: &str) -> Result<Self, XmlExprError> {
+
+        let invalid = || XmlExprError::InvalidLoop(input.to_string());
+
+        let in_pos = input.find(" in ").ok_or_else(invalid)?;
+        let (variables, items) = (&input[..in_pos], &input[(in_pos + 4)..]);
+
+        let mut variables = variables.split(',').map(|v| v.trim());
+        let item = variables.next().filter(|v| is_identifier(v)).ok_or_else(invalid)?;
+        let index = match variables.next() {
+            Some(v) if is_identifier(v) => Some(v.to_string()),
+            Some(_) => return Err(invalid()),
+            None => None,
+        };
+
+        if variables.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            item: item.to_string(),
+            index,
+            items: XmlExpr::parse(items)?,
+        })
+    }
+}
+
+impl XmlExpr {
+
+    /// Parses an expression, i.e. `count > 0 && !hidden`
+    pub fn parse(input: &str) -> Result<Self, XmlExprError> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens: &tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(t) => Err(XmlExprError::UnexpectedToken(t.to_string())),
+        }
+    }
+
+    /// Evaluates the expression, `variables` resolves a variable name to its value
+    pub fn evaluate<F: Fn(&str) -> Option<XmlValue>>(&self, variables: &F) -> Result<XmlValue, XmlExprError> {
+
+        use self::XmlValue::*;
+        use self::XmlBinaryOp::*;
+
+        match self {
+            XmlExpr::Literal(v) => Ok(v.clone()),
+            XmlExpr::Variable(v) => variables(v).ok_or_else(|| XmlExprError::UnknownVariable(v.clone())),
+            XmlExpr::List(items) => {
+                Ok(List(items.iter().map(|i| i.evaluate(variables)).collect::<Result<Vec<_>, _>>()?))
+            },
+            XmlExpr::Not(e) => match e.evaluate(variables)? {
+                Bool(b) => Ok(Bool(!b)),
+                other => Err(XmlExprError::TypeMismatch("!", other.type_name(), other.type_name())),
+            },
+            XmlExpr::Negate(e) => match e.evaluate(variables)? {
+                Number(n) => Ok(Number(-n)),
+                other => Err(XmlExprError::TypeMismatch("-", other.type_name(), other.type_name())),
+            },
+            XmlExpr::Property(e, property) => {
+                let value = e.evaluate(variables)?;
+                match (&value, property.as_str()) {
+                    (String(s), "len") => Ok(Number(s.chars().count() as f64)),
+                    (List(l), "len") => Ok(Number(l.len() as f64)),
+                    (String(s), "is_empty") => Ok(Bool(s.is_empty())),
+                    (List(l), "is_empty") => Ok(Bool(l.is_empty())),
+                    _ => Err(XmlExprError::UnknownProperty(value.type_name(), property.clone())),
+                }
+            },
+            XmlExpr::Binary(a, op, b) => {
+
+                // short-circuit && and ||
+                if *op == And || *op == Or {
+                    let a = a.evaluate(variables)?;
+                    let a = match a {
+                        Bool(a) => a,
+                        other => return Err(XmlExprError::TypeMismatch(op.as_str(), other.type_name(), "bool")),
+                    };
+                    if (*op == And && !a) || (*op == Or && a) {
+                        return Ok(Bool(a));
+                    }
+                    return match b.evaluate(variables)? {
+                        Bool(b) => Ok(Bool(b)),
+                        other => Err(XmlExprError::TypeMismatch(op.as_str(), "bool", other.type_name())),
+                    };
+                }
+
+                let a = a.evaluate(variables)?;
+                let b = b.evaluate(variables)?;
+                let mismatch = || XmlExprError::TypeMismatch(op.as_str(), a.type_name(), b.type_name());
+
+                if a.type_name() != b.type_name() {
+                    return Err(mismatch());
+                }
+
+                match op {
+                    Equal => Ok(Bool(a == b)),
+                    NotEqual => Ok(Bool(a != b)),
+                    Less | LessEqual | Greater | GreaterEqual => {
+                        let ordering = match (&a, &b) {
+                            (Number(a), Number(b)) => a.partial_cmp(b),
+                            (String(a), String(b)) => Some(a.cmp(b)),
+                            _ => return Err(mismatch()),
+                        };
+                        let ordering = match ordering {
+                            Some(s) => s,
+                            None => return Ok(Bool(false)), // NaN
+                        };
+                        Ok(Bool(match op {
+                            Less => ordering == Ordering::Less,
+                            LessEqual => ordering != Ordering::Greater,
+                            Greater => ordering == Ordering::Greater,
+                            _ => ordering != Ordering::Less,
+                        }))
+                    },
+                    Add => match (&a, &b) {
+                        (Number(a), Number(b)) => Ok(Number(a + b)),
+                        (String(a), String(b)) => Ok(String(format!("{}{}", a, b))),
+                        (List(a), List(b)) => Ok(List(a.iter().chain(b.iter()).cloned().collect())),
+                        _ => Err(mismatch()),
+                    },
+                    Subtract | Multiply | Divide => match (&a, &b) {
+                        (Number(a), Number(b)) => Ok(Number(match op {
+                            Subtract => a - b,
+                            Multiply => a * b,
+                            _ => a / b,
+                        })),
+                        _ => Err(mismatch()),
+                    },
+                    And | Or => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Compiles the expression to Rust code, variables are passed through as-is
+    /// (the caller has to make sure that they are in scope)
+    pub fn to_rust_code(&self) -> String {
+        match self {
+            XmlExpr::Literal(v) => value_to_rust_code(v),
+            XmlExpr::Variable(v) => v.clone(),
+            XmlExpr::List(items) => format!("[{}]", items.iter().map(|i| i.to_rust_code()).collect::<Vec<_>>().join(", ")),
+            XmlExpr::Not(e) => format!("!{}", e.to_rust_code()),
+            XmlExpr::Negate(e) => format!("-{}", e.to_rust_code()),
+            XmlExpr::Property(e, p) => format!("{}.{}()", e.to_rust_code(), p),
+            XmlExpr::Binary(a, op, b) => {
+                // compare strings as &str: `name.as_str() == "x"`
+                let string_comparison = match (&**a, &**b) {
+                    (XmlExpr::Literal(XmlValue::String(_)), _) |
+                    (_, XmlExpr::Literal(XmlValue::String(_))) => true,
+                    _ => false,
+                };
+                let format_operand = |e: &XmlExpr| match e {
+                    XmlExpr::Variable(v) if string_comparison => format!("{}.as_str()", v),
+                    other => other.to_rust_code(),
+                };
+                format!("({} {} {})", format_operand(a), op.as_str(), format_operand(b))
+            },
+        }
+    }
+}
+
+fn value_to_rust_code(value: &XmlValue) -> String {
+    match value {
+        XmlValue::Bool(b) => format!("{}", b),
+        XmlValue::Number(_) => value.to_string(),
+        XmlValue::String(s) => format!("{:?}", s),
+        XmlValue::List(items) => format!("[{}]", items.iter().map(value_to_rust_code).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+enum Token {
+    Number(f64),
+    Str(String),
+    Ident(String),
+    Op(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", XmlValue::Number(*n)),
+            Token::Str(s) => write!(f, "'{}'", s),
+            Token::Ident(i) => write!(f, "{}", i),
+            Token::Op(o) => write!(f, "{}", o),
+        }
+    }
+}
+
+const OPERATORS: &[&str] = &[
+    "&&", "||", "==", "!=", "<=", ">=",
+    "(", ")", "[", "]", ",", ".", "!", "<", ">", "+", "-", "*", "/",
+];
+
+fn tokenize(input: &str) -> Result<Vec<Token>, XmlExprError> {
+
+    let chars = input.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    'outer: while pos < chars.len() {
+        let c = chars[pos];
+
+        if c.is_whitespace() {
+            pos += 1;
+            continue;
+        }
+
+        if c == '\'' || c == '"' {
+            let end = chars[(pos + 1)..].iter().position(|e| *e == c).ok_or(XmlExprError::UnterminatedString)?;
+            tokens.push(Token::Str(chars[(pos + 1)..(pos + 1 + end)].iter().collect()));
+            pos += end + 2;
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let len = chars[pos..].iter().take_while(|c| c.is_ascii_digit() || **c == '.').count();
+            let s = chars[pos..(pos + len)].iter().collect::<String>();
+            let number = s.parse::<f64>().map_err(|_| XmlExprError::UnexpectedToken(s.clone()))?;
+            tokens.push(Token::Number(number));
+            pos += len;
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let len = chars[pos..].iter().take_while(|c| c.is_alphanumeric() || **c == '_').count();
+            tokens.push(Token::Ident(chars[pos..(pos + len)].iter().collect()));
+            pos += len;
+            continue;
+        }
+
+        for op in OPERATORS {
+            let op_len = op.chars().count();
+            if chars.len() >= pos + op_len && chars[pos..(pos + op_len)].iter().copied().eq(op.chars()) {
+                tokens.push(Token::Op(op));
+                pos += op_len;
+                continue 'outer;
+            }
+        }
+
+        return Err(XmlExprError::UnexpectedChar(c, pos));
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<&'a Token, XmlExprError> {
+        let token = self.tokens.get(self.pos).ok_or(XmlExprError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    /// Consumes the next token if it is one of the operators (or keywords)
+    fn eat(&mut self, ops: &[&str]) -> Option<&'static str> {
+        let found = match self.peek()? {
+            Token::Op(o) => ops.iter().find(|op| *op == o).map(|_| *o),
+            Token::Ident(i) => match i.as_str() {
+                "and" if ops.contains(&"&&") => Some("&&"),
+                "or" if ops.contains(&"||") => Some("||"),
+                "not" if ops.contains(&"!") => Some("!"),
+                _ => None,
+            },
+            _ => None,
+        }?;
+        self.pos += 1;
+        Some(found)
+    }
+
+    fn expect(&mut self, op: &'static str) -> Result<(), XmlExprError> {
+        match self.next()? {
+            Token::Op(o) if *o == op => Ok(()),
+            other => Err(XmlExprError::UnexpectedToken(other.to_string())),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<XmlExpr, XmlExprError> {
+        let mut expr = self.parse_and()?;
+        while self.eat(&["||"]).is_some() {
+            expr = XmlExpr::Binary(Box::new(expr), XmlBinaryOp::Or, Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<XmlExpr, XmlExprError> {
+        let mut expr = self.parse_comparison()?;
+        while self.eat(&["&&"]).is_some() {
+            expr = XmlExpr::Binary(Box::new(expr), XmlBinaryOp::And, Box::new(self.parse_comparison()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_comparison(&mut self) -> Result<XmlExpr, XmlExprError> {
+        let expr = self.parse_sum()?;
+        let op = match self.eat(&["==", "!=", "<=", ">=", "<", ">"]) {
+            Some("==") => XmlBinaryOp::Equal,
+            Some("!=") => XmlBinaryOp::NotEqual,
+            Some("<=") => XmlBinaryOp::LessEqual,
+            Some(">=") => XmlBinaryOp::GreaterEqual,
+            Some("<") => XmlBinaryOp::Less,
+            Some(">") => XmlBinaryOp::Greater,
+            _ => return Ok(expr),
+        };
+        Ok(XmlExpr::Binary(Box::new(expr), op, Box::new(self.parse_sum()?)))
+    }
+
+    fn parse_sum(&mut self) -> Result<XmlExpr, XmlExprError> {
+        let mut expr = self.parse_product()?;
+        while let Some(op) = self.eat(&["+", "-"]) {
+            let op = if op == "+" { XmlBinaryOp::Add } else { XmlBinaryOp::Subtract };
+            expr = XmlExpr::Binary(Box::new(expr), op, Box::new(self.parse_product()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_product(&mut self) -> Result<XmlExpr, XmlExprError> {
+        let mut expr = self.parse_unary()?;
+        while let Some(op) = self.eat(&["*", "/"]) {
+            let op = if op == "*" { XmlBinaryOp::Multiply } else { XmlBinaryOp::Divide };
+            expr = XmlExpr::Binary(Box::new(expr), op, Box::new(self.parse_unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> Result<XmlExpr, XmlExprError> {
+        match self.eat(&["!", "-"]) {
+            Some("!") => Ok(XmlExpr::Not(Box::new(self.parse_unary()?))),
+            Some(_) => Ok(XmlExpr::Negate(Box::new(self.parse_unary()?))),
+            None => self.parse_postfix(),
+        }
+    }
+
+    fn parse_postfix(&mut self) -> Result<XmlExpr, XmlExprError> {
+        let mut expr = self.parse_primary()?;
+        while self.eat(&["."]).is_some() {
+            match self.next()? {
+                Token::Ident(property) => { expr = XmlExpr::Property(Box::new(expr), property.clone()); },
+                other => return Err(XmlExprError::UnexpectedToken(other.to_string())),
+            }
+        }
+        Ok(expr)
+    }
+
+    fn parse_primary(&mut self) -> Result<XmlExpr, XmlExprError> {
+        match self.next()? {
+            Token::Number(n) => Ok(XmlExpr::Literal(XmlValue::Number(*n))),
+            Token::Str(s) => Ok(XmlExpr::Literal(XmlValue::String(s.clone()))),
+            Token::Ident(i) => match i.as_str() {
+                "true" => Ok(XmlExpr::Literal(XmlValue::Bool(true))),
+                "false" => Ok(XmlExpr::Literal(XmlValue::Bool(false))),
+                _ => Ok(XmlExpr::Variable(i.clone())),
+            },
+            Token::Op("(") => {
+                let expr = self.parse_or()?;
+                self.expect(")")?;
+                Ok(expr)
+            },
+            Token::Op("[") => {
+                let mut items = Vec::new();
+                if self.eat(&["]"]).is_some() {
+                    return Ok(XmlExpr::List(items));
+                }
+                loop {
+                    items.push(self.parse_or()?);
+                    if self.eat(&["]"]).is_some() {
+                        return Ok(XmlExpr::List(items));
+                    }
+                    self.expect(",")?;
+                }
+            },
+            other => Err(XmlExprError::UnexpectedToken(other.to_string())),
+        }
+    }
+}
+
+fn is_identifier(input: &str) -> bool {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => chars.all(|c| c.is_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+fn parse_number(input: &str) -> Option<f64> {
+    // don't parse "inf" or "NaN" as numbers
+    let first = input.trim_start_matches('-').chars().next()?;
+    if !first.is_ascii_digit() && first != '.' {
+        return None;
+    }
+    input.parse::<f64>().ok()
+}
+
+/// Returns the contents of a `'quoted'` or `"quoted"` string
+fn unquote(input: &str) -> Option<&str> {
+    let first = input.chars().next()?;
+    if (first == '\'' || first == '"') && input.len() >= 2 && input.ends_with(first) {
+        Some(&input[1..(input.len() - 1)])
+    } else {
+        None
+    }
+}
+
+/// Splits `a, 'b, c', [d, e]` at the commas that are not in quotes or brackets
+fn split_list_items(input: &str) -> Vec<&str> {
+
+    let mut items = Vec::new();
+    let mut depth = 0_usize;
+    let mut quote = None;
+    let mut last = 0;
+
+    for (pos, c) in input.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => { quote = None; },
+            (Some(_), _) => { },
+            (None, '\'') | (None, '"') => { quote = Some(c); },
+            (None, '[') => { depth += 1; },
+            (None, ']') => { depth = depth.saturating_sub(1); },
+            (None, ',') if depth == 0 => {
+                items.push(&input[last..pos]);
+                last = pos + 1;
+            },
+            _ => { },
+        }
+    }
+
+    items.push(&input[last..]);
+    items
+}
+
+#[test]
+fn test_xml_value_from_argument() {
+    use self::XmlValue::*;
+    assert_eq!(XmlValue::from_argument("true"), Bool(true));
+    assert_eq!(XmlValue::from_argument("-2.5"), Number(-2.5));
+    assert_eq!(XmlValue::from_argument("inf"), String("inf".into()));
+    assert_eq!(XmlValue::from_argument("[a, 'b, c', 3]"), List(vec![String("a".into()), String("b, c".into()), Number(3.0)]));
+    assert_eq!(XmlValue::from_argument("[]"), List(Vec::new()));
+
+    let list = XmlValue::from_argument("[a, 'b, c', 3, '4']");
+    assert_eq!(XmlValue::from_argument(&list.to_string()), list);
+}
+
+#[test]
+fn test_xml_expr_evaluate() {
+    let variables = |name: &str| match name {
+        "items" => Some(XmlValue::from_argument("[a, b]")),
+        "count" => Some(XmlValue::from_argument("3")),
+        "name" => Some(XmlValue::from_argument("Bob")),
+        _ => None,
+    };
+    let eval = |s: &str| XmlExpr::parse(s).unwrap().evaluate(&variables);
+
+    assert_eq!(eval("items.len == 2 and count * 2 > 5"), Ok(XmlValue::Bool(true)));
+    assert_eq!(eval("!(name == 'Bob') || items.is_empty"), Ok(XmlValue::Bool(false)));
+    assert_eq!(eval("false && missing"), Ok(XmlValue::Bool(false)));
+    assert_eq!(eval("missing"), Err(XmlExprError::UnknownVariable("missing".into())));
+    assert_eq!(eval("count + name"), Err(XmlExprError::TypeMismatch("+", "number", "string")));
+    assert_eq!(XmlExpr::parse("count >"), Err(XmlExprError::UnexpectedEnd));
+}
+
+#[test]
+fn test_xml_for_loop_parse() {
+    let l = XmlForLoop::parse("item, i in items").unwrap();
+    assert_eq!(l.item, "item");
+    assert_eq!(l.index, Some("i".into()));
+    assert_eq!(l.items, XmlExpr::Variable("items".into()));
+    assert!(XmlForLoop::parse("items").is_err());
+}
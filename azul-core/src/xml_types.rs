@@ -0,0 +1,320 @@
+//! Type system of the XML component arguments
+//!
+//! The type of an argument is declared in the `args` of the component, the values of
+//! the XML attributes are checked against this type when the XML is loaded:
+//!
+//! ```xml,no_run,ignore
+//! <component name="Badge" args="count: u32, color: ColorU, size: PixelValue, shape: Shape(Round|Square)">
+//!     ...
+//! </component>
+//!
+//! <Badge count="5" color="#ff0000" size="12px" shape="round" />
+//! ```
+//!
+//! Supported are `bool`, the integer types (`i8` - `i64`, `u8` - `u64`, `isize`, `usize`),
+//! `f32` / `f64`, strings (`String`, `AzString`, `&str`), colors (`ColorU`, `Color`), CSS
+//! lengths (`PixelValue`, `Length`) and enums, which are declared as `Name(VariantA|VariantB)`.
+//! Values of other types are not checked and compiled to `Type::from("value")`.
+
+use core::fmt;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use azul_css::{ColorU, PixelValue, SizeMetric};
+
+/// Integer types that can be used as component arguments
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IntegerType {
+    I8,
+    I16,
+    I32,
+    I64,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
+}
+
+impl IntegerType {
+
+    pub fn from_type_name(type_name: &str) -> Option<Self> {
+        use self::IntegerType::*;
+        match type_name {
+            "i8" => Some(I8),
+            "i16" => Some(I16),
+            "i32" => Some(I32),
+            "i64" => Some(I64),
+            "isize" => Some(Isize),
+            "u8" => Some(U8),
+            "u16" => Some(U16),
+            "u32" => Some(U32),
+            "u64" => Some(U64),
+            "usize" => Some(Usize),
+            _ => None,
+        }
+    }
+
+    pub fn get_type_name(&self) -> &'static str {
+        use self::IntegerType::*;
+        match self {
+            I8 => "i8",
+            I16 => "i16",
+            I32 => "i32",
+            I64 => "i64",
+            Isize => "isize",
+            U8 => "u8",
+            U16 => "u16",
+            U32 => "u32",
+            U64 => "u64",
+            Usize => "usize",
+        }
+    }
+
+    /// Inclusive range of valid values (`isize` / `usize` are assumed to be 64-bit)
+    pub fn get_range(&self) -> (i128, i128) {
+        use self::IntegerType::*;
+        match self {
+            I8 => (i8::MIN as i128, i8::MAX as i128),
+            I16 => (i16::MIN as i128, i16::MAX as i128),
+            I32 => (i32::MIN as i128, i32::MAX as i128),
+            I64 | Isize => (i64::MIN as i128, i64::MAX as i128),
+            U8 => (0, u8::MAX as i128),
+            U16 => (0, u16::MAX as i128),
+            U32 => (0, u32::MAX as i128),
+            U64 | Usize => (0, u64::MAX as i128),
+        }
+    }
+}
+
+/// Parsed `ComponentArgumentType`, i.e. the `u32` in `args="count: u32"`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ComponentArgumentKind {
+    Bool,
+    Integer(IntegerType),
+    /// `f32` (false) or `f64` (true)
+    Float(bool),
+    String,
+    Color,
+    Length,
+    /// `Name(VariantA|VariantB)`: name of the enum and the names of the variants
+    Enum(String, Vec<String>),
+    /// Any other type, values are not checked
+    Other(String),
+}
+
+impl ComponentArgumentKind {
+
+    /// Parses the type of a component argument, returns `None` for malformed
+    /// enum declarations (i.e. `Shape(|Round)` or `Shape(Round`)
+    pub fn from_type_name(type_name: &str) -> Option<Self> {
+
+        use self::ComponentArgumentKind::*;
+
+        let type_name = type_name.trim();
+
+        if let Some(int) = IntegerType::from_type_name(type_name) {
+            return Some(Integer(int));
+        }
+
+        let kind = match type_name {
+            "bool" => Bool,
+            "f32" => Float(false),
+            "f64" => Float(true),
+            "String" | "AzString" | "&str" | "str" => String,
+            "ColorU" | "Color" => Color,
+            "PixelValue" | "Length" => Length,
+            t if t.contains('(') || t.contains(')') => {
+                let (name, variants) = t.split_at(t.find('(')?);
+                if name.is_empty() || !variants.ends_with(')') {
+                    return None;
+                }
+                let variants = variants[1..(variants.len() - 1)]
+                    .split('|')
+                    .map(|v| v.trim().to_string())
+                    .collect::<Vec<_>>();
+                if variants.iter().any(|v| v.is_empty() || !v.chars().all(|c| c.is_alphanumeric() || c == '_')) {
+                    return None;
+                }
+                Enum(name.to_string(), variants)
+            },
+            t => Other(t.to_string()),
+        };
+
+        Some(kind)
+    }
+
+    /// Name of the type in the generated Rust code
+    pub fn get_rust_type_name(&self) -> String {
+        use self::ComponentArgumentKind::*;
+        match self {
+            Bool => "bool".to_string(),
+            Integer(i) => i.get_type_name().to_string(),
+            Float(false) => "f32".to_string(),
+            Float(true) => "f64".to_string(),
+            String => "AzString".to_string(),
+            Color => "ColorU".to_string(),
+            Length => "PixelValue".to_string(),
+            Enum(name, _) => name.clone(),
+            Other(t) => t.clone(),
+        }
+    }
+
+    /// Parses and validates the (static) value of an XML attribute
+    pub fn parse_value(&self, value: &str) -> Result<ComponentArgumentValue, ArgumentValueError> {
+
+        use self::ComponentArgumentKind::*;
+
+        let trimmed = value.trim();
+
+        match self {
+            Bool => match trimmed {
+                "true" => Ok(ComponentArgumentValue::Bool(true)),
+                "false" => Ok(ComponentArgumentValue::Bool(false)),
+                _ => Err(ArgumentValueError::ExpectedBool),
+            },
+            Integer(int_type) => {
+                let i = trimmed.parse::<i128>().map_err(|_| ArgumentValueError::ExpectedInteger)?;
+                let (min, max) = int_type.get_range();
+                if i < min || i > max {
+                    return Err(ArgumentValueError::IntegerOutOfRange(min, max));
+                }
+                Ok(ComponentArgumentValue::Integer(i))
+            },
+            Float(_) => match trimmed.parse::<f64>() {
+                Ok(f) if f.is_finite() => Ok(ComponentArgumentValue::Float(f)),
+                _ => Err(ArgumentValueError::ExpectedFloat),
+            },
+            String => Ok(ComponentArgumentValue::String(value.to_string())),
+            Color => azul_css_parser::parse_css_color(trimmed)
+                .map(ComponentArgumentValue::Color)
+                .map_err(|e| ArgumentValueError::InvalidColor(format!("{}", e))),
+            Length => azul_css_parser::parse_pixel_value(trimmed)
+                .map(ComponentArgumentValue::Length)
+                .map_err(|e| ArgumentValueError::InvalidLength(format!("{}", e))),
+            Enum(_, variants) => {
+                // variants are matched case-insensitively: "round" selects the variant "Round"
+                let normalized = normalize_variant(trimmed);
+                variants.iter()
+                    .find(|v| normalize_variant(v) == normalized)
+                    .map(|v| ComponentArgumentValue::Enum(v.clone()))
+                    .ok_or_else(|| ArgumentValueError::UnknownVariant(variants.clone()))
+            },
+            Other(_) => Ok(ComponentArgumentValue::Other(value.to_string())),
+        }
+    }
+
+    /// Compiles the (static) value of an XML attribute to a typed Rust literal,
+    /// i.e. `"#ff0000"` to `ColorU { r: 255, g: 0, b: 0, a: 255 }`
+    pub fn compile_to_rust_code(&self, value: &str) -> Result<String, ArgumentValueError> {
+        use self::ComponentArgumentValue as V;
+        let compiled = match self.parse_value(value)? {
+            V::Bool(b) => format!("{}", b),
+            V::Integer(i) => format!("{}", i),
+            V::Float(f) => match self {
+                ComponentArgumentKind::Float(false) => format!("{:?}", f as f32),
+                _ => format!("{:?}", f),
+            },
+            V::String(s) => format!("AzString::from_const_str({:?})", s),
+            V::Color(c) => format!("ColorU {{ r: {}, g: {}, b: {}, a: {} }}", c.r, c.g, c.b, c.a),
+            V::Length(p) => {
+                let (const_fn, fn_name) = match p.metric {
+                    SizeMetric::Px => ("const_px", "px"),
+                    SizeMetric::Pt => ("const_pt", "pt"),
+                    SizeMetric::Em => ("const_em", "em"),
+                    SizeMetric::Percent => ("const_percent", "percent"),
+                };
+                let number = p.number.get();
+                if libm::roundf(number) == number {
+                    format!("PixelValue::{}({})", const_fn, number as isize)
+                } else {
+                    format!("PixelValue::{}({:?})", fn_name, number)
+                }
+            },
+            V::Enum(variant) => format!("{}::{}", self.get_rust_type_name(), variant),
+            V::Other(s) => format!("{}::from({:?})", self.get_rust_type_name(), s),
+        };
+        Ok(compiled)
+    }
+}
+
+/// Parsed value of a component argument
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentArgumentValue {
+    Bool(bool),
+    Integer(i128),
+    Float(f64),
+    String(String),
+    Color(ColorU),
+    Length(PixelValue),
+    /// Name of the selected variant, as it was declared
+    Enum(String),
+    Other(String),
+}
+
+/// Reason why the value of an XML attribute doesn't match the type of the component argument
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArgumentValueError {
+    ExpectedBool,
+    ExpectedInteger,
+    /// IntegerOutOfRange(min, max)
+    IntegerOutOfRange(i128, i128),
+    ExpectedFloat,
+    InvalidColor(String),
+    InvalidLength(String),
+    /// Value is not one of the variants of the enum
+    UnknownVariant(Vec<String>),
+}
+
+impl fmt::Display for ArgumentValueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ArgumentValueError::*;
+        match self {
+            ExpectedBool => write!(f, "expected \"true\" or \"false\""),
+            ExpectedInteger => write!(f, "expected an integer"),
+            IntegerOutOfRange(min, max) => write!(f, "integer out of range (expected {} to {})", min, max),
+            ExpectedFloat => write!(f, "expected a number"),
+            InvalidColor(e) => write!(f, "invalid color (expected i.e. \"#ff0000\" or \"rgba(255, 0, 0, 1.0)\"): {}", e),
+            InvalidLength(e) => write!(f, "invalid length (expected i.e. \"10px\", \"1.5em\" or \"50%\"): {}", e),
+            UnknownVariant(variants) => write!(f, "expected one of: {}", variants.join(", ")),
+        }
+    }
+}
+
+fn normalize_variant(variant: &str) -> String {
+    variant.chars().filter(|c| *c != '_' && *c != '-').flat_map(|c| c.to_lowercase()).collect()
+}
+
+#[test]
+fn test_parse_argument_types() {
+    use self::ComponentArgumentKind::*;
+    assert_eq!(ComponentArgumentKind::from_type_name("u8"), Some(Integer(IntegerType::U8)));
+    assert_eq!(ComponentArgumentKind::from_type_name(" String "), Some(String));
+    assert_eq!(
+        ComponentArgumentKind::from_type_name("Shape(Round|Square)"),
+        Some(Enum("Shape".to_string(), vec!["Round".to_string(), "Square".to_string()]))
+    );
+    assert_eq!(ComponentArgumentKind::from_type_name("Shape(Round|)"), None);
+    assert_eq!(ComponentArgumentKind::from_type_name("Shape(Round"), None);
+    assert_eq!(ComponentArgumentKind::from_type_name("DateTime"), Some(Other("DateTime".to_string())));
+}
+
+#[test]
+fn test_compile_argument_values() {
+    let kind = |t: &str| ComponentArgumentKind::from_type_name(t).unwrap();
+    assert_eq!(kind("bool").compile_to_rust_code("true"), Ok("true".to_string()));
+    assert_eq!(kind("bool").compile_to_rust_code("yes"), Err(ArgumentValueError::ExpectedBool));
+    assert_eq!(kind("u8").compile_to_rust_code("255"), Ok("255".to_string()));
+    assert_eq!(kind("u8").compile_to_rust_code("256"), Err(ArgumentValueError::IntegerOutOfRange(0, 255)));
+    assert_eq!(kind("f32").compile_to_rust_code("1"), Ok("1.0".to_string()));
+    assert_eq!(kind("String").compile_to_rust_code("a \"b\""), Ok("AzString::from_const_str(\"a \\\"b\\\"\")".to_string()));
+    assert_eq!(kind("ColorU").compile_to_rust_code("#ff0000"), Ok("ColorU { r: 255, g: 0, b: 0, a: 255 }".to_string()));
+    assert_eq!(kind("PixelValue").compile_to_rust_code("10px"), Ok("PixelValue::const_px(10)".to_string()));
+    assert_eq!(kind("PixelValue").compile_to_rust_code("1.5em"), Ok("PixelValue::em(1.5)".to_string()));
+    assert_eq!(kind("Shape(Round|Square)").compile_to_rust_code("round"), Ok("Shape::Round".to_string()));
+    assert_eq!(
+        kind("Shape(Round|Square)").compile_to_rust_code("circle"),
+        Err(ArgumentValueError::UnknownVariant(vec!["Round".to_string(), "Square".to_string()]))
+    );
+    assert_eq!(kind("DateTime").compile_to_rust_code("01.01.2018"), Ok("DateTime::from(\"01.01.2018\")".to_string()));
+}
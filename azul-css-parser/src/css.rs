@@ -145,6 +145,9 @@ fn pseudo_selector_from_str<'a>(selector: &'a str, value: Option<&'a str>)
         "hover" => Ok(CssPathPseudoSelector::Hover),
         "active" => Ok(CssPathPseudoSelector::Active),
         "focus" => Ok(CssPathPseudoSelector::Focus),
+        "focus-visible" => Ok(CssPathPseudoSelector::FocusVisible),
+        "focus-within" => Ok(CssPathPseudoSelector::FocusWithin),
+        "drag-over" => Ok(CssPathPseudoSelector::DragOver),
         "nth-child" => {
             let value = value.ok_or(CssPseudoSelectorParseError::EmptyNthChild)?;
             let parsed = parse_nth_child_selector(value)?;
@@ -230,6 +233,9 @@ fn test_css_pseudo_selector_parse() {
         (("hover", None), Hover),
         (("active", None), Active),
         (("focus", None), Focus),
+        (("focus-visible", None), FocusVisible),
+        (("focus-within", None), FocusWithin),
+        (("drag-over", None), DragOver),
         (("nth-child", Some("4")), NthChild(Number(4))),
         (("nth-child", Some("even")), NthChild(Even)),
         (("nth-child", Some("odd")), NthChild(Odd)),
@@ -298,6 +304,14 @@ pub fn new_from_str<'a>(css_string: &'a str) -> Result<Css, CssParseError<'a>> {
     Ok(Css { stylesheets: vec![stylesheet].into() })
 }
 
+/// Same as `new_from_str`, but also returns the warnings of the parser,
+/// i.e. the properties that were skipped because they are not supported
+pub fn new_from_str_with_warnings<'a>(css_string: &'a str) -> Result<(Css, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
+    let mut tokenizer = Tokenizer::new(css_string);
+    let (stylesheet, warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
+    Ok((Css { stylesheets: vec![stylesheet].into() }, warnings))
+}
+
 /// Returns the location of where the parser is currently in the document
 fn get_error_location(tokenizer: &Tokenizer) -> ErrorLocation {
     ErrorLocation {
@@ -415,8 +429,8 @@ pub struct UnparsedCssRuleBlock<'a> {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CssParseWarnMsg<'a> {
-    warning: CssParseWarnMsgInner<'a>,
-    location: (ErrorLocation, ErrorLocation),
+    pub warning: CssParseWarnMsgInner<'a>,
+    pub location: (ErrorLocation, ErrorLocation),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -425,6 +439,10 @@ pub enum CssParseWarnMsgInner<'a> {
     UnsupportedKeyValuePair { key: &'a str, value: &'a str },
 }
 
+impl_display!{ CssParseWarnMsgInner<'a>, {
+    UnsupportedKeyValuePair { key, value } => format!("Unsupported CSS property: \"{}: {}\"", key, value),
+}}
+
 /// Parses a CSS string (single-threaded) and returns the parsed rules in blocks
 ///
 /// May return "warning" messages, i.e. messages that just serve as a warning,
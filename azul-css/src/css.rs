@@ -471,6 +471,13 @@ pub enum CssPathPseudoSelector {
     Active,
     /// `:focus` - element has received focus
     Focus,
+    /// `:focus-visible` - element has received focus via the keyboard
+    /// (tab navigation, arrow keys) or programmatically, but not via the mouse
+    FocusVisible,
+    /// `:focus-within` - element or one of its children has received focus
+    FocusWithin,
+    /// `:drag-over` - element is hovered while a drag is in progress
+    DragOver,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -511,6 +518,9 @@ impl fmt::Display for CssPathPseudoSelector {
             Hover => write!(f, "hover"),
             Active => write!(f, "active"),
             Focus => write!(f, "focus"),
+            FocusVisible => write!(f, "focus-visible"),
+            FocusWithin => write!(f, "focus-within"),
+            DragOver => write!(f, "drag-over"),
         }
     }
 }
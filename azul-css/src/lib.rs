@@ -173,7 +173,7 @@ macro_rules! impl_vec_as_hashmap {($struct_type:ident, $struct_name:ident) => (
 
         pub fn remove_hm_item(&mut self, remove_key: &$struct_type) {
             let mut vec = self.clone().into_library_owned_vec();
-            vec.retain(|v| v == remove_key);
+            vec.retain(|v| v != remove_key);
             *self = Self::from_vec(vec);
         }
 
@@ -2,7 +2,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use crate::css_properties::*;
-use crate::css::PrintAsCssValue;
+use crate::css::{PrintAsCssValue, Css, CssPath, CssPathSelector, CssDeclaration};
 
 impl PrintAsCssValue for StyleTextColor {
     fn print_as_css_value(&self) -> String {
@@ -654,4 +654,49 @@ impl PrintAsCssValue for ScrollbarInfo {
             self.resizer.print_as_css_value(),
         )
     }
-}
\ No newline at end of file
+}
+
+impl CssPath {
+    /// Prints the path as a CSS selector, i.e. `#main > .button:hover`
+    pub fn print_as_css_selector(&self) -> String {
+        let mut s = String::new();
+        for selector in self.selectors.as_ref() {
+            match selector {
+                CssPathSelector::DirectChildren => s.push_str(" > "),
+                CssPathSelector::Children => s.push(' '),
+                other => s.push_str(&format!("{}", other)),
+            }
+        }
+        s
+    }
+}
+
+impl CssDeclaration {
+    /// Prints the declaration as CSS, i.e. `width: var(--my_width, 100px)`
+    pub fn print_as_css_declaration(&self) -> String {
+        match self {
+            CssDeclaration::Static(s) => format!("{}: {}", s.key(), s.value()),
+            CssDeclaration::Dynamic(d) => format!("{}: var(--{}, {})", d.default_value.key(), d.dynamic_id, d.default_value.value()),
+        }
+    }
+}
+
+impl Css {
+    /// Prints the stylesheets in a canonical format: one rule block per selector
+    /// and one declaration per line, indented with four spaces. Shorthand
+    /// properties (`padding: 5px`) are printed as their expanded properties.
+    pub fn print_as_css(&self) -> String {
+        self.stylesheets.as_ref().iter()
+        .flat_map(|stylesheet| stylesheet.rules.as_ref().iter())
+        .map(|rule_block| {
+            let mut s = format!("{} {{\n", rule_block.path.print_as_css_selector());
+            for declaration in rule_block.declarations.as_ref().iter() {
+                s.push_str(&format!("    {};\n", declaration.print_as_css_declaration()));
+            }
+            s.push('}');
+            s
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+    }
+}
@@ -0,0 +1,870 @@
+//! Exposes the `AccessibilityTree` of a window on the AT-SPI2 accessibility bus
+//!
+//! Every exposed node is an object at `/org/a11y/atspi/accessible/<id>`
+//! implementing `org.a11y.atspi.Accessible` and `org.a11y.atspi.Component`,
+//! the application object at `/org/a11y/atspi/accessible/root` has the window
+//! as its only child. Method calls are answered on a background thread from
+//! the shared tree, `AtSpiBridge::update` swaps in the tree of the new frame
+//! and emits the `org.a11y.atspi.Event.Object` signals for what changed.
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use zbus::{Connection, Message, MessageType};
+use zvariant::{ObjectPath, OwnedObjectPath, Value, StructureBuilder};
+use azul_core::{
+    dom::{AccessibilityRole, AccessibilityState},
+    window::{LogicalPosition, LogicalRect, VirtualKeyCodeCombo},
+};
+use super::{AccessibilityTree, AccessibilityNode, AccessibilityNodeId, AccessibilityTreeUpdate};
+
+const ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+const NULL_PATH: &str = "/org/a11y/atspi/null";
+const ACCESSIBLE_PATH_PREFIX: &str = "/org/a11y/atspi/accessible/";
+
+const REGISTRY_BUS_NAME: &str = "org.a11y.atspi.Registry";
+const A11Y_BUS_NAME: &str = "org.a11y.Bus";
+const A11Y_BUS_PATH: &str = "/org/a11y/bus";
+
+/// Signal that the `AtSpiBridge` sends to itself to stop the receiving thread
+const SHUTDOWN_INTERFACE: &str = "rs.azul.AtSpiBridge";
+const SHUTDOWN_SIGNAL: &str = "Shutdown";
+
+const ACCESSIBLE_INTERFACE: &str = "org.a11y.atspi.Accessible";
+const COMPONENT_INTERFACE: &str = "org.a11y.atspi.Component";
+const APPLICATION_INTERFACE: &str = "org.a11y.atspi.Application";
+const PROPERTIES_INTERFACE: &str = "org.freedesktop.DBus.Properties";
+const OBJECT_EVENT_INTERFACE: &str = "org.a11y.atspi.Event.Object";
+
+/// AT-SPI role of the application object (`ATSPI_ROLE_APPLICATION`)
+const ATSPI_ROLE_APPLICATION: u32 = 75;
+/// `ATSPI_COORD_TYPE_SCREEN`
+const ATSPI_COORD_TYPE_SCREEN: u32 = 0;
+
+#[derive(Debug)]
+pub enum AtSpiError {
+    /// Could not connect to the session bus or the accessibility bus
+    Connection(zbus::Error),
+    /// The connection has no unique name (not a bus connection)
+    NoUniqueName,
+}
+
+impl fmt::Display for AtSpiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AtSpiError::Connection(e) => write!(f, "AT-SPI: D-Bus connection failed: {}", e),
+            AtSpiError::NoUniqueName => write!(f, "AT-SPI: connection has no unique bus name"),
+        }
+    }
+}
+
+impl From<zbus::Error> for AtSpiError {
+    fn from(e: zbus::Error) -> Self {
+        AtSpiError::Connection(e)
+    }
+}
+
+/// State shared between the window and the thread answering the method calls
+#[derive(Debug)]
+struct SharedState {
+    app_name: String,
+    tree: AccessibilityTree,
+    /// Position of the window on the screen, for `ATSPI_COORD_TYPE_SCREEN`
+    window_position: LogicalPosition,
+    /// Set by the registry via the `Id` property of the application
+    app_id: i32,
+    /// Reference of the desktop object the application is embedded into
+    desktop: Option<(String, OwnedObjectPath)>,
+}
+
+/// Connection of one window to the AT-SPI2 accessibility bus
+pub struct AtSpiBridge {
+    connection: Connection,
+    shared: Arc<Mutex<SharedState>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl fmt::Debug for AtSpiBridge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AtSpiBridge {{ unique_name: {:?} }}", self.connection.unique_name())
+    }
+}
+
+impl AtSpiBridge {
+
+    /// Connects to the accessibility bus of the current session and
+    /// registers the application with the AT-SPI registry
+    pub fn new(app_name: &str) -> Result<Self, AtSpiError> {
+        let session = Connection::new_session()?;
+        let reply = session.call_method(Some(A11Y_BUS_NAME), A11Y_BUS_PATH, Some(A11Y_BUS_NAME), "GetAddress", &())?;
+        let address: String = reply.body()?;
+        let bridge = Self::new_for_address(&address, app_name)?;
+        bridge.embed()?;
+        Ok(bridge)
+    }
+
+    /// Connects to the bus at the given address without registering with the
+    /// registry, i.e. for tests on a private bus
+    pub fn new_for_address(address: &str, app_name: &str) -> Result<Self, AtSpiError> {
+
+        let connection = Connection::new_for_address(address, true)?;
+
+        if connection.unique_name().is_none() {
+            return Err(AtSpiError::NoUniqueName);
+        }
+
+        let shared = Arc::new(Mutex::new(SharedState {
+            app_name: app_name.to_string(),
+            tree: AccessibilityTree::default(),
+            window_position: LogicalPosition::zero(),
+            app_id: 0,
+            desktop: None,
+        }));
+
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let connection = connection.clone();
+            let shared = shared.clone();
+            let stop = stop.clone();
+            thread::Builder::new()
+            .name("azul-atspi".into())
+            .spawn(move || {
+                loop {
+                    let msg = match connection.receive_message() {
+                        Ok(o) => o,
+                        Err(_) => break, // connection closed
+                    };
+                    // set by Drop, which then sends SHUTDOWN_SIGNAL to wake up the thread
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    handle_message(&connection, &shared, &msg);
+                }
+            })
+            .ok()
+        };
+
+        Ok(Self { connection, shared, stop, thread })
+    }
+
+    /// Returns the unique bus name under which the objects are exported
+    pub fn get_bus_name(&self) -> String {
+        self.connection.unique_name().unwrap_or_default().to_string()
+    }
+
+    /// Embeds the application root into the desktop of the AT-SPI registry
+    fn embed(&self) -> Result<(), AtSpiError> {
+        let root = (self.get_bus_name(), object_path(ROOT_PATH));
+        let reply = self.connection.call_method(
+            Some(REGISTRY_BUS_NAME),
+            ROOT_PATH,
+            Some("org.a11y.atspi.Socket"),
+            "Embed",
+            &(root,),
+        )?;
+        let desktop: (String, OwnedObjectPath) = reply.body()?;
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.desktop = Some(desktop);
+        }
+        Ok(())
+    }
+
+    /// Updates the position of the window on the screen (for screen coordinates)
+    pub fn set_window_position(&self, position: LogicalPosition) {
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.window_position = position;
+        }
+    }
+
+    /// Replaces the tree with the tree of the current frame and notifies
+    /// the screen readers about the changes
+    pub fn update(&self, new_tree: AccessibilityTree) {
+
+        let updates = {
+            let mut shared = match self.shared.lock() {
+                Ok(o) => o,
+                Err(_) => return,
+            };
+            let updates = shared.tree.diff(&new_tree);
+            shared.tree = new_tree;
+            updates
+        };
+
+        let bus_name = self.get_bus_name();
+        for update in updates {
+            let _ = self.emit_update(&bus_name, &update);
+        }
+    }
+
+    fn emit_update(&self, bus_name: &str, update: &AccessibilityTreeUpdate) -> Result<(), zbus::Error> {
+
+        use super::AccessibilityTreeUpdate::*;
+
+        match update {
+            NodeAdded { id, parent, index } => {
+                let child = object_reference_value(bus_name, &get_path(*id));
+                self.emit_object_event(&get_path(*parent), "ChildrenChanged", "add", *index as i32, 0, child)
+            },
+            NodeRemoved { id, parent, index } => {
+                let child = object_reference_value(bus_name, &get_path(*id));
+                self.emit_object_event(&get_path(*parent), "ChildrenChanged", "remove", *index as i32, 0, child)
+            },
+            RoleChanged(id) => {
+                self.emit_object_event(&get_path(*id), "PropertyChange", "accessible-role", 0, 0, Value::from(0_u32))
+            },
+            NameChanged(id) => {
+                self.emit_object_event(&get_path(*id), "PropertyChange", "accessible-name", 0, 0, Value::from(""))
+            },
+            ValueChanged(id) => {
+                self.emit_object_event(&get_path(*id), "PropertyChange", "accessible-value", 0, 0, Value::from(""))
+            },
+            BoundsChanged(id) => {
+                let bounds = self.shared.lock().ok()
+                    .and_then(|s| s.tree.get_node(id).map(|n| get_extents(&n.bounds, LogicalPosition::zero())))
+                    .unwrap_or_default();
+                let extents = StructureBuilder::new()
+                    .add_field(bounds.0).add_field(bounds.1)
+                    .add_field(bounds.2).add_field(bounds.3)
+                    .build();
+                self.emit_object_event(&get_path(*id), "BoundsChanged", "", 0, 0, Value::from(extents))
+            },
+            StateChanged { id, state, enabled } => {
+                let detail1 = if *enabled { 1 } else { 0 };
+                self.emit_object_event(&get_path(*id), "StateChanged", get_state_name(*state), detail1, 0, Value::from(0_i32))
+            },
+            FocusChanged { new: Some(id), .. } => {
+                self.connection.emit_signal(
+                    None,
+                    &get_path(*id),
+                    "org.a11y.atspi.Event.Focus",
+                    "Focus",
+                    &("", 0_i32, 0_i32, Value::from(0_i32), HashMap::<&str, Value>::new()),
+                )
+            },
+            FocusChanged { new: None, .. } => Ok(()),
+        }
+    }
+
+    fn emit_object_event(
+        &self,
+        path: &str,
+        event: &str,
+        detail: &str,
+        detail1: i32,
+        detail2: i32,
+        any_data: Value,
+    ) -> Result<(), zbus::Error> {
+        self.connection.emit_signal(
+            None,
+            path,
+            OBJECT_EVENT_INTERFACE,
+            event,
+            &(detail, detail1, detail2, any_data, HashMap::<&str, Value>::new()),
+        )
+    }
+}
+
+impl Drop for AtSpiBridge {
+    fn drop(&mut self) {
+
+        self.stop.store(true, Ordering::SeqCst);
+
+        let thread = match self.thread.take() {
+            Some(s) => s,
+            None => return,
+        };
+
+        // the thread is blocked in receive_message(): send a signal to our own
+        // bus name, so that it wakes up, sees the stop flag and exits
+        let woken_up = self.connection.emit_signal(
+            Some(&self.get_bus_name()),
+            ROOT_PATH,
+            SHUTDOWN_INTERFACE,
+            SHUTDOWN_SIGNAL,
+            &(),
+        ).is_ok();
+
+        // if the signal could not be sent, the connection is broken and
+        // receive_message() fails on its own - don't block the window on it
+        if woken_up {
+            let _ = thread.join();
+        }
+
+        // the connection is closed when `self.connection` (the last clone) is dropped
+    }
+}
+
+fn object_path(path: &str) -> OwnedObjectPath {
+    OwnedObjectPath::try_from(path).unwrap_or_else(|_| OwnedObjectPath::try_from(NULL_PATH).unwrap())
+}
+
+fn object_reference_value<'a>(bus_name: &str, path: &str) -> Value<'a> {
+    Value::from(
+        StructureBuilder::new()
+        .add_field(bus_name.to_string())
+        .add_field(ObjectPath::try_from(path.to_string()).unwrap_or_else(|_| ObjectPath::try_from(NULL_PATH).unwrap()))
+        .build()
+    )
+}
+
+/// Object path of a node in the accessibility tree
+pub fn get_path(id: AccessibilityNodeId) -> String {
+    format!("{}{}", ACCESSIBLE_PATH_PREFIX, id.as_u64())
+}
+
+/// Node ID of an object path (`None` for the application root or unknown paths)
+pub fn get_node_id(path: &str) -> Option<AccessibilityNodeId> {
+    path.strip_prefix(ACCESSIBLE_PATH_PREFIX)?.parse::<u64>().ok().map(AccessibilityNodeId::from_u64)
+}
+
+/// Returns (x, y, width, height), rounded to whole pixels
+fn get_extents(bounds: &LogicalRect, origin: LogicalPosition) -> (i32, i32, i32, i32) {
+    (
+        (origin.x + bounds.origin.x).round() as i32,
+        (origin.y + bounds.origin.y).round() as i32,
+        bounds.size.width.round() as i32,
+        bounds.size.height.round() as i32,
+    )
+}
+
+/// The object that is being called: either the application root or a node
+enum Target<'a> {
+    Application,
+    Node(&'a AccessibilityNode),
+}
+
+fn handle_message(connection: &Connection, shared: &Arc<Mutex<SharedState>>, msg: &Message) {
+
+    let header = match msg.header() {
+        Ok(o) => o,
+        Err(_) => return,
+    };
+
+    if header.message_type().ok() != Some(MessageType::MethodCall) {
+        return;
+    }
+
+    let path = match header.path() {
+        Ok(Some(p)) => p.as_str().to_string(),
+        _ => return,
+    };
+    let interface = header.interface().ok().flatten().map(|s| s.to_string()).unwrap_or_default();
+    let member = match header.member() {
+        Ok(Some(m)) => m.to_string(),
+        _ => return,
+    };
+
+    let mut shared = match shared.lock() {
+        Ok(o) => o,
+        Err(_) => return,
+    };
+
+    let bus_name = connection.unique_name().unwrap_or_default().to_string();
+
+    // the registry sets the ID of the application
+    if interface == PROPERTIES_INTERFACE && member == "Set" && path == ROOT_PATH {
+        if let Ok((_, property, value)) = msg.body::<(String, String, Value)>() {
+            if property == "Id" {
+                if let Value::I32(id) = value {
+                    shared.app_id = id;
+                }
+            }
+        }
+        let _ = connection.reply(msg, &());
+        return;
+    }
+
+    let target = if path == ROOT_PATH {
+        Target::Application
+    } else {
+        match get_node_id(&path).and_then(|id| shared.tree.get_node(&id)) {
+            Some(node) => Target::Node(node),
+            None => {
+                let _ = connection.reply_error(msg, "org.freedesktop.DBus.Error.UnknownObject", &(format!("unknown object: {}", path),));
+                return;
+            },
+        }
+    };
+
+    let shared = &*shared;
+
+    let result = match (interface.as_str(), member.as_str()) {
+        (PROPERTIES_INTERFACE, "Get") => {
+            match msg.body::<(String, String)>() {
+                Ok((iface, property)) => match get_property(shared, &bus_name, &target, &iface, &property) {
+                    Some(value) => connection.reply(msg, &(value,)),
+                    None => connection.reply_error(msg, "org.freedesktop.DBus.Error.UnknownProperty", &(property,)),
+                },
+                Err(_) => return,
+            }
+        },
+        (PROPERTIES_INTERFACE, "GetAll") => {
+            match msg.body::<String>() {
+                Ok(iface) => {
+                    let properties: &[&str] = match iface.as_str() {
+                        ACCESSIBLE_INTERFACE => &["Name", "Description", "Parent", "ChildCount", "Locale", "AccessibleId"],
+                        APPLICATION_INTERFACE => &["ToolkitName", "Version", "AtspiVersion", "Id"],
+                        _ => &[],
+                    };
+                    let all = properties.iter().filter_map(|p| {
+                        Some((p.to_string(), get_property(shared, &bus_name, &target, &iface, p)?))
+                    }).collect::<HashMap<String, Value>>();
+                    connection.reply(msg, &(all,))
+                },
+                Err(_) => return,
+            }
+        },
+        (ACCESSIBLE_INTERFACE, "GetChildAtIndex") => {
+            let index = msg.body::<i32>().unwrap_or(-1);
+            let children = get_children(shared, &target);
+            let child = if index >= 0 { children.get(index as usize).copied() } else { None };
+            connection.reply(msg, &(reference(&bus_name, child.map(get_path).as_deref()),))
+        },
+        (ACCESSIBLE_INTERFACE, "GetChildren") => {
+            let children = get_children(shared, &target).into_iter()
+                .map(|c| reference(&bus_name, Some(&get_path(c))))
+                .collect::<Vec<_>>();
+            connection.reply(msg, &(children,))
+        },
+        (ACCESSIBLE_INTERFACE, "GetIndexInParent") => {
+            let index = match &target {
+                Target::Application => -1,
+                Target::Node(node) if node.parent.is_none() => 0,
+                Target::Node(node) => shared.tree.get_index_in_parent(&node.id).map(|i| i as i32).unwrap_or(-1),
+            };
+            connection.reply(msg, &(index,))
+        },
+        (ACCESSIBLE_INTERFACE, "GetRelationSet") => {
+            connection.reply(msg, &(Vec::<(u32, Vec<(String, OwnedObjectPath)>)>::new(),))
+        },
+        (ACCESSIBLE_INTERFACE, "GetRole") => {
+            let role = match &target {
+                Target::Application => ATSPI_ROLE_APPLICATION,
+                Target::Node(node) => get_atspi_role(node.role),
+            };
+            connection.reply(msg, &(role,))
+        },
+        (ACCESSIBLE_INTERFACE, "GetRoleName") | (ACCESSIBLE_INTERFACE, "GetLocalizedRoleName") => {
+            let role_name = match &target {
+                Target::Application => "application",
+                Target::Node(node) => get_atspi_role_name(node.role),
+            };
+            connection.reply(msg, &(role_name,))
+        },
+        (ACCESSIBLE_INTERFACE, "GetState") => {
+            let states = match &target {
+                Target::Application => Vec::new(),
+                Target::Node(node) => node.states.iter().copied().collect(),
+            };
+            connection.reply(msg, &(get_atspi_state_set(&states),))
+        },
+        (ACCESSIBLE_INTERFACE, "GetAttributes") => {
+            let mut attributes = HashMap::new();
+            attributes.insert("toolkit".to_string(), "azul".to_string());
+            if let Target::Node(node) = &target {
+                if let Some(accelerator) = node.accelerator.as_ref() {
+                    attributes.insert("keyshortcuts".to_string(), get_accelerator_string(accelerator));
+                }
+                if let Some(default_action) = node.default_action.as_ref() {
+                    attributes.insert("default-action".to_string(), default_action.clone());
+                }
+            }
+            connection.reply(msg, &(attributes,))
+        },
+        (ACCESSIBLE_INTERFACE, "GetApplication") => {
+            connection.reply(msg, &(reference(&bus_name, Some(ROOT_PATH)),))
+        },
+        (ACCESSIBLE_INTERFACE, "GetInterfaces") => {
+            let interfaces = match &target {
+                Target::Application => vec![ACCESSIBLE_INTERFACE, APPLICATION_INTERFACE],
+                Target::Node(_) => vec![ACCESSIBLE_INTERFACE, COMPONENT_INTERFACE],
+            };
+            connection.reply(msg, &(interfaces,))
+        },
+        (COMPONENT_INTERFACE, "GetExtents") => {
+            let coord_type = msg.body::<u32>().unwrap_or(ATSPI_COORD_TYPE_SCREEN);
+            let origin = if coord_type == ATSPI_COORD_TYPE_SCREEN { shared.window_position } else { LogicalPosition::zero() };
+            let extents = match &target {
+                Target::Node(node) => get_extents(&node.bounds, origin),
+                Target::Application => (0, 0, 0, 0),
+            };
+            connection.reply(msg, &(extents,))
+        },
+        (COMPONENT_INTERFACE, "GetPosition") => {
+            let coord_type = msg.body::<u32>().unwrap_or(ATSPI_COORD_TYPE_SCREEN);
+            let origin = if coord_type == ATSPI_COORD_TYPE_SCREEN { shared.window_position } else { LogicalPosition::zero() };
+            let (x, y, _, _) = match &target {
+                Target::Node(node) => get_extents(&node.bounds, origin),
+                Target::Application => (0, 0, 0, 0),
+            };
+            connection.reply(msg, &(x, y))
+        },
+        (COMPONENT_INTERFACE, "GetSize") => {
+            let (_, _, w, h) = match &target {
+                Target::Node(node) => get_extents(&node.bounds, LogicalPosition::zero()),
+                Target::Application => (0, 0, 0, 0),
+            };
+            connection.reply(msg, &(w, h))
+        },
+        (COMPONENT_INTERFACE, "Contains") => {
+            let (x, y, coord_type) = msg.body::<(i32, i32, u32)>().unwrap_or((-1, -1, ATSPI_COORD_TYPE_SCREEN));
+            let origin = if coord_type == ATSPI_COORD_TYPE_SCREEN { shared.window_position } else { LogicalPosition::zero() };
+            let contains = match &target {
+                Target::Node(node) => {
+                    let (nx, ny, nw, nh) = get_extents(&node.bounds, origin);
+                    x >= nx && x < nx + nw && y >= ny && y < ny + nh
+                },
+                Target::Application => false,
+            };
+            connection.reply(msg, &(contains,))
+        },
+        (COMPONENT_INTERFACE, "GetLayer") => {
+            // ATSPI_LAYER_WIDGET
+            connection.reply(msg, &(3_u32,))
+        },
+        ("org.freedesktop.DBus.Introspectable", "Introspect") => {
+            connection.reply(msg, &(get_introspection_xml(&target),))
+        },
+        _ => {
+            connection.reply_error(msg, "org.freedesktop.DBus.Error.UnknownMethod", &(format!("unknown method: {}.{}", interface, member),))
+        },
+    };
+
+    let _ = result;
+}
+
+fn reference(bus_name: &str, path: Option<&str>) -> (String, OwnedObjectPath) {
+    match path {
+        Some(p) => (bus_name.to_string(), object_path(p)),
+        None => (String::new(), object_path(NULL_PATH)),
+    }
+}
+
+fn get_children(shared: &SharedState, target: &Target) -> Vec<AccessibilityNodeId> {
+    match target {
+        Target::Application => if shared.tree.get_root().is_some() { vec![shared.tree.root] } else { Vec::new() },
+        Target::Node(node) => node.children.clone(),
+    }
+}
+
+fn get_property<'a>(
+    shared: &'a SharedState,
+    bus_name: &str,
+    target: &Target,
+    interface: &str,
+    property: &str,
+) -> Option<Value<'a>> {
+
+    let value = match (interface, property, target) {
+        (ACCESSIBLE_INTERFACE, "Name", Target::Application) => Value::from(shared.app_name.clone()),
+        (ACCESSIBLE_INTERFACE, "Name", Target::Node(node)) => Value::from(node.name.clone().unwrap_or_default()),
+        (ACCESSIBLE_INTERFACE, "Description", Target::Node(node)) => Value::from(node.value.clone().unwrap_or_default()),
+        (ACCESSIBLE_INTERFACE, "Description", Target::Application) => Value::from(String::new()),
+        (ACCESSIBLE_INTERFACE, "Parent", Target::Application) => {
+            let (name, path) = shared.desktop.clone().unwrap_or_else(|| reference(bus_name, None));
+            Value::from(StructureBuilder::new().add_field(name).add_field(path.into_inner()).build())
+        },
+        (ACCESSIBLE_INTERFACE, "Parent", Target::Node(node)) => {
+            let parent_path = node.parent.map(get_path).unwrap_or_else(|| ROOT_PATH.to_string());
+            object_reference_value(bus_name, &parent_path)
+        },
+        (ACCESSIBLE_INTERFACE, "ChildCount", _) => Value::from(get_children(shared, target).len() as i32),
+        (ACCESSIBLE_INTERFACE, "Locale", _) => Value::from(String::new()),
+        (ACCESSIBLE_INTERFACE, "AccessibleId", Target::Node(node)) => Value::from(node.id.as_u64().to_string()),
+        (ACCESSIBLE_INTERFACE, "AccessibleId", Target::Application) => Value::from(String::new()),
+        (APPLICATION_INTERFACE, "ToolkitName", Target::Application) => Value::from("azul"),
+        (APPLICATION_INTERFACE, "Version", Target::Application) => Value::from(env!("CARGO_PKG_VERSION")),
+        (APPLICATION_INTERFACE, "AtspiVersion", Target::Application) => Value::from("2.1"),
+        (APPLICATION_INTERFACE, "Id", Target::Application) => Value::from(shared.app_id),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+fn get_introspection_xml(target: &Target) -> String {
+    let interfaces = match target {
+        Target::Application => vec![ACCESSIBLE_INTERFACE, APPLICATION_INTERFACE],
+        Target::Node(_) => vec![ACCESSIBLE_INTERFACE, COMPONENT_INTERFACE],
+    };
+    let mut xml = String::from("<node>");
+    for i in interfaces {
+        xml.push_str(&format!("<interface name=\"{}\"/>", i));
+    }
+    xml.push_str("</node>");
+    xml
+}
+
+fn get_accelerator_string(accelerator: &VirtualKeyCodeCombo) -> String {
+    accelerator.keys.iter().map(|k| k.get_name()).collect::<Vec<_>>().join("+")
+}
+
+/// Converts the MSAA role to the `AtspiRole` (see atspi-constants.h)
+pub fn get_atspi_role(role: AccessibilityRole) -> u32 {
+    use azul_core::dom::AccessibilityRole::*;
+    match role {
+        TitleBar => 104,            // ATSPI_ROLE_TITLE_BAR
+        MenuBar => 34,              // ATSPI_ROLE_MENU_BAR
+        ScrollBar => 48,            // ATSPI_ROLE_SCROLL_BAR
+        Grip => 67,                 // ATSPI_ROLE_UNKNOWN
+        Sound => 106,               // ATSPI_ROLE_AUDIO
+        Cursor => 67,
+        Caret => 67,
+        Alert => 2,                 // ATSPI_ROLE_ALERT
+        Window => 23,               // ATSPI_ROLE_FRAME
+        Client => 39,               // ATSPI_ROLE_PANEL
+        MenuPopup => 41,            // ATSPI_ROLE_POPUP_MENU
+        MenuItem => 35,             // ATSPI_ROLE_MENU_ITEM
+        Tooltip => 64,              // ATSPI_ROLE_TOOL_TIP
+        Application => 75,          // ATSPI_ROLE_APPLICATION
+        Document => 82,             // ATSPI_ROLE_DOCUMENT_FRAME
+        Pane => 39,                 // ATSPI_ROLE_PANEL
+        Chart => 80,                // ATSPI_ROLE_CHART
+        Dialog => 16,               // ATSPI_ROLE_DIALOG
+        Border => 20,               // ATSPI_ROLE_FILLER
+        Grouping => 99,             // ATSPI_ROLE_GROUPING
+        Separator => 50,            // ATSPI_ROLE_SEPARATOR
+        Toolbar => 63,              // ATSPI_ROLE_TOOL_BAR
+        StatusBar => 54,            // ATSPI_ROLE_STATUS_BAR
+        Table => 55,                // ATSPI_ROLE_TABLE
+        ColumnHeader => 10,         // ATSPI_ROLE_COLUMN_HEADER
+        RowHeader => 47,            // ATSPI_ROLE_ROW_HEADER
+        Column => 67,
+        Row => 90,                  // ATSPI_ROLE_TABLE_ROW
+        Cell => 56,                 // ATSPI_ROLE_TABLE_CELL
+        Link => 88,                 // ATSPI_ROLE_LINK
+        HelpBalloon => 64,          // ATSPI_ROLE_TOOL_TIP
+        Character => 67,
+        List => 31,                 // ATSPI_ROLE_LIST
+        ListItem => 32,             // ATSPI_ROLE_LIST_ITEM
+        Outline => 65,              // ATSPI_ROLE_TREE
+        OutlineItem => 91,          // ATSPI_ROLE_TREE_ITEM
+        Pagetab => 37,              // ATSPI_ROLE_PAGE_TAB
+        PropertyPage => 84,         // ATSPI_ROLE_PAGE
+        Indicator => 67,
+        Graphic => 27,              // ATSPI_ROLE_IMAGE
+        StaticText => 29,           // ATSPI_ROLE_LABEL
+        Text => 79,                 // ATSPI_ROLE_ENTRY
+        PushButton => 43,           // ATSPI_ROLE_PUSH_BUTTON
+        CheckButton => 7,           // ATSPI_ROLE_CHECK_BOX
+        RadioButton => 44,          // ATSPI_ROLE_RADIO_BUTTON
+        ComboBox => 11,             // ATSPI_ROLE_COMBO_BOX
+        DropList => 11,
+        ProgressBar => 42,          // ATSPI_ROLE_PROGRESS_BAR
+        Dial => 15,                 // ATSPI_ROLE_DIAL
+        HotkeyField => 79,
+        Slider => 51,               // ATSPI_ROLE_SLIDER
+        SpinButton => 52,           // ATSPI_ROLE_SPIN_BUTTON
+        Diagram => 18,              // ATSPI_ROLE_DRAWING_AREA
+        Animation => 3,             // ATSPI_ROLE_ANIMATION
+        Equation => 113,            // ATSPI_ROLE_MATH
+        ButtonDropdown => 43,
+        ButtonMenu => 43,
+        ButtonDropdownGrid => 43,
+        Whitespace => 20,           // ATSPI_ROLE_FILLER
+        PageTabList => 38,          // ATSPI_ROLE_PAGE_TAB_LIST
+        Clock => 115,               // ATSPI_ROLE_TIMER
+        SplitButton => 43,
+        IpAddress => 79,
+        Nothing => 86,              // ATSPI_ROLE_REDUNDANT_OBJECT
+    }
+}
+
+fn get_atspi_role_name(role: AccessibilityRole) -> &'static str {
+    match get_atspi_role(role) {
+        2 => "alert",
+        3 => "animation",
+        7 => "check box",
+        10 => "column header",
+        11 => "combo box",
+        15 => "dial",
+        16 => "dialog",
+        18 => "drawing area",
+        20 => "filler",
+        23 => "frame",
+        27 => "image",
+        29 => "label",
+        31 => "list",
+        32 => "list item",
+        34 => "menu bar",
+        35 => "menu item",
+        37 => "page tab",
+        38 => "page tab list",
+        39 => "panel",
+        41 => "popup menu",
+        42 => "progress bar",
+        43 => "push button",
+        44 => "radio button",
+        47 => "row header",
+        48 => "scroll bar",
+        50 => "separator",
+        51 => "slider",
+        52 => "spin button",
+        54 => "status bar",
+        55 => "table",
+        56 => "table cell",
+        63 => "tool bar",
+        64 => "tool tip",
+        65 => "tree",
+        75 => "application",
+        79 => "entry",
+        80 => "chart",
+        82 => "document frame",
+        84 => "page",
+        86 => "redundant object",
+        88 => "link",
+        90 => "table row",
+        91 => "tree item",
+        99 => "grouping",
+        104 => "title bar",
+        106 => "audio",
+        113 => "math",
+        115 => "timer",
+        _ => "unknown",
+    }
+}
+
+/// Bit index of the state in the `AtspiStateType` set
+fn get_atspi_state(state: AccessibilityState) -> Option<u32> {
+    use azul_core::dom::AccessibilityState::*;
+    match state {
+        Unavailable => None,            // = not ATSPI_STATE_ENABLED / SENSITIVE
+        Selected => Some(23),           // ATSPI_STATE_SELECTED
+        Focused => Some(12),            // ATSPI_STATE_FOCUSED
+        Checked => Some(4),             // ATSPI_STATE_CHECKED
+        Readonly => Some(43),           // ATSPI_STATE_READ_ONLY
+        Default => Some(39),            // ATSPI_STATE_IS_DEFAULT
+        Expanded => Some(10),           // ATSPI_STATE_EXPANDED
+        Collapsed => Some(5),           // ATSPI_STATE_COLLAPSED
+        Busy => Some(3),                // ATSPI_STATE_BUSY
+        Offscreen => None,              // = not ATSPI_STATE_SHOWING
+        Focusable => Some(11),          // ATSPI_STATE_FOCUSABLE
+        Selectable => Some(22),         // ATSPI_STATE_SELECTABLE
+        Linked => None,
+        Traversed => Some(40),          // ATSPI_STATE_VISITED
+        Multiselectable => Some(18),    // ATSPI_STATE_MULTISELECTABLE
+        Protected => None,              // exposed via ATSPI_ROLE_PASSWORD_TEXT
+    }
+}
+
+fn get_state_name(state: AccessibilityState) -> &'static str {
+    use azul_core::dom::AccessibilityState::*;
+    match state {
+        Unavailable => "enabled",
+        Selected => "selected",
+        Focused => "focused",
+        Checked => "checked",
+        Readonly => "read-only",
+        Default => "is-default",
+        Expanded => "expanded",
+        Collapsed => "collapsed",
+        Busy => "busy",
+        Offscreen => "showing",
+        Focusable => "focusable",
+        Selectable => "selectable",
+        Linked => "visited",
+        Traversed => "visited",
+        Multiselectable => "multiselectable",
+        Protected => "protected",
+    }
+}
+
+/// Converts the states to the two 32-bit words of an `AtspiStateSet`
+fn get_atspi_state_set(states: &[AccessibilityState]) -> Vec<u32> {
+
+    // ATSPI_STATE_ENABLED, SENSITIVE, SHOWING, VISIBLE
+    const ENABLED: u32 = 8;
+    const SENSITIVE: u32 = 24;
+    const SHOWING: u32 = 25;
+    const VISIBLE: u32 = 30;
+
+    let mut bits: u64 = 0;
+
+    if !states.contains(&AccessibilityState::Unavailable) {
+        bits |= (1 << ENABLED) | (1 << SENSITIVE);
+    }
+    if !states.contains(&AccessibilityState::Offscreen) {
+        bits |= (1 << SHOWING) | (1 << VISIBLE);
+    }
+    for state in states {
+        if let Some(bit) = get_atspi_state(*state) {
+            bits |= 1 << bit;
+        }
+    }
+
+    vec![(bits & 0xFFFF_FFFF) as u32, (bits >> 32) as u32]
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::{Command, Stdio, Child};
+    use std::io::{BufRead, BufReader};
+    use azul_core::dom::{Dom, TabIndex, IdOrClass::Class};
+    use azul_css::Css;
+    use super::*;
+
+    /// Starts a private `dbus-daemon`, returns `None` if it is not installed
+    fn start_private_bus() -> Option<(Child, String)> {
+        let mut child = Command::new("dbus-daemon")
+            .args(&["--session", "--nofork", "--print-address"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .ok()?;
+        let mut address = String::new();
+        BufReader::new(child.stdout.as_mut()?).read_line(&mut address).ok()?;
+        Some((child, address.trim().to_string()))
+    }
+
+    #[test]
+    fn test_tree_is_exported_on_private_bus() {
+
+        let (mut daemon, address) = match start_private_bus() {
+            Some(s) => s,
+            None => return, // dbus-daemon not available
+        };
+
+        let bridge = AtSpiBridge::new_for_address(&address, "test-app").unwrap();
+
+        let styled_dom = Dom::body()
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(vec![Class("__azul-native-button-container".into())].into())
+            .with_tab_index(TabIndex::Auto)
+            .with_children(vec![Dom::text("OK")].into()),
+        ].into())
+        .style(&mut Css::empty());
+
+        bridge.update(AccessibilityTree::from_styled_dom(&styled_dom, None));
+
+        let client = Connection::new_for_address(&address, true).unwrap();
+        let bus_name = bridge.get_bus_name();
+
+        let reply = client.call_method(Some(&bus_name), ROOT_PATH, Some(ACCESSIBLE_INTERFACE), "GetChildren", &()).unwrap();
+        let windows: Vec<(String, OwnedObjectPath)> = reply.body().unwrap();
+        assert_eq!(windows.len(), 1);
+
+        let window_path = windows[0].1.as_str().to_string();
+        let reply = client.call_method(Some(&bus_name), &window_path, Some(ACCESSIBLE_INTERFACE), "GetChildren", &()).unwrap();
+        let buttons: Vec<(String, OwnedObjectPath)> = reply.body().unwrap();
+        assert_eq!(buttons.len(), 1);
+
+        let button_path = buttons[0].1.as_str().to_string();
+        let reply = client.call_method(Some(&bus_name), &button_path, Some(ACCESSIBLE_INTERFACE), "GetRole", &()).unwrap();
+        assert_eq!(reply.body::<u32>().unwrap(), 43); // ATSPI_ROLE_PUSH_BUTTON
+
+        let reply = client.call_method(
+            Some(&bus_name), &button_path, Some(PROPERTIES_INTERFACE), "Get", &(ACCESSIBLE_INTERFACE, "Name")
+        ).unwrap();
+        let name: Value = reply.body().unwrap();
+        assert_eq!(name, Value::from("OK"));
+
+        drop(bridge);
+        let _ = daemon.kill();
+    }
+}
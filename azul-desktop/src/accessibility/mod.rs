@@ -0,0 +1,656 @@
+//! Platform-neutral accessibility tree
+//!
+//! The `AccessibilityTree` is built from the `StyledDom`s of a window (including
+//! the DOMs of IFrames) and the layouted rectangles. Not every DOM node ends up in
+//! the tree: only nodes that carry `AccessibilityInfo`, built-in widgets, text,
+//! images, IFrames and focusable / clickable nodes are exposed, purely structural
+//! `div`s are skipped and their children re-parented to the next exposed ancestor.
+//!
+//! On every frame the tree is re-built and compared with the tree of the last frame
+//! (`AccessibilityTree::diff`), the resulting `AccessibilityTreeUpdate`s are then
+//! forwarded to the platform accessibility API (AT-SPI2 on Linux, see `atspi`).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
+use azul_core::{
+    id_tree::NodeId,
+    callbacks::DomNodeId,
+    dom::{NodeData, NodeType, AccessibilityRole, AccessibilityState},
+    styled_dom::{StyledDom, DomId},
+    ui_solver::{LayoutResult, PositionedRectangle},
+    window::{LogicalRect, LogicalPosition, LogicalSize, VirtualKeyCodeCombo},
+};
+
+/// Bridge to the AT-SPI2 accessibility bus (Linux)
+#[cfg(all(target_os = "linux", feature = "atspi"))]
+pub mod atspi;
+
+// CSS classes of the built-in widgets (see azul-dll/src/widgets)
+const BUTTON_CLASS: &str = "__azul-native-button-container";
+const CHECKBOX_CLASS: &str = "__azul-native-checkbox-container";
+const TEXT_INPUT_CLASS: &str = "__azul-native-text-input-container";
+const PROGRESSBAR_CLASS: &str = "__azul-native-progressbar-container";
+const VIRTUAL_LIST_CLASS: &str = "__azul-native-virtual-list-container";
+const VIRTUAL_LIST_ROW_CLASS: &str = "__azul-native-virtual-list-row";
+const DROP_DOWN_CLASS: &str = "__azul-native-drop-down-container";
+const DROP_DOWN_POPUP_CLASS: &str = "__azul-native-drop-down-popup";
+const DROP_DOWN_CHOICE_CLASS: &str = "__azul-native-drop-down-choice";
+const SLIDER_CLASS: &str = "__azul-native-slider-container";
+const TREE_VIEW_CLASS: &str = "__azul-native-tree-view-container";
+const TREE_VIEW_ROW_CLASS: &str = "__azul-native-tree-view-row";
+const TAB_CONTAINER_STRIP_CLASS: &str = "__azul-native-tab-container-strip";
+const TAB_CONTAINER_TAB_CLASS: &str = "__azul-native-tab-container-tab";
+const TAB_CONTAINER_PAGE_CLASS: &str = "__azul-native-tab-container-page";
+
+/// Identifies a node in the accessibility tree (= DOM node of a DOM in the window)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AccessibilityNodeId {
+    pub dom: DomId,
+    pub node: NodeId,
+}
+
+impl AccessibilityNodeId {
+
+    pub const fn new(dom: DomId, node: NodeId) -> Self {
+        Self { dom, node }
+    }
+
+    /// Packs the ID into a single integer, used for the object paths on the accessibility bus
+    pub fn as_u64(&self) -> u64 {
+        ((self.dom.inner as u64) << 32) | (self.node.index() as u64 & 0xFFFF_FFFF)
+    }
+
+    pub fn from_u64(id: u64) -> Self {
+        Self {
+            dom: DomId { inner: (id >> 32) as usize },
+            node: NodeId::new((id & 0xFFFF_FFFF) as usize),
+        }
+    }
+}
+
+impl From<AccessibilityNodeId> for DomNodeId {
+    fn from(id: AccessibilityNodeId) -> DomNodeId {
+        use azul_core::styled_dom::AzNodeId;
+        DomNodeId { dom: id.dom, node: AzNodeId::from_crate_internal(Some(id.node)) }
+    }
+}
+
+/// Single node in the `AccessibilityTree`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNode {
+    pub id: AccessibilityNodeId,
+    pub parent: Option<AccessibilityNodeId>,
+    pub children: Vec<AccessibilityNodeId>,
+    pub role: AccessibilityRole,
+    pub name: Option<String>,
+    pub value: Option<String>,
+    pub states: BTreeSet<AccessibilityState>,
+    pub accelerator: Option<VirtualKeyCodeCombo>,
+    pub default_action: Option<String>,
+    /// Bounds of the node, relative to the window
+    pub bounds: LogicalRect,
+}
+
+impl AccessibilityNode {
+    pub fn is_focused(&self) -> bool {
+        self.states.contains(&AccessibilityState::Focused)
+    }
+}
+
+/// What changed between two `AccessibilityTree`s
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessibilityTreeUpdate {
+    /// Node was added as a child of `parent` at `index`
+    NodeAdded { id: AccessibilityNodeId, parent: AccessibilityNodeId, index: usize },
+    /// Node was removed from `parent`
+    NodeRemoved { id: AccessibilityNodeId, parent: AccessibilityNodeId, index: usize },
+    RoleChanged(AccessibilityNodeId),
+    NameChanged(AccessibilityNodeId),
+    ValueChanged(AccessibilityNodeId),
+    BoundsChanged(AccessibilityNodeId),
+    StateChanged { id: AccessibilityNodeId, state: AccessibilityState, enabled: bool },
+    FocusChanged { old: Option<AccessibilityNodeId>, new: Option<AccessibilityNodeId> },
+}
+
+/// Accessibility tree of one window
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityTree {
+    pub root: AccessibilityNodeId,
+    pub nodes: BTreeMap<AccessibilityNodeId, AccessibilityNode>,
+}
+
+/// DOM + layout information of one DOM in the window (root DOM or IFrame)
+struct DomSource<'a> {
+    styled_dom: &'a StyledDom,
+    rects: Option<&'a [PositionedRectangle]>,
+    iframe_mapping: Option<&'a BTreeMap<NodeId, DomId>>,
+}
+
+impl Default for AccessibilityTree {
+    fn default() -> Self {
+        let root = AccessibilityNodeId::new(DomId::ROOT_ID, NodeId::ZERO);
+        let mut nodes = BTreeMap::new();
+        nodes.insert(root, AccessibilityNode {
+            id: root,
+            parent: None,
+            children: Vec::new(),
+            role: AccessibilityRole::Window,
+            name: None,
+            value: None,
+            states: BTreeSet::new(),
+            accelerator: None,
+            default_action: None,
+            bounds: LogicalRect::new(LogicalPosition::zero(), LogicalSize::zero()),
+        });
+        Self { root, nodes }
+    }
+}
+
+impl AccessibilityTree {
+
+    /// Builds the accessibility tree from the layout results of a window
+    pub fn new(layout_results: &[LayoutResult], focused_node: Option<DomNodeId>) -> Self {
+        let sources = layout_results.iter().map(|lr| DomSource {
+            styled_dom: &lr.styled_dom,
+            rects: Some(lr.rects.as_ref()),
+            iframe_mapping: Some(&lr.iframe_mapping),
+        }).collect::<Vec<_>>();
+        Self::build(&sources, focused_node)
+    }
+
+    /// Builds the accessibility tree of a single `StyledDom` without any layout
+    /// information (all bounds are zero), IFrames are not invoked
+    pub fn from_styled_dom(styled_dom: &StyledDom, focused_node: Option<DomNodeId>) -> Self {
+        let sources = [DomSource { styled_dom, rects: None, iframe_mapping: None }];
+        Self::build(&sources, focused_node)
+    }
+
+    fn build(sources: &[DomSource], focused_node: Option<DomNodeId>) -> Self {
+
+        let mut tree = Self::default();
+
+        let root_source = match sources.get(0) {
+            Some(s) => s,
+            None => return tree,
+        };
+
+        let root_node_id = match root_source.styled_dom.root.into_crate_internal() {
+            Some(s) => s,
+            None => return tree,
+        };
+
+        tree.nodes.clear();
+        tree.root = AccessibilityNodeId::new(DomId::ROOT_ID, root_node_id);
+
+        let focused_node = focused_node.and_then(|f| Some(AccessibilityNodeId::new(f.dom, f.node.into_crate_internal()?)));
+        tree.insert_subtree(sources, DomId::ROOT_ID, root_node_id, None, LogicalPosition::zero(), focused_node);
+        tree
+    }
+
+    pub fn get_node(&self, id: &AccessibilityNodeId) -> Option<&AccessibilityNode> {
+        self.nodes.get(id)
+    }
+
+    pub fn get_root(&self) -> Option<&AccessibilityNode> {
+        self.nodes.get(&self.root)
+    }
+
+    pub fn get_focused_node(&self) -> Option<AccessibilityNodeId> {
+        self.nodes.values().find(|n| n.is_focused()).map(|n| n.id)
+    }
+
+    /// Returns the index of the node in the children of its parent
+    pub fn get_index_in_parent(&self, id: &AccessibilityNodeId) -> Option<usize> {
+        let parent = self.nodes.get(id)?.parent?;
+        self.nodes.get(&parent)?.children.iter().position(|c| c == id)
+    }
+
+    fn insert_subtree(
+        &mut self,
+        sources: &[DomSource],
+        dom_id: DomId,
+        node_id: NodeId,
+        parent: Option<AccessibilityNodeId>,
+        dom_origin: LogicalPosition,
+        focused_node: Option<AccessibilityNodeId>,
+    ) {
+        let source = match sources.get(dom_id.inner) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let node_data = &source.styled_dom.node_data.as_ref()[node_id.index()];
+        let node_hierarchy = source.styled_dom.node_hierarchy.as_container();
+        let id = AccessibilityNodeId::new(dom_id, node_id);
+
+        // the root of the window is always exposed
+        let is_exposed = parent.is_none() || is_exposed(node_data);
+
+        let bounds = match source.rects.and_then(|r| r.get(node_id.index())) {
+            Some(rect) => {
+                let (x, y) = rect.position.get_static_offset();
+                LogicalRect::new(LogicalPosition::new(dom_origin.x + x, dom_origin.y + y), rect.size)
+            },
+            None => LogicalRect::new(dom_origin, LogicalSize::zero()),
+        };
+
+        let children_parent = if is_exposed {
+
+            let role = if parent.is_none() && node_data.get_accessibility_info().is_none() {
+                AccessibilityRole::Window
+            } else {
+                get_default_role(node_data)
+            };
+
+            let node = AccessibilityNode {
+                id,
+                parent,
+                children: Vec::new(),
+                role,
+                name: get_name(source.styled_dom, node_id, role),
+                value: get_value(source.styled_dom, node_id, role),
+                states: get_states(source.styled_dom, node_id, role, focused_node == Some(id)),
+                accelerator: node_data.get_accessibility_info().and_then(|i| i.accelerator.as_ref().cloned()),
+                default_action: node_data.get_accessibility_info().and_then(|i| i.default_action.as_ref().map(|s| s.as_str().into())),
+                bounds,
+            };
+
+            if let Some(parent) = parent.and_then(|p| self.nodes.get_mut(&p)) {
+                parent.children.push(id);
+            }
+            self.nodes.insert(id, node);
+
+            // the label of a button / checkbox / text input is its name,
+            // not a separate node
+            if is_leaf_role(role) {
+                return;
+            }
+
+            Some(id)
+        } else {
+            parent
+        };
+
+        // the IFrame DOM is positioned relative to the IFrame node
+        if let Some(iframe_dom_id) = source.iframe_mapping.and_then(|m| m.get(&node_id)) {
+            if let Some(iframe_root) = sources.get(iframe_dom_id.inner).and_then(|s| s.styled_dom.root.into_crate_internal()) {
+                self.insert_subtree(sources, *iframe_dom_id, iframe_root, children_parent, bounds.origin, focused_node);
+            }
+        }
+
+        for child_id in node_id.az_children(&node_hierarchy) {
+            self.insert_subtree(sources, dom_id, child_id, children_parent, dom_origin, focused_node);
+        }
+    }
+
+    /// Compares the tree of the last frame (`self`) with the `new` tree
+    pub fn diff(&self, new: &Self) -> Vec<AccessibilityTreeUpdate> {
+
+        use self::AccessibilityTreeUpdate::*;
+
+        let mut updates = Vec::new();
+
+        // removals first, so that the indices of the removed children
+        // still refer to the old parent
+        for (id, old_node) in self.nodes.iter() {
+            if new.nodes.contains_key(id) {
+                continue;
+            }
+            if let Some(parent) = old_node.parent {
+                if let Some(index) = self.get_index_in_parent(id) {
+                    updates.push(NodeRemoved { id: *id, parent, index });
+                }
+            }
+        }
+
+        for (id, new_node) in new.nodes.iter() {
+
+            let old_node = match self.nodes.get(id) {
+                Some(s) => s,
+                None => {
+                    if let Some(parent) = new_node.parent {
+                        if let Some(index) = new.get_index_in_parent(id) {
+                            updates.push(NodeAdded { id: *id, parent, index });
+                        }
+                    }
+                    continue;
+                },
+            };
+
+            if old_node.role != new_node.role {
+                updates.push(RoleChanged(*id));
+            }
+            if old_node.name != new_node.name {
+                updates.push(NameChanged(*id));
+            }
+            if old_node.value != new_node.value {
+                updates.push(ValueChanged(*id));
+            }
+            if old_node.bounds != new_node.bounds {
+                updates.push(BoundsChanged(*id));
+            }
+            for state in old_node.states.difference(&new_node.states) {
+                updates.push(StateChanged { id: *id, state: *state, enabled: false });
+            }
+            for state in new_node.states.difference(&old_node.states) {
+                updates.push(StateChanged { id: *id, state: *state, enabled: true });
+            }
+        }
+
+        let old_focus = self.get_focused_node();
+        let new_focus = new.get_focused_node();
+        if old_focus != new_focus {
+            updates.push(FocusChanged { old: old_focus, new: new_focus });
+        }
+
+        updates
+    }
+}
+
+fn is_exposed(node_data: &NodeData) -> bool {
+    if node_data.get_accessibility_info().is_some() {
+        return true;
+    }
+    if get_widget_role(node_data).is_some() {
+        return true;
+    }
+    match node_data.get_node_type() {
+        NodeType::Text(_) | NodeType::Image(_) | NodeType::IFrame(_) => true,
+        _ => node_data.is_focusable() || !node_data.get_callbacks().is_empty(),
+    }
+}
+
+/// Nodes with these roles are exposed as a single node, their children are not exposed
+fn is_leaf_role(role: AccessibilityRole) -> bool {
+    use azul_core::dom::AccessibilityRole::*;
+    match role {
+        PushButton | CheckButton | RadioButton | Text | StaticText |
+        ProgressBar | Slider | MenuItem | Link | ListItem |
+        OutlineItem | Pagetab => true,
+        _ => false,
+    }
+}
+
+/// Default role of the built-in widgets, derived from their CSS class
+fn get_widget_role(node_data: &NodeData) -> Option<AccessibilityRole> {
+    if node_data.has_class(BUTTON_CLASS) {
+        Some(AccessibilityRole::PushButton)
+    } else if node_data.has_class(CHECKBOX_CLASS) {
+        Some(AccessibilityRole::CheckButton)
+    } else if node_data.has_class(TEXT_INPUT_CLASS) {
+        Some(AccessibilityRole::Text)
+    } else if node_data.has_class(PROGRESSBAR_CLASS) {
+        Some(AccessibilityRole::ProgressBar)
+    } else if node_data.has_class(VIRTUAL_LIST_CLASS) {
+        Some(AccessibilityRole::List)
+    } else if node_data.has_class(VIRTUAL_LIST_ROW_CLASS) {
+        Some(AccessibilityRole::ListItem)
+    } else if node_data.has_class(DROP_DOWN_CLASS) {
+        Some(AccessibilityRole::ComboBox)
+    } else if node_data.has_class(DROP_DOWN_POPUP_CLASS) {
+        Some(AccessibilityRole::List)
+    } else if node_data.has_class(DROP_DOWN_CHOICE_CLASS) {
+        Some(AccessibilityRole::ListItem)
+    } else if node_data.has_class(SLIDER_CLASS) {
+        Some(AccessibilityRole::Slider)
+    } else if node_data.has_class(TREE_VIEW_CLASS) {
+        Some(AccessibilityRole::Outline)
+    } else if node_data.has_class(TREE_VIEW_ROW_CLASS) {
+        Some(AccessibilityRole::OutlineItem)
+    } else if node_data.has_class(TAB_CONTAINER_STRIP_CLASS) {
+        Some(AccessibilityRole::PageTabList)
+    } else if node_data.has_class(TAB_CONTAINER_TAB_CLASS) {
+        Some(AccessibilityRole::Pagetab)
+    } else if node_data.has_class(TAB_CONTAINER_PAGE_CLASS) {
+        Some(AccessibilityRole::PropertyPage)
+    } else {
+        None
+    }
+}
+
+/// Returns the role set via `AccessibilityInfo`, otherwise a role derived from the node
+pub fn get_default_role(node_data: &NodeData) -> AccessibilityRole {
+
+    if let Some(info) = node_data.get_accessibility_info() {
+        return info.role;
+    }
+
+    if let Some(role) = get_widget_role(node_data) {
+        return role;
+    }
+
+    match node_data.get_node_type() {
+        NodeType::Text(_) => AccessibilityRole::StaticText,
+        NodeType::Image(_) => AccessibilityRole::Graphic,
+        NodeType::IFrame(_) => AccessibilityRole::Pane,
+        NodeType::Body => AccessibilityRole::Client,
+        NodeType::Div | NodeType::Br => AccessibilityRole::Grouping,
+    }
+}
+
+fn get_name(styled_dom: &StyledDom, node_id: NodeId, role: AccessibilityRole) -> Option<String> {
+
+    let node_data = &styled_dom.node_data.as_ref()[node_id.index()];
+
+    if let Some(name) = node_data.get_accessibility_info().and_then(|i| i.name.as_ref().map(|s| s.as_str().into())) {
+        return Some(name);
+    }
+
+    match role {
+        // the text of a text input is its value, not its name
+        AccessibilityRole::Text => None,
+        _ if is_leaf_role(role) => get_text_content(styled_dom, node_id),
+        _ => None,
+    }
+}
+
+fn get_value(styled_dom: &StyledDom, node_id: NodeId, role: AccessibilityRole) -> Option<String> {
+
+    let node_data = &styled_dom.node_data.as_ref()[node_id.index()];
+
+    if let Some(value) = node_data.get_accessibility_info().and_then(|i| i.value.as_ref().map(|s| s.as_str().into())) {
+        return Some(value);
+    }
+
+    match role {
+        AccessibilityRole::Text => Some(get_text_content(styled_dom, node_id).unwrap_or_default()),
+        _ => None,
+    }
+}
+
+fn get_states(
+    styled_dom: &StyledDom,
+    node_id: NodeId,
+    role: AccessibilityRole,
+    is_focused: bool,
+) -> BTreeSet<AccessibilityState> {
+
+    let node_data = &styled_dom.node_data.as_ref()[node_id.index()];
+    let mut states = BTreeSet::new();
+
+    if let Some(info) = node_data.get_accessibility_info() {
+        states.extend(info.states.iter().copied());
+    }
+
+    if node_data.is_focusable() {
+        states.insert(AccessibilityState::Focusable);
+    }
+
+    if is_focused {
+        states.insert(AccessibilityState::Focused);
+    }
+
+    // The CheckBox widget only toggles the opacity of its content
+    // node, so the computed opacity is the current "checked" state
+    if role == AccessibilityRole::CheckButton && node_data.has_class(CHECKBOX_CLASS) {
+        let node_hierarchy = styled_dom.node_hierarchy.as_container();
+        let node_data_container = styled_dom.node_data.as_container();
+        let styled_nodes = styled_dom.styled_nodes.as_container();
+        let css_property_cache = styled_dom.get_css_property_cache();
+        let is_checked = node_id.az_children(&node_hierarchy).next().map(|content_id| {
+            css_property_cache
+            .get_opacity(&node_data_container[content_id], &content_id, &styled_nodes[content_id].state)
+            .and_then(|o| o.get_property().map(|o| o.inner.get() > 0.0))
+            .unwrap_or(true)
+        }).unwrap_or(false);
+        if is_checked {
+            states.insert(AccessibilityState::Checked);
+        }
+    }
+
+    states
+}
+
+/// Concatenates the text of all text nodes below the node
+fn get_text_content(styled_dom: &StyledDom, node_id: NodeId) -> Option<String> {
+
+    fn collect(styled_dom: &StyledDom, node_id: NodeId, out: &mut Vec<String>) {
+        let node_hierarchy = styled_dom.node_hierarchy.as_container();
+        if let NodeType::Text(t) = styled_dom.node_data.as_ref()[node_id.index()].get_node_type() {
+            let t = t.as_str().trim();
+            if !t.is_empty() {
+                out.push(t.into());
+            }
+        }
+        for child_id in node_id.az_children(&node_hierarchy) {
+            collect(styled_dom, child_id, out);
+        }
+    }
+
+    let mut texts = Vec::new();
+    collect(styled_dom, node_id, &mut texts);
+    if texts.is_empty() {
+        None
+    } else {
+        Some(texts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use azul_core::dom::{Dom, TabIndex, IdOrClass::Class};
+    use azul_css::Css;
+    use super::*;
+
+    #[test]
+    fn test_button_is_exposed_as_push_button() {
+
+        let styled_dom = Dom::body()
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(vec![Class(BUTTON_CLASS.into())].into())
+            .with_tab_index(TabIndex::Auto)
+            .with_children(vec![Dom::text("Hello")].into()),
+            // structural div without callbacks: skipped, child is re-parented
+            Dom::div().with_children(vec![Dom::text("World")].into()),
+        ].into())
+        .style(&mut Css::empty());
+
+        let tree = AccessibilityTree::from_styled_dom(&styled_dom, None);
+        let root = tree.get_root().unwrap();
+        assert_eq!(root.role, AccessibilityRole::Window);
+        assert_eq!(root.children.len(), 2);
+
+        let button = tree.get_node(&root.children[0]).unwrap();
+        assert_eq!(button.role, AccessibilityRole::PushButton);
+        assert_eq!(button.name.as_deref(), Some("Hello"));
+        assert!(button.children.is_empty());
+        assert!(button.states.contains(&AccessibilityState::Focusable));
+
+        let text = tree.get_node(&root.children[1]).unwrap();
+        assert_eq!(text.role, AccessibilityRole::StaticText);
+        assert_eq!(text.name.as_deref(), Some("World"));
+        assert_eq!(text.parent, Some(root.id));
+    }
+
+    #[test]
+    fn test_widget_roles() {
+
+        fn widget(class: &str, children: Vec<Dom>) -> Dom {
+            Dom::div()
+            .with_ids_and_classes(vec![Class(class.into())].into())
+            .with_children(children.into())
+        }
+
+        let styled_dom = Dom::body()
+        .with_children(vec![
+            widget(DROP_DOWN_CLASS, vec![
+                widget(DROP_DOWN_POPUP_CLASS, vec![
+                    widget(DROP_DOWN_CHOICE_CLASS, vec![Dom::text("Apple")]),
+                ]),
+            ]),
+            widget(SLIDER_CLASS, vec![]),
+            widget(TREE_VIEW_CLASS, vec![
+                widget(TREE_VIEW_ROW_CLASS, vec![widget("__azul-native-tree-view-label", vec![Dom::text("src")])]),
+            ]),
+            widget(TAB_CONTAINER_STRIP_CLASS, vec![
+                widget(TAB_CONTAINER_TAB_CLASS, vec![Dom::text("Tab 1")]),
+            ]),
+            widget(TAB_CONTAINER_PAGE_CLASS, vec![]),
+        ].into())
+        .style(&mut Css::empty());
+
+        let tree = AccessibilityTree::from_styled_dom(&styled_dom, None);
+        let root = tree.get_root().unwrap();
+        let roles = root.children.iter().map(|c| tree.get_node(c).unwrap().role).collect::<Vec<_>>();
+        assert_eq!(roles, vec![
+            AccessibilityRole::ComboBox,
+            AccessibilityRole::Slider,
+            AccessibilityRole::Outline,
+            AccessibilityRole::PageTabList,
+            AccessibilityRole::PropertyPage,
+        ]);
+
+        let child = |parent: &AccessibilityNodeId| tree.get_node(&tree.get_node(parent).unwrap().children[0]).unwrap();
+
+        let popup = child(&root.children[0]);
+        assert_eq!(popup.role, AccessibilityRole::List);
+        let choice = child(&popup.id);
+        assert_eq!(choice.role, AccessibilityRole::ListItem);
+        assert_eq!(choice.name.as_deref(), Some("Apple"));
+
+        // rows and tabs are named after their text, the label nodes are not exposed
+        let row = child(&root.children[2]);
+        assert_eq!(row.role, AccessibilityRole::OutlineItem);
+        assert_eq!(row.name.as_deref(), Some("src"));
+        assert!(row.children.is_empty());
+
+        let tab = child(&root.children[3]);
+        assert_eq!(tab.role, AccessibilityRole::Pagetab);
+        assert_eq!(tab.name.as_deref(), Some("Tab 1"));
+    }
+
+    #[test]
+    fn test_diff_reports_changed_text_and_focus() {
+
+        fn dom(text: &str) -> StyledDom {
+            Dom::body()
+            .with_children(vec![
+                Dom::div()
+                .with_ids_and_classes(vec![Class(TEXT_INPUT_CLASS.into())].into())
+                .with_tab_index(TabIndex::Auto)
+                .with_children(vec![Dom::text(text)].into()),
+            ].into())
+            .style(&mut Css::empty())
+        }
+
+        let input_id = AccessibilityNodeId::new(DomId::ROOT_ID, NodeId::new(1));
+        let old = AccessibilityTree::from_styled_dom(&dom("ab"), None);
+        let new = AccessibilityTree::from_styled_dom(&dom("abc"), Some(input_id.into()));
+
+        assert_eq!(new.get_node(&input_id).unwrap().value.as_deref(), Some("abc"));
+
+        let updates = old.diff(&new);
+        assert!(updates.contains(&AccessibilityTreeUpdate::ValueChanged(input_id)));
+        assert!(updates.contains(&AccessibilityTreeUpdate::StateChanged {
+            id: input_id,
+            state: AccessibilityState::Focused,
+            enabled: true,
+        }));
+        assert!(updates.contains(&AccessibilityTreeUpdate::FocusChanged { old: None, new: Some(input_id) }));
+        assert!(old.diff(&old).is_empty());
+    }
+}
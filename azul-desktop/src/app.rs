@@ -304,10 +304,39 @@ pub mod extra {
         ].into()).style(&mut Css::empty())
     }
 
+    // re-parses the file only if it (or one of its stylesheets) changed since the last call
     #[cfg(feature = "xml")]
     pub fn styled_dom_from_file(path: &str) -> StyledDom {
-        use azulc_lib::xml::XmlComponentMap;
-        azulc_lib::xml::domxml_from_file(path, &mut XmlComponentMap::default()).parsed_dom
+        azulc_lib::hot_reload::styled_dom_from_file(path)
+    }
+
+    // makes the components of the XML files in the directory available to `styled_dom_from_file`
+    #[cfg(not(feature = "xml"))]
+    pub fn add_xml_component_directory(_: &str) { }
+
+    #[cfg(feature = "xml")]
+    pub fn add_xml_component_directory(dir: &str) {
+        azulc_lib::hot_reload::add_component_directory(dir)
+    }
+
+    // adds the components of `register_fn` (i.e. the built-in widgets) to `styled_dom_from_str / _file`
+    #[cfg(not(feature = "xml"))]
+    pub fn add_xml_component_registration(_: fn(&mut azul_core::xml::XmlComponentMap)) { }
+
+    #[cfg(feature = "xml")]
+    pub fn add_xml_component_registration(register_fn: fn(&mut azul_core::xml::XmlComponentMap)) {
+        azulc_lib::hot_reload::add_component_registration(register_fn)
+    }
+
+    // called by the hot-reload timer of windows with `WindowCreateOptions::hot_reload` set
+    #[cfg(not(feature = "xml"))]
+    pub fn hot_reload_should_regenerate() -> bool {
+        true
+    }
+
+    #[cfg(feature = "xml")]
+    pub fn hot_reload_should_regenerate() -> bool {
+        azulc_lib::hot_reload::should_regenerate()
     }
 
     #[cfg(not(feature = "xml"))]
@@ -319,7 +348,6 @@ pub mod extra {
 
     #[cfg(feature = "xml")]
     pub fn styled_dom_from_str(s: &str) -> StyledDom {
-        use azulc_lib::xml::XmlComponentMap;
-        azulc_lib::xml::domxml_from_str(s, &mut XmlComponentMap::default()).parsed_dom
+        azulc_lib::xml::domxml_from_str(s, &mut azulc_lib::hot_reload::new_component_map()).parsed_dom
     }
 }
@@ -0,0 +1,76 @@
+//! Live preview window for Azul XML files (started by `azulc --preview file.xml`)
+//!
+//! usage: azul-preview [--theme dark|light] [--components DIR] file.xml
+//!
+//! The window is hot-reloaded every time the XML file or one of its stylesheets changes,
+//! errors are shown on top of the last version of the UI that could be rendered.
+
+extern crate azul_core;
+extern crate azul_desktop;
+
+use std::env;
+use std::process::exit;
+use azul_core::{
+    app_resources::{AppConfig, LayoutSolverVersion},
+    callbacks::{RefAny, LayoutCallbackInfo},
+    styled_dom::StyledDom,
+    window::{WindowCreateOptions, WindowTheme},
+};
+use azul_desktop::app::App;
+
+struct PreviewData {
+    path: String,
+}
+
+extern "C" fn layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+    match data.downcast_ref::<PreviewData>() {
+        Some(data) => azul_desktop::app::extra::styled_dom_from_file(&data.path),
+        None => StyledDom::default(),
+    }
+}
+
+fn main() {
+
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+
+    let mut theme = None;
+    if let Some(pos) = args.iter().position(|a| a.as_str() == "--theme") {
+        theme = match args.get(pos + 1).map(|s| s.as_str()) {
+            Some("dark") => Some(WindowTheme::DarkMode),
+            Some("light") => Some(WindowTheme::LightMode),
+            other => {
+                eprintln!("error: unknown theme {:?}, expected \"dark\" or \"light\"", other);
+                exit(-1);
+            }
+        };
+        args.drain(pos..(pos + 2).min(args.len()));
+    }
+
+    // component libraries, can be given multiple times
+    while let Some(pos) = args.iter().position(|a| a.as_str() == "--components") {
+        match args.get(pos + 1) {
+            Some(dir) => azul_desktop::app::extra::add_xml_component_directory(dir),
+            None => {
+                eprintln!("error: no directory specified for --components");
+                exit(-1);
+            }
+        }
+        args.drain(pos..(pos + 2));
+    }
+
+    let path = match args.last() {
+        Some(s) => s.clone(),
+        None => {
+            eprintln!("usage: azul-preview [--theme dark|light] [--components DIR] file.xml");
+            exit(-1);
+        }
+    };
+
+    let mut window = WindowCreateOptions::new(layout);
+    window.hot_reload = true;
+    window.theme = theme.into();
+    window.state.title = format!("{} - Azul preview", path).into();
+
+    let app = App::new(RefAny::new(PreviewData { path }), AppConfig::default(LayoutSolverVersion::March2021));
+    app.run(window);
+}
@@ -52,6 +52,10 @@ extern crate raw_window_handle;
 extern crate webrender;
 extern crate tinyfiledialogs;
 extern crate clipboard2;
+#[cfg(all(target_os = "linux", feature = "atspi"))]
+extern crate zbus;
+#[cfg(all(target_os = "linux", feature = "atspi"))]
+extern crate zvariant;
 
 /// Manages application state (`App` / `AppState` / `AppResources`), wrapping resources and app state
 pub mod app;
@@ -66,6 +70,8 @@ pub mod css;
 pub mod str;
 /// Extra functions for file IO (for C / C++ developers)
 pub mod file;
+/// Platform-neutral accessibility tree + AT-SPI2 bridge (Linux)
+pub mod accessibility;
 /// Bindings to the native file-chooser, color picker, etc. dialogs
 pub mod dialogs;
 pub use azul_core::dom;
@@ -1,2 +1,4 @@
 #[cfg(target_os = "windows")]
-pub mod win32;
\ No newline at end of file
+pub mod win32;
+/// Writes the user input into event recording files (see `AppConfig.record_events`)
+pub mod recording;
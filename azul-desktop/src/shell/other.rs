@@ -336,7 +336,6 @@ fn run_inner(app: App) {
                             window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
                             window.render_async(transaction, /* display list was rebuilt */ true);
                             // window.force_synchronize_hit_tester_during();
-                            window.internal.current_window_state.focused_node = None; // unset the focus
                         },
                         Update::RegenerateStyledDomForAllWindows => {
                             if update_screen_timers_tasks == Update::DoNothing ||
@@ -472,7 +471,6 @@ fn run_inner(app: App) {
                             window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
                             window.render_async(transaction, /* display list was rebuilt */ true);
                             // window.force_synchronize_hit_tester_during();
-                            window.internal.current_window_state.focused_node = None; // unset the focus
                         },
                         Update::RegenerateStyledDomForAllWindows => {
                             if update_screen_timers_tasks == Update::DoNothing ||
@@ -532,7 +530,6 @@ fn run_inner(app: App) {
                         window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
                         window.render_async(transaction, /* display list was rebuilt */ true);
                         // window.force_synchronize_hit_tester_during();
-                        window.internal.current_window_state.focused_node = None; // unset the focus
                     }
                 }
             },
@@ -1360,6 +1357,7 @@ fn create_window(
     active_windows.insert(glutin_window_id, window);
 
     // push hot reload timer that triggers a UI restyle every 200ms
+    // (if the XML / CSS files of the UI changed)
     if should_hot_reload_window {
 
         use azul_core::task::{Timer, TerminateTimer};
@@ -1371,13 +1369,17 @@ fn create_window(
 
         extern "C" fn hot_reload_timer(_: &mut RefAny, _: &mut RefAny, _: TimerCallbackInfo) -> TimerCallbackReturn {
             TimerCallbackReturn {
-                should_update: Update::RegenerateStyledDomForCurrentWindow,
+                should_update: if crate::app::extra::hot_reload_should_regenerate() {
+                    Update::RegenerateStyledDomForCurrentWindow
+                } else {
+                    Update::DoNothing
+                },
                 should_terminate: TerminateTimer::Continue,
             }
         }
 
         let timer = Timer::new(data.clone(), hot_reload_timer, config.system_callbacks.get_system_time_fn)
-        .with_interval(StdDuration::from_millis(800).into());
+        .with_interval(StdDuration::from_millis(200).into());
 
         timers
         .entry(glutin_window_id)
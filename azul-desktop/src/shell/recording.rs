@@ -0,0 +1,115 @@
+//! Writes the user input of all windows into an event recording file
+//!
+//! The file format is defined in `azul_core::recording`, this module only
+//! contains the file writer that the shell calls once per processed event
+//! (currently only the Windows shell, the other platforms don't record yet).
+
+use core::fmt;
+use alloc::collections::btree_map::BTreeMap;
+use std::{
+    fs::File,
+    io::{BufWriter, Error as IoError, Write},
+    path::{Path, PathBuf},
+};
+use azul_core::{
+    app_resources::AppConfig,
+    task::{Instant, GetSystemTimeCallback},
+    window::FullWindowState,
+    recording::{
+        RecordedEvent, RecordedWindowState, duration_to_micros,
+        RECORDING_HEADER, RECORD_EVENTS_ENV_VAR, DEFAULT_RECORDING_FILE,
+    },
+};
+
+/// Writes the input of all windows into an event recording file
+pub struct EventRecorder {
+    writer: BufWriter<File>,
+    start: Instant,
+    get_system_time_fn: GetSystemTimeCallback,
+    /// window key -> (window index, last recorded state)
+    windows: BTreeMap<usize, (usize, RecordedWindowState)>,
+}
+
+impl fmt::Debug for EventRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EventRecorder {{ start: {:?}, windows: {} }}", self.start, self.windows.len())
+    }
+}
+
+impl EventRecorder {
+
+    /// Creates the recording file and writes the header
+    pub fn create(path: &Path, get_system_time_fn: GetSystemTimeCallback) -> Result<Self, IoError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{}", RECORDING_HEADER)?;
+        writer.flush()?;
+        Ok(Self {
+            writer,
+            start: (get_system_time_fn.cb)(),
+            get_system_time_fn,
+            windows: BTreeMap::new(),
+        })
+    }
+
+    /// Creates a recorder if either `record_events` is set or the
+    /// `AZUL_RECORD_EVENTS` environment variable contains a file name
+    pub fn from_app_config(config: &AppConfig) -> Option<Result<Self, IoError>> {
+        let path = match std::env::var(RECORD_EVENTS_ENV_VAR) {
+            Ok(p) if !p.is_empty() => PathBuf::from(p),
+            _ if config.record_events => PathBuf::from(DEFAULT_RECORDING_FILE),
+            _ => return None,
+        };
+        Some(Self::create(&path, config.system_callbacks.get_system_time_fn))
+    }
+
+    /// Records the changes of the window state since the last call for the same window
+    ///
+    /// `window_key` is any number that uniquely identifies the window (HWND, window ID, etc.)
+    pub fn record(&mut self, window_key: usize, window_state: &FullWindowState) -> Result<(), IoError> {
+
+        let time_micros = duration_to_micros(&(self.get_system_time_fn.cb)().duration_since(&self.start));
+        let new_state = RecordedWindowState::new(window_state);
+
+        let next_window_index = self.windows.len();
+        let (window, deltas) = match self.windows.get_mut(&window_key) {
+            Some((window_index, last_state)) => {
+                let deltas = RecordedWindowState::diff(Some(last_state), &new_state);
+                *last_state = new_state;
+                (*window_index, deltas)
+            },
+            None => {
+                let deltas = RecordedWindowState::diff(None, &new_state);
+                self.windows.insert(window_key, (next_window_index, new_state));
+                (next_window_index, deltas)
+            }
+        };
+
+        if deltas.is_empty() {
+            return Ok(());
+        }
+
+        for delta in deltas {
+            writeln!(self.writer, "{}", RecordedEvent { time_micros, window, delta })?;
+        }
+
+        // flush every frame, so that the recording survives a crash
+        self.writer.flush()
+    }
+}
+
+/// Opens the event recording file if `AppConfig.record_events`
+/// or the `AZUL_RECORD_EVENTS` environment variable is set
+pub(crate) fn open_event_recorder(config: &AppConfig) -> Option<EventRecorder> {
+    match EventRecorder::from_app_config(config)? {
+        Ok(o) => Some(o),
+        Err(e) => {
+            #[cfg(feature = "logging")] {
+                error!("could not create event recording file: {}", e);
+            }
+            #[cfg(not(feature = "logging"))] {
+                let _ = e;
+            }
+            None
+        }
+    }
+}
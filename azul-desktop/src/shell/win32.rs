@@ -6,6 +6,7 @@
 
 use crate::{
     app::{App, LazyFcCache},
+    shell::recording::{EventRecorder, open_event_recorder},
     wr_translate::{
         rebuild_display_list,
         generate_frame,
@@ -35,7 +36,7 @@ use azul_core::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        MouseCursorType, CallCallbacksResult, ImeComposition,
     },
     window_state::NodesToCheck,
 };
@@ -156,6 +157,7 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
     unsafe { RegisterClassW(&wc) };
 
     let dwm = DwmFunctions::initialize();
+    let imm = ImmFunctions::initialize();
     let gl = GlFunctions::initialize();
 
     let App {
@@ -166,6 +168,8 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
         fc_cache,
     } = app;
 
+    let event_recorder = open_event_recorder(&config);
+
     let app_data_inner = Rc::new(RefCell::new(ApplicationData {
         hinstance,
         data,
@@ -174,6 +178,8 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
         fc_cache,
         windows: BTreeMap::new(),
         dwm,
+        imm,
+        event_recorder,
     }));
 
     for opts in windows {
@@ -395,8 +401,23 @@ struct ApplicationData {
     fc_cache: LazyFcCache,
     windows: BTreeMap<usize, Window>,
     dwm: Option<DwmFunctions>,
+    imm: Option<ImmFunctions>,
+    /// Writes all user input to a file if `AppConfig.record_events` is set
+    event_recorder: Option<EventRecorder>,
+}
+
+/// Logs the keyboard shortcuts that can never be invoked because another
+/// shortcut with the same key combination shadows them
+#[cfg(feature = "logging")]
+fn warn_shortcut_conflicts(internal: &WindowInternal) {
+    for conflict in internal.get_shortcut_conflicts() {
+        warn!("{}", conflict);
+    }
 }
 
+#[cfg(not(feature = "logging"))]
+fn warn_shortcut_conflicts(_: &WindowInternal) { }
+
 // Extra functions from dwmapi.dll
 struct DwmFunctions {
     _dwmapi_dll_handle: HINSTANCE,
@@ -467,6 +488,97 @@ impl Drop for DwmFunctions {
     }
 }
 
+// flags of WM_IME_COMPOSITION / WM_IME_SETCONTEXT, from imm.h
+const GCS_COMPSTR: u32 = 0x0008;
+const GCS_CURSORPOS: u32 = 0x0080;
+const ISC_SHOWUICOMPOSITIONWINDOW: u32 = 0x8000_0000;
+
+type HIMC = *mut c_void;
+
+// Input method functions from imm32.dll
+struct ImmFunctions {
+    _imm32_dll_handle: HINSTANCE,
+    ImmGetContext: Option<extern "system" fn(HWND) -> HIMC>,
+    ImmReleaseContext: Option<extern "system" fn(HWND, HIMC) -> i32>,
+    ImmGetCompositionStringW: Option<extern "system" fn(HIMC, u32, *mut c_void, u32) -> i32>,
+}
+
+impl fmt::Debug for ImmFunctions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self._imm32_dll_handle as usize).fmt(f)?;
+        (self.ImmGetContext.map(|f| f as usize)).fmt(f)?;
+        (self.ImmReleaseContext.map(|f| f as usize)).fmt(f)?;
+        (self.ImmGetCompositionStringW.map(|f| f as usize)).fmt(f)?;
+        Ok(())
+    }
+}
+
+impl ImmFunctions {
+    fn initialize() -> Option<Self> {
+        use winapi::um::libloaderapi::GetProcAddress;
+
+        let hImm32_DLL = load_dll("imm32.dll")?;
+
+        let load_fn = |name: &'static str| {
+            let mut func_name = encode_ascii(name);
+            let func = unsafe { GetProcAddress(hImm32_DLL, func_name.as_mut_ptr()) };
+            if func != ptr::null_mut() { Some(func) } else { None }
+        };
+
+        Some(Self {
+            _imm32_dll_handle: hImm32_DLL,
+            ImmGetContext: load_fn("ImmGetContext").map(|f| unsafe { mem::transmute(f) }),
+            ImmReleaseContext: load_fn("ImmReleaseContext").map(|f| unsafe { mem::transmute(f) }),
+            ImmGetCompositionStringW: load_fn("ImmGetCompositionStringW").map(|f| unsafe { mem::transmute(f) }),
+        })
+    }
+
+    /// Returns the current pre-edit text of the input method of the window,
+    /// `None` if the composition is empty (i.e. cancelled or committed)
+    fn get_composition(&self, hwnd: HWND) -> Option<ImeComposition> {
+
+        let get_context = self.ImmGetContext?;
+        let release_context = self.ImmReleaseContext?;
+        let get_composition_string = self.ImmGetCompositionStringW?;
+
+        let himc = get_context(hwnd);
+        if himc.is_null() {
+            return None;
+        }
+
+        // the length is returned in bytes, the cursor position in UTF-16 code units
+        let len_bytes = get_composition_string(himc, GCS_COMPSTR, ptr::null_mut(), 0).max(0) as usize;
+        let mut text = vec![0_u16; len_bytes / 2];
+        if !text.is_empty() {
+            get_composition_string(himc, GCS_COMPSTR, text.as_mut_ptr() as *mut _, len_bytes as u32);
+        }
+        let cursor = get_composition_string(himc, GCS_CURSORPOS, ptr::null_mut(), 0).max(0) as usize;
+
+        release_context(hwnd, himc);
+
+        if text.is_empty() {
+            return None;
+        }
+
+        // the composition is passed around as characters, not UTF-16 code units
+        let cursor = core::char::decode_utf16(text.iter().take(cursor).copied()).count();
+        let text = String::from_utf16_lossy(&text);
+
+        Some(ImeComposition {
+            text: text.into(),
+            cursor_start: cursor,
+            cursor_end: cursor,
+        })
+    }
+}
+
+impl Drop for ImmFunctions {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        unsafe { FreeLibrary(self._imm32_dll_handle); }
+    }
+}
+
 // OpenGL functions from wglGetProcAddress OR loaded from opengl32.dll
 struct GlFunctions {
     _opengl32_dll_handle: Option<HINSTANCE>,
@@ -1852,6 +1964,8 @@ impl Window {
 
         unsafe { ReleaseDC(hwnd, hdc); }
 
+        warn_shortcut_conflicts(&internal);
+
         // Since the menu bar affects the window size, set it first,
         // before querying the window size again
         let mut menu_bar = None;
@@ -2449,6 +2563,7 @@ unsafe extern "system" fn WindowProc(
         WM_QUIT, WM_HSCROLL, WM_VSCROLL,
         WM_KEYUP, WM_KEYDOWN, WM_SYSKEYUP, WM_SYSKEYDOWN,
         WM_CHAR, WM_SYSCHAR, WHEEL_DELTA,
+        WM_IME_SETCONTEXT, WM_IME_COMPOSITION, WM_IME_ENDCOMPOSITION,
 
         VK_F4,
         CREATESTRUCTW, GWLP_USERDATA,
@@ -2499,9 +2614,6 @@ unsafe extern "system" fn WindowProc(
                     let internal = &mut current_window.internal;
                     let gl_context = &current_window.gl_context_ptr;
 
-                    // unset the focus
-                    internal.current_window_state.focused_node = None;
-
                     let mut resource_updates = Vec::new();
                     fc_cache.apply_closure(|fc_cache| {
                         internal.regenerate_styled_dom(
@@ -2525,6 +2637,8 @@ unsafe extern "system" fn WindowProc(
                         );
                     });
 
+                    warn_shortcut_conflicts(&current_window.internal);
+
                     current_window.context_menu = None;
                     Window::set_menu_bar(
                         hwnd,
@@ -2564,6 +2678,7 @@ unsafe extern "system" fn WindowProc(
                         current_window.internal.current_window_state.focused_node,
                         current_window.internal.current_window_state.mouse_state.mouse_down()
                     );
+                    nodes_to_check.current_window_state_is_dragging = current_window.internal.current_window_state.is_dragging();
 
                     let mut style_layout_changes = StyleAndLayoutChanges::new(
                         &nodes_to_check,
@@ -2596,6 +2711,7 @@ unsafe extern "system" fn WindowProc(
                 let image_cache = &mut ab.image_cache;
                 let config = &ab.config;
                 let hinstance = ab.hinstance;
+                let event_recorder = &mut ab.event_recorder;
 
                 let mut new_windows = Vec::new();
                 let mut destroyed_windows = Vec::new();
@@ -2625,6 +2741,10 @@ unsafe extern "system" fn WindowProc(
                             gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
                         }
 
+                        if let Some(recorder) = event_recorder.as_mut() {
+                            let _ = recorder.record(cur_hwnd as usize, &current_window.internal.current_window_state);
+                        }
+
                         ret = process_event(
                             hinstance,
                             current_window,
@@ -2886,6 +3006,39 @@ unsafe extern "system" fn WindowProc(
                 mem::drop(app_borrow);
                 return DefWindowProcW(hwnd, msg, wparam, lparam);
             },
+            WM_IME_SETCONTEXT => {
+                // the composition is rendered by the focused text input,
+                // so the IME must not draw its own composition window
+                mem::drop(app_borrow);
+                let lparam = ((lparam as u32) & !ISC_SHOWUICOMPOSITIONWINDOW) as LPARAM;
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            },
+            WM_IME_COMPOSITION => {
+                // the committed text (GCS_RESULTSTR) is sent as WM_CHAR
+                // messages by DefWindowProcW, only the pre-edit text is handled here
+                if (lparam as u32) & GCS_COMPSTR != 0 {
+                    let ab = &mut *app_borrow;
+                    if let Some(current_window) = ab.windows.get_mut(&hwnd_key) {
+                        let composition = ab.imm.as_ref().and_then(|imm| imm.get_composition(hwnd));
+                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                        current_window.internal.current_window_state.keyboard_state.ime_composition = composition.into();
+                        PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                }
+                mem::drop(app_borrow);
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            },
+            WM_IME_ENDCOMPOSITION => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    if current_window.internal.current_window_state.keyboard_state.ime_composition.is_some() {
+                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                        current_window.internal.current_window_state.keyboard_state.ime_composition = None.into();
+                        PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                }
+                mem::drop(app_borrow);
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            },
             WM_KEYUP | WM_SYSKEYUP => {
                 use self::event::process_key_params;
                 if let Some((scancode, vk)) = process_key_params(wparam, lparam) {
@@ -3289,8 +3442,10 @@ unsafe extern "system" fn WindowProc(
 
                 match wparam {
                     AZ_TICK_REGENERATE_DOM => {
-                        // re-load the layout() callback
-                        PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        // re-load the layout() callback if the XML / CSS files changed
+                        if crate::app::extra::hot_reload_should_regenerate() {
+                            PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
                         mem::drop(app_borrow);
                         return DefWindowProcW(hwnd, msg, wparam, lparam);
                     },
@@ -3640,11 +3795,15 @@ fn process_event(
     };
     use azul_core::window::FullWindowState;
     use azul_core::callbacks::Update;
+    use azul_core::dom::WindowEventFilter;
 
     // TODO:
     // window.internal.current_window_state.monitor =
     // win32_translate_monitor(MonitorFromWindow(window.hwnd, MONITOR_DEFAULTTONEAREST));
 
+    // Advance the drag-and-drop state (emits DragStart / Drop / DragEnd)
+    window.internal.current_window_state.update_drag_state(&window.internal.previous_window_state);
+
     // Get events
     let events = Events::new(
         &window.internal.current_window_state,
@@ -3657,6 +3816,38 @@ fn process_event(
         &events
     );
 
+    // keyboard shortcuts take precedence over the VirtualKeyDown callbacks
+    if events.window_events.contains(&WindowEventFilter::VirtualKeyDown) {
+
+        use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        let shortcut_result = fc_cache.apply_closure(|fc_cache| {
+            window.internal.invoke_shortcut(
+                &window_handle,
+                &window.gl_context_ptr,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+            )
+        });
+
+        if let Some(shortcut_result) = shortcut_result {
+            return process_callback_results(
+                shortcut_result,
+                window,
+                &nodes_to_check,
+                image_cache,
+                new_windows,
+                destroyed_windows
+            );
+        }
+    }
+
     // Invoke callbacks on nodes
     let callback_result = fc_cache.apply_closure(|fc_cache| {
 
@@ -3802,6 +3993,12 @@ fn process_callback_results(
 
     use azul_core::callbacks::Update;
     use azul_core::window_state::{StyleAndLayoutChanges, NodesToCheck};
+    use azul_core::window::DragState;
+
+    // a DragStart callback accepted the drag
+    if let Some(drag_data) = callback_results.drag_started.take() {
+        window.internal.current_window_state.drag_state = Some(DragState::Dragging(drag_data));
+    }
 
     if callback_results.images_changed.is_some() ||
        callback_results.image_masks_changed.is_some() {
@@ -3845,6 +4042,7 @@ fn process_callback_results(
             modified,
             window.internal.current_window_state.dropped_file.clone(),
             window.internal.current_window_state.hovered_file.clone(),
+            window.internal.current_window_state.drag_state.clone(),
             window.internal.current_window_state.focused_node.clone(),
             window.internal.current_window_state.last_hit_test.clone(),
         );
@@ -3909,10 +4107,14 @@ fn process_callback_results(
         window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
     }
 
+    let drag_ghost_changed = window.internal.current_window_state.drag_ghost_changed(
+        &window.internal.previous_window_state
+    );
+
     if style_layout_changes.did_resize_nodes() {
         // at least update the hit-tester
         ProcessEventResult::UpdateHitTesterAndProcessAgain
-    } else if style_layout_changes.need_regenerate_display_list() {
+    } else if style_layout_changes.need_regenerate_display_list() || drag_ghost_changed {
         ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
     } else if need_scroll_render || style_layout_changes.need_redraw() {
         ProcessEventResult::ShouldReRenderCurrentWindow
@@ -66,6 +66,8 @@ use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 use winapi::shared::windef::HMENU;
 #[cfg(target_os = "windows")]
 use std::collections::BTreeMap;
+#[cfg(all(target_os = "linux", feature = "atspi"))]
+use crate::accessibility::{AccessibilityTree, atspi::AtSpiBridge};
 
 // TODO: Right now it's not very ergonomic to cache shaders between
 // renderers - notify webrender about this.
@@ -304,6 +306,9 @@ pub struct Window {
     /// Optional context popup menu that is currently open
     #[cfg(target_os = "windows")]
     pub(crate) context_menu: Option<WindowsContextMenu>,
+    /// Connection to the AT-SPI2 accessibility bus, `None` if no bus is running
+    #[cfg(all(target_os = "linux", feature = "atspi"))]
+    pub(crate) atspi: Option<AtSpiBridge>,
 }
 
 impl Window {
@@ -564,8 +569,15 @@ impl Window {
             /// Optional context popup menu that is currently open
             #[cfg(target_os = "windows")]
             context_menu: None,
+            #[cfg(all(target_os = "linux", feature = "atspi"))]
+            atspi: None,
         };
 
+        #[cfg(all(target_os = "linux", feature = "atspi"))] {
+            // screen readers are optional, failing to connect is not an error
+            window.atspi = AtSpiBridge::new(window.internal.current_window_state.title.as_str()).ok();
+        }
+
         let mut txn = WrTransaction::new();
         window.rebuild_display_list(&mut txn, image_cache, initial_resource_updates);
         window.render_async(txn, true);
@@ -692,6 +704,12 @@ impl Window {
             );
         });
 
+        #[cfg(feature = "logging")] {
+            for conflict in self.internal.get_shortcut_conflicts() {
+                warn!("{}", conflict);
+            }
+        }
+
         // self.set_menu_bar();
     }
 
@@ -749,6 +767,15 @@ impl Window {
             (wr_translate_pipeline_id(root_pipeline_id), display_list),
             true,
         );
+
+        #[cfg(all(target_os = "linux", feature = "atspi"))] {
+            if let Some(atspi) = self.atspi.as_ref() {
+                atspi.update(AccessibilityTree::new(
+                    &self.internal.layout_results,
+                    self.internal.current_window_state.focused_node,
+                ));
+            }
+        }
     }
 
     /// Synchronize the `self.internal.previous_window_state` with the `self.internal.current_window_state`
@@ -983,11 +1010,25 @@ impl Window {
         external_callbacks: &ExternalSystemCallbacks
     ) -> CallCallbacksResult {
         use azul_core::window_state::CallbacksOfHitTest;
+        use azul_core::dom::WindowEventFilter;
+
+        // keyboard shortcuts take precedence over the VirtualKeyDown callbacks
+        if events.window_events.contains(&WindowEventFilter::VirtualKeyDown) {
+            if let Some(shortcut_result) = self.internal.invoke_shortcut(
+                &self.window_handle,
+                &self.gl_context_ptr,
+                image_cache,
+                system_fonts,
+                external_callbacks,
+            ) {
+                return shortcut_result;
+            }
+        }
 
         let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &self.internal.layout_results);
         let current_scroll_states = self.internal.get_current_scroll_states();
 
-        callbacks.call(
+        let mut callback_result = callbacks.call(
             &self.internal.previous_window_state,
             &self.internal.current_window_state,
             &self.window_handle,
@@ -998,7 +1039,14 @@ impl Window {
             image_cache,
             system_fonts,
             external_callbacks,
-        )
+        );
+
+        // a DragStart callback accepted the drag
+        if let Some(drag_data) = callback_result.drag_started.take() {
+            self.internal.current_window_state.drag_state = Some(azul_core::window::DragState::Dragging(drag_data));
+        }
+
+        callback_result
     }
 
     /// Returns what monitor the window is currently residing on (to query monitor size, etc.).
@@ -895,7 +895,7 @@ pub(crate) fn rebuild_display_list(
     internal: &mut WindowInternal,
     render_api: &mut WrRenderApi,
     image_cache: &ImageCache,
-    resources: Vec<ResourceUpdate>,
+    mut resources: Vec<ResourceUpdate>,
 ) {
     use crate::wr_translate::{
         wr_translate_display_list, wr_translate_document_id, wr_translate_epoch,
@@ -904,9 +904,25 @@ pub(crate) fn rebuild_display_list(
     use azul_core::callbacks::PipelineId;
     use azul_core::styled_dom::DomId;
     use azul_core::ui_solver::LayoutResult;
+    use azul_core::app_resources::{build_add_image_resource_updates, add_resources};
 
     let mut txn = WrTransaction::new();
 
+    // the drag ghost image is not part of the DOM, register it separately
+    if let Some(ghost) = internal.current_window_state.get_drag_ghost() {
+        let mut ghost_images = azul_core::FastBTreeSet::new();
+        ghost_images.insert(ghost.clone());
+        let add_image_resources = build_add_image_resource_updates(
+            &internal.renderer_resources,
+            internal.id_namespace,
+            internal.epoch,
+            &internal.document_id,
+            &ghost_images,
+            crate::app::CALLBACKS.insert_into_active_gl_textures_fn,
+        );
+        add_resources(&mut internal.renderer_resources, &mut resources, Vec::new(), add_image_resources);
+    }
+
     // NOTE: Display list has to be rebuilt every frame, otherwise, the epochs get out of sync
     let root_id = DomId { inner: 0 };
     let cached_display_list = LayoutResult::get_cached_display_list(
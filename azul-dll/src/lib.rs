@@ -280,6 +280,12 @@ pub use AzWindowPositionTT as AzWindowPosition;
 pub type AzImePositionTT = azul_core::window::ImePosition;
 pub use AzImePositionTT as AzImePosition;
 
+/// Pre-edit text of an input method, the IME shows its candidate window at the `WindowState.ime_position`
+pub type AzImeCompositionTT = azul_core::window::ImeComposition;
+pub use AzImeCompositionTT as AzImeComposition;
+/// Destructor: Takes ownership of the `ImeComposition` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzImeComposition_delete(object: &mut AzImeComposition) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Current state of touch devices / touch inputs
 pub type AzTouchStateTT = azul_core::window::TouchState;
 pub use AzTouchStateTT as AzTouchState;
@@ -336,6 +342,8 @@ pub type AzCallbackInfoTT = azul_impl::callbacks::CallbackInfo;
 pub use AzCallbackInfoTT as AzCallbackInfo;
 /// Returns the `DomNodeId` of the element that the callback was attached to.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getHitNode(callbackinfo: &AzCallbackInfo) -> AzDomNodeId { callbackinfo.get_hit_node() }
+/// Returns the `DomNodeId` of the node that currently has the keyboard focus, if any.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getFocusedNode(callbackinfo: &AzCallbackInfo) -> AzOptionDomNodeId { callbackinfo.get_focused_node().into() }
 /// Returns the function pointer necessary to query the current time.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getSystemTimeFn(callbackinfo: &AzCallbackInfo) -> AzGetSystemTimeFn { callbackinfo.get_system_time_fn() }
 /// Returns the `LayoutPoint` of the cursor in the viewport (relative to the origin of the `Dom`). Set to `None` if the cursor is not in the current window.
@@ -428,6 +436,10 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_sendThreadMsg(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId, msg: AzThreadSendMsg) -> bool { callbackinfo.send_thread_msg(thread_id, msg) }
 /// Stops a thread at the nearest possible opportunity. Sends a `ThreadSendMsg::TerminateThread` message to the thread and joins the thread.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_stopThread(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { callbackinfo.stop_thread(thread_id) }
+/// Accepts a pending drag operation on the hit node, only has an effect when called from a `DragStart` callback. The `payload` is handed to the `Drop` callback of the target node, the `ghost` image (if any) follows the cursor while dragging.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_startDrag(callbackinfo: &mut AzCallbackInfo, payload: AzRefAny, ghost: AzOptionImageRef) -> bool { callbackinfo.start_drag(payload, ghost) }
+/// Returns the data of the drag operation that is currently in progress (or that was just dropped)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getDragData(callbackinfo: &AzCallbackInfo) -> AzOptionDragData { callbackinfo.get_drag_data() }
 /// Destructor: Takes ownership of the `CallbackInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_delete(object: &mut AzCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -509,6 +521,12 @@ pub use AzFocusTargetTT as AzFocusTarget;
 /// Destructor: Takes ownership of the `FocusTarget` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzFocusTarget_delete(object: &mut AzFocusTarget) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Data of an in-app drag operation, created by `CallbackInfo::start_drag`
+pub type AzDragDataTT = azul_impl::window::DragData;
+pub use AzDragDataTT as AzDragData;
+/// Destructor: Takes ownership of the `DragData` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDragData_delete(object: &mut AzDragData) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// CSS path to set the keyboard input focus
 pub type AzFocusTargetPathTT = azul_impl::callbacks::FocusTargetPath;
 pub use AzFocusTargetPathTT as AzFocusTargetPath;
@@ -740,6 +758,18 @@ pub use AzDomTT as AzDom;
 #[no_mangle] pub extern "C" fn AzDom_setTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) { dom.root.set_tab_index(tab_index) }
 /// Same as set_tab_index, but as a builder method
 #[no_mangle] pub extern "C" fn AzDom_withTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_tab_index(tab_index); dom }
+/// Sets a key that identifies the DOM root node among its siblings, so that it keeps its focus and scroll position when the DOM is re-generated
+#[no_mangle] pub extern "C" fn AzDom_setKey(dom: &mut AzDom, key: u64) { dom.root.set_key(key) }
+/// Same as set_key, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withKey(dom: &mut AzDom, key: u64) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_key(key); dom }
+/// Makes the DOM root node a focus trap or a roving focus group
+#[no_mangle] pub extern "C" fn AzDom_setFocusScope(dom: &mut AzDom, focus_scope: AzFocusScope) { dom.root.set_focus_scope(focus_scope) }
+/// Same as set_focus_scope, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withFocusScope(dom: &mut AzDom, focus_scope: AzFocusScope) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_focus_scope(focus_scope); dom }
+/// Adds a keyboard shortcut to the DOM root node that is active while the focus is inside of the node. See `NodeData::add_shortcut` for more information.
+#[no_mangle] pub extern "C" fn AzDom_addShortcut(dom: &mut AzDom, combo: AzVirtualKeyCodeCombo, data: AzRefAny, callback: AzCallbackType) { dom.root.add_shortcut(combo, data, callback) }
+/// Same as add_shortcut, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withShortcut(dom: &mut AzDom, combo: AzVirtualKeyCodeCombo, data: AzRefAny, callback: AzCallbackType) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_shortcut(combo, data, callback); dom }
 /// Sets accessibility attributes for the DOM root node.
 #[no_mangle] pub extern "C" fn AzDom_setAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) { dom.root.set_accessibility_info(accessibility_info) }
 /// Same as set_accessibility_info, but as a builder method
@@ -828,6 +858,12 @@ pub use AzNodeDataTT as AzNodeData;
 #[no_mangle] pub extern "C" fn AzNodeData_setClipMask(nodedata: &mut AzNodeData, image_mask: AzImageMask) { nodedata.set_clip_mask(image_mask) }
 /// Sets the tab index for this node
 #[no_mangle] pub extern "C" fn AzNodeData_setTabIndex(nodedata: &mut AzNodeData, tab_index: AzTabIndex) { nodedata.set_tab_index(tab_index) }
+/// Sets a key that identifies this node among its siblings, so that it keeps its focus and scroll position when the DOM is re-generated
+#[no_mangle] pub extern "C" fn AzNodeData_setKey(nodedata: &mut AzNodeData, key: u64) { nodedata.set_key(key) }
+/// Makes this node a focus trap or a roving focus group
+#[no_mangle] pub extern "C" fn AzNodeData_setFocusScope(nodedata: &mut AzNodeData, focus_scope: AzFocusScope) { nodedata.set_focus_scope(focus_scope) }
+/// Adds a keyboard shortcut that invokes the callback if the key combination is pressed while the focus is on this node or one of its children. Shortcuts of nodes closer to the focused node take precedence over the menu bar accelerators.
+#[no_mangle] pub extern "C" fn AzNodeData_addShortcut(nodedata: &mut AzNodeData, combo: AzVirtualKeyCodeCombo, data: AzRefAny, callback: AzCallbackType) { nodedata.add_shortcut(combo, data, callback) }
 /// Sets accessibility attributes for this node
 #[no_mangle] pub extern "C" fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { nodedata.set_accessibility_info(accessibility_info) }
 /// Adds a (native) menu bar: If this node is the root node the menu bar will be added to the window, else it will be displayed using the width and position of the bounding rectangle
@@ -897,6 +933,18 @@ pub use AzAccessibilityStateTT as AzAccessibilityState;
 pub type AzTabIndexTT = azul_impl::dom::TabIndex;
 pub use AzTabIndexTT as AzTabIndex;
 
+/// Restricts the keyboard focus navigation to the subtree of a node
+pub type AzFocusScopeTT = azul_impl::dom::FocusScope;
+pub use AzFocusScopeTT as AzFocusScope;
+
+/// Configures the arrow key navigation of a `FocusScope::Roving`
+pub type AzRovingFocusTT = azul_impl::dom::RovingFocus;
+pub use AzRovingFocusTT as AzRovingFocus;
+
+/// Re-export of rust-allocated (stack based) `RovingFocusOrientation` struct
+pub type AzRovingFocusOrientationTT = azul_impl::dom::RovingFocusOrientation;
+pub use AzRovingFocusOrientationTT as AzRovingFocusOrientation;
+
 /// Re-export of rust-allocated (stack based) `IdOrClass` struct
 pub type AzIdOrClassTT = azul_impl::dom::IdOrClass;
 pub use AzIdOrClassTT as AzIdOrClass;
@@ -1812,6 +1860,73 @@ pub type AzCheckBoxOnToggleCallbackType = extern "C" fn(&mut AzRefAny, &AzCheckB
 pub type AzCheckBoxStateTT = crate::widgets::check_box::CheckBoxState;
 pub use AzCheckBoxStateTT as AzCheckBoxState;
 
+/// Re-export of rust-allocated (stack based) `DropDown` struct
+pub type AzDropDownTT = crate::widgets::drop_down::DropDown;
+pub use AzDropDownTT as AzDropDown;
+/// Creates a new `DropDown` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `DropDown::new()` constructor.
+#[no_mangle] pub extern "C" fn AzDropDown_new(choices: AzDropDownChoiceVec) -> AzDropDown { AzDropDown::new(choices) }
+/// Parses a list of choices in the form `"Apple, Fruits/Banana, !Cherry"`: `Group/Label` puts the choice into a group, a leading `!` disables the choice
+#[no_mangle] pub extern "C" fn AzDropDown_fromChoicesStr(choices: AzString) -> AzDropDown { AzDropDown::from_choices_str(choices.as_str()) }
+/// Equivalent to the Rust `DropDown::set_selected()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setSelected(dropdown: &mut AzDropDown, selected: AzOptionUsize) { dropdown.set_selected(selected); }
+/// Equivalent to the Rust `DropDown::with_selected()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withSelected(dropdown: &mut AzDropDown, selected: usize) -> AzDropDown { dropdown.swap_with_default().with_selected(selected) }
+/// Selects the first choice with the given label, does nothing if no choice matches
+#[no_mangle] pub extern "C" fn AzDropDown_withSelectedLabel(dropdown: &mut AzDropDown, label: AzString) -> AzDropDown { dropdown.swap_with_default().with_selected_label(label.as_str()) }
+/// Equivalent to the Rust `DropDown::set_on_choice_change()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setOnChoiceChange(dropdown: &mut AzDropDown, data: AzRefAny, callback: AzDropDownOnChoiceChangeCallbackType) { dropdown.set_on_choice_change(data, callback); }
+/// Equivalent to the Rust `DropDown::with_on_choice_change()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withOnChoiceChange(dropdown: &mut AzDropDown, data: AzRefAny, callback: AzDropDownOnChoiceChangeCallbackType) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_on_choice_change(data, callback); dropdown }
+/// Equivalent to the Rust `DropDown::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setContainerStyle(dropdown: &mut AzDropDown, style: AzNodeDataInlineCssPropertyVec) { dropdown.set_container_style(style); }
+/// Equivalent to the Rust `DropDown::set_header_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setHeaderStyle(dropdown: &mut AzDropDown, style: AzNodeDataInlineCssPropertyVec) { dropdown.set_header_style(style); }
+/// Equivalent to the Rust `DropDown::set_popup_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setPopupStyle(dropdown: &mut AzDropDown, style: AzNodeDataInlineCssPropertyVec) { dropdown.set_popup_style(style); }
+/// Equivalent to the Rust `DropDown::set_choice_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setChoiceStyle(dropdown: &mut AzDropDown, style: AzNodeDataInlineCssPropertyVec) { dropdown.set_choice_style(style); }
+/// Equivalent to the Rust `DropDown::set_group_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setGroupStyle(dropdown: &mut AzDropDown, style: AzNodeDataInlineCssPropertyVec) { dropdown.set_group_style(style); }
+/// Equivalent to the Rust `DropDown::dom()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_dom(dropdown: &mut AzDropDown) -> AzDom { dropdown.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `DropDown` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDropDown_delete(object: &mut AzDropDown) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+pub type AzDropDownStateWrapperTT = crate::widgets::drop_down::DropDownStateWrapper;
+pub use AzDropDownStateWrapperTT as AzDropDownStateWrapper;
+/// Destructor: Takes ownership of the `DropDownStateWrapper` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDropDownStateWrapper_delete(object: &mut AzDropDownStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `DropDownState` struct
+pub type AzDropDownStateTT = crate::widgets::drop_down::DropDownState;
+pub use AzDropDownStateTT as AzDropDownState;
+/// Equivalent to the Rust `DropDownState::is_selectable()` function.
+#[no_mangle] pub extern "C" fn AzDropDownState_isSelectable(dropdownstate: &AzDropDownState, index: usize) -> bool { dropdownstate.is_selectable(index) }
+/// Destructor: Takes ownership of the `DropDownState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDropDownState_delete(object: &mut AzDropDownState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Single selectable entry of a `DropDown`
+pub type AzDropDownChoiceTT = crate::widgets::drop_down::DropDownChoice;
+pub use AzDropDownChoiceTT as AzDropDownChoice;
+/// Creates a new `DropDownChoice` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `DropDownChoice::new()` constructor.
+#[no_mangle] pub extern "C" fn AzDropDownChoice_new(label: AzString) -> AzDropDownChoice { AzDropDownChoice::new(label) }
+/// Destructor: Takes ownership of the `DropDownChoice` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDropDownChoice_delete(object: &mut AzDropDownChoice) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+pub type AzDropDownOnChoiceChangeTT = crate::widgets::drop_down::DropDownOnChoiceChange;
+pub use AzDropDownOnChoiceChangeTT as AzDropDownOnChoiceChange;
+/// Destructor: Takes ownership of the `DropDownOnChoiceChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDropDownOnChoiceChange_delete(object: &mut AzDropDownOnChoiceChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+pub type AzDropDownOnChoiceChangeCallbackTT = crate::widgets::drop_down::DropDownOnChoiceChangeCallback;
+pub use AzDropDownOnChoiceChangeCallbackTT as AzDropDownOnChoiceChangeCallback;
+
+pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `Label` struct
 pub type AzLabelTT = crate::widgets::label::Label;
 pub use AzLabelTT as AzLabel;
@@ -1889,6 +2004,24 @@ pub use AzTextInputTT as AzTextInput;
 #[no_mangle] pub extern "C" fn AzTextInput_setLabelStyle(textinput: &mut AzTextInput, label_style: AzNodeDataInlineCssPropertyVec) { textinput.set_container_style(label_style) }
 /// Equivalent to the Rust `TextInput::with_label_style()` function.
 #[no_mangle] pub extern "C" fn AzTextInput_withLabelStyle(textinput: &mut AzTextInput, label_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_label_style(label_style); textinput }
+/// Equivalent to the Rust `TextInput::set_on_validation_change()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_setOnValidationChange(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnValidationChangeCallbackType) { textinput.set_on_validation_change(data, callback) }
+/// Equivalent to the Rust `TextInput::add_validator()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_addValidator(textinput: &mut AzTextInput, validator: AzTextInputValidator) { textinput.add_validator(validator) }
+/// Equivalent to the Rust `TextInput::with_validator()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withValidator(textinput: &mut AzTextInput, validator: AzTextInputValidator) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.add_validator(validator); textinput }
+/// Equivalent to the Rust `TextInput::set_mask()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_setMask(textinput: &mut AzTextInput, mask: AzTextInputMask) { textinput.set_mask(mask) }
+/// Equivalent to the Rust `TextInput::with_mask()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withMask(textinput: &mut AzTextInput, mask: AzTextInputMask) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_mask(mask); textinput }
+/// Password mode: displays every character as `obscure_char` (`None` = display the text)
+#[no_mangle] pub extern "C" fn AzTextInput_setObscureChar(textinput: &mut AzTextInput, obscure_char: AzOptionChar) { textinput.set_obscure_char(obscure_char) }
+/// Equivalent to the Rust `TextInput::with_password_mode()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withPasswordMode(textinput: &mut AzTextInput) -> AzTextInput { textinput.swap_with_default().with_password_mode() }
+/// Sets the properties that are applied to the container while the text is invalid
+#[no_mangle] pub extern "C" fn AzTextInput_setErrorStyle(textinput: &mut AzTextInput, error_style: AzCssPropertyVec) { textinput.set_error_style(error_style) }
+/// Equivalent to the Rust `TextInput::set_preedit_style()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_setPreeditStyle(textinput: &mut AzTextInput, preedit_style: AzNodeDataInlineCssPropertyVec) { textinput.set_preedit_style(preedit_style) }
 /// Equivalent to the Rust `TextInput::dom()` function.
 #[no_mangle] pub extern "C" fn AzTextInput_dom(textinput: &mut AzTextInput) -> AzDom { textinput.swap_with_default().dom() }
 /// Destructor: Takes ownership of the `TextInput` pointer and deletes it.
@@ -1905,6 +2038,10 @@ pub type AzTextInputStateTT = crate::widgets::text_input::TextInputState;
 pub use AzTextInputStateTT as AzTextInputState;
 /// Equivalent to the Rust `TextInputState::get_text()` function.
 #[no_mangle] pub extern "C" fn AzTextInputState_getText(textinputstate: &AzTextInputState) -> AzString { textinputstate.get_text().into() }
+/// Equivalent to the Rust `TextInputState::get_display_text()` function.
+#[no_mangle] pub extern "C" fn AzTextInputState_getDisplayText(textinputstate: &AzTextInputState) -> AzString { textinputstate.get_display_text().into() }
+/// Equivalent to the Rust `TextInputState::validate()` function.
+#[no_mangle] pub extern "C" fn AzTextInputState_validate(textinputstate: &AzTextInputState) -> AzTextInputValidationResult { textinputstate.validate() }
 /// Destructor: Takes ownership of the `TextInputState` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzTextInputState_delete(object: &mut AzTextInputState) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -1957,6 +2094,61 @@ pub use AzOnTextInputReturnTT as AzOnTextInputReturn;
 pub type AzTextInputValidTT = crate::widgets::text_input::TextInputValid;
 pub use AzTextInputValidTT as AzTextInputValid;
 
+/// Re-export of rust-allocated (stack based) `TextInputValidationResult` struct
+pub type AzTextInputValidationResultTT = crate::widgets::text_input::TextInputValidationResult;
+pub use AzTextInputValidationResultTT as AzTextInputValidationResult;
+/// Destructor: Takes ownership of the `TextInputValidationResult` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputValidationResult_delete(object: &mut AzTextInputValidationResult) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Validator that is run on every change of the text, an empty text is only rejected by `NotEmpty`
+pub type AzTextInputValidatorTT = crate::widgets::text_input::TextInputValidator;
+pub use AzTextInputValidatorTT as AzTextInputValidator;
+/// Destructor: Takes ownership of the `TextInputValidator` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputValidator_delete(object: &mut AzTextInputValidator) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextInputCustomValidator` struct
+pub type AzTextInputCustomValidatorTT = crate::widgets::text_input::TextInputCustomValidator;
+pub use AzTextInputCustomValidatorTT as AzTextInputCustomValidator;
+/// Destructor: Takes ownership of the `TextInputCustomValidator` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputCustomValidator_delete(object: &mut AzTextInputCustomValidator) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextInputValidatorCallback` struct
+pub type AzTextInputValidatorCallbackTT = crate::widgets::text_input::TextInputValidatorCallback;
+pub use AzTextInputValidatorCallbackTT as AzTextInputValidatorCallback;
+
+pub type AzTextInputValidatorCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState) -> AzTextInputValidationResult;
+/// Input mask: `9` = digit, `#` = optional digit, `a` = letter, `*` = letter or digit, other characters are literals that are inserted automatically (`99/99/9999`)
+pub type AzTextInputMaskTT = crate::widgets::text_input::TextInputMask;
+pub use AzTextInputMaskTT as AzTextInputMask;
+/// Creates a new `TextInputMask` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextInputMask::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTextInputMask_new(pattern: AzString) -> AzTextInputMask { AzTextInputMask::new(pattern) }
+/// Creates a new `TextInputMask` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextInputMask::date()` constructor.
+#[no_mangle] pub extern "C" fn AzTextInputMask_date() -> AzTextInputMask { AzTextInputMask::date() }
+/// Creates a new `TextInputMask` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextInputMask::time()` constructor.
+#[no_mangle] pub extern "C" fn AzTextInputMask_time() -> AzTextInputMask { AzTextInputMask::time() }
+/// Creates a new `TextInputMask` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextInputMask::phone()` constructor.
+#[no_mangle] pub extern "C" fn AzTextInputMask_phone() -> AzTextInputMask { AzTextInputMask::phone() }
+/// Creates a new `TextInputMask` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextInputMask::ipv4()` constructor.
+#[no_mangle] pub extern "C" fn AzTextInputMask_ipv4() -> AzTextInputMask { AzTextInputMask::ipv4() }
+/// Destructor: Takes ownership of the `TextInputMask` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputMask_delete(object: &mut AzTextInputMask) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextInputOnValidationChange` struct
+pub type AzTextInputOnValidationChangeTT = crate::widgets::text_input::TextInputOnValidationChange;
+pub use AzTextInputOnValidationChangeTT as AzTextInputOnValidationChange;
+/// Destructor: Takes ownership of the `TextInputOnValidationChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputOnValidationChange_delete(object: &mut AzTextInputOnValidationChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextInputOnValidationChangeCallback` struct
+pub type AzTextInputOnValidationChangeCallbackTT = crate::widgets::text_input::TextInputOnValidationChangeCallback;
+pub use AzTextInputOnValidationChangeCallbackTT as AzTextInputOnValidationChangeCallback;
+
+pub type AzTextInputOnValidationChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState, &mut AzCallbackInfo) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NumberInput` struct
 pub type AzNumberInputTT = crate::widgets::number_input::NumberInput;
 pub use AzNumberInputTT as AzNumberInput;
@@ -2044,6 +2236,622 @@ pub use AzProgressBarTT as AzProgressBar;
 pub type AzProgressBarStateTT = crate::widgets::progressbar::ProgressBarState;
 pub use AzProgressBarStateTT as AzProgressBarState;
 
+/// Re-export of rust-allocated (stack based) `SplitPane` struct
+pub type AzSplitPaneTT = crate::widgets::split_pane::SplitPane;
+pub use AzSplitPaneTT as AzSplitPane;
+/// Creates a new `SplitPane` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `SplitPane::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSplitPane_new(orientation: AzSplitPaneOrientation, first_pane: AzDom, second_pane: AzDom) -> AzSplitPane { AzSplitPane::new(orientation, first_pane, second_pane) }
+/// Sets the size of the first pane relative to the container (0.0 - 1.0)
+#[no_mangle] pub extern "C" fn AzSplitPane_setRatio(splitpane: &mut AzSplitPane, ratio: f32) { splitpane.set_ratio(ratio); }
+/// Equivalent to the Rust `SplitPane::with_ratio()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_withRatio(splitpane: &mut AzSplitPane, ratio: f32) -> AzSplitPane { splitpane.swap_with_default().with_ratio(ratio) }
+/// Sets the minimum sizes (in pixels) of the first and second pane
+#[no_mangle] pub extern "C" fn AzSplitPane_setMinSizes(splitpane: &mut AzSplitPane, min_first_size: f32, min_second_size: f32) { splitpane.set_min_sizes(min_first_size, min_second_size); }
+/// Equivalent to the Rust `SplitPane::with_min_sizes()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_withMinSizes(splitpane: &mut AzSplitPane, min_first_size: f32, min_second_size: f32) -> AzSplitPane { splitpane.swap_with_default().with_min_sizes(min_first_size, min_second_size) }
+/// Equivalent to the Rust `SplitPane::set_collapsible()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_setCollapsible(splitpane: &mut AzSplitPane, collapsible: bool) { splitpane.set_collapsible(collapsible); }
+/// Equivalent to the Rust `SplitPane::with_collapsible()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_withCollapsible(splitpane: &mut AzSplitPane, collapsible: bool) -> AzSplitPane { splitpane.swap_with_default().with_collapsible(collapsible) }
+/// Equivalent to the Rust `SplitPane::set_collapsed()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_setCollapsed(splitpane: &mut AzSplitPane, collapsed: AzSplitPaneCollapsed) { splitpane.set_collapsed(collapsed); }
+/// Equivalent to the Rust `SplitPane::with_collapsed()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_withCollapsed(splitpane: &mut AzSplitPane, collapsed: AzSplitPaneCollapsed) -> AzSplitPane { splitpane.swap_with_default().with_collapsed(collapsed) }
+/// Equivalent to the Rust `SplitPane::set_on_resize()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_setOnResize(splitpane: &mut AzSplitPane, data: AzRefAny, callback: AzSplitPaneOnResizeCallbackType) { splitpane.set_on_resize(data, callback); }
+/// Equivalent to the Rust `SplitPane::with_on_resize()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_withOnResize(splitpane: &mut AzSplitPane, data: AzRefAny, callback: AzSplitPaneOnResizeCallbackType) -> AzSplitPane { let mut splitpane = splitpane.swap_with_default(); splitpane.set_on_resize(data, callback); splitpane }
+/// Equivalent to the Rust `SplitPane::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_setContainerStyle(splitpane: &mut AzSplitPane, style: AzNodeDataInlineCssPropertyVec) { splitpane.set_container_style(style); }
+/// Equivalent to the Rust `SplitPane::set_pane_style()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_setPaneStyle(splitpane: &mut AzSplitPane, style: AzNodeDataInlineCssPropertyVec) { splitpane.set_pane_style(style); }
+/// Equivalent to the Rust `SplitPane::set_divider_style()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_setDividerStyle(splitpane: &mut AzSplitPane, style: AzNodeDataInlineCssPropertyVec) { splitpane.set_divider_style(style); }
+/// Equivalent to the Rust `SplitPane::dom()` function.
+#[no_mangle] pub extern "C" fn AzSplitPane_dom(splitpane: &mut AzSplitPane) -> AzDom { splitpane.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `SplitPane` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitPane_delete(object: &mut AzSplitPane) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SplitPaneStateWrapper` struct
+pub type AzSplitPaneStateWrapperTT = crate::widgets::split_pane::SplitPaneStateWrapper;
+pub use AzSplitPaneStateWrapperTT as AzSplitPaneStateWrapper;
+/// Destructor: Takes ownership of the `SplitPaneStateWrapper` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitPaneStateWrapper_delete(object: &mut AzSplitPaneStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SplitPaneState` struct
+pub type AzSplitPaneStateTT = crate::widgets::split_pane::SplitPaneState;
+pub use AzSplitPaneStateTT as AzSplitPaneState;
+
+/// Re-export of rust-allocated (stack based) `SplitPaneOrientation` struct
+pub type AzSplitPaneOrientationTT = crate::widgets::split_pane::SplitPaneOrientation;
+pub use AzSplitPaneOrientationTT as AzSplitPaneOrientation;
+
+/// Re-export of rust-allocated (stack based) `SplitPaneCollapsed` struct
+pub type AzSplitPaneCollapsedTT = crate::widgets::split_pane::SplitPaneCollapsed;
+pub use AzSplitPaneCollapsedTT as AzSplitPaneCollapsed;
+
+/// Re-export of rust-allocated (stack based) `SplitPaneOnResize` struct
+pub type AzSplitPaneOnResizeTT = crate::widgets::split_pane::SplitPaneOnResize;
+pub use AzSplitPaneOnResizeTT as AzSplitPaneOnResize;
+/// Destructor: Takes ownership of the `SplitPaneOnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitPaneOnResize_delete(object: &mut AzSplitPaneOnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SplitPaneOnResizeCallback` struct
+pub type AzSplitPaneOnResizeCallbackTT = crate::widgets::split_pane::SplitPaneOnResizeCallback;
+pub use AzSplitPaneOnResizeCallbackTT as AzSplitPaneOnResizeCallback;
+
+pub type AzSplitPaneOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSplitPaneState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TabContainer` struct
+pub type AzTabContainerTT = crate::widgets::tab_container::TabContainer;
+pub use AzTabContainerTT as AzTabContainer;
+/// Creates a new `TabContainer` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TabContainer::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTabContainer_new() -> AzTabContainer { AzTabContainer::new() }
+/// Creates a tab container from a comma-separated list of tab titles (`"Editor, Console, Output"`) and the content of the tabs, in the same order
+#[no_mangle] pub extern "C" fn AzTabContainer_fromTitlesStr(titles: AzString, pages: AzDomVec) -> AzTabContainer { AzTabContainer::from_titles_str(titles.as_str(), pages) }
+/// Appends a tab, the first tab is activated automatically
+#[no_mangle] pub extern "C" fn AzTabContainer_addTab(tabcontainer: &mut AzTabContainer, title: AzString, content: AzDom) { tabcontainer.add_tab(title, content); }
+/// Equivalent to the Rust `TabContainer::with_tab()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withTab(tabcontainer: &mut AzTabContainer, title: AzString, content: AzDom) -> AzTabContainer { tabcontainer.swap_with_default().with_tab(title, content) }
+/// Equivalent to the Rust `TabContainer::set_active_tab()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setActiveTab(tabcontainer: &mut AzTabContainer, tab: usize) { tabcontainer.set_active_tab(tab); }
+/// Equivalent to the Rust `TabContainer::with_active_tab()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withActiveTab(tabcontainer: &mut AzTabContainer, tab: usize) -> AzTabContainer { tabcontainer.swap_with_default().with_active_tab(tab) }
+/// Adds / removes the close button of all tabs that were added so far
+#[no_mangle] pub extern "C" fn AzTabContainer_setClosable(tabcontainer: &mut AzTabContainer, closable: bool) { tabcontainer.set_closable(closable); }
+/// Equivalent to the Rust `TabContainer::with_closable()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withClosable(tabcontainer: &mut AzTabContainer, closable: bool) -> AzTabContainer { tabcontainer.swap_with_default().with_closable(closable) }
+/// Equivalent to the Rust `TabContainer::set_overflow()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setOverflow(tabcontainer: &mut AzTabContainer, overflow: AzTabContainerOverflow) { tabcontainer.set_overflow(overflow); }
+/// Equivalent to the Rust `TabContainer::with_overflow()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withOverflow(tabcontainer: &mut AzTabContainer, overflow: AzTabContainerOverflow) -> AzTabContainer { tabcontainer.swap_with_default().with_overflow(overflow) }
+/// Allows reordering the tabs by dragging them along the tab strip
+#[no_mangle] pub extern "C" fn AzTabContainer_setAllowReorder(tabcontainer: &mut AzTabContainer, allow_reorder: bool) { tabcontainer.set_allow_reorder(allow_reorder); }
+/// Equivalent to the Rust `TabContainer::with_allow_reorder()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withAllowReorder(tabcontainer: &mut AzTabContainer, allow_reorder: bool) -> AzTabContainer { tabcontainer.swap_with_default().with_allow_reorder(allow_reorder) }
+/// Equivalent to the Rust `TabContainer::set_on_tab_change()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setOnTabChange(tabcontainer: &mut AzTabContainer, data: AzRefAny, callback: AzTabContainerOnTabChangeCallbackType) { tabcontainer.set_on_tab_change(data, callback); }
+/// Equivalent to the Rust `TabContainer::with_on_tab_change()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withOnTabChange(tabcontainer: &mut AzTabContainer, data: AzRefAny, callback: AzTabContainerOnTabChangeCallbackType) -> AzTabContainer { let mut tabcontainer = tabcontainer.swap_with_default(); tabcontainer.set_on_tab_change(data, callback); tabcontainer }
+/// Equivalent to the Rust `TabContainer::set_on_tab_close()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setOnTabClose(tabcontainer: &mut AzTabContainer, data: AzRefAny, callback: AzTabContainerOnTabCloseCallbackType) { tabcontainer.set_on_tab_close(data, callback); }
+/// Equivalent to the Rust `TabContainer::with_on_tab_close()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withOnTabClose(tabcontainer: &mut AzTabContainer, data: AzRefAny, callback: AzTabContainerOnTabCloseCallbackType) -> AzTabContainer { let mut tabcontainer = tabcontainer.swap_with_default(); tabcontainer.set_on_tab_close(data, callback); tabcontainer }
+/// Equivalent to the Rust `TabContainer::set_on_tab_move()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setOnTabMove(tabcontainer: &mut AzTabContainer, data: AzRefAny, callback: AzTabContainerOnTabMoveCallbackType) { tabcontainer.set_on_tab_move(data, callback); }
+/// Equivalent to the Rust `TabContainer::with_on_tab_move()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_withOnTabMove(tabcontainer: &mut AzTabContainer, data: AzRefAny, callback: AzTabContainerOnTabMoveCallbackType) -> AzTabContainer { let mut tabcontainer = tabcontainer.swap_with_default(); tabcontainer.set_on_tab_move(data, callback); tabcontainer }
+/// Equivalent to the Rust `TabContainer::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setContainerStyle(tabcontainer: &mut AzTabContainer, style: AzNodeDataInlineCssPropertyVec) { tabcontainer.set_container_style(style); }
+/// Equivalent to the Rust `TabContainer::set_header_style()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setHeaderStyle(tabcontainer: &mut AzTabContainer, style: AzNodeDataInlineCssPropertyVec) { tabcontainer.set_header_style(style); }
+/// Equivalent to the Rust `TabContainer::set_tab_style()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setTabStyle(tabcontainer: &mut AzTabContainer, style: AzNodeDataInlineCssPropertyVec) { tabcontainer.set_tab_style(style); }
+/// Equivalent to the Rust `TabContainer::set_close_button_style()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setCloseButtonStyle(tabcontainer: &mut AzTabContainer, style: AzNodeDataInlineCssPropertyVec) { tabcontainer.set_close_button_style(style); }
+/// Equivalent to the Rust `TabContainer::set_button_style()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setButtonStyle(tabcontainer: &mut AzTabContainer, style: AzNodeDataInlineCssPropertyVec) { tabcontainer.set_button_style(style); }
+/// Equivalent to the Rust `TabContainer::set_menu_style()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setMenuStyle(tabcontainer: &mut AzTabContainer, style: AzNodeDataInlineCssPropertyVec) { tabcontainer.set_menu_style(style); }
+/// Equivalent to the Rust `TabContainer::set_page_style()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_setPageStyle(tabcontainer: &mut AzTabContainer, style: AzNodeDataInlineCssPropertyVec) { tabcontainer.set_page_style(style); }
+/// Equivalent to the Rust `TabContainer::dom()` function.
+#[no_mangle] pub extern "C" fn AzTabContainer_dom(tabcontainer: &mut AzTabContainer) -> AzDom { tabcontainer.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `TabContainer` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainer_delete(object: &mut AzTabContainer) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabContainerStateWrapper` struct
+pub type AzTabContainerStateWrapperTT = crate::widgets::tab_container::TabContainerStateWrapper;
+pub use AzTabContainerStateWrapperTT as AzTabContainerStateWrapper;
+/// Destructor: Takes ownership of the `TabContainerStateWrapper` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainerStateWrapper_delete(object: &mut AzTabContainerStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabContainerState` struct
+pub type AzTabContainerStateTT = crate::widgets::tab_container::TabContainerState;
+pub use AzTabContainerStateTT as AzTabContainerState;
+/// Destructor: Takes ownership of the `TabContainerState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainerState_delete(object: &mut AzTabContainerState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Header of a single tab
+pub type AzTabContainerTabTT = crate::widgets::tab_container::TabContainerTab;
+pub use AzTabContainerTabTT as AzTabContainerTab;
+/// Destructor: Takes ownership of the `TabContainerTab` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainerTab_delete(object: &mut AzTabContainerTab) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// What happens if the tabs don't fit into the header
+pub type AzTabContainerOverflowTT = crate::widgets::tab_container::TabContainerOverflow;
+pub use AzTabContainerOverflowTT as AzTabContainerOverflow;
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabChange` struct
+pub type AzTabContainerOnTabChangeTT = crate::widgets::tab_container::TabContainerOnTabChange;
+pub use AzTabContainerOnTabChangeTT as AzTabContainerOnTabChange;
+/// Destructor: Takes ownership of the `TabContainerOnTabChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainerOnTabChange_delete(object: &mut AzTabContainerOnTabChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabChangeCallback` struct
+pub type AzTabContainerOnTabChangeCallbackTT = crate::widgets::tab_container::TabContainerOnTabChangeCallback;
+pub use AzTabContainerOnTabChangeCallbackTT as AzTabContainerOnTabChangeCallback;
+
+pub type AzTabContainerOnTabChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabClose` struct
+pub type AzTabContainerOnTabCloseTT = crate::widgets::tab_container::TabContainerOnTabClose;
+pub use AzTabContainerOnTabCloseTT as AzTabContainerOnTabClose;
+/// Destructor: Takes ownership of the `TabContainerOnTabClose` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainerOnTabClose_delete(object: &mut AzTabContainerOnTabClose) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabCloseCallback` struct
+pub type AzTabContainerOnTabCloseCallbackTT = crate::widgets::tab_container::TabContainerOnTabCloseCallback;
+pub use AzTabContainerOnTabCloseCallbackTT as AzTabContainerOnTabCloseCallback;
+
+pub type AzTabContainerOnTabCloseCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, AzTabContainerTab, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabMove` struct
+pub type AzTabContainerOnTabMoveTT = crate::widgets::tab_container::TabContainerOnTabMove;
+pub use AzTabContainerOnTabMoveTT as AzTabContainerOnTabMove;
+/// Destructor: Takes ownership of the `TabContainerOnTabMove` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainerOnTabMove_delete(object: &mut AzTabContainerOnTabMove) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabMoveCallback` struct
+pub type AzTabContainerOnTabMoveCallbackTT = crate::widgets::tab_container::TabContainerOnTabMoveCallback;
+pub use AzTabContainerOnTabMoveCallbackTT as AzTabContainerOnTabMoveCallback;
+
+pub type AzTabContainerOnTabMoveCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, usize, usize, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `Slider` struct
+pub type AzSliderTT = crate::widgets::slider::Slider;
+pub use AzSliderTT as AzSlider;
+/// Creates a new `Slider` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Slider::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSlider_new(value: f32, min: f32, max: f32) -> AzSlider { AzSlider::new(value, min, max) }
+/// Creates a range slider with two thumbs at `lower` and `upper`
+#[no_mangle] pub extern "C" fn AzSlider_newRange(lower: f32, upper: f32, min: f32, max: f32) -> AzSlider { AzSlider::new_range(lower, upper, min, max) }
+/// Sets the orientation, resets the container and track style to the default style of the orientation
+#[no_mangle] pub extern "C" fn AzSlider_setOrientation(slider: &mut AzSlider, orientation: AzSliderOrientation) { slider.set_orientation(orientation); }
+/// Equivalent to the Rust `Slider::with_orientation()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withOrientation(slider: &mut AzSlider, orientation: AzSliderOrientation) -> AzSlider { slider.swap_with_default().with_orientation(orientation) }
+/// Equivalent to the Rust `Slider::set_step()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setStep(slider: &mut AzSlider, step: f32) { slider.set_step(step); }
+/// Equivalent to the Rust `Slider::with_step()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withStep(slider: &mut AzSlider, step: f32) -> AzSlider { slider.swap_with_default().with_step(step) }
+/// Equivalent to the Rust `Slider::set_tick_interval()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setTickInterval(slider: &mut AzSlider, tick_interval: AzOptionF32) { slider.set_tick_interval(tick_interval); }
+/// Equivalent to the Rust `Slider::with_tick_interval()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withTickInterval(slider: &mut AzSlider, tick_interval: f32) -> AzSlider { slider.swap_with_default().with_tick_interval(tick_interval) }
+/// Equivalent to the Rust `Slider::set_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setOnValueChange(slider: &mut AzSlider, data: AzRefAny, callback: AzSliderOnValueChangeCallbackType) { slider.set_on_value_change(data, callback); }
+/// Equivalent to the Rust `Slider::with_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withOnValueChange(slider: &mut AzSlider, data: AzRefAny, callback: AzSliderOnValueChangeCallbackType) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_on_value_change(data, callback); slider }
+/// Equivalent to the Rust `Slider::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setContainerStyle(slider: &mut AzSlider, style: AzNodeDataInlineCssPropertyVec) { slider.set_container_style(style); }
+/// Equivalent to the Rust `Slider::set_track_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setTrackStyle(slider: &mut AzSlider, style: AzNodeDataInlineCssPropertyVec) { slider.set_track_style(style); }
+/// Equivalent to the Rust `Slider::set_fill_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setFillStyle(slider: &mut AzSlider, style: AzNodeDataInlineCssPropertyVec) { slider.set_fill_style(style); }
+/// Equivalent to the Rust `Slider::set_thumb_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setThumbStyle(slider: &mut AzSlider, style: AzNodeDataInlineCssPropertyVec) { slider.set_thumb_style(style); }
+/// Equivalent to the Rust `Slider::set_tick_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setTickStyle(slider: &mut AzSlider, style: AzNodeDataInlineCssPropertyVec) { slider.set_tick_style(style); }
+/// Equivalent to the Rust `Slider::dom()` function.
+#[no_mangle] pub extern "C" fn AzSlider_dom(slider: &mut AzSlider) -> AzDom { slider.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `Slider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSlider_delete(object: &mut AzSlider) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
+pub type AzSliderStateWrapperTT = crate::widgets::slider::SliderStateWrapper;
+pub use AzSliderStateWrapperTT as AzSliderStateWrapper;
+/// Destructor: Takes ownership of the `SliderStateWrapper` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSliderStateWrapper_delete(object: &mut AzSliderStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SliderState` struct
+pub type AzSliderStateTT = crate::widgets::slider::SliderState;
+pub use AzSliderStateTT as AzSliderState;
+
+/// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+pub type AzSliderOrientationTT = crate::widgets::slider::SliderOrientation;
+pub use AzSliderOrientationTT as AzSliderOrientation;
+
+/// Identifies one of the thumbs, a non-range slider only has the `Lower` thumb
+pub type AzSliderThumbTT = crate::widgets::slider::SliderThumb;
+pub use AzSliderThumbTT as AzSliderThumb;
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+pub type AzSliderOnValueChangeTT = crate::widgets::slider::SliderOnValueChange;
+pub use AzSliderOnValueChangeTT as AzSliderOnValueChange;
+/// Destructor: Takes ownership of the `SliderOnValueChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSliderOnValueChange_delete(object: &mut AzSliderOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+pub type AzSliderOnValueChangeCallbackTT = crate::widgets::slider::SliderOnValueChangeCallback;
+pub use AzSliderOnValueChangeCallbackTT as AzSliderOnValueChangeCallback;
+
+pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TextEdit` struct
+pub type AzTextEditTT = crate::widgets::text_edit::TextEdit;
+pub use AzTextEditTT as AzTextEdit;
+/// Creates a new `TextEdit` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextEdit::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTextEdit_new(text: AzString) -> AzTextEdit { AzTextEdit::new(text) }
+/// Creates the editor from a previous state, e.g. the state that was passed to the `on_text_change` callback (keeps the cursor and scroll position)
+#[no_mangle] pub extern "C" fn AzTextEdit_fromState(state: AzTextEditState) -> AzTextEdit { AzTextEdit::from_state(state) }
+/// Sets the number of visible rows, the height of the editor is `rows * 18px`
+#[no_mangle] pub extern "C" fn AzTextEdit_setVisibleRows(textedit: &mut AzTextEdit, rows: usize) { textedit.set_visible_rows(rows); }
+/// Equivalent to the Rust `TextEdit::with_visible_rows()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withVisibleRows(textedit: &mut AzTextEdit, rows: usize) -> AzTextEdit { textedit.swap_with_default().with_visible_rows(rows) }
+/// Equivalent to the Rust `TextEdit::set_wrap_lines()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setWrapLines(textedit: &mut AzTextEdit, wrap_lines: bool) { textedit.set_wrap_lines(wrap_lines); }
+/// Equivalent to the Rust `TextEdit::with_wrap_lines()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withWrapLines(textedit: &mut AzTextEdit, wrap_lines: bool) -> AzTextEdit { textedit.swap_with_default().with_wrap_lines(wrap_lines) }
+/// Equivalent to the Rust `TextEdit::set_on_text_change()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setOnTextChange(textedit: &mut AzTextEdit, data: AzRefAny, callback: AzTextEditOnTextChangeCallbackType) { textedit.set_on_text_change(data, callback); }
+/// Equivalent to the Rust `TextEdit::with_on_text_change()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withOnTextChange(textedit: &mut AzTextEdit, data: AzRefAny, callback: AzTextEditOnTextChangeCallbackType) -> AzTextEdit { let mut textedit = textedit.swap_with_default(); textedit.set_on_text_change(data, callback); textedit }
+/// Equivalent to the Rust `TextEdit::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setContainerStyle(textedit: &mut AzTextEdit, style: AzNodeDataInlineCssPropertyVec) { textedit.set_container_style(style); }
+/// Equivalent to the Rust `TextEdit::set_row_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setRowStyle(textedit: &mut AzTextEdit, style: AzNodeDataInlineCssPropertyVec) { textedit.set_row_style(style); }
+/// Equivalent to the Rust `TextEdit::set_label_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setLabelStyle(textedit: &mut AzTextEdit, style: AzNodeDataInlineCssPropertyVec) { textedit.set_label_style(style); }
+/// Equivalent to the Rust `TextEdit::set_selection_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setSelectionStyle(textedit: &mut AzTextEdit, style: AzNodeDataInlineCssPropertyVec) { textedit.set_selection_style(style); }
+/// Equivalent to the Rust `TextEdit::set_caret_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setCaretStyle(textedit: &mut AzTextEdit, style: AzNodeDataInlineCssPropertyVec) { textedit.set_caret_style(style); }
+/// Equivalent to the Rust `TextEdit::dom()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_dom(textedit: &mut AzTextEdit) -> AzDom { textedit.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `TextEdit` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextEdit_delete(object: &mut AzTextEdit) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+pub type AzTextEditStateWrapperTT = crate::widgets::text_edit::TextEditStateWrapper;
+pub use AzTextEditStateWrapperTT as AzTextEditStateWrapper;
+/// Destructor: Takes ownership of the `TextEditStateWrapper` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextEditStateWrapper_delete(object: &mut AzTextEditStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextEditState` struct
+pub type AzTextEditStateTT = crate::widgets::text_edit::TextEditState;
+pub use AzTextEditStateTT as AzTextEditState;
+/// Returns the text, lines separated by `\n`
+#[no_mangle] pub extern "C" fn AzTextEditState_getText(texteditstate: &AzTextEditState) -> AzString { texteditstate.get_text().into() }
+/// Destructor: Takes ownership of the `TextEditState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextEditState_delete(object: &mut AzTextEditState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Position in the text: line index and character (not byte) index in the line
+pub type AzTextEditCursorTT = crate::widgets::text_edit::TextEditCursor;
+pub use AzTextEditCursorTT as AzTextEditCursor;
+
+/// Kind of an edit, consecutive edits of the same kind are undone together
+pub type AzTextEditEditKindTT = crate::widgets::text_edit::TextEditEditKind;
+pub use AzTextEditEditKindTT as AzTextEditEditKind;
+
+/// Reversible edit: `removed` was replaced by `inserted` at `start`
+pub type AzTextEditUndoEntryTT = crate::widgets::text_edit::TextEditUndoEntry;
+pub use AzTextEditUndoEntryTT as AzTextEditUndoEntry;
+/// Destructor: Takes ownership of the `TextEditUndoEntry` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextEditUndoEntry_delete(object: &mut AzTextEditUndoEntry) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+pub type AzTextEditOnTextChangeTT = crate::widgets::text_edit::TextEditOnTextChange;
+pub use AzTextEditOnTextChangeTT as AzTextEditOnTextChange;
+/// Destructor: Takes ownership of the `TextEditOnTextChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextEditOnTextChange_delete(object: &mut AzTextEditOnTextChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+pub type AzTextEditOnTextChangeCallbackTT = crate::widgets::text_edit::TextEditOnTextChangeCallback;
+pub use AzTextEditOnTextChangeCallbackTT as AzTextEditOnTextChangeCallback;
+
+pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeView` struct
+pub type AzTreeViewTT = crate::widgets::tree_view::TreeView;
+pub use AzTreeViewTT as AzTreeView;
+/// Creates a new `TreeView` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TreeView::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTreeView_new(state: AzTreeViewState) -> AzTreeView { AzTreeView::new(state) }
+/// Creates a tree from a list of paths in the form `"Documents/notes.txt, Documents/Photos, Music"`: every path segment is a node, nodes with the same path are only created once
+#[no_mangle] pub extern "C" fn AzTreeView_fromPathsStr(paths: AzString) -> AzTreeView { AzTreeView::from_paths_str(paths.as_str()) }
+/// Sets the number of visible rows, the height of the tree is `rows * 20px`
+#[no_mangle] pub extern "C" fn AzTreeView_setVisibleRows(treeview: &mut AzTreeView, rows: usize) { treeview.set_visible_rows(rows); }
+/// Equivalent to the Rust `TreeView::with_visible_rows()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withVisibleRows(treeview: &mut AzTreeView, rows: usize) -> AzTreeView { treeview.swap_with_default().with_visible_rows(rows) }
+/// Equivalent to the Rust `TreeView::set_selection_mode()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) { treeview.set_selection_mode(selection_mode); }
+/// Equivalent to the Rust `TreeView::with_selection_mode()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) -> AzTreeView { treeview.swap_with_default().with_selection_mode(selection_mode) }
+/// Allows reordering nodes by dragging them onto other nodes
+#[no_mangle] pub extern "C" fn AzTreeView_setAllowReorder(treeview: &mut AzTreeView, allow_reorder: bool) { treeview.set_allow_reorder(allow_reorder); }
+/// Equivalent to the Rust `TreeView::with_allow_reorder()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withAllowReorder(treeview: &mut AzTreeView, allow_reorder: bool) -> AzTreeView { treeview.swap_with_default().with_allow_reorder(allow_reorder) }
+/// Equivalent to the Rust `TreeView::set_on_load_children()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnLoadChildren(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnLoadChildrenCallbackType) { treeview.set_on_load_children(data, callback); }
+/// Equivalent to the Rust `TreeView::with_on_load_children()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnLoadChildren(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnLoadChildrenCallbackType) -> AzTreeView { let mut treeview = treeview.swap_with_default(); treeview.set_on_load_children(data, callback); treeview }
+/// Equivalent to the Rust `TreeView::set_on_selection_change()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnSelectionChange(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectionChangeCallbackType) { treeview.set_on_selection_change(data, callback); }
+/// Equivalent to the Rust `TreeView::with_on_selection_change()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnSelectionChange(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectionChangeCallbackType) -> AzTreeView { let mut treeview = treeview.swap_with_default(); treeview.set_on_selection_change(data, callback); treeview }
+/// Equivalent to the Rust `TreeView::set_on_node_moved()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnNodeMoved(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnNodeMovedCallbackType) { treeview.set_on_node_moved(data, callback); }
+/// Equivalent to the Rust `TreeView::with_on_node_moved()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnNodeMoved(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnNodeMovedCallbackType) -> AzTreeView { let mut treeview = treeview.swap_with_default(); treeview.set_on_node_moved(data, callback); treeview }
+/// Equivalent to the Rust `TreeView::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setContainerStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_container_style(style); }
+/// Equivalent to the Rust `TreeView::set_row_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setRowStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_row_style(style); }
+/// Equivalent to the Rust `TreeView::set_guides_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setGuidesStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_guides_style(style); }
+/// Equivalent to the Rust `TreeView::set_toggle_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setToggleStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_toggle_style(style); }
+/// Equivalent to the Rust `TreeView::set_icon_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setIconStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_icon_style(style); }
+/// Equivalent to the Rust `TreeView::set_label_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setLabelStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_label_style(style); }
+/// Equivalent to the Rust `TreeView::set_scrollbar_thumb_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setScrollbarThumbStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_scrollbar_thumb_style(style); }
+/// Equivalent to the Rust `TreeView::set_drop_indicator_style()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setDropIndicatorStyle(treeview: &mut AzTreeView, style: AzNodeDataInlineCssPropertyVec) { treeview.set_drop_indicator_style(style); }
+/// Equivalent to the Rust `TreeView::dom()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_dom(treeview: &mut AzTreeView) -> AzDom { treeview.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `TreeView` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeView_delete(object: &mut AzTreeView) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TreeViewStateWrapper` struct
+pub type AzTreeViewStateWrapperTT = crate::widgets::tree_view::TreeViewStateWrapper;
+pub use AzTreeViewStateWrapperTT as AzTreeViewStateWrapper;
+/// Destructor: Takes ownership of the `TreeViewStateWrapper` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewStateWrapper_delete(object: &mut AzTreeViewStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TreeViewState` struct
+pub type AzTreeViewStateTT = crate::widgets::tree_view::TreeViewState;
+pub use AzTreeViewStateTT as AzTreeViewState;
+/// Creates a new `TreeViewState` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TreeViewState::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTreeViewState_new() -> AzTreeViewState { AzTreeViewState::new() }
+/// Appends a node to the children of `parent` (or to the root nodes), returns the id of the new node. Call `update_rows` once the tree is built.
+#[no_mangle] pub extern "C" fn AzTreeViewState_addNode(treeviewstate: &mut AzTreeViewState, parent: AzOptionUsize, data: AzTreeViewNodeData) -> usize { treeviewstate.add_node(parent.into_option(), data) }
+/// Replaces the children of `node_id` and marks them as loaded
+#[no_mangle] pub extern "C" fn AzTreeViewState_setChildren(treeviewstate: &mut AzTreeViewState, node_id: usize, children: AzTreeViewNodeDataVec) { treeviewstate.set_children(node_id, children); }
+/// Recomputes the visible rows, has to be called after the tree structure or the expanded state of a node was modified directly
+#[no_mangle] pub extern "C" fn AzTreeViewState_updateRows(treeviewstate: &mut AzTreeViewState) { treeviewstate.update_rows(); }
+/// Expands the node, returns true if the children of the node have to be loaded (the node is set to `TreeViewChildren::Loading`)
+#[no_mangle] pub extern "C" fn AzTreeViewState_expand(treeviewstate: &mut AzTreeViewState, node_id: usize) -> bool { treeviewstate.expand(node_id) }
+/// Collapses the node, moves the cursor to the node if it was inside the collapsed subtree
+#[no_mangle] pub extern "C" fn AzTreeViewState_collapse(treeviewstate: &mut AzTreeViewState, node_id: usize) { treeviewstate.collapse(node_id); }
+/// Expands all ancestors of the node, so that the node becomes visible
+#[no_mangle] pub extern "C" fn AzTreeViewState_reveal(treeviewstate: &mut AzTreeViewState, node_id: usize) { treeviewstate.reveal(node_id); }
+/// Equivalent to the Rust `TreeViewState::is_expanded()` function.
+#[no_mangle] pub extern "C" fn AzTreeViewState_isExpanded(treeviewstate: &AzTreeViewState, node_id: usize) -> bool { treeviewstate.is_expanded(node_id) }
+/// Equivalent to the Rust `TreeViewState::clear_selection()` function.
+#[no_mangle] pub extern "C" fn AzTreeViewState_clearSelection(treeviewstate: &mut AzTreeViewState) { treeviewstate.clear_selection(); }
+/// Equivalent to the Rust `TreeViewState::set_selected()` function.
+#[no_mangle] pub extern "C" fn AzTreeViewState_setSelected(treeviewstate: &mut AzTreeViewState, node_id: usize, selected: bool) { treeviewstate.set_selected(node_id, selected); }
+/// Moves the cursor to the node and selects it according to the selection mode: `extend` selects the rows from the anchor to the node, `toggle` adds / removes the node to / from the selection (only for `Multiple`)
+#[no_mangle] pub extern "C" fn AzTreeViewState_select(treeviewstate: &mut AzTreeViewState, node_id: usize, extend: bool, toggle: bool) { treeviewstate.select(node_id, extend, toggle); }
+/// Moves the node (with its subtree) to the target, returns false if the move isn't allowed
+#[no_mangle] pub extern "C" fn AzTreeViewState_moveNode(treeviewstate: &mut AzTreeViewState, node_id: usize, target: AzTreeViewDropTarget) -> bool { treeviewstate.move_node(node_id, target) }
+/// Destructor: Takes ownership of the `TreeViewState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewState_delete(object: &mut AzTreeViewState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Node in the arena of the `TreeViewState`, the links are indices into `TreeViewState::nodes`
+pub type AzTreeViewNodeTT = crate::widgets::tree_view::TreeViewNode;
+pub use AzTreeViewNodeTT as AzTreeViewNode;
+/// Destructor: Takes ownership of the `TreeViewNode` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewNode_delete(object: &mut AzTreeViewNode) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// User-facing content of a node
+pub type AzTreeViewNodeDataTT = crate::widgets::tree_view::TreeViewNodeData;
+pub use AzTreeViewNodeDataTT as AzTreeViewNodeData;
+/// Creates a new `TreeViewNodeData` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TreeViewNodeData::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTreeViewNodeData_new(label: AzString) -> AzTreeViewNodeData { AzTreeViewNodeData::new(label) }
+/// Equivalent to the Rust `TreeViewNodeData::with_icon()` function.
+#[no_mangle] pub extern "C" fn AzTreeViewNodeData_withIcon(treeviewnodedata: &mut AzTreeViewNodeData, icon: AzImageRef) -> AzTreeViewNodeData { treeviewnodedata.clone().with_icon(icon) }
+/// Marks the node as having children that are loaded on the first expand
+#[no_mangle] pub extern "C" fn AzTreeViewNodeData_withLazyChildren(treeviewnodedata: &mut AzTreeViewNodeData) -> AzTreeViewNodeData { treeviewnodedata.clone().with_lazy_children() }
+/// Destructor: Takes ownership of the `TreeViewNodeData` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewNodeData_delete(object: &mut AzTreeViewNodeData) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Whether a node has children and whether they have been loaded yet
+pub type AzTreeViewChildrenTT = crate::widgets::tree_view::TreeViewChildren;
+pub use AzTreeViewChildrenTT as AzTreeViewChildren;
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+pub type AzTreeViewSelectionModeTT = crate::widgets::tree_view::TreeViewSelectionMode;
+pub use AzTreeViewSelectionModeTT as AzTreeViewSelectionMode;
+
+/// Re-export of rust-allocated (stack based) `TreeViewDropPosition` struct
+pub type AzTreeViewDropPositionTT = crate::widgets::tree_view::TreeViewDropPosition;
+pub use AzTreeViewDropPositionTT as AzTreeViewDropPosition;
+
+/// Re-export of rust-allocated (stack based) `TreeViewDropTarget` struct
+pub type AzTreeViewDropTargetTT = crate::widgets::tree_view::TreeViewDropTarget;
+pub use AzTreeViewDropTargetTT as AzTreeViewDropTarget;
+
+/// Visible node: all ancestors of the node are expanded
+pub type AzTreeViewRowTT = crate::widgets::tree_view::TreeViewRow;
+pub use AzTreeViewRowTT as AzTreeViewRow;
+
+/// Content of one row of the row pool, `node` is `None` for the empty rows below the last node
+pub type AzTreeViewRenderedRowTT = crate::widgets::tree_view::TreeViewRenderedRow;
+pub use AzTreeViewRenderedRowTT as AzTreeViewRenderedRow;
+/// Destructor: Takes ownership of the `TreeViewRenderedRow` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewRenderedRow_delete(object: &mut AzTreeViewRenderedRow) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Passed to the `on_load_children` callback
+pub type AzTreeViewLoadRequestTT = crate::widgets::tree_view::TreeViewLoadRequest;
+pub use AzTreeViewLoadRequestTT as AzTreeViewLoadRequest;
+/// Destructor: Takes ownership of the `TreeViewLoadRequest` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewLoadRequest_delete(object: &mut AzTreeViewLoadRequest) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Children that were loaded on a background thread
+pub type AzTreeViewLoadedChildrenTT = crate::widgets::tree_view::TreeViewLoadedChildren;
+pub use AzTreeViewLoadedChildrenTT as AzTreeViewLoadedChildren;
+/// Creates a new `TreeViewLoadedChildren` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TreeViewLoadedChildren::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTreeViewLoadedChildren_new(node: usize, children: AzTreeViewNodeDataVec) -> AzTreeViewLoadedChildren { AzTreeViewLoadedChildren::new(node, children) }
+/// Message that inserts the children into the tree when it is sent from the thread
+#[no_mangle] pub extern "C" fn AzTreeViewLoadedChildren_intoWritebackMsg(treeviewloadedchildren: &mut AzTreeViewLoadedChildren) -> AzThreadWriteBackMsg { treeviewloadedchildren.clone().into_writeback_msg() }
+/// Destructor: Takes ownership of the `TreeViewLoadedChildren` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewLoadedChildren_delete(object: &mut AzTreeViewLoadedChildren) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Payload of a drag that was started on a node, can be used by other widgets to accept nodes that are dragged out of the tree
+pub type AzTreeViewDragPayloadTT = crate::widgets::tree_view::TreeViewDragPayload;
+pub use AzTreeViewDragPayloadTT as AzTreeViewDragPayload;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildren` struct
+pub type AzTreeViewOnLoadChildrenTT = crate::widgets::tree_view::TreeViewOnLoadChildren;
+pub use AzTreeViewOnLoadChildrenTT as AzTreeViewOnLoadChildren;
+/// Destructor: Takes ownership of the `TreeViewOnLoadChildren` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnLoadChildren_delete(object: &mut AzTreeViewOnLoadChildren) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildrenCallback` struct
+pub type AzTreeViewOnLoadChildrenCallbackTT = crate::widgets::tree_view::TreeViewOnLoadChildrenCallback;
+pub use AzTreeViewOnLoadChildrenCallbackTT as AzTreeViewOnLoadChildrenCallback;
+
+pub type AzTreeViewOnLoadChildrenCallbackType = extern "C" fn(&mut AzRefAny, &mut AzTreeViewState, AzTreeViewLoadRequest, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChange` struct
+pub type AzTreeViewOnSelectionChangeTT = crate::widgets::tree_view::TreeViewOnSelectionChange;
+pub use AzTreeViewOnSelectionChangeTT as AzTreeViewOnSelectionChange;
+/// Destructor: Takes ownership of the `TreeViewOnSelectionChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnSelectionChange_delete(object: &mut AzTreeViewOnSelectionChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChangeCallback` struct
+pub type AzTreeViewOnSelectionChangeCallbackTT = crate::widgets::tree_view::TreeViewOnSelectionChangeCallback;
+pub use AzTreeViewOnSelectionChangeCallbackTT as AzTreeViewOnSelectionChangeCallback;
+
+pub type AzTreeViewOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnNodeMoved` struct
+pub type AzTreeViewOnNodeMovedTT = crate::widgets::tree_view::TreeViewOnNodeMoved;
+pub use AzTreeViewOnNodeMovedTT as AzTreeViewOnNodeMoved;
+/// Destructor: Takes ownership of the `TreeViewOnNodeMoved` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnNodeMoved_delete(object: &mut AzTreeViewOnNodeMoved) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnNodeMovedCallback` struct
+pub type AzTreeViewOnNodeMovedCallbackTT = crate::widgets::tree_view::TreeViewOnNodeMovedCallback;
+pub use AzTreeViewOnNodeMovedCallbackTT as AzTreeViewOnNodeMovedCallback;
+
+pub type AzTreeViewOnNodeMovedCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, usize, &mut AzCallbackInfo) -> AzUpdate;
+/// Virtualized list: renders only the rows that are visible, so that lists with millions of rows stay cheap to lay out
+pub type AzVirtualListTT = crate::widgets::virtual_list::VirtualList;
+pub use AzVirtualListTT as AzVirtualList;
+/// Creates a list with rows of a fixed height
+#[no_mangle] pub extern "C" fn AzVirtualList_new(row_count: usize, row_height: f32, data: AzRefAny, render_row: AzVirtualListRenderRowCallbackType) -> AzVirtualList { AzVirtualList::new(row_count, row_height, data, render_row) }
+/// Re-creates a list from the state of a previous list (see `get_state`), keeping the scroll offset and the measured row heights
+#[no_mangle] pub extern "C" fn AzVirtualList_fromState(state: AzRefAny) -> AzVirtualList { AzVirtualList::from_state(state) }
+/// Returns the shared state of the list, store it and pass it to `from_state` so that the scroll offset survives when the DOM is regenerated
+#[no_mangle] pub extern "C" fn AzVirtualList_getState(virtuallist: &AzVirtualList) -> AzRefAny { virtuallist.get_state() }
+/// Sets the number of rows (i.e. if rows were added to a list created with `from_state`)
+#[no_mangle] pub extern "C" fn AzVirtualList_setRowCount(virtuallist: &mut AzVirtualList, row_count: usize) { virtuallist.set_row_count(row_count); }
+/// Equivalent to the Rust `VirtualList::with_row_count()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_withRowCount(virtuallist: &mut AzVirtualList, row_count: usize) -> AzVirtualList { virtuallist.swap_with_default().with_row_count(row_count) }
+/// Sets whether the rows have a fixed or an estimated height, discards the measured heights if the height changes
+#[no_mangle] pub extern "C" fn AzVirtualList_setRowHeight(virtuallist: &mut AzVirtualList, row_height: AzVirtualListRowHeight) { virtuallist.set_row_height(row_height); }
+/// Equivalent to the Rust `VirtualList::with_row_height()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_withRowHeight(virtuallist: &mut AzVirtualList, row_height: AzVirtualListRowHeight) -> AzVirtualList { virtuallist.swap_with_default().with_row_height(row_height) }
+/// Sets the scroll offset (in pixels) that the list is rendered with
+#[no_mangle] pub extern "C" fn AzVirtualList_setScrollOffset(virtuallist: &mut AzVirtualList, scroll_offset: f32) { virtuallist.set_scroll_offset(scroll_offset); }
+/// Equivalent to the Rust `VirtualList::with_scroll_offset()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_withScrollOffset(virtuallist: &mut AzVirtualList, scroll_offset: f32) -> AzVirtualList { virtuallist.swap_with_default().with_scroll_offset(scroll_offset) }
+/// Equivalent to the Rust `VirtualList::set_overscan()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_setOverscan(virtuallist: &mut AzVirtualList, overscan: usize) { virtuallist.set_overscan(overscan); }
+/// Equivalent to the Rust `VirtualList::with_overscan()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_withOverscan(virtuallist: &mut AzVirtualList, overscan: usize) -> AzVirtualList { virtuallist.swap_with_default().with_overscan(overscan) }
+/// Equivalent to the Rust `VirtualList::set_on_scroll()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_setOnScroll(virtuallist: &mut AzVirtualList, data: AzRefAny, callback: AzVirtualListOnScrollCallbackType) { virtuallist.set_on_scroll(data, callback); }
+/// Equivalent to the Rust `VirtualList::with_on_scroll()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_withOnScroll(virtuallist: &mut AzVirtualList, data: AzRefAny, callback: AzVirtualListOnScrollCallbackType) -> AzVirtualList { let mut virtuallist = virtuallist.swap_with_default(); virtuallist.set_on_scroll(data, callback); virtuallist }
+/// Equivalent to the Rust `VirtualList::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_setContainerStyle(virtuallist: &mut AzVirtualList, style: AzNodeDataInlineCssPropertyVec) { virtuallist.set_container_style(style); }
+/// Equivalent to the Rust `VirtualList::set_row_style()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_setRowStyle(virtuallist: &mut AzVirtualList, style: AzNodeDataInlineCssPropertyVec) { virtuallist.set_row_style(style); }
+/// Equivalent to the Rust `VirtualList::set_scrollbar_track_style()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_setScrollbarTrackStyle(virtuallist: &mut AzVirtualList, style: AzNodeDataInlineCssPropertyVec) { virtuallist.set_scrollbar_track_style(style); }
+/// Equivalent to the Rust `VirtualList::set_scrollbar_thumb_style()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_setScrollbarThumbStyle(virtuallist: &mut AzVirtualList, style: AzNodeDataInlineCssPropertyVec) { virtuallist.set_scrollbar_thumb_style(style); }
+/// Equivalent to the Rust `VirtualList::dom()` function.
+#[no_mangle] pub extern "C" fn AzVirtualList_dom(virtuallist: &mut AzVirtualList) -> AzDom { virtuallist.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `VirtualList` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzVirtualList_delete(object: &mut AzVirtualList) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `VirtualListStateWrapper` struct
+pub type AzVirtualListStateWrapperTT = crate::widgets::virtual_list::VirtualListStateWrapper;
+pub use AzVirtualListStateWrapperTT as AzVirtualListStateWrapper;
+/// Destructor: Takes ownership of the `VirtualListStateWrapper` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzVirtualListStateWrapper_delete(object: &mut AzVirtualListStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `VirtualListState` struct
+pub type AzVirtualListStateTT = crate::widgets::virtual_list::VirtualListState;
+pub use AzVirtualListStateTT as AzVirtualListState;
+/// Creates a new `VirtualListState` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `VirtualListState::new()` constructor.
+#[no_mangle] pub extern "C" fn AzVirtualListState_new(row_count: usize, row_height: AzVirtualListRowHeight) -> AzVirtualListState { AzVirtualListState::new(row_count, row_height) }
+/// Returns the fixed row height or the estimate for rows that were not measured yet
+#[no_mangle] pub extern "C" fn AzVirtualListState_getDefaultRowHeight(virtualliststate: &AzVirtualListState) -> f32 { virtualliststate.get_default_row_height() }
+/// Returns the (measured or estimated) height of the given row
+#[no_mangle] pub extern "C" fn AzVirtualListState_getRowHeight(virtualliststate: &AzVirtualListState, row: usize) -> f32 { virtualliststate.get_row_height(row) }
+/// Returns the distance of the top of the row to the top of the first row
+#[no_mangle] pub extern "C" fn AzVirtualListState_getRowTop(virtualliststate: &AzVirtualListState, row: usize) -> f32 { virtualliststate.get_row_top(row) }
+/// Returns the row at the given distance from the top of the first row, `row_count` if the offset is below the last row
+#[no_mangle] pub extern "C" fn AzVirtualListState_getRowAtOffset(virtualliststate: &AzVirtualListState, offset: f32) -> usize { virtualliststate.get_row_at_offset(offset) }
+/// Height of all rows combined, i.e. the scrollable height of the list
+#[no_mangle] pub extern "C" fn AzVirtualListState_getTotalHeight(virtualliststate: &AzVirtualListState) -> f32 { virtualliststate.get_total_height() }
+/// Equivalent to the Rust `VirtualListState::get_max_scroll_offset()` function.
+#[no_mangle] pub extern "C" fn AzVirtualListState_getMaxScrollOffset(virtualliststate: &AzVirtualListState) -> f32 { virtualliststate.get_max_scroll_offset() }
+/// Equivalent to the Rust `VirtualListState::clamp_scroll_offset()` function.
+#[no_mangle] pub extern "C" fn AzVirtualListState_clampScrollOffset(virtualliststate: &AzVirtualListState, scroll_offset: f32) -> f32 { virtualliststate.clamp_scroll_offset(scroll_offset) }
+/// Destructor: Takes ownership of the `VirtualListState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzVirtualListState_delete(object: &mut AzVirtualListState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Height of the rows of a `VirtualList`
+pub type AzVirtualListRowHeightTT = crate::widgets::virtual_list::VirtualListRowHeight;
+pub use AzVirtualListRowHeightTT as AzVirtualListRowHeight;
+
+/// Measured height of a row (only used with `VirtualListRowHeight::Estimated`)
+pub type AzVirtualListMeasuredRowTT = crate::widgets::virtual_list::VirtualListMeasuredRow;
+pub use AzVirtualListMeasuredRowTT as AzVirtualListMeasuredRow;
+
+/// Nodes inside the IFrame that are updated in place when the list is scrolled
+pub type AzVirtualListIFrameNodesTT = crate::widgets::virtual_list::VirtualListIFrameNodes;
+pub use AzVirtualListIFrameNodesTT as AzVirtualListIFrameNodes;
+
+/// Re-export of rust-allocated (stack based) `VirtualListRenderRow` struct
+pub type AzVirtualListRenderRowTT = crate::widgets::virtual_list::VirtualListRenderRow;
+pub use AzVirtualListRenderRowTT as AzVirtualListRenderRow;
+/// Destructor: Takes ownership of the `VirtualListRenderRow` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzVirtualListRenderRow_delete(object: &mut AzVirtualListRenderRow) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `VirtualListRenderRowCallback` struct
+pub type AzVirtualListRenderRowCallbackTT = crate::widgets::virtual_list::VirtualListRenderRowCallback;
+pub use AzVirtualListRenderRowCallbackTT as AzVirtualListRenderRowCallback;
+
+pub type AzVirtualListRenderRowCallbackType = extern "C" fn(&mut AzRefAny, usize) -> AzStyledDom;
+/// Re-export of rust-allocated (stack based) `VirtualListOnScroll` struct
+pub type AzVirtualListOnScrollTT = crate::widgets::virtual_list::VirtualListOnScroll;
+pub use AzVirtualListOnScrollTT as AzVirtualListOnScroll;
+/// Destructor: Takes ownership of the `VirtualListOnScroll` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzVirtualListOnScroll_delete(object: &mut AzVirtualListOnScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `VirtualListOnScrollCallback` struct
+pub type AzVirtualListOnScrollCallbackTT = crate::widgets::virtual_list::VirtualListOnScrollCallback;
+pub use AzVirtualListOnScrollCallbackTT as AzVirtualListOnScrollCallback;
+
+pub type AzVirtualListOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &AzVirtualListState, &mut AzCallbackInfo) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `Node` struct
 pub type AzNodeTT = azul_impl::styled_dom::AzNode;
 pub use AzNodeTT as AzNode;
@@ -2096,9 +2904,9 @@ pub use AzStyledDomTT as AzStyledDom;
 /// Returns a default, empty `Dom`, usually returned if you don't want to crash in an error case.
 #[no_mangle] pub extern "C" fn AzStyledDom_default() -> AzStyledDom { AzStyledDom::default() }
 /// Returns a DOM loaded from an XML file
-#[no_mangle] pub extern "C" fn AzStyledDom_fromXml(xml_string: AzString) -> AzStyledDom { azul_impl::app::extra::styled_dom_from_str(xml_string.as_str()) }
-/// Same as `from_xml`, but loads the file relative to the current directory
-#[no_mangle] pub extern "C" fn AzStyledDom_fromFile(xml_file_path: AzString) -> AzStyledDom { azul_impl::app::extra::styled_dom_from_file(xml_file_path.as_str()) }
+#[no_mangle] pub extern "C" fn AzStyledDom_fromXml(xml_string: AzString) -> AzStyledDom { crate::widgets::init_xml_components(); azul_impl::app::extra::styled_dom_from_str(xml_string.as_str()) }
+/// Same as `from_xml`, but loads the file relative to the current directory. Stylesheets linked via `<link rel="stylesheet" href="..."/>` and `@import` are loaded as well. The file is only re-parsed if it changed since the last call - if it can't be parsed, the error is shown on top of the last working version of the UI
+#[no_mangle] pub extern "C" fn AzStyledDom_fromFile(xml_file_path: AzString) -> AzStyledDom { crate::widgets::init_xml_components(); azul_impl::app::extra::styled_dom_from_file(xml_file_path.as_str()) }
 /// Appends an already styled list of DOM nodes to the current `dom.root` - complexity `O(count(dom.dom_nodes))`
 #[no_mangle] pub extern "C" fn AzStyledDom_appendChild(styleddom: &mut AzStyledDom, dom: AzStyledDom) { styleddom.append_child(dom); }
 /// Restyles an already styled DOM with a new CSS - overwrites old styles, but does not replace them, useful for implementing user styles that are applied on top of the existing application style
@@ -3042,6 +3850,10 @@ pub use AzXmlNodeTT as AzXmlNode;
 /// Destructor: Takes ownership of the `XmlNode` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzXmlNode_delete(object: &mut AzXmlNode) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Start and end position of a node, attribute or text in the XML source
+pub type AzXmlSpanTT = azul_impl::xml::XmlSpan;
+pub use AzXmlSpanTT as AzXmlSpan;
+
 /// **Reference-counted** file handle
 pub type AzFileTT = azul_impl::file::File;
 pub use AzFileTT as AzFile;
@@ -3359,6 +4171,12 @@ pub use AzXmlNodeVecTT as AzXmlNodeVec;
 /// Destructor: Takes ownership of the `XmlNodeVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzXmlNodeVec_delete(object: &mut AzXmlNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<XmlSpan>`
+pub type AzXmlSpanVecTT = azul_impl::xml::XmlSpanVec;
+pub use AzXmlSpanVecTT as AzXmlSpanVec;
+/// Destructor: Takes ownership of the `XmlSpanVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzXmlSpanVec_delete(object: &mut AzXmlSpanVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<FmtArg>`
 pub type AzFmtArgVecTT = azul_impl::str::FmtArgVec;
 pub use AzFmtArgVecTT as AzFmtArgVec;
@@ -3515,6 +4333,60 @@ pub use AzScanCodeVecTT as AzScanCodeVec;
 /// Destructor: Takes ownership of the `ScanCodeVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzScanCodeVec_delete(object: &mut AzScanCodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `TextInputValidator`
+pub type AzTextInputValidatorVecTT = crate::widgets::text_input::TextInputValidatorVec;
+pub use AzTextInputValidatorVecTT as AzTextInputValidatorVec;
+/// Destructor: Takes ownership of the `TextInputValidatorVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputValidatorVec_delete(object: &mut AzTextInputValidatorVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `DropDownChoice`
+pub type AzDropDownChoiceVecTT = crate::widgets::drop_down::DropDownChoiceVec;
+pub use AzDropDownChoiceVecTT as AzDropDownChoiceVec;
+/// Destructor: Takes ownership of the `DropDownChoiceVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDropDownChoiceVec_delete(object: &mut AzDropDownChoiceVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `TabContainerTab`
+pub type AzTabContainerTabVecTT = crate::widgets::tab_container::TabContainerTabVec;
+pub use AzTabContainerTabVecTT as AzTabContainerTabVec;
+/// Destructor: Takes ownership of the `TabContainerTabVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabContainerTabVec_delete(object: &mut AzTabContainerTabVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `TextEditUndoEntry`
+pub type AzTextEditUndoEntryVecTT = crate::widgets::text_edit::TextEditUndoEntryVec;
+pub use AzTextEditUndoEntryVecTT as AzTextEditUndoEntryVec;
+/// Destructor: Takes ownership of the `TextEditUndoEntryVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextEditUndoEntryVec_delete(object: &mut AzTextEditUndoEntryVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `TreeViewNodeData`
+pub type AzTreeViewNodeDataVecTT = crate::widgets::tree_view::TreeViewNodeDataVec;
+pub use AzTreeViewNodeDataVecTT as AzTreeViewNodeDataVec;
+/// Destructor: Takes ownership of the `TreeViewNodeDataVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewNodeDataVec_delete(object: &mut AzTreeViewNodeDataVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `TreeViewNode`
+pub type AzTreeViewNodeVecTT = crate::widgets::tree_view::TreeViewNodeVec;
+pub use AzTreeViewNodeVecTT as AzTreeViewNodeVec;
+/// Destructor: Takes ownership of the `TreeViewNodeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewNodeVec_delete(object: &mut AzTreeViewNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `TreeViewRow`
+pub type AzTreeViewRowVecTT = crate::widgets::tree_view::TreeViewRowVec;
+pub use AzTreeViewRowVecTT as AzTreeViewRowVec;
+/// Destructor: Takes ownership of the `TreeViewRowVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewRowVec_delete(object: &mut AzTreeViewRowVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `TreeViewRenderedRow`
+pub type AzTreeViewRenderedRowVecTT = crate::widgets::tree_view::TreeViewRenderedRowVec;
+pub use AzTreeViewRenderedRowVecTT as AzTreeViewRenderedRowVec;
+/// Destructor: Takes ownership of the `TreeViewRenderedRowVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewRenderedRowVec_delete(object: &mut AzTreeViewRenderedRowVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `VirtualListMeasuredRow`
+pub type AzVirtualListMeasuredRowVecTT = crate::widgets::virtual_list::VirtualListMeasuredRowVec;
+pub use AzVirtualListMeasuredRowVecTT as AzVirtualListMeasuredRowVec;
+/// Destructor: Takes ownership of the `VirtualListMeasuredRowVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzVirtualListMeasuredRowVec_delete(object: &mut AzVirtualListMeasuredRowVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `CssDeclaration`
 pub type AzCssDeclarationVecTT = azul_impl::css::CssDeclarationVec;
 pub use AzCssDeclarationVecTT as AzCssDeclarationVec;
@@ -3670,6 +4542,11 @@ pub type AzXmlNodeVecDestructorTT = azul_impl::xml::XmlNodeVecDestructor;
 pub use AzXmlNodeVecDestructorTT as AzXmlNodeVecDestructor;
 
 pub type AzXmlNodeVecDestructorType = extern "C" fn(&mut AzXmlNodeVec);
+/// Re-export of rust-allocated (stack based) `XmlSpanVecDestructor` struct
+pub type AzXmlSpanVecDestructorTT = azul_impl::xml::XmlSpanVecDestructor;
+pub use AzXmlSpanVecDestructorTT as AzXmlSpanVecDestructor;
+
+pub type AzXmlSpanVecDestructorType = extern "C" fn(&mut AzXmlSpanVec);
 /// Re-export of rust-allocated (stack based) `FmtArgVecDestructor` struct
 pub type AzFmtArgVecDestructorTT = azul_impl::str::FmtArgVecDestructor;
 pub use AzFmtArgVecDestructorTT as AzFmtArgVecDestructor;
@@ -3800,6 +4677,51 @@ pub type AzScanCodeVecDestructorTT = azul_core::window::ScanCodeVecDestructor;
 pub use AzScanCodeVecDestructorTT as AzScanCodeVecDestructor;
 
 pub type AzScanCodeVecDestructorType = extern "C" fn(&mut AzScanCodeVec);
+/// Re-export of rust-allocated (stack based) `TextInputValidatorVecDestructor` struct
+pub type AzTextInputValidatorVecDestructorTT = crate::widgets::text_input::TextInputValidatorVecDestructor;
+pub use AzTextInputValidatorVecDestructorTT as AzTextInputValidatorVecDestructor;
+
+pub type AzTextInputValidatorVecDestructorType = extern "C" fn(&mut AzTextInputValidatorVec);
+/// Re-export of rust-allocated (stack based) `DropDownChoiceVecDestructor` struct
+pub type AzDropDownChoiceVecDestructorTT = crate::widgets::drop_down::DropDownChoiceVecDestructor;
+pub use AzDropDownChoiceVecDestructorTT as AzDropDownChoiceVecDestructor;
+
+pub type AzDropDownChoiceVecDestructorType = extern "C" fn(&mut AzDropDownChoiceVec);
+/// Re-export of rust-allocated (stack based) `TabContainerTabVecDestructor` struct
+pub type AzTabContainerTabVecDestructorTT = crate::widgets::tab_container::TabContainerTabVecDestructor;
+pub use AzTabContainerTabVecDestructorTT as AzTabContainerTabVecDestructor;
+
+pub type AzTabContainerTabVecDestructorType = extern "C" fn(&mut AzTabContainerTabVec);
+/// Re-export of rust-allocated (stack based) `TextEditUndoEntryVecDestructor` struct
+pub type AzTextEditUndoEntryVecDestructorTT = crate::widgets::text_edit::TextEditUndoEntryVecDestructor;
+pub use AzTextEditUndoEntryVecDestructorTT as AzTextEditUndoEntryVecDestructor;
+
+pub type AzTextEditUndoEntryVecDestructorType = extern "C" fn(&mut AzTextEditUndoEntryVec);
+/// Re-export of rust-allocated (stack based) `TreeViewNodeDataVecDestructor` struct
+pub type AzTreeViewNodeDataVecDestructorTT = crate::widgets::tree_view::TreeViewNodeDataVecDestructor;
+pub use AzTreeViewNodeDataVecDestructorTT as AzTreeViewNodeDataVecDestructor;
+
+pub type AzTreeViewNodeDataVecDestructorType = extern "C" fn(&mut AzTreeViewNodeDataVec);
+/// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
+pub type AzTreeViewNodeVecDestructorTT = crate::widgets::tree_view::TreeViewNodeVecDestructor;
+pub use AzTreeViewNodeVecDestructorTT as AzTreeViewNodeVecDestructor;
+
+pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
+/// Re-export of rust-allocated (stack based) `TreeViewRowVecDestructor` struct
+pub type AzTreeViewRowVecDestructorTT = crate::widgets::tree_view::TreeViewRowVecDestructor;
+pub use AzTreeViewRowVecDestructorTT as AzTreeViewRowVecDestructor;
+
+pub type AzTreeViewRowVecDestructorType = extern "C" fn(&mut AzTreeViewRowVec);
+/// Re-export of rust-allocated (stack based) `TreeViewRenderedRowVecDestructor` struct
+pub type AzTreeViewRenderedRowVecDestructorTT = crate::widgets::tree_view::TreeViewRenderedRowVecDestructor;
+pub use AzTreeViewRenderedRowVecDestructorTT as AzTreeViewRenderedRowVecDestructor;
+
+pub type AzTreeViewRenderedRowVecDestructorType = extern "C" fn(&mut AzTreeViewRenderedRowVec);
+/// Re-export of rust-allocated (stack based) `VirtualListMeasuredRowVecDestructor` struct
+pub type AzVirtualListMeasuredRowVecDestructorTT = crate::widgets::virtual_list::VirtualListMeasuredRowVecDestructor;
+pub use AzVirtualListMeasuredRowVecDestructorTT as AzVirtualListMeasuredRowVecDestructor;
+
+pub type AzVirtualListMeasuredRowVecDestructorType = extern "C" fn(&mut AzVirtualListMeasuredRowVec);
 /// Re-export of rust-allocated (stack based) `CssDeclarationVecDestructor` struct
 pub type AzCssDeclarationVecDestructorTT = azul_impl::css::CssDeclarationVecDestructor;
 pub use AzCssDeclarationVecDestructorTT as AzCssDeclarationVecDestructor;
@@ -3945,6 +4867,96 @@ pub use AzOptionTextInputOnFocusLostTT as AzOptionTextInputOnFocusLost;
 pub type AzOptionTextInputSelectionTT = crate::widgets::text_input::OptionTextInputSelection;
 pub use AzOptionTextInputSelectionTT as AzOptionTextInputSelection;
 
+/// Re-export of rust-allocated (stack based) `OptionTextInputMask` struct
+pub type AzOptionTextInputMaskTT = crate::widgets::text_input::OptionTextInputMask;
+pub use AzOptionTextInputMaskTT as AzOptionTextInputMask;
+/// Destructor: Takes ownership of the `OptionTextInputMask` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTextInputMask_delete(object: &mut AzOptionTextInputMask) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTextInputOnValidationChange` struct
+pub type AzOptionTextInputOnValidationChangeTT = crate::widgets::text_input::OptionTextInputOnValidationChange;
+pub use AzOptionTextInputOnValidationChangeTT as AzOptionTextInputOnValidationChange;
+/// Destructor: Takes ownership of the `OptionTextInputOnValidationChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTextInputOnValidationChange_delete(object: &mut AzOptionTextInputOnValidationChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+pub type AzOptionDropDownOnChoiceChangeTT = crate::widgets::drop_down::OptionDropDownOnChoiceChange;
+pub use AzOptionDropDownOnChoiceChangeTT as AzOptionDropDownOnChoiceChange;
+/// Destructor: Takes ownership of the `OptionDropDownOnChoiceChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDropDownOnChoiceChange_delete(object: &mut AzOptionDropDownOnChoiceChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabChange` struct
+pub type AzOptionTabContainerOnTabChangeTT = crate::widgets::tab_container::OptionTabContainerOnTabChange;
+pub use AzOptionTabContainerOnTabChangeTT as AzOptionTabContainerOnTabChange;
+/// Destructor: Takes ownership of the `OptionTabContainerOnTabChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTabContainerOnTabChange_delete(object: &mut AzOptionTabContainerOnTabChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabClose` struct
+pub type AzOptionTabContainerOnTabCloseTT = crate::widgets::tab_container::OptionTabContainerOnTabClose;
+pub use AzOptionTabContainerOnTabCloseTT as AzOptionTabContainerOnTabClose;
+/// Destructor: Takes ownership of the `OptionTabContainerOnTabClose` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTabContainerOnTabClose_delete(object: &mut AzOptionTabContainerOnTabClose) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabMove` struct
+pub type AzOptionTabContainerOnTabMoveTT = crate::widgets::tab_container::OptionTabContainerOnTabMove;
+pub use AzOptionTabContainerOnTabMoveTT as AzOptionTabContainerOnTabMove;
+/// Destructor: Takes ownership of the `OptionTabContainerOnTabMove` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTabContainerOnTabMove_delete(object: &mut AzOptionTabContainerOnTabMove) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionSplitPaneOnResize` struct
+pub type AzOptionSplitPaneOnResizeTT = crate::widgets::split_pane::OptionSplitPaneOnResize;
+pub use AzOptionSplitPaneOnResizeTT as AzOptionSplitPaneOnResize;
+/// Destructor: Takes ownership of the `OptionSplitPaneOnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionSplitPaneOnResize_delete(object: &mut AzOptionSplitPaneOnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+pub type AzOptionSliderOnValueChangeTT = crate::widgets::slider::OptionSliderOnValueChange;
+pub use AzOptionSliderOnValueChangeTT as AzOptionSliderOnValueChange;
+/// Destructor: Takes ownership of the `OptionSliderOnValueChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionSliderOnValueChange_delete(object: &mut AzOptionSliderOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+pub type AzOptionTextEditOnTextChangeTT = crate::widgets::text_edit::OptionTextEditOnTextChange;
+pub use AzOptionTextEditOnTextChangeTT as AzOptionTextEditOnTextChange;
+/// Destructor: Takes ownership of the `OptionTextEditOnTextChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTextEditOnTextChange_delete(object: &mut AzOptionTextEditOnTextChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditCursor` struct
+pub type AzOptionTextEditCursorTT = crate::widgets::text_edit::OptionTextEditCursor;
+pub use AzOptionTextEditCursorTT as AzOptionTextEditCursor;
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnLoadChildren` struct
+pub type AzOptionTreeViewOnLoadChildrenTT = crate::widgets::tree_view::OptionTreeViewOnLoadChildren;
+pub use AzOptionTreeViewOnLoadChildrenTT as AzOptionTreeViewOnLoadChildren;
+/// Destructor: Takes ownership of the `OptionTreeViewOnLoadChildren` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnLoadChildren_delete(object: &mut AzOptionTreeViewOnLoadChildren) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelectionChange` struct
+pub type AzOptionTreeViewOnSelectionChangeTT = crate::widgets::tree_view::OptionTreeViewOnSelectionChange;
+pub use AzOptionTreeViewOnSelectionChangeTT as AzOptionTreeViewOnSelectionChange;
+/// Destructor: Takes ownership of the `OptionTreeViewOnSelectionChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnSelectionChange_delete(object: &mut AzOptionTreeViewOnSelectionChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnNodeMoved` struct
+pub type AzOptionTreeViewOnNodeMovedTT = crate::widgets::tree_view::OptionTreeViewOnNodeMoved;
+pub use AzOptionTreeViewOnNodeMovedTT as AzOptionTreeViewOnNodeMoved;
+/// Destructor: Takes ownership of the `OptionTreeViewOnNodeMoved` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnNodeMoved_delete(object: &mut AzOptionTreeViewOnNodeMoved) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewDropTarget` struct
+pub type AzOptionTreeViewDropTargetTT = crate::widgets::tree_view::OptionTreeViewDropTarget;
+pub use AzOptionTreeViewDropTargetTT as AzOptionTreeViewDropTarget;
+
+/// Re-export of rust-allocated (stack based) `OptionVirtualListOnScroll` struct
+pub type AzOptionVirtualListOnScrollTT = crate::widgets::virtual_list::OptionVirtualListOnScroll;
+pub use AzOptionVirtualListOnScrollTT as AzOptionVirtualListOnScroll;
+/// Destructor: Takes ownership of the `OptionVirtualListOnScroll` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionVirtualListOnScroll_delete(object: &mut AzOptionVirtualListOnScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionVirtualListIFrameNodes` struct
+pub type AzOptionVirtualListIFrameNodesTT = crate::widgets::virtual_list::OptionVirtualListIFrameNodes;
+pub use AzOptionVirtualListIFrameNodesTT as AzOptionVirtualListIFrameNodes;
+
 /// Re-export of rust-allocated (stack based) `OptionNumberInputOnValueChange` struct
 pub type AzOptionNumberInputOnValueChangeTT = crate::widgets::number_input::OptionNumberInputOnValueChange;
 pub use AzOptionNumberInputOnValueChangeTT as AzOptionNumberInputOnValueChange;
@@ -4005,6 +5017,18 @@ pub use AzOptionImageRefTT as AzOptionImageRef;
 /// Destructor: Takes ownership of the `OptionImageRef` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionImageRef_delete(object: &mut AzOptionImageRef) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionDragData` struct
+pub type AzOptionDragDataTT = azul_impl::window::OptionDragData;
+pub use AzOptionDragDataTT as AzOptionDragData;
+/// Destructor: Takes ownership of the `OptionDragData` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDragData_delete(object: &mut AzOptionDragData) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+pub type AzOptionImeCompositionTT = azul_impl::window::OptionImeComposition;
+pub use AzOptionImeCompositionTT as AzOptionImeComposition;
+/// Destructor: Takes ownership of the `OptionImeComposition` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionImeComposition_delete(object: &mut AzOptionImeComposition) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionFontRef` struct
 pub type AzOptionFontRefTT = azul_impl::css::OptionFontRef;
 pub use AzOptionFontRefTT as AzOptionFontRef;
@@ -5093,6 +6117,13 @@ mod test_sizes {
         TouchMove,
         TouchEnd,
         TouchCancel,
+        DragStart,
+        DragEnter,
+        DragOver,
+        DragLeave,
+        Drop,
+        DragEnd,
+        ImeComposition,
     }
 
     /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -5117,6 +6148,7 @@ mod test_sizes {
         VirtualKeyUp,
         FocusReceived,
         FocusLost,
+        ImeComposition,
     }
 
     /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -5152,6 +6184,11 @@ mod test_sizes {
         FocusLost,
         CloseRequested,
         ThemeChanged,
+        DragStart,
+        DragOver,
+        Drop,
+        DragEnd,
+        ImeComposition,
     }
 
     /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -5269,6 +6306,14 @@ mod test_sizes {
         NoKeyboardFocus,
     }
 
+    /// Re-export of rust-allocated (stack based) `RovingFocusOrientation` struct
+    #[repr(C)]
+    pub enum AzRovingFocusOrientation {
+        Horizontal,
+        Vertical,
+        Both,
+    }
+
     /// Determines whether this context menu should pop up on a left, right or middle click
     #[repr(C)]
     pub enum AzContextMenuMouseButton {
@@ -5647,6 +6692,15 @@ mod test_sizes {
         pub checked: bool,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+    #[repr(C)]
+    pub struct AzDropDownOnChoiceChangeCallback {
+        pub cb: AzDropDownOnChoiceChangeCallbackType,
+    }
+
+    /// `AzDropDownOnChoiceChangeCallbackType` struct
+    pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `ColorInputOnValueChangeCallback` struct
     #[repr(C)]
     pub struct AzColorInputOnValueChangeCallback {
@@ -5697,6 +6751,24 @@ mod test_sizes {
         No,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextInputValidatorCallback` struct
+    #[repr(C)]
+    pub struct AzTextInputValidatorCallback {
+        pub cb: AzTextInputValidatorCallbackType,
+    }
+
+    /// `AzTextInputValidatorCallbackType` struct
+    pub type AzTextInputValidatorCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState) -> AzTextInputValidationResult;
+
+    /// Re-export of rust-allocated (stack based) `TextInputOnValidationChangeCallback` struct
+    #[repr(C)]
+    pub struct AzTextInputOnValidationChangeCallback {
+        pub cb: AzTextInputOnValidationChangeCallbackType,
+    }
+
+    /// `AzTextInputOnValidationChangeCallbackType` struct
+    pub type AzTextInputOnValidationChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `NumberInputState` struct
     #[repr(C)]
     pub struct AzNumberInputState {
@@ -5722,6 +6794,210 @@ mod test_sizes {
         pub display_percentage: bool,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPaneOrientation` struct
+    #[repr(C)]
+    pub enum AzSplitPaneOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitPaneCollapsed` struct
+    #[repr(C)]
+    pub enum AzSplitPaneCollapsed {
+        None,
+        First,
+        Second,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitPaneOnResizeCallback` struct
+    #[repr(C)]
+    pub struct AzSplitPaneOnResizeCallback {
+        pub cb: AzSplitPaneOnResizeCallbackType,
+    }
+
+    /// `AzSplitPaneOnResizeCallbackType` struct
+    pub type AzSplitPaneOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSplitPaneState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// What happens if the tabs don't fit into the header
+    #[repr(C)]
+    pub enum AzTabContainerOverflow {
+        Scroll,
+        Menu,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabChangeCallback` struct
+    #[repr(C)]
+    pub struct AzTabContainerOnTabChangeCallback {
+        pub cb: AzTabContainerOnTabChangeCallbackType,
+    }
+
+    /// `AzTabContainerOnTabChangeCallbackType` struct
+    pub type AzTabContainerOnTabChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabCloseCallback` struct
+    #[repr(C)]
+    pub struct AzTabContainerOnTabCloseCallback {
+        pub cb: AzTabContainerOnTabCloseCallbackType,
+    }
+
+    /// `AzTabContainerOnTabCloseCallbackType` struct
+    pub type AzTabContainerOnTabCloseCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, AzTabContainerTab, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabMoveCallback` struct
+    #[repr(C)]
+    pub struct AzTabContainerOnTabMoveCallback {
+        pub cb: AzTabContainerOnTabMoveCallbackType,
+    }
+
+    /// `AzTabContainerOnTabMoveCallbackType` struct
+    pub type AzTabContainerOnTabMoveCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, usize, usize, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+    #[repr(C)]
+    pub enum AzSliderOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Identifies one of the thumbs, a non-range slider only has the `Lower` thumb
+    #[repr(C)]
+    pub enum AzSliderThumb {
+        Lower,
+        Upper,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+    #[repr(C)]
+    pub struct AzSliderOnValueChangeCallback {
+        pub cb: AzSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzSliderOnValueChangeCallbackType` struct
+    pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Position in the text: line index and character (not byte) index in the line
+    #[repr(C)]
+    pub struct AzTextEditCursor {
+        pub line: usize,
+        pub column: usize,
+    }
+
+    /// Kind of an edit, consecutive edits of the same kind are undone together
+    #[repr(C)]
+    pub enum AzTextEditEditKind {
+        Insert,
+        DeleteBackward,
+        DeleteForward,
+        Other,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+    #[repr(C)]
+    pub struct AzTextEditOnTextChangeCallback {
+        pub cb: AzTextEditOnTextChangeCallbackType,
+    }
+
+    /// `AzTextEditOnTextChangeCallbackType` struct
+    pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Whether a node has children and whether they have been loaded yet
+    #[repr(C)]
+    pub enum AzTreeViewChildren {
+        None,
+        NotLoaded,
+        Loading,
+        Loaded,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+    #[repr(C)]
+    pub enum AzTreeViewSelectionMode {
+        None,
+        Single,
+        Multiple,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewDropPosition` struct
+    #[repr(C)]
+    pub enum AzTreeViewDropPosition {
+        Before,
+        After,
+        Into,
+    }
+
+    /// Visible node: all ancestors of the node are expanded
+    #[repr(C)]
+    pub struct AzTreeViewRow {
+        pub node: usize,
+        pub depth: usize,
+    }
+
+    /// Payload of a drag that was started on a node, can be used by other widgets to accept nodes that are dragged out of the tree
+    #[repr(C)]
+    pub struct AzTreeViewDragPayload {
+        pub node: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildrenCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnLoadChildrenCallback {
+        pub cb: AzTreeViewOnLoadChildrenCallbackType,
+    }
+
+    /// `AzTreeViewOnLoadChildrenCallbackType` struct
+    pub type AzTreeViewOnLoadChildrenCallbackType = extern "C" fn(&mut AzRefAny, &mut AzTreeViewState, AzTreeViewLoadRequest, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChangeCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnSelectionChangeCallback {
+        pub cb: AzTreeViewOnSelectionChangeCallbackType,
+    }
+
+    /// `AzTreeViewOnSelectionChangeCallbackType` struct
+    pub type AzTreeViewOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnNodeMovedCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnNodeMovedCallback {
+        pub cb: AzTreeViewOnNodeMovedCallbackType,
+    }
+
+    /// `AzTreeViewOnNodeMovedCallbackType` struct
+    pub type AzTreeViewOnNodeMovedCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, usize, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Height of the rows of a `VirtualList`
+    #[repr(C, u8)]
+    pub enum AzVirtualListRowHeight {
+        Fixed(f32),
+        Estimated(f32),
+    }
+
+    /// Measured height of a row (only used with `VirtualListRowHeight::Estimated`)
+    #[repr(C)]
+    pub struct AzVirtualListMeasuredRow {
+        pub row: usize,
+        pub height: f32,
+        pub delta_before: f64,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListRenderRowCallback` struct
+    #[repr(C)]
+    pub struct AzVirtualListRenderRowCallback {
+        pub cb: AzVirtualListRenderRowCallbackType,
+    }
+
+    /// `AzVirtualListRenderRowCallbackType` struct
+    pub type AzVirtualListRenderRowCallbackType = extern "C" fn(&mut AzRefAny, usize) -> AzStyledDom;
+
+    /// Re-export of rust-allocated (stack based) `VirtualListOnScrollCallback` struct
+    #[repr(C)]
+    pub struct AzVirtualListOnScrollCallback {
+        pub cb: AzVirtualListOnScrollCallbackType,
+    }
+
+    /// `AzVirtualListOnScrollCallbackType` struct
+    pub type AzVirtualListOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &AzVirtualListState, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     pub struct AzNode {
@@ -5745,6 +7021,9 @@ mod test_sizes {
         pub hover: bool,
         pub active: bool,
         pub focused: bool,
+        pub focus_visible: bool,
+        pub focus_within: bool,
+        pub drag_over: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `TagId` struct
@@ -6353,6 +7632,17 @@ mod test_sizes {
     /// `AzXmlNodeVecDestructorType` struct
     pub type AzXmlNodeVecDestructorType = extern "C" fn(&mut AzXmlNodeVec);
 
+    /// Re-export of rust-allocated (stack based) `XmlSpanVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzXmlSpanVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzXmlSpanVecDestructorType),
+    }
+
+    /// `AzXmlSpanVecDestructorType` struct
+    pub type AzXmlSpanVecDestructorType = extern "C" fn(&mut AzXmlSpanVec);
+
     /// Re-export of rust-allocated (stack based) `FmtArgVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzFmtArgVecDestructor {
@@ -6639,6 +7929,105 @@ mod test_sizes {
     /// `AzScanCodeVecDestructorType` struct
     pub type AzScanCodeVecDestructorType = extern "C" fn(&mut AzScanCodeVec);
 
+    /// Re-export of rust-allocated (stack based) `TextInputValidatorVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTextInputValidatorVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextInputValidatorVecDestructorType),
+    }
+
+    /// `AzTextInputValidatorVecDestructorType` struct
+    pub type AzTextInputValidatorVecDestructorType = extern "C" fn(&mut AzTextInputValidatorVec);
+
+    /// Re-export of rust-allocated (stack based) `DropDownChoiceVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzDropDownChoiceVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzDropDownChoiceVecDestructorType),
+    }
+
+    /// `AzDropDownChoiceVecDestructorType` struct
+    pub type AzDropDownChoiceVecDestructorType = extern "C" fn(&mut AzDropDownChoiceVec);
+
+    /// Re-export of rust-allocated (stack based) `TabContainerTabVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTabContainerTabVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTabContainerTabVecDestructorType),
+    }
+
+    /// `AzTabContainerTabVecDestructorType` struct
+    pub type AzTabContainerTabVecDestructorType = extern "C" fn(&mut AzTabContainerTabVec);
+
+    /// Re-export of rust-allocated (stack based) `TextEditUndoEntryVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTextEditUndoEntryVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextEditUndoEntryVecDestructorType),
+    }
+
+    /// `AzTextEditUndoEntryVecDestructorType` struct
+    pub type AzTextEditUndoEntryVecDestructorType = extern "C" fn(&mut AzTextEditUndoEntryVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeDataVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTreeViewNodeDataVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewNodeDataVecDestructorType),
+    }
+
+    /// `AzTreeViewNodeDataVecDestructorType` struct
+    pub type AzTreeViewNodeDataVecDestructorType = extern "C" fn(&mut AzTreeViewNodeDataVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTreeViewNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewNodeVecDestructorType),
+    }
+
+    /// `AzTreeViewNodeVecDestructorType` struct
+    pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewRowVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTreeViewRowVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewRowVecDestructorType),
+    }
+
+    /// `AzTreeViewRowVecDestructorType` struct
+    pub type AzTreeViewRowVecDestructorType = extern "C" fn(&mut AzTreeViewRowVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewRenderedRowVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTreeViewRenderedRowVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewRenderedRowVecDestructorType),
+    }
+
+    /// `AzTreeViewRenderedRowVecDestructorType` struct
+    pub type AzTreeViewRenderedRowVecDestructorType = extern "C" fn(&mut AzTreeViewRenderedRowVec);
+
+    /// Re-export of rust-allocated (stack based) `VirtualListMeasuredRowVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzVirtualListMeasuredRowVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzVirtualListMeasuredRowVecDestructorType),
+    }
+
+    /// `AzVirtualListMeasuredRowVecDestructorType` struct
+    pub type AzVirtualListMeasuredRowVecDestructorType = extern "C" fn(&mut AzVirtualListMeasuredRowVec);
+
     /// Re-export of rust-allocated (stack based) `CssDeclarationVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzCssDeclarationVecDestructor {
@@ -7134,6 +8523,13 @@ mod test_sizes {
         Focus(AzFocusEventFilter),
     }
 
+    /// Configures the arrow key navigation of a `FocusScope::Roving`
+    #[repr(C)]
+    pub struct AzRovingFocus {
+        pub orientation: AzRovingFocusOrientation,
+        pub wrap: bool,
+    }
+
     /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
     #[repr(C)]
     pub struct AzMenuCallback {
@@ -8208,6 +9604,13 @@ mod test_sizes {
         pub callback: AzCheckBoxOnToggleCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+    #[repr(C)]
+    pub struct AzDropDownOnChoiceChange {
+        pub data: AzRefAny,
+        pub callback: AzDropDownOnChoiceChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ColorInputState` struct
     #[repr(C)]
     pub struct AzColorInputState {
@@ -8256,6 +9659,20 @@ mod test_sizes {
         pub valid: AzTextInputValid,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextInputCustomValidator` struct
+    #[repr(C)]
+    pub struct AzTextInputCustomValidator {
+        pub data: AzRefAny,
+        pub callback: AzTextInputValidatorCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextInputOnValidationChange` struct
+    #[repr(C)]
+    pub struct AzTextInputOnValidationChange {
+        pub data: AzRefAny,
+        pub callback: AzTextInputOnValidationChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
     #[repr(C)]
     pub struct AzNumberInputOnValueChange {
@@ -8263,6 +9680,121 @@ mod test_sizes {
         pub callback: AzNumberInputOnValueChangeCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPaneState` struct
+    #[repr(C)]
+    pub struct AzSplitPaneState {
+        pub orientation: AzSplitPaneOrientation,
+        pub ratio: f32,
+        pub min_first_size: f32,
+        pub min_second_size: f32,
+        pub collapsible: bool,
+        pub collapsed: AzSplitPaneCollapsed,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitPaneOnResize` struct
+    #[repr(C)]
+    pub struct AzSplitPaneOnResize {
+        pub data: AzRefAny,
+        pub callback: AzSplitPaneOnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabChange` struct
+    #[repr(C)]
+    pub struct AzTabContainerOnTabChange {
+        pub data: AzRefAny,
+        pub callback: AzTabContainerOnTabChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabClose` struct
+    #[repr(C)]
+    pub struct AzTabContainerOnTabClose {
+        pub data: AzRefAny,
+        pub callback: AzTabContainerOnTabCloseCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerOnTabMove` struct
+    #[repr(C)]
+    pub struct AzTabContainerOnTabMove {
+        pub data: AzRefAny,
+        pub callback: AzTabContainerOnTabMoveCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderState` struct
+    #[repr(C)]
+    pub struct AzSliderState {
+        pub value: f32,
+        pub upper_value: AzOptionF32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
+        pub orientation: AzSliderOrientation,
+        pub tick_interval: AzOptionF32,
+        pub active_thumb: AzSliderThumb,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+    #[repr(C)]
+    pub struct AzSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzSliderOnValueChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+    #[repr(C)]
+    pub struct AzTextEditOnTextChange {
+        pub data: AzRefAny,
+        pub callback: AzTextEditOnTextChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewDropTarget` struct
+    #[repr(C)]
+    pub struct AzTreeViewDropTarget {
+        pub node: usize,
+        pub position: AzTreeViewDropPosition,
+    }
+
+    /// Passed to the `on_load_children` callback
+    #[repr(C)]
+    pub struct AzTreeViewLoadRequest {
+        pub node: usize,
+        pub tree: AzRefAny,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildren` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnLoadChildren {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnLoadChildrenCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChange` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnSelectionChange {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnSelectionChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnNodeMoved` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnNodeMoved {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnNodeMovedCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListRenderRow` struct
+    #[repr(C)]
+    pub struct AzVirtualListRenderRow {
+        pub data: AzRefAny,
+        pub callback: AzVirtualListRenderRowCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListOnScroll` struct
+    #[repr(C)]
+    pub struct AzVirtualListOnScroll {
+        pub data: AzRefAny,
+        pub callback: AzVirtualListOnScrollCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
     #[repr(C)]
     pub struct AzParentWithNodeDepth {
@@ -8396,7 +9928,14 @@ mod test_sizes {
         pub high_quality_aa: bool,
     }
 
-    /// Re-export of rust-allocated (stack based) `InstantPtr` struct
+    /// Start and end position of a node, attribute or text in the XML source
+    #[repr(C)]
+    pub struct AzXmlSpan {
+        pub start: AzSvgParseErrorPosition,
+        pub end: AzSvgParseErrorPosition,
+    }
+
+    /// Re-export of rust-allocated (stack based) `InstantPtr` struct
     #[repr(C)]
     pub struct AzInstantPtr {
         pub(crate) ptr: *const c_void,
@@ -8453,6 +9992,15 @@ mod test_sizes {
         pub destructor: AzXmlNodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<XmlSpan>`
+    #[repr(C)]
+    pub struct AzXmlSpanVec {
+        pub(crate) ptr: *const AzXmlSpan,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzXmlSpanVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<InlineGlyph>`
     #[repr(C)]
     pub struct AzInlineGlyphVec {
@@ -8570,6 +10118,24 @@ mod test_sizes {
         pub destructor: AzScanCodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `TreeViewRow`
+    #[repr(C)]
+    pub struct AzTreeViewRowVec {
+        pub(crate) ptr: *const AzTreeViewRow,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewRowVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `VirtualListMeasuredRow`
+    #[repr(C)]
+    pub struct AzVirtualListMeasuredRowVec {
+        pub(crate) ptr: *const AzVirtualListMeasuredRow,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzVirtualListMeasuredRowVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<u16>`
     #[repr(C)]
     pub struct AzU16Vec {
@@ -8709,6 +10275,104 @@ mod test_sizes {
         Some(AzTextInputSelection),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextInputOnValidationChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextInputOnValidationChange {
+        None,
+        Some(AzTextInputOnValidationChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDropDownOnChoiceChange {
+        None,
+        Some(AzDropDownOnChoiceChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTabContainerOnTabChange {
+        None,
+        Some(AzTabContainerOnTabChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabClose` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTabContainerOnTabClose {
+        None,
+        Some(AzTabContainerOnTabClose),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabMove` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTabContainerOnTabMove {
+        None,
+        Some(AzTabContainerOnTabMove),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSplitPaneOnResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSplitPaneOnResize {
+        None,
+        Some(AzSplitPaneOnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSliderOnValueChange {
+        None,
+        Some(AzSliderOnValueChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextEditOnTextChange {
+        None,
+        Some(AzTextEditOnTextChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditCursor` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextEditCursor {
+        None,
+        Some(AzTextEditCursor),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnLoadChildren` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnLoadChildren {
+        None,
+        Some(AzTreeViewOnLoadChildren),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelectionChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnSelectionChange {
+        None,
+        Some(AzTreeViewOnSelectionChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnNodeMoved` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnNodeMoved {
+        None,
+        Some(AzTreeViewOnNodeMoved),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewDropTarget` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewDropTarget {
+        None,
+        Some(AzTreeViewDropTarget),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionVirtualListOnScroll` struct
+    #[repr(C, u8)]
+    pub enum AzOptionVirtualListOnScroll {
+        None,
+        Some(AzVirtualListOnScroll),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionNumberInputOnValueChange` struct
     #[repr(C, u8)]
     pub enum AzOptionNumberInputOnValueChange {
@@ -9006,6 +10670,7 @@ mod test_sizes {
         pub enable_visual_panic_hook: bool,
         pub enable_logging_on_panic: bool,
         pub enable_tab_navigation: bool,
+        pub record_events: bool,
         pub system_callbacks: AzSystemCallbacks,
     }
 
@@ -9048,19 +10713,6 @@ mod test_sizes {
         pub max_dimensions: AzOptionLogicalSize,
     }
 
-    /// Current keyboard state, stores what keys / characters have been pressed
-    #[repr(C)]
-    pub struct AzKeyboardState {
-        pub shift_down: bool,
-        pub ctrl_down: bool,
-        pub alt_down: bool,
-        pub super_down: bool,
-        pub current_char: AzOptionChar,
-        pub current_virtual_keycode: AzOptionVirtualKeyCode,
-        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-        pub pressed_scancodes: AzScanCodeVec,
-    }
-
     /// Current mouse / cursor state
     #[repr(C)]
     pub struct AzMouseState {
@@ -9088,6 +10740,14 @@ mod test_sizes {
         pub bounds: AzLogicalRect,
     }
 
+    /// Data of an in-app drag operation, created by `CallbackInfo::start_drag`
+    #[repr(C)]
+    pub struct AzDragData {
+        pub source: AzDomNodeId,
+        pub payload: AzRefAny,
+        pub ghost: AzOptionImageRef,
+    }
+
     /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
     #[repr(C, u8)]
     pub enum AzAnimationEasing {
@@ -9139,6 +10799,13 @@ mod test_sizes {
         Application(AzApplicationEventFilter),
     }
 
+    /// Restricts the keyboard focus navigation to the subtree of a node
+    #[repr(C, u8)]
+    pub enum AzFocusScope {
+        Trap,
+        Roving(AzRovingFocus),
+    }
+
     /// Menu struct (application / window menu, dropdown menu, context menu). Modeled after the Windows API
     #[repr(C)]
     pub struct AzMenu {
@@ -9162,6 +10829,9 @@ mod test_sizes {
         Hover,
         Active,
         Focus,
+        FocusVisible,
+        FocusWithin,
+        DragOver,
     }
 
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -9275,6 +10945,18 @@ mod test_sizes {
         pub on_toggle: AzOptionCheckBoxOnToggle,
     }
 
+    /// Validator that is run on every change of the text, an empty text is only rejected by `NotEmpty`
+    #[repr(C, u8)]
+    pub enum AzTextInputValidator {
+        NotEmpty,
+        Integer,
+        Number,
+        Email,
+        Ipv4,
+        MinLength(usize),
+        Custom(AzTextInputCustomValidator),
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
     #[repr(C)]
     pub struct AzNumberInputStateWrapper {
@@ -9282,6 +10964,43 @@ mod test_sizes {
         pub on_value_change: AzOptionNumberInputOnValueChange,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPaneStateWrapper` struct
+    #[repr(C)]
+    pub struct AzSplitPaneStateWrapper {
+        pub inner: AzSplitPaneState,
+        pub on_resize: AzOptionSplitPaneOnResize,
+        pub is_dragging: bool,
+        pub drag_position: f32,
+        pub state_before_drag: AzSplitPaneState,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
+    #[repr(C)]
+    pub struct AzSliderStateWrapper {
+        pub inner: AzSliderState,
+        pub on_value_change: AzOptionSliderOnValueChange,
+        pub is_dragging: bool,
+        pub drag_value: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListState` struct
+    #[repr(C)]
+    pub struct AzVirtualListState {
+        pub row_count: usize,
+        pub row_height: AzVirtualListRowHeight,
+        pub overscan: usize,
+        pub scroll_offset: f32,
+        pub viewport_height: f32,
+        pub measured_rows: AzVirtualListMeasuredRowVec,
+    }
+
+    /// Nodes inside the IFrame that are updated in place when the list is scrolled
+    #[repr(C)]
+    pub struct AzVirtualListIFrameNodes {
+        pub rows: AzDomNodeId,
+        pub scrollbar_thumb: AzOptionDomNodeId,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyledNode` struct
     #[repr(C)]
     pub struct AzStyledNode {
@@ -9430,6 +11149,15 @@ mod test_sizes {
         pub destructor: AzSvgPathElementVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `TextInputValidator`
+    #[repr(C)]
+    pub struct AzTextInputValidatorVec {
+        pub(crate) ptr: *const AzTextInputValidator,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextInputValidatorVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `StringVec`
     #[repr(C)]
     pub struct AzStringVec {
@@ -9457,6 +11185,13 @@ mod test_sizes {
         pub destructor: AzTagIdToNodeIdMappingVecDestructor,
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionVirtualListIFrameNodes` struct
+    #[repr(C, u8)]
+    pub enum AzOptionVirtualListIFrameNodes {
+        None,
+        Some(AzVirtualListIFrameNodes),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCodeCombo` struct
     #[repr(C, u8)]
     pub enum AzOptionVirtualKeyCodeCombo {
@@ -9464,18 +11199,18 @@ mod test_sizes {
         Some(AzVirtualKeyCodeCombo),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
+    /// Re-export of rust-allocated (stack based) `OptionDragData` struct
     #[repr(C, u8)]
-    pub enum AzOptionMouseState {
+    pub enum AzOptionDragData {
         None,
-        Some(AzMouseState),
+        Some(AzDragData),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+    /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
     #[repr(C, u8)]
-    pub enum AzOptionKeyboardState {
+    pub enum AzOptionMouseState {
         None,
-        Some(AzKeyboardState),
+        Some(AzMouseState),
     }
 
     /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
@@ -9636,6 +11371,14 @@ mod test_sizes {
         pub value: AzString,
     }
 
+    /// Pre-edit text of an input method, the IME shows its candidate window at the `WindowState.ime_position`
+    #[repr(C)]
+    pub struct AzImeComposition {
+        pub text: AzString,
+        pub cursor_start: usize,
+        pub cursor_end: usize,
+    }
+
     /// Information about a single (or many) monitors, useful for dock widgets
     #[repr(C)]
     pub struct AzMonitor {
@@ -9782,6 +11525,14 @@ mod test_sizes {
         Exact(AzStyleTransformVec),
     }
 
+    /// Single selectable entry of a `DropDown`
+    #[repr(C)]
+    pub struct AzDropDownChoice {
+        pub label: AzString,
+        pub disabled: bool,
+        pub group: AzOptionString,
+    }
+
     /// Re-export of rust-allocated (stack based) `ColorInputStateWrapper` struct
     #[repr(C)]
     pub struct AzColorInputStateWrapper {
@@ -9790,14 +11541,67 @@ mod test_sizes {
         pub on_value_change: AzOptionColorInputOnValueChange,
     }
 
-    /// Re-export of rust-allocated (stack based) `TextInputState` struct
+    /// Re-export of rust-allocated (stack based) `TextInputValidationResult` struct
+    #[repr(C, u8)]
+    pub enum AzTextInputValidationResult {
+        Valid,
+        Invalid(AzString),
+    }
+
+    /// Input mask: `9` = digit, `#` = optional digit, `a` = letter, `*` = letter or digit, other characters are literals that are inserted automatically (`99/99/9999`)
     #[repr(C)]
-    pub struct AzTextInputState {
-        pub text: AzU32Vec,
-        pub placeholder: AzOptionString,
-        pub max_len: usize,
-        pub selection: AzOptionTextInputSelection,
-        pub cursor_pos: usize,
+    pub struct AzTextInputMask {
+        pub pattern: AzString,
+    }
+
+    /// Header of a single tab
+    #[repr(C)]
+    pub struct AzTabContainerTab {
+        pub title: AzString,
+        pub closable: bool,
+        pub page: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditState` struct
+    #[repr(C)]
+    pub struct AzTextEditState {
+        pub lines: AzStringVec,
+        pub cursor: AzTextEditCursor,
+        pub selection_anchor: AzOptionTextEditCursor,
+        pub preferred_column: AzOptionUsize,
+        pub first_visible_row: usize,
+        pub visible_rows: usize,
+        pub wrap_column: usize,
+    }
+
+    /// Reversible edit: `removed` was replaced by `inserted` at `start`
+    #[repr(C)]
+    pub struct AzTextEditUndoEntry {
+        pub start: AzTextEditCursor,
+        pub removed: AzString,
+        pub inserted: AzString,
+        pub cursor_before: AzTextEditCursor,
+        pub kind: AzTextEditEditKind,
+    }
+
+    /// User-facing content of a node
+    #[repr(C)]
+    pub struct AzTreeViewNodeData {
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub children: AzTreeViewChildren,
+    }
+
+    /// Content of one row of the row pool, `node` is `None` for the empty rows below the last node
+    #[repr(C)]
+    pub struct AzTreeViewRenderedRow {
+        pub node: AzOptionUsize,
+        pub depth: usize,
+        pub toggle: AzString,
+        pub icon: AzOptionImageRef,
+        pub label: AzString,
+        pub is_selected: bool,
+        pub is_cursor: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
@@ -10000,6 +11804,51 @@ mod test_sizes {
         pub destructor: AzVertexAttributeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `DropDownChoice`
+    #[repr(C)]
+    pub struct AzDropDownChoiceVec {
+        pub(crate) ptr: *const AzDropDownChoice,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzDropDownChoiceVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TabContainerTab`
+    #[repr(C)]
+    pub struct AzTabContainerTabVec {
+        pub(crate) ptr: *const AzTabContainerTab,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTabContainerTabVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TextEditUndoEntry`
+    #[repr(C)]
+    pub struct AzTextEditUndoEntryVec {
+        pub(crate) ptr: *const AzTextEditUndoEntry,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextEditUndoEntryVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TreeViewNodeData`
+    #[repr(C)]
+    pub struct AzTreeViewNodeDataVec {
+        pub(crate) ptr: *const AzTreeViewNodeData,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeDataVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `TreeViewRenderedRow`
+    #[repr(C)]
+    pub struct AzTreeViewRenderedRowVec {
+        pub(crate) ptr: *const AzTreeViewRenderedRow,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewRenderedRowVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `CssPathSelector`
     #[repr(C)]
     pub struct AzCssPathSelectorVec {
@@ -10036,6 +11885,20 @@ mod test_sizes {
         pub destructor: AzStringPairVecDestructor,
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextInputMask` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextInputMask {
+        None,
+        Some(AzTextInputMask),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+    #[repr(C, u8)]
+    pub enum AzOptionImeComposition {
+        None,
+        Some(AzImeComposition),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionFileTypeList` struct
     #[repr(C, u8)]
     pub enum AzOptionFileTypeList {
@@ -10089,6 +11952,20 @@ mod test_sizes {
         InvalidCharacterData,
     }
 
+    /// Current keyboard state, stores what keys / characters have been pressed
+    #[repr(C)]
+    pub struct AzKeyboardState {
+        pub shift_down: bool,
+        pub ctrl_down: bool,
+        pub alt_down: bool,
+        pub super_down: bool,
+        pub current_char: AzOptionChar,
+        pub current_virtual_keycode: AzOptionVirtualKeyCode,
+        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+        pub pressed_scancodes: AzScanCodeVec,
+        pub ime_composition: AzOptionImeComposition,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
     #[repr(C)]
     pub struct AzLinuxWindowOptions {
@@ -10222,15 +12099,72 @@ mod test_sizes {
         BackfaceVisibility(AzStyleBackfaceVisibilityValue),
     }
 
-    /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+    /// Re-export of rust-allocated (stack based) `DropDownState` struct
     #[repr(C)]
-    pub struct AzTextInputStateWrapper {
-        pub inner: AzTextInputState,
-        pub on_text_input: AzOptionTextInputOnTextInput,
-        pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDown,
-        pub on_focus_lost: AzOptionTextInputOnFocusLost,
-        pub update_text_input_before_calling_focus_lost_fn: bool,
-        pub update_text_input_before_calling_vk_down_fn: bool,
+    pub struct AzDropDownState {
+        pub choices: AzDropDownChoiceVec,
+        pub selected: AzOptionUsize,
+        pub highlighted: AzOptionUsize,
+        pub is_open: bool,
+        pub search: AzString,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextInputState` struct
+    #[repr(C)]
+    pub struct AzTextInputState {
+        pub text: AzU32Vec,
+        pub placeholder: AzOptionString,
+        pub max_len: usize,
+        pub selection: AzOptionTextInputSelection,
+        pub cursor_pos: usize,
+        pub validators: AzTextInputValidatorVec,
+        pub mask: AzOptionTextInputMask,
+        pub obscure_char: AzOptionChar,
+        pub validation: AzTextInputValidationResult,
+        pub ime_composition: AzOptionImeComposition,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerState` struct
+    #[repr(C)]
+    pub struct AzTabContainerState {
+        pub tabs: AzTabContainerTabVec,
+        pub active_tab: AzOptionUsize,
+        pub scroll_offset: f32,
+        pub overflow: AzTabContainerOverflow,
+        pub menu_open: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+    #[repr(C)]
+    pub struct AzTextEditStateWrapper {
+        pub inner: AzTextEditState,
+        pub on_text_change: AzOptionTextEditOnTextChange,
+        pub undo_stack: AzTextEditUndoEntryVec,
+        pub redo_stack: AzTextEditUndoEntryVec,
+        pub wrap_lines: bool,
+        pub char_width: f32,
+        pub rendered_rows: AzStringVec,
+        pub is_selecting: bool,
+    }
+
+    /// Node in the arena of the `TreeViewState`, the links are indices into `TreeViewState::nodes`
+    #[repr(C)]
+    pub struct AzTreeViewNode {
+        pub data: AzTreeViewNodeData,
+        pub parent: AzOptionUsize,
+        pub first_child: AzOptionUsize,
+        pub last_child: AzOptionUsize,
+        pub previous_sibling: AzOptionUsize,
+        pub next_sibling: AzOptionUsize,
+        pub is_expanded: bool,
+        pub is_selected: bool,
+    }
+
+    /// Children that were loaded on a background thread
+    #[repr(C)]
+    pub struct AzTreeViewLoadedChildren {
+        pub node: usize,
+        pub children: AzTreeViewNodeDataVec,
     }
 
     /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
@@ -10278,6 +12212,9 @@ mod test_sizes {
         pub attributes: AzStringPairVec,
         pub children: AzXmlNodeVec,
         pub text: AzOptionString,
+        pub span: AzXmlSpan,
+        pub attribute_spans: AzXmlSpanVec,
+        pub text_span: AzXmlSpan,
     }
 
     /// Wrapper over a Rust-allocated `Vec<InlineLine>`
@@ -10307,6 +12244,15 @@ mod test_sizes {
         pub destructor: AzSvgMultiPolygonVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `TreeViewNode`
+    #[repr(C)]
+    pub struct AzTreeViewNodeVec {
+        pub(crate) ptr: *const AzTreeViewNode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
     #[repr(C, u8)]
     pub enum AzOptionCssProperty {
@@ -10314,6 +12260,13 @@ mod test_sizes {
         Some(AzCssProperty),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+    #[repr(C, u8)]
+    pub enum AzOptionKeyboardState {
+        None,
+        Some(AzKeyboardState),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlTextError` struct
     #[repr(C)]
     pub struct AzXmlTextError {
@@ -10374,6 +12327,7 @@ mod test_sizes {
         pub datasets: *mut c_void,
         pub stop_propagation: *mut bool,
         pub focus_target: *mut c_void,
+        pub drag_started: *mut c_void,
         pub words_cache: *const c_void,
         pub shaped_words_cache: *const c_void,
         pub positioned_words_cache: *const c_void,
@@ -10448,6 +12402,55 @@ mod test_sizes {
         pub default_value: AzCssProperty,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+    #[repr(C)]
+    pub struct AzDropDownStateWrapper {
+        pub inner: AzDropDownState,
+        pub on_choice_change: AzOptionDropDownOnChoiceChange,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+    #[repr(C)]
+    pub struct AzTextInputStateWrapper {
+        pub inner: AzTextInputState,
+        pub on_text_input: AzOptionTextInputOnTextInput,
+        pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDown,
+        pub on_focus_lost: AzOptionTextInputOnFocusLost,
+        pub update_text_input_before_calling_focus_lost_fn: bool,
+        pub update_text_input_before_calling_vk_down_fn: bool,
+        pub on_validation_change: AzOptionTextInputOnValidationChange,
+        pub error_style: AzCssPropertyVec,
+        pub style_before_error: AzCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabContainerStateWrapper` struct
+    #[repr(C)]
+    pub struct AzTabContainerStateWrapper {
+        pub inner: AzTabContainerState,
+        pub on_tab_change: AzOptionTabContainerOnTabChange,
+        pub on_tab_close: AzOptionTabContainerOnTabClose,
+        pub on_tab_move: AzOptionTabContainerOnTabMove,
+        pub allow_reorder: bool,
+        pub tab_count: usize,
+        pub pressed_tab: AzOptionUsize,
+        pub dragged_tab: AzOptionUsize,
+        pub drop_index: AzOptionUsize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewState` struct
+    #[repr(C)]
+    pub struct AzTreeViewState {
+        pub nodes: AzTreeViewNodeVec,
+        pub first_root: AzOptionUsize,
+        pub last_root: AzOptionUsize,
+        pub rows: AzTreeViewRowVec,
+        pub cursor: AzOptionUsize,
+        pub selection_anchor: AzOptionUsize,
+        pub selection_mode: AzTreeViewSelectionMode,
+        pub first_visible_row: usize,
+        pub visible_rows: usize,
+    }
+
     /// Re-export of rust-allocated (stack based) `SvgNode` struct
     #[repr(C, u8)]
     pub enum AzSvgNode {
@@ -10564,6 +12567,17 @@ mod test_sizes {
         pub content_style: AzNodeDataInlineCssPropertyVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `DropDown` struct
+    #[repr(C)]
+    pub struct AzDropDown {
+        pub state: AzDropDownStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub header_style: AzNodeDataInlineCssPropertyVec,
+        pub popup_style: AzNodeDataInlineCssPropertyVec,
+        pub choice_style: AzNodeDataInlineCssPropertyVec,
+        pub group_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `Label` struct
     #[repr(C)]
     pub struct AzLabel {
@@ -10585,6 +12599,7 @@ mod test_sizes {
         pub placeholder_style: AzNodeDataInlineCssPropertyVec,
         pub container_style: AzNodeDataInlineCssPropertyVec,
         pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub preedit_style: AzNodeDataInlineCssPropertyVec,
     }
 
     /// Re-export of rust-allocated (stack based) `NumberInput` struct
@@ -10603,6 +12618,80 @@ mod test_sizes {
         pub label_style: AzNodeDataInlineCssPropertyVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `TabContainer` struct
+    #[repr(C)]
+    pub struct AzTabContainer {
+        pub state: AzTabContainerStateWrapper,
+        pub pages: AzDomVec,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub header_style: AzNodeDataInlineCssPropertyVec,
+        pub tab_style: AzNodeDataInlineCssPropertyVec,
+        pub close_button_style: AzNodeDataInlineCssPropertyVec,
+        pub button_style: AzNodeDataInlineCssPropertyVec,
+        pub menu_style: AzNodeDataInlineCssPropertyVec,
+        pub page_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Slider` struct
+    #[repr(C)]
+    pub struct AzSlider {
+        pub state: AzSliderStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub fill_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEdit` struct
+    #[repr(C)]
+    pub struct AzTextEdit {
+        pub state: AzTextEditStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub selection_style: AzNodeDataInlineCssPropertyVec,
+        pub caret_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewStateWrapper` struct
+    #[repr(C)]
+    pub struct AzTreeViewStateWrapper {
+        pub inner: AzTreeViewState,
+        pub on_load_children: AzOptionTreeViewOnLoadChildren,
+        pub on_selection_change: AzOptionTreeViewOnSelectionChange,
+        pub on_node_moved: AzOptionTreeViewOnNodeMoved,
+        pub allow_reorder: bool,
+        pub rendered_rows: AzTreeViewRenderedRowVec,
+        pub pressed_node: AzOptionUsize,
+        pub dragged_node: AzOptionUsize,
+        pub drop_target: AzOptionTreeViewDropTarget,
+    }
+
+    /// Virtualized list: renders only the rows that are visible, so that lists with millions of rows stay cheap to lay out
+    #[repr(C)]
+    pub struct AzVirtualList {
+        pub state: AzRefAny,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_track_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `VirtualListStateWrapper` struct
+    #[repr(C)]
+    pub struct AzVirtualListStateWrapper {
+        pub inner: AzVirtualListState,
+        pub render_row: AzVirtualListRenderRow,
+        pub on_scroll: AzOptionVirtualListOnScroll,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_track_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub rendered_rows_start: usize,
+        pub rendered_rows_end: usize,
+        pub iframe_nodes: AzOptionVirtualListIFrameNodes,
+    }
+
     /// Wrapper over a Rust-allocated `CssDeclaration`
     #[repr(C)]
     pub struct AzCssDeclarationVec {
@@ -10658,6 +12747,31 @@ mod test_sizes {
         pub declarations: AzCssDeclarationVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitPane` struct
+    #[repr(C)]
+    pub struct AzSplitPane {
+        pub state: AzSplitPaneStateWrapper,
+        pub first_pane: AzDom,
+        pub second_pane: AzDom,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub pane_style: AzNodeDataInlineCssPropertyVec,
+        pub divider_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeView` struct
+    #[repr(C)]
+    pub struct AzTreeView {
+        pub state: AzTreeViewStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub row_style: AzNodeDataInlineCssPropertyVec,
+        pub guides_style: AzNodeDataInlineCssPropertyVec,
+        pub toggle_style: AzNodeDataInlineCssPropertyVec,
+        pub icon_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub drop_indicator_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyledDom` struct
     #[repr(C)]
     pub struct AzStyledDom {
@@ -10817,6 +12931,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityRole>(), "AzAccessibilityRole"), (Layout::new::<AzAccessibilityRole>(), "AzAccessibilityRole"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityState>(), "AzAccessibilityState"), (Layout::new::<AzAccessibilityState>(), "AzAccessibilityState"));
         assert_eq!((Layout::new::<azul_impl::dom::TabIndex>(), "AzTabIndex"), (Layout::new::<AzTabIndex>(), "AzTabIndex"));
+        assert_eq!((Layout::new::<azul_impl::dom::RovingFocusOrientation>(), "AzRovingFocusOrientation"), (Layout::new::<AzRovingFocusOrientation>(), "AzRovingFocusOrientation"));
         assert_eq!((Layout::new::<azul_core::window::ContextMenuMouseButton>(), "AzContextMenuMouseButton"), (Layout::new::<AzContextMenuMouseButton>(), "AzContextMenuMouseButton"));
         assert_eq!((Layout::new::<azul_core::window::MenuPopupPosition>(), "AzMenuPopupPosition"), (Layout::new::<AzMenuPopupPosition>(), "AzMenuPopupPosition"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemState>(), "AzMenuItemState"), (Layout::new::<AzMenuItemState>(), "AzMenuItemState"));
@@ -10849,15 +12964,43 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlign>(), "AzStyleTextAlign"), (Layout::new::<AzStyleTextAlign>(), "AzStyleTextAlign"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxOnToggleCallback>(), "AzCheckBoxOnToggleCallback"), (Layout::new::<AzCheckBoxOnToggleCallback>(), "AzCheckBoxOnToggleCallback"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxState>(), "AzCheckBoxState"), (Layout::new::<AzCheckBoxState>(), "AzCheckBoxState"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"), (Layout::new::<AzDropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputOnValueChangeCallback>(), "AzColorInputOnValueChangeCallback"), (Layout::new::<AzColorInputOnValueChangeCallback>(), "AzColorInputOnValueChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputSelectionRange>(), "AzTextInputSelectionRange"), (Layout::new::<AzTextInputSelectionRange>(), "AzTextInputSelectionRange"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnTextInputCallback>(), "AzTextInputOnTextInputCallback"), (Layout::new::<AzTextInputOnTextInputCallback>(), "AzTextInputOnTextInputCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnVirtualKeyDownCallback>(), "AzTextInputOnVirtualKeyDownCallback"), (Layout::new::<AzTextInputOnVirtualKeyDownCallback>(), "AzTextInputOnVirtualKeyDownCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnFocusLostCallback>(), "AzTextInputOnFocusLostCallback"), (Layout::new::<AzTextInputOnFocusLostCallback>(), "AzTextInputOnFocusLostCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValid>(), "AzTextInputValid"), (Layout::new::<AzTextInputValid>(), "AzTextInputValid"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValidatorCallback>(), "AzTextInputValidatorCallback"), (Layout::new::<AzTextInputValidatorCallback>(), "AzTextInputValidatorCallback"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnValidationChangeCallback>(), "AzTextInputOnValidationChangeCallback"), (Layout::new::<AzTextInputOnValidationChangeCallback>(), "AzTextInputOnValidationChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputState>(), "AzNumberInputState"), (Layout::new::<AzNumberInputState>(), "AzNumberInputState"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"), (Layout::new::<AzNumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBarState>(), "AzProgressBarState"), (Layout::new::<AzProgressBarState>(), "AzProgressBarState"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::SplitPaneOrientation>(), "AzSplitPaneOrientation"), (Layout::new::<AzSplitPaneOrientation>(), "AzSplitPaneOrientation"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::SplitPaneCollapsed>(), "AzSplitPaneCollapsed"), (Layout::new::<AzSplitPaneCollapsed>(), "AzSplitPaneCollapsed"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::SplitPaneOnResizeCallback>(), "AzSplitPaneOnResizeCallback"), (Layout::new::<AzSplitPaneOnResizeCallback>(), "AzSplitPaneOnResizeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerOverflow>(), "AzTabContainerOverflow"), (Layout::new::<AzTabContainerOverflow>(), "AzTabContainerOverflow"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerOnTabChangeCallback>(), "AzTabContainerOnTabChangeCallback"), (Layout::new::<AzTabContainerOnTabChangeCallback>(), "AzTabContainerOnTabChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerOnTabCloseCallback>(), "AzTabContainerOnTabCloseCallback"), (Layout::new::<AzTabContainerOnTabCloseCallback>(), "AzTabContainerOnTabCloseCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerOnTabMoveCallback>(), "AzTabContainerOnTabMoveCallback"), (Layout::new::<AzTabContainerOnTabMoveCallback>(), "AzTabContainerOnTabMoveCallback"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOrientation>(), "AzSliderOrientation"), (Layout::new::<AzSliderOrientation>(), "AzSliderOrientation"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderThumb>(), "AzSliderThumb"), (Layout::new::<AzSliderThumb>(), "AzSliderThumb"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOnValueChangeCallback>(), "AzSliderOnValueChangeCallback"), (Layout::new::<AzSliderOnValueChangeCallback>(), "AzSliderOnValueChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditCursor>(), "AzTextEditCursor"), (Layout::new::<AzTextEditCursor>(), "AzTextEditCursor"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditEditKind>(), "AzTextEditEditKind"), (Layout::new::<AzTextEditEditKind>(), "AzTextEditEditKind"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditOnTextChangeCallback>(), "AzTextEditOnTextChangeCallback"), (Layout::new::<AzTextEditOnTextChangeCallback>(), "AzTextEditOnTextChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewChildren>(), "AzTreeViewChildren"), (Layout::new::<AzTreeViewChildren>(), "AzTreeViewChildren"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewSelectionMode>(), "AzTreeViewSelectionMode"), (Layout::new::<AzTreeViewSelectionMode>(), "AzTreeViewSelectionMode"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewDropPosition>(), "AzTreeViewDropPosition"), (Layout::new::<AzTreeViewDropPosition>(), "AzTreeViewDropPosition"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewRow>(), "AzTreeViewRow"), (Layout::new::<AzTreeViewRow>(), "AzTreeViewRow"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewDragPayload>(), "AzTreeViewDragPayload"), (Layout::new::<AzTreeViewDragPayload>(), "AzTreeViewDragPayload"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnLoadChildrenCallback>(), "AzTreeViewOnLoadChildrenCallback"), (Layout::new::<AzTreeViewOnLoadChildrenCallback>(), "AzTreeViewOnLoadChildrenCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnSelectionChangeCallback>(), "AzTreeViewOnSelectionChangeCallback"), (Layout::new::<AzTreeViewOnSelectionChangeCallback>(), "AzTreeViewOnSelectionChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnNodeMovedCallback>(), "AzTreeViewOnNodeMovedCallback"), (Layout::new::<AzTreeViewOnNodeMovedCallback>(), "AzTreeViewOnNodeMovedCallback"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListRowHeight>(), "AzVirtualListRowHeight"), (Layout::new::<AzVirtualListRowHeight>(), "AzVirtualListRowHeight"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListMeasuredRow>(), "AzVirtualListMeasuredRow"), (Layout::new::<AzVirtualListMeasuredRow>(), "AzVirtualListMeasuredRow"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListRenderRowCallback>(), "AzVirtualListRenderRowCallback"), (Layout::new::<AzVirtualListRenderRowCallback>(), "AzVirtualListRenderRowCallback"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListOnScrollCallback>(), "AzVirtualListOnScrollCallback"), (Layout::new::<AzVirtualListOnScrollCallback>(), "AzVirtualListOnScrollCallback"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::AzNode>(), "AzNode"), (Layout::new::<AzNode>(), "AzNode"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfo>(), "AzCascadeInfo"), (Layout::new::<AzCascadeInfo>(), "AzCascadeInfo"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeState>(), "AzStyledNodeState"), (Layout::new::<AzStyledNodeState>(), "AzStyledNodeState"));
@@ -10937,6 +13080,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MenuItemVecDestructor>(), "AzMenuItemVecDestructor"), (Layout::new::<AzMenuItemVecDestructor>(), "AzMenuItemVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"), (Layout::new::<AzTessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVecDestructor>(), "AzXmlNodeVecDestructor"), (Layout::new::<AzXmlNodeVecDestructor>(), "AzXmlNodeVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::xml::XmlSpanVecDestructor>(), "AzXmlSpanVecDestructor"), (Layout::new::<AzXmlSpanVecDestructor>(), "AzXmlSpanVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::str::FmtArgVecDestructor>(), "AzFmtArgVecDestructor"), (Layout::new::<AzFmtArgVecDestructor>(), "AzFmtArgVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLineVecDestructor>(), "AzInlineLineVecDestructor"), (Layout::new::<AzInlineLineVecDestructor>(), "AzInlineLineVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineWordVecDestructor>(), "AzInlineWordVecDestructor"), (Layout::new::<AzInlineWordVecDestructor>(), "AzInlineWordVecDestructor"));
@@ -10963,6 +13107,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeVecDestructor>(), "AzVirtualKeyCodeVecDestructor"), (Layout::new::<AzVirtualKeyCodeVecDestructor>(), "AzVirtualKeyCodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfoVecDestructor>(), "AzCascadeInfoVecDestructor"), (Layout::new::<AzCascadeInfoVecDestructor>(), "AzCascadeInfoVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::ScanCodeVecDestructor>(), "AzScanCodeVecDestructor"), (Layout::new::<AzScanCodeVecDestructor>(), "AzScanCodeVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValidatorVecDestructor>(), "AzTextInputValidatorVecDestructor"), (Layout::new::<AzTextInputValidatorVecDestructor>(), "AzTextInputValidatorVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownChoiceVecDestructor>(), "AzDropDownChoiceVecDestructor"), (Layout::new::<AzDropDownChoiceVecDestructor>(), "AzDropDownChoiceVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerTabVecDestructor>(), "AzTabContainerTabVecDestructor"), (Layout::new::<AzTabContainerTabVecDestructor>(), "AzTabContainerTabVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditUndoEntryVecDestructor>(), "AzTextEditUndoEntryVecDestructor"), (Layout::new::<AzTextEditUndoEntryVecDestructor>(), "AzTextEditUndoEntryVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeDataVecDestructor>(), "AzTreeViewNodeDataVecDestructor"), (Layout::new::<AzTreeViewNodeDataVecDestructor>(), "AzTreeViewNodeDataVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeVecDestructor>(), "AzTreeViewNodeVecDestructor"), (Layout::new::<AzTreeViewNodeVecDestructor>(), "AzTreeViewNodeVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewRowVecDestructor>(), "AzTreeViewRowVecDestructor"), (Layout::new::<AzTreeViewRowVecDestructor>(), "AzTreeViewRowVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewRenderedRowVecDestructor>(), "AzTreeViewRenderedRowVecDestructor"), (Layout::new::<AzTreeViewRenderedRowVecDestructor>(), "AzTreeViewRenderedRowVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListMeasuredRowVecDestructor>(), "AzVirtualListMeasuredRowVecDestructor"), (Layout::new::<AzVirtualListMeasuredRowVecDestructor>(), "AzVirtualListMeasuredRowVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"), (Layout::new::<AzCssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"), (Layout::new::<AzCssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StylesheetVecDestructor>(), "AzStylesheetVecDestructor"), (Layout::new::<AzStylesheetVecDestructor>(), "AzStylesheetVecDestructor"));
@@ -11015,6 +13168,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::RefAny>(), "AzRefAny"), (Layout::new::<AzRefAny>(), "AzRefAny"));
         assert_eq!((Layout::new::<azul_impl::dom::IFrameNode>(), "AzIFrameNode"), (Layout::new::<AzIFrameNode>(), "AzIFrameNode"));
         assert_eq!((Layout::new::<azul_impl::dom::NotEventFilter>(), "AzNotEventFilter"), (Layout::new::<AzNotEventFilter>(), "AzNotEventFilter"));
+        assert_eq!((Layout::new::<azul_impl::dom::RovingFocus>(), "AzRovingFocus"), (Layout::new::<AzRovingFocus>(), "AzRovingFocus"));
         assert_eq!((Layout::new::<azul_core::window::MenuCallback>(), "AzMenuCallback"), (Layout::new::<AzMenuCallback>(), "AzMenuCallback"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemIcon>(), "AzMenuItemIcon"), (Layout::new::<AzMenuItemIcon>(), "AzMenuItemIcon"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildSelector>(), "AzCssNthChildSelector"), (Layout::new::<AzCssNthChildSelector>(), "AzCssNthChildSelector"));
@@ -11145,6 +13299,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"), (Layout::new::<AzStyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"));
         assert_eq!((Layout::new::<crate::widgets::button::ButtonOnClick>(), "AzButtonOnClick"), (Layout::new::<AzButtonOnClick>(), "AzButtonOnClick"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxOnToggle>(), "AzCheckBoxOnToggle"), (Layout::new::<AzCheckBoxOnToggle>(), "AzCheckBoxOnToggle"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"), (Layout::new::<AzDropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputState>(), "AzColorInputState"), (Layout::new::<AzColorInputState>(), "AzColorInputState"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputOnValueChange>(), "AzColorInputOnValueChange"), (Layout::new::<AzColorInputOnValueChange>(), "AzColorInputOnValueChange"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputSelection>(), "AzTextInputSelection"), (Layout::new::<AzTextInputSelection>(), "AzTextInputSelection"));
@@ -11152,7 +13307,24 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnVirtualKeyDown>(), "AzTextInputOnVirtualKeyDown"), (Layout::new::<AzTextInputOnVirtualKeyDown>(), "AzTextInputOnVirtualKeyDown"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnFocusLost>(), "AzTextInputOnFocusLost"), (Layout::new::<AzTextInputOnFocusLost>(), "AzTextInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OnTextInputReturn>(), "AzOnTextInputReturn"), (Layout::new::<AzOnTextInputReturn>(), "AzOnTextInputReturn"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputCustomValidator>(), "AzTextInputCustomValidator"), (Layout::new::<AzTextInputCustomValidator>(), "AzTextInputCustomValidator"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnValidationChange>(), "AzTextInputOnValidationChange"), (Layout::new::<AzTextInputOnValidationChange>(), "AzTextInputOnValidationChange"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChange>(), "AzNumberInputOnValueChange"), (Layout::new::<AzNumberInputOnValueChange>(), "AzNumberInputOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::SplitPaneState>(), "AzSplitPaneState"), (Layout::new::<AzSplitPaneState>(), "AzSplitPaneState"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::SplitPaneOnResize>(), "AzSplitPaneOnResize"), (Layout::new::<AzSplitPaneOnResize>(), "AzSplitPaneOnResize"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerOnTabChange>(), "AzTabContainerOnTabChange"), (Layout::new::<AzTabContainerOnTabChange>(), "AzTabContainerOnTabChange"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerOnTabClose>(), "AzTabContainerOnTabClose"), (Layout::new::<AzTabContainerOnTabClose>(), "AzTabContainerOnTabClose"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerOnTabMove>(), "AzTabContainerOnTabMove"), (Layout::new::<AzTabContainerOnTabMove>(), "AzTabContainerOnTabMove"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderState>(), "AzSliderState"), (Layout::new::<AzSliderState>(), "AzSliderState"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOnValueChange>(), "AzSliderOnValueChange"), (Layout::new::<AzSliderOnValueChange>(), "AzSliderOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditOnTextChange>(), "AzTextEditOnTextChange"), (Layout::new::<AzTextEditOnTextChange>(), "AzTextEditOnTextChange"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewDropTarget>(), "AzTreeViewDropTarget"), (Layout::new::<AzTreeViewDropTarget>(), "AzTreeViewDropTarget"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewLoadRequest>(), "AzTreeViewLoadRequest"), (Layout::new::<AzTreeViewLoadRequest>(), "AzTreeViewLoadRequest"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnLoadChildren>(), "AzTreeViewOnLoadChildren"), (Layout::new::<AzTreeViewOnLoadChildren>(), "AzTreeViewOnLoadChildren"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnSelectionChange>(), "AzTreeViewOnSelectionChange"), (Layout::new::<AzTreeViewOnSelectionChange>(), "AzTreeViewOnSelectionChange"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnNodeMoved>(), "AzTreeViewOnNodeMoved"), (Layout::new::<AzTreeViewOnNodeMoved>(), "AzTreeViewOnNodeMoved"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListRenderRow>(), "AzVirtualListRenderRow"), (Layout::new::<AzVirtualListRenderRow>(), "AzVirtualListRenderRow"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListOnScroll>(), "AzVirtualListOnScroll"), (Layout::new::<AzVirtualListOnScroll>(), "AzVirtualListOnScroll"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepth>(), "AzParentWithNodeDepth"), (Layout::new::<AzParentWithNodeDepth>(), "AzParentWithNodeDepth"));
         assert_eq!((Layout::new::<azul_impl::gl::GlContextPtr>(), "AzGl"), (Layout::new::<AzGl>(), "AzGl"));
         assert_eq!((Layout::new::<azul_impl::gl::RefstrVecRef>(), "AzRefstrVecRef"), (Layout::new::<AzRefstrVecRef>(), "AzRefstrVecRef"));
@@ -11163,6 +13335,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::svg::SvgCubicCurve>(), "AzSvgCubicCurve"), (Layout::new::<AzSvgCubicCurve>(), "AzSvgCubicCurve"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgXmlOptions>(), "AzSvgStringFormatOptions"), (Layout::new::<AzSvgStringFormatOptions>(), "AzSvgStringFormatOptions"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgFillStyle>(), "AzSvgFillStyle"), (Layout::new::<AzSvgFillStyle>(), "AzSvgFillStyle"));
+        assert_eq!((Layout::new::<azul_impl::xml::XmlSpan>(), "AzXmlSpan"), (Layout::new::<AzXmlSpan>(), "AzXmlSpan"));
         assert_eq!((Layout::new::<azul_impl::task::AzInstantPtr>(), "AzInstantPtr"), (Layout::new::<AzInstantPtr>(), "AzInstantPtr"));
         assert_eq!((Layout::new::<azul_impl::task::Duration>(), "AzDuration"), (Layout::new::<AzDuration>(), "AzDuration"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadSendMsg>(), "AzThreadSendMsg"), (Layout::new::<AzThreadSendMsg>(), "AzThreadSendMsg"));
@@ -11170,6 +13343,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVec>(), "AzAccessibilityStateVec"), (Layout::new::<AzAccessibilityStateVec>(), "AzAccessibilityStateVec"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVec>(), "AzMenuItemVec"), (Layout::new::<AzMenuItemVec>(), "AzMenuItemVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVec>(), "AzXmlNodeVec"), (Layout::new::<AzXmlNodeVec>(), "AzXmlNodeVec"));
+        assert_eq!((Layout::new::<azul_impl::xml::XmlSpanVec>(), "AzXmlSpanVec"), (Layout::new::<AzXmlSpanVec>(), "AzXmlSpanVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVec>(), "AzInlineGlyphVec"), (Layout::new::<AzInlineGlyphVec>(), "AzInlineGlyphVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextHitVec>(), "AzInlineTextHitVec"), (Layout::new::<AzInlineTextHitVec>(), "AzInlineTextHitVec"));
         assert_eq!((Layout::new::<azul_core::window::VideoModeVec>(), "AzVideoModeVec"), (Layout::new::<AzVideoModeVec>(), "AzVideoModeVec"));
@@ -11183,6 +13357,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeVec>(), "AzVirtualKeyCodeVec"), (Layout::new::<AzVirtualKeyCodeVec>(), "AzVirtualKeyCodeVec"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfoVec>(), "AzCascadeInfoVec"), (Layout::new::<AzCascadeInfoVec>(), "AzCascadeInfoVec"));
         assert_eq!((Layout::new::<azul_core::window::ScanCodeVec>(), "AzScanCodeVec"), (Layout::new::<AzScanCodeVec>(), "AzScanCodeVec"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewRowVec>(), "AzTreeViewRowVec"), (Layout::new::<AzTreeViewRowVec>(), "AzTreeViewRowVec"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListMeasuredRowVec>(), "AzVirtualListMeasuredRowVec"), (Layout::new::<AzVirtualListMeasuredRowVec>(), "AzVirtualListMeasuredRowVec"));
         assert_eq!((Layout::new::<azul_impl::css::U16Vec>(), "AzU16Vec"), (Layout::new::<AzU16Vec>(), "AzU16Vec"));
         assert_eq!((Layout::new::<azul_impl::css::F32Vec>(), "AzF32Vec"), (Layout::new::<AzF32Vec>(), "AzF32Vec"));
         assert_eq!((Layout::new::<azul_impl::css::U8Vec>(), "AzU8Vec"), (Layout::new::<AzU8Vec>(), "AzU8Vec"));
@@ -11200,6 +13376,20 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnVirtualKeyDown>(), "AzOptionTextInputOnVirtualKeyDown"), (Layout::new::<AzOptionTextInputOnVirtualKeyDown>(), "AzOptionTextInputOnVirtualKeyDown"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"), (Layout::new::<AzOptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputSelection>(), "AzOptionTextInputSelection"), (Layout::new::<AzOptionTextInputSelection>(), "AzOptionTextInputSelection"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnValidationChange>(), "AzOptionTextInputOnValidationChange"), (Layout::new::<AzOptionTextInputOnValidationChange>(), "AzOptionTextInputOnValidationChange"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::OptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"), (Layout::new::<AzOptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::OptionTabContainerOnTabChange>(), "AzOptionTabContainerOnTabChange"), (Layout::new::<AzOptionTabContainerOnTabChange>(), "AzOptionTabContainerOnTabChange"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::OptionTabContainerOnTabClose>(), "AzOptionTabContainerOnTabClose"), (Layout::new::<AzOptionTabContainerOnTabClose>(), "AzOptionTabContainerOnTabClose"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::OptionTabContainerOnTabMove>(), "AzOptionTabContainerOnTabMove"), (Layout::new::<AzOptionTabContainerOnTabMove>(), "AzOptionTabContainerOnTabMove"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::OptionSplitPaneOnResize>(), "AzOptionSplitPaneOnResize"), (Layout::new::<AzOptionSplitPaneOnResize>(), "AzOptionSplitPaneOnResize"));
+        assert_eq!((Layout::new::<crate::widgets::slider::OptionSliderOnValueChange>(), "AzOptionSliderOnValueChange"), (Layout::new::<AzOptionSliderOnValueChange>(), "AzOptionSliderOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::OptionTextEditOnTextChange>(), "AzOptionTextEditOnTextChange"), (Layout::new::<AzOptionTextEditOnTextChange>(), "AzOptionTextEditOnTextChange"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::OptionTextEditCursor>(), "AzOptionTextEditCursor"), (Layout::new::<AzOptionTextEditCursor>(), "AzOptionTextEditCursor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnLoadChildren>(), "AzOptionTreeViewOnLoadChildren"), (Layout::new::<AzOptionTreeViewOnLoadChildren>(), "AzOptionTreeViewOnLoadChildren"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnSelectionChange>(), "AzOptionTreeViewOnSelectionChange"), (Layout::new::<AzOptionTreeViewOnSelectionChange>(), "AzOptionTreeViewOnSelectionChange"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnNodeMoved>(), "AzOptionTreeViewOnNodeMoved"), (Layout::new::<AzOptionTreeViewOnNodeMoved>(), "AzOptionTreeViewOnNodeMoved"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewDropTarget>(), "AzOptionTreeViewDropTarget"), (Layout::new::<AzOptionTreeViewDropTarget>(), "AzOptionTreeViewDropTarget"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::OptionVirtualListOnScroll>(), "AzOptionVirtualListOnScroll"), (Layout::new::<AzOptionVirtualListOnScroll>(), "AzOptionVirtualListOnScroll"));
         assert_eq!((Layout::new::<crate::widgets::number_input::OptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"), (Layout::new::<AzOptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuItemIcon>(), "AzOptionMenuItemIcon"), (Layout::new::<AzOptionMenuItemIcon>(), "AzOptionMenuItemIcon"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuCallback>(), "AzOptionMenuCallback"), (Layout::new::<AzOptionMenuCallback>(), "AzOptionMenuCallback"));
@@ -11247,14 +13437,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowIcon>(), "AzWindowIcon"), (Layout::new::<AzWindowIcon>(), "AzWindowIcon"));
         assert_eq!((Layout::new::<azul_core::window::TaskBarIcon>(), "AzTaskBarIcon"), (Layout::new::<AzTaskBarIcon>(), "AzTaskBarIcon"));
         assert_eq!((Layout::new::<azul_core::window::WindowSize>(), "AzWindowSize"), (Layout::new::<AzWindowSize>(), "AzWindowSize"));
-        assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::MouseState>(), "AzMouseState"), (Layout::new::<AzMouseState>(), "AzMouseState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"), (Layout::new::<AzMarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextContents>(), "AzInlineTextContents"), (Layout::new::<AzInlineTextContents>(), "AzInlineTextContents"));
+        assert_eq!((Layout::new::<azul_impl::window::DragData>(), "AzDragData"), (Layout::new::<AzDragData>(), "AzDragData"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationEasing"), (Layout::new::<AzAnimationEasing>(), "AzAnimationEasing"));
         assert_eq!((Layout::new::<azul_impl::callbacks::RenderImageCallbackInfo>(), "AzRenderImageCallbackInfo"), (Layout::new::<AzRenderImageCallbackInfo>(), "AzRenderImageCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallbackInfo>(), "AzLayoutCallbackInfo"), (Layout::new::<AzLayoutCallbackInfo>(), "AzLayoutCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::EventFilter>(), "AzEventFilter"), (Layout::new::<AzEventFilter>(), "AzEventFilter"));
+        assert_eq!((Layout::new::<azul_impl::dom::FocusScope>(), "AzFocusScope"), (Layout::new::<AzFocusScope>(), "AzFocusScope"));
         assert_eq!((Layout::new::<azul_core::window::Menu>(), "AzMenu"), (Layout::new::<AzMenu>(), "AzMenu"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"), (Layout::new::<AzVirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathPseudoSelector>(), "AzCssPathPseudoSelector"), (Layout::new::<AzCssPathPseudoSelector>(), "AzCssPathPseudoSelector"));
@@ -11268,7 +13459,12 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"), (Layout::new::<AzStyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"), (Layout::new::<AzStyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"), (Layout::new::<AzCheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValidator>(), "AzTextInputValidator"), (Layout::new::<AzTextInputValidator>(), "AzTextInputValidator"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputStateWrapper>(), "AzNumberInputStateWrapper"), (Layout::new::<AzNumberInputStateWrapper>(), "AzNumberInputStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::SplitPaneStateWrapper>(), "AzSplitPaneStateWrapper"), (Layout::new::<AzSplitPaneStateWrapper>(), "AzSplitPaneStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderStateWrapper>(), "AzSliderStateWrapper"), (Layout::new::<AzSliderStateWrapper>(), "AzSliderStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListState>(), "AzVirtualListState"), (Layout::new::<AzVirtualListState>(), "AzVirtualListState"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListIFrameNodes>(), "AzVirtualListIFrameNodes"), (Layout::new::<AzVirtualListIFrameNodes>(), "AzVirtualListIFrameNodes"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNode>(), "AzStyledNode"), (Layout::new::<AzStyledNode>(), "AzStyledNode"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMapping>(), "AzTagIdToNodeIdMapping"), (Layout::new::<AzTagIdToNodeIdMapping>(), "AzTagIdToNodeIdMapping"));
         assert_eq!((Layout::new::<azul_impl::gl::Texture>(), "AzTexture"), (Layout::new::<AzTexture>(), "AzTexture"));
@@ -11287,12 +13483,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"), (Layout::new::<AzTessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVec>(), "AzStyleTransformVec"), (Layout::new::<AzStyleTransformVec>(), "AzStyleTransformVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathElementVec>(), "AzSvgPathElementVec"), (Layout::new::<AzSvgPathElementVec>(), "AzSvgPathElementVec"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValidatorVec>(), "AzTextInputValidatorVec"), (Layout::new::<AzTextInputValidatorVec>(), "AzTextInputValidatorVec"));
         assert_eq!((Layout::new::<azul_impl::css::StringVec>(), "AzStringVec"), (Layout::new::<AzStringVec>(), "AzStringVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeVec>(), "AzStyledNodeVec"), (Layout::new::<AzStyledNodeVec>(), "AzStyledNodeVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"), (Layout::new::<AzTagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::OptionVirtualListIFrameNodes>(), "AzOptionVirtualListIFrameNodes"), (Layout::new::<AzOptionVirtualListIFrameNodes>(), "AzOptionVirtualListIFrameNodes"));
         assert_eq!((Layout::new::<azul_core::window::OptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"), (Layout::new::<AzOptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"));
+        assert_eq!((Layout::new::<azul_impl::window::OptionDragData>(), "AzOptionDragData"), (Layout::new::<AzOptionDragData>(), "AzOptionDragData"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseState>(), "AzOptionMouseState"), (Layout::new::<AzOptionMouseState>(), "AzOptionMouseState"));
-        assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::css::OptionStringVec>(), "AzOptionStringVec"), (Layout::new::<AzOptionStringVec>(), "AzOptionStringVec"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"), (Layout::new::<AzOptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"));
         assert_eq!((Layout::new::<azul_core::window::OptionTaskBarIcon>(), "AzOptionTaskBarIcon"), (Layout::new::<AzOptionTaskBarIcon>(), "AzOptionTaskBarIcon"));
@@ -11309,6 +13507,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowsWindowOptions>(), "AzWindowsWindowOptions"), (Layout::new::<AzWindowsWindowOptions>(), "AzWindowsWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::WaylandTheme>(), "AzWaylandTheme"), (Layout::new::<AzWaylandTheme>(), "AzWaylandTheme"));
         assert_eq!((Layout::new::<azul_core::window::AzStringPair>(), "AzStringPair"), (Layout::new::<AzStringPair>(), "AzStringPair"));
+        assert_eq!((Layout::new::<azul_core::window::ImeComposition>(), "AzImeComposition"), (Layout::new::<AzImeComposition>(), "AzImeComposition"));
         assert_eq!((Layout::new::<azul_core::window::Monitor>(), "AzMonitor"), (Layout::new::<AzMonitor>(), "AzMonitor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallback>(), "AzLayoutCallback"), (Layout::new::<AzLayoutCallback>(), "AzLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineWord>(), "AzInlineWord"), (Layout::new::<AzInlineWord>(), "AzInlineWord"));
@@ -11324,8 +13523,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamily>(), "AzStyleFontFamily"), (Layout::new::<AzStyleFontFamily>(), "AzStyleFontFamily"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyleValue>(), "AzScrollbarStyleValue"), (Layout::new::<AzScrollbarStyleValue>(), "AzScrollbarStyleValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecValue>(), "AzStyleTransformVecValue"), (Layout::new::<AzStyleTransformVecValue>(), "AzStyleTransformVecValue"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownChoice>(), "AzDropDownChoice"), (Layout::new::<AzDropDownChoice>(), "AzDropDownChoice"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputStateWrapper>(), "AzColorInputStateWrapper"), (Layout::new::<AzColorInputStateWrapper>(), "AzColorInputStateWrapper"));
-        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputState>(), "AzTextInputState"), (Layout::new::<AzTextInputState>(), "AzTextInputState"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValidationResult>(), "AzTextInputValidationResult"), (Layout::new::<AzTextInputValidationResult>(), "AzTextInputValidationResult"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputMask>(), "AzTextInputMask"), (Layout::new::<AzTextInputMask>(), "AzTextInputMask"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerTab>(), "AzTabContainerTab"), (Layout::new::<AzTabContainerTab>(), "AzTabContainerTab"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditState>(), "AzTextEditState"), (Layout::new::<AzTextEditState>(), "AzTextEditState"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditUndoEntry>(), "AzTextEditUndoEntry"), (Layout::new::<AzTextEditUndoEntry>(), "AzTextEditUndoEntry"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeData>(), "AzTreeViewNodeData"), (Layout::new::<AzTreeViewNodeData>(), "AzTreeViewNodeData"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewRenderedRow>(), "AzTreeViewRenderedRow"), (Layout::new::<AzTreeViewRenderedRow>(), "AzTreeViewRenderedRow"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttribute>(), "AzVertexAttribute"), (Layout::new::<AzVertexAttribute>(), "AzVertexAttribute"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessage>(), "AzDebugMessage"), (Layout::new::<AzDebugMessage>(), "AzDebugMessage"));
         assert_eq!((Layout::new::<azul_impl::gl::GetActiveAttribReturn>(), "AzGetActiveAttribReturn"), (Layout::new::<AzGetActiveAttribReturn>(), "AzGetActiveAttribReturn"));
@@ -11347,16 +13553,24 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"), (Layout::new::<AzStyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathVec>(), "AzSvgPathVec"), (Layout::new::<AzSvgPathVec>(), "AzSvgPathVec"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttributeVec>(), "AzVertexAttributeVec"), (Layout::new::<AzVertexAttributeVec>(), "AzVertexAttributeVec"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownChoiceVec>(), "AzDropDownChoiceVec"), (Layout::new::<AzDropDownChoiceVec>(), "AzDropDownChoiceVec"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerTabVec>(), "AzTabContainerTabVec"), (Layout::new::<AzTabContainerTabVec>(), "AzTabContainerTabVec"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditUndoEntryVec>(), "AzTextEditUndoEntryVec"), (Layout::new::<AzTextEditUndoEntryVec>(), "AzTextEditUndoEntryVec"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeDataVec>(), "AzTreeViewNodeDataVec"), (Layout::new::<AzTreeViewNodeDataVec>(), "AzTreeViewNodeDataVec"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewRenderedRowVec>(), "AzTreeViewRenderedRowVec"), (Layout::new::<AzTreeViewRenderedRowVec>(), "AzTreeViewRenderedRowVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVec>(), "AzCssPathSelectorVec"), (Layout::new::<AzCssPathSelectorVec>(), "AzCssPathSelectorVec"));
         assert_eq!((Layout::new::<azul_impl::dom::CallbackDataVec>(), "AzCallbackDataVec"), (Layout::new::<AzCallbackDataVec>(), "AzCallbackDataVec"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessageVec>(), "AzDebugMessageVec"), (Layout::new::<AzDebugMessageVec>(), "AzDebugMessageVec"));
         assert_eq!((Layout::new::<azul_core::window::StringPairVec>(), "AzStringPairVec"), (Layout::new::<AzStringPairVec>(), "AzStringPairVec"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputMask>(), "AzOptionTextInputMask"), (Layout::new::<AzOptionTextInputMask>(), "AzOptionTextInputMask"));
+        assert_eq!((Layout::new::<azul_impl::window::OptionImeComposition>(), "AzOptionImeComposition"), (Layout::new::<AzOptionImeComposition>(), "AzOptionImeComposition"));
         assert_eq!((Layout::new::<azul_impl::dialogs::OptionFileTypeList>(), "AzOptionFileTypeList"), (Layout::new::<AzOptionFileTypeList>(), "AzOptionFileTypeList"));
         assert_eq!((Layout::new::<azul_impl::file::OptionFile>(), "AzOptionFile"), (Layout::new::<AzOptionFile>(), "AzOptionFile"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionRawImage>(), "AzOptionRawImage"), (Layout::new::<AzOptionRawImage>(), "AzOptionRawImage"));
         assert_eq!((Layout::new::<azul_core::window::OptionWaylandTheme>(), "AzOptionWaylandTheme"), (Layout::new::<AzOptionWaylandTheme>(), "AzOptionWaylandTheme"));
         assert_eq!((Layout::new::<azul_impl::resources::decode::ResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"), (Layout::new::<AzResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlStreamError>(), "AzXmlStreamError"), (Layout::new::<AzXmlStreamError>(), "AzXmlStreamError"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::LinuxWindowOptions>(), "AzLinuxWindowOptions"), (Layout::new::<AzLinuxWindowOptions>(), "AzLinuxWindowOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
@@ -11364,7 +13578,12 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"), (Layout::new::<AzStyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"), (Layout::new::<AzStyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::CssProperty>(), "AzCssProperty"), (Layout::new::<AzCssProperty>(), "AzCssProperty"));
-        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputStateWrapper>(), "AzTextInputStateWrapper"), (Layout::new::<AzTextInputStateWrapper>(), "AzTextInputStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownState>(), "AzDropDownState"), (Layout::new::<AzDropDownState>(), "AzDropDownState"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputState>(), "AzTextInputState"), (Layout::new::<AzTextInputState>(), "AzTextInputState"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerState>(), "AzTabContainerState"), (Layout::new::<AzTabContainerState>(), "AzTabContainerState"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditStateWrapper>(), "AzTextEditStateWrapper"), (Layout::new::<AzTextEditStateWrapper>(), "AzTextEditStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNode>(), "AzTreeViewNode"), (Layout::new::<AzTreeViewNode>(), "AzTreeViewNode"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewLoadedChildren>(), "AzTreeViewLoadedChildren"), (Layout::new::<AzTreeViewLoadedChildren>(), "AzTreeViewLoadedChildren"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::CssPropertySource>(), "AzCssPropertySource"), (Layout::new::<AzCssPropertySource>(), "AzCssPropertySource"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexLayout>(), "AzVertexLayout"), (Layout::new::<AzVertexLayout>(), "AzVertexLayout"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexArrayObject>(), "AzVertexArrayObject"), (Layout::new::<AzVertexArrayObject>(), "AzVertexArrayObject"));
@@ -11374,7 +13593,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLineVec>(), "AzInlineLineVec"), (Layout::new::<AzInlineLineVec>(), "AzInlineLineVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyVec>(), "AzCssPropertyVec"), (Layout::new::<AzCssPropertyVec>(), "AzCssPropertyVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"), (Layout::new::<AzSvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeVec>(), "AzTreeViewNodeVec"), (Layout::new::<AzTreeViewNodeVec>(), "AzTreeViewNodeVec"));
         assert_eq!((Layout::new::<azul_impl::css::OptionCssProperty>(), "AzOptionCssProperty"), (Layout::new::<AzOptionCssProperty>(), "AzOptionCssProperty"));
+        assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextError>(), "AzXmlTextError"), (Layout::new::<AzXmlTextError>(), "AzXmlTextError"));
         assert_eq!((Layout::new::<azul_core::window::PlatformSpecificOptions>(), "AzPlatformSpecificOptions"), (Layout::new::<AzPlatformSpecificOptions>(), "AzPlatformSpecificOptions"));
         assert_eq!((Layout::new::<azul_core::window::WindowState>(), "AzWindowState"), (Layout::new::<AzWindowState>(), "AzWindowState"));
@@ -11385,6 +13606,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackInfo>(), "AzTimerCallbackInfo"), (Layout::new::<AzTimerCallbackInfo>(), "AzTimerCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"), (Layout::new::<AzNodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"));
         assert_eq!((Layout::new::<azul_impl::css::DynamicCssProperty>(), "AzDynamicCssProperty"), (Layout::new::<AzDynamicCssProperty>(), "AzDynamicCssProperty"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownStateWrapper>(), "AzDropDownStateWrapper"), (Layout::new::<AzDropDownStateWrapper>(), "AzDropDownStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputStateWrapper>(), "AzTextInputStateWrapper"), (Layout::new::<AzTextInputStateWrapper>(), "AzTextInputStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainerStateWrapper>(), "AzTabContainerStateWrapper"), (Layout::new::<AzTabContainerStateWrapper>(), "AzTabContainerStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewState>(), "AzTreeViewState"), (Layout::new::<AzTreeViewState>(), "AzTreeViewState"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgNode>(), "AzSvgNode"), (Layout::new::<AzSvgNode>(), "AzSvgNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgStyledNode>(), "AzSvgStyledNode"), (Layout::new::<AzSvgStyledNode>(), "AzSvgStyledNode"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"), (Layout::new::<AzNodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"));
@@ -11397,16 +13622,25 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::CssDeclaration>(), "AzCssDeclaration"), (Layout::new::<AzCssDeclaration>(), "AzCssDeclaration"));
         assert_eq!((Layout::new::<crate::widgets::button::Button>(), "AzButton"), (Layout::new::<AzButton>(), "AzButton"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBox>(), "AzCheckBox"), (Layout::new::<AzCheckBox>(), "AzCheckBox"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDown>(), "AzDropDown"), (Layout::new::<AzDropDown>(), "AzDropDown"));
         assert_eq!((Layout::new::<crate::widgets::label::Label>(), "AzLabel"), (Layout::new::<AzLabel>(), "AzLabel"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInput>(), "AzColorInput"), (Layout::new::<AzColorInput>(), "AzColorInput"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInput>(), "AzTextInput"), (Layout::new::<AzTextInput>(), "AzTextInput"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInput>(), "AzNumberInput"), (Layout::new::<AzNumberInput>(), "AzNumberInput"));
         assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBar>(), "AzProgressBar"), (Layout::new::<AzProgressBar>(), "AzProgressBar"));
+        assert_eq!((Layout::new::<crate::widgets::tab_container::TabContainer>(), "AzTabContainer"), (Layout::new::<AzTabContainer>(), "AzTabContainer"));
+        assert_eq!((Layout::new::<crate::widgets::slider::Slider>(), "AzSlider"), (Layout::new::<AzSlider>(), "AzSlider"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEdit>(), "AzTextEdit"), (Layout::new::<AzTextEdit>(), "AzTextEdit"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewStateWrapper>(), "AzTreeViewStateWrapper"), (Layout::new::<AzTreeViewStateWrapper>(), "AzTreeViewStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualList>(), "AzVirtualList"), (Layout::new::<AzVirtualList>(), "AzVirtualList"));
+        assert_eq!((Layout::new::<crate::widgets::virtual_list::VirtualListStateWrapper>(), "AzVirtualListStateWrapper"), (Layout::new::<AzVirtualListStateWrapper>(), "AzVirtualListStateWrapper"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVec>(), "AzCssDeclarationVec"), (Layout::new::<AzCssDeclarationVec>(), "AzCssDeclarationVec"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataVec>(), "AzNodeDataVec"), (Layout::new::<AzNodeDataVec>(), "AzNodeDataVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlError>(), "AzXmlError"), (Layout::new::<AzXmlError>(), "AzXmlError"));
         assert_eq!((Layout::new::<azul_impl::dom::Dom>(), "AzDom"), (Layout::new::<AzDom>(), "AzDom"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlock>(), "AzCssRuleBlock"), (Layout::new::<AzCssRuleBlock>(), "AzCssRuleBlock"));
+        assert_eq!((Layout::new::<crate::widgets::split_pane::SplitPane>(), "AzSplitPane"), (Layout::new::<AzSplitPane>(), "AzSplitPane"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeView>(), "AzTreeView"), (Layout::new::<AzTreeView>(), "AzTreeView"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledDom>(), "AzStyledDom"), (Layout::new::<AzStyledDom>(), "AzStyledDom"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlockVec>(), "AzCssRuleBlockVec"), (Layout::new::<AzCssRuleBlockVec>(), "AzCssRuleBlockVec"));
         assert_eq!((Layout::new::<azul_impl::dom::OptionDom>(), "AzOptionDom"), (Layout::new::<AzOptionDom>(), "AzOptionDom"));
@@ -1440,6 +1440,13 @@ pub enum AzHoverEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    DragStart,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    DragEnd,
+    ImeComposition,
 }
 
 /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -1464,6 +1471,7 @@ pub enum AzFocusEventFilter {
     VirtualKeyUp,
     FocusReceived,
     FocusLost,
+    ImeComposition,
 }
 
 /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -1499,6 +1507,11 @@ pub enum AzWindowEventFilter {
     FocusLost,
     CloseRequested,
     ThemeChanged,
+    DragStart,
+    DragOver,
+    Drop,
+    DragEnd,
+    ImeComposition,
 }
 
 /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1616,6 +1629,14 @@ pub enum AzTabIndex {
     NoKeyboardFocus,
 }
 
+/// Re-export of rust-allocated (stack based) `RovingFocusOrientation` struct
+#[repr(C)]
+pub enum AzRovingFocusOrientation {
+    Horizontal,
+    Vertical,
+    Both,
+}
+
 /// Determines whether this context menu should pop up on a left, right or middle click
 #[repr(C)]
 pub enum AzContextMenuMouseButton {
@@ -2007,6 +2028,16 @@ pub struct AzCheckBoxState {
     pub checked: bool,
 }
 
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "DropDownOnChoiceChangeCallback")]
+pub struct AzDropDownOnChoiceChangeCallback {
+    pub cb: AzDropDownOnChoiceChangeCallbackType,
+}
+
+/// `AzDropDownOnChoiceChangeCallbackType` struct
+pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `ColorInputOnValueChangeCallback` struct
 #[repr(C)]
 #[pyclass(name = "ColorInputOnValueChangeCallback")]
@@ -2064,6 +2095,26 @@ pub enum AzTextInputValid {
     No,
 }
 
+/// Re-export of rust-allocated (stack based) `TextInputValidatorCallback` struct
+#[repr(C)]
+#[pyclass(name = "TextInputValidatorCallback")]
+pub struct AzTextInputValidatorCallback {
+    pub cb: AzTextInputValidatorCallbackType,
+}
+
+/// `AzTextInputValidatorCallbackType` struct
+pub type AzTextInputValidatorCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState) -> AzTextInputValidationResult;
+
+/// Re-export of rust-allocated (stack based) `TextInputOnValidationChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "TextInputOnValidationChangeCallback")]
+pub struct AzTextInputOnValidationChangeCallback {
+    pub cb: AzTextInputOnValidationChangeCallbackType,
+}
+
+/// `AzTextInputOnValidationChangeCallbackType` struct
+pub type AzTextInputOnValidationChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextInputState, &mut AzCallbackInfo) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `NumberInputState` struct
 #[repr(C)]
 #[pyclass(name = "NumberInputState")]
@@ -2098,6 +2149,233 @@ pub struct AzProgressBarState {
     pub display_percentage: bool,
 }
 
+/// Re-export of rust-allocated (stack based) `SplitPaneOrientation` struct
+#[repr(C)]
+pub enum AzSplitPaneOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// Re-export of rust-allocated (stack based) `SplitPaneCollapsed` struct
+#[repr(C)]
+pub enum AzSplitPaneCollapsed {
+    None,
+    First,
+    Second,
+}
+
+/// Re-export of rust-allocated (stack based) `SplitPaneOnResizeCallback` struct
+#[repr(C)]
+#[pyclass(name = "SplitPaneOnResizeCallback")]
+pub struct AzSplitPaneOnResizeCallback {
+    pub cb: AzSplitPaneOnResizeCallbackType,
+}
+
+/// `AzSplitPaneOnResizeCallbackType` struct
+pub type AzSplitPaneOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSplitPaneState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// What happens if the tabs don't fit into the header
+#[repr(C)]
+pub enum AzTabContainerOverflow {
+    Scroll,
+    Menu,
+}
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerOnTabChangeCallback")]
+pub struct AzTabContainerOnTabChangeCallback {
+    pub cb: AzTabContainerOnTabChangeCallbackType,
+}
+
+/// `AzTabContainerOnTabChangeCallbackType` struct
+pub type AzTabContainerOnTabChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabCloseCallback` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerOnTabCloseCallback")]
+pub struct AzTabContainerOnTabCloseCallback {
+    pub cb: AzTabContainerOnTabCloseCallbackType,
+}
+
+/// `AzTabContainerOnTabCloseCallbackType` struct
+pub type AzTabContainerOnTabCloseCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, AzTabContainerTab, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabMoveCallback` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerOnTabMoveCallback")]
+pub struct AzTabContainerOnTabMoveCallback {
+    pub cb: AzTabContainerOnTabMoveCallbackType,
+}
+
+/// `AzTabContainerOnTabMoveCallbackType` struct
+pub type AzTabContainerOnTabMoveCallbackType = extern "C" fn(&mut AzRefAny, &AzTabContainerState, usize, usize, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+#[repr(C)]
+pub enum AzSliderOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// Identifies one of the thumbs, a non-range slider only has the `Lower` thumb
+#[repr(C)]
+pub enum AzSliderThumb {
+    Lower,
+    Upper,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "SliderOnValueChangeCallback")]
+pub struct AzSliderOnValueChangeCallback {
+    pub cb: AzSliderOnValueChangeCallbackType,
+}
+
+/// `AzSliderOnValueChangeCallbackType` struct
+pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Position in the text: line index and character (not byte) index in the line
+#[repr(C)]
+#[pyclass(name = "TextEditCursor")]
+pub struct AzTextEditCursor {
+    #[pyo3(get, set)]
+    pub line: usize,
+    #[pyo3(get, set)]
+    pub column: usize,
+}
+
+/// Kind of an edit, consecutive edits of the same kind are undone together
+#[repr(C)]
+pub enum AzTextEditEditKind {
+    Insert,
+    DeleteBackward,
+    DeleteForward,
+    Other,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "TextEditOnTextChangeCallback")]
+pub struct AzTextEditOnTextChangeCallback {
+    pub cb: AzTextEditOnTextChangeCallbackType,
+}
+
+/// `AzTextEditOnTextChangeCallbackType` struct
+pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Whether a node has children and whether they have been loaded yet
+#[repr(C)]
+pub enum AzTreeViewChildren {
+    None,
+    NotLoaded,
+    Loading,
+    Loaded,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+#[repr(C)]
+pub enum AzTreeViewSelectionMode {
+    None,
+    Single,
+    Multiple,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewDropPosition` struct
+#[repr(C)]
+pub enum AzTreeViewDropPosition {
+    Before,
+    After,
+    Into,
+}
+
+/// Visible node: all ancestors of the node are expanded
+#[repr(C)]
+#[pyclass(name = "TreeViewRow")]
+pub struct AzTreeViewRow {
+    #[pyo3(get, set)]
+    pub node: usize,
+    #[pyo3(get, set)]
+    pub depth: usize,
+}
+
+/// Payload of a drag that was started on a node, can be used by other widgets to accept nodes that are dragged out of the tree
+#[repr(C)]
+#[pyclass(name = "TreeViewDragPayload")]
+pub struct AzTreeViewDragPayload {
+    #[pyo3(get, set)]
+    pub node: usize,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildrenCallback` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewOnLoadChildrenCallback")]
+pub struct AzTreeViewOnLoadChildrenCallback {
+    pub cb: AzTreeViewOnLoadChildrenCallbackType,
+}
+
+/// `AzTreeViewOnLoadChildrenCallbackType` struct
+pub type AzTreeViewOnLoadChildrenCallbackType = extern "C" fn(&mut AzRefAny, &mut AzTreeViewState, AzTreeViewLoadRequest, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewOnSelectionChangeCallback")]
+pub struct AzTreeViewOnSelectionChangeCallback {
+    pub cb: AzTreeViewOnSelectionChangeCallbackType,
+}
+
+/// `AzTreeViewOnSelectionChangeCallbackType` struct
+pub type AzTreeViewOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnNodeMovedCallback` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewOnNodeMovedCallback")]
+pub struct AzTreeViewOnNodeMovedCallback {
+    pub cb: AzTreeViewOnNodeMovedCallbackType,
+}
+
+/// `AzTreeViewOnNodeMovedCallbackType` struct
+pub type AzTreeViewOnNodeMovedCallbackType = extern "C" fn(&mut AzRefAny, &AzTreeViewState, usize, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Height of the rows of a `VirtualList`
+#[repr(C, u8)]
+pub enum AzVirtualListRowHeight {
+    Fixed(f32),
+    Estimated(f32),
+}
+
+/// Measured height of a row (only used with `VirtualListRowHeight::Estimated`)
+#[repr(C)]
+#[pyclass(name = "VirtualListMeasuredRow")]
+pub struct AzVirtualListMeasuredRow {
+    #[pyo3(get, set)]
+    pub row: usize,
+    #[pyo3(get, set)]
+    pub height: f32,
+    #[pyo3(get, set)]
+    pub delta_before: f64,
+}
+
+/// Re-export of rust-allocated (stack based) `VirtualListRenderRowCallback` struct
+#[repr(C)]
+#[pyclass(name = "VirtualListRenderRowCallback")]
+pub struct AzVirtualListRenderRowCallback {
+    pub cb: AzVirtualListRenderRowCallbackType,
+}
+
+/// `AzVirtualListRenderRowCallbackType` struct
+pub type AzVirtualListRenderRowCallbackType = extern "C" fn(&mut AzRefAny, usize) -> AzStyledDom;
+
+/// Re-export of rust-allocated (stack based) `VirtualListOnScrollCallback` struct
+#[repr(C)]
+#[pyclass(name = "VirtualListOnScrollCallback")]
+pub struct AzVirtualListOnScrollCallback {
+    pub cb: AzVirtualListOnScrollCallbackType,
+}
+
+/// `AzVirtualListOnScrollCallbackType` struct
+pub type AzVirtualListOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &AzVirtualListState, &mut AzCallbackInfo) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `Node` struct
 #[repr(C)]
 #[pyclass(name = "Node")]
@@ -2134,6 +2412,12 @@ pub struct AzStyledNodeState {
     pub active: bool,
     #[pyo3(get, set)]
     pub focused: bool,
+    #[pyo3(get, set)]
+    pub focus_visible: bool,
+    #[pyo3(get, set)]
+    pub focus_within: bool,
+    #[pyo3(get, set)]
+    pub drag_over: bool,
 }
 
 /// Re-export of rust-allocated (stack based) `TagId` struct
@@ -2848,6 +3132,17 @@ pub enum AzXmlNodeVecDestructor {
 /// `AzXmlNodeVecDestructorType` struct
 pub type AzXmlNodeVecDestructorType = extern "C" fn(&mut AzXmlNodeVec);
 
+/// Re-export of rust-allocated (stack based) `XmlSpanVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzXmlSpanVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzXmlSpanVecDestructorType),
+}
+
+/// `AzXmlSpanVecDestructorType` struct
+pub type AzXmlSpanVecDestructorType = extern "C" fn(&mut AzXmlSpanVec);
+
 /// Re-export of rust-allocated (stack based) `FmtArgVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzFmtArgVecDestructor {
@@ -3134,6 +3429,105 @@ pub enum AzScanCodeVecDestructor {
 /// `AzScanCodeVecDestructorType` struct
 pub type AzScanCodeVecDestructorType = extern "C" fn(&mut AzScanCodeVec);
 
+/// Re-export of rust-allocated (stack based) `TextInputValidatorVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTextInputValidatorVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTextInputValidatorVecDestructorType),
+}
+
+/// `AzTextInputValidatorVecDestructorType` struct
+pub type AzTextInputValidatorVecDestructorType = extern "C" fn(&mut AzTextInputValidatorVec);
+
+/// Re-export of rust-allocated (stack based) `DropDownChoiceVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzDropDownChoiceVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzDropDownChoiceVecDestructorType),
+}
+
+/// `AzDropDownChoiceVecDestructorType` struct
+pub type AzDropDownChoiceVecDestructorType = extern "C" fn(&mut AzDropDownChoiceVec);
+
+/// Re-export of rust-allocated (stack based) `TabContainerTabVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTabContainerTabVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTabContainerTabVecDestructorType),
+}
+
+/// `AzTabContainerTabVecDestructorType` struct
+pub type AzTabContainerTabVecDestructorType = extern "C" fn(&mut AzTabContainerTabVec);
+
+/// Re-export of rust-allocated (stack based) `TextEditUndoEntryVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTextEditUndoEntryVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTextEditUndoEntryVecDestructorType),
+}
+
+/// `AzTextEditUndoEntryVecDestructorType` struct
+pub type AzTextEditUndoEntryVecDestructorType = extern "C" fn(&mut AzTextEditUndoEntryVec);
+
+/// Re-export of rust-allocated (stack based) `TreeViewNodeDataVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTreeViewNodeDataVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTreeViewNodeDataVecDestructorType),
+}
+
+/// `AzTreeViewNodeDataVecDestructorType` struct
+pub type AzTreeViewNodeDataVecDestructorType = extern "C" fn(&mut AzTreeViewNodeDataVec);
+
+/// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTreeViewNodeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTreeViewNodeVecDestructorType),
+}
+
+/// `AzTreeViewNodeVecDestructorType` struct
+pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
+
+/// Re-export of rust-allocated (stack based) `TreeViewRowVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTreeViewRowVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTreeViewRowVecDestructorType),
+}
+
+/// `AzTreeViewRowVecDestructorType` struct
+pub type AzTreeViewRowVecDestructorType = extern "C" fn(&mut AzTreeViewRowVec);
+
+/// Re-export of rust-allocated (stack based) `TreeViewRenderedRowVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTreeViewRenderedRowVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTreeViewRenderedRowVecDestructorType),
+}
+
+/// `AzTreeViewRenderedRowVecDestructorType` struct
+pub type AzTreeViewRenderedRowVecDestructorType = extern "C" fn(&mut AzTreeViewRenderedRowVec);
+
+/// Re-export of rust-allocated (stack based) `VirtualListMeasuredRowVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzVirtualListMeasuredRowVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzVirtualListMeasuredRowVecDestructorType),
+}
+
+/// `AzVirtualListMeasuredRowVecDestructorType` struct
+pub type AzVirtualListMeasuredRowVecDestructorType = extern "C" fn(&mut AzVirtualListMeasuredRowVec);
+
 /// Re-export of rust-allocated (stack based) `CssDeclarationVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCssDeclarationVecDestructor {
@@ -3704,6 +4098,16 @@ pub enum AzNotEventFilter {
     Focus(AzFocusEventFilter),
 }
 
+/// Configures the arrow key navigation of a `FocusScope::Roving`
+#[repr(C)]
+#[pyclass(name = "RovingFocus")]
+pub struct AzRovingFocus {
+    #[pyo3(get, set)]
+    pub orientation: AzRovingFocusOrientationEnumWrapper,
+    #[pyo3(get, set)]
+    pub wrap: bool,
+}
+
 /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
 #[repr(C)]
 #[pyclass(name = "MenuCallback")]
@@ -4952,6 +5356,16 @@ pub struct AzCheckBoxOnToggle {
     pub callback: AzCheckBoxOnToggleCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+#[repr(C)]
+#[pyclass(name = "DropDownOnChoiceChange")]
+pub struct AzDropDownOnChoiceChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzDropDownOnChoiceChangeCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `ColorInputState` struct
 #[repr(C)]
 #[pyclass(name = "ColorInputState")]
@@ -5017,6 +5431,26 @@ pub struct AzOnTextInputReturn {
     pub valid: AzTextInputValidEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `TextInputCustomValidator` struct
+#[repr(C)]
+#[pyclass(name = "TextInputCustomValidator")]
+pub struct AzTextInputCustomValidator {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTextInputValidatorCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TextInputOnValidationChange` struct
+#[repr(C)]
+#[pyclass(name = "TextInputOnValidationChange")]
+pub struct AzTextInputOnValidationChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTextInputOnValidationChangeCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
 #[repr(C)]
 #[pyclass(name = "NumberInputOnValueChange")]
@@ -5027,6 +5461,176 @@ pub struct AzNumberInputOnValueChange {
     pub callback: AzNumberInputOnValueChangeCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `SplitPaneState` struct
+#[repr(C)]
+#[pyclass(name = "SplitPaneState")]
+pub struct AzSplitPaneState {
+    #[pyo3(get, set)]
+    pub orientation: AzSplitPaneOrientationEnumWrapper,
+    #[pyo3(get, set)]
+    pub ratio: f32,
+    #[pyo3(get, set)]
+    pub min_first_size: f32,
+    #[pyo3(get, set)]
+    pub min_second_size: f32,
+    #[pyo3(get, set)]
+    pub collapsible: bool,
+    #[pyo3(get, set)]
+    pub collapsed: AzSplitPaneCollapsedEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `SplitPaneOnResize` struct
+#[repr(C)]
+#[pyclass(name = "SplitPaneOnResize")]
+pub struct AzSplitPaneOnResize {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzSplitPaneOnResizeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabChange` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerOnTabChange")]
+pub struct AzTabContainerOnTabChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTabContainerOnTabChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabClose` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerOnTabClose")]
+pub struct AzTabContainerOnTabClose {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTabContainerOnTabCloseCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TabContainerOnTabMove` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerOnTabMove")]
+pub struct AzTabContainerOnTabMove {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTabContainerOnTabMoveCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderState` struct
+#[repr(C)]
+#[pyclass(name = "SliderState")]
+pub struct AzSliderState {
+    #[pyo3(get, set)]
+    pub value: f32,
+    #[pyo3(get, set)]
+    pub upper_value: AzOptionF32EnumWrapper,
+    #[pyo3(get, set)]
+    pub min: f32,
+    #[pyo3(get, set)]
+    pub max: f32,
+    #[pyo3(get, set)]
+    pub step: f32,
+    #[pyo3(get, set)]
+    pub orientation: AzSliderOrientationEnumWrapper,
+    #[pyo3(get, set)]
+    pub tick_interval: AzOptionF32EnumWrapper,
+    #[pyo3(get, set)]
+    pub active_thumb: AzSliderThumbEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+#[repr(C)]
+#[pyclass(name = "SliderOnValueChange")]
+pub struct AzSliderOnValueChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzSliderOnValueChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+#[repr(C)]
+#[pyclass(name = "TextEditOnTextChange")]
+pub struct AzTextEditOnTextChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTextEditOnTextChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewDropTarget` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewDropTarget")]
+pub struct AzTreeViewDropTarget {
+    #[pyo3(get, set)]
+    pub node: usize,
+    #[pyo3(get, set)]
+    pub position: AzTreeViewDropPositionEnumWrapper,
+}
+
+/// Passed to the `on_load_children` callback
+#[repr(C)]
+#[pyclass(name = "TreeViewLoadRequest")]
+pub struct AzTreeViewLoadRequest {
+    #[pyo3(get, set)]
+    pub node: usize,
+    #[pyo3(get, set)]
+    pub tree: AzRefAny,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnLoadChildren` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewOnLoadChildren")]
+pub struct AzTreeViewOnLoadChildren {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTreeViewOnLoadChildrenCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectionChange` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewOnSelectionChange")]
+pub struct AzTreeViewOnSelectionChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTreeViewOnSelectionChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnNodeMoved` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewOnNodeMoved")]
+pub struct AzTreeViewOnNodeMoved {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTreeViewOnNodeMovedCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `VirtualListRenderRow` struct
+#[repr(C)]
+#[pyclass(name = "VirtualListRenderRow")]
+pub struct AzVirtualListRenderRow {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzVirtualListRenderRowCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `VirtualListOnScroll` struct
+#[repr(C)]
+#[pyclass(name = "VirtualListOnScroll")]
+pub struct AzVirtualListOnScroll {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzVirtualListOnScrollCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
 #[repr(C)]
 #[pyclass(name = "ParentWithNodeDepth")]
@@ -5250,6 +5854,16 @@ pub struct AzSvgFillStyle {
     pub high_quality_aa: bool,
 }
 
+/// Start and end position of a node, attribute or text in the XML source
+#[repr(C)]
+#[pyclass(name = "XmlSpan")]
+pub struct AzXmlSpan {
+    #[pyo3(get, set)]
+    pub start: AzSvgParseErrorPosition,
+    #[pyo3(get, set)]
+    pub end: AzSvgParseErrorPosition,
+}
+
 /// Re-export of rust-allocated (stack based) `InstantPtr` struct
 #[repr(C)]
 #[pyclass(name = "InstantPtr")]
@@ -5325,6 +5939,19 @@ pub struct AzXmlNodeVec {
     pub destructor: AzXmlNodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<XmlSpan>`
+#[repr(C)]
+#[pyclass(name = "XmlSpanVec")]
+pub struct AzXmlSpanVec {
+    pub(crate) ptr: *const AzXmlSpan,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzXmlSpanVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<InlineGlyph>`
 #[repr(C)]
 #[pyclass(name = "InlineGlyphVec")]
@@ -5494,6 +6121,32 @@ pub struct AzScanCodeVec {
     pub destructor: AzScanCodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `TreeViewRow`
+#[repr(C)]
+#[pyclass(name = "TreeViewRowVec")]
+pub struct AzTreeViewRowVec {
+    pub(crate) ptr: *const AzTreeViewRow,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTreeViewRowVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `VirtualListMeasuredRow`
+#[repr(C)]
+#[pyclass(name = "VirtualListMeasuredRowVec")]
+pub struct AzVirtualListMeasuredRowVec {
+    pub(crate) ptr: *const AzVirtualListMeasuredRow,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzVirtualListMeasuredRowVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<u16>`
 #[repr(C)]
 #[pyclass(name = "U16Vec")]
@@ -5673,6 +6326,104 @@ pub enum AzOptionTextInputSelection {
     Some(AzTextInputSelection),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTextInputOnValidationChange` struct
+#[repr(C, u8)]
+pub enum AzOptionTextInputOnValidationChange {
+    None,
+    Some(AzTextInputOnValidationChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+#[repr(C, u8)]
+pub enum AzOptionDropDownOnChoiceChange {
+    None,
+    Some(AzDropDownOnChoiceChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabChange` struct
+#[repr(C, u8)]
+pub enum AzOptionTabContainerOnTabChange {
+    None,
+    Some(AzTabContainerOnTabChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabClose` struct
+#[repr(C, u8)]
+pub enum AzOptionTabContainerOnTabClose {
+    None,
+    Some(AzTabContainerOnTabClose),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTabContainerOnTabMove` struct
+#[repr(C, u8)]
+pub enum AzOptionTabContainerOnTabMove {
+    None,
+    Some(AzTabContainerOnTabMove),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSplitPaneOnResize` struct
+#[repr(C, u8)]
+pub enum AzOptionSplitPaneOnResize {
+    None,
+    Some(AzSplitPaneOnResize),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+#[repr(C, u8)]
+pub enum AzOptionSliderOnValueChange {
+    None,
+    Some(AzSliderOnValueChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+#[repr(C, u8)]
+pub enum AzOptionTextEditOnTextChange {
+    None,
+    Some(AzTextEditOnTextChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditCursor` struct
+#[repr(C, u8)]
+pub enum AzOptionTextEditCursor {
+    None,
+    Some(AzTextEditCursor),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnLoadChildren` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnLoadChildren {
+    None,
+    Some(AzTreeViewOnLoadChildren),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelectionChange` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnSelectionChange {
+    None,
+    Some(AzTreeViewOnSelectionChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnNodeMoved` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnNodeMoved {
+    None,
+    Some(AzTreeViewOnNodeMoved),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewDropTarget` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewDropTarget {
+    None,
+    Some(AzTreeViewDropTarget),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionVirtualListOnScroll` struct
+#[repr(C, u8)]
+pub enum AzOptionVirtualListOnScroll {
+    None,
+    Some(AzVirtualListOnScroll),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionNumberInputOnValueChange` struct
 #[repr(C, u8)]
 pub enum AzOptionNumberInputOnValueChange {
@@ -5994,6 +6745,8 @@ pub struct AzAppConfig {
     #[pyo3(get, set)]
     pub enable_tab_navigation: bool,
     #[pyo3(get, set)]
+    pub record_events: bool,
+    #[pyo3(get, set)]
     pub system_callbacks: AzSystemCallbacks,
 }
 
@@ -6052,28 +6805,6 @@ pub struct AzWindowSize {
     pub max_dimensions: AzOptionLogicalSizeEnumWrapper,
 }
 
-/// Current keyboard state, stores what keys / characters have been pressed
-#[repr(C)]
-#[pyclass(name = "KeyboardState")]
-pub struct AzKeyboardState {
-    #[pyo3(get, set)]
-    pub shift_down: bool,
-    #[pyo3(get, set)]
-    pub ctrl_down: bool,
-    #[pyo3(get, set)]
-    pub alt_down: bool,
-    #[pyo3(get, set)]
-    pub super_down: bool,
-    #[pyo3(get, set)]
-    pub current_char: AzOptionCharEnumWrapper,
-    #[pyo3(get, set)]
-    pub current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper,
-    #[pyo3(get, set)]
-    pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-    #[pyo3(get, set)]
-    pub pressed_scancodes: AzScanCodeVec,
-}
-
 /// Current mouse / cursor state
 #[repr(C)]
 #[pyclass(name = "MouseState")]
@@ -6115,6 +6846,18 @@ pub struct AzInlineTextContents {
     pub bounds: AzLogicalRect,
 }
 
+/// Data of an in-app drag operation, created by `CallbackInfo::start_drag`
+#[repr(C)]
+#[pyclass(name = "DragData")]
+pub struct AzDragData {
+    #[pyo3(get, set)]
+    pub source: AzDomNodeId,
+    #[pyo3(get, set)]
+    pub payload: AzRefAny,
+    #[pyo3(get, set)]
+    pub ghost: AzOptionImageRefEnumWrapper,
+}
+
 /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
 #[repr(C, u8)]
 pub enum AzAnimationEasing {
@@ -6172,6 +6915,13 @@ pub enum AzEventFilter {
     Application(AzApplicationEventFilter),
 }
 
+/// Restricts the keyboard focus navigation to the subtree of a node
+#[repr(C, u8)]
+pub enum AzFocusScope {
+    Trap,
+    Roving(AzRovingFocus),
+}
+
 /// Menu struct (application / window menu, dropdown menu, context menu). Modeled after the Windows API
 #[repr(C)]
 #[pyclass(name = "Menu")]
@@ -6201,6 +6951,9 @@ pub enum AzCssPathPseudoSelector {
     Hover,
     Active,
     Focus,
+    FocusVisible,
+    FocusWithin,
+    DragOver,
 }
 
 /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -6338,6 +7091,18 @@ pub struct AzCheckBoxStateWrapper {
     pub on_toggle: AzOptionCheckBoxOnToggleEnumWrapper,
 }
 
+/// Validator that is run on every change of the text, an empty text is only rejected by `NotEmpty`
+#[repr(C, u8)]
+pub enum AzTextInputValidator {
+    NotEmpty,
+    Integer,
+    Number,
+    Email,
+    Ipv4,
+    MinLength(usize),
+    Custom(AzTextInputCustomValidator),
+}
+
 /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
 #[repr(C)]
 #[pyclass(name = "NumberInputStateWrapper")]
@@ -6348,6 +7113,64 @@ pub struct AzNumberInputStateWrapper {
     pub on_value_change: AzOptionNumberInputOnValueChangeEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `SplitPaneStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "SplitPaneStateWrapper")]
+pub struct AzSplitPaneStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzSplitPaneState,
+    #[pyo3(get, set)]
+    pub on_resize: AzOptionSplitPaneOnResizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub is_dragging: bool,
+    #[pyo3(get, set)]
+    pub drag_position: f32,
+    #[pyo3(get, set)]
+    pub state_before_drag: AzSplitPaneState,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "SliderStateWrapper")]
+pub struct AzSliderStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzSliderState,
+    #[pyo3(get, set)]
+    pub on_value_change: AzOptionSliderOnValueChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub is_dragging: bool,
+    #[pyo3(get, set)]
+    pub drag_value: f32,
+}
+
+/// Re-export of rust-allocated (stack based) `VirtualListState` struct
+#[repr(C)]
+#[pyclass(name = "VirtualListState")]
+pub struct AzVirtualListState {
+    #[pyo3(get, set)]
+    pub row_count: usize,
+    #[pyo3(get, set)]
+    pub row_height: AzVirtualListRowHeightEnumWrapper,
+    #[pyo3(get, set)]
+    pub overscan: usize,
+    #[pyo3(get, set)]
+    pub scroll_offset: f32,
+    #[pyo3(get, set)]
+    pub viewport_height: f32,
+    #[pyo3(get, set)]
+    pub measured_rows: AzVirtualListMeasuredRowVec,
+}
+
+/// Nodes inside the IFrame that are updated in place when the list is scrolled
+#[repr(C)]
+#[pyclass(name = "VirtualListIFrameNodes")]
+pub struct AzVirtualListIFrameNodes {
+    #[pyo3(get, set)]
+    pub rows: AzDomNodeId,
+    #[pyo3(get, set)]
+    pub scrollbar_thumb: AzOptionDomNodeIdEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `StyledNode` struct
 #[repr(C)]
 #[pyclass(name = "StyledNode")]
@@ -6554,6 +7377,19 @@ pub struct AzSvgPathElementVec {
     pub destructor: AzSvgPathElementVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `TextInputValidator`
+#[repr(C)]
+#[pyclass(name = "TextInputValidatorVec")]
+pub struct AzTextInputValidatorVec {
+    pub(crate) ptr: *const AzTextInputValidatorEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTextInputValidatorVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `StringVec`
 #[repr(C)]
 #[pyclass(name = "StringVec")]
@@ -6593,6 +7429,13 @@ pub struct AzTagIdToNodeIdMappingVec {
     pub destructor: AzTagIdToNodeIdMappingVecDestructorEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `OptionVirtualListIFrameNodes` struct
+#[repr(C, u8)]
+pub enum AzOptionVirtualListIFrameNodes {
+    None,
+    Some(AzVirtualListIFrameNodes),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCodeCombo` struct
 #[repr(C, u8)]
 pub enum AzOptionVirtualKeyCodeCombo {
@@ -6600,18 +7443,18 @@ pub enum AzOptionVirtualKeyCodeCombo {
     Some(AzVirtualKeyCodeCombo),
 }
 
-/// Re-export of rust-allocated (stack based) `OptionMouseState` struct
+/// Re-export of rust-allocated (stack based) `OptionDragData` struct
 #[repr(C, u8)]
-pub enum AzOptionMouseState {
+pub enum AzOptionDragData {
     None,
-    Some(AzMouseState),
+    Some(AzDragData),
 }
 
-/// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+/// Re-export of rust-allocated (stack based) `OptionMouseState` struct
 #[repr(C, u8)]
-pub enum AzOptionKeyboardState {
+pub enum AzOptionMouseState {
     None,
-    Some(AzKeyboardState),
+    Some(AzMouseState),
 }
 
 /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
@@ -6803,6 +7646,18 @@ pub struct AzStringPair {
     pub value: AzString,
 }
 
+/// Pre-edit text of an input method, the IME shows its candidate window at the `WindowState.ime_position`
+#[repr(C)]
+#[pyclass(name = "ImeComposition")]
+pub struct AzImeComposition {
+    #[pyo3(get, set)]
+    pub text: AzString,
+    #[pyo3(get, set)]
+    pub cursor_start: usize,
+    #[pyo3(get, set)]
+    pub cursor_end: usize,
+}
+
 /// Information about a single (or many) monitors, useful for dock widgets
 #[repr(C)]
 #[pyclass(name = "Monitor")]
@@ -6987,6 +7842,18 @@ pub enum AzStyleTransformVecValue {
     Exact(AzStyleTransformVec),
 }
 
+/// Single selectable entry of a `DropDown`
+#[repr(C)]
+#[pyclass(name = "DropDownChoice")]
+pub struct AzDropDownChoice {
+    #[pyo3(get, set)]
+    pub label: AzString,
+    #[pyo3(get, set)]
+    pub disabled: bool,
+    #[pyo3(get, set)]
+    pub group: AzOptionStringEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `ColorInputStateWrapper` struct
 #[repr(C)]
 #[pyclass(name = "ColorInputStateWrapper")]
@@ -6999,20 +7866,99 @@ pub struct AzColorInputStateWrapper {
     pub on_value_change: AzOptionColorInputOnValueChangeEnumWrapper,
 }
 
-/// Re-export of rust-allocated (stack based) `TextInputState` struct
+/// Re-export of rust-allocated (stack based) `TextInputValidationResult` struct
+#[repr(C, u8)]
+pub enum AzTextInputValidationResult {
+    Valid,
+    Invalid(AzString),
+}
+
+/// Input mask: `9` = digit, `#` = optional digit, `a` = letter, `*` = letter or digit, other characters are literals that are inserted automatically (`99/99/9999`)
 #[repr(C)]
-#[pyclass(name = "TextInputState")]
-pub struct AzTextInputState {
+#[pyclass(name = "TextInputMask")]
+pub struct AzTextInputMask {
     #[pyo3(get, set)]
-    pub text: AzU32Vec,
+    pub pattern: AzString,
+}
+
+/// Header of a single tab
+#[repr(C)]
+#[pyclass(name = "TabContainerTab")]
+pub struct AzTabContainerTab {
     #[pyo3(get, set)]
-    pub placeholder: AzOptionStringEnumWrapper,
+    pub title: AzString,
     #[pyo3(get, set)]
-    pub max_len: usize,
+    pub closable: bool,
     #[pyo3(get, set)]
-    pub selection: AzOptionTextInputSelectionEnumWrapper,
+    pub page: usize,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditState` struct
+#[repr(C)]
+#[pyclass(name = "TextEditState")]
+pub struct AzTextEditState {
     #[pyo3(get, set)]
-    pub cursor_pos: usize,
+    pub lines: AzStringVec,
+    #[pyo3(get, set)]
+    pub cursor: AzTextEditCursor,
+    #[pyo3(get, set)]
+    pub selection_anchor: AzOptionTextEditCursorEnumWrapper,
+    #[pyo3(get, set)]
+    pub preferred_column: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub first_visible_row: usize,
+    #[pyo3(get, set)]
+    pub visible_rows: usize,
+    #[pyo3(get, set)]
+    pub wrap_column: usize,
+}
+
+/// Reversible edit: `removed` was replaced by `inserted` at `start`
+#[repr(C)]
+#[pyclass(name = "TextEditUndoEntry")]
+pub struct AzTextEditUndoEntry {
+    #[pyo3(get, set)]
+    pub start: AzTextEditCursor,
+    #[pyo3(get, set)]
+    pub removed: AzString,
+    #[pyo3(get, set)]
+    pub inserted: AzString,
+    #[pyo3(get, set)]
+    pub cursor_before: AzTextEditCursor,
+    #[pyo3(get, set)]
+    pub kind: AzTextEditEditKindEnumWrapper,
+}
+
+/// User-facing content of a node
+#[repr(C)]
+#[pyclass(name = "TreeViewNodeData")]
+pub struct AzTreeViewNodeData {
+    #[pyo3(get, set)]
+    pub label: AzString,
+    #[pyo3(get, set)]
+    pub icon: AzOptionImageRefEnumWrapper,
+    #[pyo3(get, set)]
+    pub children: AzTreeViewChildrenEnumWrapper,
+}
+
+/// Content of one row of the row pool, `node` is `None` for the empty rows below the last node
+#[repr(C)]
+#[pyclass(name = "TreeViewRenderedRow")]
+pub struct AzTreeViewRenderedRow {
+    #[pyo3(get, set)]
+    pub node: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub depth: usize,
+    #[pyo3(get, set)]
+    pub toggle: AzString,
+    #[pyo3(get, set)]
+    pub icon: AzOptionImageRefEnumWrapper,
+    #[pyo3(get, set)]
+    pub label: AzString,
+    #[pyo3(get, set)]
+    pub is_selected: bool,
+    #[pyo3(get, set)]
+    pub is_cursor: bool,
 }
 
 /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
@@ -7303,6 +8249,71 @@ pub struct AzVertexAttributeVec {
     pub destructor: AzVertexAttributeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `DropDownChoice`
+#[repr(C)]
+#[pyclass(name = "DropDownChoiceVec")]
+pub struct AzDropDownChoiceVec {
+    pub(crate) ptr: *const AzDropDownChoice,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzDropDownChoiceVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `TabContainerTab`
+#[repr(C)]
+#[pyclass(name = "TabContainerTabVec")]
+pub struct AzTabContainerTabVec {
+    pub(crate) ptr: *const AzTabContainerTab,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTabContainerTabVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `TextEditUndoEntry`
+#[repr(C)]
+#[pyclass(name = "TextEditUndoEntryVec")]
+pub struct AzTextEditUndoEntryVec {
+    pub(crate) ptr: *const AzTextEditUndoEntry,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTextEditUndoEntryVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `TreeViewNodeData`
+#[repr(C)]
+#[pyclass(name = "TreeViewNodeDataVec")]
+pub struct AzTreeViewNodeDataVec {
+    pub(crate) ptr: *const AzTreeViewNodeData,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTreeViewNodeDataVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `TreeViewRenderedRow`
+#[repr(C)]
+#[pyclass(name = "TreeViewRenderedRowVec")]
+pub struct AzTreeViewRenderedRowVec {
+    pub(crate) ptr: *const AzTreeViewRenderedRow,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTreeViewRenderedRowVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `CssPathSelector`
 #[repr(C)]
 #[pyclass(name = "CssPathSelectorVec")]
@@ -7355,6 +8366,20 @@ pub struct AzStringPairVec {
     pub destructor: AzStringPairVecDestructorEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTextInputMask` struct
+#[repr(C, u8)]
+pub enum AzOptionTextInputMask {
+    None,
+    Some(AzTextInputMask),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+#[repr(C, u8)]
+pub enum AzOptionImeComposition {
+    None,
+    Some(AzImeComposition),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionFileTypeList` struct
 #[repr(C, u8)]
 pub enum AzOptionFileTypeList {
@@ -7408,6 +8433,30 @@ pub enum AzXmlStreamError {
     InvalidCharacterData,
 }
 
+/// Current keyboard state, stores what keys / characters have been pressed
+#[repr(C)]
+#[pyclass(name = "KeyboardState")]
+pub struct AzKeyboardState {
+    #[pyo3(get, set)]
+    pub shift_down: bool,
+    #[pyo3(get, set)]
+    pub ctrl_down: bool,
+    #[pyo3(get, set)]
+    pub alt_down: bool,
+    #[pyo3(get, set)]
+    pub super_down: bool,
+    #[pyo3(get, set)]
+    pub current_char: AzOptionCharEnumWrapper,
+    #[pyo3(get, set)]
+    pub current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper,
+    #[pyo3(get, set)]
+    pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+    #[pyo3(get, set)]
+    pub pressed_scancodes: AzScanCodeVec,
+    #[pyo3(get, set)]
+    pub ime_composition: AzOptionImeCompositionEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
 #[repr(C)]
 #[pyclass(name = "LinuxWindowOptions")]
@@ -7559,22 +8608,116 @@ pub enum AzCssProperty {
     BackfaceVisibility(AzStyleBackfaceVisibilityValue),
 }
 
-/// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+/// Re-export of rust-allocated (stack based) `DropDownState` struct
 #[repr(C)]
-#[pyclass(name = "TextInputStateWrapper")]
-pub struct AzTextInputStateWrapper {
+#[pyclass(name = "DropDownState")]
+pub struct AzDropDownState {
     #[pyo3(get, set)]
-    pub inner: AzTextInputState,
+    pub choices: AzDropDownChoiceVec,
     #[pyo3(get, set)]
-    pub on_text_input: AzOptionTextInputOnTextInputEnumWrapper,
+    pub selected: AzOptionUsizeEnumWrapper,
     #[pyo3(get, set)]
-    pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper,
+    pub highlighted: AzOptionUsizeEnumWrapper,
     #[pyo3(get, set)]
-    pub on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper,
+    pub is_open: bool,
     #[pyo3(get, set)]
-    pub update_text_input_before_calling_focus_lost_fn: bool,
+    pub search: AzString,
+}
+
+/// Re-export of rust-allocated (stack based) `TextInputState` struct
+#[repr(C)]
+#[pyclass(name = "TextInputState")]
+pub struct AzTextInputState {
     #[pyo3(get, set)]
-    pub update_text_input_before_calling_vk_down_fn: bool,
+    pub text: AzU32Vec,
+    #[pyo3(get, set)]
+    pub placeholder: AzOptionStringEnumWrapper,
+    #[pyo3(get, set)]
+    pub max_len: usize,
+    #[pyo3(get, set)]
+    pub selection: AzOptionTextInputSelectionEnumWrapper,
+    #[pyo3(get, set)]
+    pub cursor_pos: usize,
+    #[pyo3(get, set)]
+    pub validators: AzTextInputValidatorVec,
+    #[pyo3(get, set)]
+    pub mask: AzOptionTextInputMaskEnumWrapper,
+    #[pyo3(get, set)]
+    pub obscure_char: AzOptionCharEnumWrapper,
+    #[pyo3(get, set)]
+    pub validation: AzTextInputValidationResultEnumWrapper,
+    #[pyo3(get, set)]
+    pub ime_composition: AzOptionImeCompositionEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `TabContainerState` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerState")]
+pub struct AzTabContainerState {
+    #[pyo3(get, set)]
+    pub tabs: AzTabContainerTabVec,
+    #[pyo3(get, set)]
+    pub active_tab: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub scroll_offset: f32,
+    #[pyo3(get, set)]
+    pub overflow: AzTabContainerOverflowEnumWrapper,
+    #[pyo3(get, set)]
+    pub menu_open: bool,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "TextEditStateWrapper")]
+pub struct AzTextEditStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzTextEditState,
+    #[pyo3(get, set)]
+    pub on_text_change: AzOptionTextEditOnTextChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub undo_stack: AzTextEditUndoEntryVec,
+    #[pyo3(get, set)]
+    pub redo_stack: AzTextEditUndoEntryVec,
+    #[pyo3(get, set)]
+    pub wrap_lines: bool,
+    #[pyo3(get, set)]
+    pub char_width: f32,
+    #[pyo3(get, set)]
+    pub rendered_rows: AzStringVec,
+    #[pyo3(get, set)]
+    pub is_selecting: bool,
+}
+
+/// Node in the arena of the `TreeViewState`, the links are indices into `TreeViewState::nodes`
+#[repr(C)]
+#[pyclass(name = "TreeViewNode")]
+pub struct AzTreeViewNode {
+    #[pyo3(get, set)]
+    pub data: AzTreeViewNodeData,
+    #[pyo3(get, set)]
+    pub parent: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub first_child: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub last_child: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub previous_sibling: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub next_sibling: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub is_expanded: bool,
+    #[pyo3(get, set)]
+    pub is_selected: bool,
+}
+
+/// Children that were loaded on a background thread
+#[repr(C)]
+#[pyclass(name = "TreeViewLoadedChildren")]
+pub struct AzTreeViewLoadedChildren {
+    #[pyo3(get, set)]
+    pub node: usize,
+    #[pyo3(get, set)]
+    pub children: AzTreeViewNodeDataVec,
 }
 
 /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
@@ -7642,6 +8785,12 @@ pub struct AzXmlNode {
     pub children: AzXmlNodeVec,
     #[pyo3(get, set)]
     pub text: AzOptionStringEnumWrapper,
+    #[pyo3(get, set)]
+    pub span: AzXmlSpan,
+    #[pyo3(get, set)]
+    pub attribute_spans: AzXmlSpanVec,
+    #[pyo3(get, set)]
+    pub text_span: AzXmlSpan,
 }
 
 /// Wrapper over a Rust-allocated `Vec<InlineLine>`
@@ -7683,6 +8832,19 @@ pub struct AzSvgMultiPolygonVec {
     pub destructor: AzSvgMultiPolygonVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `TreeViewNode`
+#[repr(C)]
+#[pyclass(name = "TreeViewNodeVec")]
+pub struct AzTreeViewNodeVec {
+    pub(crate) ptr: *const AzTreeViewNode,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTreeViewNodeVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
 #[repr(C, u8)]
 pub enum AzOptionCssProperty {
@@ -7690,6 +8852,13 @@ pub enum AzOptionCssProperty {
     Some(AzCssProperty),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+#[repr(C, u8)]
+pub enum AzOptionKeyboardState {
+    None,
+    Some(AzKeyboardState),
+}
+
 /// Re-export of rust-allocated (stack based) `XmlTextError` struct
 #[repr(C)]
 #[pyclass(name = "XmlTextError")]
@@ -7776,6 +8945,7 @@ pub struct AzCallbackInfo {
     pub datasets: *mut c_void,
     pub stop_propagation: *mut bool,
     pub focus_target: *mut c_void,
+    pub drag_started: *mut c_void,
     pub words_cache: *const c_void,
     pub shaped_words_cache: *const c_void,
     pub positioned_words_cache: *const c_void,
@@ -7879,6 +9049,88 @@ pub struct AzDynamicCssProperty {
     pub default_value: AzCssPropertyEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "DropDownStateWrapper")]
+pub struct AzDropDownStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzDropDownState,
+    #[pyo3(get, set)]
+    pub on_choice_change: AzOptionDropDownOnChoiceChangeEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "TextInputStateWrapper")]
+pub struct AzTextInputStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzTextInputState,
+    #[pyo3(get, set)]
+    pub on_text_input: AzOptionTextInputOnTextInputEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper,
+    #[pyo3(get, set)]
+    pub update_text_input_before_calling_focus_lost_fn: bool,
+    #[pyo3(get, set)]
+    pub update_text_input_before_calling_vk_down_fn: bool,
+    #[pyo3(get, set)]
+    pub on_validation_change: AzOptionTextInputOnValidationChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub error_style: AzCssPropertyVec,
+    #[pyo3(get, set)]
+    pub style_before_error: AzCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `TabContainerStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "TabContainerStateWrapper")]
+pub struct AzTabContainerStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzTabContainerState,
+    #[pyo3(get, set)]
+    pub on_tab_change: AzOptionTabContainerOnTabChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_tab_close: AzOptionTabContainerOnTabCloseEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_tab_move: AzOptionTabContainerOnTabMoveEnumWrapper,
+    #[pyo3(get, set)]
+    pub allow_reorder: bool,
+    #[pyo3(get, set)]
+    pub tab_count: usize,
+    #[pyo3(get, set)]
+    pub pressed_tab: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub dragged_tab: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub drop_index: AzOptionUsizeEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewState` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewState")]
+pub struct AzTreeViewState {
+    #[pyo3(get, set)]
+    pub nodes: AzTreeViewNodeVec,
+    #[pyo3(get, set)]
+    pub first_root: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub last_root: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub rows: AzTreeViewRowVec,
+    #[pyo3(get, set)]
+    pub cursor: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub selection_anchor: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub selection_mode: AzTreeViewSelectionModeEnumWrapper,
+    #[pyo3(get, set)]
+    pub first_visible_row: usize,
+    #[pyo3(get, set)]
+    pub visible_rows: usize,
+}
+
 /// Re-export of rust-allocated (stack based) `SvgNode` struct
 #[repr(C, u8)]
 pub enum AzSvgNode {
@@ -8027,6 +9279,24 @@ pub struct AzCheckBox {
     pub content_style: AzNodeDataInlineCssPropertyVec,
 }
 
+/// Re-export of rust-allocated (stack based) `DropDown` struct
+#[repr(C)]
+#[pyclass(name = "DropDown")]
+pub struct AzDropDown {
+    #[pyo3(get, set)]
+    pub state: AzDropDownStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub header_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub popup_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub choice_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub group_style: AzNodeDataInlineCssPropertyVec,
+}
+
 /// Re-export of rust-allocated (stack based) `Label` struct
 #[repr(C)]
 #[pyclass(name = "Label")]
@@ -8059,6 +9329,8 @@ pub struct AzTextInput {
     pub container_style: AzNodeDataInlineCssPropertyVec,
     #[pyo3(get, set)]
     pub label_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub preedit_style: AzNodeDataInlineCssPropertyVec,
 }
 
 /// Re-export of rust-allocated (stack based) `NumberInput` struct
@@ -8085,6 +9357,130 @@ pub struct AzProgressBar {
     pub label_style: AzNodeDataInlineCssPropertyVec,
 }
 
+/// Re-export of rust-allocated (stack based) `TabContainer` struct
+#[repr(C)]
+#[pyclass(name = "TabContainer")]
+pub struct AzTabContainer {
+    #[pyo3(get, set)]
+    pub state: AzTabContainerStateWrapper,
+    #[pyo3(get, set)]
+    pub pages: AzDomVec,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub header_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub tab_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub close_button_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub button_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub menu_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub page_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `Slider` struct
+#[repr(C)]
+#[pyclass(name = "Slider")]
+pub struct AzSlider {
+    #[pyo3(get, set)]
+    pub state: AzSliderStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub track_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub fill_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub thumb_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub tick_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEdit` struct
+#[repr(C)]
+#[pyclass(name = "TextEdit")]
+pub struct AzTextEdit {
+    #[pyo3(get, set)]
+    pub state: AzTextEditStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub row_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub label_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub selection_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub caret_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "TreeViewStateWrapper")]
+pub struct AzTreeViewStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzTreeViewState,
+    #[pyo3(get, set)]
+    pub on_load_children: AzOptionTreeViewOnLoadChildrenEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_selection_change: AzOptionTreeViewOnSelectionChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_node_moved: AzOptionTreeViewOnNodeMovedEnumWrapper,
+    #[pyo3(get, set)]
+    pub allow_reorder: bool,
+    #[pyo3(get, set)]
+    pub rendered_rows: AzTreeViewRenderedRowVec,
+    #[pyo3(get, set)]
+    pub pressed_node: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub dragged_node: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub drop_target: AzOptionTreeViewDropTargetEnumWrapper,
+}
+
+/// Virtualized list: renders only the rows that are visible, so that lists with millions of rows stay cheap to lay out
+#[repr(C)]
+#[pyclass(name = "VirtualList")]
+pub struct AzVirtualList {
+    #[pyo3(get, set)]
+    pub state: AzRefAny,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub row_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub scrollbar_track_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `VirtualListStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "VirtualListStateWrapper")]
+pub struct AzVirtualListStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzVirtualListState,
+    #[pyo3(get, set)]
+    pub render_row: AzVirtualListRenderRow,
+    #[pyo3(get, set)]
+    pub on_scroll: AzOptionVirtualListOnScrollEnumWrapper,
+    #[pyo3(get, set)]
+    pub row_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub scrollbar_track_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub rendered_rows_start: usize,
+    #[pyo3(get, set)]
+    pub rendered_rows_end: usize,
+    #[pyo3(get, set)]
+    pub iframe_nodes: AzOptionVirtualListIFrameNodesEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `CssDeclaration`
 #[repr(C)]
 #[pyclass(name = "CssDeclarationVec")]
@@ -8155,6 +9551,48 @@ pub struct AzCssRuleBlock {
     pub declarations: AzCssDeclarationVec,
 }
 
+/// Re-export of rust-allocated (stack based) `SplitPane` struct
+#[repr(C)]
+#[pyclass(name = "SplitPane")]
+pub struct AzSplitPane {
+    #[pyo3(get, set)]
+    pub state: AzSplitPaneStateWrapper,
+    #[pyo3(get, set)]
+    pub first_pane: AzDom,
+    #[pyo3(get, set)]
+    pub second_pane: AzDom,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub pane_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub divider_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeView` struct
+#[repr(C)]
+#[pyclass(name = "TreeView")]
+pub struct AzTreeView {
+    #[pyo3(get, set)]
+    pub state: AzTreeViewStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub row_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub guides_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub toggle_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub icon_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub label_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub drop_indicator_style: AzNodeDataInlineCssPropertyVec,
+}
+
 /// Re-export of rust-allocated (stack based) `StyledDom` struct
 #[repr(C)]
 #[pyclass(name = "StyledDom")]
@@ -8456,6 +9894,13 @@ pub struct AzTabIndexEnumWrapper {
     pub inner: AzTabIndex,
 }
 
+/// `AzRovingFocusOrientationEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "RovingFocusOrientation")]
+pub struct AzRovingFocusOrientationEnumWrapper {
+    pub inner: AzRovingFocusOrientation,
+}
+
 /// `AzContextMenuMouseButtonEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "ContextMenuMouseButton")]
@@ -8652,6 +10097,76 @@ pub struct AzTextInputValidEnumWrapper {
     pub inner: AzTextInputValid,
 }
 
+/// `AzSplitPaneOrientationEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SplitPaneOrientation")]
+pub struct AzSplitPaneOrientationEnumWrapper {
+    pub inner: AzSplitPaneOrientation,
+}
+
+/// `AzSplitPaneCollapsedEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SplitPaneCollapsed")]
+pub struct AzSplitPaneCollapsedEnumWrapper {
+    pub inner: AzSplitPaneCollapsed,
+}
+
+/// `AzTabContainerOverflowEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TabContainerOverflow")]
+pub struct AzTabContainerOverflowEnumWrapper {
+    pub inner: AzTabContainerOverflow,
+}
+
+/// `AzSliderOrientationEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SliderOrientation")]
+pub struct AzSliderOrientationEnumWrapper {
+    pub inner: AzSliderOrientation,
+}
+
+/// `AzSliderThumbEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SliderThumb")]
+pub struct AzSliderThumbEnumWrapper {
+    pub inner: AzSliderThumb,
+}
+
+/// `AzTextEditEditKindEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TextEditEditKind")]
+pub struct AzTextEditEditKindEnumWrapper {
+    pub inner: AzTextEditEditKind,
+}
+
+/// `AzTreeViewChildrenEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TreeViewChildren")]
+pub struct AzTreeViewChildrenEnumWrapper {
+    pub inner: AzTreeViewChildren,
+}
+
+/// `AzTreeViewSelectionModeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TreeViewSelectionMode")]
+pub struct AzTreeViewSelectionModeEnumWrapper {
+    pub inner: AzTreeViewSelectionMode,
+}
+
+/// `AzTreeViewDropPositionEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TreeViewDropPosition")]
+pub struct AzTreeViewDropPositionEnumWrapper {
+    pub inner: AzTreeViewDropPosition,
+}
+
+/// `AzVirtualListRowHeightEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "VirtualListRowHeight")]
+pub struct AzVirtualListRowHeightEnumWrapper {
+    pub inner: AzVirtualListRowHeight,
+}
+
 /// `AzVertexAttributeTypeEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "VertexAttributeType")]
@@ -8820,6 +10335,13 @@ pub struct AzXmlNodeVecDestructorEnumWrapper {
     pub inner: AzXmlNodeVecDestructor,
 }
 
+/// `AzXmlSpanVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "XmlSpanVecDestructor")]
+pub struct AzXmlSpanVecDestructorEnumWrapper {
+    pub inner: AzXmlSpanVecDestructor,
+}
+
 /// `AzFmtArgVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "FmtArgVecDestructor")]
@@ -9002,6 +10524,69 @@ pub struct AzScanCodeVecDestructorEnumWrapper {
     pub inner: AzScanCodeVecDestructor,
 }
 
+/// `AzTextInputValidatorVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TextInputValidatorVecDestructor")]
+pub struct AzTextInputValidatorVecDestructorEnumWrapper {
+    pub inner: AzTextInputValidatorVecDestructor,
+}
+
+/// `AzDropDownChoiceVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "DropDownChoiceVecDestructor")]
+pub struct AzDropDownChoiceVecDestructorEnumWrapper {
+    pub inner: AzDropDownChoiceVecDestructor,
+}
+
+/// `AzTabContainerTabVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TabContainerTabVecDestructor")]
+pub struct AzTabContainerTabVecDestructorEnumWrapper {
+    pub inner: AzTabContainerTabVecDestructor,
+}
+
+/// `AzTextEditUndoEntryVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TextEditUndoEntryVecDestructor")]
+pub struct AzTextEditUndoEntryVecDestructorEnumWrapper {
+    pub inner: AzTextEditUndoEntryVecDestructor,
+}
+
+/// `AzTreeViewNodeDataVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TreeViewNodeDataVecDestructor")]
+pub struct AzTreeViewNodeDataVecDestructorEnumWrapper {
+    pub inner: AzTreeViewNodeDataVecDestructor,
+}
+
+/// `AzTreeViewNodeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TreeViewNodeVecDestructor")]
+pub struct AzTreeViewNodeVecDestructorEnumWrapper {
+    pub inner: AzTreeViewNodeVecDestructor,
+}
+
+/// `AzTreeViewRowVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TreeViewRowVecDestructor")]
+pub struct AzTreeViewRowVecDestructorEnumWrapper {
+    pub inner: AzTreeViewRowVecDestructor,
+}
+
+/// `AzTreeViewRenderedRowVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TreeViewRenderedRowVecDestructor")]
+pub struct AzTreeViewRenderedRowVecDestructorEnumWrapper {
+    pub inner: AzTreeViewRenderedRowVecDestructor,
+}
+
+/// `AzVirtualListMeasuredRowVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "VirtualListMeasuredRowVecDestructor")]
+pub struct AzVirtualListMeasuredRowVecDestructorEnumWrapper {
+    pub inner: AzVirtualListMeasuredRowVecDestructor,
+}
+
 /// `AzCssDeclarationVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CssDeclarationVecDestructor")]
@@ -9786,6 +11371,104 @@ pub struct AzOptionTextInputSelectionEnumWrapper {
     pub inner: AzOptionTextInputSelection,
 }
 
+/// `AzOptionTextInputOnValidationChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTextInputOnValidationChange")]
+pub struct AzOptionTextInputOnValidationChangeEnumWrapper {
+    pub inner: AzOptionTextInputOnValidationChange,
+}
+
+/// `AzOptionDropDownOnChoiceChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionDropDownOnChoiceChange")]
+pub struct AzOptionDropDownOnChoiceChangeEnumWrapper {
+    pub inner: AzOptionDropDownOnChoiceChange,
+}
+
+/// `AzOptionTabContainerOnTabChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTabContainerOnTabChange")]
+pub struct AzOptionTabContainerOnTabChangeEnumWrapper {
+    pub inner: AzOptionTabContainerOnTabChange,
+}
+
+/// `AzOptionTabContainerOnTabCloseEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTabContainerOnTabClose")]
+pub struct AzOptionTabContainerOnTabCloseEnumWrapper {
+    pub inner: AzOptionTabContainerOnTabClose,
+}
+
+/// `AzOptionTabContainerOnTabMoveEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTabContainerOnTabMove")]
+pub struct AzOptionTabContainerOnTabMoveEnumWrapper {
+    pub inner: AzOptionTabContainerOnTabMove,
+}
+
+/// `AzOptionSplitPaneOnResizeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSplitPaneOnResize")]
+pub struct AzOptionSplitPaneOnResizeEnumWrapper {
+    pub inner: AzOptionSplitPaneOnResize,
+}
+
+/// `AzOptionSliderOnValueChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSliderOnValueChange")]
+pub struct AzOptionSliderOnValueChangeEnumWrapper {
+    pub inner: AzOptionSliderOnValueChange,
+}
+
+/// `AzOptionTextEditOnTextChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTextEditOnTextChange")]
+pub struct AzOptionTextEditOnTextChangeEnumWrapper {
+    pub inner: AzOptionTextEditOnTextChange,
+}
+
+/// `AzOptionTextEditCursorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTextEditCursor")]
+pub struct AzOptionTextEditCursorEnumWrapper {
+    pub inner: AzOptionTextEditCursor,
+}
+
+/// `AzOptionTreeViewOnLoadChildrenEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTreeViewOnLoadChildren")]
+pub struct AzOptionTreeViewOnLoadChildrenEnumWrapper {
+    pub inner: AzOptionTreeViewOnLoadChildren,
+}
+
+/// `AzOptionTreeViewOnSelectionChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTreeViewOnSelectionChange")]
+pub struct AzOptionTreeViewOnSelectionChangeEnumWrapper {
+    pub inner: AzOptionTreeViewOnSelectionChange,
+}
+
+/// `AzOptionTreeViewOnNodeMovedEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTreeViewOnNodeMoved")]
+pub struct AzOptionTreeViewOnNodeMovedEnumWrapper {
+    pub inner: AzOptionTreeViewOnNodeMoved,
+}
+
+/// `AzOptionTreeViewDropTargetEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTreeViewDropTarget")]
+pub struct AzOptionTreeViewDropTargetEnumWrapper {
+    pub inner: AzOptionTreeViewDropTarget,
+}
+
+/// `AzOptionVirtualListOnScrollEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionVirtualListOnScroll")]
+pub struct AzOptionVirtualListOnScrollEnumWrapper {
+    pub inner: AzOptionVirtualListOnScroll,
+}
+
 /// `AzOptionNumberInputOnValueChangeEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionNumberInputOnValueChange")]
@@ -10059,6 +11742,13 @@ pub struct AzEventFilterEnumWrapper {
     pub inner: AzEventFilter,
 }
 
+/// `AzFocusScopeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "FocusScope")]
+pub struct AzFocusScopeEnumWrapper {
+    pub inner: AzFocusScope,
+}
+
 /// `AzCssPathPseudoSelectorEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CssPathPseudoSelector")]
@@ -10101,6 +11791,13 @@ pub struct AzStyleBackgroundSizeVecValueEnumWrapper {
     pub inner: AzStyleBackgroundSizeVecValue,
 }
 
+/// `AzTextInputValidatorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TextInputValidator")]
+pub struct AzTextInputValidatorEnumWrapper {
+    pub inner: AzTextInputValidator,
+}
+
 /// `AzRawImageDataEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "RawImageData")]
@@ -10129,6 +11826,13 @@ pub struct AzThreadReceiveMsgEnumWrapper {
     pub inner: AzThreadReceiveMsg,
 }
 
+/// `AzOptionVirtualListIFrameNodesEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionVirtualListIFrameNodes")]
+pub struct AzOptionVirtualListIFrameNodesEnumWrapper {
+    pub inner: AzOptionVirtualListIFrameNodes,
+}
+
 /// `AzOptionVirtualKeyCodeComboEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionVirtualKeyCodeCombo")]
@@ -10136,6 +11840,13 @@ pub struct AzOptionVirtualKeyCodeComboEnumWrapper {
     pub inner: AzOptionVirtualKeyCodeCombo,
 }
 
+/// `AzOptionDragDataEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionDragData")]
+pub struct AzOptionDragDataEnumWrapper {
+    pub inner: AzOptionDragData,
+}
+
 /// `AzOptionMouseStateEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionMouseState")]
@@ -10143,13 +11854,6 @@ pub struct AzOptionMouseStateEnumWrapper {
     pub inner: AzOptionMouseState,
 }
 
-/// `AzOptionKeyboardStateEnumWrapper` struct
-#[repr(transparent)]
-#[pyclass(name = "OptionKeyboardState")]
-pub struct AzOptionKeyboardStateEnumWrapper {
-    pub inner: AzOptionKeyboardState,
-}
-
 /// `AzOptionStringVecEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionStringVec")]
@@ -10262,6 +11966,13 @@ pub struct AzStyleTransformVecValueEnumWrapper {
     pub inner: AzStyleTransformVecValue,
 }
 
+/// `AzTextInputValidationResultEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TextInputValidationResult")]
+pub struct AzTextInputValidationResultEnumWrapper {
+    pub inner: AzTextInputValidationResult,
+}
+
 /// `AzSvgStyleEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "SvgStyle")]
@@ -10276,6 +11987,20 @@ pub struct AzFmtValueEnumWrapper {
     pub inner: AzFmtValue,
 }
 
+/// `AzOptionTextInputMaskEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTextInputMask")]
+pub struct AzOptionTextInputMaskEnumWrapper {
+    pub inner: AzOptionTextInputMask,
+}
+
+/// `AzOptionImeCompositionEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionImeComposition")]
+pub struct AzOptionImeCompositionEnumWrapper {
+    pub inner: AzOptionImeComposition,
+}
+
 /// `AzOptionFileTypeListEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionFileTypeList")]
@@ -10360,6 +12085,13 @@ pub struct AzOptionCssPropertyEnumWrapper {
     pub inner: AzOptionCssProperty,
 }
 
+/// `AzOptionKeyboardStateEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionKeyboardState")]
+pub struct AzOptionKeyboardStateEnumWrapper {
+    pub inner: AzOptionKeyboardState,
+}
+
 /// `AzNodeDataInlineCssPropertyEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "NodeDataInlineCssProperty")]
@@ -10498,6 +12230,7 @@ unsafe impl Send for AzInstantPtr { }
 unsafe impl Send for AzAccessibilityStateVec { }
 unsafe impl Send for AzMenuItemVec { }
 unsafe impl Send for AzXmlNodeVec { }
+unsafe impl Send for AzXmlSpanVec { }
 unsafe impl Send for AzInlineGlyphVec { }
 unsafe impl Send for AzInlineTextHitVec { }
 unsafe impl Send for AzVideoModeVec { }
@@ -10511,6 +12244,8 @@ unsafe impl Send for AzXWindowTypeVec { }
 unsafe impl Send for AzVirtualKeyCodeVec { }
 unsafe impl Send for AzCascadeInfoVec { }
 unsafe impl Send for AzScanCodeVec { }
+unsafe impl Send for AzTreeViewRowVec { }
+unsafe impl Send for AzVirtualListMeasuredRowVec { }
 unsafe impl Send for AzU16Vec { }
 unsafe impl Send for AzF32Vec { }
 unsafe impl Send for AzU8Vec { }
@@ -10527,6 +12262,7 @@ unsafe impl Send for AzTessellatedSvgNodeVecRef { }
 unsafe impl Send for AzTessellatedSvgNodeVec { }
 unsafe impl Send for AzStyleTransformVec { }
 unsafe impl Send for AzSvgPathElementVec { }
+unsafe impl Send for AzTextInputValidatorVec { }
 unsafe impl Send for AzStringVec { }
 unsafe impl Send for AzStyledNodeVec { }
 unsafe impl Send for AzTagIdToNodeIdMappingVec { }
@@ -10540,6 +12276,11 @@ unsafe impl Send for AzIdOrClassVec { }
 unsafe impl Send for AzStyleBackgroundContentVec { }
 unsafe impl Send for AzSvgPathVec { }
 unsafe impl Send for AzVertexAttributeVec { }
+unsafe impl Send for AzDropDownChoiceVec { }
+unsafe impl Send for AzTabContainerTabVec { }
+unsafe impl Send for AzTextEditUndoEntryVec { }
+unsafe impl Send for AzTreeViewNodeDataVec { }
+unsafe impl Send for AzTreeViewRenderedRowVec { }
 unsafe impl Send for AzCssPathSelectorVec { }
 unsafe impl Send for AzCallbackDataVec { }
 unsafe impl Send for AzDebugMessageVec { }
@@ -10547,6 +12288,7 @@ unsafe impl Send for AzStringPairVec { }
 unsafe impl Send for AzInlineLineVec { }
 unsafe impl Send for AzCssPropertyVec { }
 unsafe impl Send for AzSvgMultiPolygonVec { }
+unsafe impl Send for AzTreeViewNodeVec { }
 unsafe impl Send for AzCallbackInfo { }
 unsafe impl Send for AzTimerCallbackInfo { }
 unsafe impl Send for AzNodeDataInlineCssPropertyVec { }
@@ -10615,6 +12357,7 @@ impl Clone for AzApplicationEventFilterEnumWrapper { fn clone(&self) -> Self { l
 impl Clone for AzAccessibilityRoleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityRole = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabIndexEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::TabIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRovingFocusOrientationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::RovingFocusOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzContextMenuMouseButtonEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ContextMenuMouseButton = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuPopupPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuPopupPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10647,15 +12390,43 @@ impl Clone for AzStyleBackfaceVisibilityEnumWrapper { fn clone(&self) -> Self {
 impl Clone for AzStyleTextAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxOnToggleCallback { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxOnToggleCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxState { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownOnChoiceChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputSelectionRange { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputSelectionRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnTextInputCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnTextInputCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnVirtualKeyDownCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnVirtualKeyDownCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnFocusLostCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnFocusLostCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputValidEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValid = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputValidatorCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValidatorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputOnValidationChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnValidationChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputState { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzProgressBarState { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBarState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitPaneOrientationEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::SplitPaneOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitPaneCollapsedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::SplitPaneCollapsed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitPaneOnResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::SplitPaneOnResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerOverflowEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerOverflow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerOnTabChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerOnTabChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerOnTabCloseCallback { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerOnTabCloseCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerOnTabMoveCallback { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerOnTabMoveCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOrientationEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderThumbEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderThumb = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditCursor { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditEditKindEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditEditKind = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditOnTextChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditOnTextChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewChildrenEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewChildren = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewSelectionModeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewDropPositionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewDropPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewRow { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewRow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewDragPayload { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewDragPayload = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnLoadChildrenCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnLoadChildrenCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnSelectionChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnSelectionChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnNodeMovedCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnNodeMovedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListRowHeightEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListRowHeight = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListMeasuredRow { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListMeasuredRow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListRenderRowCallback { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListRenderRowCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListOnScrollCallback { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListOnScrollCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNode { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::AzNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfo { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeState { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10735,6 +12506,7 @@ impl Clone for AzAccessibilityStateVecDestructorEnumWrapper { fn clone(&self) ->
 impl Clone for AzMenuItemVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzXmlSpanVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlSpanVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFmtArgVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::str::FmtArgVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLineVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLineVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineWordVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10761,6 +12533,15 @@ impl Clone for AzXWindowTypeVecDestructorEnumWrapper { fn clone(&self) -> Self {
 impl Clone for AzVirtualKeyCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfoVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfoVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScanCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ScanCodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputValidatorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValidatorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownChoiceVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownChoiceVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerTabVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerTabVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditUndoEntryVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditUndoEntryVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeDataVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeDataVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewRowVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewRowVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewRenderedRowVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewRenderedRowVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListMeasuredRowVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListMeasuredRowVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylesheetVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylesheetVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10813,6 +12594,7 @@ impl Clone for AzTimerCallbackReturn { fn clone(&self) -> Self { let r: &azul_im
 impl Clone for AzRefAny { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RefAny = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameNode { fn clone(&self) -> Self { let r: &azul_impl::dom::IFrameNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNotEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NotEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRovingFocus { fn clone(&self) -> Self { let r: &azul_impl::dom::RovingFocus = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuCallback { fn clone(&self) -> Self { let r: &azul_core::window::MenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10943,6 +12725,7 @@ impl Clone for AzStylePerspectiveOriginValueEnumWrapper { fn clone(&self) -> Sel
 impl Clone for AzStyleBackfaceVisibilityValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibilityValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzButtonOnClick { fn clone(&self) -> Self { let r: &crate::widgets::button::ButtonOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxOnToggle { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxOnToggle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownOnChoiceChange { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputState { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputSelectionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10950,7 +12733,24 @@ impl Clone for AzTextInputOnTextInput { fn clone(&self) -> Self { let r: &crate:
 impl Clone for AzTextInputOnVirtualKeyDown { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnVirtualKeyDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnFocusLost { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOnTextInputReturn { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OnTextInputReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputCustomValidator { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputCustomValidator = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputOnValidationChange { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnValidationChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitPaneState { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::SplitPaneState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitPaneOnResize { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::SplitPaneOnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerOnTabChange { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerOnTabChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerOnTabClose { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerOnTabClose = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerOnTabMove { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerOnTabMove = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderState { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditOnTextChange { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditOnTextChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewDropTarget { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewDropTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewLoadRequest { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewLoadRequest = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnLoadChildren { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnLoadChildren = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnSelectionChange { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnSelectionChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnNodeMoved { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnNodeMoved = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListRenderRow { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListRenderRow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListOnScroll { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListOnScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepth { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGl { fn clone(&self) -> Self { let r: &azul_impl::gl::GlContextPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRefstrVecRef { fn clone(&self) -> Self { let r: &azul_impl::gl::RefstrVecRef = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10961,6 +12761,7 @@ impl Clone for AzSvgQuadraticCurve { fn clone(&self) -> Self { let r: &azul_impl
 impl Clone for AzSvgCubicCurve { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgCubicCurve = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStringFormatOptions { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgXmlOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgFillStyle { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgFillStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzXmlSpan { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlSpan = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInstantPtr { fn clone(&self) -> Self { let r: &azul_impl::task::AzInstantPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDurationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::Duration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadSendMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSendMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10968,6 +12769,7 @@ impl Clone for AzThreadWriteBackMsg { fn clone(&self) -> Self { let r: &azul_imp
 impl Clone for AzAccessibilityStateVec { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVec { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVec { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzXmlSpanVec { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlSpanVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHitVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextHitVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoModeVec { fn clone(&self) -> Self { let r: &azul_core::window::VideoModeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10981,6 +12783,8 @@ impl Clone for AzXWindowTypeVec { fn clone(&self) -> Self { let r: &azul_core::w
 impl Clone for AzVirtualKeyCodeVec { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfoVec { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfoVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScanCodeVec { fn clone(&self) -> Self { let r: &azul_core::window::ScanCodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewRowVec { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewRowVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListMeasuredRowVec { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListMeasuredRowVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU16Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U16Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzF32Vec { fn clone(&self) -> Self { let r: &azul_impl::css::F32Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU8Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U8Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10998,6 +12802,20 @@ impl Clone for AzOptionTextInputOnTextInputEnumWrapper { fn clone(&self) -> Self
 impl Clone for AzOptionTextInputOnVirtualKeyDownEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnVirtualKeyDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputOnFocusLostEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputSelectionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextInputOnValidationChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnValidationChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDropDownOnChoiceChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTabContainerOnTabChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::OptionTabContainerOnTabChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTabContainerOnTabCloseEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::OptionTabContainerOnTabClose = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTabContainerOnTabMoveEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::OptionTabContainerOnTabMove = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSplitPaneOnResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::OptionSplitPaneOnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSliderOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextEditOnTextChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::OptionTextEditOnTextChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextEditCursorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::OptionTextEditCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnLoadChildrenEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnLoadChildren = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnSelectionChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnSelectionChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnNodeMovedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnNodeMoved = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewDropTargetEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewDropTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionVirtualListOnScrollEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::OptionVirtualListOnScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNumberInputOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::OptionNumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11045,14 +12863,15 @@ impl Clone for AzLargeWindowIconBytes { fn clone(&self) -> Self { let r: &azul_c
 impl Clone for AzWindowIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTaskBarIcon { fn clone(&self) -> Self { let r: &azul_core::window::TaskBarIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowSize { fn clone(&self) -> Self { let r: &azul_core::window::WindowSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseState { fn clone(&self) -> Self { let r: &azul_core::window::MouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextContents { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextContents = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDragData { fn clone(&self) -> Self { let r: &azul_impl::window::DragData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationEasingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRenderImageCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RenderImageCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::EventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFocusScopeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::FocusScope = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenu { fn clone(&self) -> Self { let r: &azul_core::window::Menu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeCombo { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathPseudoSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathPseudoSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11066,7 +12885,12 @@ impl Clone for AzStyleBackgroundPositionVecValueEnumWrapper { fn clone(&self) ->
 impl Clone for AzStyleBackgroundRepeatVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputValidatorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValidator = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitPaneStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::SplitPaneStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListState { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListIFrameNodes { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListIFrameNodes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNode { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTagIdToNodeIdMapping { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMapping = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTexture { fn clone(&self) -> Self { let r: &azul_impl::gl::Texture = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11085,12 +12909,14 @@ impl Clone for AzString { fn clone(&self) -> Self { let r: &azul_impl::css::AzSt
 impl Clone for AzTessellatedSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathElementVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathElementVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputValidatorVec { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValidatorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringVec { fn clone(&self) -> Self { let r: &azul_impl::css::StringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTagIdToNodeIdMappingVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMappingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionVirtualListIFrameNodesEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::OptionVirtualListIFrameNodes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionVirtualKeyCodeComboEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionVirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDragDataEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::window::OptionDragData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionStringVecEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionStringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadReceiveMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadReceiveMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTaskBarIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionTaskBarIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11107,6 +12933,7 @@ impl Clone for AzInvalidStringError { fn clone(&self) -> Self { let r: &azul_imp
 impl Clone for AzWindowsWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowsWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWaylandTheme { fn clone(&self) -> Self { let r: &azul_core::window::WaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPair { fn clone(&self) -> Self { let r: &azul_core::window::AzStringPair = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzImeComposition { fn clone(&self) -> Self { let r: &azul_core::window::ImeComposition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMonitor { fn clone(&self) -> Self { let r: &azul_core::window::Monitor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineWord = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11122,8 +12949,15 @@ impl Clone for AzScrollbarStyle { fn clone(&self) -> Self { let r: &azul_impl::c
 impl Clone for AzStyleFontFamilyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamily = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownChoice { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownChoice = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzTextInputState { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputValidationResultEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValidationResult = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputMask { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputMask = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerTab { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerTab = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditState { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditUndoEntry { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditUndoEntry = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeData { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewRenderedRow { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewRenderedRow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttribute { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttribute = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessage { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGetActiveAttribReturn { fn clone(&self) -> Self { let r: &azul_impl::gl::GetActiveAttribReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11145,16 +12979,24 @@ impl Clone for AzIdOrClassVec { fn clone(&self) -> Self { let r: &azul_impl::dom
 impl Clone for AzStyleBackgroundContentVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttributeVec { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttributeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownChoiceVec { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownChoiceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerTabVec { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerTabVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditUndoEntryVec { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditUndoEntryVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeDataVec { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewRenderedRowVec { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewRenderedRowVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::CallbackDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessageVec { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessageVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPairVec { fn clone(&self) -> Self { let r: &azul_core::window::StringPairVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextInputMaskEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputMask = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionImeCompositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::window::OptionImeComposition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFileTypeListEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dialogs::OptionFileTypeList = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFileEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::file::OptionFile = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRawImageEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionRawImage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWaylandThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultRawImageDecodeImageErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::decode::ResultRawImageDecodeImageError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlStreamErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlStreamError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::LinuxWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11162,7 +13004,12 @@ impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::Css
 impl Clone for AzStyleBackgroundContentVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzTextInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownState { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputState { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerState { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNode { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewLoadedChildren { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewLoadedChildren = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertySourceEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::CssPropertySource = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexLayout { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexLayout = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexArrayObject { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexArrayObject = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11172,7 +13019,9 @@ impl Clone for AzXmlNode { fn clone(&self) -> Self { let r: &azul_impl::xml::Xml
 impl Clone for AzInlineLineVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLineVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgMultiPolygonVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeVec { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlTextError { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPlatformSpecificOptions { fn clone(&self) -> Self { let r: &azul_core::window::PlatformSpecificOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowState { fn clone(&self) -> Self { let r: &azul_core::window::WindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11183,6 +13032,10 @@ impl Clone for AzAnimation { fn clone(&self) -> Self { let r: &azul_impl::callba
 impl Clone for AzTimerCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDynamicCssProperty { fn clone(&self) -> Self { let r: &azul_impl::css::DynamicCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainerStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainerStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewState { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgNodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStyledNode { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgStyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11195,16 +13048,25 @@ impl Clone for AzNodeData { fn clone(&self) -> Self { let r: &azul_impl::dom::No
 impl Clone for AzCssDeclarationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclaration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzButton { fn clone(&self) -> Self { let r: &crate::widgets::button::Button = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBox { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBox = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDown { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLabel { fn clone(&self) -> Self { let r: &crate::widgets::label::Label = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInput { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInput { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInput { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzProgressBar { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBar = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabContainer { fn clone(&self) -> Self { let r: &crate::widgets::tab_container::TabContainer = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSlider { fn clone(&self) -> Self { let r: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEdit { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualList { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualList = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualListStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::virtual_list::VirtualListStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDom { fn clone(&self) -> Self { let r: &azul_impl::dom::Dom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlock { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlock = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitPane { fn clone(&self) -> Self { let r: &crate::widgets::split_pane::SplitPane = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeView { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledDom { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledDom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlockVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlockVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionDomEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::OptionDom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11237,6 +13099,7 @@ impl Drop for AzInstantPtr { fn drop(&mut self) { crate::AzInstantPtr_delete(uns
 impl Drop for AzAccessibilityStateVec { fn drop(&mut self) { crate::AzAccessibilityStateVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzMenuItemVec { fn drop(&mut self) { crate::AzMenuItemVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzXmlNodeVec { fn drop(&mut self) { crate::AzXmlNodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzXmlSpanVec { fn drop(&mut self) { crate::AzXmlSpanVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineGlyphVec { fn drop(&mut self) { crate::AzInlineGlyphVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineTextHitVec { fn drop(&mut self) { crate::AzInlineTextHitVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVideoModeVec { fn drop(&mut self) { crate::AzVideoModeVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11250,6 +13113,8 @@ impl Drop for AzXWindowTypeVec { fn drop(&mut self) { crate::AzXWindowTypeVec_de
 impl Drop for AzVirtualKeyCodeVec { fn drop(&mut self) { crate::AzVirtualKeyCodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCascadeInfoVec { fn drop(&mut self) { crate::AzCascadeInfoVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzScanCodeVec { fn drop(&mut self) { crate::AzScanCodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTreeViewRowVec { fn drop(&mut self) { crate::AzTreeViewRowVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzVirtualListMeasuredRowVec { fn drop(&mut self) { crate::AzVirtualListMeasuredRowVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU16Vec { fn drop(&mut self) { crate::AzU16Vec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzF32Vec { fn drop(&mut self) { crate::AzF32Vec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU8Vec { fn drop(&mut self) { crate::AzU8Vec_delete(unsafe { mem::transmute(self) }); } }
@@ -11264,6 +13129,7 @@ impl Drop for AzTexture { fn drop(&mut self) { crate::AzTexture_delete(unsafe {
 impl Drop for AzTessellatedSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleTransformVec { fn drop(&mut self) { crate::AzStyleTransformVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathElementVec { fn drop(&mut self) { crate::AzSvgPathElementVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTextInputValidatorVec { fn drop(&mut self) { crate::AzTextInputValidatorVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStringVec { fn drop(&mut self) { crate::AzStringVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyledNodeVec { fn drop(&mut self) { crate::AzStyledNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTagIdToNodeIdMappingVec { fn drop(&mut self) { crate::AzTagIdToNodeIdMappingVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11276,6 +13142,11 @@ impl Drop for AzIdOrClassVec { fn drop(&mut self) { crate::AzIdOrClassVec_delete
 impl Drop for AzStyleBackgroundContentVec { fn drop(&mut self) { crate::AzStyleBackgroundContentVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathVec { fn drop(&mut self) { crate::AzSvgPathVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVertexAttributeVec { fn drop(&mut self) { crate::AzVertexAttributeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzDropDownChoiceVec { fn drop(&mut self) { crate::AzDropDownChoiceVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTabContainerTabVec { fn drop(&mut self) { crate::AzTabContainerTabVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTextEditUndoEntryVec { fn drop(&mut self) { crate::AzTextEditUndoEntryVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTreeViewNodeDataVec { fn drop(&mut self) { crate::AzTreeViewNodeDataVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTreeViewRenderedRowVec { fn drop(&mut self) { crate::AzTreeViewRenderedRowVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssPathSelectorVec { fn drop(&mut self) { crate::AzCssPathSelectorVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCallbackDataVec { fn drop(&mut self) { crate::AzCallbackDataVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzDebugMessageVec { fn drop(&mut self) { crate::AzDebugMessageVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11283,6 +13154,7 @@ impl Drop for AzStringPairVec { fn drop(&mut self) { crate::AzStringPairVec_dele
 impl Drop for AzInlineLineVec { fn drop(&mut self) { crate::AzInlineLineVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssPropertyVec { fn drop(&mut self) { crate::AzCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgMultiPolygonVec { fn drop(&mut self) { crate::AzSvgMultiPolygonVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTreeViewNodeVec { fn drop(&mut self) { crate::AzTreeViewNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataInlineCssPropertyVec { fn drop(&mut self) { crate::AzNodeDataInlineCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssDeclarationVec { fn drop(&mut self) { crate::AzCssDeclarationVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataVec { fn drop(&mut self) { crate::AzNodeDataVec_delete(unsafe { mem::transmute(self) }); } }
@@ -12614,7 +14486,7 @@ impl PyObjectProtocol for AzDebugState {
 #[pymethods]
 impl AzKeyboardState {
     #[new]
-    fn __new__(shift_down: bool, ctrl_down: bool, alt_down: bool, super_down: bool, current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec) -> Self {
+    fn __new__(shift_down: bool, ctrl_down: bool, alt_down: bool, super_down: bool, current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec, ime_composition: AzOptionImeCompositionEnumWrapper) -> Self {
         Self {
             shift_down,
             ctrl_down,
@@ -12624,6 +14496,7 @@ impl AzKeyboardState {
             current_virtual_keycode,
             pressed_virtual_keycodes,
             pressed_scancodes,
+            ime_composition,
         }
     }
 
@@ -13098,6 +14971,29 @@ impl PyObjectProtocol for AzImePositionEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzImeComposition {
+    #[new]
+    fn __new__(text: AzString, cursor_start: usize, cursor_end: usize) -> Self {
+        Self {
+            text,
+            cursor_start,
+            cursor_end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzImeComposition {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeComposition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeComposition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzTouchState {
     #[new]
@@ -13319,6 +15215,16 @@ impl AzCallbackInfo {
             mem::transmute(self),
         )) }
     }
+    fn get_focused_node(&self) -> Option<AzDomNodeId> {
+        let m: AzOptionDomNodeId = unsafe { mem::transmute(crate::AzCallbackInfo_getFocusedNode(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionDomNodeId::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionDomNodeId::None => None,
+        }
+
+    }
     fn get_system_time_fn(&self) -> AzGetSystemTimeFn {
         unsafe { mem::transmute(crate::AzCallbackInfo_getSystemTimeFn(
             mem::transmute(self),
@@ -13703,6 +15609,35 @@ impl AzCallbackInfo {
             mem::transmute(thread_id),
         )) }
     }
+    fn get_drag_data(&self) -> Option<AzDragData> {
+        let m: AzOptionDragData = unsafe { mem::transmute(crate::AzCallbackInfo_getDragData(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionDragData::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionDragData::None => None,
+        }
+
+    }
+    // impl CallbackInfo {
+
+    fn start_drag(&mut self, py: Python, payload: PyObject, ghost: AzOptionImageRefEnumWrapper) -> Result<bool, PyErr> { // RefAny<DatasetTy>
+        use pyo3::type_object::PyTypeInfo;
+
+        if payload.as_ref(py.clone()).is_callable() {
+            return Err(PyException::new_err(format!("ERROR in CallbackInfo.start_drag: - argument \"payload\" is a function callback, expected class")));
+        }
+
+        let payload_refany = azul_impl::callbacks::RefAny::new(DatasetTy {
+            _py_data: Some(payload),
+        });
+
+        Ok(unsafe { mem::transmute(crate::AzCallbackInfo_startDrag(
+            mem::transmute(self),
+            payload_refany,
+            mem::transmute(ghost),
+        )) })
+    }
 }
 
 #[pyproto]
@@ -14146,6 +16081,29 @@ impl PyObjectProtocol for AzFocusTargetEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzDragData {
+    #[new]
+    fn __new__(source: AzDomNodeId, payload: AzRefAny, ghost: AzOptionImageRefEnumWrapper) -> Self {
+        Self {
+            source,
+            payload,
+            ghost,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDragData {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::window::DragData = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::window::DragData = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzFocusTargetPath {
     #[new]
@@ -14860,6 +16818,30 @@ impl AzDom {
             mem::transmute(tab_index),
         )) }
     }
+    fn set_key(&mut self, key: u64) -> () {
+        unsafe { mem::transmute(crate::AzDom_setKey(
+            mem::transmute(self),
+            mem::transmute(key),
+        )) }
+    }
+    fn with_key(&mut self, key: u64) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withKey(
+            mem::transmute(self),
+            mem::transmute(key),
+        )) }
+    }
+    fn set_focus_scope(&mut self, focus_scope: AzFocusScopeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDom_setFocusScope(
+            mem::transmute(self),
+            mem::transmute(focus_scope),
+        )) }
+    }
+    fn with_focus_scope(&mut self, focus_scope: AzFocusScopeEnumWrapper) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withFocusScope(
+            mem::transmute(self),
+            mem::transmute(focus_scope),
+        )) }
+    }
     fn set_accessibility_info(&mut self, accessibility_info: AzAccessibilityInfo) -> () {
         unsafe { mem::transmute(crate::AzDom_setAccessibilityInfo(
             mem::transmute(self),
@@ -15004,6 +16986,42 @@ impl AzDom {
         self.add_callback(py, event, data, callback)?;
         let d: &mut azul_impl::dom::Dom = unsafe { mem::transmute(self) };
         Ok(unsafe { mem::transmute(d.swap_with_default()) })
+    }
+
+    fn add_shortcut(&mut self, py: Python, combo: AzVirtualKeyCodeCombo, data: PyObject, callback: PyObject) -> Result<(), PyErr> { // RefAny<CallbackTy>
+        use pyo3::type_object::PyTypeInfo;
+
+        if data.as_ref(py.clone()).is_callable() {
+            return Err(PyException::new_err(format!("ERROR in Dom.add_shortcut: - argument \"data\" is a function callback, expected class")));
+        }
+
+        let cb_any = callback.as_ref(py);
+        if !cb_any.is_callable() {
+            let type_name = cb_any.get_type().name().unwrap_or("<unknown>");
+            return Err(PyException::new_err(format!("ERROR in Dom.add_shortcut: - argument \"callback\" is of type \"{}\", expected function", type_name)));
+        }
+
+        let callback_refany = azul_impl::callbacks::RefAny::new(CallbackTy {
+            _py_callback: Some(callback),
+            _py_data: Some(data),
+        });
+
+        unsafe {
+            crate::AzDom_addShortcut(
+                mem::transmute(self),
+                mem::transmute(combo),
+                callback_refany,
+                invoke_python_callback
+            );
+        }
+
+        Ok(())
+    }
+
+    fn with_shortcut(&mut self, py: Python, combo: AzVirtualKeyCodeCombo, data: PyObject, callback: PyObject) -> Result<Self, PyErr> { // RefAny<CallbackTy>
+        self.add_shortcut(py, combo, data, callback)?;
+        let d: &mut azul_impl::dom::Dom = unsafe { mem::transmute(self) };
+        Ok(unsafe { mem::transmute(d.swap_with_default()) })
     }}
 
 #[pyproto]
@@ -15154,6 +17172,18 @@ impl AzNodeData {
             mem::transmute(tab_index),
         )) }
     }
+    fn set_key(&mut self, key: u64) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setKey(
+            mem::transmute(self),
+            mem::transmute(key),
+        )) }
+    }
+    fn set_focus_scope(&mut self, focus_scope: AzFocusScopeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setFocusScope(
+            mem::transmute(self),
+            mem::transmute(focus_scope),
+        )) }
+    }
     fn set_accessibility_info(&mut self, accessibility_info: AzAccessibilityInfo) -> () {
         unsafe { mem::transmute(crate::AzNodeData_setAccessibilityInfo(
             mem::transmute(self),
@@ -15258,6 +17288,42 @@ impl AzNodeData {
         self.add_callback(py, event, data, callback)?;
         let d: &mut azul_impl::dom::NodeData = unsafe { mem::transmute(self) };
         Ok(unsafe { mem::transmute(d.swap_with_default()) })
+    }
+
+    fn add_shortcut(&mut self, py: Python, combo: AzVirtualKeyCodeCombo, data: PyObject, callback: PyObject) -> Result<(), PyErr> { // RefAny<CallbackTy>
+        use pyo3::type_object::PyTypeInfo;
+
+        if data.as_ref(py.clone()).is_callable() {
+            return Err(PyException::new_err(format!("ERROR in NodeData.add_shortcut: - argument \"data\" is a function callback, expected class")));
+        }
+
+        let cb_any = callback.as_ref(py);
+        if !cb_any.is_callable() {
+            let type_name = cb_any.get_type().name().unwrap_or("<unknown>");
+            return Err(PyException::new_err(format!("ERROR in NodeData.add_shortcut: - argument \"callback\" is of type \"{}\", expected function", type_name)));
+        }
+
+        let callback_refany = azul_impl::callbacks::RefAny::new(CallbackTy {
+            _py_callback: Some(callback),
+            _py_data: Some(data),
+        });
+
+        unsafe {
+            crate::AzNodeData_addShortcut(
+                mem::transmute(self),
+                mem::transmute(combo),
+                callback_refany,
+                invoke_python_callback
+            );
+        }
+
+        Ok(())
+    }
+
+    fn with_shortcut(&mut self, py: Python, combo: AzVirtualKeyCodeCombo, data: PyObject, callback: PyObject) -> Result<Self, PyErr> { // RefAny<CallbackTy>
+        self.add_shortcut(py, combo, data, callback)?;
+        let d: &mut azul_impl::dom::NodeData = unsafe { mem::transmute(self) };
+        Ok(unsafe { mem::transmute(d.swap_with_default()) })
     }}
 
 #[pyproto]
@@ -15466,6 +17532,20 @@ impl AzHoverEventFilterEnumWrapper {
     fn TouchEnd() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TouchEnd } }
     #[classattr]
     fn TouchCancel() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TouchCancel } }
+    #[classattr]
+    fn DragStart() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragStart } }
+    #[classattr]
+    fn DragEnter() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragEnter } }
+    #[classattr]
+    fn DragOver() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragOver } }
+    #[classattr]
+    fn DragLeave() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragLeave } }
+    #[classattr]
+    fn Drop() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::Drop } }
+    #[classattr]
+    fn DragEnd() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragEnd } }
+    #[classattr]
+    fn ImeComposition() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::ImeComposition } }
 }
 
 #[pyproto]
@@ -15528,6 +17608,8 @@ impl AzFocusEventFilterEnumWrapper {
     fn FocusReceived() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusReceived } }
     #[classattr]
     fn FocusLost() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusLost } }
+    #[classattr]
+    fn ImeComposition() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::ImeComposition } }
 }
 
 #[pyproto]
@@ -15641,6 +17723,16 @@ impl AzWindowEventFilterEnumWrapper {
     fn CloseRequested() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::CloseRequested } }
     #[classattr]
     fn ThemeChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ThemeChanged } }
+    #[classattr]
+    fn DragStart() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragStart } }
+    #[classattr]
+    fn DragOver() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragOver } }
+    #[classattr]
+    fn Drop() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::Drop } }
+    #[classattr]
+    fn DragEnd() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragEnd } }
+    #[classattr]
+    fn ImeComposition() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ImeComposition } }
 }
 
 #[pyproto]
@@ -15991,6 +18083,87 @@ impl PyObjectProtocol for AzTabIndexEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzFocusScopeEnumWrapper {
+    #[classattr]
+    fn Trap() -> AzFocusScopeEnumWrapper { AzFocusScopeEnumWrapper { inner: AzFocusScope::Trap } }
+    #[staticmethod]
+    fn Roving(v: AzRovingFocus) -> AzFocusScopeEnumWrapper { AzFocusScopeEnumWrapper { inner: AzFocusScope::Roving(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzFocusScope;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzFocusScope::Trap => Ok(vec!["Trap".into_py(py), ().into_py(py)]),
+            AzFocusScope::Roving(v) => Ok(vec!["Roving".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFocusScopeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::FocusScope = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::FocusScope = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRovingFocus {
+    #[new]
+    fn __new__(orientation: AzRovingFocusOrientationEnumWrapper, wrap: bool) -> Self {
+        Self {
+            orientation,
+            wrap,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRovingFocus {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::RovingFocus = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::RovingFocus = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRovingFocusOrientationEnumWrapper {
+    #[classattr]
+    fn Horizontal() -> AzRovingFocusOrientationEnumWrapper { AzRovingFocusOrientationEnumWrapper { inner: AzRovingFocusOrientation::Horizontal } }
+    #[classattr]
+    fn Vertical() -> AzRovingFocusOrientationEnumWrapper { AzRovingFocusOrientationEnumWrapper { inner: AzRovingFocusOrientation::Vertical } }
+    #[classattr]
+    fn Both() -> AzRovingFocusOrientationEnumWrapper { AzRovingFocusOrientationEnumWrapper { inner: AzRovingFocusOrientation::Both } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRovingFocusOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::RovingFocusOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::RovingFocusOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzRovingFocusOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzIdOrClassEnumWrapper {
     #[staticmethod]
@@ -16520,6 +18693,12 @@ impl AzCssPathPseudoSelectorEnumWrapper {
     fn Active() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Active } }
     #[classattr]
     fn Focus() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Focus } }
+    #[classattr]
+    fn FocusVisible() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::FocusVisible } }
+    #[classattr]
+    fn FocusWithin() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::FocusWithin } }
+    #[classattr]
+    fn DragOver() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::DragOver } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPathPseudoSelector;
@@ -16533,6 +18712,9 @@ impl AzCssPathPseudoSelectorEnumWrapper {
             AzCssPathPseudoSelector::Hover => Ok(vec!["Hover".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::Active => Ok(vec!["Active".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::Focus => Ok(vec!["Focus".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::FocusVisible => Ok(vec!["FocusVisible".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::FocusWithin => Ok(vec!["FocusWithin".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::DragOver => Ok(vec!["DragOver".into_py(py), ().into_py(py)]),
         }
     }
 }
@@ -22352,6 +24534,203 @@ impl PyObjectProtocol for AzCheckBoxState {
     }
 }
 
+#[pymethods]
+impl AzDropDown {
+    #[new]
+    fn new(choices: AzDropDownChoiceVec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_new(
+            mem::transmute(choices),
+        )) }
+    }
+    #[staticmethod]
+    fn from_choices_str(choices: String) -> AzDropDown {
+        let choices = pystring_to_azstring(&choices);
+        unsafe { mem::transmute(crate::AzDropDown_fromChoicesStr(
+            mem::transmute(choices),
+        )) }
+    }
+    fn set_selected(&mut self, selected: AzOptionUsizeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setSelected(
+            mem::transmute(self),
+            mem::transmute(selected),
+        )) }
+    }
+    fn with_selected(&mut self, selected: usize) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withSelected(
+            mem::transmute(self),
+            mem::transmute(selected),
+        )) }
+    }
+    fn with_selected_label(&mut self, label: String) -> AzDropDown {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzDropDown_withSelectedLabel(
+            mem::transmute(self),
+            mem::transmute(label),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_header_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setHeaderStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_popup_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setPopupStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_choice_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setChoiceStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_group_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setGroupStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzDropDown_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDown {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownStateWrapper {
+    #[new]
+    fn __new__(inner: AzDropDownState, on_choice_change: AzOptionDropDownOnChoiceChangeEnumWrapper) -> Self {
+        Self {
+            inner,
+            on_choice_change,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownState {
+    #[new]
+    fn __new__(choices: AzDropDownChoiceVec, selected: AzOptionUsizeEnumWrapper, highlighted: AzOptionUsizeEnumWrapper, is_open: bool, search: AzString) -> Self {
+        Self {
+            choices,
+            selected,
+            highlighted,
+            is_open,
+            search,
+        }
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        unsafe { mem::transmute(crate::AzDropDownState_isSelectable(
+            mem::transmute(self),
+            mem::transmute(index),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownChoice {
+    #[new]
+    fn new(label: String) -> AzDropDownChoice {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzDropDownChoice_new(
+            mem::transmute(label),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownChoice {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownChoice = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownChoice = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownOnChoiceChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDropDownOnChoiceChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownOnChoiceChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownOnChoiceChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownOnChoiceChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLabel {
     #[new]
@@ -22528,6 +24907,53 @@ impl AzTextInput {
             mem::transmute(label_style),
         )) }
     }
+    fn add_validator(&mut self, validator: AzTextInputValidatorEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_addValidator(
+            mem::transmute(self),
+            mem::transmute(validator),
+        )) }
+    }
+    fn with_validator(&mut self, validator: AzTextInputValidatorEnumWrapper) -> AzTextInput {
+        unsafe { mem::transmute(crate::AzTextInput_withValidator(
+            mem::transmute(self),
+            mem::transmute(validator),
+        )) }
+    }
+    fn set_mask(&mut self, mask: AzTextInputMask) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setMask(
+            mem::transmute(self),
+            mem::transmute(mask),
+        )) }
+    }
+    fn with_mask(&mut self, mask: AzTextInputMask) -> AzTextInput {
+        unsafe { mem::transmute(crate::AzTextInput_withMask(
+            mem::transmute(self),
+            mem::transmute(mask),
+        )) }
+    }
+    fn set_obscure_char(&mut self, obscure_char: AzOptionCharEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setObscureChar(
+            mem::transmute(self),
+            mem::transmute(obscure_char),
+        )) }
+    }
+    fn with_password_mode(&mut self) -> AzTextInput {
+        unsafe { mem::transmute(crate::AzTextInput_withPasswordMode(
+            mem::transmute(self),
+        )) }
+    }
+    fn set_error_style(&mut self, error_style: AzCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setErrorStyle(
+            mem::transmute(self),
+            mem::transmute(error_style),
+        )) }
+    }
+    fn set_preedit_style(&mut self, preedit_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setPreeditStyle(
+            mem::transmute(self),
+            mem::transmute(preedit_style),
+        )) }
+    }
     fn dom(&mut self) -> AzDom {
         unsafe { mem::transmute(crate::AzTextInput_dom(
             mem::transmute(self),
@@ -22548,7 +24974,7 @@ impl PyObjectProtocol for AzTextInput {
 #[pymethods]
 impl AzTextInputStateWrapper {
     #[new]
-    fn __new__(inner: AzTextInputState, on_text_input: AzOptionTextInputOnTextInputEnumWrapper, on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper, on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper, update_text_input_before_calling_focus_lost_fn: bool, update_text_input_before_calling_vk_down_fn: bool) -> Self {
+    fn __new__(inner: AzTextInputState, on_text_input: AzOptionTextInputOnTextInputEnumWrapper, on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper, on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper, update_text_input_before_calling_focus_lost_fn: bool, update_text_input_before_calling_vk_down_fn: bool, on_validation_change: AzOptionTextInputOnValidationChangeEnumWrapper, error_style: AzCssPropertyVec, style_before_error: AzCssPropertyVec) -> Self {
         Self {
             inner,
             on_text_input,
@@ -22556,6 +24982,9 @@ impl AzTextInputStateWrapper {
             on_focus_lost,
             update_text_input_before_calling_focus_lost_fn,
             update_text_input_before_calling_vk_down_fn,
+            on_validation_change,
+            error_style,
+            style_before_error,
         }
     }
 
@@ -22574,13 +25003,18 @@ impl PyObjectProtocol for AzTextInputStateWrapper {
 #[pymethods]
 impl AzTextInputState {
     #[new]
-    fn __new__(text: AzU32Vec, placeholder: AzOptionStringEnumWrapper, max_len: usize, selection: AzOptionTextInputSelectionEnumWrapper, cursor_pos: usize) -> Self {
+    fn __new__(text: AzU32Vec, placeholder: AzOptionStringEnumWrapper, max_len: usize, selection: AzOptionTextInputSelectionEnumWrapper, cursor_pos: usize, validators: AzTextInputValidatorVec, mask: AzOptionTextInputMaskEnumWrapper, obscure_char: AzOptionCharEnumWrapper, validation: AzTextInputValidationResultEnumWrapper, ime_composition: AzOptionImeCompositionEnumWrapper) -> Self {
         Self {
             text,
             placeholder,
             max_len,
             selection,
             cursor_pos,
+            validators,
+            mask,
+            obscure_char,
+            validation,
+            ime_composition,
         }
     }
 
@@ -22589,6 +25023,16 @@ impl AzTextInputState {
             mem::transmute(self),
         )) })
     }
+    fn get_display_text(&self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzTextInputState_getDisplayText(
+            mem::transmute(self),
+        )) })
+    }
+    fn validate(&self) -> AzTextInputValidationResultEnumWrapper {
+        unsafe { mem::transmute(crate::AzTextInputState_validate(
+            mem::transmute(self),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -22810,6 +25254,200 @@ impl PyObjectProtocol for AzTextInputValidEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTextInputValidationResultEnumWrapper {
+    #[classattr]
+    fn Valid() -> AzTextInputValidationResultEnumWrapper { AzTextInputValidationResultEnumWrapper { inner: AzTextInputValidationResult::Valid } }
+    #[staticmethod]
+    fn Invalid(v: AzString) -> AzTextInputValidationResultEnumWrapper { AzTextInputValidationResultEnumWrapper { inner: AzTextInputValidationResult::Invalid(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextInputValidationResult;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTextInputValidationResult::Valid => Ok(vec!["Valid".into_py(py), ().into_py(py)]),
+            AzTextInputValidationResult::Invalid(v) => Ok(vec!["Invalid".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputValidationResultEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidationResult = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidationResult = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputValidatorEnumWrapper {
+    #[classattr]
+    fn NotEmpty() -> AzTextInputValidatorEnumWrapper { AzTextInputValidatorEnumWrapper { inner: AzTextInputValidator::NotEmpty } }
+    #[classattr]
+    fn Integer() -> AzTextInputValidatorEnumWrapper { AzTextInputValidatorEnumWrapper { inner: AzTextInputValidator::Integer } }
+    #[classattr]
+    fn Number() -> AzTextInputValidatorEnumWrapper { AzTextInputValidatorEnumWrapper { inner: AzTextInputValidator::Number } }
+    #[classattr]
+    fn Email() -> AzTextInputValidatorEnumWrapper { AzTextInputValidatorEnumWrapper { inner: AzTextInputValidator::Email } }
+    #[classattr]
+    fn Ipv4() -> AzTextInputValidatorEnumWrapper { AzTextInputValidatorEnumWrapper { inner: AzTextInputValidator::Ipv4 } }
+    #[staticmethod]
+    fn MinLength(v: usize) -> AzTextInputValidatorEnumWrapper { AzTextInputValidatorEnumWrapper { inner: AzTextInputValidator::MinLength(v) } }
+    #[staticmethod]
+    fn Custom(v: AzTextInputCustomValidator) -> AzTextInputValidatorEnumWrapper { AzTextInputValidatorEnumWrapper { inner: AzTextInputValidator::Custom(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextInputValidator;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTextInputValidator::NotEmpty => Ok(vec!["NotEmpty".into_py(py), ().into_py(py)]),
+            AzTextInputValidator::Integer => Ok(vec!["Integer".into_py(py), ().into_py(py)]),
+            AzTextInputValidator::Number => Ok(vec!["Number".into_py(py), ().into_py(py)]),
+            AzTextInputValidator::Email => Ok(vec!["Email".into_py(py), ().into_py(py)]),
+            AzTextInputValidator::Ipv4 => Ok(vec!["Ipv4".into_py(py), ().into_py(py)]),
+            AzTextInputValidator::MinLength(v) => Ok(vec!["MinLength".into_py(py), v.into_py(py)]),
+            AzTextInputValidator::Custom(v) => Ok(vec!["Custom".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputValidatorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidator = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidator = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputCustomValidator {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTextInputValidatorCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputCustomValidator {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputCustomValidator = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputCustomValidator = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputValidatorCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputValidatorCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidatorCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidatorCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputMask {
+    #[new]
+    fn new(pattern: String) -> AzTextInputMask {
+        let pattern = pystring_to_azstring(&pattern);
+        unsafe { mem::transmute(crate::AzTextInputMask_new(
+            mem::transmute(pattern),
+        )) }
+    }
+    #[staticmethod]
+    fn date() -> AzTextInputMask {
+        unsafe { mem::transmute(crate::AzTextInputMask_date()) }
+    }
+    #[staticmethod]
+    fn time() -> AzTextInputMask {
+        unsafe { mem::transmute(crate::AzTextInputMask_time()) }
+    }
+    #[staticmethod]
+    fn phone() -> AzTextInputMask {
+        unsafe { mem::transmute(crate::AzTextInputMask_phone()) }
+    }
+    #[staticmethod]
+    fn ipv4() -> AzTextInputMask {
+        unsafe { mem::transmute(crate::AzTextInputMask_ipv4()) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputMask {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputMask = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputMask = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputOnValidationChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTextInputOnValidationChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputOnValidationChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnValidationChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnValidationChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputOnValidationChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputOnValidationChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnValidationChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnValidationChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNumberInput {
     #[new]
@@ -23036,6 +25674,2128 @@ impl PyObjectProtocol for AzProgressBarState {
     }
 }
 
+#[pymethods]
+impl AzSplitPane {
+    #[new]
+    fn new(orientation: AzSplitPaneOrientationEnumWrapper, first_pane: AzDom, second_pane: AzDom) -> AzSplitPane {
+        unsafe { mem::transmute(crate::AzSplitPane_new(
+            mem::transmute(orientation),
+            mem::transmute(first_pane),
+            mem::transmute(second_pane),
+        )) }
+    }
+    fn set_ratio(&mut self, ratio: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitPane_setRatio(
+            mem::transmute(self),
+            mem::transmute(ratio),
+        )) }
+    }
+    fn with_ratio(&mut self, ratio: f32) -> AzSplitPane {
+        unsafe { mem::transmute(crate::AzSplitPane_withRatio(
+            mem::transmute(self),
+            mem::transmute(ratio),
+        )) }
+    }
+    fn set_min_sizes(&mut self, min_first_size: f32, min_second_size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitPane_setMinSizes(
+            mem::transmute(self),
+            mem::transmute(min_first_size),
+            mem::transmute(min_second_size),
+        )) }
+    }
+    fn with_min_sizes(&mut self, min_first_size: f32, min_second_size: f32) -> AzSplitPane {
+        unsafe { mem::transmute(crate::AzSplitPane_withMinSizes(
+            mem::transmute(self),
+            mem::transmute(min_first_size),
+            mem::transmute(min_second_size),
+        )) }
+    }
+    fn set_collapsible(&mut self, collapsible: bool) -> () {
+        unsafe { mem::transmute(crate::AzSplitPane_setCollapsible(
+            mem::transmute(self),
+            mem::transmute(collapsible),
+        )) }
+    }
+    fn with_collapsible(&mut self, collapsible: bool) -> AzSplitPane {
+        unsafe { mem::transmute(crate::AzSplitPane_withCollapsible(
+            mem::transmute(self),
+            mem::transmute(collapsible),
+        )) }
+    }
+    fn set_collapsed(&mut self, collapsed: AzSplitPaneCollapsedEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzSplitPane_setCollapsed(
+            mem::transmute(self),
+            mem::transmute(collapsed),
+        )) }
+    }
+    fn with_collapsed(&mut self, collapsed: AzSplitPaneCollapsedEnumWrapper) -> AzSplitPane {
+        unsafe { mem::transmute(crate::AzSplitPane_withCollapsed(
+            mem::transmute(self),
+            mem::transmute(collapsed),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSplitPane_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_pane_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSplitPane_setPaneStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_divider_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSplitPane_setDividerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSplitPane_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitPane {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPane = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPane = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitPaneStateWrapper {
+    #[new]
+    fn __new__(inner: AzSplitPaneState, on_resize: AzOptionSplitPaneOnResizeEnumWrapper, is_dragging: bool, drag_position: f32, state_before_drag: AzSplitPaneState) -> Self {
+        Self {
+            inner,
+            on_resize,
+            is_dragging,
+            drag_position,
+            state_before_drag,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitPaneStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitPaneState {
+    #[new]
+    fn __new__(orientation: AzSplitPaneOrientationEnumWrapper, ratio: f32, min_first_size: f32, min_second_size: f32, collapsible: bool, collapsed: AzSplitPaneCollapsedEnumWrapper) -> Self {
+        Self {
+            orientation,
+            ratio,
+            min_first_size,
+            min_second_size,
+            collapsible,
+            collapsed,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitPaneState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitPaneOrientationEnumWrapper {
+    #[classattr]
+    fn Horizontal() -> AzSplitPaneOrientationEnumWrapper { AzSplitPaneOrientationEnumWrapper { inner: AzSplitPaneOrientation::Horizontal } }
+    #[classattr]
+    fn Vertical() -> AzSplitPaneOrientationEnumWrapper { AzSplitPaneOrientationEnumWrapper { inner: AzSplitPaneOrientation::Vertical } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitPaneOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSplitPaneOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSplitPaneCollapsedEnumWrapper {
+    #[classattr]
+    fn None() -> AzSplitPaneCollapsedEnumWrapper { AzSplitPaneCollapsedEnumWrapper { inner: AzSplitPaneCollapsed::None } }
+    #[classattr]
+    fn First() -> AzSplitPaneCollapsedEnumWrapper { AzSplitPaneCollapsedEnumWrapper { inner: AzSplitPaneCollapsed::First } }
+    #[classattr]
+    fn Second() -> AzSplitPaneCollapsedEnumWrapper { AzSplitPaneCollapsedEnumWrapper { inner: AzSplitPaneCollapsed::Second } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitPaneCollapsedEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneCollapsed = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneCollapsed = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSplitPaneCollapsedEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSplitPaneOnResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSplitPaneOnResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitPaneOnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneOnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneOnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitPaneOnResizeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitPaneOnResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneOnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::SplitPaneOnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainer {
+    #[new]
+    fn new() -> AzTabContainer {
+        unsafe { mem::transmute(crate::AzTabContainer_new()) }
+    }
+    #[staticmethod]
+    fn from_titles_str(titles: String, pages: AzDomVec) -> AzTabContainer {
+        let titles = pystring_to_azstring(&titles);
+        unsafe { mem::transmute(crate::AzTabContainer_fromTitlesStr(
+            mem::transmute(titles),
+            mem::transmute(pages),
+        )) }
+    }
+    fn add_tab(&mut self, title: String, content: AzDom) -> () {
+        let title = pystring_to_azstring(&title);
+        unsafe { mem::transmute(crate::AzTabContainer_addTab(
+            mem::transmute(self),
+            mem::transmute(title),
+            mem::transmute(content),
+        )) }
+    }
+    fn with_tab(&mut self, title: String, content: AzDom) -> AzTabContainer {
+        let title = pystring_to_azstring(&title);
+        unsafe { mem::transmute(crate::AzTabContainer_withTab(
+            mem::transmute(self),
+            mem::transmute(title),
+            mem::transmute(content),
+        )) }
+    }
+    fn set_active_tab(&mut self, tab: usize) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setActiveTab(
+            mem::transmute(self),
+            mem::transmute(tab),
+        )) }
+    }
+    fn with_active_tab(&mut self, tab: usize) -> AzTabContainer {
+        unsafe { mem::transmute(crate::AzTabContainer_withActiveTab(
+            mem::transmute(self),
+            mem::transmute(tab),
+        )) }
+    }
+    fn set_closable(&mut self, closable: bool) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setClosable(
+            mem::transmute(self),
+            mem::transmute(closable),
+        )) }
+    }
+    fn with_closable(&mut self, closable: bool) -> AzTabContainer {
+        unsafe { mem::transmute(crate::AzTabContainer_withClosable(
+            mem::transmute(self),
+            mem::transmute(closable),
+        )) }
+    }
+    fn set_overflow(&mut self, overflow: AzTabContainerOverflowEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setOverflow(
+            mem::transmute(self),
+            mem::transmute(overflow),
+        )) }
+    }
+    fn with_overflow(&mut self, overflow: AzTabContainerOverflowEnumWrapper) -> AzTabContainer {
+        unsafe { mem::transmute(crate::AzTabContainer_withOverflow(
+            mem::transmute(self),
+            mem::transmute(overflow),
+        )) }
+    }
+    fn set_allow_reorder(&mut self, allow_reorder: bool) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setAllowReorder(
+            mem::transmute(self),
+            mem::transmute(allow_reorder),
+        )) }
+    }
+    fn with_allow_reorder(&mut self, allow_reorder: bool) -> AzTabContainer {
+        unsafe { mem::transmute(crate::AzTabContainer_withAllowReorder(
+            mem::transmute(self),
+            mem::transmute(allow_reorder),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_header_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setHeaderStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_tab_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setTabStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_close_button_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setCloseButtonStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_button_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setButtonStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_menu_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setMenuStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_page_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTabContainer_setPageStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzTabContainer_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainer {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainer = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainer = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerStateWrapper {
+    #[new]
+    fn __new__(inner: AzTabContainerState, on_tab_change: AzOptionTabContainerOnTabChangeEnumWrapper, on_tab_close: AzOptionTabContainerOnTabCloseEnumWrapper, on_tab_move: AzOptionTabContainerOnTabMoveEnumWrapper, allow_reorder: bool, tab_count: usize, pressed_tab: AzOptionUsizeEnumWrapper, dragged_tab: AzOptionUsizeEnumWrapper, drop_index: AzOptionUsizeEnumWrapper) -> Self {
+        Self {
+            inner,
+            on_tab_change,
+            on_tab_close,
+            on_tab_move,
+            allow_reorder,
+            tab_count,
+            pressed_tab,
+            dragged_tab,
+            drop_index,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerState {
+    #[new]
+    fn __new__(tabs: AzTabContainerTabVec, active_tab: AzOptionUsizeEnumWrapper, scroll_offset: f32, overflow: AzTabContainerOverflowEnumWrapper, menu_open: bool) -> Self {
+        Self {
+            tabs,
+            active_tab,
+            scroll_offset,
+            overflow,
+            menu_open,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerTab {
+    #[new]
+    fn __new__(title: AzString, closable: bool, page: usize) -> Self {
+        Self {
+            title,
+            closable,
+            page,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerTab {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerTab = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerTab = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerOverflowEnumWrapper {
+    #[classattr]
+    fn Scroll() -> AzTabContainerOverflowEnumWrapper { AzTabContainerOverflowEnumWrapper { inner: AzTabContainerOverflow::Scroll } }
+    #[classattr]
+    fn Menu() -> AzTabContainerOverflowEnumWrapper { AzTabContainerOverflowEnumWrapper { inner: AzTabContainerOverflow::Menu } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerOverflowEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOverflow = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOverflow = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTabContainerOverflowEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTabContainerOnTabChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTabContainerOnTabChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerOnTabChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerOnTabChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerOnTabChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerOnTabClose {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTabContainerOnTabCloseCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerOnTabClose {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabClose = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabClose = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerOnTabCloseCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerOnTabCloseCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabCloseCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabCloseCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerOnTabMove {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTabContainerOnTabMoveCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerOnTabMove {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabMove = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabMove = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerOnTabMoveCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerOnTabMoveCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabMoveCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerOnTabMoveCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSlider {
+    #[new]
+    fn new(value: f32, min: f32, max: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_new(
+            mem::transmute(value),
+            mem::transmute(min),
+            mem::transmute(max),
+        )) }
+    }
+    #[staticmethod]
+    fn new_range(lower: f32, upper: f32, min: f32, max: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_newRange(
+            mem::transmute(lower),
+            mem::transmute(upper),
+            mem::transmute(min),
+            mem::transmute(max),
+        )) }
+    }
+    fn set_orientation(&mut self, orientation: AzSliderOrientationEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setOrientation(
+            mem::transmute(self),
+            mem::transmute(orientation),
+        )) }
+    }
+    fn with_orientation(&mut self, orientation: AzSliderOrientationEnumWrapper) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withOrientation(
+            mem::transmute(self),
+            mem::transmute(orientation),
+        )) }
+    }
+    fn set_step(&mut self, step: f32) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn with_step(&mut self, step: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn set_tick_interval(&mut self, tick_interval: AzOptionF32EnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setTickInterval(
+            mem::transmute(self),
+            mem::transmute(tick_interval),
+        )) }
+    }
+    fn with_tick_interval(&mut self, tick_interval: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withTickInterval(
+            mem::transmute(self),
+            mem::transmute(tick_interval),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_track_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setTrackStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_fill_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setFillStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_thumb_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setThumbStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_tick_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setTickStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSlider_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSlider {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderStateWrapper {
+    #[new]
+    fn __new__(inner: AzSliderState, on_value_change: AzOptionSliderOnValueChangeEnumWrapper, is_dragging: bool, drag_value: f32) -> Self {
+        Self {
+            inner,
+            on_value_change,
+            is_dragging,
+            drag_value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderState {
+    #[new]
+    fn __new__(value: f32, upper_value: AzOptionF32EnumWrapper, min: f32, max: f32, step: f32, orientation: AzSliderOrientationEnumWrapper, tick_interval: AzOptionF32EnumWrapper, active_thumb: AzSliderThumbEnumWrapper) -> Self {
+        Self {
+            value,
+            upper_value,
+            min,
+            max,
+            step,
+            orientation,
+            tick_interval,
+            active_thumb,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderOrientationEnumWrapper {
+    #[classattr]
+    fn Horizontal() -> AzSliderOrientationEnumWrapper { AzSliderOrientationEnumWrapper { inner: AzSliderOrientation::Horizontal } }
+    #[classattr]
+    fn Vertical() -> AzSliderOrientationEnumWrapper { AzSliderOrientationEnumWrapper { inner: AzSliderOrientation::Vertical } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSliderOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSliderThumbEnumWrapper {
+    #[classattr]
+    fn Lower() -> AzSliderThumbEnumWrapper { AzSliderThumbEnumWrapper { inner: AzSliderThumb::Lower } }
+    #[classattr]
+    fn Upper() -> AzSliderThumbEnumWrapper { AzSliderThumbEnumWrapper { inner: AzSliderThumb::Upper } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderThumbEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderThumb = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderThumb = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSliderThumbEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSliderOnValueChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSliderOnValueChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOnValueChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderOnValueChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOnValueChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEdit {
+    #[new]
+    fn new(text: String) -> AzTextEdit {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextEdit_new(
+            mem::transmute(text),
+        )) }
+    }
+    #[staticmethod]
+    fn from_state(state: AzTextEditState) -> AzTextEdit {
+        unsafe { mem::transmute(crate::AzTextEdit_fromState(
+            mem::transmute(state),
+        )) }
+    }
+    fn set_visible_rows(&mut self, rows: usize) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setVisibleRows(
+            mem::transmute(self),
+            mem::transmute(rows),
+        )) }
+    }
+    fn with_visible_rows(&mut self, rows: usize) -> AzTextEdit {
+        unsafe { mem::transmute(crate::AzTextEdit_withVisibleRows(
+            mem::transmute(self),
+            mem::transmute(rows),
+        )) }
+    }
+    fn set_wrap_lines(&mut self, wrap_lines: bool) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setWrapLines(
+            mem::transmute(self),
+            mem::transmute(wrap_lines),
+        )) }
+    }
+    fn with_wrap_lines(&mut self, wrap_lines: bool) -> AzTextEdit {
+        unsafe { mem::transmute(crate::AzTextEdit_withWrapLines(
+            mem::transmute(self),
+            mem::transmute(wrap_lines),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_row_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setRowStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_label_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setLabelStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_selection_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setSelectionStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_caret_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setCaretStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzTextEdit_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEdit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditStateWrapper {
+    #[new]
+    fn __new__(inner: AzTextEditState, on_text_change: AzOptionTextEditOnTextChangeEnumWrapper, undo_stack: AzTextEditUndoEntryVec, redo_stack: AzTextEditUndoEntryVec, wrap_lines: bool, char_width: f32, rendered_rows: AzStringVec, is_selecting: bool) -> Self {
+        Self {
+            inner,
+            on_text_change,
+            undo_stack,
+            redo_stack,
+            wrap_lines,
+            char_width,
+            rendered_rows,
+            is_selecting,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditState {
+    #[new]
+    fn __new__(lines: AzStringVec, cursor: AzTextEditCursor, selection_anchor: AzOptionTextEditCursorEnumWrapper, preferred_column: AzOptionUsizeEnumWrapper, first_visible_row: usize, visible_rows: usize, wrap_column: usize) -> Self {
+        Self {
+            lines,
+            cursor,
+            selection_anchor,
+            preferred_column,
+            first_visible_row,
+            visible_rows,
+            wrap_column,
+        }
+    }
+
+    fn get_text(&self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzTextEditState_getText(
+            mem::transmute(self),
+        )) })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditCursor {
+    #[new]
+    fn __new__(line: usize, column: usize) -> Self {
+        Self {
+            line,
+            column,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditCursor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditCursor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditCursor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditEditKindEnumWrapper {
+    #[classattr]
+    fn Insert() -> AzTextEditEditKindEnumWrapper { AzTextEditEditKindEnumWrapper { inner: AzTextEditEditKind::Insert } }
+    #[classattr]
+    fn DeleteBackward() -> AzTextEditEditKindEnumWrapper { AzTextEditEditKindEnumWrapper { inner: AzTextEditEditKind::DeleteBackward } }
+    #[classattr]
+    fn DeleteForward() -> AzTextEditEditKindEnumWrapper { AzTextEditEditKindEnumWrapper { inner: AzTextEditEditKind::DeleteForward } }
+    #[classattr]
+    fn Other() -> AzTextEditEditKindEnumWrapper { AzTextEditEditKindEnumWrapper { inner: AzTextEditEditKind::Other } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditEditKindEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTextEditEditKindEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTextEditUndoEntry {
+    #[new]
+    fn __new__(start: AzTextEditCursor, removed: AzString, inserted: AzString, cursor_before: AzTextEditCursor, kind: AzTextEditEditKindEnumWrapper) -> Self {
+        Self {
+            start,
+            removed,
+            inserted,
+            cursor_before,
+            kind,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditUndoEntry {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditUndoEntry = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditUndoEntry = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditOnTextChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTextEditOnTextChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditOnTextChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditOnTextChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditOnTextChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeView {
+    #[new]
+    fn new(state: AzTreeViewState) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_new(
+            mem::transmute(state),
+        )) }
+    }
+    #[staticmethod]
+    fn from_paths_str(paths: String) -> AzTreeView {
+        let paths = pystring_to_azstring(&paths);
+        unsafe { mem::transmute(crate::AzTreeView_fromPathsStr(
+            mem::transmute(paths),
+        )) }
+    }
+    fn set_visible_rows(&mut self, rows: usize) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setVisibleRows(
+            mem::transmute(self),
+            mem::transmute(rows),
+        )) }
+    }
+    fn with_visible_rows(&mut self, rows: usize) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withVisibleRows(
+            mem::transmute(self),
+            mem::transmute(rows),
+        )) }
+    }
+    fn set_selection_mode(&mut self, selection_mode: AzTreeViewSelectionModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setSelectionMode(
+            mem::transmute(self),
+            mem::transmute(selection_mode),
+        )) }
+    }
+    fn with_selection_mode(&mut self, selection_mode: AzTreeViewSelectionModeEnumWrapper) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withSelectionMode(
+            mem::transmute(self),
+            mem::transmute(selection_mode),
+        )) }
+    }
+    fn set_allow_reorder(&mut self, allow_reorder: bool) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setAllowReorder(
+            mem::transmute(self),
+            mem::transmute(allow_reorder),
+        )) }
+    }
+    fn with_allow_reorder(&mut self, allow_reorder: bool) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withAllowReorder(
+            mem::transmute(self),
+            mem::transmute(allow_reorder),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_row_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setRowStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_guides_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setGuidesStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_toggle_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setToggleStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_icon_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setIconStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_label_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setLabelStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_scrollbar_thumb_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setScrollbarThumbStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_drop_indicator_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setDropIndicatorStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzTreeView_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeView {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewStateWrapper {
+    #[new]
+    fn __new__(inner: AzTreeViewState, on_load_children: AzOptionTreeViewOnLoadChildrenEnumWrapper, on_selection_change: AzOptionTreeViewOnSelectionChangeEnumWrapper, on_node_moved: AzOptionTreeViewOnNodeMovedEnumWrapper, allow_reorder: bool, rendered_rows: AzTreeViewRenderedRowVec, pressed_node: AzOptionUsizeEnumWrapper, dragged_node: AzOptionUsizeEnumWrapper, drop_target: AzOptionTreeViewDropTargetEnumWrapper) -> Self {
+        Self {
+            inner,
+            on_load_children,
+            on_selection_change,
+            on_node_moved,
+            allow_reorder,
+            rendered_rows,
+            pressed_node,
+            dragged_node,
+            drop_target,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewState {
+    #[new]
+    fn new() -> AzTreeViewState {
+        unsafe { mem::transmute(crate::AzTreeViewState_new()) }
+    }
+    fn add_node(&mut self, parent: AzOptionUsizeEnumWrapper, data: AzTreeViewNodeData) -> usize {
+        unsafe { mem::transmute(crate::AzTreeViewState_addNode(
+            mem::transmute(self),
+            mem::transmute(parent),
+            mem::transmute(data),
+        )) }
+    }
+    fn set_children(&mut self, node_id: usize, children: AzTreeViewNodeDataVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeViewState_setChildren(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(children),
+        )) }
+    }
+    fn update_rows(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzTreeViewState_updateRows(
+            mem::transmute(self),
+        )) }
+    }
+    fn expand(&mut self, node_id: usize) -> bool {
+        unsafe { mem::transmute(crate::AzTreeViewState_expand(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) }
+    }
+    fn collapse(&mut self, node_id: usize) -> () {
+        unsafe { mem::transmute(crate::AzTreeViewState_collapse(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) }
+    }
+    fn reveal(&mut self, node_id: usize) -> () {
+        unsafe { mem::transmute(crate::AzTreeViewState_reveal(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) }
+    }
+    fn is_expanded(&self, node_id: usize) -> bool {
+        unsafe { mem::transmute(crate::AzTreeViewState_isExpanded(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) }
+    }
+    fn clear_selection(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzTreeViewState_clearSelection(
+            mem::transmute(self),
+        )) }
+    }
+    fn set_selected(&mut self, node_id: usize, selected: bool) -> () {
+        unsafe { mem::transmute(crate::AzTreeViewState_setSelected(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(selected),
+        )) }
+    }
+    fn select(&mut self, node_id: usize, extend: bool, toggle: bool) -> () {
+        unsafe { mem::transmute(crate::AzTreeViewState_select(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(extend),
+            mem::transmute(toggle),
+        )) }
+    }
+    fn move_node(&mut self, node_id: usize, target: AzTreeViewDropTarget) -> bool {
+        unsafe { mem::transmute(crate::AzTreeViewState_moveNode(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(target),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNode {
+    #[new]
+    fn __new__(data: AzTreeViewNodeData, parent: AzOptionUsizeEnumWrapper, first_child: AzOptionUsizeEnumWrapper, last_child: AzOptionUsizeEnumWrapper, previous_sibling: AzOptionUsizeEnumWrapper, next_sibling: AzOptionUsizeEnumWrapper, is_expanded: bool, is_selected: bool) -> Self {
+        Self {
+            data,
+            parent,
+            first_child,
+            last_child,
+            previous_sibling,
+            next_sibling,
+            is_expanded,
+            is_selected,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNode {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNodeData {
+    #[new]
+    fn new(label: String) -> AzTreeViewNodeData {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzTreeViewNodeData_new(
+            mem::transmute(label),
+        )) }
+    }
+    fn with_icon(&mut self, icon: AzImageRef) -> AzTreeViewNodeData {
+        unsafe { mem::transmute(crate::AzTreeViewNodeData_withIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+    fn with_lazy_children(&mut self) -> AzTreeViewNodeData {
+        unsafe { mem::transmute(crate::AzTreeViewNodeData_withLazyChildren(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeData {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeData = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeData = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewChildrenEnumWrapper {
+    #[classattr]
+    fn None() -> AzTreeViewChildrenEnumWrapper { AzTreeViewChildrenEnumWrapper { inner: AzTreeViewChildren::None } }
+    #[classattr]
+    fn NotLoaded() -> AzTreeViewChildrenEnumWrapper { AzTreeViewChildrenEnumWrapper { inner: AzTreeViewChildren::NotLoaded } }
+    #[classattr]
+    fn Loading() -> AzTreeViewChildrenEnumWrapper { AzTreeViewChildrenEnumWrapper { inner: AzTreeViewChildren::Loading } }
+    #[classattr]
+    fn Loaded() -> AzTreeViewChildrenEnumWrapper { AzTreeViewChildrenEnumWrapper { inner: AzTreeViewChildren::Loaded } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewChildrenEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewChildren = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewChildren = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTreeViewChildrenEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTreeViewSelectionModeEnumWrapper {
+    #[classattr]
+    fn None() -> AzTreeViewSelectionModeEnumWrapper { AzTreeViewSelectionModeEnumWrapper { inner: AzTreeViewSelectionMode::None } }
+    #[classattr]
+    fn Single() -> AzTreeViewSelectionModeEnumWrapper { AzTreeViewSelectionModeEnumWrapper { inner: AzTreeViewSelectionMode::Single } }
+    #[classattr]
+    fn Multiple() -> AzTreeViewSelectionModeEnumWrapper { AzTreeViewSelectionModeEnumWrapper { inner: AzTreeViewSelectionMode::Multiple } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewSelectionModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTreeViewSelectionModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTreeViewDropPositionEnumWrapper {
+    #[classattr]
+    fn Before() -> AzTreeViewDropPositionEnumWrapper { AzTreeViewDropPositionEnumWrapper { inner: AzTreeViewDropPosition::Before } }
+    #[classattr]
+    fn After() -> AzTreeViewDropPositionEnumWrapper { AzTreeViewDropPositionEnumWrapper { inner: AzTreeViewDropPosition::After } }
+    #[classattr]
+    fn Into() -> AzTreeViewDropPositionEnumWrapper { AzTreeViewDropPositionEnumWrapper { inner: AzTreeViewDropPosition::Into } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewDropPositionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewDropPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewDropPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTreeViewDropPositionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTreeViewDropTarget {
+    #[new]
+    fn __new__(node: usize, position: AzTreeViewDropPositionEnumWrapper) -> Self {
+        Self {
+            node,
+            position,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewDropTarget {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewDropTarget = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewDropTarget = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewRow {
+    #[new]
+    fn __new__(node: usize, depth: usize) -> Self {
+        Self {
+            node,
+            depth,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewRow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewRenderedRow {
+    #[new]
+    fn __new__(node: AzOptionUsizeEnumWrapper, depth: usize, toggle: AzString, icon: AzOptionImageRefEnumWrapper, label: AzString, is_selected: bool, is_cursor: bool) -> Self {
+        Self {
+            node,
+            depth,
+            toggle,
+            icon,
+            label,
+            is_selected,
+            is_cursor,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewRenderedRow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRenderedRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRenderedRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewLoadRequest {
+    #[new]
+    fn __new__(node: usize, tree: AzRefAny) -> Self {
+        Self {
+            node,
+            tree,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewLoadRequest {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewLoadRequest = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewLoadRequest = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewLoadedChildren {
+    #[new]
+    fn new(node: usize, children: AzTreeViewNodeDataVec) -> AzTreeViewLoadedChildren {
+        unsafe { mem::transmute(crate::AzTreeViewLoadedChildren_new(
+            mem::transmute(node),
+            mem::transmute(children),
+        )) }
+    }
+    fn into_writeback_msg(&mut self) -> AzThreadWriteBackMsg {
+        unsafe { mem::transmute(crate::AzTreeViewLoadedChildren_intoWritebackMsg(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewLoadedChildren {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewLoadedChildren = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewLoadedChildren = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewDragPayload {
+    #[new]
+    fn __new__(node: usize) -> Self {
+        Self {
+            node,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewDragPayload {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewDragPayload = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewDragPayload = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnLoadChildren {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnLoadChildrenCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnLoadChildren {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLoadChildren = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLoadChildren = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnLoadChildrenCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnLoadChildrenCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLoadChildrenCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLoadChildrenCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnSelectionChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnSelectionChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnSelectionChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectionChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectionChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnSelectionChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnSelectionChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectionChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectionChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnNodeMoved {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnNodeMovedCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnNodeMoved {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnNodeMoved = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnNodeMoved = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnNodeMovedCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnNodeMovedCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnNodeMovedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnNodeMovedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualList {
+    fn set_row_count(&mut self, row_count: usize) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setRowCount(
+            mem::transmute(self),
+            mem::transmute(row_count),
+        )) }
+    }
+    fn with_row_count(&mut self, row_count: usize) -> AzVirtualList {
+        unsafe { mem::transmute(crate::AzVirtualList_withRowCount(
+            mem::transmute(self),
+            mem::transmute(row_count),
+        )) }
+    }
+    fn set_row_height(&mut self, row_height: AzVirtualListRowHeightEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setRowHeight(
+            mem::transmute(self),
+            mem::transmute(row_height),
+        )) }
+    }
+    fn with_row_height(&mut self, row_height: AzVirtualListRowHeightEnumWrapper) -> AzVirtualList {
+        unsafe { mem::transmute(crate::AzVirtualList_withRowHeight(
+            mem::transmute(self),
+            mem::transmute(row_height),
+        )) }
+    }
+    fn set_scroll_offset(&mut self, scroll_offset: f32) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setScrollOffset(
+            mem::transmute(self),
+            mem::transmute(scroll_offset),
+        )) }
+    }
+    fn with_scroll_offset(&mut self, scroll_offset: f32) -> AzVirtualList {
+        unsafe { mem::transmute(crate::AzVirtualList_withScrollOffset(
+            mem::transmute(self),
+            mem::transmute(scroll_offset),
+        )) }
+    }
+    fn set_overscan(&mut self, overscan: usize) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setOverscan(
+            mem::transmute(self),
+            mem::transmute(overscan),
+        )) }
+    }
+    fn with_overscan(&mut self, overscan: usize) -> AzVirtualList {
+        unsafe { mem::transmute(crate::AzVirtualList_withOverscan(
+            mem::transmute(self),
+            mem::transmute(overscan),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_row_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setRowStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_scrollbar_track_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setScrollbarTrackStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_scrollbar_thumb_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzVirtualList_setScrollbarThumbStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzVirtualList_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualList {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualList = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualList = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListStateWrapper {
+    #[new]
+    fn __new__(inner: AzVirtualListState, render_row: AzVirtualListRenderRow, on_scroll: AzOptionVirtualListOnScrollEnumWrapper, row_style: AzNodeDataInlineCssPropertyVec, scrollbar_track_style: AzNodeDataInlineCssPropertyVec, scrollbar_thumb_style: AzNodeDataInlineCssPropertyVec, rendered_rows_start: usize, rendered_rows_end: usize, iframe_nodes: AzOptionVirtualListIFrameNodesEnumWrapper) -> Self {
+        Self {
+            inner,
+            render_row,
+            on_scroll,
+            row_style,
+            scrollbar_track_style,
+            scrollbar_thumb_style,
+            rendered_rows_start,
+            rendered_rows_end,
+            iframe_nodes,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListState {
+    #[new]
+    fn new(row_count: usize, row_height: AzVirtualListRowHeightEnumWrapper) -> AzVirtualListState {
+        unsafe { mem::transmute(crate::AzVirtualListState_new(
+            mem::transmute(row_count),
+            mem::transmute(row_height),
+        )) }
+    }
+    fn get_default_row_height(&self) -> f32 {
+        unsafe { mem::transmute(crate::AzVirtualListState_getDefaultRowHeight(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_row_height(&self, row: usize) -> f32 {
+        unsafe { mem::transmute(crate::AzVirtualListState_getRowHeight(
+            mem::transmute(self),
+            mem::transmute(row),
+        )) }
+    }
+    fn get_row_top(&self, row: usize) -> f32 {
+        unsafe { mem::transmute(crate::AzVirtualListState_getRowTop(
+            mem::transmute(self),
+            mem::transmute(row),
+        )) }
+    }
+    fn get_row_at_offset(&self, offset: f32) -> usize {
+        unsafe { mem::transmute(crate::AzVirtualListState_getRowAtOffset(
+            mem::transmute(self),
+            mem::transmute(offset),
+        )) }
+    }
+    fn get_total_height(&self) -> f32 {
+        unsafe { mem::transmute(crate::AzVirtualListState_getTotalHeight(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_max_scroll_offset(&self) -> f32 {
+        unsafe { mem::transmute(crate::AzVirtualListState_getMaxScrollOffset(
+            mem::transmute(self),
+        )) }
+    }
+    fn clamp_scroll_offset(&self, scroll_offset: f32) -> f32 {
+        unsafe { mem::transmute(crate::AzVirtualListState_clampScrollOffset(
+            mem::transmute(self),
+            mem::transmute(scroll_offset),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListRowHeightEnumWrapper {
+    #[staticmethod]
+    fn Fixed(v: f32) -> AzVirtualListRowHeightEnumWrapper { AzVirtualListRowHeightEnumWrapper { inner: AzVirtualListRowHeight::Fixed(v) } }
+    #[staticmethod]
+    fn Estimated(v: f32) -> AzVirtualListRowHeightEnumWrapper { AzVirtualListRowHeightEnumWrapper { inner: AzVirtualListRowHeight::Estimated(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzVirtualListRowHeight;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzVirtualListRowHeight::Fixed(v) => Ok(vec!["Fixed".into_py(py), v.into_py(py)]),
+            AzVirtualListRowHeight::Estimated(v) => Ok(vec!["Estimated".into_py(py), v.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListRowHeightEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListRowHeight = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListRowHeight = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListMeasuredRow {
+    #[new]
+    fn __new__(row: usize, height: f32, delta_before: f64) -> Self {
+        Self {
+            row,
+            height,
+            delta_before,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListMeasuredRow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListMeasuredRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListMeasuredRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListIFrameNodes {
+    #[new]
+    fn __new__(rows: AzDomNodeId, scrollbar_thumb: AzOptionDomNodeIdEnumWrapper) -> Self {
+        Self {
+            rows,
+            scrollbar_thumb,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListIFrameNodes {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListIFrameNodes = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListIFrameNodes = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListRenderRow {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzVirtualListRenderRowCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListRenderRow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListRenderRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListRenderRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListRenderRowCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListRenderRowCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListRenderRowCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListRenderRowCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListOnScroll {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzVirtualListOnScrollCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListOnScroll {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListOnScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListOnScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListOnScrollCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListOnScrollCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListOnScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListOnScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNode {
     #[new]
@@ -23114,12 +27874,15 @@ impl PyObjectProtocol for AzCssPropertySourceEnumWrapper {
 #[pymethods]
 impl AzStyledNodeState {
     #[new]
-    fn __new__(normal: bool, hover: bool, active: bool, focused: bool) -> Self {
+    fn __new__(normal: bool, hover: bool, active: bool, focused: bool, focus_visible: bool, focus_within: bool, drag_over: bool) -> Self {
         Self {
             normal,
             hover,
             active,
             focused,
+            focus_visible,
+            focus_within,
+            drag_over,
         }
     }
 
@@ -29977,12 +34740,15 @@ impl PyObjectProtocol for AzXml {
 #[pymethods]
 impl AzXmlNode {
     #[new]
-    fn __new__(tag: AzString, attributes: AzStringPairVec, children: AzXmlNodeVec, text: AzOptionStringEnumWrapper) -> Self {
+    fn __new__(tag: AzString, attributes: AzStringPairVec, children: AzXmlNodeVec, text: AzOptionStringEnumWrapper, span: AzXmlSpan, attribute_spans: AzXmlSpanVec, text_span: AzXmlSpan) -> Self {
         Self {
             tag,
             attributes,
             children,
             text,
+            span,
+            attribute_spans,
+            text_span,
         }
     }
 
@@ -29998,6 +34764,28 @@ impl PyObjectProtocol for AzXmlNode {
     }
 }
 
+#[pymethods]
+impl AzXmlSpan {
+    #[new]
+    fn __new__(start: AzSvgParseErrorPosition, end: AzSvgParseErrorPosition) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzXmlSpan {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpan = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpan = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzFile {
     #[staticmethod]
@@ -31188,6 +35976,31 @@ impl PyObjectProtocol for AzXmlNodeVec {
     }
 }
 
+#[pymethods]
+impl AzXmlSpanVec {
+    /// Creates a new `XmlSpanVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzXmlSpan>) -> Self {
+        let m: azul_impl::xml::XmlSpanVec = azul_impl::xml::XmlSpanVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the XmlSpan as a Python array
+    fn array(&self) -> Vec<AzXmlSpan> {
+        let m: &azul_impl::xml::XmlSpanVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzXmlSpanVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpanVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpanVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzFmtArgVec {
     /// Creates a new `FmtArgVec` from a Python array
@@ -31838,6 +36651,231 @@ impl PyObjectProtocol for AzScanCodeVec {
     }
 }
 
+#[pymethods]
+impl AzTextInputValidatorVec {
+    /// Creates a new `TextInputValidatorEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTextInputValidatorEnumWrapper>) -> Self {
+        let m: crate::widgets::text_input::TextInputValidatorVec = crate::widgets::text_input::TextInputValidatorVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TextInputValidatorEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzTextInputValidatorEnumWrapper> {
+        let m: &crate::widgets::text_input::TextInputValidatorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputValidatorVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidatorVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidatorVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownChoiceVec {
+    /// Creates a new `DropDownChoiceVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzDropDownChoice>) -> Self {
+        let m: crate::widgets::drop_down::DropDownChoiceVec = crate::widgets::drop_down::DropDownChoiceVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the DropDownChoice as a Python array
+    fn array(&self) -> Vec<AzDropDownChoice> {
+        let m: &crate::widgets::drop_down::DropDownChoiceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownChoiceVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownChoiceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownChoiceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerTabVec {
+    /// Creates a new `TabContainerTabVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTabContainerTab>) -> Self {
+        let m: crate::widgets::tab_container::TabContainerTabVec = crate::widgets::tab_container::TabContainerTabVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TabContainerTab as a Python array
+    fn array(&self) -> Vec<AzTabContainerTab> {
+        let m: &crate::widgets::tab_container::TabContainerTabVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerTabVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerTabVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerTabVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditUndoEntryVec {
+    /// Creates a new `TextEditUndoEntryVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTextEditUndoEntry>) -> Self {
+        let m: crate::widgets::text_edit::TextEditUndoEntryVec = crate::widgets::text_edit::TextEditUndoEntryVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TextEditUndoEntry as a Python array
+    fn array(&self) -> Vec<AzTextEditUndoEntry> {
+        let m: &crate::widgets::text_edit::TextEditUndoEntryVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditUndoEntryVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditUndoEntryVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditUndoEntryVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNodeDataVec {
+    /// Creates a new `TreeViewNodeDataVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTreeViewNodeData>) -> Self {
+        let m: crate::widgets::tree_view::TreeViewNodeDataVec = crate::widgets::tree_view::TreeViewNodeDataVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TreeViewNodeData as a Python array
+    fn array(&self) -> Vec<AzTreeViewNodeData> {
+        let m: &crate::widgets::tree_view::TreeViewNodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeDataVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeDataVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeDataVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNodeVec {
+    /// Creates a new `TreeViewNodeVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTreeViewNode>) -> Self {
+        let m: crate::widgets::tree_view::TreeViewNodeVec = crate::widgets::tree_view::TreeViewNodeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TreeViewNode as a Python array
+    fn array(&self) -> Vec<AzTreeViewNode> {
+        let m: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewRowVec {
+    /// Creates a new `TreeViewRowVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTreeViewRow>) -> Self {
+        let m: crate::widgets::tree_view::TreeViewRowVec = crate::widgets::tree_view::TreeViewRowVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TreeViewRow as a Python array
+    fn array(&self) -> Vec<AzTreeViewRow> {
+        let m: &crate::widgets::tree_view::TreeViewRowVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewRowVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRowVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRowVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewRenderedRowVec {
+    /// Creates a new `TreeViewRenderedRowVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTreeViewRenderedRow>) -> Self {
+        let m: crate::widgets::tree_view::TreeViewRenderedRowVec = crate::widgets::tree_view::TreeViewRenderedRowVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TreeViewRenderedRow as a Python array
+    fn array(&self) -> Vec<AzTreeViewRenderedRow> {
+        let m: &crate::widgets::tree_view::TreeViewRenderedRowVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewRenderedRowVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRenderedRowVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRenderedRowVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListMeasuredRowVec {
+    /// Creates a new `VirtualListMeasuredRowVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzVirtualListMeasuredRow>) -> Self {
+        let m: crate::widgets::virtual_list::VirtualListMeasuredRowVec = crate::widgets::virtual_list::VirtualListMeasuredRowVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the VirtualListMeasuredRow as a Python array
+    fn array(&self) -> Vec<AzVirtualListMeasuredRow> {
+        let m: &crate::widgets::virtual_list::VirtualListMeasuredRowVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListMeasuredRowVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListMeasuredRowVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListMeasuredRowVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssDeclarationVec {
     /// Creates a new `CssDeclarationEnumWrapperVec` from a Python array
@@ -32502,6 +37540,36 @@ impl PyObjectProtocol for AzXmlNodeVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzXmlSpanVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzXmlSpanVecDestructorEnumWrapper { AzXmlSpanVecDestructorEnumWrapper { inner: AzXmlSpanVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzXmlSpanVecDestructorEnumWrapper { AzXmlSpanVecDestructorEnumWrapper { inner: AzXmlSpanVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzXmlSpanVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzXmlSpanVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzXmlSpanVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzXmlSpanVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzXmlSpanVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpanVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpanVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzFmtArgVecDestructorEnumWrapper {
     #[classattr]
@@ -33282,6 +38350,276 @@ impl PyObjectProtocol for AzScanCodeVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTextInputValidatorVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTextInputValidatorVecDestructorEnumWrapper { AzTextInputValidatorVecDestructorEnumWrapper { inner: AzTextInputValidatorVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTextInputValidatorVecDestructorEnumWrapper { AzTextInputValidatorVecDestructorEnumWrapper { inner: AzTextInputValidatorVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextInputValidatorVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTextInputValidatorVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTextInputValidatorVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTextInputValidatorVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputValidatorVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidatorVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputValidatorVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownChoiceVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzDropDownChoiceVecDestructorEnumWrapper { AzDropDownChoiceVecDestructorEnumWrapper { inner: AzDropDownChoiceVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzDropDownChoiceVecDestructorEnumWrapper { AzDropDownChoiceVecDestructorEnumWrapper { inner: AzDropDownChoiceVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzDropDownChoiceVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzDropDownChoiceVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzDropDownChoiceVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzDropDownChoiceVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownChoiceVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownChoiceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownChoiceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabContainerTabVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTabContainerTabVecDestructorEnumWrapper { AzTabContainerTabVecDestructorEnumWrapper { inner: AzTabContainerTabVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTabContainerTabVecDestructorEnumWrapper { AzTabContainerTabVecDestructorEnumWrapper { inner: AzTabContainerTabVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTabContainerTabVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTabContainerTabVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTabContainerTabVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTabContainerTabVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabContainerTabVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerTabVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::TabContainerTabVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditUndoEntryVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTextEditUndoEntryVecDestructorEnumWrapper { AzTextEditUndoEntryVecDestructorEnumWrapper { inner: AzTextEditUndoEntryVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTextEditUndoEntryVecDestructorEnumWrapper { AzTextEditUndoEntryVecDestructorEnumWrapper { inner: AzTextEditUndoEntryVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextEditUndoEntryVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTextEditUndoEntryVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTextEditUndoEntryVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTextEditUndoEntryVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditUndoEntryVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditUndoEntryVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditUndoEntryVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNodeDataVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTreeViewNodeDataVecDestructorEnumWrapper { AzTreeViewNodeDataVecDestructorEnumWrapper { inner: AzTreeViewNodeDataVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTreeViewNodeDataVecDestructorEnumWrapper { AzTreeViewNodeDataVecDestructorEnumWrapper { inner: AzTreeViewNodeDataVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTreeViewNodeDataVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTreeViewNodeDataVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeDataVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeDataVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeDataVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeDataVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeDataVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNodeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTreeViewNodeVecDestructorEnumWrapper { AzTreeViewNodeVecDestructorEnumWrapper { inner: AzTreeViewNodeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTreeViewNodeVecDestructorEnumWrapper { AzTreeViewNodeVecDestructorEnumWrapper { inner: AzTreeViewNodeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTreeViewNodeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTreeViewNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewRowVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTreeViewRowVecDestructorEnumWrapper { AzTreeViewRowVecDestructorEnumWrapper { inner: AzTreeViewRowVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTreeViewRowVecDestructorEnumWrapper { AzTreeViewRowVecDestructorEnumWrapper { inner: AzTreeViewRowVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTreeViewRowVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTreeViewRowVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTreeViewRowVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTreeViewRowVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewRowVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRowVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRowVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewRenderedRowVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTreeViewRenderedRowVecDestructorEnumWrapper { AzTreeViewRenderedRowVecDestructorEnumWrapper { inner: AzTreeViewRenderedRowVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTreeViewRenderedRowVecDestructorEnumWrapper { AzTreeViewRenderedRowVecDestructorEnumWrapper { inner: AzTreeViewRenderedRowVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTreeViewRenderedRowVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTreeViewRenderedRowVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTreeViewRenderedRowVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTreeViewRenderedRowVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewRenderedRowVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRenderedRowVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewRenderedRowVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzVirtualListMeasuredRowVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzVirtualListMeasuredRowVecDestructorEnumWrapper { AzVirtualListMeasuredRowVecDestructorEnumWrapper { inner: AzVirtualListMeasuredRowVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzVirtualListMeasuredRowVecDestructorEnumWrapper { AzVirtualListMeasuredRowVecDestructorEnumWrapper { inner: AzVirtualListMeasuredRowVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzVirtualListMeasuredRowVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzVirtualListMeasuredRowVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzVirtualListMeasuredRowVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzVirtualListMeasuredRowVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualListMeasuredRowVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListMeasuredRowVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::VirtualListMeasuredRowVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssDeclarationVecDestructorEnumWrapper {
     #[classattr]
@@ -34115,6 +39453,470 @@ impl PyObjectProtocol for AzOptionTextInputSelectionEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTextInputMaskEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextInputMaskEnumWrapper { AzOptionTextInputMaskEnumWrapper { inner: AzOptionTextInputMask::None } }
+    #[staticmethod]
+    fn Some(v: AzTextInputMask) -> AzOptionTextInputMaskEnumWrapper { AzOptionTextInputMaskEnumWrapper { inner: AzOptionTextInputMask::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextInputMask;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextInputMask::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextInputMask::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextInputMaskEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::OptionTextInputMask = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::OptionTextInputMask = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTextInputOnValidationChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextInputOnValidationChangeEnumWrapper { AzOptionTextInputOnValidationChangeEnumWrapper { inner: AzOptionTextInputOnValidationChange::None } }
+    #[staticmethod]
+    fn Some(v: AzTextInputOnValidationChange) -> AzOptionTextInputOnValidationChangeEnumWrapper { AzOptionTextInputOnValidationChangeEnumWrapper { inner: AzOptionTextInputOnValidationChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextInputOnValidationChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextInputOnValidationChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextInputOnValidationChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextInputOnValidationChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::OptionTextInputOnValidationChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::OptionTextInputOnValidationChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDropDownOnChoiceChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDropDownOnChoiceChangeEnumWrapper { AzOptionDropDownOnChoiceChangeEnumWrapper { inner: AzOptionDropDownOnChoiceChange::None } }
+    #[staticmethod]
+    fn Some(v: AzDropDownOnChoiceChange) -> AzOptionDropDownOnChoiceChangeEnumWrapper { AzOptionDropDownOnChoiceChangeEnumWrapper { inner: AzOptionDropDownOnChoiceChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDropDownOnChoiceChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDropDownOnChoiceChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDropDownOnChoiceChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDropDownOnChoiceChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTabContainerOnTabChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTabContainerOnTabChangeEnumWrapper { AzOptionTabContainerOnTabChangeEnumWrapper { inner: AzOptionTabContainerOnTabChange::None } }
+    #[staticmethod]
+    fn Some(v: AzTabContainerOnTabChange) -> AzOptionTabContainerOnTabChangeEnumWrapper { AzOptionTabContainerOnTabChangeEnumWrapper { inner: AzOptionTabContainerOnTabChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTabContainerOnTabChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTabContainerOnTabChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTabContainerOnTabChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTabContainerOnTabChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::OptionTabContainerOnTabChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::OptionTabContainerOnTabChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTabContainerOnTabCloseEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTabContainerOnTabCloseEnumWrapper { AzOptionTabContainerOnTabCloseEnumWrapper { inner: AzOptionTabContainerOnTabClose::None } }
+    #[staticmethod]
+    fn Some(v: AzTabContainerOnTabClose) -> AzOptionTabContainerOnTabCloseEnumWrapper { AzOptionTabContainerOnTabCloseEnumWrapper { inner: AzOptionTabContainerOnTabClose::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTabContainerOnTabClose;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTabContainerOnTabClose::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTabContainerOnTabClose::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTabContainerOnTabCloseEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::OptionTabContainerOnTabClose = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::OptionTabContainerOnTabClose = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTabContainerOnTabMoveEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTabContainerOnTabMoveEnumWrapper { AzOptionTabContainerOnTabMoveEnumWrapper { inner: AzOptionTabContainerOnTabMove::None } }
+    #[staticmethod]
+    fn Some(v: AzTabContainerOnTabMove) -> AzOptionTabContainerOnTabMoveEnumWrapper { AzOptionTabContainerOnTabMoveEnumWrapper { inner: AzOptionTabContainerOnTabMove::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTabContainerOnTabMove;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTabContainerOnTabMove::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTabContainerOnTabMove::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTabContainerOnTabMoveEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::OptionTabContainerOnTabMove = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tab_container::OptionTabContainerOnTabMove = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSplitPaneOnResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSplitPaneOnResizeEnumWrapper { AzOptionSplitPaneOnResizeEnumWrapper { inner: AzOptionSplitPaneOnResize::None } }
+    #[staticmethod]
+    fn Some(v: AzSplitPaneOnResize) -> AzOptionSplitPaneOnResizeEnumWrapper { AzOptionSplitPaneOnResizeEnumWrapper { inner: AzOptionSplitPaneOnResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSplitPaneOnResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSplitPaneOnResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSplitPaneOnResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSplitPaneOnResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::OptionSplitPaneOnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::split_pane::OptionSplitPaneOnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSliderOnValueChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSliderOnValueChangeEnumWrapper { AzOptionSliderOnValueChangeEnumWrapper { inner: AzOptionSliderOnValueChange::None } }
+    #[staticmethod]
+    fn Some(v: AzSliderOnValueChange) -> AzOptionSliderOnValueChangeEnumWrapper { AzOptionSliderOnValueChangeEnumWrapper { inner: AzOptionSliderOnValueChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSliderOnValueChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSliderOnValueChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSliderOnValueChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSliderOnValueChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTextEditOnTextChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextEditOnTextChangeEnumWrapper { AzOptionTextEditOnTextChangeEnumWrapper { inner: AzOptionTextEditOnTextChange::None } }
+    #[staticmethod]
+    fn Some(v: AzTextEditOnTextChange) -> AzOptionTextEditOnTextChangeEnumWrapper { AzOptionTextEditOnTextChangeEnumWrapper { inner: AzOptionTextEditOnTextChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextEditOnTextChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextEditOnTextChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextEditOnTextChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextEditOnTextChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditOnTextChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditOnTextChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTextEditCursorEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextEditCursorEnumWrapper { AzOptionTextEditCursorEnumWrapper { inner: AzOptionTextEditCursor::None } }
+    #[staticmethod]
+    fn Some(v: AzTextEditCursor) -> AzOptionTextEditCursorEnumWrapper { AzOptionTextEditCursorEnumWrapper { inner: AzOptionTextEditCursor::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextEditCursor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextEditCursor::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextEditCursor::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextEditCursorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditCursor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditCursor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnLoadChildrenEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnLoadChildrenEnumWrapper { AzOptionTreeViewOnLoadChildrenEnumWrapper { inner: AzOptionTreeViewOnLoadChildren::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnLoadChildren) -> AzOptionTreeViewOnLoadChildrenEnumWrapper { AzOptionTreeViewOnLoadChildrenEnumWrapper { inner: AzOptionTreeViewOnLoadChildren::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnLoadChildren;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnLoadChildren::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnLoadChildren::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnLoadChildrenEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnLoadChildren = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnLoadChildren = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnSelectionChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnSelectionChangeEnumWrapper { AzOptionTreeViewOnSelectionChangeEnumWrapper { inner: AzOptionTreeViewOnSelectionChange::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnSelectionChange) -> AzOptionTreeViewOnSelectionChangeEnumWrapper { AzOptionTreeViewOnSelectionChangeEnumWrapper { inner: AzOptionTreeViewOnSelectionChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnSelectionChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnSelectionChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnSelectionChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnSelectionChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnNodeMovedEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnNodeMovedEnumWrapper { AzOptionTreeViewOnNodeMovedEnumWrapper { inner: AzOptionTreeViewOnNodeMoved::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnNodeMoved) -> AzOptionTreeViewOnNodeMovedEnumWrapper { AzOptionTreeViewOnNodeMovedEnumWrapper { inner: AzOptionTreeViewOnNodeMoved::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnNodeMoved;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnNodeMoved::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnNodeMoved::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnNodeMovedEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnNodeMoved = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnNodeMoved = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewDropTargetEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewDropTargetEnumWrapper { AzOptionTreeViewDropTargetEnumWrapper { inner: AzOptionTreeViewDropTarget::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewDropTarget) -> AzOptionTreeViewDropTargetEnumWrapper { AzOptionTreeViewDropTargetEnumWrapper { inner: AzOptionTreeViewDropTarget::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewDropTarget;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewDropTarget::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewDropTarget::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewDropTargetEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewDropTarget = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewDropTarget = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionVirtualListOnScrollEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionVirtualListOnScrollEnumWrapper { AzOptionVirtualListOnScrollEnumWrapper { inner: AzOptionVirtualListOnScroll::None } }
+    #[staticmethod]
+    fn Some(v: AzVirtualListOnScroll) -> AzOptionVirtualListOnScrollEnumWrapper { AzOptionVirtualListOnScrollEnumWrapper { inner: AzOptionVirtualListOnScroll::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionVirtualListOnScroll;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionVirtualListOnScroll::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionVirtualListOnScroll::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionVirtualListOnScrollEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::OptionVirtualListOnScroll = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::OptionVirtualListOnScroll = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionVirtualListIFrameNodesEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionVirtualListIFrameNodesEnumWrapper { AzOptionVirtualListIFrameNodesEnumWrapper { inner: AzOptionVirtualListIFrameNodes::None } }
+    #[staticmethod]
+    fn Some(v: AzVirtualListIFrameNodes) -> AzOptionVirtualListIFrameNodesEnumWrapper { AzOptionVirtualListIFrameNodesEnumWrapper { inner: AzOptionVirtualListIFrameNodes::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionVirtualListIFrameNodes;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionVirtualListIFrameNodes::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionVirtualListIFrameNodes::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionVirtualListIFrameNodesEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::OptionVirtualListIFrameNodes = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::virtual_list::OptionVirtualListIFrameNodes = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionNumberInputOnValueChangeEnumWrapper {
     #[classattr]
@@ -34463,6 +40265,64 @@ impl PyObjectProtocol for AzOptionImageRefEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionDragDataEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDragDataEnumWrapper { AzOptionDragDataEnumWrapper { inner: AzOptionDragData::None } }
+    #[staticmethod]
+    fn Some(v: AzDragData) -> AzOptionDragDataEnumWrapper { AzOptionDragDataEnumWrapper { inner: AzOptionDragData::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDragData;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDragData::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDragData::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDragDataEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::window::OptionDragData = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::window::OptionDragData = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionImeCompositionEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionImeCompositionEnumWrapper { AzOptionImeCompositionEnumWrapper { inner: AzOptionImeComposition::None } }
+    #[staticmethod]
+    fn Some(v: AzImeComposition) -> AzOptionImeCompositionEnumWrapper { AzOptionImeCompositionEnumWrapper { inner: AzOptionImeComposition::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionImeComposition;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionImeComposition::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionImeComposition::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionImeCompositionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::window::OptionImeComposition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::window::OptionImeComposition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionFontRefEnumWrapper {
     #[classattr]
@@ -36676,6 +42536,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzWindowThemeEnumWrapper>()?;
     m.add_class::<AzWindowPositionEnumWrapper>()?;
     m.add_class::<AzImePositionEnumWrapper>()?;
+    m.add_class::<AzImeComposition>()?;
     m.add_class::<AzTouchState>()?;
     m.add_class::<AzMonitor>()?;
     m.add_class::<AzVideoMode>()?;
@@ -36702,6 +42563,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzInlineGlyph>()?;
     m.add_class::<AzInlineTextHit>()?;
     m.add_class::<AzFocusTargetEnumWrapper>()?;
+    m.add_class::<AzDragData>()?;
     m.add_class::<AzFocusTargetPath>()?;
     m.add_class::<AzAnimation>()?;
     m.add_class::<AzAnimationRepeatEnumWrapper>()?;
@@ -36738,6 +42600,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzAccessibilityRoleEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateEnumWrapper>()?;
     m.add_class::<AzTabIndexEnumWrapper>()?;
+    m.add_class::<AzFocusScopeEnumWrapper>()?;
+    m.add_class::<AzRovingFocus>()?;
+    m.add_class::<AzRovingFocusOrientationEnumWrapper>()?;
     m.add_class::<AzIdOrClassEnumWrapper>()?;
     m.add_class::<AzNodeDataInlineCssPropertyEnumWrapper>()?;
 
@@ -36938,6 +42803,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCheckBoxOnToggle>()?;
     m.add_class::<AzCheckBoxOnToggleCallback>()?;
     m.add_class::<AzCheckBoxState>()?;
+    m.add_class::<AzDropDown>()?;
+    m.add_class::<AzDropDownStateWrapper>()?;
+    m.add_class::<AzDropDownState>()?;
+    m.add_class::<AzDropDownChoice>()?;
+    m.add_class::<AzDropDownOnChoiceChange>()?;
+    m.add_class::<AzDropDownOnChoiceChangeCallback>()?;
     m.add_class::<AzLabel>()?;
     m.add_class::<AzColorInput>()?;
     m.add_class::<AzColorInputStateWrapper>()?;
@@ -36957,6 +42828,13 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTextInputOnFocusLostCallback>()?;
     m.add_class::<AzOnTextInputReturn>()?;
     m.add_class::<AzTextInputValidEnumWrapper>()?;
+    m.add_class::<AzTextInputValidationResultEnumWrapper>()?;
+    m.add_class::<AzTextInputValidatorEnumWrapper>()?;
+    m.add_class::<AzTextInputCustomValidator>()?;
+    m.add_class::<AzTextInputValidatorCallback>()?;
+    m.add_class::<AzTextInputMask>()?;
+    m.add_class::<AzTextInputOnValidationChange>()?;
+    m.add_class::<AzTextInputOnValidationChangeCallback>()?;
     m.add_class::<AzNumberInput>()?;
     m.add_class::<AzNumberInputStateWrapper>()?;
     m.add_class::<AzNumberInputState>()?;
@@ -36964,6 +42842,69 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNumberInputOnValueChangeCallback>()?;
     m.add_class::<AzProgressBar>()?;
     m.add_class::<AzProgressBarState>()?;
+    m.add_class::<AzSplitPane>()?;
+    m.add_class::<AzSplitPaneStateWrapper>()?;
+    m.add_class::<AzSplitPaneState>()?;
+    m.add_class::<AzSplitPaneOrientationEnumWrapper>()?;
+    m.add_class::<AzSplitPaneCollapsedEnumWrapper>()?;
+    m.add_class::<AzSplitPaneOnResize>()?;
+    m.add_class::<AzSplitPaneOnResizeCallback>()?;
+    m.add_class::<AzTabContainer>()?;
+    m.add_class::<AzTabContainerStateWrapper>()?;
+    m.add_class::<AzTabContainerState>()?;
+    m.add_class::<AzTabContainerTab>()?;
+    m.add_class::<AzTabContainerOverflowEnumWrapper>()?;
+    m.add_class::<AzTabContainerOnTabChange>()?;
+    m.add_class::<AzTabContainerOnTabChangeCallback>()?;
+    m.add_class::<AzTabContainerOnTabClose>()?;
+    m.add_class::<AzTabContainerOnTabCloseCallback>()?;
+    m.add_class::<AzTabContainerOnTabMove>()?;
+    m.add_class::<AzTabContainerOnTabMoveCallback>()?;
+    m.add_class::<AzSlider>()?;
+    m.add_class::<AzSliderStateWrapper>()?;
+    m.add_class::<AzSliderState>()?;
+    m.add_class::<AzSliderOrientationEnumWrapper>()?;
+    m.add_class::<AzSliderThumbEnumWrapper>()?;
+    m.add_class::<AzSliderOnValueChange>()?;
+    m.add_class::<AzSliderOnValueChangeCallback>()?;
+    m.add_class::<AzTextEdit>()?;
+    m.add_class::<AzTextEditStateWrapper>()?;
+    m.add_class::<AzTextEditState>()?;
+    m.add_class::<AzTextEditCursor>()?;
+    m.add_class::<AzTextEditEditKindEnumWrapper>()?;
+    m.add_class::<AzTextEditUndoEntry>()?;
+    m.add_class::<AzTextEditOnTextChange>()?;
+    m.add_class::<AzTextEditOnTextChangeCallback>()?;
+    m.add_class::<AzTreeView>()?;
+    m.add_class::<AzTreeViewStateWrapper>()?;
+    m.add_class::<AzTreeViewState>()?;
+    m.add_class::<AzTreeViewNode>()?;
+    m.add_class::<AzTreeViewNodeData>()?;
+    m.add_class::<AzTreeViewChildrenEnumWrapper>()?;
+    m.add_class::<AzTreeViewSelectionModeEnumWrapper>()?;
+    m.add_class::<AzTreeViewDropPositionEnumWrapper>()?;
+    m.add_class::<AzTreeViewDropTarget>()?;
+    m.add_class::<AzTreeViewRow>()?;
+    m.add_class::<AzTreeViewRenderedRow>()?;
+    m.add_class::<AzTreeViewLoadRequest>()?;
+    m.add_class::<AzTreeViewLoadedChildren>()?;
+    m.add_class::<AzTreeViewDragPayload>()?;
+    m.add_class::<AzTreeViewOnLoadChildren>()?;
+    m.add_class::<AzTreeViewOnLoadChildrenCallback>()?;
+    m.add_class::<AzTreeViewOnSelectionChange>()?;
+    m.add_class::<AzTreeViewOnSelectionChangeCallback>()?;
+    m.add_class::<AzTreeViewOnNodeMoved>()?;
+    m.add_class::<AzTreeViewOnNodeMovedCallback>()?;
+    m.add_class::<AzVirtualList>()?;
+    m.add_class::<AzVirtualListStateWrapper>()?;
+    m.add_class::<AzVirtualListState>()?;
+    m.add_class::<AzVirtualListRowHeightEnumWrapper>()?;
+    m.add_class::<AzVirtualListMeasuredRow>()?;
+    m.add_class::<AzVirtualListIFrameNodes>()?;
+    m.add_class::<AzVirtualListRenderRow>()?;
+    m.add_class::<AzVirtualListRenderRowCallback>()?;
+    m.add_class::<AzVirtualListOnScroll>()?;
+    m.add_class::<AzVirtualListOnScrollCallback>()?;
 
     m.add_class::<AzNode>()?;
     m.add_class::<AzCascadeInfo>()?;
@@ -37055,6 +42996,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
 
     m.add_class::<AzXml>()?;
     m.add_class::<AzXmlNode>()?;
+    m.add_class::<AzXmlSpan>()?;
 
     m.add_class::<AzFile>()?;
 
@@ -37107,6 +43049,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTessellatedSvgNodeVec>()?;
     m.add_class::<AzStyleFontFamilyVec>()?;
     m.add_class::<AzXmlNodeVec>()?;
+    m.add_class::<AzXmlSpanVec>()?;
     m.add_class::<AzFmtArgVec>()?;
     m.add_class::<AzInlineLineVec>()?;
     m.add_class::<AzInlineWordVec>()?;
@@ -37133,6 +43076,15 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzVirtualKeyCodeVec>()?;
     m.add_class::<AzCascadeInfoVec>()?;
     m.add_class::<AzScanCodeVec>()?;
+    m.add_class::<AzTextInputValidatorVec>()?;
+    m.add_class::<AzDropDownChoiceVec>()?;
+    m.add_class::<AzTabContainerTabVec>()?;
+    m.add_class::<AzTextEditUndoEntryVec>()?;
+    m.add_class::<AzTreeViewNodeDataVec>()?;
+    m.add_class::<AzTreeViewNodeVec>()?;
+    m.add_class::<AzTreeViewRowVec>()?;
+    m.add_class::<AzTreeViewRenderedRowVec>()?;
+    m.add_class::<AzVirtualListMeasuredRowVec>()?;
     m.add_class::<AzCssDeclarationVec>()?;
     m.add_class::<AzCssPathSelectorVec>()?;
     m.add_class::<AzStylesheetVec>()?;
@@ -37159,6 +43111,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzMenuItemVecDestructorEnumWrapper>()?;
     m.add_class::<AzTessellatedSvgNodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzXmlNodeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzXmlSpanVecDestructorEnumWrapper>()?;
     m.add_class::<AzFmtArgVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineLineVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineWordVecDestructorEnumWrapper>()?;
@@ -37185,6 +43138,15 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzVirtualKeyCodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzCascadeInfoVecDestructorEnumWrapper>()?;
     m.add_class::<AzScanCodeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTextInputValidatorVecDestructorEnumWrapper>()?;
+    m.add_class::<AzDropDownChoiceVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTabContainerTabVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTextEditUndoEntryVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTreeViewNodeDataVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTreeViewNodeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTreeViewRowVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTreeViewRenderedRowVecDestructorEnumWrapper>()?;
+    m.add_class::<AzVirtualListMeasuredRowVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssDeclarationVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssPathSelectorVecDestructorEnumWrapper>()?;
     m.add_class::<AzStylesheetVecDestructorEnumWrapper>()?;
@@ -37214,6 +43176,22 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionTextInputOnVirtualKeyDownEnumWrapper>()?;
     m.add_class::<AzOptionTextInputOnFocusLostEnumWrapper>()?;
     m.add_class::<AzOptionTextInputSelectionEnumWrapper>()?;
+    m.add_class::<AzOptionTextInputMaskEnumWrapper>()?;
+    m.add_class::<AzOptionTextInputOnValidationChangeEnumWrapper>()?;
+    m.add_class::<AzOptionDropDownOnChoiceChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTabContainerOnTabChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTabContainerOnTabCloseEnumWrapper>()?;
+    m.add_class::<AzOptionTabContainerOnTabMoveEnumWrapper>()?;
+    m.add_class::<AzOptionSplitPaneOnResizeEnumWrapper>()?;
+    m.add_class::<AzOptionSliderOnValueChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTextEditOnTextChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTextEditCursorEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnLoadChildrenEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnSelectionChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnNodeMovedEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewDropTargetEnumWrapper>()?;
+    m.add_class::<AzOptionVirtualListOnScrollEnumWrapper>()?;
+    m.add_class::<AzOptionVirtualListIFrameNodesEnumWrapper>()?;
     m.add_class::<AzOptionNumberInputOnValueChangeEnumWrapper>()?;
     m.add_class::<AzOptionMenuItemIconEnumWrapper>()?;
     m.add_class::<AzOptionMenuCallbackEnumWrapper>()?;
@@ -37226,6 +43204,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionU16EnumWrapper>()?;
     m.add_class::<AzOptionU32EnumWrapper>()?;
     m.add_class::<AzOptionImageRefEnumWrapper>()?;
+    m.add_class::<AzOptionDragDataEnumWrapper>()?;
+    m.add_class::<AzOptionImeCompositionEnumWrapper>()?;
     m.add_class::<AzOptionFontRefEnumWrapper>()?;
     m.add_class::<AzOptionSystemClipboardEnumWrapper>()?;
     m.add_class::<AzOptionFileTypeListEnumWrapper>()?;
@@ -0,0 +1,943 @@
+//! Drop-down selection widget (select box)
+//!
+//! The popup list is a `position: absolute` child of the container, so it is
+//! laid out and rendered above the following siblings of the drop-down. The
+//! popup is toggled and highlighted via `set_css_property`, the DOM is only
+//! regenerated if the `on_choice_change` callback requests it.
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClassVec, IdOrClass, IdOrClass::Class,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Hover},
+    },
+    callbacks::{Callback, CallbackInfo, Update, RefAny},
+};
+use azul_desktop::css::{
+    impl_option, impl_option_inner,
+    impl_vec, impl_vec_debug, impl_vec_clone, impl_vec_partialeq, impl_vec_mut,
+};
+use azul_core::gl::OptionUsize;
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{
+    XmlComponent, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, ComponentArgumentsMap, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use std::vec::Vec;
+use std::string::String;
+
+static DROP_DOWN_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-container"))];
+static DROP_DOWN_HEADER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-header"))];
+static DROP_DOWN_LABEL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-label"))];
+static DROP_DOWN_ARROW_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-arrow"))];
+static DROP_DOWN_POPUP_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-popup"))];
+static DROP_DOWN_GROUP_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-group"))];
+static DROP_DOWN_CHOICE_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-choice"))];
+static DROP_DOWN_CHOICE_DISABLED_CLASS: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-drop-down-choice")),
+    Class(AzString::from_const_str("__azul-native-drop-down-choice-disabled")),
+];
+
+pub type DropDownOnChoiceChangeCallbackType = extern "C" fn(&mut RefAny, &DropDownState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct DropDownOnChoiceChangeCallback {
+    pub cb: DropDownOnChoiceChangeCallbackType,
+}
+
+impl_callback!(DropDownOnChoiceChangeCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DropDown {
+    pub state: DropDownStateWrapper,
+    /// Style for the outer container (receives the keyboard events)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for the always visible header (selected label + arrow)
+    pub header_style: NodeDataInlineCssPropertyVec,
+    /// Style for the popup list
+    pub popup_style: NodeDataInlineCssPropertyVec,
+    /// Style for each choice in the popup list
+    pub choice_style: NodeDataInlineCssPropertyVec,
+    /// Style for the (non-selectable) group headers in the popup list
+    pub group_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct DropDownStateWrapper {
+    pub inner: DropDownState,
+    /// Optional: Function to call when the selected choice changes
+    pub on_choice_change: OptionDropDownOnChoiceChange,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct DropDownOnChoiceChange {
+    pub data: RefAny,
+    pub callback: DropDownOnChoiceChangeCallback,
+}
+
+impl_option!(DropDownOnChoiceChange, OptionDropDownOnChoiceChange, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+/// Single selectable entry of a `DropDown`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DropDownChoice {
+    pub label: AzString,
+    /// Disabled choices are rendered, but can't be selected
+    pub disabled: bool,
+    /// Choices with the same group are listed under a common group header,
+    /// consecutive choices should have the same group
+    pub group: OptionAzString,
+}
+
+impl_vec!(DropDownChoice, DropDownChoiceVec, DropDownChoiceVecDestructor);
+impl_vec_debug!(DropDownChoice, DropDownChoiceVec);
+impl_vec_clone!(DropDownChoice, DropDownChoiceVec, DropDownChoiceVecDestructor);
+impl_vec_partialeq!(DropDownChoice, DropDownChoiceVec);
+impl_vec_mut!(DropDownChoice, DropDownChoiceVec);
+
+impl DropDownChoice {
+    pub fn new(label: AzString) -> Self {
+        Self { label, disabled: false, group: None.into() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DropDownState {
+    pub choices: DropDownChoiceVec,
+    /// Index of the currently selected choice
+    pub selected: OptionUsize,
+    /// Index of the choice that is highlighted in the open popup
+    pub highlighted: OptionUsize,
+    /// Whether the popup list is currently visible
+    pub is_open: bool,
+    /// Characters typed since the popup was opened ("type-to-search")
+    pub search: AzString,
+}
+
+impl Default for DropDownState {
+    fn default() -> Self {
+        Self {
+            choices: DropDownChoiceVec::from_const_slice(&[]),
+            selected: None.into(),
+            highlighted: None.into(),
+            is_open: false,
+            search: AzString::from_const_str(""),
+        }
+    }
+}
+
+/// Row of the popup list, in render order
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DropDownRow<'a> {
+    Group(&'a str),
+    Choice(usize),
+}
+
+impl DropDownState {
+
+    /// Returns the currently selected choice
+    pub fn get_selected_choice(&self) -> Option<&DropDownChoice> {
+        self.selected.into_option().and_then(|i| self.choices.as_ref().get(i))
+    }
+
+    pub fn is_selectable(&self, index: usize) -> bool {
+        self.choices.as_ref().get(index).map(|c| !c.disabled).unwrap_or(false)
+    }
+
+    /// Returns the next (or previous) choice after `from` that is not disabled.
+    /// If `from` is `None`, returns the first (or last) selectable choice.
+    pub fn get_next_selectable(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let len = self.choices.len();
+        match (from, forward) {
+            (None, true) => (0..len).find(|i| self.is_selectable(*i)),
+            (None, false) => (0..len).rev().find(|i| self.is_selectable(*i)),
+            (Some(from), true) => (from.saturating_add(1)..len).find(|i| self.is_selectable(*i)),
+            (Some(from), false) => (0..from.min(len)).rev().find(|i| self.is_selectable(*i)),
+        }
+    }
+
+    /// Appends `c` to the search buffer and returns the first selectable choice
+    /// whose label starts with the buffer (case-insensitive). If nothing
+    /// matches, the search restarts with just `c`.
+    pub fn search(&mut self, c: char) -> Option<usize> {
+
+        fn find(choices: &[DropDownChoice], search: &str) -> Option<usize> {
+            choices.iter().position(|choice| {
+                !choice.disabled && choice.label.as_str().to_lowercase().starts_with(search)
+            })
+        }
+
+        let mut search = self.search.as_str().to_lowercase();
+        search.extend(c.to_lowercase());
+
+        let found = match find(self.choices.as_ref(), &search) {
+            Some(s) => Some(s),
+            None => {
+                search = c.to_lowercase().collect();
+                find(self.choices.as_ref(), &search)
+            }
+        };
+
+        self.search = search.into();
+        found
+    }
+
+    /// Opens or closes the popup, highlighting the selected choice when opening
+    pub fn set_open(&mut self, is_open: bool) {
+        self.is_open = is_open;
+        self.search = AzString::from_const_str("");
+        self.highlighted = if is_open { self.selected } else { None.into() };
+    }
+
+    /// Returns the group headers and choices in the order they are rendered
+    fn get_rows(&self) -> Vec<DropDownRow> {
+        let mut rows = Vec::new();
+        let mut current_group = None;
+        for (i, choice) in self.choices.as_ref().iter().enumerate() {
+            let group = choice.group.as_ref().map(|g| g.as_str());
+            if let Some(g) = group {
+                if group != current_group {
+                    rows.push(DropDownRow::Group(g));
+                }
+            }
+            current_group = group;
+            rows.push(DropDownRow::Choice(i));
+        }
+        rows
+    }
+}
+
+const BACKGROUND_COLOR: ColorU = ColorU { r: 255,  g: 255,  b: 255,  a: 255 }; // white
+const BACKGROUND_THEME_LIGHT: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BACKGROUND_COLOR)];
+const BACKGROUND_COLOR_LIGHT: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(BACKGROUND_THEME_LIGHT);
+const HIGHLIGHT_COLOR: ColorU = ColorU { r: 204, g: 232, b: 255, a: 255 }; // #cce8ff
+const HIGHLIGHT_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(HIGHLIGHT_COLOR)];
+const HIGHLIGHT_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(HIGHLIGHT_THEME);
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+static DEFAULT_DROP_DOWN_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    // position: relative, so that the popup is positioned below the header
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(150))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+];
+
+static DEFAULT_DROP_DOWN_HEADER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::SpaceBetween)),
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+
+    // padding: 2px 4px
+
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(2))),
+
+    // border: 1px solid #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    // Hover(border-color: #4286f4;)
+
+    Hover(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+static DEFAULT_DROP_DOWN_POPUP_STYLE: &[NodeDataInlineCssProperty] = &[
+    // hidden until the drop-down is opened
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_percent(100))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_percent(100))),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+
+    // border: 1px solid #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+];
+
+static DEFAULT_DROP_DOWN_CHOICE_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(2))),
+];
+
+static DEFAULT_DROP_DOWN_GROUP_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(4))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(2))),
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+];
+
+static DROP_DOWN_DISABLED_CHOICE_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+];
+
+impl DropDown {
+
+    pub fn new(choices: DropDownChoiceVec) -> Self {
+        Self {
+            state: DropDownStateWrapper {
+                inner: DropDownState {
+                    choices,
+                    .. Default::default()
+                },
+                .. Default::default()
+            },
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_CONTAINER_STYLE),
+            header_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_HEADER_STYLE),
+            popup_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_POPUP_STYLE),
+            choice_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_CHOICE_STYLE),
+            group_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_GROUP_STYLE),
+        }
+    }
+
+    /// Parses a list of choices in the form `"Apple, Fruits/Banana, !Cherry"`:
+    /// `Group/Label` puts the choice into a group, a leading `!` disables the choice
+    pub fn from_choices_str(choices: &str) -> Self {
+        let choices = choices.split(',').filter_map(|choice| {
+            let choice = choice.trim();
+            let (disabled, choice) = match choice.strip_prefix('!') {
+                Some(s) => (true, s.trim()),
+                None => (false, choice),
+            };
+            let (group, label) = match choice.find('/') {
+                Some(pos) => (Some(choice[..pos].trim()), choice[(pos + 1)..].trim()),
+                None => (None, choice),
+            };
+            if label.is_empty() {
+                return None;
+            }
+            Some(DropDownChoice {
+                label: label.to_string().into(),
+                disabled,
+                group: group.map(|g| AzString::from(g.to_string())).into(),
+            })
+        }).collect::<Vec<_>>();
+        Self::new(choices.into())
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new(DropDownChoiceVec::from_const_slice(&[]));
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    #[inline]
+    pub fn set_selected(&mut self, selected: OptionUsize) {
+        self.state.inner.selected = selected;
+    }
+
+    #[inline]
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.set_selected(Some(selected).into());
+        self
+    }
+
+    /// Selects the first choice with the given label, does nothing if no choice matches
+    #[inline]
+    pub fn with_selected_label(mut self, label: &str) -> Self {
+        if let Some(pos) = self.state.inner.choices.as_ref().iter().position(|c| c.label.as_str() == label) {
+            self.set_selected(Some(pos).into());
+        }
+        self
+    }
+
+    #[inline]
+    pub fn set_on_choice_change(&mut self, data: RefAny, on_choice_change: DropDownOnChoiceChangeCallbackType) {
+        self.state.on_choice_change = Some(DropDownOnChoiceChange {
+            callback: DropDownOnChoiceChangeCallback { cb: on_choice_change },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_choice_change(mut self, data: RefAny, on_choice_change: DropDownOnChoiceChangeCallbackType) -> Self {
+        self.set_on_choice_change(data, on_choice_change);
+        self
+    }
+
+    #[inline]
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    #[inline]
+    pub fn set_header_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.header_style = style;
+    }
+
+    #[inline]
+    pub fn set_popup_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.popup_style = style;
+    }
+
+    #[inline]
+    pub fn set_choice_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.choice_style = style;
+    }
+
+    #[inline]
+    pub fn set_group_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.group_style = style;
+    }
+
+    pub fn dom(mut self) -> Dom {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter,
+        };
+
+        // the popup is always closed after regenerating the DOM
+        self.state.inner.set_open(false);
+
+        let selected_label = self.state.inner.get_selected_choice()
+            .map(|c| c.label.clone())
+            .unwrap_or_default();
+
+        let inner = self.state.inner.clone();
+        let state_ref = RefAny::new(self.state);
+
+        let popup_rows = inner.get_rows().into_iter().map(|row| match row {
+            DropDownRow::Group(group) => {
+                Dom::text(AzString::from(group.to_string()))
+                .with_ids_and_classes(IdOrClassVec::from(DROP_DOWN_GROUP_CLASS))
+                .with_inline_css_props(self.group_style.clone())
+            },
+            DropDownRow::Choice(index) => {
+
+                let choice = &inner.choices.as_ref()[index];
+                let (choice_class, choice_style) = if choice.disabled {
+                    let mut style = self.choice_style.clone().into_library_owned_vec();
+                    style.extend_from_slice(DROP_DOWN_DISABLED_CHOICE_STYLE);
+                    (DROP_DOWN_CHOICE_DISABLED_CLASS, style.into())
+                } else {
+                    (DROP_DOWN_CHOICE_CLASS, self.choice_style.clone())
+                };
+
+                let choice_ref = RefAny::new(DropDownChoiceRef {
+                    drop_down: state_ref.clone(),
+                    index,
+                });
+
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from(choice_class))
+                .with_inline_css_props(choice_style)
+                .with_callbacks(vec![
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                        data: choice_ref.clone(),
+                        callback: Callback { cb: self::input::default_on_choice_clicked },
+                    },
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::MouseEnter),
+                        data: choice_ref,
+                        callback: Callback { cb: self::input::default_on_choice_mouse_enter },
+                    },
+                ].into())
+                .with_children(vec![Dom::text(choice.label.clone())].into())
+            },
+        }).collect::<Vec<Dom>>();
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(DROP_DOWN_CONTAINER_CLASS))
+        .with_inline_css_props(self.container_style)
+        .with_tab_index(TabIndex::Auto)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextInput),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_text_input },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::FocusLost),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_focus_lost },
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(DROP_DOWN_HEADER_CLASS))
+            .with_inline_css_props(self.header_style)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_header_clicked },
+                },
+            ].into())
+            .with_children(vec![
+                Dom::text(selected_label)
+                .with_ids_and_classes(IdOrClassVec::from(DROP_DOWN_LABEL_CLASS)),
+                Dom::text(AzString::from_const_str("\u{25BE}")) // ▾
+                .with_ids_and_classes(IdOrClassVec::from(DROP_DOWN_ARROW_CLASS)),
+            ].into()),
+            // absolutely positioned children are rendered after their
+            // siblings, so the popup is drawn on top of the following content
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(DROP_DOWN_POPUP_CLASS))
+            .with_inline_css_props(self.popup_style)
+            .with_children(popup_rows.into()),
+        ].into())
+    }
+}
+
+/// Callback data of a single choice in the popup list
+#[derive(Debug)]
+struct DropDownChoiceRef {
+    drop_down: RefAny,
+    index: usize,
+}
+
+// handle input events for the DropDown
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId, FocusTarget};
+    use azul_desktop::css::{CssProperty, LayoutDisplay, StyleBackgroundContentVec};
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        DropDownStateWrapper, DropDownOnChoiceChange, DropDownChoiceRef, DropDownRow,
+        HIGHLIGHT_BACKGROUND,
+    };
+
+    pub(in super) extern "C" fn default_on_header_clicked(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = match info.get_parent(info.get_hit_node()) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let is_open = drop_down.inner.is_open;
+        drop_down.inner.set_open(!is_open);
+        info.set_focus(FocusTarget::Id(container_id));
+        update_popup(&drop_down, container_id, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_choice_clicked(choice: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut choice = match choice.downcast_mut::<DropDownChoiceRef>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let index = choice.index;
+        let mut drop_down = match choice.drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // choice -> popup -> container
+        let container_id = match info.get_parent(info.get_hit_node()).and_then(|popup| info.get_parent(popup)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !drop_down.inner.is_selectable(index) {
+            return Update::DoNothing;
+        }
+
+        drop_down.inner.set_open(false);
+        let result = select_choice(&mut drop_down, index, &mut info);
+        update_popup(&drop_down, container_id, &mut info);
+        result
+    }
+
+    pub(in super) extern "C" fn default_on_choice_mouse_enter(choice: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut choice = match choice.downcast_mut::<DropDownChoiceRef>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let index = choice.index;
+        let mut drop_down = match choice.drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = match info.get_parent(info.get_hit_node()).and_then(|popup| info.get_parent(popup)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !drop_down.inner.is_open || !drop_down.inner.is_selectable(index) {
+            return Update::DoNothing;
+        }
+
+        drop_down.inner.highlighted = Some(index).into();
+        update_popup(&drop_down, container_id, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = info.get_hit_node();
+        let mut result = Update::DoNothing;
+
+        if drop_down.inner.is_open {
+            let highlighted = drop_down.inner.highlighted.into_option();
+            match last_keycode {
+                VirtualKeyCode::Up => {
+                    if let Some(s) = drop_down.inner.get_next_selectable(highlighted, false) {
+                        drop_down.inner.highlighted = Some(s).into();
+                    }
+                },
+                VirtualKeyCode::Down => {
+                    if let Some(s) = drop_down.inner.get_next_selectable(highlighted, true) {
+                        drop_down.inner.highlighted = Some(s).into();
+                    }
+                },
+                VirtualKeyCode::Home => {
+                    drop_down.inner.highlighted = drop_down.inner.get_next_selectable(None, true).into();
+                },
+                VirtualKeyCode::End => {
+                    drop_down.inner.highlighted = drop_down.inner.get_next_selectable(None, false).into();
+                },
+                VirtualKeyCode::Return => {
+                    drop_down.inner.set_open(false);
+                    if let Some(s) = highlighted {
+                        result = select_choice(&mut drop_down, s, &mut info);
+                    }
+                },
+                VirtualKeyCode::Escape | VirtualKeyCode::Tab => {
+                    drop_down.inner.set_open(false);
+                },
+                _ => return Update::DoNothing,
+            }
+        } else {
+            let selected = drop_down.inner.selected.into_option();
+            match last_keycode {
+                VirtualKeyCode::Up => {
+                    if let Some(s) = drop_down.inner.get_next_selectable(selected, false) {
+                        result = select_choice(&mut drop_down, s, &mut info);
+                    }
+                },
+                VirtualKeyCode::Down => {
+                    if let Some(s) = drop_down.inner.get_next_selectable(selected, true) {
+                        result = select_choice(&mut drop_down, s, &mut info);
+                    }
+                },
+                VirtualKeyCode::Return | VirtualKeyCode::Space => {
+                    drop_down.inner.set_open(true);
+                },
+                _ => return Update::DoNothing,
+            }
+        }
+
+        update_popup(&drop_down, container_id, &mut info);
+        result
+    }
+
+    pub(in super) extern "C" fn default_on_text_input(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+
+        let c = match keyboard_state.current_char.into_option().and_then(core::char::from_u32) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // space opens the popup (see default_on_virtual_key_down)
+        if c.is_control() || (c == ' ' && !drop_down.inner.is_open) {
+            return Update::DoNothing;
+        }
+
+        let container_id = info.get_hit_node();
+
+        let found = match drop_down.inner.search(c) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let result = if drop_down.inner.is_open {
+            drop_down.inner.highlighted = Some(found).into();
+            Update::DoNothing
+        } else {
+            // like a native select box, typing on a closed drop-down changes the selection
+            select_choice(&mut drop_down, found, &mut info)
+        };
+
+        update_popup(&drop_down, container_id, &mut info);
+        result
+    }
+
+    pub(in super) extern "C" fn default_on_focus_lost(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if drop_down.inner.is_open {
+            drop_down.inner.set_open(false);
+            update_popup(&drop_down, info.get_hit_node(), &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    /// Selects the choice and invokes the `on_choice_change` callback if the selection changed
+    fn select_choice(drop_down: &mut DropDownStateWrapper, index: usize, info: &mut CallbackInfo) -> Update {
+
+        if drop_down.inner.selected.into_option() == Some(index) || !drop_down.inner.is_selectable(index) {
+            return Update::DoNothing;
+        }
+
+        drop_down.inner.selected = Some(index).into();
+
+        // rustc doesn't understand the borrowing lifetime here
+        let onchoicechange = &mut drop_down.on_choice_change;
+        let inner = &drop_down.inner;
+
+        match onchoicechange.as_mut() {
+            Some(DropDownOnChoiceChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Synchronizes the label, the popup visibility and the highlighted
+    /// choice on the screen with the state, without regenerating the DOM
+    fn update_popup(drop_down: &DropDownStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) {
+
+        // container -> [header -> [label, arrow], popup -> [rows]]
+        let header_id = match info.get_first_child(container_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        if let Some(label_id) = info.get_first_child(header_id) {
+            let label = drop_down.inner.get_selected_choice()
+                .map(|c| c.label.clone())
+                .unwrap_or_default();
+            info.set_string_contents(label_id, label);
+        }
+
+        let popup_id = match info.get_next_sibling(header_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        info.set_css_property(popup_id, CssProperty::const_display(
+            if drop_down.inner.is_open { LayoutDisplay::Block } else { LayoutDisplay::None }
+        ));
+
+        let highlighted = drop_down.inner.highlighted.into_option();
+        let mut row_id = info.get_first_child(popup_id);
+
+        for row in drop_down.inner.get_rows() {
+            let current_row_id = match row_id {
+                Some(s) => s,
+                None => break,
+            };
+            if let DropDownRow::Choice(index) = row {
+                info.set_css_property(current_row_id, CssProperty::const_background_content(
+                    if highlighted == Some(index) {
+                        HIGHLIGHT_BACKGROUND
+                    } else {
+                        StyleBackgroundContentVec::from_const_slice(&[])
+                    }
+                ));
+            }
+            row_id = info.get_next_sibling(current_row_id);
+        }
+    }
+}
+
+impl From<DropDown> for Dom {
+    fn from(d: DropDown) -> Dom {
+        d.dom()
+    }
+}
+
+/// XML component for `<drop_down choices="Apple, Fruits/Banana, !Cherry" selected="Apple" />`,
+/// see `DropDown::from_choices_str` for the syntax of the `choices`
+pub struct DropDownRenderer {
+    node: XmlNode,
+}
+
+impl DropDownRenderer {
+    pub fn new() -> Self {
+        Self { node: XmlNode::new("drop_down") }
+    }
+}
+
+impl XmlComponent for DropDownRenderer {
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        let mut args = ComponentArgumentsMap::default();
+        args.insert(String::from("choices"), (String::from("String"), 0));
+        args.insert(String::from("selected"), (String::from("String"), 1));
+        ComponentArguments {
+            args,
+            accepts_text: false,
+            accepts_slot: false,
+        }
+    }
+
+    fn render_dom(&self, _: &XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent) -> Result<StyledDom, RenderDomError> {
+        let choices = args.args.get("choices").map(|(v, _)| v.as_str()).unwrap_or_default();
+        let selected = args.args.get("selected").map(|(v, _)| v.as_str()).unwrap_or_default();
+        Ok(DropDown::from_choices_str(choices).with_selected_label(selected).dom().style(&mut Css::empty()))
+    }
+
+    fn compile_to_rust_code(&self, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(String::from("azul::widgets::DropDown::from_choices_str(choices).with_selected_label(selected).dom()"))
+    }
+
+    fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
+}
+
+#[cfg(test)]
+fn test_state(choices: &str) -> DropDownState {
+    DropDown::from_choices_str(choices).state.inner
+}
+
+#[test]
+fn test_from_choices_str() {
+    let state = test_state("Apple, Fruits/Banana, !Cherry, , Fruits/");
+    let choices = state.choices.as_ref();
+    assert_eq!(choices.len(), 3);
+    assert_eq!(choices[0], DropDownChoice::new("Apple".into()));
+    assert_eq!(choices[1].label.as_str(), "Banana");
+    assert_eq!(choices[1].group.as_ref().map(|g| g.as_str()), Some("Fruits"));
+    assert_eq!(choices[2].label.as_str(), "Cherry");
+    assert!(choices[2].disabled);
+
+    let drop_down = DropDown::from_choices_str("Apple, Banana").with_selected_label("Banana");
+    assert_eq!(drop_down.state.inner.selected.into_option(), Some(1));
+    let drop_down = DropDown::from_choices_str("Apple, Banana").with_selected_label("Durian");
+    assert_eq!(drop_down.state.inner.selected.into_option(), None);
+}
+
+#[test]
+fn test_get_next_selectable_skips_disabled() {
+    let state = test_state("!Apple, Banana, !Cherry, !Date, Elderberry, !Fig");
+    assert_eq!(state.get_next_selectable(None, true), Some(1));
+    assert_eq!(state.get_next_selectable(None, false), Some(4));
+    assert_eq!(state.get_next_selectable(Some(1), true), Some(4));
+    assert_eq!(state.get_next_selectable(Some(4), false), Some(1));
+    // no selectable choice past the first / last one
+    assert_eq!(state.get_next_selectable(Some(4), true), None);
+    assert_eq!(state.get_next_selectable(Some(1), false), None);
+    // starting from a disabled or out-of-range index
+    assert_eq!(state.get_next_selectable(Some(2), true), Some(4));
+    assert_eq!(state.get_next_selectable(Some(100), false), Some(4));
+
+    let all_disabled = test_state("!Apple, !Banana");
+    assert_eq!(all_disabled.get_next_selectable(None, true), None);
+    assert_eq!(all_disabled.get_next_selectable(None, false), None);
+}
+
+#[test]
+fn test_search() {
+    let mut state = test_state("Apple, !Apricot, Avocado, banana, Blueberry");
+
+    // case-insensitive, skips the disabled "Apricot"
+    assert_eq!(state.search('A'), Some(0));
+    assert_eq!(state.search('v'), Some(2));
+    assert_eq!(state.search.as_str(), "av");
+
+    // "avb" doesn't match anything: restart with just "b"
+    assert_eq!(state.search('B'), Some(3));
+    assert_eq!(state.search.as_str(), "b");
+    assert_eq!(state.search('l'), Some(4));
+
+    // nothing matches, not even the restarted search
+    assert_eq!(state.search('!'), None);
+
+    // only the disabled "Apricot" matches "apr"
+    let mut state = test_state("Apple, !Apricot");
+    state.search('a');
+    assert_eq!(state.search('p'), Some(0));
+    assert_eq!(state.search('r'), None);
+
+    // opening the popup resets the search and highlights the selection
+    state.selected = Some(0).into();
+    state.set_open(true);
+    assert_eq!(state.search.as_str(), "");
+    assert_eq!(state.highlighted.into_option(), Some(0));
+    state.set_open(false);
+    assert_eq!(state.highlighted.into_option(), None);
+}
@@ -73,7 +73,7 @@ pub mod check_box;
 /// Box displaying a color which opens a color picker dialog on being clicked
 pub mod color_input;
 /// Dropdown selection widget
-pub mod drop_down; // TODO
+pub mod drop_down;
 /// Label widget (centered text)
 pub mod label;
 // /// Single line text input widget
@@ -82,9 +82,34 @@ pub mod text_input;
 pub mod number_input;
 /// Progress bar widget
 pub mod progressbar;
+/// Virtualized list that only renders the visible rows (IFrame-based)
+pub mod virtual_list;
+/// Horizontal / vertical slider, optionally with two thumbs
+pub mod slider;
 // /// Spreadsheet (iframe) widget
 // pub mod spreadsheet;
-// /// Slider widget
-// pub mod slider;
-// /// Multi-line text input
-// pub mod text_edit;
\ No newline at end of file
+/// Multi-line text editor with line wrapping, selection and undo / redo
+pub mod text_edit;
+/// Tree view with expandable, lazily loaded nodes
+pub mod tree_view;
+/// Tab container with closable, reorderable tabs
+pub mod tab_container;
+/// Resizable split pane with collapsible panes
+pub mod split_pane;
+
+/// Registers the XML components of the built-in widgets (`<drop_down />`, ...),
+/// so that they can be used in `.azul` / `.xml` files
+pub fn register_xml_components(component_map: &mut azul_core::xml::XmlComponentMap) {
+    component_map.register_component("drop_down", Box::new(drop_down::DropDownRenderer::new()), false);
+    component_map.register_component("tab_container", Box::new(tab_container::TabContainerRenderer::new()), false);
+    component_map.register_component("split_pane", Box::new(split_pane::SplitPaneRenderer::new()), false);
+    component_map.register_component("slider", Box::new(slider::SliderRenderer::new()), false);
+    component_map.register_component("text_edit", Box::new(text_edit::TextEditRenderer::new()), false);
+    component_map.register_component("tree_view", Box::new(tree_view::TreeViewRenderer::new()), false);
+}
+
+/// Makes the widget components available to `StyledDom::from_xml`, `StyledDom::from_file`
+/// and the hot-reloaded files of the desktop runtime (called by the generated API functions)
+pub fn init_xml_components() {
+    azul_desktop::app::extra::add_xml_component_registration(register_xml_components)
+}
@@ -0,0 +1,965 @@
+//! Horizontal / vertical slider, optionally with two thumbs (range slider)
+//!
+//! The thumbs are dragged by tracking the mouse movement between the previous
+//! and the current `MouseState` (window-level `MouseOver` callbacks), so the
+//! thumb keeps following the cursor even if it leaves the slider. The thumb
+//! positions are updated via `set_css_property`, the DOM is only regenerated
+//! if the `on_value_change` callback requests it.
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClassVec, IdOrClass, IdOrClass::Class,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Hover},
+        AccessibilityInfo, AccessibilityRole, AccessibilityState,
+    },
+    callbacks::{Callback, CallbackInfo, Update, RefAny},
+};
+use azul_desktop::css::{impl_option, impl_option_inner};
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{
+    XmlComponent, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, ComponentArgumentsMap, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use std::vec::Vec;
+use std::string::String;
+
+static SLIDER_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-container"))];
+static SLIDER_TRACK_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-track"))];
+static SLIDER_FILL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-fill"))];
+static SLIDER_THUMB_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-thumb"))];
+static SLIDER_TICK_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-tick"))];
+
+/// Size of the (square) thumb in pixels, the thumb is centered on the value
+const THUMB_SIZE: isize = 12;
+/// Number of steps that PageUp / PageDown move the thumb
+const PAGE_STEPS: f32 = 10.0;
+/// If `step` is 0 (continuous slider), the keyboard moves the thumb by 1% of the range
+const CONTINUOUS_KEYBOARD_STEP: f32 = 0.01;
+
+pub type SliderOnValueChangeCallbackType = extern "C" fn(&mut RefAny, &SliderState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct SliderOnValueChangeCallback {
+    pub cb: SliderOnValueChangeCallbackType,
+}
+
+impl_callback!(SliderOnValueChangeCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Slider {
+    pub state: SliderStateWrapper,
+    /// Style for the outer container (receives the keyboard events)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for the track that the thumbs move on
+    pub track_style: NodeDataInlineCssPropertyVec,
+    /// Style for the filled part of the track (from `min` to the value or between the two thumbs)
+    pub fill_style: NodeDataInlineCssPropertyVec,
+    /// Style for the thumb(s)
+    pub thumb_style: NodeDataInlineCssPropertyVec,
+    /// Style for the tick marks
+    pub tick_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct SliderStateWrapper {
+    pub inner: SliderState,
+    /// Optional: Function to call when the value (or one of the values in range mode) changes
+    pub on_value_change: OptionSliderOnValueChange,
+    /// Whether the `active_thumb` is currently being dragged
+    pub is_dragging: bool,
+    /// Unsnapped value of the dragged thumb, so that small mouse movements
+    /// accumulate until they reach the next `step`
+    pub drag_value: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SliderOnValueChange {
+    pub data: RefAny,
+    pub callback: SliderOnValueChangeCallback,
+}
+
+impl_option!(SliderOnValueChange, OptionSliderOnValueChange, copy = false, [Debug, Clone, PartialEq]);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub enum SliderOrientation {
+    /// `min` is on the left
+    Horizontal,
+    /// `min` is at the bottom
+    Vertical,
+}
+
+/// Identifies one of the thumbs, a non-range slider only has the `Lower` thumb
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub enum SliderThumb {
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SliderState {
+    /// Current value (lower value in range mode)
+    pub value: f32,
+    /// Upper value, if set the slider is a range slider with two thumbs
+    pub upper_value: OptionF32,
+    pub min: f32,
+    pub max: f32,
+    /// Values snap to `min + n * step`, 0 for a continuous slider
+    pub step: f32,
+    pub orientation: SliderOrientation,
+    /// Optional: Distance between two tick marks (in value units)
+    pub tick_interval: OptionF32,
+    /// Thumb that is moved by the keyboard, last thumb that was clicked
+    pub active_thumb: SliderThumb,
+}
+
+impl Default for SliderState {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            upper_value: None.into(),
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            orientation: SliderOrientation::Horizontal,
+            tick_interval: None.into(),
+            active_thumb: SliderThumb::Lower,
+        }
+    }
+}
+
+impl SliderState {
+
+    #[inline]
+    pub fn is_range(&self) -> bool {
+        self.upper_value.is_some()
+    }
+
+    pub fn get_thumb_value(&self, thumb: SliderThumb) -> f32 {
+        match (thumb, self.upper_value.into_option()) {
+            (SliderThumb::Upper, Some(upper)) => upper,
+            _ => self.value,
+        }
+    }
+
+    /// Clamps the value to `min..=max` and snaps it to the nearest step
+    pub fn snap_value(&self, value: f32) -> f32 {
+        let (min, max) = (self.min.min(self.max), self.min.max(self.max));
+        let value = value.max(min).min(max);
+        if self.step > 0.0 {
+            (min + ((value - min) / self.step).round() * self.step).min(max)
+        } else {
+            value
+        }
+    }
+
+    /// Sets the value of the thumb (snapped and clamped, in range mode the
+    /// thumbs can't pass each other), returns whether the value changed
+    pub fn set_thumb_value(&mut self, thumb: SliderThumb, value: f32) -> bool {
+        let mut value = self.snap_value(value);
+        match (thumb, self.upper_value.into_option()) {
+            (SliderThumb::Upper, Some(upper)) => {
+                value = value.max(self.value);
+                self.upper_value = Some(value).into();
+                value != upper
+            },
+            (_, upper) => {
+                if let Some(upper) = upper {
+                    value = value.min(upper);
+                }
+                let changed = value != self.value;
+                self.value = value;
+                changed
+            }
+        }
+    }
+
+    /// Returns how much the arrow keys move the thumb
+    pub fn get_keyboard_step(&self) -> f32 {
+        if self.step > 0.0 {
+            self.step
+        } else {
+            (self.max - self.min).abs() * CONTINUOUS_KEYBOARD_STEP
+        }
+    }
+
+    /// Returns the position of the value on the track, from 0.0 to 1.0
+    pub fn get_ratio(&self, value: f32) -> f32 {
+        let range = self.max - self.min;
+        if range == 0.0 {
+            0.0
+        } else {
+            ((value - self.min) / range).max(0.0).min(1.0)
+        }
+    }
+
+    /// Inverse of `get_ratio`: returns the (unsnapped) value at the position on the track
+    pub fn get_value_at_ratio(&self, ratio: f32) -> f32 {
+        self.min + ratio.max(0.0).min(1.0) * (self.max - self.min)
+    }
+
+    /// Returns the thumb that is closest to the value
+    pub fn get_nearest_thumb(&self, value: f32) -> SliderThumb {
+        match self.upper_value.into_option() {
+            Some(upper) if (upper - value).abs() < (self.value - value).abs() => SliderThumb::Upper,
+            // both thumbs at the same position: move the one that can move in that direction
+            Some(upper) if upper == self.value && value > upper => SliderThumb::Upper,
+            _ => SliderThumb::Lower,
+        }
+    }
+
+    /// Returns the values of the tick marks (at most one tick per step)
+    pub fn get_tick_values(&self) -> Vec<f32> {
+        let interval = match self.tick_interval.into_option() {
+            Some(s) if s > 0.0 => s.max(self.step),
+            _ => return Vec::new(),
+        };
+        let (min, max) = (self.min.min(self.max), self.min.max(self.max));
+        let count = ((max - min) / interval).floor() as usize;
+        (0..=count).map(|i| min + i as f32 * interval).collect()
+    }
+}
+
+const COLOR_BCBCBC: ColorU = ColorU::new_rgb(188, 188, 188); // border color
+const COLOR_E6E6E6: ColorU = ColorU::new_rgb(230, 230, 230); // track background
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const COLOR_WHITE: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 };
+
+const TRACK_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_E6E6E6)];
+const FILL_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_4286F4)];
+const THUMB_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_WHITE)];
+const TICK_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_9B9B9B)];
+
+static SLIDER_CONTAINER_STYLE_HORIZONTAL: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(100))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(24))),
+    // leave space for the thumb at both ends of the track
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(THUMB_SIZE / 2))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(THUMB_SIZE / 2))),
+];
+
+static SLIDER_CONTAINER_STYLE_VERTICAL: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(100))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(24))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(THUMB_SIZE / 2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(THUMB_SIZE / 2))),
+];
+
+// .__azul-native-slider-track {
+//     position: relative;
+//     flex-grow: 1;
+//     height: 4px;
+//     background: #E6E6E6;
+//     border: 1px solid #BCBCBC;
+// }
+static SLIDER_TRACK_STYLE_HORIZONTAL: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(4))),
+    Normal(CssProperty::background_content(StyleBackgroundContentVec::from_const_slice(TRACK_BACKGROUND))),
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_BCBCBC })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_BCBCBC })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_BCBCBC })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_BCBCBC })),
+
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+];
+
+static SLIDER_TRACK_STYLE_VERTICAL: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(4))),
+    Normal(CssProperty::background_content(StyleBackgroundContentVec::from_const_slice(TRACK_BACKGROUND))),
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_BCBCBC })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_BCBCBC })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_BCBCBC })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_BCBCBC })),
+
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+];
+
+static SLIDER_FILL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::background_content(StyleBackgroundContentVec::from_const_slice(FILL_BACKGROUND))),
+];
+
+// .__azul-native-slider-thumb {
+//     position: absolute;
+//     width: 12px;
+//     height: 12px;
+//     border-radius: 6px;
+//     background: white;
+//     border: 1px solid #9b9b9b;
+// }
+static SLIDER_THUMB_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(THUMB_SIZE))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(THUMB_SIZE))),
+    Normal(CssProperty::background_content(StyleBackgroundContentVec::from_const_slice(THUMB_BACKGROUND))),
+
+    Normal(CssProperty::const_border_top_left_radius(StyleBorderTopLeftRadius::const_px(THUMB_SIZE / 2))),
+    Normal(CssProperty::const_border_top_right_radius(StyleBorderTopRightRadius::const_px(THUMB_SIZE / 2))),
+    Normal(CssProperty::const_border_bottom_left_radius(StyleBorderBottomLeftRadius::const_px(THUMB_SIZE / 2))),
+    Normal(CssProperty::const_border_bottom_right_radius(StyleBorderBottomRightRadius::const_px(THUMB_SIZE / 2))),
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    // Hover / Focus(border-color: #4286f4;)
+
+    Hover(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+static SLIDER_TICK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::background_content(StyleBackgroundContentVec::from_const_slice(TICK_BACKGROUND))),
+];
+
+impl Slider {
+
+    pub fn new(value: f32, min: f32, max: f32) -> Self {
+        Self::with_state(SliderState {
+            value,
+            min,
+            max,
+            .. Default::default()
+        })
+    }
+
+    /// Creates a range slider with two thumbs at `lower` and `upper`
+    pub fn new_range(lower: f32, upper: f32, min: f32, max: f32) -> Self {
+        Self::with_state(SliderState {
+            value: lower,
+            upper_value: Some(upper).into(),
+            min,
+            max,
+            .. Default::default()
+        })
+    }
+
+    fn with_state(mut inner: SliderState) -> Self {
+
+        // normalize the initial values
+        let value = inner.value;
+        let upper = inner.upper_value.into_option();
+        inner.upper_value = None.into();
+        inner.set_thumb_value(SliderThumb::Lower, value);
+        if let Some(upper) = upper {
+            inner.upper_value = Some(inner.value).into();
+            inner.set_thumb_value(SliderThumb::Upper, upper);
+        }
+
+        Self {
+            state: SliderStateWrapper {
+                inner,
+                .. Default::default()
+            },
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_CONTAINER_STYLE_HORIZONTAL),
+            track_style: NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_TRACK_STYLE_HORIZONTAL),
+            fill_style: NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_FILL_STYLE),
+            thumb_style: NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_THUMB_STYLE),
+            tick_style: NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_TICK_STYLE),
+        }
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new(0.0, 0.0, 100.0);
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    /// Sets the orientation, resets the container and track style to the default style of the orientation
+    pub fn set_orientation(&mut self, orientation: SliderOrientation) {
+        self.state.inner.orientation = orientation;
+        match orientation {
+            SliderOrientation::Horizontal => {
+                self.container_style = NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_CONTAINER_STYLE_HORIZONTAL);
+                self.track_style = NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_TRACK_STYLE_HORIZONTAL);
+            },
+            SliderOrientation::Vertical => {
+                self.container_style = NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_CONTAINER_STYLE_VERTICAL);
+                self.track_style = NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_TRACK_STYLE_VERTICAL);
+            },
+        }
+    }
+
+    #[inline]
+    pub fn with_orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.set_orientation(orientation);
+        self
+    }
+
+    #[inline]
+    pub fn set_step(&mut self, step: f32) {
+        self.state.inner.step = step.max(0.0);
+        let (value, upper) = (self.state.inner.value, self.state.inner.upper_value.into_option());
+        self.state.inner.set_thumb_value(SliderThumb::Lower, value);
+        if let Some(upper) = upper {
+            self.state.inner.set_thumb_value(SliderThumb::Upper, upper);
+        }
+    }
+
+    #[inline]
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.set_step(step);
+        self
+    }
+
+    #[inline]
+    pub fn set_tick_interval(&mut self, tick_interval: OptionF32) {
+        self.state.inner.tick_interval = tick_interval;
+    }
+
+    #[inline]
+    pub fn with_tick_interval(mut self, tick_interval: f32) -> Self {
+        self.set_tick_interval(Some(tick_interval).into());
+        self
+    }
+
+    #[inline]
+    pub fn set_on_value_change(&mut self, data: RefAny, on_value_change: SliderOnValueChangeCallbackType) {
+        self.state.on_value_change = Some(SliderOnValueChange {
+            callback: SliderOnValueChangeCallback { cb: on_value_change },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_value_change(mut self, data: RefAny, on_value_change: SliderOnValueChangeCallbackType) -> Self {
+        self.set_on_value_change(data, on_value_change);
+        self
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_track_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.track_style = style;
+    }
+
+    pub fn set_fill_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.fill_style = style;
+    }
+
+    pub fn set_thumb_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.thumb_style = style;
+    }
+
+    pub fn set_tick_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.tick_style = style;
+    }
+
+    pub fn dom(mut self) -> Dom {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter, WindowEventFilter,
+        };
+
+        self.state.is_dragging = false;
+
+        let inner = self.state.inner.clone();
+        let vertical = inner.orientation == SliderOrientation::Vertical;
+
+        let mut fill_style = self.fill_style.into_library_owned_vec();
+        fill_style.extend(get_fill_position(&inner).iter().cloned().map(Normal));
+
+        let mut track_children = vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(SLIDER_FILL_CLASS))
+            .with_inline_css_props(fill_style.into())
+        ];
+
+        let thumbs = if inner.is_range() { &[SliderThumb::Lower, SliderThumb::Upper][..] } else { &[SliderThumb::Lower][..] };
+        for thumb in thumbs {
+            let mut thumb_style = self.thumb_style.clone().into_library_owned_vec();
+            thumb_style.extend(get_thumb_position(&inner, *thumb).iter().cloned().map(Normal));
+            track_children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from(SLIDER_THUMB_CLASS))
+                .with_inline_css_props(thumb_style.into())
+            );
+        }
+
+        // tick marks are drawn below (horizontal) or to the right (vertical) of the track
+        for tick in inner.get_tick_values() {
+            let percent = inner.get_ratio(tick) * 100.0;
+            let mut tick_style = self.tick_style.clone().into_library_owned_vec();
+            if vertical {
+                tick_style.push(Normal(CssProperty::bottom(LayoutBottom::percent(percent))));
+                tick_style.push(Normal(CssProperty::const_left(LayoutLeft::const_px(6))));
+                tick_style.push(Normal(CssProperty::const_width(LayoutWidth::const_px(4))));
+                tick_style.push(Normal(CssProperty::const_height(LayoutHeight::const_px(1))));
+            } else {
+                tick_style.push(Normal(CssProperty::left(LayoutLeft::percent(percent))));
+                tick_style.push(Normal(CssProperty::const_top(LayoutTop::const_px(6))));
+                tick_style.push(Normal(CssProperty::const_width(LayoutWidth::const_px(1))));
+                tick_style.push(Normal(CssProperty::const_height(LayoutHeight::const_px(4))));
+            }
+            track_children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from(SLIDER_TICK_CLASS))
+                .with_inline_css_props(tick_style.into())
+            );
+        }
+
+        let state_ref = RefAny::new(self.state);
+
+        let mut dom = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(SLIDER_CONTAINER_CLASS))
+        .with_inline_css_props(self.container_style)
+        .with_tab_index(TabIndex::Auto)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down },
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(SLIDER_TRACK_CLASS))
+            .with_inline_css_props(self.track_style)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_track_mouse_down },
+                },
+                // window events: keep dragging when the cursor leaves the track
+                CallbackData {
+                    event: EventFilter::Window(WindowEventFilter::MouseOver),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_mouse_move },
+                },
+                CallbackData {
+                    event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_mouse_up },
+                },
+            ].into())
+            .with_children(track_children.into())
+        ].into());
+
+        dom.root.set_accessibility_info(get_accessibility_info(&inner));
+        dom
+    }
+}
+
+/// Returns the `left` + `width` (or `bottom` + `height`) of the filled part of the track
+fn get_fill_position(state: &SliderState) -> [CssProperty;2] {
+    let (start, end) = match state.upper_value.into_option() {
+        Some(upper) => (state.get_ratio(state.value), state.get_ratio(upper)),
+        None => (0.0, state.get_ratio(state.value)),
+    };
+    let (start, length) = (start * 100.0, (end - start).max(0.0) * 100.0);
+    match state.orientation {
+        SliderOrientation::Horizontal => [
+            CssProperty::left(LayoutLeft::percent(start)),
+            CssProperty::width(LayoutWidth::percent(length)),
+        ],
+        SliderOrientation::Vertical => [
+            CssProperty::bottom(LayoutBottom::percent(start)),
+            CssProperty::height(LayoutHeight::percent(length)),
+        ],
+    }
+}
+
+/// Returns the position of the thumb, the thumb is centered on the value
+fn get_thumb_position(state: &SliderState, thumb: SliderThumb) -> [CssProperty;2] {
+    let percent = state.get_ratio(state.get_thumb_value(thumb)) * 100.0;
+    match state.orientation {
+        SliderOrientation::Horizontal => [
+            CssProperty::left(LayoutLeft::percent(percent)),
+            CssProperty::const_margin_left(LayoutMarginLeft::const_px(-THUMB_SIZE / 2)),
+        ],
+        SliderOrientation::Vertical => [
+            CssProperty::bottom(LayoutBottom::percent(percent)),
+            CssProperty::const_margin_bottom(LayoutMarginBottom::const_px(-THUMB_SIZE / 2)),
+        ],
+    }
+}
+
+fn get_accessibility_info(state: &SliderState) -> AccessibilityInfo {
+    let value = match state.upper_value.into_option() {
+        Some(upper) => format!("{} - {}", state.value, upper),
+        None => format!("{}", state.value),
+    };
+    AccessibilityInfo {
+        name: None.into(),
+        value: Some(AzString::from(value)).into(),
+        role: AccessibilityRole::Slider,
+        states: vec![AccessibilityState::Focusable].into(),
+        accelerator: None.into(),
+        default_action: None.into(),
+    }
+}
+
+// handle input events for the Slider
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId, FocusTarget};
+    use azul_core::window::{CursorPosition, VirtualKeyCode};
+    use super::{
+        SliderStateWrapper, SliderOnValueChange, SliderOrientation, SliderThumb,
+        get_fill_position, get_thumb_position,
+    };
+
+    pub(in super) extern "C" fn default_on_track_mouse_down(slider: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut slider = match slider.downcast_mut::<SliderStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let track_id = info.get_hit_node();
+
+        let (cursor, track_size) = match (info.get_cursor_relative_to_node().into_option(), info.get_node_size(track_id)) {
+            (Some(c), Some(s)) => (c, s),
+            _ => return Update::DoNothing,
+        };
+
+        // jump to the clicked position with the nearest thumb and start dragging it
+        let ratio = match slider.inner.orientation {
+            SliderOrientation::Horizontal if track_size.width > 0.0 => cursor.x / track_size.width,
+            SliderOrientation::Vertical if track_size.height > 0.0 => 1.0 - cursor.y / track_size.height,
+            _ => return Update::DoNothing,
+        };
+
+        let value = slider.inner.get_value_at_ratio(ratio);
+        let thumb = slider.inner.get_nearest_thumb(value);
+        slider.inner.active_thumb = thumb;
+        slider.is_dragging = true;
+        slider.drag_value = value;
+
+        if let Some(container_id) = info.get_parent(track_id) {
+            info.set_focus(FocusTarget::Id(container_id));
+        }
+
+        set_value(&mut slider, thumb, value, track_id, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_move(slider: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut slider = match slider.downcast_mut::<SliderStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !slider.is_dragging {
+            return Update::DoNothing;
+        }
+
+        let mouse_state = info.get_current_mouse_state();
+
+        // mouse button was released outside of the window
+        if !mouse_state.left_down {
+            slider.is_dragging = false;
+            return Update::DoNothing;
+        }
+
+        let previous_cursor = info.get_previous_mouse_state().map(|m| m.cursor_position);
+        let (current, previous) = match (mouse_state.cursor_position, previous_cursor) {
+            (CursorPosition::InWindow(c), Some(CursorPosition::InWindow(p))) => (c, p),
+            _ => return Update::DoNothing,
+        };
+
+        let track_id = info.get_hit_node();
+        let track_size = match info.get_node_size(track_id) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (delta, track_length) = match slider.inner.orientation {
+            SliderOrientation::Horizontal => (current.x - previous.x, track_size.width),
+            // y goes down, the value goes up
+            SliderOrientation::Vertical => (previous.y - current.y, track_size.height),
+        };
+
+        if delta == 0.0 || track_length <= 0.0 {
+            return Update::DoNothing;
+        }
+
+        // don't snap the accumulated value, otherwise slow movements never reach the next step
+        let value_delta = delta / track_length * (slider.inner.max - slider.inner.min);
+        let (min, max) = (slider.inner.min.min(slider.inner.max), slider.inner.min.max(slider.inner.max));
+        slider.drag_value = (slider.drag_value + value_delta).max(min).min(max);
+
+        let thumb = slider.inner.active_thumb;
+        let value = slider.drag_value;
+        set_value(&mut slider, thumb, value, track_id, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_up(slider: &mut RefAny, _: CallbackInfo) -> Update {
+
+        let mut slider = match slider.downcast_mut::<SliderStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        slider.is_dragging = false;
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(slider: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut slider = match slider.downcast_mut::<SliderStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let track_id = match info.get_first_child(info.get_hit_node()) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let thumb = slider.inner.active_thumb;
+        let value = slider.inner.get_thumb_value(thumb);
+        let step = slider.inner.get_keyboard_step();
+
+        let new_value = match last_keycode {
+            VirtualKeyCode::Left | VirtualKeyCode::Down => value - step,
+            VirtualKeyCode::Right | VirtualKeyCode::Up => value + step,
+            VirtualKeyCode::PageDown => value - step * super::PAGE_STEPS,
+            VirtualKeyCode::PageUp => value + step * super::PAGE_STEPS,
+            VirtualKeyCode::Home => slider.inner.min,
+            VirtualKeyCode::End => slider.inner.max,
+            // switch between the two thumbs of a range slider
+            VirtualKeyCode::Tab if slider.inner.is_range() && keyboard_state.ctrl_down => {
+                slider.inner.active_thumb = match thumb {
+                    SliderThumb::Lower => SliderThumb::Upper,
+                    SliderThumb::Upper => SliderThumb::Lower,
+                };
+                return Update::DoNothing;
+            },
+            _ => return Update::DoNothing,
+        };
+
+        set_value(&mut slider, thumb, new_value, track_id, &mut info)
+    }
+
+    /// Sets the value of the thumb, updates the thumb and fill positions on
+    /// the screen and invokes the `on_value_change` callback if the value changed
+    fn set_value(slider: &mut SliderStateWrapper, thumb: SliderThumb, value: f32, track_id: DomNodeId, info: &mut CallbackInfo) -> Update {
+
+        if !slider.inner.set_thumb_value(thumb, value) {
+            return Update::DoNothing;
+        }
+
+        // track -> [fill, lower thumb, upper thumb, ticks]
+        if let Some(fill_id) = info.get_first_child(track_id) {
+            for prop in get_fill_position(&slider.inner).iter() {
+                info.set_css_property(fill_id, prop.clone());
+            }
+            let lower_id = info.get_next_sibling(fill_id);
+            let upper_id = lower_id.and_then(|l| info.get_next_sibling(l));
+            let thumb_id = match thumb {
+                SliderThumb::Lower => lower_id,
+                SliderThumb::Upper => upper_id,
+            };
+            if let Some(thumb_id) = thumb_id {
+                for prop in get_thumb_position(&slider.inner, thumb).iter() {
+                    info.set_css_property(thumb_id, prop.clone());
+                }
+            }
+        }
+
+        // rustc doesn't understand the borrowing lifetime here
+        let onvaluechange = &mut slider.on_value_change;
+        let inner = &slider.inner;
+
+        match onvaluechange.as_mut() {
+            Some(SliderOnValueChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+impl From<Slider> for Dom {
+    fn from(s: Slider) -> Dom {
+        s.dom()
+    }
+}
+
+/// XML component for `<slider value="20" min="0" max="100" step="5" orientation="vertical" />`
+pub struct SliderRenderer {
+    node: XmlNode,
+}
+
+impl SliderRenderer {
+    pub fn new() -> Self {
+        Self { node: XmlNode::new("slider") }
+    }
+
+    fn get_slider(args: &FilteredComponentArguments) -> Slider {
+
+        let get_arg = |name: &str| args.args.get(name).map(|(v, _)| v.trim());
+        let get_f32 = |name: &str| get_arg(name).and_then(|v| v.parse::<f32>().ok());
+
+        let orientation = match get_arg("orientation") {
+            Some(o) if o.eq_ignore_ascii_case("vertical") => SliderOrientation::Vertical,
+            _ => SliderOrientation::Horizontal,
+        };
+
+        Slider::new(get_f32("value").unwrap_or(0.0), get_f32("min").unwrap_or(0.0), get_f32("max").unwrap_or(100.0))
+            .with_step(get_f32("step").unwrap_or(1.0))
+            .with_orientation(orientation)
+    }
+}
+
+impl XmlComponent for SliderRenderer {
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        let mut args = ComponentArgumentsMap::default();
+        args.insert(String::from("value"), (String::from("f32"), 0));
+        args.insert(String::from("min"), (String::from("f32"), 1));
+        args.insert(String::from("max"), (String::from("f32"), 2));
+        args.insert(String::from("step"), (String::from("f32"), 3));
+        args.insert(String::from("orientation"), (String::from("azul::widgets::SliderOrientation(Horizontal|Vertical)"), 4));
+        ComponentArguments {
+            args,
+            accepts_text: false,
+            accepts_slot: false,
+        }
+    }
+
+    fn render_dom(&self, _: &XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent) -> Result<StyledDom, RenderDomError> {
+        Ok(Self::get_slider(args).dom().style(&mut Css::empty()))
+    }
+
+    fn compile_to_rust_code(&self, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(String::from("azul::widgets::Slider::new(value, min, max).with_step(step).with_orientation(orientation).dom()"))
+    }
+
+    fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
+}
+
+#[test]
+fn test_snap_value() {
+    let mut state = SliderState { step: 5.0, .. Default::default() };
+    assert_eq!(state.snap_value(12.0), 10.0);
+    assert_eq!(state.snap_value(13.0), 15.0);
+    assert_eq!(state.snap_value(-5.0), 0.0);
+    assert_eq!(state.snap_value(120.0), 100.0);
+
+    // the last step doesn't end at `max`: never snap past `max`
+    state.min = 10.0;
+    state.max = 20.0;
+    state.step = 3.0;
+    assert_eq!(state.snap_value(14.0), 13.0);
+    assert_eq!(state.snap_value(20.0), 19.0);
+
+    // continuous slider: only clamp
+    state.step = 0.0;
+    assert_eq!(state.snap_value(12.5), 12.5);
+    assert_eq!(state.snap_value(25.0), 20.0);
+
+    // min > max
+    state.min = 100.0;
+    state.max = 0.0;
+    assert_eq!(state.snap_value(150.0), 100.0);
+    assert_eq!(state.snap_value(-1.0), 0.0);
+}
+
+#[test]
+fn test_set_thumb_value() {
+    let mut state = Slider::new(50.0, 0.0, 100.0).with_step(10.0).state.inner;
+    assert!(!state.is_range());
+    assert!(state.set_thumb_value(SliderThumb::Lower, 64.0));
+    assert_eq!(state.value, 60.0);
+    assert!(!state.set_thumb_value(SliderThumb::Lower, 58.0));
+    // a non-range slider only has the lower thumb
+    assert!(state.set_thumb_value(SliderThumb::Upper, 80.0));
+    assert_eq!(state.value, 80.0);
+    assert_eq!(state.upper_value.into_option(), None);
+}
+
+#[test]
+fn test_set_thumb_value_range_thumbs_cant_cross() {
+    let mut state = Slider::new_range(20.0, 60.0, 0.0, 100.0).state.inner;
+    assert!(state.is_range());
+
+    // the lower thumb stops at the upper thumb
+    assert!(state.set_thumb_value(SliderThumb::Lower, 80.0));
+    assert_eq!(state.value, 60.0);
+    assert_eq!(state.get_thumb_value(SliderThumb::Upper), 60.0);
+
+    // the upper thumb stops at the lower thumb
+    assert!(!state.set_thumb_value(SliderThumb::Upper, 10.0));
+    assert_eq!(state.get_thumb_value(SliderThumb::Upper), 60.0);
+    assert!(state.set_thumb_value(SliderThumb::Upper, 90.0));
+    assert!(state.set_thumb_value(SliderThumb::Lower, 30.0));
+    assert_eq!((state.value, state.get_thumb_value(SliderThumb::Upper)), (30.0, 90.0));
+
+    // both thumbs at the same position: pick the one that can move towards the value
+    state.set_thumb_value(SliderThumb::Lower, 90.0);
+    assert_eq!(state.get_nearest_thumb(95.0), SliderThumb::Upper);
+    assert_eq!(state.get_nearest_thumb(85.0), SliderThumb::Lower);
+
+    // swapped initial values are normalized
+    let state = Slider::new_range(80.0, 20.0, 0.0, 100.0).state.inner;
+    assert_eq!((state.value, state.get_thumb_value(SliderThumb::Upper)), (80.0, 80.0));
+}
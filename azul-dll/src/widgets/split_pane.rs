@@ -0,0 +1,790 @@
+//! Resizable split pane: two panes separated by a draggable divider
+//!
+//! The size of the first pane is stored as a ratio of the container size, so
+//! the split stays proportional when the window is resized and the ratio can
+//! be persisted by the application (`on_resize` is called after every drag /
+//! keyboard resize). The divider is dragged like the thumb of a `Slider`
+//! (window-level `MouseOver` callbacks), the pane sizes are updated via
+//! `set_css_property`. Dragging the divider far enough into the minimum size
+//! of a pane collapses the pane to the edge, if `collapsible` is set.
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClassVec, IdOrClass, IdOrClass::Class,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Hover, Focus},
+    },
+    callbacks::{Callback, CallbackInfo, Update, RefAny},
+};
+use azul_desktop::css::{impl_option, impl_option_inner};
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{
+    XmlComponent, XmlComponentMap, XmlNode, XmlTextContent, XmlSlot,
+    ComponentArguments, ComponentArgumentsMap, FilteredComponentArguments,
+    RenderDomError, CompileError, parse_bool, render_dom_from_template_nodes,
+};
+use std::string::String;
+
+/// Thickness of the divider in pixels
+const DIVIDER_SIZE: f32 = 4.0;
+/// A pane collapses if the divider is dragged this far into the pane,
+/// or half of the minimum size of the pane if that is larger
+const COLLAPSE_DISTANCE: f32 = 16.0;
+/// How much one arrow key press moves the divider (relative to the container size)
+const KEYBOARD_STEP: f32 = 0.02;
+
+static SPLIT_PANE_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-split-pane-container"))];
+static SPLIT_PANE_PANE_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-split-pane-pane"))];
+static SPLIT_PANE_DIVIDER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-split-pane-divider"))];
+
+pub type SplitPaneOnResizeCallbackType = extern "C" fn(&mut RefAny, &SplitPaneState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct SplitPaneOnResizeCallback {
+    pub cb: SplitPaneOnResizeCallbackType,
+}
+
+impl_callback!(SplitPaneOnResizeCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SplitPane {
+    pub state: SplitPaneStateWrapper,
+    /// Content of the left (horizontal) / top (vertical) pane
+    pub first_pane: Dom,
+    /// Content of the right (horizontal) / bottom (vertical) pane
+    pub second_pane: Dom,
+    /// Style for the outer container
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for both panes
+    pub pane_style: NodeDataInlineCssPropertyVec,
+    /// Style for the divider between the panes (receives the mouse and keyboard events)
+    pub divider_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SplitPaneStateWrapper {
+    pub inner: SplitPaneState,
+    /// Optional: Function to call after the panes have been resized or collapsed
+    pub on_resize: OptionSplitPaneOnResize,
+    /// Whether the divider is currently being dragged
+    pub is_dragging: bool,
+    /// Unclamped position of the divider during a drag (in pixels from the
+    /// start of the container), so that the divider doesn't drift from the cursor
+    pub drag_position: f32,
+    /// State at the start of the drag, `on_resize` is only called if the drag changed it
+    pub state_before_drag: SplitPaneState,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SplitPaneOnResize {
+    pub data: RefAny,
+    pub callback: SplitPaneOnResizeCallback,
+}
+
+impl_option!(SplitPaneOnResize, OptionSplitPaneOnResize, copy = false, [Debug, Clone, PartialEq]);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum SplitPaneOrientation {
+    /// Panes side by side, vertical divider
+    Horizontal,
+    /// Panes on top of each other, horizontal divider
+    Vertical,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum SplitPaneCollapsed {
+    None,
+    /// The first pane is hidden, the divider is at the start of the container
+    First,
+    /// The second pane is hidden, the divider is at the end of the container
+    Second,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SplitPaneState {
+    pub orientation: SplitPaneOrientation,
+    /// Size of the first pane relative to the container (0.0 - 1.0),
+    /// kept while a pane is collapsed, so that it can be restored
+    pub ratio: f32,
+    /// Minimum size of the first pane in pixels
+    pub min_first_size: f32,
+    /// Minimum size of the second pane in pixels
+    pub min_second_size: f32,
+    /// Whether the panes can be collapsed by dragging the divider to the edge
+    pub collapsible: bool,
+    pub collapsed: SplitPaneCollapsed,
+}
+
+impl Default for SplitPaneState {
+    fn default() -> Self {
+        Self::new(SplitPaneOrientation::Horizontal)
+    }
+}
+
+impl SplitPaneState {
+
+    pub const fn new(orientation: SplitPaneOrientation) -> Self {
+        Self {
+            orientation,
+            ratio: 0.5,
+            min_first_size: 0.0,
+            min_second_size: 0.0,
+            collapsible: false,
+            collapsed: SplitPaneCollapsed::None,
+        }
+    }
+
+    /// Clamps the ratio so that both panes keep their minimum size
+    /// (if the container is large enough for both minimum sizes)
+    pub fn clamp_ratio(&self, ratio: f32, container_size: f32) -> f32 {
+        let ratio = ratio.max(0.0).min(1.0);
+        let available = container_size - DIVIDER_SIZE;
+        if available <= 0.0 {
+            return ratio;
+        }
+        let min_ratio = self.min_first_size / available;
+        let max_ratio = 1.0 - self.min_second_size / available;
+        if min_ratio > max_ratio {
+            return ratio;
+        }
+        ratio.max(min_ratio).min(max_ratio)
+    }
+
+    /// Position of the divider in pixels from the start of the container
+    pub fn get_divider_position(&self, container_size: f32) -> f32 {
+        let available = (container_size - DIVIDER_SIZE).max(0.0);
+        match self.collapsed {
+            SplitPaneCollapsed::First => 0.0,
+            SplitPaneCollapsed::Second => available,
+            SplitPaneCollapsed::None => self.clamp_ratio(self.ratio, container_size) * available,
+        }
+    }
+
+    /// Moves the divider to the position (in pixels from the start of the
+    /// container), collapses a pane if the position is too far inside of it
+    pub fn set_divider_position(&mut self, position: f32, container_size: f32) {
+
+        let available = container_size - DIVIDER_SIZE;
+        if available <= 0.0 {
+            return;
+        }
+
+        let collapse_first = COLLAPSE_DISTANCE.max(self.min_first_size / 2.0);
+        let collapse_second = COLLAPSE_DISTANCE.max(self.min_second_size / 2.0);
+
+        self.collapsed = if self.collapsible && position < collapse_first.min(available / 2.0) {
+            SplitPaneCollapsed::First
+        } else if self.collapsible && available - position < collapse_second.min(available / 2.0) {
+            SplitPaneCollapsed::Second
+        } else {
+            self.ratio = self.clamp_ratio(position / available, container_size);
+            SplitPaneCollapsed::None
+        };
+    }
+
+    /// Collapses the pane, `SplitPaneCollapsed::None` restores the previous ratio
+    #[inline]
+    pub fn set_collapsed(&mut self, collapsed: SplitPaneCollapsed) {
+        self.collapsed = collapsed;
+    }
+
+    /// Size + visibility of the first pane and visibility of the second pane
+    fn get_pane_properties(&self) -> [CssProperty;3] {
+        let percent = match self.collapsed {
+            SplitPaneCollapsed::First => 0.0,
+            SplitPaneCollapsed::Second => 100.0,
+            SplitPaneCollapsed::None => self.ratio.max(0.0).min(1.0) * 100.0,
+        };
+        let first_size = match self.orientation {
+            SplitPaneOrientation::Horizontal => CssProperty::width(LayoutWidth::percent(percent)),
+            SplitPaneOrientation::Vertical => CssProperty::height(LayoutHeight::percent(percent)),
+        };
+        [
+            first_size,
+            get_pane_display(self.collapsed != SplitPaneCollapsed::First),
+            get_pane_display(self.collapsed != SplitPaneCollapsed::Second),
+        ]
+    }
+}
+
+const DIVIDER_COLOR: ColorU = ColorU { r: 218, g: 218, b: 218, a: 255 }; // #dadada
+const DIVIDER_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(DIVIDER_COLOR)];
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const DIVIDER_ACTIVE_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_4286F4)];
+
+static SPLIT_PANE_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static SPLIT_PANE_PANE_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static SPLIT_PANE_DIVIDER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(DIVIDER_BACKGROUND))),
+    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(DIVIDER_ACTIVE_BACKGROUND))),
+    Focus(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(DIVIDER_ACTIVE_BACKGROUND))),
+];
+
+fn get_pane_display(visible: bool) -> CssProperty {
+    CssProperty::const_display(if visible { LayoutDisplay::Flex } else { LayoutDisplay::None })
+}
+
+impl SplitPane {
+
+    pub fn new(orientation: SplitPaneOrientation, first_pane: Dom, second_pane: Dom) -> Self {
+        let state = SplitPaneState::new(orientation);
+        Self {
+            state: SplitPaneStateWrapper {
+                inner: state,
+                on_resize: None.into(),
+                is_dragging: false,
+                drag_position: 0.0,
+                state_before_drag: state,
+            },
+            first_pane,
+            second_pane,
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(SPLIT_PANE_CONTAINER_STYLE),
+            pane_style: NodeDataInlineCssPropertyVec::from_const_slice(SPLIT_PANE_PANE_STYLE),
+            divider_style: NodeDataInlineCssPropertyVec::from_const_slice(SPLIT_PANE_DIVIDER_STYLE),
+        }
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new(SplitPaneOrientation::Horizontal, Dom::div(), Dom::div());
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    /// Sets the size of the first pane relative to the container (0.0 - 1.0)
+    #[inline]
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.state.inner.ratio = ratio.max(0.0).min(1.0);
+    }
+
+    #[inline]
+    pub fn with_ratio(mut self, ratio: f32) -> Self {
+        self.set_ratio(ratio);
+        self
+    }
+
+    /// Sets the minimum sizes (in pixels) of the first and second pane
+    #[inline]
+    pub fn set_min_sizes(&mut self, min_first_size: f32, min_second_size: f32) {
+        self.state.inner.min_first_size = min_first_size.max(0.0);
+        self.state.inner.min_second_size = min_second_size.max(0.0);
+    }
+
+    #[inline]
+    pub fn with_min_sizes(mut self, min_first_size: f32, min_second_size: f32) -> Self {
+        self.set_min_sizes(min_first_size, min_second_size);
+        self
+    }
+
+    #[inline]
+    pub fn set_collapsible(&mut self, collapsible: bool) {
+        self.state.inner.collapsible = collapsible;
+    }
+
+    #[inline]
+    pub fn with_collapsible(mut self, collapsible: bool) -> Self {
+        self.set_collapsible(collapsible);
+        self
+    }
+
+    #[inline]
+    pub fn set_collapsed(&mut self, collapsed: SplitPaneCollapsed) {
+        self.state.inner.set_collapsed(collapsed);
+    }
+
+    #[inline]
+    pub fn with_collapsed(mut self, collapsed: SplitPaneCollapsed) -> Self {
+        self.set_collapsed(collapsed);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_resize(&mut self, data: RefAny, on_resize: SplitPaneOnResizeCallbackType) {
+        self.state.on_resize = Some(SplitPaneOnResize {
+            callback: SplitPaneOnResizeCallback { cb: on_resize },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_resize(mut self, data: RefAny, on_resize: SplitPaneOnResizeCallbackType) -> Self {
+        self.set_on_resize(data, on_resize);
+        self
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_pane_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.pane_style = style;
+    }
+
+    pub fn set_divider_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.divider_style = style;
+    }
+
+    pub fn dom(mut self) -> Dom {
+        let first_pane = core::mem::replace(&mut self.first_pane, Dom::div());
+        let second_pane = core::mem::replace(&mut self.second_pane, Dom::div());
+        let [container, first_wrapper, divider, second_wrapper] = self.build();
+        container.with_children(vec![
+            first_wrapper.with_children(vec![first_pane].into()),
+            divider,
+            second_wrapper.with_children(vec![second_pane].into()),
+        ].into())
+    }
+
+    /// Returns the container, the (empty) first pane, the divider and the (empty) second pane,
+    /// so that the panes can either be `Dom`s or already styled XML children
+    fn build(mut self) -> [Dom;4] {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter, WindowEventFilter,
+        };
+
+        self.state.state_before_drag = self.state.inner;
+
+        let state = &self.state.inner;
+        let [first_size, first_display, second_display] = state.get_pane_properties();
+
+        let (flex_direction, cursor, divider_size) = match state.orientation {
+            SplitPaneOrientation::Horizontal => (
+                LayoutFlexDirection::Row,
+                StyleCursor::ColResize,
+                CssProperty::const_width(LayoutWidth::const_px(DIVIDER_SIZE as isize)),
+            ),
+            SplitPaneOrientation::Vertical => (
+                LayoutFlexDirection::Column,
+                StyleCursor::RowResize,
+                CssProperty::const_height(LayoutHeight::const_px(DIVIDER_SIZE as isize)),
+            ),
+        };
+
+        let min_size = |px: f32| match state.orientation {
+            SplitPaneOrientation::Horizontal => CssProperty::min_width(LayoutMinWidth::px(px)),
+            SplitPaneOrientation::Vertical => CssProperty::min_height(LayoutMinHeight::px(px)),
+        };
+        // the second pane takes the remaining space
+        let zero_size = match state.orientation {
+            SplitPaneOrientation::Horizontal => CssProperty::const_width(LayoutWidth::const_px(0)),
+            SplitPaneOrientation::Vertical => CssProperty::const_height(LayoutHeight::const_px(0)),
+        };
+
+        let mut container_style = self.container_style.into_library_owned_vec();
+        container_style.push(Normal(CssProperty::const_flex_direction(flex_direction)));
+
+        let mut first_style = self.pane_style.clone().into_library_owned_vec();
+        first_style.push(Normal(first_size));
+        first_style.push(Normal(first_display));
+        first_style.push(Normal(min_size(state.min_first_size)));
+
+        let mut second_style = self.pane_style.into_library_owned_vec();
+        second_style.push(Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })));
+        second_style.push(Normal(zero_size));
+        second_style.push(Normal(second_display));
+        second_style.push(Normal(min_size(state.min_second_size)));
+
+        let mut divider_style = self.divider_style.into_library_owned_vec();
+        divider_style.push(Normal(CssProperty::const_cursor(cursor)));
+        divider_style.push(Normal(divider_size));
+
+        let state_ref = RefAny::new(self.state);
+
+        let container = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(SPLIT_PANE_CONTAINER_CLASS))
+        .with_inline_css_props(container_style.into())
+        .with_dataset(Some(state_ref.clone()).into());
+
+        let first_wrapper = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(SPLIT_PANE_PANE_CLASS))
+        .with_inline_css_props(first_style.into());
+
+        let divider = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(SPLIT_PANE_DIVIDER_CLASS))
+        .with_inline_css_props(divider_style.into())
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_divider_mouse_down },
+            },
+            // window events: keep dragging when the cursor leaves the divider
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_move },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_up },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down },
+            },
+        ].into());
+
+        let second_wrapper = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(SPLIT_PANE_PANE_CLASS))
+        .with_inline_css_props(second_style.into());
+
+        [container, first_wrapper, divider, second_wrapper]
+    }
+}
+
+// handle input events for the SplitPane
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId, FocusTarget};
+    use azul_core::window::{CursorPosition, VirtualKeyCode};
+    use super::{
+        SplitPaneStateWrapper, SplitPaneOnResize, SplitPaneOrientation, SplitPaneCollapsed,
+        DIVIDER_SIZE, KEYBOARD_STEP,
+    };
+
+    pub(in super) extern "C" fn default_on_divider_mouse_down(split_pane: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut split_pane = match split_pane.downcast_mut::<SplitPaneStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let divider_id = info.get_hit_node();
+        let container_size = match get_container_size(&split_pane, divider_id, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        split_pane.is_dragging = true;
+        split_pane.drag_position = split_pane.inner.get_divider_position(container_size);
+        split_pane.state_before_drag = split_pane.inner;
+        info.set_focus(FocusTarget::Id(divider_id));
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_move(split_pane: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut split_pane = match split_pane.downcast_mut::<SplitPaneStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !split_pane.is_dragging {
+            return Update::DoNothing;
+        }
+
+        let mouse_state = info.get_current_mouse_state();
+
+        // mouse button was released outside of the window
+        if !mouse_state.left_down {
+            split_pane.is_dragging = false;
+            return on_resized(&mut split_pane, &mut info);
+        }
+
+        let previous_cursor = info.get_previous_mouse_state().map(|m| m.cursor_position);
+        let (current, previous) = match (mouse_state.cursor_position, previous_cursor) {
+            (CursorPosition::InWindow(c), Some(CursorPosition::InWindow(p))) => (c, p),
+            _ => return Update::DoNothing,
+        };
+
+        let delta = match split_pane.inner.orientation {
+            SplitPaneOrientation::Horizontal => current.x - previous.x,
+            SplitPaneOrientation::Vertical => current.y - previous.y,
+        };
+
+        if delta == 0.0 {
+            return Update::DoNothing;
+        }
+
+        let divider_id = info.get_hit_node();
+        let container_size = match get_container_size(&split_pane, divider_id, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        split_pane.drag_position += delta;
+        let drag_position = split_pane.drag_position;
+        split_pane.inner.set_divider_position(drag_position, container_size);
+        update_panes(&split_pane, divider_id, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_up(split_pane: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut split_pane = match split_pane.downcast_mut::<SplitPaneStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !split_pane.is_dragging {
+            return Update::DoNothing;
+        }
+
+        split_pane.is_dragging = false;
+        on_resized(&mut split_pane, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(split_pane: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut split_pane = match split_pane.downcast_mut::<SplitPaneStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let last_keycode = match info.get_current_keyboard_state().current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let divider_id = info.get_hit_node();
+        let container_size = match get_container_size(&split_pane, divider_id, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let horizontal = split_pane.inner.orientation == SplitPaneOrientation::Horizontal;
+        let collapsible = split_pane.inner.collapsible;
+        let step = KEYBOARD_STEP * (container_size - DIVIDER_SIZE);
+        let divider_position = split_pane.inner.get_divider_position(container_size);
+
+        split_pane.state_before_drag = split_pane.inner;
+
+        match last_keycode {
+            VirtualKeyCode::Left if horizontal => split_pane.inner.set_divider_position(divider_position - step, container_size),
+            VirtualKeyCode::Right if horizontal => split_pane.inner.set_divider_position(divider_position + step, container_size),
+            VirtualKeyCode::Up if !horizontal => split_pane.inner.set_divider_position(divider_position - step, container_size),
+            VirtualKeyCode::Down if !horizontal => split_pane.inner.set_divider_position(divider_position + step, container_size),
+            VirtualKeyCode::Home if collapsible => split_pane.inner.set_collapsed(SplitPaneCollapsed::First),
+            VirtualKeyCode::End if collapsible => split_pane.inner.set_collapsed(SplitPaneCollapsed::Second),
+            // restore the collapsed pane
+            VirtualKeyCode::Return => split_pane.inner.set_collapsed(SplitPaneCollapsed::None),
+            _ => return Update::DoNothing,
+        }
+
+        update_panes(&split_pane, divider_id, &mut info);
+        on_resized(&mut split_pane, &mut info)
+    }
+
+    /// Size of the container along the split axis
+    fn get_container_size(split_pane: &SplitPaneStateWrapper, divider_id: DomNodeId, info: &CallbackInfo) -> Option<f32> {
+        let container_id = info.get_parent(divider_id)?;
+        let size = info.get_node_size(container_id)?;
+        Some(match split_pane.inner.orientation {
+            SplitPaneOrientation::Horizontal => size.width,
+            SplitPaneOrientation::Vertical => size.height,
+        })
+    }
+
+    /// Calls `on_resize` if the ratio or the collapsed pane changed since `state_before_drag`
+    fn on_resized(split_pane: &mut SplitPaneStateWrapper, info: &mut CallbackInfo) -> Update {
+
+        if split_pane.inner == split_pane.state_before_drag {
+            return Update::DoNothing;
+        }
+
+        split_pane.state_before_drag = split_pane.inner;
+
+        // rustc doesn't understand the borrowing lifetime here
+        let onresize = &mut split_pane.on_resize;
+        let inner = &split_pane.inner;
+
+        match onresize.as_mut() {
+            Some(SplitPaneOnResize { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Updates the size and visibility of the panes (siblings of the divider)
+    fn update_panes(split_pane: &SplitPaneStateWrapper, divider_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let (first_pane_id, second_pane_id) = match (info.get_previous_sibling(divider_id), info.get_next_sibling(divider_id)) {
+            (Some(f), Some(s)) => (f, s),
+            _ => return,
+        };
+
+        let [first_size, first_display, second_display] = split_pane.inner.get_pane_properties();
+        info.set_css_property(first_pane_id, first_size);
+        info.set_css_property(first_pane_id, first_display);
+        info.set_css_property(second_pane_id, second_display);
+    }
+}
+
+impl From<SplitPane> for Dom {
+    fn from(s: SplitPane) -> Dom {
+        s.dom()
+    }
+}
+
+/// XML component for `<split_pane orientation="vertical" ratio="0.3">`,
+/// the first two child nodes of the `<split_pane>` are rendered as the panes
+pub struct SplitPaneRenderer {
+    node: XmlNode,
+}
+
+impl SplitPaneRenderer {
+    pub fn new() -> Self {
+        Self { node: XmlNode::new("split_pane") }
+    }
+
+    fn get_split_pane(args: &FilteredComponentArguments) -> SplitPane {
+
+        let get_arg = |name: &str| args.args.get(name).map(|(v, _)| v.trim());
+        let get_f32 = |name: &str| get_arg(name).and_then(|v| v.parse::<f32>().ok());
+
+        let orientation = match get_arg("orientation") {
+            Some(o) if o.eq_ignore_ascii_case("vertical") => SplitPaneOrientation::Vertical,
+            _ => SplitPaneOrientation::Horizontal,
+        };
+
+        let collapsed = match get_arg("collapsed") {
+            Some(c) if c.eq_ignore_ascii_case("first") => SplitPaneCollapsed::First,
+            Some(c) if c.eq_ignore_ascii_case("second") => SplitPaneCollapsed::Second,
+            _ => SplitPaneCollapsed::None,
+        };
+
+        SplitPane::new(orientation, Dom::div(), Dom::div())
+            .with_ratio(get_f32("ratio").unwrap_or(0.5))
+            .with_min_sizes(get_f32("min_first_size").unwrap_or(0.0), get_f32("min_second_size").unwrap_or(0.0))
+            .with_collapsible(get_arg("collapsible").and_then(parse_bool).unwrap_or(false))
+            .with_collapsed(collapsed)
+    }
+}
+
+impl XmlComponent for SplitPaneRenderer {
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        let mut args = ComponentArgumentsMap::default();
+        args.insert(String::from("orientation"), (String::from("azul::widgets::SplitPaneOrientation(Horizontal|Vertical)"), 0));
+        args.insert(String::from("ratio"), (String::from("f32"), 1));
+        args.insert(String::from("min_first_size"), (String::from("f32"), 2));
+        args.insert(String::from("min_second_size"), (String::from("f32"), 3));
+        args.insert(String::from("collapsible"), (String::from("bool"), 4));
+        args.insert(String::from("collapsed"), (String::from("azul::widgets::SplitPaneCollapsed(None|First|Second)"), 5));
+        ComponentArguments {
+            args,
+            accepts_text: false,
+            accepts_slot: true,
+        }
+    }
+
+    fn render_dom<'a>(&'a self, _: &'a XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent) -> Result<StyledDom, RenderDomError<'a>> {
+        Ok(Self::get_split_pane(args).dom().style(&mut Css::empty()))
+    }
+
+    fn render_dom_with_slot<'a>(&'a self, components: &'a XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent, slot: &XmlSlot<'a, '_>) -> Result<(StyledDom, bool), RenderDomError<'a>> {
+
+        let [mut container, mut first_wrapper, mut divider, mut second_wrapper] = Self::get_split_pane(args).build();
+        let mut dom = container.style(&mut Css::empty());
+
+        let mut first_pane = first_wrapper.style(&mut Css::empty());
+        if let Some(child) = slot.children.get(0) {
+            render_dom_from_template_nodes(&mut first_pane, core::slice::from_ref(child), components, slot.scope, slot.parent)?;
+        }
+
+        let mut second_pane = second_wrapper.style(&mut Css::empty());
+        if let Some(child) = slot.children.get(1) {
+            render_dom_from_template_nodes(&mut second_pane, core::slice::from_ref(child), components, slot.scope, slot.parent)?;
+        }
+
+        dom.append_child(first_pane);
+        dom.append_child(divider.style(&mut Css::empty()));
+        dom.append_child(second_pane);
+
+        Ok((dom, true))
+    }
+
+    fn compile_to_rust_code(&self, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(String::from("azul::widgets::SplitPane::new(orientation, slot.get(0).cloned().unwrap_or_else(Dom::div), slot.get(1).cloned().unwrap_or_else(Dom::div)).with_ratio(ratio).with_min_sizes(min_first_size, min_second_size).with_collapsible(collapsible).with_collapsed(collapsed).dom()"))
+    }
+
+    fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
+}
+
+#[cfg(test)]
+fn test_state(min_first_size: f32, min_second_size: f32) -> SplitPaneState {
+    SplitPaneState {
+        min_first_size,
+        min_second_size,
+        collapsible: true,
+        .. SplitPaneState::new(SplitPaneOrientation::Horizontal)
+    }
+}
+
+#[test]
+fn test_clamp_ratio() {
+    let state = test_state(100.0, 50.0);
+    let container_size = 400.0 + DIVIDER_SIZE;
+    assert_eq!(state.clamp_ratio(0.5, container_size), 0.5);
+    assert_eq!(state.clamp_ratio(0.1, container_size), 0.25);
+    assert_eq!(state.clamp_ratio(-1.0, container_size), 0.25);
+    assert_eq!(state.clamp_ratio(0.95, container_size), 0.875);
+    assert_eq!(state.clamp_ratio(2.0, container_size), 0.875);
+
+    // container too small for both minimum sizes: only clamp to 0.0 - 1.0
+    assert_eq!(state.clamp_ratio(0.3, 100.0 + DIVIDER_SIZE), 0.3);
+    assert_eq!(state.clamp_ratio(1.5, 100.0 + DIVIDER_SIZE), 1.0);
+    assert_eq!(state.clamp_ratio(0.3, DIVIDER_SIZE), 0.3);
+
+    let mut state = state;
+    state.ratio = 0.1;
+    assert_eq!(state.get_divider_position(container_size), 100.0);
+}
+
+#[test]
+fn test_set_divider_position() {
+    let mut state = test_state(100.0, 50.0);
+    let container_size = 400.0 + DIVIDER_SIZE;
+
+    state.set_divider_position(200.0, container_size);
+    assert_eq!((state.ratio, state.collapsed), (0.5, SplitPaneCollapsed::None));
+
+    // inside of the minimum size, but not far enough to collapse the first pane
+    state.set_divider_position(60.0, container_size);
+    assert_eq!((state.ratio, state.collapsed), (0.25, SplitPaneCollapsed::None));
+    assert_eq!(state.get_divider_position(container_size), 100.0);
+
+    state.set_divider_position(40.0, container_size);
+    assert_eq!((state.ratio, state.collapsed), (0.25, SplitPaneCollapsed::First));
+    assert_eq!(state.get_divider_position(container_size), 0.0);
+
+    state.set_divider_position(380.0, container_size);
+    assert_eq!((state.ratio, state.collapsed), (0.25, SplitPaneCollapsed::Second));
+    assert_eq!(state.get_divider_position(container_size), 400.0);
+
+    // expanding restores the ratio from before the pane was collapsed
+    state.set_collapsed(SplitPaneCollapsed::None);
+    assert_eq!(state.get_divider_position(container_size), 100.0);
+
+    state.collapsible = false;
+    state.set_divider_position(0.0, container_size);
+    assert_eq!((state.ratio, state.collapsed), (0.25, SplitPaneCollapsed::None));
+}
@@ -0,0 +1,1543 @@
+//! Tab container with closable, reorderable tabs
+//!
+//! All pages are rendered at once, only the page of the active tab is visible
+//! (`display: none` on the other pages), so switching tabs keeps the state of
+//! the pages (scroll positions, focus, text inputs, ...). The tab headers have a
+//! fixed width, so that the tab under the cursor, the drop position of a dragged
+//! tab and the scroll offset of the tab strip can be computed without a relayout:
+//! selecting, closing, reordering and scrolling only update the existing nodes
+//! via `set_css_property`. If the tabs don't fit into the header, the tab strip
+//! can either be scrolled (arrow buttons + mouse wheel) or the tabs can be
+//! selected from a menu (`TabContainerOverflow`).
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, DomVec, TabIndex, IdOrClassVec, IdOrClass, IdOrClass::Class,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Hover, Focus},
+    },
+    callbacks::{Callback, CallbackInfo, Update, RefAny},
+};
+use azul_desktop::css::{
+    impl_option, impl_option_inner,
+    impl_vec, impl_vec_debug, impl_vec_clone, impl_vec_partialeq, impl_vec_mut,
+};
+use azul_core::gl::OptionUsize;
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{
+    XmlComponent, XmlComponentMap, XmlNode, XmlTextContent, XmlSlot,
+    ComponentArguments, ComponentArgumentsMap, FilteredComponentArguments,
+    RenderDomError, CompileError, parse_bool, render_dom_from_template_nodes,
+};
+use std::vec::Vec;
+use std::string::String;
+
+/// Width of a tab header in pixels
+const TAB_WIDTH: f32 = 120.0;
+/// Height of the header (tab strip + buttons)
+const HEADER_HEIGHT: f32 = 28.0;
+/// Width of the close button at the right edge of a tab, including its right margin
+const CLOSE_BUTTON_WIDTH: f32 = 20.0;
+/// How many pixels one "notch" of the mouse wheel scrolls the tab strip
+const WHEEL_STEP: f32 = 40.0;
+/// Height of one row of the overflow menu
+const MENU_ROW_HEIGHT: f32 = 22.0;
+
+static TAB_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container"))];
+static TAB_CONTAINER_HEADER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-header"))];
+static TAB_CONTAINER_VIEWPORT_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-viewport"))];
+static TAB_CONTAINER_STRIP_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-strip"))];
+static TAB_CONTAINER_TAB_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-tab"))];
+static TAB_CONTAINER_TAB_TITLE_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-tab-title"))];
+static TAB_CONTAINER_TAB_CLOSE_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-tab-close"))];
+static TAB_CONTAINER_DROP_INDICATOR_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-drop-indicator"))];
+static TAB_CONTAINER_BUTTON_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-button"))];
+static TAB_CONTAINER_MENU_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-menu"))];
+static TAB_CONTAINER_MENU_ROW_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-menu-row"))];
+static TAB_CONTAINER_PAGE_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tab-container-page"))];
+
+pub type TabContainerOnTabChangeCallbackType = extern "C" fn(&mut RefAny, &TabContainerState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct TabContainerOnTabChangeCallback {
+    pub cb: TabContainerOnTabChangeCallbackType,
+}
+
+impl_callback!(TabContainerOnTabChangeCallback);
+
+/// Called after a tab has been closed, the `TabContainerTab` is the closed tab
+/// (its `page` is the index of the page that was hidden)
+pub type TabContainerOnTabCloseCallbackType = extern "C" fn(&mut RefAny, &TabContainerState, TabContainerTab, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct TabContainerOnTabCloseCallback {
+    pub cb: TabContainerOnTabCloseCallbackType,
+}
+
+impl_callback!(TabContainerOnTabCloseCallback);
+
+/// Called after a tab has been dragged from the first to the second index
+pub type TabContainerOnTabMoveCallbackType = extern "C" fn(&mut RefAny, &TabContainerState, usize, usize, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct TabContainerOnTabMoveCallback {
+    pub cb: TabContainerOnTabMoveCallbackType,
+}
+
+impl_callback!(TabContainerOnTabMoveCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TabContainer {
+    pub state: TabContainerStateWrapper,
+    /// Content of the tabs, `pages[tab.page]` is the content of the `tab`
+    pub pages: DomVec,
+    /// Style for the outer container
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for the header (tab strip + scroll / menu buttons, receives the keyboard events)
+    pub header_style: NodeDataInlineCssPropertyVec,
+    /// Style for each tab header
+    pub tab_style: NodeDataInlineCssPropertyVec,
+    /// Style for the close button of the tabs
+    pub close_button_style: NodeDataInlineCssPropertyVec,
+    /// Style for the scroll and menu buttons
+    pub button_style: NodeDataInlineCssPropertyVec,
+    /// Style for the overflow menu
+    pub menu_style: NodeDataInlineCssPropertyVec,
+    /// Style for the page of each tab
+    pub page_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TabContainerStateWrapper {
+    pub inner: TabContainerState,
+    /// Optional: Function to call when the active tab changes
+    pub on_tab_change: OptionTabContainerOnTabChange,
+    /// Optional: Function to call when a tab is closed
+    pub on_tab_close: OptionTabContainerOnTabClose,
+    /// Optional: Function to call when a tab has been reordered by drag-and-drop
+    pub on_tab_move: OptionTabContainerOnTabMove,
+    /// Whether tabs can be reordered by drag-and-drop
+    pub allow_reorder: bool,
+    /// Number of rendered tab headers / pages (closed tabs are only hidden)
+    pub tab_count: usize,
+    /// Tab that the left mouse button was pressed on (start of a drag)
+    pub pressed_tab: OptionUsize,
+    /// Tab that is currently being dragged
+    pub dragged_tab: OptionUsize,
+    /// Gap between the tabs where the dragged tab would be inserted
+    pub drop_index: OptionUsize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TabContainerOnTabChange {
+    pub data: RefAny,
+    pub callback: TabContainerOnTabChangeCallback,
+}
+
+impl_option!(TabContainerOnTabChange, OptionTabContainerOnTabChange, copy = false, [Debug, Clone, PartialEq]);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TabContainerOnTabClose {
+    pub data: RefAny,
+    pub callback: TabContainerOnTabCloseCallback,
+}
+
+impl_option!(TabContainerOnTabClose, OptionTabContainerOnTabClose, copy = false, [Debug, Clone, PartialEq]);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TabContainerOnTabMove {
+    pub data: RefAny,
+    pub callback: TabContainerOnTabMoveCallback,
+}
+
+impl_option!(TabContainerOnTabMove, OptionTabContainerOnTabMove, copy = false, [Debug, Clone, PartialEq]);
+
+/// What happens if the tabs don't fit into the header
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TabContainerOverflow {
+    /// The tab strip can be scrolled with the arrow buttons or the mouse wheel
+    Scroll,
+    /// All tabs are listed in a menu that is opened with the button at the right
+    Menu,
+}
+
+/// Header of a single tab
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TabContainerTab {
+    pub title: AzString,
+    /// Whether the tab has a close button
+    pub closable: bool,
+    /// Index of the page of this tab, doesn't change when the tab is moved
+    pub page: usize,
+}
+
+impl_vec!(TabContainerTab, TabContainerTabVec, TabContainerTabVecDestructor);
+impl_vec_debug!(TabContainerTab, TabContainerTabVec);
+impl_vec_clone!(TabContainerTab, TabContainerTabVec, TabContainerTabVecDestructor);
+impl_vec_partialeq!(TabContainerTab, TabContainerTabVec);
+impl_vec_mut!(TabContainerTab, TabContainerTabVec);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TabContainerState {
+    /// Open tabs in display order
+    pub tabs: TabContainerTabVec,
+    /// Index (into `tabs`) of the active tab
+    pub active_tab: OptionUsize,
+    /// How many pixels the tab strip is scrolled to the left
+    pub scroll_offset: f32,
+    pub overflow: TabContainerOverflow,
+    /// Whether the overflow menu is currently visible
+    pub menu_open: bool,
+}
+
+impl Default for TabContainerState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TabContainerState {
+
+    pub fn new() -> Self {
+        Self {
+            tabs: TabContainerTabVec::new(),
+            active_tab: None.into(),
+            scroll_offset: 0.0,
+            overflow: TabContainerOverflow::Scroll,
+            menu_open: false,
+        }
+    }
+
+    #[inline]
+    pub fn get_tab(&self, tab: usize) -> Option<&TabContainerTab> {
+        self.tabs.get(tab)
+    }
+
+    #[inline]
+    pub fn get_active_tab(&self) -> Option<&TabContainerTab> {
+        self.get_tab(self.active_tab.into_option()?)
+    }
+
+    /// Returns the index of the page that is currently visible
+    #[inline]
+    pub fn get_active_page(&self) -> Option<usize> {
+        self.get_active_tab().map(|t| t.page)
+    }
+
+    /// Returns the index (into `tabs`) of the tab of the page
+    pub fn get_tab_of_page(&self, page: usize) -> Option<usize> {
+        self.tabs.as_ref().iter().position(|t| t.page == page)
+    }
+
+    /// Activates the tab, returns whether the active tab changed
+    pub fn select_tab(&mut self, tab: usize) -> bool {
+        if tab >= self.tabs.len() || self.active_tab.into_option() == Some(tab) {
+            return false;
+        }
+        self.active_tab = Some(tab).into();
+        true
+    }
+
+    /// Removes the tab, activates the next (or previous) tab if the closed tab was active
+    pub fn close_tab(&mut self, tab: usize) -> Option<TabContainerTab> {
+
+        if tab >= self.tabs.len() {
+            return None;
+        }
+
+        let mut tabs = core::mem::replace(&mut self.tabs, TabContainerTabVec::new()).into_library_owned_vec();
+        let closed = tabs.remove(tab);
+        let remaining = tabs.len();
+        self.tabs = tabs.into();
+
+        self.active_tab = match self.active_tab.into_option() {
+            _ if remaining == 0 => None,
+            Some(active) if active > tab => Some(active - 1),
+            Some(active) if active == tab => Some(tab.min(remaining - 1)),
+            other => other,
+        }.into();
+
+        Some(closed)
+    }
+
+    /// Moves the tab from the index `from` to the index `to`, the active tab stays active
+    pub fn move_tab(&mut self, from: usize, to: usize) -> bool {
+
+        if from >= self.tabs.len() || to >= self.tabs.len() || from == to {
+            return false;
+        }
+
+        let active_page = self.get_active_page();
+        let mut tabs = core::mem::replace(&mut self.tabs, TabContainerTabVec::new()).into_library_owned_vec();
+        let tab = tabs.remove(from);
+        tabs.insert(to, tab);
+        self.tabs = tabs.into();
+        self.active_tab = active_page.and_then(|p| self.get_tab_of_page(p)).into();
+        true
+    }
+
+    /// Width of all tab headers together
+    #[inline]
+    pub fn get_strip_width(&self) -> f32 {
+        self.tabs.len() as f32 * TAB_WIDTH
+    }
+
+    /// Returns the tab at the x position (relative to the visible part of the
+    /// tab strip) and whether the position is on the close button of the tab
+    pub fn get_tab_at(&self, x: f32) -> Option<(usize, bool)> {
+        let strip_x = x + self.scroll_offset;
+        if strip_x < 0.0 {
+            return None;
+        }
+        let tab = (strip_x / TAB_WIDTH).floor() as usize;
+        let tab_x = strip_x - tab as f32 * TAB_WIDTH;
+        let closable = self.get_tab(tab)?.closable;
+        Some((tab, closable && tab_x >= TAB_WIDTH - CLOSE_BUTTON_WIDTH))
+    }
+
+    /// Returns the gap between two tabs (0 = before the first tab) that is
+    /// closest to the x position, used as the drop position of a dragged tab
+    pub fn get_insert_index_at(&self, x: f32) -> usize {
+        let gap = ((x + self.scroll_offset) / TAB_WIDTH).round().max(0.0) as usize;
+        gap.min(self.tabs.len())
+    }
+
+    /// Scrolls the tab strip by `delta` pixels
+    pub fn scroll_by(&mut self, delta: f32, viewport_width: f32) {
+        let max_offset = (self.get_strip_width() - viewport_width).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).max(0.0).min(max_offset);
+    }
+
+    /// Scrolls the tab strip so that the active tab is fully visible
+    pub fn scroll_to_active_tab(&mut self, viewport_width: f32) {
+        let tab = match self.active_tab.into_option() {
+            Some(s) => s,
+            None => return,
+        };
+        let tab_start = tab as f32 * TAB_WIDTH;
+        let tab_end = tab_start + TAB_WIDTH;
+        if tab_start < self.scroll_offset {
+            self.scroll_offset = tab_start;
+        } else if tab_end > self.scroll_offset + viewport_width {
+            self.scroll_offset = tab_end - viewport_width;
+        }
+        self.scroll_by(0.0, viewport_width);
+    }
+}
+
+const HEADER_COLOR: ColorU = ColorU { r: 236, g: 236, b: 236, a: 255 }; // #ececec
+const HEADER_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(HEADER_COLOR)];
+const ACTIVE_TAB_COLOR: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 }; // white
+const ACTIVE_TAB_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ACTIVE_TAB_COLOR)];
+const NO_BACKGROUND: &[StyleBackgroundContent] = &[];
+const MENU_HIGHLIGHT_COLOR: ColorU = ColorU { r: 204, g: 232, b: 255, a: 255 }; // #cce8ff
+const MENU_HIGHLIGHT_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(MENU_HIGHLIGHT_COLOR)];
+const BUTTON_HOVER_COLOR: ColorU = ColorU { r: 218, g: 218, b: 218, a: 255 }; // #dadada
+const BUTTON_HOVER_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BUTTON_HOVER_COLOR)];
+const TEXT_COLOR: ColorU = ColorU { r: 0, g: 0, b: 0, a: 255 }; // black
+const COLOR_606060: ColorU = ColorU { r: 96, g: 96, b: 96, a: 255 }; // #606060
+const COLOR_C8C8C8: ColorU = ColorU { r: 200, g: 200, b: 200, a: 255 }; // #c8c8c8
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const DROP_INDICATOR_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_4286F4)];
+
+static TAB_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static TAB_CONTAINER_HEADER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(HEADER_HEIGHT as isize))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(HEADER_BACKGROUND))),
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+
+    // border-bottom: 1px solid #c8c8c8;
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_C8C8C8 })),
+
+    // Focus(border-bottom-color: #4286f4;)
+    Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+];
+
+static TAB_CONTAINER_VIEWPORT_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static TAB_CONTAINER_STRIP_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(HEADER_HEIGHT as isize))),
+];
+
+static TAB_CONTAINER_TAB_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(TAB_WIDTH as isize))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(HEADER_HEIGHT as isize))),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(8))),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+
+    // border-right: 1px solid #c8c8c8;
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_C8C8C8 })),
+];
+
+static TAB_CONTAINER_TAB_TITLE_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: TEXT_COLOR })),
+];
+
+static TAB_CONTAINER_TAB_CLOSE_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(16))),
+    Normal(CssProperty::const_margin_right(LayoutMarginRight::const_px(4))),
+    Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_606060 })),
+    Hover(CssProperty::const_text_color(StyleTextColor { inner: TEXT_COLOR })),
+    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BUTTON_HOVER_BACKGROUND))),
+];
+
+static TAB_CONTAINER_DROP_INDICATOR_STYLE: &[NodeDataInlineCssProperty] = &[
+    // only visible while a tab is dragged over the tab strip
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(2))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(HEADER_HEIGHT as isize))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(DROP_INDICATOR_BACKGROUND))),
+];
+
+static TAB_CONTAINER_BUTTON_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(20))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(HEADER_HEIGHT as isize))),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_606060 })),
+    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BUTTON_HOVER_BACKGROUND))),
+];
+
+static TAB_CONTAINER_MENU_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(HEADER_HEIGHT as isize))),
+    Normal(CssProperty::const_right(LayoutRight::const_px(0))),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(150))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(ACTIVE_TAB_BACKGROUND))),
+
+    // border: 1px solid #c8c8c8;
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_C8C8C8 })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_C8C8C8 })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_C8C8C8 })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_C8C8C8 })),
+];
+
+static TAB_CONTAINER_MENU_ROW_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(MENU_ROW_HEIGHT as isize))),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(8))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(8))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: TEXT_COLOR })),
+];
+
+static TAB_CONTAINER_PAGE_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+fn get_tab_background(is_active: bool) -> StyleBackgroundContentVec {
+    if is_active {
+        StyleBackgroundContentVec::from_const_slice(ACTIVE_TAB_BACKGROUND)
+    } else {
+        StyleBackgroundContentVec::from_const_slice(NO_BACKGROUND)
+    }
+}
+
+fn get_menu_row_background(is_active: bool) -> StyleBackgroundContentVec {
+    if is_active {
+        StyleBackgroundContentVec::from_const_slice(MENU_HIGHLIGHT_BACKGROUND)
+    } else {
+        StyleBackgroundContentVec::from_const_slice(NO_BACKGROUND)
+    }
+}
+
+fn get_display(visible: bool, display: LayoutDisplay) -> CssProperty {
+    CssProperty::const_display(if visible { display } else { LayoutDisplay::None })
+}
+
+impl TabContainer {
+
+    pub fn new() -> Self {
+        Self {
+            state: TabContainerStateWrapper {
+                inner: TabContainerState::new(),
+                on_tab_change: None.into(),
+                on_tab_close: None.into(),
+                on_tab_move: None.into(),
+                allow_reorder: false,
+                tab_count: 0,
+                pressed_tab: None.into(),
+                dragged_tab: None.into(),
+                drop_index: None.into(),
+            },
+            pages: DomVec::from_const_slice(&[]),
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_STYLE),
+            header_style: NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_HEADER_STYLE),
+            tab_style: NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_TAB_STYLE),
+            close_button_style: NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_TAB_CLOSE_STYLE),
+            button_style: NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_BUTTON_STYLE),
+            menu_style: NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_MENU_STYLE),
+            page_style: NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_PAGE_STYLE),
+        }
+    }
+
+    /// Creates a tab container from a comma-separated list of tab titles
+    /// (`"Editor, Console, Output"`) and the content of the tabs, in the same order
+    pub fn from_titles_str(titles: &str, pages: DomVec) -> Self {
+        let mut tab_container = Self::new();
+        let mut pages = pages.into_library_owned_vec().into_iter();
+        for title in titles.split(',').map(|t| t.trim()).filter(|t| !t.is_empty()) {
+            tab_container.add_tab(AzString::from(title.to_string()), pages.next().unwrap_or_else(Dom::div));
+        }
+        tab_container
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    /// Appends a tab, the first tab is activated automatically
+    pub fn add_tab(&mut self, title: AzString, content: Dom) {
+        let page = self.pages.len();
+        self.state.inner.tabs.push(TabContainerTab { title, closable: false, page });
+        self.pages.push(content);
+        if self.state.inner.active_tab.is_none() {
+            self.state.inner.active_tab = Some(0).into();
+        }
+    }
+
+    #[inline]
+    pub fn with_tab(mut self, title: AzString, content: Dom) -> Self {
+        self.add_tab(title, content);
+        self
+    }
+
+    #[inline]
+    pub fn set_active_tab(&mut self, tab: usize) {
+        self.state.inner.select_tab(tab);
+    }
+
+    #[inline]
+    pub fn with_active_tab(mut self, tab: usize) -> Self {
+        self.set_active_tab(tab);
+        self
+    }
+
+    /// Adds / removes the close button of all tabs that were added so far
+    pub fn set_closable(&mut self, closable: bool) {
+        for tab in self.state.inner.tabs.as_mut().iter_mut() {
+            tab.closable = closable;
+        }
+    }
+
+    #[inline]
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.set_closable(closable);
+        self
+    }
+
+    #[inline]
+    pub fn set_overflow(&mut self, overflow: TabContainerOverflow) {
+        self.state.inner.overflow = overflow;
+    }
+
+    #[inline]
+    pub fn with_overflow(mut self, overflow: TabContainerOverflow) -> Self {
+        self.set_overflow(overflow);
+        self
+    }
+
+    /// Allows reordering the tabs by dragging them along the tab strip
+    #[inline]
+    pub fn set_allow_reorder(&mut self, allow_reorder: bool) {
+        self.state.allow_reorder = allow_reorder;
+    }
+
+    #[inline]
+    pub fn with_allow_reorder(mut self, allow_reorder: bool) -> Self {
+        self.set_allow_reorder(allow_reorder);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_tab_change(&mut self, data: RefAny, on_tab_change: TabContainerOnTabChangeCallbackType) {
+        self.state.on_tab_change = Some(TabContainerOnTabChange {
+            callback: TabContainerOnTabChangeCallback { cb: on_tab_change },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_tab_change(mut self, data: RefAny, on_tab_change: TabContainerOnTabChangeCallbackType) -> Self {
+        self.set_on_tab_change(data, on_tab_change);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_tab_close(&mut self, data: RefAny, on_tab_close: TabContainerOnTabCloseCallbackType) {
+        self.state.on_tab_close = Some(TabContainerOnTabClose {
+            callback: TabContainerOnTabCloseCallback { cb: on_tab_close },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_tab_close(mut self, data: RefAny, on_tab_close: TabContainerOnTabCloseCallbackType) -> Self {
+        self.set_on_tab_close(data, on_tab_close);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_tab_move(&mut self, data: RefAny, on_tab_move: TabContainerOnTabMoveCallbackType) {
+        self.state.on_tab_move = Some(TabContainerOnTabMove {
+            callback: TabContainerOnTabMoveCallback { cb: on_tab_move },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_tab_move(mut self, data: RefAny, on_tab_move: TabContainerOnTabMoveCallbackType) -> Self {
+        self.set_on_tab_move(data, on_tab_move);
+        self
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_header_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.header_style = style;
+    }
+
+    pub fn set_tab_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.tab_style = style;
+    }
+
+    pub fn set_close_button_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.close_button_style = style;
+    }
+
+    pub fn set_button_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.button_style = style;
+    }
+
+    pub fn set_menu_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.menu_style = style;
+    }
+
+    pub fn set_page_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.page_style = style;
+    }
+
+    pub fn dom(mut self) -> Dom {
+        let pages = core::mem::replace(&mut self.pages, DomVec::from_const_slice(&[])).into_library_owned_vec();
+        let (container, header, page_wrappers) = self.build();
+        let mut children = vec![header];
+        children.extend(page_wrappers.into_iter().zip(pages.into_iter()).map(|(wrapper, page)| wrapper.with_children(vec![page].into())));
+        container.with_children(children.into())
+    }
+
+    /// Returns the container (without children), the header and the (empty) page wrappers,
+    /// so that the pages can either be `Dom`s or already styled XML children
+    fn build(mut self) -> (Dom, Dom, Vec<Dom>) {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter,
+        };
+
+        let tab_count = self.state.inner.tabs.len();
+        self.state.tab_count = tab_count;
+        if self.state.inner.active_tab.into_option().map(|a| a >= tab_count).unwrap_or(tab_count > 0) {
+            self.state.inner.active_tab = if tab_count > 0 { Some(0) } else { None }.into();
+        }
+
+        let active_tab = self.state.inner.active_tab.into_option();
+        let active_page = self.state.inner.get_active_page();
+        let show_scroll_buttons = self.state.inner.overflow == TabContainerOverflow::Scroll;
+
+        let tabs = self.state.inner.tabs.as_ref().iter().enumerate().map(|(i, tab)| {
+            let mut tab_style = self.tab_style.clone().into_library_owned_vec();
+            tab_style.push(Normal(CssProperty::const_background_content(get_tab_background(active_tab == Some(i)))));
+            let mut close_style = self.close_button_style.clone().into_library_owned_vec();
+            close_style.push(Normal(get_display(tab.closable, LayoutDisplay::Block)));
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_TAB_CLASS))
+            .with_inline_css_props(tab_style.into())
+            .with_children(vec![
+                Dom::text(tab.title.clone())
+                .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_TAB_TITLE_CLASS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_TAB_TITLE_STYLE)),
+                Dom::text(AzString::from_const_str("\u{00D7}")) // ×
+                .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_TAB_CLOSE_CLASS))
+                .with_inline_css_props(close_style.into()),
+            ].into())
+        }).collect::<Vec<Dom>>();
+
+        let menu_rows = self.state.inner.tabs.as_ref().iter().enumerate().map(|(i, tab)| {
+            let mut row_style = NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_MENU_ROW_STYLE).into_library_owned_vec();
+            row_style.push(Normal(CssProperty::const_background_content(get_menu_row_background(active_tab == Some(i)))));
+            Dom::text(tab.title.clone())
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_MENU_ROW_CLASS))
+            .with_inline_css_props(row_style.into())
+        }).collect::<Vec<Dom>>();
+
+        let mut strip_style = NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_STRIP_STYLE).into_library_owned_vec();
+        strip_style.push(Normal(CssProperty::left(LayoutLeft::px(-self.state.inner.scroll_offset))));
+        strip_style.push(Normal(CssProperty::width(LayoutWidth::px(self.state.inner.get_strip_width()))));
+
+        let mut scroll_button_style = self.button_style.clone().into_library_owned_vec();
+        scroll_button_style.push(Normal(get_display(show_scroll_buttons, LayoutDisplay::Block)));
+        let scroll_button_style: NodeDataInlineCssPropertyVec = scroll_button_style.into();
+
+        let mut menu_button_style = self.button_style.clone().into_library_owned_vec();
+        menu_button_style.push(Normal(get_display(!show_scroll_buttons, LayoutDisplay::Block)));
+
+        let mut menu_style = self.menu_style.clone().into_library_owned_vec();
+        menu_style.push(Normal(get_display(self.state.inner.menu_open, LayoutDisplay::Flex)));
+
+        let page_wrappers = (0..tab_count).map(|page| {
+            let mut page_style = self.page_style.clone().into_library_owned_vec();
+            page_style.push(Normal(get_display(active_page == Some(page), LayoutDisplay::Flex)));
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_PAGE_CLASS))
+            .with_inline_css_props(page_style.into())
+        }).collect::<Vec<Dom>>();
+
+        let state_ref = RefAny::new(self.state);
+
+        let container = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_CLASS))
+        .with_inline_css_props(self.container_style)
+        .with_dataset(Some(state_ref.clone()).into());
+
+        let header = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_HEADER_CLASS))
+        .with_inline_css_props(self.header_style)
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down },
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_VIEWPORT_CLASS))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_VIEWPORT_STYLE))
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_tab_mouse_down },
+                },
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::Scroll),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_scroll },
+                },
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::DragStart),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_drag_start },
+                },
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::DragOver),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_drag_over },
+                },
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::Drop),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_drop },
+                },
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::DragEnd),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_drag_end },
+                },
+            ].into())
+            .with_children(vec![
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_STRIP_CLASS))
+                .with_inline_css_props(strip_style.into())
+                .with_children(tabs.into()),
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_DROP_INDICATOR_CLASS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_CONTAINER_DROP_INDICATOR_STYLE)),
+            ].into()),
+            Dom::text(AzString::from_const_str("\u{2039}")) // ‹
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_BUTTON_CLASS))
+            .with_inline_css_props(scroll_button_style.clone())
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_scroll_left },
+                },
+            ].into()),
+            Dom::text(AzString::from_const_str("\u{203A}")) // ›
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_BUTTON_CLASS))
+            .with_inline_css_props(scroll_button_style)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_scroll_right },
+                },
+            ].into()),
+            Dom::text(AzString::from_const_str("\u{25BE}")) // ▾
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_BUTTON_CLASS))
+            .with_inline_css_props(menu_button_style.into())
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_menu_button_clicked },
+                },
+            ].into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TAB_CONTAINER_MENU_CLASS))
+            .with_inline_css_props(menu_style.into())
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_menu_row_clicked },
+                },
+            ].into())
+            .with_children(menu_rows.into()),
+        ].into());
+
+        (container, header, page_wrappers)
+    }
+}
+
+impl Default for TabContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Payload of a drag that was started on a tab
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TabContainerDragPayload {
+    /// Index of the dragged tab
+    pub tab: usize,
+    /// Index of the page of the dragged tab
+    pub page: usize,
+}
+
+// handle input events for the TabContainer
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId, FocusTarget};
+    use azul_desktop::css::{CssProperty, LayoutDisplay, LayoutLeft, LayoutWidth};
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        TabContainerStateWrapper, TabContainerDragPayload,
+        TabContainerOnTabChange, TabContainerOnTabClose, TabContainerOnTabMove,
+        get_tab_background, get_menu_row_background, get_display,
+        TAB_WIDTH, WHEEL_STEP, MENU_ROW_HEIGHT,
+    };
+
+    /// Container -> [header -> [viewport -> [strip -> [tabs], drop indicator],
+    /// scroll left, scroll right, menu button, menu -> [rows]], pages]
+    struct TabContainerNodes {
+        header: DomNodeId,
+        viewport: DomNodeId,
+        strip: DomNodeId,
+        drop_indicator: DomNodeId,
+        menu: DomNodeId,
+    }
+
+    impl TabContainerNodes {
+        fn new(container: DomNodeId, info: &CallbackInfo) -> Option<Self> {
+            let header = info.get_first_child(container)?;
+            let viewport = info.get_first_child(header)?;
+            let strip = info.get_first_child(viewport)?;
+            let drop_indicator = info.get_next_sibling(strip)?;
+            let menu = info.get_last_child(header)?;
+            Some(Self { header, viewport, strip, drop_indicator, menu })
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_tab_mouse_down(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let viewport_id = info.get_hit_node();
+        let container_id = match info.get_parent(viewport_id).and_then(|h| info.get_parent(h)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (tab, on_close_button) = match tab_container.inner.get_tab_at(cursor.x) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if let Some(header_id) = info.get_parent(viewport_id) {
+            info.set_focus(FocusTarget::Id(header_id));
+        }
+
+        tab_container.inner.menu_open = false;
+
+        if on_close_button {
+            tab_container.pressed_tab = None.into();
+            return close_tab(&mut tab_container, tab, container_id, &mut info);
+        }
+
+        tab_container.pressed_tab = Some(tab).into();
+        select_tab(&mut tab_container, tab, container_id, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = match info.get_parent(info.get_hit_node()) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let tab_count = tab_container.inner.tabs.len();
+        if tab_count == 0 {
+            return Update::DoNothing;
+        }
+
+        let active_tab = tab_container.inner.active_tab.into_option().unwrap_or(0);
+
+        match last_keycode {
+            VirtualKeyCode::Left => select_tab(&mut tab_container, active_tab.saturating_sub(1), container_id, &mut info),
+            VirtualKeyCode::Right => select_tab(&mut tab_container, (active_tab + 1).min(tab_count - 1), container_id, &mut info),
+            VirtualKeyCode::Home => select_tab(&mut tab_container, 0, container_id, &mut info),
+            VirtualKeyCode::End => select_tab(&mut tab_container, tab_count - 1, container_id, &mut info),
+            VirtualKeyCode::Delete => {
+                let closable = tab_container.inner.get_tab(active_tab).map(|t| t.closable).unwrap_or(false);
+                if closable {
+                    close_tab(&mut tab_container, active_tab, container_id, &mut info)
+                } else {
+                    Update::DoNothing
+                }
+            },
+            VirtualKeyCode::Escape if tab_container.inner.menu_open => {
+                tab_container.inner.menu_open = false;
+                refresh(&tab_container, container_id, &mut info);
+                Update::DoNothing
+            },
+            _ => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_scroll(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (scroll_x, scroll_y) = match info.get_current_mouse_state().get_scroll_amount() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // vertical mouse wheels scroll the tab strip horizontally
+        let scroll = if scroll_x != 0.0 { scroll_x } else { scroll_y };
+        let viewport_id = info.get_hit_node();
+        scroll_strip(&mut tab_container, -scroll * WHEEL_STEP, viewport_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_scroll_left(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let viewport_id = match info.get_parent(info.get_hit_node()).and_then(|h| info.get_first_child(h)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        scroll_strip(&mut tab_container, -TAB_WIDTH, viewport_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_scroll_right(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let viewport_id = match info.get_parent(info.get_hit_node()).and_then(|h| info.get_first_child(h)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        scroll_strip(&mut tab_container, TAB_WIDTH, viewport_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_menu_button_clicked(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = match info.get_parent(info.get_hit_node()).and_then(|h| info.get_parent(h)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        tab_container.inner.menu_open = !tab_container.inner.menu_open;
+        refresh(&tab_container, container_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_menu_row_clicked(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = match info.get_parent(info.get_hit_node()).and_then(|h| info.get_parent(h)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        tab_container.inner.menu_open = false;
+
+        let row = (cursor.y / MENU_ROW_HEIGHT).floor();
+        if row < 0.0 || row as usize >= tab_container.inner.tabs.len() {
+            refresh(&tab_container, container_id, &mut info);
+            return Update::DoNothing;
+        }
+
+        select_tab(&mut tab_container, row as usize, container_id, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_drag_start(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !tab_container.allow_reorder {
+            return Update::DoNothing;
+        }
+
+        let tab = match tab_container.pressed_tab.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let page = match tab_container.inner.get_tab(tab) {
+            Some(s) => s.page,
+            None => return Update::DoNothing,
+        };
+
+        if info.start_drag(RefAny::new(TabContainerDragPayload { tab, page }), None.into()) {
+            tab_container.dragged_tab = Some(tab).into();
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_drag_over(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // only tabs of this container can be dropped
+        if tab_container.dragged_tab.is_none() {
+            return Update::DoNothing;
+        }
+
+        let cursor = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let drop_index = tab_container.inner.get_insert_index_at(cursor.x);
+        if tab_container.drop_index.into_option() != Some(drop_index) {
+            tab_container.drop_index = Some(drop_index).into();
+            let viewport_id = info.get_hit_node();
+            update_drop_indicator(&tab_container, viewport_id, &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_drop(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let viewport_id = info.get_hit_node();
+        let dragged_tab = tab_container.dragged_tab.into_option();
+        let drop_index = tab_container.drop_index.into_option();
+        tab_container.pressed_tab = None.into();
+        tab_container.dragged_tab = None.into();
+        tab_container.drop_index = None.into();
+        update_drop_indicator(&tab_container, viewport_id, &mut info);
+
+        let (from, drop_index) = match (dragged_tab, drop_index) {
+            (Some(f), Some(d)) => (f, d),
+            _ => return Update::DoNothing,
+        };
+
+        // the gap index counts the dragged tab itself
+        let to = if drop_index > from { drop_index - 1 } else { drop_index };
+
+        if !tab_container.inner.move_tab(from, to) {
+            return Update::DoNothing;
+        }
+
+        if let Some(container_id) = info.get_parent(viewport_id).and_then(|h| info.get_parent(h)) {
+            refresh(&tab_container, container_id, &mut info);
+        }
+
+        // rustc doesn't understand the borrowing lifetime here
+        let ontabmove = &mut tab_container.on_tab_move;
+        let inner = &tab_container.inner;
+
+        match ontabmove.as_mut() {
+            Some(TabContainerOnTabMove { callback, data }) => (callback.cb)(data, &inner, from, to, &mut info),
+            None => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_drag_end(tab_container: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tab_container = match tab_container.downcast_mut::<TabContainerStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // dropped outside of the tab strip or cancelled
+        tab_container.pressed_tab = None.into();
+        tab_container.dragged_tab = None.into();
+        if tab_container.drop_index.is_some() {
+            tab_container.drop_index = None.into();
+            update_drop_indicator(&tab_container, info.get_hit_node(), &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    /// Activates the tab and calls `on_tab_change` if the active tab changed
+    fn select_tab(tab_container: &mut TabContainerStateWrapper, tab: usize, container_id: DomNodeId, info: &mut CallbackInfo) -> Update {
+
+        let changed = tab_container.inner.select_tab(tab);
+        scroll_to_active_tab(tab_container, container_id, info);
+        refresh(tab_container, container_id, info);
+
+        if !changed {
+            return Update::DoNothing;
+        }
+
+        on_tab_changed(tab_container, info)
+    }
+
+    /// Closes the tab, calls `on_tab_close` and `on_tab_change` (if the closed tab was active)
+    fn close_tab(tab_container: &mut TabContainerStateWrapper, tab: usize, container_id: DomNodeId, info: &mut CallbackInfo) -> Update {
+
+        let active_page = tab_container.inner.get_active_page();
+        let closed = match tab_container.inner.close_tab(tab) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        scroll_to_active_tab(tab_container, container_id, info);
+        refresh(tab_container, container_id, info);
+
+        // rustc doesn't understand the borrowing lifetime here
+        let ontabclose = &mut tab_container.on_tab_close;
+        let inner = &tab_container.inner;
+
+        let mut update = match ontabclose.as_mut() {
+            Some(TabContainerOnTabClose { callback, data }) => (callback.cb)(data, &inner, closed, info),
+            None => Update::DoNothing,
+        };
+
+        if tab_container.inner.get_active_page() != active_page {
+            update.max_self(on_tab_changed(tab_container, info));
+        }
+
+        update
+    }
+
+    fn on_tab_changed(tab_container: &mut TabContainerStateWrapper, info: &mut CallbackInfo) -> Update {
+
+        // rustc doesn't understand the borrowing lifetime here
+        let ontabchange = &mut tab_container.on_tab_change;
+        let inner = &tab_container.inner;
+
+        match ontabchange.as_mut() {
+            Some(TabContainerOnTabChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn scroll_to_active_tab(tab_container: &mut TabContainerStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) {
+        let viewport_width = TabContainerNodes::new(container_id, info)
+            .and_then(|nodes| info.get_node_size(nodes.viewport))
+            .map(|s| s.width);
+        if let Some(viewport_width) = viewport_width {
+            tab_container.inner.scroll_to_active_tab(viewport_width);
+        }
+    }
+
+    fn scroll_strip(tab_container: &mut TabContainerStateWrapper, delta: f32, viewport_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let viewport_width = match info.get_node_size(viewport_id) {
+            Some(s) => s.width,
+            None => return,
+        };
+
+        let scroll_offset = tab_container.inner.scroll_offset;
+        tab_container.inner.scroll_by(delta, viewport_width);
+
+        if tab_container.inner.scroll_offset != scroll_offset {
+            if let Some(strip_id) = info.get_first_child(viewport_id) {
+                info.set_css_property(strip_id, CssProperty::left(LayoutLeft::px(-tab_container.inner.scroll_offset)));
+            }
+        }
+    }
+
+    /// Synchronizes the tab headers, the menu and the visible page with the
+    /// state, without regenerating the DOM
+    fn refresh(tab_container: &TabContainerStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let nodes = match TabContainerNodes::new(container_id, info) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let state = &tab_container.inner;
+        let active_tab = state.active_tab.into_option();
+        let active_page = state.get_active_page();
+
+        info.set_css_property(nodes.strip, CssProperty::left(LayoutLeft::px(-state.scroll_offset)));
+        info.set_css_property(nodes.strip, CssProperty::width(LayoutWidth::px(state.get_strip_width())));
+
+        // tab node i shows the tab at index i, the remaining nodes are hidden
+        let mut tab_id = info.get_first_child(nodes.strip);
+        let mut menu_row_id = info.get_first_child(nodes.menu);
+        for i in 0..tab_container.tab_count {
+
+            let tab = state.get_tab(i);
+            let is_active = active_tab == Some(i);
+
+            if let Some(current_tab_id) = tab_id {
+                info.set_css_property(current_tab_id, get_display(tab.is_some(), LayoutDisplay::Flex));
+                if let Some(tab) = tab {
+                    info.set_css_property(current_tab_id, CssProperty::const_background_content(get_tab_background(is_active)));
+                    if let Some(title_id) = info.get_first_child(current_tab_id) {
+                        info.set_string_contents(title_id, tab.title.clone());
+                        if let Some(close_id) = info.get_next_sibling(title_id) {
+                            info.set_css_property(close_id, get_display(tab.closable, LayoutDisplay::Block));
+                        }
+                    }
+                }
+                tab_id = info.get_next_sibling(current_tab_id);
+            }
+
+            if let Some(current_row_id) = menu_row_id {
+                info.set_css_property(current_row_id, get_display(tab.is_some(), LayoutDisplay::Block));
+                if let Some(tab) = tab {
+                    info.set_string_contents(current_row_id, tab.title.clone());
+                    info.set_css_property(current_row_id, CssProperty::const_background_content(get_menu_row_background(is_active)));
+                }
+                menu_row_id = info.get_next_sibling(current_row_id);
+            }
+        }
+
+        info.set_css_property(nodes.menu, get_display(state.menu_open, LayoutDisplay::Flex));
+
+        let mut page_id = info.get_next_sibling(nodes.header);
+        let mut page = 0;
+        while let Some(current_page_id) = page_id {
+            info.set_css_property(current_page_id, get_display(active_page == Some(page), LayoutDisplay::Flex));
+            page_id = info.get_next_sibling(current_page_id);
+            page += 1;
+        }
+
+        if tab_container.drop_index.is_some() {
+            update_drop_indicator(tab_container, nodes.viewport, info);
+        }
+    }
+
+    /// Shows a vertical line in the gap where the dragged tab would be inserted
+    fn update_drop_indicator(tab_container: &TabContainerStateWrapper, viewport_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let drop_indicator_id = match info.get_first_child(viewport_id).and_then(|s| info.get_next_sibling(s)) {
+            Some(s) => s,
+            None => return,
+        };
+
+        match tab_container.drop_index.into_option() {
+            Some(drop_index) => {
+                let left = drop_index as f32 * TAB_WIDTH - tab_container.inner.scroll_offset - 1.0;
+                info.set_css_property(drop_indicator_id, CssProperty::const_display(LayoutDisplay::Block));
+                info.set_css_property(drop_indicator_id, CssProperty::left(LayoutLeft::px(left.max(0.0))));
+            },
+            None => {
+                info.set_css_property(drop_indicator_id, CssProperty::const_display(LayoutDisplay::None));
+            },
+        }
+    }
+}
+
+impl From<TabContainer> for Dom {
+    fn from(t: TabContainer) -> Dom {
+        t.dom()
+    }
+}
+
+/// XML component for `<tab_container tabs="Editor, Console" closable="true">`,
+/// each child node of the `<tab_container>` is rendered as the page of one tab
+pub struct TabContainerRenderer {
+    node: XmlNode,
+}
+
+impl TabContainerRenderer {
+    pub fn new() -> Self {
+        Self { node: XmlNode::new("tab_container") }
+    }
+
+    fn get_tab_container(args: &FilteredComponentArguments) -> TabContainer {
+
+        let get_arg = |name: &str| args.args.get(name).map(|(v, _)| v.trim());
+
+        let mut tab_container = TabContainer::from_titles_str(get_arg("tabs").unwrap_or_default(), DomVec::from_const_slice(&[]))
+            .with_closable(get_arg("closable").and_then(parse_bool).unwrap_or(false))
+            .with_allow_reorder(get_arg("reorderable").and_then(parse_bool).unwrap_or(false));
+
+        if let Some(active) = get_arg("active").and_then(|a| a.parse::<usize>().ok()) {
+            tab_container.set_active_tab(active);
+        }
+
+        if get_arg("overflow").map(|o| o.eq_ignore_ascii_case("menu")).unwrap_or(false) {
+            tab_container.set_overflow(TabContainerOverflow::Menu);
+        }
+
+        tab_container
+    }
+}
+
+impl XmlComponent for TabContainerRenderer {
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        let mut args = ComponentArgumentsMap::default();
+        args.insert(String::from("tabs"), (String::from("String"), 0));
+        args.insert(String::from("active"), (String::from("usize"), 1));
+        args.insert(String::from("closable"), (String::from("bool"), 2));
+        args.insert(String::from("reorderable"), (String::from("bool"), 3));
+        args.insert(String::from("overflow"), (String::from("azul::widgets::TabContainerOverflow(Scroll|Menu)"), 4));
+        ComponentArguments {
+            args,
+            accepts_text: false,
+            accepts_slot: true,
+        }
+    }
+
+    fn render_dom<'a>(&'a self, _: &'a XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent) -> Result<StyledDom, RenderDomError<'a>> {
+        Ok(Self::get_tab_container(args).dom().style(&mut Css::empty()))
+    }
+
+    fn render_dom_with_slot<'a>(&'a self, components: &'a XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent, slot: &XmlSlot<'a, '_>) -> Result<(StyledDom, bool), RenderDomError<'a>> {
+
+        // children without a title in `tabs` get a generic title
+        let mut tab_container = Self::get_tab_container(args);
+        for i in tab_container.state.inner.tabs.len()..slot.children.len() {
+            tab_container.add_tab(AzString::from(format!("Tab {}", i + 1)), Dom::div());
+        }
+
+        let (mut container, mut header, page_wrappers) = tab_container.build();
+        let mut dom = container.style(&mut Css::empty());
+        dom.append_child(header.style(&mut Css::empty()));
+
+        for (i, mut page_wrapper) in page_wrappers.into_iter().enumerate() {
+            let mut page = page_wrapper.style(&mut Css::empty());
+            if let Some(child) = slot.children.get(i) {
+                render_dom_from_template_nodes(&mut page, core::slice::from_ref(child), components, slot.scope, slot.parent)?;
+            }
+            dom.append_child(page);
+        }
+
+        Ok((dom, true))
+    }
+
+    fn compile_to_rust_code(&self, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(String::from("azul::widgets::TabContainer::from_titles_str(tabs, slot).with_active_tab(active).with_closable(closable).with_allow_reorder(reorderable).with_overflow(overflow).dom()"))
+    }
+
+    fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
+}
+
+#[cfg(test)]
+fn test_state(titles: &str) -> TabContainerState {
+    TabContainer::from_titles_str(titles, DomVec::from_const_slice(&[])).with_closable(true).state.inner
+}
+
+#[cfg(test)]
+fn get_test_pages(state: &TabContainerState) -> Vec<usize> {
+    state.tabs.as_ref().iter().map(|t| t.page).collect()
+}
+
+#[test]
+fn test_close_tab() {
+    let mut state = test_state("A, B, C, D");
+    assert_eq!(state.active_tab.into_option(), Some(0));
+    assert!(state.select_tab(2));
+    assert!(!state.select_tab(2));
+    assert!(!state.select_tab(4));
+
+    // closing a tab before the active tab keeps the same page active
+    assert_eq!(state.close_tab(0).map(|t| t.page), Some(0));
+    assert_eq!(get_test_pages(&state), vec![1, 2, 3]);
+    assert_eq!(state.active_tab.into_option(), Some(1));
+    assert_eq!(state.get_active_page(), Some(2));
+
+    // closing the active tab activates the next tab ...
+    assert_eq!(state.close_tab(1).map(|t| t.page), Some(2));
+    assert_eq!(get_test_pages(&state), vec![1, 3]);
+    assert_eq!(state.get_active_page(), Some(3));
+
+    // ... or the previous tab if the closed tab was the last one
+    assert_eq!(state.close_tab(1).map(|t| t.page), Some(3));
+    assert_eq!(get_test_pages(&state), vec![1]);
+    assert_eq!(state.get_active_page(), Some(1));
+
+    assert!(state.close_tab(1).is_none());
+    assert_eq!(state.close_tab(0).map(|t| t.page), Some(1));
+    assert!(state.tabs.is_empty());
+    assert_eq!(state.active_tab.into_option(), None);
+}
+
+#[test]
+fn test_move_tab() {
+    let mut state = test_state("A, B, C, D");
+    state.select_tab(1);
+
+    assert!(state.move_tab(0, 3));
+    assert_eq!(get_test_pages(&state), vec![1, 2, 3, 0]);
+    assert_eq!(state.active_tab.into_option(), Some(0));
+    assert_eq!(state.get_active_page(), Some(1));
+
+    assert!(state.move_tab(2, 0));
+    assert_eq!(get_test_pages(&state), vec![3, 1, 2, 0]);
+    assert_eq!(state.active_tab.into_option(), Some(1));
+    assert_eq!(state.get_active_page(), Some(1));
+
+    assert!(!state.move_tab(1, 1));
+    assert!(!state.move_tab(0, 4));
+    assert_eq!(get_test_pages(&state), vec![3, 1, 2, 0]);
+}
+
+#[test]
+fn test_get_tab_at() {
+    let mut state = test_state("A, B, C, D");
+    assert_eq!(state.get_tab_at(10.0), Some((0, false)));
+    assert_eq!(state.get_tab_at(TAB_WIDTH - 10.0), Some((0, true)));
+    assert_eq!(state.get_tab_at(TAB_WIDTH + 10.0), Some((1, false)));
+    assert_eq!(state.get_tab_at(4.0 * TAB_WIDTH + 10.0), None);
+    assert_eq!(state.get_tab_at(-10.0), None);
+
+    assert_eq!(state.get_insert_index_at(TAB_WIDTH * 0.4), 0);
+    assert_eq!(state.get_insert_index_at(TAB_WIDTH * 0.6), 1);
+    assert_eq!(state.get_insert_index_at(TAB_WIDTH * 10.0), 4);
+
+    state.scroll_by(TAB_WIDTH / 2.0, TAB_WIDTH * 2.0);
+    assert_eq!(state.get_tab_at(TAB_WIDTH / 2.0 + 10.0), Some((1, false)));
+    assert_eq!(state.get_insert_index_at(TAB_WIDTH * 0.2), 1);
+}
@@ -0,0 +1,1575 @@
+//! Multi-line text editor
+//!
+//! Only the visible rows are rendered: the editor renders a fixed pool of
+//! `visible_rows` row nodes, scrolling and editing only replace the text of
+//! these rows via `set_string_contents`, so the cost of an edit does not depend
+//! on the number of lines. Lines are wrapped at `wrap_column` characters
+//! (measured from the width of the editor), the editor uses a monospace font,
+//! so that the caret and the selection can be positioned by column.
+//!
+//! The width of a character is measured from the `InlineText` of the rendered
+//! rows, mouse clicks are hit-tested against the `InlineText` of the clicked row.
+
+use core::ops::Range;
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClassVec, IdOrClass, IdOrClass::Class,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Focus},
+    },
+    callbacks::{Callback, CallbackInfo, Update, RefAny},
+};
+use azul_desktop::css::{
+    impl_option, impl_option_inner,
+    impl_vec, impl_vec_debug, impl_vec_clone, impl_vec_partialeq, impl_vec_mut,
+};
+use azul_core::gl::OptionUsize;
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{
+    XmlComponent, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, ComponentArgumentsMap, FilteredComponentArguments,
+    RenderDomError, CompileError, parse_bool,
+};
+use std::vec::Vec;
+use std::string::String;
+
+/// Default number of rows that are visible (and rendered) at the same time
+pub const DEFAULT_VISIBLE_ROWS: usize = 20;
+/// Maximum number of entries in the undo stack
+const MAX_UNDO_ENTRIES: usize = 1000;
+/// Height of a single row in pixels
+const LINE_HEIGHT: f32 = 18.0;
+/// Padding between the border of the editor and the text
+const PADDING: f32 = 4.0;
+/// Estimated width of a character of the default font (13px monospace),
+/// replaced by the measured width as soon as the text has been laid out
+const DEFAULT_CHAR_WIDTH: f32 = 7.8;
+/// How many rows one "notch" of the mouse wheel scrolls
+const ROWS_PER_WHEEL_NOTCH: f32 = 3.0;
+
+static TEXT_EDIT_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-text-edit-container"))];
+static TEXT_EDIT_ROWS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-text-edit-rows"))];
+static TEXT_EDIT_ROW_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-text-edit-row"))];
+static TEXT_EDIT_SELECTION_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-text-edit-selection"))];
+static TEXT_EDIT_LABEL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-text-edit-label"))];
+static TEXT_EDIT_CARET_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-text-edit-caret"))];
+
+pub type TextEditOnTextChangeCallbackType = extern "C" fn(&mut RefAny, &TextEditState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct TextEditOnTextChangeCallback {
+    pub cb: TextEditOnTextChangeCallbackType,
+}
+
+impl_callback!(TextEditOnTextChangeCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEdit {
+    pub state: TextEditStateWrapper,
+    /// Style for the outer container (receives the keyboard and mouse events)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for each rendered row
+    pub row_style: NodeDataInlineCssPropertyVec,
+    /// Style for the text of each row
+    pub label_style: NodeDataInlineCssPropertyVec,
+    /// Style for the selection background of each row
+    pub selection_style: NodeDataInlineCssPropertyVec,
+    /// Style for the caret
+    pub caret_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditStateWrapper {
+    pub inner: TextEditState,
+    /// Optional: Function to call when the text changes
+    pub on_text_change: OptionTextEditOnTextChange,
+    pub undo_stack: TextEditUndoEntryVec,
+    pub redo_stack: TextEditUndoEntryVec,
+    /// Wrap lines at the width of the editor (otherwise long lines are clipped)
+    pub wrap_lines: bool,
+    /// Width of a single character in pixels, measured from the rendered text
+    pub char_width: f32,
+    /// Text of the row nodes as they are currently rendered, so that
+    /// unchanged rows don't have to be laid out again
+    pub rendered_rows: StringVec,
+    /// Whether the left mouse button was pressed inside the editor (drag-selection)
+    pub is_selecting: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditOnTextChange {
+    pub data: RefAny,
+    pub callback: TextEditOnTextChangeCallback,
+}
+
+impl_option!(TextEditOnTextChange, OptionTextEditOnTextChange, copy = false, [Debug, Clone, PartialEq]);
+
+/// Position in the text: line index and character (not byte) index in the line
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TextEditCursor {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl_option!(TextEditCursor, OptionTextEditCursor, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+impl TextEditCursor {
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// Kind of an edit, consecutive edits of the same kind are undone together
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub enum TextEditEditKind {
+    /// Typed characters
+    Insert,
+    /// Backspace
+    DeleteBackward,
+    /// Delete key
+    DeleteForward,
+    /// Paste, cut, newline, ... - never grouped
+    Other,
+}
+
+/// Reversible edit: `removed` was replaced by `inserted` at `start`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditUndoEntry {
+    pub start: TextEditCursor,
+    pub removed: AzString,
+    pub inserted: AzString,
+    /// Cursor position before the edit, restored on undo
+    pub cursor_before: TextEditCursor,
+    pub kind: TextEditEditKind,
+}
+
+impl_vec!(TextEditUndoEntry, TextEditUndoEntryVec, TextEditUndoEntryVecDestructor);
+impl_vec_debug!(TextEditUndoEntry, TextEditUndoEntryVec);
+impl_vec_clone!(TextEditUndoEntry, TextEditUndoEntryVec, TextEditUndoEntryVecDestructor);
+impl_vec_partialeq!(TextEditUndoEntry, TextEditUndoEntryVec);
+impl_vec_mut!(TextEditUndoEntry, TextEditUndoEntryVec);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditState {
+    /// Lines of the text, without the line breaks
+    pub lines: StringVec,
+    pub cursor: TextEditCursor,
+    /// Other end of the selection, the selection spans from the anchor to the cursor
+    pub selection_anchor: OptionTextEditCursor,
+    /// Column (in the visual row) that vertical cursor movements return to
+    pub preferred_column: OptionUsize,
+    /// Index of the first visible (visual) row
+    pub first_visible_row: usize,
+    /// Number of rows that are visible at the same time
+    pub visible_rows: usize,
+    /// Maximum number of characters per row, 0 = don't wrap
+    pub wrap_column: usize,
+}
+
+impl Default for TextEditState {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+/// Returns the byte offset of the character at `column` (or the length of the string)
+fn char_to_byte(s: &str, column: usize) -> usize {
+    s.char_indices().nth(column).map(|(i, _)| i).unwrap_or(s.len())
+}
+
+/// Returns the end of the text when it is inserted at `start`
+fn get_end_of_inserted_text(start: TextEditCursor, text: &str) -> TextEditCursor {
+    match text.rfind('\n') {
+        Some(last_newline) => TextEditCursor {
+            line: start.line + text.matches('\n').count(),
+            column: text[(last_newline + 1)..].chars().count(),
+        },
+        None => TextEditCursor {
+            line: start.line,
+            column: start.column + text.chars().count(),
+        },
+    }
+}
+
+/// Splits the line into rows of at most `wrap_column` characters, breaking
+/// after whitespace if possible. Returns the character ranges of the rows.
+pub fn wrap_line(line: &str, wrap_column: usize) -> Vec<Range<usize>> {
+
+    let len = line.chars().count();
+
+    if wrap_column == 0 || len <= wrap_column {
+        return vec![0..len];
+    }
+
+    let chars = line.chars().collect::<Vec<char>>();
+    let mut rows = Vec::new();
+    let mut start = 0;
+
+    while len - start > wrap_column {
+        let max_end = start + wrap_column;
+        let end = chars[start..max_end].iter()
+            .rposition(|c| c.is_whitespace())
+            .map(|ws| start + ws + 1)
+            .unwrap_or(max_end);
+        rows.push(start..end);
+        start = end;
+    }
+
+    rows.push(start..len);
+    rows
+}
+
+impl TextEditState {
+
+    pub fn new(text: &str) -> Self {
+        Self {
+            lines: text.split('\n').map(|l| AzString::from(l.trim_end_matches('\r').to_string())).collect::<Vec<_>>().into(),
+            cursor: TextEditCursor::new(0, 0),
+            selection_anchor: None.into(),
+            preferred_column: None.into(),
+            first_visible_row: 0,
+            visible_rows: DEFAULT_VISIBLE_ROWS,
+            wrap_column: 0,
+        }
+    }
+
+    /// Returns the text, lines separated by `\n`
+    pub fn get_text(&self) -> String {
+        self.lines.as_ref().iter().map(|l| l.as_str()).collect::<Vec<_>>().join("\n")
+    }
+
+    #[inline]
+    pub fn get_line(&self, line: usize) -> &str {
+        self.lines.as_ref().get(line).map(|l| l.as_str()).unwrap_or("")
+    }
+
+    #[inline]
+    pub fn get_line_len(&self, line: usize) -> usize {
+        self.get_line(line).chars().count()
+    }
+
+    /// Clamps the cursor to the existing lines and columns
+    pub fn clamp_cursor(&self, cursor: TextEditCursor) -> TextEditCursor {
+        let line = cursor.line.min(self.lines.len().saturating_sub(1));
+        TextEditCursor { line, column: cursor.column.min(self.get_line_len(line)) }
+    }
+
+    /// Returns the ordered (start, end) of the selection, `None` if nothing is selected
+    pub fn get_selection(&self) -> Option<(TextEditCursor, TextEditCursor)> {
+        let anchor = self.selection_anchor.into_option()?;
+        if anchor == self.cursor {
+            None
+        } else {
+            Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+        }
+    }
+
+    /// Returns the text between `start` and `end`
+    pub fn get_text_range(&self, start: TextEditCursor, end: TextEditCursor) -> String {
+        let (start, end) = (self.clamp_cursor(start), self.clamp_cursor(end));
+        if start.line == end.line {
+            let line = self.get_line(start.line);
+            return line[char_to_byte(line, start.column)..char_to_byte(line, end.column)].to_string();
+        }
+        let first = self.get_line(start.line);
+        let last = self.get_line(end.line);
+        let mut text = first[char_to_byte(first, start.column)..].to_string();
+        for line in (start.line + 1)..end.line {
+            text.push('\n');
+            text.push_str(self.get_line(line));
+        }
+        text.push('\n');
+        text.push_str(&last[..char_to_byte(last, end.column)]);
+        text
+    }
+
+    pub fn get_selected_text(&self) -> Option<String> {
+        self.get_selection().map(|(start, end)| self.get_text_range(start, end))
+    }
+
+    /// Replaces the text between `start` and `end` with `text`, returns the
+    /// removed text, the cursor is placed at the end of the inserted text
+    pub fn replace_range(&mut self, start: TextEditCursor, end: TextEditCursor, text: &str) -> String {
+
+        let (start, end) = (self.clamp_cursor(start), self.clamp_cursor(end));
+        let (start, end) = (start.min(end), start.max(end));
+        let removed = self.get_text_range(start, end);
+
+        let first = self.get_line(start.line);
+        let last = self.get_line(end.line);
+        let mut new_text = first[..char_to_byte(first, start.column)].to_string();
+        new_text.push_str(text);
+        new_text.push_str(&last[char_to_byte(last, end.column)..]);
+
+        let mut lines = core::mem::replace(&mut self.lines, StringVec::from_const_slice(&[])).into_library_owned_vec();
+        lines.splice(start.line..=end.line, new_text.split('\n').map(|l| AzString::from(l.to_string())));
+        self.lines = lines.into();
+
+        self.cursor = get_end_of_inserted_text(start, text);
+        self.selection_anchor = None.into();
+        self.preferred_column = None.into();
+
+        removed
+    }
+
+    /// Returns the position before the cursor (previous line if at the start of the line)
+    pub fn get_previous_position(&self, cursor: TextEditCursor) -> TextEditCursor {
+        if cursor.column > 0 {
+            TextEditCursor::new(cursor.line, cursor.column - 1)
+        } else if cursor.line > 0 {
+            TextEditCursor::new(cursor.line - 1, self.get_line_len(cursor.line - 1))
+        } else {
+            cursor
+        }
+    }
+
+    /// Returns the position after the cursor (next line if at the end of the line)
+    pub fn get_next_position(&self, cursor: TextEditCursor) -> TextEditCursor {
+        if cursor.column < self.get_line_len(cursor.line) {
+            TextEditCursor::new(cursor.line, cursor.column + 1)
+        } else if cursor.line + 1 < self.lines.len() {
+            TextEditCursor::new(cursor.line + 1, 0)
+        } else {
+            cursor
+        }
+    }
+
+    #[inline]
+    pub fn get_line_rows(&self, line: usize) -> Vec<Range<usize>> {
+        wrap_line(self.get_line(line), self.wrap_column)
+    }
+
+    /// Returns the number of visual rows of all lines
+    pub fn get_total_rows(&self) -> usize {
+        if self.wrap_column == 0 {
+            return self.lines.len();
+        }
+        (0..self.lines.len()).map(|l| self.get_line_rows(l).len()).sum()
+    }
+
+    /// Returns the (line, character range) of the visual rows in `rows`
+    pub fn get_rows(&self, rows: Range<usize>) -> Vec<(usize, Range<usize>)> {
+        let mut result = Vec::new();
+        let mut row_index = 0;
+        for line in 0..self.lines.len() {
+            if row_index >= rows.end {
+                break;
+            }
+            if self.wrap_column == 0 {
+                if row_index >= rows.start {
+                    result.push((line, 0..self.get_line_len(line)));
+                }
+                row_index += 1;
+                continue;
+            }
+            for range in self.get_line_rows(line) {
+                if row_index >= rows.start && row_index < rows.end {
+                    result.push((line, range));
+                }
+                row_index += 1;
+            }
+        }
+        result
+    }
+
+    /// Returns the visual row of the cursor and the column of the cursor in that row
+    pub fn get_cursor_row(&self, cursor: TextEditCursor) -> (usize, usize) {
+        let cursor = self.clamp_cursor(cursor);
+        if self.wrap_column == 0 {
+            return (cursor.line, cursor.column);
+        }
+        let rows_before = (0..cursor.line).map(|l| self.get_line_rows(l).len()).sum::<usize>();
+        let line_rows = self.get_line_rows(cursor.line);
+        let last = line_rows.len() - 1;
+        // a cursor at the end of a wrapped row is at the start of the next row
+        let row = line_rows.iter()
+            .position(|r| cursor.column >= r.start && cursor.column < r.end)
+            .unwrap_or(last);
+        (rows_before + row, cursor.column - line_rows[row].start)
+    }
+
+    /// Inverse of `get_cursor_row`, the column is clamped to the end of the row
+    pub fn get_cursor_at_row(&self, row: usize, column: usize) -> TextEditCursor {
+        let total_rows = self.get_total_rows();
+        let row = row.min(total_rows.saturating_sub(1));
+        let (line, range) = match self.get_rows(row..(row + 1)).pop() {
+            Some(s) => s,
+            None => return TextEditCursor::new(0, 0),
+        };
+        let is_last_row_of_line = range.end == self.get_line_len(line);
+        // the end of a wrapped row belongs to the next row
+        let max_column = if is_last_row_of_line { range.end } else { range.end.saturating_sub(1).max(range.start) };
+        TextEditCursor::new(line, (range.start + column).min(max_column))
+    }
+
+    /// Moves the cursor by `rows` visual rows, keeping the preferred column
+    pub fn move_cursor_rows(&mut self, rows: isize) {
+        let (row, column) = self.get_cursor_row(self.cursor);
+        let column = self.preferred_column.into_option().unwrap_or(column);
+        let new_row = if rows < 0 {
+            row.saturating_sub(rows.unsigned_abs())
+        } else {
+            row.saturating_add(rows as usize)
+        };
+        self.cursor = self.get_cursor_at_row(new_row, column);
+        self.preferred_column = Some(column).into();
+    }
+
+    /// Scrolls so that the row of the cursor is visible
+    pub fn scroll_to_cursor(&mut self) {
+        let (row, _) = self.get_cursor_row(self.cursor);
+        let visible_rows = self.visible_rows.max(1);
+        if row < self.first_visible_row {
+            self.first_visible_row = row;
+        } else if row >= self.first_visible_row + visible_rows {
+            self.first_visible_row = row + 1 - visible_rows;
+        }
+    }
+
+    /// Scrolls by `rows` rows, clamped to the number of rows
+    pub fn scroll_rows(&mut self, rows: isize) {
+        let max = self.get_total_rows().saturating_sub(self.visible_rows.max(1));
+        let new_row = if rows < 0 {
+            self.first_visible_row.saturating_sub(rows.unsigned_abs())
+        } else {
+            self.first_visible_row.saturating_add(rows as usize)
+        };
+        self.first_visible_row = new_row.min(max);
+    }
+}
+
+impl TextEditStateWrapper {
+
+    /// Replaces the text between `start` and `end`, records the edit in the
+    /// undo stack (merging it with the previous edit if possible)
+    pub fn edit(&mut self, start: TextEditCursor, end: TextEditCursor, text: &str, kind: TextEditEditKind) {
+
+        let cursor_before = self.inner.cursor;
+        let start = self.inner.clamp_cursor(start.min(end));
+        let removed = self.inner.replace_range(start, end.max(start), text);
+
+        if removed.is_empty() && text.is_empty() {
+            return;
+        }
+
+        let mut undo_stack = core::mem::replace(&mut self.undo_stack, TextEditUndoEntryVec::from_const_slice(&[])).into_library_owned_vec();
+
+        let merged = match undo_stack.last_mut() {
+            Some(last) if last.kind == kind => match kind {
+                // typing: merge if the text is inserted at the end of the previous
+                // insert, start a new group at the start of a new word
+                TextEditEditKind::Insert => {
+                    let last_end = get_end_of_inserted_text(last.start, last.inserted.as_str());
+                    let new_word = !text.starts_with(char::is_whitespace) &&
+                        last.inserted.as_str().ends_with(char::is_whitespace);
+                    if last_end == start && removed.is_empty() && last.removed.as_str().is_empty() && !new_word {
+                        let mut inserted = last.inserted.as_str().to_string();
+                        inserted.push_str(text);
+                        last.inserted = inserted.into();
+                        true
+                    } else {
+                        false
+                    }
+                },
+                // backspace: the removed text is in front of the previously removed text
+                TextEditEditKind::DeleteBackward => {
+                    if get_end_of_inserted_text(start, &removed) == last.start && text.is_empty() {
+                        let mut new_removed = removed.clone();
+                        new_removed.push_str(last.removed.as_str());
+                        last.removed = new_removed.into();
+                        last.start = start;
+                        true
+                    } else {
+                        false
+                    }
+                },
+                // delete: the removed text is after the previously removed text
+                TextEditEditKind::DeleteForward => {
+                    if start == last.start && text.is_empty() {
+                        let mut new_removed = last.removed.as_str().to_string();
+                        new_removed.push_str(&removed);
+                        last.removed = new_removed.into();
+                        true
+                    } else {
+                        false
+                    }
+                },
+                TextEditEditKind::Other => false,
+            },
+            _ => false,
+        };
+
+        if !merged {
+            undo_stack.push(TextEditUndoEntry {
+                start,
+                removed: removed.into(),
+                inserted: text.to_string().into(),
+                cursor_before,
+                kind,
+            });
+            if undo_stack.len() > MAX_UNDO_ENTRIES {
+                undo_stack.remove(0);
+            }
+        }
+
+        self.undo_stack = undo_stack.into();
+        self.redo_stack = TextEditUndoEntryVec::from_const_slice(&[]);
+    }
+
+    /// Reverts the last edit (group), returns whether anything was undone
+    pub fn undo(&mut self) -> bool {
+
+        let mut undo_stack = core::mem::replace(&mut self.undo_stack, TextEditUndoEntryVec::from_const_slice(&[])).into_library_owned_vec();
+        let entry = undo_stack.pop();
+        self.undo_stack = undo_stack.into();
+
+        let entry = match entry {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let end = get_end_of_inserted_text(entry.start, entry.inserted.as_str());
+        self.inner.replace_range(entry.start, end, entry.removed.as_str());
+        self.inner.cursor = self.inner.clamp_cursor(entry.cursor_before);
+
+        let mut redo_stack = core::mem::replace(&mut self.redo_stack, TextEditUndoEntryVec::from_const_slice(&[])).into_library_owned_vec();
+        redo_stack.push(entry);
+        self.redo_stack = redo_stack.into();
+
+        true
+    }
+
+    /// Re-applies the last undone edit (group), returns whether anything was redone
+    pub fn redo(&mut self) -> bool {
+
+        let mut redo_stack = core::mem::replace(&mut self.redo_stack, TextEditUndoEntryVec::from_const_slice(&[])).into_library_owned_vec();
+        let entry = redo_stack.pop();
+        self.redo_stack = redo_stack.into();
+
+        let entry = match entry {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let end = get_end_of_inserted_text(entry.start, entry.removed.as_str());
+        self.inner.replace_range(entry.start, end, entry.inserted.as_str());
+
+        let mut undo_stack = core::mem::replace(&mut self.undo_stack, TextEditUndoEntryVec::from_const_slice(&[])).into_library_owned_vec();
+        undo_stack.push(entry);
+        self.undo_stack = undo_stack.into();
+
+        true
+    }
+}
+
+const BACKGROUND_COLOR: ColorU = ColorU { r: 255,  g: 255,  b: 255,  a: 255 }; // white
+const BACKGROUND_THEME_LIGHT: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BACKGROUND_COLOR)];
+const BACKGROUND_COLOR_LIGHT: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(BACKGROUND_THEME_LIGHT);
+const SELECTION_COLOR: ColorU = ColorU { r: 173, g: 214, b: 255, a: 255 }; // #add6ff
+const SELECTION_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(SELECTION_COLOR)];
+const CARET_COLOR: ColorU = ColorU { r: 0, g: 0, b: 0, a: 255 }; // black
+const CARET_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(CARET_COLOR)];
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+
+const MONOSPACE_STR: &str = "monospace";
+const MONOSPACE: AzString = AzString::from_const_str(MONOSPACE_STR);
+const MONOSPACE_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(MONOSPACE)];
+const MONOSPACE_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(MONOSPACE_FAMILIES);
+
+static TEXT_EDIT_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+    Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_font_family(MONOSPACE_FAMILY)),
+
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(PADDING as isize))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(PADDING as isize))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(PADDING as isize))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(PADDING as isize))),
+
+    // border: 1px solid #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Inset })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    // Focus(border-color: #4286f4;)
+
+    Focus(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+static TEXT_EDIT_ROWS_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+];
+
+static TEXT_EDIT_ROW_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(LINE_HEIGHT as isize))),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static TEXT_EDIT_LABEL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: CARET_COLOR })),
+];
+
+static TEXT_EDIT_SELECTION_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(LINE_HEIGHT as isize))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SELECTION_THEME))),
+];
+
+static TEXT_EDIT_CARET_STYLE: &[NodeDataInlineCssProperty] = &[
+    // hidden until the editor receives the focus
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(1))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(LINE_HEIGHT as isize))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(CARET_THEME))),
+];
+
+impl TextEdit {
+
+    pub fn new(text: AzString) -> Self {
+        Self {
+            state: TextEditStateWrapper {
+                inner: TextEditState::new(text.as_str()),
+                on_text_change: None.into(),
+                undo_stack: TextEditUndoEntryVec::from_const_slice(&[]),
+                redo_stack: TextEditUndoEntryVec::from_const_slice(&[]),
+                wrap_lines: true,
+                char_width: DEFAULT_CHAR_WIDTH,
+                rendered_rows: StringVec::from_const_slice(&[]),
+                is_selecting: false,
+            },
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_CONTAINER_STYLE),
+            row_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_ROW_STYLE),
+            label_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_LABEL_STYLE),
+            selection_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_SELECTION_STYLE),
+            caret_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_CARET_STYLE),
+        }
+    }
+
+    /// Creates the editor from a previous state, e.g. the state that was
+    /// passed to the `on_text_change` callback (keeps the cursor and scroll position)
+    pub fn from_state(state: TextEditState) -> Self {
+        let mut s = Self::new(AzString::from_const_str(""));
+        s.state.inner = state;
+        s
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new(AzString::from_const_str(""));
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    /// Sets the number of visible rows, the height of the editor is `rows * 18px`
+    #[inline]
+    pub fn set_visible_rows(&mut self, rows: usize) {
+        self.state.inner.visible_rows = rows.max(1);
+    }
+
+    #[inline]
+    pub fn with_visible_rows(mut self, rows: usize) -> Self {
+        self.set_visible_rows(rows);
+        self
+    }
+
+    #[inline]
+    pub fn set_wrap_lines(&mut self, wrap_lines: bool) {
+        self.state.wrap_lines = wrap_lines;
+        if !wrap_lines {
+            self.state.inner.wrap_column = 0;
+        }
+    }
+
+    #[inline]
+    pub fn with_wrap_lines(mut self, wrap_lines: bool) -> Self {
+        self.set_wrap_lines(wrap_lines);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_text_change(&mut self, data: RefAny, on_text_change: TextEditOnTextChangeCallbackType) {
+        self.state.on_text_change = Some(TextEditOnTextChange {
+            callback: TextEditOnTextChangeCallback { cb: on_text_change },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_text_change(mut self, data: RefAny, on_text_change: TextEditOnTextChangeCallbackType) -> Self {
+        self.set_on_text_change(data, on_text_change);
+        self
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_row_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.row_style = style;
+    }
+
+    pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.label_style = style;
+    }
+
+    pub fn set_selection_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.selection_style = style;
+    }
+
+    pub fn set_caret_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.caret_style = style;
+    }
+
+    pub fn dom(mut self) -> Dom {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter,
+        };
+
+        self.state.inner.cursor = self.state.inner.clamp_cursor(self.state.inner.cursor);
+        self.state.inner.scroll_to_cursor();
+
+        let visible_rows = self.state.inner.visible_rows.max(1);
+        let first_row = self.state.inner.first_visible_row;
+
+        let mut row_texts = self.state.inner.get_rows(first_row..(first_row + visible_rows))
+            .into_iter()
+            .map(|(line, range)| {
+                let line = self.state.inner.get_line(line);
+                AzString::from(line[char_to_byte(line, range.start)..char_to_byte(line, range.end)].to_string())
+            })
+            .collect::<Vec<_>>();
+        row_texts.resize(visible_rows, AzString::from_const_str(""));
+
+        // the selection is positioned by the first callback (after the char width is known)
+        let rows = row_texts.iter().map(|text| {
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TEXT_EDIT_ROW_CLASS))
+            .with_inline_css_props(self.row_style.clone())
+            .with_children(vec![
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from(TEXT_EDIT_SELECTION_CLASS))
+                .with_inline_css_props(self.selection_style.clone()),
+                Dom::text(text.clone())
+                .with_ids_and_classes(IdOrClassVec::from(TEXT_EDIT_LABEL_CLASS))
+                .with_inline_css_props(self.label_style.clone()),
+            ].into())
+        }).collect::<Vec<Dom>>();
+
+        self.state.rendered_rows = row_texts.into();
+
+        let mut container_style = self.container_style.into_library_owned_vec();
+        container_style.push(Normal(CssProperty::height(LayoutHeight::px(visible_rows as f32 * LINE_HEIGHT + 2.0 * PADDING + 2.0))));
+
+        let state_ref = RefAny::new(self.state);
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(TEXT_EDIT_CONTAINER_CLASS))
+        .with_inline_css_props(container_style.into())
+        .with_tab_index(TabIndex::Auto)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextInput),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_text_input },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::FocusReceived),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_focus_received },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::FocusLost),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_focus_lost },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_down },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::MouseOver),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_over },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_up },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_scroll },
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TEXT_EDIT_ROWS_CLASS))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_ROWS_STYLE))
+            .with_children(rows.into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TEXT_EDIT_CARET_CLASS))
+            .with_inline_css_props(self.caret_style),
+        ].into())
+    }
+}
+
+// handle input events for the TextEdit
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId};
+    use azul_desktop::css::{CssProperty, LayoutDisplay, LayoutLeft, LayoutTop, LayoutWidth, AzString};
+    use azul_desktop::app::Clipboard;
+    use azul_core::window::{LogicalPosition, VirtualKeyCode};
+    use super::{
+        TextEditStateWrapper, TextEditOnTextChange, TextEditCursor, TextEditEditKind,
+        char_to_byte, LINE_HEIGHT, PADDING, ROWS_PER_WHEEL_NOTCH,
+    };
+    use std::string::String;
+
+    /// Container -> [rows -> [row -> [selection, label]], caret]
+    struct TextEditNodes {
+        container: DomNodeId,
+        rows: DomNodeId,
+        caret: DomNodeId,
+    }
+
+    impl TextEditNodes {
+        fn new(container: DomNodeId, info: &CallbackInfo) -> Option<Self> {
+            let rows = info.get_first_child(container)?;
+            let caret = info.get_next_sibling(rows)?;
+            Some(Self { container, rows, caret })
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_text_input(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+
+        let c = match keyboard_state.current_char.into_option().and_then(core::char::from_u32) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // newlines and tabs are handled in default_on_virtual_key_down
+        if c.is_control() || keyboard_state.ctrl_down {
+            return Update::DoNothing;
+        }
+
+        let mut text = String::new();
+        text.push(c);
+        insert_text(&mut text_edit, &text, TextEditEditKind::Insert);
+
+        on_text_changed(&mut text_edit, info.get_hit_node(), &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = info.get_hit_node();
+        let shift = keyboard_state.shift_down;
+        let ctrl = keyboard_state.ctrl_down;
+        let cursor = text_edit.inner.cursor;
+        let page = text_edit.inner.visible_rows.max(2) as isize - 1;
+
+        match last_keycode {
+
+            // -- cursor movement, shift extends the selection
+
+            VirtualKeyCode::Left => {
+                let new_cursor = match text_edit.inner.get_selection() {
+                    Some((start, _)) if !shift => start,
+                    _ => text_edit.inner.get_previous_position(cursor),
+                };
+                move_cursor(&mut text_edit, new_cursor, shift);
+            },
+            VirtualKeyCode::Right => {
+                let new_cursor = match text_edit.inner.get_selection() {
+                    Some((_, end)) if !shift => end,
+                    _ => text_edit.inner.get_next_position(cursor),
+                };
+                move_cursor(&mut text_edit, new_cursor, shift);
+            },
+            VirtualKeyCode::Up => move_cursor_rows(&mut text_edit, -1, shift),
+            VirtualKeyCode::Down => move_cursor_rows(&mut text_edit, 1, shift),
+            VirtualKeyCode::PageUp => {
+                text_edit.inner.scroll_rows(-page);
+                move_cursor_rows(&mut text_edit, -page, shift);
+            },
+            VirtualKeyCode::PageDown => {
+                text_edit.inner.scroll_rows(page);
+                move_cursor_rows(&mut text_edit, page, shift);
+            },
+            VirtualKeyCode::Home => {
+                let new_cursor = if ctrl {
+                    TextEditCursor::new(0, 0)
+                } else {
+                    let (row, _) = text_edit.inner.get_cursor_row(cursor);
+                    text_edit.inner.get_cursor_at_row(row, 0)
+                };
+                move_cursor(&mut text_edit, new_cursor, shift);
+            },
+            VirtualKeyCode::End => {
+                let new_cursor = if ctrl {
+                    let last_line = text_edit.inner.lines.len().saturating_sub(1);
+                    TextEditCursor::new(last_line, text_edit.inner.get_line_len(last_line))
+                } else {
+                    let (row, _) = text_edit.inner.get_cursor_row(cursor);
+                    text_edit.inner.get_cursor_at_row(row, usize::MAX)
+                };
+                move_cursor(&mut text_edit, new_cursor, shift);
+            },
+            VirtualKeyCode::A if ctrl => {
+                let last_line = text_edit.inner.lines.len().saturating_sub(1);
+                text_edit.inner.selection_anchor = Some(TextEditCursor::new(0, 0)).into();
+                text_edit.inner.cursor = TextEditCursor::new(last_line, text_edit.inner.get_line_len(last_line));
+            },
+
+            // -- editing
+
+            VirtualKeyCode::Back => {
+                if text_edit.inner.get_selection().is_some() {
+                    insert_text(&mut text_edit, "", TextEditEditKind::Other);
+                } else {
+                    let start = text_edit.inner.get_previous_position(cursor);
+                    text_edit.edit(start, cursor, "", TextEditEditKind::DeleteBackward);
+                }
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+            VirtualKeyCode::Delete => {
+                if text_edit.inner.get_selection().is_some() {
+                    insert_text(&mut text_edit, "", TextEditEditKind::Other);
+                } else {
+                    let end = text_edit.inner.get_next_position(cursor);
+                    text_edit.edit(cursor, end, "", TextEditEditKind::DeleteForward);
+                }
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                insert_text(&mut text_edit, "\n", TextEditEditKind::Other);
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+            VirtualKeyCode::Tab => {
+                insert_text(&mut text_edit, "\t", TextEditEditKind::Other);
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+            VirtualKeyCode::Z if ctrl && shift => {
+                if !text_edit.redo() { return Update::DoNothing; }
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+            VirtualKeyCode::Z if ctrl => {
+                if !text_edit.undo() { return Update::DoNothing; }
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+            VirtualKeyCode::Y if ctrl => {
+                if !text_edit.redo() { return Update::DoNothing; }
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+
+            // -- clipboard
+
+            VirtualKeyCode::C if ctrl => {
+                if let Some(selected) = text_edit.inner.get_selected_text() {
+                    if let Some(mut clipboard) = Clipboard::new() {
+                        clipboard.set_clipboard_string(selected.into());
+                    }
+                }
+                return Update::DoNothing;
+            },
+            VirtualKeyCode::X if ctrl => {
+                let selected = match text_edit.inner.get_selected_text() {
+                    Some(s) => s,
+                    None => return Update::DoNothing,
+                };
+                if let Some(mut clipboard) = Clipboard::new() {
+                    clipboard.set_clipboard_string(selected.into());
+                }
+                insert_text(&mut text_edit, "", TextEditEditKind::Other);
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+            VirtualKeyCode::V if ctrl => {
+                let pasted = match Clipboard::new().and_then(|c| c.get_clipboard_string()) {
+                    Some(s) => s.as_str().replace("\r\n", "\n"),
+                    None => return Update::DoNothing,
+                };
+                insert_text(&mut text_edit, &pasted, TextEditEditKind::Other);
+                return on_text_changed(&mut text_edit, container_id, &mut info);
+            },
+
+            _ => return Update::DoNothing,
+        }
+
+        text_edit.inner.scroll_to_cursor();
+        refresh(&mut text_edit, container_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_down(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = info.get_hit_node();
+        let new_cursor = match get_cursor_at_mouse(&mut text_edit, container_id, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let shift = info.get_current_keyboard_state().shift_down;
+        move_cursor(&mut text_edit, new_cursor, shift);
+        text_edit.is_selecting = true;
+
+        refresh(&mut text_edit, container_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_over(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !text_edit.is_selecting {
+            return Update::DoNothing;
+        }
+
+        // mouse button was released outside of the editor
+        if !info.get_current_mouse_state().left_down {
+            text_edit.is_selecting = false;
+            return Update::DoNothing;
+        }
+
+        let container_id = info.get_hit_node();
+        let new_cursor = match get_cursor_at_mouse(&mut text_edit, container_id, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if new_cursor == text_edit.inner.cursor {
+            return Update::DoNothing;
+        }
+
+        move_cursor(&mut text_edit, new_cursor, true);
+        refresh(&mut text_edit, container_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_up(text_edit: &mut RefAny, _: CallbackInfo) -> Update {
+        if let Some(mut text_edit) = text_edit.downcast_mut::<TextEditStateWrapper>() {
+            text_edit.is_selecting = false;
+        }
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_scroll(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (_, scroll_y) = match info.get_current_mouse_state().get_scroll_amount() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // positive values = wheel moved away from the user = scroll up
+        let rows = (-scroll_y * ROWS_PER_WHEEL_NOTCH).round() as isize;
+        let first_visible_row = text_edit.inner.first_visible_row;
+        text_edit.inner.scroll_rows(rows);
+
+        if text_edit.inner.first_visible_row != first_visible_row {
+            refresh(&mut text_edit, info.get_hit_node(), &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_focus_received(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = info.get_hit_node();
+        if let Some(nodes) = TextEditNodes::new(container_id, &info) {
+            info.set_css_property(nodes.caret, CssProperty::const_display(LayoutDisplay::Block));
+        }
+
+        refresh(&mut text_edit, container_id, &mut info);
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_focus_lost(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        if let Some(mut text_edit) = text_edit.downcast_mut::<TextEditStateWrapper>() {
+            text_edit.is_selecting = false;
+        }
+
+        if let Some(nodes) = TextEditNodes::new(info.get_hit_node(), &info) {
+            info.set_css_property(nodes.caret, CssProperty::const_display(LayoutDisplay::None));
+        }
+
+        Update::DoNothing
+    }
+
+    /// Replaces the selection (or inserts at the cursor)
+    fn insert_text(text_edit: &mut TextEditStateWrapper, text: &str, kind: TextEditEditKind) {
+        let (start, end) = text_edit.inner.get_selection().unwrap_or((text_edit.inner.cursor, text_edit.inner.cursor));
+        text_edit.edit(start, end, text, kind);
+    }
+
+    /// Moves the cursor, starts / extends the selection if `select` is set, otherwise clears it
+    fn move_cursor(text_edit: &mut TextEditStateWrapper, new_cursor: TextEditCursor, select: bool) {
+        if select {
+            if text_edit.inner.selection_anchor.is_none() {
+                text_edit.inner.selection_anchor = Some(text_edit.inner.cursor).into();
+            }
+        } else {
+            text_edit.inner.selection_anchor = None.into();
+        }
+        text_edit.inner.cursor = text_edit.inner.clamp_cursor(new_cursor);
+        text_edit.inner.preferred_column = None.into();
+    }
+
+    /// Same as `move_cursor`, but keeps the column for vertical movements
+    fn move_cursor_rows(text_edit: &mut TextEditStateWrapper, rows: isize, select: bool) {
+        let anchor = match text_edit.inner.selection_anchor.into_option() {
+            Some(s) if select => Some(s),
+            _ if select => Some(text_edit.inner.cursor),
+            _ => None,
+        };
+        text_edit.inner.move_cursor_rows(rows);
+        text_edit.inner.selection_anchor = anchor.into();
+    }
+
+    /// Hit-tests the mouse position against the `InlineText` of the row under the cursor
+    fn get_cursor_at_mouse(text_edit: &mut TextEditStateWrapper, container_id: DomNodeId, info: &CallbackInfo) -> Option<TextEditCursor> {
+
+        let cursor = info.get_cursor_relative_to_node().into_option()?;
+        let nodes = TextEditNodes::new(container_id, info)?;
+
+        let visible_rows = text_edit.inner.visible_rows.max(1);
+        let row_in_view = (((cursor.y - PADDING) / LINE_HEIGHT).floor().max(0.0) as usize).min(visible_rows - 1);
+        let row = text_edit.inner.first_visible_row + row_in_view;
+        let x = (cursor.x - PADDING).max(0.0);
+
+        let mut label_id = None;
+        let mut row_id = info.get_first_child(nodes.rows);
+        for _ in 0..row_in_view {
+            row_id = row_id.and_then(|r| info.get_next_sibling(r));
+        }
+        if let Some(row_id) = row_id {
+            label_id = info.get_first_child(row_id).and_then(|selection| info.get_next_sibling(selection));
+        }
+
+        let column = match label_id.and_then(|l| info.get_inline_text(l)) {
+            Some(inline_text) => {
+                measure_char_width(text_edit, &inline_text, row_in_view);
+                let hits = inline_text.hit_test(LogicalPosition::new(x, LINE_HEIGHT / 2.0));
+                match hits.first() {
+                    // clicked on the right half of the glyph: cursor after the glyph
+                    Some(hit) if hit.hit_relative_to_glyph.x > text_edit.char_width / 2.0 => hit.char_index_relative_to_text + 1,
+                    Some(hit) => hit.char_index_relative_to_text,
+                    // clicked after the end of the row
+                    None => (x / text_edit.char_width).round() as usize,
+                }
+            },
+            None => (x / text_edit.char_width).round() as usize,
+        };
+
+        Some(text_edit.inner.get_cursor_at_row(row, column))
+    }
+
+    /// Measures the width of a character (monospace font) from the laid out text of a row
+    fn measure_char_width(text_edit: &mut TextEditStateWrapper, inline_text: &azul_desktop::callbacks::InlineText, row_in_view: usize) {
+        let chars = match text_edit.rendered_rows.as_ref().get(row_in_view) {
+            Some(s) => s.as_str().chars().count(),
+            None => return,
+        };
+        if chars > 0 && inline_text.content_size.width > 0.0 {
+            text_edit.char_width = inline_text.content_size.width / chars as f32;
+        }
+    }
+
+    /// Refreshes the screen and calls the `on_text_change` callback
+    fn on_text_changed(text_edit: &mut TextEditStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) -> Update {
+
+        text_edit.inner.scroll_to_cursor();
+        refresh(text_edit, container_id, info);
+
+        // rustc doesn't understand the borrowing lifetime here
+        let ontextchange = &mut text_edit.on_text_change;
+        let inner = &text_edit.inner;
+
+        match ontextchange.as_mut() {
+            Some(TextEditOnTextChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Synchronizes the rendered rows, the selection and the caret with the
+    /// state, without regenerating the DOM: only the text of rows that changed
+    /// is replaced, so the cost doesn't depend on the number of lines
+    fn refresh(text_edit: &mut TextEditStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let nodes = match TextEditNodes::new(container_id, info) {
+            Some(s) => s,
+            None => return,
+        };
+
+        // re-measure the wrap column, the editor may have been resized
+        if text_edit.wrap_lines {
+            if let Some(size) = info.get_node_size(nodes.rows) {
+                let wrap_column = (size.width / text_edit.char_width).floor().max(1.0) as usize;
+                if wrap_column != text_edit.inner.wrap_column {
+                    text_edit.inner.wrap_column = wrap_column;
+                    text_edit.inner.scroll_to_cursor();
+                }
+            }
+        }
+
+        let visible_rows = text_edit.inner.visible_rows.max(1);
+        let first_row = text_edit.inner.first_visible_row;
+        let rows = text_edit.inner.get_rows(first_row..(first_row + visible_rows));
+        let selection = text_edit.inner.get_selection();
+        let char_width = text_edit.char_width;
+        let mut rendered_rows = text_edit.rendered_rows.clone().into_library_owned_vec();
+        rendered_rows.resize(visible_rows, AzString::from_const_str(""));
+
+        let mut row_id = info.get_first_child(nodes.rows);
+        for row_in_view in 0..visible_rows {
+
+            let current_row_id = match row_id {
+                Some(s) => s,
+                None => break,
+            };
+            row_id = info.get_next_sibling(current_row_id);
+
+            let selection_id = match info.get_first_child(current_row_id) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let row = rows.get(row_in_view);
+
+            let text = match row {
+                Some((line, range)) => {
+                    let line = text_edit.inner.get_line(*line);
+                    &line[char_to_byte(line, range.start)..char_to_byte(line, range.end)]
+                },
+                None => "",
+            };
+
+            if rendered_rows[row_in_view].as_str() != text {
+                if let Some(label_id) = info.get_next_sibling(selection_id) {
+                    info.set_string_contents(label_id, text.to_string().into());
+                }
+                rendered_rows[row_in_view] = text.to_string().into();
+            }
+
+            // selected columns of this row, the line break is drawn as one selected character
+            let selected = match (row, selection) {
+                (Some((line, range)), Some((start, end))) if *line >= start.line && *line <= end.line => {
+                    let sel_start = if *line == start.line { start.column.max(range.start) } else { range.start };
+                    let is_last_row_of_line = range.end == text_edit.inner.get_line_len(*line);
+                    let (sel_end, newline) = if *line == end.line {
+                        (end.column.min(range.end), false)
+                    } else {
+                        (range.end, is_last_row_of_line)
+                    };
+                    if sel_start < sel_end || (newline && sel_start <= sel_end) {
+                        Some((sel_start - range.start, sel_end - sel_start + if newline { 1 } else { 0 }))
+                    } else {
+                        None
+                    }
+                },
+                _ => None,
+            };
+
+            let (left, width) = selected.map(|(s, w)| (s as f32 * char_width, w as f32 * char_width)).unwrap_or((0.0, 0.0));
+            info.set_css_property(selection_id, CssProperty::left(LayoutLeft::px(left)));
+            info.set_css_property(selection_id, CssProperty::width(LayoutWidth::px(width)));
+        }
+
+        text_edit.rendered_rows = rendered_rows.into();
+
+        // caret
+        let (cursor_row, cursor_column) = text_edit.inner.get_cursor_row(text_edit.inner.cursor);
+        let caret_visible = cursor_row >= first_row && cursor_row < first_row + visible_rows;
+        let caret_top = PADDING + (cursor_row as f32 - first_row as f32) * LINE_HEIGHT;
+        let caret_left = PADDING + cursor_column as f32 * char_width;
+
+        if caret_visible {
+            info.set_css_property(nodes.caret, CssProperty::top(LayoutTop::px(caret_top)));
+            info.set_css_property(nodes.caret, CssProperty::left(LayoutLeft::px(caret_left)));
+            scroll_into_view(nodes.container, caret_top, info);
+        }
+    }
+
+    /// If the editor is inside a scrollable parent, scrolls the parent so that the caret is visible
+    fn scroll_into_view(container_id: DomNodeId, caret_top: f32, info: &mut CallbackInfo) {
+
+        let container_position = match info.get_node_position(container_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let caret_y = container_position.get_static_offset().1 + caret_top;
+
+        let mut parent = info.get_parent(container_id);
+        while let Some(parent_id) = parent {
+            if let (Some(scroll), Some(position), Some(size)) = (
+                info.get_scroll_position(parent_id),
+                info.get_node_position(parent_id),
+                info.get_node_size(parent_id),
+            ) {
+                let caret_y_in_parent = caret_y - position.get_static_offset().1;
+                let new_scroll_y = if caret_y_in_parent < scroll.y {
+                    caret_y_in_parent
+                } else if caret_y_in_parent + LINE_HEIGHT > scroll.y + size.height {
+                    caret_y_in_parent + LINE_HEIGHT - size.height
+                } else {
+                    return;
+                };
+                info.set_scroll_position(parent_id, LogicalPosition::new(scroll.x, new_scroll_y.max(0.0)));
+                return;
+            }
+            parent = info.get_parent(parent_id);
+        }
+    }
+}
+
+impl From<TextEdit> for Dom {
+    fn from(t: TextEdit) -> Dom {
+        t.dom()
+    }
+}
+
+/// XML component for `<text_edit text="Hello" visible_rows="10" wrap_lines="false" />`
+pub struct TextEditRenderer {
+    node: XmlNode,
+}
+
+impl TextEditRenderer {
+    pub fn new() -> Self {
+        Self { node: XmlNode::new("text_edit") }
+    }
+}
+
+impl XmlComponent for TextEditRenderer {
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        let mut args = ComponentArgumentsMap::default();
+        args.insert(String::from("text"), (String::from("String"), 0));
+        args.insert(String::from("visible_rows"), (String::from("usize"), 1));
+        args.insert(String::from("wrap_lines"), (String::from("bool"), 2));
+        ComponentArguments {
+            args,
+            accepts_text: false,
+            accepts_slot: false,
+        }
+    }
+
+    fn render_dom(&self, _: &XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent) -> Result<StyledDom, RenderDomError> {
+        let get_arg = |name: &str| args.args.get(name).map(|(v, _)| v.as_str());
+        let text = get_arg("text").unwrap_or_default();
+        let visible_rows = get_arg("visible_rows").and_then(|v| v.trim().parse::<usize>().ok()).unwrap_or(DEFAULT_VISIBLE_ROWS);
+        let wrap_lines = get_arg("wrap_lines").and_then(|v| parse_bool(v.trim())).unwrap_or(true);
+        Ok(TextEdit::new(AzString::from(text.to_string()))
+            .with_visible_rows(visible_rows)
+            .with_wrap_lines(wrap_lines)
+            .dom()
+            .style(&mut Css::empty()))
+    }
+
+    fn compile_to_rust_code(&self, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(String::from("azul::widgets::TextEdit::new(text).with_visible_rows(visible_rows).with_wrap_lines(wrap_lines).dom()"))
+    }
+
+    fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
+}
+
+#[cfg(test)]
+fn type_text(text_edit: &mut TextEditStateWrapper, text: &str) {
+    for c in text.chars() {
+        let cursor = text_edit.inner.cursor;
+        text_edit.edit(cursor, cursor, &c.to_string(), TextEditEditKind::Insert);
+    }
+}
+
+#[test]
+fn test_undo_redo_typing_is_grouped_by_word() {
+    let mut text_edit = TextEdit::new("".into()).state;
+    type_text(&mut text_edit, "hello world");
+    assert_eq!(text_edit.inner.get_text(), "hello world");
+    // the trailing space belongs to the first word
+    assert_eq!(text_edit.undo_stack.len(), 2);
+
+    assert!(text_edit.undo());
+    assert_eq!(text_edit.inner.get_text(), "hello ");
+    assert_eq!(text_edit.inner.cursor, TextEditCursor::new(0, 6));
+    assert!(text_edit.undo());
+    assert_eq!(text_edit.inner.get_text(), "");
+    assert_eq!(text_edit.inner.cursor, TextEditCursor::new(0, 0));
+    assert!(!text_edit.undo());
+
+    assert!(text_edit.redo());
+    assert!(text_edit.redo());
+    assert_eq!(text_edit.inner.get_text(), "hello world");
+    assert_eq!(text_edit.inner.cursor, TextEditCursor::new(0, 11));
+    assert!(!text_edit.redo());
+
+    // a new edit clears the redo stack
+    assert!(text_edit.undo());
+    type_text(&mut text_edit, "!");
+    assert_eq!(text_edit.inner.get_text(), "hello !");
+    assert!(!text_edit.redo());
+}
+
+#[test]
+fn test_undo_redo_deletes_are_grouped() {
+    let mut text_edit = TextEdit::new("abcdef".into()).state;
+
+    // two backspaces at the end of the line
+    text_edit.inner.cursor = TextEditCursor::new(0, 6);
+    for _ in 0..2 {
+        let cursor = text_edit.inner.cursor;
+        let previous = text_edit.inner.get_previous_position(cursor);
+        text_edit.edit(previous, cursor, "", TextEditEditKind::DeleteBackward);
+    }
+    assert_eq!(text_edit.inner.get_text(), "abcd");
+
+    // two deletes after the "a"
+    text_edit.inner.cursor = TextEditCursor::new(0, 1);
+    for _ in 0..2 {
+        let cursor = text_edit.inner.cursor;
+        let next = text_edit.inner.get_next_position(cursor);
+        text_edit.edit(cursor, next, "", TextEditEditKind::DeleteForward);
+    }
+    assert_eq!(text_edit.inner.get_text(), "ad");
+    assert_eq!(text_edit.undo_stack.len(), 2);
+
+    assert!(text_edit.undo());
+    assert_eq!(text_edit.inner.get_text(), "abcd");
+    assert_eq!(text_edit.inner.cursor, TextEditCursor::new(0, 1));
+    assert!(text_edit.undo());
+    assert_eq!(text_edit.inner.get_text(), "abcdef");
+    assert_eq!(text_edit.inner.cursor, TextEditCursor::new(0, 6));
+
+    // pastes are never grouped, not even with each other
+    let cursor = text_edit.inner.cursor;
+    text_edit.edit(cursor, cursor, "\nxy", TextEditEditKind::Other);
+    let cursor = text_edit.inner.cursor;
+    text_edit.edit(cursor, cursor, "z", TextEditEditKind::Other);
+    assert_eq!(text_edit.inner.get_text(), "abcdef\nxyz");
+    assert!(text_edit.undo());
+    assert_eq!(text_edit.inner.get_text(), "abcdef\nxy");
+    assert!(text_edit.undo());
+    assert_eq!(text_edit.inner.get_text(), "abcdef");
+}
+
+#[test]
+fn test_wrap_line() {
+    assert_eq!(wrap_line("aaaa bbbb cccc", 0), vec![0..14]);
+    assert_eq!(wrap_line("aaaa bbbb cccc", 5), vec![0..5, 5..10, 10..14]);
+    // no whitespace: break in the middle of the word
+    assert_eq!(wrap_line("abcdefgh", 3), vec![0..3, 3..6, 6..8]);
+}
+
+#[test]
+fn test_move_cursor_across_wrapped_lines() {
+    let mut state = TextEditState::new("aaaa bbbb cccc\nxy");
+    state.wrap_column = 5;
+    assert_eq!(state.get_total_rows(), 4);
+    assert_eq!(state.get_rows(1..3), vec![(0, 5..10), (0, 10..14)]);
+
+    assert_eq!(state.get_cursor_row(TextEditCursor::new(0, 7)), (1, 2));
+    // a cursor at the end of a wrapped row is at the start of the next row
+    assert_eq!(state.get_cursor_row(TextEditCursor::new(0, 5)), (1, 0));
+    assert_eq!(state.get_cursor_row(TextEditCursor::new(0, 14)), (2, 4));
+    assert_eq!(state.get_cursor_row(TextEditCursor::new(1, 1)), (3, 1));
+    assert_eq!(state.get_cursor_at_row(0, 10), TextEditCursor::new(0, 4));
+
+    state.cursor = TextEditCursor::new(0, 9);
+    state.move_cursor_rows(1);
+    assert_eq!(state.cursor, TextEditCursor::new(0, 14));
+    state.move_cursor_rows(1);
+    assert_eq!(state.cursor, TextEditCursor::new(1, 2));
+    // moving back up returns to the preferred column
+    state.move_cursor_rows(-1);
+    assert_eq!(state.cursor, TextEditCursor::new(0, 14));
+    state.move_cursor_rows(-1);
+    assert_eq!(state.cursor, TextEditCursor::new(0, 9));
+    state.move_cursor_rows(-10);
+    assert_eq!(state.cursor, TextEditCursor::new(0, 4));
+    state.move_cursor_rows(10);
+    assert_eq!(state.cursor, TextEditCursor::new(1, 2));
+}
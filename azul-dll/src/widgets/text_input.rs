@@ -11,7 +11,7 @@ use azul_desktop::{
     },
     callbacks::{RefAny, Callback, CallbackInfo, Update},
 };
-use azul_core::window::{KeyboardState, VirtualKeyCode};
+use azul_core::window::{KeyboardState, VirtualKeyCode, OptionChar, OptionImeComposition};
 use std::vec::Vec;
 use std::string::String;
 
@@ -22,6 +22,8 @@ pub struct TextInput {
     pub placeholder_style: NodeDataInlineCssPropertyVec,
     pub container_style: NodeDataInlineCssPropertyVec,
     pub label_style: NodeDataInlineCssPropertyVec,
+    /// Style of the composition text of the input method (IME)
+    pub preedit_style: NodeDataInlineCssPropertyVec,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -38,6 +40,74 @@ pub enum TextInputValid {
     No,
 }
 
+/// Result of running the validators of a `TextInput`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C, u8)]
+pub enum TextInputValidationResult {
+    Valid,
+    /// Text is invalid, contains the error message
+    Invalid(AzString),
+}
+
+impl TextInputValidationResult {
+    pub fn is_valid(&self) -> bool {
+        *self == TextInputValidationResult::Valid
+    }
+}
+
+/// Validator that is run on every change of the text,
+/// an empty text is only rejected by `NotEmpty`
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C, u8)]
+pub enum TextInputValidator {
+    /// Text must not be empty
+    NotEmpty,
+    /// Optionally signed whole number (`-12`)
+    Integer,
+    /// Any (finite) floating-point number (`-1.5e3`)
+    Number,
+    /// E-mail address (`name@example.com`)
+    Email,
+    /// IPv4 address (`192.168.0.1`)
+    Ipv4,
+    /// Text must have at least N characters
+    MinLength(usize),
+    /// User-defined validator
+    Custom(TextInputCustomValidator),
+}
+
+impl_vec!(TextInputValidator, TextInputValidatorVec, TextInputValidatorVecDestructor);
+impl_vec_debug!(TextInputValidator, TextInputValidatorVec);
+impl_vec_clone!(TextInputValidator, TextInputValidatorVec, TextInputValidatorVecDestructor);
+impl_vec_partialeq!(TextInputValidator, TextInputValidatorVec);
+impl_vec_mut!(TextInputValidator, TextInputValidatorVec);
+
+pub type TextInputValidatorCallbackType = extern "C" fn(&mut RefAny, &TextInputState) -> TextInputValidationResult;
+#[repr(C)]
+pub struct TextInputValidatorCallback { pub cb: TextInputValidatorCallbackType }
+impl_callback!(TextInputValidatorCallback);
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextInputCustomValidator {
+    pub data: RefAny,
+    pub callback: TextInputValidatorCallback,
+}
+
+/// Input mask, restricts which characters can be typed at which position.
+///
+/// Each character of the `pattern` is either a placeholder - `9` (digit),
+/// `#` (optional digit), `a` (letter), `*` (letter or digit) - or a literal,
+/// which is inserted automatically while typing. For example `99/99/9999`
+/// for a date or `9##.9##.9##.9##` for an IPv4 address.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct TextInputMask {
+    pub pattern: AzString,
+}
+
+impl_option!(TextInputMask, OptionTextInputMask, copy = false, [Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord]);
+
 // The text input field has a special return which specifies
 // whether the text input should handle the character
 pub type TextInputOnTextInputCallbackType = extern "C" fn(&mut RefAny, &TextInputState, &mut CallbackInfo) -> OnTextInputReturn;
@@ -55,6 +125,12 @@ pub type TextInputOnFocusLostCallbackType = extern "C" fn(&mut RefAny, &TextInpu
 pub struct TextInputOnFocusLostCallback { pub cb: TextInputOnFocusLostCallbackType }
 impl_callback!(TextInputOnFocusLostCallback);
 
+// Called when the text switches between valid and invalid (or the error message changes)
+pub type TextInputOnValidationChangeCallbackType = extern "C" fn(&mut RefAny, &TextInputState, &mut CallbackInfo) -> Update;
+#[repr(C)]
+pub struct TextInputOnValidationChangeCallback { pub cb: TextInputOnValidationChangeCallbackType }
+impl_callback!(TextInputOnValidationChangeCallback);
+
 #[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct TextInputState {
@@ -63,6 +139,16 @@ pub struct TextInputState {
     pub max_len: usize,
     pub selection: OptionTextInputSelection,
     pub cursor_pos: usize,
+    /// Validators that are run on every change of the text
+    pub validators: TextInputValidatorVec,
+    /// Optional input mask (dates, phone numbers, ...)
+    pub mask: OptionTextInputMask,
+    /// Password mode: if set, every character is displayed as this character
+    pub obscure_char: OptionChar,
+    /// Result of the last validation
+    pub validation: TextInputValidationResult,
+    /// Text that is currently being composed by the input method (not part of the `text` yet)
+    pub ime_composition: OptionImeComposition,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -74,6 +160,11 @@ pub struct TextInputStateWrapper {
     pub on_focus_lost: OptionTextInputOnFocusLost,
     pub update_text_input_before_calling_focus_lost_fn: bool,
     pub update_text_input_before_calling_vk_down_fn: bool,
+    pub on_validation_change: OptionTextInputOnValidationChange,
+    /// Properties that are set on the container while the text is invalid
+    pub error_style: CssPropertyVec,
+    /// Properties of the container that were overwritten by the `error_style`
+    pub style_before_error: CssPropertyVec,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -103,11 +194,21 @@ pub struct TextInputOnFocusLost {
 
 impl_option!(TextInputOnFocusLost, OptionTextInputOnFocusLost, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
 
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextInputOnValidationChange {
+    pub data: RefAny,
+    pub callback: TextInputOnValidationChangeCallback,
+}
+
+impl_option!(TextInputOnValidationChange, OptionTextInputOnValidationChange, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
 const BACKGROUND_COLOR: ColorU = ColorU { r: 255,  g: 255,  b: 255,  a: 255 }; // white
 const TEXT_COLOR: StyleTextColor = StyleTextColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }; // black
 const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
 const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
 const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const COLOR_E81123: ColorU = ColorU { r: 232, g: 17, b: 35, a: 255 }; // #e81123
 const BACKGROUND_THEME_LIGHT: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BACKGROUND_COLOR)];
 const BACKGROUND_COLOR_LIGHT: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(BACKGROUND_THEME_LIGHT);
 
@@ -122,6 +223,7 @@ const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_sli
 static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
     Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
@@ -171,6 +273,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
     Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
@@ -226,6 +329,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
     Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
@@ -295,6 +399,35 @@ static TEXT_INPUT_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
 ];
 
+// -- composition (IME) text style
+
+static TEXT_INPUT_PREEDIT_PROPS: &[NodeDataInlineCssProperty] = &[
+    // hidden until the input method starts composing
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(1))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(2))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    // underline the text that is being composed
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4C4C4C })),
+];
+
+// -- error style (border-color: #e81123;)
+
+static TEXT_INPUT_ERROR_PROPS: &[CssProperty] = &[
+    CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_E81123 }),
+    CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_E81123 }),
+    CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_E81123 }),
+    CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_E81123 }),
+];
+
+/// Character that is displayed instead of the text in password mode
+pub const DEFAULT_OBSCURE_CHAR: char = '\u{2022}'; // •
+
 impl Default for TextInput {
     fn default() -> Self {
         TextInput {
@@ -302,6 +435,7 @@ impl Default for TextInput {
             placeholder_style: Vec::new().into(), // TEXT_INPUT_PLACEHOLDER_PROPS
             container_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_CONTAINER_PROPS),
             label_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_LABEL_PROPS),
+            preedit_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_PREEDIT_PROPS),
         }
     }
 }
@@ -314,6 +448,11 @@ impl Default for TextInputState {
             max_len: 50,
             selection: None.into(),
             cursor_pos: 0,
+            validators: TextInputValidatorVec::from_const_slice(&[]),
+            mask: None.into(),
+            obscure_char: None.into(),
+            validation: TextInputValidationResult::Valid,
+            ime_composition: None.into(),
         }
     }
 }
@@ -327,6 +466,9 @@ impl Default for TextInputStateWrapper {
             on_focus_lost: None.into(),
             update_text_input_before_calling_focus_lost_fn: true,
             update_text_input_before_calling_vk_down_fn: true,
+            on_validation_change: None.into(),
+            error_style: CssPropertyVec::from_const_slice(TEXT_INPUT_ERROR_PROPS),
+            style_before_error: CssPropertyVec::from_const_slice(&[]),
         }
     }
 }
@@ -367,6 +509,53 @@ impl TextInput {
         }).into();
     }
 
+    pub fn set_on_validation_change(&mut self, data: RefAny, callback: TextInputOnValidationChangeCallbackType) {
+        self.state.on_validation_change = Some(TextInputOnValidationChange {
+            callback: TextInputOnValidationChangeCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn add_validator(&mut self, validator: TextInputValidator) {
+        let mut validators = self.state.inner.validators.clone().into_library_owned_vec();
+        validators.push(validator);
+        self.state.inner.validators = validators.into();
+    }
+
+    pub fn with_validator(mut self, validator: TextInputValidator) -> Self {
+        self.add_validator(validator);
+        self
+    }
+
+    pub fn set_mask(&mut self, mask: TextInputMask) {
+        self.state.inner.mask = Some(mask).into();
+    }
+
+    pub fn with_mask(mut self, mask: TextInputMask) -> Self {
+        self.set_mask(mask);
+        self
+    }
+
+    /// Displays every character as `obscure_char` (`None` = display the text)
+    pub fn set_obscure_char(&mut self, obscure_char: OptionChar) {
+        self.state.inner.obscure_char = obscure_char;
+    }
+
+    /// Shorthand for `set_obscure_char(Some('•'))`
+    pub fn with_password_mode(mut self) -> Self {
+        self.set_obscure_char(Some(DEFAULT_OBSCURE_CHAR as u32).into());
+        self
+    }
+
+    /// Sets the properties that are applied to the container while the text is invalid
+    pub fn set_error_style(&mut self, style: CssPropertyVec) {
+        self.state.error_style = style;
+    }
+
+    pub fn set_preedit_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.preedit_style = style;
+    }
+
     pub fn set_placeholder_style(&mut self, style: NodeDataInlineCssPropertyVec) {
         self.placeholder_style = style;
     }
@@ -394,16 +583,32 @@ impl TextInput {
         };
 
         self.state.inner.cursor_pos = self.state.inner.text.len();
+        self.state.inner.ime_composition = None.into();
+
+        let label_text = self.state.inner.get_display_text();
 
-        let label_text: String = self.state.inner.text.iter().filter_map(|s| {
-            core::char::from_u32(*s)
-        }).collect();
+        // initial validation: apply the error style right away
+        self.state.inner.validation = self.state.inner.validate();
+        let container_style = if self.state.inner.validation.is_valid() {
+            self.container_style
+        } else {
+            let mut container_style = self.container_style.into_library_owned_vec();
+            self.state.style_before_error = self.state.error_style.iter().map(|error_prop| {
+                let prop_type = error_prop.get_type();
+                container_style.iter().find_map(|p| match p {
+                    Normal(p) if p.get_type() == prop_type => Some(p.clone()),
+                    _ => None,
+                }).unwrap_or(CssProperty::initial(prop_type))
+            }).collect::<Vec<_>>().into();
+            container_style.extend(self.state.error_style.iter().map(|p| Normal(p.clone())));
+            container_style.into()
+        };
 
         let state_ref = RefAny::new(self.state);
 
         Dom::div()
         .with_ids_and_classes(vec![Class("__azul-native-text-input-container".into())].into())
-        .with_inline_css_props(self.container_style)
+        .with_inline_css_props(container_style)
         .with_tab_index(TabIndex::Auto)
         .with_dataset(Some(state_ref.clone()).into())
         .with_callbacks(vec![
@@ -427,6 +632,11 @@ impl TextInput {
                 data: state_ref.clone(),
                 callback: Callback { cb: self::input::default_on_virtual_key_down }
             },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::ImeComposition),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_ime_composition }
+            },
             CallbackData {
                 event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
                 data: state_ref.clone(),
@@ -437,6 +647,9 @@ impl TextInput {
             Dom::text(label_text)
             .with_ids_and_classes(vec![Class("__azul-native-text-input-label".into())].into())
             .with_inline_css_props(self.label_style),
+            Dom::text(AzString::from_const_str(""))
+            .with_ids_and_classes(vec![Class("__azul-native-text-input-preedit".into())].into())
+            .with_inline_css_props(self.preedit_style),
             // let cursor = Dom::div().with_class("__azul-native-text-input-cursor");
             // let text_selection = Dom::div().with_class("__azul-native-text-input-selection".into());
         ].into())
@@ -477,26 +690,109 @@ impl TextInputState {
         .collect()
     }
 
+    /// Returns the text as it is displayed (obscured in password mode)
+    pub fn get_display_text(&self) -> String {
+        match self.obscure_char.into_option().and_then(core::char::from_u32) {
+            Some(obscure_char) => core::iter::repeat(obscure_char).take(self.text.len()).collect(),
+            None => self.get_text(),
+        }
+    }
+
+    /// Runs the mask and the validators, returns the first error
+    pub fn validate(&self) -> TextInputValidationResult {
+
+        use self::TextInputValidationResult::*;
+
+        let text = self.get_text();
+
+        if let Some(mask) = self.mask.as_ref() {
+            if !text.is_empty() && !mask.is_complete(self.text.as_ref()) {
+                return Invalid(AzString::from_const_str("The input is incomplete"));
+            }
+        }
+
+        for validator in self.validators.iter() {
+
+            // only NotEmpty rejects an empty text, so that optional fields can be validated
+            let is_valid = match validator {
+                TextInputValidator::NotEmpty => !text.trim().is_empty(),
+                _ if text.is_empty() => true,
+                TextInputValidator::Integer => is_integer(&text),
+                TextInputValidator::Number => text.trim().parse::<f64>().map(|f| f.is_finite()).unwrap_or(false),
+                TextInputValidator::Email => is_email(&text),
+                TextInputValidator::Ipv4 => is_ipv4(&text),
+                TextInputValidator::MinLength(min) => self.text.len() >= *min,
+                TextInputValidator::Custom(custom) => {
+                    let mut data = custom.data.clone();
+                    match (custom.callback.cb)(&mut data, self) {
+                        Valid => true,
+                        invalid => return invalid,
+                    }
+                },
+            };
+
+            if !is_valid {
+                return Invalid(match validator {
+                    TextInputValidator::NotEmpty => AzString::from_const_str("This field is required"),
+                    TextInputValidator::Integer => AzString::from_const_str("Please enter a whole number"),
+                    TextInputValidator::Number => AzString::from_const_str("Please enter a number"),
+                    TextInputValidator::Email => AzString::from_const_str("Please enter a valid e-mail address"),
+                    TextInputValidator::Ipv4 => AzString::from_const_str("Please enter a valid IPv4 address"),
+                    TextInputValidator::MinLength(min) => format!("Please enter at least {} characters", min).into(),
+                    TextInputValidator::Custom(_) => AzString::from_const_str("Invalid input"),
+                });
+            }
+        }
+
+        Valid
+    }
+
+    // Sets the text that is currently composed by the input method, returns the text of the
+    // preedit node (empty if the composition ended). The composition is not part of the `text`
+    // and not validated until the input method commits it as regular text input.
+    fn set_ime_composition(&mut self, ime_composition: OptionImeComposition) -> AzString {
+        let preedit_text = ime_composition.as_ref()
+            .map(|composition| composition.text.clone())
+            .unwrap_or_else(|| AzString::from_const_str(""));
+        self.ime_composition = ime_composition;
+        preedit_text
+    }
+
     fn handle_on_text_input(&mut self, c: char) {
-        match self.selection.clone().into_option() {
-            None => {
-                if self.cursor_pos == self.text.len() {
-                    self.text.push(c as u32);
-                } else {
-                    // TODO: insert character at the cursor location!
-                    self.text.insert(self.cursor_pos, c as u32);
-                }
-                self.cursor_pos = self.cursor_pos.saturating_add(1).min(self.text.len());
+
+        let text_len = self.text.len();
+        let range = match self.selection.clone().into_option() {
+            None => self.cursor_pos.min(text_len)..self.cursor_pos.min(text_len),
+            Some(selection) => {
+                let Range { start, end } = selection.get_range(text_len);
+                start.min(text_len)..end.min(text_len)
             },
-            Some(TextInputSelection::All) => {
-                self.text = vec![c as u32].into();
-                self.cursor_pos = 1;
-                self.selection = None.into();
+        };
+
+        let inserted = match self.mask.as_ref() {
+            // typing at the end: insert the literals of the mask automatically
+            Some(mask) if range.end == text_len => {
+                match mask.get_chars_to_append(&self.text.as_ref()[..range.start], c) {
+                    Some(s) => s,
+                    None => return,
+                }
             },
-            Some(TextInputSelection::FromTo(range)) => {
-                self.delete_selection(range.from..range.to, Some(c));
+            Some(mask) => {
+                let mut new_text = self.text.clone().into_library_owned_vec();
+                new_text.splice(range.clone(), core::iter::once(c as u32));
+                if !mask.matches(&new_text) {
+                    return;
+                }
+                vec![c as u32]
             },
-        }
+            None => vec![c as u32],
+        };
+
+        let mut text = self.text.clone().into_library_owned_vec();
+        self.cursor_pos = range.start + inserted.len();
+        text.splice(range, inserted);
+        self.text = text.into();
+        self.selection = None.into();
     }
 
     fn handle_on_virtual_key_down(
@@ -619,15 +915,137 @@ impl TextInputState {
     }
 }
 
+fn is_integer(text: &str) -> bool {
+    let digits = text.trim().trim_start_matches(|c| c == '-' || c == '+');
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_email(text: &str) -> bool {
+    let text = text.trim();
+    let mut parts = text.split('@');
+    let (local, domain) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(local), Some(domain), None) => (local, domain),
+        _ => return false,
+    };
+    !local.is_empty() &&
+    !text.contains(char::is_whitespace) &&
+    domain.contains('.') &&
+    !domain.starts_with('.') &&
+    !domain.ends_with('.')
+}
+
+fn is_ipv4(text: &str) -> bool {
+    let parts = text.trim().split('.').collect::<Vec<_>>();
+    parts.len() == 4 && parts.iter().all(|p| {
+        !p.is_empty() && p.len() <= 3 && p.chars().all(|c| c.is_ascii_digit()) && p.parse::<u8>().is_ok()
+    })
+}
+
+impl TextInputMask {
+
+    pub const fn new(pattern: AzString) -> Self {
+        Self { pattern }
+    }
+
+    /// `99/99/9999`
+    pub const fn date() -> Self {
+        Self::new(AzString::from_const_str("99/99/9999"))
+    }
+
+    /// `99:99`
+    pub const fn time() -> Self {
+        Self::new(AzString::from_const_str("99:99"))
+    }
+
+    /// `(999) 999-9999`
+    pub const fn phone() -> Self {
+        Self::new(AzString::from_const_str("(999) 999-9999"))
+    }
+
+    /// `9##.9##.9##.9##`
+    pub const fn ipv4() -> Self {
+        Self::new(AzString::from_const_str("9##.9##.9##.9##"))
+    }
+
+    // Returns whether the placeholder accepts the character, `None` if the pattern character is a literal
+    fn placeholder_accepts(placeholder: char, c: char) -> Option<bool> {
+        match placeholder {
+            '9' | '#' => Some(c.is_ascii_digit()),
+            'a' => Some(c.is_alphabetic()),
+            '*' => Some(c.is_alphanumeric()),
+            _ => None,
+        }
+    }
+
+    // Matches `c` at the position `pos` of the pattern, returns the literals
+    // that have to be inserted in front of `c` and the position after `c`
+    fn match_char(pattern: &[char], mut pos: usize, c: char, insert_literals: bool) -> Option<(Vec<u32>, usize)> {
+        let mut literals = Vec::new();
+        loop {
+            let p = *pattern.get(pos)?;
+            match Self::placeholder_accepts(p, c) {
+                Some(true) => return Some((literals, pos + 1)),
+                // skip optional digits
+                Some(false) if p == '#' => { pos += 1; },
+                Some(false) => return None,
+                None if p == c => return Some((literals, pos + 1)),
+                None if insert_literals => {
+                    literals.push(p as u32);
+                    pos += 1;
+                },
+                None => return None,
+            }
+        }
+    }
+
+    // Returns the position in the pattern after the `text`, `None` if the text doesn't match the pattern
+    fn match_prefix(&self, text: &[u32]) -> Option<usize> {
+        let pattern = self.pattern.as_str().chars().collect::<Vec<_>>();
+        let mut pos = 0;
+        for c in text.iter().filter_map(|c| core::char::from_u32(*c)) {
+            pos = Self::match_char(&pattern, pos, c, false)?.1;
+        }
+        Some(pos)
+    }
+
+    /// Returns whether the text is a (possibly incomplete) input of the mask
+    pub fn matches(&self, text: &[u32]) -> bool {
+        self.match_prefix(text).is_some()
+    }
+
+    /// Returns whether all non-optional placeholders of the mask are filled
+    pub fn is_complete(&self, text: &[u32]) -> bool {
+        match self.match_prefix(text) {
+            Some(pos) => self.pattern.as_str().chars().skip(pos).all(|c| c == '#'),
+            None => false,
+        }
+    }
+
+    /// Returns the characters to append if `c` is typed at the end of the `text`
+    /// (`c`, preceded by the literals of the pattern), `None` if `c` is rejected
+    pub fn get_chars_to_append(&self, text: &[u32], c: char) -> Option<Vec<u32>> {
+        let pattern = self.pattern.as_str().chars().collect::<Vec<_>>();
+        let pos = self.match_prefix(text)?;
+        let (mut chars, _) = Self::match_char(&pattern, pos, c, true)?;
+        chars.push(c as u32);
+        Some(chars)
+    }
+}
+
 // handle input events for the TextInput
 mod input {
 
-    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update};
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId};
+    use azul_desktop::css::{CssProperty, LayoutDisplay, LayoutLeft};
+    use azul_core::window::{ImePosition, LogicalPosition};
     use super::{
         TextInputStateWrapper, OnTextInputReturn, TextInputValid,
         TextInputOnTextInput, TextInputOnFocusLost, TextInputOnVirtualKeyDown,
+        TextInputOnValidationChange,
     };
-    use std::string::String;
+
+    /// Left padding of the container, the composition text is positioned after the label
+    const PADDING_LEFT: f32 = 2.0;
 
     pub(in super) extern "C" fn default_on_text_input(text_input: &mut RefAny, mut info: CallbackInfo) -> Update {
 
@@ -677,15 +1095,15 @@ mod input {
 
         // Update the string, cursor position on the screen and selection background
         // TODO: restart the timer for cursor blinking
-        let text_now: String = text_input.inner.text.iter()
-            .filter_map(|s| core::char::from_u32(*s))
-            .collect();
-
-        info.set_string_contents(label_node_id, text_now.into());
+        info.set_string_contents(label_node_id, text_input.inner.get_display_text().into());
         // info.set_css_property(cursor_node_id, CssProperty::const_transform(get_cursor_transform(info.get_text_contents()[self.cursor_pos])))
         // info.update_image(selection_node_id, render_selection(self.selection));
 
-        result.update
+        let container_id = info.get_hit_node();
+        let mut update = result.update;
+        update.max_self(update_validation(&mut text_input, container_id, &mut info));
+        update_ime_position(container_id, &mut info);
+        update
     }
 
     pub(in super) extern "C" fn default_on_virtual_key_down(text_input: &mut RefAny, mut info: CallbackInfo) -> Update {
@@ -700,6 +1118,11 @@ mod input {
             None => return Update::DoNothing,
         };
 
+        // keys are handled by the input method while it is composing
+        if text_input.inner.ime_composition.is_some() {
+            return Update::DoNothing;
+        }
+
         let kb_state = info.get_current_keyboard_state();
 
         let result = {
@@ -724,7 +1147,14 @@ mod input {
             text_input.inner.handle_on_virtual_key_down(last_keycode, &kb_state, &mut info);
         }
 
-        result.update
+        let container_id = info.get_hit_node();
+        if let Some(label_node_id) = info.get_first_child(container_id) {
+            info.set_string_contents(label_node_id, text_input.inner.get_display_text().into());
+        }
+
+        let mut update = result.update;
+        update.max_self(update_validation(&mut text_input, container_id, &mut info));
+        update
     }
 
     pub(in super) extern "C" fn default_on_container_click(text_input: &mut RefAny, info: CallbackInfo) -> Update {
@@ -745,16 +1175,116 @@ mod input {
         Update::DoNothing
     }
 
-    pub(in super) extern "C" fn default_on_focus_received(text_input: &mut RefAny, info: CallbackInfo) -> Update {
+    pub(in super) extern "C" fn default_on_focus_received(text_input: &mut RefAny, mut info: CallbackInfo) -> Update {
         let mut text_input = match text_input.downcast_mut::<TextInputStateWrapper>() {
             Some(s) => s,
             None => return Update::DoNothing,
         };
 
+        // the IME candidate window has to be positioned before the composition starts
+        update_ime_position(info.get_hit_node(), &mut info);
+
         // TODO: start text cursor blinking
         Update::DoNothing
     }
 
+    pub(in super) extern "C" fn default_on_ime_composition(text_input: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_input = match text_input.downcast_mut::<TextInputStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = info.get_hit_node();
+        let label_node_id = match info.get_first_child(container_id) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+        let preedit_node_id = match info.get_next_sibling(label_node_id) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let preedit_text = text_input.inner.set_ime_composition(info.get_current_keyboard_state().ime_composition);
+        info.set_string_contents(preedit_node_id, preedit_text);
+
+        if text_input.inner.ime_composition.is_some() {
+            // the composition text is rendered after the text of the label
+            let text_width = info.get_inline_text(label_node_id).map(|t| t.content_size.width).unwrap_or(0.0);
+            info.set_css_property(preedit_node_id, CssProperty::left(LayoutLeft::px(PADDING_LEFT + text_width)));
+            info.set_css_property(preedit_node_id, CssProperty::const_display(LayoutDisplay::Block));
+        } else {
+            info.set_css_property(preedit_node_id, CssProperty::const_display(LayoutDisplay::None));
+        }
+
+        update_ime_position(container_id, &mut info);
+
+        Update::DoNothing
+    }
+
+    /// Moves the candidate window of the input method below the end of the text
+    fn update_ime_position(container_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let label_node_id = match info.get_first_child(container_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let (x, y) = match info.get_node_position(container_id) {
+            Some(s) => s.get_static_offset(),
+            None => return,
+        };
+
+        // NOTE: the label is laid out again after the callback, so the width
+        // lags behind by the last character, which is good enough for positioning
+        let height = info.get_node_size(container_id).map(|s| s.height).unwrap_or(0.0);
+        let text_width = info.get_inline_text(label_node_id).map(|t| t.content_size.width).unwrap_or(0.0);
+        let new_ime_position = ImePosition::Initialized(LogicalPosition::new(x + PADDING_LEFT + text_width, y + height));
+
+        let mut window_state = info.get_current_window_state();
+        if window_state.ime_position != new_ime_position {
+            window_state.ime_position = new_ime_position;
+            info.set_window_state(window_state);
+        }
+    }
+
+    /// Runs the validators, switches the container between the normal and the
+    /// error style and calls `on_validation_change` if the result changed
+    fn update_validation(text_input: &mut TextInputStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) -> Update {
+
+        let validation = text_input.inner.validate();
+        if validation == text_input.inner.validation {
+            return Update::DoNothing;
+        }
+
+        let was_valid = text_input.inner.validation.is_valid();
+        let is_valid = validation.is_valid();
+        text_input.inner.validation = validation;
+
+        if was_valid && !is_valid {
+            text_input.style_before_error = text_input.error_style.iter().map(|error_prop| {
+                let prop_type = error_prop.get_type();
+                info.get_computed_css_property(container_id, prop_type).unwrap_or(CssProperty::initial(prop_type))
+            }).collect::<Vec<_>>().into();
+            for prop in text_input.error_style.iter() {
+                info.set_css_property(container_id, prop.clone());
+            }
+        } else if !was_valid && is_valid {
+            for prop in text_input.style_before_error.iter() {
+                info.set_css_property(container_id, prop.clone());
+            }
+        }
+
+        // rustc doesn't understand the borrowing lifetime here
+        let onvalidationchange = &mut text_input.on_validation_change;
+        let inner = &text_input.inner;
+
+        match onvalidationchange.as_mut() {
+            Some(TextInputOnValidationChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
     pub(in super) extern "C" fn default_on_focus_lost(text_input: &mut RefAny, mut info: CallbackInfo) -> Update {
         let mut text_input = match text_input.downcast_mut::<TextInputStateWrapper>() {
             Some(s) => s,
@@ -781,4 +1311,170 @@ impl From<TextInput> for Dom {
     fn from(t: TextInput) -> Dom {
         t.dom()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+fn test_state(text: &str) -> TextInputState {
+    TextInputState {
+        text: text.chars().map(|c| c as u32).collect::<Vec<_>>().into(),
+        cursor_pos: text.chars().count(),
+        .. Default::default()
+    }
+}
+
+#[cfg(test)]
+fn validate(text: &str, validators: Vec<TextInputValidator>) -> TextInputValidationResult {
+    let mut state = test_state(text);
+    state.validators = validators.into();
+    state.validate()
+}
+
+#[test]
+fn test_validators() {
+    use self::TextInputValidator::*;
+    use self::TextInputValidationResult::*;
+
+    assert_eq!(validate("", vec![NotEmpty]), Invalid("This field is required".into()));
+    assert_eq!(validate("  ", vec![NotEmpty]), Invalid("This field is required".into()));
+    assert_eq!(validate("x", vec![NotEmpty]), Valid);
+
+    // only NotEmpty rejects an empty text
+    for validator in vec![Integer, Number, Email, Ipv4, MinLength(3)] {
+        assert_eq!(validate("", vec![validator]), Valid);
+    }
+
+    assert_eq!(validate("-12", vec![Integer]), Valid);
+    assert_eq!(validate("1.5", vec![Integer]), Invalid("Please enter a whole number".into()));
+    assert_eq!(validate("-", vec![Integer]), Invalid("Please enter a whole number".into()));
+
+    assert_eq!(validate("-1.5e3", vec![Number]), Valid);
+    assert_eq!(validate("inf", vec![Number]), Invalid("Please enter a number".into()));
+
+    assert_eq!(validate("name@example.com", vec![Email]), Valid);
+    for invalid in &["name@example", "@example.com", "a@b@example.com", "na me@example.com", "name@.com"] {
+        assert_eq!(validate(invalid, vec![Email]), Invalid("Please enter a valid e-mail address".into()));
+    }
+
+    assert_eq!(validate("192.168.0.1", vec![Ipv4]), Valid);
+    for invalid in &["256.1.1.1", "1.2.3", "1.2.3.4.5", "1..2.3", "a.b.c.d"] {
+        assert_eq!(validate(invalid, vec![Ipv4]), Invalid("Please enter a valid IPv4 address".into()));
+    }
+
+    assert_eq!(validate("ab", vec![MinLength(3)]), Invalid("Please enter at least 3 characters".into()));
+    assert_eq!(validate("abc", vec![MinLength(3)]), Valid);
+
+    // the first failing validator wins
+    assert_eq!(validate("ab", vec![Integer, MinLength(3)]), Invalid("Please enter a whole number".into()));
+    assert_eq!(validate("12", vec![Integer, MinLength(3)]), Invalid("Please enter at least 3 characters".into()));
+}
+
+#[test]
+fn test_custom_validator() {
+
+    extern "C" fn reject_admin(_: &mut RefAny, state: &TextInputState) -> TextInputValidationResult {
+        if state.get_text() == "admin" {
+            TextInputValidationResult::Invalid("This name is reserved".into())
+        } else {
+            TextInputValidationResult::Valid
+        }
+    }
+
+    let custom = TextInputValidator::Custom(TextInputCustomValidator {
+        data: RefAny::new(()),
+        callback: TextInputValidatorCallback { cb: reject_admin },
+    });
+
+    assert_eq!(validate("user", vec![custom.clone()]), TextInputValidationResult::Valid);
+    assert_eq!(validate("admin", vec![custom]), TextInputValidationResult::Invalid("This name is reserved".into()));
+}
+
+#[test]
+fn test_mask_inserts_literals() {
+    let mut state = test_state("");
+    state.mask = Some(TextInputMask::date()).into();
+
+    for c in "1225".chars() {
+        state.handle_on_text_input(c);
+    }
+    assert_eq!(state.get_text(), "12/25");
+    // incomplete input is rejected by the validation, but an empty field is valid
+    assert_eq!(state.validate(), TextInputValidationResult::Invalid("The input is incomplete".into()));
+
+    // typing the literal itself doesn't insert it twice
+    state.handle_on_text_input('/');
+    for c in "2024".chars() {
+        state.handle_on_text_input(c);
+    }
+    assert_eq!(state.get_text(), "12/25/2024");
+    assert_eq!(state.cursor_pos, 10);
+    assert!(state.validate().is_valid());
+
+    // rejected: not a digit, past the end of the mask
+    state.handle_on_text_input('x');
+    state.handle_on_text_input('1');
+    assert_eq!(state.get_text(), "12/25/2024");
+
+    // editing in the middle of the text must keep the text valid
+    state.cursor_pos = 0;
+    state.handle_on_text_input('x');
+    assert_eq!(state.get_text(), "12/25/2024");
+    state.selection = Some(TextInputSelection::FromTo(TextInputSelectionRange { from: 0, to: 1 })).into();
+    state.handle_on_text_input('0');
+    assert_eq!(state.get_text(), "02/25/2024");
+    assert_eq!(state.cursor_pos, 1);
+
+    let mut empty = test_state("");
+    empty.mask = Some(TextInputMask::date()).into();
+    assert!(empty.validate().is_valid());
+}
+
+#[test]
+fn test_mask_optional_digits() {
+    let mask = TextInputMask::ipv4();
+    let text = |s: &str| s.chars().map(|c| c as u32).collect::<Vec<_>>();
+
+    assert!(mask.is_complete(&text("1.2.3.4")));
+    assert!(mask.is_complete(&text("192.168.0.1")));
+    assert!(mask.matches(&text("1.2")));
+    assert!(!mask.is_complete(&text("1.2")));
+    assert!(!mask.matches(&text("1234")));
+
+    // the '.' skips the optional digits
+    assert_eq!(mask.get_chars_to_append(&text("1"), '.'), Some(text(".")));
+    // the fourth digit starts the next group
+    assert_eq!(mask.get_chars_to_append(&text("123"), '4'), Some(text(".4")));
+}
+
+#[test]
+fn test_ime_preedit() {
+    use azul_core::window::ImeComposition;
+
+    let mut state = test_state("abc");
+    state.validators = vec![TextInputValidator::MinLength(5)].into();
+
+    let preedit = state.set_ime_composition(Some(ImeComposition {
+        text: "にほん".into(),
+        cursor_start: 3,
+        cursor_end: 3,
+    }).into());
+    assert_eq!(preedit.as_str(), "にほん");
+    assert!(state.ime_composition.is_some());
+
+    // the composition is neither part of the text nor validated
+    assert_eq!(state.get_text(), "abc");
+    assert_eq!(state.cursor_pos, 3);
+    assert!(!state.validate().is_valid());
+
+    // the input method commits the converted text as regular text input
+    assert_eq!(state.set_ime_composition(None.into()).as_str(), "");
+    assert!(state.ime_composition.is_none());
+    for c in "日本".chars() {
+        state.handle_on_text_input(c);
+    }
+    assert_eq!(state.get_text(), "abc日本");
+    assert_eq!(state.cursor_pos, 5);
+    assert!(state.validate().is_valid());
+
+    // password mode obscures every character, including the committed ones
+    state.obscure_char = Some('*' as u32).into();
+    assert_eq!(state.get_display_text(), "*****");
+}
@@ -0,0 +1,2085 @@
+//! Tree view with expandable nodes and lazily loaded children
+//!
+//! The nodes are stored in a flat arena (`TreeViewState::nodes`), every node
+//! links to its parent, its first / last child and its siblings by index, so
+//! that nodes can be inserted and moved without copying the tree. Only the
+//! visible rows are rendered: like the `TextEdit`, the tree renders a fixed pool
+//! of `visible_rows` row nodes, scrolling, expanding and selecting only update
+//! these rows in place, so a tree with 100k nodes is as cheap to render as a
+//! tree with 20 nodes.
+//!
+//! Nodes with `TreeViewChildren::NotLoaded` load their children when they are
+//! expanded for the first time: the `on_load_children` callback either adds
+//! the children directly (`TreeViewState::set_children`) or starts a `Thread`
+//! with `TreeViewLoadRequest::load_in_thread`, the thread then sends the
+//! children back with `TreeViewLoadedChildren::into_writeback_msg`.
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClassVec, IdOrClass, IdOrClass::Class,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Focus},
+    },
+    callbacks::{Callback, CallbackInfo, Update, RefAny, ThreadCallback, ThreadCallbackType},
+    resources::{ImageRef, OptionImageRef, RawImageFormat},
+    task::{ThreadId, ThreadWriteBackMsg},
+};
+use azul_desktop::css::{
+    impl_option, impl_option_inner,
+    impl_vec, impl_vec_debug, impl_vec_clone, impl_vec_partialeq, impl_vec_mut,
+};
+use azul_core::gl::OptionUsize;
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{
+    XmlComponent, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, ComponentArgumentsMap, FilteredComponentArguments,
+    RenderDomError, CompileError, parse_bool,
+};
+use std::collections::BTreeMap;
+use std::vec::Vec;
+use std::string::String;
+
+/// Default number of rows that are visible (and rendered) at the same time
+pub const DEFAULT_VISIBLE_ROWS: usize = 20;
+/// Height of a single row in pixels
+const ROW_HEIGHT: f32 = 20.0;
+/// Indentation per level, also the width of the expand / collapse toggle
+const INDENT: f32 = 16.0;
+/// How many rows one "notch" of the mouse wheel scrolls
+const ROWS_PER_WHEEL_NOTCH: f32 = 3.0;
+/// Minimum height of the scrollbar thumb in pixels
+const MIN_THUMB_HEIGHT: f32 = 20.0;
+/// Dropping on the top / bottom quarter of a row inserts before / after the
+/// row, dropping on the middle of the row inserts the node as the last child
+const DROP_EDGE: f32 = 0.25;
+
+const TOGGLE_COLLAPSED: &str = "\u{25B8}"; // ▸
+const TOGGLE_EXPANDED: &str = "\u{25BE}"; // ▾
+const TOGGLE_LOADING: &str = "\u{2026}"; // …
+
+static TREE_VIEW_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-container"))];
+static TREE_VIEW_ROWS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-rows"))];
+static TREE_VIEW_ROW_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-row"))];
+static TREE_VIEW_GUIDES_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-guides"))];
+static TREE_VIEW_TOGGLE_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-toggle"))];
+static TREE_VIEW_ICON_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-icon"))];
+static TREE_VIEW_LABEL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-label"))];
+static TREE_VIEW_SCROLLBAR_THUMB_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-scrollbar-thumb"))];
+static TREE_VIEW_DROP_INDICATOR_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-tree-view-drop-indicator"))];
+
+/// Called when a node with `TreeViewChildren::NotLoaded` is expanded
+pub type TreeViewOnLoadChildrenCallbackType = extern "C" fn(&mut RefAny, &mut TreeViewState, TreeViewLoadRequest, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct TreeViewOnLoadChildrenCallback {
+    pub cb: TreeViewOnLoadChildrenCallbackType,
+}
+
+impl_callback!(TreeViewOnLoadChildrenCallback);
+
+pub type TreeViewOnSelectionChangeCallbackType = extern "C" fn(&mut RefAny, &TreeViewState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct TreeViewOnSelectionChangeCallback {
+    pub cb: TreeViewOnSelectionChangeCallbackType,
+}
+
+impl_callback!(TreeViewOnSelectionChangeCallback);
+
+/// Called after a node has been moved by drag-and-drop, the `usize` is the id of the moved node
+pub type TreeViewOnNodeMovedCallbackType = extern "C" fn(&mut RefAny, &TreeViewState, usize, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct TreeViewOnNodeMovedCallback {
+    pub cb: TreeViewOnNodeMovedCallbackType,
+}
+
+impl_callback!(TreeViewOnNodeMovedCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeView {
+    pub state: TreeViewStateWrapper,
+    /// Style for the outer container (receives the keyboard, mouse and drag events)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for each rendered row
+    pub row_style: NodeDataInlineCssPropertyVec,
+    /// Style for the indentation in front of each row (draws the indentation guides)
+    pub guides_style: NodeDataInlineCssPropertyVec,
+    /// Style for the expand / collapse toggle
+    pub toggle_style: NodeDataInlineCssPropertyVec,
+    /// Style for the icon of each row
+    pub icon_style: NodeDataInlineCssPropertyVec,
+    /// Style for the label of each row
+    pub label_style: NodeDataInlineCssPropertyVec,
+    /// Style for the scrollbar thumb
+    pub scrollbar_thumb_style: NodeDataInlineCssPropertyVec,
+    /// Style for the marker that shows where a dragged node will be dropped
+    pub drop_indicator_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewStateWrapper {
+    pub inner: TreeViewState,
+    /// Optional: Function to load the children of a `TreeViewChildren::NotLoaded`
+    /// node, without this function these nodes become leaf nodes when expanded
+    pub on_load_children: OptionTreeViewOnLoadChildren,
+    /// Optional: Function to call when the selected nodes change
+    pub on_selection_change: OptionTreeViewOnSelectionChange,
+    /// Optional: Function to call when a node has been moved by drag-and-drop
+    pub on_node_moved: OptionTreeViewOnNodeMoved,
+    /// Whether nodes can be reordered by drag-and-drop
+    pub allow_reorder: bool,
+    /// Rows as they are currently rendered, so that unchanged rows aren't touched
+    pub rendered_rows: TreeViewRenderedRowVec,
+    /// Node that the left mouse button was pressed on (start of a drag)
+    pub pressed_node: OptionUsize,
+    /// Node that is currently being dragged
+    pub dragged_node: OptionUsize,
+    /// Where the dragged node would be dropped
+    pub drop_target: OptionTreeViewDropTarget,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewOnLoadChildren {
+    pub data: RefAny,
+    pub callback: TreeViewOnLoadChildrenCallback,
+}
+
+impl_option!(TreeViewOnLoadChildren, OptionTreeViewOnLoadChildren, copy = false, [Debug, Clone, PartialEq]);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewOnSelectionChange {
+    pub data: RefAny,
+    pub callback: TreeViewOnSelectionChangeCallback,
+}
+
+impl_option!(TreeViewOnSelectionChange, OptionTreeViewOnSelectionChange, copy = false, [Debug, Clone, PartialEq]);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewOnNodeMoved {
+    pub data: RefAny,
+    pub callback: TreeViewOnNodeMovedCallback,
+}
+
+impl_option!(TreeViewOnNodeMoved, OptionTreeViewOnNodeMoved, copy = false, [Debug, Clone, PartialEq]);
+
+/// Whether a node has children and whether they have been loaded yet
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TreeViewChildren {
+    /// Leaf node, no expand / collapse toggle
+    None,
+    /// The children are loaded by the `on_load_children` callback on the first expand
+    NotLoaded,
+    /// `on_load_children` has been called, but the children haven't arrived yet
+    Loading,
+    /// The children are part of the tree (may be empty)
+    Loaded,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TreeViewSelectionMode {
+    /// Nodes can't be selected, only the cursor moves
+    None,
+    /// At most one node is selected
+    Single,
+    /// Ctrl + click toggles, Shift + click selects a range of rows
+    Multiple,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TreeViewDropPosition {
+    /// Insert as the previous sibling of the target
+    Before,
+    /// Insert as the next sibling of the target
+    After,
+    /// Insert as the last child of the target
+    Into,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TreeViewDropTarget {
+    pub node: usize,
+    pub position: TreeViewDropPosition,
+}
+
+impl_option!(TreeViewDropTarget, OptionTreeViewDropTarget, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+/// User-facing content of a node
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewNodeData {
+    pub label: AzString,
+    pub icon: OptionImageRef,
+    pub children: TreeViewChildren,
+}
+
+impl_vec!(TreeViewNodeData, TreeViewNodeDataVec, TreeViewNodeDataVecDestructor);
+impl_vec_debug!(TreeViewNodeData, TreeViewNodeDataVec);
+impl_vec_clone!(TreeViewNodeData, TreeViewNodeDataVec, TreeViewNodeDataVecDestructor);
+impl_vec_partialeq!(TreeViewNodeData, TreeViewNodeDataVec);
+impl_vec_mut!(TreeViewNodeData, TreeViewNodeDataVec);
+
+impl TreeViewNodeData {
+
+    pub fn new(label: AzString) -> Self {
+        Self {
+            label,
+            icon: None.into(),
+            children: TreeViewChildren::None,
+        }
+    }
+
+    #[inline]
+    pub fn with_icon(mut self, icon: ImageRef) -> Self {
+        self.icon = Some(icon).into();
+        self
+    }
+
+    /// Marks the node as having children that are loaded on the first expand
+    #[inline]
+    pub fn with_lazy_children(mut self) -> Self {
+        self.children = TreeViewChildren::NotLoaded;
+        self
+    }
+}
+
+/// Node in the arena of the `TreeViewState`, the links are indices into `TreeViewState::nodes`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewNode {
+    pub data: TreeViewNodeData,
+    pub parent: OptionUsize,
+    pub first_child: OptionUsize,
+    pub last_child: OptionUsize,
+    pub previous_sibling: OptionUsize,
+    pub next_sibling: OptionUsize,
+    pub is_expanded: bool,
+    pub is_selected: bool,
+}
+
+impl_vec!(TreeViewNode, TreeViewNodeVec, TreeViewNodeVecDestructor);
+impl_vec_debug!(TreeViewNode, TreeViewNodeVec);
+impl_vec_clone!(TreeViewNode, TreeViewNodeVec, TreeViewNodeVecDestructor);
+impl_vec_partialeq!(TreeViewNode, TreeViewNodeVec);
+impl_vec_mut!(TreeViewNode, TreeViewNodeVec);
+
+impl TreeViewNode {
+
+    fn new(data: TreeViewNodeData) -> Self {
+        Self {
+            data,
+            parent: None.into(),
+            first_child: None.into(),
+            last_child: None.into(),
+            previous_sibling: None.into(),
+            next_sibling: None.into(),
+            is_expanded: false,
+            is_selected: false,
+        }
+    }
+
+    /// Whether the node can be expanded (has or may have children)
+    pub fn has_children(&self) -> bool {
+        match self.data.children {
+            TreeViewChildren::None => false,
+            TreeViewChildren::NotLoaded | TreeViewChildren::Loading => true,
+            TreeViewChildren::Loaded => self.first_child.is_some(),
+        }
+    }
+
+    /// Returns the text of the expand / collapse toggle
+    pub fn get_toggle_str(&self) -> &'static str {
+        match self.data.children {
+            TreeViewChildren::Loading => TOGGLE_LOADING,
+            _ if !self.has_children() => "",
+            _ if self.is_expanded => TOGGLE_EXPANDED,
+            _ => TOGGLE_COLLAPSED,
+        }
+    }
+}
+
+/// Visible node: all ancestors of the node are expanded
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TreeViewRow {
+    pub node: usize,
+    pub depth: usize,
+}
+
+impl_vec!(TreeViewRow, TreeViewRowVec, TreeViewRowVecDestructor);
+impl_vec_debug!(TreeViewRow, TreeViewRowVec);
+impl_vec_clone!(TreeViewRow, TreeViewRowVec, TreeViewRowVecDestructor);
+impl_vec_partialeq!(TreeViewRow, TreeViewRowVec);
+impl_vec_mut!(TreeViewRow, TreeViewRowVec);
+
+/// Content of one row of the row pool, `node` is `None` for the empty rows below the last node
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewRenderedRow {
+    pub node: OptionUsize,
+    pub depth: usize,
+    pub toggle: AzString,
+    pub icon: OptionImageRef,
+    pub label: AzString,
+    pub is_selected: bool,
+    pub is_cursor: bool,
+}
+
+impl_vec!(TreeViewRenderedRow, TreeViewRenderedRowVec, TreeViewRenderedRowVecDestructor);
+impl_vec_debug!(TreeViewRenderedRow, TreeViewRenderedRowVec);
+impl_vec_clone!(TreeViewRenderedRow, TreeViewRenderedRowVec, TreeViewRenderedRowVecDestructor);
+impl_vec_partialeq!(TreeViewRenderedRow, TreeViewRenderedRowVec);
+impl_vec_mut!(TreeViewRenderedRow, TreeViewRenderedRowVec);
+
+impl TreeViewRenderedRow {
+    pub const fn empty() -> Self {
+        Self {
+            node: OptionUsize::None,
+            depth: 0,
+            toggle: AzString::from_const_str(""),
+            icon: OptionImageRef::None,
+            label: AzString::from_const_str(""),
+            is_selected: false,
+            is_cursor: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewState {
+    /// All nodes, the index of a node in this vec is the id of the node
+    pub nodes: TreeViewNodeVec,
+    pub first_root: OptionUsize,
+    pub last_root: OptionUsize,
+    /// Visible nodes in display order, updated by `update_rows`
+    pub rows: TreeViewRowVec,
+    /// Node with the keyboard focus
+    pub cursor: OptionUsize,
+    /// Other end of a Shift + click / Shift + arrow range selection
+    pub selection_anchor: OptionUsize,
+    pub selection_mode: TreeViewSelectionMode,
+    /// Index of the first visible row
+    pub first_visible_row: usize,
+    /// Number of rows that are visible at the same time
+    pub visible_rows: usize,
+}
+
+impl Default for TreeViewState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreeViewState {
+
+    pub fn new() -> Self {
+        Self {
+            nodes: TreeViewNodeVec::new(),
+            first_root: None.into(),
+            last_root: None.into(),
+            rows: TreeViewRowVec::new(),
+            cursor: None.into(),
+            selection_anchor: None.into(),
+            selection_mode: TreeViewSelectionMode::Single,
+            first_visible_row: 0,
+            visible_rows: DEFAULT_VISIBLE_ROWS,
+        }
+    }
+
+    #[inline]
+    pub fn get_node(&self, node_id: usize) -> Option<&TreeViewNode> {
+        self.nodes.get(node_id)
+    }
+
+    #[inline]
+    pub fn get_parent(&self, node_id: usize) -> Option<usize> {
+        self.get_node(node_id)?.parent.into_option()
+    }
+
+    /// Returns the children of `parent` (the root nodes if `parent` is `None`)
+    pub fn get_children(&self, parent: Option<usize>) -> Vec<usize> {
+        let mut children = Vec::new();
+        let mut child = match parent {
+            Some(p) => self.get_node(p).and_then(|n| n.first_child.into_option()),
+            None => self.first_root.into_option(),
+        };
+        while let Some(c) = child {
+            children.push(c);
+            child = self.nodes.as_ref()[c].next_sibling.into_option();
+        }
+        children
+    }
+
+    /// Returns whether `ancestor` is a (direct or indirect) parent of `node_id`
+    pub fn is_ancestor(&self, ancestor: usize, node_id: usize) -> bool {
+        let mut parent = self.get_parent(node_id);
+        while let Some(p) = parent {
+            if p == ancestor {
+                return true;
+            }
+            parent = self.get_parent(p);
+        }
+        false
+    }
+
+    /// Appends a node to the children of `parent` (or to the root nodes),
+    /// returns the id of the new node
+    ///
+    /// Doesn't update the visible rows, so that large trees can be built
+    /// quickly: call `update_rows` once the tree is built.
+    pub fn add_node(&mut self, parent: Option<usize>, data: TreeViewNodeData) -> usize {
+        let parent = parent.filter(|p| *p < self.nodes.len());
+        let node_id = self.nodes.len();
+        self.nodes.push(TreeViewNode::new(data));
+        if let Some(p) = parent {
+            self.nodes.as_mut()[p].data.children = TreeViewChildren::Loaded;
+        }
+        self.append_child(parent, node_id);
+        node_id
+    }
+
+    /// Replaces the children of `node_id` and marks them as loaded
+    ///
+    /// The previous children are removed from the tree, but stay in `nodes`,
+    /// so that the ids of the other nodes don't change.
+    pub fn set_children(&mut self, node_id: usize, children: TreeViewNodeDataVec) {
+
+        if node_id >= self.nodes.len() {
+            return;
+        }
+
+        if let Some(cursor) = self.cursor.into_option() {
+            if self.is_ancestor(node_id, cursor) {
+                self.cursor = Some(node_id).into();
+            }
+        }
+
+        for removed in self.get_subtree(node_id) {
+            self.nodes.as_mut()[removed].is_selected = false;
+        }
+
+        while let Some(child) = self.nodes.as_ref()[node_id].first_child.into_option() {
+            self.detach(child);
+        }
+
+        for child in children.into_library_owned_vec() {
+            self.add_node(Some(node_id), child);
+        }
+
+        self.nodes.as_mut()[node_id].data.children = TreeViewChildren::Loaded;
+        self.update_rows();
+    }
+
+    /// Returns all descendants of `node_id` (not including `node_id`)
+    pub fn get_subtree(&self, node_id: usize) -> Vec<usize> {
+        let mut subtree = Vec::new();
+        let mut stack = self.get_children(Some(node_id));
+        while let Some(n) = stack.pop() {
+            subtree.push(n);
+            stack.extend(self.get_children(Some(n)));
+        }
+        subtree
+    }
+
+    /// Recomputes the visible rows, has to be called after the tree structure
+    /// or the expanded state of a node was modified directly
+    pub fn update_rows(&mut self) {
+
+        let mut rows = Vec::new();
+        let mut current = self.first_root.into_option();
+        let mut depth = 0;
+
+        while let Some(node_id) = current {
+
+            rows.push(TreeViewRow { node: node_id, depth });
+            let node = &self.nodes.as_ref()[node_id];
+
+            if node.is_expanded {
+                if let Some(first_child) = node.first_child.into_option() {
+                    current = Some(first_child);
+                    depth += 1;
+                    continue;
+                }
+            }
+
+            // next sibling, or the next sibling of the nearest ancestor that has one
+            let mut n = node_id;
+            current = loop {
+                let node = &self.nodes.as_ref()[n];
+                if let Some(next) = node.next_sibling.into_option() {
+                    break Some(next);
+                }
+                match node.parent.into_option() {
+                    Some(p) => {
+                        n = p;
+                        depth -= 1;
+                    },
+                    None => break None,
+                }
+            };
+        }
+
+        self.rows = rows.into();
+        self.first_visible_row = self.first_visible_row.min(self.get_max_first_visible_row());
+    }
+
+    #[inline]
+    pub fn get_row(&self, row: usize) -> Option<TreeViewRow> {
+        self.rows.get(row).copied()
+    }
+
+    /// Returns the index of the row of the node, `None` if the node isn't visible
+    pub fn get_row_of_node(&self, node_id: usize) -> Option<usize> {
+        self.rows.as_ref().iter().position(|r| r.node == node_id)
+    }
+
+    #[inline]
+    pub fn get_cursor_row(&self) -> Option<usize> {
+        self.get_row_of_node(self.cursor.into_option()?)
+    }
+
+    /// Returns the row at the y position (relative to the top of the tree)
+    pub fn get_row_at(&self, y: f32) -> Option<usize> {
+        let row_in_view = (y / ROW_HEIGHT).floor();
+        if row_in_view < 0.0 || row_in_view as usize >= self.visible_rows.max(1) {
+            return None;
+        }
+        let row = self.first_visible_row + row_in_view as usize;
+        if row < self.rows.len() { Some(row) } else { None }
+    }
+
+    /// Expands the node, returns true if the children of the node have to be
+    /// loaded (the node is set to `TreeViewChildren::Loading`)
+    pub fn expand(&mut self, node_id: usize) -> bool {
+
+        let node = match self.nodes.as_mut().get_mut(node_id) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        node.is_expanded = true;
+        let needs_loading = node.data.children == TreeViewChildren::NotLoaded;
+        if needs_loading {
+            node.data.children = TreeViewChildren::Loading;
+        }
+
+        self.update_rows();
+        needs_loading
+    }
+
+    /// Collapses the node, moves the cursor to the node if it was inside the collapsed subtree
+    pub fn collapse(&mut self, node_id: usize) {
+
+        match self.nodes.as_mut().get_mut(node_id) {
+            Some(s) => s.is_expanded = false,
+            None => return,
+        }
+
+        if let Some(cursor) = self.cursor.into_option() {
+            if self.is_ancestor(node_id, cursor) {
+                self.cursor = Some(node_id).into();
+            }
+        }
+
+        self.update_rows();
+    }
+
+    /// Expands all ancestors of the node, so that the node becomes visible
+    ///
+    /// Ancestors whose children aren't loaded yet are left collapsed.
+    pub fn reveal(&mut self, node_id: usize) {
+        let mut parent = self.get_parent(node_id);
+        while let Some(p) = parent {
+            self.nodes.as_mut()[p].is_expanded = true;
+            parent = self.get_parent(p);
+        }
+        self.update_rows();
+    }
+
+    #[inline]
+    pub fn is_expanded(&self, node_id: usize) -> bool {
+        self.get_node(node_id).map(|n| n.is_expanded).unwrap_or(false)
+    }
+
+    /// Returns the ids of the selected nodes
+    pub fn get_selected_nodes(&self) -> Vec<usize> {
+        self.nodes.as_ref().iter().enumerate()
+            .filter(|(_, n)| n.is_selected)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    pub fn clear_selection(&mut self) {
+        for node in self.nodes.as_mut().iter_mut() {
+            node.is_selected = false;
+        }
+    }
+
+    #[inline]
+    pub fn set_selected(&mut self, node_id: usize, selected: bool) {
+        if let Some(node) = self.nodes.as_mut().get_mut(node_id) {
+            node.is_selected = selected;
+        }
+    }
+
+    /// Moves the cursor to the node and selects it according to the selection mode:
+    /// `extend` selects the rows from the anchor to the node, `toggle` adds /
+    /// removes the node to / from the selection (only for `Multiple`)
+    pub fn select(&mut self, node_id: usize, extend: bool, toggle: bool) {
+
+        match self.selection_mode {
+            TreeViewSelectionMode::None => { },
+            TreeViewSelectionMode::Single => {
+                self.clear_selection();
+                self.set_selected(node_id, true);
+            },
+            TreeViewSelectionMode::Multiple => {
+                let anchor_row = self.selection_anchor.into_option().and_then(|a| self.get_row_of_node(a));
+                match (anchor_row, self.get_row_of_node(node_id)) {
+                    (Some(anchor_row), Some(row)) if extend => {
+                        self.clear_selection();
+                        for r in anchor_row.min(row)..=anchor_row.max(row) {
+                            let node = self.rows.as_ref()[r].node;
+                            self.set_selected(node, true);
+                        }
+                    },
+                    _ if toggle => {
+                        let selected = self.get_node(node_id).map(|n| n.is_selected).unwrap_or(false);
+                        self.set_selected(node_id, !selected);
+                    },
+                    _ => {
+                        self.clear_selection();
+                        self.set_selected(node_id, true);
+                    },
+                }
+            },
+        }
+
+        if !extend || self.selection_anchor.is_none() {
+            self.selection_anchor = Some(node_id).into();
+        }
+        self.cursor = Some(node_id).into();
+    }
+
+    /// Returns the drop target at the y position (relative to the top of the tree)
+    pub fn get_drop_target_at(&self, y: f32) -> Option<TreeViewDropTarget> {
+        let row = self.get_row_at(y)?;
+        let fraction = (y - (row - self.first_visible_row) as f32 * ROW_HEIGHT) / ROW_HEIGHT;
+        let position = if fraction < DROP_EDGE {
+            TreeViewDropPosition::Before
+        } else if fraction > 1.0 - DROP_EDGE {
+            TreeViewDropPosition::After
+        } else {
+            TreeViewDropPosition::Into
+        };
+        Some(TreeViewDropTarget { node: self.rows.as_ref()[row].node, position })
+    }
+
+    /// Returns whether `node_id` can be moved to the target: a node can't be
+    /// moved into itself or its own subtree, and not into a node whose
+    /// children haven't been loaded yet
+    pub fn can_move(&self, node_id: usize, target: TreeViewDropTarget) -> bool {
+
+        let target_node = match self.get_node(target.node) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        if node_id >= self.nodes.len() || node_id == target.node || self.is_ancestor(node_id, target.node) {
+            return false;
+        }
+
+        match (target.position, target_node.data.children) {
+            (TreeViewDropPosition::Into, TreeViewChildren::NotLoaded) |
+            (TreeViewDropPosition::Into, TreeViewChildren::Loading) => false,
+            _ => true,
+        }
+    }
+
+    /// Moves the node (with its subtree) to the target, returns false if the move isn't allowed
+    pub fn move_node(&mut self, node_id: usize, target: TreeViewDropTarget) -> bool {
+
+        if !self.can_move(node_id, target) {
+            return false;
+        }
+
+        self.detach(node_id);
+
+        match target.position {
+            TreeViewDropPosition::Before => self.insert_before(target.node, node_id),
+            TreeViewDropPosition::After => self.insert_after(target.node, node_id),
+            TreeViewDropPosition::Into => {
+                self.append_child(Some(target.node), node_id);
+                let target_node = &mut self.nodes.as_mut()[target.node];
+                target_node.data.children = TreeViewChildren::Loaded;
+                target_node.is_expanded = true;
+            },
+        }
+
+        self.update_rows();
+        true
+    }
+
+    /// Scrolls so that the row of the cursor is visible
+    pub fn scroll_to_cursor(&mut self) {
+        let row = match self.get_cursor_row() {
+            Some(s) => s,
+            None => return,
+        };
+        let visible_rows = self.visible_rows.max(1);
+        if row < self.first_visible_row {
+            self.first_visible_row = row;
+        } else if row >= self.first_visible_row + visible_rows {
+            self.first_visible_row = row + 1 - visible_rows;
+        }
+    }
+
+    /// Scrolls by `rows` rows, clamped to the number of rows
+    pub fn scroll_rows(&mut self, rows: isize) {
+        let new_row = if rows < 0 {
+            self.first_visible_row.saturating_sub(rows.unsigned_abs())
+        } else {
+            self.first_visible_row.saturating_add(rows as usize)
+        };
+        self.first_visible_row = new_row.min(self.get_max_first_visible_row());
+    }
+
+    #[inline]
+    fn get_max_first_visible_row(&self) -> usize {
+        self.rows.len().saturating_sub(self.visible_rows.max(1))
+    }
+
+    /// Returns the (top, height) of the scrollbar thumb in pixels
+    /// or `None` if all rows fit into the tree
+    pub fn get_scrollbar_thumb(&self) -> Option<(f32, f32)> {
+        let visible_rows = self.visible_rows.max(1);
+        let total_rows = self.rows.len();
+        if total_rows <= visible_rows {
+            return None;
+        }
+        let track_height = visible_rows as f32 * ROW_HEIGHT;
+        let thumb_height = (track_height * visible_rows as f32 / total_rows as f32)
+            .max(MIN_THUMB_HEIGHT)
+            .min(track_height);
+        let thumb_top = (track_height - thumb_height) * self.first_visible_row as f32 / (total_rows - visible_rows) as f32;
+        Some((thumb_top, thumb_height))
+    }
+
+    /// Returns what the row pool should display for the `row`
+    pub fn get_rendered_row(&self, row: usize) -> TreeViewRenderedRow {
+        let TreeViewRow { node: node_id, depth } = match self.get_row(row) {
+            Some(s) => s,
+            None => return TreeViewRenderedRow::empty(),
+        };
+        let node = &self.nodes.as_ref()[node_id];
+        TreeViewRenderedRow {
+            node: Some(node_id).into(),
+            depth,
+            toggle: AzString::from_const_str(node.get_toggle_str()),
+            icon: node.data.icon.clone(),
+            label: node.data.label.clone(),
+            is_selected: node.is_selected,
+            is_cursor: self.cursor.into_option() == Some(node_id),
+        }
+    }
+
+    // -- linking
+
+    /// Removes the node from the child list of its parent (the subtree stays attached to the node)
+    fn detach(&mut self, node_id: usize) {
+
+        let nodes = self.nodes.as_mut();
+        let parent = nodes[node_id].parent.into_option();
+        let previous = nodes[node_id].previous_sibling.into_option();
+        let next = nodes[node_id].next_sibling.into_option();
+
+        match (previous, parent) {
+            (Some(p), _) => nodes[p].next_sibling = next.into(),
+            (None, Some(p)) => nodes[p].first_child = next.into(),
+            (None, None) => self.first_root = next.into(),
+        }
+
+        match (next, parent) {
+            (Some(n), _) => nodes[n].previous_sibling = previous.into(),
+            (None, Some(p)) => nodes[p].last_child = previous.into(),
+            (None, None) => self.last_root = previous.into(),
+        }
+
+        nodes[node_id].parent = None.into();
+        nodes[node_id].previous_sibling = None.into();
+        nodes[node_id].next_sibling = None.into();
+    }
+
+    fn append_child(&mut self, parent: Option<usize>, node_id: usize) {
+
+        let last = match parent {
+            Some(p) => self.nodes.as_ref()[p].last_child,
+            None => self.last_root,
+        };
+
+        let nodes = self.nodes.as_mut();
+        nodes[node_id].parent = parent.into();
+        nodes[node_id].previous_sibling = last;
+        nodes[node_id].next_sibling = None.into();
+
+        match (last.into_option(), parent) {
+            (Some(l), _) => nodes[l].next_sibling = Some(node_id).into(),
+            (None, Some(p)) => nodes[p].first_child = Some(node_id).into(),
+            (None, None) => self.first_root = Some(node_id).into(),
+        }
+
+        match parent {
+            Some(p) => nodes[p].last_child = Some(node_id).into(),
+            None => self.last_root = Some(node_id).into(),
+        }
+    }
+
+    fn insert_before(&mut self, sibling: usize, node_id: usize) {
+
+        let nodes = self.nodes.as_mut();
+        let parent = nodes[sibling].parent;
+        let previous = nodes[sibling].previous_sibling.into_option();
+
+        nodes[node_id].parent = parent;
+        nodes[node_id].previous_sibling = previous.into();
+        nodes[node_id].next_sibling = Some(sibling).into();
+        nodes[sibling].previous_sibling = Some(node_id).into();
+
+        match (previous, parent.into_option()) {
+            (Some(p), _) => nodes[p].next_sibling = Some(node_id).into(),
+            (None, Some(p)) => nodes[p].first_child = Some(node_id).into(),
+            (None, None) => self.first_root = Some(node_id).into(),
+        }
+    }
+
+    fn insert_after(&mut self, sibling: usize, node_id: usize) {
+
+        let nodes = self.nodes.as_mut();
+        let parent = nodes[sibling].parent;
+        let next = nodes[sibling].next_sibling.into_option();
+
+        nodes[node_id].parent = parent;
+        nodes[node_id].previous_sibling = Some(sibling).into();
+        nodes[node_id].next_sibling = next.into();
+        nodes[sibling].next_sibling = Some(node_id).into();
+
+        match (next, parent.into_option()) {
+            (Some(n), _) => nodes[n].previous_sibling = Some(node_id).into(),
+            (None, Some(p)) => nodes[p].last_child = Some(node_id).into(),
+            (None, None) => self.last_root = Some(node_id).into(),
+        }
+    }
+}
+
+/// Passed to the `on_load_children` callback
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewLoadRequest {
+    /// Node whose children should be loaded
+    pub node: usize,
+    /// The `TreeViewStateWrapper`, receives the children from a background thread
+    pub tree: RefAny,
+}
+
+impl TreeViewLoadRequest {
+    /// Starts a thread that loads the children in the background: the thread
+    /// has to send a `ThreadReceiveMsg::WriteBack(TreeViewLoadedChildren::into_writeback_msg())`
+    /// with the id of the node (so `data` has to contain the id of the node)
+    pub fn load_in_thread(self, info: &mut CallbackInfo, data: RefAny, callback: ThreadCallbackType) -> Option<ThreadId> {
+        info.start_thread(data, self.tree, ThreadCallback { cb: callback })
+    }
+}
+
+/// Children that were loaded on a background thread
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewLoadedChildren {
+    pub node: usize,
+    pub children: TreeViewNodeDataVec,
+}
+
+impl TreeViewLoadedChildren {
+
+    pub fn new(node: usize, children: TreeViewNodeDataVec) -> Self {
+        Self { node, children }
+    }
+
+    /// Message that inserts the children into the tree when it is sent from the thread
+    pub fn into_writeback_msg(self) -> ThreadWriteBackMsg {
+        ThreadWriteBackMsg::new(self::input::default_on_children_loaded, RefAny::new(self))
+    }
+}
+
+/// Payload of a drag that was started on a node, can be used by other
+/// widgets to accept nodes that are dragged out of the tree
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TreeViewDragPayload {
+    pub node: usize,
+}
+
+const BACKGROUND_COLOR: ColorU = ColorU { r: 255,  g: 255,  b: 255,  a: 255 }; // white
+const BACKGROUND_THEME_LIGHT: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BACKGROUND_COLOR)];
+const BACKGROUND_COLOR_LIGHT: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(BACKGROUND_THEME_LIGHT);
+const SELECTED_COLOR: ColorU = ColorU { r: 204, g: 232, b: 255, a: 255 }; // #cce8ff
+const SELECTED_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(SELECTED_COLOR)];
+const CURSOR_COLOR: ColorU = ColorU { r: 229, g: 243, b: 255, a: 255 }; // #e5f3ff
+const CURSOR_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(CURSOR_COLOR)];
+const NO_BACKGROUND: &[StyleBackgroundContent] = &[];
+const TEXT_COLOR: ColorU = ColorU { r: 0, g: 0, b: 0, a: 255 }; // black
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const COLOR_4286F4_TRANSLUCENT: ColorU = ColorU { r: 66, g: 134, b: 244, a: 64 };
+const COLOR_606060: ColorU = ColorU { r: 96, g: 96, b: 96, a: 255 }; // #606060
+const COLOR_C1C1C1: ColorU = ColorU { r: 193, g: 193, b: 193, a: 255 }; // #c1c1c1
+const GUIDE_COLOR: ColorU = ColorU { r: 216, g: 216, b: 216, a: 255 }; // #d8d8d8
+
+const SCROLLBAR_THUMB_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_C1C1C1)];
+const DROP_LINE_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_4286F4)];
+const DROP_INTO_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_4286F4_TRANSLUCENT)];
+
+// one vertical 1px line in the middle of each indentation level,
+// below the center of the toggle of the parent:
+//
+// background: linear-gradient(to right, transparent 47%, #d8d8d8 47%, #d8d8d8 53%, transparent 53%);
+// background-size: 16px 100%;
+// background-repeat: repeat-x;
+const GUIDE_COLOR_STOPS: &[NormalizedLinearColorStop] = &[
+    NormalizedLinearColorStop { offset: PercentageValue::const_new(0), color: ColorU::TRANSPARENT },
+    NormalizedLinearColorStop { offset: PercentageValue::const_new(47), color: ColorU::TRANSPARENT },
+    NormalizedLinearColorStop { offset: PercentageValue::const_new(47), color: GUIDE_COLOR },
+    NormalizedLinearColorStop { offset: PercentageValue::const_new(53), color: GUIDE_COLOR },
+    NormalizedLinearColorStop { offset: PercentageValue::const_new(53), color: ColorU::TRANSPARENT },
+    NormalizedLinearColorStop { offset: PercentageValue::const_new(100), color: ColorU::TRANSPARENT },
+];
+const GUIDE_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::LinearGradient(LinearGradient {
+    direction: Direction::FromTo(DirectionCorners {
+        from: DirectionCorner::Left,
+        to: DirectionCorner::Right,
+    }),
+    extend_mode: ExtendMode::Clamp,
+    stops: NormalizedLinearColorStopVec::from_const_slice(GUIDE_COLOR_STOPS),
+})];
+const GUIDE_BACKGROUND_SIZE: &[StyleBackgroundSize] = &[StyleBackgroundSize::ExactSize([PixelValue::const_px(INDENT as isize), PixelValue::const_percent(100)])];
+const GUIDE_BACKGROUND_REPEAT: &[StyleBackgroundRepeat] = &[StyleBackgroundRepeat::RepeatX];
+
+static TREE_VIEW_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(150))),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+
+    // border: 1px solid #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    // Focus(border-color: #4286f4;)
+
+    Focus(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+static TREE_VIEW_ROWS_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+];
+
+static TREE_VIEW_ROW_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(ROW_HEIGHT as isize))),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static TREE_VIEW_GUIDES_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(ROW_HEIGHT as isize))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(GUIDE_BACKGROUND))),
+    Normal(CssProperty::const_background_size(StyleBackgroundSizeVec::from_const_slice(GUIDE_BACKGROUND_SIZE))),
+    Normal(CssProperty::const_background_repeat(StyleBackgroundRepeatVec::from_const_slice(GUIDE_BACKGROUND_REPEAT))),
+];
+
+static TREE_VIEW_TOGGLE_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(INDENT as isize))),
+    Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_606060 })),
+];
+
+static TREE_VIEW_ICON_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(16))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(16))),
+    Normal(CssProperty::const_margin_right(LayoutMarginRight::const_px(4))),
+];
+
+static TREE_VIEW_LABEL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: TEXT_COLOR })),
+];
+
+static TREE_VIEW_SCROLLBAR_THUMB_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_right(LayoutRight::const_px(1))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(6))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SCROLLBAR_THUMB_BACKGROUND))),
+];
+
+static TREE_VIEW_DROP_INDICATOR_STYLE: &[NodeDataInlineCssProperty] = &[
+    // only visible while a node is dragged over the tree
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_right(LayoutRight::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(2))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(DROP_LINE_BACKGROUND))),
+];
+
+/// Background of a row, depending on whether it is selected / has the cursor
+fn get_row_background(is_selected: bool, is_cursor: bool) -> StyleBackgroundContentVec {
+    if is_selected {
+        StyleBackgroundContentVec::from_const_slice(SELECTED_THEME)
+    } else if is_cursor {
+        StyleBackgroundContentVec::from_const_slice(CURSOR_THEME)
+    } else {
+        StyleBackgroundContentVec::from_const_slice(NO_BACKGROUND)
+    }
+}
+
+impl TreeView {
+
+    pub fn new(state: TreeViewState) -> Self {
+        Self {
+            state: TreeViewStateWrapper {
+                inner: state,
+                on_load_children: None.into(),
+                on_selection_change: None.into(),
+                on_node_moved: None.into(),
+                allow_reorder: false,
+                rendered_rows: TreeViewRenderedRowVec::from_const_slice(&[]),
+                pressed_node: None.into(),
+                dragged_node: None.into(),
+                drop_target: None.into(),
+            },
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_CONTAINER_STYLE),
+            row_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_ROW_STYLE),
+            guides_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_GUIDES_STYLE),
+            toggle_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_TOGGLE_STYLE),
+            icon_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_ICON_STYLE),
+            label_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_LABEL_STYLE),
+            scrollbar_thumb_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_SCROLLBAR_THUMB_STYLE),
+            drop_indicator_style: NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_DROP_INDICATOR_STYLE),
+        }
+    }
+
+    /// Creates a tree from a list of paths in the form `"Documents/notes.txt, Documents/Photos, Music"`:
+    /// every path segment is a node, nodes with the same path are only created once
+    pub fn from_paths_str(paths: &str) -> Self {
+
+        let mut state = TreeViewState::new();
+        let mut path_nodes = BTreeMap::<String, usize>::new();
+
+        for path in paths.split(',') {
+            let mut parent = None;
+            let mut current_path = String::new();
+            for segment in path.split('/').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+                current_path.push('/');
+                current_path.push_str(segment);
+                let node_id = match path_nodes.get(&current_path) {
+                    Some(s) => *s,
+                    None => {
+                        let node_id = state.add_node(parent, TreeViewNodeData::new(AzString::from(segment.to_string())));
+                        path_nodes.insert(current_path.clone(), node_id);
+                        node_id
+                    },
+                };
+                parent = Some(node_id);
+            }
+        }
+
+        state.update_rows();
+        Self::new(state)
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new(TreeViewState::new());
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    /// Sets the number of visible rows, the height of the tree is `rows * 20px`
+    #[inline]
+    pub fn set_visible_rows(&mut self, rows: usize) {
+        self.state.inner.visible_rows = rows.max(1);
+    }
+
+    #[inline]
+    pub fn with_visible_rows(mut self, rows: usize) -> Self {
+        self.set_visible_rows(rows);
+        self
+    }
+
+    #[inline]
+    pub fn set_selection_mode(&mut self, selection_mode: TreeViewSelectionMode) {
+        self.state.inner.selection_mode = selection_mode;
+    }
+
+    #[inline]
+    pub fn with_selection_mode(mut self, selection_mode: TreeViewSelectionMode) -> Self {
+        self.set_selection_mode(selection_mode);
+        self
+    }
+
+    /// Allows reordering nodes by dragging them onto other nodes
+    #[inline]
+    pub fn set_allow_reorder(&mut self, allow_reorder: bool) {
+        self.state.allow_reorder = allow_reorder;
+    }
+
+    #[inline]
+    pub fn with_allow_reorder(mut self, allow_reorder: bool) -> Self {
+        self.set_allow_reorder(allow_reorder);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_load_children(&mut self, data: RefAny, on_load_children: TreeViewOnLoadChildrenCallbackType) {
+        self.state.on_load_children = Some(TreeViewOnLoadChildren {
+            callback: TreeViewOnLoadChildrenCallback { cb: on_load_children },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_load_children(mut self, data: RefAny, on_load_children: TreeViewOnLoadChildrenCallbackType) -> Self {
+        self.set_on_load_children(data, on_load_children);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_selection_change(&mut self, data: RefAny, on_selection_change: TreeViewOnSelectionChangeCallbackType) {
+        self.state.on_selection_change = Some(TreeViewOnSelectionChange {
+            callback: TreeViewOnSelectionChangeCallback { cb: on_selection_change },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_selection_change(mut self, data: RefAny, on_selection_change: TreeViewOnSelectionChangeCallbackType) -> Self {
+        self.set_on_selection_change(data, on_selection_change);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_node_moved(&mut self, data: RefAny, on_node_moved: TreeViewOnNodeMovedCallbackType) {
+        self.state.on_node_moved = Some(TreeViewOnNodeMoved {
+            callback: TreeViewOnNodeMovedCallback { cb: on_node_moved },
+            data,
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_on_node_moved(mut self, data: RefAny, on_node_moved: TreeViewOnNodeMovedCallbackType) -> Self {
+        self.set_on_node_moved(data, on_node_moved);
+        self
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_row_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.row_style = style;
+    }
+
+    pub fn set_guides_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.guides_style = style;
+    }
+
+    pub fn set_toggle_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.toggle_style = style;
+    }
+
+    pub fn set_icon_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.icon_style = style;
+    }
+
+    pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.label_style = style;
+    }
+
+    pub fn set_scrollbar_thumb_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.scrollbar_thumb_style = style;
+    }
+
+    pub fn set_drop_indicator_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.drop_indicator_style = style;
+    }
+
+    pub fn dom(mut self) -> Dom {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter,
+        };
+
+        self.state.inner.update_rows();
+        self.state.inner.scroll_to_cursor();
+
+        let visible_rows = self.state.inner.visible_rows.max(1);
+        let first_row = self.state.inner.first_visible_row;
+
+        let rendered_rows = (first_row..(first_row + visible_rows))
+            .map(|row| self.state.inner.get_rendered_row(row))
+            .collect::<Vec<_>>();
+
+        let rows = rendered_rows.iter().map(|row| {
+
+            let mut row_style = self.row_style.clone().into_library_owned_vec();
+            row_style.push(Normal(CssProperty::const_background_content(get_row_background(row.is_selected, row.is_cursor))));
+            if row.node.is_none() {
+                row_style.push(Normal(CssProperty::const_display(LayoutDisplay::None)));
+            }
+
+            let mut guides_style = self.guides_style.clone().into_library_owned_vec();
+            guides_style.push(Normal(CssProperty::width(LayoutWidth::px(row.depth as f32 * INDENT))));
+
+            // the icon node always exists, so that the icon can be replaced in place
+            let mut icon_style = self.icon_style.clone().into_library_owned_vec();
+            let icon = match row.icon.clone().into_option() {
+                Some(s) => s,
+                None => {
+                    icon_style.push(Normal(CssProperty::const_display(LayoutDisplay::None)));
+                    ImageRef::invalid(0, 0, RawImageFormat::BGRA8)
+                },
+            };
+
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_ROW_CLASS))
+            .with_inline_css_props(row_style.into())
+            .with_children(vec![
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_GUIDES_CLASS))
+                .with_inline_css_props(guides_style.into()),
+                Dom::text(row.toggle.clone())
+                .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_TOGGLE_CLASS))
+                .with_inline_css_props(self.toggle_style.clone()),
+                Dom::image(icon)
+                .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_ICON_CLASS))
+                .with_inline_css_props(icon_style.into()),
+                Dom::text(row.label.clone())
+                .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_LABEL_CLASS))
+                .with_inline_css_props(self.label_style.clone()),
+            ].into())
+        }).collect::<Vec<Dom>>();
+
+        let mut scrollbar_thumb_style = self.scrollbar_thumb_style.into_library_owned_vec();
+        if let Some((thumb_top, thumb_height)) = self.state.inner.get_scrollbar_thumb() {
+            scrollbar_thumb_style.push(Normal(CssProperty::const_display(LayoutDisplay::Block)));
+            scrollbar_thumb_style.push(Normal(CssProperty::top(LayoutTop::px(thumb_top))));
+            scrollbar_thumb_style.push(Normal(CssProperty::height(LayoutHeight::px(thumb_height))));
+        }
+
+        self.state.rendered_rows = rendered_rows.into();
+
+        let mut container_style = self.container_style.into_library_owned_vec();
+        container_style.push(Normal(CssProperty::height(LayoutHeight::px(visible_rows as f32 * ROW_HEIGHT + 2.0))));
+
+        let state_ref = RefAny::new(self.state);
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_CONTAINER_CLASS))
+        .with_inline_css_props(container_style.into())
+        .with_tab_index(TabIndex::Auto)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_down },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_scroll },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::DragStart),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_drag_start },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::DragOver),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_drag_over },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Drop),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_drop },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::DragEnd),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_drag_end },
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_ROWS_CLASS))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_ROWS_STYLE))
+            .with_children(rows.into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_SCROLLBAR_THUMB_CLASS))
+            .with_inline_css_props(scrollbar_thumb_style.into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from(TREE_VIEW_DROP_INDICATOR_CLASS))
+            .with_inline_css_props(self.drop_indicator_style),
+        ].into())
+    }
+}
+
+// handle input events for the TreeView
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId, UpdateImageType};
+    use azul_desktop::css::{
+        CssProperty, LayoutDisplay, LayoutLeft, LayoutTop, LayoutWidth, LayoutHeight,
+        StyleBackgroundContentVec,
+    };
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        TreeViewStateWrapper, TreeViewRenderedRow, TreeViewRenderedRowVec, TreeViewLoadRequest,
+        TreeViewLoadedChildren, TreeViewDragPayload, TreeViewDropPosition, TreeViewSelectionMode,
+        TreeViewChildren, TreeViewNodeDataVec,
+        TreeViewOnLoadChildren, TreeViewOnSelectionChange, TreeViewOnNodeMoved,
+        get_row_background, ROW_HEIGHT, INDENT, ROWS_PER_WHEEL_NOTCH,
+        DROP_LINE_BACKGROUND, DROP_INTO_BACKGROUND,
+    };
+    use std::vec::Vec;
+
+    /// Container -> [rows -> [row -> [guides, toggle, icon, label]], scrollbar thumb, drop indicator]
+    struct TreeViewNodes {
+        rows: DomNodeId,
+        scrollbar_thumb: DomNodeId,
+        drop_indicator: DomNodeId,
+    }
+
+    impl TreeViewNodes {
+        fn new(container: DomNodeId, info: &CallbackInfo) -> Option<Self> {
+            let rows = info.get_first_child(container)?;
+            let scrollbar_thumb = info.get_next_sibling(rows)?;
+            let drop_indicator = info.get_next_sibling(scrollbar_thumb)?;
+            Some(Self { rows, scrollbar_thumb, drop_indicator })
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_down(tree_view: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let tree_ref = tree_view.clone();
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = info.get_hit_node();
+        let cursor = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let row = match tree_view.inner.get_row_at(cursor.y).and_then(|r| tree_view.inner.get_row(r)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        tree_view.pressed_node = Some(row.node).into();
+
+        let has_children = tree_view.inner.get_node(row.node).map(|n| n.has_children()).unwrap_or(false);
+        let toggle_start = row.depth as f32 * INDENT;
+        let update = if has_children && cursor.x >= toggle_start && cursor.x < toggle_start + INDENT {
+            toggle_node(&mut tree_view, row.node, tree_ref, &mut info)
+        } else {
+            let keyboard_state = info.get_current_keyboard_state();
+            let selection_before = tree_view.inner.get_selected_nodes();
+            tree_view.inner.select(row.node, keyboard_state.shift_down, keyboard_state.ctrl_down);
+            on_selection_changed(&mut tree_view, selection_before, &mut info)
+        };
+
+        refresh(&mut tree_view, container_id, &mut info);
+        update
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(tree_view: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let tree_ref = tree_view.clone();
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let row_count = tree_view.inner.rows.len();
+        if row_count == 0 {
+            return Update::DoNothing;
+        }
+
+        let container_id = info.get_hit_node();
+        let shift = keyboard_state.shift_down;
+        let ctrl = keyboard_state.ctrl_down;
+        let multiple = tree_view.inner.selection_mode == TreeViewSelectionMode::Multiple;
+        let cursor_row = tree_view.inner.get_cursor_row().unwrap_or(0);
+        let cursor = tree_view.inner.rows.as_ref()[cursor_row];
+        let page = tree_view.inner.visible_rows.max(2) - 1;
+        let selection_before = tree_view.inner.get_selected_nodes();
+        let mut update = Update::DoNothing;
+
+        let new_row = match last_keycode {
+            VirtualKeyCode::Up => Some(cursor_row.saturating_sub(1)),
+            VirtualKeyCode::Down => Some((cursor_row + 1).min(row_count - 1)),
+            VirtualKeyCode::PageUp => Some(cursor_row.saturating_sub(page)),
+            VirtualKeyCode::PageDown => Some((cursor_row + page).min(row_count - 1)),
+            VirtualKeyCode::Home => Some(0),
+            VirtualKeyCode::End => Some(row_count - 1),
+            // collapse the node or move to the parent
+            VirtualKeyCode::Left => {
+                if tree_view.inner.is_expanded(cursor.node) {
+                    tree_view.inner.collapse(cursor.node);
+                    None
+                } else {
+                    tree_view.inner.get_parent(cursor.node).and_then(|p| tree_view.inner.get_row_of_node(p))
+                }
+            },
+            // expand the node or move to the first child
+            VirtualKeyCode::Right => {
+                let has_children = tree_view.inner.get_node(cursor.node).map(|n| n.has_children()).unwrap_or(false);
+                if !has_children {
+                    None
+                } else if !tree_view.inner.is_expanded(cursor.node) {
+                    update = toggle_node(&mut tree_view, cursor.node, tree_ref, &mut info);
+                    None
+                } else {
+                    tree_view.inner.get_row(cursor_row + 1)
+                        .filter(|r| r.depth > cursor.depth)
+                        .map(|_| cursor_row + 1)
+                }
+            },
+            VirtualKeyCode::Return => {
+                if tree_view.inner.get_node(cursor.node).map(|n| n.has_children()).unwrap_or(false) {
+                    update = toggle_node(&mut tree_view, cursor.node, tree_ref, &mut info);
+                }
+                None
+            },
+            VirtualKeyCode::Space => {
+                tree_view.inner.select(cursor.node, shift, ctrl);
+                None
+            },
+            VirtualKeyCode::A if ctrl && multiple => {
+                for row in tree_view.inner.rows.clone().as_ref().iter() {
+                    tree_view.inner.set_selected(row.node, true);
+                }
+                None
+            },
+            _ => return Update::DoNothing,
+        };
+
+        if let Some(new_row) = new_row {
+            let node = tree_view.inner.rows.as_ref()[new_row].node;
+            if ctrl && multiple {
+                // Ctrl + arrow keys only move the cursor, Ctrl + Space toggles the selection
+                tree_view.inner.cursor = Some(node).into();
+            } else {
+                tree_view.inner.select(node, shift, false);
+            }
+        }
+
+        tree_view.inner.scroll_to_cursor();
+        update.max_self(on_selection_changed(&mut tree_view, selection_before, &mut info));
+        refresh(&mut tree_view, container_id, &mut info);
+        update
+    }
+
+    pub(in super) extern "C" fn default_on_scroll(tree_view: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (_, scroll_y) = match info.get_current_mouse_state().get_scroll_amount() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // positive values = wheel moved away from the user = scroll up
+        let rows = (-scroll_y * ROWS_PER_WHEEL_NOTCH).round() as isize;
+        let first_visible_row = tree_view.inner.first_visible_row;
+        tree_view.inner.scroll_rows(rows);
+
+        if tree_view.inner.first_visible_row != first_visible_row {
+            refresh(&mut tree_view, info.get_hit_node(), &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_drag_start(tree_view: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !tree_view.allow_reorder {
+            return Update::DoNothing;
+        }
+
+        let node = match tree_view.pressed_node.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if info.start_drag(RefAny::new(TreeViewDragPayload { node }), None.into()) {
+            tree_view.dragged_node = Some(node).into();
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_drag_over(tree_view: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // only nodes of this tree can be dropped
+        let dragged_node = match tree_view.dragged_node.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let drop_target = tree_view.inner.get_drop_target_at(cursor.y)
+            .filter(|t| tree_view.inner.can_move(dragged_node, *t));
+
+        if drop_target != tree_view.drop_target.into_option() {
+            tree_view.drop_target = drop_target.into();
+            update_drop_indicator(&tree_view, info.get_hit_node(), &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_drop(tree_view: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let container_id = info.get_hit_node();
+        let dragged_node = tree_view.dragged_node.into_option();
+        let drop_target = tree_view.drop_target.into_option();
+        tree_view.dragged_node = None.into();
+        tree_view.drop_target = None.into();
+        update_drop_indicator(&tree_view, container_id, &mut info);
+
+        let (dragged_node, drop_target) = match (dragged_node, drop_target) {
+            (Some(d), Some(t)) => (d, t),
+            _ => return Update::DoNothing,
+        };
+
+        if !tree_view.inner.move_node(dragged_node, drop_target) {
+            return Update::DoNothing;
+        }
+
+        tree_view.inner.cursor = Some(dragged_node).into();
+        tree_view.inner.scroll_to_cursor();
+        refresh(&mut tree_view, container_id, &mut info);
+
+        // rustc doesn't understand the borrowing lifetime here
+        let onnodemoved = &mut tree_view.on_node_moved;
+        let inner = &tree_view.inner;
+
+        match onnodemoved.as_mut() {
+            Some(TreeViewOnNodeMoved { callback, data }) => (callback.cb)(data, &inner, dragged_node, &mut info),
+            None => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_drag_end(tree_view: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // dropped outside of the tree or cancelled
+        tree_view.pressed_node = None.into();
+        tree_view.dragged_node = None.into();
+        if tree_view.drop_target.is_some() {
+            tree_view.drop_target = None.into();
+            update_drop_indicator(&tree_view, info.get_hit_node(), &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    /// Writeback callback of the thread started by `TreeViewLoadRequest::load_in_thread`
+    pub(in super) extern "C" fn default_on_children_loaded(tree_view: &mut RefAny, mut loaded: RefAny, mut info: CallbackInfo) -> Update {
+
+        let (node, children) = match loaded.downcast_ref::<TreeViewLoadedChildren>() {
+            Some(s) => (s.node, s.children.clone()),
+            None => return Update::DoNothing,
+        };
+
+        let tree_ref = tree_view.clone();
+        let mut tree_view = match tree_view.downcast_mut::<TreeViewStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // the node was reloaded / replaced in the meantime
+        let is_loading = tree_view.inner.get_node(node).map(|n| n.data.children) == Some(TreeViewChildren::Loading);
+        if !is_loading {
+            return Update::DoNothing;
+        }
+
+        tree_view.inner.set_children(node, children);
+
+        // the writeback has no hit node: find the tree via its dataset
+        if let Some(container_id) = info.get_node_id_of_root_dataset(tree_ref) {
+            refresh(&mut tree_view, container_id, &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    /// Expands / collapses the node, calls `on_load_children` if the children aren't loaded yet
+    fn toggle_node(tree_view: &mut TreeViewStateWrapper, node: usize, tree_ref: RefAny, info: &mut CallbackInfo) -> Update {
+
+        if tree_view.inner.is_expanded(node) {
+            tree_view.inner.collapse(node);
+            return Update::DoNothing;
+        }
+
+        if !tree_view.inner.expand(node) {
+            return Update::DoNothing;
+        }
+
+        let request = TreeViewLoadRequest { node, tree: tree_ref };
+
+        // rustc doesn't understand the borrowing lifetime here
+        let onloadchildren = &mut tree_view.on_load_children;
+        let inner = &mut tree_view.inner;
+
+        let update = match onloadchildren.as_mut() {
+            Some(TreeViewOnLoadChildren { callback, data }) => (callback.cb)(data, inner, request, info),
+            // nothing can load the children: the node becomes a leaf
+            None => {
+                inner.set_children(node, TreeViewNodeDataVec::from_const_slice(&[]));
+                Update::DoNothing
+            },
+        };
+
+        // the callback may have added nodes with `add_node`
+        tree_view.inner.update_rows();
+        update
+    }
+
+    /// Calls `on_selection_change` if the selected nodes differ from `selection_before`
+    fn on_selection_changed(tree_view: &mut TreeViewStateWrapper, selection_before: Vec<usize>, info: &mut CallbackInfo) -> Update {
+
+        if tree_view.inner.get_selected_nodes() == selection_before {
+            return Update::DoNothing;
+        }
+
+        // rustc doesn't understand the borrowing lifetime here
+        let onselectionchange = &mut tree_view.on_selection_change;
+        let inner = &tree_view.inner;
+
+        match onselectionchange.as_mut() {
+            Some(TreeViewOnSelectionChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Synchronizes the row pool and the scrollbar with the state, without
+    /// regenerating the DOM: only the parts of the rows that changed are updated
+    fn refresh(tree_view: &mut TreeViewStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let nodes = match TreeViewNodes::new(container_id, info) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let visible_rows = tree_view.inner.visible_rows.max(1);
+        let first_row = tree_view.inner.first_visible_row;
+        let mut rendered_rows = core::mem::replace(&mut tree_view.rendered_rows, TreeViewRenderedRowVec::from_const_slice(&[])).into_library_owned_vec();
+        rendered_rows.resize(visible_rows, TreeViewRenderedRow::empty());
+
+        let mut row_id = info.get_first_child(nodes.rows);
+        for (row_in_view, rendered_row) in rendered_rows.iter_mut().enumerate() {
+
+            let current_row_id = match row_id {
+                Some(s) => s,
+                None => break,
+            };
+            row_id = info.get_next_sibling(current_row_id);
+
+            let new_row = tree_view.inner.get_rendered_row(first_row + row_in_view);
+            if new_row != *rendered_row {
+                update_row(current_row_id, rendered_row, &new_row, info);
+                *rendered_row = new_row;
+            }
+        }
+
+        tree_view.rendered_rows = rendered_rows.into();
+
+        match tree_view.inner.get_scrollbar_thumb() {
+            Some((thumb_top, thumb_height)) => {
+                info.set_css_property(nodes.scrollbar_thumb, CssProperty::const_display(LayoutDisplay::Block));
+                info.set_css_property(nodes.scrollbar_thumb, CssProperty::top(LayoutTop::px(thumb_top)));
+                info.set_css_property(nodes.scrollbar_thumb, CssProperty::height(LayoutHeight::px(thumb_height)));
+            },
+            None => {
+                info.set_css_property(nodes.scrollbar_thumb, CssProperty::const_display(LayoutDisplay::None));
+            },
+        }
+
+        // the rows under the drop indicator may have moved
+        if tree_view.drop_target.is_some() {
+            update_drop_indicator(tree_view, container_id, info);
+        }
+    }
+
+    /// Updates the nodes of one row of the row pool from `old` to `new`
+    fn update_row(row_id: DomNodeId, old: &TreeViewRenderedRow, new: &TreeViewRenderedRow, info: &mut CallbackInfo) {
+
+        let guides_id = match info.get_first_child(row_id) {
+            Some(s) => s,
+            None => return,
+        };
+        let toggle_id = match info.get_next_sibling(guides_id) {
+            Some(s) => s,
+            None => return,
+        };
+        let icon_id = match info.get_next_sibling(toggle_id) {
+            Some(s) => s,
+            None => return,
+        };
+        let label_id = match info.get_next_sibling(icon_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        if old.node.is_some() != new.node.is_some() {
+            let display = if new.node.is_some() { LayoutDisplay::Flex } else { LayoutDisplay::None };
+            info.set_css_property(row_id, CssProperty::const_display(display));
+        }
+
+        if old.depth != new.depth {
+            info.set_css_property(guides_id, CssProperty::width(LayoutWidth::px(new.depth as f32 * INDENT)));
+        }
+
+        if old.toggle != new.toggle {
+            info.set_string_contents(toggle_id, new.toggle.clone());
+        }
+
+        if old.icon != new.icon {
+            match new.icon.clone().into_option() {
+                Some(icon) => {
+                    info.update_image(icon_id, icon, UpdateImageType::Content);
+                    info.set_css_property(icon_id, CssProperty::const_display(LayoutDisplay::Block));
+                },
+                None => {
+                    info.set_css_property(icon_id, CssProperty::const_display(LayoutDisplay::None));
+                },
+            }
+        }
+
+        if old.label != new.label {
+            info.set_string_contents(label_id, new.label.clone());
+        }
+
+        if (old.is_selected, old.is_cursor) != (new.is_selected, new.is_cursor) {
+            info.set_css_property(row_id, CssProperty::const_background_content(get_row_background(new.is_selected, new.is_cursor)));
+        }
+    }
+
+    /// Shows a line between the rows (drop before / after) or highlights the row (drop into)
+    fn update_drop_indicator(tree_view: &TreeViewStateWrapper, container_id: DomNodeId, info: &mut CallbackInfo) {
+
+        let nodes = match TreeViewNodes::new(container_id, info) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let first_row = tree_view.inner.first_visible_row;
+        let visible_rows = tree_view.inner.visible_rows.max(1);
+
+        let target = tree_view.drop_target.into_option().and_then(|target| {
+            let row = tree_view.inner.get_row_of_node(target.node)?;
+            if row < first_row || row >= first_row + visible_rows {
+                None
+            } else {
+                Some((target, row - first_row, tree_view.inner.rows.as_ref()[row].depth))
+            }
+        });
+
+        let (target, row_in_view, depth) = match target {
+            Some(s) => s,
+            None => {
+                info.set_css_property(nodes.drop_indicator, CssProperty::const_display(LayoutDisplay::None));
+                return;
+            },
+        };
+
+        let row_top = row_in_view as f32 * ROW_HEIGHT;
+        let (top, height, background) = match target.position {
+            TreeViewDropPosition::Before => (row_top - 1.0, 2.0, DROP_LINE_BACKGROUND),
+            TreeViewDropPosition::After => (row_top + ROW_HEIGHT - 1.0, 2.0, DROP_LINE_BACKGROUND),
+            TreeViewDropPosition::Into => (row_top, ROW_HEIGHT, DROP_INTO_BACKGROUND),
+        };
+
+        info.set_css_property(nodes.drop_indicator, CssProperty::const_display(LayoutDisplay::Block));
+        info.set_css_property(nodes.drop_indicator, CssProperty::top(LayoutTop::px(top.max(0.0))));
+        info.set_css_property(nodes.drop_indicator, CssProperty::left(LayoutLeft::px((depth + 1) as f32 * INDENT)));
+        info.set_css_property(nodes.drop_indicator, CssProperty::height(LayoutHeight::px(height)));
+        info.set_css_property(nodes.drop_indicator, CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(background)));
+    }
+}
+
+impl From<TreeView> for Dom {
+    fn from(t: TreeView) -> Dom {
+        t.dom()
+    }
+}
+
+/// XML component for `<tree_view paths="Documents/notes.txt, Documents/Photos, Music" />`,
+/// see `TreeView::from_paths_str` for the syntax of the `paths`
+pub struct TreeViewRenderer {
+    node: XmlNode,
+}
+
+impl TreeViewRenderer {
+    pub fn new() -> Self {
+        Self { node: XmlNode::new("tree_view") }
+    }
+
+    fn get_tree_view(args: &FilteredComponentArguments) -> TreeView {
+
+        let get_arg = |name: &str| args.args.get(name).map(|(v, _)| v.trim());
+
+        let selection_mode = match get_arg("selection_mode") {
+            Some(m) if m.eq_ignore_ascii_case("none") => TreeViewSelectionMode::None,
+            Some(m) if m.eq_ignore_ascii_case("multiple") => TreeViewSelectionMode::Multiple,
+            _ => TreeViewSelectionMode::Single,
+        };
+
+        TreeView::from_paths_str(get_arg("paths").unwrap_or_default())
+            .with_visible_rows(get_arg("visible_rows").and_then(|v| v.parse::<usize>().ok()).unwrap_or(DEFAULT_VISIBLE_ROWS))
+            .with_selection_mode(selection_mode)
+            .with_allow_reorder(get_arg("reorderable").and_then(parse_bool).unwrap_or(false))
+    }
+}
+
+impl XmlComponent for TreeViewRenderer {
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        let mut args = ComponentArgumentsMap::default();
+        args.insert(String::from("paths"), (String::from("String"), 0));
+        args.insert(String::from("visible_rows"), (String::from("usize"), 1));
+        args.insert(String::from("selection_mode"), (String::from("azul::widgets::TreeViewSelectionMode(None|Single|Multiple)"), 2));
+        args.insert(String::from("reorderable"), (String::from("bool"), 3));
+        ComponentArguments {
+            args,
+            accepts_text: false,
+            accepts_slot: false,
+        }
+    }
+
+    fn render_dom(&self, _: &XmlComponentMap, args: &FilteredComponentArguments, _: &XmlTextContent) -> Result<StyledDom, RenderDomError> {
+        Ok(Self::get_tree_view(args).dom().style(&mut Css::empty()))
+    }
+
+    fn compile_to_rust_code(&self, _: &XmlComponentMap, _: &FilteredComponentArguments, _: &XmlTextContent) -> Result<String, CompileError> {
+        Ok(String::from("azul::widgets::TreeView::from_paths_str(paths).with_visible_rows(visible_rows).with_selection_mode(selection_mode).with_allow_reorder(reorderable).dom()"))
+    }
+
+    fn get_xml_node<'a>(&'a self) -> &'a XmlNode { &self.node }
+}
+
+#[cfg(test)]
+fn get_test_rows(state: &TreeViewState) -> Vec<(usize, usize)> {
+    state.rows.as_ref().iter().map(|r| (r.node, r.depth)).collect()
+}
+
+#[test]
+fn test_update_rows_lazy_children() {
+    let mut state = TreeViewState::new();
+    let documents = state.add_node(None, TreeViewNodeData::new("Documents".into()));
+    let notes = state.add_node(Some(documents), TreeViewNodeData::new("notes.txt".into()));
+    let photos = state.add_node(Some(documents), TreeViewNodeData::new("Photos".into()).with_lazy_children());
+    let music = state.add_node(None, TreeViewNodeData::new("Music".into()));
+    state.update_rows();
+    assert_eq!(get_test_rows(&state), vec![(documents, 0), (music, 0)]);
+
+    assert!(!state.expand(documents));
+    assert_eq!(get_test_rows(&state), vec![(documents, 0), (notes, 1), (photos, 1), (music, 0)]);
+    assert_eq!(state.get_node(notes).unwrap().get_toggle_str(), "");
+    assert_eq!(state.get_node(photos).unwrap().get_toggle_str(), TOGGLE_COLLAPSED);
+
+    // the first expand requests the children, the node stays empty until they arrive
+    assert!(state.expand(photos));
+    assert_eq!(state.get_node(photos).unwrap().data.children, TreeViewChildren::Loading);
+    assert_eq!(state.get_node(photos).unwrap().get_toggle_str(), TOGGLE_LOADING);
+    assert_eq!(get_test_rows(&state), vec![(documents, 0), (notes, 1), (photos, 1), (music, 0)]);
+    assert!(!state.expand(photos));
+
+    state.set_children(photos, vec![
+        TreeViewNodeData::new("1.jpg".into()),
+        TreeViewNodeData::new("Album".into()).with_lazy_children(),
+    ].into());
+    let (photo, album) = (4, 5);
+    assert_eq!(get_test_rows(&state), vec![
+        (documents, 0), (notes, 1), (photos, 1), (photo, 2), (album, 2), (music, 0),
+    ]);
+    assert_eq!(state.get_node(photos).unwrap().get_toggle_str(), TOGGLE_EXPANDED);
+
+    // collapsing moves the cursor out of the hidden subtree
+    state.cursor = Some(album).into();
+    state.collapse(documents);
+    assert_eq!(get_test_rows(&state), vec![(documents, 0), (music, 0)]);
+    assert_eq!(state.cursor.into_option(), Some(documents));
+
+    // reloading replaces the children, the ids of the other nodes stay the same
+    state.set_selected(photo, true);
+    state.set_children(photos, vec![TreeViewNodeData::new("2.jpg".into())].into());
+    assert_eq!(state.get_children(Some(photos)), vec![6]);
+    assert_eq!(state.get_selected_nodes(), Vec::<usize>::new());
+    state.reveal(6);
+    assert_eq!(get_test_rows(&state), vec![(documents, 0), (notes, 1), (photos, 1), (6, 2), (music, 0)]);
+}
+
+#[test]
+fn test_move_node() {
+    let mut state = TreeViewState::new();
+    let a = state.add_node(None, TreeViewNodeData::new("A".into()));
+    let b = state.add_node(None, TreeViewNodeData::new("B".into()));
+    let c = state.add_node(None, TreeViewNodeData::new("C".into()));
+    let a1 = state.add_node(Some(a), TreeViewNodeData::new("A1".into()));
+    let a2 = state.add_node(Some(a), TreeViewNodeData::new("A2".into()));
+    let lazy = state.add_node(None, TreeViewNodeData::new("Lazy".into()).with_lazy_children());
+    state.expand(a);
+    assert_eq!(get_test_rows(&state), vec![(a, 0), (a1, 1), (a2, 1), (b, 0), (c, 0), (lazy, 0)]);
+
+    let target = |node, position| TreeViewDropTarget { node, position };
+
+    assert!(state.move_node(c, target(a, TreeViewDropPosition::Before)));
+    assert!(state.move_node(a1, target(a2, TreeViewDropPosition::After)));
+    assert_eq!(state.get_children(None), vec![c, a, b, lazy]);
+    assert_eq!(state.get_children(Some(a)), vec![a2, a1]);
+
+    // dropping into a leaf makes it a (expanded) parent
+    assert!(state.move_node(b, target(a2, TreeViewDropPosition::Into)));
+    assert_eq!(get_test_rows(&state), vec![(c, 0), (a, 0), (a2, 1), (b, 2), (a1, 1), (lazy, 0)]);
+    assert_eq!(state.get_node(a2).unwrap().get_toggle_str(), TOGGLE_EXPANDED);
+
+    // not into itself, its own subtree or a node whose children aren't loaded
+    assert!(!state.can_move(a, target(a, TreeViewDropPosition::After)));
+    assert!(!state.can_move(a, target(b, TreeViewDropPosition::Into)));
+    assert!(!state.can_move(c, target(lazy, TreeViewDropPosition::Into)));
+    assert!(state.can_move(c, target(lazy, TreeViewDropPosition::After)));
+    assert!(!state.move_node(a, target(b, TreeViewDropPosition::Before)));
+    assert_eq!(state.get_children(None), vec![c, a, lazy]);
+
+    // moving the last child out of a node, the first and last roots are updated
+    assert!(state.move_node(b, target(lazy, TreeViewDropPosition::After)));
+    assert!(state.move_node(c, target(b, TreeViewDropPosition::After)));
+    assert_eq!(state.get_children(None), vec![a, lazy, b, c]);
+    assert_eq!(state.first_root.into_option(), Some(a));
+    assert_eq!(state.last_root.into_option(), Some(c));
+    assert_eq!(state.get_node(a2).unwrap().get_toggle_str(), "");
+    assert_eq!(get_test_rows(&state), vec![(a, 0), (a2, 1), (a1, 1), (lazy, 0), (b, 0), (c, 0)]);
+}
+
+#[test]
+fn test_get_drop_target_at() {
+    let mut state = TreeViewState::new();
+    let a = state.add_node(None, TreeViewNodeData::new("A".into()));
+    let b = state.add_node(None, TreeViewNodeData::new("B".into()));
+    state.update_rows();
+
+    let target = |node, position| Some(TreeViewDropTarget { node, position });
+    assert_eq!(state.get_drop_target_at(1.0), target(a, TreeViewDropPosition::Before));
+    assert_eq!(state.get_drop_target_at(ROW_HEIGHT * 0.5), target(a, TreeViewDropPosition::Into));
+    assert_eq!(state.get_drop_target_at(ROW_HEIGHT - 1.0), target(a, TreeViewDropPosition::After));
+    assert_eq!(state.get_drop_target_at(ROW_HEIGHT + 1.0), target(b, TreeViewDropPosition::Before));
+    assert_eq!(state.get_drop_target_at(ROW_HEIGHT * 2.5), None);
+    assert_eq!(state.get_drop_target_at(-1.0), None);
+}
+
+#[test]
+fn test_from_paths_str() {
+    let tree = TreeView::from_paths_str("Documents/notes.txt, Documents/Photos, Music, Documents/Photos/beach.png,,");
+    let state = &tree.state.inner;
+    let labels = state.nodes.as_ref().iter().map(|n| n.data.label.as_str()).collect::<Vec<_>>();
+    assert_eq!(labels, vec!["Documents", "notes.txt", "Photos", "Music", "beach.png"]);
+    assert_eq!(state.get_children(None), vec![0, 3]);
+    assert_eq!(state.get_children(Some(0)), vec![1, 2]);
+    assert_eq!(state.get_children(Some(2)), vec![4]);
+    assert_eq!(get_test_rows(state), vec![(0, 0), (3, 0)]);
+}
@@ -0,0 +1,1074 @@
+//! Virtualized list: renders only the rows that are visible
+//!
+//! The rows are rendered by an IFrame, so the row-render callback is invoked
+//! with the actual height of the list on every layout / resize. Only the
+//! visible rows (plus `overscan` rows above and below) are rendered, the
+//! scrollbar is computed from the total height of all rows, so that lists with
+//! millions of rows stay cheap to lay out.
+//!
+//! Scrolling moves the rendered rows and the scrollbar thumb in place (via
+//! `set_css_property`), the IFrame is only rendered again once the visible rows
+//! leave the rendered rows. The state of the list (scroll offset, measured row
+//! heights) is stored in a `RefAny` that is shared between the IFrame and the
+//! event handlers: keep a clone of it (`VirtualList::get_state`) and re-create
+//! the list with `VirtualList::from_state`, so that the scroll position
+//! survives when the DOM is regenerated.
+//!
+//! Rows either have a fixed height (`VirtualListRowHeight::Fixed`) or are as
+//! high as their content (`VirtualListRowHeight::Estimated`): in that case rows
+//! that were not rendered yet are assumed to have the estimated height, the
+//! real height of the rendered rows is measured on the next scroll / mouse
+//! event and replaces the estimate.
+
+use core::ops::Range;
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClassVec, IdOrClass, IdOrClass::Class,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::Normal,
+    },
+    styled_dom::StyledDom,
+    window::{LogicalSize, LogicalPosition},
+    callbacks::{
+        Callback, CallbackInfo, Update, RefAny,
+        DomNodeId, OptionDomNodeId,
+        IFrameCallbackInfo, IFrameCallbackReturn,
+    },
+};
+use azul_desktop::css::{
+    impl_option, impl_option_inner,
+    impl_vec, impl_vec_debug, impl_vec_clone, impl_vec_partialeq, impl_vec_mut,
+};
+use std::vec::Vec;
+
+/// Default number of rows that are rendered above and below the visible rows
+pub const DEFAULT_OVERSCAN: usize = 5;
+/// How many rows one "notch" of the mouse wheel scrolls
+const ROWS_PER_WHEEL_NOTCH: f32 = 3.0;
+/// Minimum height of the scrollbar thumb in pixels
+const MIN_THUMB_HEIGHT: f32 = 20.0;
+
+static VIRTUAL_LIST_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-virtual-list-container"))];
+static VIRTUAL_LIST_IFRAME_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-virtual-list-iframe"))];
+static VIRTUAL_LIST_ROWS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-virtual-list-rows"))];
+static VIRTUAL_LIST_ROW_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-virtual-list-row"))];
+static VIRTUAL_LIST_SCROLLBAR_TRACK_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-virtual-list-scrollbar-track"))];
+static VIRTUAL_LIST_SCROLLBAR_THUMB_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-virtual-list-scrollbar-thumb"))];
+
+pub type VirtualListRenderRowCallbackType = extern "C" fn(&mut RefAny, usize) -> StyledDom;
+
+#[repr(C)]
+pub struct VirtualListRenderRowCallback {
+    pub cb: VirtualListRenderRowCallbackType,
+}
+
+impl_callback!(VirtualListRenderRowCallback);
+
+pub type VirtualListOnScrollCallbackType = extern "C" fn(&mut RefAny, &VirtualListState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct VirtualListOnScrollCallback {
+    pub cb: VirtualListOnScrollCallbackType,
+}
+
+impl_callback!(VirtualListOnScrollCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct VirtualList {
+    /// Shared `VirtualListStateWrapper`, see `get_state` / `from_state`
+    pub state: RefAny,
+    /// Style for the outer container (receives the key events)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for the wrapper around each rendered row
+    pub row_style: NodeDataInlineCssPropertyVec,
+    /// Style for the scrollbar track
+    pub scrollbar_track_style: NodeDataInlineCssPropertyVec,
+    /// Style for the scrollbar thumb
+    pub scrollbar_thumb_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct VirtualListStateWrapper {
+    pub inner: VirtualListState,
+    /// Function that renders a single row, called only for the visible rows
+    pub render_row: VirtualListRenderRow,
+    /// Optional: Function to call when the list is scrolled
+    pub on_scroll: OptionVirtualListOnScroll,
+    /// Copied from the `VirtualList` so that the IFrame callback can use them
+    pub row_style: NodeDataInlineCssPropertyVec,
+    pub scrollbar_track_style: NodeDataInlineCssPropertyVec,
+    pub scrollbar_thumb_style: NodeDataInlineCssPropertyVec,
+    /// Rows that were rendered by the last invocation of the IFrame callback
+    pub rendered_rows_start: usize,
+    pub rendered_rows_end: usize,
+    /// Nodes inside the IFrame, set by the first event inside of the IFrame
+    /// after it was rendered (the IFrame callback doesn't know its `DomId`)
+    pub iframe_nodes: OptionVirtualListIFrameNodes,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct VirtualListRenderRow {
+    pub data: RefAny,
+    pub callback: VirtualListRenderRowCallback,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct VirtualListOnScroll {
+    pub data: RefAny,
+    pub callback: VirtualListOnScrollCallback,
+}
+
+impl_option!(VirtualListOnScroll, OptionVirtualListOnScroll, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+/// Nodes inside the IFrame that are updated in place when the list is scrolled
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct VirtualListIFrameNodes {
+    /// Container of the rendered rows
+    pub rows: DomNodeId,
+    /// Scrollbar thumb, `None` if all rows fit into the viewport
+    pub scrollbar_thumb: OptionDomNodeId,
+}
+
+impl_option!(VirtualListIFrameNodes, OptionVirtualListIFrameNodes, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+/// Height of the rows of a `VirtualList`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C, u8)]
+pub enum VirtualListRowHeight {
+    /// All rows have the same height in pixels, rows are clipped to this height
+    Fixed(f32),
+    /// Rows are as high as their content: rows that were not rendered yet
+    /// are assumed to have this height, until their real height is measured
+    Estimated(f32),
+}
+
+/// Measured height of a row (only used with `VirtualListRowHeight::Estimated`)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct VirtualListMeasuredRow {
+    pub row: usize,
+    pub height: f32,
+    /// Sum of the differences between the measured and the estimated height
+    /// of all measured rows above this row (updated by `set_row_heights`)
+    pub delta_before: f64,
+}
+
+impl_vec!(VirtualListMeasuredRow, VirtualListMeasuredRowVec, VirtualListMeasuredRowVecDestructor);
+impl_vec_debug!(VirtualListMeasuredRow, VirtualListMeasuredRowVec);
+impl_vec_clone!(VirtualListMeasuredRow, VirtualListMeasuredRowVec, VirtualListMeasuredRowVecDestructor);
+impl_vec_partialeq!(VirtualListMeasuredRow, VirtualListMeasuredRowVec);
+impl_vec_mut!(VirtualListMeasuredRow, VirtualListMeasuredRowVec);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct VirtualListState {
+    /// Total number of rows in the list
+    pub row_count: usize,
+    /// Fixed or estimated height of a single row in pixels
+    pub row_height: VirtualListRowHeight,
+    /// Number of rows to render above and below the visible rows
+    pub overscan: usize,
+    /// Current vertical scroll offset in pixels
+    pub scroll_offset: f32,
+    /// Height of the list in pixels, updated on every layout (0 before the first layout)
+    pub viewport_height: f32,
+    /// Heights of the rows that were measured so far, sorted by row
+    pub measured_rows: VirtualListMeasuredRowVec,
+}
+
+impl VirtualListState {
+
+    pub fn new(row_count: usize, row_height: VirtualListRowHeight) -> Self {
+        Self {
+            row_count,
+            row_height,
+            overscan: DEFAULT_OVERSCAN,
+            scroll_offset: 0.0,
+            viewport_height: 0.0,
+            measured_rows: VirtualListMeasuredRowVec::from_const_slice(&[]),
+        }
+    }
+
+    /// Returns the fixed row height or the estimate for rows that were not measured yet
+    #[inline]
+    pub fn get_default_row_height(&self) -> f32 {
+        match self.row_height {
+            VirtualListRowHeight::Fixed(h) | VirtualListRowHeight::Estimated(h) => h,
+        }
+    }
+
+    /// Returns the (measured or estimated) height of the given row
+    pub fn get_row_height(&self, row: usize) -> f32 {
+        match self.row_height {
+            VirtualListRowHeight::Fixed(h) => h,
+            VirtualListRowHeight::Estimated(h) => {
+                match self.measured_rows.as_slice().binary_search_by_key(&row, |m| m.row) {
+                    Ok(idx) => self.measured_rows.as_slice()[idx].height,
+                    Err(_) => h,
+                }
+            }
+        }
+    }
+
+    /// Returns the distance of the top of the row to the top of the first row
+    pub fn get_row_top(&self, row: usize) -> f32 {
+        self.get_row_top_f64(row) as f32
+    }
+
+    // f32 can't represent the top of row 300000+ to the pixel, so the offsets
+    // are computed in f64 and only converted at the end
+    fn get_row_top_f64(&self, row: usize) -> f64 {
+        let default_height = self.get_default_row_height() as f64;
+        let top = row as f64 * default_height;
+        if let VirtualListRowHeight::Estimated(_) = self.row_height {
+            let measured_rows = self.measured_rows.as_slice();
+            // number of measured rows above the row
+            let measured_above = match measured_rows.binary_search_by_key(&row, |m| m.row) {
+                Ok(idx) | Err(idx) => idx,
+            };
+            if let Some(last) = measured_above.checked_sub(1).map(|idx| &measured_rows[idx]) {
+                return top + last.delta_before + (last.height as f64 - default_height);
+            }
+        }
+        top
+    }
+
+    /// Returns the row at the given distance from the top of the first row,
+    /// `row_count` if the offset is below the last row
+    pub fn get_row_at_offset(&self, offset: f32) -> usize {
+
+        let default_height = self.get_default_row_height() as f64;
+        let offset = offset as f64;
+        if default_height <= 0.0 || offset <= 0.0 {
+            return 0;
+        }
+
+        let mut row = 0;
+        let mut top = 0.0;
+
+        if let VirtualListRowHeight::Estimated(_) = self.row_height {
+            let measured_rows = self.measured_rows.as_slice();
+            // measured rows after the end of the list are ignored
+            let measured_rows = &measured_rows[..measured_rows.partition_point(|m| m.row < self.row_count)];
+            // last measured row that starts above the offset
+            let measured_above = measured_rows.partition_point(|m| {
+                m.row as f64 * default_height + m.delta_before <= offset
+            });
+            if let Some(measured) = measured_above.checked_sub(1).map(|idx| &measured_rows[idx]) {
+                let measured_top = measured.row as f64 * default_height + measured.delta_before;
+                if offset < measured_top + measured.height as f64 {
+                    return measured.row;
+                }
+                row = measured.row + 1;
+                top = measured_top + measured.height as f64;
+            }
+        }
+
+        let remaining = ((offset - top) / default_height).floor().max(0.0) as usize;
+        row.saturating_add(remaining).min(self.row_count)
+    }
+
+    /// Height of all rows combined, i.e. the scrollable height of the list
+    #[inline]
+    pub fn get_total_height(&self) -> f32 {
+        self.get_row_top(self.row_count)
+    }
+
+    #[inline]
+    pub fn get_max_scroll_offset(&self) -> f32 {
+        (self.get_total_height() - self.viewport_height).max(0.0)
+    }
+
+    #[inline]
+    pub fn clamp_scroll_offset(&self, scroll_offset: f32) -> f32 {
+        scroll_offset.max(0.0).min(self.get_max_scroll_offset())
+    }
+
+    /// Returns the rows that intersect the viewport, without overscan
+    pub fn get_visible_rows(&self) -> Range<usize> {
+        if self.row_count == 0 || self.get_default_row_height() <= 0.0 {
+            return 0..0;
+        }
+        let first = self.get_row_at_offset(self.scroll_offset);
+        let bottom = self.scroll_offset + self.viewport_height;
+        let last = self.get_row_at_offset(bottom);
+        // the last row is only visible if its top is above the bottom edge
+        let last = if last < self.row_count && self.get_row_top(last) < bottom { last + 1 } else { last };
+        first..last.max(first)
+    }
+
+    /// Returns the rows that have to be rendered (visible rows + overscan)
+    pub fn get_rendered_rows(&self) -> Range<usize> {
+        let visible = self.get_visible_rows();
+        visible.start.saturating_sub(self.overscan)..visible.end.saturating_add(self.overscan).min(self.row_count)
+    }
+
+    /// Returns whether the visible rows are not contained in the
+    /// `rendered_rows` anymore, i.e. the rows have to be rendered again
+    pub fn needs_rerender(&self, rendered_rows: &Range<usize>) -> bool {
+        let visible = self.get_visible_rows();
+        if visible.start == visible.end {
+            return false;
+        }
+        visible.start < rendered_rows.start || visible.end > rendered_rows.end
+    }
+
+    /// Returns the (top, height) of the scrollbar thumb in pixels
+    /// or `None` if all rows fit into the viewport
+    pub fn get_scrollbar_thumb(&self) -> Option<(f32, f32)> {
+        let total_height = self.get_total_height();
+        if total_height <= self.viewport_height || self.viewport_height <= 0.0 {
+            return None;
+        }
+        let thumb_height = (self.viewport_height * self.viewport_height / total_height)
+            .max(MIN_THUMB_HEIGHT)
+            .min(self.viewport_height);
+        let max_scroll_offset = self.get_max_scroll_offset();
+        let thumb_top = if max_scroll_offset > 0.0 {
+            (self.viewport_height - thumb_height) * (self.scroll_offset / max_scroll_offset).max(0.0).min(1.0)
+        } else {
+            0.0
+        };
+        Some((thumb_top, thumb_height))
+    }
+
+    /// Stores the measured heights of the rows starting at `first_row`
+    ///
+    /// Only has an effect with `VirtualListRowHeight::Estimated`. The scroll
+    /// offset is corrected, so that the first visible row stays at the same
+    /// position on the screen. Returns whether any height changed.
+    pub fn set_row_heights(&mut self, first_row: usize, heights: &[f32]) -> bool {
+
+        if let VirtualListRowHeight::Fixed(_) = self.row_height {
+            return false;
+        }
+
+        let anchor_row = self.get_row_at_offset(self.scroll_offset);
+        let anchor_delta = self.scroll_offset - self.get_row_top(anchor_row);
+
+        let mut measured_rows = self.measured_rows.clone().into_library_owned_vec();
+        let mut changed = false;
+
+        for (i, height) in heights.iter().enumerate() {
+            let row = first_row + i;
+            if row >= self.row_count || !height.is_finite() || *height < 0.0 {
+                continue;
+            }
+            match measured_rows.binary_search_by_key(&row, |m| m.row) {
+                Ok(idx) => {
+                    if measured_rows[idx].height != *height {
+                        measured_rows[idx].height = *height;
+                        changed = true;
+                    }
+                },
+                Err(idx) => {
+                    measured_rows.insert(idx, VirtualListMeasuredRow { row, height: *height, delta_before: 0.0 });
+                    changed = true;
+                },
+            }
+        }
+
+        if !changed {
+            return false;
+        }
+
+        let default_height = self.get_default_row_height() as f64;
+        let mut delta_before = 0.0;
+        for measured in measured_rows.iter_mut() {
+            measured.delta_before = delta_before;
+            delta_before += measured.height as f64 - default_height;
+        }
+
+        self.measured_rows = measured_rows.into();
+        self.scroll_offset = self.clamp_scroll_offset(self.get_row_top(anchor_row) + anchor_delta);
+        true
+    }
+}
+
+const COLOR_F0F0F0: ColorU = ColorU::new_rgb(240, 240, 240); // scrollbar track
+const COLOR_C1C1C1: ColorU = ColorU::new_rgb(193, 193, 193); // scrollbar thumb
+
+const SCROLLBAR_TRACK_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_F0F0F0)];
+const SCROLLBAR_THUMB_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_C1C1C1)];
+
+// .__azul-native-virtual-list-container {
+//     display: flex;
+//     flex-grow: 1;
+//     overflow: hidden;
+// }
+static VIRTUAL_LIST_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static VIRTUAL_LIST_IFRAME_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+];
+
+// root of the IFrame: rows on the left, scrollbar on the right
+static VIRTUAL_LIST_ROOT_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static VIRTUAL_LIST_ROWS_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+];
+
+static VIRTUAL_LIST_ROW_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+// .__azul-native-virtual-list-scrollbar-track {
+//     width: 12px;
+//     background: #F0F0F0;
+// }
+static VIRTUAL_LIST_SCROLLBAR_TRACK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(12))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SCROLLBAR_TRACK_BACKGROUND))),
+];
+
+// .__azul-native-virtual-list-scrollbar-thumb {
+//     margin: 0px 2px;
+//     background: #C1C1C1;
+// }
+static VIRTUAL_LIST_SCROLLBAR_THUMB_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_margin_left(LayoutMarginLeft::const_px(2))),
+    Normal(CssProperty::const_margin_right(LayoutMarginRight::const_px(2))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SCROLLBAR_THUMB_BACKGROUND))),
+];
+
+impl VirtualList {
+
+    /// Creates a list with rows of a fixed height
+    pub fn new(row_count: usize, row_height: f32, data: RefAny, render_row: VirtualListRenderRowCallbackType) -> Self {
+        Self::from_state(RefAny::new(VirtualListStateWrapper {
+            inner: VirtualListState::new(row_count, VirtualListRowHeight::Fixed(row_height)),
+            render_row: VirtualListRenderRow {
+                data,
+                callback: VirtualListRenderRowCallback { cb: render_row },
+            },
+            on_scroll: None.into(),
+            row_style: NodeDataInlineCssPropertyVec::from_const_slice(&[]),
+            scrollbar_track_style: NodeDataInlineCssPropertyVec::from_const_slice(&[]),
+            scrollbar_thumb_style: NodeDataInlineCssPropertyVec::from_const_slice(&[]),
+            rendered_rows_start: 0,
+            rendered_rows_end: 0,
+            iframe_nodes: None.into(),
+        }))
+    }
+
+    /// Re-creates a list from the state of a previous list (see `get_state`),
+    /// keeping the scroll offset, the measured row heights and the callbacks
+    ///
+    /// If the `state` does not contain a `VirtualListStateWrapper`, the list is empty.
+    pub fn from_state(state: RefAny) -> Self {
+        Self {
+            state,
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(VIRTUAL_LIST_CONTAINER_STYLE),
+            row_style: NodeDataInlineCssPropertyVec::from_const_slice(VIRTUAL_LIST_ROW_STYLE),
+            scrollbar_track_style: NodeDataInlineCssPropertyVec::from_const_slice(VIRTUAL_LIST_SCROLLBAR_TRACK_STYLE),
+            scrollbar_thumb_style: NodeDataInlineCssPropertyVec::from_const_slice(VIRTUAL_LIST_SCROLLBAR_THUMB_STYLE),
+        }
+    }
+
+    /// Returns the state that is shared between the IFrame and the event handlers
+    ///
+    /// Store it in the application data and pass it to `from_state` in the
+    /// next layout callback, so that the list doesn't jump back to the top
+    /// when the DOM is regenerated.
+    #[inline]
+    pub fn get_state(&self) -> RefAny {
+        self.state.clone()
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        extern "C" fn render_nothing(_: &mut RefAny, _: usize) -> StyledDom { StyledDom::default() }
+        let mut s = Self::new(0, 0.0, RefAny::new(()), render_nothing);
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    fn with_state_mut<F: FnOnce(&mut VirtualListStateWrapper)>(&mut self, f: F) {
+        if let Some(mut state) = self.state.downcast_mut::<VirtualListStateWrapper>() {
+            f(&mut *state);
+        }
+    }
+
+    /// Sets the number of rows (i.e. if rows were added to a list created with `from_state`)
+    #[inline]
+    pub fn set_row_count(&mut self, row_count: usize) {
+        self.with_state_mut(|s| s.inner.row_count = row_count);
+    }
+
+    #[inline]
+    pub fn with_row_count(mut self, row_count: usize) -> Self {
+        self.set_row_count(row_count);
+        self
+    }
+
+    /// Sets whether the rows have a fixed or an estimated height,
+    /// discards the measured heights if the height changes
+    #[inline]
+    pub fn set_row_height(&mut self, row_height: VirtualListRowHeight) {
+        self.with_state_mut(|s| {
+            if s.inner.row_height != row_height {
+                s.inner.row_height = row_height;
+                s.inner.measured_rows = VirtualListMeasuredRowVec::from_const_slice(&[]);
+            }
+        });
+    }
+
+    #[inline]
+    pub fn with_row_height(mut self, row_height: VirtualListRowHeight) -> Self {
+        self.set_row_height(row_height);
+        self
+    }
+
+    /// Sets the scroll offset (in pixels) that the list is rendered with
+    #[inline]
+    pub fn set_scroll_offset(&mut self, scroll_offset: f32) {
+        self.with_state_mut(|s| s.inner.scroll_offset = scroll_offset.max(0.0));
+    }
+
+    #[inline]
+    pub fn with_scroll_offset(mut self, scroll_offset: f32) -> Self {
+        self.set_scroll_offset(scroll_offset);
+        self
+    }
+
+    #[inline]
+    pub fn set_overscan(&mut self, overscan: usize) {
+        self.with_state_mut(|s| s.inner.overscan = overscan);
+    }
+
+    #[inline]
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.set_overscan(overscan);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_scroll(&mut self, data: RefAny, on_scroll: VirtualListOnScrollCallbackType) {
+        self.with_state_mut(|s| {
+            s.on_scroll = Some(VirtualListOnScroll {
+                callback: VirtualListOnScrollCallback { cb: on_scroll },
+                data,
+            }).into();
+        });
+    }
+
+    #[inline]
+    pub fn with_on_scroll(mut self, data: RefAny, on_scroll: VirtualListOnScrollCallbackType) -> Self {
+        self.set_on_scroll(data, on_scroll);
+        self
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_row_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.row_style = style;
+    }
+
+    pub fn set_scrollbar_track_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.scrollbar_track_style = style;
+    }
+
+    pub fn set_scrollbar_thumb_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.scrollbar_thumb_style = style;
+    }
+
+    pub fn dom(mut self) -> Dom {
+
+        use azul_desktop::dom::{CallbackData, EventFilter, FocusEventFilter};
+
+        let row_style = self.row_style.clone();
+        let scrollbar_track_style = self.scrollbar_track_style.clone();
+        let scrollbar_thumb_style = self.scrollbar_thumb_style.clone();
+
+        self.with_state_mut(|s| {
+            s.row_style = row_style;
+            s.scrollbar_track_style = scrollbar_track_style;
+            s.scrollbar_thumb_style = scrollbar_thumb_style;
+        });
+
+        // scroll events are handled inside of the IFrame,
+        // key events need a focusable node outside of it
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(VIRTUAL_LIST_CONTAINER_CLASS))
+        .with_inline_css_props(self.container_style)
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: self.state.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down },
+            },
+        ].into())
+        .with_children(vec![
+            Dom::iframe(self.state, render_visible_rows)
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(VIRTUAL_LIST_IFRAME_CLASS))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(VIRTUAL_LIST_IFRAME_STYLE))
+        ].into())
+    }
+}
+
+fn get_rows_position(state: &VirtualListState, rendered_rows_start: usize) -> CssProperty {
+    // shift the rendered rows up, so that the row at the scroll offset is at the top
+    CssProperty::margin_top(LayoutMarginTop::px(state.get_row_top(rendered_rows_start) - state.scroll_offset))
+}
+
+fn get_thumb_position(thumb_top: f32, thumb_height: f32) -> [CssProperty;2] {
+    [
+        CssProperty::margin_top(LayoutMarginTop::px(thumb_top)),
+        CssProperty::height(LayoutHeight::px(thumb_height)),
+    ]
+}
+
+extern "C" fn render_visible_rows(data: &mut RefAny, info: IFrameCallbackInfo) -> IFrameCallbackReturn {
+
+    use azul_desktop::dom::{CallbackData, EventFilter, HoverEventFilter};
+
+    let state_ref = data.clone();
+    let mut data = match data.downcast_mut::<VirtualListStateWrapper>() {
+        Some(s) => s,
+        None => return IFrameCallbackReturn::default(),
+    };
+
+    let bounds = info.get_bounds().get_logical_size();
+    data.inner.viewport_height = bounds.height;
+    data.inner.scroll_offset = data.inner.clamp_scroll_offset(data.inner.scroll_offset);
+
+    let rendered_rows = data.inner.get_rendered_rows();
+    data.rendered_rows_start = rendered_rows.start;
+    data.rendered_rows_end = rendered_rows.end;
+    // node IDs of the previous render are not valid anymore
+    data.iframe_nodes = None.into();
+
+    let rendered_top = data.inner.get_row_top(rendered_rows.start);
+    let rendered_height = data.inner.get_row_top(rendered_rows.end) - rendered_top;
+
+    let mut rows_style = VIRTUAL_LIST_ROWS_STYLE.to_vec();
+    rows_style.push(Normal(get_rows_position(&data.inner, rendered_rows.start)));
+
+    let mut rows = Dom::div()
+    .with_ids_and_classes(IdOrClassVec::from_const_slice(VIRTUAL_LIST_ROWS_CLASS))
+    .with_inline_css_props(rows_style.into())
+    .style(&mut Css::empty());
+
+    // rows with an estimated height are as high as their content
+    let mut row_style = data.row_style.clone().into_library_owned_vec();
+    if let VirtualListRowHeight::Fixed(row_height) = data.inner.row_height {
+        row_style.push(Normal(CssProperty::const_height(LayoutHeight::px(row_height))));
+    }
+    let row_style = NodeDataInlineCssPropertyVec::from(row_style);
+
+    for row_idx in rendered_rows {
+        let row_content = (data.render_row.callback.cb)(&mut data.render_row.data, row_idx);
+        let mut row = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(VIRTUAL_LIST_ROW_CLASS))
+        .with_inline_css_props(row_style.clone())
+        .style(&mut Css::empty());
+        row.append_child(row_content);
+        rows.append_child(row);
+    }
+
+    let mut root = Dom::div()
+    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(VIRTUAL_LIST_ROOT_STYLE))
+    .with_callbacks(vec![
+        CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::Scroll),
+            data: state_ref.clone(),
+            callback: Callback { cb: self::input::default_on_scroll },
+        },
+        CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::MouseEnter),
+            data: state_ref,
+            callback: Callback { cb: self::input::default_on_mouse_enter },
+        },
+    ].into())
+    .style(&mut Css::empty());
+
+    root.append_child(rows);
+
+    if let Some((thumb_top, thumb_height)) = data.inner.get_scrollbar_thumb() {
+
+        let mut thumb_style = data.scrollbar_thumb_style.clone().into_library_owned_vec();
+        thumb_style.extend(get_thumb_position(thumb_top, thumb_height).iter().cloned().map(Normal));
+
+        root.append_child(
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(VIRTUAL_LIST_SCROLLBAR_TRACK_CLASS))
+            .with_inline_css_props(data.scrollbar_track_style.clone())
+            .with_children(vec![
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(VIRTUAL_LIST_SCROLLBAR_THUMB_CLASS))
+                .with_inline_css_props(thumb_style.into())
+            ].into())
+            .style(&mut Css::empty())
+        );
+    }
+
+    IFrameCallbackReturn {
+        dom: root,
+        scroll_size: LogicalSize::new(bounds.width, rendered_height),
+        scroll_offset: LogicalPosition::new(0.0, rendered_top),
+        virtual_scroll_size: LogicalSize::new(bounds.width, data.inner.get_total_height()),
+        virtual_scroll_offset: LogicalPosition::new(0.0, data.inner.scroll_offset),
+    }
+}
+
+// handle scroll and keyboard events for the list
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId};
+    use azul_desktop::window::VirtualKeyCode;
+    use std::vec::Vec;
+    use super::{
+        VirtualListStateWrapper, VirtualListOnScroll, VirtualListIFrameNodes,
+        ROWS_PER_WHEEL_NOTCH, get_rows_position, get_thumb_position,
+    };
+
+    pub(in super) extern "C" fn default_on_scroll(virtual_list: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut virtual_list = match virtual_list.downcast_mut::<VirtualListStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (_, scroll_y) = match info.get_current_mouse_state().get_scroll_amount() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let root_id = info.get_hit_node();
+        let mut update = measure_rendered_rows(&mut *virtual_list, root_id, &mut info);
+
+        // positive values = wheel moved away from the user = scroll up
+        let row_height = virtual_list.inner.get_default_row_height();
+        let new_offset = virtual_list.inner.scroll_offset - scroll_y * ROWS_PER_WHEEL_NOTCH * row_height;
+        update.max_self(scroll_to(&mut *virtual_list, new_offset, &mut info));
+        update
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_enter(virtual_list: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut virtual_list = match virtual_list.downcast_mut::<VirtualListStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let root_id = info.get_hit_node();
+        measure_rendered_rows(&mut *virtual_list, root_id, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(virtual_list: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut virtual_list = match virtual_list.downcast_mut::<VirtualListStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let state = &virtual_list.inner;
+        let first_visible = state.get_visible_rows().start;
+        let row_height = state.get_row_height(first_visible);
+        let previous_row_height = state.get_row_height(first_visible.saturating_sub(1));
+        let page = (state.viewport_height - row_height).max(row_height);
+
+        let new_offset = match last_keycode {
+            VirtualKeyCode::Up => state.scroll_offset - previous_row_height,
+            VirtualKeyCode::Down => state.scroll_offset + row_height,
+            VirtualKeyCode::PageUp => state.scroll_offset - page,
+            VirtualKeyCode::PageDown => state.scroll_offset + page,
+            VirtualKeyCode::Home => 0.0,
+            VirtualKeyCode::End => state.get_max_scroll_offset(),
+            _ => return Update::DoNothing,
+        };
+
+        scroll_to(&mut *virtual_list, new_offset, &mut info)
+    }
+
+    /// Stores the node IDs inside of the IFrame and the heights of the rendered rows
+    fn measure_rendered_rows(virtual_list: &mut VirtualListStateWrapper, root_id: DomNodeId, info: &mut CallbackInfo) -> Update {
+
+        // root -> [rows, scrollbar track -> [thumb]]
+        let rows_id = match info.get_first_child(root_id) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let scrollbar_thumb = info.get_next_sibling(rows_id).and_then(|track| info.get_first_child(track));
+        virtual_list.iframe_nodes = Some(VirtualListIFrameNodes {
+            rows: rows_id,
+            scrollbar_thumb: scrollbar_thumb.into(),
+        }).into();
+
+        let mut heights = Vec::new();
+        let mut row_id = info.get_first_child(rows_id);
+        while let Some(current_row_id) = row_id {
+            match info.get_node_size(current_row_id) {
+                Some(s) => heights.push(s.height),
+                None => break,
+            }
+            row_id = info.get_next_sibling(current_row_id);
+        }
+
+        let first_row = virtual_list.rendered_rows_start;
+        if !virtual_list.inner.set_row_heights(first_row, &heights) {
+            return Update::DoNothing;
+        }
+
+        // the rows above the viewport might have changed their height
+        if update_in_place(virtual_list, info) {
+            Update::DoNothing
+        } else {
+            Update::RegenerateStyledDomForCurrentWindow
+        }
+    }
+
+    fn scroll_to(virtual_list: &mut VirtualListStateWrapper, new_offset: f32, info: &mut CallbackInfo) -> Update {
+
+        let new_offset = virtual_list.inner.clamp_scroll_offset(new_offset);
+        if new_offset == virtual_list.inner.scroll_offset {
+            return Update::DoNothing;
+        }
+
+        virtual_list.inner.scroll_offset = new_offset;
+
+        // only render the rows again if the visible rows are not rendered yet
+        let mut update = if update_in_place(virtual_list, info) {
+            Update::DoNothing
+        } else {
+            Update::RegenerateStyledDomForCurrentWindow
+        };
+
+        // rustc doesn't understand the borrowing lifetime here
+        let on_scroll = &mut virtual_list.on_scroll;
+        let inner = &virtual_list.inner;
+
+        if let Some(VirtualListOnScroll { callback, data }) = on_scroll.as_mut() {
+            update.max_self((callback.cb)(data, inner, info));
+        }
+
+        update
+    }
+
+    /// Moves the rendered rows and the scrollbar thumb to the current scroll offset,
+    /// returns `false` if the rows have to be rendered again
+    fn update_in_place(virtual_list: &VirtualListStateWrapper, info: &mut CallbackInfo) -> bool {
+
+        let nodes = match virtual_list.iframe_nodes.into_option() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let rendered_rows = virtual_list.rendered_rows_start..virtual_list.rendered_rows_end;
+        if virtual_list.inner.needs_rerender(&rendered_rows) {
+            return false;
+        }
+
+        match (virtual_list.inner.get_scrollbar_thumb(), nodes.scrollbar_thumb.into_option()) {
+            (Some((thumb_top, thumb_height)), Some(thumb_id)) => {
+                for prop in get_thumb_position(thumb_top, thumb_height).iter() {
+                    info.set_css_property(thumb_id, prop.clone());
+                }
+            },
+            (None, None) => { },
+            // scrollbar has to be shown or hidden
+            _ => return false,
+        }
+
+        info.set_css_property(nodes.rows, get_rows_position(&virtual_list.inner, rendered_rows.start));
+        true
+    }
+}
+
+impl From<VirtualList> for Dom {
+    fn from(v: VirtualList) -> Dom {
+        v.dom()
+    }
+}
+
+#[test]
+fn test_visible_rows_empty_list() {
+    let mut state = VirtualListState::new(0, VirtualListRowHeight::Fixed(20.0));
+    state.viewport_height = 100.0;
+    assert_eq!(state.get_visible_rows(), 0..0);
+    assert_eq!(state.get_rendered_rows(), 0..0);
+    assert_eq!(state.get_scrollbar_thumb(), None);
+    assert_eq!(state.get_max_scroll_offset(), 0.0);
+}
+
+#[test]
+fn test_visible_rows_fixed_height() {
+    let mut state = VirtualListState::new(100, VirtualListRowHeight::Fixed(20.0));
+    state.viewport_height = 100.0;
+    state.overscan = 2;
+    assert_eq!(state.get_visible_rows(), 0..5);
+    assert_eq!(state.get_rendered_rows(), 0..7);
+
+    // partially visible rows at the top and the bottom
+    state.scroll_offset = 30.0;
+    assert_eq!(state.get_visible_rows(), 1..7);
+    assert_eq!(state.get_rendered_rows(), 0..9);
+
+    state.scroll_offset = state.get_max_scroll_offset();
+    assert_eq!(state.get_visible_rows(), 95..100);
+    assert_eq!(state.get_rendered_rows(), 93..100);
+}
+
+#[test]
+fn test_visible_rows_offset_past_end() {
+    let mut state = VirtualListState::new(10, VirtualListRowHeight::Fixed(20.0));
+    state.viewport_height = 50.0;
+    state.scroll_offset = 1000.0;
+    assert_eq!(state.get_visible_rows(), 10..10);
+    assert_eq!(state.get_rendered_rows(), 5..10);
+    assert_eq!(state.clamp_scroll_offset(state.scroll_offset), 150.0);
+
+    // the thumb stays at the end of the track
+    let (thumb_top, thumb_height) = state.get_scrollbar_thumb().unwrap();
+    assert_eq!(thumb_height, 20.0);
+    assert_eq!(thumb_top, 30.0);
+}
+
+#[test]
+fn test_visible_rows_viewport_taller_than_content() {
+    let mut state = VirtualListState::new(3, VirtualListRowHeight::Fixed(20.0));
+    state.viewport_height = 500.0;
+    assert_eq!(state.get_visible_rows(), 0..3);
+    assert_eq!(state.get_rendered_rows(), 0..3);
+    assert_eq!(state.get_max_scroll_offset(), 0.0);
+    assert_eq!(state.clamp_scroll_offset(40.0), 0.0);
+    assert_eq!(state.get_scrollbar_thumb(), None);
+}
+
+#[test]
+fn test_scrollbar_thumb() {
+    let mut state = VirtualListState::new(100, VirtualListRowHeight::Fixed(10.0));
+    state.viewport_height = 200.0;
+    assert_eq!(state.get_scrollbar_thumb(), Some((0.0, 40.0)));
+    state.scroll_offset = 400.0;
+    assert_eq!(state.get_scrollbar_thumb(), Some((80.0, 40.0)));
+    state.scroll_offset = 800.0;
+    assert_eq!(state.get_scrollbar_thumb(), Some((160.0, 40.0)));
+
+    // very long lists: the thumb doesn't get smaller than MIN_THUMB_HEIGHT
+    state.row_count = 1_000_000;
+    state.scroll_offset = 0.0;
+    assert_eq!(state.get_scrollbar_thumb(), Some((0.0, MIN_THUMB_HEIGHT)));
+
+    // not laid out yet
+    state.viewport_height = 0.0;
+    assert_eq!(state.get_scrollbar_thumb(), None);
+}
+
+#[test]
+fn test_needs_rerender() {
+    let mut state = VirtualListState::new(100, VirtualListRowHeight::Fixed(20.0));
+    state.viewport_height = 100.0;
+    state.overscan = 5;
+    let rendered_rows = state.get_rendered_rows();
+    assert_eq!(rendered_rows, 0..10);
+
+    // scrolling within the overscan only moves the rendered rows
+    state.scroll_offset = 100.0;
+    assert!(!state.needs_rerender(&rendered_rows));
+    state.scroll_offset = 110.0;
+    assert!(state.needs_rerender(&rendered_rows));
+}
+
+#[test]
+fn test_estimated_row_heights() {
+    let mut state = VirtualListState::new(100, VirtualListRowHeight::Estimated(20.0));
+    state.viewport_height = 100.0;
+    assert_eq!(state.get_total_height(), 2000.0);
+    assert_eq!(state.get_visible_rows(), 0..5);
+
+    // rows 0..5 are measured: 40px each instead of 20px
+    assert!(state.set_row_heights(0, &[40.0, 40.0, 40.0, 40.0, 40.0]));
+    assert!(!state.set_row_heights(0, &[40.0]));
+    assert_eq!(state.get_total_height(), 2100.0);
+    assert_eq!(state.get_row_top(5), 200.0);
+    assert_eq!(state.get_row_top(6), 220.0);
+    assert_eq!(state.get_row_height(4), 40.0);
+    assert_eq!(state.get_row_height(5), 20.0);
+    assert_eq!(state.get_visible_rows(), 0..3);
+    assert_eq!(state.get_row_at_offset(199.0), 4);
+    assert_eq!(state.get_row_at_offset(200.0), 5);
+    assert_eq!(state.get_row_at_offset(230.0), 6);
+    assert_eq!(state.get_row_at_offset(10_000.0), 100);
+
+    // measured rows after the end of the list are ignored
+    assert!(!state.set_row_heights(100, &[50.0]));
+}
+
+#[test]
+fn test_estimated_row_heights_keep_scroll_anchor() {
+    let mut state = VirtualListState::new(100, VirtualListRowHeight::Estimated(20.0));
+    state.viewport_height = 100.0;
+
+    // scrolled to the middle of row 10
+    state.scroll_offset = 210.0;
+    assert_eq!(state.get_row_at_offset(state.scroll_offset), 10);
+
+    // rows above the viewport turn out to be higher than estimated:
+    // the scroll offset moves down, so that row 10 stays at the same position
+    assert!(state.set_row_heights(5, &[30.0, 30.0]));
+    assert_eq!(state.scroll_offset, 230.0);
+    assert_eq!(state.get_row_at_offset(state.scroll_offset), 10);
+
+    // fixed row heights are never measured
+    let mut fixed = VirtualListState::new(100, VirtualListRowHeight::Fixed(20.0));
+    assert!(!fixed.set_row_heights(0, &[40.0]));
+    assert_eq!(fixed.get_total_height(), 2000.0);
+}
+
+#[test]
+fn test_row_top_large_row_count() {
+    let mut state = VirtualListState::new(1_000_000, VirtualListRowHeight::Estimated(17.3));
+    assert_eq!(state.get_row_top(300_001), (300_001.0_f64 * 17.3_f32 as f64) as f32);
+    assert_eq!(state.get_row_at_offset(state.get_row_top(300_001)), 300_001);
+    assert_eq!(state.get_row_at_offset(state.get_row_top(300_001) - 1.0), 300_000);
+
+    // the measured rows are accumulated in `delta_before`
+    assert!(state.set_row_heights(10, &[27.3, 7.3]));
+    assert!(state.set_row_heights(500_000, &[117.3]));
+    let measured_rows = state.measured_rows.as_slice();
+    assert_eq!(measured_rows.iter().map(|m| m.row).collect::<Vec<_>>(), vec![10, 11, 500_000]);
+    assert_eq!(measured_rows[0].delta_before, 0.0);
+    assert!((measured_rows[1].delta_before - 10.0).abs() < 0.001);
+    assert!(measured_rows[2].delta_before.abs() < 0.001);
+    assert_eq!(state.get_row_top(500_001), (500_001.0_f64 * 17.3_f32 as f64 + 100.0) as f32);
+    assert_eq!(state.get_row_at_offset(state.get_row_top(500_000) + 50.0), 500_000);
+}
@@ -1943,6 +1943,7 @@ pub fn do_the_layout_internal(
         scrollable_nodes: overflowing_rects,
         iframe_mapping: BTreeMap::new(),
         gpu_value_cache,
+        roving_focus: BTreeMap::new(),
     }
 }
 
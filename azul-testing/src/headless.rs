@@ -0,0 +1,529 @@
+//! Headless window for simulating user input
+//!
+//! Wraps a `WindowInternal` without an OS window or a renderer. Synthetic mouse
+//! and keyboard input is written into the `current_window_state`, then the same
+//! event pipeline as in the platform shells runs (`Events` -> `NodesToCheck` ->
+//! `CallbacksOfHitTest` -> `StyleAndLayoutChanges`, keyboard shortcuts before the
+//! `VirtualKeyDown` callbacks), using the CPU hit-tester (`FullHitTest::new`)
+//! instead of the webrender hit-tester.
+//!
+//! ```rust,no_run
+//! # use azul_core::{callbacks::{RefAny, LayoutCallbackInfo}, styled_dom::StyledDom, window::WindowCreateOptions};
+//! # use azul_testing::headless::HeadlessWindow;
+//! # extern "C" fn layout(_: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom { StyledDom::default() }
+//! let mut window = HeadlessWindow::new(RefAny::new(5_usize), WindowCreateOptions::new(layout));
+//! window.click(100.0, 20.0);
+//! let styled_dom = window.get_styled_dom();
+//! ```
+
+use azul_core::{
+    FastHashMap, FastBTreeSet,
+    callbacks::{RefAny, Update, DocumentId},
+    window::{
+        WindowInternal, WindowInternalInit, WindowCreateOptions, FullWindowState,
+        FullHitTest, CallCallbacksResult, CursorPosition, LogicalPosition,
+        LogicalSize, ScrollStates, VirtualKeyCode, RawWindowHandle, WindowTheme,
+        DragState,
+    },
+    window_state::{Events, NodesToCheck, CallbacksOfHitTest, StyleAndLayoutChanges},
+    dom::WindowEventFilter,
+    styled_dom::StyledDom,
+    ui_solver::LayoutResult,
+    app_resources::{ImageCache, IdNamespace},
+    task::{ExternalSystemCallbacks, TimerId, Timer, ThreadId, Thread},
+    gl::OptionGlContextPtr,
+};
+use rust_fontconfig::FcFontCache;
+
+/// What the event loop of a real window would do after an event was processed
+///
+/// The `HeadlessWindow` already performs the action (re-generating the DOM,
+/// processing the events again after a re-layout), the result is only returned for assertions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProcessEventResult {
+    DoNothing,
+    ShouldRegenerateDomCurrentWindow,
+    ShouldRegenerateDomAllWindows,
+    ShouldUpdateDisplayListCurrentWindow,
+    // node sizes changed: do another hit-test
+    UpdateHitTesterAndProcessAgain,
+    // Only refresh the display (in case of pure scroll or GPU-only events)
+    ShouldReRenderCurrentWindow,
+}
+
+/// Window without an OS window, GL context or renderer
+pub struct HeadlessWindow {
+    /// Window state, layout results, timers, etc. - same as in a real window
+    pub internal: WindowInternal,
+    /// Application data, passed to the layout and event callbacks
+    pub data: RefAny,
+    /// Windows that the callbacks requested to be created (not opened)
+    pub windows_created: Vec<WindowCreateOptions>,
+    image_cache: ImageCache,
+    fc_cache: FcFontCache,
+    system_callbacks: ExternalSystemCallbacks,
+}
+
+impl HeadlessWindow {
+
+    /// Creates the window and calls the layout callback once
+    ///
+    /// Note: building the font cache is slow, use `with_font_cache` to reuse it between tests
+    pub fn new(data: RefAny, options: WindowCreateOptions) -> Self {
+        Self::with_font_cache(data, options, azulc_lib::font_loading::build_font_cache())
+    }
+
+    pub fn with_font_cache(mut data: RefAny, options: WindowCreateOptions, mut fc_cache: FcFontCache) -> Self {
+
+        let image_cache = ImageCache::new();
+        let mut resource_updates = Vec::new();
+
+        let internal = WindowInternal::new(
+            WindowInternalInit {
+                window_create_options: options,
+                document_id: DocumentId { namespace_id: IdNamespace(0), id: 0 },
+                id_namespace: IdNamespace(0),
+            },
+            &mut data,
+            &image_cache,
+            &OptionGlContextPtr::None,
+            &mut resource_updates,
+            &crate::CALLBACKS,
+            &mut fc_cache,
+            azul_layout::do_the_relayout,
+            |window_state, scroll_states, layout_results| cpu_hit_test(window_state, scroll_states, layout_results),
+        );
+
+        Self {
+            internal,
+            data,
+            windows_created: Vec::new(),
+            image_cache,
+            fc_cache,
+            system_callbacks: ExternalSystemCallbacks::rust_internal(),
+        }
+    }
+
+    /// Returns the `StyledDom` of the root DOM (after the last re-layout)
+    pub fn get_styled_dom(&self) -> &StyledDom {
+        &self.internal.layout_results[0].styled_dom
+    }
+
+    /// Returns the layout results of all DOMs (root DOM + IFrames)
+    pub fn get_layout_results(&self) -> &[LayoutResult] {
+        &self.internal.layout_results
+    }
+
+    /// Returns the hit-test of the last processed event
+    pub fn get_last_hit_test(&self) -> &FullHitTest {
+        &self.internal.current_window_state.last_hit_test
+    }
+
+    /// Moves the mouse cursor to the given logical position
+    pub fn mouse_move(&mut self, x: f32, y: f32) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(x, y));
+        self.process_event()
+    }
+
+    /// Moves the mouse cursor out of the window
+    pub fn mouse_leave(&mut self) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.mouse_state.cursor_position = CursorPosition::OutOfWindow;
+        self.process_event()
+    }
+
+    pub fn left_mouse_down(&mut self) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.mouse_state.left_down = true;
+        self.process_event()
+    }
+
+    pub fn left_mouse_up(&mut self) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.mouse_state.left_down = false;
+        self.process_event()
+    }
+
+    pub fn right_mouse_down(&mut self) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.mouse_state.right_down = true;
+        self.process_event()
+    }
+
+    pub fn right_mouse_up(&mut self) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.mouse_state.right_down = false;
+        self.process_event()
+    }
+
+    /// Moves the mouse to (x, y), then presses and releases the left mouse button
+    pub fn click(&mut self, x: f32, y: f32) -> ProcessEventResult {
+        self.mouse_move(x, y);
+        self.left_mouse_down();
+        self.left_mouse_up()
+    }
+
+    /// Scrolls by the given amount of logical pixels at the current cursor position
+    pub fn scroll(&mut self, scroll_x: f32, scroll_y: f32) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.mouse_state.scroll_x = Some(scroll_x).into();
+        self.internal.current_window_state.mouse_state.scroll_y = Some(scroll_y).into();
+        let result = self.process_event();
+        self.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+        result
+    }
+
+    pub fn key_down(&mut self, key: VirtualKeyCode) -> ProcessEventResult {
+        self.save_previous_state();
+        let keyboard_state = &mut self.internal.current_window_state.keyboard_state;
+        keyboard_state.current_char = None.into();
+        keyboard_state.current_virtual_keycode = Some(key).into();
+        keyboard_state.pressed_virtual_keycodes.insert_hm_item(key);
+        set_modifier(keyboard_state, key, true);
+        self.process_event()
+    }
+
+    pub fn key_up(&mut self, key: VirtualKeyCode) -> ProcessEventResult {
+        self.save_previous_state();
+        let keyboard_state = &mut self.internal.current_window_state.keyboard_state;
+        keyboard_state.current_char = None.into();
+        keyboard_state.current_virtual_keycode = None.into();
+        keyboard_state.pressed_virtual_keycodes.remove_hm_item(&key);
+        set_modifier(keyboard_state, key, false);
+        self.process_event()
+    }
+
+    /// Presses and releases a key
+    pub fn key_press(&mut self, key: VirtualKeyCode) -> ProcessEventResult {
+        self.key_down(key);
+        self.key_up(key)
+    }
+
+    /// Sends one `TextInput` event per character (same as `WM_CHAR` / `ReceivedCharacter`)
+    pub fn text_input(&mut self, text: &str) -> ProcessEventResult {
+        let mut result = ProcessEventResult::DoNothing;
+        for c in text.chars() {
+            self.save_previous_state();
+            self.internal.current_window_state.keyboard_state.current_char = Some(c as u32).into();
+            result = self.process_event();
+        }
+        self.internal.current_window_state.keyboard_state.current_char = None.into();
+        result
+    }
+
+    /// Resizes the window and re-layouts the DOM (without calling the layout callback)
+    pub fn resize(&mut self, width: f32, height: f32) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.size.dimensions = LogicalSize::new(width, height);
+        let window_size = self.internal.current_window_state.size;
+        let window_theme = self.internal.current_window_state.theme;
+        let _ = self.internal.do_quick_resize(
+            &self.image_cache,
+            &crate::CALLBACKS,
+            azul_layout::do_the_relayout,
+            &self.fc_cache,
+            &window_size,
+            window_theme,
+        );
+        self.process_event()
+    }
+
+    /// Changes the theme of the window, emits a `ThemeChanged` event
+    pub fn set_theme(&mut self, theme: WindowTheme) -> ProcessEventResult {
+        self.save_previous_state();
+        self.internal.current_window_state.theme = theme;
+        self.process_event()
+    }
+
+    /// Calls the layout callback again, as if a callback returned `Update::RegenerateStyledDomForCurrentWindow`
+    pub fn regenerate_styled_dom(&mut self) {
+        let mut resource_updates = Vec::new();
+        self.internal.regenerate_styled_dom(
+            &mut self.data,
+            &self.image_cache,
+            &OptionGlContextPtr::None,
+            &mut resource_updates,
+            &crate::CALLBACKS,
+            &mut self.fc_cache,
+            azul_layout::do_the_relayout,
+            |window_state, scroll_states, layout_results| cpu_hit_test(window_state, scroll_states, layout_results),
+        );
+    }
+
+    /// Runs the same event pipeline as the platform shells on the difference
+    /// between the `previous_window_state` and the `current_window_state`
+    pub fn process_event(&mut self) -> ProcessEventResult {
+
+        let hit_test = cpu_hit_test(
+            &self.internal.current_window_state,
+            &self.internal.scroll_states,
+            &self.internal.layout_results,
+        );
+        self.internal.current_window_state.last_hit_test = hit_test;
+        self.internal.current_window_state.update_drag_state(&self.internal.previous_window_state);
+
+        let events = Events::new(
+            &self.internal.current_window_state,
+            &self.internal.previous_window_state,
+        );
+
+        let nodes_to_check = NodesToCheck::new(
+            &self.internal.current_window_state.last_hit_test,
+            &events
+        );
+
+        // keyboard shortcuts take precedence over the VirtualKeyDown callbacks
+        if events.window_events.contains(&WindowEventFilter::VirtualKeyDown) {
+            if let Some(callback_results) = self.internal.invoke_shortcut(
+                &RawWindowHandle::Unsupported,
+                &OptionGlContextPtr::None,
+                &mut self.image_cache,
+                &mut self.fc_cache,
+                &self.system_callbacks,
+            ) {
+                let result = self.process_callback_results(callback_results, &nodes_to_check);
+                self.apply_process_event_result(result);
+                return result;
+            }
+        }
+
+        let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &self.internal.layout_results);
+        let current_scroll_states = self.internal.get_current_scroll_states();
+
+        let callback_results = callbacks.call(
+            &self.internal.previous_window_state,
+            &self.internal.current_window_state,
+            &RawWindowHandle::Unsupported,
+            &current_scroll_states,
+            &OptionGlContextPtr::None,
+            &mut self.internal.layout_results,
+            &mut self.internal.scroll_states,
+            &mut self.image_cache,
+            &mut self.fc_cache,
+            &self.system_callbacks,
+            &self.internal.renderer_resources,
+        );
+
+        let result = self.process_callback_results(callback_results, &nodes_to_check);
+        self.apply_process_event_result(result);
+        result
+    }
+
+    /// Invokes all timers whose interval has elapsed, using the
+    /// `GetSystemTimeCallback` of the window to get the current time
+    pub fn run_timers(&mut self) -> Vec<ProcessEventResult> {
+
+        let timer_ids = self.internal.timers.keys().copied().collect::<Vec<_>>();
+        let mut results = Vec::new();
+
+        for timer_id in timer_ids {
+            let frame_start = (self.system_callbacks.get_system_time_fn.cb)();
+            let callback_results = self.internal.run_single_timer(
+                timer_id.id,
+                frame_start,
+                &mut self.data,
+                &RawWindowHandle::Unsupported,
+                &OptionGlContextPtr::None,
+                &mut self.image_cache,
+                &mut self.fc_cache,
+                &self.system_callbacks,
+            );
+            let nodes_to_check = NodesToCheck::empty(
+                self.internal.current_window_state.mouse_state.mouse_down(),
+                self.internal.current_window_state.focused_node,
+            );
+            let result = self.process_callback_results(callback_results, &nodes_to_check);
+            self.apply_process_event_result(result);
+            results.push(result);
+        }
+
+        results
+    }
+
+    /// Polls all running threads once and invokes the write-back callbacks
+    pub fn run_threads(&mut self) -> ProcessEventResult {
+
+        if self.internal.threads.is_empty() {
+            return ProcessEventResult::DoNothing;
+        }
+
+        let callback_results = self.internal.run_all_threads(
+            &mut self.data,
+            &RawWindowHandle::Unsupported,
+            &OptionGlContextPtr::None,
+            &mut self.image_cache,
+            &mut self.fc_cache,
+            &self.system_callbacks,
+        );
+        let nodes_to_check = NodesToCheck::empty(
+            self.internal.current_window_state.mouse_state.mouse_down(),
+            self.internal.current_window_state.focused_node,
+        );
+        let result = self.process_callback_results(callback_results, &nodes_to_check);
+        self.apply_process_event_result(result);
+        result
+    }
+
+    /// Replaces the system callbacks, i.e. to use a `FakeClock` instead of the system time
+    pub fn set_system_callbacks(&mut self, system_callbacks: ExternalSystemCallbacks) {
+        self.system_callbacks = system_callbacks;
+    }
+
+    /// Performs the action that the event loop of a real window would do
+    fn apply_process_event_result(&mut self, result: ProcessEventResult) {
+        match result {
+            ProcessEventResult::ShouldRegenerateDomCurrentWindow |
+            ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                self.regenerate_styled_dom();
+            },
+            ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                // same as AZ_REDO_HIT_TEST: the nodes were resized, so the nodes
+                // under the cursor may have changed - hit-test and process again
+                self.save_previous_state();
+                self.process_event();
+            },
+            _ => { },
+        }
+    }
+
+    /// Same as `process_callback_results` in the platform shells,
+    /// minus the synchronization with the OS window and the renderer
+    fn process_callback_results(
+        &mut self,
+        mut callback_results: CallCallbacksResult,
+        nodes_to_check: &NodesToCheck,
+    ) -> ProcessEventResult {
+
+        if let Some(drag_data) = callback_results.drag_started.take() {
+            self.internal.current_window_state.drag_state = Some(DragState::Dragging(drag_data));
+        }
+
+        self.start_stop_timers(
+            callback_results.timers.take().unwrap_or_default(),
+            callback_results.timers_removed.take().unwrap_or_default(),
+        );
+        self.start_stop_threads(
+            callback_results.threads.take().unwrap_or_default(),
+            callback_results.threads_removed.take().unwrap_or_default(),
+        );
+
+        self.windows_created.extend(callback_results.windows_created.drain(..));
+
+        let mut result = ProcessEventResult::DoNothing;
+
+        let need_scroll_render = self.internal.current_window_state
+            .get_scroll_amount()
+            .map(|se| self.internal.scroll_states.should_scroll_render(&se, &self.internal.current_window_state.last_hit_test))
+            .unwrap_or(false);
+
+        if let Some(modified) = callback_results.modified_window_state.as_ref() {
+            let current = &self.internal.current_window_state;
+            let size_changed = modified.size.get_layout_size() != current.size.get_layout_size();
+            self.internal.current_window_state = FullWindowState::from_window_state(
+                modified,
+                current.dropped_file.clone(),
+                current.hovered_file.clone(),
+                current.drag_state.clone(),
+                current.focused_node.clone(),
+                current.last_hit_test.clone(),
+            );
+            if size_changed {
+                result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+            } else if !need_scroll_render {
+                result = ProcessEventResult::ShouldReRenderCurrentWindow;
+            }
+        }
+
+        let layout_callback_changed = self.internal.current_window_state.layout_callback_changed(
+            &self.internal.previous_window_state
+        );
+
+        if layout_callback_changed {
+            return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+        } else {
+            match callback_results.callbacks_update_screen {
+                Update::RegenerateStyledDomForCurrentWindow => {
+                    return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+                },
+                Update::RegenerateStyledDomForAllWindows => {
+                    return ProcessEventResult::ShouldRegenerateDomAllWindows;
+                },
+                Update::DoNothing => { },
+            }
+        }
+
+        // Re-layout and re-style the layout results
+        let style_layout_changes = StyleAndLayoutChanges::new(
+            nodes_to_check,
+            &mut self.internal.layout_results,
+            &self.image_cache,
+            &mut self.internal.renderer_resources,
+            self.internal.current_window_state.size.get_layout_size(),
+            &self.internal.document_id,
+            callback_results.css_properties_changed.as_ref(),
+            callback_results.words_changed.as_ref(),
+            &callback_results.update_focused_node,
+            azul_layout::do_the_relayout,
+        );
+
+        if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+            self.internal.current_window_state.focused_node = focus_change.new;
+        }
+
+        let drag_ghost_changed = self.internal.current_window_state.drag_ghost_changed(
+            &self.internal.previous_window_state
+        );
+
+        if style_layout_changes.did_resize_nodes() {
+            ProcessEventResult::UpdateHitTesterAndProcessAgain
+        } else if style_layout_changes.need_regenerate_display_list() || drag_ghost_changed {
+            ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
+        } else if need_scroll_render || style_layout_changes.need_redraw() {
+            ProcessEventResult::ShouldReRenderCurrentWindow
+        } else {
+            result
+        }
+    }
+
+    fn save_previous_state(&mut self) {
+        self.internal.previous_window_state = Some(self.internal.current_window_state.clone());
+    }
+
+    fn start_stop_timers(&mut self, added: FastHashMap<TimerId, Timer>, removed: FastBTreeSet<TimerId>) {
+        for (id, timer) in added {
+            self.internal.timers.insert(id, timer);
+        }
+        for id in removed {
+            self.internal.timers.remove(&id);
+        }
+    }
+
+    fn start_stop_threads(&mut self, added: FastHashMap<ThreadId, Thread>, removed: FastBTreeSet<ThreadId>) {
+        for (id, thread) in added {
+            self.internal.threads.insert(id, thread);
+        }
+        for id in removed {
+            self.internal.threads.remove(&id);
+        }
+    }
+}
+
+fn cpu_hit_test(window_state: &FullWindowState, scroll_states: &ScrollStates, layout_results: &[LayoutResult]) -> FullHitTest {
+    FullHitTest::new(
+        layout_results,
+        &window_state.mouse_state.cursor_position,
+        scroll_states,
+        window_state.focused_node,
+    )
+}
+
+fn set_modifier(keyboard_state: &mut azul_core::window::KeyboardState, key: VirtualKeyCode, down: bool) {
+    match key {
+        VirtualKeyCode::LShift | VirtualKeyCode::RShift => keyboard_state.shift_down = down,
+        VirtualKeyCode::LControl | VirtualKeyCode::RControl => keyboard_state.ctrl_down = down,
+        VirtualKeyCode::LAlt | VirtualKeyCode::RAlt => keyboard_state.alt_down = down,
+        VirtualKeyCode::LWin | VirtualKeyCode::RWin => keyboard_state.super_down = down,
+        _ => { },
+    }
+}
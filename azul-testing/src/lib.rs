@@ -0,0 +1,49 @@
+//! Test utilities for the Azul GUI framework
+//!
+//! Renders `StyledDom`s / XML files offscreen on the CPU, so that UI tests can run
+//! under `cargo test` without a display or a GPU:
+//!
+//! ```rust,no_run
+//! use azul_testing::snapshot::{SnapshotRenderer, SnapshotOptions, SnapshotDir};
+//!
+//! let mut renderer = SnapshotRenderer::new();
+//! let image = renderer.render_xml_file("examples/rust/ui.xml", &SnapshotOptions::default()).unwrap();
+//!
+//! // compares against snapshots/ui.png, writes snapshots/ui.diff.png on failure
+//! SnapshotDir::new("snapshots").assert_matches("ui", &image, &SnapshotOptions::default()).unwrap();
+//! ```
+//!
+//! The `headless` module runs callbacks without a window: synthetic mouse and
+//! keyboard input is fed through the same hit-testing and callback dispatch as
+//! in a real window, the resulting `StyledDom` / `LayoutResult` can be inspected.
+//! The `replay` module feeds event recordings (written by the desktop shell when
+//! `AppConfig.record_events` is set) into a headless window.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/maps4print/azul/master/assets/images/azul_logo_full_min.svg.png",
+    html_favicon_url = "https://raw.githubusercontent.com/maps4print/azul/master/assets/images/favicon.ico",
+)]
+
+#[macro_use(impl_display)]
+extern crate azul_core;
+extern crate azul_css;
+extern crate azul_layout;
+extern crate azulc_lib;
+extern crate rust_fontconfig;
+extern crate tiny_skia;
+
+use azul_core::display_list::RenderCallbacks;
+
+/// Offscreen rendering of `StyledDom`s and comparison against golden PNG images
+pub mod snapshot;
+/// Headless window: simulated mouse / keyboard input against the real event pipeline
+pub mod headless;
+/// Deterministic replay of event recordings (with a fake clock for timers)
+pub mod replay;
+
+pub(crate) const CALLBACKS: RenderCallbacks = RenderCallbacks {
+    insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
+    layout_fn: azul_layout::do_the_layout,
+    load_font_fn: azulc_lib::font_loading::font_source_get_bytes,
+    parse_font_fn: azul_layout::parse_font_fn,
+};
@@ -0,0 +1,163 @@
+//! Deterministic replay of event recordings
+//!
+//! Event recordings are written by the desktop shell if `AppConfig.record_events`
+//! is set or the `AZUL_RECORD_EVENTS` environment variable points to a file
+//! (see `azul_core::recording`). The `Replayer` feeds the recorded window state
+//! changes into a `HeadlessWindow`, while a `FakeClock` replaces the system time,
+//! so that timers fire at the same (recorded) time on every run:
+//!
+//! ```rust,no_run
+//! # use azul_core::{callbacks::{RefAny, LayoutCallbackInfo}, styled_dom::StyledDom, window::WindowCreateOptions};
+//! # use azul_testing::{headless::HeadlessWindow, replay::Replayer};
+//! # extern "C" fn layout(_: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom { StyledDom::default() }
+//! let recording = std::fs::read_to_string("azul-events.txt").unwrap();
+//! let mut window = HeadlessWindow::new(RefAny::new(5_usize), WindowCreateOptions::new(layout));
+//! Replayer::from_str(&recording).unwrap().replay(&mut window);
+//! let styled_dom = window.get_styled_dom();
+//! ```
+
+use core::cell::Cell;
+use std::time::{Instant as StdInstant, Duration as StdDuration};
+use azul_core::{
+    task::{Instant, ExternalSystemCallbacks, GetSystemTimeCallback},
+    recording::{EventRecording, RecordedEvent, RecordingParseError},
+};
+use crate::headless::{HeadlessWindow, ProcessEventResult};
+
+/// Default interval in which timers and threads are polled between two events (~60 FPS)
+pub const DEFAULT_FRAME_MICROS: u64 = 16_000;
+
+thread_local! {
+    static FAKE_CLOCK: Cell<Option<(StdInstant, u64)>> = Cell::new(None);
+}
+
+extern "C" fn get_fake_system_time() -> Instant {
+    FakeClock::now().into()
+}
+
+/// Clock that only advances when told to, shared by all `HeadlessWindow`s on the current thread
+///
+/// The clock is thread-local, so tests running in parallel do not influence each other.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FakeClock;
+
+impl FakeClock {
+
+    /// Sets the clock back to zero (relative to the current system time)
+    pub fn reset() {
+        FAKE_CLOCK.with(|c| c.set(Some((StdInstant::now(), 0))));
+    }
+
+    /// Advances the clock by the given amount of microseconds
+    pub fn advance_micros(micros: u64) {
+        let (base, offset) = Self::get();
+        FAKE_CLOCK.with(|c| c.set(Some((base, offset + micros))));
+    }
+
+    /// Returns the microseconds since the last `reset()`
+    pub fn elapsed_micros() -> u64 {
+        Self::get().1
+    }
+
+    /// Returns the current (fake) time
+    pub fn now() -> StdInstant {
+        let (base, offset) = Self::get();
+        base + StdDuration::from_micros(offset)
+    }
+
+    /// Returns the system callbacks with the fake clock in place of the system time
+    pub fn system_callbacks() -> ExternalSystemCallbacks {
+        ExternalSystemCallbacks {
+            get_system_time_fn: GetSystemTimeCallback { cb: get_fake_system_time },
+            .. ExternalSystemCallbacks::rust_internal()
+        }
+    }
+
+    fn get() -> (StdInstant, u64) {
+        FAKE_CLOCK.with(|c| match c.get() {
+            Some(s) => s,
+            None => {
+                let s = (StdInstant::now(), 0);
+                c.set(Some(s));
+                s
+            }
+        })
+    }
+}
+
+/// Replays the events of one window of an `EventRecording` into a `HeadlessWindow`
+#[derive(Debug, Clone)]
+pub struct Replayer {
+    pub recording: EventRecording,
+    /// Index of the window in the recording (0 = first window that received input)
+    pub window: usize,
+    /// Interval in which timers and threads are run between two events
+    pub frame_micros: u64,
+}
+
+impl Replayer {
+
+    pub fn new(recording: EventRecording) -> Self {
+        Self { recording, window: 0, frame_micros: DEFAULT_FRAME_MICROS }
+    }
+
+    /// Parses the contents of an event recording file
+    pub fn from_str(s: &str) -> Result<Self, RecordingParseError> {
+        EventRecording::parse(s).map(Self::new)
+    }
+
+    pub fn with_window(self, window: usize) -> Self {
+        Self { window, .. self }
+    }
+
+    pub fn with_frame_micros(self, frame_micros: u64) -> Self {
+        Self { frame_micros: frame_micros.max(1), .. self }
+    }
+
+    /// Resets the `FakeClock`, installs it in the window and replays all events
+    ///
+    /// Returns the result of every processed event and of every timer / thread
+    /// callback that did not return `DoNothing`, in order.
+    pub fn replay(&self, window: &mut HeadlessWindow) -> Vec<ProcessEventResult> {
+
+        FakeClock::reset();
+        window.set_system_callbacks(FakeClock::system_callbacks());
+
+        let mut results = Vec::new();
+        let mut events = self.recording.get_window_events(self.window).peekable();
+
+        while let Some(first) = events.next() {
+
+            self.advance_to(window, first.time_micros, &mut results);
+
+            // all changes with the same timestamp were recorded from the same frame
+            window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+            apply(window, first);
+            while let Some(next) = events.next_if(|e| e.time_micros == first.time_micros) {
+                apply(window, next);
+            }
+
+            results.push(window.process_event());
+            window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+        }
+
+        results
+    }
+
+    /// Advances the clock frame by frame up to `time_micros`, running timers and threads
+    fn advance_to(&self, window: &mut HeadlessWindow, time_micros: u64, results: &mut Vec<ProcessEventResult>) {
+        while FakeClock::elapsed_micros() < time_micros {
+            let step = self.frame_micros.min(time_micros - FakeClock::elapsed_micros());
+            FakeClock::advance_micros(step);
+            results.extend(window.run_timers().into_iter().filter(|r| *r != ProcessEventResult::DoNothing));
+            match window.run_threads() {
+                ProcessEventResult::DoNothing => { },
+                r => results.push(r),
+            }
+        }
+    }
+}
+
+fn apply(window: &mut HeadlessWindow, event: &RecordedEvent) {
+    event.delta.apply(&mut window.internal.current_window_state);
+}
@@ -0,0 +1,429 @@
+//! Screenshot-based visual regression testing
+//!
+//! `StyledDom` -> layout -> `CachedDisplayList` -> CPU rasterizer -> PNG, compared
+//! against golden images stored in the repository. Set the environment variable
+//! `AZUL_UPDATE_SNAPSHOTS=1` to (re-)write the golden images instead of comparing,
+//! a missing golden image is an error otherwise.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::io::Error as IoError;
+use azul_css::ColorU;
+use azul_core::{
+    xml::{XmlComponentMap, XmlNode, str_to_dom},
+    window::{FullWindowState, LogicalSize, WindowTheme},
+    styled_dom::{StyledDom, DomId},
+    callbacks::DocumentId,
+    ui_solver::LayoutResult,
+    app_resources::{
+        IdNamespace, Epoch, RendererResources,
+        ImageCache, RawImage, RawImageData,
+    },
+    display_list::{SolvedLayout, GlTextureCache},
+};
+use azulc_lib::render::{render_display_list_cpu, CpuRenderOptions};
+use rust_fontconfig::FcFontCache;
+use tiny_skia::Pixmap;
+
+/// Environment variable that switches `SnapshotDir::assert_matches` into "update" mode
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &str = "AZUL_UPDATE_SNAPSHOTS";
+
+/// Parameters for rendering and comparing a snapshot
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SnapshotOptions {
+    /// Logical size of the (fake) window
+    pub size: LogicalSize,
+    /// Theme of the (fake) window
+    pub theme: WindowTheme,
+    /// HiDPI factor, the PNG will have `size * dpi_factor` pixels
+    pub dpi_factor: f32,
+    /// Color of the window background
+    pub background_color: ColorU,
+    /// Maximum difference per color channel (0 - 255) for two pixels to be considered equal
+    pub tolerance: u8,
+    /// How many pixels may differ (by more than `tolerance`) before the comparison fails
+    pub max_differing_pixels: usize,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self {
+            size: LogicalSize::new(800.0, 600.0),
+            theme: WindowTheme::LightMode,
+            dpi_factor: 1.0,
+            background_color: ColorU::WHITE,
+            // allow small antialiasing differences between font rasterizers
+            tolerance: 2,
+            max_differing_pixels: 0,
+        }
+    }
+}
+
+impl SnapshotOptions {
+    pub fn with_size(self, size: LogicalSize) -> Self { Self { size, .. self } }
+    pub fn with_theme(self, theme: WindowTheme) -> Self { Self { theme, .. self } }
+    pub fn with_dpi_factor(self, dpi_factor: f32) -> Self { Self { dpi_factor, .. self } }
+    pub fn with_tolerance(self, tolerance: u8) -> Self { Self { tolerance, .. self } }
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// Could not read or write a file
+    Io(PathBuf, IoError),
+    /// The XML could not be parsed or rendered into a `StyledDom`
+    Xml(String),
+    /// The display list could not be rendered (e.g. zero-sized window)
+    RenderFailed,
+    /// The golden image could not be decoded / the snapshot could not be encoded as PNG
+    Png(PathBuf, String),
+    /// The golden image `{name}.png` does not exist (and `AZUL_UPDATE_SNAPSHOTS` is not set),
+    /// the rendered image was written to `actual_path`
+    MissingGolden { name: String, actual_path: PathBuf },
+    /// The golden image has a different size than the rendered image
+    SizeMismatch { name: String, expected: (u32, u32), actual: (u32, u32) },
+    /// Too many pixels differ, the diff image was written to `diff_path`
+    Mismatch { name: String, differing_pixels: usize, max_channel_delta: u8, diff_path: PathBuf },
+}
+
+impl_display!(SnapshotError, {
+    Io(path, e) => format!("snapshot: I/O error on \"{}\": {}", path.display(), e),
+    Xml(e) => format!("snapshot: could not build DOM from XML: {}", e),
+    RenderFailed => format!("snapshot: could not render display list"),
+    Png(path, e) => format!("snapshot: PNG error on \"{}\": {}", path.display(), e),
+    MissingGolden { name, actual_path } => format!(
+        "snapshot \"{}\": golden image is missing, rendered image written to \"{}\" (set {}=1 to accept it)",
+        name, actual_path.display(), UPDATE_SNAPSHOTS_ENV_VAR
+    ),
+    SizeMismatch { name, expected, actual } => format!(
+        "snapshot \"{}\": size mismatch: expected {}x{}, got {}x{}",
+        name, expected.0, expected.1, actual.0, actual.1
+    ),
+    Mismatch { name, differing_pixels, max_channel_delta, diff_path } => format!(
+        "snapshot \"{}\": {} pixels differ (max. channel delta: {}), diff written to \"{}\"",
+        name, differing_pixels, max_channel_delta, diff_path.display()
+    ),
+});
+
+impl std::error::Error for SnapshotError { }
+
+/// Renders `StyledDom`s offscreen, caches the system font list between renders
+pub struct SnapshotRenderer {
+    fc_cache: FcFontCache,
+}
+
+impl SnapshotRenderer {
+
+    /// Note: building the font cache is slow, reuse the renderer if possible
+    pub fn new() -> Self {
+        Self { fc_cache: azulc_lib::font_loading::build_font_cache() }
+    }
+
+    pub fn with_font_cache(fc_cache: FcFontCache) -> Self {
+        Self { fc_cache }
+    }
+
+    /// Lays out and rasterizes the `StyledDom` at the size, theme and DPI given in the options
+    pub fn render_styled_dom(&mut self, styled_dom: StyledDom, options: &SnapshotOptions) -> Result<Pixmap, SnapshotError> {
+
+        let epoch = Epoch(0);
+        let document_id = DocumentId { namespace_id: IdNamespace(0), id: 0 };
+        let image_cache = ImageCache::default();
+        let mut renderer_resources = RendererResources::default();
+
+        let mut fake_window_state = FullWindowState::default();
+        fake_window_state.size.dimensions = options.size;
+        fake_window_state.size.hidpi_factor = options.dpi_factor;
+        fake_window_state.size.system_hidpi_factor = options.dpi_factor;
+        fake_window_state.theme = options.theme;
+
+        let layout_results = solve_layout(
+            styled_dom,
+            document_id,
+            epoch,
+            &fake_window_state,
+            &image_cache,
+            &self.fc_cache,
+            &mut renderer_resources,
+        );
+
+        let display_list = LayoutResult::get_cached_display_list(
+            &document_id,
+            DomId::ROOT_ID,
+            epoch,
+            &layout_results,
+            &fake_window_state,
+            &GlTextureCache::default(),
+            &renderer_resources,
+            &image_cache,
+        );
+
+        let image = render_display_list_cpu(
+            &display_list,
+            &layout_results,
+            &renderer_resources,
+            &image_cache,
+            CpuRenderOptions {
+                background_color: options.background_color,
+                dpi_factor: options.dpi_factor,
+            },
+        ).ok_or(SnapshotError::RenderFailed)?;
+
+        raw_image_to_pixmap(image).ok_or(SnapshotError::RenderFailed)
+    }
+
+    /// Renders a parsed Azul XML document (`<html><head>...</head><body>...</body></html>`)
+    pub fn render_xml_nodes(&mut self, root_nodes: &[XmlNode], options: &SnapshotOptions) -> Result<Pixmap, SnapshotError> {
+        let mut component_map = XmlComponentMap::default();
+        let styled_dom = str_to_dom(root_nodes, &mut component_map).map_err(|e| SnapshotError::Xml(format!("{}", e)))?;
+        self.render_styled_dom(styled_dom, options)
+    }
+
+    pub fn render_xml_str(&mut self, xml: &str, options: &SnapshotOptions) -> Result<Pixmap, SnapshotError> {
+        let root_nodes = azulc_lib::xml::parse_xml_string(xml).map_err(|e| SnapshotError::Xml(format!("{}", e)))?;
+        self.render_xml_nodes(root_nodes.as_ref(), options)
+    }
+
+    pub fn render_xml_file<P: AsRef<Path>>(&mut self, path: P, options: &SnapshotOptions) -> Result<Pixmap, SnapshotError> {
+        let path = path.as_ref();
+        let xml = fs::read_to_string(path).map_err(|e| SnapshotError::Io(path.to_path_buf(), e))?;
+        self.render_xml_str(&xml, options)
+    }
+}
+
+/// Directory containing the golden `{name}.png` images
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDir {
+    pub path: PathBuf,
+    /// If true, golden images are overwritten instead of compared
+    pub update: bool,
+}
+
+impl SnapshotDir {
+
+    /// Creates a new snapshot dir, `update` is initialized from the `AZUL_UPDATE_SNAPSHOTS` environment variable
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        let update = std::env::var(UPDATE_SNAPSHOTS_ENV_VAR).map(|v| v == "1" || v == "true").unwrap_or(false);
+        Self { path: path.into(), update }
+    }
+
+    pub fn golden_path(&self, name: &str) -> PathBuf { self.path.join(format!("{}.png", name)) }
+    pub fn actual_path(&self, name: &str) -> PathBuf { self.path.join(format!("{}.actual.png", name)) }
+    pub fn diff_path(&self, name: &str) -> PathBuf { self.path.join(format!("{}.diff.png", name)) }
+
+    /// Compares the image against the golden image `{name}.png`
+    ///
+    /// - If `update` is set, the golden image is (over)written and the check passes
+    /// - If the golden image does not exist, `{name}.actual.png` is written and the check fails
+    /// - On failure, `{name}.actual.png` and `{name}.diff.png` are written next to the golden image
+    /// - On success, stale `.actual.png` / `.diff.png` files are removed
+    pub fn assert_matches(&self, name: &str, actual: &Pixmap, options: &SnapshotOptions) -> Result<(), SnapshotError> {
+
+        let golden_path = self.golden_path(name);
+        let actual_path = self.actual_path(name);
+        let diff_path = self.diff_path(name);
+
+        if self.update {
+            save_png(actual, &golden_path)?;
+            let _ = fs::remove_file(&actual_path);
+            let _ = fs::remove_file(&diff_path);
+            return Ok(());
+        }
+
+        if !golden_path.exists() {
+            save_png(actual, &actual_path)?;
+            return Err(SnapshotError::MissingGolden { name: name.to_string(), actual_path });
+        }
+
+        let golden = Pixmap::load_png(&golden_path).map_err(|e| SnapshotError::Png(golden_path.clone(), format!("{}", e)))?;
+
+        if golden.width() != actual.width() || golden.height() != actual.height() {
+            save_png(actual, &actual_path)?;
+            return Err(SnapshotError::SizeMismatch {
+                name: name.to_string(),
+                expected: (golden.width(), golden.height()),
+                actual: (actual.width(), actual.height()),
+            });
+        }
+
+        let diff = compare_pixmaps(&golden, actual, options.tolerance);
+
+        if diff.differing_pixels > options.max_differing_pixels {
+            save_png(actual, &actual_path)?;
+            save_png(&diff.diff_image, &diff_path)?;
+            return Err(SnapshotError::Mismatch {
+                name: name.to_string(),
+                differing_pixels: diff.differing_pixels,
+                max_channel_delta: diff.max_channel_delta,
+                diff_path,
+            });
+        }
+
+        let _ = fs::remove_file(&actual_path);
+        let _ = fs::remove_file(&diff_path);
+
+        Ok(())
+    }
+}
+
+/// Result of comparing two images of the same size
+#[derive(Debug, Clone)]
+pub struct ImageDiff {
+    /// Number of pixels where any channel differs by more than the tolerance
+    pub differing_pixels: usize,
+    /// Largest difference of a single color channel
+    pub max_channel_delta: u8,
+    /// Faded-out copy of the expected image, differing pixels are painted red
+    pub diff_image: Pixmap,
+}
+
+/// Compares two pixmaps of the same size pixel-by-pixel
+///
+/// Panics if the sizes of the two images differ.
+pub fn compare_pixmaps(expected: &Pixmap, actual: &Pixmap, tolerance: u8) -> ImageDiff {
+
+    assert_eq!((expected.width(), expected.height()), (actual.width(), actual.height()));
+
+    let mut diff_image = Pixmap::new(expected.width(), expected.height()).unwrap();
+    let mut differing_pixels = 0;
+    let mut max_channel_delta = 0;
+
+    let pixels = expected.data().chunks_exact(4).zip(actual.data().chunks_exact(4));
+    for ((e, a), d) in pixels.zip(diff_image.data_mut().chunks_exact_mut(4)) {
+
+        let delta = e.iter().zip(a.iter()).map(|(e, a)| (*e as i16 - *a as i16).abs() as u8).max().unwrap_or(0);
+        max_channel_delta = max_channel_delta.max(delta);
+
+        if delta > tolerance {
+            differing_pixels += 1;
+            d.copy_from_slice(&[255, 0, 0, 255]);
+        } else {
+            // grayscale, faded version of the expected image for orientation
+            let gray = ((e[0] as u16 + e[1] as u16 + e[2] as u16) / 3) as u8;
+            let faded = 255 - ((255 - gray) / 4);
+            d.copy_from_slice(&[faded, faded, faded, 255]);
+        }
+    }
+
+    ImageDiff { differing_pixels, max_channel_delta, diff_image }
+}
+
+fn solve_layout(
+    styled_dom: StyledDom,
+    document_id: DocumentId,
+    epoch: Epoch,
+    fake_window_state: &FullWindowState,
+    image_cache: &ImageCache,
+    fc_cache: &FcFontCache,
+    renderer_resources: &mut RendererResources,
+) -> Vec<LayoutResult> {
+
+    let mut resource_updates = Vec::new();
+    let solved_layout = SolvedLayout::new(
+        styled_dom,
+        epoch,
+        &document_id,
+        fake_window_state,
+        &mut resource_updates,
+        IdNamespace(0),
+        image_cache,
+        fc_cache,
+        &crate::CALLBACKS,
+        renderer_resources,
+    );
+
+    solved_layout.layout_results
+}
+
+fn raw_image_to_pixmap(image: RawImage) -> Option<Pixmap> {
+    let mut pixmap = Pixmap::new(image.width as u32, image.height as u32)?;
+    match &image.pixels {
+        RawImageData::U8(u) if u.as_ref().len() == pixmap.data().len() => {
+            pixmap.data_mut().copy_from_slice(u.as_ref());
+        },
+        _ => return None,
+    }
+    Some(pixmap)
+}
+
+fn save_png(pixmap: &Pixmap, path: &Path) -> Result<(), SnapshotError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| SnapshotError::Io(parent.to_path_buf(), e))?;
+    }
+    pixmap.save_png(path).map_err(|e| SnapshotError::Png(path.to_path_buf(), format!("{}", e)))
+}
+
+#[cfg(test)]
+fn solid_pixmap(width: u32, height: u32, rgba: [u8;4]) -> Pixmap {
+    let mut pixmap = Pixmap::new(width, height).unwrap();
+    for p in pixmap.data_mut().chunks_exact_mut(4) {
+        p.copy_from_slice(&rgba);
+    }
+    pixmap
+}
+
+#[test]
+fn test_compare_pixmaps() {
+
+    let expected = solid_pixmap(4, 2, [100, 100, 100, 255]);
+
+    let diff = compare_pixmaps(&expected, &expected, 0);
+    assert_eq!(diff.differing_pixels, 0);
+    assert_eq!(diff.max_channel_delta, 0);
+
+    // two pixels differ: one within the tolerance, one outside of it
+    let mut actual = expected.clone();
+    actual.data_mut()[0..4].copy_from_slice(&[102, 100, 100, 255]);
+    actual.data_mut()[12..16].copy_from_slice(&[100, 100, 90, 255]);
+
+    let diff = compare_pixmaps(&expected, &actual, 2);
+    assert_eq!(diff.differing_pixels, 1);
+    assert_eq!(diff.max_channel_delta, 10);
+    // the differing pixel is painted red in the diff image, the others are faded gray
+    assert_eq!(&diff.diff_image.data()[12..16], &[255, 0, 0, 255]);
+    assert_eq!(&diff.diff_image.data()[0..4], &[217, 217, 217, 255]);
+
+    // the tolerance is inclusive
+    assert_eq!(compare_pixmaps(&expected, &actual, 10).differing_pixels, 0);
+    assert_eq!(compare_pixmaps(&expected, &actual, 1).differing_pixels, 2);
+}
+
+#[test]
+#[should_panic]
+fn test_compare_pixmaps_size_mismatch() {
+    compare_pixmaps(&solid_pixmap(4, 2, [0, 0, 0, 255]), &solid_pixmap(2, 4, [0, 0, 0, 255]), 0);
+}
+
+#[test]
+fn test_snapshot_dir_missing_golden() {
+
+    let path = std::env::temp_dir().join(format!("azul-test-snapshot-dir-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+
+    let options = SnapshotOptions::default();
+    let image = solid_pixmap(4, 2, [0, 0, 255, 255]);
+    let mut snapshot_dir = SnapshotDir { path: path.clone(), update: false };
+
+    // a missing golden image fails the check instead of being written
+    match snapshot_dir.assert_matches("blue", &image, &options) {
+        Err(SnapshotError::MissingGolden { actual_path, .. }) => assert_eq!(actual_path, snapshot_dir.actual_path("blue")),
+        other => panic!("expected MissingGolden, got {:?}", other),
+    }
+    assert!(!snapshot_dir.golden_path("blue").exists());
+    assert!(snapshot_dir.actual_path("blue").exists());
+
+    // update mode writes the golden image and removes the stale actual image
+    snapshot_dir.update = true;
+    snapshot_dir.assert_matches("blue", &image, &options).unwrap();
+    assert!(snapshot_dir.golden_path("blue").exists());
+    assert!(!snapshot_dir.actual_path("blue").exists());
+
+    snapshot_dir.update = false;
+    snapshot_dir.assert_matches("blue", &image, &options).unwrap();
+    match snapshot_dir.assert_matches("blue", &solid_pixmap(4, 2, [255, 0, 0, 255]), &options) {
+        Err(SnapshotError::Mismatch { differing_pixels, .. }) => assert_eq!(differing_pixels, 8),
+        other => panic!("expected Mismatch, got {:?}", other),
+    }
+    assert!(snapshot_dir.diff_path("blue").exists());
+
+    let _ = fs::remove_dir_all(&path);
+}
@@ -0,0 +1,779 @@
+//! Simulated input against a `HeadlessWindow`: the callbacks modify the
+//! application data, the tests check the re-generated `StyledDom`
+
+extern crate azul_core;
+extern crate azul_css;
+extern crate azul_css_parser;
+extern crate azul_testing;
+
+use azul_core::{
+    callbacks::{RefAny, Callback, CallbackInfo, LayoutCallbackInfo, Update, DomNodeId},
+    dom::{
+        Dom, NodeType, IdOrClass, IdOrClassVec, CallbackData, EventFilter, HoverEventFilter,
+        FocusEventFilter, TabIndex, FocusScope, RovingFocus,
+    },
+    focus::constrain_focus,
+    id_tree::NodeId,
+    resources::OptionImageRef,
+    shortcuts::{ShortcutSource, get_matching_shortcut, get_shortcut_conflicts},
+    styled_dom::{StyledDom, DomId, AzNodeId},
+    window::{
+        WindowCreateOptions, KeyboardState, VirtualKeyCode, VirtualKeyCodeCombo,
+        Menu, MenuItem, StringMenuItem, MenuItemState, MenuCallback, LogicalPosition,
+    },
+};
+use azul_css::{AzString, CssProperty, LayoutHeight};
+use azul_testing::{
+    headless::{HeadlessWindow, ProcessEventResult},
+    replay::Replayer,
+};
+
+const CSS: &str = "
+    #button { width: 100px; height: 40px; }
+    #input { width: 100px; height: 40px; }
+";
+
+struct AppData {
+    clicks: usize,
+    text: String,
+}
+
+extern "C" fn layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    let (clicks, text) = match data.downcast_ref::<AppData>() {
+        Some(s) => (s.clicks, s.text.clone()),
+        None => return StyledDom::default(),
+    };
+
+    let mut css = azul_css_parser::new_from_str(CSS).unwrap();
+
+    Dom::body()
+    .with_children(vec![
+        Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Id(AzString::from_const_str("button"))].into())
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::MouseUp),
+            callback: Callback { cb: on_click },
+            data: data.clone(),
+        }].into()),
+        Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Id(AzString::from_const_str("input"))].into())
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Focus(FocusEventFilter::TextInput),
+            callback: Callback { cb: on_text_input },
+            data: data.clone(),
+        }].into()),
+        Dom::text(format!("clicked {} times", clicks)),
+        Dom::text(text),
+    ].into())
+    .style(&mut css)
+}
+
+extern "C" fn on_click(data: &mut RefAny, _: CallbackInfo) -> Update {
+    match data.downcast_mut::<AppData>() {
+        Some(mut s) => {
+            s.clicks += 1;
+            Update::RegenerateStyledDomForCurrentWindow
+        },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_text_input(data: &mut RefAny, info: CallbackInfo) -> Update {
+    let c = match info.get_current_keyboard_state().current_char.into_option().and_then(core::char::from_u32) {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    match data.downcast_mut::<AppData>() {
+        Some(mut s) => {
+            s.text.push(c);
+            Update::RegenerateStyledDomForCurrentWindow
+        },
+        None => Update::DoNothing,
+    }
+}
+
+fn new_window() -> HeadlessWindow {
+    let data = RefAny::new(AppData { clicks: 0, text: String::new() });
+    HeadlessWindow::new(data, WindowCreateOptions::new(layout))
+}
+
+fn texts(styled_dom: &StyledDom) -> Vec<String> {
+    styled_dom.node_data.as_ref().iter().filter_map(|n| match n.get_node_type() {
+        NodeType::Text(t) => Some(t.as_str().to_string()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn click_button_changes_label() {
+
+    let mut window = new_window();
+    assert_eq!(texts(window.get_styled_dom())[0], "clicked 0 times");
+
+    let result = window.click(50.0, 20.0);
+    assert_eq!(result, ProcessEventResult::ShouldRegenerateDomCurrentWindow);
+    assert_eq!(texts(window.get_styled_dom())[0], "clicked 1 times");
+
+    window.click(50.0, 20.0);
+    assert_eq!(texts(window.get_styled_dom())[0], "clicked 2 times");
+
+    // clicking outside of the button does nothing
+    let result = window.click(500.0, 500.0);
+    assert_eq!(result, ProcessEventResult::DoNothing);
+    assert_eq!(texts(window.get_styled_dom())[0], "clicked 2 times");
+}
+
+#[test]
+fn text_input_goes_to_focused_node() {
+
+    let mut window = new_window();
+
+    // no node is focused yet, text input is ignored
+    window.text_input("x");
+    assert_eq!(texts(window.get_styled_dom())[1], "");
+
+    // the input is below the button
+    window.click(50.0, 60.0);
+    assert!(window.internal.current_window_state.focused_node.is_some());
+
+    // the focus is preserved when the DOM is re-generated after every character
+    window.text_input("abc");
+    assert_eq!(texts(window.get_styled_dom())[1], "abc");
+    assert!(window.internal.current_window_state.focused_node.is_some());
+}
+
+// a (0..20) shrinks to 5px when clicked, which moves b (20..40) under the cursor
+const RESIZE_CSS: &str = "
+    #a { width: 100px; height: 20px; }
+    #b { width: 100px; height: 20px; }
+";
+
+struct ResizeAppData {
+    b_entered: usize,
+}
+
+extern "C" fn resize_layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    let mut css = azul_css_parser::new_from_str(RESIZE_CSS).unwrap();
+    let id = |id: &'static str| -> IdOrClassVec { vec![IdOrClass::Id(AzString::from_const_str(id))].into() };
+
+    Dom::body()
+    .with_children(vec![
+        Dom::div()
+        .with_ids_and_classes(id("a"))
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::MouseUp),
+            callback: Callback { cb: on_shrink },
+            data: data.clone(),
+        }].into()),
+        Dom::div()
+        .with_ids_and_classes(id("b"))
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::MouseEnter),
+            callback: Callback { cb: on_mouse_enter },
+            data: data.clone(),
+        }].into()),
+    ].into())
+    .style(&mut css)
+}
+
+extern "C" fn on_shrink(_: &mut RefAny, mut info: CallbackInfo) -> Update {
+    let hit_node = info.get_hit_node();
+    info.set_css_property(hit_node, CssProperty::height(LayoutHeight::px(5.0)));
+    Update::DoNothing
+}
+
+extern "C" fn on_mouse_enter(data: &mut RefAny, _: CallbackInfo) -> Update {
+    if let Some(mut s) = data.downcast_mut::<ResizeAppData>() {
+        s.b_entered += 1;
+    }
+    Update::DoNothing
+}
+
+#[test]
+fn resized_nodes_are_hit_tested_again() {
+
+    let data = RefAny::new(ResizeAppData { b_entered: 0 });
+    let mut window = HeadlessWindow::new(data, WindowCreateOptions::new(resize_layout));
+
+    let result = window.click(50.0, 10.0);
+    assert_eq!(result, ProcessEventResult::UpdateHitTesterAndProcessAgain);
+
+    // the events were processed again against the new layout, without moving the mouse
+    assert_eq!(window.data.downcast_ref::<ResizeAppData>().unwrap().b_entered, 1);
+    assert!(window.get_last_hit_test().hovered_nodes[&DomId::ROOT_ID].regular_hit_test_nodes.contains_key(&NodeId::new(2)));
+}
+
+#[test]
+fn replay_recorded_clicks() {
+
+    let recording = "azul-event-recording 1
+0 0 cursor 50 20
+100000 0 mouse 1 0 0
+150000 0 mouse 0 0 0
+900000 0 mouse 1 0 0
+950000 0 mouse 0 0 0
+1000000 0 cursor 500 500
+1100000 0 mouse 1 0 0
+1150000 0 mouse 0 0 0
+";
+
+    let replayer = Replayer::from_str(recording).unwrap();
+    assert_eq!(replayer.recording.to_string(), recording);
+
+    let mut window = new_window();
+    let results = replayer.replay(&mut window);
+    assert_eq!(results.len(), 7);
+    assert_eq!(results.iter().filter(|r| **r == ProcessEventResult::ShouldRegenerateDomCurrentWindow).count(), 2);
+    assert_eq!(texts(window.get_styled_dom())[0], "clicked 2 times");
+}
+
+const DRAG_CSS: &str = "
+    #source { width: 100px; height: 40px; }
+    #target { width: 100px; height: 40px; }
+";
+
+struct DragAppData {
+    dropped: Option<usize>,
+}
+
+extern "C" fn drag_layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    let dropped = match data.downcast_ref::<DragAppData>() {
+        Some(s) => s.dropped,
+        None => return StyledDom::default(),
+    };
+
+    let mut css = azul_css_parser::new_from_str(DRAG_CSS).unwrap();
+
+    Dom::body()
+    .with_children(vec![
+        Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Id(AzString::from_const_str("source"))].into())
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::DragStart),
+            callback: Callback { cb: on_drag_start },
+            data: data.clone(),
+        }].into()),
+        Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Id(AzString::from_const_str("target"))].into())
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::Drop),
+            callback: Callback { cb: on_drop },
+            data: data.clone(),
+        }].into()),
+        Dom::text(format!("dropped {:?}", dropped)),
+    ].into())
+    .style(&mut css)
+}
+
+extern "C" fn on_drag_start(_: &mut RefAny, mut info: CallbackInfo) -> Update {
+    info.start_drag(RefAny::new(5_usize), OptionImageRef::None);
+    Update::DoNothing
+}
+
+extern "C" fn on_drop(data: &mut RefAny, info: CallbackInfo) -> Update {
+    let mut drag = match info.get_drag_data().into_option() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let payload = match drag.payload.downcast_ref::<usize>() {
+        Some(s) => *s,
+        None => return Update::DoNothing,
+    };
+    match data.downcast_mut::<DragAppData>() {
+        Some(mut s) => {
+            s.dropped = Some(payload);
+            Update::RegenerateStyledDomForCurrentWindow
+        },
+        None => Update::DoNothing,
+    }
+}
+
+#[test]
+fn drag_and_drop_between_nodes() {
+
+    let data = RefAny::new(DragAppData { dropped: None });
+    let mut window = HeadlessWindow::new(data, WindowCreateOptions::new(drag_layout));
+
+    window.mouse_move(50.0, 20.0);
+    window.left_mouse_down();
+    assert!(!window.internal.current_window_state.is_dragging());
+
+    // the drag only starts once the cursor moved past the threshold
+    window.mouse_move(51.0, 21.0);
+    assert!(!window.internal.current_window_state.is_dragging());
+    window.mouse_move(50.0, 30.0);
+    assert!(window.internal.current_window_state.is_dragging());
+
+    window.mouse_move(50.0, 60.0);
+    assert!(window.internal.current_window_state.is_dragging());
+
+    let result = window.left_mouse_up();
+    assert_eq!(result, ProcessEventResult::ShouldRegenerateDomCurrentWindow);
+    assert!(!window.internal.current_window_state.is_dragging());
+    assert_eq!(texts(window.get_styled_dom())[0], "dropped Some(5)");
+}
+
+#[test]
+fn drop_outside_of_target_does_nothing() {
+
+    let data = RefAny::new(DragAppData { dropped: None });
+    let mut window = HeadlessWindow::new(data, WindowCreateOptions::new(drag_layout));
+
+    window.mouse_move(50.0, 20.0);
+    window.left_mouse_down();
+    window.mouse_move(50.0, 30.0);
+    window.mouse_move(500.0, 500.0);
+    window.left_mouse_up();
+    assert_eq!(texts(window.get_styled_dom())[0], "dropped None");
+}
+
+const SHORTCUT_CSS: &str = "
+    #child { width: 100px; height: 40px; }
+    #input { width: 100px; height: 40px; }
+";
+
+struct ShortcutAppData {
+    invoked: Vec<DomNodeId>,
+    text: String,
+}
+
+fn combo(keys: &[VirtualKeyCode]) -> VirtualKeyCodeCombo {
+    VirtualKeyCodeCombo { keys: keys.to_vec().into() }
+}
+
+fn menu_item(label: &'static str, keys: &[VirtualKeyCode], data: &RefAny) -> MenuItem {
+    MenuItem::String(StringMenuItem {
+        label: AzString::from_const_str(label),
+        accelerator: Some(combo(keys)).into(),
+        callback: Some(MenuCallback { callback: Callback { cb: on_shortcut }, data: data.clone() }).into(),
+        state: MenuItemState::Normal,
+        icon: None.into(),
+        children: Vec::new().into(),
+    })
+}
+
+// body (0) > parent (1) > [child (2), input (3)]
+extern "C" fn shortcut_layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    use azul_core::window::VirtualKeyCode::*;
+
+    let mut css = azul_css_parser::new_from_str(SHORTCUT_CSS).unwrap();
+
+    let mut dom = Dom::body()
+    .with_children(vec![
+        Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Id(AzString::from_const_str("parent"))].into())
+        .with_shortcut(combo(&[LControl, S]), data.clone(), on_shortcut)
+        .with_shortcut(combo(&[S, RControl]), data.clone(), on_shortcut)
+        .with_shortcut(combo(&[A]), data.clone(), on_shortcut)
+        .with_shortcut(combo(&[F2]), data.clone(), on_shortcut)
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(vec![IdOrClass::Id(AzString::from_const_str("child"))].into())
+            .with_tab_index(TabIndex::Auto)
+            .with_shortcut(combo(&[LControl, S]), data.clone(), on_shortcut),
+            Dom::div()
+            .with_ids_and_classes(vec![IdOrClass::Id(AzString::from_const_str("input"))].into())
+            .with_tab_index(TabIndex::Auto)
+            .with_callbacks(vec![CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextInput),
+                callback: Callback { cb: on_shortcut_text_input },
+                data: data.clone(),
+            }].into()),
+        ].into()),
+    ].into());
+
+    dom.root.set_menu_bar(Menu {
+        items: vec![
+            menu_item("Save", &[LControl, S], data),
+            menu_item("Open", &[LControl, O], data),
+        ].into(),
+        .. Menu::default()
+    });
+
+    dom.style(&mut css)
+}
+
+extern "C" fn on_shortcut(data: &mut RefAny, info: CallbackInfo) -> Update {
+    match data.downcast_mut::<ShortcutAppData>() {
+        Some(mut s) => {
+            s.invoked.push(info.get_hit_node());
+            Update::DoNothing
+        },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_shortcut_text_input(data: &mut RefAny, info: CallbackInfo) -> Update {
+    let c = match info.get_current_keyboard_state().current_char.into_option().and_then(core::char::from_u32) {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    match data.downcast_mut::<ShortcutAppData>() {
+        Some(mut s) => {
+            s.text.push(c);
+            Update::DoNothing
+        },
+        None => Update::DoNothing,
+    }
+}
+
+fn new_shortcut_window() -> HeadlessWindow {
+    let data = RefAny::new(ShortcutAppData { invoked: Vec::new(), text: String::new() });
+    HeadlessWindow::new(data, WindowCreateOptions::new(shortcut_layout))
+}
+
+fn node(node_id: usize) -> DomNodeId {
+    DomNodeId { dom: DomId::ROOT_ID, node: AzNodeId::from_crate_internal(Some(NodeId::new(node_id))) }
+}
+
+/// Source of the shortcut that `keys` (the last key being the one that was just pressed) would invoke
+fn matching_source(window: &HeadlessWindow, focused_node: Option<DomNodeId>, keys: &[VirtualKeyCode]) -> Option<ShortcutSource> {
+    let mut keyboard_state = KeyboardState::default();
+    keyboard_state.ctrl_down = keys.iter().any(|k| *k == VirtualKeyCode::LControl);
+    keyboard_state.current_virtual_keycode = keys.last().copied().into();
+    keyboard_state.pressed_virtual_keycodes = keys.to_vec().into();
+    get_matching_shortcut(window.get_layout_results(), focused_node, &keyboard_state).map(|m| m.source)
+}
+
+#[test]
+fn shortcut_precedence() {
+
+    use azul_core::window::VirtualKeyCode::*;
+
+    let window = new_shortcut_window();
+    let (parent, child, input) = (node(1), node(2), node(3));
+
+    // the shortcut of the focused node wins over the parent and the menu bar
+    assert_eq!(matching_source(&window, Some(child), &[LControl, S]), Some(ShortcutSource::Node(child)));
+    // then the parents of the focused node
+    assert_eq!(matching_source(&window, Some(input), &[LControl, S]), Some(ShortcutSource::Node(parent)));
+    // then the menu bar
+    assert_eq!(matching_source(&window, None, &[LControl, S]), Some(ShortcutSource::MenuBar(AzString::from_const_str("Save"))));
+    assert_eq!(matching_source(&window, Some(child), &[LControl, O]), Some(ShortcutSource::MenuBar(AzString::from_const_str("Open"))));
+    // modifiers have to match exactly
+    assert_eq!(matching_source(&window, Some(child), &[S]), None);
+    // modifier key presses never match
+    assert_eq!(matching_source(&window, Some(child), &[S, LControl]), None);
+}
+
+#[test]
+fn shortcuts_without_modifiers_are_skipped_in_text_inputs() {
+
+    use azul_core::window::VirtualKeyCode::*;
+
+    let window = new_shortcut_window();
+    let (parent, child, input) = (node(1), node(2), node(3));
+
+    assert_eq!(matching_source(&window, Some(child), &[A]), Some(ShortcutSource::Node(parent)));
+    assert_eq!(matching_source(&window, Some(input), &[A]), None);
+    // function keys and combos with Ctrl still work while typing
+    assert_eq!(matching_source(&window, Some(input), &[F2]), Some(ShortcutSource::Node(parent)));
+    assert_eq!(matching_source(&window, Some(input), &[LControl, S]), Some(ShortcutSource::Node(parent)));
+}
+
+#[test]
+fn shortcut_conflicts() {
+
+    let window = new_shortcut_window();
+    let (parent, child) = (node(1), node(2));
+    let save = ShortcutSource::MenuBar(AzString::from_const_str("Save"));
+
+    let conflicts = get_shortcut_conflicts(window.get_layout_results())
+        .into_iter()
+        .map(|c| (c.active, c.shadowed))
+        .collect::<Vec<_>>();
+
+    assert_eq!(conflicts, vec![
+        // LControl + S and S + RControl on the same node
+        (ShortcutSource::Node(parent), ShortcutSource::Node(parent)),
+        // node shortcuts shadow the menu bar while the node is focused
+        (ShortcutSource::Node(parent), save.clone()),
+        (ShortcutSource::Node(child), save),
+    ]);
+}
+
+#[test]
+fn shortcuts_are_routed_before_key_down_callbacks() {
+
+    use azul_core::window::VirtualKeyCode::*;
+
+    let mut window = new_shortcut_window();
+    let (child, input) = (node(2), node(3));
+
+    window.internal.current_window_state.focused_node = Some(child);
+    window.key_down(LControl);
+    window.key_down(S);
+    window.key_up(S);
+    window.key_up(LControl);
+    assert_eq!(window.data.downcast_ref::<ShortcutAppData>().unwrap().invoked, vec![child]);
+
+    // typing "a" into the input doesn't invoke the "A" shortcut of the parent
+    window.internal.current_window_state.focused_node = Some(input);
+    window.key_down(A);
+    window.text_input("a");
+    window.key_up(A);
+
+    let data = window.data.downcast_ref::<ShortcutAppData>().unwrap();
+    assert_eq!(data.invoked, vec![child]);
+    assert_eq!(data.text, "a");
+}
+
+const FOCUS_CSS: &str = "
+    #a { width: 100px; height: 20px; }
+    .item { width: 100px; height: 20px; }
+    #b { width: 100px; height: 20px; }
+    #ok { width: 100px; height: 20px; }
+    #cancel { width: 100px; height: 20px; }
+";
+
+struct FocusAppData {
+    show_dialog: bool,
+}
+
+// body (0) > [a (1), toolbar (2) > [item (3), item (4), item (5)], b (6), dialog (7) > [ok (8), cancel (9)]]
+extern "C" fn focus_layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    let show_dialog = match data.downcast_ref::<FocusAppData>() {
+        Some(s) => s.show_dialog,
+        None => return StyledDom::default(),
+    };
+
+    let mut css = azul_css_parser::new_from_str(FOCUS_CSS).unwrap();
+
+    let id = |id: &'static str| -> IdOrClassVec { vec![IdOrClass::Id(AzString::from_const_str(id))].into() };
+    let item = || Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Class(AzString::from_const_str("item"))].into())
+        .with_tab_index(TabIndex::Auto);
+
+    let mut children = vec![
+        Dom::div().with_ids_and_classes(id("a")).with_tab_index(TabIndex::Auto),
+        Dom::div()
+        .with_focus_scope(FocusScope::Roving(RovingFocus::horizontal()))
+        .with_children(vec![item(), item(), item()].into()),
+        Dom::div().with_ids_and_classes(id("b")).with_tab_index(TabIndex::Auto),
+    ];
+
+    if show_dialog {
+        children.push(
+            Dom::div()
+            .with_focus_scope(FocusScope::Trap)
+            .with_children(vec![
+                Dom::div().with_ids_and_classes(id("ok")).with_tab_index(TabIndex::Auto),
+                Dom::div().with_ids_and_classes(id("cancel")).with_tab_index(TabIndex::Auto),
+            ].into())
+        );
+    }
+
+    Dom::body().with_children(children.into()).style(&mut css)
+}
+
+fn new_focus_window() -> HeadlessWindow {
+    let data = RefAny::new(FocusAppData { show_dialog: false });
+    HeadlessWindow::new(data, WindowCreateOptions::new(focus_layout))
+}
+
+fn focused(window: &HeadlessWindow) -> Option<usize> {
+    window.internal.current_window_state.focused_node.and_then(|n| n.node.into_crate_internal()).map(|n| n.index())
+}
+
+fn shift_tab(window: &mut HeadlessWindow) {
+    window.key_down(VirtualKeyCode::LShift);
+    window.key_press(VirtualKeyCode::Tab);
+    window.key_up(VirtualKeyCode::LShift);
+}
+
+fn set_show_dialog(window: &mut HeadlessWindow, show_dialog: bool) {
+    window.data.downcast_mut::<FocusAppData>().unwrap().show_dialog = show_dialog;
+    window.regenerate_styled_dom();
+}
+
+#[test]
+fn tab_navigation_remembers_roving_item() {
+
+    use azul_core::window::VirtualKeyCode::*;
+
+    let mut window = new_focus_window();
+
+    window.key_press(Tab);
+    assert_eq!(focused(&window), Some(1));
+    // the toolbar is a single tab stop
+    window.key_press(Tab);
+    assert_eq!(focused(&window), Some(3));
+    window.key_press(Right);
+    assert_eq!(focused(&window), Some(4));
+    window.key_press(Tab);
+    assert_eq!(focused(&window), Some(6));
+    // Shift+Tab back into the toolbar focuses the item that was active last
+    shift_tab(&mut window);
+    assert_eq!(focused(&window), Some(4));
+    shift_tab(&mut window);
+    assert_eq!(focused(&window), Some(1));
+    window.key_press(Tab);
+    assert_eq!(focused(&window), Some(4));
+
+    // the active item is kept when the DOM is re-generated
+    window.click(50.0, 90.0);
+    assert_eq!(focused(&window), Some(6));
+    window.regenerate_styled_dom();
+    shift_tab(&mut window);
+    assert_eq!(focused(&window), Some(4));
+}
+
+#[test]
+fn focus_visible_and_focus_within() {
+
+    let mut window = new_focus_window();
+
+    // focus moved with the keyboard: :focus-visible
+    window.key_press(VirtualKeyCode::Tab);
+    window.key_press(VirtualKeyCode::Tab);
+    assert_eq!(focused(&window), Some(3));
+    {
+        let styled_nodes = window.get_styled_dom().styled_nodes.as_container();
+        assert!(styled_nodes[NodeId::new(3)].state.focused);
+        assert!(styled_nodes[NodeId::new(3)].state.focus_visible);
+        assert!(styled_nodes[NodeId::new(3)].state.focus_within);
+        assert!(styled_nodes[NodeId::new(2)].state.focus_within);
+        assert!(styled_nodes[NodeId::new(0)].state.focus_within);
+        assert!(!styled_nodes[NodeId::new(1)].state.focus_within);
+        assert!(!styled_nodes[NodeId::new(2)].state.focused);
+    }
+
+    // focus moved with the mouse: no :focus-visible
+    window.click(50.0, 90.0);
+    assert_eq!(focused(&window), Some(6));
+    {
+        let styled_nodes = window.get_styled_dom().styled_nodes.as_container();
+        assert!(styled_nodes[NodeId::new(6)].state.focused);
+        assert!(!styled_nodes[NodeId::new(6)].state.focus_visible);
+        assert!(!styled_nodes[NodeId::new(3)].state.focused);
+        assert!(!styled_nodes[NodeId::new(3)].state.focus_visible);
+        assert!(!styled_nodes[NodeId::new(3)].state.focus_within);
+        assert!(!styled_nodes[NodeId::new(2)].state.focus_within);
+        assert!(styled_nodes[NodeId::new(0)].state.focus_within);
+    }
+}
+
+#[test]
+fn focus_trap() {
+
+    let mut window = new_focus_window();
+
+    window.click(50.0, 10.0);
+    assert_eq!(focused(&window), Some(1));
+
+    // the focus moves into the trap once it appears
+    set_show_dialog(&mut window, true);
+    assert_eq!(focused(&window), Some(8));
+
+    // Tab / Shift+Tab wrap around inside of the trap
+    window.key_press(VirtualKeyCode::Tab);
+    assert_eq!(focused(&window), Some(9));
+    window.key_press(VirtualKeyCode::Tab);
+    assert_eq!(focused(&window), Some(8));
+    shift_tab(&mut window);
+    assert_eq!(focused(&window), Some(9));
+
+    // clicking outside of the trap doesn't move the focus
+    window.click(50.0, 90.0);
+    assert_eq!(focused(&window), Some(9));
+    window.click(50.0, 110.0);
+    assert_eq!(focused(&window), Some(8));
+
+    // the focus is restored once the trap is removed
+    set_show_dialog(&mut window, false);
+    assert_eq!(focused(&window), Some(1));
+    window.click(50.0, 90.0);
+    assert_eq!(focused(&window), Some(6));
+}
+
+#[test]
+fn constrain_focus_to_trap() {
+
+    let mut window = new_focus_window();
+    let (a, b, ok, cancel) = (node(1), node(6), node(8), node(9));
+
+    // no trap: the focus can move anywhere
+    assert_eq!(constrain_focus(window.get_layout_results(), Some(a), Some(b)), Some(b));
+    assert_eq!(constrain_focus(window.get_layout_results(), Some(a), None), None);
+
+    set_show_dialog(&mut window, true);
+    let layout_results = window.get_layout_results();
+
+    assert_eq!(constrain_focus(layout_results, Some(ok), Some(cancel)), Some(cancel));
+    assert_eq!(constrain_focus(layout_results, Some(a), Some(ok)), Some(ok));
+    // leaving the trap keeps the old focus
+    assert_eq!(constrain_focus(layout_results, Some(ok), Some(b)), Some(ok));
+    assert_eq!(constrain_focus(layout_results, Some(ok), None), Some(ok));
+    // both nodes outside of the trap: the focus is cleared
+    assert_eq!(constrain_focus(layout_results, Some(a), Some(b)), None);
+}
+
+// -- keyed nodes keep their state when the DOM is re-generated
+
+const KEYED_CSS: &str = "
+    .item { width: 100px; height: 20px; overflow: auto; }
+    .content { width: 100px; height: 100px; }
+";
+
+struct KeyedAppData {
+    keys: Vec<u64>,
+}
+
+// body (0) > [item (1) > content (2), item (3) > content (4), ...]
+extern "C" fn keyed_layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    let keys = match data.downcast_ref::<KeyedAppData>() {
+        Some(s) => s.keys.clone(),
+        None => return StyledDom::default(),
+    };
+
+    let mut css = azul_css_parser::new_from_str(KEYED_CSS).unwrap();
+    let class = |class: &'static str| -> IdOrClassVec { vec![IdOrClass::Class(AzString::from_const_str(class))].into() };
+
+    Dom::body()
+    .with_children(keys.iter().map(|key| {
+        Dom::div()
+        .with_ids_and_classes(class("item"))
+        .with_tab_index(TabIndex::Auto)
+        .with_key(*key)
+        .with_children(vec![Dom::div().with_ids_and_classes(class("content"))].into())
+    }).collect::<Vec<_>>().into())
+    .style(&mut css)
+}
+
+fn scroll_position(window: &HeadlessWindow, node_id: usize) -> Option<LogicalPosition> {
+    let overflowing_nodes = &window.get_layout_results()[0].scrollable_nodes.overflowing_nodes;
+    let scroll_node = overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(NodeId::new(node_id))))?;
+    window.internal.scroll_states.get_scroll_position(&scroll_node.parent_external_scroll_id)
+}
+
+#[test]
+fn keyed_node_keeps_focus_and_scroll_position() {
+
+    let data = RefAny::new(KeyedAppData { keys: vec![1, 2] });
+    let mut window = HeadlessWindow::new(data, WindowCreateOptions::new(keyed_layout));
+
+    // focus and scroll the item with the key 2
+    window.internal.current_window_state.focused_node = Some(node(3));
+    let scroll_node = window.get_layout_results()[0].scrollable_nodes.overflowing_nodes
+        .get(&AzNodeId::from_crate_internal(Some(NodeId::new(3))))
+        .copied()
+        .expect("item 2 should be scrollable");
+    window.internal.scroll_states.set_scroll_position(&scroll_node, LogicalPosition::new(0.0, 30.0));
+    assert_eq!(scroll_position(&window, 3), Some(LogicalPosition::new(0.0, 30.0)));
+
+    // insert a new item in front: the item with the key 2 moves from node 3 to node 5
+    window.data.downcast_mut::<KeyedAppData>().unwrap().keys = vec![0, 1, 2];
+    window.regenerate_styled_dom();
+
+    assert_eq!(focused(&window), Some(5));
+    assert_eq!(scroll_position(&window, 5), Some(LogicalPosition::new(0.0, 30.0)));
+    assert_eq!(scroll_position(&window, 1).unwrap_or(LogicalPosition::zero()), LogicalPosition::zero());
+    assert_eq!(scroll_position(&window, 3).unwrap_or(LogicalPosition::zero()), LogicalPosition::zero());
+}
@@ -0,0 +1,109 @@
+//! Renders every `examples/rust/*.xml` file and every `<test>` in `tests/*.xml`
+//! and compares the result against the golden images in `azul-testing/snapshots`.
+//!
+//! Run with `AZUL_UPDATE_SNAPSHOTS=1 cargo test -p azul-testing` to regenerate the golden images,
+//! a snapshot without a golden image fails.
+
+extern crate azul_core;
+extern crate azul_testing;
+extern crate azulc_lib;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use azul_core::{
+    window::{LogicalSize, WindowTheme},
+    xml::XmlNode,
+};
+use azul_testing::snapshot::{SnapshotRenderer, SnapshotOptions, SnapshotDir, SnapshotError};
+
+fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+fn xml_files_in(dir: &Path) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(dir)
+    .map(|r| r.filter_map(|e| e.ok()).map(|e| e.path()).collect::<Vec<_>>())
+    .unwrap_or_default();
+    files.retain(|p| p.extension().and_then(|e| e.to_str()) == Some("xml"));
+    files.sort();
+    files
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string()
+}
+
+fn report(errors: Vec<SnapshotError>) {
+    if !errors.is_empty() {
+        let msg = errors.iter().map(|e| format!("{}", e)).collect::<Vec<_>>().join("\r\n");
+        panic!("{} snapshot(s) failed:\r\n{}", errors.len(), msg);
+    }
+}
+
+#[test]
+fn snapshot_examples() {
+
+    let mut renderer = SnapshotRenderer::new();
+    let snapshot_dir = SnapshotDir::new(workspace_dir().join("azul-testing").join("snapshots").join("examples"));
+    let mut errors = Vec::new();
+
+    for file in xml_files_in(&workspace_dir().join("examples").join("rust")) {
+        for (theme, theme_name) in &[(WindowTheme::LightMode, "light"), (WindowTheme::DarkMode, "dark")] {
+            let options = SnapshotOptions::default().with_theme(*theme);
+            let name = format!("{}-{}", file_stem(&file), theme_name);
+            let result = renderer
+                .render_xml_file(&file, &options)
+                .and_then(|image| snapshot_dir.assert_matches(&name, &image, &options));
+            if let Err(e) = result {
+                errors.push(e);
+            }
+        }
+    }
+
+    report(errors);
+}
+
+/// The layout tests in `/tests` have the form
+/// `<test name="..."><html>...</html><output size="WxH">...</output></test>`,
+/// each `<output>` is rendered as one snapshot
+#[test]
+fn snapshot_layout_tests() {
+
+    let mut renderer = SnapshotRenderer::new();
+    let snapshot_dir = SnapshotDir::new(workspace_dir().join("azul-testing").join("snapshots").join("tests"));
+    let mut errors = Vec::new();
+
+    for file in xml_files_in(&workspace_dir().join("tests")) {
+
+        let xml = fs::read_to_string(&file).unwrap();
+        let root_nodes = match azulc_lib::xml::parse_xml_string(&xml) {
+            Ok(o) => o,
+            Err(e) => panic!("could not parse {}: {}", file.display(), e),
+        };
+
+        for test in root_nodes.as_ref().iter().filter(|n| n.node_type.as_str() == "test") {
+
+            let test_name = test.attributes.get_key("name").map(|s| s.as_str().to_string()).unwrap_or_default();
+            let html = test.children.as_ref().iter().filter(|n| n.node_type.as_str() == "html").cloned().collect::<Vec<XmlNode>>();
+
+            for output in test.children.as_ref().iter().filter(|n| n.node_type.as_str() == "output") {
+
+                let size = match output.attributes.get_key("size").and_then(|s| azulc_lib::parse_display_list_size(s.as_str())) {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                let options = SnapshotOptions::default().with_size(LogicalSize::new(size.0, size.1));
+                let name = format!("{}-{}-{}x{}", file_stem(&file), test_name, size.0, size.1);
+                let result = renderer
+                    .render_xml_nodes(&html, &options)
+                    .and_then(|image| snapshot_dir.assert_matches(&name, &image, &options));
+                if let Err(e) = result {
+                    errors.push(e);
+                }
+            }
+        }
+    }
+
+    report(errors);
+}
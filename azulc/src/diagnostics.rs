@@ -0,0 +1,400 @@
+//! Pretty-printing of XML / CSS errors with the offending source line,
+//! similar to the error messages of `rustc`:
+//!
+//! ```text,no_run,ignore
+//! error: unknown component <buton>
+//!  --> ui.xml:3:9
+//!   |
+//! 3 |         <buton id="ok"/>
+//!   |         ^^^^^^^^^^^^^^^^
+//!   |
+//!   = help: did you mean "button"?
+//! ```
+
+use core::fmt::Write;
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_core::xml::{
+    XmlSpan, XmlTextPos, XmlError, DomXmlParseError,
+    RenderDomError, ComponentParseError, ComponentError, CompileError,
+};
+use azul_css_parser::CssParseError;
+
+/// Whether the diagnostic is an error or a warning (i.e. from `azulc lint`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DiagnosticLevel {
+    Error,
+    Warning,
+}
+
+/// Error message with an (optional) location in the source file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub level: DiagnosticLevel,
+    /// Main error message, i.e. `unknown component <buton>`
+    pub message: String,
+    /// Location of the error in the XML source
+    pub span: Option<XmlSpan>,
+    /// Suggestion on how to fix the error, i.e. `did you mean "button"?`
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+
+    pub fn new(message: String) -> Self {
+        Self { level: DiagnosticLevel::Error, message, span: None, help: None }
+    }
+
+    pub fn warning(message: String, span: Option<XmlSpan>) -> Self {
+        Self { level: DiagnosticLevel::Warning, message, span: span.filter(|s| s.is_known()), help: None }
+    }
+
+    pub fn with_help(mut self, help: String) -> Self {
+        self.help = Some(help);
+        self
+    }
+
+    /// Sets the span of the diagnostic, if the diagnostic doesn't have a (more precise) span yet
+    fn with_outer_span(mut self, span: XmlSpan) -> Self {
+        if self.span.is_none() && span.is_known() {
+            self.span = Some(span);
+        }
+        self
+    }
+
+    pub fn from_xml_error(e: &XmlError) -> Self {
+        let mut d = Self::new(format!("{}", e));
+        d.span = e.get_pos().map(|pos| XmlSpan { start: pos, end: pos });
+        d
+    }
+
+    /// `style_text_span` is the location of the text of the `<style>` node that
+    /// contained the CSS: the position of the CSS error is relative to this span
+    pub fn from_css_error(e: &CssParseError, style_text_span: Option<XmlSpan>) -> Self {
+        let mut d = Self::new(format!("invalid CSS: {}", e.error));
+        d.span = style_text_span.map(|text_span| {
+            get_css_span(e.css_string, (e.location.0.original_pos, e.location.1.original_pos), text_span)
+        });
+        d.span = d.span.filter(|s| s.is_known());
+        d
+    }
+
+    pub fn from_component_error(e: &ComponentError) -> Self {
+        let message = match e {
+            ComponentError::UnknownComponent(name, _) => format!("unknown component <{}>", name.as_str()),
+            ComponentError::UselessFunctionArgument(k, _, available_args) => {
+                format!("unknown argument \"{}\" (available arguments: {})", k.as_str(), available_args.join(", "))
+            },
+            ComponentError::InvalidArgumentValue(k, v, ty, e) => {
+                format!("invalid value \"{}\" for argument \"{}: {}\": {}", v.as_str(), k.as_str(), ty.as_str(), e)
+            },
+            e => format!("{}", e),
+        };
+        Self {
+            level: DiagnosticLevel::Error,
+            message,
+            span: None,
+            help: e.get_suggestion().map(|s| format!("did you mean \"{}\"?", s)),
+        }
+    }
+
+    pub fn from_render_dom_error(e: &RenderDomError) -> Self {
+        match e {
+            RenderDomError::Component(c) => Self::from_component_error(c),
+            RenderDomError::CssError(c) => Self::from_css_error(c, None),
+            RenderDomError::Spanned(span, inner) => match &**inner {
+                RenderDomError::CssError(c) => Self::from_css_error(c, Some(*span)),
+                inner => Self::from_render_dom_error(inner).with_outer_span(*span),
+            },
+        }
+    }
+
+    pub fn from_component_parse_error(e: &ComponentParseError) -> Self {
+        match e {
+            ComponentParseError::CssError(c) => Self::from_css_error(c, None),
+            ComponentParseError::Spanned(span, inner) => match &**inner {
+                ComponentParseError::CssError(c) => Self::from_css_error(c, Some(*span)),
+                inner => Self::from_component_parse_error(inner).with_outer_span(*span),
+            },
+            e => Self::new(format!("invalid component: {}", e)),
+        }
+    }
+
+    pub fn from_dom_xml_parse_error(e: &DomXmlParseError) -> Self {
+        match e {
+            DomXmlParseError::Xml(x) => Self::from_xml_error(x),
+            DomXmlParseError::RenderDom(r) => Self::from_render_dom_error(r),
+            DomXmlParseError::Component(c) => Self::from_component_parse_error(c),
+            DomXmlParseError::Css(c) => Self::from_css_error(c, None),
+            DomXmlParseError::Spanned(span, inner) => match &**inner {
+                DomXmlParseError::Css(c) => Self::from_css_error(c, Some(*span)),
+                inner => Self::from_dom_xml_parse_error(inner).with_outer_span(*span),
+            },
+            e => Self::new(format!("{}", e)),
+        }
+    }
+
+    pub fn from_compile_error(e: &CompileError) -> Self {
+        match e {
+            CompileError::Dom(d) => Self::from_render_dom_error(d),
+            CompileError::Xml(x) => Self::from_dom_xml_parse_error(x),
+            CompileError::Css(c) => Self::from_css_error(c, None),
+        }
+    }
+
+    /// Formats the diagnostic, with the line of the `source` the error points to
+    pub fn format(&self, file_name: &str, source: &str) -> String {
+
+        let mut s = String::new();
+        let level = match self.level {
+            DiagnosticLevel::Error => "error",
+            DiagnosticLevel::Warning => "warning",
+        };
+        let _ = writeln!(s, "{}: {}", level, self.message);
+
+        let span = match self.span {
+            Some(s) if s.is_known() => s,
+            _ => {
+                let _ = writeln!(s, " --> {}", file_name);
+                if let Some(help) = self.help.as_ref() {
+                    let _ = writeln!(s, "  = help: {}", help);
+                }
+                return s;
+            }
+        };
+
+        let line_number = format!("{}", span.start.row);
+        let gutter = " ".repeat(line_number.len());
+        let _ = writeln!(s, "{}--> {}:{}:{}", gutter, file_name, span.start.row, span.start.col);
+
+        if let Some(line) = source.lines().nth(span.start.row as usize - 1) {
+            let line = line.trim_end_matches('\r');
+            let line_len = line.chars().count() as u32;
+            let start_col = span.start.col.max(1).min(line_len + 1);
+            // spans over multiple lines are underlined until the end of the first line
+            let end_col = if span.end.row == span.start.row { span.end.col.min(line_len + 1) } else { line_len + 1 };
+            let underline_len = end_col.saturating_sub(start_col).max(1);
+
+            // keep the tabs, so that the underline lines up with the source line
+            let indent = line.chars()
+                .take(start_col as usize - 1)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+
+            let _ = writeln!(s, "{} |", gutter);
+            let _ = writeln!(s, "{} | {}", line_number, line);
+            let _ = writeln!(s, "{} | {}{}", gutter, indent, "^".repeat(underline_len as usize));
+        }
+
+        if let Some(help) = self.help.as_ref() {
+            let _ = writeln!(s, "{} |", gutter);
+            let _ = writeln!(s, "{} = help: {}", gutter, help);
+        }
+
+        s
+    }
+}
+
+/// Translates the (start, end) byte offsets of a CSS error or warning into a span in the
+/// XML file, `style_text_span` is the location of the text of the `<style>` node
+pub fn get_css_span(css_string: &str, location: (usize, usize), style_text_span: XmlSpan) -> XmlSpan {
+    if !style_text_span.is_known() {
+        return style_text_span;
+    }
+    let css_lines = LineIndex::new(css_string);
+    XmlSpan {
+        start: offset_pos(style_text_span.start, css_lines.get_pos(location.0)),
+        end: offset_pos(style_text_span.start, css_lines.get_pos(location.1)),
+    }
+}
+
+/// Translates a (1-based) position relative to the start of a text node
+/// into a position relative to the start of the file
+fn offset_pos(text_start: XmlTextPos, relative: XmlTextPos) -> XmlTextPos {
+    if relative.row <= 1 {
+        XmlTextPos { row: text_start.row, col: text_start.col + relative.col.saturating_sub(1) }
+    } else {
+        XmlTextPos { row: text_start.row + relative.row - 1, col: relative.col }
+    }
+}
+
+/// Translates byte offsets of the source string into (1-based) line / column positions
+pub(crate) struct LineIndex<'a> {
+    source: &'a str,
+    /// Byte offset of the first character of every line
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+
+    pub(crate) fn new(source: &'a str) -> Self {
+        let line_starts = core::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { source, line_starts }
+    }
+
+    pub(crate) fn get_pos(&self, byte_offset: usize) -> XmlTextPos {
+        let byte_offset = byte_offset.min(self.source.len());
+        let line = match self.line_starts.binary_search(&byte_offset) {
+            Ok(line) => line,
+            Err(next_line) => next_line.saturating_sub(1),
+        };
+        let line_start = self.line_starts[line];
+        let col = self.source.get(line_start..byte_offset).map(|s| s.chars().count()).unwrap_or(0);
+        XmlTextPos { row: line as u32 + 1, col: col as u32 + 1 }
+    }
+
+    pub(crate) fn get_span(&self, start: usize, end: usize) -> XmlSpan {
+        XmlSpan { start: self.get_pos(start), end: self.get_pos(end) }
+    }
+}
+
+#[test]
+fn test_line_index() {
+    let lines = LineIndex::new("<app>\n  <p/>\n</app>");
+    assert_eq!(lines.get_pos(0), XmlTextPos { row: 1, col: 1 });
+    assert_eq!(lines.get_pos(8), XmlTextPos { row: 2, col: 3 });
+    assert_eq!(lines.get_pos(13), XmlTextPos { row: 3, col: 1 });
+}
+
+#[test]
+fn test_format_diagnostic() {
+
+    let source = "<body>\n    <buton/>\n</body>";
+    let diagnostic = Diagnostic {
+        level: DiagnosticLevel::Error,
+        message: String::from("unknown component <buton>"),
+        span: Some(XmlSpan {
+            start: XmlTextPos { row: 2, col: 5 },
+            end: XmlTextPos { row: 2, col: 13 },
+        }),
+        help: Some(String::from("did you mean \"button\"?")),
+    };
+
+    assert_eq!(diagnostic.format("ui.xml", source), [
+        "error: unknown component <buton>",
+        " --> ui.xml:2:5",
+        "  |",
+        "2 |     <buton/>",
+        "  |     ^^^^^^^^",
+        "  |",
+        "  = help: did you mean \"button\"?",
+        "",
+    ].join("\n"));
+}
+
+/// Renders the XML with the default components, returns the diagnostic of the error
+#[cfg(test)]
+fn get_render_diagnostic(source: &str) -> Diagnostic {
+    use azul_core::xml::XmlComponentMap;
+    let nodes = crate::xml::parse_xml_string(source).unwrap();
+    let mut component_map = XmlComponentMap::default();
+    let e = azul_core::xml::str_to_dom(nodes.as_ref(), &mut component_map).unwrap_err();
+    Diagnostic::from_dom_xml_parse_error(&e)
+}
+
+#[cfg(test)]
+fn get_span(diagnostic: &Diagnostic) -> Option<((u32, u32), (u32, u32))> {
+    diagnostic.span.map(|s| ((s.start.row, s.start.col), (s.end.row, s.end.col)))
+}
+
+#[test]
+fn test_diagnostic_unknown_component() {
+
+    let source = [
+        "<html>",
+        "    <body>",
+        "        <divv />",
+        "    </body>",
+        "</html>",
+    ].join("\n");
+
+    let diagnostic = get_render_diagnostic(&source);
+    assert_eq!(diagnostic.message, "unknown component <divv>");
+    assert_eq!(get_span(&diagnostic), Some(((3, 9), (3, 17))));
+    assert_eq!(diagnostic.help.as_deref(), Some("did you mean \"div\"?"));
+
+    assert_eq!(diagnostic.format("ui.xml", &source), [
+        "error: unknown component <divv>",
+        " --> ui.xml:3:9",
+        "  |",
+        "3 |         <divv />",
+        "  |         ^^^^^^^^",
+        "  |",
+        "  = help: did you mean \"div\"?",
+        "",
+    ].join("\n"));
+}
+
+#[test]
+fn test_diagnostic_unknown_argument() {
+
+    let source = [
+        "<html>",
+        "    <head>",
+        "        <component name=\"card\" args=\"title: String\"><p>{title}</p></component>",
+        "    </head>",
+        "    <body>",
+        "        <card tittle=\"Hello\" />",
+        "    </body>",
+        "</html>",
+    ].join("\n");
+
+    // the span points to the attribute, not to the node
+    let diagnostic = get_render_diagnostic(&source);
+    assert_eq!(diagnostic.message, "unknown argument \"tittle\" (available arguments: title)");
+    assert_eq!(get_span(&diagnostic), Some(((6, 15), (6, 29))));
+    assert_eq!(diagnostic.help.as_deref(), Some("did you mean \"title\"?"));
+}
+
+#[test]
+fn test_diagnostic_invalid_component_arguments() {
+
+    let source = [
+        "<html>",
+        "    <head>",
+        "        <component name=\"card\" args=\"title String\"><p/></component>",
+        "    </head>",
+        "    <body>",
+        "        <card />",
+        "    </body>",
+        "</html>",
+    ].join("\n");
+
+    let diagnostic = get_render_diagnostic(&source);
+    assert!(diagnostic.message.starts_with("invalid component: "));
+    assert_eq!(get_span(&diagnostic), Some(((3, 32), (3, 51))));
+    assert_eq!(diagnostic.help, None);
+}
+
+#[test]
+fn test_diagnostic_css_error() {
+
+    let source = [
+        "<html>",
+        "    <head>",
+        "        <style>",
+        "            #a { color: red; }",
+        "            #b { width: 10qq; }",
+        "        </style>",
+        "    </head>",
+        "    <body>",
+        "        <p id=\"a\" />",
+        "    </body>",
+        "</html>",
+    ].join("\n");
+
+    let diagnostic = get_render_diagnostic(&source);
+    assert!(diagnostic.message.starts_with("invalid CSS: "));
+
+    // the location of the CSS error is relative to the text of the <style> node
+    let ((start_row, start_col), (end_row, end_col)) = get_span(&diagnostic).unwrap();
+    assert_eq!((start_row, end_row), (5, 5));
+    let underlined = source.lines().nth(4).unwrap()
+        .chars()
+        .skip(start_col as usize - 1)
+        .take((end_col - start_col) as usize)
+        .collect::<String>();
+    assert!(underlined.contains("width: 10qq"), "{:?}", underlined);
+}
@@ -0,0 +1,444 @@
+//! Formatter for Azul XML files (`azulc fmt`)
+//!
+//! The formatter works on the tokens of the XML file instead of the parsed
+//! `XmlNode`s, so that comments and the casing of node / attribute names
+//! are preserved. It only changes whitespace, the order of attributes
+//! and the formatting of `<style>` blocks:
+//!
+//! - every element is on its own line, indented by its depth
+//! - attributes are sorted: `name`, `args`, `accepts_text`, `id`, `class`,
+//!   then all other attributes in alphabetical order
+//! - tags that are longer than `max_line_length` get one attribute per line
+//! - `<style>` blocks are printed with `Css::print_as_css`
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_core::svg::Indent;
+use azul_core::xml::{XmlError, normalize_casing};
+use xmlparser::Tokenizer;
+
+/// Options for `format_xml`
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlFormatOptions {
+    /// Indentation of nested nodes (default: 4 spaces)
+    pub indent: Indent,
+    /// Maximum length of a line before the attributes of a node are wrapped (default: 100)
+    pub max_line_length: usize,
+}
+
+impl Default for XmlFormatOptions {
+    fn default() -> Self {
+        Self {
+            indent: Indent::Spaces(4),
+            max_line_length: 100,
+        }
+    }
+}
+
+/// Attributes that are printed first (in this order), all other
+/// attributes are printed in alphabetical order after them
+const ATTRIBUTE_PRIORITY: &[&str] = &["name", "args", "accepts_text", "id", "class"];
+
+/// Formats the XML source, returns an error if the source isn't valid XML
+///
+/// ```rust
+/// # use azulc_lib::fmt::{format_xml, XmlFormatOptions};
+/// let source = "<body><div class='a' id=\"b\">Hello</div></body>";
+/// let formatted = format_xml(source, &XmlFormatOptions::default()).unwrap();
+/// assert_eq!(formatted, "<body>\n    <div id=\"b\" class=\"a\">Hello</div>\n</body>\n");
+/// ```
+pub fn format_xml(source: &str, options: &XmlFormatOptions) -> Result<String, XmlError> {
+
+    // validate the XML first, so that the formatter doesn't need to handle errors
+    crate::xml::parse_xml_string(source)?;
+
+    let nodes = parse_format_nodes(source)?;
+    let formatter = XmlFormatter {
+        indent: match options.indent {
+            Indent::None => String::new(),
+            Indent::Spaces(s) => " ".repeat(s as usize),
+            Indent::Tabs => String::from("\t"),
+        },
+        max_line_length: options.max_line_length,
+    };
+
+    let mut output = String::with_capacity(source.len());
+    formatter.format_children(&nodes, 0, &mut output);
+    Ok(output)
+}
+
+/// Lossless (except for whitespace) representation of the XML file
+#[derive(Debug, Clone, PartialEq)]
+enum FormatNode<'a> {
+    Element {
+        /// Name of the node, including the namespace prefix (`svg:rect`)
+        name: String,
+        /// Attributes with their unescaped values
+        attributes: Vec<(String, &'a str)>,
+        children: Vec<FormatNode<'a>>,
+    },
+    Text(&'a str),
+    Comment(&'a str),
+    /// Whitespace with two or more line breaks, printed as a single empty line
+    BlankLine,
+    /// Declarations, processing instructions, DTDs and CDATA, printed as-is
+    Raw(&'a str),
+}
+
+fn qualified_name(prefix: &str, local: &str) -> String {
+    if prefix.is_empty() { local.to_string() } else { format!("{}:{}", prefix, local) }
+}
+
+fn parse_format_nodes(source: &str) -> Result<Vec<FormatNode>, XmlError> {
+
+    use xmlparser::Token::*;
+    use xmlparser::ElementEnd::*;
+
+    let mut root = Vec::new();
+    // stack of the currently open elements: (name, attributes, children)
+    let mut stack: Vec<(String, Vec<(String, &str)>, Vec<FormatNode>)> = Vec::new();
+    let mut dtd_start = None;
+
+    fn push<'a>(stack: &mut Vec<(String, Vec<(String, &'a str)>, Vec<FormatNode<'a>>)>, root: &mut Vec<FormatNode<'a>>, node: FormatNode<'a>) {
+        match stack.last_mut() {
+            Some(parent) => parent.2.push(node),
+            None => root.push(node),
+        }
+    }
+
+    for token in Tokenizer::from_fragment(source, 0..source.len()) {
+
+        let token = token.map_err(|e| XmlError::ParserError(crate::xml::translate_xmlparser_error(e)))?;
+
+        match token {
+            ElementStart { prefix, local, .. } => {
+                stack.push((qualified_name(prefix.as_str(), local.as_str()), Vec::new(), Vec::new()));
+            },
+            Attribute { prefix, local, value, .. } => {
+                if let Some(element) = stack.last_mut() {
+                    element.1.push((qualified_name(prefix.as_str(), local.as_str()), value.as_str()));
+                }
+            },
+            ElementEnd { end: Open, .. } => { },
+            ElementEnd { end: Empty, .. } | ElementEnd { end: Close(..), .. } => {
+                if let Some((name, attributes, children)) = stack.pop() {
+                    push(&mut stack, &mut root, FormatNode::Element { name, attributes, children });
+                }
+            },
+            Text { text } => {
+                let text = text.as_str();
+                if !text.trim().is_empty() {
+                    push(&mut stack, &mut root, FormatNode::Text(text));
+                } else if text.matches('\n').count() > 1 {
+                    push(&mut stack, &mut root, FormatNode::BlankLine);
+                }
+            },
+            Comment { text, .. } => push(&mut stack, &mut root, FormatNode::Comment(text.as_str())),
+            Cdata { span, .. } |
+            Declaration { span, .. } |
+            ProcessingInstruction { span, .. } |
+            EmptyDtd { span, .. } => push(&mut stack, &mut root, FormatNode::Raw(span.as_str())),
+            DtdStart { span, .. } => { dtd_start = Some(span.start()); },
+            DtdEnd { span } => {
+                let start = dtd_start.take().unwrap_or(span.start());
+                push(&mut stack, &mut root, FormatNode::Raw(&source[start..span.end()]));
+            },
+            EntityDeclaration { .. } => { }, // part of the DTD
+        }
+    }
+
+    Ok(root)
+}
+
+struct XmlFormatter {
+    /// Indentation of one level
+    indent: String,
+    max_line_length: usize,
+}
+
+impl XmlFormatter {
+
+    fn format_children(&self, nodes: &[FormatNode], depth: usize, output: &mut String) {
+
+        // no blank lines at the start or end of a node and no two blank lines in a row
+        let mut last_was_blank = true;
+        let last_non_blank = nodes.iter().rposition(|n| *n != FormatNode::BlankLine).map(|p| p + 1).unwrap_or(0);
+
+        for node in &nodes[..last_non_blank] {
+            if *node == FormatNode::BlankLine {
+                if !last_was_blank {
+                    output.push('\n');
+                }
+                last_was_blank = true;
+                continue;
+            }
+            last_was_blank = false;
+            self.format_node(node, depth, output);
+        }
+    }
+
+    fn format_node(&self, node: &FormatNode, depth: usize, output: &mut String) {
+
+        let indent = self.indent.repeat(depth);
+
+        match node {
+            FormatNode::Element { name, attributes, children } => {
+                self.format_element(name, attributes, children, depth, output);
+            },
+            FormatNode::Text(text) => {
+                for line in format_text(text) {
+                    if line.is_empty() {
+                        output.push('\n');
+                    } else {
+                        output.push_str(&format!("{}{}\n", indent, line));
+                    }
+                }
+            },
+            FormatNode::Comment(text) => {
+                let lines = dedent(text);
+                match lines.as_slice() {
+                    [] => output.push_str(&format!("{}<!-- -->\n", indent)),
+                    [line] => output.push_str(&format!("{}<!-- {} -->\n", indent, line)),
+                    lines => {
+                        output.push_str(&format!("{}<!--\n", indent));
+                        self.push_lines(lines, depth + 1, output);
+                        output.push_str(&format!("{}-->\n", indent));
+                    }
+                }
+            },
+            FormatNode::Raw(raw) => {
+                output.push_str(&format!("{}{}\n", indent, raw.trim()));
+            },
+            FormatNode::BlankLine => { },
+        }
+    }
+
+    fn format_element(&self, name: &str, attributes: &[(String, &str)], children: &[FormatNode], depth: usize, output: &mut String) {
+
+        let indent = self.indent.repeat(depth);
+
+        let mut attributes = attributes.iter()
+            .map(|(k, v)| (k.as_str(), format_attribute(k, v)))
+            .collect::<Vec<_>>();
+        attributes.sort_by_key(|(k, _)| {
+            let k = normalize_casing(k);
+            let priority = ATTRIBUTE_PRIORITY.iter().position(|p| *p == k.as_str()).unwrap_or(ATTRIBUTE_PRIORITY.len());
+            (priority, k)
+        });
+
+        let all_children = children;
+        let children = children.iter().filter(|c| **c != FormatNode::BlankLine).collect::<Vec<_>>();
+        let tag_end = if children.is_empty() { " />" } else { ">" };
+
+        let single_line_tag = format!("{}<{}{}", indent, name,
+            attributes.iter().map(|(_, a)| format!(" {}", a)).collect::<String>()
+        );
+
+        // one attribute per line, if the tag is too long
+        let open_tag = if attributes.len() > 1 && line_length(&single_line_tag) + tag_end.len() > self.max_line_length {
+            let attribute_indent = self.indent.repeat(depth + 1);
+            let mut tag = format!("{}<{}", indent, name);
+            for (_, a) in attributes.iter() {
+                tag.push_str(&format!("\n{}{}", attribute_indent, a));
+            }
+            tag
+        } else {
+            single_line_tag
+        };
+
+        if children.is_empty() {
+            output.push_str(&format!("{}{}\n", open_tag, tag_end));
+            return;
+        }
+
+        if normalize_casing(name).as_str() == "style" {
+            let css = children.iter().filter_map(|c| match c {
+                FormatNode::Text(t) => Some(*t),
+                _ => None,
+            }).collect::<String>();
+            if children.iter().all(|c| matches!(c, FormatNode::Text(_))) {
+                output.push_str(&format!("{}>\n", open_tag));
+                self.push_lines(&format_css(&css), depth + 1, output);
+                output.push_str(&format!("{}</{}>\n", indent, name));
+                return;
+            }
+        }
+
+        // <p>Hello</p>, if the text is short enough
+        if let [FormatNode::Text(text)] = children.as_slice() {
+            if let [line] = format_text(text).as_slice() {
+                let inline = format!("{}>{}</{}>", open_tag, line, name);
+                if !open_tag.contains('\n') && line_length(&inline) <= self.max_line_length {
+                    output.push_str(&format!("{}\n", inline));
+                    return;
+                }
+            }
+        }
+
+        output.push_str(&format!("{}>\n", open_tag));
+        self.format_children(all_children, depth + 1, output);
+        output.push_str(&format!("{}</{}>\n", indent, name));
+    }
+
+    /// Pushes the (already dedented) lines, indented by `depth`
+    fn push_lines(&self, lines: &[String], depth: usize, output: &mut String) {
+        let indent = self.indent.repeat(depth);
+        for line in lines {
+            if line.is_empty() {
+                output.push('\n');
+            } else {
+                // nested lines are indented with 4 spaces by dedent / print_as_css
+                let line_indent = line.len() - line.trim_start().len();
+                let nested_indent = self.indent.repeat(line_indent / 4) + &" ".repeat(line_indent % 4);
+                output.push_str(&format!("{}{}{}\n", indent, nested_indent, line.trim_start()));
+            }
+        }
+    }
+}
+
+/// Quotes the attribute value with `"`, unless the value contains a `"`
+fn format_attribute(key: &str, value: &str) -> String {
+    if value.contains('"') {
+        format!("{}='{}'", key, value)
+    } else {
+        format!("{}=\"{}\"", key, value)
+    }
+}
+
+/// Length of the line, counting tabs as four characters
+fn line_length(line: &str) -> usize {
+    let last_line = line.rsplit('\n').next().unwrap_or("");
+    last_line.chars().map(|c| if c == '\t' { 4 } else { 1 }).sum()
+}
+
+/// Trims every line of the text and collapses multiple empty lines into one,
+/// which doesn't change the text (see `azul_core::xml::prepare_string`)
+fn format_text(text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    for line in text.trim().lines().map(|l| l.trim()) {
+        if line.is_empty() && lines.last().map(|l: &String| l.is_empty()).unwrap_or(true) {
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+    lines
+}
+
+/// Removes the common indentation and the leading / trailing empty lines of the text,
+/// collapses multiple empty lines into one and converts the indentation to spaces
+fn dedent(text: &str) -> Vec<String> {
+
+    let lines = text.lines()
+        .map(|l| l.trim_end().replace('\t', "    "))
+        .collect::<Vec<_>>();
+
+    let common_indent = lines.iter()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut dedented = Vec::new();
+    for line in lines.iter() {
+        let line = line.get(common_indent..).unwrap_or("").to_string();
+        if line.is_empty() && dedented.last().map(|l: &String| l.is_empty()).unwrap_or(true) {
+            continue;
+        }
+        dedented.push(line);
+    }
+    while dedented.last().map(|l| l.is_empty()).unwrap_or(false) {
+        dedented.pop();
+    }
+    dedented
+}
+
+/// Prints the CSS in the canonical format of `Css::print_as_css`. CSS with comments or
+/// `@` rules (which the CSS printer can't reproduce) and CSS that would change its meaning
+/// when printed is only re-indented.
+fn format_css(css: &str) -> Vec<String> {
+
+    if css.contains("/*") || css.contains('@') {
+        return dedent(css);
+    }
+
+    let parsed = match azul_css_parser::new_from_str(css) {
+        Ok(o) => o,
+        Err(_) => return dedent(css),
+    };
+
+    let printed = parsed.print_as_css();
+    match azul_css_parser::new_from_str(&printed) {
+        Ok(reparsed) if reparsed == parsed => dedent(&printed),
+        _ => dedent(css),
+    }
+}
+
+#[test]
+fn test_format_xml() {
+
+    let source = [
+        "<html><head>",
+        "<style>#a{color:red}</style>",
+        "</head>",
+        "<body>",
+        "  <!--   comment -->",
+        "",
+        "",
+        "      <div",
+        "class=\"x\" id=\"a\" data='\"quoted\"'><p>",
+        "     Hello",
+        "",
+        "",
+        "         World",
+        "</p><Button   label=\"ok\"/></div>",
+        "</body></html>",
+    ].join("\n");
+
+    let expected = [
+        "<html>",
+        "    <head>",
+        "        <style>",
+        "            #a {",
+        "                color: #ff0000ff;",
+        "            }",
+        "        </style>",
+        "    </head>",
+        "    <body>",
+        "        <!-- comment -->",
+        "",
+        "        <div id=\"a\" class=\"x\" data='\"quoted\"'>",
+        "            <p>",
+        "                Hello",
+        "",
+        "                World",
+        "            </p>",
+        "            <Button label=\"ok\" />",
+        "        </div>",
+        "    </body>",
+        "</html>",
+        "",
+    ].join("\n");
+
+    let formatted = format_xml(&source, &XmlFormatOptions::default()).unwrap();
+    assert_eq!(formatted, expected);
+
+    // formatting is idempotent
+    assert_eq!(format_xml(&formatted, &XmlFormatOptions::default()).unwrap(), formatted);
+}
+
+#[test]
+fn test_format_xml_wrap_attributes() {
+
+    let source = "<body><component name=\"row\" args=\"label: String, value: String\" accepts_text=\"true\"/></body>";
+    let options = XmlFormatOptions { indent: Indent::Tabs, max_line_length: 40 };
+
+    assert_eq!(format_xml(source, &options).unwrap(), [
+        "<body>",
+        "\t<component",
+        "\t\tname=\"row\"",
+        "\t\targs=\"label: String, value: String\"",
+        "\t\taccepts_text=\"true\" />",
+        "</body>",
+        "",
+    ].join("\n"));
+}
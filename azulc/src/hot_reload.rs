@@ -0,0 +1,492 @@
+//! Loading of XML files (including the CSS files they link to) with
+//! change detection, so that the UI can be reloaded while the app is running.
+//!
+//! Stylesheets can be linked from the `<head>` of the XML file via
+//! `<link rel="stylesheet" href="style.css"/>` and CSS files can `@import`
+//! other CSS files. All paths are relative to the file that references them.
+//!
+//! The `<import>`s of components of other XML files are resolved by the `import` module.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use azul_core::dom::{Dom, IdOrClass};
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{
+    XmlNode, XmlNodeVec, XmlSpan, XmlTextPos, XmlComponentMap,
+    normalize_casing, str_to_dom,
+};
+use azul_css::{AzString, Css};
+use crate::diagnostics::Diagnostic;
+use crate::import::{ImportContext, canonicalize, resolve_imports, load_component_directory};
+use crate::xml::parse_xml_string;
+
+/// Remembers the modification time of a set of files
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FileWatcher {
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl FileWatcher {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts watching the file (the file doesn't have to exist yet)
+    pub fn watch<P: Into<PathBuf>>(&mut self, path: P) {
+        let path = path.into();
+        let modified = get_modified_time(&path);
+        self.files.insert(path, modified);
+    }
+
+    /// Returns all watched files
+    pub fn get_files(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(|p| p.as_path())
+    }
+
+    /// Returns whether any of the watched files was modified, created
+    /// or deleted since it was added to the watcher
+    pub fn is_outdated(&self) -> bool {
+        self.files.iter().any(|(path, modified)| get_modified_time(path) != *modified)
+    }
+}
+
+fn get_modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Parsed XML file, with all linked stylesheets inlined as `<style>` nodes
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlFile {
+    /// Source code of the XML file, necessary for printing errors
+    pub source: String,
+    pub nodes: XmlNodeVec,
+}
+
+/// Loads and parses the XML file, inlines all `<link rel="stylesheet">`
+/// nodes and `@import` rules and adds all loaded files to the `watcher`.
+///
+/// The error is a formatted `Diagnostic`, pointing to the file that caused the error.
+pub fn load_xml_file(path: &Path, watcher: &mut FileWatcher) -> Result<XmlFile, String> {
+
+    watcher.watch(path);
+
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("error: could not read \"{}\": {}\n", path.display(), e))?;
+
+    let mut nodes = parse_xml_string(&source)
+        .map_err(|e| Diagnostic::from_xml_error(&e).format(&path.display().to_string(), &source))?;
+
+    let file_name = path.display().to_string();
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    let head_nodes = nodes.as_mut().iter_mut()
+        .filter(|n| normalize_casing(&n.node_type).as_str() == "html")
+        .flat_map(|html| html.children.as_mut().iter_mut())
+        .filter(|n| normalize_casing(&n.node_type).as_str() == "head");
+
+    let mut imports = ImportContext::default();
+    imports.import_stack.push(canonicalize(path));
+
+    for head in head_nodes {
+        let children = resolve_imports(head.children.as_ref(), base_dir, watcher, &mut imports, &file_name, &source)?;
+        head.children = children.into();
+        for node in head.children.as_mut().iter_mut() {
+            inline_stylesheet(node, base_dir, watcher)?;
+        }
+    }
+
+    Ok(XmlFile { source, nodes })
+}
+
+/// Replaces a `<link rel="stylesheet" href="..."/>` node with a `<style>` node
+/// containing the CSS of the file and resolves the `@import`s of `<style>` nodes
+fn inline_stylesheet(node: &mut XmlNode, base_dir: &Path, watcher: &mut FileWatcher) -> Result<(), String> {
+
+    match normalize_casing(&node.node_type).as_str() {
+        "link" => {
+            let is_stylesheet = node.attributes.get_key("rel").map(|r| r.as_str().trim() == "stylesheet").unwrap_or(false);
+            let href = match node.attributes.get_key("href") {
+                Some(href) if is_stylesheet => href.as_str().to_string(),
+                _ => return Ok(()),
+            };
+            let css = load_css_file(&base_dir.join(href), watcher, &mut Vec::new())?;
+            *node = XmlNode {
+                text: Some(AzString::from(css)).into(),
+                span: node.span,
+                .. XmlNode::new("style")
+            };
+        },
+        "style" => {
+            let text = match node.text.as_ref() {
+                Some(s) if s.as_str().contains("@import") => s.as_str().to_string(),
+                _ => return Ok(()),
+            };
+            let css = resolve_css_imports(&text, base_dir, watcher, &mut Vec::new())?;
+            node.text = Some(AzString::from(css)).into();
+            // the text was modified, CSS errors can't point into the XML source anymore
+            node.text_span = XmlSpan::default();
+        },
+        _ => { },
+    }
+
+    Ok(())
+}
+
+/// Loads the CSS file and recursively inlines all `@import`ed files
+fn load_css_file(path: &Path, watcher: &mut FileWatcher, import_stack: &mut Vec<PathBuf>) -> Result<String, String> {
+
+    watcher.watch(path);
+
+    if import_stack.iter().any(|p| p == path) {
+        return Err(format!("error: \"{}\" imports itself (circular @import)\n", path.display()));
+    }
+
+    let css = fs::read_to_string(path)
+        .map_err(|e| format!("error: could not read stylesheet \"{}\": {}\n", path.display(), e))?;
+
+    // validate the CSS of this file (without the imports, so that the
+    // error locations are correct), imported files are validated separately
+    let css_without_imports = blank_css_imports(&css);
+    if let Err(e) = azul_css_parser::new_from_str(&css_without_imports) {
+        let file_start = XmlTextPos { row: 1, col: 1 };
+        let diagnostic = Diagnostic::from_css_error(&e, Some(XmlSpan { start: file_start, end: file_start }));
+        return Err(diagnostic.format(&path.display().to_string(), &css));
+    }
+
+    import_stack.push(path.to_path_buf());
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    let resolved = resolve_css_imports(&css, base_dir, watcher, import_stack);
+    import_stack.pop();
+
+    resolved
+}
+
+/// Replaces every `@import "file.css";` with the contents of the file
+fn resolve_css_imports(css: &str, base_dir: &Path, watcher: &mut FileWatcher, import_stack: &mut Vec<PathBuf>) -> Result<String, String> {
+
+    let mut resolved = String::with_capacity(css.len());
+    let mut rest = css;
+
+    while let Some((before, target, after)) = split_css_import(rest) {
+        let target = target.ok_or_else(|| format!("error: invalid @import in \"{}\"\n", base_dir.display()))?;
+        resolved.push_str(before);
+        resolved.push_str(&load_css_file(&base_dir.join(target), watcher, import_stack)?);
+        rest = after;
+    }
+
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+/// Replaces all `@import` rules with spaces, so that the line / column of the
+/// remaining CSS doesn't change
+fn blank_css_imports(css: &str) -> String {
+    let mut blanked = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some((before, _, after)) = split_css_import(rest) {
+        blanked.push_str(before);
+        let import_len = rest.len() - before.len() - after.len();
+        blanked.extend(rest[before.len()..before.len() + import_len].chars().map(|c| if c == '\n' { '\n' } else { ' ' }));
+        rest = after;
+    }
+    blanked.push_str(rest);
+    blanked
+}
+
+/// Splits `a @import "b.css"; c` into `("a ", Some("b.css"), " c")`
+fn split_css_import(css: &str) -> Option<(&str, Option<&str>, &str)> {
+
+    let start = css.find("@import")?;
+    let statement_start = start + "@import".len();
+    let statement_len = css[statement_start..].find(';').unwrap_or(css.len() - statement_start);
+    let statement = css[statement_start..statement_start + statement_len].trim();
+    let after = css.get(statement_start + statement_len + 1..).unwrap_or("");
+
+    let target = if statement.starts_with("url(") {
+        statement[4..].split(')').next().map(|s| s.trim().trim_matches(|c| c == '"' || c == '\''))
+    } else if statement.starts_with('"') || statement.starts_with('\'') {
+        statement[1..].split(|c| c == '"' || c == '\'').next()
+    } else {
+        None
+    };
+
+    Some((&css[..start], target.filter(|t| !t.is_empty()), after))
+}
+
+/// XML file that is only re-parsed when the file (or one of its stylesheets) changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlHotReload {
+    path: PathBuf,
+    /// Directories of component libraries, see `load_component_directory`
+    component_dirs: Vec<PathBuf>,
+    watcher: FileWatcher,
+    is_loaded: bool,
+    /// DOM of the last version of the file that could be rendered without errors
+    last_styled_dom: Option<StyledDom>,
+    /// Formatted error of the current version of the file
+    last_error: Option<String>,
+}
+
+impl XmlHotReload {
+
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            component_dirs: Vec::new(),
+            watcher: FileWatcher::new(),
+            is_loaded: false,
+            last_styled_dom: None,
+            last_error: None,
+        }
+    }
+
+    /// Makes the components of all XML files in the directory available to the file
+    /// (namespaced with the file name). The directory is re-loaded on every reload.
+    pub fn with_component_directory<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.component_dirs.push(dir.into());
+        self.is_loaded = false;
+        self
+    }
+
+    /// Returns whether the file has to be re-parsed on the next call to `get_styled_dom`
+    pub fn needs_reload(&self) -> bool {
+        !self.is_loaded || self.watcher.is_outdated()
+    }
+
+    /// Returns the DOM of the file, re-parsing it only if necessary. If the current version
+    /// of the file can't be parsed, the error is shown as an overlay on top of the last DOM
+    /// that could be parsed, so that the app keeps running while the file is being edited.
+    pub fn get_styled_dom(&mut self) -> StyledDom {
+
+        if self.needs_reload() {
+            self.reload();
+        }
+
+        match (self.last_styled_dom.as_ref(), self.last_error.as_ref()) {
+            (Some(dom), None) => dom.clone(),
+            (Some(dom), Some(error)) => {
+                let mut dom = dom.clone();
+                dom.append_child(render_error_overlay(error));
+                dom
+            },
+            (None, Some(error)) => {
+                let mut dom = Dom::body().style(&mut Css::empty());
+                dom.append_child(render_error_overlay(error));
+                dom
+            },
+            (None, None) => StyledDom::default(),
+        }
+    }
+
+    fn reload(&mut self) {
+
+        self.watcher = FileWatcher::new();
+        self.is_loaded = true;
+
+        let file_name = self.path.display().to_string();
+        let mut component_map = new_component_map();
+        let path = &self.path;
+        let watcher = &mut self.watcher;
+        let result = self.component_dirs.iter()
+            .try_for_each(|dir| load_component_directory(dir, &mut component_map, watcher).map(|_| ()))
+            .and_then(|_| load_xml_file(path, watcher))
+            .and_then(|file| {
+                str_to_dom(file.nodes.as_ref(), &mut component_map)
+                .map_err(|e| Diagnostic::from_dom_xml_parse_error(&e).format(&file_name, &file.source))
+            });
+
+        match result {
+            Ok(styled_dom) => {
+                self.last_styled_dom = Some(styled_dom);
+                self.last_error = None;
+            },
+            Err(e) => {
+                self.last_error = Some(e);
+            },
+        }
+    }
+}
+
+const ERROR_OVERLAY_CSS: &str = "
+    .__azul-hot-reload-error {
+        position: absolute;
+        top: 0px;
+        left: 0px;
+        width: 100%;
+        padding: 10px;
+        background-color: #1e1e1eee;
+        border-bottom: 3px solid #e03c31;
+        color: #ffffff;
+        font-family: monospace;
+        font-size: 13px;
+    }
+";
+
+fn render_error_overlay(error: &str) -> StyledDom {
+    let mut css = azul_css_parser::new_from_str(ERROR_OVERLAY_CSS).unwrap_or_else(|_| Css::empty());
+    Dom::div()
+    .with_ids_and_classes(vec![IdOrClass::Class("__azul-hot-reload-error".into())].into())
+    .with_children(error.lines().map(|line| Dom::text(line.to_string())).collect::<Vec<_>>().into())
+    .style(&mut css)
+}
+
+thread_local! {
+    /// Files loaded via `styled_dom_from_file` (by canonical path), so that the hot-reload
+    /// timer can check if any of the files changed. Entries are removed as soon as one of
+    /// the files they depend on changes, see `should_regenerate`.
+    static LOADED_FILES: RefCell<BTreeMap<PathBuf, XmlHotReload>> = RefCell::new(BTreeMap::new());
+    /// Component directories that are available to all files loaded via `styled_dom_from_file`
+    static COMPONENT_DIRECTORIES: RefCell<Vec<PathBuf>> = RefCell::new(Vec::new());
+    /// Functions that register additional built-in components (i.e. the widgets), see `new_component_map`
+    static COMPONENT_REGISTRATIONS: RefCell<Vec<fn(&mut XmlComponentMap)>> = RefCell::new(Vec::new());
+}
+
+/// Registers a function that adds components to every component map created by `new_component_map`
+/// (which includes the maps of `XmlHotReload` and `styled_dom_from_file`). Registering the same
+/// function twice has no effect.
+pub fn add_component_registration(register_fn: fn(&mut XmlComponentMap)) {
+    let is_new = COMPONENT_REGISTRATIONS.with(|fns| {
+        let mut fns = fns.borrow_mut();
+        if fns.iter().any(|f| *f as usize == register_fn as usize) {
+            return false;
+        }
+        fns.push(register_fn);
+        true
+    });
+    if is_new {
+        // files that were already loaded have to be re-parsed with the new components
+        LOADED_FILES.with(|files| files.borrow_mut().clear());
+    }
+}
+
+/// Returns the default component map (`body`, `div`, `p`, ...) plus the components
+/// of all functions registered via `add_component_registration`
+pub fn new_component_map() -> XmlComponentMap {
+    let mut component_map = XmlComponentMap::default();
+    COMPONENT_REGISTRATIONS.with(|fns| {
+        for register_fn in fns.borrow().iter() {
+            register_fn(&mut component_map);
+        }
+    });
+    component_map
+}
+
+/// Makes the components of all XML files in the directory available to all files
+/// loaded via `styled_dom_from_file`, usually called once at startup
+pub fn add_component_directory(dir: &str) {
+    COMPONENT_DIRECTORIES.with(|dirs| dirs.borrow_mut().push(PathBuf::from(dir)));
+    // files that were already loaded have to be re-parsed with the new components
+    LOADED_FILES.with(|files| files.borrow_mut().clear());
+}
+
+/// Loads the XML file, but only re-parses it if the file or one of the stylesheets
+/// it references changed since the last call (with the same path)
+pub fn styled_dom_from_file(path: &str) -> StyledDom {
+    let path = canonicalize(Path::new(path));
+    LOADED_FILES.with(|files| {
+        files.borrow_mut()
+        .entry(path.clone())
+        .or_insert_with(|| {
+            COMPONENT_DIRECTORIES.with(|dirs| {
+                dirs.borrow().iter().fold(XmlHotReload::new(path), |f, dir| f.with_component_directory(dir.clone()))
+            })
+        })
+        .get_styled_dom()
+    })
+}
+
+/// Returns whether the UI of a hot-reloading window has to be regenerated: true if any
+/// file loaded via `styled_dom_from_file` changed. If no file has been loaded that way, the
+/// UI is always regenerated, since it's not known what files the layout callback depends on.
+///
+/// The cached DOMs of the changed files are dropped, the regenerated layout callback
+/// re-parses them. That way a file that the layout callback doesn't load anymore can't
+/// trigger a regeneration on every call.
+pub fn should_regenerate() -> bool {
+    LOADED_FILES.with(|files| {
+        let mut files = files.borrow_mut();
+        if files.is_empty() {
+            return true;
+        }
+        let loaded_files = files.len();
+        files.retain(|_, f| !f.needs_reload());
+        files.len() != loaded_files
+    })
+}
+
+/// Returns an empty directory for the files of a test, unique per test and test process
+#[cfg(test)]
+pub(crate) fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("azulc_test_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_split_css_import() {
+    assert_eq!(split_css_import("a { } @import \"b.css\"; c"), Some(("a { } ", Some("b.css"), " c")));
+    assert_eq!(split_css_import("@import url('b.css');"), Some(("", Some("b.css"), "")));
+    assert_eq!(split_css_import("@import;"), Some(("", None, "")));
+    assert_eq!(split_css_import("a { }"), None);
+    assert_eq!(blank_css_imports("@import \"b.css\";\na { }"), "                \na { }");
+}
+
+#[test]
+fn test_styled_dom_from_file_cache() {
+
+    let dir = test_dir("styled_dom_from_file_cache");
+    let path = dir.join("ui.xml");
+    fs::write(&path, "<html><body><p>Hello</p></body></html>").unwrap();
+
+    let path_str = path.display().to_string();
+    let loaded_files = || LOADED_FILES.with(|files| files.borrow().len());
+
+    let _ = styled_dom_from_file(&path_str);
+    let _ = styled_dom_from_file(&path_str);
+    assert_eq!(loaded_files(), 1);
+    assert!(!should_regenerate());
+
+    // a change of the file drops the cached DOM
+    fs::remove_file(&path).unwrap();
+    assert!(should_regenerate());
+    assert_eq!(loaded_files(), 0);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_hot_reload_component_directory() {
+
+    let dir = test_dir("hot_reload_component_directory");
+    let _ = fs::create_dir_all(dir.join("components"));
+
+    fs::write(dir.join("components").join("cards.xml"), "
+        <component name=\"card\" accepts_text=\"true\"><p class=\"card\">{text}</p></component>
+    ").unwrap();
+    fs::write(dir.join("ui.xml"), "<html><body><cards:card>Hello</cards:card></body></html>").unwrap();
+
+    let mut watcher = FileWatcher::new();
+    let mut component_map = XmlComponentMap::default();
+    let names = load_component_directory(&dir.join("components"), &mut component_map, &mut watcher).unwrap();
+    assert_eq!(names, vec![String::from("cards:card")]);
+    assert!(watcher.get_files().any(|p| p == dir.join("components")));
+
+    // without the directory, the component is unknown
+    let mut file = XmlHotReload::new(dir.join("ui.xml"));
+    file.get_styled_dom();
+    assert!(file.last_error.is_some());
+
+    // the directory components are still available after the file is reloaded
+    let mut file = XmlHotReload::new(dir.join("ui.xml")).with_component_directory(dir.join("components"));
+    file.get_styled_dom();
+    assert_eq!(file.last_error, None);
+    file.reload();
+    assert_eq!(file.last_error, None);
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,466 @@
+//! Export of a `StyledDom` as a standalone HTML page
+//!
+//! Unlike `StyledDom::get_html_string` (which is only meant for debugging),
+//! the exported page can be opened in a browser to compare the output of the
+//! layout solver with the output of a web browser:
+//!
+//! - the computed CSS of every node is inlined as a `style` attribute
+//! - images are embedded as `data:image/png` URIs (needs the `png` feature)
+//! - nodes are mapped to semantic HTML tags (`<button>`, `<ul>`, ...) and
+//!   ARIA roles, based on their `AccessibilityInfo` or their native widget class
+//! - optionally, the solved layout boxes of the `LayoutResult` are drawn on top
+//!   of the page, so that differences to the browser layout are visible
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_css::{AzString, CssPropertyValue, StyleBackgroundContent, PrintAsCssValue};
+use azul_core::{
+    app_resources::{ImageCache, ImageRef, DecodedImage, ImageData, RawImageFormat},
+    dom::{NodeData, NodeType, AccessibilityRole, AccessibilityState},
+    id_tree::NodeId,
+    styled_dom::StyledDom,
+    ui_solver::LayoutResult,
+};
+
+/// Styles that every node gets in the Azul layout solver, but not in the browser
+const DEFAULT_NODE_STYLE: &str = "overflow: visible; box-sizing: border-box; margin: 0px; padding: 0px; display: flex; flex-direction: column;";
+
+const HTML_TEMPLATE_HEAD: &str = "<!DOCTYPE html>
+<html>
+<head>
+    <meta charset=\"utf-8\">
+    <title>Azul HTML export</title>
+    <style>
+        body, html { overflow: visible; width: 100%; height: 100%; box-sizing: border-box; margin: 0px; padding: 0px; display: flex; }
+        /* reset the default browser styles of the semantic tags */
+        button, a, ul, li, label, span, p, article, section { all: unset; }
+        .__azul-layout { position: absolute; left: 0px; top: 0px; pointer-events: none; }
+        .__azul-layout div { position: absolute; box-sizing: border-box; outline: 1px solid rgba(255, 0, 0, 0.6); }
+        #__azul-show-layout { position: fixed; right: 5px; top: 5px; z-index: 1; }
+        #__azul-show-layout:not(:checked) ~ .__azul-layout { display: none; }
+    </style>
+</head>
+<body>
+";
+
+const HTML_TEMPLATE_FOOTER: &str = "</body>
+</html>
+";
+
+/// Exports the DOM as a HTML page. If the `layout` (of the same `styled_dom`) is given,
+/// the solved layout rectangles are drawn on top of the page (can be toggled with a checkbox)
+pub fn export_html(styled_dom: &StyledDom, image_cache: &ImageCache, layout: Option<&LayoutResult>) -> String {
+
+    let mut output = String::from(HTML_TEMPLATE_HEAD);
+
+    if styled_dom.node_data.as_ref().len() > 0 {
+        let mut exporter = HtmlExporter { styled_dom, image_cache, output: &mut output };
+        exporter.export_node(NodeId::ZERO, 1, false);
+    }
+
+    if let Some(layout) = layout {
+        output.push_str("    <input type=\"checkbox\" id=\"__azul-show-layout\" title=\"Show Azul layout\" checked>\n");
+        output.push_str("    <div class=\"__azul-layout\">\n");
+        output.push_str(&export_layout_boxes(layout));
+        output.push_str("    </div>\n");
+    }
+
+    output.push_str(HTML_TEMPLATE_FOOTER);
+    output
+}
+
+struct HtmlExporter<'a> {
+    styled_dom: &'a StyledDom,
+    image_cache: &'a ImageCache,
+    output: &'a mut String,
+}
+
+impl<'a> HtmlExporter<'a> {
+
+    /// `inline` is true if the parent tag can only contain "phrasing content" (i.e. `<button>`)
+    fn export_node(&mut self, node_id: NodeId, depth: usize, inline: bool) {
+
+        let styled_dom = self.styled_dom;
+        let node_hierarchy = styled_dom.node_hierarchy.as_container();
+        let node_data = &styled_dom.node_data.as_container()[node_id];
+        let node_state = &styled_dom.styled_nodes.as_container()[node_id].state;
+        let css_property_cache = styled_dom.get_css_property_cache();
+
+        let (tag, role) = get_semantic_tag(node_data, inline);
+        let indent = "    ".repeat(depth);
+
+        let mut style = String::from(DEFAULT_NODE_STYLE);
+        style.push_str(&css_property_cache.get_computed_css_style_string(node_data, &node_id, node_state));
+        // url("image_id") only works in Azul, replace the image ids with the image data
+        if let Some(CssPropertyValue::Exact(backgrounds)) = css_property_cache.get_background_content(node_data, &node_id, node_state) {
+            if backgrounds.as_ref().iter().any(|b| matches!(b, StyleBackgroundContent::Image(_))) {
+                let layers = backgrounds.as_ref().iter().map(|b| match b {
+                    StyleBackgroundContent::Image(id) => {
+                        self.image_cache.get_css_image_id(id)
+                        .and_then(image_to_data_uri)
+                        .map(|uri| format!("url(\"{}\")", uri))
+                        .unwrap_or_else(|| String::from("none"))
+                    },
+                    other => other.print_as_css_value(),
+                }).collect::<Vec<_>>();
+                style.push_str(&format!("background: {};", layers.join(", ")));
+            }
+        }
+
+        let mut attributes = format!(" data-az-node-id=\"{}\"", node_id.index());
+        if let Some(role) = role {
+            attributes.push_str(&format!(" role=\"{}\"", role));
+        }
+        attributes.push_str(&get_node_attributes(node_data));
+        attributes.push_str(&format!(" style=\"{}\"", escape_html(&style)));
+
+        match node_data.get_node_type() {
+            NodeType::Br => {
+                self.output.push_str(&format!("{}<br{}>\n", indent, attributes));
+                return;
+            },
+            NodeType::Image(image) => {
+                let src = image_to_data_uri(image).unwrap_or_default();
+                self.output.push_str(&format!("{}<img{} src=\"{}\" alt=\"\">\n", indent, attributes, src));
+                return;
+            },
+            _ => { },
+        }
+
+        self.output.push_str(&format!("{}<{}{}>", indent, tag, attributes));
+
+        match node_data.get_node_type() {
+            NodeType::Text(text) => self.output.push_str(&escape_html(text.as_str())),
+            NodeType::IFrame(_) => self.output.push_str("<!-- IFrame content is only known after the layout -->"),
+            _ => { },
+        }
+
+        let children = node_id.az_children_collect(&node_hierarchy);
+        if !children.is_empty() {
+            self.output.push('\n');
+            // block elements (<div>, <ul>) can't be children of <button>, <p>, etc.
+            let children_inline = inline || matches!(tag, "button" | "a" | "label" | "span" | "p");
+            for child_id in children {
+                self.export_node(child_id, depth + 1, children_inline);
+            }
+            self.output.push_str(&indent);
+        }
+
+        self.output.push_str(&format!("</{}>\n", tag));
+    }
+}
+
+/// Returns the HTML tag and the ARIA role of the node
+fn get_semantic_tag(node_data: &NodeData, inline: bool) -> (&'static str, Option<&'static str>) {
+
+    use azul_core::dom::AccessibilityRole::*;
+
+    let role = node_data.get_accessibility_info().map(|a| a.role)
+        .or_else(|| node_data.get_ids_and_classes().as_ref().iter()
+            .filter_map(|c| c.as_class())
+            .find_map(get_native_widget_role));
+
+    let div = if inline { "span" } else { "div" };
+
+    match node_data.get_node_type() {
+        NodeType::Body => return ("main", None),
+        NodeType::Br => return ("br", None),
+        NodeType::Image(_) => return ("img", None),
+        NodeType::Text(_) => return (if inline { "span" } else { "p" }, None),
+        NodeType::IFrame(_) => return (div, Some("presentation")),
+        NodeType::Div => { },
+    }
+
+    match role {
+        Some(PushButton) if !inline => ("button", None),
+        Some(PushButton) => (div, Some("button")),
+        Some(Link) => ("a", None),
+        Some(StaticText) => ("label", None),
+        Some(List) if !inline => ("ul", None),
+        Some(ListItem) if !inline => ("li", None),
+        Some(Document) if !inline => ("article", None),
+        Some(Pane) if !inline => ("section", None),
+        Some(r) => (div, get_aria_role(r)),
+        None => (div, None),
+    }
+}
+
+/// Role of the native widgets that don't set an `AccessibilityInfo` yet
+fn get_native_widget_role(class: &str) -> Option<AccessibilityRole> {
+    use azul_core::dom::AccessibilityRole::*;
+    match class {
+        "__azul-native-button-container" => Some(PushButton),
+        "__azul-native-checkbox-container" => Some(CheckButton),
+        "__azul-native-progressbar-container" => Some(ProgressBar),
+        "__azul-native-text-input-container" => Some(Text),
+        "__azul-native-label" => Some(StaticText),
+        "__azul-native-virtual-list-container" => Some(List),
+        _ => None,
+    }
+}
+
+fn get_aria_role(role: AccessibilityRole) -> Option<&'static str> {
+    use azul_core::dom::AccessibilityRole::*;
+    Some(match role {
+        PushButton => "button",
+        CheckButton => "checkbox",
+        RadioButton => "radio",
+        ComboBox => "combobox",
+        DropList => "listbox",
+        ProgressBar => "progressbar",
+        Slider => "slider",
+        SpinButton => "spinbutton",
+        Text => "textbox",
+        StaticText => "note",
+        List => "list",
+        ListItem => "listitem",
+        Table => "table",
+        Row => "row",
+        Cell => "cell",
+        ColumnHeader => "columnheader",
+        RowHeader => "rowheader",
+        Dialog => "dialog",
+        Alert => "alert",
+        Tooltip => "tooltip",
+        MenuBar => "menubar",
+        MenuPopup => "menu",
+        MenuItem => "menuitem",
+        Toolbar => "toolbar",
+        StatusBar => "status",
+        Separator => "separator",
+        ScrollBar => "scrollbar",
+        Grouping => "group",
+        Graphic => "img",
+        Document => "document",
+        Pane => "region",
+        Pagetab => "tab",
+        PageTabList => "tablist",
+        PropertyPage => "tabpanel",
+        Outline => "tree",
+        OutlineItem => "treeitem",
+        Link => "link",
+        _ => return None,
+    })
+}
+
+/// Returns the `id`, `class`, `tabindex` and `aria-*` attributes of the node
+fn get_node_attributes(node_data: &NodeData) -> String {
+
+    let mut s = String::new();
+
+    let ids = node_data.get_ids_and_classes().as_ref().iter()
+        .filter_map(|s| s.as_id()).collect::<Vec<_>>().join(" ");
+    if !ids.is_empty() {
+        s.push_str(&format!(" id=\"{}\"", escape_html(&ids)));
+    }
+
+    let classes = node_data.get_ids_and_classes().as_ref().iter()
+        .filter_map(|s| s.as_class()).collect::<Vec<_>>().join(" ");
+    if !classes.is_empty() {
+        s.push_str(&format!(" class=\"{}\"", escape_html(&classes)));
+    }
+
+    if let Some(tab_index) = node_data.get_tab_index() {
+        s.push_str(&format!(" tabindex=\"{}\"", tab_index.get_index()));
+    }
+
+    if let Some(info) = node_data.get_accessibility_info() {
+        if let Some(name) = info.name.as_ref() {
+            s.push_str(&format!(" aria-label=\"{}\"", escape_html(name.as_str())));
+        }
+        if let Some(value) = info.value.as_ref() {
+            s.push_str(&format!(" aria-valuetext=\"{}\"", escape_html(value.as_str())));
+        }
+        for state in info.states.as_ref() {
+            let attribute = match state {
+                AccessibilityState::Checked => "aria-checked=\"true\"",
+                AccessibilityState::Selected => "aria-selected=\"true\"",
+                AccessibilityState::Expanded => "aria-expanded=\"true\"",
+                AccessibilityState::Collapsed => "aria-expanded=\"false\"",
+                AccessibilityState::Unavailable => "aria-disabled=\"true\"",
+                AccessibilityState::Readonly => "aria-readonly=\"true\"",
+                AccessibilityState::Busy => "aria-busy=\"true\"",
+                AccessibilityState::Multiselectable => "aria-multiselectable=\"true\"",
+                _ => continue,
+            };
+            s.push(' ');
+            s.push_str(attribute);
+        }
+    }
+
+    s
+}
+
+/// Returns the (deduplicated) ids of all images that the nodes reference via
+/// `background: image("id")`, so that the images can be loaded into the `ImageCache`
+/// before exporting the DOM
+pub fn get_css_image_ids(styled_dom: &StyledDom) -> Vec<AzString> {
+
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let node_data = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+
+    let mut image_ids = Vec::new();
+
+    for node_id in styled_dom.node_hierarchy.as_container().linear_iter() {
+        let node_state = &styled_nodes[node_id].state;
+        if let Some(CssPropertyValue::Exact(backgrounds)) = css_property_cache.get_background_content(&node_data[node_id], &node_id, node_state) {
+            for background in backgrounds.as_ref().iter() {
+                if let StyleBackgroundContent::Image(id) = background {
+                    if !image_ids.contains(id) {
+                        image_ids.push(id.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    image_ids
+}
+
+/// Draws the border box of every node at its solved (absolute) position
+fn export_layout_boxes(layout: &LayoutResult) -> String {
+
+    let mut s = String::new();
+
+    for node_id in layout.styled_dom.node_hierarchy.as_container().linear_iter() {
+        let x = layout.solved_pos_x.as_ref()[node_id].0;
+        let y = layout.solved_pos_y.as_ref()[node_id].0;
+        let width = layout.width_calculated_rects.as_ref()[node_id].total();
+        let height = layout.height_calculated_rects.as_ref()[node_id].total();
+        s.push_str(&format!(
+            "        <div data-az-node-id=\"{id}\" title=\"node {id}: {w}x{h} @ ({x}, {y})\" style=\"left: {x}px; top: {y}px; width: {w}px; height: {h}px;\"></div>\n",
+            id = node_id.index(), x = x, y = y, w = width, h = height,
+        ));
+    }
+
+    s
+}
+
+/// Encodes the image as a `data:image/png;base64,...` URI, returns `None`
+/// for OpenGL textures, image callbacks and 16-bit images
+#[cfg(feature = "png")]
+fn image_to_data_uri(image: &ImageRef) -> Option<String> {
+
+    use azul_core::app_resources::{RawImage, RawImageData};
+    use crate::image::encode::{encode_png, ResultU8VecEncodeImageError};
+
+    let (descriptor, data) = match image.get_data() {
+        DecodedImage::Raw((descriptor, ImageData::Raw(data))) => (descriptor, data.as_ref()),
+        _ => return None,
+    };
+
+    // the PNG encoder doesn't support BGR(A), convert everything to RGBA
+    let convert = |bpp: usize, f: &dyn Fn(&[u8]) -> [u8;4]| -> Vec<u8> {
+        data.chunks_exact(bpp).flat_map(|px| f(px).to_vec()).collect()
+    };
+
+    let pixels = match descriptor.format {
+        RawImageFormat::R8 => convert(1, &|s: &[u8]| [s[0], s[0], s[0], 255]),
+        RawImageFormat::RG8 => convert(2, &|s: &[u8]| [s[0], s[0], s[0], s[1]]),
+        RawImageFormat::RGB8 => convert(3, &|s: &[u8]| [s[0], s[1], s[2], 255]),
+        RawImageFormat::RGBA8 => data.to_vec(),
+        RawImageFormat::BGR8 => convert(3, &|s: &[u8]| [s[2], s[1], s[0], 255]),
+        RawImageFormat::BGRA8 => convert(4, &|s: &[u8]| [s[2], s[1], s[0], s[3]]),
+        _ => return None,
+    };
+
+    let raw_image = RawImage {
+        pixels: RawImageData::U8(pixels.into()),
+        width: descriptor.width,
+        height: descriptor.height,
+        premultiplied_alpha: false,
+        data_format: RawImageFormat::RGBA8,
+    };
+
+    match encode_png(&raw_image) {
+        ResultU8VecEncodeImageError::Ok(png) => Some(format!("data:image/png;base64,{}", encode_base64(png.as_ref()))),
+        ResultU8VecEncodeImageError::Err(_) => None,
+    }
+}
+
+#[cfg(not(feature = "png"))]
+fn image_to_data_uri(_: &ImageRef) -> Option<String> {
+    None
+}
+
+/// Standard base64 encoding (with padding)
+#[cfg_attr(not(feature = "png"), allow(dead_code))]
+fn encode_base64(input: &[u8]) -> String {
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut s = String::with_capacity((input.len() + 2) / 3 * 4);
+    for chunk in input.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        s.push(ALPHABET[(n >> 18) as usize & 63] as char);
+        s.push(ALPHABET[(n >> 12) as usize & 63] as char);
+        s.push(if chunk.len() > 1 { ALPHABET[(n >> 6) as usize & 63] as char } else { '=' });
+        s.push(if chunk.len() > 2 { ALPHABET[n as usize & 63] as char } else { '=' });
+    }
+    s
+}
+
+fn escape_html(input: &str) -> String {
+    input
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+#[test]
+fn test_encode_base64() {
+    assert_eq!(encode_base64(b""), "");
+    assert_eq!(encode_base64(b"f"), "Zg==");
+    assert_eq!(encode_base64(b"fo"), "Zm8=");
+    assert_eq!(encode_base64(b"foo"), "Zm9v");
+    assert_eq!(encode_base64(b"foobar"), "Zm9vYmFy");
+}
+
+#[test]
+fn test_export_html() {
+
+    use azul_core::dom::{Dom, IdOrClass};
+    use azul_css::Css;
+
+    let button = Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Class("__azul-native-button-container".into())].into())
+        .with_children(vec![Dom::text("OK")].into());
+
+    let styled_dom = Dom::body()
+        .with_children(vec![Dom::text("a < b"), button].into())
+        .style(&mut Css::empty());
+
+    let html = export_html(&styled_dom, &ImageCache::default(), None);
+
+    assert!(html.contains("<main data-az-node-id=\"0\""));
+    assert!(html.contains("<p data-az-node-id=\"1\""));
+    assert!(html.contains(">a &lt; b</p>"));
+    assert!(html.contains("<button data-az-node-id=\"2\" class=\"__azul-native-button-container\""));
+    assert!(html.contains("<span data-az-node-id=\"3\""));
+    assert!(!html.contains("__azul-show-layout\" title"));
+}
+
+#[test]
+fn test_get_css_image_ids() {
+
+    use azul_core::dom::{Dom, IdOrClass};
+
+    let mut css = azul_css_parser::new_from_str("
+        .logo { background: image(\"logo.png\"); }
+        .icon { background: image(\"icon.png\"); }
+    ").unwrap();
+
+    let styled_dom = Dom::body()
+        .with_children(vec![
+            Dom::div().with_ids_and_classes(vec![IdOrClass::Class("logo".into())].into()),
+            Dom::div().with_ids_and_classes(vec![IdOrClass::Class("icon".into())].into()),
+            Dom::div().with_ids_and_classes(vec![IdOrClass::Class("logo".into())].into()),
+            Dom::div(),
+        ].into())
+        .style(&mut css);
+
+    assert_eq!(get_css_image_ids(&styled_dom), vec![AzString::from("logo.png"), AzString::from("icon.png")]);
+}
@@ -0,0 +1,257 @@
+//! Component libraries: `<import src="widgets/cards.xml"/>` makes the components
+//! of another XML file available, namespaced with the name of the file (or the
+//! `as="..."` attribute), i.e. `<component name="card">` is used as `<cards:card>`.
+//!
+//! Imported files can import other files themselves, circular imports and two
+//! files imported under the same namespace are reported as errors.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use azul_core::xml::{XmlNode, XmlSpan, XmlComponentMap, DynamicXmlComponent, normalize_casing};
+use crate::diagnostics::Diagnostic;
+use crate::hot_reload::FileWatcher;
+use crate::xml::parse_xml_string;
+
+/// Component library (XML file with `<component>` nodes) that was loaded via `<import>`
+#[derive(Debug, Clone, PartialEq)]
+struct ComponentLibrary {
+    /// `<component>` nodes of the file, not yet namespaced
+    components: Vec<XmlNode>,
+    /// Components of the files that the library imports itself (already namespaced)
+    imported: Vec<XmlNode>,
+}
+
+/// State of all `<import>`s of one XML file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct ImportContext {
+    /// Libraries that were already loaded, so that every file is only parsed once
+    cache: BTreeMap<PathBuf, ComponentLibrary>,
+    /// Files that are currently being imported, to detect circular imports
+    pub(crate) import_stack: Vec<PathBuf>,
+    /// Namespace -> file, two files can't be imported with the same namespace
+    namespaces: BTreeMap<String, PathBuf>,
+}
+
+pub(crate) fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Replaces every `<import>` node of the `nodes` with the (namespaced) components of the imported file
+pub(crate) fn resolve_imports(
+    nodes: &[XmlNode],
+    base_dir: &Path,
+    watcher: &mut FileWatcher,
+    imports: &mut ImportContext,
+    file_name: &str,
+    source: &str,
+) -> Result<Vec<XmlNode>, String> {
+
+    let mut resolved = Vec::with_capacity(nodes.len());
+
+    for node in nodes.iter() {
+
+        if normalize_casing(&node.node_type).as_str() != "import" {
+            resolved.push(node.clone());
+            continue;
+        }
+
+        let src = node.attributes.get_key("src")
+            .ok_or_else(|| import_error("<import> has no src=\"...\" attribute", node.span, file_name, source))?;
+        let path = canonicalize(&base_dir.join(src.as_str()));
+
+        let namespace = match node.attributes.get_key("as") {
+            Some(s) => normalize_casing(s.as_str()),
+            None => path.file_stem().map(|s| normalize_casing(&s.to_string_lossy())).unwrap_or_default(),
+        };
+
+        let is_valid_namespace = namespace.chars().next().map(|c| c.is_ascii_alphabetic()).unwrap_or(false) &&
+            namespace.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_valid_namespace {
+            let message = format!("invalid namespace \"{}\": must start with a letter and only contain letters, digits and underscores", namespace);
+            return Err(import_error(&message, node.get_attribute_span("as"), file_name, source));
+        }
+
+        match imports.namespaces.get(&namespace) {
+            Some(p) if *p == path => continue, // file was already imported, components are already present
+            Some(p) => {
+                let message = format!("namespace \"{}\" is already used by \"{}\"", namespace, p.display());
+                return Err(import_error(&message, node.span, file_name, source));
+            },
+            None => { imports.namespaces.insert(namespace.clone(), path.clone()); },
+        }
+
+        if imports.import_stack.contains(&path) {
+            let message = format!("\"{}\" imports itself (circular <import>)", src.as_str());
+            return Err(import_error(&message, node.get_attribute_span("src"), file_name, source));
+        }
+
+        let library = load_component_library_file(&path, watcher, imports)?;
+
+        resolved.extend(library.imported);
+        resolved.extend(library.components.iter().map(|c| namespace_component(c, &namespace, &library.components)));
+    }
+
+    Ok(resolved)
+}
+
+/// Loads the `<component>`s of the file (either at the root of the file or in the `<head>`)
+fn load_component_library_file(path: &Path, watcher: &mut FileWatcher, imports: &mut ImportContext) -> Result<ComponentLibrary, String> {
+
+    watcher.watch(path);
+
+    if let Some(library) = imports.cache.get(path) {
+        return Ok(library.clone());
+    }
+
+    let file_name = path.display().to_string();
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("error: could not read \"{}\": {}\n", file_name, e))?;
+    let nodes = parse_xml_string(&source)
+        .map_err(|e| Diagnostic::from_xml_error(&e).format(&file_name, &source))?;
+
+    let head_nodes = nodes.as_ref().iter()
+        .filter(|n| normalize_casing(&n.node_type).as_str() == "html")
+        .flat_map(|html| html.children.as_ref().iter())
+        .filter(|n| normalize_casing(&n.node_type).as_str() == "head")
+        .flat_map(|head| head.children.as_ref().iter());
+
+    let library_nodes = nodes.as_ref().iter().chain(head_nodes)
+        .filter(|n| matches!(normalize_casing(&n.node_type).as_str(), "component" | "import"))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    imports.import_stack.push(path.to_path_buf());
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    let resolved = resolve_imports(&library_nodes, base_dir, watcher, imports, &file_name, &source);
+    imports.import_stack.pop();
+
+    let (imported, components) = resolved?.into_iter().partition::<Vec<_>, _>(|n| {
+        n.attributes.get_key("name").map(|name| name.as_str().contains(':')).unwrap_or(false)
+    });
+
+    // report errors in the arguments of the components with the location in the library file
+    for component in components.iter() {
+        if let Err(e) = DynamicXmlComponent::new(component) {
+            return Err(Diagnostic::from_component_parse_error(&e).format(&file_name, &source));
+        }
+    }
+
+    let library = ComponentLibrary { components, imported };
+    imports.cache.insert(path.to_path_buf(), library.clone());
+    Ok(library)
+}
+
+/// Prefixes the name of the component and all usages of the components
+/// of the same library with the namespace (`card` -> `cards:card`)
+fn namespace_component(component: &XmlNode, namespace: &str, library_components: &[XmlNode]) -> XmlNode {
+
+    fn namespace_children(node: &mut XmlNode, namespace: &str, local_names: &[String]) {
+        for child in node.children.as_mut().iter_mut() {
+            if local_names.contains(&normalize_casing(&child.node_type)) {
+                child.node_type = format!("{}:{}", namespace, normalize_casing(&child.node_type)).into();
+            }
+            namespace_children(child, namespace, local_names);
+        }
+    }
+
+    let local_names = library_components.iter()
+        .filter_map(|c| c.attributes.get_key("name").map(|n| normalize_casing(n.as_str())))
+        .collect::<Vec<_>>();
+
+    let mut component = component.clone();
+    if let Some(name) = component.attributes.get_key_mut("name") {
+        name.value = format!("{}:{}", namespace, normalize_casing(name.value.as_str())).into();
+    }
+    namespace_children(&mut component, namespace, &local_names);
+    component
+}
+
+fn import_error(message: &str, span: XmlSpan, file_name: &str, source: &str) -> String {
+    let mut diagnostic = Diagnostic::new(message.to_string());
+    diagnostic.span = Some(span).filter(|s| s.is_known());
+    diagnostic.format(file_name, source)
+}
+
+/// Loads all `.xml` files in the directory as component libraries and registers their
+/// components in the `component_map`, namespaced with the file name (`dir/cards.xml` -> `<cards:card>`).
+/// Returns the names of the registered components.
+///
+/// The directory itself is added to the `watcher`, so that adding or
+/// removing a file also counts as a change.
+pub fn load_component_directory(dir: &Path, component_map: &mut XmlComponentMap, watcher: &mut FileWatcher) -> Result<Vec<String>, String> {
+
+    watcher.watch(dir);
+
+    let mut paths = fs::read_dir(dir)
+        .map_err(|e| format!("error: could not read directory \"{}\": {}\n", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().map(|e| e == "xml").unwrap_or(false))
+        .collect::<Vec<_>>();
+
+    // load the files in a stable order, so that namespace errors are reproducible
+    paths.sort();
+
+    let import_nodes = paths.iter().map(|p| {
+        let mut import = XmlNode::new("import");
+        import.attributes.insert_kv("src".to_string(), p.display().to_string());
+        import
+    }).collect::<Vec<_>>();
+
+    let mut imports = ImportContext::default();
+    let components = resolve_imports(&import_nodes, Path::new(""), watcher, &mut imports, &dir.display().to_string(), "")?;
+
+    let mut names = Vec::new();
+    for component in components.iter() {
+        if let Ok(component) = DynamicXmlComponent::new(component) {
+            let component_name = component.name.clone();
+            component_map.register_component(component_name.as_str(), Box::new(component), false);
+            names.push(component_name);
+        }
+    }
+
+    Ok(names)
+}
+
+#[test]
+fn test_import_components() {
+
+    use azul_core::xml::str_to_dom;
+    use crate::hot_reload::{load_xml_file, test_dir};
+
+    let dir = test_dir("import_components");
+    let _ = fs::create_dir_all(dir.join("widgets"));
+
+    fs::write(dir.join("widgets").join("cards.xml"), "
+        <component name=\"card-header\" accepts_text=\"true\"><p>{text}</p></component>
+        <component name=\"card\" accepts_text=\"true\"><card-header>{text}</card-header></component>
+    ").unwrap();
+    fs::write(dir.join("main.xml"), "
+        <html>
+            <head><import src=\"widgets/cards.xml\" /></head>
+            <body><cards:card>Hello</cards:card></body>
+        </html>
+    ").unwrap();
+    fs::write(dir.join("circular.xml"), "<import src=\"circular.xml\" as=\"c\" />").unwrap();
+    fs::write(dir.join("main_circular.xml"), "<html><head><import src=\"circular.xml\" /></head><body /></html>").unwrap();
+
+    let mut watcher = FileWatcher::new();
+    let file = load_xml_file(&dir.join("main.xml"), &mut watcher).unwrap();
+    let head = &file.nodes.as_ref()[0].children.as_ref()[0];
+    let components = head.children.as_ref().iter()
+        .map(|c| (c.attributes.get_key("name").unwrap().as_str().to_string(), c.children.as_ref()[0].node_type.as_str().to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(components, vec![
+        (String::from("cards:card_header"), String::from("p")),
+        (String::from("cards:card"), String::from("cards:card_header")),
+    ]);
+    assert_eq!(watcher.get_files().count(), 2);
+    assert!(str_to_dom(file.nodes.as_ref(), &mut XmlComponentMap::default()).is_ok());
+
+    let error = load_xml_file(&dir.join("main_circular.xml"), &mut FileWatcher::new()).unwrap_err();
+    assert!(error.contains("imports itself"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
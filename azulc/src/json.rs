@@ -0,0 +1,715 @@
+//! Versioned JSON (and CBOR) serialization of `StyledDom`s, `LayoutResult`s and
+//! `CachedDisplayList`s, for external tools (inspectors, diffing UIs) and snapshot tests
+//!
+//! Every document is an object with a `"version"` (see `JSON_FORMAT_VERSION`) and a
+//! `"type"` (`"styled_dom"`, `"layout_result"` or `"display_list"`). The output is
+//! stable: object keys are always in the same order and numbers are printed in
+//! their shortest form, so that two outputs can be compared with a plain diff.
+//!
+//! CSS values are serialized as their CSS string (i.e. `"10px"`, `"#ff0000ff"`).
+//! Scroll IDs are 64-bit hashes and are serialized as strings, since a JSON number
+//! (a 64-bit float) can't represent them exactly.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+use azul_css::{CssPropertyValue, PrintAsCssValue, StyleBackgroundContent, LayoutSize};
+use azul_core::{
+    display_list::{
+        CachedDisplayList, DisplayListMsg, DisplayListFrame,
+        LayoutRectContent, RectBackground, StyleBorderRadius,
+    },
+    dom::NodeType,
+    styled_dom::{StyledDom, StyledNodeState},
+    ui_solver::{LayoutResult, PositionInfo, ResolvedOffsets},
+    window::{LogicalRect, LogicalSize, LogicalPosition},
+};
+
+/// Version of the JSON format, incremented on every incompatible change
+pub const JSON_FORMAT_VERSION: u32 = 1;
+
+/// JSON document, object keys keep their insertion order
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl From<bool> for JsonValue { fn from(b: bool) -> Self { JsonValue::Bool(b) } }
+impl From<usize> for JsonValue { fn from(u: usize) -> Self { JsonValue::Number(u as f64) } }
+impl From<u32> for JsonValue { fn from(u: u32) -> Self { JsonValue::Number(u as f64) } }
+impl From<u64> for JsonValue { fn from(u: u64) -> Self { JsonValue::Number(u as f64) } }
+impl From<isize> for JsonValue { fn from(i: isize) -> Self { JsonValue::Number(i as f64) } }
+impl From<String> for JsonValue { fn from(s: String) -> Self { JsonValue::String(s) } }
+impl From<&str> for JsonValue { fn from(s: &str) -> Self { JsonValue::String(s.to_string()) } }
+
+impl From<f32> for JsonValue {
+    fn from(f: f32) -> Self {
+        if !f.is_finite() {
+            return JsonValue::Null;
+        }
+        // print the shortest representation of the f32 (0.1 instead of 0.10000000149011612)
+        JsonValue::Number(format!("{}", f).parse::<f64>().unwrap_or(f as f64))
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(o: Option<T>) -> Self {
+        match o {
+            Some(s) => s.into(),
+            None => JsonValue::Null,
+        }
+    }
+}
+
+impl<T: Into<JsonValue>> From<Vec<T>> for JsonValue {
+    fn from(v: Vec<T>) -> Self {
+        JsonValue::Array(v.into_iter().map(|i| i.into()).collect())
+    }
+}
+
+/// Shorthand for building a `JsonValue::Object`
+macro_rules! json_object {($($key:expr => $value:expr),* $(,)?) => (
+    JsonValue::Object(vec![$(($key.to_string(), JsonValue::from($value))),*])
+)}
+
+impl JsonValue {
+
+    /// Pretty-prints the JSON, indented with two spaces
+    pub fn to_json_string(&self) -> String {
+        let mut s = String::new();
+        self.write_json(&mut s, 0);
+        s
+    }
+
+    fn write_json(&self, s: &mut String, depth: usize) {
+        match self {
+            JsonValue::Null => s.push_str("null"),
+            JsonValue::Bool(b) => s.push_str(if *b { "true" } else { "false" }),
+            JsonValue::Number(n) => { let _ = write!(s, "{}", n); },
+            JsonValue::String(string) => write_json_string(s, string),
+            JsonValue::Array(a) if a.is_empty() => s.push_str("[]"),
+            JsonValue::Object(o) if o.is_empty() => s.push_str("{}"),
+            JsonValue::Array(a) => {
+                s.push_str("[\n");
+                for (i, item) in a.iter().enumerate() {
+                    s.push_str(&"  ".repeat(depth + 1));
+                    item.write_json(s, depth + 1);
+                    s.push_str(if i + 1 == a.len() { "\n" } else { ",\n" });
+                }
+                s.push_str(&"  ".repeat(depth));
+                s.push(']');
+            },
+            JsonValue::Object(o) => {
+                s.push_str("{\n");
+                for (i, (key, value)) in o.iter().enumerate() {
+                    s.push_str(&"  ".repeat(depth + 1));
+                    write_json_string(s, key);
+                    s.push_str(": ");
+                    value.write_json(s, depth + 1);
+                    s.push_str(if i + 1 == o.len() { "\n" } else { ",\n" });
+                }
+                s.push_str(&"  ".repeat(depth));
+                s.push('}');
+            },
+        }
+    }
+
+    /// Encodes the value as CBOR (RFC 8949), integers are encoded as
+    /// integers, all other numbers as 64-bit floats
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_cbor(&mut bytes);
+        bytes
+    }
+
+    fn write_cbor(&self, bytes: &mut Vec<u8>) {
+        match self {
+            JsonValue::Null => bytes.push(0xf6),
+            JsonValue::Bool(false) => bytes.push(0xf4),
+            JsonValue::Bool(true) => bytes.push(0xf5),
+            JsonValue::Number(n) => {
+                if *n == (*n as i64) as f64 {
+                    if *n >= 0.0 {
+                        write_cbor_header(bytes, 0, *n as u64);
+                    } else {
+                        write_cbor_header(bytes, 1, (-1.0 - *n) as u64);
+                    }
+                } else {
+                    bytes.push(0xfb);
+                    bytes.extend_from_slice(&n.to_bits().to_be_bytes());
+                }
+            },
+            JsonValue::String(s) => {
+                write_cbor_header(bytes, 3, s.len() as u64);
+                bytes.extend_from_slice(s.as_bytes());
+            },
+            JsonValue::Array(a) => {
+                write_cbor_header(bytes, 4, a.len() as u64);
+                for item in a.iter() {
+                    item.write_cbor(bytes);
+                }
+            },
+            JsonValue::Object(o) => {
+                write_cbor_header(bytes, 5, o.len() as u64);
+                for (key, value) in o.iter() {
+                    write_cbor_header(bytes, 3, key.len() as u64);
+                    bytes.extend_from_slice(key.as_bytes());
+                    value.write_cbor(bytes);
+                }
+            },
+        }
+    }
+}
+
+fn write_json_string(s: &mut String, string: &str) {
+    s.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if (c as u32) < 0x20 => { let _ = write!(s, "\\u{:04x}", c as u32); },
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+}
+
+/// Writes the major type and the length / value of a CBOR data item
+fn write_cbor_header(bytes: &mut Vec<u8>, major_type: u8, value: u64) {
+    let major_type = major_type << 5;
+    if value < 24 {
+        bytes.push(major_type | value as u8);
+    } else if value <= u8::MAX as u64 {
+        bytes.push(major_type | 24);
+        bytes.push(value as u8);
+    } else if value <= u16::MAX as u64 {
+        bytes.push(major_type | 25);
+        bytes.extend_from_slice(&(value as u16).to_be_bytes());
+    } else if value <= u32::MAX as u64 {
+        bytes.push(major_type | 26);
+        bytes.extend_from_slice(&(value as u32).to_be_bytes());
+    } else {
+        bytes.push(major_type | 27);
+        bytes.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+/// Serializes the node hierarchy, the node data and the cascaded CSS properties of every node
+pub fn styled_dom_to_json(styled_dom: &StyledDom) -> JsonValue {
+
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let css_key_map = azul_css::get_css_key_map();
+
+    let nodes = node_hierarchy.linear_iter().map(|node_id| {
+
+        let data = &node_data[node_id];
+        let state = &styled_nodes[node_id].state;
+
+        let (node_type, text) = match data.get_node_type() {
+            NodeType::Body => ("body", None),
+            NodeType::Div => ("div", None),
+            NodeType::Br => ("br", None),
+            NodeType::Text(t) => ("text", Some(t.as_str())),
+            NodeType::Image(_) => ("image", None),
+            NodeType::IFrame(_) => ("iframe", None),
+        };
+
+        let ids_and_classes = data.get_ids_and_classes().as_ref();
+
+        // keys of the CSS key map are sorted, so the output is stable
+        let style = css_key_map.non_shorthands.iter().filter_map(|(key, property_type)| {
+            let property = css_property_cache.get_property(data, &node_id, state, property_type)?;
+            Some((key.to_string(), JsonValue::String(property.value())))
+        }).collect();
+
+        json_object! {
+            "id" => node_id.index(),
+            "parent" => node_hierarchy[node_id].parent_id().map(|p| p.index()),
+            "children" => node_id.az_children_collect(&node_hierarchy).into_iter().map(|c| c.index()).collect::<Vec<_>>(),
+            "node_type" => node_type,
+            "text" => text,
+            "ids" => ids_and_classes.iter().filter_map(|i| i.as_id()).collect::<Vec<_>>(),
+            "classes" => ids_and_classes.iter().filter_map(|i| i.as_class()).collect::<Vec<_>>(),
+            "tab_index" => data.get_tab_index().map(|t| t.get_index()),
+            "state" => node_state_to_json(state),
+            "style" => JsonValue::Object(style),
+        }
+    }).collect::<Vec<_>>();
+
+    json_object! {
+        "version" => JSON_FORMAT_VERSION,
+        "type" => "styled_dom",
+        "nodes" => nodes,
+    }
+}
+
+fn node_state_to_json(state: &StyledNodeState) -> JsonValue {
+    json_object! {
+        "normal" => state.normal,
+        "hover" => state.hover,
+        "active" => state.active,
+        "focused" => state.focused,
+        "focus_visible" => state.focus_visible,
+        "focus_within" => state.focus_within,
+        "drag_over" => state.drag_over,
+    }
+}
+
+/// Serializes the positioned rectangles and the scroll / clip nodes of the layout
+pub fn layout_result_to_json(layout: &LayoutResult) -> JsonValue {
+
+    let rects = layout.styled_dom.node_hierarchy.as_container().linear_iter().map(|node_id| {
+        let rect = &layout.rects.as_ref()[node_id];
+        json_object! {
+            "id" => node_id.index(),
+            "x" => layout.solved_pos_x.as_ref()[node_id].0,
+            "y" => layout.solved_pos_y.as_ref()[node_id].0,
+            "width" => layout.width_calculated_rects.as_ref()[node_id].total(),
+            "height" => layout.height_calculated_rects.as_ref()[node_id].total(),
+            "size" => logical_size_to_json(rect.size),
+            "position" => position_info_to_json(&rect.position),
+            "padding" => offsets_to_json(&rect.padding),
+            "margin" => offsets_to_json(&rect.margin),
+            "border_widths" => offsets_to_json(&rect.border_widths),
+            "overflow_x" => rect.overflow_x.print_as_css_value(),
+            "overflow_y" => rect.overflow_y.print_as_css_value(),
+        }
+    }).collect::<Vec<_>>();
+
+    let scroll_nodes = layout.scrollable_nodes.overflowing_nodes.iter().map(|(node_id, scroll_node)| {
+        json_object! {
+            "id" => node_id.into_crate_internal().map(|n| n.index()),
+            "parent_rect" => logical_rect_to_json(&scroll_node.parent_rect),
+            "child_rect" => logical_rect_to_json(&scroll_node.child_rect),
+            "virtual_child_rect" => logical_rect_to_json(&scroll_node.virtual_child_rect),
+            "scroll_id" => scroll_node.parent_external_scroll_id.0.to_string(),
+            "scroll_tag" => (scroll_node.scroll_tag_id.0).0,
+        }
+    }).collect::<Vec<_>>();
+
+    let clip_nodes = layout.scrollable_nodes.clip_nodes.iter().map(|(node_id, size)| {
+        json_object! {
+            "id" => node_id.index(),
+            "size" => logical_size_to_json(*size),
+        }
+    }).collect::<Vec<_>>();
+
+    json_object! {
+        "version" => JSON_FORMAT_VERSION,
+        "type" => "layout_result",
+        "dom_id" => layout.dom_id.inner,
+        "root_size" => layout_size_to_json(layout.root_size),
+        "rects" => rects,
+        "scroll_nodes" => scroll_nodes,
+        "clip_nodes" => clip_nodes,
+    }
+}
+
+/// Serializes the display list, including the display lists of all IFrames
+pub fn display_list_to_json(display_list: &CachedDisplayList) -> JsonValue {
+    json_object! {
+        "version" => JSON_FORMAT_VERSION,
+        "type" => "display_list",
+        "root_size" => logical_size_to_json(display_list.root_size),
+        "root" => display_list_msg_to_json(&display_list.root),
+    }
+}
+
+fn display_list_msg_to_json(msg: &DisplayListMsg) -> JsonValue {
+    match msg {
+        DisplayListMsg::IFrame(pipeline_id, size, epoch, display_list) => json_object! {
+            "type" => "iframe",
+            "pipeline_id" => vec![pipeline_id.0, pipeline_id.1],
+            "size" => logical_size_to_json(*size),
+            "epoch" => epoch.0,
+            "display_list" => display_list_to_json(display_list),
+        },
+        DisplayListMsg::Frame(frame) => frame_to_json(frame, "frame"),
+        DisplayListMsg::ScrollFrame(scroll_frame) => {
+            let mut frame = frame_to_json(&scroll_frame.frame, "scroll_frame");
+            if let JsonValue::Object(o) = &mut frame {
+                o.insert(1, (String::from("parent_rect"), logical_rect_to_json(&scroll_frame.parent_rect)));
+                o.insert(2, (String::from("content_rect"), logical_rect_to_json(&scroll_frame.content_rect)));
+                o.insert(3, (String::from("scroll_id"), scroll_frame.scroll_id.0.to_string().into()));
+                o.insert(4, (String::from("scroll_tag"), ((scroll_frame.scroll_tag.0).0).into()));
+            }
+            frame
+        },
+    }
+}
+
+fn frame_to_json(frame: &DisplayListFrame, frame_type: &str) -> JsonValue {
+    json_object! {
+        "type" => frame_type,
+        "size" => logical_size_to_json(frame.size),
+        "position" => position_info_to_json(&frame.position),
+        "clip_children" => frame.clip_children.map(logical_size_to_json),
+        "border_radius" => border_radius_to_json(&frame.border_radius),
+        "tag" => frame.tag.map(|t| t.0),
+        "box_shadow" => frame.box_shadow.as_ref().map(|b| json_object! {
+            "clip_mode" => format!("{:?}", b.clip_mode).to_lowercase(),
+            "top" => b.top.as_ref().map(css_value),
+            "right" => b.right.as_ref().map(css_value),
+            "bottom" => b.bottom.as_ref().map(css_value),
+            "left" => b.left.as_ref().map(css_value),
+        }),
+        "transform" => frame.transform.as_ref().map(|(_, t)| {
+            t.m.iter().flat_map(|row| row.iter().copied()).collect::<Vec<f32>>()
+        }),
+        "opacity" => frame.opacity.map(|(_, o)| o),
+        "content" => frame.content.iter().map(rect_content_to_json).collect::<Vec<_>>(),
+        "children" => frame.children.iter().map(display_list_msg_to_json).collect::<Vec<_>>(),
+    }
+}
+
+fn rect_content_to_json(content: &LayoutRectContent) -> JsonValue {
+    match content {
+        LayoutRectContent::Text { glyphs, font_instance_key, color, glyph_options: _, overflow } => json_object! {
+            "type" => "text",
+            "font_instance_key" => font_instance_key.key,
+            "color" => color.to_hash(),
+            "overflow" => vec![overflow.0, overflow.1],
+            "glyphs" => glyphs.iter().map(|g| json_object! {
+                "index" => g.index,
+                "x" => g.point.x,
+                "y" => g.point.y,
+                "width" => g.size.width,
+                "height" => g.size.height,
+            }).collect::<Vec<_>>(),
+        },
+        LayoutRectContent::Background { content, size, offset, repeat } => json_object! {
+            "type" => "background",
+            "content" => match content {
+                RectBackground::LinearGradient(g) => StyleBackgroundContent::LinearGradient(g.clone()).print_as_css_value(),
+                RectBackground::RadialGradient(g) => StyleBackgroundContent::RadialGradient(g.clone()).print_as_css_value(),
+                RectBackground::ConicGradient(g) => StyleBackgroundContent::ConicGradient(g.clone()).print_as_css_value(),
+                RectBackground::Image((image_key, _)) => format!("image({})", image_key.key),
+                RectBackground::Color(c) => c.to_hash(),
+            },
+            "size" => size.as_ref().map(|s| s.print_as_css_value()),
+            "offset" => offset.as_ref().map(|o| o.print_as_css_value()),
+            "repeat" => repeat.as_ref().map(|r| r.print_as_css_value()),
+        },
+        LayoutRectContent::Image { size, offset, image_rendering, alpha_type, image_key, background_color } => json_object! {
+            "type" => "image",
+            "size" => logical_size_to_json(*size),
+            "offset" => logical_position_to_json(*offset),
+            "image_rendering" => format!("{:?}", image_rendering),
+            "alpha_type" => format!("{:?}", alpha_type),
+            "image_key" => image_key.key,
+            "background_color" => background_color.to_hash(),
+        },
+        LayoutRectContent::Border { widths, colors, styles } => json_object! {
+            "type" => "border",
+            "widths" => json_object! {
+                "top" => widths.top.as_ref().map(css_value),
+                "right" => widths.right.as_ref().map(css_value),
+                "bottom" => widths.bottom.as_ref().map(css_value),
+                "left" => widths.left.as_ref().map(css_value),
+            },
+            "colors" => json_object! {
+                "top" => colors.top.as_ref().map(css_value),
+                "right" => colors.right.as_ref().map(css_value),
+                "bottom" => colors.bottom.as_ref().map(css_value),
+                "left" => colors.left.as_ref().map(css_value),
+            },
+            "styles" => json_object! {
+                "top" => styles.top.as_ref().map(css_value),
+                "right" => styles.right.as_ref().map(css_value),
+                "bottom" => styles.bottom.as_ref().map(css_value),
+                "left" => styles.left.as_ref().map(css_value),
+            },
+        },
+    }
+}
+
+fn css_value<T: PrintAsCssValue>(value: &CssPropertyValue<T>) -> String {
+    value.get_css_value_fmt()
+}
+
+fn border_radius_to_json(radius: &StyleBorderRadius) -> JsonValue {
+    json_object! {
+        "top_left" => radius.top_left.as_ref().map(css_value),
+        "top_right" => radius.top_right.as_ref().map(css_value),
+        "bottom_left" => radius.bottom_left.as_ref().map(css_value),
+        "bottom_right" => radius.bottom_right.as_ref().map(css_value),
+    }
+}
+
+fn position_info_to_json(position: &PositionInfo) -> JsonValue {
+    let (position_type, p) = match position {
+        PositionInfo::Static(p) => ("static", p),
+        PositionInfo::Fixed(p) => ("fixed", p),
+        PositionInfo::Absolute(p) => ("absolute", p),
+        PositionInfo::Relative(p) => ("relative", p),
+    };
+    json_object! {
+        "type" => position_type,
+        "x" => p.x_offset,
+        "y" => p.y_offset,
+        "static_x" => p.static_x_offset,
+        "static_y" => p.static_y_offset,
+    }
+}
+
+fn offsets_to_json(offsets: &ResolvedOffsets) -> JsonValue {
+    json_object! {
+        "top" => offsets.top,
+        "right" => offsets.right,
+        "bottom" => offsets.bottom,
+        "left" => offsets.left,
+    }
+}
+
+fn logical_rect_to_json(rect: &LogicalRect) -> JsonValue {
+    json_object! {
+        "x" => rect.origin.x,
+        "y" => rect.origin.y,
+        "width" => rect.size.width,
+        "height" => rect.size.height,
+    }
+}
+
+fn logical_size_to_json(size: LogicalSize) -> JsonValue {
+    json_object! {
+        "width" => size.width,
+        "height" => size.height,
+    }
+}
+
+fn logical_position_to_json(position: LogicalPosition) -> JsonValue {
+    json_object! {
+        "x" => position.x,
+        "y" => position.y,
+    }
+}
+
+fn layout_size_to_json(size: LayoutSize) -> JsonValue {
+    json_object! {
+        "width" => size.width,
+        "height" => size.height,
+    }
+}
+
+#[test]
+fn test_json_to_string() {
+
+    let value = json_object! {
+        "version" => JSON_FORMAT_VERSION,
+        "text" => "a \"b\"\n",
+        "width" => 0.1_f32,
+        "children" => vec![1_usize, 2],
+        "empty" => Vec::<usize>::new(),
+        "parent" => None::<usize>,
+    };
+
+    assert_eq!(value.to_json_string(), [
+        "{",
+        "  \"version\": 1,",
+        "  \"text\": \"a \\\"b\\\"\\n\",",
+        "  \"width\": 0.1,",
+        "  \"children\": [",
+        "    1,",
+        "    2",
+        "  ],",
+        "  \"empty\": [],",
+        "  \"parent\": null",
+        "}",
+    ].join("\n"));
+}
+
+#[test]
+fn test_json_to_cbor() {
+    let value = json_object! {
+        "a" => vec![JsonValue::Number(1.0), JsonValue::Number(-500.0), JsonValue::Number(1.5)],
+        "b" => JsonValue::Null,
+    };
+    assert_eq!(value.to_cbor(), vec![
+        0xa2,
+        0x61, b'a', 0x83, 0x01, 0x39, 0x01, 0xf3, 0xfb, 0x3f, 0xf8, 0, 0, 0, 0, 0, 0,
+        0x61, b'b', 0xf6,
+    ]);
+}
+
+#[cfg(test)]
+fn get<'a>(value: &'a JsonValue, key: &str) -> &'a JsonValue {
+    match value {
+        JsonValue::Object(o) => &o.iter().find(|(k, _)| k == key).unwrap_or_else(|| panic!("missing key {}", key)).1,
+        _ => panic!("not an object: {:?}", value),
+    }
+}
+
+#[cfg(test)]
+fn get_index(value: &JsonValue, index: usize) -> &JsonValue {
+    match value {
+        JsonValue::Array(a) => &a[index],
+        _ => panic!("not an array: {:?}", value),
+    }
+}
+
+#[cfg(test)]
+fn keys(value: &JsonValue) -> Vec<&str> {
+    match value {
+        JsonValue::Object(o) => o.iter().map(|(k, _)| k.as_str()).collect(),
+        _ => panic!("not an object: {:?}", value),
+    }
+}
+
+#[cfg(test)]
+fn test_styled_dom() -> StyledDom {
+    use azul_core::dom::{Dom, IdOrClass};
+    let mut css = azul_css_parser::new_from_str(".a { width: 100px; }").unwrap();
+    Dom::body()
+        .with_children(vec![
+            Dom::text("Hello").with_ids_and_classes(vec![IdOrClass::Class("a".into())].into()),
+        ].into())
+        .style(&mut css)
+}
+
+/// Builds a `LayoutResult` with default values for every node, without running the layout
+#[cfg(test)]
+fn test_layout_result(styled_dom: StyledDom) -> LayoutResult {
+
+    use alloc::collections::btree_map::BTreeMap;
+    use azul_core::{
+        id_tree::NodeDataContainer,
+        styled_dom::DomId,
+        ui_solver::{ScrolledNodes, GpuValueCache},
+    };
+    use azul_css::LayoutPoint;
+
+    fn filled<T: Default>(node_count: usize) -> NodeDataContainer<T> {
+        NodeDataContainer::new((0..node_count).map(|_| T::default()).collect())
+    }
+
+    let node_count = styled_dom.node_data.len();
+
+    LayoutResult {
+        dom_id: DomId::ROOT_ID,
+        parent_dom_id: None,
+        styled_dom,
+        root_size: LayoutSize::new(800, 600),
+        root_position: LayoutPoint::zero(),
+        preferred_widths: filled(node_count),
+        preferred_heights: filled(node_count),
+        width_calculated_rects: filled(node_count),
+        height_calculated_rects: filled(node_count),
+        solved_pos_x: filled(node_count),
+        solved_pos_y: filled(node_count),
+        layout_flex_grows: filled(node_count),
+        layout_displays: filled(node_count),
+        layout_positions: filled(node_count),
+        layout_flex_directions: filled(node_count),
+        layout_justify_contents: filled(node_count),
+        rects: filled(node_count),
+        words_cache: BTreeMap::new(),
+        shaped_words_cache: BTreeMap::new(),
+        positioned_words_cache: BTreeMap::new(),
+        scrollable_nodes: ScrolledNodes::default(),
+        iframe_mapping: BTreeMap::new(),
+        gpu_value_cache: GpuValueCache::default(),
+        roving_focus: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn test_styled_dom_to_json() {
+
+    let json = styled_dom_to_json(&test_styled_dom());
+
+    assert_eq!(get(&json, "version"), &JsonValue::from(JSON_FORMAT_VERSION));
+    assert_eq!(get(&json, "type"), &JsonValue::from("styled_dom"));
+
+    let body = get_index(get(&json, "nodes"), 0);
+    assert_eq!(get(body, "node_type"), &JsonValue::from("body"));
+    assert_eq!(get(body, "parent"), &JsonValue::Null);
+    assert_eq!(get(body, "children"), &JsonValue::from(vec![1_usize]));
+
+    let text = get_index(get(&json, "nodes"), 1);
+    assert_eq!(get(text, "parent"), &JsonValue::from(0_usize));
+    assert_eq!(get(text, "node_type"), &JsonValue::from("text"));
+    assert_eq!(get(text, "text"), &JsonValue::from("Hello"));
+    assert_eq!(get(text, "classes"), &JsonValue::from(vec!["a"]));
+    assert_eq!(get(get(text, "style"), "width"), &JsonValue::from("100px"));
+}
+
+#[test]
+fn test_layout_result_to_json() {
+
+    use azul_core::{
+        callbacks::PipelineId,
+        dom::{ScrollTagId, TagId},
+        id_tree::NodeId,
+        styled_dom::AzNodeId,
+        ui_solver::{ExternalScrollId, HorizontalSolvedPosition, OverflowingScrollNode},
+    };
+
+    let mut layout = test_layout_result(test_styled_dom());
+    layout.solved_pos_x.internal[1] = HorizontalSolvedPosition(10.0);
+    layout.scrollable_nodes.overflowing_nodes.insert(AzNodeId::from_crate_internal(Some(NodeId::new(1))), OverflowingScrollNode {
+        parent_external_scroll_id: ExternalScrollId(u64::MAX, PipelineId(0, 0)),
+        scroll_tag_id: ScrollTagId(TagId(5)),
+        .. Default::default()
+    });
+
+    let json = layout_result_to_json(&layout);
+
+    assert_eq!(get(&json, "type"), &JsonValue::from("layout_result"));
+    assert_eq!(get(get_index(get(&json, "rects"), 1), "x"), &JsonValue::Number(10.0));
+
+    let scroll_node = get_index(get(&json, "scroll_nodes"), 0);
+    assert_eq!(get(scroll_node, "id"), &JsonValue::from(1_usize));
+    // u64::MAX can't be represented as a f64, so it has to be a string
+    assert_eq!(get(scroll_node, "scroll_id"), &JsonValue::from("18446744073709551615"));
+    assert_eq!(get(scroll_node, "scroll_tag"), &JsonValue::from(5_u64));
+}
+
+#[test]
+fn test_display_list_to_json() {
+
+    use azul_core::{
+        callbacks::PipelineId,
+        display_list::DisplayListScrollFrame,
+        dom::{ScrollTagId, TagId},
+        ui_solver::ExternalScrollId,
+    };
+    use azul_css::LayoutPoint;
+
+    let rect = LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(100.0, 50.0));
+    let mut root = DisplayListFrame::root(LayoutSize::new(800, 600), LayoutPoint::zero());
+    root.children.push(DisplayListMsg::ScrollFrame(DisplayListScrollFrame {
+        parent_rect: rect,
+        content_rect: rect,
+        scroll_id: ExternalScrollId(u64::MAX, PipelineId(0, 0)),
+        scroll_tag: ScrollTagId(TagId(5)),
+        frame: DisplayListFrame::root(LayoutSize::new(100, 50), LayoutPoint::zero()),
+    }));
+
+    let json = display_list_to_json(&CachedDisplayList {
+        root: DisplayListMsg::Frame(root),
+        root_size: LogicalSize::new(800.0, 600.0),
+    });
+
+    assert_eq!(get(&json, "type"), &JsonValue::from("display_list"));
+    assert_eq!(get(get(&json, "root_size"), "width"), &JsonValue::Number(800.0));
+
+    let root = get(&json, "root");
+    assert_eq!(get(root, "type"), &JsonValue::from("frame"));
+
+    let scroll_frame = get_index(get(root, "children"), 0);
+    assert_eq!(keys(scroll_frame)[..5], ["type", "parent_rect", "content_rect", "scroll_id", "scroll_tag"]);
+    assert_eq!(get(scroll_frame, "type"), &JsonValue::from("scroll_frame"));
+    assert_eq!(get(scroll_frame, "scroll_id"), &JsonValue::from("18446744073709551615"));
+    assert_eq!(get(get(scroll_frame, "size"), "width"), &JsonValue::Number(100.0));
+}
@@ -21,14 +21,39 @@ extern crate azul_layout;
 extern crate rust_fontconfig;
 #[cfg(feature = "image_loading")]
 extern crate image as image_crate;
+#[cfg(feature = "cpurender")]
+extern crate azul_text_layout;
+#[cfg(feature = "cpurender")]
+extern crate tiny_skia;
 
 /// XML-based DOM serialization and XML-to-Rust compiler implementation
 pub mod xml;
+/// rustc-style error messages (with source line and location) for XML and CSS errors
+pub mod diagnostics;
+/// Loading XML files with linked stylesheets and imported components and reloading them when they change
+#[cfg(all(feature = "std", feature = "xml"))]
+pub mod hot_reload;
+/// `<import>` of the components of other XML files and directories of component libraries
+#[cfg(all(feature = "std", feature = "xml"))]
+pub mod import;
+/// Formatter for XML files, re-indents nodes and sorts attributes (`azulc fmt`)
+#[cfg(feature = "xml")]
+pub mod fmt;
+/// Warnings for unused components, unknown CSS properties, etc. (`azulc lint`)
+#[cfg(feature = "xml")]
+pub mod lint;
 pub mod svg;
+/// Export of a `StyledDom` as a standalone HTML page with inlined styles and images
+pub mod html;
+/// Versioned JSON / CBOR serialization of styled DOMs, layout results and display lists
+pub mod json;
 #[cfg(feature = "font_loading")]
 pub mod font;
 #[cfg(feature = "image_loading")]
 pub mod image;
+/// CPU rasterizer for display lists (offscreen rendering without a window)
+#[cfg(feature = "cpurender")]
+pub mod render;
 /// Module for compiling CSS to Rust code
 pub mod css {
     pub use azul_core::css::*;
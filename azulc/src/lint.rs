@@ -0,0 +1,225 @@
+//! Linter for Azul XML files (`azulc lint`)
+//!
+//! Reports problems that are not errors, but most likely mistakes:
+//!
+//! - `<component>`s that are never used
+//! - CSS properties that are not supported (and are ignored by the CSS parser)
+//! - CSS selectors that don't match any node of the document
+//! - `id` attributes that are used more than once
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_core::styled_dom::StyledDom;
+use azul_core::xml::{XmlNode, XmlSpan, normalize_casing, find_node_by_type};
+use crate::diagnostics::{Diagnostic, get_css_span};
+
+/// Lints the parsed XML file, `styled_dom` is the rendered DOM of the file
+/// (necessary to find CSS selectors that never match), returns the warnings
+pub fn lint_xml(root_nodes: &[XmlNode], styled_dom: Option<&StyledDom>) -> Vec<Diagnostic> {
+
+    let mut warnings = Vec::new();
+
+    let html_node = match find_node_by_type(root_nodes, "html") {
+        Some(s) => s,
+        None => return warnings,
+    };
+
+    let head_nodes = find_node_by_type(html_node.children.as_ref(), "head")
+        .map(|head| head.children.as_ref())
+        .unwrap_or(&[]);
+    let body_node = find_node_by_type(html_node.children.as_ref(), "body");
+
+    // components of <import>ed files ("cards:card") are linted in their own file
+    let components = head_nodes.iter()
+        .filter(|n| normalize_casing(&n.node_type).as_str() == "component")
+        .filter(|n| n.attributes.get_key("name").map(|name| !name.as_str().contains(':')).unwrap_or(true))
+        .collect::<Vec<_>>();
+
+    // unused components
+    for component in components.iter() {
+        let name = match component.attributes.get_key("name") {
+            Some(s) => normalize_casing(s.as_str()),
+            None => continue,
+        };
+        let used_in_body = body_node.map(|b| is_node_type_used(b, &name)).unwrap_or(false);
+        let used_in_other_component = components.iter()
+            .filter(|c| !core::ptr::eq(**c, *component))
+            .any(|c| is_node_type_used(c, &name));
+        if !used_in_body && !used_in_other_component {
+            warnings.push(Diagnostic::warning(
+                format!("component <{}> is never used", name),
+                Some(component.get_attribute_span("name")),
+            ));
+        }
+    }
+
+    // unsupported CSS properties and CSS selectors that never match
+    for style_node in head_nodes.iter().filter(|n| normalize_casing(&n.node_type).as_str() == "style") {
+        warnings.extend(lint_style_node(style_node, styled_dom));
+    }
+
+    // duplicated ids, every component is a separate document
+    if let Some(body) = body_node {
+        warnings.extend(lint_duplicated_ids(body));
+    }
+    for component in components.iter() {
+        warnings.extend(lint_duplicated_ids(component));
+    }
+
+    warnings
+}
+
+/// Returns whether any child of the node has the (normalized) `node_type`
+fn is_node_type_used(node: &XmlNode, node_type: &str) -> bool {
+    node.children.as_ref().iter().any(|c| {
+        normalize_casing(&c.node_type).as_str() == node_type || is_node_type_used(c, node_type)
+    })
+}
+
+fn lint_style_node(style_node: &XmlNode, styled_dom: Option<&StyledDom>) -> Vec<Diagnostic> {
+
+    let mut warnings = Vec::new();
+
+    let css_string = match style_node.text.as_ref() {
+        Some(s) => s.as_str(),
+        None => return warnings,
+    };
+
+    // CSS errors are reported by str_to_dom
+    let (css, css_warnings) = match azul_css_parser::new_from_str_with_warnings(css_string) {
+        Ok(o) => o,
+        Err(_) => return warnings,
+    };
+
+    // inlined <link> stylesheets have no location in the XML file
+    let css_span = |start: usize, end: usize| -> XmlSpan {
+        if style_node.text_span.is_known() {
+            get_css_span(css_string, (start, end), style_node.text_span)
+        } else {
+            style_node.span
+        }
+    };
+
+    for w in css_warnings {
+        use azul_css_parser::CssParseWarnMsgInner::*;
+        let (mut start, end) = (w.location.0.original_pos, w.location.1.original_pos);
+        // the location starts at the end of the previous declaration, skip to the key
+        let UnsupportedKeyValuePair { key, .. } = w.warning;
+        if let Some(key_start) = css_string.get(start..end).and_then(|s| s.find(key)) {
+            start += key_start;
+        }
+        warnings.push(Diagnostic::warning(format!("{}", w.warning), Some(css_span(start, end))));
+    }
+
+    let styled_dom = match styled_dom {
+        Some(s) => s,
+        None => return warnings,
+    };
+
+    for rule in css.rules() {
+        if styled_dom.matches_any_node(&rule.path) {
+            continue;
+        }
+        let selector = rule.path.print_as_css_selector();
+        // the parser doesn't store the location of the selector, search for it in the source
+        let span = match css_string.find(selector.as_str()) {
+            Some(start) => css_span(start, start + selector.len()),
+            None => style_node.span,
+        };
+        warnings.push(Diagnostic::warning(
+            format!("CSS selector \"{}\" doesn't match any node", selector),
+            Some(span),
+        ));
+    }
+
+    warnings
+}
+
+/// Warns about every static `id` that was already used by a previous node
+fn lint_duplicated_ids(root: &XmlNode) -> Vec<Diagnostic> {
+
+    fn collect_ids<'a>(node: &'a XmlNode, ids: &mut BTreeMap<&'a str, usize>, warnings: &mut Vec<Diagnostic>) {
+        for child in node.children.as_ref() {
+            if let Some(id) = child.attributes.get_key("id") {
+                // ids with {variables} can be different for every instance
+                for id in id.as_str().split_whitespace().filter(|id| !id.contains('{')) {
+                    let count = ids.entry(id).or_insert(0);
+                    *count += 1;
+                    if *count == 2 {
+                        warnings.push(Diagnostic::warning(
+                            format!("id \"{}\" is used more than once", id),
+                            Some(child.get_attribute_span("id")),
+                        ).with_help(String::from("ids have to be unique, use a class instead")));
+                    }
+                }
+            }
+            collect_ids(child, ids, warnings);
+        }
+    }
+
+    let mut warnings = Vec::new();
+    collect_ids(root, &mut BTreeMap::new(), &mut warnings);
+    warnings
+}
+
+#[test]
+fn test_lint_xml() {
+
+    let source = [
+        "<html>",
+        "    <head>",
+        "        <style>#a { color: red; colr: blue; }</style>",
+        "        <component name=\"unused\"><div/></component>",
+        "        <component name=\"used\"><div/></component>",
+        "    </head>",
+        "    <body>",
+        "        <used />",
+        "        <p id=\"x\" />",
+        "        <p id=\"x\" />",
+        "    </body>",
+        "</html>",
+    ].join("\n");
+
+    let nodes = crate::xml::parse_xml_string(&source).unwrap();
+    let warnings = lint_xml(nodes.as_ref(), None)
+        .into_iter()
+        .map(|w| (w.message, w.span.map(|s| (s.start.row, s.start.col))))
+        .collect::<Vec<_>>();
+
+    assert_eq!(warnings, vec![
+        (String::from("component <unused> is never used"), Some((4, 20))),
+        (String::from("Unsupported CSS property: \"colr: blue\""), Some((3, 33))),
+        (String::from("id \"x\" is used more than once"), Some((10, 12))),
+    ]);
+}
+
+#[test]
+fn test_lint_xml_unmatched_selector() {
+
+    use azul_core::xml::XmlComponentMap;
+
+    let source = [
+        "<html>",
+        "    <head>",
+        "        <style>#a { color: red; } p#x:hover { color: blue; }</style>",
+        "    </head>",
+        "    <body>",
+        "        <p id=\"x\" />",
+        "    </body>",
+        "</html>",
+    ].join("\n");
+
+    let nodes = crate::xml::parse_xml_string(&source).unwrap();
+    let styled_dom = azul_core::xml::str_to_dom(nodes.as_ref(), &mut XmlComponentMap::default()).unwrap();
+
+    // the :hover selector matches, because the p#x node exists
+    let warnings = lint_xml(nodes.as_ref(), Some(&styled_dom))
+        .into_iter()
+        .map(|w| (w.message, w.span.map(|s| ((s.start.row, s.start.col), (s.end.row, s.end.col)))))
+        .collect::<Vec<_>>();
+
+    assert_eq!(warnings, vec![
+        (String::from("CSS selector \"#a\" doesn't match any node"), Some(((3, 16), (3, 18)))),
+    ]);
+}
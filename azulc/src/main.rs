@@ -3,13 +3,22 @@ extern crate azul_core;
 
 use std::env;
 use std::fs;
-use std::path::Path;
-use std::process::exit;
-
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+use std::thread;
+use std::time::Duration as StdDuration;
+
+use azulc_lib::diagnostics::Diagnostic;
+use azulc_lib::hot_reload::{load_xml_file, new_component_map, FileWatcher};
+use azulc_lib::import::load_component_directory;
+use azulc_lib::fmt::{format_xml, XmlFormatOptions};
+use azulc_lib::lint::lint_xml;
+use azulc_lib::html::{export_html, get_css_image_ids};
+use azulc_lib::json::{JsonValue, styled_dom_to_json, layout_result_to_json, display_list_to_json};
 use azul_core::{
     gl::OptionGlContextPtr,
-    window::FullWindowState,
-    xml::{XmlComponentMap, XmlNode},
+    window::{FullWindowState, WindowTheme},
+    xml::{XmlComponentMap, XmlNode, ForeignLanguage},
     window::LogicalSize,
     styled_dom::{StyledDom, DomId},
     callbacks::{PipelineId, DocumentId},
@@ -17,13 +26,16 @@ use azul_core::{
     app_resources::{
         IdNamespace, LoadFontFn,
         Epoch, RendererResources,
-        ImageCache,
+        ImageCache, RawImage, RawImageData,
     },
     display_list::{
         SolvedLayout, GlTextureCache,
         CachedDisplayList, RenderCallbacks
     },
 };
+use azul_css::ColorU;
+use azulc_lib::render::{render_display_list_cpu, CpuRenderOptions};
+use azulc_lib::image::encode::{encode_png, ResultU8VecEncodeImageError};
 
 #[derive(PartialEq)]
 enum Action {
@@ -31,23 +43,78 @@ enum Action {
     PrintHtmlCode,
     PrintStyledDom,
     PrintRustCode,
-    PrintCCode, // unimplemented, does nothing
-    PrintCppCode, // unimplemented, does nothing
-    PrintPythonCode, // unimplemented, does nothing
+    PrintCCode,
+    PrintCppCode,
+    PrintPythonCode,
     PrintDebugLayout(LogicalSize),
     PrintScrollClips(LogicalSize),
     PrintDisplayList(LogicalSize),
+    PrintHtmlLayout(LogicalSize),
+    RenderToPng(LogicalSize, String),
+    Preview,
+    /// Format the file in place, or only check whether it is formatted
+    Format { check: bool },
+    Lint,
+}
+
+/// Output format of `--cascade`, `--debug-layout`, `--scroll-clips` and `--display-list`
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum OutputFormat {
+    /// Human-readable debug output, not stable across versions
+    Debug,
+    /// Versioned JSON, see `azulc_lib::json`
+    Json,
+    /// Same document as `Json`, encoded as CBOR (binary)
+    Cbor,
+}
+
+/// Options that can be combined with any action
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    /// Theme that is passed to the callbacks, also selects the background color of `--render`
+    theme: WindowTheme,
+    /// HiDPI factor, `--render` produces an image of `WIDTH * dpi x HEIGHT * dpi` pixels
+    dpi: f32,
+    /// Output format of the printed styled DOM / layout / display list
+    format: OutputFormat,
+    /// Directories of component libraries, see `load_component_directory`
+    component_dirs: Vec<PathBuf>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            theme: WindowTheme::LightMode,
+            dpi: 1.0,
+            format: OutputFormat::Debug,
+            component_dirs: Vec::new(),
+        }
+    }
 }
 
 fn print_help() {
     eprintln!("usage: azulc [OPTIONS] file.xml");
+    eprintln!("       azulc fmt [--check] file.xml");
+    eprintln!("       azulc lint file.xml");
+    eprintln!("");
+    eprintln!("[COMMANDS]:");
+    eprintln!("    fmt: re-indent the file and sort the attributes (--check: only fail if the file isn't formatted)");
+    eprintln!("    lint: warn about unused components, unknown CSS properties, unused CSS selectors and duplicated ids");
     eprintln!("");
     eprintln!("[OPTIONS]:");
-    eprintln!("    --language=[rust | c | python | cpp | html]: compile XML file to source code");
+    eprintln!("    --language=[rust | c | python | cpp | html]: compile XML file to source code (or a HTML page)");
+    eprintln!("    --html-layout WIDTHxHEIGHT: print a HTML page with the solved layout boxes drawn on top");
     eprintln!("    --debug-layout WIDTHxHEIGHT: print a debug output of the layout solver");
     eprintln!("    --display-list WIDTHxHEIGHT: print the display list given WIDTH and HEIGHT");
     eprintln!("    --scroll-clips WIDTHxHEIGHT: print the overflowing scroll clips given WIDTH and HEIGHT");
+    eprintln!("    --render WIDTHxHEIGHT out.png: render the file on the CPU and save it as a PNG image");
+    eprintln!("    --preview: open a window that shows the file and reloads it when it changes");
     eprintln!("    --cascade: print the cascaded styled DOM");
+    eprintln!("    --watch: re-run the command every time the XML file or its stylesheets change");
+    eprintln!("    --theme [dark | light]: window theme to lay out / render the file with (default: light)");
+    eprintln!("    --dpi FACTOR: HiDPI factor to lay out / render the file with (default: 1.0)");
+    eprintln!("    --format [debug | json | cbor]: output format of --cascade, --debug-layout, --scroll-clips and --display-list (default: debug)");
+    eprintln!("    --components DIR: make the components of all XML files in DIR available as <file:component> (can be repeated)");
     eprintln!("");
     eprintln!("If OPTIONS is empty, the file will be printed to Rust code");
 }
@@ -56,6 +123,49 @@ fn main() {
 
     let args = env::args().collect::<Vec<String>>();
 
+    // --watch, --theme, --dpi, --format and --components can be combined with any other option
+    let watch = args.iter().any(|a| a.as_str() == "--watch");
+    let mut args = args.into_iter().filter(|a| a.as_str() != "--watch").collect::<Vec<String>>();
+
+    let mut options = Options::default();
+    if let Some(theme) = take_option_value(&mut args, "--theme") {
+        options.theme = match theme.as_str() {
+            "dark" => WindowTheme::DarkMode,
+            "light" => WindowTheme::LightMode,
+            _ => {
+                eprintln!("error: unknown theme \"{}\", expected \"dark\" or \"light\"", theme);
+                print_help();
+                exit(-1);
+            }
+        };
+    }
+    if let Some(dpi) = take_option_value(&mut args, "--dpi") {
+        options.dpi = match dpi.parse::<f32>() {
+            Ok(f) if f > 0.0 => f,
+            _ => {
+                eprintln!("error: dpi factor \"{}\" could not be parsed", dpi);
+                print_help();
+                exit(-1);
+            }
+        };
+    }
+    if let Some(format) = take_option_value(&mut args, "--format") {
+        options.format = match format.as_str() {
+            "debug" => OutputFormat::Debug,
+            "json" => OutputFormat::Json,
+            "cbor" => OutputFormat::Cbor,
+            _ => {
+                eprintln!("error: unknown format \"{}\", expected \"debug\", \"json\" or \"cbor\"", format);
+                print_help();
+                exit(-1);
+            }
+        };
+    }
+
+    while let Some(dir) = take_option_value(&mut args, "--components") {
+        options.component_dirs.push(PathBuf::from(dir));
+    }
+
     if args.len() == 1 {
         // no input file
         eprintln!("error: no input file given");
@@ -76,52 +186,65 @@ fn main() {
         Some("--language=c")            => Action::PrintCCode,
         Some("--language=cpp")          => Action::PrintCppCode,
         Some("--language=python")       => Action::PrintPythonCode,
-        Some("--debug-layout")          => {
-            let size = env::args().nth(2).expect("no output size specified for display list");
-            let size_parsed = match azulc_lib::parse_display_list_size(&size) {
-                Some(s) => s,
-                None => {
-                    eprintln!("error: debug layout size \"{}\" could not be parsed", size);
+        Some("--debug-layout")          => Action::PrintDebugLayout(parse_size_arg(&args, "debug layout")),
+        Some("--scroll-clips")          => Action::PrintScrollClips(parse_size_arg(&args, "scroll clip")),
+        Some("--display-list")          => Action::PrintDisplayList(parse_size_arg(&args, "display list")),
+        Some("--html-layout")           => Action::PrintHtmlLayout(parse_size_arg(&args, "layout")),
+        Some("--render")                => {
+            let size = parse_size_arg(&args, "render");
+            let output_path = match args.get(3) {
+                Some(s) if args.len() > 4 => s.clone(),
+                _ => {
+                    eprintln!("error: no output file specified for rendering");
                     print_help();
                     exit(-1);
                 }
             };
-            Action::PrintDebugLayout(LogicalSize::new(size_parsed.0, size_parsed.1))
-        },
-        Some("--scroll-clips")          => {
-            let size = env::args().nth(2).expect("no output size specified for display list");
-            let size_parsed = match azulc_lib::parse_display_list_size(&size) {
-                Some(s) => s,
-                None => {
-                    eprintln!("error: scroll clip size \"{}\" could not be parsed", size);
-                    print_help();
-                    exit(-1);
-                }
-            };
-            Action::PrintScrollClips(LogicalSize::new(size_parsed.0, size_parsed.1))
-        },
-        Some("--display-list")          => {
-            let size = env::args().nth(2).expect("no output size specified for display list");
-            let size_parsed = match azulc_lib::parse_display_list_size(&size) {
-                Some(s) => s,
-                None => {
-                    eprintln!("error: display list size \"{}\" could not be parsed", size);
-                    print_help();
-                    exit(-1);
-                }
-            };
-            Action::PrintDisplayList(LogicalSize::new(size_parsed.0, size_parsed.1))
+            Action::RenderToPng(size, output_path)
         },
+        Some("--preview")               => Action::Preview,
+        Some("fmt")                     => Action::Format { check: args.iter().any(|a| a.as_str() == "--check") },
+        Some("lint")                    => Action::Lint,
         _ => Action::PrintRustCode,
     };
 
-    process(action, input_file)
+    process(action, input_file, watch, options)
 }
 
-fn process(action: Action, file: Option<&String>) {
+/// Parses the `WIDTHxHEIGHT` argument after the action (i.e. `--render 800x600`)
+fn parse_size_arg(args: &[String], name: &str) -> LogicalSize {
+    let size = match args.get(2) {
+        Some(s) => s,
+        None => {
+            eprintln!("error: no output size specified for {}", name);
+            print_help();
+            exit(-1);
+        }
+    };
+    match azulc_lib::parse_display_list_size(size) {
+        Some((width, height)) => LogicalSize::new(width, height),
+        None => {
+            eprintln!("error: {} size \"{}\" could not be parsed", name, size);
+            print_help();
+            exit(-1);
+        }
+    }
+}
 
-    use azul_core::xml::*;
-    use azulc_lib::xml::parse_xml_string;
+/// Removes `name VALUE` from the arguments and returns the `VALUE`
+fn take_option_value(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let pos = args.iter().position(|a| a.as_str() == name)?;
+    let value = if pos + 1 < args.len() { Some(args.remove(pos + 1)) } else { None };
+    args.remove(pos);
+    if value.is_none() {
+        eprintln!("error: no value specified for {}", name);
+        print_help();
+        exit(-1);
+    }
+    value
+}
+
+fn process(action: Action, file: Option<&String>, watch: bool, options: Options) {
 
     if action == Action::PrintHelp {
         print_help();
@@ -137,66 +260,106 @@ fn process(action: Action, file: Option<&String>) {
        },
    };
 
-    let file_contents = match fs::read_to_string(input_file.clone()) {
+    // the preview window watches the files itself
+    if action == Action::Preview {
+        exit(if run_preview(input_file, &options) { 0 } else { -1 });
+    }
+
+    if !watch {
+        let success = run_action(&action, input_file, &mut FileWatcher::new(), &options);
+        exit(if success { 0 } else { -1 });
+    }
+
+    loop {
+        let mut watcher = FileWatcher::new();
+        run_action(&action, input_file, &mut watcher, &options);
+        eprintln!("[watching {} file(s) for changes, press Ctrl+C to exit]", watcher.get_files().count());
+        while !watcher.is_outdated() {
+            thread::sleep(StdDuration::from_millis(200));
+        }
+        eprintln!("");
+    }
+}
+
+/// Runs the action on the file, adds the XML and CSS files that
+/// were loaded to the `watcher`, returns false on error
+fn run_action(action: &Action, input_file: &str, watcher: &mut FileWatcher, options: &Options) -> bool {
+
+    use azul_core::xml::*;
+
+    // the formatter works on the XML source, not on the parsed nodes
+    if let Action::Format { check } = *action {
+        watcher.watch(input_file);
+        return format_file(input_file, check);
+    }
+
+    let file = match load_xml_file(Path::new(input_file), watcher) {
         Ok(s) => s,
         Err(e) => {
-            eprintln!("error: could not read file: \"{}\" - error:\r\n{}", input_file, e);
-            exit(-1);
-        },
+            eprint!("{}", e);
+            return false;
+        }
     };
 
-    // parse the XML
-    let root_nodes = match parse_xml_string(&file_contents).ok() {
-        Some(s) => s,
-        None => {
-            eprintln!("error: input could not be parsed as xml");
-            print_help();
-            exit(-1);
+    let root_nodes = file.nodes;
+    let file_contents = file.source;
+
+    let mut component_map = new_component_map();
+    for dir in options.component_dirs.iter() {
+        if let Err(e) = load_component_directory(dir, &mut component_map, watcher) {
+            eprint!("{}", e);
+            return false;
         }
-    };
+    }
+
+    if *action == Action::Lint {
+        return lint_file(input_file, &file_contents, root_nodes.as_ref(), &mut component_map);
+    }
 
-    let styled_dom = match str_to_dom(root_nodes.as_ref(), &mut XmlComponentMap::default()) {
+    let styled_dom = match str_to_dom(root_nodes.as_ref(), &mut component_map) {
         Ok(s) => s,
         Err(e) => {
-            eprintln!("error: could not render DOM:\r\n{}", e);
-            print_help();
-            exit(-1);
+            eprint!("{}", Diagnostic::from_dom_xml_parse_error(&e).format(input_file, &file_contents));
+            return false;
         }
     };
 
-    match action {
-        Action::PrintHelp => {
+    match *action {
+        Action::PrintHelp | Action::Preview | Action::Format { .. } | Action::Lint => {
             print_help();
-            exit(0);
         },
         Action::PrintStyledDom => {
-            println!("{:#?}", styled_dom);
+            match options.format {
+                OutputFormat::Debug => println!("{:#?}", styled_dom),
+                format => print_json(&styled_dom_to_json(&styled_dom), format),
+            }
         },
         Action::PrintHtmlCode => {
-            println!("{}", styled_dom.get_html_string("", "", false));
+            let image_cache = load_css_images(&styled_dom, input_file, watcher);
+            println!("{}", export_html(&styled_dom, &image_cache, None));
         },
         Action::PrintRustCode => {
-            match get_rust_code(root_nodes.as_ref()) {
+            match get_rust_code(root_nodes.as_ref(), &mut component_map, input_file, &file_contents) {
                 Ok(o) => { println!("{}", o); },
-                Err(e) => { eprintln!("{}", e); },
+                Err(e) => { eprint!("{}", e); return false; },
             }
         },
         Action::PrintCCode => {
-            match get_c_code(root_nodes.as_ref()) {
+            match get_c_code(root_nodes.as_ref(), &mut component_map, input_file, &file_contents) {
                 Ok(o) => { println!("{}", o); },
-                Err(e) => { eprintln!("{}", e); },
+                Err(e) => { eprint!("{}", e); return false; },
             }
         },
         Action::PrintCppCode => {
-            match get_cpp_code(root_nodes.as_ref()) {
+            match get_cpp_code(root_nodes.as_ref(), &mut component_map, input_file, &file_contents) {
                 Ok(o) => { println!("{}", o); },
-                Err(e) => { eprintln!("{}", e); },
+                Err(e) => { eprint!("{}", e); return false; },
             }
         },
         Action::PrintPythonCode => {
-            match get_python_code(root_nodes.as_ref()) {
+            match get_python_code(root_nodes.as_ref(), &mut component_map, input_file, &file_contents) {
                 Ok(o) => { println!("{}", o); },
-                Err(e) => { eprintln!("{}", e); },
+                Err(e) => { eprint!("{}", e); return false; },
             }
         },
         Action::PrintDebugLayout(size) => {
@@ -206,12 +369,13 @@ fn process(action: Action, file: Option<&String>) {
                 namespace_id: IdNamespace(0),
                 id: 0,
             };
-            let mut fake_window_state = FullWindowState::default();
-            fake_window_state.size.dimensions = size;
+            let fake_window_state = get_fake_window_state(size, options);
             let mut renderer_resources = RendererResources::default();
             let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
-            let layout_debug = layout_result_print_layout(&layout);
-            println!("{}", layout_debug);
+            match options.format {
+                OutputFormat::Debug => println!("{}", layout_result_print_layout(&layout)),
+                format => print_json(&layout_result_to_json(&layout), format),
+            }
         },
         Action::PrintScrollClips(size) => {
             let document_id = DocumentId {
@@ -219,11 +383,25 @@ fn process(action: Action, file: Option<&String>) {
                 id: 0,
             };
             let epoch = Epoch(0);
-            let mut fake_window_state = FullWindowState::default();
-            fake_window_state.size.dimensions = size;
+            let fake_window_state = get_fake_window_state(size, options);
+            let mut renderer_resources = RendererResources::default();
+            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+            match options.format {
+                OutputFormat::Debug => println!("{:#?}", layout.scrollable_nodes),
+                format => print_json(&layout_result_to_json(&layout), format),
+            }
+        },
+        Action::PrintHtmlLayout(size) => {
+            let document_id = DocumentId {
+                namespace_id: IdNamespace(0),
+                id: 0,
+            };
+            let epoch = Epoch(0);
+            let fake_window_state = get_fake_window_state(size, options);
             let mut renderer_resources = RendererResources::default();
             let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
-            println!("{:#?}", layout.scrollable_nodes);
+            let image_cache = load_css_images(&layout.styled_dom, input_file, watcher);
+            println!("{}", export_html(&layout.styled_dom, &image_cache, Some(&layout)));
         },
         Action::PrintDisplayList(size) => {
             let epoch = Epoch(0);
@@ -232,8 +410,7 @@ fn process(action: Action, file: Option<&String>) {
                 id: 0,
             };
             let dom_id = DomId { inner: 0 };
-            let mut fake_window_state = FullWindowState::default();
-            fake_window_state.size.dimensions = size;
+            let fake_window_state = get_fake_window_state(size, options);
             let mut renderer_resources = RendererResources::default();
             let image_cache = ImageCache::default();
             let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
@@ -248,10 +425,259 @@ fn process(action: Action, file: Option<&String>) {
                 &image_cache,
             );
 
-            println!("{:#?}", display_list.root);
+            match options.format {
+                OutputFormat::Debug => println!("{:#?}", display_list.root),
+                format => print_json(&display_list_to_json(&display_list), format),
+            }
+        },
+        Action::RenderToPng(size, ref output_path) => {
+
+            let image = match render_to_image(styled_dom, size, options) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: could not render {}x{} image", size.width, size.height);
+                    return false;
+                }
+            };
+
+            let png = match encode_png(&image) {
+                ResultU8VecEncodeImageError::Ok(o) => o,
+                ResultU8VecEncodeImageError::Err(e) => {
+                    eprintln!("error: could not encode PNG: {:?}", e);
+                    return false;
+                }
+            };
+
+            if let Err(e) = fs::write(output_path, png.as_ref()) {
+                eprintln!("error: could not write {}: {}", output_path, e);
+                return false;
+            }
+
+            eprintln!("rendered {} to {}", input_file, output_path);
+        },
+    }
+
+    true
+}
+
+/// Prints the JSON document to stdout, CBOR is written as raw bytes
+fn print_json(value: &JsonValue, format: OutputFormat) {
+    use std::io::Write;
+    if format == OutputFormat::Cbor {
+        let stdout = std::io::stdout();
+        let mut lock = stdout.lock();
+        let _ = lock.write_all(&value.to_cbor());
+        let _ = lock.flush();
+    } else {
+        println!("{}", value.to_json_string());
+    }
+}
+
+/// Formats the file in place, or with `check = true`, fails if the file isn't formatted
+fn format_file(input_file: &str, check: bool) -> bool {
+
+    let source = match fs::read_to_string(input_file) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("error: could not read {}: {}", input_file, e);
+            return false;
+        }
+    };
+
+    let formatted = match format_xml(&source, &XmlFormatOptions::default()) {
+        Ok(o) => o,
+        Err(e) => {
+            eprint!("{}", Diagnostic::from_xml_error(&e).format(input_file, &source));
+            return false;
+        }
+    };
+
+    if formatted == source {
+        return true;
+    }
+
+    if check {
+        eprintln!("{} is not formatted, run `azulc fmt {}`", input_file, input_file);
+        return false;
+    }
+
+    if let Err(e) = fs::write(input_file, formatted) {
+        eprintln!("error: could not write {}: {}", input_file, e);
+        return false;
+    }
+
+    eprintln!("formatted {}", input_file);
+    true
+}
+
+/// Prints the warnings of the linter, returns false if there are any warnings
+fn lint_file(input_file: &str, source: &str, root_nodes: &[XmlNode], component_map: &mut XmlComponentMap) -> bool {
+
+    // the DOM is only necessary to check the CSS selectors, lint the rest even if it is invalid
+    let styled_dom = match azul_core::xml::str_to_dom(root_nodes, component_map) {
+        Ok(o) => Some(o),
+        Err(e) => {
+            eprint!("{}", Diagnostic::from_dom_xml_parse_error(&e).format(input_file, source));
+            None
+        }
+    };
+
+    let warnings = lint_xml(root_nodes, styled_dom.as_ref());
+    for w in warnings.iter() {
+        eprint!("{}", w.format(input_file, source));
+        eprintln!("");
+    }
+
+    match warnings.len() {
+        0 => eprintln!("{}: no warnings", input_file),
+        1 => eprintln!("{}: 1 warning", input_file),
+        n => eprintln!("{}: {} warnings", input_file, n),
+    }
+
+    styled_dom.is_some() && warnings.is_empty()
+}
+
+/// Loads the images that the DOM references via `background: image("path")` into an image
+/// cache (the paths are relative to the XML file) and adds them to the `watcher`.
+/// Images that can't be loaded are skipped with a warning.
+fn load_css_images(styled_dom: &StyledDom, input_file: &str, watcher: &mut FileWatcher) -> ImageCache {
+
+    use azul_core::app_resources::ImageRef;
+    use azulc_lib::image::decode::{decode_raw_image_from_any_bytes, ResultRawImageDecodeImageError};
+
+    let base_dir = Path::new(input_file).parent().unwrap_or(Path::new(""));
+    let mut image_cache = ImageCache::default();
+
+    for image_id in get_css_image_ids(styled_dom) {
+
+        let path = base_dir.join(image_id.as_str());
+        watcher.watch(path.clone());
+
+        let bytes = match fs::read(&path) {
+            Ok(o) => o,
+            Err(e) => {
+                eprintln!("warning: could not load image \"{}\" ({}): {}", image_id.as_str(), path.display(), e);
+                continue;
+            }
+        };
+
+        let image = match decode_raw_image_from_any_bytes(&bytes) {
+            ResultRawImageDecodeImageError::Ok(o) => o,
+            ResultRawImageDecodeImageError::Err(e) => {
+                eprintln!("warning: could not decode image \"{}\": {}", path.display(), e);
+                continue;
+            }
+        };
+
+        match ImageRef::new_rawimage(image) {
+            Some(image) => image_cache.add_css_image_id(image_id, image),
+            None => eprintln!("warning: image \"{}\" has an unsupported pixel format", path.display()),
+        }
+    }
+
+    image_cache
+}
+
+/// Window state that the layout is solved with, since there is no real window
+fn get_fake_window_state(size: LogicalSize, options: &Options) -> FullWindowState {
+    let mut fake_window_state = FullWindowState::default();
+    fake_window_state.size.dimensions = size;
+    fake_window_state.size.hidpi_factor = options.dpi;
+    fake_window_state.size.system_hidpi_factor = options.dpi;
+    fake_window_state.theme = options.theme;
+    fake_window_state
+}
+
+/// Lays out the `styled_dom` and renders it on the CPU, the image has `size * options.dpi`
+/// pixels, the background color depends on the `options.theme`
+fn render_to_image(styled_dom: StyledDom, size: LogicalSize, options: &Options) -> Option<RawImage> {
+
+    let epoch = Epoch(0);
+    let document_id = DocumentId {
+        namespace_id: IdNamespace(0),
+        id: 0,
+    };
+    let dom_id = DomId { inner: 0 };
+    let fake_window_state = get_fake_window_state(size, options);
+    let mut renderer_resources = RendererResources::default();
+    let image_cache = ImageCache::default();
+    let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+    let layouts = [layout];
+    let display_list = LayoutResult::get_cached_display_list(
+        &document_id,
+        dom_id,
+        epoch,
+        &layouts,
+        &fake_window_state,
+        &GlTextureCache::default(),
+        &renderer_resources,
+        &image_cache,
+    );
+
+    let render_options = CpuRenderOptions {
+        background_color: match options.theme {
+            WindowTheme::LightMode => ColorU::WHITE,
+            WindowTheme::DarkMode => ColorU { r: 32, g: 32, b: 32, a: ColorU::ALPHA_OPAQUE },
         },
-        // Action::DisplayFile => // TODO: open window and show the file,
-        // Action::RenderToPng(output_path) -- TODO!
+        dpi_factor: options.dpi,
+    };
+
+    render_display_list_cpu(&display_list, &layouts, &renderer_resources, &image_cache, render_options)
+    .map(unpremultiply_image)
+}
+
+/// PNG stores non-premultiplied colors, but the CPU renderer outputs premultiplied colors
+fn unpremultiply_image(mut image: RawImage) -> RawImage {
+    if !image.premultiplied_alpha {
+        return image;
+    }
+    if let RawImageData::U8(ref pixels) = image.pixels {
+        let mut pixels = pixels.clone().into_library_owned_vec();
+        for px in pixels.chunks_exact_mut(4) {
+            let a = px[3] as u32;
+            if a == 0 || a == 255 {
+                continue;
+            }
+            for c in px[..3].iter_mut() {
+                *c = ((*c as u32 * 255 + a / 2) / a).min(255) as u8;
+            }
+        }
+        image.pixels = RawImageData::U8(pixels.into());
+    }
+    image.premultiplied_alpha = false;
+    image
+}
+
+/// Starts the `azul-preview` binary, which opens a hot-reloading
+/// window for the file and blocks until the window is closed
+fn run_preview(input_file: &str, options: &Options) -> bool {
+
+    let binary_name = if cfg!(windows) { "azul-preview.exe" } else { "azul-preview" };
+
+    // prefer the azul-preview that was installed next to azulc, otherwise search the PATH
+    let binary = env::current_exe().ok()
+        .and_then(|exe| exe.parent().map(|p| p.join(binary_name)))
+        .filter(|p| p.exists())
+        .unwrap_or_else(|| PathBuf::from(binary_name));
+
+    let theme = match options.theme {
+        WindowTheme::DarkMode => "dark",
+        WindowTheme::LightMode => "light",
+    };
+
+    let mut command = Command::new(&binary);
+    command.arg("--theme").arg(theme);
+    for dir in options.component_dirs.iter() {
+        command.arg("--components").arg(dir);
+    }
+
+    match command.arg(input_file).status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("error: could not start {}: {}", binary.display(), e);
+            eprintln!("note: azul-preview is installed with `cargo install azul-desktop --features xml`");
+            false
+        }
     }
 }
 
@@ -329,18 +755,49 @@ fn layout_result_print_layout(result: &LayoutResult) -> String {
     s
 }
 
-fn get_rust_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    azul_core::xml::str_to_rust_code(root_nodes, "", &mut XmlComponentMap::default()).map_err(|e| format!("{}", e))
+fn get_rust_code(root_nodes: &[XmlNode], component_map: &mut XmlComponentMap, file_name: &str, source: &str) -> Result<String, String> {
+    azul_core::xml::str_to_rust_code(root_nodes, "", component_map)
+    .map_err(|e| Diagnostic::from_compile_error(&e).format(file_name, source))
+}
+
+fn get_c_code(root_nodes: &[XmlNode], component_map: &mut XmlComponentMap, file_name: &str, source: &str) -> Result<String, String> {
+    azul_core::xml::str_to_foreign_code(root_nodes, ForeignLanguage::C, component_map)
+    .map_err(|e| Diagnostic::from_compile_error(&e).format(file_name, source))
 }
 
-fn get_c_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+fn get_cpp_code(root_nodes: &[XmlNode], component_map: &mut XmlComponentMap, file_name: &str, source: &str) -> Result<String, String> {
+    azul_core::xml::str_to_foreign_code(root_nodes, ForeignLanguage::Cpp, component_map)
+    .map_err(|e| Diagnostic::from_compile_error(&e).format(file_name, source))
 }
 
-fn get_cpp_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+fn get_python_code(root_nodes: &[XmlNode], component_map: &mut XmlComponentMap, file_name: &str, source: &str) -> Result<String, String> {
+    azul_core::xml::str_to_foreign_code(root_nodes, ForeignLanguage::Python, component_map)
+    .map_err(|e| Diagnostic::from_compile_error(&e).format(file_name, source))
 }
+#[test]
+fn test_render_to_image_dpi_and_theme() {
+
+    use azul_core::dom::Dom;
+    use azul_css::Css;
+
+    let render = |options: &Options| {
+        let styled_dom = Dom::body().style(&mut Css::empty());
+        render_to_image(styled_dom, LogicalSize::new(10.0, 5.0), options).unwrap()
+    };
+
+    let first_pixel = |image: &RawImage| match &image.pixels {
+        RawImageData::U8(u) => u.as_ref()[..4].to_vec(),
+        _ => panic!("expected an RGBA8 image"),
+    };
 
-fn get_python_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
-}
\ No newline at end of file
+    let light = render(&Options::default());
+    assert_eq!((light.width, light.height), (10, 5));
+    assert_eq!(first_pixel(&light), vec![255, 255, 255, 255]);
+
+    let hidpi = render(&Options { dpi: 2.0, .. Options::default() });
+    assert_eq!((hidpi.width, hidpi.height), (20, 10));
+
+    let dark = render(&Options { theme: WindowTheme::DarkMode, .. Options::default() });
+    assert_eq!((dark.width, dark.height), (10, 5));
+    assert_eq!(first_pixel(&dark), vec![32, 32, 32, 255]);
+}
@@ -0,0 +1,632 @@
+//! CPU rasterizer for `CachedDisplayList`s
+//!
+//! Renders a display list into an RGBA image without needing a GPU or a
+//! window, so that layouts can be rendered on headless CI machines. The output
+//! is not pixel-identical to WebRender (no box shadows, transforms or
+//! non-solid border styles), but it is deterministic, which is what matters
+//! for snapshot tests.
+
+#![cfg(feature = "cpurender")]
+
+use alloc::vec::Vec;
+use alloc::collections::btree_map::BTreeMap;
+use azul_css::{ColorU, LayoutPoint, LayoutRect, LayoutSize};
+use azul_core::{
+    app_resources::{
+        ImageCache, RendererResources, ImageKey, ImageRef,
+        FontKey, FontInstanceKey, DecodedImage, ImageData,
+        RawImage, RawImageData, RawImageFormat,
+    },
+    display_list::{
+        CachedDisplayList, DisplayListMsg, DisplayListFrame,
+        LayoutRectContent, RectBackground, GlyphInstance,
+        StyleBorderRadius,
+    },
+    dom::NodeType,
+    ui_solver::{LayoutResult, PositionInfo},
+    window::{LogicalRect, LogicalPosition, LogicalSize},
+};
+use azul_text_layout::text_shaping::{ParsedFont, GlyphOutlineOperation};
+use tiny_skia::{
+    Pixmap, PixmapPaint, Paint, Path, PathBuilder, FillRule,
+    ClipMask, Transform, Rect, Color, Point, GradientStop,
+    LinearGradient as SkLinearGradient, SpreadMode,
+};
+
+/// Options for rendering a display list on the CPU
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CpuRenderOptions {
+    /// Color that the image is cleared with before any content is drawn
+    pub background_color: ColorU,
+    /// Physical pixels per logical pixel, the output image will have
+    /// the size `display_list.root_size * dpi_factor`
+    pub dpi_factor: f32,
+}
+
+impl Default for CpuRenderOptions {
+    fn default() -> Self {
+        Self {
+            background_color: ColorU::WHITE,
+            dpi_factor: 1.0,
+        }
+    }
+}
+
+/// Renders the display list to a premultiplied RGBA8 image
+///
+/// The `layout_results` are only used to look up images that are
+/// referenced directly from the DOM (`NodeType::Image`), images
+/// referenced from CSS are looked up in the `image_cache`.
+pub fn render_display_list_cpu(
+    display_list: &CachedDisplayList,
+    layout_results: &[LayoutResult],
+    renderer_resources: &RendererResources,
+    image_cache: &ImageCache,
+    options: CpuRenderOptions,
+) -> Option<RawImage> {
+
+    let dpi = options.dpi_factor.max(0.01);
+    let width = (display_list.root_size.width * dpi).round().max(1.0) as u32;
+    let height = (display_list.root_size.height * dpi).round().max(1.0) as u32;
+
+    let mut pixmap = Pixmap::new(width, height)?;
+    pixmap.fill(translate_color(options.background_color, 1.0));
+
+    let mut renderer = CpuRenderer {
+        pixmap,
+        dpi,
+        renderer_resources,
+        images: collect_images(layout_results, image_cache, renderer_resources),
+        fonts: BTreeMap::new(),
+    };
+
+    let root_clip = LogicalRect::new(LogicalPosition::zero(), display_list.root_size);
+    renderer.render_msg(&display_list.root, LogicalPosition::zero(), root_clip, &mut Vec::new(), 1.0);
+
+    let CpuRenderer { pixmap, .. } = renderer;
+
+    Some(RawImage {
+        pixels: RawImageData::U8(pixmap.take().into()),
+        width: width as usize,
+        height: height as usize,
+        premultiplied_alpha: true,
+        data_format: RawImageFormat::RGBA8,
+    })
+}
+
+struct CpuRenderer<'a> {
+    pixmap: Pixmap,
+    dpi: f32,
+    renderer_resources: &'a RendererResources,
+    images: BTreeMap<ImageKey, ImageRef>,
+    // fonts are re-parsed with glyph outlines, since the fonts
+    // used for the layout are usually loaded without outlines
+    fonts: BTreeMap<FontKey, Option<ParsedFont>>,
+}
+
+impl<'a> CpuRenderer<'a> {
+
+    fn render_msg(
+        &mut self,
+        msg: &DisplayListMsg,
+        parent_origin: LogicalPosition,
+        parent_clip: LogicalRect,
+        positioned_origins: &mut Vec<LogicalPosition>,
+        parent_opacity: f32,
+    ) {
+        use azul_core::display_list::DisplayListMsg::*;
+
+        let position = msg.get_position();
+        let origin = match position {
+            PositionInfo::Static(p) | PositionInfo::Relative(p) => {
+                LogicalPosition::new(parent_origin.x + p.x_offset, parent_origin.y + p.y_offset)
+            },
+            PositionInfo::Absolute(p) => {
+                let o = positioned_origins.last().copied().unwrap_or(LogicalPosition::zero());
+                LogicalPosition::new(o.x + p.x_offset, o.y + p.y_offset)
+            },
+            PositionInfo::Fixed(p) => LogicalPosition::new(p.x_offset, p.y_offset),
+        };
+
+        let opacity = parent_opacity * msg.get_opacity_key().map(|o| o.1).unwrap_or(1.0);
+
+        if position.is_positioned() {
+            positioned_origins.push(origin);
+        }
+
+        match msg {
+            IFrame(_, clip_size, _, display_list) => {
+                let iframe_clip = intersect_rect(parent_clip, LogicalRect::new(origin, *clip_size));
+                if let Some(iframe_clip) = iframe_clip {
+                    self.render_msg(&display_list.root, origin, iframe_clip, &mut Vec::new(), opacity);
+                }
+            },
+            Frame(f) => {
+                self.render_frame(f, origin, parent_clip, positioned_origins, opacity);
+            },
+            ScrollFrame(sf) => {
+                // scroll frames always clip their children, scroll offsets are ignored
+                let mut frame = sf.frame.clone();
+                frame.clip_children = Some(frame.size);
+                self.render_frame(&frame, origin, parent_clip, positioned_origins, opacity);
+            },
+        }
+
+        if position.is_positioned() {
+            positioned_origins.pop();
+        }
+    }
+
+    fn render_frame(
+        &mut self,
+        frame: &DisplayListFrame,
+        origin: LogicalPosition,
+        parent_clip: LogicalRect,
+        positioned_origins: &mut Vec<LogicalPosition>,
+        opacity: f32,
+    ) {
+        let frame_rect = LogicalRect::new(origin, frame.size);
+        let content_clip = intersect_rect(parent_clip, frame_rect);
+
+        for content in frame.content.iter() {
+            match content {
+                LayoutRectContent::Border { widths, colors, .. } => {
+                    // borders are outside of the content clip
+                    self.render_border(frame_rect, &frame.border_radius, widths, colors, parent_clip, opacity);
+                },
+                other => {
+                    if let Some(content_clip) = content_clip {
+                        self.render_content(other, frame_rect, &frame.border_radius, content_clip, opacity);
+                    }
+                }
+            }
+        }
+
+        let children_clip = match frame.clip_children {
+            Some(_) => match content_clip {
+                Some(s) => s,
+                None => return, // children are fully clipped
+            },
+            None => parent_clip,
+        };
+
+        for child in frame.children.iter() {
+            self.render_msg(child, origin, children_clip, positioned_origins, opacity);
+        }
+    }
+
+    fn render_content(
+        &mut self,
+        content: &LayoutRectContent,
+        rect: LogicalRect,
+        border_radius: &StyleBorderRadius,
+        clip: LogicalRect,
+        opacity: f32,
+    ) {
+        use azul_core::display_list::LayoutRectContent::*;
+
+        let clip_mask = self.build_clip_mask(clip);
+
+        match content {
+            Text { glyphs, font_instance_key, color, .. } => {
+                self.render_text(rect.origin, glyphs, *font_instance_key, *color, clip_mask.as_ref(), opacity);
+            },
+            Background { content, .. } => {
+                let path = match self.rounded_rect_path(rect, border_radius) { Some(s) => s, None => return, };
+                let mut paint = Paint::default();
+                paint.anti_alias = true;
+                match content {
+                    RectBackground::Color(c) => {
+                        paint.set_color(translate_color(*c, opacity));
+                    },
+                    RectBackground::LinearGradient(lg) => {
+                        let layout_rect = LayoutRect::new(
+                            LayoutPoint::new(0, 0),
+                            LayoutSize::new(rect.size.width.round() as isize, rect.size.height.round() as isize),
+                        );
+                        let (start, end) = lg.direction.to_points(&layout_rect);
+                        let stops = lg.stops.iter().map(|s| {
+                            GradientStop::new(s.offset.normalized(), translate_color(s.color, opacity))
+                        }).collect::<Vec<_>>();
+                        let shader = SkLinearGradient::new(
+                            Point::from_xy((rect.origin.x + start.x as f32) * self.dpi, (rect.origin.y + start.y as f32) * self.dpi),
+                            Point::from_xy((rect.origin.x + end.x as f32) * self.dpi, (rect.origin.y + end.y as f32) * self.dpi),
+                            stops,
+                            SpreadMode::Pad,
+                            Transform::identity(),
+                        );
+                        match shader {
+                            Some(s) => { paint.shader = s; },
+                            None => return,
+                        }
+                    },
+                    // radial / conic gradients and image backgrounds are approximated
+                    // with the first color stop, so that the area is at least visible
+                    RectBackground::RadialGradient(rg) => {
+                        let c = match rg.stops.as_ref().first() { Some(s) => s.color, None => return };
+                        paint.set_color(translate_color(c, opacity));
+                    },
+                    RectBackground::ConicGradient(cg) => {
+                        let c = match cg.stops.as_ref().first() { Some(s) => s.color, None => return };
+                        paint.set_color(translate_color(c, opacity));
+                    },
+                    RectBackground::Image((image_key, _)) => {
+                        self.render_image(*image_key, rect, clip_mask.as_ref(), opacity);
+                        return;
+                    },
+                }
+                let _ = self.pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), clip_mask.as_ref());
+            },
+            Image { size, offset, image_key, .. } => {
+                let image_rect = LogicalRect::new(
+                    LogicalPosition::new(rect.origin.x + offset.x, rect.origin.y + offset.y),
+                    *size,
+                );
+                self.render_image(*image_key, image_rect, clip_mask.as_ref(), opacity);
+            },
+            Border { .. } => { }, // handled in render_frame
+        }
+    }
+
+    fn render_border(
+        &mut self,
+        rect: LogicalRect,
+        border_radius: &StyleBorderRadius,
+        widths: &azul_core::display_list::StyleBorderWidths,
+        colors: &azul_core::display_list::StyleBorderColors,
+        clip: LogicalRect,
+        opacity: f32,
+    ) {
+        let top = widths.top_width();
+        let right = widths.right_width();
+        let bottom = widths.bottom_width();
+        let left = widths.left_width();
+
+        let get_color = |c: Option<ColorU>| c.unwrap_or(ColorU::BLACK);
+        let top_color = get_color(colors.top.and_then(|c| c.get_property_owned()).map(|c| c.inner));
+        let right_color = get_color(colors.right.and_then(|c| c.get_property_owned()).map(|c| c.inner));
+        let bottom_color = get_color(colors.bottom.and_then(|c| c.get_property_owned()).map(|c| c.inner));
+        let left_color = get_color(colors.left.and_then(|c| c.get_property_owned()).map(|c| c.inner));
+
+        let clip_mask = self.build_clip_mask(clip);
+
+        // uniform border with a radius: draw the outer rounded rect minus the inner one
+        let is_uniform = top == right && top == bottom && top == left &&
+                         top_color == right_color && top_color == bottom_color && top_color == left_color;
+
+        if is_uniform && !border_radius.is_none() {
+            if top <= 0.0 { return; }
+            let mut pb = PathBuilder::new();
+            self.push_rounded_rect(&mut pb, rect, border_radius, 0.0);
+            let inner = LogicalRect::new(
+                LogicalPosition::new(rect.origin.x + left, rect.origin.y + top),
+                LogicalSize::new((rect.size.width - left - right).max(0.0), (rect.size.height - top - bottom).max(0.0)),
+            );
+            self.push_rounded_rect(&mut pb, inner, border_radius, top);
+            let path = match pb.finish() { Some(s) => s, None => return, };
+            let mut paint = Paint::default();
+            paint.anti_alias = true;
+            paint.set_color(translate_color(top_color, opacity));
+            let _ = self.pixmap.fill_path(&path, &paint, FillRule::EvenOdd, Transform::identity(), clip_mask.as_ref());
+            return;
+        }
+
+        let x = rect.origin.x;
+        let y = rect.origin.y;
+        let w = rect.size.width;
+        let h = rect.size.height;
+
+        let edges = [
+            (LogicalRect::new(LogicalPosition::new(x, y), LogicalSize::new(w, top)), top_color),
+            (LogicalRect::new(LogicalPosition::new(x + w - right, y), LogicalSize::new(right, h)), right_color),
+            (LogicalRect::new(LogicalPosition::new(x, y + h - bottom), LogicalSize::new(w, bottom)), bottom_color),
+            (LogicalRect::new(LogicalPosition::new(x, y), LogicalSize::new(left, h)), left_color),
+        ];
+
+        for (edge, color) in edges.iter() {
+            if edge.size.width <= 0.0 || edge.size.height <= 0.0 { continue; }
+            let sk_rect = match self.translate_rect(*edge) { Some(s) => s, None => continue, };
+            let mut paint = Paint::default();
+            paint.set_color(translate_color(*color, opacity));
+            let _ = self.pixmap.fill_rect(sk_rect, &paint, Transform::identity(), clip_mask.as_ref());
+        }
+    }
+
+    fn render_text(
+        &mut self,
+        origin: LogicalPosition,
+        glyphs: &[GlyphInstance],
+        font_instance_key: FontInstanceKey,
+        color: ColorU,
+        clip_mask: Option<&ClipMask>,
+        opacity: f32,
+    ) {
+        let (font_key, font_size_px) = match self.get_font_instance(font_instance_key) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let renderer_resources = self.renderer_resources;
+        let parsed_font = self.fonts.entry(font_key).or_insert_with(|| {
+            let (font_ref, _) = renderer_resources.currently_registered_fonts.get(&font_key)?;
+            let font_data = font_ref.get_data();
+            ParsedFont::from_bytes(font_data.bytes.as_ref(), font_data.font_index as usize, /* parse_outlines */ true)
+        });
+
+        let parsed_font = match parsed_font.as_ref() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let units_per_em = parsed_font.font_metrics.units_per_em.max(1) as f32;
+        let scale = font_size_px / units_per_em * self.dpi;
+
+        let mut pb = PathBuilder::new();
+        for glyph in glyphs {
+            let outline = match parsed_font.glyph_records_decoded.get(&(glyph.index as u16)).and_then(|g| g.outline.as_ref()) {
+                Some(s) => s,
+                None => continue,
+            };
+            // glyph.point is the position of the baseline, relative to the rect origin
+            let gx = (origin.x + glyph.point.x) * self.dpi;
+            let gy = (origin.y + glyph.point.y) * self.dpi;
+            // font units have the y axis pointing upwards
+            let tx = |x: f32| gx + x * scale;
+            let ty = |y: f32| gy - y * scale;
+            for op in outline.operations.as_ref() {
+                match op {
+                    GlyphOutlineOperation::MoveTo(m) => pb.move_to(tx(m.x), ty(m.y)),
+                    GlyphOutlineOperation::LineTo(l) => pb.line_to(tx(l.x), ty(l.y)),
+                    GlyphOutlineOperation::QuadraticCurveTo(q) => pb.quad_to(
+                        tx(q.ctrl_1_x), ty(q.ctrl_1_y),
+                        tx(q.end_x), ty(q.end_y),
+                    ),
+                    GlyphOutlineOperation::CubicCurveTo(c) => pb.cubic_to(
+                        tx(c.ctrl_1_x), ty(c.ctrl_1_y),
+                        tx(c.ctrl_2_x), ty(c.ctrl_2_y),
+                        tx(c.end_x), ty(c.end_y),
+                    ),
+                    GlyphOutlineOperation::ClosePath => pb.close(),
+                }
+            }
+        }
+
+        let path = match pb.finish() { Some(s) => s, None => return, };
+        let mut paint = Paint::default();
+        paint.anti_alias = true;
+        paint.set_color(translate_color(color, opacity));
+        let _ = self.pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), clip_mask);
+    }
+
+    fn render_image(
+        &mut self,
+        image_key: ImageKey,
+        rect: LogicalRect,
+        clip_mask: Option<&ClipMask>,
+        opacity: f32,
+    ) {
+        let image_pixmap = match self.images.get(&image_key).and_then(|i| image_ref_to_pixmap(i)) {
+            Some(s) => s,
+            None => return,
+        };
+
+        if image_pixmap.width() == 0 || image_pixmap.height() == 0 { return; }
+
+        let sx = rect.size.width * self.dpi / image_pixmap.width() as f32;
+        let sy = rect.size.height * self.dpi / image_pixmap.height() as f32;
+        let transform = Transform {
+            sx,
+            kx: 0.0,
+            ky: 0.0,
+            sy,
+            tx: rect.origin.x * self.dpi,
+            ty: rect.origin.y * self.dpi,
+        };
+
+        let mut paint = PixmapPaint::default();
+        paint.opacity = opacity;
+        let _ = self.pixmap.draw_pixmap(0, 0, image_pixmap.as_ref(), &paint, transform, clip_mask);
+    }
+
+    fn get_font_instance(&self, font_instance_key: FontInstanceKey) -> Option<(FontKey, f32)> {
+        self.renderer_resources.currently_registered_fonts.iter().find_map(|(font_key, (_, instances))| {
+            instances.iter()
+            .find(|(_, k)| **k == font_instance_key)
+            .map(|(au, _)| (*font_key, au.into_px()))
+        })
+    }
+
+    fn build_clip_mask(&self, clip: LogicalRect) -> Option<ClipMask> {
+        let full = LogicalRect::new(
+            LogicalPosition::zero(),
+            LogicalSize::new(self.pixmap.width() as f32 / self.dpi, self.pixmap.height() as f32 / self.dpi),
+        );
+        if clip.origin.x <= 0.0 && clip.origin.y <= 0.0 &&
+           clip.size.width >= full.size.width && clip.size.height >= full.size.height {
+            return None; // clip covers the entire canvas
+        }
+        let path = PathBuilder::from_rect(self.translate_rect(clip)?);
+        let mut clip_mask = ClipMask::new();
+        clip_mask.set_path(self.pixmap.width(), self.pixmap.height(), &path, FillRule::Winding, false)?;
+        Some(clip_mask)
+    }
+
+    fn translate_rect(&self, r: LogicalRect) -> Option<Rect> {
+        Rect::from_xywh(
+            r.origin.x * self.dpi,
+            r.origin.y * self.dpi,
+            r.size.width * self.dpi,
+            r.size.height * self.dpi,
+        )
+    }
+
+    fn rounded_rect_path(&self, rect: LogicalRect, border_radius: &StyleBorderRadius) -> Option<Path> {
+        if border_radius.is_none() {
+            return Some(PathBuilder::from_rect(self.translate_rect(rect)?));
+        }
+        let mut pb = PathBuilder::new();
+        self.push_rounded_rect(&mut pb, rect, border_radius, 0.0);
+        pb.finish()
+    }
+
+    /// Pushes a rounded rect, `inset` is subtracted from each radius (for inner border edges)
+    fn push_rounded_rect(&self, pb: &mut PathBuilder, rect: LogicalRect, border_radius: &StyleBorderRadius, inset: f32) {
+
+        let max_radius = rect.size.width.min(rect.size.height) / 2.0;
+        let resolve = |r: Option<f32>| (r.unwrap_or(0.0) - inset).max(0.0).min(max_radius) * self.dpi;
+
+        let tl = resolve(border_radius.top_left.and_then(|r| r.get_property_owned()).map(|r| r.inner.to_pixels(rect.size.width)));
+        let tr = resolve(border_radius.top_right.and_then(|r| r.get_property_owned()).map(|r| r.inner.to_pixels(rect.size.width)));
+        let br = resolve(border_radius.bottom_right.and_then(|r| r.get_property_owned()).map(|r| r.inner.to_pixels(rect.size.width)));
+        let bl = resolve(border_radius.bottom_left.and_then(|r| r.get_property_owned()).map(|r| r.inner.to_pixels(rect.size.width)));
+
+        let x0 = rect.origin.x * self.dpi;
+        let y0 = rect.origin.y * self.dpi;
+        let x1 = (rect.origin.x + rect.size.width) * self.dpi;
+        let y1 = (rect.origin.y + rect.size.height) * self.dpi;
+
+        pb.move_to(x0 + tl, y0);
+        pb.line_to(x1 - tr, y0);
+        pb.quad_to(x1, y0, x1, y0 + tr);
+        pb.line_to(x1, y1 - br);
+        pb.quad_to(x1, y1, x1 - br, y1);
+        pb.line_to(x0 + bl, y1);
+        pb.quad_to(x0, y1, x0, y1 - bl);
+        pb.line_to(x0, y0 + tl);
+        pb.quad_to(x0, y0, x0 + tl, y0);
+        pb.close();
+    }
+}
+
+/// Collects all CPU-backed images, keyed by the `ImageKey` they were registered with
+fn collect_images(
+    layout_results: &[LayoutResult],
+    image_cache: &ImageCache,
+    renderer_resources: &RendererResources,
+) -> BTreeMap<ImageKey, ImageRef> {
+
+    let dom_images = layout_results.iter().flat_map(|lr| {
+        lr.styled_dom.node_data.as_ref().iter().filter_map(|nd| match nd.get_node_type() {
+            NodeType::Image(i) => Some(i.clone()),
+            _ => None,
+        }).collect::<Vec<_>>()
+    });
+
+    let css_images = image_cache.image_id_map.values().cloned();
+
+    dom_images.chain(css_images).filter_map(|image_ref| {
+        let (image_key, _) = renderer_resources.currently_registered_images.get(&image_ref.get_hash())?;
+        Some((*image_key, image_ref))
+    }).collect()
+}
+
+fn image_ref_to_pixmap(image_ref: &ImageRef) -> Option<Pixmap> {
+
+    let (descriptor, data) = match image_ref.get_data() {
+        DecodedImage::Raw((descriptor, ImageData::Raw(data))) => (descriptor, data),
+        _ => return None, // GL textures, callbacks and null images can't be rendered on the CPU
+    };
+
+    let mut pixmap = Pixmap::new(descriptor.width as u32, descriptor.height as u32)?;
+    let data = data.as_ref();
+    let pixels = pixmap.data_mut();
+
+    macro_rules! convert {($bpp:expr, $f:expr) => ({
+        for (src, dst) in data.chunks_exact($bpp).zip(pixels.chunks_exact_mut(4)) {
+            let (r, g, b, a): (u8, u8, u8, u8) = $f(src);
+            // tiny-skia expects premultiplied alpha
+            let premultiply = |c: u8| ((c as u16 * a as u16) / 255) as u8;
+            dst[0] = premultiply(r);
+            dst[1] = premultiply(g);
+            dst[2] = premultiply(b);
+            dst[3] = a;
+        }
+    })}
+
+    match descriptor.format {
+        RawImageFormat::R8 => convert!(1, |s: &[u8]| (s[0], s[0], s[0], 255)),
+        RawImageFormat::RG8 => convert!(2, |s: &[u8]| (s[0], s[0], s[0], s[1])),
+        RawImageFormat::RGB8 => convert!(3, |s: &[u8]| (s[0], s[1], s[2], 255)),
+        RawImageFormat::RGBA8 => convert!(4, |s: &[u8]| (s[0], s[1], s[2], s[3])),
+        RawImageFormat::BGR8 => convert!(3, |s: &[u8]| (s[2], s[1], s[0], 255)),
+        RawImageFormat::BGRA8 => convert!(4, |s: &[u8]| (s[2], s[1], s[0], s[3])),
+        _ => return None, // 16-bit formats are not supported
+    }
+
+    Some(pixmap)
+}
+
+fn intersect_rect(a: LogicalRect, b: LogicalRect) -> Option<LogicalRect> {
+    let x0 = a.origin.x.max(b.origin.x);
+    let y0 = a.origin.y.max(b.origin.y);
+    let x1 = (a.origin.x + a.size.width).min(b.origin.x + b.size.width);
+    let y1 = (a.origin.y + a.size.height).min(b.origin.y + b.size.height);
+    if x1 <= x0 || y1 <= y0 {
+        None
+    } else {
+        Some(LogicalRect::new(LogicalPosition::new(x0, y0), LogicalSize::new(x1 - x0, y1 - y0)))
+    }
+}
+
+#[inline]
+fn translate_color(c: ColorU, opacity: f32) -> Color {
+    let a = (c.a as f32 * opacity.max(0.0).min(1.0)).round() as u8;
+    Color::from_rgba8(c.r, c.g, c.b, a)
+}
+
+#[cfg(test)]
+fn get_pixel(image: &RawImage, x: usize, y: usize) -> [u8;4] {
+    match &image.pixels {
+        RawImageData::U8(u) => {
+            let i = (y * image.width + x) * 4;
+            let p = &u.as_ref()[i..(i + 4)];
+            [p[0], p[1], p[2], p[3]]
+        },
+        _ => panic!("expected an RGBA8 image"),
+    }
+}
+
+#[test]
+fn test_render_display_list_cpu() {
+
+    // 20x10 root with a red 10x4 rectangle at (5, 2)
+    let mut rect = DisplayListFrame::root(LayoutSize::new(10, 4), LayoutPoint::new(5, 2));
+    rect.content.push(LayoutRectContent::Background {
+        content: RectBackground::Color(ColorU::RED),
+        size: None,
+        offset: None,
+        repeat: None,
+    });
+    let mut root = DisplayListFrame::root(LayoutSize::new(20, 10), LayoutPoint::zero());
+    root.children.push(DisplayListMsg::Frame(rect));
+    let display_list = CachedDisplayList {
+        root: DisplayListMsg::Frame(root),
+        root_size: LogicalSize::new(20.0, 10.0),
+    };
+
+    let render = |options| render_display_list_cpu(
+        &display_list,
+        &[],
+        &RendererResources::default(),
+        &ImageCache::default(),
+        options,
+    ).unwrap();
+
+    let image = render(CpuRenderOptions::default());
+    assert_eq!((image.width, image.height), (20, 10));
+    assert!(image.premultiplied_alpha);
+    assert_eq!(get_pixel(&image, 0, 0), [255, 255, 255, 255]);
+    assert_eq!(get_pixel(&image, 5, 2), [255, 0, 0, 255]);
+    assert_eq!(get_pixel(&image, 14, 5), [255, 0, 0, 255]);
+    assert_eq!(get_pixel(&image, 15, 5), [255, 255, 255, 255]);
+    assert_eq!(get_pixel(&image, 14, 6), [255, 255, 255, 255]);
+
+    // the DPI factor scales the image and all content
+    let image = render(CpuRenderOptions { background_color: ColorU::BLACK, dpi_factor: 2.0 });
+    assert_eq!((image.width, image.height), (40, 20));
+    assert_eq!(get_pixel(&image, 9, 4), [0, 0, 0, 255]);
+    assert_eq!(get_pixel(&image, 10, 4), [255, 0, 0, 255]);
+    assert_eq!(get_pixel(&image, 29, 11), [255, 0, 0, 255]);
+    assert_eq!(get_pixel(&image, 30, 11), [0, 0, 0, 255]);
+    assert_eq!(get_pixel(&image, 29, 12), [0, 0, 0, 255]);
+}
@@ -41,7 +41,8 @@ pub fn domxml_from_str(xml: &str, component_map: &mut XmlComponentMap) -> DomXml
     DomXml { parsed_dom }
 }
 
-/// Loads, parses and builds a DOM from an XML file
+/// Loads, parses and builds a DOM from an XML file, including the
+/// stylesheets linked via `<link rel="stylesheet" href="..."/>`
 ///
 /// **Warning**: The file is reloaded from disk on every function call - do not
 /// use this in release builds! This function deliberately never fails: In an error case,
@@ -49,22 +50,30 @@ pub fn domxml_from_str(xml: &str, component_map: &mut XmlComponentMap) -> DomXml
 #[cfg(all(feature = "std", feature = "xml"))]
 pub fn domxml_from_file<I: AsRef<Path>>(file_path: I, component_map: &mut XmlComponentMap) -> DomXml {
 
-    use std::fs;
+    use crate::hot_reload::{load_xml_file, FileWatcher};
+    use crate::diagnostics::Diagnostic;
 
-    let mut error_css = Css::empty();
-
-    let xml = match fs::read_to_string(file_path.as_ref()) {
-        Ok(xml) => xml,
-        Err(e) => return DomXml {
+    fn render_error(e: String) -> DomXml {
+        DomXml {
             parsed_dom: Dom::body()
-            .with_children(vec![
-                Dom::text(format!("Error reading: \"{}\": {}", file_path.as_ref().to_string_lossy(), e))
-            ].into())
-            .style(&mut error_css),
-        },
+            .with_children(vec![Dom::text(e)].into())
+            .style(&mut Css::empty()),
+        }
+    }
+
+    let file_path = file_path.as_ref();
+
+    let file = match load_xml_file(file_path, &mut FileWatcher::new()) {
+        Ok(file) => file,
+        Err(e) => return render_error(e),
+    };
+
+    let parsed_dom = match str_to_dom(file.nodes.as_ref(), component_map) {
+        Ok(o) => o,
+        Err(e) => return render_error(Diagnostic::from_dom_xml_parse_error(&e).format(&file_path.display().to_string(), &file.source)),
     };
 
-    domxml_from_str(&xml, component_map)
+    DomXml { parsed_dom }
 }
 
 /// Parses the XML string into an XML tree, returns
@@ -74,19 +83,20 @@ pub fn domxml_from_file<I: AsRef<Path>>(file_path: I, component_map: &mut XmlCom
 /// a `Vec<XmlNode>` - which are the "root" nodes, containing all their
 /// children recursively.
 ///
+/// Every node remembers its location in the source string (see `XmlNode::span`),
+/// so that later errors (unknown components, invalid CSS, etc.) can point to
+/// the line and column of the offending node.
+///
 /// # Example
 ///
 /// ```rust
 /// # use azulc::xml::{XmlNode, parse_xml_string};
-/// assert_eq!(
-///     parse_xml_string("<app><p /><div id='thing' /></app>").unwrap(),
-///     vec![
-///          XmlNode::new("app").with_children(vec![
-///             XmlNode::new("p"),
-///             XmlNode::new("div").with_attribute("id", "thing"),
-///         ])
-///     ]
-/// )
+/// let nodes = parse_xml_string("<app><p /><div id='thing' /></app>").unwrap();
+/// let app = &nodes.as_ref()[0];
+/// assert_eq!(app.node_type.as_str(), "app");
+/// assert_eq!(app.children.as_ref()[1].node_type.as_str(), "div");
+/// assert_eq!(app.children.as_ref()[1].span.start.row, 1);
+/// assert_eq!(app.children.as_ref()[1].span.start.col, 11);
 /// ```
 #[cfg(feature = "xml")]
 pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
@@ -97,6 +107,7 @@ pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
     let mut root_node = XmlNode::default();
 
     let tokenizer = Tokenizer::from_fragment(xml, 0..xml.len());
+    let line_index = crate::diagnostics::LineIndex::new(xml);
 
     // In order to insert where the item is, let's say
     // [0 -> 1st element, 5th-element -> node]
@@ -109,34 +120,56 @@ pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
         .map_err(|e| XmlError::ParserError(translate_xmlparser_error(e)))?;
 
         match token {
-            ElementStart { local, .. } => {
+            ElementStart { prefix, local, span } => {
                 if let Some(current_parent) = get_item(&current_hierarchy, &mut root_node) {
                     let children_len = current_parent.children.as_ref().len();
                     current_parent.children.push(XmlNode {
-                        node_type: normalize_casing(local.as_str()).into(),
+                        node_type: normalize_node_name(prefix.as_str(), local.as_str()).into(),
                         attributes: Vec::new().into(),
                         children: Vec::new().into(),
                         text: None.into(),
+                        span: line_index.get_span(span.start(), span.end()),
+                        attribute_spans: Vec::new().into(),
+                        text_span: XmlSpan::default(),
                     });
                     current_hierarchy.push(children_len);
                 }
             },
-            ElementEnd { end: Empty, .. } => {
+            ElementEnd { end: Empty, span } => {
+                if let Some(last) = get_item(&current_hierarchy, &mut root_node) {
+                    last.span.end = line_index.get_pos(span.end());
+                }
                 current_hierarchy.pop();
             },
-            ElementEnd { end: Close(_, close_value), .. } => {
-                let close_value = normalize_casing(close_value.as_str());
+            ElementEnd { end: Close(close_prefix, close_value), span } => {
+                let close_value = normalize_node_name(close_prefix.as_str(), close_value.as_str());
                 if let Some(last) = get_item(&current_hierarchy, &mut root_node) {
                     if last.node_type.as_str() != close_value.as_str() {
-                        return Err(XmlError::MalformedHierarchy(close_value.into(), last.node_type.clone().into()));
+                        return Err(XmlError::UnexpectedCloseTag(UnexpectedCloseTagError {
+                            expected: last.node_type.clone(),
+                            actual: close_value.into(),
+                            pos: line_index.get_pos(span.start()),
+                        }));
                     }
+                    last.span.end = line_index.get_pos(span.end());
                 }
                 current_hierarchy.pop();
             },
-            Attribute { local, value, .. } => {
+            Attribute { local, value, span, .. } => {
                 if let Some(last) = get_item(&current_hierarchy, &mut root_node) {
                     // NOTE: Only lowercase the key ("local"), not the value!
-                    last.attributes.insert_kv(normalize_casing(local.as_str()), value.as_str().to_string());
+                    let key = normalize_casing(local.as_str());
+                    let attribute_span = line_index.get_span(span.start(), span.end());
+                    match last.attributes.as_ref().iter().position(|a| a.key.as_str() == key.as_str()) {
+                        Some(i) => {
+                            // duplicated attribute: the last value wins
+                            if let Some(s) = last.attribute_spans.as_mut().get_mut(i) {
+                                *s = attribute_span;
+                            }
+                        },
+                        None => last.attribute_spans.push(attribute_span),
+                    }
+                    last.attributes.insert_kv(key, value.as_str().to_string());
                 }
             },
             Text { text } => {
@@ -148,6 +181,7 @@ pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
                     }
                     if last.text.is_none() {
                         last.text = Some(AzString::from(text.as_str())).into();
+                        last.text_span = line_index.get_span(text.start(), text.end());
                     }
                 }
             }
@@ -158,6 +192,17 @@ pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
     Ok(root_node.children)
 }
 
+/// Normalizes the name of a node, nodes with a namespace prefix (`<cards:card-header>`)
+/// refer to components of an `<import>`ed file (`cards:card_header`)
+#[cfg(feature = "xml")]
+fn normalize_node_name(prefix: &str, local: &str) -> String {
+    if prefix.is_empty() {
+        normalize_casing(local)
+    } else {
+        format!("{}:{}", normalize_casing(prefix), normalize_casing(local))
+    }
+}
+
 #[cfg(feature = "xml")]
 pub fn parse_xml(s: &str) -> Result<Xml, XmlError> {
     Ok(Xml { root: parse_xml_string(s)? })
@@ -207,7 +252,7 @@ fn translate_xmlparser_streamerror(e: xmlparser::StreamError) -> XmlStreamError
 }
 
 #[cfg(feature = "xml")]
-fn translate_xmlparser_error(e: xmlparser::Error) -> XmlParseError {
+pub(crate) fn translate_xmlparser_error(e: xmlparser::Error) -> XmlParseError {
     match e {
         xmlparser::Error::InvalidDeclaration(se, tp) => XmlParseError::InvalidDeclaration(XmlTextError { stream_error: translate_xmlparser_streamerror(se), pos: translate_roxml_textpos(tp) }),
         xmlparser::Error::InvalidComment(se, tp) => XmlParseError::InvalidComment(XmlTextError { stream_error: translate_xmlparser_streamerror(se), pos: translate_roxml_textpos(tp) }),
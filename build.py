@@ -1167,9 +1167,12 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("dom", "Dom", "with_dataset"), # ok: replaced
         ("dom", "Dom", "add_callback"), # ok: replaced
         ("dom", "Dom", "with_callback"), # ok: replaced
+        ("dom", "Dom", "add_shortcut"), # ok: replaced
+        ("dom", "Dom", "with_shortcut"), # ok: replaced
 
         ("dom", "NodeData", "add_callback"), # ok: replaced
         ("dom", "NodeData", "with_callback"), # ok: replaced
+        ("dom", "NodeData", "add_shortcut"), # ok: replaced
         ("dom", "NodeData", "iframe"), # ok: replaced
         ("dom", "NodeData", "set_dataset"), # ok: replaced
         ("dom", "NodeData", "with_dataset"), # ok: replaced
@@ -1184,6 +1187,7 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("task", "Timer", "new"),
         ("callbacks", "CallbackInfo", "start_thread"),
         ("callbacks", "CallbackInfo", "get_node_id_of_root_dataset"),
+        ("callbacks", "CallbackInfo", "start_drag"),
         ("image", "ImageRef", "callback"),
 
         ("widgets", "CheckBox", "set_on_toggle"),
@@ -1204,6 +1208,33 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("widgets", "NumberInput", "set_on_text_input"),
         ("widgets", "NumberInput", "set_on_virtual_key_down"),
         ("widgets", "NumberInput", "set_on_focus_lost"),
+        ("widgets", "TextInput", "set_on_validation_change"),
+        ("widgets", "TextInput", "with_on_validation_change"),
+        ("widgets", "DropDown", "set_on_choice_change"),
+        ("widgets", "DropDown", "with_on_choice_change"),
+        ("widgets", "TabContainer", "set_on_tab_change"),
+        ("widgets", "TabContainer", "with_on_tab_change"),
+        ("widgets", "TabContainer", "set_on_tab_close"),
+        ("widgets", "TabContainer", "with_on_tab_close"),
+        ("widgets", "TabContainer", "set_on_tab_move"),
+        ("widgets", "TabContainer", "with_on_tab_move"),
+        ("widgets", "SplitPane", "set_on_resize"),
+        ("widgets", "SplitPane", "with_on_resize"),
+        ("widgets", "Slider", "set_on_value_change"),
+        ("widgets", "Slider", "with_on_value_change"),
+        ("widgets", "TextEdit", "set_on_text_change"),
+        ("widgets", "TextEdit", "with_on_text_change"),
+        ("widgets", "TreeView", "set_on_load_children"),
+        ("widgets", "TreeView", "with_on_load_children"),
+        ("widgets", "TreeView", "set_on_selection_change"),
+        ("widgets", "TreeView", "with_on_selection_change"),
+        ("widgets", "TreeView", "set_on_node_moved"),
+        ("widgets", "TreeView", "with_on_node_moved"),
+        ("widgets", "VirtualList", "new"),
+        ("widgets", "VirtualList", "from_state"),
+        ("widgets", "VirtualList", "get_state"),
+        ("widgets", "VirtualList", "set_on_scroll"),
+        ("widgets", "VirtualList", "with_on_scroll"),
 
         # unnecessary due to Python string wrappers
         ("str", "String", "as_refstr"),
@@ -1220,6 +1251,7 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("dom", "NodeData"): read_file(root_folder + "/api/_patches/python/nodedata.rs"),
         ("widgets", "Button"): read_file(root_folder + "/api/_patches/python/button.rs"),
         ("callbacks", "LayoutCallback"): read_file(root_folder + "/api/_patches/python/layout_callback.rs"),
+        ("callbacks", "CallbackInfo"): read_file(root_folder + "/api/_patches/python/callback_info.rs"),
         ("window", "WindowCreateOptions"): read_file(root_folder + "/api/_patches/python/window_create_options.rs"),
         ("window", "WindowState"): read_file(root_folder + "/api/_patches/python/window_state.rs"),
     }